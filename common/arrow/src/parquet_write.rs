@@ -17,9 +17,18 @@ use std::io::Write;
 use arrow::array::Array;
 use arrow::chunk::Chunk;
 use arrow::datatypes::Schema;
+use arrow::error::ArrowError;
 use arrow::error::Result;
+use arrow::io::parquet::write::array_to_page;
+use arrow::io::parquet::write::array_to_pages;
 use arrow::io::parquet::write::to_parquet_schema;
 use arrow::io::parquet::write::RowGroupIterator;
+use parquet2::encoding::Encoding;
+use parquet2::metadata::ColumnDescriptor;
+use parquet2::page::EncodedPage;
+use parquet2::write::Compressor;
+use parquet2::write::DynIter;
+use parquet2::write::DynStreamingIterator;
 use parquet2::write::FileWriter;
 use parquet2::write::WriteOptions;
 use parquet2::FileMetaData;
@@ -50,3 +59,65 @@ where
     let (size, _writer, file_meta_data) = file_writer.end_ext(None)?;
     Ok((size, file_meta_data))
 }
+
+// Write the chunk as a single row group, the columns are split into data pages
+// of at most `row_per_page` rows, 0 means one data page per column.
+pub fn write_parquet_chunk<W: Write, A>(
+    writer: &mut W,
+    chunk: Chunk<A>,
+    schema: Schema,
+    options: WriteOptions,
+    encodings: Vec<Encoding>,
+    row_per_page: usize,
+) -> Result<(u64, FileMetaData)>
+where
+    W: Write,
+    A: AsRef<dyn Array> + 'static + Send + Sync,
+{
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let num_rows = chunk.len();
+    let columns = chunk
+        .into_arrays()
+        .into_iter()
+        .zip(parquet_schema.columns().to_vec())
+        .zip(encodings)
+        .map(|((array, descriptor), encoding)| {
+            let pages = column_pages(array.as_ref(), descriptor, options, encoding, row_per_page)?;
+            let pages =
+                Compressor::new(DynIter::new(pages.into_iter()), options.compression, vec![])
+                    .map_err(ArrowError::from);
+            Ok(DynStreamingIterator::new(pages))
+        })
+        .collect::<Vec<_>>();
+
+    let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+    let mut file_writer = FileWriter::new(writer, parquet_schema, options, created_by);
+
+    file_writer.start()?;
+    file_writer.write(DynIter::new(columns.into_iter()), num_rows)?;
+    let (size, _writer, file_meta_data) = file_writer.end_ext(None)?;
+    Ok((size, file_meta_data))
+}
+
+fn column_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    row_per_page: usize,
+) -> Result<Vec<Result<EncodedPage>>> {
+    // A column chunk has at most one dictionary page, so the dictionary encoded
+    // columns are not split.
+    if row_per_page == 0 || array.len() <= row_per_page || encoding == Encoding::RleDictionary {
+        return Ok(array_to_pages(array, descriptor, options, encoding)?.collect());
+    }
+
+    Ok((0..array.len())
+        .step_by(row_per_page)
+        .map(|offset| {
+            let length = row_per_page.min(array.len() - offset);
+            let page = array.slice(offset, length);
+            array_to_page(page.as_ref(), descriptor.clone(), options, encoding)
+        })
+        .collect())
+}
@@ -23,6 +23,7 @@ use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
@@ -57,6 +58,8 @@ pub trait ExprVisitor: Sized + Send {
             Expr::Subquery(subquery) => self.visit_subquery(subquery),
             Expr::Function(function) => self.visit_function(function).await,
             Expr::Cast { expr, data_type } => self.visit_cast(expr, data_type).await,
+            Expr::TryCast { expr, data_type } => self.visit_try_cast(expr, data_type).await,
+            Expr::Collate { expr, collation } => self.visit_collate(expr, collation).await,
             Expr::TypedString { data_type, value } => self.visit_typed_string(data_type, value),
             Expr::Position {
                 substr_expr,
@@ -79,6 +82,7 @@ pub trait ExprVisitor: Sized + Send {
             Expr::Tuple(exprs) => self.visit_tuple(exprs).await,
             Expr::InList { expr, list, .. } => self.visit_inlist(expr, list).await,
             Expr::Extract { field, expr } => self.visit_extract(field, expr).await,
+            Expr::MapAccess { column, keys } => self.visit_map_access(column, keys).await,
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -157,6 +161,16 @@ pub trait ExprVisitor: Sized + Send {
             };
         }
 
+        // The PARTITION BY and then the ORDER BY expressions of the window follow the arguments.
+        if let Some(window) = &function.over {
+            for expr in &window.partition_by {
+                ExprTraverser::accept(expr, self).await?;
+            }
+            for order_by in &window.order_by {
+                ExprTraverser::accept(&order_by.expr, self).await?;
+            }
+        }
+
         Ok(())
     }
 
@@ -164,6 +178,14 @@ pub trait ExprVisitor: Sized + Send {
         ExprTraverser::accept(expr, self).await
     }
 
+    async fn visit_try_cast(&mut self, expr: &Expr, _data_type: &DataType) -> Result<()> {
+        ExprTraverser::accept(expr, self).await
+    }
+
+    async fn visit_collate(&mut self, expr: &Expr, _collation: &ObjectName) -> Result<()> {
+        ExprTraverser::accept(expr, self).await
+    }
+
     fn visit_typed_string(&mut self, _data_type: &DataType, _value: &str) -> Result<()> {
         Ok(())
     }
@@ -225,4 +247,8 @@ pub trait ExprVisitor: Sized + Send {
     async fn visit_extract(&mut self, _field: &DateTimeField, expr: &Expr) -> Result<()> {
         ExprTraverser::accept(expr, self).await
     }
+
+    async fn visit_map_access(&mut self, column: &Expr, _keys: &[Value]) -> Result<()> {
+        ExprTraverser::accept(column, self).await
+    }
 }
@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use async_trait::async_trait;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Function;
@@ -32,6 +33,11 @@ use crate::parser::expr::ExprVisitor;
 pub struct UDFParser {
     name: String,
     expr_params: HashSet<String>,
+    // The aggregate functions the visited expression is in.
+    aggregate_depth: usize,
+    has_aggregate: bool,
+    // A parameter is used out of the aggregate functions.
+    has_bare_param: bool,
 }
 
 impl UDFParser {
@@ -46,6 +52,30 @@ impl UDFParser {
         Ok(expr)
     }
 
+    /// Parse the definition of an aggregate UDF, the parameters must only be used as the
+    /// arguments of the aggregate functions, e.g. `sum(x) / count(x)`.
+    pub async fn parse_aggregate(
+        &mut self,
+        name: &str,
+        parameters: &[String],
+        definition: &str,
+    ) -> Result<Expr> {
+        let expr = self.parse(name, parameters, definition).await?;
+        if !self.has_aggregate {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Aggregate function {} must call aggregate functions: {}",
+                name, definition
+            )));
+        }
+        if self.has_bare_param {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Parameters of aggregate function {} must be used in aggregate functions: {}",
+                name, definition
+            )));
+        }
+        Ok(expr)
+    }
+
     fn parse_definition(&mut self, definition: &str) -> Result<Expr> {
         let dialect = &GenericDialect {};
         let mut tokenizer = Tokenizer::new(dialect, definition);
@@ -73,6 +103,9 @@ impl UDFParser {
         let expr_params = &mut self.expr_params;
         expr_params.clear();
         self.name = name.to_string();
+        self.aggregate_depth = 0;
+        self.has_aggregate = false;
+        self.has_bare_param = false;
 
         ExprTraverser::accept(definition_expr, self).await?;
         let expr_params = &self.expr_params;
@@ -102,16 +135,32 @@ impl UDFParser {
 
 #[async_trait]
 impl ExprVisitor for UDFParser {
+    async fn pre_visit(&mut self, expr: &Expr) -> Result<Expr> {
+        if let Expr::Function(Function { name, .. }) = expr {
+            if AggregateFunctionFactory::instance().check(name.to_string()) {
+                self.aggregate_depth += 1;
+                self.has_aggregate = true;
+            }
+        }
+        Ok(expr.clone())
+    }
+
     async fn post_visit(&mut self, expr: &Expr) -> Result<()> {
         match expr {
             Expr::Identifier(Ident { value, .. }) => {
                 let expr_params = &mut self.expr_params;
                 expr_params.insert(value.to_string());
+                if self.aggregate_depth == 0 {
+                    self.has_bare_param = true;
+                }
 
                 Ok(())
             }
             Expr::Function(Function { name, .. }) => {
                 let name = name.to_string();
+                if AggregateFunctionFactory::instance().check(&name) {
+                    self.aggregate_depth -= 1;
+                }
                 if !is_builtin_function(&name) && self.name == name {
                     Err(ErrorCode::SyntaxException(format!(
                         "Function is not builtin or defined: {}",
@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The number of the CPUs the process can use: the logical CPUs, limited by the CPU quota
+/// of the cgroup (v1 or v2) if it runs in a container.
+pub fn get_available_cpus() -> usize {
+    let cpus = num_cpus::get();
+    match cgroup_cpu_quota() {
+        Some(quota) => cpus.min(quota).max(1),
+        None => cpus,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn cgroup_cpu_quota() -> Option<usize> {
+    match std::fs::read_to_string("/sys/fs/cgroup/cpu.max") {
+        Ok(cpu_max) => parse_cgroup_v2_cpu_max(&cpu_max),
+        Err(_) => {
+            let read = |path| std::fs::read_to_string(path).ok();
+            let quota = read("/sys/fs/cgroup/cpu/cpu.cfs_quota_us")?;
+            let period = read("/sys/fs/cgroup/cpu/cpu.cfs_period_us")?;
+            parse_cgroup_v1_cpu_quota(&quota, &period)
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cgroup_cpu_quota() -> Option<usize> {
+    None
+}
+
+/// The CPUs of the cgroup v2 `cpu.max`: `<quota> <period>`, or `max <period>` if unlimited.
+pub fn parse_cgroup_v2_cpu_max(cpu_max: &str) -> Option<usize> {
+    let mut parts = cpu_max.split_whitespace();
+    let quota = parts.next()?.parse::<i64>().ok()?;
+    let period = parts.next()?.parse::<i64>().ok()?;
+    quota_to_cpus(quota, period)
+}
+
+/// The CPUs of the cgroup v1 `cpu.cfs_quota_us` and `cpu.cfs_period_us`, the quota is -1 if
+/// unlimited.
+pub fn parse_cgroup_v1_cpu_quota(quota: &str, period: &str) -> Option<usize> {
+    let quota = quota.trim().parse::<i64>().ok()?;
+    let period = period.trim().parse::<i64>().ok()?;
+    quota_to_cpus(quota, period)
+}
+
+// A fraction of a CPU still needs a thread.
+fn quota_to_cpus(quota: i64, period: i64) -> Option<usize> {
+    match quota > 0 && period > 0 {
+        true => Some(((quota + period - 1) / period) as usize),
+        false => None,
+    }
+}
@@ -14,6 +14,7 @@
 
 #![feature(thread_local)]
 
+mod cpus;
 mod format;
 mod http_shutdown_handlers;
 mod net;
@@ -28,6 +29,9 @@ mod string_func;
 mod thread;
 mod uniq_id;
 
+pub use cpus::get_available_cpus;
+pub use cpus::parse_cgroup_v1_cpu_quota;
+pub use cpus::parse_cgroup_v2_cpu_max;
 pub use format::Format;
 pub use http_shutdown_handlers::HttpShutdownHandler;
 pub use net::get_free_tcp_port;
@@ -38,8 +42,10 @@ pub use progress::ProgressValues;
 pub use runtime::Dropper;
 pub use runtime::Runtime;
 pub use runtime::TrySpawn;
+pub use runtime_tracker::MemoryTracker;
 pub use runtime_tracker::RuntimeTracker;
 pub use runtime_tracker::ThreadTracker;
+pub use runtime_tracker::ThreadTrackerScope;
 pub use shutdown_signal::signal_stream;
 pub use shutdown_signal::DummySignalStream;
 pub use shutdown_signal::SignalStream;
@@ -13,9 +13,14 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::collections::HashMap;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 #[thread_local]
 static mut TRACKER: *mut ThreadTracker = std::ptr::null_mut();
@@ -25,6 +30,8 @@ static UNTRACKED_MEMORY_LIMIT: i64 = 4 * 1024 * 1024;
 pub struct ThreadTracker {
     rt_tracker: Arc<RuntimeTracker>,
     untracked_memory: i64,
+    // The memory is also tracked by it in a scope, see `ThreadTracker::track_scope`.
+    scoped_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
 impl ThreadTracker {
@@ -33,6 +40,7 @@ impl ThreadTracker {
             TRACKER = Box::into_raw(Box::new(ThreadTracker {
                 rt_tracker,
                 untracked_memory: 0,
+                scoped_memory_tracker: None,
             }));
 
             TRACKER
@@ -61,11 +69,7 @@ impl ThreadTracker {
                 (*TRACKER).untracked_memory += size;
 
                 if (*TRACKER).untracked_memory > UNTRACKED_MEMORY_LIMIT {
-                    (*TRACKER)
-                        .rt_tracker
-                        .memory_tracker
-                        .alloc_memory((*TRACKER).untracked_memory);
-                    (*TRACKER).untracked_memory = 0;
+                    (*TRACKER).flush_untracked_memory();
                 }
             }
         }
@@ -78,16 +82,26 @@ impl ThreadTracker {
                 (*TRACKER).untracked_memory -= size;
 
                 if (*TRACKER).untracked_memory < -UNTRACKED_MEMORY_LIMIT {
-                    (*TRACKER)
-                        .rt_tracker
-                        .memory_tracker
-                        .dealloc_memory(-(*TRACKER).untracked_memory);
-                    (*TRACKER).untracked_memory = 0;
+                    (*TRACKER).flush_untracked_memory();
                 }
             }
         }
     }
 
+    /// Fails once an allocation of the runtime of the current thread exceeded the limit of its
+    /// memory tracker, see `MemoryTracker::check_limit`. It only loads an atomic, so it's called
+    /// inside the loops doing big allocations, e.g. building the hash tables or merging the
+    /// sorted blocks, which would run out of memory before the work is done otherwise.
+    #[inline]
+    pub fn check_memory_limit() -> Result<()> {
+        unsafe {
+            match TRACKER.is_null() {
+                true => Ok(()),
+                false => (*TRACKER).rt_tracker.memory_tracker.check_limit(),
+            }
+        }
+    }
+
     #[inline]
     pub fn realloc_memory(old_size: i64, new_size: i64) {
         let addition = new_size - old_size;
@@ -96,10 +110,54 @@ impl ThreadTracker {
             false => Self::dealloc_memory(-addition),
         }
     }
+
+    /// Tracks the memory allocated and freed by the current thread with `memory_tracker` as well,
+    /// until the returned scope is dropped. Nothing is tracked if the thread has no tracker.
+    pub fn track_scope(memory_tracker: Arc<MemoryTracker>) -> ThreadTrackerScope {
+        unsafe {
+            if TRACKER.is_null() {
+                return ThreadTrackerScope { previous: None };
+            }
+
+            // The memory allocated before the scope is not tracked by the scope.
+            (*TRACKER).flush_untracked_memory();
+            let previous = (*TRACKER).scoped_memory_tracker.replace(memory_tracker);
+            ThreadTrackerScope { previous }
+        }
+    }
+
+    #[inline]
+    fn flush_untracked_memory(&mut self) {
+        let size = std::mem::replace(&mut self.untracked_memory, 0);
+        self.rt_tracker.memory_tracker.track_memory(size);
+        if let Some(scoped_memory_tracker) = &self.scoped_memory_tracker {
+            scoped_memory_tracker.track_memory(size);
+        }
+    }
+}
+
+pub struct ThreadTrackerScope {
+    previous: Option<Arc<MemoryTracker>>,
+}
+
+impl Drop for ThreadTrackerScope {
+    fn drop(&mut self) {
+        unsafe {
+            if !TRACKER.is_null() {
+                (*TRACKER).flush_untracked_memory();
+                (*TRACKER).scoped_memory_tracker = self.previous.take();
+            }
+        }
+    }
 }
 
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    peak_memory_usage: AtomicI64,
+    // The max memory usage, 0 if there is no limit.
+    limit: AtomicI64,
+    // The memory usage which exceeded the limit first, 0 if it's never exceeded.
+    exceeded_memory_usage: AtomicI64,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -108,12 +166,34 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            peak_memory_usage: AtomicI64::new(0),
+            limit: AtomicI64::new(0),
+            exceeded_memory_usage: AtomicI64::new(0),
         })
     }
 
+    /// Limit the memory usage, 0 means no limit. The limit is checked on each allocation
+    /// tracked by the tracker, the allocation exceeding it can't fail by itself, so it marks the
+    /// tracker as failed there and every later `check_limit` reports it, the big allocations
+    /// check it while they grow, see `ThreadTracker::check_memory_limit`.
+    pub fn set_limit(&self, limit: i64) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn alloc_memory(&self, size: i64) {
-        self.memory_usage.fetch_add(size, Ordering::Relaxed);
+        let usage = self.memory_usage.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_memory_usage.fetch_max(usage, Ordering::Relaxed);
+
+        let limit = self.limit.load(Ordering::Relaxed);
+        if limit > 0 && usage > limit {
+            let _ = self.exceeded_memory_usage.compare_exchange(
+                0,
+                usage,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.alloc_memory(size);
@@ -129,6 +209,19 @@ impl MemoryTracker {
         }
     }
 
+    // Allocates if the size is positive, otherwise deallocates.
+    #[inline]
+    fn track_memory(&self, size: i64) {
+        match size >= 0 {
+            true => self.alloc_memory(size),
+            false => self.dealloc_memory(-size),
+        }
+    }
+
+    pub fn get_parent_memory_tracker(&self) -> Option<Arc<MemoryTracker>> {
+        self.parent_memory_tracker.clone()
+    }
+
     #[inline]
     pub fn current() -> Option<Arc<MemoryTracker>> {
         unsafe {
@@ -144,10 +237,31 @@ impl MemoryTracker {
     pub fn get_memory_usage(&self) -> i64 {
         self.memory_usage.load(Ordering::Relaxed)
     }
+
+    /// The max memory usage since the tracker is created.
+    #[inline]
+    pub fn get_peak_memory_usage(&self) -> i64 {
+        self.peak_memory_usage.load(Ordering::Relaxed)
+    }
+
+    /// Fails once an allocation exceeded the limit, even if the memory is freed since.
+    #[inline]
+    pub fn check_limit(&self) -> Result<()> {
+        match self.exceeded_memory_usage.load(Ordering::Relaxed) {
+            0 => Ok(()),
+            usage => Err(ErrorCode::MemoryLimitExceeded(format!(
+                "Query memory usage {} bytes exceeds the max_memory_usage {} bytes",
+                usage,
+                self.limit.load(Ordering::Relaxed)
+            ))),
+        }
+    }
 }
 
 pub struct RuntimeTracker {
     memory_tracker: Arc<MemoryTracker>,
+    // The memory tracked by categories, e.g. the kinds of the processors, in the runtime.
+    category_memory_trackers: Mutex<HashMap<String, Arc<MemoryTracker>>>,
 }
 
 impl RuntimeTracker {
@@ -155,6 +269,7 @@ impl RuntimeTracker {
         let parent_memory_tracker = MemoryTracker::current();
         Arc::new(RuntimeTracker {
             memory_tracker: MemoryTracker::create(parent_memory_tracker),
+            category_memory_trackers: Mutex::new(HashMap::new()),
         })
     }
 
@@ -163,6 +278,34 @@ impl RuntimeTracker {
         &self.memory_tracker
     }
 
+    /// The tracker of a category, it has no parent: the memory is already tracked by the
+    /// tracker of the runtime.
+    pub fn get_category_memory_tracker(&self, category: &str) -> Arc<MemoryTracker> {
+        let mut trackers = self.category_memory_trackers.lock().unwrap();
+        match trackers.get(category) {
+            Some(tracker) => tracker.clone(),
+            None => {
+                let tracker = MemoryTracker::create(None);
+                trackers.insert(category.to_string(), tracker.clone());
+                tracker
+            }
+        }
+    }
+
+    /// The memory usage and the peak memory usage of the categories, ordered by the category.
+    pub fn get_category_memory_usages(&self) -> Vec<(String, i64, i64)> {
+        let trackers = self.category_memory_trackers.lock().unwrap();
+        let mut usages = trackers
+            .iter()
+            .map(|(category, tracker)| {
+                let usage = tracker.get_memory_usage();
+                (category.clone(), usage, tracker.get_peak_memory_usage())
+            })
+            .collect::<Vec<_>>();
+        usages.sort();
+        usages
+    }
+
     pub fn on_stop_thread(self: &Arc<Self>) -> impl Fn() {
         move || unsafe {
             let tracker = std::mem::replace(&mut TRACKER, std::ptr::null_mut());
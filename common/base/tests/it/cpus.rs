@@ -0,0 +1,35 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::get_available_cpus;
+use common_base::parse_cgroup_v1_cpu_quota;
+use common_base::parse_cgroup_v2_cpu_max;
+
+#[test]
+fn test_cgroup_cpu_quota() {
+    assert_eq!(parse_cgroup_v2_cpu_max("400000 100000\n"), Some(4));
+    assert_eq!(parse_cgroup_v2_cpu_max("150000 100000\n"), Some(2));
+    assert_eq!(parse_cgroup_v2_cpu_max("max 100000\n"), None);
+    assert_eq!(parse_cgroup_v2_cpu_max(""), None);
+
+    assert_eq!(parse_cgroup_v1_cpu_quota("200000\n", "100000\n"), Some(2));
+    assert_eq!(parse_cgroup_v1_cpu_quota("-1\n", "100000\n"), None);
+}
+
+#[test]
+fn test_get_available_cpus() {
+    let cpus = get_available_cpus();
+    assert!(cpus >= 1);
+    assert!(cpus <= num_cpus::get());
+}
@@ -59,3 +59,36 @@ async fn test_runtime() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_runtime_memory_limit() -> Result<()> {
+    let runtime = Runtime::with_worker_threads(1, None)?;
+    let tracker = runtime.get_tracker();
+    tracker.get_memory_tracker().set_limit(2048);
+
+    // The threads of the runtime see the limit of its tracker.
+    assert!(runtime
+        .spawn(async { ThreadTracker::check_memory_limit() })
+        .await
+        .unwrap()
+        .is_ok());
+
+    tracker.get_memory_tracker().alloc_memory(4096);
+    tracker.get_memory_tracker().dealloc_memory(4096);
+    let result = runtime
+        .spawn(async { ThreadTracker::check_memory_limit() })
+        .await
+        .unwrap();
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Query memory usage 4096 bytes exceeds the max_memory_usage 2048 bytes"
+    );
+
+    // The threads outside of a runtime have no limit.
+    assert!(std::thread::spawn(ThreadTracker::check_memory_limit)
+        .join()
+        .unwrap()
+        .is_ok());
+
+    Ok(())
+}
@@ -16,3 +16,4 @@ mod pool;
 
 pub use pool::ItemManager;
 pub use pool::Pool;
+pub use pool::PoolItem;
@@ -18,6 +18,8 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use async_trait::async_trait;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
 use opendal::io_util::observe_read;
 use opendal::io_util::observe_write;
 use opendal::io_util::ReadEvent;
@@ -78,13 +80,16 @@ impl Layer for DalContext {
 #[async_trait]
 impl Accessor for DalContext {
     async fn create(&self, args: &OpCreate) -> Result<()> {
-        self.get_inner()?.create(args).await
+        let span = tracing::debug_span!("dal_create", path = args.path.as_str());
+        self.get_inner()?.create(args).instrument(span).await
     }
 
     async fn read(&self, args: &OpRead) -> Result<BytesReader> {
         let metric = self.metrics.clone();
+        let span = tracing::debug_span!("dal_read", path = args.path.as_str());
 
-        self.get_inner()?.read(args).await.map(|r| {
+        let inner = self.get_inner()?;
+        inner.read(args).instrument(span).await.map(|r| {
             let mut last_pending = None;
             let r = observe_read(r, move |e| {
                 let start = match last_pending {
@@ -109,8 +114,10 @@ impl Accessor for DalContext {
 
     async fn write(&self, args: &OpWrite) -> Result<BytesWriter> {
         let metric = self.metrics.clone();
+        let span = tracing::debug_span!("dal_write", path = args.path.as_str());
 
-        self.get_inner()?.write(args).await.map(|w| {
+        let inner = self.get_inner()?;
+        inner.write(args).instrument(span).await.map(|w| {
             let mut last_pending = None;
             let w = observe_write(w, move |e| {
                 let start = match last_pending {
@@ -134,14 +141,17 @@ impl Accessor for DalContext {
     }
 
     async fn stat(&self, args: &OpStat) -> Result<Metadata> {
-        self.get_inner()?.stat(args).await
+        let span = tracing::debug_span!("dal_stat", path = args.path.as_str());
+        self.get_inner()?.stat(args).instrument(span).await
     }
 
     async fn delete(&self, args: &OpDelete) -> Result<()> {
-        self.get_inner()?.delete(args).await
+        let span = tracing::debug_span!("dal_delete", path = args.path.as_str());
+        self.get_inner()?.delete(args).instrument(span).await
     }
 
     async fn list(&self, args: &OpList) -> Result<ObjectStreamer> {
-        self.get_inner()?.list(args).await
+        let span = tracing::debug_span!("dal_list", path = args.path.as_str());
+        self.get_inner()?.list(args).instrument(span).await
     }
 }
@@ -32,6 +32,8 @@ pub struct DalMetrics {
     partitions_scanned: Arc<AtomicU64>,
     /// Number of partitions, before pruning
     partitions_total: Arc<AtomicU64>,
+    /// Number of partitions taken by the readers so far
+    partitions_read: Arc<AtomicU64>,
 }
 
 impl DalMetrics {
@@ -94,4 +96,14 @@ impl DalMetrics {
     pub fn get_partitions_total(&self) -> u64 {
         self.partitions_total.load(Ordering::Relaxed)
     }
+
+    pub fn inc_partitions_read(&self, v: u64) {
+        if v > 0 {
+            self.partitions_read.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_partitions_read(&self) -> u64 {
+        self.partitions_read.load(Ordering::Relaxed)
+    }
 }
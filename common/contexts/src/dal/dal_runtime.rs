@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use common_base::tokio::runtime::Handle;
+use common_tracing::tracing::Instrument;
 use opendal::ops::OpCreate;
 use opendal::ops::OpDelete;
 use opendal::ops::OpList;
@@ -79,7 +80,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.create(&args).await })
+            .spawn(async move { op.create(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -88,7 +89,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.read(&args).await })
+            .spawn(async move { op.read(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -97,7 +98,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.write(&args).await })
+            .spawn(async move { op.write(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -106,7 +107,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.stat(&args).await })
+            .spawn(async move { op.stat(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -115,7 +116,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.delete(&args).await })
+            .spawn(async move { op.delete(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -124,7 +125,7 @@ impl Accessor for DalRuntime {
         let op = self.get_inner()?;
         let args = args.clone();
         self.runtime
-            .spawn(async move { op.list(&args).await })
+            .spawn(async move { op.list(&args).await }.in_current_span())
             .await
             .expect("join must success")
     }
@@ -210,10 +210,12 @@ impl TryFrom<DataBlock> for Chunk<ArrayRef> {
     type Error = ErrorCode;
 
     fn try_from(v: DataBlock) -> Result<Chunk<ArrayRef>> {
+        // The decimal arrays are tagged with the precision and the scale of their fields.
         let arrays = v
             .columns()
             .iter()
-            .map(|c| c.as_arrow_array())
+            .zip(v.schema().fields().iter())
+            .map(|(c, f)| decimal_arrow_array(c.as_arrow_array(), &f.data_type().arrow_type()))
             .collect::<Vec<_>>();
 
         Ok(Chunk::try_new(arrays)?)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::collation_of;
 use common_datavalues::remove_nullable;
 use common_datavalues::TypeID;
 use common_exception::Result;
@@ -122,4 +123,42 @@ impl DataBlock {
             }
         })
     }
+
+    /// Strings with a collation are grouped by their folded form, e.g. lowercase for the
+    /// case insensitive collations, the group by columns are replaced by the folded strings.
+    /// The block is only used to build the keys, the aggregates read the original strings.
+    pub fn fold_collations(block: DataBlock, column_names: &[String]) -> Result<DataBlock> {
+        let schema = block.schema().clone();
+        let mut columns = block.columns().to_vec();
+        let mut folded = false;
+        for name in column_names {
+            let index = schema.index_of(name)?;
+            let collation = collation_of(schema.field(index).data_type());
+            if !collation.is_binary() {
+                columns[index] = collation.fold_column(&columns[index])?;
+                folded = true;
+            }
+        }
+
+        match folded {
+            true => Ok(DataBlock::create(schema, columns)),
+            false => Ok(block),
+        }
+    }
+
+    /// The folded group by columns are replaced by an original string of each group, given the
+    /// name of each group by column with the name of the column of its value.
+    pub fn unfold_collations(block: DataBlock, values: &[(String, String)]) -> Result<DataBlock> {
+        if values.is_empty() {
+            return Ok(block);
+        }
+
+        let schema = block.schema().clone();
+        let mut columns = block.columns().to_vec();
+        for (name, value_name) in values {
+            let index = schema.index_of(name)?;
+            columns[index] = block.try_column_by_name(value_name)?.clone();
+        }
+        Ok(DataBlock::create(schema, columns))
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ use common_arrow::arrow::array::Array;
 use common_arrow::arrow::array::ArrayRef;
 use common_arrow::arrow::compute::merge_sort::*;
 use common_arrow::arrow::compute::sort as arrow_sort;
+use common_base::ThreadTracker;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -38,9 +40,13 @@ impl DataBlock {
         sort_columns_descriptions: &[SortColumnDescription],
         limit: Option<usize>,
     ) -> Result<DataBlock> {
+        if Self::need_sort_by_values(block, sort_columns_descriptions)? {
+            return Self::sort_block_by_values(block, sort_columns_descriptions, limit);
+        }
+
         let order_columns = sort_columns_descriptions
             .iter()
-            .map(|f| Ok(block.try_column_by_name(&f.column_name)?.as_arrow_array()))
+            .map(|f| Ok(Self::sort_column(block, &f.column_name)?.as_arrow_array()))
             .collect::<Result<Vec<_>>>()?;
 
         let order_arrays = sort_columns_descriptions
@@ -61,6 +67,75 @@ impl DataBlock {
         DataBlock::block_take_by_indices(block, indices.values())
     }
 
+    /// The column to order by, strings with a collation are ordered by their sort keys.
+    fn sort_column(block: &DataBlock, column_name: &str) -> Result<ColumnRef> {
+        let column = block.try_column_by_name(column_name)?;
+        let field = block.schema().field_with_name(column_name)?;
+        let collation = collation_of(field.data_type());
+        match collation.is_binary() {
+            true => Ok(column.clone()),
+            false => collation.sort_key_column(column),
+        }
+    }
+
+    /// Arrow can't compare nested types like arrays, structs and maps,
+    /// they are sorted by the comparison of values.
+    fn need_sort_by_values(
+        block: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<bool> {
+        for f in sort_columns_descriptions {
+            let column = block.try_column_by_name(&f.column_name)?;
+            let type_id = remove_nullable(&column.data_type()).data_type_id();
+            if matches!(type_id, TypeID::Array | TypeID::Struct | TypeID::Map) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn sort_block_by_values(
+        block: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+        limit: Option<usize>,
+    ) -> Result<DataBlock> {
+        let order_values = sort_columns_descriptions
+            .iter()
+            .map(|f| Ok(Self::sort_column(block, &f.column_name)?.to_values()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut indices: Vec<u32> = (0..block.num_rows() as u32).collect();
+        indices.sort_by(|a, b| {
+            for (values, f) in order_values.iter().zip(sort_columns_descriptions) {
+                let (lhs, rhs) = (&values[*a as usize], &values[*b as usize]);
+                let ordering = match (lhs.is_null(), rhs.is_null()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) if f.nulls_first => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                    (false, true) if f.nulls_first => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => {
+                        let ordering = lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal);
+                        if f.asc {
+                            ordering
+                        } else {
+                            ordering.reverse()
+                        }
+                    }
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+
+        if let Some(limit) = limit {
+            indices.truncate(limit);
+        }
+        DataBlock::block_take_by_indices(block, &indices)
+    }
+
     pub fn merge_sort_block(
         lhs: &DataBlock,
         rhs: &DataBlock,
@@ -75,13 +150,18 @@ impl DataBlock {
             return Ok(lhs.clone());
         }
 
+        if Self::need_sort_by_values(lhs, sort_columns_descriptions)? {
+            let block = DataBlock::concat_blocks(&[lhs.clone(), rhs.clone()])?;
+            return Self::sort_block_by_values(&block, sort_columns_descriptions, limit);
+        }
+
         let sort_arrays = sort_columns_descriptions
             .iter()
             .map(|f| {
-                let left = lhs.try_column_by_name(&f.column_name)?.clone();
+                let left = Self::sort_column(lhs, &f.column_name)?;
                 let left = left.as_arrow_array();
 
-                let right = rhs.try_column_by_name(&f.column_name)?.clone();
+                let right = Self::sort_column(rhs, &f.column_name)?;
                 let right = right.as_arrow_array();
 
                 Ok(vec![left, right])
@@ -177,6 +257,10 @@ impl DataBlock {
         sort_columns_descriptions: &[SortColumnDescription],
         limit: Option<usize>,
     ) -> Result<DataBlock> {
+        // The merged block can be as big as all the blocks, the memory limit of the query is
+        // checked before each merge.
+        ThreadTracker::check_memory_limit()?;
+
         match blocks.len() {
             0 => Result::Err(ErrorCode::EmptyData("Can't merge empty blocks")),
             1 => Ok(blocks[0].clone()),
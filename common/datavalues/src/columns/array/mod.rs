@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mutable;
+
 use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::types::Index;
+pub use mutable::*;
 
 use crate::prelude::*;
 
@@ -129,12 +133,37 @@ impl Column for ArrayColumn {
         }
     }
 
-    fn scatter(&self, _indices: &[usize], _scattered_size: usize) -> Vec<ColumnRef> {
-        todo!()
-    }
+    fn scatter(&self, indices: &[usize], scattered_size: usize) -> Vec<ColumnRef> {
+        (0..scattered_size)
+            .map(|s| {
+                let filter = indices.iter().map(|index| *index == s).collect::<Vec<_>>();
+                self.filter(&BooleanColumn::from_slice(&filter))
+            })
+            .collect()
+    }
+
+    fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        // Filter the values by a mask which keeps the elements of the selected arrays.
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0);
+        let mut mask = MutableBitmap::with_capacity(self.values.len());
+        mask.extend_constant(self.offsets[0] as usize, false);
+
+        for (i, keep) in filter.values().iter().enumerate() {
+            let size = self.size_at_index(i);
+            if keep {
+                offsets.push(offsets.last().unwrap() + size as i64);
+            }
+            mask.extend_constant(size, keep);
+        }
+        mask.extend_constant(self.values.len() - mask.len(), false);
 
-    fn filter(&self, _filter: &BooleanColumn) -> ColumnRef {
-        todo!()
+        let values = self.values.filter(&BooleanColumn::from_arrow_data(mask.into()));
+        Arc::new(Self {
+            data_type: self.data_type.clone(),
+            offsets: offsets.into(),
+            values,
+        })
     }
 
     fn replicate(&self, offsets: &[usize]) -> ColumnRef {
@@ -143,9 +172,18 @@ impl Column for ArrayColumn {
             "Size of offsets must match size of column"
         );
 
-        // match datatypes
-        // TODO: see https://github.com/ClickHouse/ClickHouse/blob/340b53ef853348758c9042b16a8599120ebc8d22/src/Columns/ColumnArray.cpp
-        todo!()
+        let capacity = offsets.last().cloned().unwrap_or_default();
+        let mut builder = MutableArrayColumn::with_capacity(self.data_type.clone(), capacity);
+
+        let mut previous_offset: usize = 0;
+        for (i, offset) in offsets.iter().enumerate() {
+            let value = self.get(i);
+            for _ in previous_offset..*offset {
+                builder.append_data_value(value.clone()).unwrap();
+            }
+            previous_offset = *offset;
+        }
+        builder.to_column()
     }
 
     fn convert_full_column(&self) -> ColumnRef {
@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct MutableArrayColumn {
+    data_type: DataTypePtr,
+    offsets: Vec<i64>,
+    inner_column: Box<dyn MutableColumn>,
+}
+
+impl MutableArrayColumn {
+    pub fn with_capacity(data_type: DataTypePtr, capacity: usize) -> Self {
+        // Map columns are arrays of Struct(key, value).
+        let inner_type = match data_type.as_any().downcast_ref::<MapType>() {
+            Some(map_type) => map_type.entry_type(),
+            None => {
+                let array_type: &ArrayType = data_type.as_any().downcast_ref().unwrap();
+                array_type.inner_type().clone()
+            }
+        };
+
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+
+        Self {
+            data_type,
+            offsets,
+            inner_column: inner_type.create_mutable(capacity),
+        }
+    }
+
+    pub fn inner_column(&mut self) -> &mut Box<dyn MutableColumn> {
+        &mut self.inner_column
+    }
+
+    /// Mark the end of the current array, the values of it must be appended
+    /// to the inner column before.
+    #[inline]
+    pub fn add_offset(&mut self) {
+        self.offsets.push(self.inner_column.len() as i64);
+    }
+
+    pub fn append_value(&mut self, values: Vec<DataValue>) -> Result<()> {
+        for v in values {
+            self.inner_column.append_data_value(v)?;
+        }
+        self.add_offset();
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> ArrayColumn {
+        let mut offsets = std::mem::take(&mut self.offsets);
+        offsets.shrink_to_fit();
+        self.offsets.push(0);
+
+        ArrayColumn::from_data(
+            self.data_type.clone(),
+            offsets.into(),
+            self.inner_column.to_column(),
+        )
+    }
+}
+
+impl MutableColumn for MutableArrayColumn {
+    fn data_type(&self) -> DataTypePtr {
+        self.data_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn append_default(&mut self) {
+        self.add_offset();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.offsets.shrink_to_fit();
+        self.inner_column.shrink_to_fit();
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn to_column(&mut self) -> ColumnRef {
+        Arc::new(self.finish())
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(values) => self.append_value(values),
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append to array column",
+                other.value_type()
+            ))),
+        }
+    }
+}
@@ -173,11 +173,14 @@ where A: AsRef<dyn Array>
             Int16 => Arc::new(Int16Column::from_arrow_array(self.as_ref())),
             Int32 | Date32 => Arc::new(Int32Column::from_arrow_array(self.as_ref())),
             Int64 | Interval | DateTime64 => Arc::new(Int64Column::from_arrow_array(self.as_ref())),
+            Decimal => Arc::new(Int128Column::from_arrow_array(self.as_ref())),
             Float32 => Arc::new(Float32Column::from_arrow_array(self.as_ref())),
             Float64 => Arc::new(Float64Column::from_arrow_array(self.as_ref())),
-            Array => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
+            Array | Map => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
             Struct => Arc::new(StructColumn::from_arrow_array(self.as_ref())),
-            String => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
+            String | Bitmap | Geometry | Decimal256 => {
+                Arc::new(StringColumn::from_arrow_array(self.as_ref()))
+            }
             Variant => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
             VariantArray => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
             VariantObject => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
@@ -247,10 +250,10 @@ impl std::fmt::Debug for dyn Column + '_ {
                 Boolean => {
                     fmt_dyn!(col, BooleanColumn, f)
                 },
-                String => {
+                String | Bitmap | Geometry | Decimal256 => {
                     fmt_dyn!(col, StringColumn, f)
                 },
-                Array => {
+                Array | Map => {
                     fmt_dyn!(col, ArrayColumn, f)
                 },
                 Struct => {
@@ -102,6 +102,15 @@ impl Series {
         }
 
         let column = Series::remove_nullable(&column);
+        if matches!(column.data_type_id(), TypeID::Array | TypeID::Map) {
+            let col: &ArrayColumn = Series::check_get(&column)?;
+            return GroupHash::serialize(col, vec, nulls);
+        }
+        if column.data_type_id() == TypeID::Struct {
+            let col: &StructColumn = Series::check_get(&column)?;
+            return GroupHash::serialize(col, vec, nulls);
+        }
+
         let type_id = column.data_type_id().to_physical_type();
 
         with_match_scalar_type!(type_id, |$T| {
@@ -294,5 +303,53 @@ impl GroupHash for StringColumn {
     }
 }
 
+impl GroupHash for ArrayColumn {
+    /// Arrays are serialized as the number of elements followed by the serialized elements.
+    fn serialize(&self, vec: &mut Vec<SmallVu8>, nulls: Option<Bitmap>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+
+        let values = self.values();
+        let mut values_vec = vec![SmallVu8::new(); values.len()];
+        Series::serialize(values, &mut values_vec, None)?;
+
+        for (i, vec) in vec.iter_mut().enumerate() {
+            if let Some(bitmap) = &nulls {
+                let valid = bitmap.get_bit(i);
+                BinaryWrite::write_scalar(vec, &valid)?;
+                if !valid {
+                    continue;
+                }
+            }
+
+            let start = self.offsets()[i] as usize;
+            let end = self.offsets()[i + 1] as usize;
+            BinaryWrite::write_uvarint(vec, (end - start) as u64)?;
+            for value in &values_vec[start..end] {
+                vec.extend_from_slice(value);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl GroupHash for StructColumn {
+    /// Structs are serialized as the serialized fields one by one.
+    fn serialize(&self, vec: &mut Vec<SmallVu8>, nulls: Option<Bitmap>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+
+        if let Some(bitmap) = &nulls {
+            for (i, vec) in vec.iter_mut().enumerate() {
+                BinaryWrite::write_scalar(vec, &bitmap.get_bit(i))?;
+            }
+        }
+
+        for value in self.values() {
+            Series::serialize(value, vec, None)?;
+        }
+        Ok(())
+    }
+}
+
 // TODO(b41sh): implement GroupHash for JsonColumn
 impl GroupHash for JsonColumn {}
@@ -0,0 +1,188 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A compact binary encoding of JSON values, used to store VARIANT columns.
+//!
+//! Layout of an encoded value:
+//! - a leading `JSONB_MAGIC` byte, which is never the first byte of a valid UTF-8 string,
+//!   so the values written as JSON text by older versions can still be decoded.
+//! - a tag byte followed by the payload of the value:
+//!   - null, false, true: no payload.
+//!   - u64, i64, f64: 8 bytes in little endian.
+//!   - string: varint length followed by the UTF-8 bytes.
+//!   - array: varint number of elements followed by the encoded elements.
+//!   - object: varint number of entries followed by (varint key length, key, value).
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value as JsonValue;
+
+pub const JSONB_MAGIC: u8 = 0x80;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_UINT64: u8 = 3;
+const TAG_INT64: u8 = 4;
+const TAG_FLOAT64: u8 = 5;
+const TAG_STRING: u8 = 6;
+const TAG_ARRAY: u8 = 7;
+const TAG_OBJECT: u8 = 8;
+
+pub fn encode_jsonb(value: &JsonValue, buf: &mut Vec<u8>) {
+    buf.push(JSONB_MAGIC);
+    encode_value(value, buf);
+}
+
+/// Decode a value written by `encode_jsonb`, or a JSON text written by older versions.
+pub fn decode_jsonb(bytes: &[u8]) -> Result<JsonValue> {
+    match bytes.first() {
+        Some(&JSONB_MAGIC) => {
+            let mut reader = &bytes[1..];
+            let value = decode_value(&mut reader)?;
+            if !reader.is_empty() {
+                return Err(corrupted());
+            }
+            Ok(value)
+        }
+        _ => Ok(serde_json::from_slice(bytes)?),
+    }
+}
+
+fn encode_value(value: &JsonValue, buf: &mut Vec<u8>) {
+    match value {
+        JsonValue::Null => buf.push(TAG_NULL),
+        JsonValue::Bool(false) => buf.push(TAG_FALSE),
+        JsonValue::Bool(true) => buf.push(TAG_TRUE),
+        JsonValue::Number(n) => {
+            if let Some(v) = n.as_u64() {
+                buf.push(TAG_UINT64);
+                buf.extend_from_slice(&v.to_le_bytes());
+            } else if let Some(v) = n.as_i64() {
+                buf.push(TAG_INT64);
+                buf.extend_from_slice(&v.to_le_bytes());
+            } else {
+                buf.push(TAG_FLOAT64);
+                buf.extend_from_slice(&n.as_f64().unwrap_or_default().to_le_bytes());
+            }
+        }
+        JsonValue::String(s) => {
+            buf.push(TAG_STRING);
+            encode_str(s, buf);
+        }
+        JsonValue::Array(values) => {
+            buf.push(TAG_ARRAY);
+            encode_varint(values.len() as u64, buf);
+            for v in values {
+                encode_value(v, buf);
+            }
+        }
+        JsonValue::Object(obj) => {
+            buf.push(TAG_OBJECT);
+            encode_varint(obj.len() as u64, buf);
+            for (k, v) in obj {
+                encode_str(k, buf);
+                encode_value(v, buf);
+            }
+        }
+    }
+}
+
+fn decode_value(reader: &mut &[u8]) -> Result<JsonValue> {
+    let value = match read_bytes(reader, 1)?[0] {
+        TAG_NULL => JsonValue::Null,
+        TAG_FALSE => JsonValue::Bool(false),
+        TAG_TRUE => JsonValue::Bool(true),
+        TAG_UINT64 => JsonValue::from(u64::from_le_bytes(read_array(reader)?)),
+        TAG_INT64 => JsonValue::from(i64::from_le_bytes(read_array(reader)?)),
+        TAG_FLOAT64 => {
+            let v = f64::from_le_bytes(read_array(reader)?);
+            Number::from_f64(v).map_or(JsonValue::Null, JsonValue::Number)
+        }
+        TAG_STRING => JsonValue::String(decode_str(reader)?),
+        TAG_ARRAY => {
+            let len = decode_varint(reader)? as usize;
+            // Every element takes at least one byte, don't trust a corrupted length.
+            let mut values = Vec::with_capacity(len.min(reader.len()));
+            for _ in 0..len {
+                values.push(decode_value(reader)?);
+            }
+            JsonValue::Array(values)
+        }
+        TAG_OBJECT => {
+            let len = decode_varint(reader)? as usize;
+            let mut obj = Map::new();
+            for _ in 0..len {
+                let k = decode_str(reader)?;
+                let v = decode_value(reader)?;
+                obj.insert(k, v);
+            }
+            JsonValue::Object(obj)
+        }
+        _ => return Err(corrupted()),
+    };
+    Ok(value)
+}
+
+fn encode_str(s: &str, buf: &mut Vec<u8>) {
+    encode_varint(s.len() as u64, buf);
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn decode_str(reader: &mut &[u8]) -> Result<String> {
+    let len = decode_varint(reader)? as usize;
+    let bytes = read_bytes(reader, len)?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| corrupted())
+}
+
+fn encode_varint(mut v: u64, buf: &mut Vec<u8>) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn decode_varint(reader: &mut &[u8]) -> Result<u64> {
+    let mut v: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let b = read_bytes(reader, 1)?[0];
+        v |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok(v);
+        }
+    }
+    Err(corrupted())
+}
+
+fn read_bytes<'a>(reader: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if reader.len() < len {
+        return Err(corrupted());
+    }
+    let (bytes, rest) = reader.split_at(len);
+    *reader = rest;
+    Ok(bytes)
+}
+
+fn read_array(reader: &mut &[u8]) -> Result<[u8; 8]> {
+    let mut array = [0u8; 8];
+    array.copy_from_slice(read_bytes(reader, 8)?);
+    Ok(array)
+}
+
+fn corrupted() -> ErrorCode {
+    ErrorCode::BadBytes("Corrupted JSONB value")
+}
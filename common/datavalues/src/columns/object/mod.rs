@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod iterator;
+mod jsonb;
 mod mutable;
 
 use std::sync::Arc;
@@ -21,6 +22,7 @@ use common_arrow::arrow::array::Array;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::buffer::Buffer;
 pub use iterator::*;
+pub use jsonb::*;
 pub use mutable::*;
 use serde_json::Value as JsonValue;
 
@@ -55,10 +57,9 @@ impl<T: ObjectType> ObjectColumn<T> {
             }
             let off = offsets[i] as usize;
             let len = (offsets[i + 1] - offsets[i]) as usize;
-            let val = std::str::from_utf8(&array_values[off..off + len]).unwrap();
-            match T::from_str(val) {
-                Ok(v) => values.push(v),
-                Err(_) => values.push(T::default()),
+            match T::decode(&array_values[off..off + len]) {
+                Some(v) => values.push(v),
+                None => values.push(T::default()),
             }
         }
 
@@ -124,9 +125,8 @@ impl<T: ObjectType> Column for ObjectColumn<T> {
         let mut offset: i64 = 0;
         offsets.push(offset);
         for val in &self.values {
-            let v = val.to_string();
-            values.extend(v.as_bytes().to_vec());
-            offset += v.len() as i64;
+            val.encode(&mut values);
+            offset = values.len() as i64;
             offsets.push(offset);
         }
 
@@ -119,6 +119,14 @@ impl<T: PrimitiveType> PrimitiveColumn<T> {
                     let array = unary(array, |x| (x as usize / p) as u32, expected_arrow);
                     Self::from_arrow_array(&array)
                 }
+                // The precision and the scale are kept by the data type of the field.
+                ArrowDataType::Decimal(_, _) => {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<T>>()
+                        .expect("primitive cast should be ok");
+                    Self::new(array.clone())
+                }
                 _ => unreachable!(),
             }
         } else {
@@ -377,6 +385,7 @@ pub type Int8Column = PrimitiveColumn<i8>;
 pub type Int16Column = PrimitiveColumn<i16>;
 pub type Int32Column = PrimitiveColumn<i32>;
 pub type Int64Column = PrimitiveColumn<i64>;
+pub type Int128Column = PrimitiveColumn<i128>;
 
 pub type Float32Column = PrimitiveColumn<f32>;
 pub type Float64Column = PrimitiveColumn<f64>;
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mutable;
+
 use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
+pub use mutable::*;
 
 use crate::prelude::*;
 
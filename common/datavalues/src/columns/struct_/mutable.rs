@@ -0,0 +1,115 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct MutableStructColumn {
+    data_type: DataTypePtr,
+    inner_columns: Vec<Box<dyn MutableColumn>>,
+}
+
+impl MutableStructColumn {
+    pub fn with_capacity(data_type: DataTypePtr, capacity: usize) -> Self {
+        let inner_columns = {
+            let struct_type: &StructType = data_type.as_any().downcast_ref().unwrap();
+            struct_type
+                .types()
+                .iter()
+                .map(|ty| ty.create_mutable(capacity))
+                .collect()
+        };
+
+        Self {
+            data_type,
+            inner_columns,
+        }
+    }
+
+    pub fn inner_columns(&mut self) -> &mut [Box<dyn MutableColumn>] {
+        &mut self.inner_columns
+    }
+
+    pub fn append_value(&mut self, values: Vec<DataValue>) -> Result<()> {
+        if values.len() != self.inner_columns.len() {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Struct value has {} fields, but {:?} expects {}",
+                values.len(),
+                self.data_type,
+                self.inner_columns.len()
+            )));
+        }
+        for (column, v) in self.inner_columns.iter_mut().zip(values) {
+            column.append_data_value(v)?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> StructColumn {
+        let values = self
+            .inner_columns
+            .iter_mut()
+            .map(|c| c.to_column())
+            .collect();
+        StructColumn::from_data(values, self.data_type.clone())
+    }
+}
+
+impl MutableColumn for MutableStructColumn {
+    fn data_type(&self) -> DataTypePtr {
+        self.data_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn append_default(&mut self) {
+        for column in self.inner_columns.iter_mut() {
+            column.append_default();
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        for column in self.inner_columns.iter_mut() {
+            column.shrink_to_fit();
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.inner_columns[0].len()
+    }
+
+    fn to_column(&mut self) -> ColumnRef {
+        Arc::new(self.finish())
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Struct(values) => self.append_value(values),
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append to struct column",
+                other.value_type()
+            ))),
+        }
+    }
+}
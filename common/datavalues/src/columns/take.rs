@@ -34,6 +34,34 @@ impl Series {
             let validity_result = Bitmap::from_trusted_len_iter(values);
 
             Ok(Arc::new(NullableColumn::new(inner_result, validity_result)))
+        } else if matches!(column.data_type_id(), TypeID::Array | TypeID::Map) {
+            let array_c: &ArrayColumn = Series::check_get(column)?;
+            let mut offsets = Vec::with_capacity(indices.len() + 1);
+            offsets.push(0);
+            let mut value_indices: Vec<u64> = Vec::with_capacity(array_c.values().len());
+
+            for index in indices {
+                let index = index.to_usize();
+                let start = array_c.offsets()[index] as u64;
+                let end = array_c.offsets()[index + 1] as u64;
+                value_indices.extend(start..end);
+                offsets.push(value_indices.len() as i64);
+            }
+
+            let values = Self::take(array_c.values(), &value_indices)?;
+            Ok(Arc::new(ArrayColumn::from_data(
+                array_c.data_type(),
+                offsets.into(),
+                values,
+            )))
+        } else if column.data_type_id() == TypeID::Struct {
+            let struct_c: &StructColumn = Series::check_get(column)?;
+            let values = struct_c
+                .values()
+                .iter()
+                .map(|v| Self::take(v, indices))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(StructColumn::from_data(values, struct_c.data_type())))
         } else {
             let type_id = column.data_type_id().to_physical_type();
 
@@ -15,6 +15,7 @@
 // Borrow from apache/arrow/rust/datafusion/src/functions.rs
 // See notice.md
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::sync::Arc;
 
@@ -36,6 +37,8 @@ pub enum DataValue {
     UInt64(u64),
     Float64(f64),
     String(Vec<u8>),
+    /// The scaled value of a decimal.
+    Int128(i128),
 
     // Container struct.
     Array(Vec<DataValue>),
@@ -53,6 +56,7 @@ pub enum ValueType {
     Int64,
     Float64,
     String,
+    Int128,
     Array,
     Struct,
     Json,
@@ -83,6 +87,7 @@ impl DataValue {
             DataValue::UInt64(_) => ValueType::UInt64,
             DataValue::Float64(_) => ValueType::Float64,
             DataValue::String(_) => ValueType::String,
+            DataValue::Int128(_) => ValueType::Int128,
             DataValue::Array(_) => ValueType::Array,
             DataValue::Struct(_) => ValueType::Struct,
             DataValue::Json(_) => ValueType::Json,
@@ -120,6 +125,7 @@ impl DataValue {
             }
             DataValue::Float64(_) => Float64Type::arc(),
             DataValue::String(_) => StringType::arc(),
+            DataValue::Int128(_) => DecimalType::arc(DECIMAL128_MAX_PRECISION, 0),
             DataValue::Array(x) => {
                 let inner_type = if x.is_empty() {
                     UInt8Type::arc()
@@ -146,6 +152,7 @@ impl DataValue {
             DataValue::UInt64(_) => UInt64Type::arc(),
             DataValue::Float64(_) => Float64Type::arc(),
             DataValue::String(_) => StringType::arc(),
+            DataValue::Int128(_) => DecimalType::arc(DECIMAL128_MAX_PRECISION, 0),
             DataValue::Array(x) => {
                 let inner_type = if x.is_empty() {
                     UInt8Type::arc()
@@ -200,6 +207,18 @@ impl DataValue {
         }
     }
 
+    pub fn as_i128(&self) -> Result<i128> {
+        match self {
+            DataValue::Int64(v) => Ok(*v as i128),
+            DataValue::UInt64(v) => Ok(*v as i128),
+            DataValue::Int128(v) => Ok(*v),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get i128 number",
+                other.value_type()
+            ))),
+        }
+    }
+
     pub fn as_bool(&self) -> Result<bool> {
         match self {
             DataValue::Boolean(v) => Ok(*v),
@@ -215,6 +234,7 @@ impl DataValue {
             DataValue::Int64(v) => Ok(*v as f64),
             DataValue::UInt64(v) => Ok(*v as f64),
             DataValue::Float64(v) => Ok(*v),
+            DataValue::Int128(v) => Ok(*v as f64),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get f64 number",
                 other.value_type()
@@ -227,6 +247,7 @@ impl DataValue {
             DataValue::Int64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::UInt64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::Float64(v) => Ok(Vec::<u8>::from((*v).to_string())),
+            DataValue::Int128(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::String(v) => Ok(v.to_owned()),
             DataValue::Json(v) => Ok(v.to_string().into_bytes()),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
@@ -287,6 +308,7 @@ impl DFTryFrom<DataValue> for JsonValue {
             DataValue::UInt64(v) => Ok(v.into()),
             DataValue::Float64(v) => Ok(v.into()),
             DataValue::String(v) => Ok(v.into()),
+            DataValue::Int128(v) => Ok(i128_to_json(v)),
             DataValue::Array(v) => Ok(json!(v)),
             DataValue::Struct(v) => Ok(json!(v)),
             DataValue::Json(v) => Ok(v),
@@ -303,6 +325,7 @@ impl DFTryFrom<&DataValue> for JsonValue {
             DataValue::UInt64(v) => Ok((*v as u64).into()),
             DataValue::Float64(v) => Ok((*v as f64).into()),
             DataValue::String(v) => Ok(String::from_utf8(v.to_vec()).unwrap().into()),
+            DataValue::Int128(v) => Ok(i128_to_json(*v)),
             DataValue::Array(v) => Ok(json!(*v)),
             DataValue::Struct(v) => Ok(json!(*v)),
             DataValue::Json(v) => Ok(v.to_owned()),
@@ -310,6 +333,14 @@ impl DFTryFrom<&DataValue> for JsonValue {
     }
 }
 
+// The json numbers are at most 64 bits, the wider values are kept as strings.
+fn i128_to_json(v: i128) -> JsonValue {
+    match i64::try_from(v) {
+        Ok(v) => v.into(),
+        Err(_) => v.to_string().into(),
+    }
+}
+
 try_cast_data_value_to_std!(u8, as_u64);
 try_cast_data_value_to_std!(u16, as_u64);
 try_cast_data_value_to_std!(u32, as_u64);
@@ -319,6 +350,7 @@ try_cast_data_value_to_std!(i8, as_i64);
 try_cast_data_value_to_std!(i16, as_i64);
 try_cast_data_value_to_std!(i32, as_i64);
 try_cast_data_value_to_std!(i64, as_i64);
+try_cast_data_value_to_std!(i128, as_i128);
 
 try_cast_data_value_to_std!(f32, as_f64);
 try_cast_data_value_to_std!(f64, as_f64);
@@ -328,6 +360,7 @@ std_to_data_value!(Int64, i8, i64);
 std_to_data_value!(Int64, i16, i64);
 std_to_data_value!(Int64, i32, i64);
 std_to_data_value!(Int64, i64, i64);
+std_to_data_value!(Int128, i128, i128);
 std_to_data_value!(UInt64, u8, u64);
 std_to_data_value!(UInt64, u16, u64);
 std_to_data_value!(UInt64, u32, u64);
@@ -370,6 +403,27 @@ impl From<JsonValue> for DataValue {
     }
 }
 
+impl PartialOrd for DataValue {
+    /// Values of the same kind are comparable, NULL is less than any other value.
+    /// Arrays and structs are compared lexicographically.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (DataValue::Null, DataValue::Null) => Some(Ordering::Equal),
+            (DataValue::Null, _) => Some(Ordering::Less),
+            (_, DataValue::Null) => Some(Ordering::Greater),
+            (DataValue::Boolean(a), DataValue::Boolean(b)) => a.partial_cmp(b),
+            (DataValue::Int64(a), DataValue::Int64(b)) => a.partial_cmp(b),
+            (DataValue::UInt64(a), DataValue::UInt64(b)) => a.partial_cmp(b),
+            (DataValue::Float64(a), DataValue::Float64(b)) => a.partial_cmp(b),
+            (DataValue::Int128(a), DataValue::Int128(b)) => a.partial_cmp(b),
+            (DataValue::String(a), DataValue::String(b)) => a.partial_cmp(b),
+            (DataValue::Array(a), DataValue::Array(b)) => a.partial_cmp(b),
+            (DataValue::Struct(a), DataValue::Struct(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for DataValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -378,6 +432,7 @@ impl fmt::Display for DataValue {
             DataValue::Float64(v) => write!(f, "{}", v),
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
             DataValue::String(v) => match std::str::from_utf8(v) {
                 Ok(v) => write!(f, "{}", v),
                 Err(_e) => {
@@ -411,6 +466,7 @@ impl fmt::Debug for DataValue {
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
             DataValue::Float64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
             DataValue::String(_) => write!(f, "{}", self),
             DataValue::Array(_) => write!(f, "{}", self),
             DataValue::Struct(v) => write!(f, "{:?}", v),
@@ -36,6 +36,7 @@ mod types;
 /// third partry
 pub use chrono;
 pub use chrono_tz::Tz;
+pub use roaring;
 /// current
 pub use columns::*;
 pub use data_field::*;
@@ -21,6 +21,7 @@ macro_rules! for_all_scalar_types {
             { i16 },
             { i32 },
             { i64 },
+            { i128 },
             { u8 },
             { u16 },
             { u32 },
@@ -129,6 +130,7 @@ macro_rules! with_match_physical_primitive_type {
             PhysicalTypeID::Int16 => __with_ty__! { i16 },
             PhysicalTypeID::Int32 => __with_ty__! { i32 },
             PhysicalTypeID::Int64 => __with_ty__! { i64 },
+            PhysicalTypeID::Int128 => __with_ty__! { i128 },
             PhysicalTypeID::UInt8 => __with_ty__! { u8 },
             PhysicalTypeID::UInt16 => __with_ty__! { u16 },
             PhysicalTypeID::UInt32 => __with_ty__! { u32 },
@@ -151,6 +153,7 @@ macro_rules! with_match_physical_primitive_type_error {(
         PhysicalTypeID::Int16 => __with_ty__! { i16 },
         PhysicalTypeID::Int32 => __with_ty__! { i32 },
         PhysicalTypeID::Int64 => __with_ty__! { i64 },
+        PhysicalTypeID::Int128 => __with_ty__! { i128 },
         PhysicalTypeID::UInt8 => __with_ty__! { u8 },
         PhysicalTypeID::UInt16 => __with_ty__! { u16 },
         PhysicalTypeID::UInt32 => __with_ty__! { u32 },
@@ -182,6 +185,7 @@ macro_rules! with_match_scalar_type {
             PhysicalTypeID::Int16 => __with_ty__! { i16 },
             PhysicalTypeID::Int32 => __with_ty__! { i32 },
             PhysicalTypeID::Int64 => __with_ty__! { i64 },
+            PhysicalTypeID::Int128 => __with_ty__! { i128 },
             PhysicalTypeID::UInt8 => __with_ty__! { u8 },
             PhysicalTypeID::UInt16 => __with_ty__! { u16 },
             PhysicalTypeID::UInt32 => __with_ty__! { u32 },
@@ -209,6 +213,7 @@ macro_rules! with_match_scalar_types_error {(
         PhysicalTypeID::Int16 => __with_ty__! { i16 },
         PhysicalTypeID::Int32 => __with_ty__! { i32 },
         PhysicalTypeID::Int64 => __with_ty__! { i64 },
+        PhysicalTypeID::Int128 => __with_ty__! { i128 },
         PhysicalTypeID::UInt8 => __with_ty__! { u8 },
         PhysicalTypeID::UInt16 => __with_ty__! { u16 },
         PhysicalTypeID::UInt32 => __with_ty__! { u32 },
@@ -0,0 +1,223 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// The rules to compare strings with.
+///
+/// Every collation folds a string into a canonical form, two strings are equal if their
+/// folded forms are equal, and orders the folded forms by the weights of their characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Collation {
+    /// Compares the utf8 bytes, this is the default.
+    Binary,
+    /// Case insensitive.
+    CaseInsensitive,
+    /// Case and accent insensitive, e.g. `'e' = 'É'`.
+    AccentInsensitive,
+    /// German phonebook order, `ä`, `ö`, `ü` and `ß` are compared as `ae`, `oe`, `ue` and `ss`.
+    German,
+    /// Swedish, `å`, `ä` and `ö` are letters sorted after `z`.
+    Swedish,
+}
+
+impl Default for Collation {
+    fn default() -> Self {
+        Collation::Binary
+    }
+}
+
+impl Collation {
+    pub fn from_name(name: &str) -> Result<Collation> {
+        match name.to_lowercase().as_str() {
+            "binary" | "utf8_bin" | "utf8mb4_bin" => Ok(Collation::Binary),
+            "utf8_general_ci" | "utf8mb4_general_ci" => Ok(Collation::CaseInsensitive),
+            "utf8_unicode_ci" | "utf8mb4_unicode_ci" => Ok(Collation::AccentInsensitive),
+            "de_de" => Ok(Collation::German),
+            "sv_se" => Ok(Collation::Swedish),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown collation: '{}'",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Collation::Binary => "utf8_bin",
+            Collation::CaseInsensitive => "utf8_general_ci",
+            Collation::AccentInsensitive => "utf8_unicode_ci",
+            Collation::German => "de_DE",
+            Collation::Swedish => "sv_SE",
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        *self == Collation::Binary
+    }
+
+    /// Picks the collation to compare two strings with, an explicit collation wins over
+    /// the binary default.
+    pub fn resolve(lhs: Collation, rhs: Collation) -> Result<Collation> {
+        match (lhs, rhs) {
+            (Collation::Binary, v) | (v, Collation::Binary) => Ok(v),
+            (lhs, rhs) if lhs == rhs => Ok(lhs),
+            (lhs, rhs) => Err(ErrorCode::IllegalDataType(format!(
+                "Illegal mix of collations {} and {}",
+                lhs, rhs
+            ))),
+        }
+    }
+
+    /// The canonical form of the string, strings are equal in the collation if and only if
+    /// their canonical forms are equal.
+    pub fn fold(&self, value: &[u8]) -> Vec<u8> {
+        if self.is_binary() {
+            return value.to_vec();
+        }
+
+        let mut folded = String::with_capacity(value.len());
+        for c in String::from_utf8_lossy(value)
+            .chars()
+            .flat_map(char::to_lowercase)
+        {
+            match self.fold_char(c) {
+                Some(s) => folded.push_str(s),
+                None => folded.push(c),
+            }
+        }
+        folded.into_bytes()
+    }
+
+    /// The key to order the string by, keys are compared as bytes.
+    pub fn sort_key(&self, value: &[u8]) -> Vec<u8> {
+        if self.is_binary() {
+            return value.to_vec();
+        }
+
+        let folded = self.fold(value);
+        let folded = String::from_utf8_lossy(&folded);
+        let mut key = Vec::with_capacity(folded.len() * 4);
+        for c in folded.chars() {
+            key.extend_from_slice(&self.weight(c).to_be_bytes());
+        }
+        key
+    }
+
+    fn fold_char(&self, c: char) -> Option<&'static str> {
+        match self {
+            Collation::Binary | Collation::CaseInsensitive => None,
+            Collation::AccentInsensitive => fold_accent(c),
+            Collation::German => match c {
+                'ä' => Some("ae"),
+                'ö' => Some("oe"),
+                'ü' => Some("ue"),
+                _ => fold_accent(c),
+            },
+            Collation::Swedish => match c {
+                'å' | 'ä' | 'ö' => None,
+                'æ' => Some("ä"),
+                'ø' => Some("ö"),
+                _ => fold_accent(c),
+            },
+        }
+    }
+
+    /// Characters are ordered by their code points, the letters a locale puts between
+    /// others take the free slots between two code points.
+    fn weight(&self, c: char) -> u32 {
+        let z = ('z' as u32) << 2;
+        match (self, c) {
+            (Collation::Swedish, 'å') => z + 1,
+            (Collation::Swedish, 'ä') => z + 2,
+            (Collation::Swedish, 'ö') => z + 3,
+            _ => (c as u32) << 2,
+        }
+    }
+
+    /// Folds every string of the column, the nulls and constants are kept.
+    pub fn fold_column(&self, column: &ColumnRef) -> Result<ColumnRef> {
+        map_string_column(column, &|v| self.fold(v))
+    }
+
+    /// Replaces every string of the column by its sort key.
+    pub fn sort_key_column(&self, column: &ColumnRef) -> Result<ColumnRef> {
+        map_string_column(column, &|v| self.sort_key(v))
+    }
+}
+
+impl fmt::Display for Collation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The collation of a string type, non string types are binary.
+pub fn collation_of(data_type: &DataTypePtr) -> Collation {
+    let data_type = remove_nullable(data_type);
+    match data_type.as_any().downcast_ref::<StringType>() {
+        Some(string_type) => string_type.collation(),
+        None => Collation::Binary,
+    }
+}
+
+fn map_string_column(column: &ColumnRef, f: &dyn Fn(&[u8]) -> Vec<u8>) -> Result<ColumnRef> {
+    if column.is_const() {
+        let col: &ConstColumn = Series::check_get(column)?;
+        let inner = map_string_column(col.inner(), f)?;
+        return Ok(ConstColumn::new(inner, column.len()).arc());
+    }
+
+    if column.is_nullable() {
+        let col: &NullableColumn = Series::check_get(column)?;
+        let inner = map_string_column(col.inner(), f)?;
+        return Ok(NullableColumn::new(inner, col.ensure_validity().clone()).arc());
+    }
+
+    let col: &StringColumn = Series::check_get(column)?;
+    let mut builder = MutableStringColumn::with_capacity(col.len());
+    for value in col.iter() {
+        builder.append_value(f(value));
+    }
+    Ok(builder.to_column())
+}
+
+fn fold_accent(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' => "s",
+        'ß' => "ss",
+        'ť' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
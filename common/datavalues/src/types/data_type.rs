@@ -115,6 +115,7 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypePtr {
         ArrowType::Boolean => Arc::new(BooleanType::default()),
         ArrowType::Float32 => Arc::new(Float32Type::default()),
         ArrowType::Float64 => Arc::new(Float64Type::default()),
+        ArrowType::Decimal(precision, scale) => DecimalType::arc(*precision, *scale),
 
         // TODO support other list
         ArrowType::LargeList(f) => {
@@ -167,9 +168,44 @@ pub fn from_arrow_field(f: &ArrowField) -> DataTypePtr {
                 None => return DateTime64Type::arc(3, None),
             },
             "Interval" => return IntervalType::arc(metadata.unwrap().into()),
+            "String" => {
+                let collation = metadata
+                    .as_deref()
+                    .and_then(|name| Collation::from_name(name).ok())
+                    .unwrap_or_default();
+                let ty = StringType::arc_with_collation(collation);
+                return match f.is_nullable {
+                    true => Arc::new(NullableType::create(ty)),
+                    false => ty,
+                };
+            }
+            "Decimal256" => {
+                let precision_scale = metadata
+                    .as_ref()
+                    .and_then(|meta| meta.split_once(','))
+                    .and_then(|(p, s)| Some((p.parse().ok()?, s.parse().ok()?)));
+                if let Some((precision, scale)) = precision_scale {
+                    let ty = DecimalType::arc(precision, scale);
+                    return match f.is_nullable {
+                        true => Arc::new(NullableType::create(ty)),
+                        false => ty,
+                    };
+                }
+            }
+            "Bitmap" => return BitmapType::arc(),
+            "Geometry" => return GeometryType::arc(),
             "Variant" => return VariantType::arc(),
             "VariantArray" => return VariantArrayType::arc(),
             "VariantObject" => return VariantObjectType::arc(),
+            "Map" => {
+                if let ArrowType::LargeList(entry) = f.data_type() {
+                    if let ArrowType::Struct(fields) = entry.data_type() {
+                        let key = from_arrow_field(&fields[0]);
+                        let value = from_arrow_field(&fields[1]);
+                        return MapType::arc(key, value);
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -0,0 +1,129 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+use common_io::prelude::BufferReadExt;
+use common_io::prelude::BufferReader;
+use common_io::prelude::CpBufferReader;
+
+use crate::prelude::*;
+
+pub struct ArrayDeserializer {
+    pub inner: Box<dyn TypeDeserializer>,
+    pub offsets: Vec<i64>,
+    /// The number of values appended to the inner deserializer.
+    pub size: i64,
+    pub data_type: DataTypePtr,
+}
+
+impl ArrayDeserializer {
+    #[inline]
+    fn add_offset(&mut self, len: usize) {
+        self.size += len as i64;
+        self.offsets.push(self.size);
+    }
+}
+
+impl TypeDeserializer for ArrayDeserializer {
+    // See GroupHash.rs for ArrayColumn
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let len = reader.read_uvarint()? as usize;
+        for _ in 0..len {
+            self.inner.de_binary(reader)?;
+        }
+        self.add_offset(len);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.add_offset(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::BadDataValueType(
+            "Array values don't have a fixed binary size",
+        ))
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::Array(values) => {
+                for v in values {
+                    self.inner.de_json(v)?;
+                }
+                self.add_offset(values.len());
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be array")),
+        }
+    }
+
+    /// Parse the text form like `[1, 2, 3]` or `['a', 'b']`.
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        reader.must_ignore_byte(b'[')?;
+        let mut len = 0;
+        loop {
+            reader.ignore_white_spaces()?;
+            if reader.ignore_byte(b']')? {
+                break;
+            }
+            if len != 0 {
+                reader.must_ignore_byte(b',')?;
+                reader.ignore_white_spaces()?;
+            }
+            self.inner.de_text_quoted(reader)?;
+            len += 1;
+        }
+        self.add_offset(len);
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let mut reader = CpBufferReader::new(Box::new(BufferReader::new(reader)));
+        self.de_text(&mut reader)?;
+        reader.must_eof()
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(values) => {
+                let len = values.len();
+                for v in values {
+                    self.inner.append_data_value(v)?;
+                }
+                self.add_offset(len);
+                Ok(())
+            }
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append to array column",
+                other.value_type()
+            ))),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        let values = self.inner.finish_to_column();
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        self.size = 0;
+        Arc::new(ArrayColumn::from_data(
+            self.data_type.clone(),
+            offsets.into(),
+            values,
+        ))
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+use common_io::prelude::BufferReadExt;
+use common_io::prelude::CpBufferReader;
+use roaring::RoaringTreemap;
+
+use crate::prelude::*;
+
+/// Deserializes bitmaps from the serialized roaring format in binary, and from
+/// a comma separated list of unsigned integers in text, e.g. `1,2,3`.
+pub struct BitmapDeserializer {
+    pub buffer: Vec<u8>,
+    pub builder: MutableStringColumn,
+}
+
+impl BitmapDeserializer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            builder: MutableStringColumn::with_capacity(capacity),
+        }
+    }
+}
+
+impl TypeDeserializer for BitmapDeserializer {
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let offset: u64 = reader.read_uvarint()?;
+
+        self.buffer.clear();
+        self.buffer.resize(offset as usize, 0);
+        reader.read_exact(&mut self.buffer)?;
+
+        bitmap_deserialize(&self.buffer)?;
+        self.builder.append_value(&self.buffer);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.builder
+            .append_value(bitmap_serialize(&RoaringTreemap::new()));
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let reader = &reader[step * row..];
+            bitmap_deserialize(reader)?;
+            self.builder.append_value(reader);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::String(s) => {
+                let bitmap = bitmap_from_text(s.as_bytes())?;
+                self.builder.append_value(bitmap_serialize(&bitmap));
+                Ok(())
+            }
+            serde_json::Value::Array(values) => {
+                let mut bitmap = RoaringTreemap::new();
+                for v in values {
+                    match v.as_u64() {
+                        Some(v) => bitmap.insert(v),
+                        None => {
+                            return Err(ErrorCode::BadBytes(
+                                "Incorrect json value, must be array of unsigned integers",
+                            ))
+                        }
+                    };
+                }
+                self.builder.append_value(bitmap_serialize(&bitmap));
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be string or array",
+            )),
+        }
+    }
+
+    fn de_text_quoted(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        self.buffer.clear();
+        reader.read_quoted_text(&mut self.buffer, b'\'')?;
+        let bitmap = bitmap_from_text(&self.buffer)?;
+        self.builder.append_value(bitmap_serialize(&bitmap));
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let bitmap = bitmap_from_text(reader)?;
+        self.builder.append_value(bitmap_serialize(&bitmap));
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        self.buffer.clear();
+        reader.read_escaped_string_text(&mut self.buffer)?;
+        let bitmap = bitmap_from_text(&self.buffer)?;
+        self.builder.append_value(bitmap_serialize(&bitmap));
+        Ok(())
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        self.builder.append_data_value(value)
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct DecimalDeserializer {
+    pub builder: MutablePrimitiveColumn<i128>,
+    pub data_type: DecimalType,
+}
+
+impl DecimalDeserializer {
+    fn read_decimal_text(&self, reader: &mut CpBufferReader) -> Result<i128> {
+        let mut buf = Vec::new();
+        reader.keep_read(&mut buf, |b| {
+            b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E')
+        })?;
+        self.data_type.parse_value(&buf)
+    }
+}
+
+impl TypeDeserializer for DecimalDeserializer {
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i128 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.builder.append_value(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i128 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        let v = match value {
+            serde_json::Value::Number(v) => self.data_type.parse_value(v.to_string().as_bytes()),
+            serde_json::Value::String(v) => self.data_type.parse_value(v.as_bytes()),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be number")),
+        }?;
+        self.builder.append_value(v);
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let v = self.data_type.parse_value(reader)?;
+        self.builder.append_value(v);
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        let v = self.read_decimal_text(reader)?;
+        self.builder.append_value(v);
+        Ok(())
+    }
+
+    fn de_text_csv(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let v = self.read_decimal_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+        }
+        self.builder.append_value(v);
+        Ok(())
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        self.builder.append_value(value.as_i128()?);
+        Ok(())
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
+
+/// Deserializer of the decimals wider than 38 digits, the values are stored as the bytes of
+/// the I256 integers, see `decimal256_to_bytes`.
+pub struct Decimal256Deserializer {
+    pub builder: MutableStringColumn,
+    pub data_type: DecimalType,
+}
+
+impl Decimal256Deserializer {
+    fn append_value(&mut self, v: I256) {
+        self.builder.append_value(decimal256_to_bytes(v));
+    }
+
+    fn read_decimal_text(&self, reader: &mut CpBufferReader) -> Result<I256> {
+        let mut buf = Vec::new();
+        reader.keep_read(&mut buf, |b| {
+            b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E')
+        })?;
+        self.data_type.parse_value(&buf)
+    }
+}
+
+impl TypeDeserializer for Decimal256Deserializer {
+    // The values are serialized as binary strings, see GroupHash.rs for StringColumn.
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let len = reader.read_uvarint()? as usize;
+        let mut buf = vec![0; len];
+        reader.read_exact(&mut buf)?;
+        self.append_value(decimal256_from_bytes(&buf)?);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.append_value(I256::ZERO);
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let reader = &reader[step * row..];
+            let v = decimal256_from_bytes(reader.get(..32).unwrap_or(reader))?;
+            self.append_value(v);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        let v = match value {
+            serde_json::Value::Number(v) => self.data_type.parse_value(v.to_string().as_bytes()),
+            serde_json::Value::String(v) => self.data_type.parse_value(v.as_bytes()),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be number")),
+        }?;
+        self.append_value(v);
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let v = self.data_type.parse_value(reader)?;
+        self.append_value(v);
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        let v = self.read_decimal_text(reader)?;
+        self.append_value(v);
+        Ok(())
+    }
+
+    fn de_text_csv(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let v = self.read_decimal_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+        }
+        self.append_value(v);
+        Ok(())
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        self.builder.append_value(decimal256_data_value(&value)?);
+        Ok(())
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::BinaryRead;
+use common_io::prelude::BufferReadExt;
+use common_io::prelude::BufferReader;
+use common_io::prelude::CpBufferReader;
+
+use crate::prelude::*;
+
+pub struct MapDeserializer {
+    pub key: Box<dyn TypeDeserializer>,
+    pub value: Box<dyn TypeDeserializer>,
+    pub offsets: Vec<i64>,
+    /// The number of entries appended to the key and value deserializers.
+    pub size: i64,
+    pub data_type: DataTypePtr,
+}
+
+impl MapDeserializer {
+    #[inline]
+    fn add_offset(&mut self, len: usize) {
+        self.size += len as i64;
+        self.offsets.push(self.size);
+    }
+}
+
+impl TypeDeserializer for MapDeserializer {
+    // See GroupHash.rs for ArrayColumn, the entries are Struct(key, value)
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let len = reader.read_uvarint()? as usize;
+        for _ in 0..len {
+            self.key.de_binary(reader)?;
+            self.value.de_binary(reader)?;
+        }
+        self.add_offset(len);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.add_offset(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::BadDataValueType(
+            "Map values don't have a fixed binary size",
+        ))
+    }
+
+    /// Accept the object form `{"a": 1, "b": 2}`, the keys are parsed from their text form.
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::Object(obj) => {
+                for (k, v) in obj {
+                    self.key.de_whole_text(k.as_bytes())?;
+                    self.value.de_json(v)?;
+                }
+                self.add_offset(obj.len());
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be object")),
+        }
+    }
+
+    /// Parse the text form like `{'a': 1, 'b': 2}`.
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        reader.must_ignore_byte(b'{')?;
+        let mut len = 0;
+        loop {
+            reader.ignore_white_spaces()?;
+            if reader.ignore_byte(b'}')? {
+                break;
+            }
+            if len != 0 {
+                reader.must_ignore_byte(b',')?;
+                reader.ignore_white_spaces()?;
+            }
+            self.key.de_text_quoted(reader)?;
+            reader.ignore_white_spaces()?;
+            reader.must_ignore_byte(b':')?;
+            reader.ignore_white_spaces()?;
+            self.value.de_text_quoted(reader)?;
+            len += 1;
+        }
+        self.add_offset(len);
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let mut reader = CpBufferReader::new(Box::new(BufferReader::new(reader)));
+        self.de_text(&mut reader)?;
+        reader.must_eof()
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(entries) => {
+                let len = entries.len();
+                for entry in entries {
+                    match entry {
+                        DataValue::Struct(mut kv) if kv.len() == 2 => {
+                            let v = kv.pop().unwrap();
+                            let k = kv.pop().unwrap();
+                            self.key.append_data_value(k)?;
+                            self.value.append_data_value(v)?;
+                        }
+                        other => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "Unexpected type:{:?} to append to map entries",
+                                other.value_type()
+                            )))
+                        }
+                    }
+                }
+                self.add_offset(len);
+                Ok(())
+            }
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append to map column",
+                other.value_type()
+            ))),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        let map_type: &MapType = self.data_type.as_any().downcast_ref().unwrap();
+        let keys = self.key.finish_to_column();
+        let values = self.value.finish_to_column();
+        let entries = StructColumn::from_data(vec![keys, values], map_type.entry_type());
+
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        self.size = 0;
+        Arc::new(ArrayColumn::from_data(
+            self.data_type.clone(),
+            offsets.into(),
+            Arc::new(entries),
+        ))
+    }
+}
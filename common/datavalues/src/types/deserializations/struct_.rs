@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::BufferReadExt;
+use common_io::prelude::BufferReader;
+use common_io::prelude::CpBufferReader;
+
+use crate::prelude::*;
+
+pub struct StructDeserializer {
+    pub names: Vec<String>,
+    pub inners: Vec<Box<dyn TypeDeserializer>>,
+    pub data_type: DataTypePtr,
+}
+
+impl TypeDeserializer for StructDeserializer {
+    // See GroupHash.rs for StructColumn
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        for inner in self.inners.iter_mut() {
+            inner.de_binary(reader)?;
+        }
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        for inner in self.inners.iter_mut() {
+            inner.de_default();
+        }
+    }
+
+    fn de_fixed_binary_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::BadDataValueType(
+            "Struct values don't have a fixed binary size",
+        ))
+    }
+
+    /// Accept both the object form `{"a": 1, "b": "x"}` and the array form `[1, "x"]`.
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::Object(obj) => {
+                for (name, inner) in self.names.iter().zip(self.inners.iter_mut()) {
+                    match obj.get(name) {
+                        Some(v) => inner.de_json(v)?,
+                        None => inner.de_default(),
+                    }
+                }
+                Ok(())
+            }
+            serde_json::Value::Array(values) if values.len() == self.inners.len() => {
+                for (v, inner) in values.iter().zip(self.inners.iter_mut()) {
+                    inner.de_json(v)?;
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be object or array",
+            )),
+        }
+    }
+
+    /// Parse the text form like `(1, 'a')`.
+    fn de_text(&mut self, reader: &mut CpBufferReader) -> Result<()> {
+        reader.must_ignore_byte(b'(')?;
+        for (i, inner) in self.inners.iter_mut().enumerate() {
+            reader.ignore_white_spaces()?;
+            if i != 0 {
+                reader.must_ignore_byte(b',')?;
+                reader.ignore_white_spaces()?;
+            }
+            inner.de_text_quoted(reader)?;
+        }
+        reader.ignore_white_spaces()?;
+        reader.must_ignore_byte(b')')?;
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8]) -> Result<()> {
+        let mut reader = CpBufferReader::new(Box::new(BufferReader::new(reader)));
+        self.de_text(&mut reader)?;
+        reader.must_eof()
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Struct(values) if values.len() == self.inners.len() => {
+                for (v, inner) in values.into_iter().zip(self.inners.iter_mut()) {
+                    inner.append_data_value(v)?;
+                }
+                Ok(())
+            }
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append to struct column",
+                other.value_type()
+            ))),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        let values = self
+            .inners
+            .iter_mut()
+            .map(|inner| inner.finish_to_column())
+            .collect();
+        Arc::new(StructColumn::from_data(values, self.data_type.clone()))
+    }
+}
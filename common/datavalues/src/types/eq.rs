@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use super::type_array::ArrayType;
 use super::type_datetime64::DateTime64Type;
+use super::type_decimal::DecimalType;
+use super::type_map::MapType;
 use super::type_nullable::NullableType;
 use super::type_struct::StructType;
 use super::DataType;
@@ -48,8 +50,8 @@ pub fn equal(lhs: &dyn DataType, rhs: &dyn DataType) -> bool {
     use crate::prelude::TypeID::*;
     match lhs.data_type_id() {
         Boolean | UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32
-        | Float64 | String | Date16 | Date32 | Interval | DateTime32 | Null | Variant
-        | VariantArray | VariantObject => true,
+        | Float64 | String | Date16 | Date32 | Interval | DateTime32 | Null | Bitmap | Geometry
+        | Variant | VariantArray | VariantObject => true,
 
         DateTime64 => {
             let lhs: &DateTime64Type = lhs.as_any().downcast_ref().unwrap();
@@ -58,6 +60,13 @@ pub fn equal(lhs: &dyn DataType, rhs: &dyn DataType) -> bool {
             lhs.precision() == rhs.precision()
         }
 
+        Decimal | Decimal256 => {
+            let lhs: &DecimalType = lhs.as_any().downcast_ref().unwrap();
+            let rhs: &DecimalType = rhs.as_any().downcast_ref().unwrap();
+
+            lhs.precision() == rhs.precision() && lhs.scale() == rhs.scale()
+        }
+
         Nullable => {
             let lhs: &NullableType = lhs.as_any().downcast_ref().unwrap();
             let rhs: &NullableType = rhs.as_any().downcast_ref().unwrap();
@@ -72,6 +81,13 @@ pub fn equal(lhs: &dyn DataType, rhs: &dyn DataType) -> bool {
             *lhs.inner_type() == *rhs.inner_type()
         }
 
+        Map => {
+            let lhs: &MapType = lhs.as_any().downcast_ref().unwrap();
+            let rhs: &MapType = rhs.as_any().downcast_ref().unwrap();
+
+            *lhs.key_type() == *rhs.key_type() && *lhs.value_type() == *rhs.value_type()
+        }
+
         Struct => {
             let lhs: &StructType = lhs.as_any().downcast_ref().unwrap();
             let rhs: &StructType = rhs.as_any().downcast_ref().unwrap();
@@ -0,0 +1,291 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const WKB_LITTLE_ENDIAN: u8 = 1;
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Coord {
+    pub fn new(x: f64, y: f64) -> Self {
+        Coord { x, y }
+    }
+}
+
+/// A planar geometry, which is written as WKT, e.g. `POINT(1 2)`, and stored as WKB.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Coord),
+    LineString(Vec<Coord>),
+    /// The exterior ring followed by the holes, every ring is closed.
+    Polygon(Vec<Vec<Coord>>),
+}
+
+impl Geometry {
+    /// Parses the well-known text, e.g. `POINT(1 2)`, `LINESTRING(0 0, 1 1)` or
+    /// `POLYGON((0 0, 2 0, 2 2, 0 0))`, keywords are case insensitive.
+    pub fn from_wkt(text: &str) -> Result<Geometry> {
+        let text = text.trim();
+        let (keyword, body) = match text.find('(') {
+            Some(pos) if text.ends_with(')') => (text[..pos].trim(), &text[pos..]),
+            _ => return Err(wkt_error(text, "expected KEYWORD(...)")),
+        };
+
+        let geometry = match keyword.to_uppercase().as_str() {
+            "POINT" => {
+                let coords = parse_coords(strip_parens(body)?)?;
+                if coords.len() != 1 {
+                    return Err(wkt_error(text, "a point must have one coordinate"));
+                }
+                Geometry::Point(coords[0])
+            }
+            "LINESTRING" => {
+                let coords = parse_coords(strip_parens(body)?)?;
+                if coords.len() < 2 {
+                    return Err(wkt_error(
+                        text,
+                        "a linestring must have at least two coordinates",
+                    ));
+                }
+                Geometry::LineString(coords)
+            }
+            "POLYGON" => {
+                let mut rings = vec![];
+                for ring in split_rings(strip_parens(body)?)? {
+                    let coords = parse_coords(ring)?;
+                    if coords.len() < 4 || coords.first() != coords.last() {
+                        return Err(wkt_error(
+                            text,
+                            "a polygon ring must be closed and have at least four coordinates",
+                        ));
+                    }
+                    rings.push(coords);
+                }
+                if rings.is_empty() {
+                    return Err(wkt_error(text, "a polygon must have at least one ring"));
+                }
+                Geometry::Polygon(rings)
+            }
+            _ => return Err(wkt_error(text, "unsupported geometry type")),
+        };
+        Ok(geometry)
+    }
+
+    pub fn to_wkt(&self) -> String {
+        self.to_string()
+    }
+
+    /// Encodes the geometry as little-endian well-known binary.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut buf = vec![WKB_LITTLE_ENDIAN];
+        match self {
+            Geometry::Point(c) => {
+                buf.extend_from_slice(&WKB_POINT.to_le_bytes());
+                write_coord(&mut buf, c);
+            }
+            Geometry::LineString(coords) => {
+                buf.extend_from_slice(&WKB_LINESTRING.to_le_bytes());
+                write_coords(&mut buf, coords);
+            }
+            Geometry::Polygon(rings) => {
+                buf.extend_from_slice(&WKB_POLYGON.to_le_bytes());
+                buf.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+                for ring in rings {
+                    write_coords(&mut buf, ring);
+                }
+            }
+        }
+        buf
+    }
+
+    pub fn from_wkb(bytes: &[u8]) -> Result<Geometry> {
+        let mut reader = WkbReader {
+            bytes,
+            pos: 0,
+            little_endian: true,
+        };
+        reader.little_endian = match reader.read_u8()? {
+            0 => false,
+            1 => true,
+            _ => return Err(ErrorCode::BadBytes("Incorrect Geometry value")),
+        };
+
+        match reader.read_u32()? {
+            WKB_POINT => Ok(Geometry::Point(reader.read_coord()?)),
+            WKB_LINESTRING => Ok(Geometry::LineString(reader.read_coords()?)),
+            WKB_POLYGON => {
+                let num_rings = reader.read_u32()?;
+                let rings = (0..num_rings)
+                    .map(|_| reader.read_coords())
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Geometry::Polygon(rings))
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Geometry value")),
+        }
+    }
+
+    /// All the coordinates of the geometry.
+    pub fn coords(&self) -> Vec<Coord> {
+        match self {
+            Geometry::Point(c) => vec![*c],
+            Geometry::LineString(coords) => coords.clone(),
+            Geometry::Polygon(rings) => rings.iter().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coords = |coords: &[Coord]| {
+            coords
+                .iter()
+                .map(|c| format!("{} {}", c.x, c.y))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        match self {
+            Geometry::Point(c) => write!(f, "POINT({} {})", c.x, c.y),
+            Geometry::LineString(v) => write!(f, "LINESTRING({})", coords(v)),
+            Geometry::Polygon(rings) => {
+                let rings = rings
+                    .iter()
+                    .map(|ring| format!("({})", coords(ring)))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "POLYGON({})", rings)
+            }
+        }
+    }
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.pos + N > self.bytes.len() {
+            return Err(ErrorCode::BadBytes("Incorrect Geometry value"));
+        }
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(&self.bytes[self.pos..self.pos + N]);
+        self.pos += N;
+        Ok(buf)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes::<1>()?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let buf = self.read_bytes::<4>()?;
+        if self.little_endian {
+            Ok(u32::from_le_bytes(buf))
+        } else {
+            Ok(u32::from_be_bytes(buf))
+        }
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        let buf = self.read_bytes::<8>()?;
+        if self.little_endian {
+            Ok(f64::from_le_bytes(buf))
+        } else {
+            Ok(f64::from_be_bytes(buf))
+        }
+    }
+
+    fn read_coord(&mut self) -> Result<Coord> {
+        Ok(Coord::new(self.read_f64()?, self.read_f64()?))
+    }
+
+    fn read_coords(&mut self) -> Result<Vec<Coord>> {
+        let num_points = self.read_u32()?;
+        (0..num_points).map(|_| self.read_coord()).collect()
+    }
+}
+
+fn write_coord(buf: &mut Vec<u8>, c: &Coord) {
+    buf.extend_from_slice(&c.x.to_le_bytes());
+    buf.extend_from_slice(&c.y.to_le_bytes());
+}
+
+fn write_coords(buf: &mut Vec<u8>, coords: &[Coord]) {
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for c in coords {
+        write_coord(buf, c);
+    }
+}
+
+fn wkt_error(text: &str, reason: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Incorrect Geometry value '{}': {}", text, reason))
+}
+
+fn strip_parens(text: &str) -> Result<&str> {
+    let text = text.trim();
+    match text.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+        Some(inner) => Ok(inner),
+        None => Err(wkt_error(text, "unbalanced parentheses")),
+    }
+}
+
+/// Splits `(x y, ...), (x y, ...)` into the text of each ring.
+fn split_rings(text: &str) -> Result<Vec<&str>> {
+    let mut rings = vec![];
+    let mut start = None;
+    for (i, ch) in text.char_indices() {
+        match (ch, start) {
+            ('(', None) => start = Some(i + 1),
+            (')', Some(s)) => {
+                rings.push(&text[s..i]);
+                start = None;
+            }
+            ('(', Some(_)) | (')', None) => return Err(wkt_error(text, "unbalanced parentheses")),
+            _ => {}
+        }
+    }
+    if start.is_some() {
+        return Err(wkt_error(text, "unbalanced parentheses"));
+    }
+    Ok(rings)
+}
+
+fn parse_coords(text: &str) -> Result<Vec<Coord>> {
+    text.split(',')
+        .map(|point| {
+            let values = point
+                .split_whitespace()
+                .map(|v| v.parse::<f64>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|_| wkt_error(point.trim(), "invalid number"))?;
+            match values.as_slice() {
+                [x, y] => Ok(Coord::new(*x, *y)),
+                _ => Err(wkt_error(point.trim(), "a coordinate must be 'x y'")),
+            }
+        })
+        .collect()
+}
@@ -15,13 +15,17 @@
 pub mod arithmetics_type;
 pub mod data_type;
 pub mod type_array;
+pub mod type_bitmap;
 pub mod type_boolean;
 pub mod type_coercion;
 pub mod type_date16;
 pub mod type_date32;
 pub mod type_datetime32;
 pub mod type_datetime64;
+pub mod type_decimal;
+pub mod type_geometry;
 pub mod type_interval;
+pub mod type_map;
 pub mod type_null;
 pub mod type_nullable;
 pub mod type_primitive;
@@ -35,27 +39,35 @@ pub mod type_variant_object;
 pub mod eq;
 pub mod type_id;
 
+mod collation;
 mod date_converter;
 pub mod deserializations;
+mod geometry;
 pub mod serializations;
 mod type_factory;
 
 pub use arithmetics_type::*;
+pub use collation::*;
 pub use data_type::*;
 pub use date_converter::*;
 pub use date_converter::*;
 pub use deserializations::*;
 pub use eq::*;
+pub use geometry::*;
 pub use serializations::*;
 pub use type_array::*;
+pub use type_bitmap::*;
 pub use type_boolean::*;
 pub use type_date16::*;
 pub use type_date32::*;
 pub use type_datetime32::*;
 pub use type_datetime64::*;
+pub use type_decimal::*;
 pub use type_factory::*;
+pub use type_geometry::*;
 pub use type_id::*;
 pub use type_interval::*;
+pub use type_map::*;
 pub use type_null::*;
 pub use type_nullable::*;
 pub use type_primitive::*;
@@ -61,8 +61,16 @@ impl TypeSerializer for ArraySerializer {
         Ok(result)
     }
 
-    fn serialize_json(&self, _column: &ColumnRef) -> Result<Vec<Value>> {
-        todo!()
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let mut result = Vec::with_capacity(column.len());
+        for i in 0..column.len() {
+            let offset = column.offsets()[i] as usize;
+            let values = column.values().slice(offset, column.size_at_index(i));
+            let values = self.inner.serialize_json(&values)?;
+            result.push(Value::Array(values));
+        }
+        Ok(result)
     }
 
     fn serialize_clickhouse_format(
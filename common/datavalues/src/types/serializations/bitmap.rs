@@ -0,0 +1,61 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnWrapper;
+use opensrv_clickhouse::types::column::ColumnFrom;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+pub struct BitmapSerializer {}
+
+impl TypeSerializer for BitmapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if let DataValue::String(x) = value {
+            let bitmap = bitmap_deserialize(x)?;
+            Ok(bitmap_to_text(&bitmap))
+        } else {
+            Err(ErrorCode::BadBytes("Incorrect Bitmap value"))
+        }
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let column: &StringColumn = Series::check_get(column)?;
+        column
+            .iter()
+            .map(|v| Ok(bitmap_to_text(&bitmap_deserialize(v)?)))
+            .collect()
+    }
+
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &StringColumn = Series::check_get(column)?;
+        column
+            .iter()
+            .map(|v| {
+                let bitmap = bitmap_deserialize(v)?;
+                Ok(Value::Array(bitmap.iter().map(Value::from).collect()))
+            })
+            .collect()
+    }
+
+    fn serialize_clickhouse_format(
+        &self,
+        column: &ColumnRef,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let values = self.serialize_column(column)?;
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+}
@@ -27,14 +27,21 @@ pub struct DateTimeSerializer<T: PrimitiveType> {
     _marker: PhantomData<T>,
     precision: u32,
     tz: Tz,
+    format: String,
 }
 
 impl<T: PrimitiveType> DateTimeSerializer<T> {
     pub fn create(tz: Tz, precision: u32) -> Self {
+        // Keep the sub-second part of DateTime64, e.g. 2022-01-01 00:00:00.123456
+        let format = match precision {
+            0 => TIME_FMT.to_string(),
+            _ => format!("{}%.{}f", TIME_FMT, precision),
+        };
         Self {
             _marker: PhantomData,
             precision,
             tz,
+            format,
         }
     }
 
@@ -55,7 +62,7 @@ impl<T: PrimitiveType> TypeSerializer for DateTimeSerializer<T> {
     fn serialize_value(&self, value: &DataValue) -> Result<String> {
         let value = DFTryFrom::try_from(value.clone())?;
         let dt = self.to_date_time(&value);
-        Ok(dt.format(TIME_FMT).to_string())
+        Ok(dt.format(&self.format).to_string())
     }
 
     fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
@@ -64,7 +71,7 @@ impl<T: PrimitiveType> TypeSerializer for DateTimeSerializer<T> {
             .iter()
             .map(|v| {
                 let dt = self.to_date_time(v);
-                dt.format(TIME_FMT).to_string()
+                dt.format(&self.format).to_string()
             })
             .collect();
         Ok(result)
@@ -76,7 +83,7 @@ impl<T: PrimitiveType> TypeSerializer for DateTimeSerializer<T> {
             .iter()
             .map(|v| {
                 let dt = self.to_date_time(v);
-                serde_json::to_value(dt.format(TIME_FMT).to_string()).unwrap()
+                serde_json::to_value(dt.format(&self.format).to_string()).unwrap()
             })
             .collect();
         Ok(result)
@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnWrapper;
+use opensrv_clickhouse::types::column::ColumnFrom;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+pub struct DecimalSerializer {
+    data_type: DecimalType,
+}
+
+impl DecimalSerializer {
+    pub fn create(data_type: DecimalType) -> Self {
+        Self { data_type }
+    }
+
+    fn format_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        match self.data_type.is_decimal256() {
+            true => self.format_values::<I256>(column),
+            false => self.format_values::<i128>(column),
+        }
+    }
+
+    fn format_values<T: DecimalNative>(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let values = T::column_values(column)?;
+        Ok(values
+            .into_iter()
+            .map(|v| self.data_type.format_value(v))
+            .collect())
+    }
+
+    fn float_column(&self, column: &ColumnRef) -> Result<Vec<f64>> {
+        match self.data_type.is_decimal256() {
+            true => self.float_values::<I256>(column),
+            false => self.float_values::<i128>(column),
+        }
+    }
+
+    fn float_values<T: DecimalNative>(&self, column: &ColumnRef) -> Result<Vec<f64>> {
+        let values = T::column_values(column)?;
+        Ok(values
+            .into_iter()
+            .map(|v| self.data_type.to_f64(v))
+            .collect())
+    }
+}
+
+impl TypeSerializer for DecimalSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match self.data_type.is_decimal256() {
+            true => {
+                let v = decimal256_from_bytes(&decimal256_data_value(value)?)?;
+                Ok(self.data_type.format_value(v))
+            }
+            false => Ok(self.data_type.format_value(value.as_i128()?)),
+        }
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        self.format_column(column)
+    }
+
+    // Decimals are serialized as json strings to keep the exact digits.
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .format_column(column)?
+            .into_iter()
+            .map(Value::String)
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_clickhouse_format(
+        &self,
+        column: &ColumnRef,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let values = self.format_column(column)?;
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+
+    fn serialize_json_object(
+        &self,
+        column: &ColumnRef,
+        _valids: Option<&Bitmap>,
+    ) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .float_column(column)?
+            .into_iter()
+            .map(|v| serde_json::to_value(v).unwrap())
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_json_object_suppress_error(
+        &self,
+        column: &ColumnRef,
+    ) -> Result<Vec<Option<Value>>> {
+        let result: Vec<Option<Value>> = self
+            .float_column(column)?
+            .into_iter()
+            .map(|v| serde_json::to_value(v).ok())
+            .collect();
+        Ok(result)
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnData;
+use opensrv_clickhouse::types::column::ArrayColumnData;
+use opensrv_clickhouse::types::column::List;
+use opensrv_clickhouse::types::column::TupleColumnData;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+pub struct MapSerializer {
+    pub key: Box<dyn TypeSerializer>,
+    pub value: Box<dyn TypeSerializer>,
+    pub key_type: DataTypePtr,
+    pub value_type: DataTypePtr,
+}
+
+impl MapSerializer {
+    fn serialize_quoted(
+        serializer: &dyn TypeSerializer,
+        typ: &DataTypePtr,
+        value: &DataValue,
+    ) -> Result<String> {
+        let s = serializer.serialize_value(value)?;
+        if typ.data_type_id().is_quoted() {
+            Ok(format!("'{}'", s))
+        } else {
+            Ok(s)
+        }
+    }
+}
+
+impl TypeSerializer for MapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if let DataValue::Array(entries) = value {
+            let mut res = String::new();
+            res.push('{');
+            let mut first = true;
+            for entry in entries {
+                let (k, v) = match entry {
+                    DataValue::Struct(kv) if kv.len() == 2 => (&kv[0], &kv[1]),
+                    _ => return Err(ErrorCode::BadBytes("Incorrect Map entry")),
+                };
+                if !first {
+                    res.push_str(", ");
+                }
+                first = false;
+
+                res.push_str(&Self::serialize_quoted(&*self.key, &self.key_type, k)?);
+                res.push_str(": ");
+                res.push_str(&Self::serialize_quoted(&*self.value, &self.value_type, v)?);
+            }
+            res.push('}');
+            Ok(res)
+        } else {
+            Err(ErrorCode::BadBytes("Incorrect Map value"))
+        }
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let mut result = Vec::with_capacity(column.len());
+        for i in 0..column.len() {
+            let val = column.get(i);
+            let s = self.serialize_value(&val)?;
+            result.push(s);
+        }
+        Ok(result)
+    }
+
+    /// Maps are serialized as JSON objects, the keys are in their text form.
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let entries: &StructColumn = Series::check_get(column.values())?;
+        let keys = self.key.serialize_column(&entries.values()[0])?;
+        let values = self.value.serialize_json(&entries.values()[1])?;
+
+        let mut result = Vec::with_capacity(column.len());
+        for i in 0..column.len() {
+            let offset = column.offsets()[i] as usize;
+            let end = offset + column.size_at_index(i);
+            let obj = keys[offset..end]
+                .iter()
+                .cloned()
+                .zip(values[offset..end].iter().cloned())
+                .collect::<Map<_, _>>();
+            result.push(Value::Object(obj));
+        }
+        Ok(result)
+    }
+
+    /// Maps are sent as `Array(Tuple(key, value))`, the wire format of the ClickHouse maps.
+    fn serialize_clickhouse_format(&self, column: &ColumnRef) -> Result<ArcColumnData> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let entries: &StructColumn = Series::check_get(column.values())?;
+        let keys = self.key.serialize_clickhouse_format(&entries.values()[0])?;
+        let values = self
+            .value
+            .serialize_clickhouse_format(&entries.values()[1])?;
+        let inner = Arc::new(TupleColumnData {
+            inner: vec![keys, values],
+        });
+
+        let mut offsets = List::with_capacity(column.len());
+        for offset in column.offsets().iter().skip(1) {
+            offsets.push(*offset as u64);
+        }
+        Ok(Arc::new(ArrayColumnData::create(inner, offsets)))
+    }
+}
@@ -20,9 +20,13 @@ use serde_json::Value;
 
 use crate::prelude::*;
 mod array;
+mod bitmap;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
+mod geometry;
+mod map;
 mod null;
 mod nullable;
 mod number;
@@ -31,9 +35,13 @@ mod struct_;
 mod variant;
 
 pub use array::*;
+pub use bitmap::*;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
+pub use geometry::*;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
@@ -67,16 +67,24 @@ impl TypeSerializer for StructSerializer {
         Ok(result)
     }
 
-    fn serialize_json(&self, _column: &ColumnRef) -> Result<Vec<Value>> {
-        // let column: &StructColumn = Series::check_get(column)?;
-        // let inner_columns = column.values();
-        // let result = self
-        //     .inners
-        //     .iter()
-        //     .zip(inner_columns.iter())
-        //     .map(|(inner, col)| inner.serialize_json(col))
-        //     .collect::<Result<Vec<Vec<Value>>>>()?;
-        todo!()
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &StructColumn = Series::check_get(column)?;
+        let inner_values = self
+            .inners
+            .iter()
+            .zip(column.values().iter())
+            .map(|(inner, col)| inner.serialize_json(col))
+            .collect::<Result<Vec<Vec<Value>>>>()?;
+
+        let mut result = Vec::with_capacity(column.len());
+        for row in 0..column.len() {
+            let mut obj = serde_json::Map::with_capacity(self.names.len());
+            for (name, values) in self.names.iter().zip(inner_values.iter()) {
+                obj.insert(name.clone(), values[row].clone());
+            }
+            result.push(Value::Object(obj));
+        }
+        Ok(result)
     }
 
     fn serialize_clickhouse_format(&self, column: &ColumnRef) -> Result<ArcColumnData> {
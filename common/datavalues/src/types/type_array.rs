@@ -38,6 +38,10 @@ impl ArrayType {
         }
     }
 
+    pub fn arc(inner: DataTypePtr) -> DataTypePtr {
+        Arc::new(Self::create(inner))
+    }
+
     pub fn inner_type(&self) -> &DataTypePtr {
         &self.inner
     }
@@ -121,12 +125,20 @@ impl DataType for ArrayType {
         })
     }
 
-    fn create_deserializer(&self, _capacity: usize) -> Box<dyn TypeDeserializer> {
-        todo!()
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(ArrayDeserializer {
+            inner: self.inner.create_deserializer(capacity),
+            offsets: vec![0],
+            size: 0,
+            data_type: Arc::new(self.clone()),
+        })
     }
 
-    fn create_mutable(&self, _capacity: usize) -> Box<dyn MutableColumn> {
-        todo!()
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableArrayColumn::with_capacity(
+            Arc::new(self.clone()),
+            capacity,
+        ))
     }
 }
 
@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use roaring::RoaringTreemap;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// A set of unsigned 64-bit integers backed by a roaring bitmap, the column is stored
+/// as a `StringColumn` of the serialized bitmaps.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BitmapType {}
+
+impl BitmapType {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+}
+
+#[typetag::serde]
+impl DataType for BitmapType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Bitmap
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "Bitmap"
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(bitmap_serialize(&RoaringTreemap::new()))
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_string()?;
+        let column = Series::from_data(&[value.as_slice()]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let mut builder = MutableStringColumn::with_capacity(data.len());
+        for value in data.iter() {
+            builder.append_value(value.as_string()?);
+        }
+        Ok(builder.to_column())
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Bitmap".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(BitmapSerializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(BitmapDeserializer::with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for BitmapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub fn bitmap_serialize(bitmap: &RoaringTreemap) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(bitmap.serialized_size());
+    // Writing into a Vec never fails.
+    bitmap.serialize_into(&mut buf).unwrap();
+    buf
+}
+
+pub fn bitmap_deserialize(bytes: &[u8]) -> Result<RoaringTreemap> {
+    RoaringTreemap::deserialize_from(bytes)
+        .map_err(|e| ErrorCode::BadBytes(format!("Incorrect Bitmap value: {}", e)))
+}
+
+/// Parses a bitmap from its text form, a comma separated list of unsigned integers, e.g. `1,2,3`.
+pub fn bitmap_from_text(text: &[u8]) -> Result<RoaringTreemap> {
+    let text = std::str::from_utf8(text)
+        .map_err(|e| ErrorCode::BadBytes(format!("Incorrect Bitmap value: {}", e)))?;
+    let mut bitmap = RoaringTreemap::new();
+    for v in text.split(',').map(|v| v.trim()).filter(|v| !v.is_empty()) {
+        let v = v.parse::<u64>().map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "Incorrect Bitmap value: '{}' is not an unsigned integer",
+                v
+            ))
+        })?;
+        bitmap.insert(v);
+    }
+    Ok(bitmap)
+}
+
+pub fn bitmap_to_text(bitmap: &RoaringTreemap) -> String {
+    bitmap
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
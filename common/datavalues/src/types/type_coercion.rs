@@ -149,6 +149,126 @@ pub fn numerical_coercion(
     construct_numeric_type(has_signed, has_float, max_size)
 }
 
+/// The number of decimal digits an integer type can hold, it's used to
+/// treat an integer as a Decimal(digits, 0).
+fn integer_decimal_digits(type_id: TypeID) -> Result<usize> {
+    match type_id {
+        Int8 | UInt8 => Ok(3),
+        Int16 | UInt16 => Ok(5),
+        Int32 | UInt32 => Ok(10),
+        Int64 => Ok(19),
+        UInt64 => Ok(20),
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't convert {:?} to decimal",
+            type_id
+        ))),
+    }
+}
+
+/// Returns the (precision, scale) of a decimal or integer type.
+pub fn decimal_precision_scale(data_type: &DataTypePtr) -> Result<(usize, usize)> {
+    let type_id = data_type.data_type_id();
+    if type_id.is_decimal() {
+        let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+        return Ok((decimal.precision(), decimal.scale()));
+    }
+    Ok((integer_decimal_digits(type_id)?, 0))
+}
+
+/// The results of the decimals up to 38 digits stay in 38 digits, so they are still backed by
+/// i128, they can widen to 76 digits once one side is wider than 38 digits.
+fn decimal_max_precision(lhs_precision: usize, rhs_precision: usize) -> usize {
+    match cmp::max(lhs_precision, rhs_precision) > DECIMAL128_MAX_PRECISION {
+        true => DECIMAL_MAX_PRECISION,
+        false => DECIMAL128_MAX_PRECISION,
+    }
+}
+
+/// Coercion rule for decimal types: at least one of lhs and rhs is decimal, the other
+/// one is decimal, integer or float. The result keeps the max integral digits and the max scale
+/// of both sides, and falls back to Float64 if one of them is float.
+pub fn decimal_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<DataTypePtr> {
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::arc());
+    }
+
+    let (lhs_precision, lhs_scale) = decimal_precision_scale(lhs_type)?;
+    let (rhs_precision, rhs_scale) = decimal_precision_scale(rhs_type)?;
+
+    let scale = cmp::max(lhs_scale, rhs_scale);
+    let integral = cmp::max(lhs_precision - lhs_scale, rhs_precision - rhs_scale);
+    let max_precision = decimal_max_precision(lhs_precision, rhs_precision);
+    let precision = cmp::min(integral + scale, max_precision);
+    DecimalType::try_arc(precision, scale)
+}
+
+// The scale the result of `*` and `/` keeps at least once its precision is capped.
+const DECIMAL_MIN_ADJUSTED_SCALE: usize = 6;
+
+/// The decimal type holding `integral` digits before the decimal point and `scale` digits after
+/// it. If it's wider than the max precision, the scale is reduced to keep the integral digits
+/// but no less than `DECIMAL_MIN_ADJUSTED_SCALE` digits, the integral part which still doesn't
+/// fit is reported as an overflow by the values.
+fn adjusted_decimal(integral: usize, scale: usize, max_precision: usize) -> Result<DataTypePtr> {
+    let scale = match integral + scale > max_precision {
+        true => cmp::max(
+            cmp::min(scale, DECIMAL_MIN_ADJUSTED_SCALE),
+            max_precision.saturating_sub(integral),
+        ),
+        false => scale,
+    };
+    let precision = cmp::min(integral + scale, max_precision);
+    DecimalType::try_arc(cmp::max(precision, 1), scale)
+}
+
+/// Coercion rule for arithmetic operations on decimals: at least one of lhs and rhs is decimal.
+/// The result of `+`, `-` and `%` keeps the max scale, `*` sums up both scales, `/` keeps at
+/// least `DECIMAL_MIN_ADJUSTED_SCALE` digits and the digits of the divisor after the scale of
+/// the dividend, `DIV` produces Int64 and any operation with float produces Float64.
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueBinaryOperator,
+    lhs_type: &DataTypePtr,
+    rhs_type: &DataTypePtr,
+) -> Result<DataTypePtr> {
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::arc());
+    }
+
+    let (lhs_precision, lhs_scale) = decimal_precision_scale(lhs_type)?;
+    let (rhs_precision, rhs_scale) = decimal_precision_scale(rhs_type)?;
+    let integral = cmp::max(lhs_precision - lhs_scale, rhs_precision - rhs_scale);
+    let max_precision = decimal_max_precision(lhs_precision, rhs_precision);
+
+    match op {
+        DataValueBinaryOperator::Plus | DataValueBinaryOperator::Minus => {
+            let scale = cmp::max(lhs_scale, rhs_scale);
+            let precision = cmp::min(integral + scale + 1, max_precision);
+            DecimalType::try_arc(precision, scale)
+        }
+        DataValueBinaryOperator::Modulo => {
+            let scale = cmp::max(lhs_scale, rhs_scale);
+            let precision = cmp::min(integral + scale, max_precision);
+            DecimalType::try_arc(precision, scale)
+        }
+        DataValueBinaryOperator::Mul => {
+            let scale = lhs_scale + rhs_scale;
+            let integral = lhs_precision - lhs_scale + rhs_precision - rhs_scale;
+            adjusted_decimal(integral, scale, max_precision)
+        }
+        DataValueBinaryOperator::Div => {
+            let scale = cmp::max(DECIMAL_MIN_ADJUSTED_SCALE, lhs_scale + rhs_precision + 1);
+            adjusted_decimal(lhs_precision - lhs_scale + rhs_scale, scale, max_precision)
+        }
+        DataValueBinaryOperator::IntDiv => Ok(Int64Type::arc()),
+    }
+}
+
 #[inline]
 pub fn numerical_arithmetic_coercion(
     op: &DataValueBinaryOperator,
@@ -242,6 +362,11 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
         return numerical_coercion(lhs_type, rhs_type, true);
     }
 
+    if (lhs_id.is_decimal() || lhs_id.is_numeric()) && (rhs_id.is_decimal() || rhs_id.is_numeric())
+    {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     //  one of is nothing
     {
         if lhs_id == TypeID::Null {
@@ -258,6 +383,15 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
         return Ok(Float64Type::arc());
     }
 
+    // one of is String and other is decimal
+    if lhs_id.is_decimal() && rhs_id.is_string() {
+        return Ok(lhs_type.clone());
+    }
+
+    if rhs_id.is_decimal() && lhs_id.is_string() {
+        return Ok(rhs_type.clone());
+    }
+
     // one of is datetime and other is number or string
     {
         if (lhs_id.is_numeric() || lhs_id.is_string()) && rhs_id.is_date_or_date_time() {
@@ -347,12 +481,25 @@ pub fn merge_types(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<Dat
 
             Ok(Arc::new(StructType::create(a.names().clone(), types)))
         }
+        (Map, Map) => {
+            let a = lhs_type.as_any().downcast_ref::<MapType>().unwrap();
+            let b = rhs_type.as_any().downcast_ref::<MapType>().unwrap();
+
+            let key = merge_types(a.key_type(), b.key_type())?;
+            let value = merge_types(a.value_type(), b.value_type())?;
+            Ok(MapType::arc(key, value))
+        }
+        (Decimal | Decimal256, Decimal | Decimal256) => decimal_coercion(lhs_type, rhs_type),
         _ => {
             if lhs_id == rhs_id {
                 return Ok(lhs_type.clone());
             }
             if lhs_id.is_numeric() && rhs_id.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if (lhs_id.is_decimal() || lhs_id.is_numeric())
+                && (rhs_id.is_decimal() || rhs_id.is_numeric())
+            {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {:?} and {:?}",
@@ -108,6 +108,7 @@ impl DataType for DateTime64Type {
     fn aliases(&self) -> &[&str] {
         match self.precision {
             3 => &["DateTime64"],
+            6 => &["Timestamp"],
             _ => &[],
         }
     }
@@ -0,0 +1,625 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::Sub;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+pub use ethnum::AsI256;
+pub use ethnum::I256;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_META;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// The max number of digits a Decimal backed by i128 can hold.
+pub const DECIMAL128_MAX_PRECISION: usize = 38;
+/// The max number of digits a Decimal can hold, it's bounded by the physical type I256.
+pub const DECIMAL_MAX_PRECISION: usize = 76;
+pub const DECIMAL_DEFAULT_PRECISION: usize = 18;
+pub const DECIMAL_DEFAULT_SCALE: usize = 0;
+
+/// The integer holding the value of a decimal multiplied by 10^scale, it's i128 for the
+/// decimals up to 38 digits and I256 for the wider ones.
+pub trait DecimalNative:
+    Copy
+    + Ord
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+
+    fn from_i128(v: i128) -> Self;
+    fn to_i256(self) -> I256;
+    fn try_from_i256(v: I256) -> Option<Self>;
+
+    fn from_f64(v: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    /// Read the stored integers of a non-nullable decimal column.
+    fn column_values(column: &ColumnRef) -> Result<Vec<Self>>;
+    fn new_column(values: Vec<Self>) -> ColumnRef;
+}
+
+impl DecimalNative for i128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const TEN: Self = 10;
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i128::checked_add(self, rhs)
+    }
+
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i128::checked_sub(self, rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i128::checked_mul(self, rhs)
+    }
+
+    #[inline]
+    fn checked_pow(self, exp: u32) -> Option<Self> {
+        i128::checked_pow(self, exp)
+    }
+
+    #[inline]
+    fn from_i128(v: i128) -> Self {
+        v
+    }
+
+    #[inline]
+    fn to_i256(self) -> I256 {
+        I256::new(self)
+    }
+
+    #[inline]
+    fn try_from_i256(v: I256) -> Option<Self> {
+        // It fits in i128 if the high word is the sign extension of the low word.
+        let (high, low) = v.into_words();
+        match high == low >> 127 {
+            true => Some(low),
+            false => None,
+        }
+    }
+
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v as i128
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn column_values(column: &ColumnRef) -> Result<Vec<Self>> {
+        let column = column.convert_full_column();
+        let column: &Int128Column = Series::check_get(&column)?;
+        Ok(column.values().to_vec())
+    }
+
+    fn new_column(values: Vec<Self>) -> ColumnRef {
+        Arc::new(Int128Column::new_from_vec(values))
+    }
+}
+
+impl DecimalNative for I256 {
+    const ZERO: Self = I256::ZERO;
+    const ONE: Self = I256::ONE;
+    const TEN: Self = I256::new(10);
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        I256::checked_add(self, rhs)
+    }
+
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        I256::checked_sub(self, rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        I256::checked_mul(self, rhs)
+    }
+
+    #[inline]
+    fn checked_pow(self, exp: u32) -> Option<Self> {
+        I256::checked_pow(self, exp)
+    }
+
+    #[inline]
+    fn from_i128(v: i128) -> Self {
+        I256::new(v)
+    }
+
+    #[inline]
+    fn to_i256(self) -> I256 {
+        self
+    }
+
+    #[inline]
+    fn try_from_i256(v: I256) -> Option<Self> {
+        Some(v)
+    }
+
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v.as_i256()
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    fn column_values(column: &ColumnRef) -> Result<Vec<Self>> {
+        let column = column.convert_full_column();
+        let column: &StringColumn = Series::check_get(&column)?;
+        column.iter().map(decimal256_from_bytes).collect()
+    }
+
+    fn new_column(values: Vec<Self>) -> ColumnRef {
+        let mut builder = MutableStringColumn::with_capacity(values.len());
+        for v in values {
+            builder.append_value(decimal256_to_bytes(v));
+        }
+        builder.to_column()
+    }
+}
+
+/// Decimals wider than 38 digits are stored as 32 bytes, big-endian with the sign bit flipped,
+/// so comparing the bytes of two values gives the same order as comparing the values.
+#[inline]
+pub fn decimal256_to_bytes(v: I256) -> [u8; 32] {
+    let mut bytes = v.to_be_bytes();
+    bytes[0] ^= 0x80;
+    bytes
+}
+
+#[inline]
+pub fn decimal256_from_bytes(bytes: &[u8]) -> Result<I256> {
+    let mut buf: [u8; 32] = bytes.try_into().map_err(|_| {
+        ErrorCode::BadBytes(format!(
+            "Incorrect Decimal256 value, expected 32 bytes but got {}",
+            bytes.len()
+        ))
+    })?;
+    buf[0] ^= 0x80;
+    Ok(I256::from_be_bytes(buf))
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct DecimalType {
+    /// The total number of digits, range from 1 to 76.
+    precision: usize,
+    /// The number of digits after the decimal point, range from 0 to precision.
+    scale: usize,
+}
+
+impl Default for DecimalType {
+    fn default() -> Self {
+        Self {
+            precision: DECIMAL_DEFAULT_PRECISION,
+            scale: DECIMAL_DEFAULT_SCALE,
+        }
+    }
+}
+
+impl DecimalType {
+    pub fn try_create(precision: usize, scale: usize) -> Result<Self> {
+        if precision == 0 || precision > DECIMAL_MAX_PRECISION {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Decimal precision must be between 1 and {}, but got {}",
+                DECIMAL_MAX_PRECISION, precision
+            )));
+        }
+
+        if scale > precision {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Decimal scale must be between 0 and precision {}, but got {}",
+                precision, scale
+            )));
+        }
+
+        Ok(Self { precision, scale })
+    }
+
+    pub fn try_arc(precision: usize, scale: usize) -> Result<DataTypePtr> {
+        Ok(Arc::new(Self::try_create(precision, scale)?))
+    }
+
+    /// Callers must guarantee that `precision` and `scale` are valid.
+    pub fn arc(precision: usize, scale: usize) -> DataTypePtr {
+        Arc::new(Self { precision, scale })
+    }
+
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+
+    /// Whether the values are stored as I256 instead of i128.
+    #[inline]
+    pub fn is_decimal256(&self) -> bool {
+        self.precision > DECIMAL128_MAX_PRECISION
+    }
+
+    /// The factor between the stored integer and the logical value: 10^scale.
+    /// The methods generic on the stored integer expect I256 for the decimals wider than 38 digits.
+    #[inline]
+    pub fn scale_factor<T: DecimalNative>(&self) -> T {
+        T::TEN.checked_pow(self.scale as u32).unwrap()
+    }
+
+    /// The max absolute stored value this decimal can hold: 10^precision - 1.
+    #[inline]
+    pub fn max_value<T: DecimalNative>(&self) -> T {
+        T::TEN.checked_pow(self.precision as u32).unwrap() - T::ONE
+    }
+
+    #[inline]
+    pub fn check_overflow<T: DecimalNative>(&self, v: T) -> Result<T> {
+        let max = self.max_value::<T>();
+        if v > max || v < -max {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: value {} is out of range of {:?}",
+                self.format_value(v),
+                self
+            )));
+        }
+        Ok(v)
+    }
+
+    /// Rescale the stored value `v` from `from_scale` to the scale of this decimal.
+    /// Digits beyond the target scale are rounded half away from zero.
+    pub fn rescale<T: DecimalNative>(&self, v: T, from_scale: usize) -> Result<T> {
+        let res = match from_scale.cmp(&self.scale) {
+            std::cmp::Ordering::Equal => Some(v),
+            std::cmp::Ordering::Less => T::TEN
+                .checked_pow((self.scale - from_scale) as u32)
+                .and_then(|f| v.checked_mul(f)),
+            // All the digits are dropped if the factor is out of the range of T.
+            std::cmp::Ordering::Greater => {
+                match T::TEN.checked_pow((from_scale - self.scale) as u32) {
+                    Some(f) => Some(div_round(v, f)),
+                    None => Some(T::ZERO),
+                }
+            }
+        };
+
+        match res {
+            Some(v) => self.check_overflow(v),
+            None => Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: can't rescale value to {:?}",
+                self
+            ))),
+        }
+    }
+
+    /// Rescale the stored value `v` of the decimal `from` to the stored value of this decimal,
+    /// they can be backed by different integers.
+    pub fn rescale_from<T: DecimalNative, U: DecimalNative>(
+        &self,
+        v: T,
+        from: &DecimalType,
+    ) -> Result<U> {
+        if let Some(v) = U::try_from_i256(v.to_i256()) {
+            return self.rescale(v, from.scale);
+        }
+
+        // The value is narrowed down after it's rescaled to this decimal.
+        let v = self.rescale(v.to_i256(), from.scale)?;
+        U::try_from_i256(v).ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: value {} is out of range of {:?}",
+                self.format_value(v),
+                self
+            ))
+        })
+    }
+
+    pub fn from_f64<T: DecimalNative>(&self, v: f64) -> Result<T> {
+        let v = (v * 10_f64.powi(self.scale as i32)).round();
+        if !v.is_finite() || v.abs() > self.max_value::<T>().to_f64() {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: value is out of range of {:?}",
+                self
+            )));
+        }
+        Ok(T::from_f64(v))
+    }
+
+    #[inline]
+    pub fn to_f64<T: DecimalNative>(&self, v: T) -> f64 {
+        v.to_f64() / 10_f64.powi(self.scale as i32)
+    }
+
+    /// Format the stored value as a plain decimal string, e.g. 12345 with scale 2 is "123.45".
+    pub fn format_value<T: DecimalNative>(&self, v: T) -> String {
+        let s = v.to_string();
+        if self.scale == 0 {
+            return s;
+        }
+
+        let (sign, digits) = match s.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", s.as_str()),
+        };
+        let digits = format!("{:0>width$}", digits, width = self.scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.scale);
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+
+    /// Parse a decimal string like "-123.456" or "1e3" to the stored value of this decimal.
+    pub fn parse_value<T: DecimalNative>(&self, s: &[u8]) -> Result<T> {
+        let err = || {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value:{:?} to {:?}",
+                String::from_utf8_lossy(s),
+                self
+            ))
+        };
+
+        let s = std::str::from_utf8(s).map_err(|_| err())?.trim();
+        let overflow = || {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: value {} is out of range of {:?}",
+                s, self
+            ))
+        };
+
+        let (mantissa, exp) = match s.find(|c| c == 'e' || c == 'E') {
+            Some(pos) => (&s[..pos], s[pos + 1..].parse::<i32>().map_err(|_| err())?),
+            None => (s, 0),
+        };
+
+        let (negative, mantissa) = match mantissa.as_bytes().first() {
+            Some(b'-') => (true, &mantissa[1..]),
+            Some(b'+') => (false, &mantissa[1..]),
+            _ => (false, mantissa),
+        };
+
+        let (int_part, frac_part) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !int_part.bytes().all(|b| b.is_ascii_digit())
+            || !frac_part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(err());
+        }
+
+        let digits = format!("{}{}", int_part, frac_part);
+        let digits = digits.trim_start_matches('0');
+        let from_scale = frac_part.len() as i32 - exp;
+
+        // Drop the trailing digits which can't affect the rounded result.
+        let keep = digits.len() as i32 - (from_scale - self.scale as i32 - 1).max(0);
+        if keep <= 0 {
+            return Ok(T::ZERO);
+        }
+        let digits = &digits[..keep as usize];
+        let from_scale = from_scale - (from_scale - self.scale as i32 - 1).max(0);
+
+        // The digits are checked above, so parsing fails only if it's out of the range of T.
+        let v = match digits.is_empty() {
+            true => T::ZERO,
+            false => digits.parse::<T>().map_err(|_| overflow())?,
+        };
+        let v = if negative { -v } else { v };
+
+        if from_scale < 0 {
+            let v = T::TEN
+                .checked_pow((-from_scale) as u32)
+                .and_then(|f| v.checked_mul(f))
+                .ok_or_else(overflow)?;
+            return self.rescale(v, 0);
+        }
+
+        self.rescale(v, from_scale as usize)
+    }
+}
+
+// The integers of a decimal column are read as a decimal without scale.
+impl ToDataType for i128 {
+    fn to_data_type() -> DataTypePtr {
+        DecimalType::arc(DECIMAL128_MAX_PRECISION, 0)
+    }
+}
+
+/// Divide and round half away from zero, `rhs` must not be zero.
+#[inline]
+pub fn div_round<T: DecimalNative>(lhs: T, rhs: T) -> T {
+    let (q, r) = (lhs / rhs, lhs % rhs);
+    let abs = |v: T| if v < T::ZERO { -v } else { v };
+    if abs(r) < abs(rhs) - abs(r) {
+        return q;
+    }
+    match (lhs < T::ZERO) == (rhs < T::ZERO) {
+        true => q + T::ONE,
+        false => q - T::ONE,
+    }
+}
+
+/// The value of a Decimal256 is the bytes of the stored integer, see `decimal256_to_bytes`.
+/// Integer values are taken as the stored integer.
+pub fn decimal256_data_value(value: &DataValue) -> Result<Vec<u8>> {
+    match value {
+        DataValue::String(v) => {
+            decimal256_from_bytes(v)?;
+            Ok(v.clone())
+        }
+        _ => Ok(decimal256_to_bytes(I256::new(value.as_i128()?)).to_vec()),
+    }
+}
+
+/// Tag the arrow array of a decimal column with the precision and the scale of its field,
+/// the column only knows its physical type, so its array is a `Decimal(38, 0)`.
+pub fn decimal_arrow_array(array: ArrayRef, arrow_type: &ArrowType) -> ArrayRef {
+    match (arrow_type, array.data_type()) {
+        (ArrowType::Decimal(_, _), ArrowType::Decimal(_, _)) if arrow_type != array.data_type() => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .expect("decimal array must be a primitive array of i128");
+            Arc::new(array.clone().to(arrow_type.clone()))
+        }
+        _ => array,
+    }
+}
+
+#[typetag::serde]
+impl DataType for DecimalType {
+    fn data_type_id(&self) -> TypeID {
+        match self.is_decimal256() {
+            true => TypeID::Decimal256,
+            false => TypeID::Decimal,
+        }
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "Decimal"
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["Numeric"]
+    }
+
+    fn default_value(&self) -> DataValue {
+        match self.is_decimal256() {
+            true => DataValue::String(decimal256_to_bytes(I256::ZERO).to_vec()),
+            false => DataValue::Int128(0),
+        }
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let column = self.create_column(&[data.clone()])?;
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        if self.is_decimal256() {
+            let mut builder = MutableStringColumn::with_capacity(data.len());
+            for value in data.iter() {
+                builder.append_value(decimal256_data_value(value)?);
+            }
+            return Ok(builder.to_column());
+        }
+
+        let value = data
+            .iter()
+            .map(|v| v.as_i128())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Series::from_data(&value))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        match self.is_decimal256() {
+            true => ArrowType::LargeBinary,
+            false => ArrowType::Decimal(self.precision, self.scale),
+        }
+    }
+
+    // Arrow has no decimal wider than 38 digits, the precision and the scale are kept in the
+    // metadata of the binary field.
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        if !self.is_decimal256() {
+            return None;
+        }
+
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Decimal256".to_string());
+        mp.insert(
+            ARROW_EXTENSION_META.to_string(),
+            format!("{},{}", self.precision, self.scale),
+        );
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(DecimalSerializer::create(self.clone()))
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        match self.is_decimal256() {
+            true => Box::new(Decimal256Deserializer {
+                builder: MutableStringColumn::with_capacity(capacity),
+                data_type: self.clone(),
+            }),
+            false => Box::new(DecimalDeserializer {
+                builder: MutablePrimitiveColumn::<i128>::with_capacity(capacity),
+                data_type: self.clone(),
+            }),
+        }
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        match self.is_decimal256() {
+            true => Box::new(MutableStringColumn::with_capacity(capacity)),
+            false => Box::new(MutablePrimitiveColumn::<i128>::with_capacity(capacity)),
+        }
+    }
+}
+
+impl std::fmt::Debug for DecimalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Decimal({}, {})", self.precision, self.scale)
+    }
+}
@@ -48,6 +48,9 @@ static TYPE_FACTORY: Lazy<Arc<TypeFactory>> = Lazy::new(|| {
     type_factory.register(Date16Type::arc());
     type_factory.register(Date32Type::arc());
     type_factory.register(DateTime32Type::arc(None));
+    type_factory.register(Arc::new(DecimalType::default()));
+    type_factory.register(BitmapType::arc());
+    type_factory.register(GeometryType::arc());
     type_factory.register(VariantType::arc());
     type_factory.register(VariantArrayType::arc());
     type_factory.register(VariantObjectType::arc());
@@ -65,9 +65,29 @@ pub enum TypeID {
     /// Underneath Interval is stored as int64, so it supports negative values.
     Interval,
 
+    /// Decimal is a fixed-point number with the given precision and scale,
+    /// it's physical type is Int128 which holds the value multiplied by 10^scale.
+    Decimal,
+
+    /// Decimal256 is a Decimal wider than 38 digits, its physical type is String which holds
+    /// the value multiplied by 10^scale as 32 bytes, see `decimal256_to_bytes`.
+    Decimal256,
+
     Array,
     Struct,
 
+    /// Map is a set of key-value pairs, it's physical type is an Array
+    /// of Struct(key, value).
+    Map,
+
+    /// Bitmap is a set of unsigned 64-bit integers backed by a roaring bitmap,
+    /// it's physical type is String which holds the serialized bitmap.
+    Bitmap,
+
+    /// Geometry is a planar geometry such as a point or a polygon,
+    /// it's physical type is String which holds the geometry encoded as WKB.
+    Geometry,
+
     /// Variant is a tagged universal type, which can store values of any other type,
     /// including Object and Array, up to a maximum size of 16 MB.
     Variant,
@@ -155,6 +175,11 @@ impl TypeID {
         self.is_integer() || self.is_floating()
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, TypeID::Decimal | TypeID::Decimal256)
+    }
+
     #[inline]
     pub fn is_interval(&self) -> bool {
         matches!(self, TypeID::Interval)
@@ -170,11 +195,18 @@ impl TypeID {
         matches!(self, TypeID::Struct)
     }
 
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, TypeID::Map)
+    }
+
     #[inline]
     pub fn is_quoted(&self) -> bool {
         matches!(
             self,
             TypeID::String
+                | TypeID::Bitmap
+                | TypeID::Geometry
                 | TypeID::Date16
                 | TypeID::Date32
                 | TypeID::DateTime32
@@ -225,6 +257,7 @@ impl TypeID {
 
             Int32 | Date32 => PhysicalTypeID::Int32,
             Int64 | Interval | DateTime64 => PhysicalTypeID::Int64,
+            Decimal => PhysicalTypeID::Int128,
 
             UInt8 => PhysicalTypeID::UInt8,
             Date16 | UInt16 => PhysicalTypeID::UInt16,
@@ -233,8 +266,8 @@ impl TypeID {
             Float32 => PhysicalTypeID::Float32,
             Float64 => PhysicalTypeID::Float64,
 
-            String => PhysicalTypeID::String,
-            Array => PhysicalTypeID::Array,
+            String | Bitmap | Geometry | Decimal256 => PhysicalTypeID::String,
+            Array | Map => PhysicalTypeID::Array,
             Struct => PhysicalTypeID::Struct,
             Variant | VariantArray | VariantObject => PhysicalTypeID::Variant,
         }
@@ -266,6 +299,8 @@ pub enum PhysicalTypeID {
     Int32,
     /// A signed 64-bit integer.
     Int64,
+    /// A signed 128-bit integer.
+    Int128,
     /// An unsigned 8-bit integer.
     UInt8,
     /// An unsigned 16-bit integer.
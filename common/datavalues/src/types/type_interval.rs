@@ -31,7 +31,9 @@ pub struct IntervalType {
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum IntervalKind {
     Year,
+    Quarter,
     Month,
+    Week,
     Day,
     Hour,
     Minute,
@@ -42,7 +44,9 @@ impl fmt::Display for IntervalKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
             IntervalKind::Year => "YEAR",
+            IntervalKind::Quarter => "QUARTER",
             IntervalKind::Month => "MONTH",
+            IntervalKind::Week => "WEEK",
             IntervalKind::Day => "DAY",
             IntervalKind::Hour => "HOUR",
             IntervalKind::Minute => "MINUTE",
@@ -55,7 +59,9 @@ impl From<String> for IntervalKind {
     fn from(s: String) -> Self {
         match s.as_str() {
             "YEAR" => IntervalKind::Year,
+            "QUARTER" => IntervalKind::Quarter,
             "MONTH" => IntervalKind::Month,
+            "WEEK" => IntervalKind::Week,
             "DAY" => IntervalKind::Day,
             "HOUR" => IntervalKind::Hour,
             "MINUTE" => IntervalKind::Minute,
@@ -65,6 +71,25 @@ impl From<String> for IntervalKind {
     }
 }
 
+impl IntervalKind {
+    /// Parse an interval unit case-insensitively, accepting both the singular and plural forms.
+    pub fn from_unit(unit: &str) -> Option<IntervalKind> {
+        let unit = unit.to_uppercase();
+        let unit = unit.strip_suffix('S').unwrap_or(&unit);
+        match unit {
+            "YEAR" => Some(IntervalKind::Year),
+            "QUARTER" => Some(IntervalKind::Quarter),
+            "MONTH" => Some(IntervalKind::Month),
+            "WEEK" => Some(IntervalKind::Week),
+            "DAY" => Some(IntervalKind::Day),
+            "HOUR" => Some(IntervalKind::Hour),
+            "MINUTE" => Some(IntervalKind::Minute),
+            "SECOND" => Some(IntervalKind::Second),
+            _ => None,
+        }
+    }
+}
+
 impl IntervalType {
     pub fn arc(kind: IntervalKind) -> DataTypePtr {
         Arc::new(Self { kind })
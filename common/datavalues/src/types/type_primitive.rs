@@ -43,6 +43,8 @@ pub fn create_primitive_datatype<T: PrimitiveType>() -> Arc<dyn DataType> {
         (true, false, 2) => Arc::new(Int16Type { _t: PhantomData }),
         (true, false, 4) => Arc::new(Int32Type { _t: PhantomData }),
         (true, false, 8) => Arc::new(Int64Type { _t: PhantomData }),
+        // The only column of i128 is the decimal.
+        (true, false, 16) => DecimalType::arc(DECIMAL128_MAX_PRECISION, 0),
 
         (true, true, 4) => Arc::new(Float32Type { _t: PhantomData }),
         (true, true, 8) => Arc::new(Float64Type { _t: PhantomData }),
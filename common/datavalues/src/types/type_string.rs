@@ -12,20 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 
 use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_META;
+use super::data_type::ARROW_EXTENSION_NAME;
 use super::type_id::TypeID;
 use crate::prelude::*;
 
 #[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
-pub struct StringType {}
+pub struct StringType {
+    #[serde(default)]
+    collation: Collation,
+}
 
 impl StringType {
     pub fn arc() -> DataTypePtr {
-        Arc::new(Self {})
+        Arc::new(Self::default())
+    }
+
+    pub fn arc_with_collation(collation: Collation) -> DataTypePtr {
+        Arc::new(Self { collation })
+    }
+
+    pub fn collation(&self) -> Collation {
+        self.collation
     }
 }
 
@@ -68,6 +82,20 @@ impl DataType for StringType {
         ArrowType::LargeBinary
     }
 
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        if self.collation.is_binary() {
+            return None;
+        }
+
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "String".to_string());
+        mp.insert(
+            ARROW_EXTENSION_META.to_string(),
+            self.collation.name().to_string(),
+        );
+        Some(mp)
+    }
+
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
         Box::new(StringSerializer {})
     }
@@ -100,6 +128,10 @@ impl DataType for StringType {
 
 impl std::fmt::Debug for StringType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name())
+        if self.collation.is_binary() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{} COLLATE {}", self.name(), self.collation)
+        }
     }
 }
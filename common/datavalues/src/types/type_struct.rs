@@ -106,12 +106,24 @@ impl DataType for StructType {
         })
     }
 
-    fn create_deserializer(&self, _capacity: usize) -> Box<dyn TypeDeserializer> {
-        todo!()
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        let inners = self
+            .types
+            .iter()
+            .map(|v| v.create_deserializer(capacity))
+            .collect();
+        Box::new(StructDeserializer {
+            names: self.names.clone(),
+            inners,
+            data_type: Arc::new(self.clone()),
+        })
     }
 
-    fn create_mutable(&self, _capacity: usize) -> Box<dyn MutableColumn> {
-        todo!()
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStructColumn::with_capacity(
+            Arc::new(self.clone()),
+            capacity,
+        ))
     }
 
     fn create_column(&self, datas: &[DataValue]) -> common_exception::Result<ColumnRef> {
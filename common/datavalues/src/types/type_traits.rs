@@ -18,6 +18,8 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 
+use crate::decode_jsonb;
+use crate::encode_jsonb;
 use crate::DFTryFrom;
 use crate::DataTypePtr;
 use crate::DataValue;
@@ -64,6 +66,7 @@ impl_primitive!(i8, i64, true, false, 1);
 impl_primitive!(i16, i64, true, false, 2);
 impl_primitive!(i32, i64, true, false, 4);
 impl_primitive!(i64, i64, true, false, 8);
+impl_primitive!(i128, i128, true, false, 16);
 impl_primitive!(f32, f64, true, true, 4);
 impl_primitive!(f64, f64, true, true, 8);
 
@@ -136,10 +139,30 @@ pub trait ObjectType:
     + Scalar
 {
     fn data_type() -> DataTypePtr;
+
+    /// Encode the value to the bytes stored in the arrow array, defaults to the text form.
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.to_string().as_bytes());
+    }
+
+    /// Decode the value from the bytes written by `encode`.
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        std::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| Self::from_str(s).ok())
+    }
 }
 
 impl ObjectType for JsonValue {
     fn data_type() -> DataTypePtr {
         VariantType::arc()
     }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_jsonb(self, buf);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        decode_jsonb(bytes).ok()
+    }
 }
@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn new_array_column(values: Vec<Vec<i64>>) -> Result<ColumnRef> {
+    let data_type = ArrayType::arc(Int64Type::arc());
+    let mut builder = MutableArrayColumn::with_capacity(data_type, values.len());
+    for vals in values {
+        builder.append_value(vals.into_iter().map(DataValue::Int64).collect())?;
+    }
+    Ok(builder.to_column())
+}
+
+#[test]
+fn test_mutable_array_column() -> Result<()> {
+    let column = new_array_column(vec![vec![1, 2], vec![], vec![3]])?;
+    assert_eq!(column.len(), 3);
+    assert_eq!(
+        column.get(0),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)])
+    );
+    assert_eq!(column.get(1), DataValue::Array(vec![]));
+    assert_eq!(column.get(2), DataValue::Array(vec![DataValue::Int64(3)]));
+    Ok(())
+}
+
+#[test]
+fn test_array_column_filter_and_replicate() -> Result<()> {
+    let column = new_array_column(vec![vec![1, 2], vec![], vec![3]])?;
+
+    let filter = BooleanColumn::from_slice(&[true, false, true]);
+    let filtered = column.filter(&filter);
+    assert_eq!(filtered, new_array_column(vec![vec![1, 2], vec![3]])?);
+
+    let replicated = column.replicate(&[2, 2, 3]);
+    assert_eq!(
+        replicated,
+        new_array_column(vec![vec![1, 2], vec![1, 2], vec![3]])?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_array_value_ordering() {
+    let a = DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)]);
+    let b = DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(3)]);
+    let c = DataValue::Array(vec![DataValue::Int64(1)]);
+    assert!(a < b);
+    assert!(c < a);
+    assert!(DataValue::Null < c);
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ColumnData;
+
+fn map_value(entries: Vec<(&str, i64)>) -> DataValue {
+    let entries = entries
+        .into_iter()
+        .map(|(k, v)| DataValue::Struct(vec![k.as_bytes().into(), DataValue::Int64(v)]))
+        .collect();
+    DataValue::Array(entries)
+}
+
+#[test]
+fn test_map_column() -> Result<()> {
+    let data_type = MapType::arc(StringType::arc(), Int64Type::arc());
+    let values = vec![map_value(vec![("a", 1), ("b", 2)]), map_value(vec![])];
+    let column = data_type.create_column(&values)?;
+    assert_eq!(column.len(), 2);
+    assert_eq!(column.data_type_id(), TypeID::Map);
+    assert_eq!(column.get(0), values[0]);
+    assert_eq!(column.get(1), values[1]);
+
+    let serializer = data_type.create_serializer();
+    let result = serializer.serialize_column(&column)?;
+    assert_eq!(result, vec!["{'a': 1, 'b': 2}", "{}"]);
+
+    let result = serializer.serialize_json(&column)?;
+    assert_eq!(result, vec![
+        serde_json::json!({"a": 1, "b": 2}),
+        serde_json::json!({})
+    ]);
+
+    let result = serializer.serialize_clickhouse_format(&column)?;
+    assert_eq!(result.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_map_deserializer() -> Result<()> {
+    let data_type = MapType::arc(StringType::arc(), Int64Type::arc());
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_whole_text(b"{'a': 1, 'b': 2}")?;
+    deserializer.de_json(&serde_json::json!({"c": 3}))?;
+    let column = deserializer.finish_to_column();
+
+    let expect = vec![
+        map_value(vec![("a", 1), ("b", 2)]),
+        map_value(vec![("c", 3)]),
+    ];
+    assert_eq!(column, data_type.create_column(&expect)?);
+    Ok(())
+}
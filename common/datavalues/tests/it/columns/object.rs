@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::prelude::*;
+use common_exception::Result;
 use serde_json::json;
 use serde_json::Value as JsonValue;
 
@@ -52,3 +53,42 @@ fn test_object_column() {
     let slice = data_column.slice(0, N / 2);
     assert!(slice.len() == N / 2);
 }
+
+#[test]
+fn test_jsonb_encoding() -> Result<()> {
+    let values = vec![
+        json!(null),
+        json!(true),
+        json!(-1_i64),
+        json!(u64::MAX),
+        json!(1.5_f64),
+        json!("abc"),
+        json!([1_i32, "a", [null]]),
+        json!({"a": {"b": [1_i32, 2]}, "c": "d"}),
+    ];
+
+    for v in values.iter() {
+        let mut buf = vec![];
+        encode_jsonb(v, &mut buf);
+        assert_eq!(buf[0], JSONB_MAGIC);
+        assert_eq!(&decode_jsonb(&buf)?, v);
+    }
+
+    // values stored as JSON text by older versions
+    assert_eq!(decode_jsonb(b"{\"a\":1}")?, json!({"a": 1_i32}));
+
+    let mut buf = vec![];
+    encode_jsonb(&json!([1_i32, 2]), &mut buf);
+    buf.pop();
+    assert!(decode_jsonb(&buf).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_object_column_arrow_roundtrip() {
+    let values = vec![json!({"a": [1_i32, 2]}), json!("abc"), json!(null)];
+    let column = JsonColumn::new_from_vec(values.clone());
+    let array = column.as_arrow_array();
+    let column = JsonColumn::from_arrow_array(array.as_ref());
+    assert_eq!(column.values(), values.as_slice());
+}
@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn struct_type() -> DataTypePtr {
+    Arc::new(StructType::create(
+        vec!["a".to_string(), "b".to_string()],
+        vec![Int64Type::arc(), StringType::arc()],
+    ))
+}
+
+fn struct_value(a: i64, b: &str) -> DataValue {
+    DataValue::Struct(vec![DataValue::Int64(a), DataValue::String(b.as_bytes().to_vec())])
+}
+
+fn new_struct_column(values: Vec<(i64, &str)>) -> Result<ColumnRef> {
+    let mut builder = MutableStructColumn::with_capacity(struct_type(), values.len());
+    for (a, b) in values {
+        builder.append_data_value(struct_value(a, b))?;
+    }
+    Ok(builder.to_column())
+}
+
+#[test]
+fn test_mutable_struct_column() -> Result<()> {
+    let column = new_struct_column(vec![(1, "x"), (2, "y")])?;
+    assert_eq!(column.len(), 2);
+    assert_eq!(column.get(0), struct_value(1, "x"));
+    assert_eq!(column.get(1), struct_value(2, "y"));
+
+    let mut builder = MutableStructColumn::with_capacity(struct_type(), 1);
+    let res = builder.append_value(vec![DataValue::Int64(1)]);
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_struct_column_take() -> Result<()> {
+    let column = new_struct_column(vec![(1, "x"), (2, "y"), (3, "z")])?;
+    let taken = Series::take(&column, &[2u32, 0])?;
+    assert_eq!(taken, new_struct_column(vec![(3, "z"), (1, "x")])?);
+    Ok(())
+}
+
+#[test]
+fn test_struct_deserializer() -> Result<()> {
+    let data_type = struct_type();
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_whole_text(b"(1, 'x')")?;
+    deserializer.de_json(&serde_json::json!({"a": 2, "b": "y"}))?;
+    let column = deserializer.finish_to_column();
+    assert_eq!(column, new_struct_column(vec![(1, "x"), (2, "y")])?);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_collation_compare() -> Result<()> {
+    let tests = vec![
+        ("utf8_bin", "abc", "ABC", Ordering::Greater),
+        ("utf8_general_ci", "abc", "ABC", Ordering::Equal),
+        ("utf8_general_ci", "Apple", "banana", Ordering::Less),
+        ("utf8_general_ci", "café", "CAFE", Ordering::Greater),
+        ("utf8_unicode_ci", "café", "CAFE", Ordering::Equal),
+        ("utf8_unicode_ci", "Straße", "STRASSE", Ordering::Equal),
+        ("de_DE", "Müller", "mueller", Ordering::Equal),
+        ("de_DE", "Äpfel", "Afrika", Ordering::Less),
+        ("sv_SE", "Ångström", "zebra", Ordering::Greater),
+        ("sv_SE", "äpple", "åsna", Ordering::Greater),
+        ("sv_SE", "Öl", "öl", Ordering::Equal),
+    ];
+
+    for (name, lhs, rhs, expect) in tests {
+        let collation = Collation::from_name(name)?;
+        let ordering = collation
+            .sort_key(lhs.as_bytes())
+            .cmp(&collation.sort_key(rhs.as_bytes()));
+        assert_eq!(ordering, expect, "{} {} {}", name, lhs, rhs);
+
+        let equal = collation.fold(lhs.as_bytes()) == collation.fold(rhs.as_bytes());
+        assert_eq!(equal, expect == Ordering::Equal, "{} {} {}", name, lhs, rhs);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_collation_fold_column() -> Result<()> {
+    let collation = Collation::from_name("utf8_general_ci")?;
+    let column = Series::from_data(vec![Some("Abc"), None, Some("ABC")]);
+    let folded = collation.fold_column(&column)?;
+
+    assert!(folded.is_nullable());
+    assert_eq!(folded.get(0), DataValue::String(b"abc".to_vec()));
+    assert_eq!(folded.get(1), DataValue::Null);
+    assert_eq!(folded.get(2), DataValue::String(b"abc".to_vec()));
+    Ok(())
+}
+
+#[test]
+fn test_collation_type() -> Result<()> {
+    let data_type = StringType::arc_with_collation(Collation::CaseInsensitive);
+    assert_eq!(format!("{:?}", data_type), "String COLLATE utf8_general_ci");
+    assert_eq!(collation_of(&data_type), Collation::CaseInsensitive);
+    assert_eq!(
+        collation_of(&wrap_nullable(&data_type)),
+        Collation::CaseInsensitive
+    );
+    assert_eq!(collation_of(&StringType::arc()), Collation::Binary);
+
+    let field = DataField::new_nullable("s", data_type);
+    let from_arrow = DataField::from(&field.to_arrow());
+    assert_eq!(
+        collation_of(from_arrow.data_type()),
+        Collation::CaseInsensitive
+    );
+    assert!(from_arrow.is_nullable());
+
+    let error = Collation::from_name("klingon").unwrap_err();
+    assert_eq!(error.message(), "Unknown collation: 'klingon'");
+    Ok(())
+}
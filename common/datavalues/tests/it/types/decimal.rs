@@ -0,0 +1,278 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_datavalues::type_coercion::decimal_arithmetic_coercion;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_decimal_create() -> Result<()> {
+    assert!(DecimalType::try_create(0, 0).is_err());
+    assert!(DecimalType::try_create(77, 2).is_err());
+    assert!(DecimalType::try_create(5, 6).is_err());
+
+    let t = DecimalType::try_create(10, 2)?;
+    assert_eq!(t.precision(), 10);
+    assert_eq!(t.scale(), 2);
+    assert_eq!(t.scale_factor::<i128>(), 100);
+    assert_eq!(format!("{:?}", t), "Decimal(10, 2)");
+
+    let t = DecimalType::try_create(38, 10)?;
+    assert_eq!(t.scale_factor::<i128>(), 10_000_000_000);
+    assert_eq!(t.data_type_id(), TypeID::Decimal);
+
+    let t = DecimalType::try_create(76, 40)?;
+    assert!(t.is_decimal256());
+    assert_eq!(t.data_type_id(), TypeID::Decimal256);
+    assert_eq!(t.scale_factor::<I256>(), I256::new(10).pow(40));
+    Ok(())
+}
+
+#[test]
+fn test_decimal_parse_value() -> Result<()> {
+    let t = DecimalType::try_create(10, 2)?;
+
+    struct Test {
+        input: &'static str,
+        expect: i128,
+    }
+
+    let tests = vec![
+        Test {
+            input: "123.45",
+            expect: 12345,
+        },
+        Test {
+            input: "-0.05",
+            expect: -5,
+        },
+        Test {
+            input: "+7",
+            expect: 700,
+        },
+        Test {
+            input: ".5",
+            expect: 50,
+        },
+        Test {
+            input: "1.005",
+            expect: 101,
+        },
+        Test {
+            input: "-1.005",
+            expect: -101,
+        },
+        Test {
+            input: "1.0049999999999999999999",
+            expect: 100,
+        },
+        Test {
+            input: "1.5e2",
+            expect: 15000,
+        },
+        Test {
+            input: "15e-1",
+            expect: 150,
+        },
+        Test {
+            input: "00012.3",
+            expect: 1230,
+        },
+    ];
+
+    for test in tests {
+        let v = t.parse_value::<i128>(test.input.as_bytes())?;
+        assert_eq!(v, test.expect, "case: {}", test.input);
+    }
+
+    // overflow and illegal inputs
+    for input in ["123456789.1", "1e9", "abc", "1.2.3", "", "-"] {
+        assert!(
+            t.parse_value::<i128>(input.as_bytes()).is_err(),
+            "case: {}",
+            input
+        );
+    }
+
+    let t = DecimalType::try_create(38, 2)?;
+    let v = t.parse_value::<i128>("123456789012345678901234567890.12".as_bytes())?;
+    assert_eq!(v, 12345678901234567890123456789012);
+    assert_eq!(t.format_value(v), "123456789012345678901234567890.12");
+    Ok(())
+}
+
+#[test]
+fn test_decimal256_parse_value() -> Result<()> {
+    let t = DecimalType::try_create(76, 10)?;
+    let input = "-123456789012345678901234567890123456789012345678901234567890.0123456789";
+    let v = t.parse_value::<I256>(input.as_bytes())?;
+    assert_eq!(t.format_value(v), input);
+    assert_eq!(t.parse_value::<I256>(b"0.00000000005")?, I256::ONE);
+
+    // 67 integral digits don't fit in Decimal(76, 10).
+    let input = format!("1{}", "0".repeat(66));
+    assert!(t.parse_value::<I256>(input.as_bytes()).is_err());
+
+    let t = DecimalType::try_create(40, 2)?;
+    let v = t.parse_value::<I256>(b"12345678901234567890123456789012345678.91")?;
+    // 40 digits don't fit in i128 once the scale is kept.
+    assert!(t.rescale_from::<I256, i128>(v, &t).is_err());
+    let narrow = DecimalType::try_create(38, 0)?;
+    assert_eq!(
+        narrow.rescale_from::<I256, i128>(v, &t)?,
+        12345678901234567890123456789012345679
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal256_bytes_order() -> Result<()> {
+    let t = DecimalType::try_create(50, 5)?;
+    let mut values = ["-99999.5", "-1", "-0.00001", "0", "0.00001", "1", "1e40"]
+        .iter()
+        .map(|v| t.parse_value::<I256>(v.as_bytes()))
+        .collect::<Result<Vec<_>>>()?;
+
+    // The encoded bytes sort the same as the values, so the physical string compare works.
+    let bytes = values
+        .iter()
+        .map(|v| decimal256_to_bytes(*v))
+        .collect::<Vec<_>>();
+    let mut sorted = bytes.clone();
+    sorted.sort();
+    assert_eq!(bytes, sorted);
+
+    for (v, b) in values.iter_mut().zip(bytes.iter()) {
+        assert_eq!(decimal256_from_bytes(b)?, *v);
+    }
+    assert!(decimal256_from_bytes(&[0u8; 16]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal_rescale() -> Result<()> {
+    let t = DecimalType::try_create(10, 2)?;
+    assert_eq!(t.rescale::<i128>(12345, 3)?, 1235);
+    assert_eq!(t.rescale::<i128>(-12345, 3)?, -1235);
+    assert_eq!(t.rescale::<i128>(12, 0)?, 1200);
+    assert!(t.rescale::<i128>(100_000_000, 0).is_err());
+
+    assert_eq!(t.format_value(t.from_f64::<i128>(3.14159)?), "3.14");
+    assert_eq!(t.to_f64::<i128>(314), 3.14);
+    Ok(())
+}
+
+#[test]
+fn test_decimal_coercion() -> Result<()> {
+    let lhs = DecimalType::arc(10, 2);
+    let rhs = DecimalType::arc(12, 4);
+    let t = compare_coercion(&lhs, &rhs)?;
+    assert_eq!(format!("{:?}", t), "Decimal(12, 4)");
+
+    let t = compare_coercion(&lhs, &Int32Type::arc())?;
+    assert_eq!(format!("{:?}", t), "Decimal(12, 2)");
+
+    let t = compare_coercion(&lhs, &Float64Type::arc())?;
+    assert_eq!(t.data_type_id(), TypeID::Float64);
+
+    let t = compare_coercion(&lhs, &Int64Type::arc())?;
+    assert_eq!(format!("{:?}", t), "Decimal(21, 2)");
+    Ok(())
+}
+
+#[test]
+fn test_decimal_arithmetic_coercion() -> Result<()> {
+    struct Test {
+        op: DataValueBinaryOperator,
+        lhs: DataTypePtr,
+        rhs: DataTypePtr,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            op: DataValueBinaryOperator::Plus,
+            lhs: DecimalType::arc(10, 2),
+            rhs: DecimalType::arc(12, 4),
+            expect: "Decimal(13, 4)",
+        },
+        Test {
+            op: DataValueBinaryOperator::Mul,
+            lhs: DecimalType::arc(18, 10),
+            rhs: DecimalType::arc(18, 10),
+            expect: "Decimal(36, 20)",
+        },
+        Test {
+            op: DataValueBinaryOperator::Mul,
+            lhs: DecimalType::arc(38, 10),
+            rhs: DecimalType::arc(20, 10),
+            expect: "Decimal(38, 6)",
+        },
+        Test {
+            op: DataValueBinaryOperator::Div,
+            lhs: DecimalType::arc(10, 2),
+            rhs: DecimalType::arc(10, 2),
+            expect: "Decimal(23, 13)",
+        },
+        Test {
+            op: DataValueBinaryOperator::Div,
+            lhs: DecimalType::arc(10, 2),
+            rhs: Int32Type::arc(),
+            expect: "Decimal(21, 13)",
+        },
+        Test {
+            op: DataValueBinaryOperator::IntDiv,
+            lhs: DecimalType::arc(10, 2),
+            rhs: DecimalType::arc(10, 2),
+            expect: "Int64",
+        },
+        Test {
+            op: DataValueBinaryOperator::Plus,
+            lhs: DecimalType::arc(38, 2),
+            rhs: DecimalType::arc(40, 2),
+            expect: "Decimal(41, 2)",
+        },
+        Test {
+            op: DataValueBinaryOperator::Mul,
+            lhs: DecimalType::arc(60, 10),
+            rhs: DecimalType::arc(30, 10),
+            expect: "Decimal(76, 6)",
+        },
+    ];
+
+    for test in tests {
+        let t = decimal_arithmetic_coercion(&test.op, &test.lhs, &test.rhs)?;
+        assert_eq!(format!("{:?}", t), test.expect, "case: {:?}", test.op);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_decimal_convert_arrow() {
+    let t = DecimalType::arc(10, 2);
+    let arrow_field = t.to_arrow_field("x");
+    let new_t = from_arrow_field(&arrow_field);
+
+    assert_eq!(format!("{:?}", new_t), "Decimal(10, 2)");
+    assert!(new_t.eq(&t));
+
+    let t = DecimalType::arc(60, 20);
+    let arrow_field = t.to_arrow_field("x");
+    let new_t = from_arrow_field(&arrow_field);
+
+    assert_eq!(format!("{:?}", new_t), "Decimal(60, 20)");
+    assert!(new_t.eq(&t));
+}
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_geometry_wkt() -> Result<()> {
+    let tests = vec![
+        ("POINT(1 2)", "POINT(1 2)"),
+        (" point ( -1.5  2 ) ", "POINT(-1.5 2)"),
+        ("LINESTRING(0 0, 1 1, 2 0)", "LINESTRING(0 0,1 1,2 0)"),
+        (
+            "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+        ),
+    ];
+
+    for (input, expect) in tests {
+        let geometry = Geometry::from_wkt(input)?;
+        assert_eq!(geometry.to_wkt(), expect, "{}", input);
+        assert_eq!(
+            Geometry::from_wkb(&geometry.to_wkb())?,
+            geometry,
+            "{}",
+            input
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_geometry_wkt_error() -> Result<()> {
+    let tests = vec![
+        ("POINT(1)", "Incorrect Geometry value '1': a coordinate must be 'x y'"),
+        ("POINT(1 a)", "Incorrect Geometry value '1 a': invalid number"),
+        (
+            "LINESTRING(0 0)",
+            "Incorrect Geometry value 'LINESTRING(0 0)': a linestring must have at least two coordinates",
+        ),
+        (
+            "POLYGON((0 0, 1 0, 1 1))",
+            "Incorrect Geometry value 'POLYGON((0 0, 1 0, 1 1))': a polygon ring must be closed and have at least four coordinates",
+        ),
+        (
+            "CIRCLE(0 0)",
+            "Incorrect Geometry value 'CIRCLE(0 0)': unsupported geometry type",
+        ),
+        ("POINT 1 2", "Incorrect Geometry value 'POINT 1 2': expected KEYWORD(...)"),
+    ];
+
+    for (input, expect) in tests {
+        let err = Geometry::from_wkt(input).unwrap_err();
+        assert_eq!(err.message(), expect, "{}", input);
+    }
+
+    assert!(Geometry::from_wkb(&[1, 1, 0, 0, 0]).is_err());
+    Ok(())
+}
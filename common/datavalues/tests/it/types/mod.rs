@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod collation;
 mod create_column;
+mod decimal;
+mod geometry;
 mod serializations;
 mod viewer;
@@ -59,6 +59,18 @@ fn test_serializers() -> Result<()> {
                 "1970-01-01 00:00:01".to_owned(),
             ],
         },
+        Test {
+            name: "datetime64",
+            data_type: DateTime64Type::arc(6, Some("Asia/Shanghai".to_string())),
+            value: DataValue::Int64(1630320462123456),
+            column: Series::from_data(vec![1630320462123456i64, 1637117572000000i64, 1]),
+            val_str: "2021-08-30 18:47:42.123456",
+            col_str: vec![
+                "2021-08-30 18:47:42.123456".to_owned(),
+                "2021-11-17 10:52:52.000000".to_owned(),
+                "1970-01-01 08:00:00.000001".to_owned(),
+            ],
+        },
         Test {
             name: "date32",
             data_type: Date32Type::arc(),
@@ -71,6 +83,14 @@ fn test_serializers() -> Result<()> {
                 "1970-01-02".to_owned(),
             ],
         },
+        Test {
+            name: "decimal",
+            data_type: DecimalType::arc(10, 2),
+            value: DataValue::Int128(12345),
+            column: Series::from_data(vec![12345i128, -5, 0]),
+            val_str: "123.45",
+            col_str: vec!["123.45".to_owned(), "-0.05".to_owned(), "0.00".to_owned()],
+        },
         Test {
             name: "string",
             data_type: StringType::arc(),
@@ -136,6 +136,15 @@ build_exceptions! {
     // Network error codes.
     NetworkRequestError(1073),
 
+    // File format error codes.
+    OrcError(1074),
+    AvroError(1075),
+
+    // Query limit error codes.
+    StatementTimeout(1076),
+    MemoryLimitExceeded(1077),
+    QueryQueueTimeout(1078),
+
     // Tenant error codes.
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
@@ -151,12 +160,27 @@ build_exceptions! {
     TableVersionMismatched(2009),
     OCCRetryFailure(2011),
 
+    // Row access policy error codes.
+    UnknownRowAccessPolicy(2101),
+    RowAccessPolicyAlreadyExists(2102),
+
+    // Masking policy error codes.
+    UnknownMaskingPolicy(2103),
+    MaskingPolicyAlreadyExists(2104),
+
+    // Network policy error codes.
+    UnknownNetworkPolicy(2105),
+    NetworkPolicyAlreadyExists(2106),
+
     // User api error codes.
     UnknownUser(2201),
     UserAlreadyExists(2202),
     IllegalUserInfoFormat(2203),
     UnknownRole(2204),
     IllegalUserSettingFormat(2205),
+    PasswordPolicyViolation(2206),
+    PasswordExpired(2207),
+    UserSeqMismatch(2208),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -185,6 +209,7 @@ build_exceptions! {
 
     // Variable error codes.
     UnknownVariable(2801),
+    SettingConstraintViolation(2802),
 
     // Warehouse error codes
     UnknownWarehouse(2901),
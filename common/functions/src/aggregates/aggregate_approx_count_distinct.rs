@@ -0,0 +1,217 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use twox_hash::XxHash64;
+
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// The number of bits of the hash that pick the register, there are 2^14 registers,
+/// the standard error of the estimate is 1.04 / sqrt(2^14), about 0.81%.
+const HLL_PRECISION: u32 = 14;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// A HyperLogLog sketch, every register keeps the max rank of the hashes routed to it,
+/// so the sketches of two partial states merge by taking the max of each register.
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // The guard bit bounds the rank when the remaining bits are all zero.
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Linear counting is more accurate for the small cardinalities.
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        serialize_into_buf(writer, &self.registers)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let registers: Vec<u8> = deserialize_from_slice(reader)?;
+        if registers.len() != HLL_REGISTERS {
+            return Err(ErrorCode::BadBytes(format!(
+                "Incorrect HyperLogLog state, expect {} registers, got {}",
+                HLL_REGISTERS,
+                registers.len()
+            )));
+        }
+        self.registers = registers;
+        Ok(())
+    }
+}
+
+/// `approx_count_distinct(x, ...)` estimates the number of distinct values with HyperLogLog.
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        let features = AggregateFunctionFeatures {
+            returns_default_when_only_null: true,
+            ..Default::default()
+        };
+        AggregateFunctionDescription::creator_with_features(Box::new(Self::try_create), features)
+    }
+
+    fn add(&self, state: &mut HyperLogLog, columns: &[ColumnRef], row: usize) -> Result<()> {
+        // The seed is fixed, so the partial states of all the nodes hash the same way.
+        let mut hasher = XxHash64::with_seed(0);
+        for column in columns {
+            DataGroupValue::try_from(&column.get(row))?.hash(&mut hasher);
+        }
+        state.add_hash(hasher.finish());
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(HyperLogLog::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<HyperLogLog>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        match validity {
+            Some(bitmap) => {
+                for (row, valid) in bitmap.iter().enumerate() {
+                    if valid {
+                        self.add(state, columns, row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    self.add(state, columns, row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        self.add(state, columns, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<HyperLogLog>();
+        let state = place.get::<HyperLogLog>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let column: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(column)?;
+        let state = place.get::<HyperLogLog>();
+        column.append_value(state.estimate());
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile::append_quantiles;
+use crate::aggregates::aggregate_quantile::get_quantile_levels;
+use crate::aggregates::aggregate_quantile::quantile_return_type;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Bigger compression keeps more centroids, which is more accurate and uses more memory.
+const TDIGEST_COMPRESSION: f64 = 100.0;
+/// The values are buffered and merged into the centroids in batches.
+const TDIGEST_BUFFER_SIZE: usize = 512;
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest, the centroids near the tails are kept small, so the extreme
+/// quantiles are estimated more accurately than the ones in the middle.
+#[derive(Serialize, Deserialize)]
+struct TDigest {
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    fn new() -> Self {
+        TDigest {
+            centroids: vec![],
+            buffer: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.buffer.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.compress();
+    }
+
+    /// The k1 scale function, two neighbouring centroids are merged only if the
+    /// merged one spans less than one unit of k.
+    fn scale(q: f64) -> f64 {
+        TDIGEST_COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.buffer);
+        centroids.extend_from_slice(&self.centroids);
+        centroids.sort_unstable_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(Ordering::Equal));
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(self.centroids.len() + 1);
+        let mut current = centroids[0];
+        let mut weight_so_far = 0.0;
+        let mut k_lower = Self::scale(0.0);
+
+        for c in centroids.into_iter().skip(1) {
+            let q = (weight_so_far + current.weight + c.weight) / total;
+            if Self::scale(q.min(1.0)) - k_lower <= 1.0 {
+                let weight = current.weight + c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                k_lower = Self::scale((weight_so_far / total).min(1.0));
+                merged.push(current);
+                current = c;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+
+    fn quantile(&self, level: f64) -> f64 {
+        if self.centroids.is_empty() {
+            return f64::NAN;
+        }
+        if self.centroids.len() == 1 {
+            return self.centroids[0].mean;
+        }
+
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = level * total;
+
+        // Every centroid is placed at the middle of the weights it covers, the quantile
+        // is interpolated between the two centroids around the target, or between the
+        // outermost centroid and the min or max value.
+        let first = &self.centroids[0];
+        if target < first.weight / 2.0 {
+            return self.min + (first.mean - self.min) * target / (first.weight / 2.0);
+        }
+
+        let mut weight_so_far = first.weight / 2.0;
+        for pair in self.centroids.windows(2) {
+            let step = (pair[0].weight + pair[1].weight) / 2.0;
+            if target < weight_so_far + step {
+                let ratio = (target - weight_so_far) / step;
+                return pair[0].mean + (pair[1].mean - pair[0].mean) * ratio;
+            }
+            weight_so_far += step;
+        }
+
+        let last = &self.centroids[self.centroids.len() - 1];
+        let ratio = ((target - weight_so_far) / (last.weight / 2.0)).min(1.0);
+        last.mean + (self.max - last.mean) * ratio
+    }
+}
+
+/// `approx_percentile(level)(x)` and `approx_percentiles(level, ...)(x)` estimate the
+/// quantiles with a t-digest, the memory it takes doesn't grow with the number of values.
+#[derive(Clone)]
+pub struct AggregateApproxPercentileFunction<T> {
+    display_name: String,
+    levels: Vec<f64>,
+    returns_array: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateApproxPercentileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateApproxPercentileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(quantile_return_type(self.returns_array))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(TDigest::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<TDigest>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<TDigest>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<TDigest>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<TDigest>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<TDigest>();
+        state.compress();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<TDigest>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<TDigest>();
+        let rhs = rhs.get::<TDigest>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<TDigest>();
+        state.compress();
+        let quantiles = self.levels.iter().map(|l| state.quantile(*l)).collect();
+        append_quantiles(column, quantiles, self.returns_array)
+    }
+}
+
+impl<T> fmt::Display for AggregateApproxPercentileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn try_create_aggregate_approx_percentile(
+    display_name: &str,
+    levels: Vec<f64>,
+    returns_array: bool,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        Ok(Arc::new(AggregateApproxPercentileFunction::<$T> {
+            display_name: display_name.to_string(),
+            levels,
+            returns_array,
+            t: PhantomData,
+        }))
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateApproxPercentileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_approx_percentile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if params.len() > 1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at most one parameter, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+    let levels = get_quantile_levels(display_name, &params, Some(0.5))?;
+    try_create_aggregate_approx_percentile(display_name, levels, false, arguments)
+}
+
+pub fn try_create_aggregate_approx_percentiles_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    let levels = get_quantile_levels(display_name, &params, None)?;
+    try_create_aggregate_approx_percentile(display_name, levels, true, arguments)
+}
+
+pub fn aggregate_approx_percentile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_percentile_function))
+}
+
+pub fn aggregate_approx_percentiles_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_approx_percentiles_function,
+    ))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::cmp;
 use std::fmt;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -33,10 +34,13 @@ use super::aggregate_sum::sum_primitive;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimal256Function;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::scalars::default_column_cast;
 
+const DECIMAL_AVG_MIN_SCALE: usize = 6;
+
 // count = 0 means it's all nullable
 // so we do not need option like sum
 #[derive(Serialize, Deserialize)]
@@ -66,6 +70,9 @@ where T: std::ops::AddAssign + PrimitiveType
 pub struct AggregateAvgFunction<T, SumT> {
     display_name: String,
     _arguments: Vec<DataField>,
+    // The types of the argument and the result of the average of decimals, the average of the
+    // other types is a Float64.
+    decimal: Option<(DecimalType, DecimalType)>,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -80,7 +87,10 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypePtr> {
-        Ok(f64::to_data_type())
+        match &self.decimal {
+            Some((_, result)) => Ok(Arc::new(result.clone())),
+            None => Ok(f64::to_data_type()),
+        }
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -172,6 +182,18 @@ where
     fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
         let state = place.get::<AggregateAvgState<SumT>>();
 
+        if let Some((argument, result)) = &self.decimal {
+            let builder: &mut MutablePrimitiveColumn<i128> =
+                Series::check_get_mutable_column(array)?;
+            let sum: i128 = NumCast::from(state.value).unwrap_or_default();
+            let val = match state.count {
+                0 => 0,
+                count => div_round(result.rescale(sum, argument.scale())?, count as i128),
+            };
+            builder.append_value(val);
+            return Ok(());
+        }
+
         let builder: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(array)?;
         let v: f64 = NumCast::from(state.value).unwrap_or_default();
         let val = v / state.count as f64;
@@ -194,10 +216,19 @@ where
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create_with_decimal(display_name, arguments, None)
+    }
+
+    pub fn try_create_with_decimal(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        decimal: Option<(DecimalType, DecimalType)>,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_string(),
             _arguments: arguments,
+            decimal,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -215,6 +246,37 @@ pub fn try_create_aggregate_avg_function(
     if data_type.data_type_id() == TypeID::Boolean {
         return AggregateAvgFunction::<u8, u64>::try_create(display_name, arguments);
     }
+
+    // The average of decimals keeps at least `DECIMAL_AVG_MIN_SCALE` digits after the point.
+    if data_type.data_type_id() == TypeID::Decimal256 {
+        let decimal: DecimalType = data_type
+            .as_any()
+            .downcast_ref::<DecimalType>()
+            .unwrap()
+            .clone();
+        let scale = cmp::max(decimal.scale(), DECIMAL_AVG_MIN_SCALE);
+        let result = DecimalType::try_create(DECIMAL_MAX_PRECISION, scale)?;
+        return AggregateDecimal256Function::try_create_avg(
+            display_name,
+            arguments,
+            decimal,
+            result,
+        );
+    }
+    if data_type.data_type_id() == TypeID::Decimal {
+        let decimal: DecimalType = data_type
+            .as_any()
+            .downcast_ref::<DecimalType>()
+            .unwrap()
+            .clone();
+        let scale = cmp::max(decimal.scale(), DECIMAL_AVG_MIN_SCALE);
+        let result = DecimalType::try_create(DECIMAL128_MAX_PRECISION, scale)?;
+        return AggregateAvgFunction::<i128, i128>::try_create_with_decimal(
+            display_name,
+            arguments,
+            Some((decimal, result)),
+        );
+    }
     with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
         AggregateAvgFunction::<$T, <$T as PrimitiveType>::LargestType>::try_create(
             display_name,
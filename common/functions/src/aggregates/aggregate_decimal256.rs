@@ -0,0 +1,181 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::StateAddr;
+
+struct AggregateDecimal256State {
+    pub value: I256,
+    pub count: u64,
+}
+
+impl AggregateDecimal256State {
+    #[inline(always)]
+    fn add(&mut self, value: I256, count: u64, result: &DecimalType) -> Result<()> {
+        self.value = self.value.checked_add(value).ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: sum is out of range of {:?}",
+                result
+            ))
+        })?;
+        self.count += count;
+        Ok(())
+    }
+}
+
+/// The sum and the average of the decimals wider than 38 digits, they are stored as bytes so the
+/// primitive sum and average can't be used.
+#[derive(Clone)]
+pub struct AggregateDecimal256Function {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    argument: DecimalType,
+    result: DecimalType,
+    avg: bool,
+}
+
+impl AggregateFunction for AggregateDecimal256Function {
+    fn name(&self) -> &str {
+        "AggregateDecimal256Function"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(Arc::new(self.result.clone()))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateDecimal256State {
+            value: I256::ZERO,
+            count: 0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimal256State>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let column: &StringColumn = Series::check_get(&columns[0])?;
+        for (row, v) in column.iter().enumerate() {
+            if validity.map(|b| b.get_bit(row)).unwrap_or(true) {
+                state.add(decimal256_from_bytes(v)?, 1, &self.result)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &StringColumn = unsafe { Series::static_cast(&columns[0]) };
+        let v = decimal256_from_bytes(unsafe { column.value_unchecked(row) })?;
+        let state = place.get::<AggregateDecimal256State>();
+        state.add(v, 1, &self.result)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        serialize_into_buf(writer, &(state.value.to_be_bytes(), state.count))
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let (value, count): ([u8; 32], u64) = deserialize_from_slice(reader)?;
+        state.value = I256::from_be_bytes(value);
+        state.count = count;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateDecimal256State>();
+        let state = place.get::<AggregateDecimal256State>();
+        state.add(rhs.value, rhs.count, &self.result)
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let val = match (self.avg, state.count) {
+            (false, _) => self.result.check_overflow(state.value)?,
+            (true, 0) => I256::ZERO,
+            (true, count) => {
+                let sum = self.result.rescale(state.value, self.argument.scale())?;
+                div_round(sum, I256::from(count))
+            }
+        };
+
+        let builder: &mut MutableStringColumn = Series::check_get_mutable_column(array)?;
+        builder.append_value(decimal256_to_bytes(val));
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateDecimal256Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateDecimal256Function {
+    pub fn try_create_sum(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        argument: DecimalType,
+    ) -> Result<AggregateFunctionRef> {
+        let result = DecimalType::try_create(DECIMAL_MAX_PRECISION, argument.scale())?;
+        Self::try_create(display_name, arguments, argument, result, false)
+    }
+
+    pub fn try_create_avg(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        argument: DecimalType,
+        result: DecimalType,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create(display_name, arguments, argument, result, true)
+    }
+
+    fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        argument: DecimalType,
+        result: DecimalType,
+        avg: bool,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            _arguments: arguments,
+            argument,
+            result,
+            avg,
+        }))
+    }
+}
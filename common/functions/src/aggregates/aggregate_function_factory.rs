@@ -18,12 +18,16 @@ use std::sync::Arc;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::RwLock;
 use once_cell::sync::Lazy;
 
 use super::AggregateFunctionBasicAdaptor;
 use super::AggregateFunctionCombinatorNull;
 use crate::aggregates::AggregateFunctionRef;
+use crate::aggregates::AggregateUDF;
+use crate::aggregates::AggregateUDFAdaptor;
 use crate::aggregates::Aggregators;
+use crate::is_builtin_function;
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<DataValue>, Vec<DataField>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -107,6 +111,8 @@ impl CombinatorDescription {
 pub struct AggregateFunctionFactory {
     case_insensitive_desc: HashMap<String, AggregateFunctionDescription>,
     case_insensitive_combinator_desc: Vec<(String, CombinatorDescription)>,
+    // The user defined aggregate functions are registered after the factory is created.
+    case_insensitive_udaf_desc: RwLock<HashMap<String, AggregateFunctionDescription>>,
 }
 
 impl AggregateFunctionFactory {
@@ -114,6 +120,7 @@ impl AggregateFunctionFactory {
         AggregateFunctionFactory {
             case_insensitive_desc: Default::default(),
             case_insensitive_combinator_desc: Default::default(),
+            case_insensitive_udaf_desc: Default::default(),
         }
     }
 
@@ -140,6 +147,32 @@ impl AggregateFunctionFactory {
         case_insensitive_combinator_desc.push((suffix.to_lowercase(), desc));
     }
 
+    /// Registers a user defined aggregate function, it can be used like the builtin ones after
+    /// that, including the combinators, e.g. `my_udaf_distinct(x)` and `my_udaf_if(x, cond)`.
+    pub fn register_udaf<T: AggregateUDF>(
+        &self,
+        name: &str,
+        udaf: T,
+        features: AggregateFunctionFeatures,
+    ) -> Result<()> {
+        if is_builtin_function(name) {
+            return Err(ErrorCode::UdfAlreadyExists(format!(
+                "Function {} already exists",
+                name
+            )));
+        }
+
+        let udaf = Arc::new(udaf);
+        let creator: AggregateFunctionCreator = Box::new(move |display_name, params, arguments| {
+            AggregateUDFAdaptor::try_create(display_name, udaf.clone(), params, arguments)
+        });
+        self.case_insensitive_udaf_desc.write().insert(
+            name.to_lowercase(),
+            AggregateFunctionDescription::creator_with_features(creator, features),
+        );
+        Ok(())
+    }
+
     pub fn get(
         &self,
         name: impl AsRef<str>,
@@ -176,8 +209,14 @@ impl AggregateFunctionFactory {
         features: &mut AggregateFunctionFeatures,
     ) -> Result<AggregateFunctionRef> {
         let lowercase_name = name.to_lowercase();
-        let aggregate_functions_map = &self.case_insensitive_desc;
-        if let Some(desc) = aggregate_functions_map.get(&lowercase_name) {
+        let udaf_functions_map = self.case_insensitive_udaf_desc.read();
+        let get_desc = |name: &str| {
+            self.case_insensitive_desc
+                .get(name)
+                .or_else(|| udaf_functions_map.get(name))
+        };
+
+        if let Some(desc) = get_desc(&lowercase_name) {
             *features = desc.features.clone();
             return (desc.aggregate_function_creator)(name, params, arguments);
         }
@@ -185,9 +224,7 @@ impl AggregateFunctionFactory {
         // find suffix
         for (suffix, desc) in &self.case_insensitive_combinator_desc {
             if let Some(nested_name) = lowercase_name.strip_suffix(suffix) {
-                let aggregate_functions_map = &self.case_insensitive_desc;
-
-                match aggregate_functions_map.get(nested_name) {
+                match get_desc(nested_name) {
                     None => {
                         break;
                     }
@@ -213,15 +250,19 @@ impl AggregateFunctionFactory {
     pub fn check(&self, name: impl AsRef<str>) -> bool {
         let origin = name.as_ref();
         let lowercase_name = origin.to_lowercase();
+        let udaf_functions_map = self.case_insensitive_udaf_desc.read();
+        let contains = |name: &str| {
+            self.case_insensitive_desc.contains_key(name) || udaf_functions_map.contains_key(name)
+        };
 
-        if self.case_insensitive_desc.contains_key(&lowercase_name) {
+        if contains(&lowercase_name) {
             return true;
         }
 
         // find suffix
         for (suffix, _) in &self.case_insensitive_combinator_desc {
             if let Some(nested_name) = lowercase_name.strip_suffix(suffix) {
-                if self.case_insensitive_desc.contains_key(nested_name) {
+                if contains(nested_name) {
                     return true;
                 }
             }
@@ -231,13 +272,17 @@ impl AggregateFunctionFactory {
     }
 
     pub fn registered_names(&self) -> Vec<String> {
-        self.case_insensitive_desc.keys().cloned().collect()
+        self.case_insensitive_desc
+            .keys()
+            .chain(self.case_insensitive_udaf_desc.read().keys())
+            .cloned()
+            .collect()
     }
 
     pub fn registered_features(&self) -> Vec<AggregateFunctionFeatures> {
         self.case_insensitive_desc
             .values()
-            .into_iter()
+            .chain(self.case_insensitive_udaf_desc.read().values())
             .map(|v| &v.features)
             .cloned()
             .collect::<Vec<_>>()
@@ -0,0 +1,207 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// The union of the bitmaps for each filter value.
+struct AggregateIntersectCountState {
+    bitmaps: Vec<RoaringTreemap>,
+}
+
+impl AggregateIntersectCountState {
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        let bitmaps: Vec<Vec<u8>> = self.bitmaps.iter().map(bitmap_serialize).collect();
+        serialize_into_buf(writer, &bitmaps)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let bitmaps: Vec<Vec<u8>> = deserialize_from_slice(reader)?;
+        self.bitmaps = bitmaps
+            .iter()
+            .map(|v| bitmap_deserialize(v))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+}
+
+/// `intersect_count(v1, v2, ...)(bitmap, column)` unions the bitmaps of the rows
+/// for each filter value `vN` of the column, and returns the number of values
+/// in the intersection of these unions.
+#[derive(Clone)]
+pub struct AggregateIntersectCountFunction {
+    display_name: String,
+    filters: Vec<DataValue>,
+}
+
+impl AggregateIntersectCountFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_binary_arguments(display_name, arguments.len())?;
+        if params.is_empty() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at least one parameter",
+                display_name
+            )));
+        }
+        if arguments[0].data_type().data_type_id() != TypeID::Bitmap {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Illegal type of the first argument in {}, must be bitmap, got: {:?}",
+                display_name,
+                arguments[0].data_type()
+            )));
+        }
+
+        // Convert the filter values to the type of the column, so they can be compared as values.
+        let filter_type = remove_nullable(arguments[1].data_type());
+        let filters = filter_type.create_column(&params)?;
+        let filters = (0..filters.len()).map(|i| filters.get(i)).collect();
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            filters,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        let features = AggregateFunctionFeatures {
+            returns_default_when_only_null: true,
+            ..Default::default()
+        };
+        AggregateFunctionDescription::creator_with_features(Box::new(Self::try_create), features)
+    }
+
+    fn add(
+        &self,
+        state: &mut AggregateIntersectCountState,
+        columns: &[ColumnRef],
+        row: usize,
+    ) -> Result<()> {
+        let value = columns[1].get(row);
+        for (i, filter) in self.filters.iter().enumerate() {
+            if *filter == value {
+                let bitmaps: &StringColumn = Series::check_get(&columns[0])?;
+                state.bitmaps[i] |= bitmap_deserialize(bitmaps.get_data(row))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateIntersectCountFunction {
+    fn name(&self) -> &str {
+        "AggregateIntersectCountFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateIntersectCountState {
+            bitmaps: vec![RoaringTreemap::new(); self.filters.len()],
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateIntersectCountState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateIntersectCountState>();
+        match validity {
+            Some(bitmap) => {
+                for (row, valid) in bitmap.iter().enumerate() {
+                    if valid {
+                        self.add(state, columns, row)?;
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    self.add(state, columns, row)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateIntersectCountState>();
+        self.add(state, columns, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateIntersectCountState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateIntersectCountState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateIntersectCountState>();
+        let state = place.get::<AggregateIntersectCountState>();
+        for (bitmap, other) in state.bitmaps.iter_mut().zip(rhs.bitmaps.iter()) {
+            *bitmap |= other;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let column: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(column)?;
+        let state = place.get::<AggregateIntersectCountState>();
+
+        let mut bitmaps = state.bitmaps.iter();
+        let mut result = bitmaps.next().cloned().unwrap_or_default();
+        for bitmap in bitmaps {
+            result &= bitmap;
+        }
+        column.append_value(result.len());
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateIntersectCountFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
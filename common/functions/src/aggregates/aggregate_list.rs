@@ -0,0 +1,252 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// A collected value with the keys to order it by.
+#[derive(Serialize, Deserialize, Clone)]
+struct ListRow {
+    value: DataValue,
+    keys: Vec<DataValue>,
+}
+
+/// The collected rows are plain data values, so the state can be serialized to be
+/// merged on another node or spilled, and read back without the column types.
+#[derive(Serialize, Deserialize)]
+struct AggregateListState {
+    rows: Vec<ListRow>,
+}
+
+impl AggregateListState {
+    fn add(&mut self, columns: &[ColumnRef], row: usize) {
+        self.rows.push(ListRow {
+            value: columns[0].get(row),
+            keys: columns[1..].iter().map(|c| c.get(row)).collect(),
+        });
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.rows.extend_from_slice(&other.rows);
+    }
+
+    /// The collected values, ordered by the keys if there are any.
+    fn values(&mut self) -> Vec<DataValue> {
+        self.rows
+            .sort_by(|a, b| a.keys.partial_cmp(&b.keys).unwrap_or(Ordering::Equal));
+        self.rows.iter().map(|row| row.value.clone()).collect()
+    }
+}
+
+/// `array_agg(expr [, key ...])` collects the values into an array, and
+/// `group_concat(expr [, separator] [, key ...])` joins them into a string.
+///
+/// The extra arguments are the keys to order the values by, NULL values are skipped.
+#[derive(Clone)]
+pub struct AggregateListFunction {
+    display_name: String,
+    value_type: DataTypePtr,
+    // The separator of group_concat, array_agg has none.
+    separator: Option<Vec<u8>>,
+}
+
+impl AggregateListFunction {
+    fn try_create(
+        display_name: &str,
+        separator: Option<Vec<u8>>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+
+        // Variant values are not able to be deserialized from the state.
+        for argument in arguments.iter() {
+            let data_type = remove_nullable(argument.data_type());
+            if matches!(
+                data_type.data_type_id(),
+                TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject
+            ) {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "{} does not support type '{:?}'",
+                    display_name, data_type
+                )));
+            }
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            value_type: remove_nullable(arguments[0].data_type()),
+            separator,
+        }))
+    }
+
+    pub fn try_create_array_agg(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        if !params.is_empty() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have no parameters, but got {}",
+                display_name,
+                params.len()
+            )));
+        }
+        Self::try_create(display_name, None, arguments)
+    }
+
+    pub fn try_create_group_concat(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        let separator = match params.as_slice() {
+            [] => b",".to_vec(),
+            [separator] => separator.as_string()?,
+            _ => {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have at most one parameter, but got {}",
+                    display_name,
+                    params.len()
+                )))
+            }
+        };
+        Self::try_create(display_name, Some(separator), arguments)
+    }
+
+    pub fn array_agg_desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create_array_agg))
+    }
+
+    pub fn group_concat_desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create_group_concat))
+    }
+}
+
+impl AggregateFunction for AggregateListFunction {
+    fn name(&self) -> &str {
+        "AggregateListFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        match self.separator {
+            Some(_) => Ok(StringType::arc()),
+            None => Ok(ArrayType::arc(self.value_type.clone())),
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateListState { rows: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateListState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateListState>();
+        match validity {
+            Some(bitmap) => {
+                for (row, valid) in bitmap.iter().enumerate() {
+                    if valid {
+                        state.add(columns, row);
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    state.add(columns, row);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateListState>();
+        state.add(columns, row);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateListState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateListState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateListState>();
+        let state = place.get::<AggregateListState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateListState>();
+        let values = state.values();
+
+        match &self.separator {
+            None => column.append_data_value(DataValue::Array(values)),
+            Some(separator) => {
+                let serializer = self.value_type.create_serializer();
+                let mut result = Vec::new();
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        result.extend_from_slice(separator);
+                    }
+                    match value {
+                        DataValue::String(v) => result.extend_from_slice(v),
+                        v => result.extend_from_slice(serializer.serialize_value(v)?.as_bytes()),
+                    }
+                }
+                let column: &mut MutableStringColumn = Series::check_get_mutable_column(column)?;
+                column.append_value(result);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for AggregateListFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
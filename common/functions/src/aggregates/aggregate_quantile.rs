@@ -0,0 +1,321 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Parses the levels of the quantiles from the parameters, every level must be in [0, 1].
+pub(crate) fn get_quantile_levels(
+    display_name: &str,
+    params: &[DataValue],
+    default: Option<f64>,
+) -> Result<Vec<f64>> {
+    if params.is_empty() {
+        return match default {
+            Some(level) => Ok(vec![level]),
+            None => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at least one parameter",
+                display_name
+            ))),
+        };
+    }
+
+    params
+        .iter()
+        .map(|param| {
+            let level = param.as_f64()?;
+            if !(0.0..=1.0).contains(&level) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} level must be in [0, 1], but got {}",
+                    display_name, level
+                )));
+            }
+            Ok(level)
+        })
+        .collect()
+}
+
+/// The return type of the quantile functions, a single level gives a Float64 and
+/// the plural variants give an Array(Float64).
+pub(crate) fn quantile_return_type(returns_array: bool) -> DataTypePtr {
+    match returns_array {
+        true => ArrayType::arc(f64::to_data_type()),
+        false => f64::to_data_type(),
+    }
+}
+
+pub(crate) fn append_quantiles(
+    column: &mut dyn MutableColumn,
+    quantiles: Vec<f64>,
+    returns_array: bool,
+) -> Result<()> {
+    match returns_array {
+        true => column.append_data_value(DataValue::Array(
+            quantiles.into_iter().map(DataValue::Float64).collect(),
+        )),
+        false => {
+            let column: &mut MutablePrimitiveColumn<f64> =
+                Series::check_get_mutable_column(column)?;
+            column.append_value(quantiles[0]);
+            Ok(())
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct AggregateQuantileState {
+    values: Vec<f64>,
+}
+
+impl AggregateQuantileState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.values.push(value);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.values.extend_from_slice(&other.values);
+    }
+
+    /// The exact quantile, interpolated linearly between the two closest ranks.
+    fn quantiles(&mut self, levels: &[f64]) -> Vec<f64> {
+        if self.values.is_empty() {
+            return vec![f64::NAN; levels.len()];
+        }
+
+        self.values
+            .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        levels
+            .iter()
+            .map(|level| {
+                let pos = level * (self.values.len() - 1) as f64;
+                let lower = pos.floor() as usize;
+                let upper = pos.ceil() as usize;
+                let lhs = self.values[lower];
+                lhs + (self.values[upper] - lhs) * (pos - lower as f64)
+            })
+            .collect()
+    }
+}
+
+/// `quantile(level)(x)`, `median(x)` and `quantiles(level, ...)(x)` compute the exact
+/// quantiles, all the values are kept in the state.
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    levels: Vec<f64>,
+    returns_array: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(quantile_return_type(self.returns_array))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateQuantileState { values: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateQuantileState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateQuantileState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let rhs = rhs.get::<AggregateQuantileState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let quantiles = state.quantiles(&self.levels);
+        append_quantiles(column, quantiles, self.returns_array)
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn try_create_aggregate_quantile(
+    display_name: &str,
+    levels: Vec<f64>,
+    returns_array: bool,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        Ok(Arc::new(AggregateQuantileFunction::<$T> {
+            display_name: display_name.to_string(),
+            levels,
+            returns_array,
+            t: PhantomData,
+        }))
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if params.len() > 1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at most one parameter, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+    let levels = get_quantile_levels(display_name, &params, Some(0.5))?;
+    try_create_aggregate_quantile(display_name, levels, false, arguments)
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if !params.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have no parameters, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+    try_create_aggregate_quantile(display_name, vec![0.5], false, arguments)
+}
+
+pub fn try_create_aggregate_quantiles_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    let levels = get_quantile_levels(display_name, &params, None)?;
+    try_create_aggregate_quantile(display_name, levels, true, arguments)
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_function))
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_median_function))
+}
+
+pub fn aggregate_quantiles_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantiles_function))
+}
@@ -33,6 +33,7 @@ use super::aggregate_function::AggregateFunctionRef;
 use super::aggregate_function_factory::AggregateFunctionDescription;
 use super::StateAddr;
 use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateDecimal256Function;
 use crate::scalars::default_column_cast;
 
 struct AggregateSumState<T> {
@@ -61,6 +62,7 @@ where T: std::ops::AddAssign + Serialize + DeserializeOwned + Copy + Clone
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
     _arguments: Vec<DataField>,
+    return_type: DataTypePtr,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -75,7 +77,7 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypePtr> {
-        Ok(SumT::to_data_type())
+        Ok(self.return_type.clone())
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -194,10 +196,19 @@ where
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create_with_type(display_name, arguments, SumT::to_data_type())
+    }
+
+    pub fn try_create_with_type(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        return_type: DataTypePtr,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
             _arguments: arguments,
+            return_type,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -215,6 +226,25 @@ pub fn try_create_aggregate_sum_function(
     if data_type.data_type_id() == TypeID::Boolean {
         return AggregateSumFunction::<u8, u64>::try_create(display_name, arguments);
     }
+
+    // The sum of decimals keeps the scale and widens to the max precision of the stored integer.
+    if data_type.data_type_id() == TypeID::Decimal256 {
+        let decimal: DecimalType = data_type
+            .as_any()
+            .downcast_ref::<DecimalType>()
+            .unwrap()
+            .clone();
+        return AggregateDecimal256Function::try_create_sum(display_name, arguments, decimal);
+    }
+    if data_type.data_type_id() == TypeID::Decimal {
+        let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+        let return_type = DecimalType::arc(DECIMAL128_MAX_PRECISION, decimal.scale());
+        return AggregateSumFunction::<i128, i128>::try_create_with_type(
+            display_name,
+            arguments,
+            return_type,
+        );
+    }
     with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
         AggregateSumFunction::<$T, <$T as PrimitiveType>::LargestType>::try_create(
              display_name,
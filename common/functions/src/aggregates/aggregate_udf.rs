@@ -0,0 +1,144 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The plugin API of user defined aggregate functions, they're registered with
+/// `AggregateFunctionFactory::register_udaf` when the server starts.
+///
+/// The state lives in the arena of the aggregator like the states of the builtin functions.
+/// It's serialized to be sent from the partial aggregation to the final aggregation, which may
+/// run on another node, so the function must be registered on all the nodes of the cluster.
+/// NULLs are skipped before the rows are accumulated, as the builtin functions do.
+pub trait AggregateUDF: Send + Sync + 'static {
+    type State: Serialize + DeserializeOwned + Default + Send + Sync + 'static;
+
+    /// Checks the arguments and returns the type of the result.
+    fn return_type(&self, params: &[DataValue], arguments: &[DataField]) -> Result<DataTypePtr>;
+
+    fn accumulate_row(
+        &self,
+        state: &mut Self::State,
+        columns: &[ColumnRef],
+        row: usize,
+    ) -> Result<()>;
+
+    fn merge(&self, state: &mut Self::State, rhs: &Self::State) -> Result<()>;
+
+    /// Returns the result of the state, it must be of the return type.
+    fn finish(&self, state: &Self::State) -> Result<DataValue>;
+}
+
+/// Adapts an `AggregateUDF` to an `AggregateFunction`.
+pub struct AggregateUDFAdaptor<T: AggregateUDF> {
+    display_name: String,
+    udaf: Arc<T>,
+    return_type: DataTypePtr,
+}
+
+impl<T: AggregateUDF> AggregateUDFAdaptor<T> {
+    pub fn try_create(
+        display_name: &str,
+        udaf: Arc<T>,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        let return_type = udaf.return_type(&params, &arguments)?;
+        Ok(Arc::new(AggregateUDFAdaptor {
+            display_name: display_name.to_string(),
+            udaf,
+            return_type,
+        }))
+    }
+}
+
+impl<T: AggregateUDF> AggregateFunction for AggregateUDFAdaptor<T> {
+    fn name(&self) -> &str {
+        "AggregateUDFAdaptor"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(T::State::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<T::State>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<T::State>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                self.udaf.accumulate_row(state, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<T::State>();
+        self.udaf.accumulate_row(state, columns, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<T::State>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<T::State>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<T::State>();
+        let rhs = rhs.get::<T::State>();
+        self.udaf.merge(state, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<T::State>();
+        array.append_data_value(self.udaf.finish(state)?)
+    }
+}
+
+impl<T: AggregateUDF> fmt::Display for AggregateUDFAdaptor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
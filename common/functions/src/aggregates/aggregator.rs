@@ -12,14 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
+use super::aggregate_approx_percentile::aggregate_approx_percentile_function_desc;
+use super::aggregate_approx_percentile::aggregate_approx_percentiles_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
 use super::aggregate_combinator_distinct::AggregateDistinctCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
+use super::aggregate_intersect_count::AggregateIntersectCountFunction;
+use super::aggregate_list::AggregateListFunction;
 use super::aggregate_min_max::aggregate_max_function_desc;
 use super::aggregate_min_max::aggregate_min_function_desc;
+use super::aggregate_quantile::aggregate_median_function_desc;
+use super::aggregate_quantile::aggregate_quantile_function_desc;
+use super::aggregate_quantile::aggregate_quantiles_function_desc;
 use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
@@ -48,8 +56,28 @@ impl Aggregators {
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
 
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("quantiles", aggregate_quantiles_function_desc());
+        factory.register("median", aggregate_median_function_desc());
+        factory.register(
+            "approx_percentile",
+            aggregate_approx_percentile_function_desc(),
+        );
+        factory.register(
+            "approx_percentiles",
+            aggregate_approx_percentiles_function_desc(),
+        );
+
+        factory.register("array_agg", AggregateListFunction::array_agg_desc());
+        factory.register("group_concat", AggregateListFunction::group_concat_desc());
+
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register(
+            "approx_count_distinct",
+            AggregateApproxCountDistinctFunction::desc(),
+        );
+        factory.register("intersect_count", AggregateIntersectCountFunction::desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -26,35 +26,51 @@ mod adaptors;
 
 #[macro_use]
 mod macros;
+mod aggregate_approx_count_distinct;
+mod aggregate_approx_percentile;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
 mod aggregate_combinator;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
+mod aggregate_decimal256;
+mod aggregate_intersect_count;
+mod aggregate_list;
 mod aggregate_min_max;
 mod aggregate_null_result;
+mod aggregate_quantile;
 mod aggregate_scalar_state;
 mod aggregate_stddev_pop;
+mod aggregate_udf;
 mod aggregate_window_funnel;
 
 pub use adaptors::*;
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
+pub use aggregate_approx_percentile::AggregateApproxPercentileFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_decimal256::AggregateDecimal256Function;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
+pub use aggregate_function_factory::AggregateFunctionFeatures;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_intersect_count::AggregateIntersectCountFunction;
+pub use aggregate_list::AggregateListFunction;
 pub use aggregate_min_max::AggregateMinMaxFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_udf::AggregateUDF;
+pub use aggregate_udf::AggregateUDFAdaptor;
 pub use aggregate_window_funnel::AggregateWindowFunnelFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -23,6 +23,26 @@ pub mod scalars;
 use aggregates::AggregateFunctionFactory;
 use scalars::FunctionFactory;
 
+/// The functions only evaluated over a window, e.g. `lag(a) OVER (ORDER BY b)`, besides the
+/// aggregate functions which can also be evaluated over a window.
+pub const WINDOW_FUNCTIONS: [&str; 7] = [
+    "row_number",
+    "rank",
+    "dense_rank",
+    "lag",
+    "lead",
+    "first_value",
+    "last_value",
+];
+
+pub fn is_window_function(name: &str) -> bool {
+    WINDOW_FUNCTIONS
+        .iter()
+        .any(|function| function.eq_ignore_ascii_case(name))
+}
+
 pub fn is_builtin_function(name: &str) -> bool {
-    FunctionFactory::instance().check(name) || AggregateFunctionFactory::instance().check(name)
+    FunctionFactory::instance().check(name)
+        || AggregateFunctionFactory::instance().check(name)
+        || is_window_function(name)
 }
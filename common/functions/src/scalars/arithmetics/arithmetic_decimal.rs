@@ -0,0 +1,260 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::decimal_arithmetic_coercion;
+use common_datavalues::type_coercion::decimal_precision_scale;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::ArithmeticDivFunction;
+use crate::scalars::ArithmeticMinusFunction;
+use crate::scalars::ArithmeticMulFunction;
+use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::Monotonicity;
+
+/// Arithmetic on decimals, the other argument can be a decimal, an integer or a float.
+/// Both arguments are casted to decimals with the proper scale, so the operation can be
+/// done on the stored integers, i128 or I256 if either side or the result is wider than 38
+/// digits, overflow is reported as an error. The operations with a float are done on Float64.
+#[derive(Clone)]
+pub struct DecimalArithmeticFunction {
+    op: DataValueBinaryOperator,
+    lhs_type: DataTypePtr,
+    rhs_type: DataTypePtr,
+    result_type: DataTypePtr,
+}
+
+impl DecimalArithmeticFunction {
+    pub fn try_create_func(
+        op: DataValueBinaryOperator,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function>> {
+        let result_type = decimal_arithmetic_coercion(&op, args[0], args[1])?;
+
+        if args[0].data_type_id().is_floating() || args[1].data_type_id().is_floating() {
+            // Float arithmetic, IntDiv is done on the rounded float result.
+            return Ok(Box::new(Self {
+                op,
+                lhs_type: Float64Type::arc(),
+                rhs_type: Float64Type::arc(),
+                result_type,
+            }));
+        }
+
+        let (lhs_precision, lhs_scale) = decimal_precision_scale(args[0])?;
+        let (rhs_precision, rhs_scale) = decimal_precision_scale(args[1])?;
+        let wide = cmp::max(lhs_precision, rhs_precision) > DECIMAL128_MAX_PRECISION
+            || result_type.data_type_id() == TypeID::Decimal256;
+        let precision = match wide {
+            true => DECIMAL_MAX_PRECISION,
+            false => DECIMAL128_MAX_PRECISION,
+        };
+        let (lhs_type, rhs_type) = match op {
+            // The scale of the result is computed from the scales of both sides.
+            DataValueBinaryOperator::Mul | DataValueBinaryOperator::Div => (
+                DecimalType::arc(precision, lhs_scale),
+                DecimalType::arc(precision, rhs_scale),
+            ),
+            _ => {
+                let scale = cmp::max(lhs_scale, rhs_scale);
+                (
+                    DecimalType::arc(precision, scale),
+                    DecimalType::arc(precision, scale),
+                )
+            }
+        };
+
+        Ok(Box::new(Self {
+            op,
+            lhs_type,
+            rhs_type,
+            result_type,
+        }))
+    }
+
+    fn eval_float(&self, lhs: &ColumnRef, rhs: &ColumnRef) -> Result<ColumnRef> {
+        let ctx = &mut EvalContext::default();
+        let col = match self.op {
+            DataValueBinaryOperator::Plus => {
+                scalar_binary_op(lhs, rhs, |l: f64, r: f64, _| l + r, ctx)
+            }
+            DataValueBinaryOperator::Minus => {
+                scalar_binary_op(lhs, rhs, |l: f64, r: f64, _| l - r, ctx)
+            }
+            DataValueBinaryOperator::Mul => {
+                scalar_binary_op(lhs, rhs, |l: f64, r: f64, _| l * r, ctx)
+            }
+            DataValueBinaryOperator::Div => {
+                scalar_binary_op(lhs, rhs, |l: f64, r: f64, _| l / r, ctx)
+            }
+            DataValueBinaryOperator::Modulo => {
+                scalar_binary_op(lhs, rhs, |l: f64, r: f64, _| l % r, ctx)
+            }
+            DataValueBinaryOperator::IntDiv => {
+                let col = scalar_binary_op(
+                    lhs,
+                    rhs,
+                    |l: f64, r: f64, ctx: &mut EvalContext| {
+                        if r == 0.0 {
+                            ctx.set_error(ErrorCode::BadArguments("Division by zero"));
+                            return 0;
+                        }
+                        (l / r).trunc() as i64
+                    },
+                    ctx,
+                )?;
+                return Ok(Arc::new(col));
+            }
+        }?;
+        Ok(Arc::new(col))
+    }
+
+    // Both sides have the same scale, the quotient is truncated.
+    fn eval_int_div<T: DecimalNative>(
+        &self,
+        lhs: &ColumnRef,
+        rhs: &ColumnRef,
+    ) -> Result<ColumnRef> {
+        let lhs = T::column_values(lhs)?;
+        let rhs = T::column_values(rhs)?;
+        let size = lhs.len();
+
+        let mut builder = ColumnBuilder::<i64>::with_capacity(size);
+        for (l, r) in lhs.into_iter().zip(rhs.into_iter()) {
+            if r == T::ZERO {
+                return Err(ErrorCode::BadArguments("Division by zero"));
+            }
+            let v = i128::try_from_i256((l / r).to_i256()).and_then(|v| i64::try_from(v).ok());
+            match v {
+                Some(v) => builder.append(v),
+                None => {
+                    return Err(ErrorCode::Overflow(format!(
+                        "Overflow: result of ({}) is out of range of Int64",
+                        self.op
+                    )));
+                }
+            }
+        }
+        Ok(builder.build(size))
+    }
+
+    fn eval_decimal<T: DecimalNative>(
+        &self,
+        lhs: &ColumnRef,
+        rhs: &ColumnRef,
+    ) -> Result<ColumnRef> {
+        let decimal: &DecimalType = self.result_type.as_any().downcast_ref().unwrap();
+        let (_, lhs_scale) = decimal_precision_scale(&self.lhs_type)?;
+        let (_, rhs_scale) = decimal_precision_scale(&self.rhs_type)?;
+        let overflow = || {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: result of ({}) is out of range of {:?}",
+                self.op, self.result_type
+            ))
+        };
+
+        // The quotient is `l * 10^(scale + rhs_scale - lhs_scale) / r`, the factor is moved to
+        // the divisor if the exponent is negative.
+        let div_exponent = decimal.scale() as i32 + rhs_scale as i32 - lhs_scale as i32;
+        let div_factor = T::TEN.checked_pow(div_exponent.unsigned_abs());
+
+        let f = |l: T, r: T| -> Result<T> {
+            let res = match self.op {
+                DataValueBinaryOperator::Plus => l.checked_add(r).ok_or_else(overflow),
+                DataValueBinaryOperator::Minus => l.checked_sub(r).ok_or_else(overflow),
+                DataValueBinaryOperator::Mul => l
+                    .checked_mul(r)
+                    .ok_or_else(overflow)
+                    .and_then(|v| decimal.rescale(v, lhs_scale + rhs_scale)),
+                DataValueBinaryOperator::Div if r == T::ZERO => {
+                    Err(ErrorCode::BadArguments("Division by zero"))
+                }
+                DataValueBinaryOperator::Div => {
+                    let quotient = match div_exponent >= 0 {
+                        true => div_factor.and_then(|f| l.checked_mul(f)).map(|l| (l, r)),
+                        false => div_factor.and_then(|f| r.checked_mul(f)).map(|r| (l, r)),
+                    };
+                    quotient.map(|(l, r)| div_round(l, r)).ok_or_else(overflow)
+                }
+                DataValueBinaryOperator::Modulo if r == T::ZERO => {
+                    Err(ErrorCode::BadArguments("Division by zero"))
+                }
+                DataValueBinaryOperator::Modulo => Ok(l % r),
+                DataValueBinaryOperator::IntDiv => unreachable!(),
+            };
+
+            res.and_then(|v| decimal.check_overflow(v).map_err(|_| overflow()))
+        };
+
+        let lhs = T::column_values(lhs)?;
+        let rhs = T::column_values(rhs)?;
+        let values = lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(l, r)| f(l, r))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(T::new_column(values))
+    }
+}
+
+impl Function for DecimalArithmeticFunction {
+    fn name(&self) -> &str {
+        "DecimalArithmeticFunction"
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.result_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let lhs = cast_column_field(&columns[0], &self.lhs_type)?;
+        let rhs = cast_column_field(&columns[1], &self.rhs_type)?;
+
+        if self.lhs_type.data_type_id().is_floating() {
+            return self.eval_float(&lhs, &rhs);
+        }
+        let wide = self.lhs_type.data_type_id() == TypeID::Decimal256;
+        match (matches!(self.op, DataValueBinaryOperator::IntDiv), wide) {
+            (true, true) => self.eval_int_div::<I256>(&lhs, &rhs),
+            (true, false) => self.eval_int_div::<i128>(&lhs, &rhs),
+            (false, true) => self.eval_decimal::<I256>(&lhs, &rhs),
+            (false, false) => self.eval_decimal::<i128>(&lhs, &rhs),
+        }
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        match self.op {
+            DataValueBinaryOperator::Plus => ArithmeticPlusFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Minus => ArithmeticMinusFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Mul => ArithmeticMulFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Div => ArithmeticDivFunction::get_monotonicity(args),
+            _ => Ok(Monotonicity::default()),
+        }
+    }
+}
+
+impl fmt::Display for DecimalArithmeticFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op)
+    }
+}
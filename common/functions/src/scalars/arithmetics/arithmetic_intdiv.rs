@@ -20,6 +20,7 @@ use num::Zero;
 use num_traits::AsPrimitive;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionFeatures;
@@ -47,6 +48,10 @@ impl ArithmeticIntDivFunction {
         _display_name: &str,
         args: &[&DataTypePtr],
     ) -> Result<Box<dyn Function>> {
+        if args[0].data_type_id().is_decimal() || args[1].data_type_id().is_decimal() {
+            return DecimalArithmeticFunction::try_create_func(DataValueBinaryOperator::IntDiv, args);
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             with_match_primitive_types_error!(args[1].data_type_id(), |$D| {
                 BinaryArithmeticFunction::<$T, $D, <($T, $D) as ResultTypeOfBinary>::IntDiv, _>::try_create_func(
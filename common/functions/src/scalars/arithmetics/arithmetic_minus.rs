@@ -24,6 +24,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingSub;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionFactory;
@@ -60,6 +61,10 @@ impl ArithmeticMinusFunction {
         let left_type = args[0].data_type_id();
         let right_type = args[1].data_type_id();
 
+        if left_type.is_decimal() || right_type.is_decimal() {
+            return DecimalArithmeticFunction::try_create_func(op, args);
+        }
+
         if left_type.is_date_or_date_time() {
             return with_match_date_type_error!(left_type, |$T| {
                 with_match_primitive_type_id!(right_type, |$D| {
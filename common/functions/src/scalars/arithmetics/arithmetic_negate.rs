@@ -47,6 +47,29 @@ impl ArithmeticNegateFunction {
     ) -> Result<Box<dyn Function>> {
         let op = DataValueUnaryOperator::Negate;
 
+        if args[0].data_type_id() == TypeID::Decimal256 {
+            // The wide decimals are stored as bytes, the negated value stays in the precision.
+            return UnaryArithmeticFunction::<Vu8, Vu8, _>::try_create_func(
+                op,
+                args[0].clone(),
+                |v: &[u8], ctx: &mut EvalContext| match decimal256_from_bytes(v) {
+                    Ok(v) => decimal256_to_bytes(-v).to_vec(),
+                    Err(e) => {
+                        ctx.set_error(e);
+                        vec![]
+                    }
+                },
+            );
+        }
+
+        if args[0].data_type_id() == TypeID::Decimal {
+            return UnaryArithmeticFunction::<i128, i128, _>::try_create_func(
+                op,
+                args[0].clone(),
+                |v: i128, _ctx: &mut EvalContext| -v,
+            );
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             let result_type = <$T as ResultTypeOfUnary>::Negate::to_data_type();
             match result_type.data_type_id() {
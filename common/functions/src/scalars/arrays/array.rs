@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::aggregate_types;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Build an array from the arguments, e.g. `array(1, 2, 3)`.
+/// The elements are casted to the least super type of the arguments.
+#[derive(Clone)]
+pub struct ArrayFunction {
+    display_name: String,
+}
+
+impl ArrayFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(1, usize::MAX),
+        )
+    }
+}
+
+impl Function for ArrayFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types = args.iter().map(|x| (*x).clone()).collect::<Vec<_>>();
+        Ok(ArrayType::arc(aggregate_types(&types)?))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let inner_type = aggregate_types(&types)?;
+
+        let columns = columns
+            .iter()
+            .map(|c| Ok(cast_column_field(c, &inner_type)?.convert_full_column()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = MutableArrayColumn::with_capacity(ArrayType::arc(inner_type), input_rows);
+        for row in 0..input_rows {
+            for column in columns.iter() {
+                builder.inner_column().append_data_value(column.get(row))?;
+            }
+            builder.add_offset();
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for ArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::array::ArrayFunction;
+use super::array_contains::ArrayContainsFunction;
+use super::array_filter::ArrayFilterFunction;
+use super::array_get::ArrayGetFunction;
+use super::array_length::ArrayLengthFunction;
+use super::array_map::ArrayMapFunction;
+use super::array_reduce::ArrayReduceFunction;
+use super::array_sort::ArraySortFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct ArraysFunction;
+
+impl ArraysFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("array", ArrayFunction::desc());
+        factory.register("array_get", ArrayGetFunction::desc());
+        factory.register("array_length", ArrayLengthFunction::desc());
+        factory.register("array_contains", ArrayContainsFunction::desc());
+        factory.register("array_map", ArrayMapFunction::desc());
+        factory.register("array_filter", ArrayFilterFunction::desc());
+        factory.register("array_reduce", ArrayReduceFunction::desc());
+        factory.register("array_sort", ArraySortFunction::desc());
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Check whether the array contains the value, the value is casted to the element type first.
+#[derive(Clone)]
+pub struct ArrayContainsFunction {
+    display_name: String,
+}
+
+impl ArrayContainsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayContainsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .bool_function()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for ArrayContainsFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].data_type_id() != TypeID::Array {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                self.display_name.to_uppercase(),
+                args[0],
+                args[1]
+            )));
+        }
+
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let array_type: &ArrayType = columns[0].data_type().as_any().downcast_ref().unwrap();
+        let needle_column = cast_column_field(&columns[1], array_type.inner_type())?;
+
+        let values = array_column.values();
+        let result = (0..input_rows).map(|row| {
+            let needle = needle_column.get(row);
+            let offset = array_column.offsets()[row] as usize;
+            (offset..offset + array_column.size_at_index(row)).any(|i| values.get(i) == needle)
+        });
+        Ok(Series::from_data(result.collect::<Vec<_>>()))
+    }
+}
+
+impl fmt::Display for ArrayContainsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
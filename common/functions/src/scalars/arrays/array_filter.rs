@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_lambda::assert_array;
+use super::array_lambda::expect_lambda;
+use super::array_lambda::lambda_arg;
+use super::array_lambda::take_array_values;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `array_filter(x -> cond, arr)` keeps the elements of the array for which the lambda is true,
+/// the elements with NULL results are dropped.
+#[derive(Clone)]
+pub struct ArrayFilterFunction {
+    display_name: String,
+    lambda: Box<dyn Function>,
+}
+
+impl ArrayFilterFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Err(expect_lambda(display_name))
+    }
+
+    pub fn try_create_with_lambda(
+        display_name: &str,
+        lambda: Box<dyn Function>,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayFilterFunction {
+            display_name: display_name.to_string(),
+            lambda,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for ArrayFilterFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let inner_type = assert_array(&self.display_name, args[0])?;
+        let predicate_type = self.lambda.return_type(&[inner_type])?;
+        if !matches!(
+            remove_nullable(&predicate_type).data_type_id(),
+            TypeID::Boolean | TypeID::Null
+        ) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "The lambda of function '{}' must return a boolean, but got {}",
+                self.display_name.to_uppercase(),
+                predicate_type.name()
+            )));
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let inner_type = assert_array(&self.display_name, columns[0].data_type())?;
+
+        let values = lambda_arg(array_column.values().clone(), inner_type);
+        let predicate = self.lambda.eval(&[values], array_column.values().len())?;
+        let predicate = DataBlock::cast_to_nonull_boolean(&predicate)?.convert_full_column();
+        let predicate: &BooleanColumn = Series::check_get(&predicate)?;
+
+        let indices = (0..input_rows)
+            .map(|row| {
+                let offset = array_column.offsets()[row] as usize;
+                (offset..offset + array_column.size_at_index(row))
+                    .filter(|i| predicate.values().get_bit(*i))
+                    .map(|i| i as u64)
+                    .collect()
+            })
+            .collect();
+        take_array_values(array_column, columns[0].data_type().clone(), indices)
+    }
+}
+
+impl fmt::Display for ArrayFilterFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Get the element of an array by the zero-based index,
+/// NULL is returned if the index is out of range.
+#[derive(Clone)]
+pub struct ArrayGetFunction {
+    display_name: String,
+}
+
+impl ArrayGetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayGetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for ArrayGetFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].data_type_id() != TypeID::Array || !args[1].data_type_id().is_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                self.display_name.to_uppercase(),
+                args[0],
+                args[1]
+            )));
+        }
+
+        let array_type: &ArrayType = args[0].as_any().downcast_ref().unwrap();
+        Ok(wrap_nullable(array_type.inner_type()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let array_type: &ArrayType = columns[0].data_type().as_any().downcast_ref().unwrap();
+        let index_column = columns[1].column();
+
+        let mut builder = wrap_nullable(array_type.inner_type()).create_mutable(input_rows);
+        for row in 0..input_rows {
+            let index = index_column.get_i64(row)?;
+            let value = match usize::try_from(index) {
+                Ok(index) if index < array_column.size_at_index(row) => {
+                    let offset = array_column.offsets()[row] as usize;
+                    array_column.values().get(offset + index)
+                }
+                _ => DataValue::Null,
+            };
+            builder.append_data_value(value)?;
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for ArrayGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
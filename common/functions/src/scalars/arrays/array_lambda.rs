@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_filter::ArrayFilterFunction;
+use super::array_map::ArrayMapFunction;
+use super::array_reduce::ArrayReduceFunction;
+use super::array_sort::ArraySortFunction;
+use crate::scalars::Function;
+
+/// Creates the higher-order array function taking the lambda as its first argument,
+/// e.g. `array_map(x -> x + 1, arr)`. The lambda is evaluated on the columns of its parameters.
+pub fn create_lambda_function(
+    display_name: &str,
+    lambda: Box<dyn Function>,
+) -> Result<Box<dyn Function>> {
+    match display_name.to_lowercase().as_str() {
+        "array_map" => ArrayMapFunction::try_create_with_lambda(display_name, lambda),
+        "array_filter" => ArrayFilterFunction::try_create_with_lambda(display_name, lambda),
+        "array_reduce" => ArrayReduceFunction::try_create_with_lambda(display_name, lambda),
+        "array_sort" => ArraySortFunction::try_create_with_lambda(display_name, lambda),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Function '{}' doesn't accept a lambda argument",
+            display_name.to_uppercase()
+        ))),
+    }
+}
+
+pub(crate) fn expect_lambda(display_name: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!(
+        "Function '{}' expects a lambda as its first argument, e.g. {}(x -> x + 1, arr)",
+        display_name.to_uppercase(),
+        display_name
+    ))
+}
+
+/// Returns the element type of the array argument.
+pub(crate) fn assert_array<'a>(
+    display_name: &str,
+    data_type: &'a DataTypePtr,
+) -> Result<&'a DataTypePtr> {
+    match data_type.as_any().downcast_ref::<ArrayType>() {
+        Some(array_type) => Ok(array_type.inner_type()),
+        None => Err(ErrorCode::IllegalDataType(format!(
+            "Expected an array for function '{}', but got {}",
+            display_name.to_uppercase(),
+            data_type.name()
+        ))),
+    }
+}
+
+pub(crate) fn lambda_arg(column: ColumnRef, data_type: &DataTypePtr) -> ColumnWithField {
+    ColumnWithField::new(column, DataField::new("_lambda_arg", data_type.clone()))
+}
+
+/// Takes the values of the arrays by the indices of each row, the indices are positions in
+/// the values column.
+pub(crate) fn take_array_values(
+    array_column: &ArrayColumn,
+    data_type: DataTypePtr,
+    indices: Vec<Vec<u64>>,
+) -> Result<ColumnRef> {
+    let mut offsets = Vec::with_capacity(indices.len() + 1);
+    offsets.push(0i64);
+    let mut value_indices = Vec::with_capacity(array_column.values().len());
+    for row in indices {
+        value_indices.extend(row);
+        offsets.push(value_indices.len() as i64);
+    }
+
+    let values = Series::take(array_column.values(), &value_indices)?;
+    Ok(ArrayColumn::from_data(data_type, offsets.into(), values).arc())
+}
@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_lambda::assert_array;
+use super::array_lambda::expect_lambda;
+use super::array_lambda::lambda_arg;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `array_map(x -> expr, arr)` applies the lambda to each element of the array.
+#[derive(Clone)]
+pub struct ArrayMapFunction {
+    display_name: String,
+    lambda: Box<dyn Function>,
+}
+
+impl ArrayMapFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Err(expect_lambda(display_name))
+    }
+
+    pub fn try_create_with_lambda(
+        display_name: &str,
+        lambda: Box<dyn Function>,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayMapFunction {
+            display_name: display_name.to_string(),
+            lambda,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for ArrayMapFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let inner_type = assert_array(&self.display_name, args[0])?;
+        let mapped_type = self.lambda.return_type(&[inner_type])?;
+        Ok(ArrayType::arc(mapped_type))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let inner_type = assert_array(&self.display_name, columns[0].data_type())?;
+
+        let values = lambda_arg(array_column.values().clone(), inner_type);
+        let mapped_type = self.lambda.return_type(&[inner_type])?;
+        let mapped = self
+            .lambda
+            .eval(&[values], array_column.values().len())?
+            .convert_full_column();
+
+        Ok(ArrayColumn::from_data(
+            ArrayType::arc(mapped_type),
+            array_column.offsets().to_vec().into(),
+            mapped,
+        )
+        .arc())
+    }
+}
+
+impl fmt::Display for ArrayMapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
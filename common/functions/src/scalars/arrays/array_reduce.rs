@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_lambda::assert_array;
+use super::array_lambda::expect_lambda;
+use super::array_lambda::lambda_arg;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+// The accumulator type may widen on each step, e.g. UInt8 + UInt8 is UInt16, it must settle
+// within a few steps.
+const MAX_ACC_TYPE_STEPS: usize = 8;
+
+/// `array_reduce((acc, x) -> expr, arr, init)` folds the elements of the array from the left,
+/// starting with `init` as the accumulator, e.g. `array_reduce((acc, x) -> acc + x, arr, 0)`.
+#[derive(Clone)]
+pub struct ArrayReduceFunction {
+    display_name: String,
+    lambda: Box<dyn Function>,
+}
+
+impl ArrayReduceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Err(expect_lambda(display_name))
+    }
+
+    pub fn try_create_with_lambda(
+        display_name: &str,
+        lambda: Box<dyn Function>,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayReduceFunction {
+            display_name: display_name.to_string(),
+            lambda,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(3))
+    }
+
+    fn acc_type(&self, init_type: &DataTypePtr, inner_type: &DataTypePtr) -> Result<DataTypePtr> {
+        let mut acc_type = init_type.clone();
+        for _ in 0..MAX_ACC_TYPE_STEPS {
+            let next_type = self.lambda.return_type(&[&acc_type, inner_type])?;
+            if next_type == acc_type {
+                return Ok(acc_type);
+            }
+            acc_type = next_type;
+        }
+
+        Err(ErrorCode::IllegalDataType(format!(
+            "The lambda of function '{}' must return the type of the accumulator, but got {} from {}",
+            self.display_name.to_uppercase(),
+            self.lambda.return_type(&[&acc_type, inner_type])?.name(),
+            acc_type.name()
+        )))
+    }
+}
+
+impl Function for ArrayReduceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let inner_type = assert_array(&self.display_name, args[0])?;
+        self.acc_type(args[1], inner_type)
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let inner_type = assert_array(&self.display_name, columns[0].data_type())?;
+        let acc_type = self.acc_type(columns[1].data_type(), inner_type)?;
+
+        let init = cast_column_field(&columns[1], &acc_type)?;
+        let mut acc = (0..input_rows).map(|row| init.get(row)).collect::<Vec<_>>();
+
+        // Fold the k-th elements of all the arrays at once, the shorter arrays drop out.
+        let mut rows = (0..input_rows).collect::<Vec<_>>();
+        let mut step = 0;
+        loop {
+            rows.retain(|row| array_column.size_at_index(*row) > step);
+            if rows.is_empty() {
+                break;
+            }
+
+            let acc_values = rows.iter().map(|row| acc[*row].clone()).collect::<Vec<_>>();
+            let indices = rows
+                .iter()
+                .map(|row| array_column.offsets()[*row] as u64 + step as u64)
+                .collect::<Vec<_>>();
+            let args = [
+                lambda_arg(acc_type.create_column(&acc_values)?, &acc_type),
+                lambda_arg(Series::take(array_column.values(), &indices)?, inner_type),
+            ];
+
+            let result = self.lambda.eval(&args, rows.len())?;
+            for (i, row) in rows.iter().enumerate() {
+                acc[*row] = result.get(i);
+            }
+            step += 1;
+        }
+        acc_type.create_column(&acc)
+    }
+}
+
+impl fmt::Display for ArrayReduceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
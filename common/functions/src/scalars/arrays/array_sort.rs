@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array_lambda::assert_array;
+use super::array_lambda::lambda_arg;
+use super::array_lambda::take_array_values;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `array_sort(arr)` sorts the elements of the array in ascending order, NULLs first.
+/// `array_sort(x -> key, arr)` sorts the elements by the keys the lambda returns, the elements
+/// with equal keys keep their order.
+#[derive(Clone)]
+pub struct ArraySortFunction {
+    display_name: String,
+    lambda: Option<Box<dyn Function>>,
+}
+
+impl ArraySortFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySortFunction {
+            display_name: display_name.to_string(),
+            lambda: None,
+        }))
+    }
+
+    pub fn try_create_with_lambda(
+        display_name: &str,
+        lambda: Box<dyn Function>,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySortFunction {
+            display_name: display_name.to_string(),
+            lambda: Some(lambda),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+impl Function for ArraySortFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let inner_type = assert_array(&self.display_name, args[0])?;
+        if let Some(lambda) = &self.lambda {
+            lambda.return_type(&[inner_type])?;
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let inner_type = assert_array(&self.display_name, columns[0].data_type())?;
+
+        let keys = match &self.lambda {
+            Some(lambda) => {
+                let values = lambda_arg(array_column.values().clone(), inner_type);
+                lambda
+                    .eval(&[values], array_column.values().len())?
+                    .convert_full_column()
+            }
+            None => array_column.values().clone(),
+        };
+
+        let indices = (0..input_rows)
+            .map(|row| {
+                let offset = array_column.offsets()[row] as usize;
+                let mut row_keys = (offset..offset + array_column.size_at_index(row))
+                    .map(|i| (i as u64, keys.get(i)))
+                    .collect::<Vec<_>>();
+                row_keys.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                row_keys.into_iter().map(|(i, _)| i).collect()
+            })
+            .collect();
+        take_array_values(array_column, columns[0].data_type().clone(), indices)
+    }
+}
+
+impl fmt::Display for ArraySortFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
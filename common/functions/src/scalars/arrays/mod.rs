@@ -0,0 +1,35 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod array;
+mod array_class;
+mod array_contains;
+mod array_filter;
+mod array_get;
+mod array_lambda;
+mod array_length;
+mod array_map;
+mod array_reduce;
+mod array_sort;
+
+pub use array::ArrayFunction;
+pub use array_class::ArraysFunction;
+pub use array_contains::ArrayContainsFunction;
+pub use array_filter::ArrayFilterFunction;
+pub use array_get::ArrayGetFunction;
+pub use array_lambda::create_lambda_function;
+pub use array_length::ArrayLengthFunction;
+pub use array_map::ArrayMapFunction;
+pub use array_reduce::ArrayReduceFunction;
+pub use array_sort::ArraySortFunction;
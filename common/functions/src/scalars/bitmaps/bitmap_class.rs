@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::bitmap_contains::BitmapContainsFunction;
+use super::bitmap_count::BitmapCountFunction;
+use super::bitmap_logic::BitmapAndFunction;
+use super::bitmap_logic::BitmapOrFunction;
+use super::to_bitmap::ToBitmapFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct BitmapsFunction;
+
+impl BitmapsFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("to_bitmap", ToBitmapFunction::desc());
+        factory.register("bitmap_count", BitmapCountFunction::desc());
+        factory.register("bitmap_and", BitmapAndFunction::desc());
+        factory.register("bitmap_or", BitmapOrFunction::desc());
+        factory.register("bitmap_contains", BitmapContainsFunction::desc());
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_bitmap;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Checks whether the bitmap contains the value, e.g. `bitmap_contains(b, 3)`.
+#[derive(Clone)]
+pub struct BitmapContainsFunction {
+    display_name: String,
+}
+
+impl BitmapContainsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitmapContainsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for BitmapContainsFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_bitmap(args[0])?;
+        if !args[1].data_type_id().is_unsigned_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected an unsigned integer type, but got {:?}",
+                args[1]
+            )));
+        }
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let bitmaps = columns[0].column().convert_full_column();
+        let bitmaps: &StringColumn = Series::check_get(&bitmaps)?;
+        let values = cast_column_field(&columns[1], &UInt64Type::arc())?;
+        let values = values.convert_full_column();
+        let values: &UInt64Column = Series::check_get(&values)?;
+
+        let result = bitmaps
+            .iter()
+            .zip(values.scalar_iter())
+            .map(|(b, v)| Ok(bitmap_deserialize(b)?.contains(v)))
+            .collect::<Result<Vec<bool>>>()?;
+        Ok(Series::from_data(result))
+    }
+}
+
+impl fmt::Display for BitmapContainsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_exception::Result;
+
+use crate::scalars::assert_bitmap;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub trait BitmapLogicOperator: Send + Sync + Clone + 'static {
+    fn apply(lhs: RoaringTreemap, rhs: &RoaringTreemap) -> RoaringTreemap;
+}
+
+#[derive(Clone)]
+pub struct BitmapAnd;
+
+impl BitmapLogicOperator for BitmapAnd {
+    fn apply(mut lhs: RoaringTreemap, rhs: &RoaringTreemap) -> RoaringTreemap {
+        lhs &= rhs;
+        lhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitmapOr;
+
+impl BitmapLogicOperator for BitmapOr {
+    fn apply(mut lhs: RoaringTreemap, rhs: &RoaringTreemap) -> RoaringTreemap {
+        lhs |= rhs;
+        lhs
+    }
+}
+
+pub type BitmapAndFunction = BitmapLogicFunction<BitmapAnd>;
+pub type BitmapOrFunction = BitmapLogicFunction<BitmapOr>;
+
+/// Returns the intersection (`bitmap_and`) or the union (`bitmap_or`) of two bitmaps.
+#[derive(Clone)]
+pub struct BitmapLogicFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T: BitmapLogicOperator> BitmapLogicFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitmapLogicFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl<T: BitmapLogicOperator> Function for BitmapLogicFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_bitmap(args[0])?;
+        assert_bitmap(args[1])?;
+        Ok(BitmapType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let lhs = columns[0].column().convert_full_column();
+        let lhs: &StringColumn = Series::check_get(&lhs)?;
+        let rhs = columns[1].column().convert_full_column();
+        let rhs: &StringColumn = Series::check_get(&rhs)?;
+
+        let values = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(l, r)| {
+                let bitmap = T::apply(bitmap_deserialize(l)?, &bitmap_deserialize(r)?);
+                Ok(bitmap_serialize(&bitmap))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Series::from_data(values))
+    }
+}
+
+impl<T> fmt::Display for BitmapLogicFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bitmap_class;
+mod bitmap_contains;
+mod bitmap_count;
+mod bitmap_logic;
+mod to_bitmap;
+
+pub use bitmap_class::BitmapsFunction;
+pub use bitmap_contains::BitmapContainsFunction;
+pub use bitmap_count::BitmapCountFunction;
+pub use bitmap_logic::BitmapAndFunction;
+pub use bitmap_logic::BitmapLogicFunction;
+pub use bitmap_logic::BitmapLogicOperator;
+pub use bitmap_logic::BitmapOrFunction;
+pub use to_bitmap::ToBitmapFunction;
@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Build a bitmap from a comma separated string, e.g. `to_bitmap('1,2,3')`,
+/// or from a single unsigned integer, e.g. `to_bitmap(1)`.
+#[derive(Clone)]
+pub struct ToBitmapFunction {
+    display_name: String,
+}
+
+impl ToBitmapFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToBitmapFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl Function for ToBitmapFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let type_id = args[0].data_type_id();
+        if type_id.is_string() || type_id.is_unsigned_integer() {
+            return Ok(BitmapType::arc());
+        }
+        Err(ErrorCode::IllegalDataType(format!(
+            "Expected a string or unsigned integer type, but got {:?}",
+            args[0]
+        )))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().data_type_id().is_string() {
+            return cast_column_field(&columns[0], &BitmapType::arc());
+        }
+
+        let column = cast_column_field(&columns[0], &UInt64Type::arc())?;
+        let column: &UInt64Column = Series::check_get(&column)?;
+        let values = column
+            .scalar_iter()
+            .map(|v| {
+                let mut bitmap = RoaringTreemap::new();
+                bitmap.insert(v);
+                bitmap_serialize(&bitmap)
+            })
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for ToBitmapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
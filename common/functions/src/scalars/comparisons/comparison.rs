@@ -108,14 +108,17 @@ pub struct ComparisonFunctionCreator<T> {
 
 impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
     pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
-        // expect array & struct
-        let has_array_struct = args
-            .iter()
-            .any(|arg| matches!(arg.data_type_id(), TypeID::Struct | TypeID::Array));
+        // expect array & struct & map
+        let has_array_struct = args.iter().any(|arg| {
+            matches!(
+                arg.data_type_id(),
+                TypeID::Struct | TypeID::Array | TypeID::Map
+            )
+        });
 
         if has_array_struct {
             return Err(ErrorCode::BadArguments(format!(
-                "Illegal types {:?} of argument of function {}, can not be struct or array",
+                "Illegal types {:?} of argument of function {}, can not be struct, array or map",
                 args, display_name
             )));
         }
@@ -123,6 +126,14 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
         let lhs_id = args[0].data_type_id();
         let rhs_id = args[1].data_type_id();
 
+        if lhs_id == TypeID::String && rhs_id == TypeID::String {
+            let collation = Collation::resolve(collation_of(args[0]), collation_of(args[1]))?;
+            if !collation.is_binary() {
+                let func = Arc::new(ComparisonCollatedImpl::new(collation, T::eval_binary));
+                return ComparisonFunction::try_create_func(display_name, func);
+            }
+        }
+
         if args[0].eq(args[1]) {
             return with_match_physical_primitive_type!(lhs_id.to_physical_type(), |$T| {
                 let func = Arc::new(ComparisonPrimitiveImpl::<$T, _>::new(args[0].clone(), false, T::eval_simd::<$T>));
@@ -133,7 +144,8 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
                         let func = Arc::new(ComparisonBooleanImpl::<T::BooleanSimd>::new());
                         ComparisonFunction::try_create_func(display_name, func)
                     },
-                    TypeID::String => {
+                    // The bytes of Decimal256 values sort as the values.
+                    TypeID::String | TypeID::Decimal256 => {
                         let func = Arc::new(ComparisonScalarImpl::<Vu8, Vu8, _>::new(T::eval_binary));
                         ComparisonFunction::try_create_func(display_name, func)
                     },
@@ -155,6 +167,14 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
         }
 
         let least_supertype = compare_coercion(args[0], args[1])?;
+        if least_supertype.data_type_id() == TypeID::Decimal256 {
+            let func = Arc::new(ComparisonDecimal256Impl::new(
+                least_supertype,
+                T::eval_binary,
+            ));
+            return ComparisonFunction::try_create_func(display_name, func);
+        }
+
         with_match_physical_primitive_type_error!(least_supertype.data_type_id().to_physical_type(), |$T| {
             let func = Arc::new(ComparisonPrimitiveImpl::<$T, _>::new(least_supertype, true, T::eval_simd::<$T>));
             ComparisonFunction::try_create_func(display_name, func)
@@ -259,6 +279,73 @@ where
     }
 }
 
+/// Compares Decimal256 values by their bytes, both sides are casted to the common decimal first.
+pub struct ComparisonDecimal256Impl<F> {
+    least_supertype: DataTypePtr,
+    func: F,
+}
+
+impl<F> ComparisonDecimal256Impl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool
+{
+    pub fn new(least_supertype: DataTypePtr, func: F) -> Self {
+        Self {
+            least_supertype,
+            func,
+        }
+    }
+}
+
+impl<F> ComparisonExpression for ComparisonDecimal256Impl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool + Send + Sync + Clone
+{
+    fn eval(&self, l: &ColumnWithField, r: &ColumnWithField) -> Result<BooleanColumn> {
+        let lhs = match l.data_type() != &self.least_supertype {
+            true => cast_column_field(l, &self.least_supertype)?,
+            false => l.column().clone(),
+        };
+        let rhs = match r.data_type() != &self.least_supertype {
+            true => cast_column_field(r, &self.least_supertype)?,
+            false => r.column().clone(),
+        };
+        scalar_binary_op::<Vu8, Vu8, _, _>(
+            &lhs,
+            &rhs,
+            self.func.clone(),
+            &mut EvalContext::default(),
+        )
+    }
+}
+
+/// Compares strings by their sort keys in the collation.
+pub struct ComparisonCollatedImpl<F> {
+    collation: Collation,
+    func: F,
+}
+
+impl<F> ComparisonCollatedImpl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool
+{
+    pub fn new(collation: Collation, func: F) -> Self {
+        Self { collation, func }
+    }
+}
+
+impl<F> ComparisonExpression for ComparisonCollatedImpl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool + Send + Sync + Clone
+{
+    fn eval(&self, l: &ColumnWithField, r: &ColumnWithField) -> Result<BooleanColumn> {
+        let lhs = self.collation.sort_key_column(l.column())?;
+        let rhs = self.collation.sort_key_column(r.column())?;
+        scalar_binary_op::<Vu8, Vu8, bool, _>(
+            &lhs,
+            &rhs,
+            self.func.clone(),
+            &mut EvalContext::default(),
+        )
+    }
+}
+
 pub struct ComparisonPrimitiveImpl<T: PrimitiveType, F> {
     least_supertype: DataTypePtr,
     need_cast: bool,
@@ -37,6 +37,7 @@ impl<const NEGATED: bool> InFunction<NEGATED> {
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .deterministic()
                 .bool_function()
                 .disable_passthrough_null()
                 .variadic_arguments(2, usize::MAX),
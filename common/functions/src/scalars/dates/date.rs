@@ -21,7 +21,11 @@ use super::AddDaysFunction;
 use super::AddMonthsFunction;
 use super::AddTimesFunction;
 use super::AddYearsFunction;
+use super::DateDiffFunction;
+use super::DateIntervalFunction;
+use super::DateTruncFunction;
 use super::RoundFunction;
+use super::ToCharFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
 use super::ToDayOfYearFunction;
@@ -30,15 +34,18 @@ use super::ToMinuteFunction;
 use super::ToMonthFunction;
 use super::ToSecondFunction;
 use super::ToStartOfISOYearFunction;
+use super::ToStartOfIntervalFunction;
 use super::ToStartOfMonthFunction;
 use super::ToStartOfQuarterFunction;
 use super::ToStartOfWeekFunction;
 use super::ToStartOfYearFunction;
+use super::ToTimestampFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
 use super::TodayFunction;
 use super::TomorrowFunction;
+use super::TryToTimestampFunction;
 use super::YesterdayFunction;
 use crate::scalars::function_factory::FactoryCreator;
 use crate::scalars::Function;
@@ -100,19 +107,34 @@ impl DateFunction {
         factory.register("toStartOfDay", Self::round_function_creator(60 * 60 * 24));
 
         factory.register("toStartOfWeek", ToStartOfWeekFunction::desc());
+        factory.register("toStartOfInterval", ToStartOfIntervalFunction::desc());
+        factory.register("to_start_of_interval", ToStartOfIntervalFunction::desc());
+        factory.register("date_trunc", DateTruncFunction::desc());
 
         //interval functions
         factory.register_typed("addYears", AddYearsFunction::desc(1));
+        factory.register_typed("addQuarters", AddMonthsFunction::desc(3));
         factory.register_typed("addMonths", AddMonthsFunction::desc(1));
+        factory.register_typed("addWeeks", AddDaysFunction::desc(7));
         factory.register_typed("addDays", AddDaysFunction::desc(1));
         factory.register_typed("addHours", AddTimesFunction::desc(3600));
         factory.register_typed("addMinutes", AddTimesFunction::desc(60));
         factory.register_typed("addSeconds", AddTimesFunction::desc(1));
         factory.register_typed("subtractYears", AddYearsFunction::desc(-1));
+        factory.register_typed("subtractQuarters", AddMonthsFunction::desc(-3));
         factory.register_typed("subtractMonths", AddMonthsFunction::desc(-1));
+        factory.register_typed("subtractWeeks", AddDaysFunction::desc(-7));
         factory.register_typed("subtractDays", AddDaysFunction::desc(-1));
         factory.register_typed("subtractHours", AddTimesFunction::desc(-3600));
         factory.register_typed("subtractMinutes", AddTimesFunction::desc(-60));
         factory.register_typed("subtractSeconds", AddTimesFunction::desc(-1));
+        factory.register_typed("date_add", DateIntervalFunction::desc("add"));
+        factory.register_typed("date_sub", DateIntervalFunction::desc("subtract"));
+        factory.register("dateDiff", DateDiffFunction::desc());
+
+        // formatting and parsing by templates
+        factory.register("to_char", ToCharFunction::desc());
+        factory.register("to_timestamp", ToTimestampFunction::desc());
+        factory.register("try_to_timestamp", TryToTimestampFunction::desc());
     }
 }
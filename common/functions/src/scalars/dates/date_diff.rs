@@ -0,0 +1,154 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_date_or_datetime;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// dateDiff('unit', start, end) returns the number of unit boundaries crossed between start and end.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(3))
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_date_or_datetime(args[1])?;
+        assert_date_or_datetime(args[2])?;
+        Ok(Int64Type::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if input_rows != 1 && !columns[0].column().is_const() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expected constant column for the first argument of function {}",
+                self.display_name
+            )));
+        }
+
+        let unit = columns[0].column().get_string(0)?;
+        let unit = String::from_utf8_lossy(&unit);
+        let kind = IntervalKind::from_unit(&unit).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Unsupported unit '{}' of function {}, expected one of YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE or SECOND",
+                unit, self.display_name
+            ))
+        })?;
+
+        let starts = to_datetimes(&columns[1])?;
+        let ends = to_datetimes(&columns[2])?;
+        let values = starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(start, end)| diff_datetime(&kind, start, end))
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn to_datetimes(column: &ColumnWithField) -> Result<Vec<NaiveDateTime>> {
+    let data_type = column.data_type();
+    let full = column.column().convert_full_column();
+    match data_type.data_type_id() {
+        TypeID::Date16 => {
+            let col: &UInt16Column = Series::check_get(&full)?;
+            Ok(col
+                .scalar_iter()
+                .map(|v| days_to_datetime(v as i64))
+                .collect())
+        }
+        TypeID::Date32 => {
+            let col: &Int32Column = Series::check_get(&full)?;
+            Ok(col
+                .scalar_iter()
+                .map(|v| days_to_datetime(v as i64))
+                .collect())
+        }
+        TypeID::DateTime32 => {
+            let col: &UInt32Column = Series::check_get(&full)?;
+            Ok(col
+                .scalar_iter()
+                .map(|v| NaiveDateTime::from_timestamp(v as i64, 0))
+                .collect())
+        }
+        TypeID::DateTime64 => {
+            let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+            let col: &Int64Column = Series::check_get(&full)?;
+            Ok(col
+                .scalar_iter()
+                .map(|v| datetime.utc_timestamp(v).naive_utc())
+                .collect())
+        }
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?} of argument of function dateDiff, expected a date or a datetime",
+            other
+        ))),
+    }
+}
+
+fn days_to_datetime(days: i64) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp(days * 24 * 3600, 0)
+}
+
+fn diff_datetime(kind: &IntervalKind, start: &NaiveDateTime, end: &NaiveDateTime) -> i64 {
+    let months = |v: &NaiveDateTime| v.year() as i64 * 12 + v.month0() as i64;
+    let days = |v: &NaiveDateTime| v.timestamp().div_euclid(24 * 3600);
+    let seconds = |v: &NaiveDateTime, unit: i64| v.timestamp().div_euclid(unit);
+
+    match kind {
+        IntervalKind::Year => end.year() as i64 - start.year() as i64,
+        IntervalKind::Quarter => months(end).div_euclid(3) - months(start).div_euclid(3),
+        IntervalKind::Month => months(end) - months(start),
+        // 1970-01-01 is a Thursday, shift by 3 days so that weeks start on Monday.
+        IntervalKind::Week => (days(end) + 3).div_euclid(7) - (days(start) + 3).div_euclid(7),
+        IntervalKind::Day => days(end) - days(start),
+        IntervalKind::Hour => seconds(end, 3600) - seconds(start, 3600),
+        IntervalKind::Minute => seconds(end, 60) - seconds(start, 60),
+        IntervalKind::Second => end.timestamp() - start.timestamp(),
+    }
+}
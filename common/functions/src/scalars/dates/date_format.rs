@@ -0,0 +1,312 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Write;
+
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_date_or_datetime;
+use crate::scalars::assert_string;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+// The template patterns and their strftime equivalents, the longer patterns go first.
+const PATTERNS: [(&str, &str); 18] = [
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("MONTH", "%B"),
+    ("MON", "%b"),
+    ("MM", "%m"),
+    ("MI", "%M"),
+    ("MS", "%3f"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("DAY", "%A"),
+    ("DY", "%a"),
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("SS", "%S"),
+    ("US", "%6f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+];
+
+/// Translates a template like `YYYY-MM-DD HH24:MI:SS` to the strftime format `%Y-%m-%d %H:%M:%S`.
+/// The patterns are case-insensitive, the text in double quotes and the other characters are kept
+/// as they are, so the result doesn't depend on the locale.
+pub(crate) fn to_strftime_format(template: &str) -> Result<String> {
+    let mut format = String::with_capacity(template.len() * 2);
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            let end = rest[1..].find('"').ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Unterminated quoted text in format '{}'",
+                    template
+                ))
+            })?;
+            format.push_str(&rest[1..end + 1].replace('%', "%%"));
+            rest = &rest[end + 2..];
+            continue;
+        }
+
+        let pattern = PATTERNS.iter().find(|(pattern, _)| {
+            rest.len() >= pattern.len()
+                && rest.is_char_boundary(pattern.len())
+                && rest[..pattern.len()].eq_ignore_ascii_case(pattern)
+        });
+        match pattern {
+            Some((pattern, strftime)) => {
+                format.push_str(strftime);
+                rest = &rest[pattern.len()..];
+            }
+            None => {
+                match c {
+                    '%' => format.push_str("%%"),
+                    c => format.push(c),
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    Ok(format)
+}
+
+/// `to_char(x, template)` formats the date or datetime by the template, in UTC, e.g.
+/// `to_char(ts, 'YYYY-MM-DD HH24:MI')`, see `to_strftime_format` for the patterns.
+#[derive(Clone)]
+pub struct ToCharFunction {
+    display_name: String,
+}
+
+impl ToCharFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToCharFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for ToCharFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_date_or_datetime(args[0])?;
+        assert_string(args[1])?;
+        Ok(Vu8::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let data_type = columns[0].data_type();
+        let template = columns[1].column();
+        let mut ctx = EvalContext::default();
+
+        let col = match data_type.data_type_id() {
+            TypeID::Date16 => scalar_binary_op::<u16, Vu8, Vu8, _>(
+                columns[0].column(),
+                template,
+                |v, t, ctx| format_datetime(from_days(v as i64), t, ctx),
+                &mut ctx,
+            )?,
+            TypeID::Date32 => scalar_binary_op::<i32, Vu8, Vu8, _>(
+                columns[0].column(),
+                template,
+                |v, t, ctx| format_datetime(from_days(v as i64), t, ctx),
+                &mut ctx,
+            )?,
+            TypeID::DateTime32 => scalar_binary_op::<u32, Vu8, Vu8, _>(
+                columns[0].column(),
+                template,
+                |v, t, ctx| format_datetime(NaiveDateTime::from_timestamp(v as i64, 0), t, ctx),
+                &mut ctx,
+            )?,
+            TypeID::DateTime64 => {
+                let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+                scalar_binary_op::<i64, Vu8, Vu8, _>(
+                    columns[0].column(),
+                    template,
+                    |v, t, ctx| format_datetime(datetime.utc_timestamp(v).naive_utc(), t, ctx),
+                    &mut ctx,
+                )?
+            }
+            _ => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected a date or datetime type, but got {:?}",
+                    data_type
+                )))
+            }
+        };
+        Ok(col.arc())
+    }
+}
+
+impl fmt::Display for ToCharFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type TryToTimestampFunction = ToTimestampFunctionImpl<true>;
+
+pub type ToTimestampFunction = ToTimestampFunctionImpl<false>;
+
+/// `to_timestamp(s, template)` parses the string by the template, it's the inverse of `to_char`,
+/// e.g. `to_timestamp('2022-04-01 10:20', 'YYYY-MM-DD HH24:MI')`. The time is 00:00:00 if the
+/// template has only the date, the result is a `Timestamp` in UTC. `try_to_timestamp` returns
+/// NULL instead of an error if the string doesn't match the template.
+#[derive(Clone)]
+pub struct ToTimestampFunctionImpl<const SUPPRESS_PARSE_ERROR: bool> {
+    display_name: String,
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> ToTimestampFunctionImpl<SUPPRESS_PARSE_ERROR> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToTimestampFunctionImpl::<SUPPRESS_PARSE_ERROR> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        let mut features = FunctionFeatures::default().deterministic().num_arguments(2);
+        // The nulls are skipped before parsing, the values under them are not valid strings.
+        if !SUPPRESS_PARSE_ERROR {
+            features = features.disable_passthrough_null()
+        }
+        FunctionDescription::creator(Box::new(Self::try_create)).features(features)
+    }
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> Function for ToTimestampFunctionImpl<SUPPRESS_PARSE_ERROR> {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args.iter().any(|arg| arg.is_null()) {
+            return Ok(NullType::arc());
+        }
+        assert_string(&remove_nullable(args[0]))?;
+        assert_string(&remove_nullable(args[1]))?;
+
+        let output_type = DateTime64Type::arc(6, None);
+        if SUPPRESS_PARSE_ERROR || args.iter().any(|arg| arg.is_nullable()) {
+            Ok(wrap_nullable(&output_type))
+        } else {
+            Ok(output_type)
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns.iter().any(|c| c.data_type().is_null()) {
+            return NullType::arc().create_constant_column(&DataValue::Null, input_rows);
+        }
+
+        let values = Vu8::try_create_viewer(columns[0].column())?;
+        let templates = Vu8::try_create_viewer(columns[1].column())?;
+        let nullable = SUPPRESS_PARSE_ERROR || columns.iter().any(|c| c.data_type().is_nullable());
+
+        if nullable {
+            let mut builder = NullableColumnBuilder::<i64>::with_capacity(input_rows);
+            for i in 0..input_rows {
+                if values.null_at(i) || templates.null_at(i) {
+                    builder.append_null();
+                    continue;
+                }
+                match parse_datetime(values.value_at(i), templates.value_at(i)) {
+                    Ok(v) => builder.append(v, true),
+                    Err(_) if SUPPRESS_PARSE_ERROR => builder.append_null(),
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(builder.build(input_rows))
+        } else {
+            let mut builder = ColumnBuilder::<i64>::with_capacity(input_rows);
+            for i in 0..input_rows {
+                builder.append(parse_datetime(values.value_at(i), templates.value_at(i))?);
+            }
+            Ok(builder.build(input_rows))
+        }
+    }
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> fmt::Display
+    for ToTimestampFunctionImpl<SUPPRESS_PARSE_ERROR>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn from_days(days: i64) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp(days * SECONDS_PER_DAY, 0)
+}
+
+fn format_datetime(datetime: NaiveDateTime, template: &[u8], ctx: &mut EvalContext) -> Vec<u8> {
+    let template = String::from_utf8_lossy(template);
+    match to_strftime_format(&template) {
+        Ok(format) => {
+            let mut buf = String::new();
+            match write!(buf, "{}", datetime.format(&format)) {
+                Ok(_) => buf.into_bytes(),
+                Err(_) => {
+                    ctx.set_error(ErrorCode::BadArguments(format!(
+                        "Invalid format '{}'",
+                        template
+                    )));
+                    vec![]
+                }
+            }
+        }
+        Err(e) => {
+            ctx.set_error(e);
+            vec![]
+        }
+    }
+}
+
+// Returns the microseconds since 1970-01-01 00:00:00 UTC.
+fn parse_datetime(value: &[u8], template: &[u8]) -> Result<i64> {
+    let value = String::from_utf8_lossy(value);
+    let template = String::from_utf8_lossy(template);
+    let format = to_strftime_format(&template)?;
+
+    let datetime = NaiveDateTime::parse_from_str(&value, &format)
+        .or_else(|_| NaiveDate::parse_from_str(&value, &format).map(|d| d.and_hms(0, 0, 0)))
+        .map_err(|e| {
+            ErrorCode::StrParseError(format!(
+                "Cannot parse '{}' with format '{}': {}",
+                value, template, e
+            ))
+        })?;
+    Ok(datetime.timestamp() * 1_000_000 + datetime.timestamp_subsec_micros() as i64)
+}
@@ -0,0 +1,269 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_date_or_datetime;
+use crate::scalars::assert_string;
+use crate::scalars::scalar_unary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::Monotonicity;
+
+const SECONDS_PER_DAY: i64 = 24 * 3600;
+// The days from 0001-01-01 to 1970-01-01.
+const EPOCH_DAYS_FROM_CE: i64 = 719163;
+// 1970-01-05 is the first Monday since the epoch, the weeks start on Mondays.
+const FIRST_MONDAY: i64 = 4;
+
+/// `date_trunc(unit, x)` truncates the date or datetime to the start of the unit, e.g.
+/// `date_trunc('hour', x)`. The result has the same type as x.
+#[derive(Clone)]
+pub struct DateTruncFunction {
+    display_name: String,
+}
+
+impl DateTruncFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTruncFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for DateTruncFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_date_or_datetime(args[1])?;
+        Ok(args[1].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        // The constant arguments are unwrapped to a single row if all the arguments are constants.
+        let unit = columns[0].column();
+        if !unit.is_const() && unit.len() > 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "The unit of {} must be a constant",
+                self.display_name
+            )));
+        }
+
+        let unit = String::from_utf8_lossy(&unit.get_string(0)?).to_string();
+        let kind = IntervalKind::from_unit(&unit).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Unsupported unit of {}: '{}', expected one of year, quarter, month, week, day, hour, minute, second",
+                self.display_name, unit
+            ))
+        })?;
+        truncate_column(&columns[1], &kind, 1)
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // Truncating to the same unit never changes the order of the values.
+        if args[0].is_constant {
+            return Ok(Monotonicity::clone_without_range(&args[1]));
+        }
+        Ok(Monotonicity::default())
+    }
+}
+
+impl fmt::Display for DateTruncFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// `to_start_of_interval(x, INTERVAL n unit)` truncates the date or datetime to the start of
+/// the n units long bucket it is in, e.g. `to_start_of_interval(x, INTERVAL 5 MINUTE)`.
+/// The buckets are counted from 1970-01-01 for units up to a day, from the first Monday since
+/// 1970-01-01 for weeks, and from the year 0 for months, quarters and years.
+#[derive(Clone)]
+pub struct ToStartOfIntervalFunction {
+    display_name: String,
+}
+
+impl ToStartOfIntervalFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToStartOfIntervalFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for ToStartOfIntervalFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_date_or_datetime(args[0])?;
+        if args[1].data_type_id() != TypeID::Interval {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected an interval type, but got {}",
+                args[1].name()
+            )));
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let interval = columns[1].column();
+        let kind = match columns[1]
+            .data_type()
+            .as_any()
+            .downcast_ref::<IntervalType>()
+        {
+            Some(interval_type) if interval.is_const() || interval.len() == 1 => {
+                interval_type.kind()
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The interval of {} must be a constant",
+                    self.display_name
+                )))
+            }
+        };
+
+        let num = interval.get_i64(0)?;
+        if num <= 0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "The interval of {} must be positive, but got {}",
+                self.display_name, num
+            )));
+        }
+        truncate_column(&columns[0], kind, num)
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        if args[1].is_constant {
+            return Ok(Monotonicity::clone_without_range(&args[0]));
+        }
+        Ok(Monotonicity::default())
+    }
+}
+
+impl fmt::Display for ToStartOfIntervalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn truncate_column(column: &ColumnWithField, kind: &IntervalKind, num: i64) -> Result<ColumnRef> {
+    let data_type = column.data_type();
+    let mut ctx = EvalContext::default();
+    match data_type.data_type_id() {
+        TypeID::Date16 => {
+            let func =
+                |v: u16, _ctx: &mut EvalContext| truncate_days(v as i64, kind, num).max(0) as u16;
+            let col = scalar_unary_op::<u16, u16, _>(column.column(), func, &mut ctx)?;
+            Ok(col.arc())
+        }
+        TypeID::Date32 => {
+            let func = |v: i32, _ctx: &mut EvalContext| truncate_days(v as i64, kind, num) as i32;
+            let col = scalar_unary_op::<i32, i32, _>(column.column(), func, &mut ctx)?;
+            Ok(col.arc())
+        }
+        TypeID::DateTime32 => {
+            let func = |v: u32, _ctx: &mut EvalContext| {
+                truncate_seconds(v as i64, kind, num).max(0) as u32
+            };
+            let col = scalar_unary_op::<u32, u32, _>(column.column(), func, &mut ctx)?;
+            Ok(col.arc())
+        }
+        TypeID::DateTime64 => {
+            let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+            let scale = 10_i64.pow(datetime.precision() as u32);
+            let func = |v: i64, _ctx: &mut EvalContext| {
+                truncate_seconds(v.div_euclid(scale), kind, num) * scale
+            };
+            let col = scalar_unary_op::<i64, i64, _>(column.column(), func, &mut ctx)?;
+            Ok(col.arc())
+        }
+        _ => Err(ErrorCode::IllegalDataType(format!(
+            "Expected a data or datetime type, but got {:?}",
+            data_type
+        ))),
+    }
+}
+
+/// Truncates the seconds since 1970-01-01 00:00:00, in UTC.
+fn truncate_seconds(seconds: i64, kind: &IntervalKind, num: i64) -> i64 {
+    match kind {
+        IntervalKind::Second => floor_to(seconds, num),
+        IntervalKind::Minute => floor_to(seconds, num * 60),
+        IntervalKind::Hour => floor_to(seconds, num * 3600),
+        _ => {
+            let days = seconds.div_euclid(SECONDS_PER_DAY);
+            truncate_days(days, kind, num) * SECONDS_PER_DAY
+        }
+    }
+}
+
+/// Truncates the days since 1970-01-01, the units shorter than a day keep the date as it is.
+fn truncate_days(days: i64, kind: &IntervalKind, num: i64) -> i64 {
+    let truncate_months = |months_num: i64| {
+        let date = NaiveDate::from_num_days_from_ce((days + EPOCH_DAYS_FROM_CE) as i32);
+        let months = floor_to(date.year() as i64 * 12 + date.month0() as i64, months_num);
+        let start = NaiveDate::from_ymd(
+            months.div_euclid(12) as i32,
+            months.rem_euclid(12) as u32 + 1,
+            1,
+        );
+        start.num_days_from_ce() as i64 - EPOCH_DAYS_FROM_CE
+    };
+
+    match kind {
+        IntervalKind::Year => truncate_months(num * 12),
+        IntervalKind::Quarter => truncate_months(num * 3),
+        IntervalKind::Month => truncate_months(num),
+        IntervalKind::Week => floor_to(days - FIRST_MONDAY, num * 7) + FIRST_MONDAY,
+        IntervalKind::Day => floor_to(days, num),
+        IntervalKind::Hour | IntervalKind::Minute | IntervalKind::Second => days,
+    }
+}
+
+#[inline]
+fn floor_to(value: i64, step: i64) -> i64 {
+    value.div_euclid(step) * step
+}
@@ -34,6 +34,7 @@ use crate::scalars::scalar_binary_op;
 use crate::scalars::EvalContext;
 use crate::scalars::FactoryCreatorWithTypes;
 use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
 use crate::scalars::FunctionFeatures;
 use crate::scalars::TypedFunctionDescription;
 
@@ -103,6 +104,41 @@ where T: IntervalArithmeticImpl + Send + Sync + Clone + 'static
     }
 }
 
+/// date_add(date, INTERVAL n unit) and date_sub(date, INTERVAL n unit), resolved to the
+/// add{unit}s/subtract{unit}s function of the interval class.
+pub struct DateIntervalFunction;
+
+impl DateIntervalFunction {
+    pub fn try_create_func(
+        display_name: &str,
+        prefix: &'static str,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function>> {
+        let interval = match args[1].as_any().downcast_ref::<IntervalType>() {
+            Some(interval) if args[0].data_type_id().is_date_or_date_time() => interval,
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "DataValue Error: Unsupported arithmetic {}({:?}, {:?}), expected a date and an interval",
+                    display_name,
+                    args[0].data_type_id(),
+                    args[1].data_type_id()
+                )))
+            }
+        };
+
+        let function_name = format!("{}{}s", prefix, interval.kind());
+        FunctionFactory::instance().get(function_name, &[args[0], &Int64Type::arc()])
+    }
+
+    pub fn desc(prefix: &'static str) -> TypedFunctionDescription {
+        let function_creator: FactoryCreatorWithTypes =
+            Box::new(move |display_name, args| Self::try_create_func(display_name, prefix, args));
+
+        TypedFunctionDescription::creator(function_creator)
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
 #[derive(Clone)]
 pub struct IntervalFunction<L: DateType, R: PrimitiveType, O: DateType, F> {
     display_name: String,
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 mod date;
+mod date_diff;
+mod date_format;
+mod date_trunc;
 mod interval_function;
 mod now;
 mod number_function;
@@ -23,10 +26,17 @@ mod week_date;
 mod macros;
 
 pub use date::DateFunction;
+pub use date_diff::DateDiffFunction;
+pub use date_format::ToCharFunction;
+pub use date_format::ToTimestampFunction;
+pub use date_format::TryToTimestampFunction;
+pub use date_trunc::DateTruncFunction;
+pub use date_trunc::ToStartOfIntervalFunction;
 pub use interval_function::AddDaysFunction;
 pub use interval_function::AddMonthsFunction;
 pub use interval_function::AddTimesFunction;
 pub use interval_function::AddYearsFunction;
+pub use interval_function::DateIntervalFunction;
 pub use number_function::ToDayOfMonthFunction;
 pub use number_function::ToDayOfWeekFunction;
 pub use number_function::ToDayOfYearFunction;
@@ -0,0 +1,320 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use aes::Aes128;
+use aes::Aes192;
+use aes::Aes256;
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::NewAead;
+use aes_gcm::Aes128Gcm;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Key;
+use aes_gcm::Nonce;
+use block_modes::block_padding::Pkcs7;
+use block_modes::BlockMode;
+use block_modes::Cbc;
+use block_modes::InvalidKeyIvLength;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use rand::RngCore;
+
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub type AesEncryptFunction = AesFunctionImpl<AesEncrypt, false>;
+pub type AesDecryptFunction = AesFunctionImpl<AesDecrypt, false>;
+pub type TryAesDecryptFunction = AesFunctionImpl<AesDecrypt, true>;
+
+type Aes128Cbc = Cbc<Aes128, Pkcs7>;
+type Aes192Cbc = Cbc<Aes192, Pkcs7>;
+type Aes256Cbc = Cbc<Aes256, Pkcs7>;
+
+const GCM_NONCE_LEN: usize = 12;
+const CBC_IV_LEN: usize = 16;
+
+/// The block encryption modes, named the same as MySQL's `block_encryption_mode`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AesMode {
+    Aes128Gcm,
+    Aes256Gcm,
+    Aes128Cbc,
+    Aes192Cbc,
+    Aes256Cbc,
+}
+
+impl AesMode {
+    pub fn parse(mode: &[u8]) -> Result<AesMode> {
+        match String::from_utf8_lossy(mode).to_lowercase().as_str() {
+            "aes-128-gcm" => Ok(AesMode::Aes128Gcm),
+            "aes-256-gcm" => Ok(AesMode::Aes256Gcm),
+            "aes-128-cbc" => Ok(AesMode::Aes128Cbc),
+            "aes-192-cbc" => Ok(AesMode::Aes192Cbc),
+            "aes-256-cbc" => Ok(AesMode::Aes256Cbc),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Unsupported block encryption mode: {:?}, expected one of aes-128-gcm, aes-256-gcm, aes-128-cbc, aes-192-cbc, aes-256-cbc",
+                other
+            ))),
+        }
+    }
+
+    pub fn key_len(&self) -> usize {
+        match self {
+            AesMode::Aes128Gcm | AesMode::Aes128Cbc => 16,
+            AesMode::Aes192Cbc => 24,
+            AesMode::Aes256Gcm | AesMode::Aes256Cbc => 32,
+        }
+    }
+
+    fn iv_len(&self) -> usize {
+        match self {
+            AesMode::Aes128Gcm | AesMode::Aes256Gcm => GCM_NONCE_LEN,
+            _ => CBC_IV_LEN,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            AesMode::Aes128Gcm => "aes-128-gcm",
+            AesMode::Aes256Gcm => "aes-256-gcm",
+            AesMode::Aes128Cbc => "aes-128-cbc",
+            AesMode::Aes192Cbc => "aes-192-cbc",
+            AesMode::Aes256Cbc => "aes-256-cbc",
+        }
+    }
+
+    fn check_key(&self, key: &[u8]) -> Result<()> {
+        if key.len() != self.key_len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The key of {} must be {} bytes, but got {} bytes",
+                self,
+                self.key_len(),
+                key.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AesMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub trait AesCipher {
+    const IS_DETERMINISTIC: bool;
+
+    fn apply(mode: AesMode, input: &[u8], key: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Encrypts with a random IV, which is put in front of the ciphertext, so the same
+/// plaintext is never encrypted to the same ciphertext.
+#[derive(Clone)]
+pub struct AesEncrypt;
+
+impl AesCipher for AesEncrypt {
+    const IS_DETERMINISTIC: bool = false;
+
+    fn apply(mode: AesMode, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>> {
+        mode.check_key(key)?;
+
+        let mut output = vec![0u8; mode.iv_len()];
+        rand::thread_rng().fill_bytes(&mut output);
+        let iv = output.clone();
+
+        let ciphertext = match mode {
+            AesMode::Aes128Gcm => Aes128Gcm::new(Key::from_slice(key))
+                .encrypt(Nonce::from_slice(&iv), plaintext)
+                .map_err(|_| ErrorCode::LogicalError("Failed to encrypt with aes-128-gcm"))?,
+            AesMode::Aes256Gcm => Aes256Gcm::new(Key::from_slice(key))
+                .encrypt(Nonce::from_slice(&iv), plaintext)
+                .map_err(|_| ErrorCode::LogicalError("Failed to encrypt with aes-256-gcm"))?,
+            AesMode::Aes128Cbc => Aes128Cbc::new_from_slices(key, &iv)
+                .map_err(cbc_error)?
+                .encrypt_vec(plaintext),
+            AesMode::Aes192Cbc => Aes192Cbc::new_from_slices(key, &iv)
+                .map_err(cbc_error)?
+                .encrypt_vec(plaintext),
+            AesMode::Aes256Cbc => Aes256Cbc::new_from_slices(key, &iv)
+                .map_err(cbc_error)?
+                .encrypt_vec(plaintext),
+        };
+        output.extend_from_slice(&ciphertext);
+        Ok(output)
+    }
+}
+
+#[derive(Clone)]
+pub struct AesDecrypt;
+
+impl AesCipher for AesDecrypt {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn apply(mode: AesMode, input: &[u8], key: &[u8]) -> Result<Vec<u8>> {
+        mode.check_key(key)?;
+
+        if input.len() < mode.iv_len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The ciphertext of {} must have at least {} bytes, but got {} bytes",
+                mode,
+                mode.iv_len(),
+                input.len()
+            )));
+        }
+        let (iv, ciphertext) = input.split_at(mode.iv_len());
+
+        let plaintext = match mode {
+            AesMode::Aes128Gcm => Aes128Gcm::new(Key::from_slice(key))
+                .decrypt(Nonce::from_slice(iv), ciphertext)
+                .ok(),
+            AesMode::Aes256Gcm => Aes256Gcm::new(Key::from_slice(key))
+                .decrypt(Nonce::from_slice(iv), ciphertext)
+                .ok(),
+            AesMode::Aes128Cbc => Aes128Cbc::new_from_slices(key, iv)
+                .map_err(cbc_error)?
+                .decrypt_vec(ciphertext)
+                .ok(),
+            AesMode::Aes192Cbc => Aes192Cbc::new_from_slices(key, iv)
+                .map_err(cbc_error)?
+                .decrypt_vec(ciphertext)
+                .ok(),
+            AesMode::Aes256Cbc => Aes256Cbc::new_from_slices(key, iv)
+                .map_err(cbc_error)?
+                .decrypt_vec(ciphertext)
+                .ok(),
+        };
+
+        // Both a wrong key and a tampered ciphertext end up here.
+        plaintext.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Failed to decrypt with {}, the key or the ciphertext is wrong",
+                mode
+            ))
+        })
+    }
+}
+
+fn cbc_error(cause: InvalidKeyIvLength) -> ErrorCode {
+    ErrorCode::BadArguments(cause.to_string())
+}
+
+/// `aes_encrypt(plaintext, key, mode)` and `aes_decrypt(ciphertext, key, mode)`.
+///
+/// The SQL analyzer fills the key and the mode from the `aes_encryption_key` and
+/// `block_encryption_mode` settings if they are omitted, so the key doesn't need to
+/// be written in the queries.
+#[derive(Clone)]
+pub struct AesFunctionImpl<T, const SUPPRESS_ERROR: bool> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T, const SUPPRESS_ERROR: bool> AesFunctionImpl<T, SUPPRESS_ERROR>
+where T: AesCipher + Clone + Sync + Send + 'static
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(AesFunctionImpl::<T, SUPPRESS_ERROR> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        // The values of the null rows are not valid keys, so we check the nulls
+        // ourselves instead of passing them through.
+        let mut features = FunctionFeatures::default()
+            .disable_passthrough_null()
+            .num_arguments(3);
+        if T::IS_DETERMINISTIC {
+            features = features.deterministic();
+        }
+        FunctionDescription::creator(Box::new(Self::try_create)).features(features)
+    }
+}
+
+impl<T, const SUPPRESS_ERROR: bool> Function for AesFunctionImpl<T, SUPPRESS_ERROR>
+where T: AesCipher + Clone + Sync + Send + 'static
+{
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let mut nullable = SUPPRESS_ERROR;
+        for arg in args {
+            if arg.data_type_id() == TypeID::Null {
+                return Ok(NullType::arc());
+            }
+            assert_string(&remove_nullable(arg))?;
+            nullable |= arg.is_nullable();
+        }
+
+        if nullable {
+            Ok(Arc::new(NullableType::create(StringType::arc())))
+        } else {
+            Ok(StringType::arc())
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns
+            .iter()
+            .any(|c| c.column().data_type_id() == TypeID::Null)
+        {
+            return NullType::arc().create_constant_column(&DataValue::Null, input_rows);
+        }
+
+        let input = Vu8::try_create_viewer(columns[0].column())?;
+        let key = Vu8::try_create_viewer(columns[1].column())?;
+        let mode = Vu8::try_create_viewer(columns[2].column())?;
+
+        let mut builder = NullableColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+        for (i, (input_value, key_value, mode_value)) in
+            izip!(input.iter(), key.iter(), mode.iter()).enumerate()
+        {
+            if input.null_at(i) || key.null_at(i) || mode.null_at(i) {
+                builder.append_null();
+                continue;
+            }
+
+            let mode = AesMode::parse(mode_value)?;
+            match T::apply(mode, input_value, key_value) {
+                Ok(output) => builder.append(&output, true),
+                Err(_) if SUPPRESS_ERROR => builder.append_null(),
+                Err(cause) => return Err(cause),
+            }
+        }
+
+        let column = builder.build(input_rows);
+        if SUPPRESS_ERROR || columns.iter().any(|c| c.data_type().is_nullable()) {
+            Ok(column)
+        } else {
+            Ok(Series::remove_nullable(&column))
+        }
+    }
+}
+
+impl<T, const SUPPRESS_ERROR: bool> fmt::Display for AesFunctionImpl<T, SUPPRESS_ERROR> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
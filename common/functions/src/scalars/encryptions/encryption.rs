@@ -0,0 +1,29 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::AesDecryptFunction;
+use super::AesEncryptFunction;
+use super::TryAesDecryptFunction;
+use crate::scalars::FunctionFactory;
+
+#[derive(Clone)]
+pub struct EncryptionFunction;
+
+impl EncryptionFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("aes_encrypt", AesEncryptFunction::desc());
+        factory.register("aes_decrypt", AesDecryptFunction::desc());
+        factory.register("try_aes_decrypt", TryAesDecryptFunction::desc());
+    }
+}
@@ -200,10 +200,47 @@ pub fn cast_from_datetime64(
             Ok((result, None))
         }
 
+        TypeID::DateTime64 => {
+            let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+            let it = c.iter().map(|v| {
+                let nanos = *v * 10_i64.pow(9 - date_time64.precision() as u32);
+                datetime.from_nano_seconds(nanos)
+            });
+            let result = Arc::new(Int64Column::from_iterator(it));
+            Ok((result, None))
+        }
+
         _ => arrow_cast_compute(column, from_type, data_type, cast_options),
     }
 }
 
+/// Integers cast to a Timestamp are seconds since the epoch, as they were when Timestamp was a
+/// DateTime32. DateTime64(3) keeps reading them as milliseconds, e.g: toDateTime64(1640019661000).
+pub fn is_timestamp_from_seconds(from_type: &DataTypePtr, data_type: &DataTypePtr) -> bool {
+    from_type.data_type_id().is_integer()
+        && data_type
+            .as_any()
+            .downcast_ref::<DateTime64Type>()
+            .map_or(false, |datetime| datetime.precision() == 6)
+}
+
+pub fn cast_from_seconds_to_timestamp(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let (seconds, valids) = arrow_cast_compute(column, from_type, &Int64Type::arc(), cast_options)?;
+    let c = Series::remove_nullable(&seconds);
+    let c: &Int64Column = Series::check_get(&c)?;
+
+    let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+    let scale = 10_i64.pow(datetime.precision() as u32);
+    let it = c.iter().map(|v| v.saturating_mul(scale));
+    let result = Arc::new(Int64Column::from_iterator(it));
+    Ok((result, valids))
+}
+
 #[inline]
 fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
     date.format(fmt).to_string()
@@ -0,0 +1,192 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = from_type.as_any().downcast_ref().unwrap();
+    match decimal.is_decimal256() {
+        true => cast_from_decimal_values::<I256>(column, from_type, data_type, cast_options),
+        false => cast_from_decimal_values::<i128>(column, from_type, data_type, cast_options),
+    }
+}
+
+fn cast_from_decimal_values<T: DecimalNative>(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = from_type.as_any().downcast_ref().unwrap();
+    let c = T::column_values(&Series::remove_nullable(column))?;
+    let size = c.len();
+
+    match data_type.data_type_id() {
+        TypeID::String => {
+            let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
+            for v in c.iter() {
+                builder.append(decimal.format_value(*v).as_bytes());
+            }
+            Ok((builder.build(size), None))
+        }
+
+        TypeID::Float32 | TypeID::Float64 => {
+            let it = c.iter().map(|v| decimal.to_f64(*v));
+            let result: ColumnRef = Arc::new(Float64Column::from_iterator(it));
+            arrow_cast_compute(&result, &Float64Type::arc(), data_type, cast_options)
+        }
+
+        TypeID::Boolean => {
+            let it = c.iter().map(|v| *v != T::ZERO);
+            Ok((Arc::new(BooleanColumn::from_owned_iterator(it)), None))
+        }
+
+        TypeID::Decimal => {
+            let target: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+            let it = c
+                .iter()
+                .map(|v| target.rescale_from::<T, i128>(*v, decimal));
+            let (result, bitmap) = build_decimal_column(it, size);
+            Ok((result, Some(bitmap)))
+        }
+
+        TypeID::Decimal256 => {
+            let target: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+            let it = c
+                .iter()
+                .map(|v| target.rescale_from::<T, I256>(*v, decimal));
+            let (result, bitmap) = build_decimal_column(it, size);
+            Ok((result, Some(bitmap)))
+        }
+
+        _ if data_type.data_type_id().is_integer() => {
+            // Round to the nearest integer, then cast the integer with arrow.
+            let mut builder = ColumnBuilder::<i64>::with_capacity(size);
+            let mut bitmap = new_mutable_bitmap(size, true);
+            for (row, v) in c.iter().enumerate() {
+                let v = div_round(*v, decimal.scale_factor::<T>()).to_i256();
+                match i128::try_from_i256(v).and_then(|v| i64::try_from(v).ok()) {
+                    Some(v) => builder.append(v),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            let result = builder.build(size);
+            let (result, valids) =
+                arrow_cast_compute(&result, &Int64Type::arc(), data_type, cast_options)?;
+            Ok((result, combine_validities_2(valids, Some(bitmap.into()))))
+        }
+
+        _ => arrow_cast_compute(column, from_type, data_type, cast_options),
+    }
+}
+
+pub fn cast_to_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+    match decimal.is_decimal256() {
+        true => cast_to_decimal_values::<I256>(column, from_type, data_type, cast_options),
+        false => cast_to_decimal_values::<i128>(column, from_type, data_type, cast_options),
+    }
+}
+
+fn cast_to_decimal_values<T: DecimalNative>(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+    let from_id = from_type.data_type_id();
+
+    if from_id.is_floating() {
+        let (c, valids) = arrow_cast_compute(column, from_type, &Float64Type::arc(), cast_options)?;
+        let c = Series::remove_nullable(&c);
+        let c: &Float64Column = Series::check_get(&c)?;
+        let size = c.len();
+
+        let it = c.iter().map(|v| decimal.from_f64::<T>(*v));
+        let (result, bitmap) = build_decimal_column(it, size);
+        return Ok((result, combine_validities_2(valids, Some(bitmap))));
+    }
+
+    if from_id.is_integer() || from_id == TypeID::Boolean {
+        // UInt64 doesn't fit in Int64, all the other integers do.
+        let (c, valids) = match from_id {
+            TypeID::UInt64 => {
+                let (c, valids) =
+                    arrow_cast_compute(column, from_type, &UInt64Type::arc(), cast_options)?;
+                let c = Series::remove_nullable(&c);
+                let c: &UInt64Column = Series::check_get(&c)?;
+                (c.iter().map(|v| *v as i128).collect::<Vec<_>>(), valids)
+            }
+            _ => {
+                let (c, valids) =
+                    arrow_cast_compute(column, from_type, &Int64Type::arc(), cast_options)?;
+                let c = Series::remove_nullable(&c);
+                let c: &Int64Column = Series::check_get(&c)?;
+                (c.iter().map(|v| *v as i128).collect::<Vec<_>>(), valids)
+            }
+        };
+        let size = c.len();
+
+        let it = c.iter().map(|v| decimal.rescale(T::from_i128(*v), 0));
+        let (result, bitmap) = build_decimal_column(it, size);
+        return Ok((result, combine_validities_2(valids, Some(bitmap))));
+    }
+
+    Err(ErrorCode::BadDataValueType(format!(
+        "Cast error happens in casting from {:?} to {:?}",
+        from_type, data_type
+    )))
+}
+
+/// Build the decimal column from the value of each row, the rows failed to cast are invalid.
+pub fn build_decimal_column<T: DecimalNative>(
+    values: impl Iterator<Item = Result<T>>,
+    size: usize,
+) -> (ColumnRef, Bitmap) {
+    let mut builder = Vec::with_capacity(size);
+    let mut bitmap = new_mutable_bitmap(size, true);
+    for (row, v) in values.enumerate() {
+        match v {
+            Ok(v) => builder.push(v),
+            Err(_) => {
+                builder.push(T::ZERO);
+                bitmap.set(row, false);
+            }
+        }
+    }
+    (T::new_column(builder), bitmap.into())
+}
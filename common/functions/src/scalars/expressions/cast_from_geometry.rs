@@ -0,0 +1,42 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Geometries can only be casted to strings, which are the WKT of the geometries.
+pub fn cast_from_geometry(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let column = Series::remove_nullable(column);
+    if data_type.data_type_id() == TypeID::Geometry {
+        return Ok((column, None));
+    }
+
+    if data_type.data_type_id() != TypeID::String {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from {} to {}",
+            from_type.name(),
+            data_type.name()
+        )));
+    }
+
+    let serializer = from_type.create_serializer();
+    let values = serializer.serialize_column(&column)?;
+    Ok((Series::from_data(values), None))
+}
@@ -14,12 +14,18 @@
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::cast_from_decimal::build_decimal_column;
 use super::cast_with_type::arrow_cast_compute;
 use super::cast_with_type::new_mutable_bitmap;
 use super::cast_with_type::CastOptions;
@@ -66,9 +72,11 @@ pub fn cast_from_string(
 
         TypeID::DateTime32 => {
             let mut builder = ColumnBuilder::<u32>::with_capacity(size);
+            let datetime = data_type.as_any().downcast_ref::<DateTime32Type>().unwrap();
+            let tz = parse_timezone(datetime.tz())?;
 
             for (row, v) in str_column.iter().enumerate() {
-                match string_to_datetime(v) {
+                match string_to_datetime(v, &tz) {
                     Some(t) => {
                         builder.append(t.timestamp() as u32);
                     }
@@ -80,9 +88,10 @@ pub fn cast_from_string(
         TypeID::DateTime64 => {
             let mut builder = ColumnBuilder::<i64>::with_capacity(size);
             let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+            let tz = parse_timezone(datetime.tz())?;
 
             for (row, v) in str_column.iter().enumerate() {
-                match string_to_datetime64(v) {
+                match string_to_datetime(v, &tz) {
                     Some(d) => {
                         builder.append(datetime.from_nano_seconds(d.timestamp_nanos()));
                     }
@@ -91,24 +100,66 @@ pub fn cast_from_string(
             }
             Ok((builder.build(size), Some(bitmap.into())))
         }
+        TypeID::Decimal => {
+            let decimal = data_type.as_any().downcast_ref::<DecimalType>().unwrap();
+            let it = str_column.iter().map(|v| decimal.parse_value::<i128>(v));
+            let (result, bitmap) = build_decimal_column(it, size);
+            Ok((result, Some(bitmap)))
+        }
+        TypeID::Decimal256 => {
+            let decimal = data_type.as_any().downcast_ref::<DecimalType>().unwrap();
+            let it = str_column.iter().map(|v| decimal.parse_value::<I256>(v));
+            let (result, bitmap) = build_decimal_column(it, size);
+            Ok((result, Some(bitmap)))
+        }
+        TypeID::Bitmap => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+
+            for (row, v) in str_column.iter().enumerate() {
+                match bitmap_from_text(v) {
+                    Ok(b) => builder.append_value(bitmap_serialize(&b)),
+                    Err(_) => {
+                        builder.append_value(bitmap_serialize(&RoaringTreemap::new()));
+                        bitmap.set(row, false)
+                    }
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+        TypeID::Geometry => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+
+            for (row, v) in str_column.iter().enumerate() {
+                match Geometry::from_wkt(&String::from_utf8_lossy(v)) {
+                    Ok(g) => builder.append_value(g.to_wkb()),
+                    Err(_) => {
+                        builder.append_value(Geometry::Point(Coord::new(0.0, 0.0)).to_wkb());
+                        bitmap.set(row, false)
+                    }
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
         TypeID::Interval => todo!(),
         _ => arrow_cast_compute(column, from_type, data_type, cast_options),
     }
 }
 
-// currently use UTC by default
-// TODO support timezone
-#[inline]
-fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
-    let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
+fn parse_timezone(tz: Option<&String>) -> Result<Tz> {
+    match tz {
+        None => Ok(Tz::UTC),
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Invalid timezone: {:?}", tz))),
+    }
 }
 
-// TODO support timezone
+// The string is the local time of the timezone, the fraction of second is optional.
 #[inline]
-fn string_to_datetime64(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
+fn string_to_datetime(date_str: impl AsRef<[u8]>, tz: &Tz) -> Option<DateTime<Tz>> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S%.9f").ok())
+    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S%.f").ok())
+        .and_then(|t| tz.from_local_datetime(&t).single())
 }
 
 #[inline]
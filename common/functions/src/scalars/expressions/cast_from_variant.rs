@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use super::cast_from_string::cast_from_string;
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+/// Cast variant values to scalar types, JSON null and values which can't be
+/// represented in the target type become NULL.
+pub fn cast_from_variant(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    if data_type.data_type_id().is_variant() {
+        return arrow_cast_compute(column, from_type, data_type, cast_options);
+    }
+
+    let json_column = Series::remove_nullable(column);
+    let json_column: &JsonColumn = Series::check_get(&json_column)?;
+    let size = json_column.len();
+    let mut bitmap = new_mutable_bitmap(size, true);
+
+    if data_type.data_type_id() == TypeID::Boolean {
+        let mut builder = ColumnBuilder::<bool>::with_capacity(size);
+        for (row, v) in json_column.iter().enumerate() {
+            match v {
+                JsonValue::Bool(b) => builder.append(*b),
+                JsonValue::Number(n) => builder.append(n.as_f64().unwrap_or_default() != 0.0),
+                JsonValue::String(s) if s.eq_ignore_ascii_case("true") => builder.append(true),
+                JsonValue::String(s) if s.eq_ignore_ascii_case("false") => builder.append(false),
+                _ => {
+                    builder.append(false);
+                    bitmap.set(row, false);
+                }
+            }
+        }
+        return Ok((builder.build(size), Some(bitmap.into())));
+    }
+
+    // Other scalar types are casted from the text form of the values,
+    // strings are unquoted so '"2022-01-01"' can be casted to a date.
+    let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
+    for (row, v) in json_column.iter().enumerate() {
+        match v {
+            JsonValue::Null => {
+                builder.append(b"");
+                bitmap.set(row, false);
+            }
+            JsonValue::String(s) => builder.append(s.as_bytes()),
+            JsonValue::Bool(b) if data_type.data_type_id().is_numeric() => {
+                builder.append(if *b { b"1" } else { b"0" })
+            }
+            _ => builder.append(v.to_string().as_bytes()),
+        }
+    }
+    let str_column = builder.build(size);
+
+    let (result, valids) = if data_type.data_type_id().is_string() {
+        (str_column, None)
+    } else {
+        cast_from_string(&str_column, &StringType::arc(), data_type, cast_options)?
+    };
+
+    Ok((result, combine_validities_2(Some(bitmap.into()), valids)))
+}
@@ -24,11 +24,18 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use serde_json::Value as JsonValue;
 
+use super::cast_from_bitmap::cast_from_bitmap;
 use super::cast_from_datetimes::cast_from_date16;
 use super::cast_from_datetimes::cast_from_date32;
+use super::cast_from_decimal::cast_from_decimal;
+use super::cast_from_decimal::cast_to_decimal;
+use super::cast_from_geometry::cast_from_geometry;
 use super::cast_from_string::cast_from_string;
+use super::cast_from_variant::cast_from_variant;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_datetime32;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_datetime64;
+use crate::scalars::expressions::cast_from_datetimes::cast_from_seconds_to_timestamp;
+use crate::scalars::expressions::cast_from_datetimes::is_timestamp_from_seconds;
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub struct CastOptions {
@@ -93,8 +100,8 @@ pub fn cast_with_type(
     data_type: &DataTypePtr,
     cast_options: &CastOptions,
 ) -> Result<ColumnRef> {
-    // they are pyhsically the same type
-    if &column.data_type() == data_type {
+    // they are pyhsically the same type, decimal needs to be rescaled
+    if &column.data_type() == data_type && !remove_nullable(from_type).data_type_id().is_decimal() {
         return Ok(column.clone());
     }
 
@@ -140,6 +147,25 @@ pub fn cast_with_type(
         TypeID::DateTime64 => {
             cast_from_datetime64(column, &nonull_from_type, &nonull_data_type, cast_options)
         }
+        TypeID::Decimal | TypeID::Decimal256 => {
+            cast_from_decimal(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
+        TypeID::Variant => {
+            cast_from_variant(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
+        TypeID::Bitmap => cast_from_bitmap(column, &nonull_from_type, &nonull_data_type),
+        TypeID::Geometry => cast_from_geometry(column, &nonull_from_type, &nonull_data_type),
+        _ if is_timestamp_from_seconds(&nonull_from_type, &nonull_data_type) => {
+            cast_from_seconds_to_timestamp(
+                column,
+                &nonull_from_type,
+                &nonull_data_type,
+                cast_options,
+            )
+        }
+        _ if nonull_data_type.data_type_id().is_decimal() => {
+            cast_to_decimal(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
         // TypeID::Interval => arrow_cast_compute(column, &nonull_data_type, cast_options),
         _ => arrow_cast_compute(column, &nonull_from_type, &nonull_data_type, cast_options),
     }?;
@@ -34,6 +34,7 @@ impl ToCastFunction {
             "Boolean" => features.num_arguments(1).bool_function(),
             "DateTime" | "DateTime32" => features.variadic_arguments(1, 2),
             "DateTime64" => features.variadic_arguments(1, 3),
+            "Timestamp" => features.variadic_arguments(1, 2),
             _ => features.num_arguments(1),
         };
 
@@ -64,6 +65,7 @@ impl ToCastFunction {
             "DateTime",
             "DateTime32",
             "DateTime64",
+            "Timestamp",
         ];
 
         for name in names {
@@ -71,5 +73,11 @@ impl ToCastFunction {
 
             factory.register(&to_name, Self::cast_function_creator(name).unwrap());
         }
+
+        // toTimeZone(x, 'tz') is rewritten by the planner into a cast to Timestamp in the timezone.
+        factory.register(
+            "toTimeZone",
+            Self::cast_function_creator("Timestamp").unwrap(),
+        );
     }
 }
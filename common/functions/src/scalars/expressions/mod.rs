@@ -14,8 +14,12 @@
 
 mod binary;
 mod cast;
+mod cast_from_bitmap;
 mod cast_from_datetimes;
+mod cast_from_decimal;
+mod cast_from_geometry;
 mod cast_from_string;
+mod cast_from_variant;
 mod cast_with_type;
 mod ctx;
 mod expression;
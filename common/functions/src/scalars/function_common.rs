@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::DataTypePtr;
+use common_datavalues::TypeID;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -45,3 +46,23 @@ pub fn assert_date_or_datetime(data_type: &DataTypePtr) -> Result<()> {
     }
     Ok(())
 }
+
+pub fn assert_bitmap(data_type: &DataTypePtr) -> Result<()> {
+    if data_type.data_type_id() != TypeID::Bitmap {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Expected a bitmap type, but got {:?}",
+            data_type
+        )));
+    }
+    Ok(())
+}
+
+pub fn assert_geometry(data_type: &DataTypePtr) -> Result<()> {
+    if data_type.data_type_id() != TypeID::Geometry {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Expected a geometry type, but got {:?}",
+            data_type
+        )));
+    }
+    Ok(())
+}
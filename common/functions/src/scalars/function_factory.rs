@@ -22,19 +22,25 @@ use once_cell::sync::Lazy;
 
 use super::function::Function;
 use super::ArithmeticFunction;
+use super::ArraysFunction;
+use super::BitmapsFunction;
 use super::ComparisonFunction;
 use super::ConditionalFunction;
 use super::ContextFunction;
+use super::EncryptionFunction;
 use super::FunctionAdapter;
 use super::FunctionFeatures;
+use super::GeoFunction;
 use super::HashesFunction;
 use super::LogicFunction;
+use super::MapsFunction;
 use super::MathsFunction;
 use super::OtherFunction;
 use super::SemiStructuredFunction;
 use super::StringFunction;
 use super::ToCastFunction;
 use super::TupleClassFunction;
+use super::UrlFunction;
 use crate::scalars::DateFunction;
 use crate::scalars::UUIDFunction;
 
@@ -92,6 +98,10 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     let mut function_factory = FunctionFactory::create();
 
     ArithmeticFunction::register(&mut function_factory);
+    ArraysFunction::register(&mut function_factory);
+    MapsFunction::register(&mut function_factory);
+    BitmapsFunction::register(&mut function_factory);
+    GeoFunction::register(&mut function_factory);
     ToCastFunction::register(&mut function_factory);
     TupleClassFunction::register(&mut function_factory);
     ComparisonFunction::register(&mut function_factory);
@@ -99,11 +109,13 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     SemiStructuredFunction::register(&mut function_factory);
     StringFunction::register(&mut function_factory);
     HashesFunction::register(&mut function_factory);
+    EncryptionFunction::register(&mut function_factory);
     ConditionalFunction::register(&mut function_factory);
     LogicFunction::register(&mut function_factory);
     DateFunction::register(&mut function_factory);
     OtherFunction::register(&mut function_factory);
     UUIDFunction::register(&mut function_factory);
+    UrlFunction::register(&mut function_factory);
     MathsFunction::register(&mut function_factory);
 
     Arc::new(function_factory)
@@ -0,0 +1,41 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::geohash::GeohashDecodeFunction;
+use super::geohash::GeohashEncodeFunction;
+use super::haversine::HaversineFunction;
+use super::point_in_polygon::PointInPolygonFunction;
+use super::st_contains::StContainsFunction;
+use super::st_distance::StDistanceFunction;
+use super::st_point::StPointFunction;
+use super::st_text::StAsTextFunction;
+use super::st_text::StGeomFromTextFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct GeoFunction;
+
+impl GeoFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("st_point", StPointFunction::desc());
+        factory.register("st_geomfromtext", StGeomFromTextFunction::desc());
+        factory.register("st_astext", StAsTextFunction::desc());
+        factory.register("st_distance", StDistanceFunction::desc());
+        factory.register("st_contains", StContainsFunction::desc());
+        factory.register("haversine", HaversineFunction::desc());
+        factory.register("great_circle_distance", HaversineFunction::desc());
+        factory.register("geohash_encode", GeohashEncodeFunction::desc());
+        factory.register("geohash_decode", GeohashDecodeFunction::desc());
+        factory.register("point_in_polygon", PointInPolygonFunction::desc());
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+pub const GEOHASH_MAX_PRECISION: usize = 12;
+
+/// Decodes the geometries of a Geometry column.
+pub fn eval_geometries(column: &ColumnWithField) -> Result<Vec<Geometry>> {
+    let column = column.column().convert_full_column();
+    let column: &StringColumn = Series::check_get(&column)?;
+    column.iter().map(Geometry::from_wkb).collect()
+}
+
+/// The great circle distance in kilometers between two points on the earth, in degrees.
+pub fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Encodes the point into a geohash, the precision is clamped into [1, 12].
+pub fn geohash_encode(lon: f64, lat: f64, precision: usize) -> String {
+    let precision = precision.clamp(1, GEOHASH_MAX_PRECISION);
+    let mut lon_range = (-180.0, 180.0);
+    let mut lat_range = (-90.0, 90.0);
+
+    let mut hash = String::with_capacity(precision);
+    let mut is_lon = true;
+    while hash.len() < precision {
+        let mut idx = 0usize;
+        for _ in 0..5 {
+            let (range, value) = if is_lon {
+                (&mut lon_range, lon)
+            } else {
+                (&mut lat_range, lat)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            idx <<= 1;
+            if value >= mid {
+                idx |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+        hash.push(GEOHASH_ALPHABET[idx] as char);
+    }
+    hash
+}
+
+/// Decodes the geohash into the center (longitude, latitude) of its cell.
+pub fn geohash_decode(hash: &str) -> Result<(f64, f64)> {
+    let mut lon_range = (-180.0, 180.0);
+    let mut lat_range = (-90.0, 90.0);
+
+    let mut is_lon = true;
+    for ch in hash.bytes().take(GEOHASH_MAX_PRECISION) {
+        let idx = GEOHASH_ALPHABET
+            .iter()
+            .position(|c| *c == ch.to_ascii_lowercase())
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Invalid geohash '{}'", hash)))?;
+        for bit in (0..5).rev() {
+            let range = if is_lon {
+                &mut lon_range
+            } else {
+                &mut lat_range
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            if (idx >> bit) & 1 == 1 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+        }
+    }
+    Ok((
+        (lon_range.0 + lon_range.1) / 2.0,
+        (lat_range.0 + lat_range.1) / 2.0,
+    ))
+}
+
+/// The minimum euclidean distance between two geometries, 0 if they intersect.
+pub fn geometry_distance(a: &Geometry, b: &Geometry) -> f64 {
+    let contains_any =
+        |g: &Geometry, other: &Geometry| other.coords().iter().any(|c| contains_coord(g, c));
+    if contains_any(a, b) || contains_any(b, a) {
+        return 0.0;
+    }
+
+    let mut distance = f64::INFINITY;
+    for (p1, p2) in segments(a) {
+        for (q1, q2) in segments(b) {
+            distance = distance.min(segment_distance(&p1, &p2, &q1, &q2));
+        }
+    }
+    distance
+}
+
+/// Whether no point of `b` lies outside `a`.
+pub fn geometry_contains(a: &Geometry, b: &Geometry) -> bool {
+    if !b.coords().iter().all(|c| contains_coord(a, c)) {
+        return false;
+    }
+    // The vertices of b are in a, b may still leave a between two vertices
+    // if one of its edges crosses the boundary of a.
+    segments(a).iter().all(|(p1, p2)| {
+        segments(b)
+            .iter()
+            .all(|(q1, q2)| !segments_cross(p1, p2, q1, q2))
+    })
+}
+
+/// The segments of the geometry, a point is a segment of zero length.
+fn segments(g: &Geometry) -> Vec<(Coord, Coord)> {
+    let pairs = |coords: &[Coord]| coords.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>();
+    match g {
+        Geometry::Point(c) => vec![(*c, *c)],
+        Geometry::LineString(coords) => pairs(coords),
+        Geometry::Polygon(rings) => rings.iter().flat_map(|r| pairs(r)).collect(),
+    }
+}
+
+fn contains_coord(g: &Geometry, c: &Coord) -> bool {
+    match g {
+        Geometry::Point(p) => p == c,
+        Geometry::LineString(_) => segments(g).iter().any(|(a, b)| on_segment(c, a, b)),
+        Geometry::Polygon(rings) => {
+            let on_boundary = segments(g).iter().any(|(a, b)| on_segment(c, a, b));
+            on_boundary
+                || (ring_contains(&rings[0], c) && !rings[1..].iter().any(|r| ring_contains(r, c)))
+        }
+    }
+}
+
+/// Ray casting, whether the coordinate is strictly inside the closed ring.
+fn ring_contains(ring: &[Coord], c: &Coord) -> bool {
+    let mut inside = false;
+    for w in ring.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        if (a.y > c.y) != (b.y > c.y) && c.x < (b.x - a.x) * (c.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn cross(o: &Coord, a: &Coord, b: &Coord) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn on_segment(c: &Coord, a: &Coord, b: &Coord) -> bool {
+    cross(a, b, c) == 0.0
+        && c.x >= a.x.min(b.x)
+        && c.x <= a.x.max(b.x)
+        && c.y >= a.y.min(b.y)
+        && c.y <= a.y.max(b.y)
+}
+
+/// Whether the segments cross each other at a single point inside both of them.
+fn segments_cross(p1: &Coord, p2: &Coord, q1: &Coord, q2: &Coord) -> bool {
+    let d1 = cross(q1, q2, p1);
+    let d2 = cross(q1, q2, p2);
+    let d3 = cross(p1, p2, q1);
+    let d4 = cross(p1, p2, q2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+fn segments_intersect(p1: &Coord, p2: &Coord, q1: &Coord, q2: &Coord) -> bool {
+    segments_cross(p1, p2, q1, q2)
+        || on_segment(p1, q1, q2)
+        || on_segment(p2, q1, q2)
+        || on_segment(q1, p1, p2)
+        || on_segment(q2, p1, p2)
+}
+
+fn point_segment_distance(c: &Coord, a: &Coord, b: &Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((c.x - a.x) * dx + (c.y - a.y) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (c.x - (a.x + t * dx)).hypot(c.y - (a.y + t * dy))
+}
+
+fn segment_distance(p1: &Coord, p2: &Coord, q1: &Coord, q2: &Coord) -> f64 {
+    if segments_intersect(p1, p2, q1, q2) {
+        return 0.0;
+    }
+    point_segment_distance(p1, q1, q2)
+        .min(point_segment_distance(p2, q1, q2))
+        .min(point_segment_distance(q1, p1, p2))
+        .min(point_segment_distance(q2, p1, p2))
+}
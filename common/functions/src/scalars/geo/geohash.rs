@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::geo_common::geohash_decode;
+use super::geo_common::geohash_encode;
+use super::geo_common::GEOHASH_MAX_PRECISION;
+use crate::scalars::assert_numeric;
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `geohash_encode(lon, lat[, precision])` encodes the point into a geohash string,
+/// the precision is the length of the geohash in [1, 12], 12 by default.
+#[derive(Clone)]
+pub struct GeohashEncodeFunction {
+    display_name: String,
+}
+
+impl GeohashEncodeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GeohashEncodeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+impl Function for GeohashEncodeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in args {
+            assert_numeric(arg)?;
+        }
+        Ok(StringType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let lons = cast_column_field(&columns[0], &Float64Type::arc())?.convert_full_column();
+        let lons: &Float64Column = Series::check_get(&lons)?;
+        let lats = cast_column_field(&columns[1], &Float64Type::arc())?.convert_full_column();
+        let lats: &Float64Column = Series::check_get(&lats)?;
+        let precisions = match columns.len() {
+            3 => {
+                let column = cast_column_field(&columns[2], &Int64Type::arc())?;
+                let column = column.convert_full_column();
+                let column: &Int64Column = Series::check_get(&column)?;
+                column.scalar_iter().collect()
+            }
+            _ => vec![GEOHASH_MAX_PRECISION as i64; input_rows],
+        };
+
+        let values = lons
+            .scalar_iter()
+            .zip(lats.scalar_iter())
+            .zip(precisions.into_iter())
+            .map(|((lon, lat), precision)| {
+                // Out of range precisions fall back to the max precision.
+                let precision = match precision {
+                    1..=12 => precision as usize,
+                    _ => GEOHASH_MAX_PRECISION,
+                };
+                geohash_encode(lon, lat, precision)
+            })
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for GeohashEncodeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+/// `geohash_decode(hash)` decodes the geohash into the tuple (longitude, latitude)
+/// of the center of its cell.
+#[derive(Clone)]
+pub struct GeohashDecodeFunction {
+    display_name: String,
+}
+
+impl GeohashDecodeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GeohashDecodeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+
+    fn result_type() -> DataTypePtr {
+        Arc::new(StructType::create(
+            vec!["longitude".to_string(), "latitude".to_string()],
+            vec![Float64Type::arc(), Float64Type::arc()],
+        ))
+    }
+}
+
+impl Function for GeohashDecodeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        Ok(Self::result_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let column: &StringColumn = Series::check_get(&column)?;
+
+        let mut lons = Vec::with_capacity(input_rows);
+        let mut lats = Vec::with_capacity(input_rows);
+        for v in column.iter() {
+            let hash = std::str::from_utf8(v).map_err(|_| {
+                ErrorCode::BadArguments(format!("Invalid geohash '{}'", String::from_utf8_lossy(v)))
+            })?;
+            let (lon, lat) = geohash_decode(hash)?;
+            lons.push(lon);
+            lats.push(lat);
+        }
+
+        let values = vec![Series::from_data(lons), Series::from_data(lats)];
+        Ok(Arc::new(StructColumn::from_data(
+            values,
+            Self::result_type(),
+        )))
+    }
+}
+
+impl fmt::Display for GeohashDecodeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::geo_common::haversine;
+use crate::scalars::assert_numeric;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `haversine(lat1, lon1, lat2, lon2)` returns the great circle distance in kilometers
+/// between two points on the earth, the coordinates are in degrees.
+#[derive(Clone)]
+pub struct HaversineFunction {
+    display_name: String,
+}
+
+impl HaversineFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(HaversineFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(4))
+    }
+}
+
+impl Function for HaversineFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in args {
+            assert_numeric(arg)?;
+        }
+        Ok(Float64Type::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let columns = columns
+            .iter()
+            .map(|c| Ok(cast_column_field(c, &Float64Type::arc())?.convert_full_column()))
+            .collect::<Result<Vec<_>>>()?;
+        let columns = columns
+            .iter()
+            .map(Series::check_get::<Float64Column>)
+            .collect::<Result<Vec<_>>>()?;
+
+        let values = (0..input_rows)
+            .map(|row| {
+                let v = |i: usize| columns[i].values()[row];
+                haversine(v(0), v(1), v(2), v(3))
+            })
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for HaversineFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
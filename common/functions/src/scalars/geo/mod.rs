@@ -0,0 +1,35 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod geo_class;
+mod geo_common;
+mod geohash;
+mod haversine;
+mod point_in_polygon;
+mod st_contains;
+mod st_distance;
+mod st_point;
+mod st_text;
+
+pub use geo_class::GeoFunction;
+pub use geo_common::*;
+pub use geohash::GeohashDecodeFunction;
+pub use geohash::GeohashEncodeFunction;
+pub use haversine::HaversineFunction;
+pub use point_in_polygon::PointInPolygonFunction;
+pub use st_contains::StContainsFunction;
+pub use st_distance::StDistanceFunction;
+pub use st_point::StPointFunction;
+pub use st_text::StAsTextFunction;
+pub use st_text::StGeomFromTextFunction;
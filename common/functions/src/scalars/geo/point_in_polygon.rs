@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::geo_common::geometry_contains;
+use crate::scalars::default_column_cast;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `point_in_polygon((x, y), [(x1, y1), (x2, y2), ...])` checks whether the point lies in the
+/// polygon given by its vertices, the points on the boundary are in the polygon.
+/// The ring is closed implicitly, so the last vertex doesn't need to repeat the first one.
+#[derive(Clone)]
+pub struct PointInPolygonFunction {
+    display_name: String,
+}
+
+impl PointInPolygonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PointInPolygonFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .bool_function()
+                .num_arguments(2),
+        )
+    }
+
+    fn assert_coordinate(&self, data_type: &DataTypePtr) -> Result<()> {
+        let is_coordinate = match data_type.as_any().downcast_ref::<StructType>() {
+            Some(struct_type) => {
+                struct_type.types().len() == 2
+                    && struct_type
+                        .types()
+                        .iter()
+                        .all(|t| t.data_type_id().is_numeric())
+            }
+            None => false,
+        };
+        if !is_coordinate {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected a coordinate tuple (x, y) of numbers for {}, but got {}",
+                self.display_name,
+                data_type.name()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Function for PointInPolygonFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        self.assert_coordinate(args[0])?;
+        match args[1].as_any().downcast_ref::<ArrayType>() {
+            Some(array_type) => self.assert_coordinate(array_type.inner_type())?,
+            None => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected an array of coordinates for {}, but got {}",
+                    self.display_name,
+                    args[1].name()
+                )))
+            }
+        }
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let points = columns[0].column().convert_full_column();
+        let points = eval_coords(Series::check_get(&points)?)?;
+
+        let polygons = columns[1].column().convert_full_column();
+        let polygons: &ArrayColumn = Series::check_get(&polygons)?;
+        let vertices = eval_coords(Series::check_get(polygons.values())?)?;
+
+        let mut values = Vec::with_capacity(input_rows);
+        for (row, point) in points.into_iter().enumerate() {
+            let offset = polygons.offsets()[row] as usize;
+            let mut ring = vertices[offset..offset + polygons.size_at_index(row)].to_vec();
+            if ring.len() < 3 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The polygon of {} must have at least 3 vertices, but got {}",
+                    self.display_name,
+                    ring.len()
+                )));
+            }
+            if ring.first() != ring.last() {
+                ring.push(ring[0]);
+            }
+
+            let polygon = Geometry::Polygon(vec![ring]);
+            values.push(geometry_contains(&polygon, &Geometry::Point(point)));
+        }
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for PointInPolygonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+fn eval_coords(column: &StructColumn) -> Result<Vec<Coord>> {
+    let values = column
+        .values()
+        .iter()
+        .map(|c| Ok(default_column_cast(c, &Float64Type::arc())?.convert_full_column()))
+        .collect::<Result<Vec<_>>>()?;
+    let xs: &Float64Column = Series::check_get(&values[0])?;
+    let ys: &Float64Column = Series::check_get(&values[1])?;
+    Ok(xs
+        .scalar_iter()
+        .zip(ys.scalar_iter())
+        .map(|(x, y)| Coord::new(x, y))
+        .collect())
+}
@@ -0,0 +1,74 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::geo_common::eval_geometries;
+use super::geo_common::geometry_distance;
+use crate::scalars::assert_geometry;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The minimum euclidean distance between two geometries, e.g.
+/// `st_distance(st_point(0, 0), st_point(3, 4))` returns 5.
+#[derive(Clone)]
+pub struct StDistanceFunction {
+    display_name: String,
+}
+
+impl StDistanceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(StDistanceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for StDistanceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_geometry(args[0])?;
+        assert_geometry(args[1])?;
+        Ok(Float64Type::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let lhs = eval_geometries(&columns[0])?;
+        let rhs = eval_geometries(&columns[1])?;
+        let values = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(a, b)| geometry_distance(a, b))
+            .collect::<Vec<f64>>();
+        Ok(Series::from_data(values))
+    }
+}
+
+impl fmt::Display for StDistanceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
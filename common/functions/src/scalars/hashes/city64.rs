@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use naive_cityhash::cityhash64;
+
+use super::BaseHashFunction;
+
+pub type City64Function = BaseHashFunction<CityHasher64, u64>;
+
+/// The hasher of `cityHash64`, which hashes the values the same way as ClickHouse:
+/// strings with CityHash64 (v1.0.2), and numbers with intHash64 of their bits
+/// zero-extended to 64 bits.
+///
+/// Like the hasher of City64WithSeed, it is a wrapper of stateless hashes and only
+/// hashes one value.
+#[derive(Clone, Default)]
+pub struct CityHasher64 {
+    value: u64,
+}
+
+impl Hasher for CityHasher64 {
+    fn finish(&self) -> u64 {
+        self.value
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.value = cityhash64(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.value = int_hash64(i);
+    }
+}
+
+/// The 64-bit finalizer of MurmurHash3, known as intHash64 in ClickHouse.
+#[inline]
+fn int_hash64(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+    x ^= x >> 33;
+    x
+}
@@ -98,6 +98,8 @@ impl Function for City64WithSeedFunction {
                 | TypeID::DateTime32
                 | TypeID::DateTime64
                 | TypeID::Interval
+                | TypeID::Decimal
+                | TypeID::Decimal256
                 | TypeID::String
         ) {
             return Err(ErrorCode::IllegalDataType(format!(
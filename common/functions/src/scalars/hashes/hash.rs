@@ -12,13 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::hash_map::DefaultHasher;
-
+use siphasher::sip::SipHasher24;
 use twox_hash::XxHash32;
 use twox_hash::XxHash64;
 
 use super::BaseHashFunction;
 use crate::scalars::Blake3HashFunction;
+use crate::scalars::City64Function;
 use crate::scalars::City64WithSeedFunction;
 use crate::scalars::FunctionFactory;
 use crate::scalars::Md5HashFunction;
@@ -30,7 +30,8 @@ pub struct HashesFunction;
 
 pub type XxHash32Function = BaseHashFunction<XxHash32, u32>;
 pub type XxHash64Function = BaseHashFunction<XxHash64, u64>;
-pub type SipHash64Function = BaseHashFunction<DefaultHasher, u64>;
+// SipHash-2-4 with the zero key, the same as sipHash64 of ClickHouse.
+pub type SipHash64Function = BaseHashFunction<SipHasher24, u64>;
 
 impl HashesFunction {
     pub fn register(factory: &mut FunctionFactory) {
@@ -45,5 +46,6 @@ impl HashesFunction {
         factory.register("siphash64", SipHash64Function::desc());
         factory.register("siphash", SipHash64Function::desc());
         factory.register("city64WithSeed", City64WithSeedFunction::desc());
+        factory.register("cityHash64", City64Function::desc());
     }
 }
@@ -123,6 +123,13 @@ macro_rules! integer_impl {
 
 for_all_integer_types! { integer_impl}
 
+impl DFHash for i128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state);
+    }
+}
+
 impl DFHash for f32 {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod blake3hash;
+mod city64;
 mod city64_with_seed;
 mod hash;
 mod hash_base;
@@ -21,6 +22,7 @@ mod sha1hash;
 mod sha2hash;
 
 pub use blake3hash::Blake3HashFunction;
+pub use city64::City64Function;
 pub use city64_with_seed::City64WithSeedFunction;
 pub use hash::*;
 pub use hash_base::BaseHashFunction;
@@ -0,0 +1,109 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::aggregate_types;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Build a map from the key-value arguments, e.g. `map('a', 1, 'b', 2)`.
+/// The keys and the values are casted to their least super types.
+#[derive(Clone)]
+pub struct MapFunction {
+    display_name: String,
+}
+
+impl MapFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(2, usize::MAX),
+        )
+    }
+
+    fn key_value_types(&self, args: &[DataTypePtr]) -> Result<(DataTypePtr, DataTypePtr)> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function '{}' expects an even number of arguments, but got {}",
+                self.display_name.to_uppercase(),
+                args.len()
+            )));
+        }
+
+        let keys = args.iter().step_by(2).cloned().collect::<Vec<_>>();
+        let values = args.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+        Ok((aggregate_types(&keys)?, aggregate_types(&values)?))
+    }
+}
+
+impl Function for MapFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types = args.iter().map(|x| (*x).clone()).collect::<Vec<_>>();
+        let (key_type, value_type) = self.key_value_types(&types)?;
+        Ok(MapType::arc(key_type, value_type))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let (key_type, value_type) = self.key_value_types(&types)?;
+
+        let columns = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let data_type = if i % 2 == 0 { &key_type } else { &value_type };
+                Ok(cast_column_field(c, data_type)?.convert_full_column())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let map_type = MapType::arc(key_type, value_type);
+        let mut builder = MutableArrayColumn::with_capacity(map_type, input_rows);
+        for row in 0..input_rows {
+            for kv in columns.chunks(2) {
+                let entry = DataValue::Struct(vec![kv[0].get(row), kv[1].get(row)]);
+                builder.inner_column().append_data_value(entry)?;
+            }
+            builder.add_offset();
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for MapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::map::MapFunction;
+use super::map_entries::MapKeysFunction;
+use super::map_entries::MapValuesFunction;
+use super::map_from_arrays::MapFromArraysFunction;
+use super::map_get::MapGetFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct MapsFunction;
+
+impl MapsFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("map", MapFunction::desc());
+        factory.register("map_from_arrays", MapFromArraysFunction::desc());
+        factory.register("map_get", MapGetFunction::desc());
+        factory.register("map_keys", MapKeysFunction::desc());
+        factory.register("map_values", MapValuesFunction::desc());
+    }
+}
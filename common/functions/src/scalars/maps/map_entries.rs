@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub type MapKeysFunction = MapEntriesFunctionImpl<true>;
+
+pub type MapValuesFunction = MapEntriesFunctionImpl<false>;
+
+/// Get the keys or the values of a map as an array.
+#[derive(Clone)]
+pub struct MapEntriesFunctionImpl<const KEYS: bool> {
+    display_name: String,
+}
+
+impl<const KEYS: bool> MapEntriesFunctionImpl<KEYS> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapEntriesFunctionImpl::<KEYS> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+
+    fn entry_type(map_type: &MapType) -> &DataTypePtr {
+        if KEYS {
+            map_type.key_type()
+        } else {
+            map_type.value_type()
+        }
+    }
+}
+
+impl<const KEYS: bool> Function for MapEntriesFunctionImpl<KEYS> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args[0].as_any().downcast_ref::<MapType>() {
+            Some(map_type) => Ok(ArrayType::arc(Self::entry_type(map_type).clone())),
+            None => Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?})",
+                self.display_name.to_uppercase(),
+                args[0]
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let map_type: &MapType = columns[0].data_type().as_any().downcast_ref().unwrap();
+
+        // The keys and the values share the offsets of the map.
+        let values = entries.values()[if KEYS { 0 } else { 1 }].clone();
+        Ok(Arc::new(ArrayColumn::from_data(
+            ArrayType::arc(Self::entry_type(map_type).clone()),
+            map_column.offsets().to_vec().into(),
+            values,
+        )))
+    }
+}
+
+impl<const KEYS: bool> fmt::Display for MapEntriesFunctionImpl<KEYS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Build a map from an array of keys and an array of values,
+/// e.g. `map_from_arrays(array('a', 'b'), array(1, 2))`.
+#[derive(Clone)]
+pub struct MapFromArraysFunction {
+    display_name: String,
+}
+
+impl MapFromArraysFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapFromArraysFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for MapFromArraysFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match (
+            args[0].as_any().downcast_ref::<ArrayType>(),
+            args[1].as_any().downcast_ref::<ArrayType>(),
+        ) {
+            (Some(keys), Some(values)) => Ok(MapType::arc(
+                keys.inner_type().clone(),
+                values.inner_type().clone(),
+            )),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                self.display_name.to_uppercase(),
+                args[0],
+                args[1]
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let data_type = self.return_type(&[columns[0].data_type(), columns[1].data_type()])?;
+        let keys = columns[0].column().convert_full_column();
+        let values = columns[1].column().convert_full_column();
+
+        let mut builder = MutableArrayColumn::with_capacity(data_type, input_rows);
+        for row in 0..input_rows {
+            match (keys.get(row), values.get(row)) {
+                (DataValue::Array(keys), DataValue::Array(values))
+                    if keys.len() == values.len() =>
+                {
+                    for (k, v) in keys.into_iter().zip(values) {
+                        let entry = DataValue::Struct(vec![k, v]);
+                        builder.inner_column().append_data_value(entry)?;
+                    }
+                    builder.add_offset();
+                }
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The keys and the values of function '{}' must have the same size",
+                        self.display_name.to_uppercase()
+                    )))
+                }
+            }
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for MapFromArraysFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Get the value of a key in the map, e.g. `map_get(m, 'a')` or `m['a']`,
+/// NULL is returned if the key doesn't exist.
+#[derive(Clone)]
+pub struct MapGetFunction {
+    display_name: String,
+}
+
+impl MapGetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapGetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for MapGetFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args[0].as_any().downcast_ref::<MapType>() {
+            Some(map_type) => Ok(wrap_nullable(map_type.value_type())),
+            None => Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                self.display_name.to_uppercase(),
+                args[0],
+                args[1]
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let map_type: &MapType = columns[0].data_type().as_any().downcast_ref().unwrap();
+
+        // Cast the keys to look up to the key type, so they can be compared as values.
+        let lookup_keys = cast_column_field(&columns[1], map_type.key_type())?;
+
+        let mut builder = wrap_nullable(map_type.value_type()).create_mutable(input_rows);
+        for row in 0..input_rows {
+            let key = lookup_keys.get(row);
+            let offset = map_column.offsets()[row] as usize;
+            let value = (offset..offset + map_column.size_at_index(row))
+                .find(|i| entries.values()[0].get(*i) == key)
+                .map(|i| entries.values()[1].get(i))
+                .unwrap_or(DataValue::Null);
+            builder.append_data_value(value)?;
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for MapGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
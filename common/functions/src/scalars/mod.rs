@@ -13,10 +13,13 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitmaps;
 mod comparisons;
 mod conditionals;
 mod contexts;
 mod dates;
+mod encryptions;
 mod expressions;
 mod function;
 mod function_adapter;
@@ -24,20 +27,26 @@ mod function_common;
 mod function_factory;
 mod function_features;
 mod function_monotonic;
+mod geo;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod others;
 mod semi_structureds;
 mod strings;
 mod tuples;
+mod urls;
 mod uuids;
 
 pub use arithmetics::*;
+pub use arrays::*;
+pub use bitmaps::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use contexts::*;
 pub use dates::*;
+pub use encryptions::*;
 pub use expressions::*;
 pub use function::*;
 pub use function_adapter::FunctionAdapter;
@@ -45,11 +54,14 @@ pub use function_common::*;
 pub use function_factory::*;
 pub use function_features::FunctionFeatures;
 pub use function_monotonic::Monotonicity;
+pub use geo::*;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use others::*;
 pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
+pub use urls::*;
 pub use uuids::*;
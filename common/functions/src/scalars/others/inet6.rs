@@ -0,0 +1,184 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+#[doc(alias = "IPv6StringToNumFunction")]
+pub type Inet6AtonFunction = Inet6FunctionImpl<Inet6Aton, false>;
+
+#[doc(alias = "TryIPv6StringToNumFunction")]
+pub type TryInet6AtonFunction = Inet6FunctionImpl<Inet6Aton, true>;
+
+#[doc(alias = "IPv6NumToStringFunction")]
+pub type Inet6NtoaFunction = Inet6FunctionImpl<Inet6Ntoa, false>;
+
+#[doc(alias = "TryIPv6NumToStringFunction")]
+pub type TryInet6NtoaFunction = Inet6FunctionImpl<Inet6Ntoa, true>;
+
+/// `inet6_aton` and `inet6_ntoa`, as in MySQL.
+///
+/// The binary form of an address is in network byte order, 4 bytes for an IPv4 address
+/// and 16 bytes for an IPv6 address, so both kinds of addresses can be stored in one column.
+#[derive(Clone)]
+pub struct Inet6FunctionImpl<T, const SUPPRESS_PARSE_ERROR: bool> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+pub trait Inet6Converter {
+    /// Writes the converted address into `buf`, or returns the reason why the input is
+    /// not a valid address.
+    fn convert(input: &[u8], buf: &mut Vec<u8>) -> std::result::Result<(), String>;
+}
+
+#[derive(Clone)]
+pub struct Inet6Aton;
+
+impl Inet6Converter for Inet6Aton {
+    fn convert(input: &[u8], buf: &mut Vec<u8>) -> std::result::Result<(), String> {
+        let addr_str = String::from_utf8_lossy(input);
+        match addr_str.parse::<IpAddr>() {
+            Ok(IpAddr::V4(addr)) => buf.extend_from_slice(&addr.octets()),
+            Ok(IpAddr::V6(addr)) => buf.extend_from_slice(&addr.octets()),
+            Err(err) => {
+                return Err(format!(
+                    "Failed to parse '{}' into a IP address, {}",
+                    addr_str, err
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Inet6Ntoa;
+
+impl Inet6Converter for Inet6Ntoa {
+    fn convert(input: &[u8], buf: &mut Vec<u8>) -> std::result::Result<(), String> {
+        let addr_str = if let Ok(octets) = <[u8; 4]>::try_from(input) {
+            Ipv4Addr::from(octets).to_string()
+        } else if let Ok(octets) = <[u8; 16]>::try_from(input) {
+            Ipv6Addr::from(octets).to_string()
+        } else {
+            return Err(format!(
+                "Expected a 4 or 16 bytes IP address, but got {} bytes",
+                input.len()
+            ));
+        };
+        buf.extend_from_slice(addr_str.as_bytes());
+        Ok(())
+    }
+}
+
+impl<T, const SUPPRESS_PARSE_ERROR: bool> Inet6FunctionImpl<T, SUPPRESS_PARSE_ERROR>
+where T: Inet6Converter + Clone + Sync + Send + 'static
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Inet6FunctionImpl::<T, SUPPRESS_PARSE_ERROR> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        // The values of the null rows are not valid addresses, so we check the nulls
+        // ourselves instead of passing them through.
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl<T, const SUPPRESS_PARSE_ERROR: bool> Function for Inet6FunctionImpl<T, SUPPRESS_PARSE_ERROR>
+where T: Inet6Converter + Clone + Sync + Send + 'static
+{
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let input_type = remove_nullable(args[0]);
+        match input_type.data_type_id() {
+            TypeID::Null => return Ok(NullType::arc()),
+            TypeID::String => {}
+            _ => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null type, but got {}",
+                    args[0].name()
+                )))
+            }
+        }
+
+        if SUPPRESS_PARSE_ERROR || args[0].is_nullable() {
+            Ok(Arc::new(NullableType::create(StringType::arc())))
+        } else {
+            Ok(StringType::arc())
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].column().data_type_id() == TypeID::Null {
+            return NullType::arc().create_constant_column(&DataValue::Null, input_rows);
+        }
+
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+        let mut builder = NullableColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+        let mut buf = Vec::with_capacity(16);
+        for (i, input) in viewer.iter().enumerate() {
+            if viewer.null_at(i) {
+                builder.append_null();
+                continue;
+            }
+
+            buf.clear();
+            match T::convert(input, &mut buf) {
+                Ok(_) => builder.append(&buf, true),
+                Err(_) if SUPPRESS_PARSE_ERROR => builder.append_null(),
+                Err(cause) => return Err(ErrorCode::StrParseError(cause)),
+            }
+        }
+
+        let column = builder.build(input_rows);
+        if SUPPRESS_PARSE_ERROR || columns[0].data_type().is_nullable() {
+            Ok(column)
+        } else {
+            Ok(Series::remove_nullable(&column))
+        }
+    }
+}
+
+impl<T, const SUPPRESS_PARSE_ERROR: bool> fmt::Display
+    for Inet6FunctionImpl<T, SUPPRESS_PARSE_ERROR>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
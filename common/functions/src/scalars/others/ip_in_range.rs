@@ -0,0 +1,214 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// A network in the CIDR notation, e.g. `10.0.0.0/8` or `2001:db8::/32`.
+#[derive(Clone, Copy, Debug)]
+struct Cidr {
+    addr: IpAddr,
+    prefix_len: u32,
+}
+
+impl Cidr {
+    /// An address without the prefix length is a network of that single address.
+    fn parse(cidr: &[u8]) -> Result<Cidr> {
+        let cidr_str = String::from_utf8_lossy(cidr);
+        let (addr, prefix_len) = match cidr_str.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (cidr_str.as_ref(), None),
+        };
+
+        let addr = addr.parse::<IpAddr>().ok();
+        let max_len = match addr {
+            Some(IpAddr::V4(_)) => 32,
+            _ => 128,
+        };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len.parse::<u32>().ok(),
+            None => Some(max_len),
+        };
+
+        match (addr, prefix_len) {
+            (Some(addr), Some(prefix_len)) if prefix_len <= max_len => {
+                Ok(Cidr { addr, prefix_len })
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Failed to parse '{}' into a CIDR network",
+                cidr_str
+            ))),
+        }
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(network), IpAddr::V4(addr)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix_len).unwrap_or(0);
+                u32::from(network) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(addr)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix_len).unwrap_or(0);
+                u128::from(network) & mask == u128::from(addr) & mask
+            }
+            // An IPv4 address is in an IPv6 network as the IPv4-mapped address.
+            (IpAddr::V6(_), IpAddr::V4(addr)) => self.contains(IpAddr::V6(addr.to_ipv6_mapped())),
+            (IpAddr::V4(_), IpAddr::V6(addr)) => match addr.to_ipv4() {
+                Some(v4) if addr.segments()[..6] == [0, 0, 0, 0, 0, 0xffff] => {
+                    self.contains(IpAddr::V4(v4))
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// `ip_in_range(addr, cidr)` checks whether the address is in the network.
+///
+/// The address is either a string of an IPv4 or IPv6 address, or the number of an IPv4
+/// address as `inet_aton` returns. It is not in any network if it is not a valid address.
+#[derive(Clone)]
+pub struct IpInRangeFunction {
+    display_name: String,
+}
+
+impl IpInRangeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IpInRangeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        // The values of the null rows are not valid networks, so we check the nulls
+        // ourselves instead of passing them through.
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for IpInRangeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let addr_type = remove_nullable(args[0]).data_type_id();
+        let cidr_type = remove_nullable(args[1]).data_type_id();
+        if addr_type == TypeID::Null || cidr_type == TypeID::Null {
+            return Ok(NullType::arc());
+        }
+        if !addr_type.is_string() && !addr_type.is_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or integer type, but got {}",
+                args[0].name()
+            )));
+        }
+        if !cidr_type.is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string type, but got {}",
+                args[1].name()
+            )));
+        }
+
+        if args[0].is_nullable() || args[1].is_nullable() {
+            Ok(Arc::new(NullableType::create(BooleanType::arc())))
+        } else {
+            Ok(BooleanType::arc())
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns
+            .iter()
+            .any(|c| c.column().data_type_id() == TypeID::Null)
+        {
+            return NullType::arc().create_constant_column(&DataValue::Null, input_rows);
+        }
+
+        // The network is a constant in most cases, so parse it only once.
+        let cidr_column = columns[1].column();
+        let mut const_cidr = None;
+        if cidr_column.is_const() && !cidr_column.null_at(0) {
+            const_cidr = Some(Cidr::parse(&cidr_column.get_string(0)?)?);
+        }
+        let cidrs = Vu8::try_create_viewer(cidr_column)?;
+
+        let mut builder = NullableColumnBuilder::<bool>::with_capacity(input_rows);
+        if remove_nullable(columns[0].data_type())
+            .data_type_id()
+            .is_string()
+        {
+            let addrs = Vu8::try_create_viewer(columns[0].column())?;
+            for (i, addr) in addrs.iter().enumerate() {
+                if addrs.null_at(i) || cidrs.null_at(i) {
+                    builder.append_null();
+                    continue;
+                }
+                let cidr = match const_cidr {
+                    Some(cidr) => cidr,
+                    None => Cidr::parse(cidrs.value_at(i))?,
+                };
+                let addr = String::from_utf8_lossy(addr).parse::<IpAddr>();
+                builder.append(addr.map_or(false, |addr| cidr.contains(addr)), true);
+            }
+        } else {
+            let mut to_type = UInt32Type::arc();
+            if columns[0].data_type().is_nullable() {
+                to_type = Arc::new(NullableType::create(to_type));
+            }
+            let addr_column = cast_column_field(&columns[0], &to_type)?;
+            let addrs = u32::try_create_viewer(&addr_column)?;
+            for (i, addr) in addrs.iter().enumerate() {
+                if addrs.null_at(i) || cidrs.null_at(i) {
+                    builder.append_null();
+                    continue;
+                }
+                let cidr = match const_cidr {
+                    Some(cidr) => cidr,
+                    None => Cidr::parse(cidrs.value_at(i))?,
+                };
+                builder.append(cidr.contains(IpAddr::V4(Ipv4Addr::from(addr))), true);
+            }
+        }
+
+        let column = builder.build(input_rows);
+        if columns.iter().any(|c| c.data_type().is_nullable()) {
+            Ok(column)
+        } else {
+            Ok(Series::remove_nullable(&column))
+        }
+    }
+}
+
+impl fmt::Display for IpInRangeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -14,20 +14,31 @@
 
 mod exists;
 mod ignore;
+mod inet6;
 mod inet_aton;
 mod inet_ntoa;
+mod ip_in_range;
 mod other;
 mod running_difference_function;
 mod sleep;
 mod to_type_name;
+mod udf_server;
+mod wasm_udf;
 
 pub use exists::ExistsFunction;
 pub use ignore::IgnoreFunction;
+pub use inet6::Inet6AtonFunction;
+pub use inet6::Inet6NtoaFunction;
+pub use inet6::TryInet6AtonFunction;
+pub use inet6::TryInet6NtoaFunction;
 pub use inet_aton::InetAtonFunction;
 pub use inet_aton::TryInetAtonFunction;
 pub use inet_ntoa::InetNtoaFunction;
 pub use inet_ntoa::TryInetNtoaFunction;
+pub use ip_in_range::IpInRangeFunction;
 pub use other::OtherFunction;
 pub use running_difference_function::RunningDifferenceFunction;
 pub use sleep::SleepFunction;
 pub use to_type_name::ToTypeNameFunction;
+pub use udf_server::UDFServerFunction;
+pub use wasm_udf::WasmUDFFunction;
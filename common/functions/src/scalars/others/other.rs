@@ -12,15 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::inet6::Inet6AtonFunction;
+use super::inet6::Inet6NtoaFunction;
+use super::inet6::TryInet6AtonFunction;
+use super::inet6::TryInet6NtoaFunction;
 use super::inet_aton::InetAtonFunction;
 use super::inet_aton::TryInetAtonFunction;
 use super::inet_ntoa::InetNtoaFunction;
 use super::inet_ntoa::TryInetNtoaFunction;
+use super::ip_in_range::IpInRangeFunction;
 use super::running_difference_function::RunningDifferenceFunction;
 use super::ExistsFunction;
 use super::IgnoreFunction;
 use super::SleepFunction;
 use super::ToTypeNameFunction;
+use super::UDFServerFunction;
+use super::WasmUDFFunction;
 use crate::scalars::FunctionFactory;
 
 #[derive(Clone)]
@@ -34,21 +41,49 @@ impl OtherFunction {
 
         factory.register("runningDifference", RunningDifferenceFunction::desc());
         factory.register("ignore", IgnoreFunction::desc());
+        factory.register("udf_server", UDFServerFunction::desc());
+        factory.register("wasm_udf", WasmUDFFunction::desc());
 
         // inet_aton
         factory.register("inet_aton", InetAtonFunction::desc());
         factory.register("IPv4StringToNum", InetAtonFunction::desc());
+        factory.register("ipv4_string_to_num", InetAtonFunction::desc());
 
         // try_inet_aton
         factory.register("try_inet_aton", TryInetAtonFunction::desc());
         factory.register("TryIPv4StringToNum", TryInetAtonFunction::desc());
+        factory.register("try_ipv4_string_to_num", TryInetAtonFunction::desc());
 
         // inet_ntoa
         factory.register("inet_ntoa", InetNtoaFunction::desc());
         factory.register("IPv4NumToString", InetNtoaFunction::desc());
+        factory.register("ipv4_num_to_string", InetNtoaFunction::desc());
 
         // try_inet_ntoa
         factory.register("try_inet_ntoa", TryInetNtoaFunction::desc());
         factory.register("TryIPv4NumToString", TryInetNtoaFunction::desc());
+        factory.register("try_ipv4_num_to_string", TryInetNtoaFunction::desc());
+
+        // inet6_aton
+        factory.register("inet6_aton", Inet6AtonFunction::desc());
+        factory.register("IPv6StringToNum", Inet6AtonFunction::desc());
+        factory.register("ipv6_string_to_num", Inet6AtonFunction::desc());
+
+        // try_inet6_aton
+        factory.register("try_inet6_aton", TryInet6AtonFunction::desc());
+        factory.register("TryIPv6StringToNum", TryInet6AtonFunction::desc());
+        factory.register("try_ipv6_string_to_num", TryInet6AtonFunction::desc());
+
+        // inet6_ntoa
+        factory.register("inet6_ntoa", Inet6NtoaFunction::desc());
+        factory.register("IPv6NumToString", Inet6NtoaFunction::desc());
+        factory.register("ipv6_num_to_string", Inet6NtoaFunction::desc());
+
+        // try_inet6_ntoa
+        factory.register("try_inet6_ntoa", TryInet6NtoaFunction::desc());
+        factory.register("TryIPv6NumToString", TryInet6NtoaFunction::desc());
+        factory.register("try_ipv6_num_to_string", TryInet6NtoaFunction::desc());
+
+        factory.register("ip_in_range", IpInRangeFunction::desc());
     }
 }
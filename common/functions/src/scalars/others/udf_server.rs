@@ -0,0 +1,269 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fmt;
+use std::sync::mpsc;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::deserialize_schemas;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow_format::flight::data::flight_descriptor::DescriptorType;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_base::tokio;
+use common_base::Runtime;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use futures::StreamExt;
+use once_cell::sync::Lazy;
+use tonic::transport::Channel;
+use tonic::transport::Endpoint;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+// The runtime calling the UDF servers, shared by all the queries of the node.
+static UDF_SERVER_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    Runtime::with_worker_threads(2, Some("udf-server-client".to_string()))
+        .expect("The runtime of the UDF server clients must be created")
+});
+
+// The clients of the UDF servers keyed by their addresses, the calls share the connection.
+static UDF_SERVER_CLIENTS: Lazy<RwLock<HashMap<String, FlightServiceClient<Channel>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+// The addresses of the UDF servers which may be called, see `set_allowed_addresses`.
+static UDF_SERVER_ALLOWED_ADDRESSES: Lazy<RwLock<Vec<String>>> = Lazy::new(|| RwLock::new(vec![]));
+
+/// Calls the handler of an external UDF server, e.g.
+/// `udf_server('127.0.0.1:8815', 'gcd', 'Int64', a, b)`.
+///
+/// The arguments after the address, the handler and the return type are sent as an Arrow
+/// record batch by the Flight `DoExchange` call whose descriptor path is the handler, the
+/// server replies with record batches of one column having the same number of rows.
+/// The address, the handler and the return type must be constants, they are resolved when
+/// the expression is planned, see `get_scalar_function`. Only the addresses allowed by the
+/// `udf_server_allow_list` of the config may be called.
+#[derive(Clone)]
+pub struct UDFServerFunction {
+    display_name: String,
+    address: String,
+    handler: String,
+    return_type: DataTypePtr,
+}
+
+impl UDFServerFunction {
+    pub fn try_create(
+        display_name: &str,
+        address: &str,
+        handler: &str,
+        return_type: &str,
+    ) -> Result<Box<dyn Function>> {
+        if !UDF_SERVER_ALLOWED_ADDRESSES
+            .read()
+            .iter()
+            .any(|allowed| allowed == address)
+        {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "The UDF server {} isn't in the udf_server_allow_list of the config",
+                address
+            )));
+        }
+
+        let return_type = TypeFactory::instance().get(return_type)?.clone();
+        Ok(Box::new(UDFServerFunction {
+            display_name: display_name.to_string(),
+            address: address.to_string(),
+            handler: handler.to_string(),
+            return_type,
+        }))
+    }
+
+    // Only called when the address, the handler or the return type isn't a constant.
+    fn try_create_non_constant(display_name: &str) -> Result<Box<dyn Function>> {
+        Err(ErrorCode::BadArguments(format!(
+            "The address, handler and return type of function '{}' must be constant strings",
+            display_name.to_uppercase()
+        )))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_non_constant))
+            .features(FunctionFeatures::default().variadic_arguments(3, usize::MAX))
+    }
+
+    /// Set the addresses of the UDF servers which may be called, `host:port` separated by
+    /// commas. No UDF server may be called if it's empty.
+    pub fn set_allowed_addresses(allow_list: &str) {
+        *UDF_SERVER_ALLOWED_ADDRESSES.write() = allow_list
+            .split(',')
+            .map(|address| address.trim().to_string())
+            .filter(|address| !address.is_empty())
+            .collect();
+    }
+
+    // The client is connected lazily on the first call, it must be created in the runtime.
+    fn client(address: &str) -> Result<FlightServiceClient<Channel>> {
+        if let Some(client) = UDF_SERVER_CLIENTS.read().get(address) {
+            return Ok(client.clone());
+        }
+
+        let cannot_connect = |cause: String| {
+            ErrorCode::CannotConnectNode(format!(
+                "Cannot connect to UDF server {}, cause: {}",
+                address, cause
+            ))
+        };
+        let channel = Endpoint::from_shared(format!("http://{}", address))
+            .map_err(|e| cannot_connect(e.to_string()))?
+            .connect_lazy()
+            .map_err(|e| cannot_connect(e.to_string()))?;
+        let client = FlightServiceClient::new(channel);
+        UDF_SERVER_CLIENTS
+            .write()
+            .insert(address.to_string(), client.clone());
+        Ok(client)
+    }
+
+    async fn exchange(
+        address: String,
+        handler: String,
+        input: DataBlock,
+        output_schema: DataSchemaRef,
+    ) -> Result<DataBlock> {
+        let mut client = Self::client(&address)?;
+
+        let arrow_schema = input.schema().to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let mut schema_data = serialize_schema(&arrow_schema, Some(&ipc_fields));
+        schema_data.flight_descriptor = Some(FlightDescriptor {
+            r#type: DescriptorType::Path as i32,
+            cmd: vec![],
+            path: vec![handler],
+        });
+
+        let chunk = input.try_into()?;
+        let options = WriteOptions { compression: None };
+        let (_, batch_data) = serialize_batch(&chunk, &ipc_fields, &options);
+
+        let request = futures::stream::iter(vec![schema_data, batch_data]);
+        let mut response = client.do_exchange(request).await?.into_inner();
+
+        let (fields, ipc_schema) = match response.next().await {
+            Some(schema_data) => {
+                let (schema, ipc_schema) = deserialize_schemas(&schema_data?.data_header)?;
+                (schema.fields, ipc_schema)
+            }
+            None => {
+                return Err(ErrorCode::UnexpectedResponseType(
+                    "The UDF server replied without a schema",
+                ))
+            }
+        };
+        if fields.len() != 1 {
+            return Err(ErrorCode::UnexpectedResponseType(format!(
+                "The UDF server must reply with one column, but got {}",
+                fields.len()
+            )));
+        }
+
+        let mut blocks = vec![];
+        while let Some(data) = response.next().await {
+            let data: FlightData = data?;
+            let chunk = deserialize_batch(&data, &fields, &ipc_schema, &Default::default())?;
+            blocks.push(DataBlock::from_chunk(&output_schema, &chunk)?);
+        }
+        match blocks.is_empty() {
+            true => Ok(DataBlock::empty_with_schema(output_schema)),
+            false => DataBlock::concat_blocks(&blocks),
+        }
+    }
+}
+
+impl Function for UDFServerFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        // Skip the address, the handler and the return type.
+        let arguments = &columns[3..];
+        let fields = arguments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| DataField::new(&format!("arg{}", i), c.data_type().clone()))
+            .collect::<Vec<_>>();
+        let values = arguments
+            .iter()
+            .map(|c| c.column().convert_full_column())
+            .collect::<Vec<_>>();
+        let input = DataBlock::create(DataSchemaRefExt::create(fields), values);
+        let output_schema =
+            DataSchemaRefExt::create(vec![DataField::new("result", self.return_type.clone())]);
+
+        // The call runs in the runtime of the clients, the function is evaluated synchronously.
+        let (tx, rx) = mpsc::sync_channel(1);
+        let exchange = Self::exchange(
+            self.address.clone(),
+            self.handler.clone(),
+            input,
+            output_schema,
+        );
+        UDF_SERVER_RUNTIME.try_spawn(async move {
+            let _ = tx.send(exchange.await);
+        })?;
+
+        // The worker of an async runtime evaluating the function is handed over to its other
+        // tasks while waiting.
+        let recv = || {
+            rx.recv()
+                .map_err(|e| ErrorCode::TokioError(format!("UDF server call failed, cause: {}", e)))
+        };
+        let output = match tokio::runtime::Handle::try_current() {
+            Ok(_) => tokio::task::block_in_place(recv),
+            Err(_) => recv(),
+        }??;
+
+        if output.num_rows() != input_rows {
+            return Err(ErrorCode::UnexpectedResponseType(format!(
+                "The UDF server handler {} returned {} rows, but {} rows were sent",
+                self.handler,
+                output.num_rows(),
+                input_rows
+            )));
+        }
+        Ok(output.column(0).clone())
+    }
+}
+
+impl fmt::Display for UDFServerFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryInto;
+use std::fmt;
+use std::io::Cursor;
+
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_cache::Cache;
+use common_cache::LruCache;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use once_cell::sync::Lazy;
+use sha2::Digest;
+use sha2::Sha256;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::Instance;
+use wasmtime::Module;
+use wasmtime::ResourceLimiter;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+// The linear memory a module may grow to on each call.
+const WASM_MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+// The instructions a module may run on each call, roughly.
+const WASM_MAX_FUEL: u64 = 10_000_000_000;
+// The compiled modules kept on the node, the least recently used one is evicted beyond it.
+const WASM_MAX_CACHED_MODULES: u64 = 64;
+
+static WASM_ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).expect("The WebAssembly engine must be created")
+});
+
+// The modules compiled on the node, keyed by the sha256 digest of their bytes.
+static WASM_MODULES: Lazy<RwLock<LruCache<String, Module>>> =
+    Lazy::new(|| RwLock::new(LruCache::new(WASM_MAX_CACHED_MODULES)));
+
+/// Calls the handler exported by the WebAssembly module of a WASM UDF, e.g.
+/// `wasm_udf('<module>', 'gcd', 'Int64', a, b)`.
+///
+/// The module runs in a sandbox: nothing is imported into it, so it can't reach the file
+/// system, the network or the clock, and each call is limited in memory and in fuel. The
+/// module must export:
+/// - `memory`, its linear memory.
+/// - `alloc(len: i32) -> i32`, allocating `len` bytes for the input.
+/// - `<handler>(ptr: i32, len: i32) -> i64`, reading the arguments as an Arrow IPC stream of
+///   the columns `arg0`, `arg1`, ..., and returning `(ptr << 32) | len` of an Arrow IPC stream
+///   of one column having the same number of rows.
+///
+/// The module is the base64 of its bytes, see `encode_module`. It's shipped in the expression, so
+/// that the function can be created on every node of the cluster, and each node compiles it once
+/// and keeps it by its digest. The module, the handler and the return type must be constants,
+/// see `get_scalar_function`.
+#[derive(Clone)]
+pub struct WasmUDFFunction {
+    display_name: String,
+    module: Module,
+    handler: String,
+    return_type: DataTypePtr,
+}
+
+impl WasmUDFFunction {
+    pub fn try_create(
+        display_name: &str,
+        module: &str,
+        handler: &str,
+        return_type: &str,
+    ) -> Result<Box<dyn Function>> {
+        let bytes = base64::decode(module).map_err(|e| {
+            ErrorCode::BadArguments(format!("Invalid WASM module encoding, cause: {}", e))
+        })?;
+        let module = Self::compile_module(&bytes)?;
+        let return_type = TypeFactory::instance().get(return_type)?.clone();
+        Ok(Box::new(WasmUDFFunction {
+            display_name: display_name.to_string(),
+            module,
+            handler: handler.to_string(),
+            return_type,
+        }))
+    }
+
+    // Only called when the module, the handler or the return type isn't a constant.
+    fn try_create_non_constant(display_name: &str) -> Result<Box<dyn Function>> {
+        Err(ErrorCode::BadArguments(format!(
+            "The module, handler and return type of function '{}' must be constant strings",
+            display_name.to_uppercase()
+        )))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_non_constant))
+            .features(FunctionFeatures::default().variadic_arguments(3, usize::MAX))
+    }
+
+    /// Check the module by compiling it, returns the module argument it's called by.
+    pub fn encode_module(bytes: &[u8]) -> Result<String> {
+        Self::compile_module(bytes)?;
+        Ok(base64::encode(bytes))
+    }
+
+    // The module is compiled once for the same bytes, unless it's evicted.
+    fn compile_module(bytes: &[u8]) -> Result<Module> {
+        let digest = hex::encode(Sha256::digest(bytes));
+        if let Some(module) = WASM_MODULES.write().get(&digest) {
+            return Ok(module.clone());
+        }
+
+        let module = Module::new(&WASM_ENGINE, bytes)
+            .map_err(|e| ErrorCode::BadArguments(format!("Invalid WASM module, cause: {}", e)))?;
+        WASM_MODULES.write().put(digest, module.clone());
+        Ok(module)
+    }
+
+    fn call(&self, input: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(WASM_MAX_MEMORY_BYTES)
+            .instances(1)
+            .build();
+        let mut store: Store<StoreLimits> = Store::new(&WASM_ENGINE, limits);
+        store.limiter(|limits| limits as &mut dyn ResourceLimiter);
+        store.add_fuel(WASM_MAX_FUEL)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| anyhow::anyhow!("The module doesn't export its memory"))?;
+        let alloc = instance.get_typed_func::<i32, i32, _>(&mut store, "alloc")?;
+        let handler = instance.get_typed_func::<(i32, i32), i64, _>(&mut store, &self.handler)?;
+
+        let input_len = i32::try_from(input.len())?;
+        let input_ptr = alloc.call(&mut store, input_len)?;
+        memory.write(&mut store, input_ptr as u32 as usize, input)?;
+
+        let output = handler.call(&mut store, (input_ptr, input_len))? as u64;
+        let (output_ptr, output_len) = ((output >> 32) as usize, (output as u32) as usize);
+        // The module returns the output range, it must be in its memory before it's allocated.
+        let memory_size = memory.data_size(&store);
+        if output_len > memory_size || output_ptr > memory_size - output_len {
+            anyhow::bail!(
+                "The output of {} bytes at {} is out of the module memory of {} bytes",
+                output_len,
+                output_ptr,
+                memory_size
+            );
+        }
+        let mut buffer = vec![0; output_len];
+        memory.read(&store, output_ptr, &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+impl Function for WasmUDFFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        // Skip the module, the handler and the return type.
+        let arguments = &columns[3..];
+        let fields = arguments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| DataField::new(&format!("arg{}", i), c.data_type().clone()))
+            .collect::<Vec<_>>();
+        let values = arguments
+            .iter()
+            .map(|c| c.column().convert_full_column())
+            .collect::<Vec<_>>();
+        let input = DataBlock::create(DataSchemaRefExt::create(fields), values);
+
+        let mut input_bytes = vec![];
+        {
+            let options = WriteOptions { compression: None };
+            let mut writer = StreamWriter::new(&mut input_bytes, options);
+            writer.start(&input.schema().to_arrow(), None)?;
+            writer.write(&input.try_into()?, None)?;
+            writer.finish()?;
+        }
+
+        let output_bytes = self.call(&input_bytes).map_err(|e| {
+            ErrorCode::UnexpectedError(format!(
+                "WASM UDF handler {} failed, cause: {}",
+                self.handler, e
+            ))
+        })?;
+
+        let output_schema =
+            DataSchemaRefExt::create(vec![DataField::new("result", self.return_type.clone())]);
+        let mut reader = Cursor::new(output_bytes);
+        let metadata = read_stream_metadata(&mut reader)?;
+        if metadata.schema.fields.len() != 1 {
+            return Err(ErrorCode::UnexpectedResponseType(format!(
+                "The WASM UDF handler {} must return one column, but got {}",
+                self.handler,
+                metadata.schema.fields.len()
+            )));
+        }
+
+        let mut blocks = vec![];
+        for state in StreamReader::new(reader, metadata) {
+            match state? {
+                StreamState::Some(chunk) => {
+                    blocks.push(DataBlock::from_chunk(&output_schema, &chunk)?)
+                }
+                StreamState::Waiting => break,
+            }
+        }
+        let output = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(output_schema),
+            false => DataBlock::concat_blocks(&blocks)?,
+        };
+
+        if output.num_rows() != input_rows {
+            return Err(ErrorCode::UnexpectedResponseType(format!(
+                "The WASM UDF handler {} returned {} rows, but {} rows were sent",
+                self.handler,
+                output.num_rows(),
+                input_rows
+            )));
+        }
+        Ok(output.column(0).clone())
+    }
+}
+
+impl fmt::Display for WasmUDFFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -24,9 +24,11 @@ use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
 use sqlparser::tokenizer::Tokenizer;
 
+use crate::scalars::ArrayGetFunction;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
+use crate::scalars::MapGetFunction;
 
 pub type GetFunction = GetFunctionImpl<false, false>;
 
@@ -63,15 +65,22 @@ impl<const BY_PATH: bool, const IGNORE_CASE: bool> Function
         let data_type = args[0];
         let path_type = args[1];
 
+        // `get` also accepts arrays and maps, e.g. `get(array(1, 2), 0)` and `m['k']`.
+        if !BY_PATH && !IGNORE_CASE && data_type.data_type_id() == TypeID::Array {
+            return ArrayGetFunction::try_create(&self.display_name)?.return_type(args);
+        }
+        if !BY_PATH && !IGNORE_CASE && data_type.data_type_id() == TypeID::Map {
+            return MapGetFunction::try_create(&self.display_name)?.return_type(args);
+        }
+
         if (IGNORE_CASE
             && (!data_type.data_type_id().is_variant_or_object()
                 || !path_type.data_type_id().is_string()))
-            || (BY_PATH
+            || (BY_PATH && (!is_json_type(data_type) || !path_type.data_type_id().is_string()))
+            || (!BY_PATH
                 && (!data_type.data_type_id().is_variant()
-                    || !path_type.data_type_id().is_string()))
-            || (!data_type.data_type_id().is_variant()
-                || (!path_type.data_type_id().is_string()
-                    && !path_type.data_type_id().is_unsigned_integer()))
+                    || (!path_type.data_type_id().is_string()
+                        && !path_type.data_type_id().is_unsigned_integer())))
         {
             return Err(ErrorCode::IllegalDataType(format!(
                 "Invalid argument types for function '{}': ({:?}, {:?})",
@@ -85,13 +94,21 @@ impl<const BY_PATH: bool, const IGNORE_CASE: bool> Function
     }
 
     fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if !BY_PATH && !IGNORE_CASE && columns[0].data_type().data_type_id() == TypeID::Array {
+            return ArrayGetFunction::try_create(&self.display_name)?.eval(columns, input_rows);
+        }
+        if !BY_PATH && !IGNORE_CASE && columns[0].data_type().data_type_id() == TypeID::Map {
+            return MapGetFunction::try_create(&self.display_name)?.eval(columns, input_rows);
+        }
+
         let path_keys = if BY_PATH {
             parse_path_keys(columns[1].column())?
         } else {
             build_path_keys(columns[1].column())?
         };
 
-        extract_value_by_path(columns[0].column(), path_keys, input_rows, IGNORE_CASE)
+        let column = to_json_column(columns[0].column())?;
+        extract_value_by_path(&column, path_keys, input_rows, IGNORE_CASE)
     }
 }
 
@@ -103,7 +120,29 @@ impl<const BY_PATH: bool, const IGNORE_CASE: bool> fmt::Display
     }
 }
 
-fn parse_path_keys(column: &ColumnRef) -> Result<Vec<Vec<DataValue>>> {
+/// Variants and strings of JSON text are both accepted as JSON arguments.
+pub(crate) fn is_json_type(data_type: &DataTypePtr) -> bool {
+    data_type.data_type_id().is_variant() || data_type.data_type_id().is_string()
+}
+
+/// Parses the strings of JSON text into a variant column, the variant columns are returned as is.
+pub(crate) fn to_json_column(column: &ColumnRef) -> Result<ColumnRef> {
+    if column.is_const() {
+        let const_column: &ConstColumn = Series::check_get(column)?;
+        let inner = to_json_column(const_column.inner())?;
+        return Ok(ConstColumn::new(inner, column.len()).arc());
+    }
+    if !column.data_type_id().is_string() {
+        return Ok(column.clone());
+    }
+
+    let values = StringType::arc()
+        .create_serializer()
+        .serialize_json_object(column, None)?;
+    Ok(JsonColumn::new_from_vec(values).arc())
+}
+
+pub(crate) fn parse_path_keys(column: &ColumnRef) -> Result<Vec<Vec<DataValue>>> {
     let column: &StringColumn = if column.is_const() {
         let const_column: &ConstColumn = Series::check_get(column)?;
         Series::check_get(const_column.inner())?
@@ -173,7 +212,7 @@ fn build_path_keys(column: &ColumnRef) -> Result<Vec<Vec<DataValue>>> {
     Ok(path_keys)
 }
 
-fn extract_value_by_path(
+pub(crate) fn extract_value_by_path(
     column: &ColumnRef,
     path_keys: Vec<Vec<DataValue>>,
     input_rows: usize,
@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use super::get::extract_value_by_path;
+use super::get::is_json_type;
+use super::get::parse_path_keys;
+use super::get::to_json_column;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `json_extract_path_text(json, path)` extracts the value by the path like `get_path` and returns
+/// it as text, the strings are unquoted. It's NULL if the path is not found or the value is null.
+#[derive(Clone)]
+pub struct JsonExtractPathTextFunction {
+    display_name: String,
+}
+
+impl JsonExtractPathTextFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonExtractPathTextFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for JsonExtractPathTextFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if !is_json_type(args[0]) || !args[1].data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                self.display_name.to_uppercase(),
+                args[0],
+                args[1]
+            )));
+        }
+
+        Ok(Arc::new(NullableType::create(StringType::arc())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let path_keys = parse_path_keys(columns[1].column())?;
+        let column = to_json_column(columns[0].column())?;
+        let values = extract_value_by_path(&column, path_keys, input_rows, false)?;
+
+        let mut builder = NullableColumnBuilder::<Vu8>::with_capacity(input_rows);
+        for row in 0..input_rows {
+            match values.get(row) {
+                DataValue::Json(JsonValue::Null) | DataValue::Null => builder.append_null(),
+                DataValue::Json(JsonValue::String(s)) => builder.append(s.as_bytes(), true),
+                DataValue::Json(v) => builder.append(v.to_string().as_bytes(), true),
+                other => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Expected a variant value in function '{}', but got {:?}",
+                        self.display_name.to_uppercase(),
+                        other
+                    )))
+                }
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for JsonExtractPathTextFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use super::get::is_json_type;
+use super::get::to_json_column;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `object_keys(obj)` returns the keys of the JSON object as a variant array, it's NULL if the
+/// value is not an object.
+#[derive(Clone)]
+pub struct ObjectKeysFunction {
+    display_name: String,
+}
+
+impl ObjectKeysFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ObjectKeysFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl Function for ObjectKeysFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if !is_json_type(args[0]) || args[0].data_type_id() == TypeID::VariantArray {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?})",
+                self.display_name.to_uppercase(),
+                args[0]
+            )));
+        }
+
+        Ok(Arc::new(NullableType::create(VariantType::arc())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = to_json_column(columns[0].column())?.convert_full_column();
+        let column: &JsonColumn = Series::check_get(&column)?;
+
+        let mut builder = NullableColumnBuilder::<JsonValue>::with_capacity(input_rows);
+        for v in column.iter() {
+            match v.as_object() {
+                Some(obj) => {
+                    let keys = obj.keys().cloned().map(JsonValue::String).collect();
+                    builder.append(&JsonValue::Array(keys), true);
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for ObjectKeysFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
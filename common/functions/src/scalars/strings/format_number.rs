@@ -0,0 +1,203 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num_traits::AsPrimitive;
+
+use super::FormatFunction;
+use crate::scalars::assert_numeric;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+const FORMAT_MAX_DECIMALS: usize = 30;
+
+// FORMAT_NUMBER(X, D) is FORMAT(X, D).
+// FORMAT_NUMBER(X, pattern) formats the number X by a pattern like '#,##0.00', where '0' is a digit
+// always shown, '#' is a digit shown if it's not a leading or trailing zero, ',' groups the integer
+// digits by three, and the text before and after the digits is kept, e.g. '$#,##0.00'.
+// The result doesn't depend on the locale.
+#[derive(Clone)]
+pub struct FormatNumberFunction {
+    display_name: String,
+}
+
+impl FormatNumberFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatNumberFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for FormatNumberFunction {
+    fn name(&self) -> &str {
+        "format_number"
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_numeric(args[0])?;
+        if !args[1].data_type_id().is_numeric() && !args[1].data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected the decimal places or a pattern string, but got {:?}",
+                args[1]
+            )));
+        }
+        Ok(Vu8::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[1].data_type().data_type_id().is_numeric() {
+            return FormatFunction::try_create(&self.display_name)?.eval(columns, input_rows);
+        }
+
+        with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$F| {
+            let col = scalar_binary_op::<$F, Vu8, Vu8, _>(
+                columns[0].column(),
+                columns[1].column(),
+                |number, pattern, ctx| {
+                    let number: f64 = number.as_();
+                    format_by_pattern(number, pattern, ctx)
+                },
+                &mut EvalContext::default(),
+            )?;
+            Ok(col.arc())
+        },{
+            unreachable!()
+        })
+    }
+}
+
+impl fmt::Display for FormatNumberFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FORMAT_NUMBER")
+    }
+}
+
+struct NumberPattern {
+    prefix: String,
+    suffix: String,
+    grouping: bool,
+    min_integer_digits: usize,
+    min_fraction_digits: usize,
+    max_fraction_digits: usize,
+}
+
+impl NumberPattern {
+    fn parse(pattern: &str) -> Result<NumberPattern> {
+        let is_digits = |c: char| matches!(c, '#' | '0' | ',' | '.');
+        let (start, end) = match (pattern.find(is_digits), pattern.rfind(is_digits)) {
+            (Some(start), Some(end)) => (start, end + 1),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid number pattern '{}', expected digits like '#,##0.00'",
+                    pattern
+                )))
+            }
+        };
+
+        let digits = &pattern[start..end];
+        if !digits.chars().all(is_digits) || digits.matches('.').count() > 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid number pattern '{}', expected digits like '#,##0.00'",
+                pattern
+            )));
+        }
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        Ok(NumberPattern {
+            prefix: pattern[..start].to_string(),
+            suffix: pattern[end..].to_string(),
+            grouping: integer.contains(','),
+            min_integer_digits: integer.matches('0').count(),
+            min_fraction_digits: fraction.matches('0').count(),
+            max_fraction_digits: fraction
+                .matches(|c| c == '0' || c == '#')
+                .count()
+                .min(FORMAT_MAX_DECIMALS),
+        })
+    }
+
+    fn format(&self, number: f64) -> String {
+        let rounded = format!("{:.*}", self.max_fraction_digits, number.abs());
+        let (integer, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+
+        let mut fraction = fraction.trim_end_matches('0').to_string();
+        while fraction.len() < self.min_fraction_digits {
+            fraction.push('0');
+        }
+
+        let integer = integer.trim_start_matches('0');
+        let mut integer = format!("{:0>1$}", integer, self.min_integer_digits);
+        if integer.is_empty() && fraction.is_empty() {
+            integer.push('0');
+        }
+        if self.grouping {
+            integer = group_by_thousands(&integer);
+        }
+
+        let is_zero = integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| !c.is_ascii_digit() || c == '0');
+        let mut result =
+            String::with_capacity(rounded.len() + self.prefix.len() + self.suffix.len() + 8);
+        if number < 0.0 && !is_zero {
+            result.push('-');
+        }
+        result.push_str(&self.prefix);
+        result.push_str(&integer);
+        if !fraction.is_empty() {
+            result.push('.');
+            result.push_str(&fraction);
+        }
+        result.push_str(&self.suffix);
+        result
+    }
+}
+
+fn group_by_thousands(integer: &str) -> String {
+    let len = integer.len();
+    let mut grouped = String::with_capacity(len + len / 3);
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (len - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+fn format_by_pattern(number: f64, pattern: &[u8], ctx: &mut EvalContext) -> Vec<u8> {
+    let pattern = String::from_utf8_lossy(pattern);
+    match NumberPattern::parse(&pattern) {
+        Ok(pattern) => pattern.format(number).into_bytes(),
+        Err(e) => {
+            ctx.set_error(e);
+            vec![]
+        }
+    }
+}
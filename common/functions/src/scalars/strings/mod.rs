@@ -25,6 +25,7 @@ mod export_set;
 mod field;
 mod find_in_set;
 mod format;
+mod format_number;
 mod hex;
 mod insert;
 mod leftright;
@@ -36,8 +37,12 @@ mod octet_length;
 mod ord;
 mod pad;
 mod quote;
+mod regexp_cache;
+mod regexp_extract;
 mod regexp_instr;
 mod regexp_like;
+mod regexp_replace;
+mod regexp_split_to_array;
 mod repeat;
 mod replace;
 mod reverse;
@@ -67,6 +72,7 @@ pub use export_set::ExportSetFunction;
 pub use field::FieldFunction;
 pub use find_in_set::FindInSetFunction;
 pub use format::FormatFunction;
+pub use format_number::FormatNumberFunction;
 pub use insert::InsertFunction;
 pub use leftright::LeftFunction;
 pub use leftright::RightFunction;
@@ -81,8 +87,11 @@ pub use ord::OrdFunction;
 pub use pad::LeftPadFunction;
 pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
+pub use regexp_extract::RegexpExtractFunction;
 pub use regexp_instr::RegexpInStrFunction;
 pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_split_to_array::RegexpSplitToArrayFunction;
 pub use repeat::RepeatFunction;
 pub use replace::ReplaceFunction;
 pub use reverse::ReverseFunction;
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_infallible::RwLock;
+use regex::bytes::Regex;
+
+use crate::scalars::strings::regexp_like::build_regexp_from_pattern;
+
+/// The cache is cleared once it holds this many patterns, so a pattern column with
+/// distinct values on every row doesn't grow it without bound.
+const REGEXP_CACHE_CAPACITY: usize = 1024;
+
+/// The compiled regular expressions of a function, keyed by the pattern and the match type.
+///
+/// A function is created once for every expression of a query and evaluated on all the
+/// blocks, so a pattern is compiled once per query instead of once per block or row.
+#[derive(Clone)]
+pub struct RegexpCache {
+    fn_name: String,
+    regexps: Arc<RwLock<HashMap<(Vec<u8>, Vec<u8>), Arc<Regex>>>>,
+}
+
+impl RegexpCache {
+    pub fn create(fn_name: &str) -> Self {
+        RegexpCache {
+            fn_name: fn_name.to_string(),
+            regexps: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn get(&self, pat: &[u8], mt: Option<&[u8]>) -> Result<Arc<Regex>> {
+        let key = (pat.to_vec(), mt.unwrap_or_default().to_vec());
+        if let Some(re) = self.regexps.read().get(&key) {
+            return Ok(re.clone());
+        }
+
+        let re = Arc::new(build_regexp_from_pattern(&self.fn_name, pat, mt)?);
+        let mut regexps = self.regexps.write();
+        if regexps.len() >= REGEXP_CACHE_CAPACITY {
+            regexps.clear();
+        }
+        regexps.insert(key, re.clone());
+        Ok(re)
+    }
+
+    /// The regexp shared by all the rows if the pattern and the match type are constants.
+    pub fn get_const(&self, pat: &ColumnRef, mt: &ColumnRef) -> Result<Option<Arc<Regex>>> {
+        if !pat.is_const() || !mt.is_const() {
+            return Ok(None);
+        }
+        let pat = pat.get_string(0)?;
+        let mt = mt.get_string(0)?;
+        Ok(Some(self.get(&pat, Some(&mt))?))
+    }
+}
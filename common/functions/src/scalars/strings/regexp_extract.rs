@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::strings::regexp_cache::RegexpCache;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `regexp_extract(expr, pat[, group[, match_type]])` returns the capture group of the
+/// first match, the whole match if the group is 0, an empty string if there is no match.
+#[derive(Clone)]
+pub struct RegexpExtractFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpExtractFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::create(display_name),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 4),
+        )
+    }
+}
+
+impl Function for RegexpExtractFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for (i, arg) in args.iter().enumerate() {
+            if i == 2 {
+                if !arg.data_type_id().is_integer() && !arg.data_type_id().is_null() {
+                    return Err(ErrorCode::IllegalDataType(format!(
+                        "Expected integer or null, but got {}",
+                        arg.data_type_id()
+                    )));
+                }
+            } else {
+                assert_string(*arg)?;
+            }
+        }
+
+        Ok(StringType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut group = ConstColumn::new(Series::from_data(vec![0_i64]), input_rows).arc();
+        let mut match_type = ConstColumn::new(Series::from_data(vec![""]), input_rows).arc();
+        if columns.len() > 2 {
+            group = cast_column_field(&columns[2], &Int64Type::arc())?;
+        }
+        if columns.len() > 3 {
+            match_type = cast_column_field(&columns[3], &StringType::arc())?;
+        }
+
+        let constant = self.cache.get_const(columns[1].column(), &match_type)?;
+
+        let source = Vu8::try_create_viewer(columns[0].column())?;
+        let pat = Vu8::try_create_viewer(columns[1].column())?;
+        let group = i64::try_create_viewer(&group)?;
+        let mt = Vu8::try_create_viewer(&match_type)?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        for (s_value, pat_value, group_value, mt_value) in izip!(source, pat, group, mt) {
+            let re = match &constant {
+                Some(re) => re.clone(),
+                None => self.cache.get(pat_value, Some(mt_value))?,
+            };
+
+            if group_value < 0 || group_value as usize >= re.captures_len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect arguments to {}: group index {} is out of range, the pattern has {} groups",
+                    self.name(),
+                    group_value,
+                    re.captures_len() - 1
+                )));
+            }
+
+            let extracted = re
+                .captures(s_value)
+                .and_then(|caps| caps.get(group_value as usize))
+                .map(|m| m.as_bytes())
+                .unwrap_or_default();
+            builder.append_value(extracted);
+        }
+
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for RegexpExtractFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
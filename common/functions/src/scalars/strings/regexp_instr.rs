@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
@@ -25,7 +24,7 @@ use regex::bytes::Regex;
 
 use crate::scalars::assert_string;
 use crate::scalars::cast_column_field;
-use crate::scalars::strings::regexp_like::build_regexp_from_pattern;
+use crate::scalars::strings::regexp_cache::RegexpCache;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
@@ -33,12 +32,14 @@ use crate::scalars::FunctionFeatures;
 #[derive(Clone)]
 pub struct RegexpInStrFunction {
     display_name: String,
+    cache: RegexpCache,
 }
 
 impl RegexpInStrFunction {
     pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
         Ok(Box::new(Self {
             display_name: display_name.to_string(),
+            cache: RegexpCache::create(display_name),
         }))
     }
 
@@ -133,7 +134,7 @@ impl RegexpInStrFunction {
         let occur = i64::try_create_viewer(occurrence)?;
         let ro = i64::try_create_viewer(return_option)?;
 
-        let re = build_regexp_from_pattern(self.name(), pat, Some(mt))?;
+        let re = self.cache.get(pat, Some(mt))?;
 
         let iter = izip!(source, pos, occur, ro);
         for (s_value, pos_value, occur_value, ro_value) in iter {
@@ -168,9 +169,6 @@ impl RegexpInStrFunction {
     ) -> Result<UInt64Column> {
         let mut builder: ColumnBuilder<u64> = ColumnBuilder::with_capacity(source.len());
 
-        let mut map: HashMap<Vec<u8>, Regex> = HashMap::new();
-        let mut key: Vec<u8> = Vec::new();
-
         let source = Vu8::try_create_viewer(source)?;
         let pat = Vu8::try_create_viewer(pat)?;
         let pos = i64::try_create_viewer(pos)?;
@@ -199,19 +197,8 @@ impl RegexpInStrFunction {
                 continue;
             }
 
-            key.extend_from_slice(pat_value);
-            key.extend_from_slice("-".as_bytes());
-            key.extend_from_slice(mt_value);
-            let re = if let Some(re) = map.get(&key) {
-                re
-            } else {
-                let re = build_regexp_from_pattern(self.name(), pat_value, Some(mt_value))?;
-                map.insert(key.clone(), re);
-                map.get(&key).unwrap()
-            };
-            key.clear();
-
-            let instr = regexp_instr(s_value, re, pos_value, occur_value, ro_value);
+            let re = self.cache.get(pat_value, Some(mt_value))?;
+            let instr = regexp_instr(s_value, &re, pos_value, occur_value, ro_value);
 
             builder.append(instr);
         }
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
@@ -25,6 +24,7 @@ use regex::bytes::Regex as BytesRegex;
 use regex::bytes::RegexBuilder as BytesRegexBuilder;
 
 use crate::scalars::assert_string;
+use crate::scalars::strings::regexp_cache::RegexpCache;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
@@ -32,12 +32,14 @@ use crate::scalars::FunctionFeatures;
 #[derive(Clone)]
 pub struct RegexpLikeFunction {
     display_name: String,
+    cache: RegexpCache,
 }
 
 impl RegexpLikeFunction {
     pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
         Ok(Box::new(Self {
             display_name: display_name.to_string(),
+            cache: RegexpCache::create(display_name),
         }))
     }
 
@@ -111,7 +113,7 @@ impl RegexpLikeFunction {
     ) -> Result<BooleanColumn> {
         let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(lhs.len());
 
-        let re = build_regexp_from_pattern(self.name(), rhs, mt)?;
+        let re = self.cache.get(rhs, mt)?;
 
         let lhs = Vu8::try_create_viewer(lhs)?;
         for lhs_value in lhs.iter() {
@@ -129,9 +131,6 @@ impl RegexpLikeFunction {
     ) -> Result<BooleanColumn> {
         let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(lhs.len());
 
-        let mut map = HashMap::new();
-        let mut key: Vec<u8> = Vec::new();
-
         let lhs = Vu8::try_create_viewer(lhs)?;
         let rhs = Vu8::try_create_viewer(rhs)?;
 
@@ -146,33 +145,12 @@ impl RegexpLikeFunction {
                         mt_value.to_str_lossy(),
                     )));
                 }
-                key.extend_from_slice(rhs_value);
-                key.extend_from_slice("-".as_bytes());
-                key.extend_from_slice(mt_value);
-
-                let pattern = if let Some(pattern) = map.get(&key) {
-                    pattern
-                } else {
-                    let re = build_regexp_from_pattern(self.name(), rhs_value, Some(mt_value))?;
-                    map.insert(key.clone(), re);
-                    map.get(&key).unwrap()
-                };
-                key.clear();
-
+                let pattern = self.cache.get(rhs_value, Some(mt_value))?;
                 builder.append(pattern.is_match(lhs_value));
             }
         } else {
             for (lhs_value, rhs_value) in lhs.zip(rhs) {
-                key.extend_from_slice(rhs_value);
-                let pattern = if let Some(pattern) = map.get(&key) {
-                    pattern
-                } else {
-                    let re = build_regexp_from_pattern(self.name(), rhs_value, None)?;
-                    map.insert(key.clone(), re);
-                    map.get(&key).unwrap()
-                };
-                key.clear();
-
+                let pattern = self.cache.get(rhs_value, None)?;
                 builder.append(pattern.is_match(lhs_value));
             }
         }
@@ -0,0 +1,146 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use regex::bytes::Regex;
+
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::strings::regexp_cache::RegexpCache;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::create(display_name),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(3, 6),
+        )
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for (i, arg) in args.iter().enumerate() {
+            if i < 3 || i == 5 {
+                assert_string(*arg)?;
+            } else if !arg.data_type_id().is_integer() && !arg.data_type_id().is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected integer or null, but got {}",
+                    arg.data_type_id()
+                )));
+            }
+        }
+
+        Ok(StringType::arc())
+    }
+
+    // Notes: https://dev.mysql.com/doc/refman/8.0/en/regexp.html#function_regexp-replace
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut pos = ConstColumn::new(Series::from_data(vec![1_i64]), input_rows).arc();
+        let mut occurrence = ConstColumn::new(Series::from_data(vec![0_i64]), input_rows).arc();
+        let mut match_type = ConstColumn::new(Series::from_data(vec![""]), input_rows).arc();
+
+        for i in 3..columns.len() {
+            match i {
+                3 => pos = cast_column_field(&columns[3], &Int64Type::arc())?,
+                4 => occurrence = cast_column_field(&columns[4], &Int64Type::arc())?,
+                _ => match_type = cast_column_field(&columns[5], &StringType::arc())?,
+            }
+        }
+
+        let constant = self.cache.get_const(columns[1].column(), &match_type)?;
+
+        let source = Vu8::try_create_viewer(columns[0].column())?;
+        let pat = Vu8::try_create_viewer(columns[1].column())?;
+        let repl = Vu8::try_create_viewer(columns[2].column())?;
+        let pos = i64::try_create_viewer(&pos)?;
+        let occur = i64::try_create_viewer(&occurrence)?;
+        let mt = Vu8::try_create_viewer(&match_type)?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        let iter = izip!(source, pat, repl, pos, occur, mt);
+        for (s_value, pat_value, repl_value, pos_value, occur_value, mt_value) in iter {
+            let re = match &constant {
+                Some(re) => re.clone(),
+                None => self.cache.get(pat_value, Some(mt_value))?,
+            };
+
+            buf.clear();
+            regexp_replace(s_value, &re, repl_value, pos_value, occur_value, &mut buf);
+            builder.append_value(&buf);
+        }
+
+        Ok(builder.to_column())
+    }
+}
+
+/// Replaces the matches starting at `pos`, all of them if `occur` is 0, otherwise only
+/// the `occur`th one. `$n` in `repl` refers to the nth capture group.
+#[inline]
+fn regexp_replace(s: &[u8], re: &Regex, repl: &[u8], pos: i64, occur: i64, buf: &mut Vec<u8>) {
+    let pos = if pos < 1 {
+        0
+    } else {
+        ((pos - 1) as usize).min(s.len())
+    };
+    let (prefix, rest) = s.split_at(pos);
+    buf.extend_from_slice(prefix);
+
+    if occur < 1 {
+        buf.extend_from_slice(&re.replace_all(rest, repl));
+        return;
+    }
+
+    match re.captures_iter(rest).nth((occur - 1) as usize) {
+        Some(caps) => {
+            let m = caps.get(0).unwrap();
+            buf.extend_from_slice(&rest[..m.start()]);
+            caps.expand(repl, buf);
+            buf.extend_from_slice(&rest[m.end()..]);
+        }
+        None => buf.extend_from_slice(rest),
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::strings::regexp_cache::RegexpCache;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `regexp_split_to_array(expr, pat[, match_type])` splits the string by the matches
+/// of the pattern, e.g. `regexp_split_to_array('a1b22c', '[0-9]+')` is `['a', 'b', 'c']`.
+#[derive(Clone)]
+pub struct RegexpSplitToArrayFunction {
+    display_name: String,
+    cache: RegexpCache,
+}
+
+impl RegexpSplitToArrayFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            cache: RegexpCache::create(display_name),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+impl Function for RegexpSplitToArrayFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in args {
+            assert_string(*arg)?;
+        }
+
+        Ok(ArrayType::arc(StringType::arc()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut match_type = ConstColumn::new(Series::from_data(vec![""]), input_rows).arc();
+        if columns.len() > 2 {
+            match_type = cast_column_field(&columns[2], &StringType::arc())?;
+        }
+
+        let constant = self.cache.get_const(columns[1].column(), &match_type)?;
+
+        let source = Vu8::try_create_viewer(columns[0].column())?;
+        let pat = Vu8::try_create_viewer(columns[1].column())?;
+        let mt = Vu8::try_create_viewer(&match_type)?;
+
+        let mut builder =
+            MutableArrayColumn::with_capacity(ArrayType::arc(StringType::arc()), input_rows);
+        for (s_value, pat_value, mt_value) in izip!(source, pat, mt) {
+            let re = match &constant {
+                Some(re) => re.clone(),
+                None => self.cache.get(pat_value, Some(mt_value))?,
+            };
+
+            let inner: &mut MutableStringColumn =
+                Series::check_get_mutable_column(builder.inner_column().as_mut())?;
+            for part in re.split(s_value) {
+                inner.append_value(part);
+            }
+            builder.add_offset();
+        }
+
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for RegexpSplitToArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -26,6 +26,7 @@ use crate::scalars::ExportSetFunction;
 use crate::scalars::FieldFunction;
 use crate::scalars::FindInSetFunction;
 use crate::scalars::FormatFunction;
+use crate::scalars::FormatNumberFunction;
 use crate::scalars::FunctionFactory;
 use crate::scalars::HexFunction;
 use crate::scalars::InsertFunction;
@@ -42,8 +43,11 @@ use crate::scalars::OrdFunction;
 use crate::scalars::PositionFunction;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpExtractFunction;
 use crate::scalars::RegexpInStrFunction;
 use crate::scalars::RegexpLikeFunction;
+use crate::scalars::RegexpReplaceFunction;
+use crate::scalars::RegexpSplitToArrayFunction;
 use crate::scalars::RepeatFunction;
 use crate::scalars::ReplaceFunction;
 use crate::scalars::ReverseFunction;
@@ -84,6 +88,9 @@ impl StringFunction {
         factory.register("length", LengthFunction::desc());
         factory.register("regexp_instr", RegexpInStrFunction::desc());
         factory.register("regexp_like", RegexpLikeFunction::desc());
+        factory.register("regexp_extract", RegexpExtractFunction::desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("regexp_split_to_array", RegexpSplitToArrayFunction::desc());
         factory.register("bin", BinFunction::desc());
         factory.register("oct", OctFunction::desc());
         factory.register("hex", HexFunction::desc());
@@ -103,6 +110,7 @@ impl StringFunction {
         factory.register("export_set", ExportSetFunction::desc());
         factory.register("find_in_set", FindInSetFunction::desc());
         factory.register("format", FormatFunction::desc());
+        factory.register("format_number", FormatNumberFunction::desc());
         factory.register("char", CharFunction::desc());
         factory.register("insert", InsertFunction::desc());
         factory.register("field", FieldFunction::desc());
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod struct_get;
 mod tuple;
 mod tuple_class;
 
+pub use struct_get::StructGetFunction;
 pub use tuple::TupleFunction;
 pub use tuple_class::TupleClassFunction;
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Access a field of a struct, e.g. `s.a`, `s:a` or `get(s, 'a')`.
+/// The field can be a name or a zero-based position, it must be a constant
+/// which is resolved when the expression is planned, so the return type is
+/// the type of the field.
+#[derive(Clone)]
+pub struct StructGetFunction {
+    display_name: String,
+    index: usize,
+    return_type: DataTypePtr,
+}
+
+impl StructGetFunction {
+    pub fn try_create(
+        display_name: &str,
+        data_type: &DataTypePtr,
+        field: &DataValue,
+    ) -> Result<Box<dyn Function>> {
+        let struct_type: &StructType = data_type.as_any().downcast_ref().ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!(
+                "Invalid argument type for function '{}': {:?}",
+                display_name.to_uppercase(),
+                data_type
+            ))
+        })?;
+
+        let index = match field {
+            DataValue::String(name) => {
+                let name = String::from_utf8_lossy(name);
+                struct_type.names().iter().position(|n| *n == name)
+            }
+            DataValue::UInt64(i) => Some(*i as usize),
+            DataValue::Int64(i) if *i >= 0 => Some(*i as usize),
+            _ => None,
+        };
+
+        match index {
+            Some(index) if index < struct_type.types().len() => Ok(Box::new(StructGetFunction {
+                display_name: display_name.to_string(),
+                index,
+                return_type: struct_type.types()[index].clone(),
+            })),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Struct with fields ({}) has no field {}",
+                struct_type.names().join(", "),
+                field
+            ))),
+        }
+    }
+}
+
+impl Function for StructGetFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column();
+        if column.is_const() {
+            let const_column: &ConstColumn = Series::check_get(column)?;
+            let struct_column: &StructColumn = Series::check_get(const_column.inner())?;
+            let value = struct_column.values()[self.index].clone();
+            return Ok(Arc::new(ConstColumn::new(value, input_rows)));
+        }
+
+        let struct_column: &StructColumn = Series::check_get(column)?;
+        Ok(struct_column.values()[self.index].clone())
+    }
+}
+
+impl fmt::Display for StructGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
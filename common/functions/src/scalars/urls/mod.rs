@@ -0,0 +1,27 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod url;
+mod url_codec;
+mod url_part;
+mod url_query_parameter;
+
+pub use url_codec::UrlDecodeFunction;
+pub use url_codec::UrlEncodeFunction;
+pub use url_part::ParseUrlFunction;
+pub use url_part::UrlPart;
+pub use url_part::UrlPartFunction;
+pub use url_query_parameter::UrlQueryParameterFunction;
+
+pub use self::url::UrlFunction;
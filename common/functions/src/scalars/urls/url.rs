@@ -0,0 +1,38 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ParseUrlFunction;
+use super::UrlDecodeFunction;
+use super::UrlEncodeFunction;
+use super::UrlPart;
+use super::UrlPartFunction;
+use super::UrlQueryParameterFunction;
+use crate::scalars::FunctionFactory;
+
+#[derive(Clone)]
+pub struct UrlFunction;
+
+impl UrlFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("parse_url", ParseUrlFunction::desc());
+        factory.register("url_protocol", UrlPartFunction::desc(UrlPart::Protocol));
+        factory.register("url_domain", UrlPartFunction::desc(UrlPart::Host));
+        factory.register("url_path", UrlPartFunction::desc(UrlPart::Path));
+        factory.register("url_query", UrlPartFunction::desc(UrlPart::Query));
+        factory.register("url_fragment", UrlPartFunction::desc(UrlPart::Ref));
+        factory.register("url_query_parameter", UrlQueryParameterFunction::desc());
+        factory.register("url_encode", UrlEncodeFunction::desc());
+        factory.register("url_decode", UrlDecodeFunction::desc());
+    }
+}
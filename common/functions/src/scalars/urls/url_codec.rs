@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::StringColumn;
+use common_exception::Result;
+use percent_encoding::percent_decode;
+use percent_encoding::percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+
+use crate::scalars::String2StringFunction;
+use crate::scalars::StringOperator;
+
+pub type UrlEncodeFunction = String2StringFunction<UrlEncode>;
+pub type UrlDecodeFunction = String2StringFunction<UrlDecode>;
+
+/// The characters to keep in `url_encode`, the unreserved characters of RFC 3986 as
+/// JavaScript's `encodeURIComponent` does.
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+#[derive(Clone, Default)]
+pub struct UrlEncode;
+
+impl StringOperator for UrlEncode {
+    #[inline]
+    fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
+        let mut len = 0;
+        for part in percent_encode(s, URL_COMPONENT) {
+            buffer[len..len + part.len()].copy_from_slice(part.as_bytes());
+            len += part.len();
+        }
+        Ok(len)
+    }
+
+    // Every byte is encoded into 3 bytes at most.
+    fn estimate_bytes(&self, array: &StringColumn) -> usize {
+        array.values().len() * 3
+    }
+}
+
+/// Decodes the `%XX` escapes, `+` is kept as it is like JavaScript's `decodeURIComponent`.
+#[derive(Clone, Default)]
+pub struct UrlDecode;
+
+impl StringOperator for UrlDecode {
+    #[inline]
+    fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
+        let mut len = 0;
+        for byte in percent_decode(s) {
+            buffer[len] = byte;
+            len += 1;
+        }
+        Ok(len)
+    }
+}
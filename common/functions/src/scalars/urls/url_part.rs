@@ -0,0 +1,249 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use url::Url;
+
+use crate::scalars::assert_string;
+use crate::scalars::function_factory::FactoryCreator;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The parts of a URL, named as the `part` argument of `parse_url`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UrlPart {
+    Protocol,
+    Host,
+    Path,
+    Query,
+    Ref,
+    File,
+    Authority,
+    UserInfo,
+}
+
+impl UrlPart {
+    pub fn parse(part: &[u8]) -> Result<UrlPart> {
+        match String::from_utf8_lossy(part).to_uppercase().as_str() {
+            "PROTOCOL" => Ok(UrlPart::Protocol),
+            "HOST" => Ok(UrlPart::Host),
+            "PATH" => Ok(UrlPart::Path),
+            "QUERY" => Ok(UrlPart::Query),
+            "REF" => Ok(UrlPart::Ref),
+            "FILE" => Ok(UrlPart::File),
+            "AUTHORITY" => Ok(UrlPart::Authority),
+            "USERINFO" => Ok(UrlPart::UserInfo),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Unsupported part of URL: {:?}, expected one of PROTOCOL, HOST, PATH, QUERY, REF, FILE, AUTHORITY, USERINFO",
+                other
+            ))),
+        }
+    }
+
+    /// Returns None if the URL doesn't have the part.
+    pub fn extract<'a>(&self, url: &'a Url) -> Option<Cow<'a, str>> {
+        match self {
+            UrlPart::Protocol => Some(Cow::Borrowed(url.scheme())),
+            UrlPart::Host => url.host_str().map(Cow::Borrowed),
+            UrlPart::Path => Some(Cow::Borrowed(url.path())),
+            UrlPart::Query => url.query().map(Cow::Borrowed),
+            UrlPart::Ref => url.fragment().map(Cow::Borrowed),
+            UrlPart::File => match url.query() {
+                Some(query) => Some(Cow::Owned(format!("{}?{}", url.path(), query))),
+                None => Some(Cow::Borrowed(url.path())),
+            },
+            UrlPart::Authority => {
+                let host = url.host_str()?;
+                let mut authority = String::with_capacity(host.len());
+                if let Some(user_info) = Self::user_info(url) {
+                    authority.push_str(&user_info);
+                    authority.push('@');
+                }
+                authority.push_str(host);
+                if let Some(port) = url.port() {
+                    authority.push_str(&format!(":{}", port));
+                }
+                Some(Cow::Owned(authority))
+            }
+            UrlPart::UserInfo => Self::user_info(url).map(Cow::Owned),
+        }
+    }
+
+    fn user_info(url: &Url) -> Option<String> {
+        match (url.username(), url.password()) {
+            ("", None) => None,
+            (username, None) => Some(username.to_string()),
+            (username, Some(password)) => Some(format!("{}:{}", username, password)),
+        }
+    }
+}
+
+/// Parses the URL of a row, the rows that are not absolute URLs are NULL.
+#[inline]
+pub(crate) fn parse_url(value: &[u8]) -> Option<Url> {
+    str::from_utf8(value).ok().and_then(|s| Url::parse(s).ok())
+}
+
+/// `url_domain(url)`, `url_path(url)` and so on, each extracts one part of the URL.
+///
+/// The result is NULL if the URL is not valid or it doesn't have the part.
+#[derive(Clone)]
+pub struct UrlPartFunction {
+    display_name: String,
+    part: UrlPart,
+}
+
+impl UrlPartFunction {
+    pub fn try_create(display_name: &str, part: UrlPart) -> Result<Box<dyn Function>> {
+        Ok(Box::new(UrlPartFunction {
+            display_name: display_name.to_string(),
+            part,
+        }))
+    }
+
+    pub fn desc(part: UrlPart) -> FunctionDescription {
+        let creator: FactoryCreator = Box::new(move |display_name| -> Result<Box<dyn Function>> {
+            UrlPartFunction::try_create(display_name, part)
+        });
+
+        FunctionDescription::creator(creator)
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl Function for UrlPartFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        Ok(Arc::new(NullableType::create(StringType::arc())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+        let mut builder = NullableColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+        for value in viewer.iter() {
+            match parse_url(value)
+                .as_ref()
+                .and_then(|url| self.part.extract(url))
+            {
+                Some(part) => builder.append(part.as_bytes(), true),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for UrlPartFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// `parse_url(url, part[, key])`, as in Hive.
+///
+/// The part is one of PROTOCOL, HOST, PATH, QUERY, REF, FILE, AUTHORITY and USERINFO. With a key,
+/// the QUERY part is the decoded value of the first query parameter with that name.
+#[derive(Clone)]
+pub struct ParseUrlFunction {
+    display_name: String,
+}
+
+impl ParseUrlFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseUrlFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+impl Function for ParseUrlFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in args {
+            assert_string(arg)?;
+        }
+        Ok(Arc::new(NullableType::create(StringType::arc())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let urls = Vu8::try_create_viewer(columns[0].column())?;
+        let parts = Vu8::try_create_viewer(columns[1].column())?;
+        let mut builder = NullableColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+
+        if columns.len() == 2 {
+            for (value, part) in izip!(urls.iter(), parts.iter()) {
+                let part = UrlPart::parse(part)?;
+                match parse_url(value).as_ref().and_then(|url| part.extract(url)) {
+                    Some(part) => builder.append(part.as_bytes(), true),
+                    None => builder.append_null(),
+                }
+            }
+            return Ok(builder.build(input_rows));
+        }
+
+        let keys = Vu8::try_create_viewer(columns[2].column())?;
+        for (value, part, key) in izip!(urls.iter(), parts.iter(), keys.iter()) {
+            if UrlPart::parse(part)? != UrlPart::Query {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The key of {} is only for the QUERY part, but got {}",
+                    self.display_name,
+                    String::from_utf8_lossy(part)
+                )));
+            }
+
+            match parse_url(value).and_then(|url| query_parameter(&url, key)) {
+                Some(value) => builder.append(value.as_bytes(), true),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for ParseUrlFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// The decoded value of the first query parameter named `key`.
+pub(crate) fn query_parameter(url: &Url, key: &[u8]) -> Option<String> {
+    url.query_pairs()
+        .find(|(name, _)| name.as_bytes() == key)
+        .map(|(_, value)| value.into_owned())
+}
@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use itertools::izip;
+
+use super::url_part::parse_url;
+use super::url_part::query_parameter;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `url_query_parameter(url, name)` extracts the decoded value of the first query
+/// parameter with the name, e.g. `url_query_parameter('https://a.com/?q=x%20y', 'q')` is 'x y'.
+///
+/// The result is NULL if the URL is not valid or it doesn't have the parameter.
+#[derive(Clone)]
+pub struct UrlQueryParameterFunction {
+    display_name: String,
+}
+
+impl UrlQueryParameterFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(UrlQueryParameterFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for UrlQueryParameterFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_string(args[1])?;
+        Ok(Arc::new(NullableType::create(StringType::arc())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let urls = Vu8::try_create_viewer(columns[0].column())?;
+        let names = Vu8::try_create_viewer(columns[1].column())?;
+
+        let mut builder = NullableColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+        for (value, name) in izip!(urls.iter(), names.iter()) {
+            match parse_url(value).and_then(|url| query_parameter(&url, name)) {
+                Some(value) => builder.append(value.as_bytes(), true),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for UrlQueryParameterFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -13,12 +13,16 @@
 // limitations under the License.
 
 mod uuid;
+mod uuid_converter;
 mod uuid_creator;
 mod uuid_verifier;
 
+pub use uuid_converter::BinToUUIDFunction;
+pub use uuid_converter::UUIDToBinFunction;
 pub use uuid_creator::UUIDZeroFunction;
 pub use uuid_creator::UUIDv4Function;
 pub use uuid_verifier::UUIDIsEmptyFunction;
 pub use uuid_verifier::UUIDIsNotEmptyFunction;
+pub use uuid_verifier::UUIDIsValidFunction;
 
 pub use self::uuid::UUIDFunction;
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::uuid_converter::BinToUUIDFunction;
+use super::uuid_converter::UUIDToBinFunction;
 use super::uuid_creator::UUIDZeroFunction;
 use super::uuid_creator::UUIDv4Function;
 use super::uuid_verifier::UUIDIsEmptyFunction;
 use super::uuid_verifier::UUIDIsNotEmptyFunction;
+use super::uuid_verifier::UUIDIsValidFunction;
 use crate::scalars::FunctionFactory;
 
 pub struct UUIDFunction;
@@ -26,5 +29,9 @@ impl UUIDFunction {
         factory.register("zeroUUID", UUIDZeroFunction::desc());
         factory.register("isemptyUUID", UUIDIsEmptyFunction::desc());
         factory.register("isnotemptyUUID", UUIDIsNotEmptyFunction::desc());
+        factory.register("gen_random_uuid", UUIDv4Function::desc());
+        factory.register("uuid_to_bin", UUIDToBinFunction::desc());
+        factory.register("bin_to_uuid", BinToUUIDFunction::desc());
+        factory.register("is_uuid", UUIDIsValidFunction::desc());
     }
 }
@@ -0,0 +1,167 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use uuid::Uuid;
+
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub type UUIDToBinFunction = UUIDConverterFunction<UUIDToBin>;
+pub type BinToUUIDFunction = UUIDConverterFunction<BinToUUID>;
+
+/// `uuid_to_bin(uuid[, swap_flag])` and `bin_to_uuid(bin[, swap_flag])`, as in MySQL.
+///
+/// If swap_flag is non-zero, the time-low and time-high parts of the UUID are swapped in
+/// the binary form, which makes the binary values of time-based UUIDs increase over time.
+#[derive(Clone, Debug)]
+pub struct UUIDConverterFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T> UUIDConverterFunction<T>
+where T: UUIDConverter + Clone + Sync + Send + 'static
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(UUIDConverterFunction::<T> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+impl<T> fmt::Display for UUIDConverterFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub trait UUIDConverter {
+    fn convert(fn_name: &str, value: &[u8], swap: bool, buf: &mut Vec<u8>) -> Result<()>;
+}
+
+#[derive(Clone, Debug)]
+pub struct UUIDToBin;
+
+impl UUIDConverter for UUIDToBin {
+    fn convert(fn_name: &str, value: &[u8], swap: bool, buf: &mut Vec<u8>) -> Result<()> {
+        let uuid = str::from_utf8(value)
+            .ok()
+            .and_then(|s| Uuid::parse_str(s).ok())
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Incorrect string value: '{}' for function {}",
+                    String::from_utf8_lossy(value),
+                    fn_name
+                ))
+            })?;
+
+        let bytes = uuid.as_bytes();
+        if swap {
+            buf.extend_from_slice(&bytes[6..8]);
+            buf.extend_from_slice(&bytes[4..6]);
+            buf.extend_from_slice(&bytes[0..4]);
+            buf.extend_from_slice(&bytes[8..16]);
+        } else {
+            buf.extend_from_slice(bytes);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BinToUUID;
+
+impl UUIDConverter for BinToUUID {
+    fn convert(fn_name: &str, value: &[u8], swap: bool, buf: &mut Vec<u8>) -> Result<()> {
+        if value.len() != 16 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Incorrect string value for function {}, expected 16 bytes, but got {}",
+                fn_name,
+                value.len()
+            )));
+        }
+
+        let mut bytes = [0u8; 16];
+        if swap {
+            bytes[0..4].copy_from_slice(&value[4..8]);
+            bytes[4..6].copy_from_slice(&value[2..4]);
+            bytes[6..8].copy_from_slice(&value[0..2]);
+            bytes[8..16].copy_from_slice(&value[8..16]);
+        } else {
+            bytes.copy_from_slice(value);
+        }
+        buf.extend_from_slice(Uuid::from_bytes(bytes).to_string().as_bytes());
+        Ok(())
+    }
+}
+
+impl<T> Function for UUIDConverterFunction<T>
+where T: UUIDConverter + Clone + Sync + Send + 'static
+{
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        if args.len() > 1 && !args[1].data_type_id().is_numeric() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected numeric, but got {}",
+                args[1].data_type_id()
+            )));
+        }
+
+        Ok(StringType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut swap_flag = ConstColumn::new(Series::from_data(vec![0_i64]), input_rows).arc();
+        if columns.len() > 1 {
+            swap_flag = cast_column_field(&columns[1], &Int64Type::arc())?;
+        }
+
+        let source = Vu8::try_create_viewer(columns[0].column())?;
+        let swap_flag = i64::try_create_viewer(&swap_flag)?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut buf = Vec::with_capacity(36);
+        for (value, swap) in izip!(source, swap_flag) {
+            buf.clear();
+            T::convert(self.name(), value, swap != 0, &mut buf)?;
+            builder.append_value(&buf);
+        }
+
+        Ok(builder.to_column())
+    }
+}
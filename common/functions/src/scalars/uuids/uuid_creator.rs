@@ -46,6 +46,8 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
         }))
     }
 
+    // Not deterministic, so the constant folding optimizer leaves it to be evaluated
+    // on every block.
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create))
             .features(FunctionFeatures::default())
@@ -60,6 +62,11 @@ impl<T> fmt::Display for UUIDCreatorFunction<T> {
 
 pub trait UUIDCreator {
     fn create() -> Uuid;
+
+    /// Whether every row gets its own UUID instead of one shared by the whole block.
+    fn is_volatile() -> bool {
+        false
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -69,6 +76,10 @@ impl UUIDCreator for UUIDv4 {
     fn create() -> Uuid {
         Uuid::new_v4()
     }
+
+    fn is_volatile() -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -99,6 +110,11 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
         _columns: &common_datavalues::ColumnsWithField,
         input_rows: usize,
     ) -> Result<common_datavalues::ColumnRef> {
+        if T::is_volatile() {
+            let uuids = (0..input_rows).map(|_| T::create().to_string());
+            return Ok(StringColumn::new_from_iter(uuids).arc());
+        }
+
         let uuid = T::create();
         let col = StringColumn::new_from_slice(vec![uuid.to_string()]);
 
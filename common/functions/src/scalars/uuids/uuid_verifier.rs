@@ -34,6 +34,7 @@ use crate::scalars::FunctionFeatures;
 
 pub type UUIDIsEmptyFunction = UUIDVerifierFunction<UUIDIsEmpty>;
 pub type UUIDIsNotEmptyFunction = UUIDVerifierFunction<UUIDIsNotEmpty>;
+pub type UUIDIsValidFunction = UUIDVerifierFunction<UUIDIsValid>;
 
 #[derive(Clone, Debug)]
 pub struct UUIDVerifierFunction<T> {
@@ -98,6 +99,20 @@ impl UUIDVerifier for UUIDIsNotEmpty {
     }
 }
 
+/// Whether the string is a valid UUID, an empty UUID is also a valid one.
+#[derive(Clone, Debug)]
+pub struct UUIDIsValid;
+
+impl UUIDVerifier for UUIDIsValid {
+    fn default_verify() -> bool {
+        false
+    }
+
+    fn verify(_uuid: Uuid) -> bool {
+        true
+    }
+}
+
 impl<T> Function for UUIDVerifierFunction<T>
 where T: UUIDVerifier + Clone + Sync + Send + 'static
 {
@@ -268,6 +268,48 @@ fn test_aggregate_function() -> Result<()> {
                 Vec::from([-1.25000f64]),
             )),
         },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<f64>::from_data(
+                f64::to_data_type(),
+                Vec::from([2.5f64]),
+            )),
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(0.25)],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<f64>::from_data(
+                f64::to_data_type(),
+                Vec::from([1.75f64]),
+            )),
+        },
+        Test {
+            name: "quantile-level-failed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(1.5)],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            error: "Code: 1006, displayText = quantile level must be in [0, 1], but got 1.5.",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+        },
         Test {
             name: "windowFunnel-passed",
             eval_nums: 2,
@@ -1068,3 +1110,391 @@ fn test_aggregate_function_on_boolean() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_aggregate_intersect_count() -> Result<()> {
+    let bitmaps = ["1,2,3", "2,3,4", "5", "3,5,6"]
+        .iter()
+        .map(|v| {
+            Ok(DataValue::String(bitmap_serialize(&bitmap_from_text(
+                v.as_bytes(),
+            )?)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let columns = vec![
+        BitmapType::arc().create_column(&bitmaps)?,
+        Series::from_data(vec!["a", "b", "a", "b"]),
+    ];
+    let args = vec![
+        DataField::new("bitmap", BitmapType::arc()),
+        DataField::new("tag", StringType::arc()),
+    ];
+
+    let tests = vec![
+        (vec![DataValue::String(b"a".to_vec())], 4u64),
+        (
+            vec![
+                DataValue::String(b"a".to_vec()),
+                DataValue::String(b"b".to_vec()),
+            ],
+            3u64,
+        ),
+        (
+            vec![
+                DataValue::String(b"a".to_vec()),
+                DataValue::String(b"c".to_vec()),
+            ],
+            0u64,
+        ),
+    ];
+
+    for (params, expect) in tests {
+        let arena = Bump::new();
+        let factory = AggregateFunctionFactory::instance();
+        let func = factory.get("intersect_count", params, args.clone())?;
+
+        // Accumulate the first two rows and the last two rows into different
+        // states, and merge them after a serialization round trip.
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate_row(addr1.into(), &columns, 0)?;
+        func.accumulate_row(addr1.into(), &columns, 1)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate_row(addr2.into(), &columns, 2)?;
+        func.accumulate_row(addr2.into(), &columns, 3)?;
+
+        let mut buf = bytes::BytesMut::new();
+        func.serialize(addr2.into(), &mut buf)?;
+        let addr3 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr3.into());
+        func.deserialize(addr3.into(), &mut buf.as_ref())?;
+        func.merge(addr1.into(), addr3.into())?;
+
+        let mut array = MutablePrimitiveColumn::<u64>::default();
+        func.merge_result(addr1.into(), &mut array)?;
+        assert_eq!(array.values(), &[expect]);
+    }
+
+    let result = AggregateFunctionFactory::instance().get("intersect_count", vec![], args);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "intersect_count expect to have at least one parameter"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_approx_count_distinct() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("a", u64::to_data_type())];
+    let func = factory.get("approx_count_distinct", vec![], args)?;
+
+    // Every value appears twice, and the two halves are accumulated into different
+    // states, which are merged after a serialization round trip.
+    let values = (0..20000u64).map(|v| v % 10000).collect::<Vec<_>>();
+    let lhs = vec![Series::from_data(values[..10000].to_vec())];
+    let rhs = vec![Series::from_data(values[10000..].to_vec())];
+
+    let addr1 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr1.into());
+    func.accumulate(addr1.into(), &lhs, None, 10000)?;
+
+    let addr2 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr2.into());
+    func.accumulate(addr2.into(), &rhs, None, 10000)?;
+
+    let mut buf = bytes::BytesMut::new();
+    func.serialize(addr2.into(), &mut buf)?;
+    let addr3 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr3.into());
+    func.deserialize(addr3.into(), &mut buf.as_ref())?;
+    func.merge(addr1.into(), addr3.into())?;
+
+    let mut array = MutablePrimitiveColumn::<u64>::default();
+    func.merge_result(addr1.into(), &mut array)?;
+    let estimate = array.values()[0] as f64;
+    assert!(
+        (estimate - 10000.0).abs() < 10000.0 * 0.05,
+        "estimate: {}",
+        estimate
+    );
+
+    // Nothing accumulated.
+    let addr4 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr4.into());
+    let mut array = MutablePrimitiveColumn::<u64>::default();
+    func.merge_result(addr4.into(), &mut array)?;
+    assert_eq!(array.values(), &[0]);
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_quantiles() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("a", u64::to_data_type())];
+    let params = vec![
+        DataValue::Float64(0.0),
+        DataValue::Float64(0.5),
+        DataValue::Float64(0.99),
+        DataValue::Float64(1.0),
+    ];
+
+    // The values 0..=10000 are shuffled across two states, which are merged after a
+    // serialization round trip.
+    let lhs = vec![Series::from_data(
+        (0..=10000u64).step_by(2).collect::<Vec<_>>(),
+    )];
+    let rhs = vec![Series::from_data(
+        (1..=10000u64).step_by(2).collect::<Vec<_>>(),
+    )];
+
+    for (name, tolerance) in [("quantiles", 1e-6), ("approx_percentiles", 100.0)] {
+        let func = factory.get(name, params.clone(), args.clone())?;
+        assert_eq!(
+            func.return_type()?,
+            ArrayType::arc(f64::to_data_type()),
+            "{}",
+            name
+        );
+
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), &lhs, None, lhs[0].len())?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate(addr2.into(), &rhs, None, rhs[0].len())?;
+
+        let mut buf = bytes::BytesMut::new();
+        func.serialize(addr2.into(), &mut buf)?;
+        let addr3 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr3.into());
+        func.deserialize(addr3.into(), &mut buf.as_ref())?;
+        func.merge(addr1.into(), addr3.into())?;
+
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr1.into(), array.as_mut())?;
+        let result = array.to_column().get(0);
+        let quantiles = match result {
+            DataValue::Array(values) => values
+                .iter()
+                .map(|v| v.as_f64())
+                .collect::<Result<Vec<_>>>()?,
+            other => panic!("unexpected result {:?}", other),
+        };
+
+        for (actual, expect) in quantiles.iter().zip([0.0, 5000.0, 9900.0, 10000.0]) {
+            assert!(
+                (actual - expect).abs() <= tolerance,
+                "{}: expect {}, got {}",
+                name,
+                expect,
+                actual
+            );
+        }
+    }
+
+    let func = factory.get("approx_percentile", vec![DataValue::Float64(0.9)], args)?;
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    func.accumulate(addr.into(), &lhs, None, lhs[0].len())?;
+    let mut array = MutablePrimitiveColumn::<f64>::default();
+    func.merge_result(addr.into(), &mut array)?;
+    assert!((array.values()[0] - 9000.0).abs() <= 100.0);
+
+    let result = factory.get("quantiles", vec![], vec![DataField::new(
+        "a",
+        u64::to_data_type(),
+    )]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "quantiles expect to have at least one parameter"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_array_agg_and_group_concat() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![
+        DataField::new("a", Vu8::to_data_type()),
+        DataField::new("b", i64::to_data_type()),
+    ];
+    let lhs = vec![
+        Series::from_data(vec!["c", "a"]),
+        Series::from_data(vec![3i64, 1]),
+    ];
+    let rhs = vec![
+        Series::from_data(vec!["d", "b"]),
+        Series::from_data(vec![4i64, 2]),
+    ];
+
+    let tests = vec![
+        (
+            "array_agg",
+            vec![],
+            DataValue::Array(vec![
+                DataValue::String(b"a".to_vec()),
+                DataValue::String(b"b".to_vec()),
+                DataValue::String(b"c".to_vec()),
+                DataValue::String(b"d".to_vec()),
+            ]),
+        ),
+        (
+            "group_concat",
+            vec![DataValue::String(b", ".to_vec())],
+            DataValue::String(b"a, b, c, d".to_vec()),
+        ),
+    ];
+
+    for (name, params, expect) in tests {
+        // The values are ordered by the second argument, after the states are merged
+        // with a serialization round trip.
+        let func = factory.get(name, params, args.clone())?;
+
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), &lhs, None, 2)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate(addr2.into(), &rhs, None, 2)?;
+
+        let mut buf = bytes::BytesMut::new();
+        func.serialize(addr2.into(), &mut buf)?;
+        let addr3 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr3.into());
+        func.deserialize(addr3.into(), &mut buf.as_ref())?;
+        func.merge(addr1.into(), addr3.into())?;
+
+        let mut builder = func.return_type()?.create_mutable(1);
+        func.merge_result(addr1.into(), builder.as_mut())?;
+        assert_eq!(builder.to_column().get(0), expect, "{}", name);
+    }
+
+    let result = factory.get("array_agg", vec![], vec![DataField::new(
+        "v",
+        VariantType::arc(),
+    )]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "array_agg does not support type 'Variant'"
+    );
+    Ok(())
+}
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct SumOfSquaresState {
+    sum: f64,
+    count: u64,
+}
+
+struct SumOfSquares;
+
+impl AggregateUDF for SumOfSquares {
+    type State = SumOfSquaresState;
+
+    fn return_type(&self, _params: &[DataValue], arguments: &[DataField]) -> Result<DataTypePtr> {
+        if arguments.len() != 1 || !arguments[0].data_type().data_type_id().is_numeric() {
+            return Err(common_exception::ErrorCode::BadArguments(
+                "sum_of_squares expects a numeric argument",
+            ));
+        }
+        Ok(f64::to_data_type())
+    }
+
+    fn accumulate_row(
+        &self,
+        state: &mut Self::State,
+        columns: &[ColumnRef],
+        row: usize,
+    ) -> Result<()> {
+        let value = columns[0].get_f64(row)?;
+        state.sum += value * value;
+        state.count += 1;
+        Ok(())
+    }
+
+    fn merge(&self, state: &mut Self::State, rhs: &Self::State) -> Result<()> {
+        state.sum += rhs.sum;
+        state.count += rhs.count;
+        Ok(())
+    }
+
+    fn finish(&self, state: &Self::State) -> Result<DataValue> {
+        Ok(DataValue::Float64(state.sum))
+    }
+}
+
+#[test]
+fn test_aggregate_udf() -> Result<()> {
+    let factory = AggregateFunctionFactory::instance();
+    factory.register_udaf(
+        "test_sum_of_squares",
+        SumOfSquares,
+        AggregateFunctionFeatures::default(),
+    )?;
+    assert!(factory.check("TEST_SUM_OF_SQUARES"));
+    assert!(factory.check("test_sum_of_squares_distinct"));
+
+    let result = factory.register_udaf("sum", SumOfSquares, AggregateFunctionFeatures::default());
+    assert_eq!(result.unwrap_err().message(), "Function sum already exists");
+
+    let column = Series::from_data(vec![1i64, 2, 2, 3]);
+    let field = DataField::new("a", i64::to_data_type());
+    let columns = [ColumnWithField::new(column.clone(), field.clone())];
+
+    let result = eval_aggr("test_sum_of_squares", vec![], &columns, 4)?;
+    assert_eq!(result.get(0), DataValue::Float64(18.0));
+    let result = eval_aggr("test_sum_of_squares_distinct", vec![], &columns, 4)?;
+    assert_eq!(result.get(0), DataValue::Float64(14.0));
+
+    // NULLs are skipped.
+    let nullable_column = Series::from_data(vec![Some(1i64), None, Some(3)]);
+    let nullable_field = DataField::new("a", wrap_nullable(&i64::to_data_type()));
+    let result = eval_aggr(
+        "test_sum_of_squares",
+        vec![],
+        &[ColumnWithField::new(nullable_column, nullable_field)],
+        3,
+    )?;
+    assert_eq!(result.get(0), DataValue::Float64(10.0));
+
+    // The partial states are merged after a serialization round trip, like in the two-phase
+    // aggregation.
+    let arena = Bump::new();
+    let func = factory.get("test_sum_of_squares", vec![], vec![field])?;
+    let addr1 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr1.into());
+    func.accumulate_row(addr1.into(), &[column.clone()], 0)?;
+
+    let addr2 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr2.into());
+    func.accumulate(addr2.into(), &[column], None, 4)?;
+
+    let mut buf = bytes::BytesMut::new();
+    func.serialize(addr2.into(), &mut buf)?;
+    let addr3 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr3.into());
+    func.deserialize(addr3.into(), &mut buf.as_ref())?;
+    func.merge(addr1.into(), addr3.into())?;
+
+    let mut array = MutablePrimitiveColumn::<f64>::default();
+    func.merge_result(addr1.into(), &mut array)?;
+    assert_eq!(array.values(), &[19.0]);
+
+    let result = factory.get("test_sum_of_squares", vec![], vec![DataField::new(
+        "s",
+        StringType::arc(),
+    )]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "sum_of_squares expects a numeric argument"
+    );
+    Ok(())
+}
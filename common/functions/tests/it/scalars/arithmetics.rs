@@ -18,7 +18,9 @@ use common_exception::Result;
 use common_functions::scalars::*;
 
 use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::test_scalar_functions_with_type;
 use super::scalar_function2_test::ScalarFunctionTest;
+use super::scalar_function2_test::ScalarFunctionWithFieldTest;
 
 #[test]
 fn test_arithmetic_function() -> Result<()> {
@@ -380,3 +382,175 @@ fn test_arithmetic_date_interval() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    let decimal_10_2 = DecimalType::arc(10, 2);
+    let decimal_10_3 = DecimalType::arc(10, 3);
+
+    let tests = vec![
+        (
+            ArithmeticPlusFunction::try_create_func("", &[&decimal_10_2, &decimal_10_3])?,
+            ScalarFunctionWithFieldTest {
+                name: "add-decimal-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![125i128, -100]),
+                        DataField::new("dummy_0", decimal_10_2.clone()),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![1i128, 2500]),
+                        DataField::new("dummy_1", decimal_10_3.clone()),
+                    ),
+                ],
+                expect: Series::from_data(vec![1251i128, 1500]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMinusFunction::try_create_func("", &[&decimal_10_2, &Int32Type::arc()])?,
+            ScalarFunctionWithFieldTest {
+                name: "sub-decimal-int-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![125i128, -100]),
+                        DataField::new("dummy_0", decimal_10_2.clone()),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![1i32, 2]),
+                        DataField::new("dummy_1", Int32Type::arc()),
+                    ),
+                ],
+                expect: Series::from_data(vec![25i128, -300]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMulFunction::try_create_func("", &[&decimal_10_2, &decimal_10_3])?,
+            ScalarFunctionWithFieldTest {
+                name: "mul-decimal-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![150i128, -200]),
+                        DataField::new("dummy_0", decimal_10_2.clone()),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![2500i128, 500]),
+                        DataField::new("dummy_1", decimal_10_3.clone()),
+                    ),
+                ],
+                expect: Series::from_data(vec![375000i128, -100000]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticDivFunction::try_create_func("", &[&decimal_10_2, &decimal_10_2])?,
+            ScalarFunctionWithFieldTest {
+                name: "div-decimal-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![300i128, 100]),
+                        DataField::new("dummy_0", decimal_10_2.clone()),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![200i128, 400]),
+                        DataField::new("dummy_1", decimal_10_2.clone()),
+                    ),
+                ],
+                expect: Series::from_data(vec![15_000_000_000_000i128, 2_500_000_000_000]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMulFunction::try_create_func("", &[
+                &DecimalType::arc(18, 10),
+                &DecimalType::arc(18, 10),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "mul-decimal-wide-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![12_345_678_901_234_567i128]),
+                        DataField::new("dummy_0", DecimalType::arc(18, 10)),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![20_000_000_000i128]),
+                        DataField::new("dummy_1", DecimalType::arc(18, 10)),
+                    ),
+                ],
+                expect: Series::from_data(vec![246_913_578_024_691_340_000_000_000i128]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticDivFunction::try_create_func("", &[&decimal_10_2, &decimal_10_2])?,
+            ScalarFunctionWithFieldTest {
+                name: "div-decimal-by-zero",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![100i128]),
+                        DataField::new("dummy_0", decimal_10_2.clone()),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![0i128]),
+                        DataField::new("dummy_1", decimal_10_2.clone()),
+                    ),
+                ],
+                expect: Series::from_data(vec![0i128]),
+                error: "Division by zero",
+            },
+        ),
+        (
+            ArithmeticPlusFunction::try_create_func("", &[
+                &DecimalType::arc(38, 0),
+                &DecimalType::arc(38, 0),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "add-decimal-overflow",
+                columns: vec![
+                    ColumnWithField::new(
+                        Series::from_data(vec![
+                            99_999_999_999_999_999_999_999_999_999_999_999_999i128,
+                        ]),
+                        DataField::new("dummy_0", DecimalType::arc(38, 0)),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![1i128]),
+                        DataField::new("dummy_1", DecimalType::arc(38, 0)),
+                    ),
+                ],
+                expect: Series::from_data(vec![0i128]),
+                error: "Decimal overflow: result of (plus) is out of range of Decimal(38, 0)",
+            },
+        ),
+        (
+            ArithmeticPlusFunction::try_create_func("", &[
+                &DecimalType::arc(40, 0),
+                &DecimalType::arc(38, 0),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "add-decimal256-passed",
+                columns: vec![
+                    ColumnWithField::new(
+                        I256::new_column(vec![I256::new(10).pow(39)]),
+                        DataField::new("dummy_0", DecimalType::arc(40, 0)),
+                    ),
+                    ColumnWithField::new(
+                        Series::from_data(vec![
+                            99_999_999_999_999_999_999_999_999_999_999_999_999i128,
+                        ]),
+                        DataField::new("dummy_1", DecimalType::arc(38, 0)),
+                    ),
+                ],
+                expect: I256::new_column(vec![I256::new(11) * I256::new(10).pow(38) - I256::ONE]),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_function, test) in tests {
+        test_scalar_functions_with_type(test_function, &[test], true)?
+    }
+
+    Ok(())
+}
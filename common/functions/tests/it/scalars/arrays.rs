@@ -0,0 +1,208 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_eval;
+use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::ScalarFunctionTest;
+
+fn new_array_column(values: Vec<Vec<i64>>) -> Result<ColumnRef> {
+    let data_type = ArrayType::arc(Int64Type::arc());
+    let mut builder = MutableArrayColumn::with_capacity(data_type, values.len());
+    for vals in values {
+        builder.append_value(vals.into_iter().map(DataValue::Int64).collect())?;
+    }
+    Ok(builder.to_column())
+}
+
+#[test]
+fn test_array_function() -> Result<()> {
+    let func = ArrayFunction::try_create("array")?;
+    let columns = vec![
+        Series::from_data(vec![1i64, 3]),
+        Series::from_data(vec![2i32, 4]),
+    ];
+    let result = test_eval(&func, &columns, false)?;
+    assert_eq!(result, new_array_column(vec![vec![1, 2], vec![3, 4]])?);
+    Ok(())
+}
+
+#[test]
+fn test_array_length_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_length",
+        columns: vec![new_array_column(vec![vec![1, 2], vec![], vec![3]])?],
+        expect: Series::from_data(vec![2u64, 0, 1]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        ArrayLengthFunction::try_create("array_length")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_array_get_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "array_get",
+            columns: vec![
+                new_array_column(vec![vec![1, 2], vec![], vec![3]])?,
+                Series::from_data(vec![1u64, 0, 0]),
+            ],
+            expect: Series::from_data(vec![Some(2i64), None, Some(3)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_get with invalid argument",
+            columns: vec![Series::from_data(vec![1i64]), Series::from_data(vec![0u64])],
+            expect: Series::from_data(vec![0i64]),
+            error: "Invalid argument types for function 'ARRAY_GET': (Int64, UInt64)",
+        },
+    ];
+
+    test_scalar_functions(ArrayGetFunction::try_create("array_get")?, &tests, true)
+}
+
+#[test]
+fn test_array_contains_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_contains",
+        columns: vec![
+            new_array_column(vec![vec![1, 2], vec![], vec![3]])?,
+            Series::from_data(vec![2u8, 2, 4]),
+        ],
+        expect: Series::from_data(vec![true, false, false]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        ArrayContainsFunction::try_create("array_contains")?,
+        &tests,
+        true,
+    )
+}
+
+// The lambdas are compiled from the expressions by the planner, any function on the
+// parameters works as a lambda here.
+fn lambda(name: &str, arg_types: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+    FunctionFactory::instance().get(name, arg_types)
+}
+
+#[test]
+fn test_array_map_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_map",
+        columns: vec![new_array_column(vec![vec![1, 2], vec![], vec![3]])?],
+        expect: new_array_column(vec![vec![-1, -2], vec![], vec![-3]])?,
+        error: "",
+    }];
+
+    let negate = lambda("negate", &[&Int64Type::arc()])?;
+    test_scalar_functions(
+        ArrayMapFunction::try_create_with_lambda("array_map", negate)?,
+        &tests,
+        true,
+    )?;
+
+    match ArrayMapFunction::try_create("array_map") {
+        Ok(_) => panic!("array_map without lambda must fail"),
+        Err(cause) => assert_eq!(
+            cause.message(),
+            "Function 'ARRAY_MAP' expects a lambda as its first argument, e.g. array_map(x -> x + 1, arr)"
+        ),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_array_filter_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_filter",
+        columns: vec![new_array_column(vec![vec![0, 1, 0], vec![2], vec![]])?],
+        expect: new_array_column(vec![vec![0, 0], vec![], vec![]])?,
+        error: "",
+    }];
+
+    let not = lambda("not", &[&Int64Type::arc()])?;
+    test_scalar_functions(
+        ArrayFilterFunction::try_create_with_lambda("array_filter", not)?,
+        &tests,
+        true,
+    )?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "array_filter-not-boolean",
+        columns: vec![new_array_column(vec![vec![1]])?],
+        expect: new_array_column(vec![vec![1]])?,
+        error: "The lambda of function 'ARRAY_FILTER' must return a boolean, but got Int64",
+    }];
+
+    let negate = lambda("negate", &[&Int64Type::arc()])?;
+    test_scalar_functions(
+        ArrayFilterFunction::try_create_with_lambda("array_filter", negate)?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_array_reduce_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_reduce",
+        columns: vec![
+            new_array_column(vec![vec![1, 2], vec![], vec![3, 4, 5]])?,
+            Series::from_data(vec![10i64, 0, 1]),
+        ],
+        expect: Series::from_data(vec![13i64, 0, 13]),
+        error: "",
+    }];
+
+    let plus = lambda("plus", &[&Int64Type::arc(), &Int64Type::arc()])?;
+    test_scalar_functions(
+        ArrayReduceFunction::try_create_with_lambda("array_reduce", plus)?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_array_sort_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array_sort",
+        columns: vec![new_array_column(vec![vec![3, 1, 2], vec![], vec![5, 4]])?],
+        expect: new_array_column(vec![vec![1, 2, 3], vec![], vec![4, 5]])?,
+        error: "",
+    }];
+    test_scalar_functions(ArraySortFunction::try_create("array_sort")?, &tests, true)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "array_sort-lambda",
+        columns: vec![new_array_column(vec![vec![3, 1, 2], vec![], vec![4, 5]])?],
+        expect: new_array_column(vec![vec![3, 2, 1], vec![], vec![5, 4]])?,
+        error: "",
+    }];
+
+    let negate = lambda("negate", &[&Int64Type::arc()])?;
+    test_scalar_functions(
+        ArraySortFunction::try_create_with_lambda("array_sort", negate)?,
+        &tests,
+        true,
+    )
+}
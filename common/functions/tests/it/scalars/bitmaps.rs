@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_eval;
+use super::scalar_function2_test::test_scalar_functions_with_type;
+use super::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+fn new_bitmap_column(values: &[&str]) -> Result<ColumnWithField> {
+    let mut builder = MutableStringColumn::with_capacity(values.len());
+    for v in values {
+        builder.append_value(bitmap_serialize(&bitmap_from_text(v.as_bytes())?));
+    }
+    Ok(ColumnWithField::new(
+        builder.to_column(),
+        DataField::new("bitmap", BitmapType::arc()),
+    ))
+}
+
+#[test]
+fn test_to_bitmap_function() -> Result<()> {
+    let func = ToBitmapFunction::try_create("to_bitmap")?;
+    let result = test_eval(&func, &[Series::from_data(vec!["1,2,3", "", "3, 1"])], true)?;
+    let expect = new_bitmap_column(&["1,2,3", "", "1,3"])?;
+    assert_eq!(&result, expect.column());
+
+    let result = test_eval(&func, &[Series::from_data(vec![5u32])], true)?;
+    let expect = new_bitmap_column(&["5"])?;
+    assert_eq!(&result, expect.column());
+
+    let result = test_eval(&func, &[Series::from_data(vec!["1,a"])], true);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Cast error happens in casting from String to Bitmap"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bitmap_count_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "bitmap_count",
+            columns: vec![new_bitmap_column(&["1,2,3", "", "18446744073709551615"])?],
+            expect: Series::from_data(vec![3u64, 0, 1]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "bitmap_count with invalid argument",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec!["1,2,3"]),
+                DataField::new("dummy", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![0u64]),
+            error: "Expected a bitmap type, but got String",
+        },
+    ];
+
+    test_scalar_functions_with_type(
+        BitmapCountFunction::try_create("bitmap_count")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_bitmap_logic_function() -> Result<()> {
+    let lhs = new_bitmap_column(&["1,2,3", "1,2", ""])?;
+    let rhs = new_bitmap_column(&["2,3,4", "3", "5"])?;
+
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "bitmap_and",
+        columns: vec![lhs.clone(), rhs.clone()],
+        expect: new_bitmap_column(&["2,3", "", ""])?.column().clone(),
+        error: "",
+    }];
+    test_scalar_functions_with_type(BitmapAndFunction::try_create("bitmap_and")?, &tests, true)?;
+
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "bitmap_or",
+        columns: vec![lhs, rhs],
+        expect: new_bitmap_column(&["1,2,3,4", "1,2,3", "5"])?
+            .column()
+            .clone(),
+        error: "",
+    }];
+    test_scalar_functions_with_type(BitmapOrFunction::try_create("bitmap_or")?, &tests, true)
+}
+
+#[test]
+fn test_bitmap_contains_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "bitmap_contains",
+            columns: vec![
+                new_bitmap_column(&["1,2,3", "1,2,3", ""])?,
+                ColumnWithField::new(
+                    Series::from_data(vec![2u8, 4, 0]),
+                    DataField::new("value", UInt8Type::arc()),
+                ),
+            ],
+            expect: Series::from_data(vec![true, false, false]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "bitmap_contains with invalid argument",
+            columns: vec![
+                new_bitmap_column(&["1,2,3"])?,
+                ColumnWithField::new(
+                    Series::from_data(vec![-1i32]),
+                    DataField::new("value", Int32Type::arc()),
+                ),
+            ],
+            expect: Series::from_data(vec![false]),
+            error: "Expected an unsigned integer type, but got Int32",
+        },
+    ];
+
+    test_scalar_functions_with_type(
+        BitmapContainsFunction::try_create("bitmap_contains")?,
+        &tests,
+        true,
+    )
+}
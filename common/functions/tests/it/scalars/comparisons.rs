@@ -211,3 +211,45 @@ fn test_not_regexp_comparison_function() -> Result<()> {
         true,
     )
 }
+
+#[test]
+fn test_collated_comparison_function() -> Result<()> {
+    let ci = StringType::arc_with_collation(Collation::CaseInsensitive);
+    let columns = vec![
+        Series::from_data(vec!["abc", "ABD", "Abe", "b"]),
+        Series::from_data(vec!["ABC", "abc", "abe", "A"]),
+    ];
+
+    test_scalar_functions(
+        ComparisonEqFunction::try_create_func("", &[&ci, &StringType::arc()])?,
+        &[ScalarFunctionTest {
+            name: "collated-eq-passed",
+            columns,
+            expect: Series::from_data(vec![true, false, true, false]),
+            error: "",
+        }],
+        true,
+    )?;
+
+    test_scalar_functions(
+        ComparisonLtFunction::try_create_func("", &[&StringType::arc(), &ci])?,
+        &[ScalarFunctionTest {
+            name: "collated-lt-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "ABD", "Abe", "b"]),
+                Series::from_data(vec!["ABD", "abc", "abe", "C"]),
+            ],
+            expect: Series::from_data(vec![true, false, false, true]),
+            error: "",
+        }],
+        true,
+    )?;
+
+    let de = StringType::arc_with_collation(Collation::German);
+    let result = ComparisonEqFunction::try_create_func("", &[&ci, &de]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Illegal mix of collations utf8_general_ci and de_DE"
+    );
+    Ok(())
+}
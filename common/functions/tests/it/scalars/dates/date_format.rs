@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::ColumnWithField;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+// 2021-09-05 03:26:06 UTC, a Sunday.
+const DATETIME: u32 = 1630812366;
+// 2021-09-05.
+const DATE: u16 = 18875;
+
+fn string(v: &str) -> ColumnWithField {
+    ColumnWithField::new(
+        Series::from_data(vec![v]),
+        DataField::new("s", StringType::arc()),
+    )
+}
+
+#[test]
+fn test_to_char_function() -> Result<()> {
+    let datetime = ColumnWithField::new(
+        Series::from_data(vec![DATETIME]),
+        DataField::new("x", DateTime32Type::arc(None)),
+    );
+    let date = ColumnWithField::new(
+        Series::from_data(vec![DATE]),
+        DataField::new("x", Date16Type::arc()),
+    );
+
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "to_char-datetime",
+            columns: vec![datetime.clone(), string("YYYY-MM-DD HH24:MI:SS")],
+            expect: Series::from_data(vec!["2021-09-05 03:26:06"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_char-names",
+            columns: vec![datetime.clone(), string("DD Mon YYYY, Day")],
+            expect: Series::from_data(vec!["05 Sep 2021, Sunday"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_char-12-hours",
+            columns: vec![datetime.clone(), string("hh12:mi am")],
+            expect: Series::from_data(vec!["03:26 AM"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_char-quoted-text",
+            columns: vec![datetime.clone(), string("\"Year\" YY, \"day\" DDD, 100%")],
+            expect: Series::from_data(vec!["Year 21, day 248, 100%"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_char-date",
+            columns: vec![date, string("YYYY/MM/DD")],
+            expect: Series::from_data(vec!["2021/09/05"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_char-unterminated-quote",
+            columns: vec![datetime, string("\"YYYY")],
+            expect: Series::from_data(vec![""]),
+            error: "Unterminated quoted text in format '\"YYYY'",
+        },
+    ];
+
+    test_scalar_functions_with_type(ToCharFunction::try_create("to_char")?, &tests, true)
+}
+
+#[test]
+fn test_to_timestamp_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "to_timestamp-datetime",
+            columns: vec![
+                string("2021-09-05 03:26:06"),
+                string("YYYY-MM-DD HH24:MI:SS"),
+            ],
+            expect: Series::from_data(vec![1630812366000000i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_timestamp-date",
+            columns: vec![string("2021/09/05"), string("YYYY/MM/DD")],
+            expect: Series::from_data(vec![1630800000000000i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_timestamp-milliseconds",
+            columns: vec![
+                string("05 Sep 2021 03:26:06.500"),
+                string("DD Mon YYYY HH24:MI:SS.MS"),
+            ],
+            expect: Series::from_data(vec![1630812366500000i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_timestamp-out-of-range",
+            columns: vec![string("2021-13-05"), string("YYYY-MM-DD")],
+            expect: Series::from_data(vec![0i64]),
+            error: "Cannot parse '2021-13-05' with format 'YYYY-MM-DD': input is out of range",
+        },
+    ];
+    test_scalar_functions_with_type(
+        ToTimestampFunction::try_create("to_timestamp")?,
+        &tests,
+        false,
+    )?;
+
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "try_to_timestamp",
+        columns: vec![
+            ColumnWithField::new(
+                Series::from_data(vec!["2021-09-05", "2021-13-05"]),
+                DataField::new("s", StringType::arc()),
+            ),
+            ColumnWithField::new(
+                Series::from_data(vec!["YYYY-MM-DD", "YYYY-MM-DD"]),
+                DataField::new("s", StringType::arc()),
+            ),
+        ],
+        expect: Series::from_data(vec![Some(1630800000000000i64), None]),
+        error: "",
+    }];
+    test_scalar_functions_with_type(
+        TryToTimestampFunction::try_create("try_to_timestamp")?,
+        &tests,
+        true,
+    )
+}
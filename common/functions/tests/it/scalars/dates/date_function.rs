@@ -704,3 +704,69 @@ fn test_tomonday_function() -> Result<()> {
 
     test_scalar_functions_with_type(ToMondayFunction::try_create("a")?, &tests, true)
 }
+
+#[test]
+fn test_date_diff_function() -> Result<()> {
+    let unit = |unit: &str| {
+        ColumnWithField::new(
+            Arc::new(ConstColumn::new(Series::from_data(vec![unit]), 1)),
+            DataField::new("unit", StringType::arc()),
+        )
+    };
+    // 2020-02-29 and 2021-03-01
+    let start = ColumnWithField::new(
+        Series::from_data(vec![18321u16]),
+        DataField::new("start", Date16Type::arc()),
+    );
+    let end = ColumnWithField::new(
+        Series::from_data(vec![18687u16]),
+        DataField::new("end", Date16Type::arc()),
+    );
+
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_year",
+            columns: vec![unit("year"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![1i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_quarter",
+            columns: vec![unit("QUARTER"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![4i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_months",
+            columns: vec![unit("months"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![13i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_week",
+            columns: vec![unit("week"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![53i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_day",
+            columns: vec![unit("day"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![366i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_hour_reversed",
+            columns: vec![unit("hour"), end.clone(), start.clone()],
+            expect: Series::from_data(vec![-8784i64]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_diff_unknown_unit",
+            columns: vec![unit("century"), start.clone(), end.clone()],
+            expect: Series::from_data(vec![0i64]),
+            error: "Unsupported unit 'century' of function dateDiff, expected one of YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE or SECOND",
+        },
+    ];
+
+    test_scalar_functions_with_type(DateDiffFunction::try_create("dateDiff")?, &tests, true)
+}
@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::ColumnWithField;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+// 2021-09-05 03:26:06 UTC, a Sunday.
+const DATETIME: u32 = 1630812366;
+// 2021-09-05.
+const DATE: u16 = 18875;
+
+fn unit(unit: &str) -> ColumnWithField {
+    ColumnWithField::new(
+        Arc::new(ConstColumn::new(Series::from_data(vec![unit]), 1)),
+        DataField::new("unit", StringType::arc()),
+    )
+}
+
+fn interval(num: i64, kind: IntervalKind) -> ColumnWithField {
+    ColumnWithField::new(
+        Arc::new(ConstColumn::new(Series::from_data(vec![num]), 1)),
+        DataField::new("interval", IntervalType::arc(kind)),
+    )
+}
+
+fn datetime(v: u32) -> ColumnWithField {
+    ColumnWithField::new(
+        Series::from_data(vec![v]),
+        DataField::new("x", DateTime32Type::arc(None)),
+    )
+}
+
+fn date(v: u16) -> ColumnWithField {
+    ColumnWithField::new(
+        Series::from_data(vec![v]),
+        DataField::new("x", Date16Type::arc()),
+    )
+}
+
+#[test]
+fn test_date_trunc_function() -> Result<()> {
+    let datetime_tests = vec![
+        ("second", 1630812366u32),
+        ("minute", 1630812360),
+        ("hour", 1630810800),
+        ("day", 1630800000),
+        ("week", 1630281600),
+        ("month", 1630454400),
+        ("quarter", 1625097600),
+        ("YEAR", 1609459200),
+    ];
+    let date_tests = vec![
+        ("hour", 18875u16),
+        ("day", 18875),
+        ("week", 18869),
+        ("month", 18871),
+        ("quarter", 18809),
+        ("year", 18628),
+    ];
+
+    let mut tests = vec![];
+    for (name, expect) in datetime_tests {
+        tests.push(ScalarFunctionWithFieldTest {
+            name: "date_trunc-datetime",
+            columns: vec![unit(name), datetime(DATETIME)],
+            expect: Series::from_data(vec![expect]),
+            error: "",
+        });
+    }
+    for (name, expect) in date_tests {
+        tests.push(ScalarFunctionWithFieldTest {
+            name: "date_trunc-date",
+            columns: vec![unit(name), date(DATE)],
+            expect: Series::from_data(vec![expect]),
+            error: "",
+        });
+    }
+    tests.push(ScalarFunctionWithFieldTest {
+        name: "date_trunc-unknown-unit",
+        columns: vec![unit("fortnight"), datetime(DATETIME)],
+        expect: Series::from_data(vec![0u32]),
+        error: "Unsupported unit of date_trunc: 'fortnight', expected one of year, quarter, month, week, day, hour, minute, second",
+    });
+
+    test_scalar_functions_with_type(DateTruncFunction::try_create("date_trunc")?, &tests, true)
+}
+
+#[test]
+fn test_to_start_of_interval_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-5-minutes",
+            columns: vec![datetime(DATETIME), interval(5, IntervalKind::Minute)],
+            expect: Series::from_data(vec![1630812300u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-3-hours",
+            columns: vec![datetime(DATETIME), interval(3, IntervalKind::Hour)],
+            expect: Series::from_data(vec![1630810800u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-3-days",
+            columns: vec![date(DATE), interval(3, IntervalKind::Day)],
+            expect: Series::from_data(vec![18873u16]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-2-weeks",
+            columns: vec![date(DATE), interval(2, IntervalKind::Week)],
+            expect: Series::from_data(vec![18862u16]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-2-years",
+            columns: vec![date(DATE), interval(2, IntervalKind::Year)],
+            expect: Series::from_data(vec![18262u16]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "to_start_of_interval-zero",
+            columns: vec![datetime(DATETIME), interval(0, IntervalKind::Minute)],
+            expect: Series::from_data(vec![0u32]),
+            error: "The interval of to_start_of_interval must be positive, but got 0",
+        },
+    ];
+
+    test_scalar_functions_with_type(
+        ToStartOfIntervalFunction::try_create("to_start_of_interval")?,
+        &tests,
+        true,
+    )
+}
@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod date;
+mod date_format;
 mod date_function;
+mod date_trunc;
 mod interval_function;
@@ -0,0 +1,193 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_eval;
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+const KEY_128: &str = "0123456789abcdef";
+const KEY_192: &str = "0123456789abcdef01234567";
+const KEY_256: &str = "0123456789abcdef0123456789abcdef";
+
+#[test]
+fn test_aes_encrypt_decrypt_roundtrip() -> Result<()> {
+    let encrypt = AesEncryptFunction::try_create("aes_encrypt")?;
+    let decrypt = AesDecryptFunction::try_create("aes_decrypt")?;
+
+    let cases = vec![
+        ("aes-128-gcm", KEY_128),
+        ("aes-256-gcm", KEY_256),
+        ("aes-128-cbc", KEY_128),
+        ("aes-192-cbc", KEY_192),
+        ("aes-256-cbc", KEY_256),
+    ];
+    for (mode, key) in cases {
+        let plaintext = Series::from_data(vec!["", "databend", "a longer text across blocks"]);
+        let keys = Series::from_data(vec![key; 3]);
+        let modes = Series::from_data(vec![mode; 3]);
+
+        let ciphertext = test_eval(
+            &encrypt,
+            &[plaintext.clone(), keys.clone(), modes.clone()],
+            false,
+        )?;
+        let again = test_eval(
+            &encrypt,
+            &[plaintext.clone(), keys.clone(), modes.clone()],
+            false,
+        )?;
+        assert_ne!(
+            ciphertext.get_string(1)?,
+            again.get_string(1)?,
+            "{}: the IV must be random",
+            mode
+        );
+
+        let decrypted = test_eval(&decrypt, &[ciphertext, keys, modes], false)?;
+        assert_eq!(decrypted, plaintext, "{}", mode);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_aes_encrypt_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "aes_encrypt-wrong-key-length",
+            columns: vec![
+                Series::from_data(vec!["databend"]),
+                Series::from_data(vec!["short"]),
+                Series::from_data(vec!["aes-256-gcm"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "The key of aes-256-gcm must be 32 bytes, but got 5 bytes",
+        },
+        ScalarFunctionTest {
+            name: "aes_encrypt-unknown-mode",
+            columns: vec![
+                Series::from_data(vec!["databend"]),
+                Series::from_data(vec![KEY_128]),
+                Series::from_data(vec!["aes-128-ecb"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Unsupported block encryption mode: \"aes-128-ecb\", expected one of aes-128-gcm, aes-256-gcm, aes-128-cbc, aes-192-cbc, aes-256-cbc",
+        },
+        ScalarFunctionTest {
+            name: "aes_encrypt-null",
+            columns: vec![
+                Series::from_data(vec![None::<&str>]),
+                Series::from_data(vec![KEY_128]),
+                Series::from_data(vec!["aes-128-gcm"]),
+            ],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(
+        AesEncryptFunction::try_create("aes_encrypt")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_aes_decrypt_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "aes_decrypt-too-short",
+            columns: vec![
+                Series::from_data(vec!["short"]),
+                Series::from_data(vec![KEY_128]),
+                Series::from_data(vec!["aes-128-gcm"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "The ciphertext of aes-128-gcm must have at least 12 bytes, but got 5 bytes",
+        },
+        ScalarFunctionTest {
+            name: "aes_decrypt-not-encrypted",
+            columns: vec![
+                Series::from_data(vec!["0123456789abcdef0123456789abcdef"]),
+                Series::from_data(vec![KEY_128]),
+                Series::from_data(vec!["aes-128-gcm"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Failed to decrypt with aes-128-gcm, the key or the ciphertext is wrong",
+        },
+    ];
+
+    test_scalar_functions(
+        AesDecryptFunction::try_create("aes_decrypt")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_try_aes_decrypt_function() -> Result<()> {
+    let encrypt = AesEncryptFunction::try_create("aes_encrypt")?;
+    let ciphertext = test_eval(
+        &encrypt,
+        &[
+            Series::from_data(vec!["databend"]),
+            Series::from_data(vec![KEY_256]),
+            Series::from_data(vec!["aes-256-gcm"]),
+        ],
+        false,
+    )?;
+    let ciphertext = ciphertext.get_string(0)?;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "try_aes_decrypt-right-key",
+            columns: vec![
+                Series::from_data(vec![ciphertext.clone()]),
+                Series::from_data(vec![KEY_256]),
+                Series::from_data(vec!["aes-256-gcm"]),
+            ],
+            expect: Series::from_data(vec![Some("databend")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "try_aes_decrypt-wrong-key",
+            columns: vec![
+                Series::from_data(vec![ciphertext.clone()]),
+                Series::from_data(vec!["fedcba9876543210fedcba9876543210"]),
+                Series::from_data(vec!["aes-256-gcm"]),
+            ],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "try_aes_decrypt-wrong-key-length",
+            columns: vec![
+                Series::from_data(vec![ciphertext]),
+                Series::from_data(vec![KEY_128]),
+                Series::from_data(vec!["aes-256-gcm"]),
+            ],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(
+        TryAesDecryptFunction::try_create("try_aes_decrypt")?,
+        &tests,
+        false,
+    )
+}
@@ -326,6 +326,54 @@ fn test_variant_cast_function() -> Result<()> {
                 error: "Failed to cast variant value [\"a\",\"b\",\"c\"] to OBJECT",
             },
         ),
+        (
+            CastFunction::create("cast", "int64")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-int64-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!(1_i32), json!("2"), json!(true)]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Series::from_data(vec![1i64, 2, 1]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "int64")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-int64-error",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!(1_i32), json!("abc")]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(2)),
+                error: "Cast error happens in casting from Variant to Int64",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!("abc"), json!(1.5_f64), json!({"a":1_i32})]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Series::from_data(vec!["abc", "1.5", "{\"a\":1}"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "boolean")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-boolean-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!(true), json!("false"), json!(0_i32)]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Series::from_data(vec![true, false, false]),
+                error: "",
+            },
+        ),
     ];
 
     for (test_func, test) in tests {
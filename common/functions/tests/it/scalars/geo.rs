@@ -0,0 +1,256 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_eval;
+use super::scalar_function2_test::test_scalar_functions_with_type;
+use super::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+fn new_geometry_column(values: &[&str]) -> Result<ColumnWithField> {
+    let mut builder = MutableStringColumn::with_capacity(values.len());
+    for v in values {
+        builder.append_value(Geometry::from_wkt(v)?.to_wkb());
+    }
+    Ok(ColumnWithField::new(
+        builder.to_column(),
+        DataField::new("geometry", GeometryType::arc()),
+    ))
+}
+
+#[test]
+fn test_st_point_function() -> Result<()> {
+    let func = StPointFunction::try_create("st_point")?;
+    let result = test_eval(
+        &func,
+        &[
+            Series::from_data(vec![1i32, -2]),
+            Series::from_data(vec![2.5f64, 0.0]),
+        ],
+        true,
+    )?;
+    let expect = new_geometry_column(&["POINT(1 2.5)", "POINT(-2 0)"])?;
+    assert_eq!(&result, expect.column());
+    Ok(())
+}
+
+#[test]
+fn test_st_text_function() -> Result<()> {
+    let func = StGeomFromTextFunction::try_create("st_geomfromtext")?;
+    let result = test_eval(
+        &func,
+        &[Series::from_data(vec![
+            "point(1 2)",
+            "LINESTRING(0 0, 1 1)",
+        ])],
+        true,
+    )?;
+    let expect = new_geometry_column(&["POINT(1 2)", "LINESTRING(0 0,1 1)"])?;
+    assert_eq!(&result, expect.column());
+
+    let result = test_eval(&func, &[Series::from_data(vec!["POINT(1)"])], true);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Incorrect Geometry value '1': a coordinate must be 'x y'"
+    );
+
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "st_astext",
+        columns: vec![new_geometry_column(&[
+            "POINT(1 2)",
+            "POLYGON((0 0, 2 0, 2 2, 0 0))",
+        ])?],
+        expect: Series::from_data(vec!["POINT(1 2)", "POLYGON((0 0,2 0,2 2,0 0))"]),
+        error: "",
+    }];
+    test_scalar_functions_with_type(StAsTextFunction::try_create("st_astext")?, &tests, true)
+}
+
+#[test]
+fn test_st_distance_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "st_distance",
+            columns: vec![
+                new_geometry_column(&[
+                    "POINT(0 0)",
+                    "POINT(5 1)",
+                    "POINT(1 1)",
+                    "LINESTRING(0 3, 3 3)",
+                    "LINESTRING(0 0, 2 2)",
+                ])?,
+                new_geometry_column(&[
+                    "POINT(3 4)",
+                    "POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))",
+                    "POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))",
+                    "POINT(1 1)",
+                    "LINESTRING(0 2, 2 0)",
+                ])?,
+            ],
+            expect: Series::from_data(vec![5.0f64, 3.0, 0.0, 2.0, 0.0]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "st_distance with invalid argument",
+            columns: vec![
+                new_geometry_column(&["POINT(0 0)"])?,
+                ColumnWithField::new(
+                    Series::from_data(vec!["POINT(0 0)"]),
+                    DataField::new("dummy", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data(vec![0.0f64]),
+            error: "Expected a geometry type, but got String",
+        },
+    ];
+
+    test_scalar_functions_with_type(StDistanceFunction::try_create("st_distance")?, &tests, true)
+}
+
+#[test]
+fn test_st_contains_function() -> Result<()> {
+    let square = "POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))";
+    let holed = "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 3 1, 3 3, 1 3, 1 1))";
+    let concave = "POLYGON((0 0, 4 0, 4 4, 2 1, 0 4, 0 0))";
+
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "st_contains",
+        columns: vec![
+            new_geometry_column(&[
+                square,
+                square,
+                square,
+                holed,
+                holed,
+                square,
+                concave,
+                "POINT(1 1)",
+            ])?,
+            new_geometry_column(&[
+                "POINT(1 1)",
+                "POINT(3 1)",
+                "POINT(2 1)",
+                "POINT(2 2)",
+                "POINT(0.5 0.5)",
+                "LINESTRING(0.5 0.5, 1.5 1.5)",
+                "LINESTRING(0.5 3, 3.5 3)",
+                "POINT(1 1)",
+            ])?,
+        ],
+        expect: Series::from_data(vec![true, false, true, false, true, true, false, true]),
+        error: "",
+    }];
+
+    test_scalar_functions_with_type(StContainsFunction::try_create("st_contains")?, &tests, true)
+}
+
+#[test]
+fn test_haversine_function() -> Result<()> {
+    let func = HaversineFunction::try_create("haversine")?;
+    let result = test_eval(
+        &func,
+        &[
+            Series::from_data(vec![40.7128f64, 0.0]),
+            Series::from_data(vec![-74.0060f64, 0.0]),
+            Series::from_data(vec![51.5074f64, 0.0]),
+            Series::from_data(vec![-0.1278f64, 1.0]),
+        ],
+        true,
+    )?;
+    let result: &Float64Column = Series::check_get(&result)?;
+    assert!((result.values()[0] - 5570.222).abs() < 1e-3);
+    assert!((result.values()[1] - 111.195).abs() < 1e-3);
+    Ok(())
+}
+
+#[test]
+fn test_geohash_function() -> Result<()> {
+    let func = GeohashEncodeFunction::try_create("geohash_encode")?;
+    let lons = Series::from_data(vec![-5.60302734375f64, 116.3974]);
+    let lats = Series::from_data(vec![42.593994140625f64, 39.9093]);
+
+    let result = test_eval(&func, &[lons.clone(), lats.clone()], true)?;
+    assert_eq!(
+        result,
+        Series::from_data(vec!["ezs42d000000", "wx4g09q0808p"])
+    );
+
+    let precisions = Series::from_data(vec![5u8, 8]);
+    let result = test_eval(&func, &[lons, lats, precisions], true)?;
+    assert_eq!(result, Series::from_data(vec!["ezs42", "wx4g09q0"]));
+
+    let func = GeohashDecodeFunction::try_create("geohash_decode")?;
+    let result = test_eval(&func, &[Series::from_data(vec!["ezs42"])], true)?;
+    assert_eq!(
+        result.get(0),
+        DataValue::Struct(vec![
+            DataValue::Float64(-5.60302734375),
+            DataValue::Float64(42.60498046875)
+        ])
+    );
+
+    let result = test_eval(&func, &[Series::from_data(vec!["ezs4a"])], true);
+    assert_eq!(result.err().unwrap().message(), "Invalid geohash 'ezs4a'");
+    Ok(())
+}
+
+#[test]
+fn test_point_in_polygon_function() -> Result<()> {
+    let coord_type = || {
+        let names = vec!["x".to_string(), "y".to_string()];
+        let types = vec![Float64Type::arc(), Float64Type::arc()];
+        Arc::new(StructType::create(names, types)) as DataTypePtr
+    };
+    let coord =
+        |x: f64, y: f64| DataValue::Struct(vec![DataValue::Float64(x), DataValue::Float64(y)]);
+
+    let mut points = MutableStructColumn::with_capacity(coord_type(), 4);
+    let mut polygons = MutableArrayColumn::with_capacity(ArrayType::arc(coord_type()), 4);
+    // A concave polygon, (2, 3) is in its notch.
+    let concave = vec![
+        coord(0.0, 0.0),
+        coord(4.0, 0.0),
+        coord(4.0, 4.0),
+        coord(2.0, 1.0),
+        coord(0.0, 4.0),
+    ];
+    for point in [
+        coord(1.0, 1.0),
+        coord(2.0, 3.0),
+        coord(4.0, 2.0),
+        coord(5.0, 5.0),
+    ] {
+        points.append_data_value(point)?;
+        polygons.append_value(concave.clone())?;
+    }
+
+    let func = PointInPolygonFunction::try_create("point_in_polygon")?;
+    let result = test_eval(&func, &[points.to_column(), polygons.to_column()], true)?;
+    assert_eq!(result, Series::from_data(vec![true, false, true, false]));
+
+    let mut points = MutableStructColumn::with_capacity(coord_type(), 1);
+    points.append_data_value(coord(0.0, 0.0))?;
+    let mut polygons = MutableArrayColumn::with_capacity(ArrayType::arc(coord_type()), 1);
+    polygons.append_value(vec![coord(0.0, 0.0), coord(1.0, 1.0)])?;
+    let result = test_eval(&func, &[points.to_column(), polygons.to_column()], true);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "The polygon of point_in_polygon must have at least 3 vertices, but got 2"
+    );
+    Ok(())
+}
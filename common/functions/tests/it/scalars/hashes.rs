@@ -18,6 +18,7 @@ use std::hash::Hasher;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::Blake3HashFunction;
+use common_functions::scalars::City64Function;
 use common_functions::scalars::City64WithSeedFunction;
 use common_functions::scalars::Md5HashFunction;
 use common_functions::scalars::Sha1HashFunction;
@@ -25,6 +26,7 @@ use common_functions::scalars::Sha2HashFunction;
 use common_functions::scalars::SipHash64Function;
 use common_functions::scalars::XxHash32Function;
 use common_functions::scalars::XxHash64Function;
+use naive_cityhash::cityhash64;
 use naive_cityhash::cityhash64_with_seed;
 use twox_hash::XxHash32;
 
@@ -38,9 +40,9 @@ fn test_siphash_function() -> Result<()> {
             name: "Int8Array siphash",
             columns: vec![Series::from_data(vec![1i8, 2, 1])],
             expect: Series::from_data(vec![
-                4952851536318644461u64,
-                7220060526038107403,
-                4952851536318644461,
+                7385293435322750976u64,
+                4155712647414461206,
+                7385293435322750976,
             ]),
             error: "",
         },
@@ -48,9 +50,9 @@ fn test_siphash_function() -> Result<()> {
             name: "Int16Array siphash",
             columns: vec![Series::from_data(vec![1i16, 2, 1])],
             expect: Series::from_data(vec![
-                10500823559348167161u64,
-                4091451155859037844,
-                10500823559348167161,
+                16287474199394656573u64,
+                12404868475562616511,
+                16287474199394656573,
             ]),
             error: "",
         },
@@ -58,9 +60,9 @@ fn test_siphash_function() -> Result<()> {
             name: "Int32Array siphash",
             columns: vec![Series::from_data(vec![1i32, 2, 1])],
             expect: Series::from_data(vec![
-                1742378985846435984u64,
-                16336925911988107921,
-                1742378985846435984,
+                13715208377448023093u64,
+                12927278975396469866,
+                13715208377448023093,
             ]),
             error: "",
         },
@@ -68,9 +70,9 @@ fn test_siphash_function() -> Result<()> {
             name: "Int64Array siphash",
             columns: vec![Series::from_data(vec![1i64, 2, 1])],
             expect: Series::from_data(vec![
-                2206609067086327257u64,
-                11876854719037224982,
-                2206609067086327257,
+                9224715256000962398u64,
+                13686418376000424449,
+                9224715256000962398,
             ]),
             error: "",
         },
@@ -78,9 +80,9 @@ fn test_siphash_function() -> Result<()> {
             name: "UInt8Array siphash",
             columns: vec![Series::from_data(vec![1u8, 2, 1])],
             expect: Series::from_data(vec![
-                4952851536318644461u64,
-                7220060526038107403,
-                4952851536318644461,
+                7385293435322750976u64,
+                4155712647414461206,
+                7385293435322750976,
             ]),
             error: "",
         },
@@ -88,9 +90,9 @@ fn test_siphash_function() -> Result<()> {
             name: "UInt16Array siphash",
             columns: vec![Series::from_data(vec![1u16, 2, 1])],
             expect: Series::from_data(vec![
-                10500823559348167161u64,
-                4091451155859037844,
-                10500823559348167161,
+                16287474199394656573u64,
+                12404868475562616511,
+                16287474199394656573,
             ]),
             error: "",
         },
@@ -98,9 +100,9 @@ fn test_siphash_function() -> Result<()> {
             name: "UInt32Array siphash",
             columns: vec![Series::from_data(vec![1u32, 2, 1])],
             expect: Series::from_data(vec![
-                1742378985846435984u64,
-                16336925911988107921,
-                1742378985846435984,
+                13715208377448023093u64,
+                12927278975396469866,
+                13715208377448023093,
             ]),
             error: "",
         },
@@ -108,9 +110,9 @@ fn test_siphash_function() -> Result<()> {
             name: "UInt64Array siphash",
             columns: vec![Series::from_data(vec![1u64, 2, 1])],
             expect: Series::from_data(vec![
-                2206609067086327257u64,
-                11876854719037224982,
-                2206609067086327257,
+                9224715256000962398u64,
+                13686418376000424449,
+                9224715256000962398,
             ]),
             error: "",
         },
@@ -118,9 +120,9 @@ fn test_siphash_function() -> Result<()> {
             name: "Float32Array siphash",
             columns: vec![Series::from_data(vec![1.0f32, 2., 1.])],
             expect: Series::from_data(vec![
-                729488449357906283u64,
-                9872512741335963328,
-                729488449357906283,
+                10016837570770182386u64,
+                4516050901263898136,
+                10016837570770182386,
             ]),
             error: "",
         },
@@ -128,12 +130,18 @@ fn test_siphash_function() -> Result<()> {
             name: "Float64Array siphash",
             columns: vec![Series::from_data(vec![1.0f64, 2., 1.])],
             expect: Series::from_data(vec![
-                13833534234735907638u64,
-                12773237290464453619,
-                13833534234735907638,
+                3177009167758079239u64,
+                9384798284944382482,
+                3177009167758079239,
             ]),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "String siphash",
+            columns: vec![Series::from_data(vec!["", "1234567890"])],
+            expect: Series::from_data(vec![2202906307356721367u64, 15570569622333497593]),
+            error: "",
+        },
     ];
 
     test_scalar_functions(SipHash64Function::try_create("siphash")?, &tests, true)
@@ -371,3 +379,35 @@ fn test_cityhash64_with_seed_string() -> Result<()> {
         true,
     )
 }
+
+#[test]
+fn test_cityhash64_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "String cityhash64",
+            columns: vec![Series::from_data(vec!["Alice", "Bob"])],
+            expect: Series::from_data(vec![cityhash64(b"Alice"), cityhash64(b"Bob")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "UInt64Array cityhash64",
+            columns: vec![Series::from_data(vec![0u64, 1, 2])],
+            expect: Series::from_data(vec![0u64, 12994781566227106604, 4233148493373801447]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "Int8Array cityhash64",
+            columns: vec![Series::from_data(vec![1i8, -1])],
+            expect: Series::from_data(vec![12994781566227106604u64, 1297215527019907880]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "Float32Array cityhash64",
+            columns: vec![Series::from_data(vec![1.0f32])],
+            expect: Series::from_data(vec![7273007373799512404u64]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(City64Function::try_create("cityHash64")?, &tests, true)
+}
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_eval;
+use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::ScalarFunctionTest;
+
+fn new_map_column(values: Vec<Vec<(&str, i64)>>) -> Result<ColumnRef> {
+    let data_type = MapType::arc(StringType::arc(), Int64Type::arc());
+    let mut builder = MutableArrayColumn::with_capacity(data_type, values.len());
+    for entries in values {
+        for (k, v) in entries {
+            let entry = DataValue::Struct(vec![k.as_bytes().into(), DataValue::Int64(v)]);
+            builder.inner_column().append_data_value(entry)?;
+        }
+        builder.add_offset();
+    }
+    Ok(builder.to_column())
+}
+
+#[test]
+fn test_map_function() -> Result<()> {
+    let func = MapFunction::try_create("map")?;
+    let columns = vec![
+        Series::from_data(vec!["a", "c"]),
+        Series::from_data(vec![1i64, 3]),
+        Series::from_data(vec!["b", "d"]),
+        Series::from_data(vec![2i32, 4]),
+    ];
+    let result = test_eval(&func, &columns, false)?;
+    let expect = new_map_column(vec![vec![("a", 1), ("b", 2)], vec![("c", 3), ("d", 4)]])?;
+    assert_eq!(result, expect);
+
+    let result = test_eval(&func, &columns[0..3], false);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Function 'MAP' expects an even number of arguments, but got 3"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_map_keys_values_function() -> Result<()> {
+    let column = new_map_column(vec![vec![("a", 1), ("b", 2)], vec![]])?;
+
+    let func = MapKeysFunction::try_create("map_keys")?;
+    let result = test_eval(&func, &[column.clone()], false)?;
+    let keys = vec![DataValue::String(b"a".to_vec()), DataValue::String(b"b".to_vec())];
+    assert_eq!(result.get(0), DataValue::Array(keys));
+    assert_eq!(result.get(1), DataValue::Array(vec![]));
+
+    let func = MapValuesFunction::try_create("map_values")?;
+    let result = test_eval(&func, &[column], false)?;
+    assert_eq!(
+        result.get(0),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)])
+    );
+    assert_eq!(result.get(1), DataValue::Array(vec![]));
+    Ok(())
+}
+
+#[test]
+fn test_map_get_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "map_get",
+            columns: vec![
+                new_map_column(vec![vec![("a", 1), ("b", 2)], vec![], vec![("b", 3)]])?,
+                Series::from_data(vec!["b", "b", "a"]),
+            ],
+            expect: Series::from_data(vec![Some(2i64), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "map_get with invalid argument",
+            columns: vec![
+                Series::from_data(vec![1i64]),
+                Series::from_data(vec!["a"]),
+            ],
+            expect: Series::from_data(vec![0i64]),
+            error: "Invalid argument types for function 'MAP_GET': (Int64, String)",
+        },
+    ];
+
+    test_scalar_functions(MapGetFunction::try_create("map_get")?, &tests, true)
+}
@@ -13,12 +13,17 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitmaps;
 mod comparisons;
 mod conditionals;
 mod dates;
+mod encryptions;
 mod expressions;
+mod geo;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
@@ -27,4 +32,5 @@ mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
+mod urls;
 mod uuids;
@@ -15,11 +15,16 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_functions::scalars::Inet6AtonFunction;
+use common_functions::scalars::Inet6NtoaFunction;
 use common_functions::scalars::InetAtonFunction;
 use common_functions::scalars::InetNtoaFunction;
+use common_functions::scalars::IpInRangeFunction;
 use common_functions::scalars::RunningDifferenceFunction;
+use common_functions::scalars::TryInet6AtonFunction;
 use common_functions::scalars::TryInetAtonFunction;
 use common_functions::scalars::TryInetNtoaFunction;
+use common_functions::scalars::UDFServerFunction;
 
 use super::scalar_function2_test::test_scalar_functions;
 use super::scalar_function2_test::ScalarFunctionTest;
@@ -426,3 +431,156 @@ fn test_inet_ntoa_function() -> Result<()> {
     let test_func = InetNtoaFunction::try_create("inet_ntoa")?;
     test_scalar_functions(test_func, &tests, true)
 }
+
+#[test]
+fn test_inet6_aton_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid input",
+            columns: vec![Series::from_data(["127.0.0.1", "fe80::1"])],
+            expect: Series::from_data(vec![vec![127_u8, 0, 0, 1], vec![
+                0xfe_u8, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+            ]]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null input",
+            columns: vec![Series::from_data([Option::<Vec<u8>>::None])],
+            expect: Series::from_data([Option::<Vec<u8>>::None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid input",
+            columns: vec![Series::from_data(["fe80::1", "batman"])],
+            expect: Series::from_data(vec![Option::<Vec<u8>>::None]),
+            error: "Failed to parse 'batman' into a IP address, invalid IP address syntax",
+        },
+    ];
+
+    let test_func = Inet6AtonFunction::try_create("inet6_aton")?;
+    test_scalar_functions(test_func, &tests, false)
+}
+
+#[test]
+fn test_try_inet6_aton_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "invalid input",
+        columns: vec![Series::from_data(["10.0.0.1", "batman"])],
+        expect: Series::from_data(vec![Some(vec![10_u8, 0, 0, 1]), None]),
+        error: "",
+    }];
+
+    let test_func = TryInet6AtonFunction::try_create("try_inet6_aton")?;
+    test_scalar_functions(test_func, &tests, false)
+}
+
+#[test]
+fn test_inet6_ntoa_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid input",
+            columns: vec![Series::from_data(vec![vec![127_u8, 0, 0, 1], vec![
+                0xfe_u8, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+            ]])],
+            expect: Series::from_data(["127.0.0.1", "fe80::1"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid input",
+            columns: vec![Series::from_data(["abc"])],
+            expect: Series::from_data([""]),
+            error: "Expected a 4 or 16 bytes IP address, but got 3 bytes",
+        },
+    ];
+
+    let test_func = Inet6NtoaFunction::try_create("inet6_ntoa")?;
+    test_scalar_functions(test_func, &tests, false)
+}
+
+#[test]
+fn test_ip_in_range_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "ipv4 input",
+            columns: vec![
+                Series::from_data(["10.1.2.3", "11.0.0.1", "::ffff:10.0.0.1", "batman"]),
+                Series::from_data(["10.0.0.0/8", "10.0.0.0/8", "10.0.0.0/8", "10.0.0.0/8"]),
+            ],
+            expect: Series::from_data([true, false, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "integer input",
+            columns: vec![
+                Series::from_data([167837955_u32, 184549377_u32]),
+                Series::from_data(["10.0.0.0/8", "10.0.0.0/8"]),
+            ],
+            expect: Series::from_data([true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "ipv6 input",
+            columns: vec![
+                Series::from_data(["2001:db8::1", "2001:db9::1", "10.0.0.1", "fe80::1"]),
+                Series::from_data(["2001:db8::/32", "2001:db8::/32", "::ffff:0:0/96", "::/0"]),
+            ],
+            expect: Series::from_data([true, false, true, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "single address",
+            columns: vec![
+                Series::from_data(["192.168.1.1", "192.168.1.2"]),
+                Series::from_data(["192.168.1.1", "192.168.1.1"]),
+            ],
+            expect: Series::from_data([true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null input",
+            columns: vec![
+                Series::from_data([Some("10.0.0.1"), None]),
+                Series::from_data([Some("10.0.0.0/8"), Some("10.0.0.0/8")]),
+            ],
+            expect: Series::from_data([Some(true), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid network",
+            columns: vec![
+                Series::from_data(["10.0.0.1"]),
+                Series::from_data(["10.0.0.0/33"]),
+            ],
+            expect: Series::from_data([false]),
+            error: "Failed to parse '10.0.0.0/33' into a CIDR network",
+        },
+    ];
+
+    let test_func = IpInRangeFunction::try_create("ip_in_range")?;
+    test_scalar_functions(test_func, &tests, false)
+}
+
+#[test]
+fn test_udf_server_allowed_addresses() -> Result<()> {
+    UDFServerFunction::set_allowed_addresses("");
+    let result = UDFServerFunction::try_create("udf_server", "127.0.0.1:8815", "gcd", "Int64");
+    assert_eq!(
+        result.err().unwrap().message(),
+        "The UDF server 127.0.0.1:8815 isn't in the udf_server_allow_list of the config"
+    );
+
+    UDFServerFunction::set_allowed_addresses("127.0.0.1:8815, 127.0.0.1:8816");
+    UDFServerFunction::try_create("udf_server", "127.0.0.1:8816", "gcd", "Int64")?;
+    assert!(
+        UDFServerFunction::try_create("udf_server", "169.254.169.254:80", "gcd", "Int64").is_err()
+    );
+    Ok(())
+}
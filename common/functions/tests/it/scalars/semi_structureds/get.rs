@@ -128,6 +128,15 @@ fn test_get_path_function() -> Result<()> {
             ]),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "get_by_path_from_string",
+            columns: vec![
+                Series::from_data(vec![r#"{"a":[[1],[2]],"o":{"p":{"q":"r"}}}"#]),
+                Series::from_data(vec!["a[1][0]", "o.p:q", "o.x"]),
+            ],
+            expect: Series::from_data(vec![Some(json!(2_u64)), Some(json!("r")), None]),
+            error: "",
+        },
         ScalarFunctionTest {
             name: "get_by_path_error_type",
             columns: vec![
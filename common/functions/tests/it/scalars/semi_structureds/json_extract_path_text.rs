@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_functions::scalars::JsonExtractPathTextFunction;
+use serde_json::json;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_json_extract_path_text_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "json_extract_path_text_from_variant",
+            columns: vec![
+                Series::from_data(vec![json!({"a":[1_i32,{"b":"x"}],"n":null})]),
+                Series::from_data(vec!["a[1].b", "a[0]", "a", "n", "c"]),
+            ],
+            expect: Series::from_data(vec![
+                Some("x"),
+                Some("1"),
+                Some(r#"[1,{"b":"x"}]"#),
+                None,
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path_text_from_string",
+            columns: vec![
+                Series::from_data(vec![r#"{"a":{"b":true}}"#]),
+                Series::from_data(vec!["a.b", "a"]),
+            ],
+            expect: Series::from_data(vec![Some("true"), Some(r#"{"b":true}"#)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path_text_invalid_json",
+            columns: vec![Series::from_data(vec!["{a"]), Series::from_data(vec!["a"])],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Error parsing JSON: key must be a string at line 1 column 2",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path_text_error_type",
+            columns: vec![Series::from_data(vec![1_i32]), Series::from_data(vec!["a"])],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Invalid argument types for function 'JSON_EXTRACT_PATH_TEXT': (Int32, String)",
+        },
+    ];
+
+    test_scalar_functions(
+        JsonExtractPathTextFunction::try_create("json_extract_path_text")?,
+        &tests,
+        false,
+    )
+}
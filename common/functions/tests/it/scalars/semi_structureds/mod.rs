@@ -14,4 +14,6 @@
 
 mod check_json;
 mod get;
+mod json_extract_path_text;
+mod object_keys;
 mod parse_json;
@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_functions::scalars::ObjectKeysFunction;
+use serde_json::json;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_object_keys_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "object_keys_from_variant",
+            columns: vec![Series::from_data(vec![
+                json!({"a":{"c":2_i32},"b":1_i32}),
+                json!({}),
+                json!([1_i32, 2]),
+                json!("a"),
+            ])],
+            expect: Series::from_data(vec![Some(json!(["a", "b"])), Some(json!([])), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "object_keys_from_string",
+            columns: vec![Series::from_data(vec![r#"{"k1":1,"k2":null}"#, "1"])],
+            expect: Series::from_data(vec![Some(json!(["k1", "k2"])), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "object_keys_error_type",
+            columns: vec![Series::from_data(vec![1_i32])],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Invalid argument types for function 'OBJECT_KEYS': (Int32)",
+        },
+    ];
+
+    test_scalar_functions(
+        ObjectKeysFunction::try_create("object_keys")?,
+        &tests,
+        false,
+    )
+}
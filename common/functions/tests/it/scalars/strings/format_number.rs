@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::FormatNumberFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_format_number_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "format_number-grouping",
+            columns: vec![
+                Series::from_data(vec![1234567.891f64]),
+                Series::from_data(vec!["#,##0.00"]),
+            ],
+            expect: Series::from_data(vec!["1,234,567.89"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-optional-digits",
+            columns: vec![
+                Series::from_data(vec![0.5f64]),
+                Series::from_data(vec!["#.##"]),
+            ],
+            expect: Series::from_data(vec![".5"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-zero-padding",
+            columns: vec![
+                Series::from_data(vec![-42i64]),
+                Series::from_data(vec!["0000"]),
+            ],
+            expect: Series::from_data(vec!["-0042"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-prefix",
+            columns: vec![
+                Series::from_data(vec![3.14159f64]),
+                Series::from_data(vec!["$#,##0.0#"]),
+            ],
+            expect: Series::from_data(vec!["$3.14"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-negative-zero",
+            columns: vec![
+                Series::from_data(vec![-0.001f64]),
+                Series::from_data(vec!["0.00"]),
+            ],
+            expect: Series::from_data(vec!["0.00"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-decimal-places",
+            columns: vec![
+                Series::from_data(vec![1234.5f64]),
+                Series::from_data(vec![2i64]),
+            ],
+            expect: Series::from_data(vec!["1,234.50"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format_number-invalid-pattern",
+            columns: vec![
+                Series::from_data(vec![1i64]),
+                Series::from_data(vec!["abc"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Invalid number pattern 'abc', expected digits like '#,##0.00'",
+        },
+    ];
+
+    test_scalar_functions(
+        FormatNumberFunction::try_create("format_number")?,
+        &tests,
+        true,
+    )
+}
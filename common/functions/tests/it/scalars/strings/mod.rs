@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod format_number;
 // mod locate;
 mod lower;
+mod regexp_extract;
 mod regexp_instr;
 mod regexp_like;
+mod regexp_replace;
+mod regexp_split_to_array;
 mod substring;
 mod trim;
 
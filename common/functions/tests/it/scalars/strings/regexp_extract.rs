@@ -0,0 +1,72 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpExtractFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_regexp_extract_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "regexp-extract-whole-match-passed",
+            columns: vec![
+                Series::from_data(vec!["abc123def456", "no digits", ""]),
+                Series::from_data(vec!["[0-9]+", "[0-9]+", "[0-9]+"]),
+            ],
+            expect: Series::from_data(vec!["123", "", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-extract-group-passed",
+            columns: vec![
+                Series::from_data(vec!["user=alice id=1", "user=bob id=22", "id=3"]),
+                Series::from_data(vec!["user=(\\w+) id=(\\d+)"; 3]),
+                Series::from_data(vec![2_i64, 1, 1]),
+            ],
+            expect: Series::from_data(vec!["1", "bob", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-extract-match-type-passed",
+            columns: vec![
+                Series::from_data(vec!["ABC", "ABC"]),
+                Series::from_data(vec!["b", "b"]),
+                Series::from_data(vec![0_i64, 0]),
+                Series::from_data(vec!["i", "c"]),
+            ],
+            expect: Series::from_data(vec!["B", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-extract-group-error",
+            columns: vec![
+                Series::from_data(vec!["abc"]),
+                Series::from_data(vec!["(b)"]),
+                Series::from_data(vec![2_i64]),
+            ],
+            expect: Series::from_data(Vec::<&str>::new()),
+            error: "Incorrect arguments to regexp_extract: group index 2 is out of range, the pattern has 1 groups",
+        },
+    ];
+
+    test_scalar_functions(
+        RegexpExtractFunction::try_create("regexp_extract")?,
+        &tests,
+        true,
+    )
+}
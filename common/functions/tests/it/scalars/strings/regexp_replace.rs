@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpReplaceFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_regexp_replace_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "regexp-replace-all-passed",
+            columns: vec![
+                Series::from_data(vec!["a b c", "abc def ghi", ""]),
+                Series::from_data(vec!["b", "[a-z]+", "x"]),
+                Series::from_data(vec!["X", "X", "y"]),
+            ],
+            expect: Series::from_data(vec!["a X c", "X X X", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-capture-group-passed",
+            columns: vec![
+                Series::from_data(vec!["2022-04-15", "1999-12-31"]),
+                Series::from_data(vec!["(\\d+)-(\\d+)-(\\d+)"; 2]),
+                Series::from_data(vec!["$3/$2/$1"; 2]),
+            ],
+            expect: Series::from_data(vec!["15/04/2022", "31/12/1999"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-position-passed",
+            columns: vec![
+                Series::from_data(vec!["abc def ghi", "abc def ghi"]),
+                Series::from_data(vec!["[a-z]+", "[a-z]+"]),
+                Series::from_data(vec!["X", "X"]),
+                Series::from_data(vec![1_i64, 5]),
+            ],
+            expect: Series::from_data(vec!["X X X", "abc X X"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-occurrence-passed",
+            columns: vec![
+                Series::from_data(vec!["abc def ghi", "abc def ghi", "abc def ghi"]),
+                Series::from_data(vec!["[a-z]+", "[a-z]+", "[a-z]+"]),
+                Series::from_data(vec!["X", "X", "X"]),
+                Series::from_data(vec![1_i64, 1, 1]),
+                Series::from_data(vec![2_i64, 3, 4]),
+            ],
+            expect: Series::from_data(vec!["abc X ghi", "abc def X", "abc def ghi"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-match-type-passed",
+            columns: vec![
+                Series::from_data(vec!["ABC", "ABC"]),
+                Series::from_data(vec!["b", "b"]),
+                Series::from_data(vec!["x", "x"]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec![0_i64, 0]),
+                Series::from_data(vec!["i", "c"]),
+            ],
+            expect: Series::from_data(vec!["AxC", "ABC"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-match-type-error",
+            columns: vec![
+                Series::from_data(vec!["ABC"]),
+                Series::from_data(vec!["b"]),
+                Series::from_data(vec!["x"]),
+                Series::from_data(vec![1_i64]),
+                Series::from_data(vec![0_i64]),
+                Series::from_data(vec!["x"]),
+            ],
+            expect: Series::from_data(Vec::<&str>::new()),
+            error: "Incorrect arguments to regexp_replace match type: x",
+        },
+    ];
+
+    test_scalar_functions(
+        RegexpReplaceFunction::try_create("regexp_replace")?,
+        &tests,
+        true,
+    )
+}
@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpSplitToArrayFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_regexp_split_to_array_function() -> Result<()> {
+    let string_array = |rows: Vec<Vec<&str>>| -> Result<ColumnRef> {
+        let mut builder = MutableArrayColumn::with_capacity(ArrayType::arc(StringType::arc()), 3);
+        for row in rows {
+            builder.append_value(
+                row.into_iter()
+                    .map(|v| DataValue::String(v.as_bytes().to_vec()))
+                    .collect(),
+            )?;
+        }
+        Ok(builder.to_column())
+    };
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "regexp-split-to-array-passed",
+            columns: vec![
+                Series::from_data(vec!["a1b22c", "hello  world", ""]),
+                Series::from_data(vec!["[0-9]+", "\\s+", ","]),
+            ],
+            expect: string_array(vec![vec!["a", "b", "c"], vec!["hello", "world"], vec![""]])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-split-to-array-match-type-passed",
+            columns: vec![
+                Series::from_data(vec!["aXbxc", "aXbxc"]),
+                Series::from_data(vec!["x", "x"]),
+                Series::from_data(vec!["i", "c"]),
+            ],
+            expect: string_array(vec![vec!["a", "b", "c"], vec!["aXb", "c"]])?,
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(
+        RegexpSplitToArrayFunction::try_create("regexp_split_to_array")?,
+        &tests,
+        true,
+    )
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
@@ -55,3 +57,31 @@ fn test_tuple_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_struct_get_function() -> Result<()> {
+    let data_type: DataTypePtr = Arc::new(StructType::create(
+        vec!["a".to_string(), "b".to_string()],
+        vec![Int64Type::arc(), StringType::arc()],
+    ));
+    let column: ColumnRef = Arc::new(StructColumn::from_data(
+        vec![Series::from_data([1i64, 2]), Series::from_data(["x", "y"])],
+        data_type.clone(),
+    ));
+
+    let field = DataValue::String(b"b".to_vec());
+    let func = StructGetFunction::try_create("get", &data_type, &field)?;
+    let result = test_eval(&func, &[column.clone()], false)?;
+    assert_eq!(result, Series::from_data(["x", "y"]));
+
+    let func = StructGetFunction::try_create("get", &data_type, &DataValue::UInt64(0))?;
+    let result = test_eval(&func, &[column], false)?;
+    assert_eq!(result, Series::from_data([1i64, 2]));
+
+    let res = StructGetFunction::try_create("get", &data_type, &DataValue::UInt64(2));
+    assert_eq!(
+        res.err().unwrap().message(),
+        "Struct with fields (a, b) has no field 2"
+    );
+    Ok(())
+}
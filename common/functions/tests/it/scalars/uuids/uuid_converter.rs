@@ -0,0 +1,92 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_uuid_to_bin_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "uuid-to-bin-passed",
+            columns: vec![Series::from_data(vec![
+                "6ccd780c-baba-1026-9564-5b8c656024db",
+            ])],
+            expect: Series::from_data(vec![vec![
+                0x6c_u8, 0xcd, 0x78, 0x0c, 0xba, 0xba, 0x10, 0x26, 0x95, 0x64, 0x5b, 0x8c, 0x65,
+                0x60, 0x24, 0xdb,
+            ]]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "uuid-to-bin-swap-passed",
+            columns: vec![
+                Series::from_data(vec!["6ccd780c-baba-1026-9564-5b8c656024db"]),
+                Series::from_data(vec![1_u8]),
+            ],
+            expect: Series::from_data(vec![vec![
+                0x10_u8, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64, 0x5b, 0x8c, 0x65,
+                0x60, 0x24, 0xdb,
+            ]]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "uuid-to-bin-failed",
+            columns: vec![Series::from_data(vec!["6ccd780c"])],
+            expect: Series::from_data(Vec::<Vec<u8>>::new()),
+            error: "Incorrect string value: '6ccd780c' for function uuid_to_bin",
+        },
+    ];
+
+    test_scalar_functions(UUIDToBinFunction::try_create("uuid_to_bin")?, &tests, true)
+}
+
+#[test]
+fn test_bin_to_uuid_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bin-to-uuid-passed",
+            columns: vec![Series::from_data(vec![vec![
+                0x6c_u8, 0xcd, 0x78, 0x0c, 0xba, 0xba, 0x10, 0x26, 0x95, 0x64, 0x5b, 0x8c, 0x65,
+                0x60, 0x24, 0xdb,
+            ]])],
+            expect: Series::from_data(vec!["6ccd780c-baba-1026-9564-5b8c656024db"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bin-to-uuid-swap-passed",
+            columns: vec![
+                Series::from_data(vec![vec![
+                    0x10_u8, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64, 0x5b, 0x8c,
+                    0x65, 0x60, 0x24, 0xdb,
+                ]]),
+                Series::from_data(vec![1_u8]),
+            ],
+            expect: Series::from_data(vec!["6ccd780c-baba-1026-9564-5b8c656024db"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bin-to-uuid-failed",
+            columns: vec![Series::from_data(vec!["abc"])],
+            expect: Series::from_data(Vec::<&str>::new()),
+            error: "Incorrect string value for function bin_to_uuid, expected 16 bytes, but got 3",
+        },
+    ];
+
+    test_scalar_functions(BinToUUIDFunction::try_create("bin_to_uuid")?, &tests, true)
+}
@@ -42,3 +42,19 @@ fn test_uuid_creator_functions() -> Result<()> {
 
     test_scalar_functions(UUIDZeroFunction::try_create("")?, &tests, true)
 }
+
+#[test]
+fn test_gen_random_uuid_function() -> Result<()> {
+    let func = UUIDv4Function::try_create("gen_random_uuid")?;
+    let column = func.eval(&[], 3)?;
+    assert!(!column.is_const());
+    assert_eq!(column.len(), 3);
+
+    let uuids = (0..3)
+        .map(|i| column.get_string(i))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(uuids.iter().all(|uuid| uuid.len() == 36));
+    assert_ne!(uuids[0], uuids[1]);
+    assert_ne!(uuids[1], uuids[2]);
+    Ok(())
+}
@@ -48,3 +48,20 @@ fn test_uuid_is_not_empty_functions() -> Result<()> {
 
     test_scalar_functions(UUIDIsNotEmptyFunction::try_create("")?, &tests, false)
 }
+
+#[test]
+fn test_is_uuid_functions() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "is-uuid-passed",
+        columns: vec![Series::from_data(vec![
+            "59b69da3-81d0-4db2-96e8-3e20b505a7b2",
+            "59b69da381d04db296e83e20b505a7b2",
+            "00000000-0000-0000-0000-000000000000",
+            "59b69da3-81d0-4db2-96e8",
+        ])],
+        expect: Series::from_data(vec![true, true, true, false]),
+        error: "",
+    }];
+
+    test_scalar_functions(UUIDIsValidFunction::try_create("")?, &tests, false)
+}
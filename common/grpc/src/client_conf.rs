@@ -17,12 +17,19 @@
 pub struct RpcClientTlsConfig {
     pub rpc_tls_server_root_ca_cert: String,
     pub domain_name: String,
+    /// The certificate and the key to identify the client, if the server verifies the clients.
+    pub rpc_tls_client_cert: String,
+    pub rpc_tls_client_key: String,
 }
 
 impl RpcClientTlsConfig {
     pub fn enabled(&self) -> bool {
         !self.rpc_tls_server_root_ca_cert.is_empty() && !self.domain_name.is_empty()
     }
+
+    pub fn client_identity_enabled(&self) -> bool {
+        !self.rpc_tls_client_cert.is_empty() && !self.rpc_tls_client_key.is_empty()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
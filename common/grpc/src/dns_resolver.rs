@@ -37,6 +37,7 @@ use serde::Serialize;
 use tonic::transport::Certificate;
 use tonic::transport::Channel;
 use tonic::transport::ClientTlsConfig;
+use tonic::transport::Identity;
 use trust_dns_resolver::TokioAsyncResolver;
 
 use crate::RpcClientTlsConfig;
@@ -195,9 +196,17 @@ impl ConnectionFactory {
         let server_root_ca_cert = std::fs::read(conf.rpc_tls_server_root_ca_cert.as_str())?;
         let server_root_ca_cert = Certificate::from_pem(server_root_ca_cert);
 
-        let tls = ClientTlsConfig::new()
+        let mut tls = ClientTlsConfig::new()
             .domain_name(conf.domain_name.to_string())
             .ca_certificate(server_root_ca_cert);
+
+        // The files are read for every new channel, so the rotated certificates are used by
+        // the new connections.
+        if conf.client_identity_enabled() {
+            let client_cert = std::fs::read(conf.rpc_tls_client_cert.as_str())?;
+            let client_key = std::fs::read(conf.rpc_tls_client_key.as_str())?;
+            tls = tls.identity(Identity::from_pem(client_cert, client_key));
+        }
         Ok(tls)
     }
 }
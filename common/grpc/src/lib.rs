@@ -19,7 +19,14 @@ pub use dns_resolver::DNSResolver;
 pub use dns_resolver::GrpcConnectionError;
 pub use grpc_token::GrpcClaim;
 pub use grpc_token::GrpcToken;
+pub use tls_server::RpcServerTlsConfig;
+pub use tls_server::TlsConnection;
+pub use tls_server::TlsIncoming;
+pub use tls_watcher::TlsFilesWatcher;
+pub use tls_watcher::TLS_RELOAD_INTERVAL;
 
 mod client_conf;
 mod dns_resolver;
 mod grpc_token;
+mod tls_server;
+mod tls_watcher;
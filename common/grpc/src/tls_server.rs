@@ -0,0 +1,224 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncWrite;
+use common_base::tokio::io::ReadBuf;
+use common_base::tokio::net::TcpListener;
+use common_base::tokio::net::TcpStream;
+use common_base::tokio::sync::mpsc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_infallible::Mutex;
+use common_tracing::tracing;
+use rustls_pemfile::Item;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::server::Connected;
+use tonic::transport::server::TcpConnectInfo;
+
+use crate::TlsFilesWatcher;
+
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, Default)]
+pub struct RpcServerTlsConfig {
+    pub rpc_tls_server_cert: String,
+    pub rpc_tls_server_key: String,
+    /// The CA to verify the client certificates, the clients are not verified if it is empty.
+    pub rpc_tls_server_root_ca_cert: String,
+}
+
+/// The TLS incoming connections of the gRPC servers.
+///
+/// The certificate files are watched by [`TlsFilesWatcher`] and reloaded if they are modified,
+/// so the certificates can be rotated without restarting the server. The established
+/// connections keep the certificates they are accepted with.
+pub struct TlsIncoming {
+    conf: RpcServerTlsConfig,
+    acceptor: Mutex<TlsAcceptor>,
+}
+
+impl TlsIncoming {
+    /// Accept the connections of the listener, fails if the certificates cannot be loaded.
+    pub fn create(
+        conf: RpcServerTlsConfig,
+        listener: TcpListener,
+    ) -> Result<ReceiverStream<io::Result<TlsConnection>>> {
+        let watcher = TlsFilesWatcher::create(vec![
+            conf.rpc_tls_server_cert.clone(),
+            conf.rpc_tls_server_key.clone(),
+            conf.rpc_tls_server_root_ca_cert.clone(),
+        ]);
+        let acceptor = Self::load(&conf)?;
+        let incoming = Arc::new(TlsIncoming {
+            conf,
+            acceptor: Mutex::new(acceptor),
+        });
+        watcher.spawn(Arc::downgrade(&incoming), |incoming: &TlsIncoming| {
+            let acceptor = Self::load(&incoming.conf)?;
+            *incoming.acceptor.lock() = acceptor;
+            Ok(())
+        });
+
+        let (tx, rx) = mpsc::channel(64);
+        tokio::spawn(async move {
+            loop {
+                // Stop accepting once the server drops the incoming stream.
+                let accepted = tokio::select! {
+                    accepted = listener.accept() => accepted,
+                    _ = tx.closed() => break,
+                };
+
+                let stream = match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(error) => {
+                        tracing::warn!("gRPC server cannot accept connection: {}", error);
+                        continue;
+                    }
+                };
+
+                let acceptor = incoming.acceptor.lock().clone();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match Self::handshake(acceptor, stream).await {
+                        Ok(connection) => {
+                            let _ = tx.send(Ok(connection)).await;
+                        }
+                        Err(error) => tracing::warn!("gRPC TLS handshake failed: {}", error),
+                    }
+                });
+            }
+        });
+
+        Ok(ReceiverStream::new(rx))
+    }
+
+    async fn handshake(acceptor: TlsAcceptor, stream: TcpStream) -> io::Result<TlsConnection> {
+        match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+            Ok(stream) => Ok(TlsConnection(stream?)),
+            Err(_) => Err(io::Error::new(io::ErrorKind::TimedOut, "handshake timeout")),
+        }
+    }
+
+    fn load(conf: &RpcServerTlsConfig) -> Result<TlsAcceptor> {
+        let certs = load_certs(&conf.rpc_tls_server_cert)?;
+        let key = load_private_key(&conf.rpc_tls_server_key)?;
+
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = match conf.rpc_tls_server_root_ca_cert.is_empty() {
+            true => builder.with_no_client_auth(),
+            false => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(&conf.rpc_tls_server_root_ca_cert)? {
+                    roots
+                        .add(&cert)
+                        .map_err_to_code(ErrorCode::TLSConfigurationFailure, || {
+                            "Invalid gRPC server TLS root CA certificate"
+                        })?;
+                }
+                builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots))
+            }
+        };
+
+        let mut server_config = builder
+            .with_single_cert(certs, key)
+            .map_err_to_code(ErrorCode::TLSConfigurationFailure, || {
+                "Invalid gRPC server TLS certificate or key"
+            })?;
+        server_config.alpn_protocols = vec![b"h2".to_vec()];
+        Ok(TlsAcceptor::from(Arc::new(server_config)))
+    }
+}
+
+fn load_certs(path: &str) -> Result<Vec<Certificate>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    match certs.is_empty() {
+        true => Err(ErrorCode::TLSConfigurationFailure(format!(
+            "No certificate found in {}",
+            path
+        ))),
+        false => Ok(certs.into_iter().map(Certificate).collect()),
+    }
+}
+
+fn load_private_key(path: &str) -> Result<PrivateKey> {
+    let mut reader = BufReader::new(File::open(path)?);
+    while let Some(item) = rustls_pemfile::read_one(&mut reader)? {
+        if let Item::RSAKey(key) | Item::PKCS8Key(key) = item {
+            return Ok(PrivateKey(key));
+        }
+    }
+    Err(ErrorCode::TLSConfigurationFailure(format!(
+        "No private key found in {}",
+        path
+    )))
+}
+
+/// The TLS connection accepted by [`TlsIncoming`].
+pub struct TlsConnection(TlsStream<TcpStream>);
+
+impl Connected for TlsConnection {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.0.get_ref().0.connect_info()
+    }
+}
+
+impl AsyncRead for TlsConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TlsConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
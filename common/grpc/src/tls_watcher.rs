@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_tracing::tracing;
+
+/// How often the modification times of the TLS files are checked.
+pub const TLS_RELOAD_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Watches the modification times of the TLS files, so the certificates can be rotated
+/// without restarting the node.
+///
+/// The files are polled in a background task, the connection paths never touch the file system
+/// to find out if the certificates are changed.
+pub struct TlsFilesWatcher {
+    paths: Vec<String>,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl TlsFilesWatcher {
+    /// Record the modification times of the files, it should be created before the files are
+    /// loaded, so a change in between is not missed. The empty paths are ignored.
+    pub fn create(paths: Vec<String>) -> TlsFilesWatcher {
+        let paths = paths.into_iter().filter(|p| !p.is_empty()).collect();
+        let modified = Self::modified(&paths);
+        TlsFilesWatcher { paths, modified }
+    }
+
+    /// Poll the files in a background task, and call `reload` on the owner when any of them is
+    /// modified. The task stops once the owner is dropped.
+    ///
+    /// If `reload` fails (e.g. the files are being written), it is called again on the next poll.
+    pub fn spawn<T, F>(mut self, owner: Weak<T>, reload: F)
+    where
+        T: Send + Sync + 'static,
+        F: Fn(&T) -> Result<()> + Send + Sync + 'static,
+    {
+        let reload = Arc::new(reload);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(TLS_RELOAD_INTERVAL).await;
+                if owner.strong_count() == 0 {
+                    break;
+                }
+
+                // The metadata and the certificates are read on the blocking threads.
+                let paths = self.paths.clone();
+                let owner = owner.clone();
+                let reload = reload.clone();
+                let loaded = self.modified.clone();
+                let reloaded = tokio::task::spawn_blocking(move || {
+                    let modified = Self::modified(&paths);
+                    if modified == loaded {
+                        return Ok(None);
+                    }
+                    match owner.upgrade() {
+                        None => Ok(None),
+                        Some(owner) => reload(&owner).map(|_| Some(modified)),
+                    }
+                })
+                .await;
+
+                match reloaded {
+                    Ok(Ok(Some(modified))) => {
+                        tracing::info!("TLS certificates reloaded: {:?}", self.paths);
+                        self.modified = modified;
+                    }
+                    Ok(Ok(None)) => {}
+                    Ok(Err(cause)) => {
+                        tracing::warn!("Cannot reload the TLS certificates: {}", cause)
+                    }
+                    Err(cause) => {
+                        tracing::warn!("Cannot reload the TLS certificates: {}", cause)
+                    }
+                }
+            }
+        });
+    }
+
+    fn modified(paths: &[String]) -> Vec<Option<SystemTime>> {
+        paths
+            .iter()
+            .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .collect()
+    }
+}
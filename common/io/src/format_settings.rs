@@ -24,7 +24,9 @@ pub struct FormatSettings {
     pub record_delimiter: Vec<u8>,
     pub field_delimiter: Vec<u8>,
     pub empty_as_default: bool,
-    pub skip_header: bool,
+    pub error_as_null: bool,
+    pub skip_header: u64,
+    pub json_strict: bool,
     pub compression: Compression,
 }
 
@@ -34,7 +36,9 @@ impl Default for FormatSettings {
             record_delimiter: vec![b'\n'],
             field_delimiter: vec![b','],
             empty_as_default: false,
-            skip_header: false,
+            error_as_null: false,
+            skip_header: 0,
+            json_strict: true,
             compression: Compression::None,
         }
     }
@@ -52,6 +56,7 @@ pub enum Compression {
     RawDeflate,
     Lzo,
     Snappy,
+    Xz,
 }
 
 impl Default for Compression {
@@ -74,6 +79,7 @@ impl FromStr for Compression {
             "rawdeflate" => Ok(Compression::RawDeflate),
             "lzo" => Ok(Compression::Lzo),
             "snappy" => Ok(Compression::Snappy),
+            "xz" => Ok(Compression::Xz),
             "none" => Ok(Compression::None),
             _ => Err(ErrorCode::IllegalUserSettingFormat(format!(
                 "Unknown compression: {}",
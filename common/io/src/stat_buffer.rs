@@ -83,6 +83,14 @@ impl StatBuffer for i64 {
     }
 }
 
+impl StatBuffer for i128 {
+    type Buffer = [u8; 16];
+
+    fn buffer() -> Self::Buffer {
+        [0; 16]
+    }
+}
+
 impl StatBuffer for f32 {
     type Buffer = [u8; 4];
 
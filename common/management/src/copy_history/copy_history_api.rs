@@ -0,0 +1,34 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+
+#[async_trait::async_trait]
+pub trait CopyHistoryApi: Sync + Send {
+    // Add the files loaded into a table to /tenant/table-id/file, a file added again replaces the old one.
+    // The files are removed after expire_secs, they are kept forever if expire_secs is 0.
+    async fn add_copied_files(
+        &self,
+        table_id: u64,
+        files: Vec<CopiedFileInfo>,
+        expire_secs: u64,
+    ) -> Result<()>;
+
+    // Get all the files loaded into a table and not expired.
+    async fn get_copied_files(&self, table_id: u64) -> Result<Vec<CopiedFileInfo>>;
+
+    // Remove all the files loaded into a table, e.g. the table is truncated.
+    async fn remove_copied_files(&self, table_id: u64) -> Result<()>;
+}
@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+use common_base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+
+use crate::copy_history::CopyHistoryApi;
+
+static COPY_HISTORY_API_KEY_PREFIX: &str = "__fd_copy_history";
+
+pub struct CopyHistoryMgr {
+    kv_api: Arc<dyn KVApi>,
+    copy_history_prefix: String,
+}
+
+impl CopyHistoryMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while copy history mgr create)",
+            ));
+        }
+
+        Ok(CopyHistoryMgr {
+            kv_api,
+            copy_history_prefix: format!(
+                "{}/{}",
+                COPY_HISTORY_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn table_prefix(&self, table_id: u64) -> String {
+        format!("{}/{}", self.copy_history_prefix, table_id)
+    }
+
+    fn new_expire_time(expire_secs: u64) -> Option<KVMeta> {
+        if expire_secs == 0 {
+            return None;
+        }
+
+        let expire_at = std::time::SystemTime::now()
+            .add(Duration::from_secs(expire_secs))
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        Some(KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyHistoryApi for CopyHistoryMgr {
+    async fn add_copied_files(
+        &self,
+        table_id: u64,
+        files: Vec<CopiedFileInfo>,
+        expire_secs: u64,
+    ) -> Result<()> {
+        let prefix = self.table_prefix(table_id);
+        for file in files {
+            let key = format!("{}/{}", prefix, escape_for_key(&file.file)?);
+            let val = Operation::Update(serde_json::to_vec(&file)?);
+            let meta = Self::new_expire_time(expire_secs);
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Any, val, meta))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_copied_files(&self, table_id: u64) -> Result<Vec<CopiedFileInfo>> {
+        // The trailing '/' keeps the files of the table 1 from the files of the table 10.
+        let prefix = format!("{}/", self.table_prefix(table_id));
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut files = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let file = serde_json::from_slice::<CopiedFileInfo>(&value.data)?;
+            files.push(file);
+        }
+        Ok(files)
+    }
+
+    async fn remove_copied_files(&self, table_id: u64) -> Result<()> {
+        let prefix = format!("{}/", self.table_prefix(table_id));
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        for (key, _) in values {
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(
+                    &key,
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+}
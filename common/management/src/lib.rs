@@ -13,7 +13,11 @@
 // limitations under the License.
 
 mod cluster;
+mod copy_history;
+mod masking_policy;
+mod network_policy;
 mod role;
+mod row_access_policy;
 mod setting;
 mod stage;
 mod udf;
@@ -22,8 +26,16 @@ mod warehouse;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use copy_history::CopyHistoryApi;
+pub use copy_history::CopyHistoryMgr;
+pub use masking_policy::MaskingPolicyApi;
+pub use masking_policy::MaskingPolicyMgr;
+pub use network_policy::NetworkPolicyApi;
+pub use network_policy::NetworkPolicyMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
@@ -79,9 +79,14 @@ impl UserMgr {
                 None,
             ))
             .await?;
-        match res.result {
-            Some(SeqV { seq: s, .. }) => Ok(s),
-            None => Err(ErrorCode::UnknownUser(format!(
+        match (res.result, seq) {
+            (Some(SeqV { seq: s, .. }), _) => Ok(s),
+            // The user exists, but it's changed since the given seq.
+            (None, Some(seq)) if res.prev.is_some() => Err(ErrorCode::UserSeqMismatch(format!(
+                "user {} is changed since seq {}",
+                user_info.name, seq
+            ))),
+            (None, _) => Err(ErrorCode::UnknownUser(format!(
                 "unknown user, or seq not match {}",
                 user_info.name
             ))),
@@ -123,7 +128,11 @@ impl UserApi for UserMgr {
 
         match MatchSeq::from(seq).match_seq(&seq_value) {
             Ok(_) => Ok(seq_value.into_seqv()?),
-            Err(_) => Err(ErrorCode::UnknownUser(format!("unknown user {}", user_key))),
+            Err(_) => Err(ErrorCode::UserSeqMismatch(format!(
+                "user {} is changed since seq {}",
+                user_key,
+                seq.unwrap_or_default()
+            ))),
         }
     }
 
@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::CopiedFileInfo;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_copied_files() -> Result<()> {
+    let (kv_api, copy_history_api) = new_copy_history_api().await?;
+
+    let file = create_test_file_info("books.csv", "e1");
+    copy_history_api
+        .add_copied_files(1, vec![file.clone()], 0)
+        .await?;
+
+    let value = kv_api
+        .get_kv("__fd_copy_history/admin/1/books%2ecsv")
+        .await?;
+    match value {
+        Some(v) => {
+            assert_eq!(v.data, serde_json::to_vec(&file)?);
+            assert!(v.meta.is_none());
+        }
+        None => panic!("The copied file is not added"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_copied_files_with_expire() -> Result<()> {
+    let (kv_api, copy_history_api) = new_copy_history_api().await?;
+
+    let file = create_test_file_info("books.csv", "e1");
+    copy_history_api
+        .add_copied_files(1, vec![file], 3600)
+        .await?;
+
+    let value = kv_api
+        .get_kv("__fd_copy_history/admin/1/books%2ecsv")
+        .await?;
+    let expire_at = value.and_then(|v| v.meta).and_then(|m| m.expire_at);
+    assert!(expire_at.is_some());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_copied_files() -> Result<()> {
+    let (_, copy_history_api) = new_copy_history_api().await?;
+
+    let files = copy_history_api.get_copied_files(1).await?;
+    assert_eq!(files, vec![]);
+
+    let file_a = create_test_file_info("a.csv", "e1");
+    let file_b = create_test_file_info("b.csv", "e2");
+    copy_history_api
+        .add_copied_files(1, vec![file_a.clone(), file_b.clone()], 0)
+        .await?;
+    // The files of the table 10 are not the files of the table 1.
+    copy_history_api
+        .add_copied_files(10, vec![create_test_file_info("c.csv", "e3")], 0)
+        .await?;
+
+    let files = copy_history_api.get_copied_files(1).await?;
+    assert_eq!(files, vec![file_a.clone(), file_b]);
+
+    // A file added again replaces the old one.
+    let file_b = create_test_file_info("b.csv", "e4");
+    copy_history_api
+        .add_copied_files(1, vec![file_b.clone()], 0)
+        .await?;
+    let files = copy_history_api.get_copied_files(1).await?;
+    assert_eq!(files, vec![file_a, file_b]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_remove_copied_files() -> Result<()> {
+    let (_, copy_history_api) = new_copy_history_api().await?;
+
+    let file_a = create_test_file_info("a.csv", "e1");
+    let file_c = create_test_file_info("c.csv", "e3");
+    copy_history_api
+        .add_copied_files(1, vec![file_a], 0)
+        .await?;
+    copy_history_api
+        .add_copied_files(10, vec![file_c.clone()], 0)
+        .await?;
+
+    copy_history_api.remove_copied_files(1).await?;
+    assert_eq!(copy_history_api.get_copied_files(1).await?, vec![]);
+    assert_eq!(copy_history_api.get_copied_files(10).await?, vec![file_c]);
+
+    // Remove the files of a table without any files.
+    copy_history_api.remove_copied_files(2).await?;
+    Ok(())
+}
+
+fn create_test_file_info(file: &str, etag: &str) -> CopiedFileInfo {
+    CopiedFileInfo {
+        file: file.to_string(),
+        etag: etag.to_string(),
+        size: 10,
+        rows_loaded: 2,
+        load_time: 1,
+    }
+}
+
+async fn new_copy_history_api() -> Result<(Arc<MetaEmbedded>, CopyHistoryMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = CopyHistoryMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 mod cluster;
+mod copy_history;
+mod masking_policy;
+mod network_policy;
+mod row_access_policy;
 mod setting;
 mod stage;
 mod udf;
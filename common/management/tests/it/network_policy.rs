@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::NetworkPolicy;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_network_policies/admin/office_only")
+        .await?;
+    assert_eq!(value.map(|v| v.data), Some(serde_json::to_vec(&policy)?));
+
+    match policy_api.add_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2106),
+    }
+
+    let got = policy_api.get_policy("office_only", None).await?;
+    assert_eq!(got.data, policy);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_policy_with_invalid_ip() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    let policy = NetworkPolicy::new("p", vec!["192.168.1.0/40".to_string()], vec![]);
+    assert!(policy_api.add_policy(policy).await.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_policy() -> Result<()> {
+    let (_, policy_api) = new_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    assert_eq!(policy_api.get_policies().await?, vec![policy.clone()]);
+
+    policy_api.drop_policy(&policy.name, None).await?;
+    assert_eq!(policy_api.get_policies().await?, vec![]);
+
+    match policy_api.drop_policy(&policy.name, None).await {
+        Ok(_) => panic!("Unknown policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2105),
+    }
+    Ok(())
+}
+
+fn create_test_policy() -> NetworkPolicy {
+    let mut policy = NetworkPolicy::new("office_only", vec!["192.168.1.0/24".to_string()], vec![
+        "192.168.1.99".to_string(),
+    ]);
+    policy.comment = "the office network".to_string();
+    policy
+}
+
+async fn new_policy_api() -> Result<(Arc<MetaEmbedded>, NetworkPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NetworkPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
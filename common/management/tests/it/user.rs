@@ -29,6 +29,8 @@ use common_meta_types::Operation;
 use common_meta_types::PasswordHashMethod;
 use common_meta_types::PrefixListReply;
 use common_meta_types::SeqV;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UserIdentity;
@@ -53,6 +55,8 @@ mock! {
         ) -> Result<MGetKVActionReply,MetaError>;
 
         async fn prefix_list_kv(&self, prefix: &str) -> Result<PrefixListReply, MetaError>;
+
+        async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError>;
         }
 }
 
@@ -290,7 +294,10 @@ mod get {
             .get_user(UserIdentity::new(test_user_name, test_hostname), Some(2))
             .await;
         assert!(res.is_err());
-        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownUser("").code());
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UserSeqMismatch("").code()
+        );
         Ok(())
     }
 
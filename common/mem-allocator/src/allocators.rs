@@ -48,6 +48,49 @@ mod platform {
         None
     }
 
+    /// The stats of jemalloc in bytes, the stats which cannot be read are skipped.
+    pub fn allocator_stats() -> Vec<(&'static str, usize)> {
+        unsafe {
+            // The stats are cached by jemalloc, they are refreshed by advancing the epoch.
+            let mut epoch: u64 = 1;
+            let mut epoch_len = std::mem::size_of::<u64>();
+            let epoch_ptr = &mut epoch as *mut u64 as *mut c_void;
+            ffi::mallctl(
+                b"epoch\0".as_ptr() as *const _,
+                epoch_ptr,
+                &mut epoch_len,
+                epoch_ptr,
+                epoch_len,
+            );
+
+            let names: [(&'static str, &[u8]); 6] = [
+                ("allocated", b"stats.allocated\0"),
+                ("active", b"stats.active\0"),
+                ("metadata", b"stats.metadata\0"),
+                ("resident", b"stats.resident\0"),
+                ("mapped", b"stats.mapped\0"),
+                ("retained", b"stats.retained\0"),
+            ];
+
+            let mut stats = Vec::with_capacity(names.len());
+            for (name, key) in names {
+                let mut value: usize = 0;
+                let mut value_len = std::mem::size_of::<usize>();
+                let ret = ffi::mallctl(
+                    key.as_ptr() as *const _,
+                    &mut value as *mut usize as *mut c_void,
+                    &mut value_len,
+                    std::ptr::null_mut(),
+                    0,
+                );
+                if ret == 0 {
+                    stats.push((name, value));
+                }
+            }
+            stats
+        }
+    }
+
     /// Memory allocation APIs compatible with libc
     pub mod libc_compat {
         pub use super::ffi::free;
@@ -20,6 +20,9 @@ use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TxnCondition;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVAction;
 use common_tracing::tracing;
 
@@ -42,6 +45,7 @@ impl KVApiTestSuite {
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
+        self.kv_transaction(&builder.build().await).await?;
 
         // Run cross node test on every 2 adjacent nodes
 
@@ -532,6 +536,97 @@ impl KVApiTestSuite {
 
         Ok(())
     }
+
+    #[tracing::instrument(level = "info", skip(self, kv))]
+    pub async fn kv_transaction<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        kv.upsert_kv(UpsertKVAction::new(
+            "txn_k1",
+            MatchSeq::Any,
+            Operation::Update(b"v1".to_vec()),
+            None,
+        ))
+        .await?;
+
+        tracing::info!("--- all conditions satisfied: apply if_then");
+        {
+            let txn = TxnRequest {
+                condition: vec![
+                    TxnCondition::new("txn_k1", MatchSeq::Exact(1)),
+                    TxnCondition::new("txn_k2", MatchSeq::Exact(0)),
+                ],
+                if_then: vec![
+                    TxnOp::put("txn_k1", b"v2".to_vec()),
+                    TxnOp::put("txn_k2", b"v2".to_vec()),
+                ],
+                else_then: vec![],
+            };
+            let res = kv.transaction(txn).await?;
+            assert!(res.success);
+            assert_eq!(res.changes.len(), 2);
+            assert_eq!(res.changes[0].ident, Some("txn_k1".to_string()));
+            assert_eq!(res.changes[0].prev, Some(SeqV::new(1, b"v1".to_vec())));
+            assert_eq!(res.changes[0].result, Some(SeqV::new(2, b"v2".to_vec())));
+            assert_eq!(res.changes[1].prev, None);
+            assert_eq!(res.changes[1].result, Some(SeqV::new(3, b"v2".to_vec())));
+
+            let res = kv
+                .mget_kv(&["txn_k1".to_string(), "txn_k2".to_string()])
+                .await?;
+            assert_eq!(res, vec![
+                Some(SeqV::new(2, b"v2".to_vec())),
+                Some(SeqV::new(3, b"v2".to_vec())),
+            ]);
+        }
+
+        tracing::info!("--- a stale condition: apply else_then");
+        {
+            let txn = TxnRequest {
+                condition: vec![
+                    TxnCondition::new("txn_k1", MatchSeq::Exact(1)),
+                    TxnCondition::new("txn_k2", MatchSeq::Exact(3)),
+                ],
+                if_then: vec![TxnOp::delete("txn_k1"), TxnOp::delete("txn_k2")],
+                else_then: vec![TxnOp::put("txn_k3", b"v3".to_vec())],
+            };
+            let res = kv.transaction(txn).await?;
+            assert!(!res.success);
+            assert_eq!(res.changes.len(), 1);
+            assert_eq!(res.changes[0].ident, Some("txn_k3".to_string()));
+
+            let res = kv
+                .mget_kv(&[
+                    "txn_k1".to_string(),
+                    "txn_k2".to_string(),
+                    "txn_k3".to_string(),
+                ])
+                .await?;
+            assert_eq!(res, vec![
+                Some(SeqV::new(2, b"v2".to_vec())),
+                Some(SeqV::new(3, b"v2".to_vec())),
+                Some(SeqV::new(4, b"v3".to_vec())),
+            ]);
+        }
+
+        tracing::info!("--- delete in a transaction");
+        {
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::new("txn_k1", MatchSeq::GE(1))],
+                if_then: vec![TxnOp::delete("txn_k1"), TxnOp::delete("txn_k2")],
+                else_then: vec![],
+            };
+            let res = kv.transaction(txn).await?;
+            assert!(res.success);
+            assert_eq!(res.changes[0].prev, Some(SeqV::new(2, b"v2".to_vec())));
+            assert_eq!(res.changes[0].result, None);
+
+            let res = kv
+                .mget_kv(&["txn_k1".to_string(), "txn_k2".to_string()])
+                .await?;
+            assert_eq!(res, vec![None, None]);
+        }
+
+        Ok(())
+    }
 }
 
 /// Test that write and read should be forwarded to leader
@@ -24,9 +24,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -35,6 +39,10 @@ use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -62,12 +70,27 @@ pub trait MetaApi: Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply, MetaError>;
 
+    /// Restore the table dropped last by the name, the dropped table keeps its id and meta.
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError>;
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError>;
 
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, MetaError>;
 
+    /// List the dropped tables of a database that are not vacuumed yet.
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<DroppedTableInfo>, MetaError>;
+
+    /// Remove a dropped table and its meta, after its data are purged.
+    async fn gc_dropped_table(
+        &self,
+        req: GcDroppedTableReq,
+    ) -> Result<GcDroppedTableReply, MetaError>;
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -78,5 +101,12 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply, MetaError>;
 
+    /// Replace the schema of a table with a new version, the previous versions are kept in
+    /// `TableMeta::schema_history`.
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, MetaError>;
+
     fn name(&self) -> String;
 }
@@ -23,14 +23,18 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReq;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 
@@ -453,6 +457,7 @@ impl MetaApiTestSuite {
                 tenant: tenant.into(),
                 db: db_name.into(),
                 table: tbl_name.into(),
+                purge: false,
             };
 
             let got = mt.drop_table(plan).await;
@@ -593,6 +598,58 @@ impl MetaApiTestSuite {
                 assert_eq!(table.options().get("key1"), Some(&"val1".into()));
             }
         }
+
+        tracing::info!("--- update table schema");
+        {
+            let new_schema = Arc::new(DataSchema::new(vec![
+                DataField::new("number", u64::to_data_type()),
+                DataField::new("name", Vu8::to_data_type()),
+            ]));
+
+            tracing::info!("--- update table schema keeps the old version");
+            {
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(0, table.meta.schema_version);
+
+                let reply = mt
+                    .update_table_schema(UpdateTableSchemaReq::new(
+                        &table.ident,
+                        new_schema.clone(),
+                    ))
+                    .await?;
+                assert_eq!(1, reply.schema_version);
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(1, table.meta.schema_version);
+                assert_eq!(new_schema, table.schema());
+                assert_eq!(Some(schema()), table.meta.schema_at(0));
+                assert_eq!(Some(new_schema.clone()), table.meta.schema_at(1));
+                assert_eq!(None, table.meta.schema_at(2));
+                // options are not affected.
+                assert_eq!(table.options().get("key1"), Some(&"val1".into()));
+            }
+
+            tracing::info!("--- update table schema with mismatched version");
+            {
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+
+                let got = mt
+                    .update_table_schema(UpdateTableSchemaReq::new(
+                        &TableIdent {
+                            table_id: table.ident.table_id,
+                            version: table.ident.version - 1,
+                        },
+                        schema(),
+                    ))
+                    .await;
+
+                let err = ErrorCode::from(got.unwrap_err());
+                assert_eq!(ErrorCode::TableVersionMismatched("").code(), err.code());
+
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(1, table.meta.schema_version);
+            }
+        }
         tracing::info!("--- drop table");
         {
             tracing::info!("--- drop table with if_exists = false");
@@ -602,6 +659,7 @@ impl MetaApiTestSuite {
                     tenant: tenant.to_string(),
                     db: db_name.to_string(),
                     table: tbl_name.to_string(),
+                    purge: false,
                 };
                 mt.drop_table(plan.clone()).await?;
 
@@ -627,6 +685,7 @@ impl MetaApiTestSuite {
                     tenant: tenant.to_string(),
                     db: db_name.to_string(),
                     table: tbl_name.to_string(),
+                    purge: false,
                 };
                 let res = mt.drop_table(plan.clone()).await;
                 let err = res.unwrap_err();
@@ -645,6 +704,7 @@ impl MetaApiTestSuite {
                     tenant: tenant.to_string(),
                     db: db_name.to_string(),
                     table: tbl_name.to_string(),
+                    purge: false,
                 };
                 mt.drop_table(plan.clone()).await?;
             }
@@ -750,11 +810,12 @@ impl MetaApiTestSuite {
                 new_db: db_name.to_string(),
                 new_table_name: new_tbl_name.to_string(),
             };
-            mt.rename_table(req.clone()).await?;
+            let res = mt.rename_table(req.clone()).await?;
+            assert_eq!(1, res.table_id, "rename keeps the table id");
 
             let got = mt.get_table((tenant, db_name, new_tbl_name).into()).await?;
             let want = TableInfo {
-                ident: TableIdent::new(2, 2),
+                ident: TableIdent::new(1, 2),
                 desc: format!("'{}'.'{}'.'{}'", tenant, db_name, new_tbl_name),
                 name: new_tbl_name.into(),
                 meta: table_meta(created_on),
@@ -795,12 +856,12 @@ impl MetaApiTestSuite {
         tracing::info!("--- create table again after rename, ok");
         {
             let res = mt.create_table(req.clone()).await?;
-            assert_eq!(3, res.table_id, "table id is 3");
+            assert_eq!(2, res.table_id, "table id is 2");
 
             let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
 
             let want = TableInfo {
-                ident: TableIdent::new(3, 3),
+                ident: TableIdent::new(2, 3),
                 desc: format!("'{}'.'{}'.'{}'", tenant, db_name, tbl_name),
                 name: tbl_name.into(),
                 meta: table_meta(created_on),
@@ -876,13 +937,14 @@ impl MetaApiTestSuite {
                 new_db: new_db_name.to_string(),
                 new_table_name: new_tbl_name.to_string(),
             };
-            mt.rename_table(req.clone()).await?;
+            let res = mt.rename_table(req.clone()).await?;
+            assert_eq!(2, res.table_id, "rename to other db keeps the table id");
 
             let got = mt
                 .get_table((tenant, new_db_name, new_tbl_name).into())
                 .await?;
             let want = TableInfo {
-                ident: TableIdent::new(4, 4),
+                ident: TableIdent::new(2, 4),
                 desc: format!("'{}'.'{}'.'{}'", tenant, new_db_name, new_tbl_name),
                 name: new_tbl_name.into(),
                 meta: table_meta(created_on),
@@ -893,6 +955,232 @@ impl MetaApiTestSuite {
         Ok(())
     }
 
+    pub async fn table_drop_undrop<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb2";
+
+        let table_meta = |created_on| TableMeta {
+            schema: Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )])),
+            engine: "JSON".to_string(),
+            created_on,
+            ..TableMeta::default()
+        };
+        let drop_req = DropTableReq {
+            if_exists: false,
+            tenant: tenant.to_string(),
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+            purge: false,
+        };
+        let undrop_req = UndropTableReq {
+            tenant: tenant.to_string(),
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+        };
+
+        tracing::info!("--- prepare db");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+        }
+
+        let created_on = Utc::now();
+        let create_req = CreateTableReq {
+            if_not_exists: false,
+            tenant: tenant.to_string(),
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+            table_meta: table_meta(created_on),
+        };
+        let res = mt.create_table(create_req.clone()).await?;
+        assert_eq!(1, res.table_id, "table id is 1");
+        let created = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+
+        tracing::info!("--- undrop a present table, error");
+        {
+            let res = mt.undrop_table(undrop_req.clone()).await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        tracing::info!("--- drop table, the meta is kept by the table id");
+        {
+            mt.drop_table(drop_req.clone()).await?;
+
+            let res = mt.get_table((tenant, db_name, tbl_name).into()).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+            let res = mt.list_tables(ListTableReq::new(tenant, db_name)).await?;
+            assert!(res.is_empty(), "dropped table is not listed");
+
+            let (_, meta) = mt.get_table_by_id(1).await?;
+            assert_eq!(table_meta(created_on), meta.as_ref().clone());
+        }
+
+        tracing::info!("--- undrop table, ok");
+        {
+            let res = mt.undrop_table(undrop_req.clone()).await?;
+            assert_eq!(1, res.table_id, "undrop keeps the table id");
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(1, got.ident.table_id);
+            assert!(got.ident.version > created.ident.version);
+            assert_eq!(table_meta(created_on), got.meta);
+
+            let res = mt.undrop_table(undrop_req.clone()).await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                ErrorCode::from(res.unwrap_err()).code(),
+                "undrop table again"
+            );
+        }
+
+        tracing::info!("--- drop, recreate and drop again, every dropped table is kept");
+        {
+            mt.drop_table(drop_req.clone()).await?;
+            let res = mt.create_table(create_req.clone()).await?;
+            assert_eq!(2, res.table_id, "table id is 2");
+            mt.drop_table(drop_req.clone()).await?;
+
+            let (_, meta) = mt.get_table_by_id(1).await?;
+            assert_eq!(table_meta(created_on), meta.as_ref().clone());
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new(tenant, db_name))
+                .await?;
+            let ids = res
+                .iter()
+                .map(|d| d.table_info.ident.table_id)
+                .collect::<Vec<_>>();
+            assert_eq!(vec![1, 2], ids, "both dropped tables are listed");
+            assert!(res.iter().all(|d| d.table_info.name == tbl_name));
+
+            let res = mt.undrop_table(undrop_req.clone()).await?;
+            assert_eq!(2, res.table_id, "undrop the table dropped last");
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(1, res.len());
+            assert_eq!(1, res[0].table_info.ident.table_id);
+        }
+
+        tracing::info!("--- gc a dropped table, it can not be restored");
+        {
+            let gc_req = GcDroppedTableReq {
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                table: tbl_name.to_string(),
+                table_id: 1,
+            };
+            mt.gc_dropped_table(gc_req.clone()).await?;
+
+            let res = mt.get_table_by_id(1).await;
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new(tenant, db_name))
+                .await?;
+            assert!(res.is_empty(), "gc-ed table is not listed");
+
+            // gc an absent dropped table is a no-op
+            mt.gc_dropped_table(gc_req).await?;
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(2, got.ident.table_id);
+        }
+
+        tracing::info!("--- drop table with purge, the meta is removed");
+        {
+            let req = DropTableReq {
+                purge: true,
+                ..drop_req.clone()
+            };
+            mt.drop_table(req).await?;
+
+            let res = mt.get_table_by_id(2).await;
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+            let res = mt.undrop_table(undrop_req.clone()).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        tracing::info!("--- undrop unknown table, error");
+        {
+            let req = UndropTableReq {
+                table: "tb_unknown".to_string(),
+                ..undrop_req.clone()
+            };
+            let res = mt.undrop_table(req).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        tracing::info!("--- drop db, the dropped tables of it are removed");
+        {
+            let res = mt.create_table(create_req.clone()).await?;
+            let table_id = res.table_id;
+            mt.drop_table(drop_req.clone()).await?;
+
+            mt.drop_database(DropDatabaseReq {
+                if_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+            })
+            .await?;
+
+            let res = mt.get_table_by_id(table_id).await;
+            assert_eq!(
+                ErrorCode::UnknownTableId("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let res = mt
+                .list_dropped_tables(ListDroppedTableReq::new(tenant, db_name))
+                .await?;
+            assert!(res.is_empty(), "no dropped table in the re-created db");
+            let res = mt.undrop_table(undrop_req).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        Ok(())
+    }
+
     pub async fn table_list<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
         let db_name = "db1";
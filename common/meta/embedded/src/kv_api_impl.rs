@@ -19,6 +19,8 @@ use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaError;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 
@@ -45,4 +47,9 @@ impl KVApi for MetaEmbedded {
         let sm = self.inner.lock().await;
         sm.prefix_list_kv(prefix).await
     }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.transaction(txn).await
+    }
 }
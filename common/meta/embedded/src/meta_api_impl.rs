@@ -25,9 +25,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -36,6 +40,10 @@ use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -85,6 +93,11 @@ impl MetaApi for MetaEmbedded {
         Ok(reply)
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.undrop_table(req).await
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
         let sm = self.inner.lock().await;
         sm.rename_table(req).await
@@ -102,6 +115,22 @@ impl MetaApi for MetaEmbedded {
         Ok(reply)
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<DroppedTableInfo>, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.list_dropped_tables(req).await
+    }
+
+    async fn gc_dropped_table(
+        &self,
+        req: GcDroppedTableReq,
+    ) -> Result<GcDroppedTableReply, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.gc_dropped_table(req).await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -120,6 +149,15 @@ impl MetaApi for MetaEmbedded {
         Ok(reply)
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, MetaError> {
+        let sm = self.inner.lock().await;
+        let reply = sm.update_table_schema(req).await?;
+        Ok(reply)
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
@@ -57,3 +57,9 @@ async fn test_kv_mget() -> anyhow::Result<()> {
     let kv = MetaEmbedded::new_temp().await?;
     KVApiTestSuite {}.kv_mget(&kv).await
 }
+
+#[tokio::test]
+async fn test_kv_transaction() -> anyhow::Result<()> {
+    let kv = MetaEmbedded::new_temp().await?;
+    KVApiTestSuite {}.kv_transaction(&kv).await
+}
@@ -26,10 +26,14 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
@@ -37,6 +41,12 @@ use common_meta_types::PrefixListReply;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
 use common_meta_types::TableInfo;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -55,10 +65,14 @@ pub enum MetaGrpcWriteReq {
 
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
+    UndropTable(UndropTableReq),
+    GcDroppedTable(GcDroppedTableReq),
     RenameTable(RenameTableReq),
     CommitTable(UpsertTableOptionReq),
+    UpdateTableSchema(UpdateTableSchemaReq),
 
     UpsertKV(UpsertKVAction),
+    Transaction(TxnRequest),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, derive_more::From)]
@@ -69,6 +83,7 @@ pub enum MetaGrpcReadReq {
     GetTable(GetTableReq),
     GetTableExt(GetTableExtReq),
     ListTables(ListTableReq),
+    ListDroppedTables(ListDroppedTableReq),
 
     GetKV(GetKVAction),
     MGetKV(MGetKVAction),
@@ -174,6 +189,10 @@ impl RequestFor for UpsertKVAction {
     type Reply = UpsertKVActionReply;
 }
 
+impl RequestFor for TxnRequest {
+    type Reply = TxnReply;
+}
+
 // == database actions ==
 
 impl RequestFor for CreateDatabaseReq {
@@ -196,6 +215,14 @@ impl RequestFor for DropTableReq {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for UndropTableReq {
+    type Reply = UndropTableReply;
+}
+
+impl RequestFor for GcDroppedTableReq {
+    type Reply = GcDroppedTableReply;
+}
+
 impl RequestFor for RenameTableReq {
     type Reply = RenameTableReply;
 }
@@ -216,10 +243,18 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for UpdateTableSchemaReq {
+    type Reply = UpdateTableSchemaReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
 
+impl RequestFor for ListDroppedTableReq {
+    type Reply = Vec<DroppedTableInfo>;
+}
+
 impl RequestFor for ListDatabaseReq {
     type Reply = Vec<Arc<DatabaseInfo>>;
 }
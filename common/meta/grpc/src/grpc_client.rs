@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::convert::Infallible;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -21,19 +22,24 @@ use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_base::tokio::sync::RwLock;
 use common_containers::ItemManager;
 use common_containers::Pool;
+use common_containers::PoolItem;
 use common_exception::Result;
 use common_grpc::ConnectionFactory;
 use common_grpc::GrpcConnectionError;
 use common_grpc::RpcClientTlsConfig;
+use common_grpc::TlsFilesWatcher;
 use common_meta_types::anyerror::AnyError;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::ConnectionError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::WatchEvent;
 use common_tracing::tracing;
+use futures::stream::BoxStream;
 use futures::stream::StreamExt;
 use prost::Message;
 use serde::de::DeserializeOwned;
@@ -52,6 +58,8 @@ use crate::grpc_action::MetaGrpcWriteReq;
 use crate::grpc_action::RequestFor;
 use crate::MetaGrpcClientConf;
 
+type ChannelItems = std::sync::Mutex<HashMap<String, PoolItem<Channel>>>;
+
 #[derive(Debug)]
 struct MetaChannelManager {
     timeout: Option<Duration>,
@@ -109,7 +117,7 @@ impl MetaGrpcClient {
             conf: conf.meta_service_config.tls_conf.clone(),
         };
         Ok(Self {
-            conn_pool: Pool::new(mgr, Duration::from_millis(50)),
+            conn_pool: Self::create_conn_pool(mgr),
             addr: conf.meta_service_config.address.to_string(),
             username: conf.meta_service_config.username.to_string(),
             password: conf.meta_service_config.password.to_string(),
@@ -117,6 +125,31 @@ impl MetaGrpcClient {
         })
     }
 
+    /// The pooled channels are dropped when the TLS files are modified, the new channels are
+    /// created with the rotated client certificates.
+    fn create_conn_pool(mgr: MetaChannelManager) -> Pool<MetaChannelManager> {
+        let tls_files = match &mgr.conf {
+            Some(conf) if conf.enabled() => vec![
+                conf.rpc_tls_server_root_ca_cert.clone(),
+                conf.rpc_tls_client_cert.clone(),
+                conf.rpc_tls_client_key.clone(),
+            ],
+            _ => vec![],
+        };
+
+        let conn_pool = Pool::new(mgr, Duration::from_millis(50));
+        if !tls_files.is_empty() {
+            TlsFilesWatcher::create(tls_files).spawn(
+                Arc::downgrade(&conn_pool.items),
+                |items: &ChannelItems| {
+                    items.lock().unwrap().clear();
+                    Ok(())
+                },
+            );
+        }
+        conn_pool
+    }
+
     #[tracing::instrument(level = "debug", skip(password))]
     pub async fn try_create(
         addr: &str,
@@ -128,7 +161,7 @@ impl MetaGrpcClient {
         let mgr = MetaChannelManager { timeout, conf };
 
         Ok(Self {
-            conn_pool: Pool::new(mgr, Duration::from_millis(50)),
+            conn_pool: Self::create_conn_pool(mgr),
             addr: addr.to_string(),
             username: username.to_string(),
             password: password.to_string(),
@@ -265,6 +298,47 @@ impl MetaGrpcClient {
         let res: std::result::Result<R, MetaError> = raft_reply.into();
         res
     }
+
+    /// Watch the changes of the keys starting with `key_prefix`, e.g. `WATCH_TABLE_PREFIX` to
+    /// invalidate the cached table metadata once a table is changed by DDL.
+    ///
+    /// The stream returns an error and ends if some changes are lost, the watcher should
+    /// reload the data then watch again.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn watch(
+        &self,
+        key_prefix: &str,
+    ) -> std::result::Result<
+        BoxStream<'static, std::result::Result<WatchEvent, MetaError>>,
+        MetaError,
+    > {
+        let req = WatchRequest {
+            key_prefix: key_prefix.to_string(),
+        };
+
+        let mut client = self.make_client().await?;
+        let streaming = match client.watch(req.clone()).await {
+            Ok(r) => r.into_inner(),
+            Err(s) => {
+                if status_is_retryable(&s) {
+                    {
+                        let mut token = self.token.write().await;
+                        *token = None;
+                    }
+                    let mut client = self.make_client().await?;
+                    client.watch(req).await?.into_inner()
+                } else {
+                    return Err(s.into());
+                }
+            }
+        };
+
+        let events = streaming.map(|resp| {
+            let event: WatchEvent = serde_json::from_str(&resp?.data)?;
+            Ok(event)
+        });
+        Ok(events.boxed())
+    }
 }
 
 fn status_is_retryable(status: &Status) -> bool {
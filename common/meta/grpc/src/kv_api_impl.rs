@@ -17,6 +17,8 @@ use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaError;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 
@@ -51,4 +53,9 @@ impl KVApi for MetaGrpcClient {
         let reply = self.do_read(PrefixListReq(prefix.to_string())).await?;
         Ok(reply)
     }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+        let reply = self.do_write(txn).await?;
+        Ok(reply)
+    }
 }
@@ -24,9 +24,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -35,6 +39,10 @@ use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -73,6 +81,10 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError> {
+        self.do_write(req).await
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
         self.do_write(req).await
     }
@@ -85,6 +97,20 @@ impl MetaApi for MetaGrpcClient {
         self.do_read(req).await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<DroppedTableInfo>, MetaError> {
+        self.do_read(req).await
+    }
+
+    async fn gc_dropped_table(
+        &self,
+        req: GcDroppedTableReq,
+    ) -> Result<GcDroppedTableReply, MetaError> {
+        self.do_write(req).await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -100,6 +126,13 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, MetaError> {
+        self.do_write(req).await
+    }
+
     fn name(&self) -> String {
         "MetaGrpcClient".to_string()
     }
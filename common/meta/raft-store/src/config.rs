@@ -53,6 +53,7 @@ pub const KVSRV_HEARTBEAT_INTERVAL: &str = "KVSRV_HEARTBEAT_INTERVAL";
 pub const KVSRV_INSTALL_SNAPSHOT_TIMEOUT: &str = "KVSRV_INSTALL_SNAPSHOT_TIMEOUT";
 pub const KVSRV_BOOT: &str = "KVSRV_BOOT";
 pub const KVSRV_SINGLE: &str = "KVSRV_SINGLE";
+pub const KVSRV_LEARNER: &str = "KVSRV_LEARNER";
 pub const KVSRV_ID: &str = "KVSRV_ID";
 
 pub const DEFAULT_LISTEN_HOST: &str = "127.0.0.1";
@@ -126,6 +127,13 @@ pub struct RaftConfig {
     )]
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner, which replicates the logs but does not vote,
+    /// e.g. a warm standby in another zone that does not affect the quorum.
+    ///
+    /// A learner can be promoted to a voter later with the `/v1/cluster/promote` API.
+    #[clap(long, env = KVSRV_LEARNER)]
+    pub learner: bool,
+
     /// The node id. Only used when this server is not initialized,
     ///  e.g. --boot or --single for the first time.
     ///  Otherwise this argument is ignored.
@@ -166,6 +174,7 @@ impl Default for RaftConfig {
             max_applied_log_to_keep: 1000,
             single: false,
             join: vec![],
+            learner: false,
             id: 0,
             sled_tree_prefix: "".to_string(),
         }
@@ -237,6 +246,11 @@ impl RaftConfig {
                 "--join must not be set to itself",
             )));
         }
+        if self.learner && self.join.is_empty() {
+            return Err(MetaError::InvalidConfig(String::from(
+                "--learner must be set with --join",
+            )));
+        }
         Ok(())
     }
 
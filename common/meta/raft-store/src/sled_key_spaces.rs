@@ -31,6 +31,7 @@ use crate::state::RaftStateValue;
 use crate::state_machine::table_lookup::TableLookupValue;
 use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::DatabaseLookupKey;
+use crate::state_machine::DroppedTableIdList;
 use crate::state_machine::LogMetaKey;
 use crate::state_machine::LogMetaValue;
 use crate::state_machine::StateMachineMetaKey;
@@ -147,6 +148,16 @@ impl SledKeySpace for TableLookup {
     type V = SeqV<TableLookupValue>;
 }
 
+/// The ids of the tables dropped by the name, their meta are kept in `Tables` until they are
+/// restored by `UNDROP TABLE` or vacuumed.
+pub struct DroppedTableLookup {}
+impl SledKeySpace for DroppedTableLookup {
+    const PREFIX: u8 = 14;
+    const NAME: &'static str = "dropped-table-lookup";
+    type K = TableLookupKey;
+    type V = SeqV<DroppedTableIdList>;
+}
+
 /// Enum of key-value pair types of all key spaces.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KeySpaceKV {
@@ -198,4 +209,8 @@ pub enum KeySpaceKV {
         key: <LogMeta as SledKeySpace>::K,
         value: <LogMeta as SledKeySpace>::V,
     },
+    DroppedTableLookup {
+        key: <DroppedTableLookup as SledKeySpace>::K,
+        value: <DroppedTableLookup as SledKeySpace>::V,
+    },
 }
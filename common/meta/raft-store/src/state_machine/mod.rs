@@ -20,9 +20,12 @@ pub use log_meta::LogMetaValue;
 pub use sm::SerializableSnapshot;
 pub use sm::SnapshotKeyValue;
 pub use sm::StateMachine;
+pub use sm::StateMachineSubscriber;
 pub use snapshot::Snapshot;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
+pub use table_lookup::DroppedTableId;
+pub use table_lookup::DroppedTableIdList;
 pub use table_lookup::TableLookupKey;
 pub use table_lookup::TableLookupValue;
 
@@ -14,6 +14,7 @@
 
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::sync::Mutex;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -27,7 +28,9 @@ use common_meta_sled_store::SledKeySpace;
 use common_meta_sled_store::SledTree;
 use common_meta_sled_store::Store;
 use common_meta_sled_store::TransactionSledTree;
+use common_meta_types::database_watch_key;
 use common_meta_types::error_context::WithContext;
+use common_meta_types::table_watch_key;
 use common_meta_types::AppError;
 use common_meta_types::AppliedState;
 use common_meta_types::Change;
@@ -48,10 +51,15 @@ use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableAlreadyExists;
 use common_meta_types::TableMeta;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UnknownDatabase;
 use common_meta_types::UnknownDatabaseId;
 use common_meta_types::UnknownTable;
 use common_meta_types::UnknownTableId;
+use common_meta_types::UpdateTableSchemaReq;
+use common_meta_types::WatchEvent;
 use common_tracing::tracing;
 use openraft::raft::Entry;
 use openraft::raft::EntryPayload;
@@ -62,6 +70,7 @@ use crate::config::RaftConfig;
 use crate::sled_key_spaces::ClientLastResps;
 use crate::sled_key_spaces::DatabaseLookup;
 use crate::sled_key_spaces::Databases;
+use crate::sled_key_spaces::DroppedTableLookup;
 use crate::sled_key_spaces::GenericKV;
 use crate::sled_key_spaces::Nodes;
 use crate::sled_key_spaces::Sequences;
@@ -70,6 +79,8 @@ use crate::sled_key_spaces::TableLookup;
 use crate::sled_key_spaces::Tables;
 use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::DatabaseLookupKey;
+use crate::state_machine::DroppedTableId;
+use crate::state_machine::DroppedTableIdList;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaKey::Initialized;
 use crate::state_machine::StateMachineMetaKey::LastApplied;
@@ -91,9 +102,12 @@ const SEQ_DATABASE_META_ID: &str = "database_meta_id";
 const TREE_STATE_MACHINE: &str = "state_machine";
 
 /// StateMachine subscriber trait
-#[async_trait::async_trait]
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    async fn kv_changed(&self, key: &str, prev: &Option<SeqV>, meta: &Option<SeqV>);
+    /// A change is applied to the state machine.
+    fn changed(&self, event: WatchEvent);
+
+    /// The state machine is replaced, e.g. by installing a snapshot, the changes are unknown.
+    fn reset(&self);
 }
 
 /// The state machine of the `MemStore`.
@@ -108,6 +122,9 @@ pub struct StateMachine {
 
     /// subscriber of statemachine data
     pub subscriber: Option<Box<dyn StateMachineSubscriber>>,
+
+    /// The changes made by the applying log, sent to the subscriber once they are committed.
+    txn_events: Mutex<Vec<WatchEvent>>,
 }
 
 /// A key-value pair in a snapshot is a vec of two `Vec<u8>`.
@@ -161,6 +178,7 @@ impl StateMachine {
         let sm = StateMachine {
             sm_tree,
             subscriber: None,
+            txn_events: Mutex::new(vec![]),
         };
 
         let inited = {
@@ -255,6 +273,9 @@ impl StateMachine {
         tracing::debug!("sled tx start: {:?}", entry);
 
         let result = self.sm_tree.txn(true, move |txn_tree| {
+            // The transaction may be retried, drop the changes of the previous try.
+            self.txn_events.lock().unwrap().clear();
+
             let txn_sm_meta = txn_tree.key_space::<StateMachineMeta>();
             txn_sm_meta.insert(&LastApplied, &StateMachineMetaValue::LogId(*log_id))?;
 
@@ -269,7 +290,7 @@ impl StateMachine {
                         }
                     }
 
-                    let res = self.apply_cmd(&data.cmd, &txn_tree);
+                    let res = self.apply_cmd_at(&data.cmd, data.time_ms, &txn_tree);
                     let applied_state = res?;
 
                     if let Some(ref txid) = data.txid {
@@ -308,6 +329,13 @@ impl StateMachine {
 
         tracing::debug!("sled tx done: {:?}", entry);
 
+        let events = std::mem::take(&mut *self.txn_events.lock().unwrap());
+        if let Some(subscriber) = &self.subscriber {
+            for event in events {
+                subscriber.changed(event);
+            }
+        }
+
         let applied_state = match opt_applied_state {
             Some(r) => r,
             None => AppliedState::None,
@@ -396,6 +424,11 @@ impl StateMachine {
 
         if prev_meta.is_none() && result_meta.is_some() {
             self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+            self.txn_watch_event(WatchEvent::new(
+                database_watch_key(tenant, name),
+                None,
+                None,
+            ));
         }
 
         tracing::debug!(
@@ -434,9 +467,17 @@ impl StateMachine {
         if let Some(seq_db_id) = prev {
             // TODO(xp): reconsider this impl. it may not be required.
             self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+            self.txn_watch_event(WatchEvent::new(
+                database_watch_key(tenant, name),
+                None,
+                None,
+            ));
 
             let db_id = seq_db_id.data;
 
+            // The dropped tables of the database can not be restored any more.
+            self.txn_remove_dropped_tables(db_id, txn_tree)?;
+
             let dbs = txn_tree.key_space::<Databases>();
             let (prev_meta, result_meta) =
                 self.txn_sub_tree_upsert(&dbs, &db_id, &MatchSeq::Any, Operation::Delete, None)?;
@@ -477,6 +518,11 @@ impl StateMachine {
         }
         if result.is_some() {
             self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+            self.txn_watch_event(WatchEvent::new(
+                table_watch_key(table_id.unwrap()),
+                None,
+                None,
+            ));
         }
 
         Ok(AppliedState::TableMeta(Change::new_with_id(
@@ -492,21 +538,152 @@ impl StateMachine {
         tenant: &str,
         db_name: &str,
         table_name: &str,
+        purge: bool,
+        now: u64,
         txn_tree: &TransactionSledTree,
     ) -> MetaStorageResult<AppliedState> {
         let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
 
-        let (table_id, prev, result) = self.txn_drop_table(txn_tree, db_id, table_name)?;
+        let (table_id, prev, result) =
+            self.txn_drop_table(txn_tree, db_id, table_name, purge, now)?;
         if prev.is_none() {
             return Ok(Change::<TableMeta>::new(None, None).into());
         }
         if result.is_none() {
             self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+            self.txn_watch_event(WatchEvent::new(
+                table_watch_key(table_id.unwrap()),
+                None,
+                None,
+            ));
         }
 
         Ok(Change::new_with_id(table_id.unwrap(), prev, result).into())
     }
 
+    /// Restore the table dropped last by the name, by moving its name lookup entry back. The table
+    /// id and the table meta are kept by drop, thus the data of the table are not touched.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_undrop_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        if table_lookup_tree.get(&lookup_key)?.is_some() {
+            return Err(MetaStorageError::AppError(AppError::TableAlreadyExists(
+                TableAlreadyExists::new(table_name, "apply_undrop_table_cmd"),
+            )));
+        }
+
+        let dropped_lookup_tree = txn_tree.key_space::<DroppedTableLookup>();
+        let mut dropped = dropped_lookup_tree
+            .get(&lookup_key)?
+            .map(|seq_ids| seq_ids.data)
+            .unwrap_or_default();
+        let table_id = dropped
+            .0
+            .pop()
+            .ok_or_else(|| {
+                AppError::UnknownTable(UnknownTable::new(table_name, "apply_undrop_table_cmd"))
+            })?
+            .table_id;
+
+        let seq_meta = self
+            .txn_get_table_meta_by_id(&table_id, txn_tree)?
+            .ok_or_else(|| {
+                AppError::UnknownTableId(UnknownTableId::new(table_id, "apply_undrop_table_cmd"))
+            })?;
+
+        self.txn_update_dropped_tables(&dropped_lookup_tree, &lookup_key, dropped)?;
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Exact(0),
+            Operation::Update(TableLookupValue(table_id)),
+            None,
+        )?;
+
+        // Bump the version, so that the `TableInfo` read before the drop becomes stale.
+        let tables = txn_tree.key_space::<Tables>();
+        let (prev, result) = self.txn_sub_tree_upsert(
+            &tables,
+            &table_id,
+            &MatchSeq::Exact(seq_meta.seq),
+            Operation::Update(seq_meta.data),
+            None,
+        )?;
+
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        self.txn_watch_event(WatchEvent::new(table_watch_key(table_id), None, None));
+        tracing::debug!(
+            "applied undrop Table: {}:{}, table id: {}",
+            db_name,
+            table_name,
+            table_id
+        );
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            table_id, prev, result,
+        )))
+    }
+
+    /// Remove a dropped table and its meta, it is called after the data of the table are purged.
+    /// The table can not be restored by `UNDROP TABLE` any more.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_gc_dropped_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        table_id: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let dropped_lookup_tree = txn_tree.key_space::<DroppedTableLookup>();
+        let mut dropped = dropped_lookup_tree
+            .get(&lookup_key)?
+            .map(|seq_ids| seq_ids.data)
+            .unwrap_or_default();
+
+        let len = dropped.0.len();
+        dropped.0.retain(|d| d.table_id != table_id);
+        if dropped.0.len() == len {
+            return Ok(Change::<TableMeta>::new(None, None).into());
+        }
+        self.txn_update_dropped_tables(&dropped_lookup_tree, &lookup_key, dropped)?;
+
+        let tables = txn_tree.key_space::<Tables>();
+        let (prev, result) =
+            self.txn_sub_tree_upsert(&tables, &table_id, &MatchSeq::Any, Operation::Delete, None)?;
+
+        tracing::debug!(
+            "applied gc dropped Table: {}:{}, table id: {}",
+            db_name,
+            table_name,
+            table_id
+        );
+        Ok(Change::new_with_id(table_id, prev, result).into())
+    }
+
+    /// Rename a table by moving its name lookup entry, the table id and the table meta are kept.
+    ///
+    /// The data and the snapshots of a table are addressed by the table id, thus a rename does
+    /// not touch them. The version of the table meta is bumped, so that the `TableInfo` with the
+    /// old name becomes stale.
     fn apply_rename_table_cmd(
         &self,
         tenant: &str,
@@ -517,38 +694,74 @@ impl StateMachine {
         txn_tree: &TransactionSledTree,
     ) -> MetaStorageResult<AppliedState> {
         let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
-        let (_, prev, result) = self.txn_drop_table(txn_tree, db_id, table_name)?;
-        if prev.is_none() {
-            return Err(MetaStorageError::AppError(AppError::UnknownTable(
-                UnknownTable::new(table_name, "apply_rename_table_cmd"),
-            )));
-        }
-        assert!(result.is_none());
 
-        let table_meta = &prev.as_ref().unwrap().data;
-        let db_id = self.txn_get_database_id(tenant, new_db_name, txn_tree)?;
-        let (new_table_id, new_prev, new_result) =
-            self.txn_create_table(txn_tree, db_id, new_table_name, table_meta)?;
-        if new_prev.is_some() {
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+        let table_id = table_lookup_tree
+            .get(&lookup_key)?
+            .ok_or_else(|| {
+                AppError::UnknownTable(UnknownTable::new(table_name, "apply_rename_table_cmd"))
+            })?
+            .data
+            .0;
+
+        let new_db_id = self.txn_get_database_id(tenant, new_db_name, txn_tree)?;
+        let new_lookup_key = TableLookupKey {
+            database_id: new_db_id,
+            table_name: new_table_name.to_string(),
+        };
+        if table_lookup_tree.get(&new_lookup_key)?.is_some() {
             return Err(MetaStorageError::AppError(AppError::TableAlreadyExists(
                 TableAlreadyExists::new(new_table_name, "apply_rename_table_cmd"),
             )));
         }
-        assert!(new_result.is_some());
+
+        let seq_meta = self
+            .txn_get_table_meta_by_id(&table_id, txn_tree)?
+            .ok_or_else(|| {
+                AppError::UnknownTableId(UnknownTableId::new(table_id, "apply_rename_table_cmd"))
+            })?;
+
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &new_lookup_key,
+            &MatchSeq::Exact(0),
+            Operation::Update(TableLookupValue(table_id)),
+            None,
+        )?;
+
+        let tables = txn_tree.key_space::<Tables>();
+        let (prev, result) = self.txn_sub_tree_upsert(
+            &tables,
+            &table_id,
+            &MatchSeq::Exact(seq_meta.seq),
+            Operation::Update(seq_meta.data),
+            None,
+        )?;
 
         self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        self.txn_watch_event(WatchEvent::new(table_watch_key(table_id), None, None));
         tracing::debug!(
-            "applied rename Table: {}:{} -> {}:{}",
+            "applied rename Table: {}:{} -> {}:{}, table id: {}",
             db_name,
             table_name,
             new_db_name,
-            new_table_name
+            new_table_name,
+            table_id
         );
 
         Ok(AppliedState::TableMeta(Change::new_with_id(
-            new_table_id.unwrap(),
-            prev,
-            new_result,
+            table_id, prev, result,
         )))
     }
 
@@ -559,9 +772,12 @@ impl StateMachine {
         seq: &MatchSeq,
         value_op: &Operation<Vec<u8>>,
         value_meta: &Option<KVMeta>,
+        now: u64,
         txn_tree: &TransactionSledTree,
     ) -> MetaStorageResult<AppliedState> {
         let sub_tree = txn_tree.key_space::<GenericKV>();
+        self.txn_get_unexpired_kv(&sub_tree, key, now)?;
+
         let key_str = key.to_string();
         let (prev, result) = self.txn_sub_tree_upsert(
             &sub_tree,
@@ -573,13 +789,107 @@ impl StateMachine {
 
         tracing::debug!("applied UpsertKV: {} {:?}", key, result);
 
-        if let Some(subscriber) = &self.subscriber {
-            let _ = subscriber.kv_changed(&key_str, &prev, &result);
+        if prev != result {
+            self.txn_watch_event(WatchEvent::new(key, prev.clone(), result.clone()));
         }
 
         Ok(Change::new(prev, result).into())
     }
 
+    /// Check all the conditions of a transaction, then apply `if_then` if they are all satisfied,
+    /// otherwise apply `else_then`. The conditions and the operations see the same snapshot,
+    /// since they are done in one sled transaction.
+    fn apply_txn_cmd(
+        &self,
+        txn: &TxnRequest,
+        now: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        let mut success = true;
+        for cond in txn.condition.iter() {
+            let prev = self.txn_get_unexpired_kv(&sub_tree, &cond.key, now)?;
+            if cond.seq.match_seq(&prev).is_err() {
+                success = false;
+                break;
+            }
+        }
+
+        let ops = if success {
+            &txn.if_then
+        } else {
+            &txn.else_then
+        };
+
+        let mut changes = Vec::with_capacity(ops.len());
+        for op in ops.iter() {
+            let (value_op, value_meta) = match op {
+                TxnOp::Put {
+                    value, value_meta, ..
+                } => (Operation::Update(value.clone()), value_meta.clone()),
+                TxnOp::Delete { .. } => (Operation::Delete, None),
+            };
+
+            let key = op.key().to_string();
+            self.txn_get_unexpired_kv(&sub_tree, &key, now)?;
+            let (prev, result) =
+                self.txn_sub_tree_upsert(&sub_tree, &key, &MatchSeq::Any, value_op, value_meta)?;
+
+            if prev != result {
+                self.txn_watch_event(WatchEvent::new(&key, prev.clone(), result.clone()));
+            }
+            changes.push(Change::new_with_id(key, prev, result));
+        }
+
+        tracing::debug!(
+            "applied Transaction: success: {}, {} ops",
+            success,
+            changes.len()
+        );
+
+        Ok(AppliedState::TxnReply(TxnReply { success, changes }))
+    }
+
+    /// Remove the given kv entries that are expired at `now`.
+    fn apply_clean_expired_cmd(
+        &self,
+        keys: &[String],
+        now: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+        for key in keys.iter() {
+            self.txn_get_unexpired_kv(&sub_tree, key, now)?;
+        }
+
+        tracing::debug!("applied CleanExpired: {} keys", keys.len());
+
+        Ok(AppliedState::None)
+    }
+
+    /// Get an entry of the general purpose kv store that is not expired at `now`, in seconds.
+    ///
+    /// An expired entry is removed, the same as it is deleted by a client.
+    fn txn_get_unexpired_kv(
+        &self,
+        sub_tree: &AsTxnKeySpace<GenericKV>,
+        key: &str,
+        now: u64,
+    ) -> MetaStorageResult<Option<SeqV>> {
+        let key = key.to_string();
+        match sub_tree.get(&key)? {
+            Some(seq_value) if seq_value.get_expire_at() < now => {
+                sub_tree.remove(&key)?;
+                tracing::debug!("removed expired kv: {} {:?}", key, seq_value);
+
+                self.txn_watch_event(WatchEvent::new(key, Some(seq_value), None));
+                Ok(None)
+            }
+            seq_value => Ok(seq_value),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_upsert_table_options_cmd(
         &self,
@@ -625,6 +935,48 @@ impl StateMachine {
         };
 
         table_tree.insert(&req.table_id, &sv)?;
+        self.txn_watch_event(WatchEvent::new(table_watch_key(req.table_id), None, None));
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            req.table_id,
+            Some(prev),
+            Some(sv),
+        )))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_update_table_schema_cmd(
+        &self,
+        req: &UpdateTableSchemaReq,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&req.table_id)?;
+
+        let prev = prev.ok_or_else(|| {
+            MetaStorageError::AppError(AppError::UnknownTableId(UnknownTableId::new(
+                req.table_id,
+                "apply_update_table_schema_cmd".to_string(),
+            )))
+        })?;
+
+        if req.seq.match_seq(&prev).is_err() {
+            let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+            return Ok(res);
+        }
+
+        let mut table_meta = prev.data.clone();
+        table_meta.update_schema(req.schema.clone());
+
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree)?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta: prev.meta.clone(),
+            data: table_meta,
+        };
+
+        table_tree.insert(&req.table_id, &sv)?;
+        self.txn_watch_event(WatchEvent::new(table_watch_key(req.table_id), None, None));
 
         Ok(AppliedState::TableMeta(Change::new_with_id(
             req.table_id,
@@ -638,14 +990,30 @@ impl StateMachine {
     /// Already applied log should be filtered out before passing into this function.
     /// This is the only entry to modify state machine.
     /// The `cmd` is always committed by raft before applying.
-    #[tracing::instrument(level = "debug", skip(self, cmd, txn_tree))]
     pub fn apply_cmd(
         &self,
         cmd: &Cmd,
         txn_tree: &TransactionSledTree,
+    ) -> Result<AppliedState, MetaStorageError> {
+        self.apply_cmd_at(cmd, None, txn_tree)
+    }
+
+    /// Apply a cmd with the time of the log in milliseconds, the expiration of the kv entries is
+    /// checked against it. The local time is used if it is None.
+    #[tracing::instrument(level = "debug", skip(self, cmd, txn_tree))]
+    pub fn apply_cmd_at(
+        &self,
+        cmd: &Cmd,
+        time_ms: Option<u64>,
+        txn_tree: &TransactionSledTree,
     ) -> Result<AppliedState, MetaStorageError> {
         tracing::debug!("apply_cmd: {:?}", cmd);
 
+        let now = match time_ms {
+            Some(time_ms) => time_ms / 1000,
+            None => Self::now_secs(),
+        };
+
         match cmd {
             Cmd::IncrSeq { ref key } => self.apply_incr_seq_cmd(key, txn_tree),
 
@@ -676,7 +1044,21 @@ impl StateMachine {
                 tenant,
                 ref db_name,
                 ref table_name,
-            } => self.apply_drop_table_cmd(tenant, db_name, table_name, txn_tree),
+                purge,
+            } => self.apply_drop_table_cmd(tenant, db_name, table_name, *purge, now, txn_tree),
+
+            Cmd::UndropTable {
+                tenant,
+                ref db_name,
+                ref table_name,
+            } => self.apply_undrop_table_cmd(tenant, db_name, table_name, txn_tree),
+
+            Cmd::GcDroppedTable {
+                tenant,
+                ref db_name,
+                ref table_name,
+                table_id,
+            } => self.apply_gc_dropped_table_cmd(tenant, db_name, table_name, *table_id, txn_tree),
 
             Cmd::RenameTable {
                 tenant,
@@ -698,9 +1080,15 @@ impl StateMachine {
                 seq,
                 value: value_op,
                 value_meta,
-            } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, txn_tree),
+            } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, now, txn_tree),
+
+            Cmd::Transaction(ref txn) => self.apply_txn_cmd(txn, now, txn_tree),
+
+            Cmd::CleanExpired { ref keys } => self.apply_clean_expired_cmd(keys, now, txn_tree),
 
             Cmd::UpsertTableOptions(ref req) => self.apply_upsert_table_options_cmd(req, txn_tree),
+
+            Cmd::UpdateTableSchema(ref req) => self.apply_update_table_schema_cmd(req, txn_tree),
         }
     }
 
@@ -794,11 +1182,10 @@ impl StateMachine {
         V: Clone + Debug,
         KS: SledKeySpace<V = SeqV<V>>,
     {
+        // The expired entries are removed by the caller with the time of the log, they must not
+        // be checked against the local time, which differs on every node.
         let prev = sub_tree.get(key)?;
 
-        // If prev is timed out, treat it as a None.
-        let prev = Self::unexpired_opt(prev);
-
         if seq.match_seq(&prev).is_err() {
             return Ok((prev.clone(), prev));
         }
@@ -931,6 +1318,8 @@ impl StateMachine {
         txn_tree: &TransactionSledTree,
         db_id: u64,
         table_name: &str,
+        purge: bool,
+        now: u64,
     ) -> MetaStorageResult<(
         Option<u64>,
         Option<SeqV<TableMeta>>,
@@ -959,10 +1348,90 @@ impl StateMachine {
         )?;
 
         let tables = txn_tree.key_space::<Tables>();
-        let (prev, result) =
-            self.txn_sub_tree_upsert(&tables, &table_id, &MatchSeq::Any, Operation::Delete, None)?;
-        tracing::debug!("applied drop Table: {} {:?}", table_name, result);
-        Ok((Some(table_id), prev, result))
+        if purge {
+            let (prev, result) = self.txn_sub_tree_upsert(
+                &tables,
+                &table_id,
+                &MatchSeq::Any,
+                Operation::Delete,
+                None,
+            )?;
+            tracing::debug!("applied drop Table: {} {:?}", table_name, result);
+            return Ok((Some(table_id), prev, result));
+        }
+
+        // The meta of the dropped table is kept for `UNDROP TABLE`, with the ones dropped before
+        // by the name. They are removed by vacuum after the data are purged.
+        let dropped_lookup_tree = txn_tree.key_space::<DroppedTableLookup>();
+        let mut dropped = dropped_lookup_tree
+            .get(&lookup_key)?
+            .map(|seq_ids| seq_ids.data)
+            .unwrap_or_default();
+        dropped.0.push(DroppedTableId {
+            table_id,
+            dropped_on: now,
+        });
+        self.txn_update_dropped_tables(&dropped_lookup_tree, &lookup_key, dropped)?;
+
+        let prev = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+        tracing::debug!("applied drop Table: {}, table id: {}", table_name, table_id);
+        Ok((Some(table_id), prev, None))
+    }
+
+    /// Store the dropped tables by a name, the entry is removed if none is left.
+    fn txn_update_dropped_tables(
+        &self,
+        dropped_lookup_tree: &AsTxnKeySpace<DroppedTableLookup>,
+        lookup_key: &TableLookupKey,
+        dropped: DroppedTableIdList,
+    ) -> MetaStorageResult<()> {
+        let op = if dropped.0.is_empty() {
+            Operation::Delete
+        } else {
+            Operation::Update(dropped)
+        };
+        self.txn_sub_tree_upsert(dropped_lookup_tree, lookup_key, &MatchSeq::Any, op, None)?;
+        Ok(())
+    }
+
+    /// Remove the dropped tables of a database and their meta.
+    fn txn_remove_dropped_tables(
+        &self,
+        db_id: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<()> {
+        // A sled transaction can not be iterated, the dropped tables are listed from the tree. They
+        // are only changed by the applied commands, which are applied one by one.
+        let dropped_tables = self.dropped_table_lookup().range_kvs(..)?;
+
+        let dropped_lookup_tree = txn_tree.key_space::<DroppedTableLookup>();
+        let tables = txn_tree.key_space::<Tables>();
+        for (k, seq_ids) in dropped_tables {
+            if k.database_id != db_id {
+                continue;
+            }
+            for d in seq_ids.data.0 {
+                self.txn_sub_tree_upsert(
+                    &tables,
+                    &d.table_id,
+                    &MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                )?;
+            }
+            self.txn_sub_tree_upsert(
+                &dropped_lookup_tree,
+                &k,
+                &MatchSeq::Any,
+                Operation::Delete,
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn txn_watch_event(&self, event: WatchEvent) {
+        self.txn_events.lock().unwrap().push(event);
     }
 
     fn txn_client_last_resp_update(
@@ -1104,32 +1573,38 @@ impl StateMachine {
         Ok(result)
     }
 
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    /// Returns the keys of the general purpose kv store that are expired at `now`, in seconds.
+    pub fn expired_kv_keys(&self, now: u64) -> MetaStorageResult<Vec<String>> {
+        let mut keys = vec![];
+        for item in self.kvs().range(..)? {
+            let (key, seq_value) = item?;
+            if seq_value.get_expire_at() < now {
+                keys.push(key);
+            }
+        }
+        Ok(keys)
+    }
+
     pub fn unexpired_opt<V: Debug>(seq_value: Option<SeqV<V>>) -> Option<SeqV<V>> {
         seq_value.and_then(Self::unexpired)
     }
 
+    /// Filter out the expired value for a read.
+    ///
+    /// A read does not remove the expired entries, since it is only done on the local node. They
+    /// are removed by the logs: a write to the key, or a `Cmd::CleanExpired` proposed by the leader,
+    /// with the time of the log assigned by the leader.
     pub fn unexpired<V: Debug>(seq_value: SeqV<V>) -> Option<SeqV<V>> {
-        // TODO(xp): log must be assigned with a ts.
-
-        // TODO(xp): background task to clean expired
-
-        // TODO(xp): Caveat: The cleanup must be consistent across raft nodes:
-        //           A conditional update, e.g. an upsert_kv() with MatchSeq::Eq(some_value),
-        //           must be applied with the same timestamp on every raft node.
-        //           Otherwise: node-1 could have applied a log with a ts that is smaller than value.expire_at,
-        //           while node-2 may fail to apply the same log if it use a greater ts > value.expire_at.
-        //           Thus:
-        //           1. A raft log must have a field ts assigned by the leader. When applying, use this ts to
-        //              check against expire_at to decide whether to purge it.
-        //           2. A GET operation must not purge any expired entry. Since a GET is only applied to a node itself.
-        //           3. The background task can only be triggered by the raft leader, by submit a "clean expired" log.
-
         // TODO(xp): maybe it needs a expiration queue for efficient cleaning up.
 
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = Self::now_secs();
 
         tracing::debug!("seq_value: {:?} now: {}", seq_value, now);
 
@@ -1200,4 +1675,8 @@ impl StateMachine {
     pub fn table_lookup(&self) -> AsKeySpace<TableLookup> {
         self.sm_tree.key_space()
     }
+
+    pub fn dropped_table_lookup(&self) -> AsKeySpace<DroppedTableLookup> {
+        self.sm_tree.key_space()
+    }
 }
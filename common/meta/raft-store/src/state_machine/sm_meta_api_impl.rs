@@ -31,9 +31,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -45,9 +49,13 @@ use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_meta_types::TableVersionMismatched;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
 use common_meta_types::UnknownDatabase;
 use common_meta_types::UnknownTable;
 use common_meta_types::UnknownTableId;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -191,6 +199,7 @@ impl MetaApi for StateMachine {
             tenant,
             db_name: db_name.clone(),
             table_name: table_name.clone(),
+            purge: req.purge,
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -208,6 +217,19 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError> {
+        let cmd = Cmd::UndropTable {
+            tenant: req.tenant,
+            db_name: req.db,
+            table_name: req.table,
+        };
+
+        let res = self.sm_tree.txn(true, |t| self.apply_cmd(&cmd, &t))?;
+        let mut ch: Change<TableMeta, u64> = res.try_into().unwrap();
+        let table_id = ch.ident.take().unwrap();
+        Ok(UndropTableReply { table_id })
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
         let if_exists = req.if_exists;
         let tenant = &req.tenant;
@@ -311,6 +333,63 @@ impl MetaApi for StateMachine {
         Ok(tbls)
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> Result<Vec<DroppedTableInfo>, MetaError> {
+        let tenant = &req.tenant;
+        let db_name = &req.db_name;
+        let db_id = self.get_database_id(tenant, db_name)?;
+
+        let mut tbls = vec![];
+        let tables = self.tables();
+        let dropped_iter = self.dropped_table_lookup().range(..)?;
+        for r in dropped_iter {
+            let (k, seq_ids) = r?;
+
+            if k.database_id != db_id {
+                continue;
+            }
+
+            for dropped in seq_ids.data.0 {
+                let table_id = dropped.table_id;
+                let seq_table_meta = tables.get(&table_id)?.ok_or_else(|| {
+                    let ut = UnknownTableId::new(table_id, "list_dropped_tables");
+                    MetaStorageError::Damaged(AnyError::new(&ut))
+                })?;
+
+                let table_info = TableInfo::new(
+                    db_name,
+                    &k.table_name,
+                    TableIdent::new(table_id, seq_table_meta.seq),
+                    seq_table_meta.data,
+                );
+
+                tbls.push(DroppedTableInfo {
+                    table_info: Arc::new(table_info),
+                    dropped_on: dropped.dropped_on,
+                });
+            }
+        }
+
+        Ok(tbls)
+    }
+
+    async fn gc_dropped_table(
+        &self,
+        req: GcDroppedTableReq,
+    ) -> Result<GcDroppedTableReply, MetaError> {
+        let cmd = Cmd::GcDroppedTable {
+            tenant: req.tenant,
+            db_name: req.db,
+            table_name: req.table,
+            table_id: req.table_id,
+        };
+
+        self.sm_tree.txn(true, |t| self.apply_cmd(&cmd, &t))?;
+        Ok(GcDroppedTableReply {})
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -352,6 +431,34 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply, MetaError> {
+        let cmd = Cmd::UpdateTableSchema(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t)?;
+            Ok(r)
+        })?;
+
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        let (prev, result) = ch.unwrap();
+        if prev.seq == result.seq {
+            let ae = AppError::from(TableVersionMismatched::new(
+                req.table_id,
+                req.seq,
+                prev.seq,
+                "update_table_schema",
+            ));
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(UpdateTableSchemaReply {
+            schema_version: result.data.schema_version,
+        })
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
@@ -72,3 +72,25 @@ impl fmt::Display for TableLookupValue {
         write!(f, "{}", self.0)
     }
 }
+
+/// A version of a table dropped by a name, `dropped_on` is in seconds since epoch.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DroppedTableId {
+    pub table_id: u64,
+    pub dropped_on: u64,
+}
+
+/// The versions of the tables dropped by a name, the last one is the one dropped last.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DroppedTableIdList(pub Vec<DroppedTableId>);
+
+impl fmt::Display for DroppedTableIdList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids = self
+            .0
+            .iter()
+            .map(|d| d.table_id.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "[{}]", ids.join(","))
+    }
+}
@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -22,7 +24,10 @@ use common_meta_api::KVApi;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
 use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::StateMachineSubscriber;
 use common_meta_sled_store::openraft;
+use common_meta_types::database_watch_key;
+use common_meta_types::table_watch_key;
 use common_meta_types::AppError;
 use common_meta_types::AppliedState;
 use common_meta_types::Change;
@@ -37,6 +42,7 @@ use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
 use common_meta_types::UnknownTableId;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchEvent;
 use common_tracing::tracing;
 use maplit::hashmap;
 use openraft::raft::Entry;
@@ -109,6 +115,7 @@ async fn test_state_machine_apply_incr_seq() -> anyhow::Result<()> {
                 log_id: LogId { term: 0, index: 5 },
                 payload: EntryPayload::Normal(LogEntry {
                     txid: txid.clone(),
+                    time_ms: None,
                     cmd: Cmd::IncrSeq { key: k.to_string() },
                 }),
             })
@@ -713,6 +720,185 @@ async fn test_state_machine_apply_non_dup_generic_kv_delete() -> anyhow::Result<
     Ok(())
 }
 
+#[derive(Debug)]
+struct EventCollector {
+    events: Arc<Mutex<Vec<WatchEvent>>>,
+}
+
+impl StateMachineSubscriber for EventCollector {
+    fn changed(&self, event: WatchEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn reset(&self) {}
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_watch_events() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let mut sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let events = Arc::new(Mutex::new(vec![]));
+    sm.set_subscriber(Box::new(EventCollector {
+        events: events.clone(),
+    }));
+
+    let tenant = "tenant1";
+    let cmds = vec![
+        Cmd::UpsertKV {
+            key: "foo".to_string(),
+            seq: MatchSeq::Any,
+            value: Operation::Update(b"x".to_vec()),
+            value_meta: None,
+        },
+        // Not changed
+        Cmd::UpsertKV {
+            key: "bar".to_string(),
+            seq: MatchSeq::Any,
+            value: Operation::Delete,
+            value_meta: None,
+        },
+        Cmd::CreateDatabase {
+            tenant: tenant.to_string(),
+            name: "db1".to_string(),
+            meta: DatabaseMeta::default(),
+        },
+        Cmd::CreateTable {
+            tenant: tenant.to_string(),
+            db_name: "db1".to_string(),
+            table_name: "t1".to_string(),
+            table_meta: TableMeta::default(),
+        },
+        Cmd::RenameTable {
+            tenant: tenant.to_string(),
+            db_name: "db1".to_string(),
+            table_name: "t1".to_string(),
+            new_db_name: "db1".to_string(),
+            new_table_name: "t2".to_string(),
+        },
+        // Not changed, the table is renamed
+        Cmd::DropTable {
+            tenant: tenant.to_string(),
+            db_name: "db1".to_string(),
+            table_name: "t1".to_string(),
+            purge: false,
+        },
+    ];
+
+    let mut table_ids = vec![];
+    for (i, cmd) in cmds.into_iter().enumerate() {
+        let resp = sm
+            .apply(&Entry {
+                log_id: LogId {
+                    term: 0,
+                    index: i as u64 + 1,
+                },
+                payload: EntryPayload::Normal(LogEntry {
+                    txid: None,
+                    time_ms: None,
+                    cmd,
+                }),
+            })
+            .await?;
+        if let AppliedState::TableMeta(Change {
+            ident: Some(table_id),
+            ..
+        }) = resp
+        {
+            table_ids.push(table_id);
+        }
+    }
+
+    let got = events
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|e| e.key.clone())
+        .collect::<Vec<_>>();
+    // The table id is kept by rename.
+    assert_eq!(table_ids[0], table_ids[1]);
+    let want = vec![
+        "foo".to_string(),
+        database_watch_key(tenant, "db1"),
+        table_watch_key(table_ids[0]),
+        table_watch_key(table_ids[0]),
+    ];
+    assert_eq!(want, got);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_clean_expired() -> anyhow::Result<()> {
+    // - The expiration is checked against the time of the log, not the local time.
+    // - CleanExpired only removes the keys expired at the time of the log.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let entry = |index: u64, time: u64, cmd: Cmd| Entry {
+        log_id: LogId { term: 0, index },
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(time * 1000),
+            cmd,
+        }),
+    };
+    let upsert = |key: &str, expire_at: Option<u64>| Cmd::UpsertKV {
+        key: key.to_string(),
+        seq: MatchSeq::Exact(0),
+        value: Operation::Update(b"v".to_vec()),
+        value_meta: expire_at.map(|x| KVMeta { expire_at: Some(x) }),
+    };
+
+    sm.apply(&entry(1, now, upsert("a", Some(now + 10))))
+        .await?;
+    sm.apply(&entry(2, now, upsert("b", None))).await?;
+
+    assert_eq!(Vec::<String>::new(), sm.expired_kv_keys(now)?);
+    assert_eq!(vec!["a".to_string()], sm.expired_kv_keys(now + 11)?);
+
+    tracing::info!("--- an add-if-absent of an expired key applies at a later log time");
+    {
+        let resp = sm.apply(&entry(3, now, upsert("a", None))).await?;
+        assert!(!resp.changed(), "a is not expired at the log time");
+
+        let resp = sm.apply(&entry(4, now + 11, upsert("a", None))).await?;
+        assert!(resp.changed(), "a is expired at the log time");
+        assert_eq!(Vec::<String>::new(), sm.expired_kv_keys(now + 11)?);
+    }
+
+    tracing::info!("--- clean expired");
+    {
+        sm.apply(&entry(5, now, upsert("c", Some(now + 10))))
+            .await?;
+
+        let clean = || Cmd::CleanExpired {
+            keys: vec!["b".to_string(), "c".to_string()],
+        };
+
+        sm.apply(&entry(6, now, clean())).await?;
+        assert!(sm.kvs().get(&"c".to_string())?.is_some());
+
+        sm.apply(&entry(7, now + 11, clean())).await?;
+        assert!(sm.kvs().get(&"c".to_string())?.is_none());
+        assert!(sm.kvs().get(&"b".to_string())?.is_some());
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_snapshot() -> anyhow::Result<()> {
     // - Feed logs into state machine.
@@ -25,6 +25,7 @@ use crate::DatabaseMeta;
 use crate::MetaError;
 use crate::Node;
 use crate::TableMeta;
+use crate::TxnReply;
 
 /// The state of an applied raft log.
 /// Normally it includes two fields: the state before applying and the state after applying the log.
@@ -50,6 +51,8 @@ pub enum AppliedState {
 
     KV(Change<Vec<u8>>),
 
+    TxnReply(TxnReply),
+
     AppError(AppError),
 
     #[try_into(ignore)]
@@ -119,6 +122,7 @@ impl AppliedState {
             AppliedState::DatabaseMeta(ref ch) => ch.changed(),
             AppliedState::TableMeta(ref ch) => ch.changed(),
             AppliedState::KV(ref ch) => ch.changed(),
+            AppliedState::TxnReply(ref reply) => reply.changes.iter().any(|ch| ch.changed()),
             AppliedState::None => false,
             AppliedState::AppError(_e) => false,
         }
@@ -148,6 +152,7 @@ impl AppliedState {
             AppliedState::DatabaseMeta(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::TableMeta(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
+            AppliedState::TxnReply(_) => false,
             AppliedState::None => true,
             AppliedState::AppError(_e) => true,
         }
@@ -161,6 +166,7 @@ impl AppliedState {
             AppliedState::DatabaseMeta(Change { ref result, .. }) => result.is_none(),
             AppliedState::TableMeta(Change { ref result, .. }) => result.is_none(),
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
+            AppliedState::TxnReply(_) => false,
             AppliedState::None => true,
             AppliedState::AppError(_e) => true,
         }
@@ -24,6 +24,8 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::TxnRequest;
+use crate::UpdateTableSchemaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -60,6 +62,24 @@ pub enum Cmd {
         tenant: String,
         db_name: String,
         table_name: String,
+        /// Remove the table meta, otherwise it is kept for `UndropTable`.
+        #[serde(default)]
+        purge: bool,
+    },
+
+    /// Restore the table dropped last by the name
+    UndropTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+    },
+
+    /// Remove a dropped table and its meta, after its data are purged
+    GcDroppedTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        table_id: u64,
     },
 
     /// Rename a table
@@ -80,6 +100,11 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the schema of a table with a new version, the previous schema is kept.
+    ///
+    /// It requires a present table, with mismatched seq it returns an unchanged state.
+    UpdateTableSchema(UpdateTableSchemaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -96,6 +121,15 @@ pub enum Cmd {
         /// Meta data of a value.
         value_meta: Option<KVMeta>,
     },
+
+    /// Check the conditions on the general purpose kv store and apply the operations atomically.
+    Transaction(TxnRequest),
+
+    /// Remove the expired entries from the general purpose kv store.
+    ///
+    /// The keys are collected by the leader, a key is removed only if it is expired at the time
+    /// of the log.
+    CleanExpired { keys: Vec<String> },
 }
 
 impl fmt::Display for Cmd {
@@ -129,9 +163,29 @@ impl fmt::Display for Cmd {
                 tenant,
                 db_name,
                 table_name,
+                ..
             } => {
                 write!(f, "delete_table:{}/{}-{}", tenant, db_name, table_name)
             }
+            Cmd::UndropTable {
+                tenant,
+                db_name,
+                table_name,
+            } => {
+                write!(f, "undrop_table:{}/{}-{}", tenant, db_name, table_name)
+            }
+            Cmd::GcDroppedTable {
+                tenant,
+                db_name,
+                table_name,
+                table_id,
+            } => {
+                write!(
+                    f,
+                    "gc_dropped_table:{}/{}-{}={}",
+                    tenant, db_name, table_name, table_id
+                )
+            }
             Cmd::RenameTable {
                 tenant,
                 db_name,
@@ -157,6 +211,23 @@ impl fmt::Display for Cmd {
                     key, seq, value, value_meta
                 )
             }
+            Cmd::Transaction(txn) => {
+                write!(
+                    f,
+                    "txn: if {:?} then {:?} else {:?}",
+                    txn.condition, txn.if_then, txn.else_then
+                )
+            }
+            Cmd::CleanExpired { keys } => {
+                write!(f, "clean_expired: {:?}", keys)
+            }
+            Cmd::UpdateTableSchema(req) => {
+                write!(
+                    f,
+                    "update-table-schema: table-id:{}({:?}) = {}",
+                    req.table_id, req.seq, req.schema
+                )
+            }
             Cmd::UpsertTableOptions(req) => {
                 write!(
                     f,
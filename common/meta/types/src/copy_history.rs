@@ -0,0 +1,37 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A file loaded into a table by COPY, kept in the copy history of the table.
+/// A file is copied again only if it is changed, i.e. its etag or size is not the same.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CopiedFileInfo {
+    /// The path of the file in the stage.
+    pub file: String,
+    /// The etag of the file, empty if the storage does not return it.
+    pub etag: String,
+    pub size: u64,
+    pub rows_loaded: u64,
+    /// The time of the load in milliseconds since the epoch.
+    pub load_time: i64,
+}
+
+impl CopiedFileInfo {
+    // Whether the other file is the same as the loaded one.
+    pub fn is_same_file(&self, other: &CopiedFileInfo) -> bool {
+        self.file == other.file && self.etag == other.etag && self.size == other.size
+    }
+}
@@ -61,3 +61,78 @@ impl UpsertKVAction {
         }
     }
 }
+
+/// A condition of a transaction: the seq of the value of `key` must match `seq`.
+///
+/// E.g., `MatchSeq::Exact(0)` requires the key to be absent.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TxnCondition {
+    pub key: String,
+    pub seq: MatchSeq,
+}
+
+impl TxnCondition {
+    pub fn new(key: &str, seq: MatchSeq) -> Self {
+        Self {
+            key: key.to_string(),
+            seq,
+        }
+    }
+}
+
+/// An operation of a transaction, applied without checking the seq of the key.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum TxnOp {
+    Put {
+        key: String,
+        value: Vec<u8>,
+        value_meta: Option<KVMeta>,
+    },
+    Delete {
+        key: String,
+    },
+}
+
+impl TxnOp {
+    pub fn put(key: &str, value: Vec<u8>) -> Self {
+        TxnOp::Put {
+            key: key.to_string(),
+            value,
+            value_meta: None,
+        }
+    }
+
+    pub fn delete(key: &str) -> Self {
+        TxnOp::Delete {
+            key: key.to_string(),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        match self {
+            TxnOp::Put { key, .. } => key,
+            TxnOp::Delete { key } => key,
+        }
+    }
+}
+
+/// A conditional transaction on the general purpose kv store.
+///
+/// If all the `condition` are satisfied, the `if_then` operations are applied atomically,
+/// otherwise the `else_then` operations are applied.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct TxnRequest {
+    pub condition: Vec<TxnCondition>,
+    pub if_then: Vec<TxnOp>,
+    pub else_then: Vec<TxnOp>,
+}
+
+/// The result of a transaction.
+///
+/// `success` tells whether all the conditions are satisfied, `changes` are the states before and
+/// after each applied operation, identified by the key.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct TxnReply {
+    pub success: bool,
+    pub changes: Vec<Change<Vec<u8>, String>>,
+}
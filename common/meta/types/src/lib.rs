@@ -19,11 +19,13 @@ mod change;
 mod cluster;
 mod cmd;
 pub mod config;
+mod copy_history;
 mod database;
 mod endpoint;
 mod errors;
 mod kv_message;
 mod log_entry;
+mod masking_policy;
 mod match_seq;
 mod message;
 mod meta_errors;
@@ -32,10 +34,12 @@ mod meta_network_errors;
 mod meta_raft_errors;
 mod meta_result_error;
 mod meta_storage_errors;
+mod network_policy;
 mod operation;
 mod raft_txid;
 mod raft_types;
 mod role_info;
+mod row_access_policy;
 mod seq_num;
 mod seq_value;
 mod table;
@@ -48,6 +52,7 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod watch;
 
 pub mod error_context;
 mod principal_identity;
@@ -73,6 +78,7 @@ pub use cluster::Node;
 pub use cluster::NodeInfo;
 pub use cluster::Slot;
 pub use cmd::Cmd;
+pub use copy_history::CopiedFileInfo;
 pub use database::CreateDatabaseReply;
 pub use database::CreateDatabaseReq;
 pub use database::DatabaseInfo;
@@ -89,11 +95,17 @@ pub use kv_message::ListKVReq;
 pub use kv_message::MGetKVActionReply;
 pub use kv_message::MGetKVReq;
 pub use kv_message::PrefixListReply;
+pub use kv_message::TxnCondition;
+pub use kv_message::TxnOp;
+pub use kv_message::TxnReply;
+pub use kv_message::TxnRequest;
 pub use kv_message::UpsertKVAction;
 pub use kv_message::UpsertKVActionReply;
 pub use log_entry::LogEntry;
+pub use masking_policy::MaskingPolicy;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
+pub use message::ChangeRoleRequest;
 pub use message::ForwardRequest;
 pub use message::ForwardRequestBody;
 pub use message::ForwardResponse;
@@ -119,6 +131,7 @@ pub use meta_storage_errors::UnknownDatabase;
 pub use meta_storage_errors::UnknownDatabaseId;
 pub use meta_storage_errors::UnknownTable;
 pub use meta_storage_errors::UnknownTableId;
+pub use network_policy::NetworkPolicy;
 pub use operation::MetaId;
 pub use operation::MetaVersion;
 pub use operation::Operation;
@@ -129,6 +142,7 @@ pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
 pub use role_info::RoleInfo;
+pub use row_access_policy::RowAccessPolicy;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
@@ -137,7 +151,11 @@ pub use table::CreateTableReply;
 pub use table::CreateTableReq;
 pub use table::DropTableReply;
 pub use table::DropTableReq;
+pub use table::DroppedTableInfo;
+pub use table::GcDroppedTableReply;
+pub use table::GcDroppedTableReq;
 pub use table::GetTableReq;
+pub use table::ListDroppedTableReq;
 pub use table::ListTableReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
@@ -145,16 +163,25 @@ pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIndent;
+pub use table::UndropTableReply;
+pub use table::UndropTableReq;
+pub use table::UpdateTableSchemaReply;
+pub use table::UpdateTableSchemaReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
+pub use user_defined_function::UDFLanguage;
+pub use user_defined_function::UDFServer;
+pub use user_defined_function::UDFWasmModule;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::UserGrantSet;
 pub use user_identity::UserIdentity;
+pub use user_info::PasswordState;
+pub use user_info::SettingConstraint;
 pub use user_info::UserInfo;
 pub use user_info::UserOption;
 pub use user_info::UserOptionFlag;
@@ -165,3 +192,8 @@ pub use user_setting::UserSetting;
 pub use user_stage::*;
 pub use warehouse_meta::WarehouseInfo;
 pub use warehouse_meta::WarehouseMeta;
+pub use watch::database_watch_key;
+pub use watch::table_watch_key;
+pub use watch::WatchEvent;
+pub use watch::WATCH_DATABASE_PREFIX;
+pub use watch::WATCH_TABLE_PREFIX;
@@ -29,6 +29,13 @@ pub struct LogEntry {
     /// When not None, it is used to filter out duplicated logs, which are caused by retries by client.
     pub txid: Option<RaftTxId>,
 
+    /// The time in milliseconds when the log is proposed, it is assigned by the leader.
+    ///
+    /// The expiration of the kv entries is checked against this time when the log is applied,
+    /// thus every node makes the same decision. When it is None, the local time is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_ms: Option<u64>,
+
     /// The action a client want to take.
     pub cmd: Cmd,
 }
@@ -0,0 +1,46 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A masking policy, e.g. `CREATE MASKING POLICY email_mask AS (val) -> concat(left(val, 1), '***')`.
+///
+/// The policy is set on a column of a table, then the values of the column are replaced by the
+/// expression with its only parameter bound to the column when they are projected, unless one
+/// of the exempt roles is active.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct MaskingPolicy {
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub definition: String,
+    pub exempt_roles: Vec<String>,
+    pub description: String,
+}
+
+impl MaskingPolicy {
+    pub fn new(name: &str, parameters: Vec<String>, definition: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: definition.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_exempt(&self, roles: &[String]) -> bool {
+        self.exempt_roles.iter().any(|role| roles.contains(role))
+    }
+}
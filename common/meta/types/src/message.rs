@@ -26,12 +26,14 @@ use crate::protobuf::RaftReply;
 use crate::protobuf::RaftRequest;
 use crate::AppliedState;
 use crate::DatabaseInfo;
+use crate::DroppedTableInfo;
 use crate::Endpoint;
 use crate::GetDatabaseReq;
 use crate::GetKVActionReply;
 use crate::GetKVReq;
 use crate::GetTableReq;
 use crate::ListDatabaseReq;
+use crate::ListDroppedTableReq;
 use crate::ListKVReq;
 use crate::ListTableReq;
 use crate::LogEntry;
@@ -53,6 +55,16 @@ pub enum RetryableError {
 pub struct JoinRequest {
     pub node_id: NodeId,
     pub endpoint: Endpoint,
+    /// Join as a learner that replicates the logs but does not vote.
+    #[serde(default)]
+    pub as_learner: bool,
+}
+
+/// Promote a learner to a voter, or demote a voter to a learner.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChangeRoleRequest {
+    pub node_id: NodeId,
+    pub as_learner: bool,
 }
 
 #[derive(
@@ -60,12 +72,14 @@ pub struct JoinRequest {
 )]
 pub enum ForwardRequestBody {
     Join(JoinRequest),
+    ChangeRole(ChangeRoleRequest),
     Write(LogEntry),
 
     ListDatabase(ListDatabaseReq),
     GetDatabase(GetDatabaseReq),
     ListTable(ListTableReq),
     GetTable(GetTableReq),
+    ListDroppedTable(ListDroppedTableReq),
 
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
@@ -91,11 +105,13 @@ impl ForwardRequest {
 #[allow(clippy::large_enum_variant)]
 pub enum ForwardResponse {
     Join(()),
+    ChangeRole(()),
     AppliedState(AppliedState),
     ListDatabase(Vec<Arc<DatabaseInfo>>),
     DatabaseInfo(Arc<DatabaseInfo>),
     ListTable(Vec<Arc<TableInfo>>),
     TableInfo(Arc<TableInfo>),
+    ListDroppedTable(Vec<DroppedTableInfo>),
 
     GetKV(GetKVActionReply),
     MGetKV(MGetKVActionReply),
@@ -45,6 +45,9 @@ pub enum MetaRaftError {
     #[error("{0}")]
     JoinClusterFail(String),
 
+    #[error("{0}")]
+    ChangeRoleFail(String),
+
     #[error("{0}")]
     RequestNotForwardToLeaderError(String),
 }
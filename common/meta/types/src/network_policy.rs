@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The IP addresses the users can connect from, the entries are IP addresses or CIDR blocks
+/// like `192.168.1.0/24`. The blocked list takes precedence over the allowed list, and an
+/// empty allowed list allows all the addresses.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+}
+
+impl NetworkPolicy {
+    pub fn new(name: &str, allowed_ip_list: Vec<String>, blocked_ip_list: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            allowed_ip_list,
+            blocked_ip_list,
+            ..Default::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for entry in self.allowed_ip_list.iter().chain(&self.blocked_ip_list) {
+            parse_ip_block(entry)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_allowed(&self, ip: &IpAddr) -> bool {
+        let matches = |entry: &String| match parse_ip_block(entry) {
+            Ok((network, prefix)) => in_ip_block(ip, &network, prefix),
+            Err(_) => false,
+        };
+
+        if self.blocked_ip_list.iter().any(matches) {
+            return false;
+        }
+        self.allowed_ip_list.is_empty() || self.allowed_ip_list.iter().any(matches)
+    }
+}
+
+// Parse `192.168.1.0/24` into the network and the prefix length, a single address has the full
+// prefix length.
+fn parse_ip_block(entry: &str) -> Result<(IpAddr, u32)> {
+    let invalid =
+        || ErrorCode::BadArguments(format!("Invalid IP address or CIDR block: {}", entry));
+    let (address, prefix) = match entry.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (entry, None),
+    };
+
+    let network = address.trim().parse::<IpAddr>().map_err(|_| invalid())?;
+    let max_prefix = match network {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+    let prefix = match prefix {
+        None => max_prefix,
+        Some(prefix) => prefix.trim().parse::<u32>().map_err(|_| invalid())?,
+    };
+    if prefix > max_prefix {
+        return Err(invalid());
+    }
+    Ok((network, prefix))
+}
+
+fn in_ip_block(ip: &IpAddr, network: &IpAddr, prefix: u32) -> bool {
+    let (ip, network, bits) = match (unmap_ipv4(ip), network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            (u32::from(ip) as u128, u32::from(*network) as u128, 32)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => (u128::from(ip), u128::from(*network), 128),
+        _ => return false,
+    };
+    if prefix == 0 {
+        return true;
+    }
+    let mask = (u128::MAX >> (128 - bits)) ^ ((1u128 << (bits - prefix)) - 1);
+    ip & mask == network & mask
+}
+
+// The IPv4 clients of a dual stack listener are seen as `::ffff:a.b.c.d`.
+fn unmap_ipv4(ip: &IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => match v6.octets() {
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, a, b, c, d] => {
+                IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+            }
+            _ => *ip,
+        },
+        IpAddr::V4(_) => *ip,
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A row access policy, e.g. `CREATE ROW ACCESS POLICY p AS (region) -> region = 'EU'`.
+///
+/// The policy is attached to the columns of a table, then the rows of the table are filtered
+/// by the predicate with the parameters bound to the columns, unless one of the exempt roles
+/// is active.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct RowAccessPolicy {
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub definition: String,
+    pub exempt_roles: Vec<String>,
+    pub description: String,
+}
+
+impl RowAccessPolicy {
+    pub fn new(name: &str, parameters: Vec<String>, definition: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: definition.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_exempt(&self, roles: &[String]) -> bool {
+        self.exempt_roles.iter().any(|role| roles.contains(role))
+    }
+}
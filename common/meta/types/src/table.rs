@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Display;
@@ -108,6 +109,32 @@ pub struct TableMeta {
     pub engine_options: HashMap<String, String>,
     pub options: HashMap<String, String>,
     pub created_on: DateTime<Utc>,
+
+    /// The version of `schema`, it is increased by every schema change.
+    #[serde(default)]
+    pub schema_version: u64,
+
+    /// The previous schemas by their versions, they are kept for reading the data written with
+    /// them, e.g. the old snapshots of a table.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub schema_history: BTreeMap<u64, Arc<DataSchema>>,
+}
+
+impl TableMeta {
+    /// Returns the schema of the given version.
+    pub fn schema_at(&self, version: u64) -> Option<Arc<DataSchema>> {
+        match version == self.schema_version {
+            true => Some(self.schema.clone()),
+            false => self.schema_history.get(&version).cloned(),
+        }
+    }
+
+    /// Replace the schema with a new version, the current one is kept in the history.
+    pub fn update_schema(&mut self, schema: Arc<DataSchema>) {
+        let prev = std::mem::replace(&mut self.schema, schema);
+        self.schema_history.insert(self.schema_version, prev);
+        self.schema_version += 1;
+    }
 }
 
 impl TableInfo {
@@ -164,6 +191,8 @@ impl Default for TableMeta {
             engine_options: HashMap::new(),
             options: HashMap::new(),
             created_on: Utc::now(),
+            schema_version: 0,
+            schema_history: BTreeMap::new(),
         }
     }
 }
@@ -172,8 +201,13 @@ impl Display for TableMeta {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Engine: {}={:?}, Schema: {}, Options: {:?} CreatedOn: {:?}",
-            self.engine, self.engine_options, self.schema, self.options, self.created_on
+            "Engine: {}={:?}, Schema: {}, SchemaVersion: {}, Options: {:?} CreatedOn: {:?}",
+            self.engine,
+            self.engine_options,
+            self.schema,
+            self.schema_version,
+            self.options,
+            self.created_on
         )
     }
 }
@@ -208,11 +242,38 @@ pub struct DropTableReq {
     pub tenant: String,
     pub db: String,
     pub table: String,
+    /// Remove the meta of the table, otherwise it is kept for `UNDROP TABLE`.
+    pub purge: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct DropTableReply {}
 
+/// Restore the table dropped last by the name, with the same table id and data.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReq {
+    pub tenant: String,
+    pub db: String,
+    pub table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReply {
+    pub table_id: u64,
+}
+
+/// Remove a dropped table and its meta, it is sent after the data of the table are purged.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GcDroppedTableReq {
+    pub tenant: String,
+    pub db: String,
+    pub table: String,
+    pub table_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct GcDroppedTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct RenameTableReq {
     pub if_exists: bool,
@@ -257,6 +318,30 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+/// Change the schema of a table, the previous schema is kept as an old version.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub schema: Arc<DataSchema>,
+}
+
+impl UpdateTableSchemaReq {
+    pub fn new(table_ident: &TableIdent, schema: Arc<DataSchema>) -> UpdateTableSchemaReq {
+        UpdateTableSchemaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            schema,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableSchemaReply {
+    /// The version of the new schema.
+    pub schema_version: u64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -311,3 +396,35 @@ impl ListTableReq {
         }
     }
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListDroppedTableReq {
+    pub inner: DatabaseNameIdent,
+}
+
+impl Deref for ListDroppedTableReq {
+    type Target = DatabaseNameIdent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ListDroppedTableReq {
+    pub fn new(tenant: impl Into<String>, db_name: impl Into<String>) -> ListDroppedTableReq {
+        ListDroppedTableReq {
+            inner: DatabaseNameIdent {
+                tenant: tenant.into(),
+                db_name: db_name.into(),
+            },
+        }
+    }
+}
+
+/// A dropped table that can be restored by `UNDROP TABLE` until it is vacuumed.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DroppedTableInfo {
+    pub table_info: Arc<TableInfo>,
+    /// In seconds since epoch.
+    pub dropped_on: u64,
+}
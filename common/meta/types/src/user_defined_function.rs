@@ -13,12 +13,64 @@
 // limitations under the License.
 
 use std::convert::TryFrom;
+use std::fmt;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
+/// The language of the UDF definition, the UDFs stored before the language is introduced are SQL.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UDFLanguage {
+    Sql,
+    /// The function is computed by a WebAssembly module in a stage, see [`UDFWasmModule`].
+    Wasm,
+    /// The function is computed by an external UDF server, see [`UDFServer`].
+    External,
+}
+
+impl Default for UDFLanguage {
+    fn default() -> Self {
+        UDFLanguage::Sql
+    }
+}
+
+impl fmt::Display for UDFLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UDFLanguage::Sql => write!(f, "SQL"),
+            UDFLanguage::Wasm => write!(f, "WASM"),
+            UDFLanguage::External => write!(f, "EXTERNAL"),
+        }
+    }
+}
+
+/// An external UDF server, the rows are sent to the `handler` at `address` as Arrow record
+/// batches and the results are sent back the same way. The types are the names of the data types.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UDFServer {
+    pub address: String,
+    pub handler: String,
+    /// The language the handler is written in, only for display, e.g. `python`.
+    pub language: String,
+    pub arg_types: Vec<String>,
+    pub return_type: String,
+}
+
+/// The WebAssembly module of a WASM UDF, the `handler` exported by the module at `stage_path`,
+/// e.g. `@my_stage/f.wasm`, is called with the columns of the arguments encoded as an Arrow IPC
+/// stream. The types are the names of the data types.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UDFWasmModule {
+    pub stage_path: String,
+    pub handler: String,
+    pub arg_types: Vec<String>,
+    pub return_type: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
 pub struct UserDefinedFunction {
@@ -27,6 +79,12 @@ pub struct UserDefinedFunction {
 
     pub description: String,
     pub definition: String,
+    pub language: UDFLanguage,
+    pub server: Option<UDFServer>,
+    pub wasm_module: Option<UDFWasmModule>,
+    /// The SQL definition is an expression of aggregate functions, e.g. `sum(x) / count(x)`,
+    /// see `CREATE AGGREGATE FUNCTION`.
+    pub is_aggregate: bool,
 }
 
 impl UserDefinedFunction {
@@ -36,6 +94,63 @@ impl UserDefinedFunction {
             parameters,
             definition: definition.to_string(),
             description: description.to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            wasm_module: None,
+            is_aggregate: false,
+        }
+    }
+
+    pub fn new_aggregate(
+        name: &str,
+        parameters: Vec<String>,
+        definition: &str,
+        description: &str,
+    ) -> Self {
+        Self {
+            is_aggregate: true,
+            ..Self::new(name, parameters, definition, description)
+        }
+    }
+
+    pub fn new_wasm(name: &str, wasm_module: UDFWasmModule, description: &str) -> Self {
+        let definition = format!(
+            "({}) RETURNS {} LANGUAGE WASM HANDLER = '{}' AS '{}'",
+            wasm_module.arg_types.join(", "),
+            wasm_module.return_type,
+            wasm_module.handler,
+            wasm_module.stage_path
+        );
+        Self {
+            name: name.to_string(),
+            parameters: vec![],
+            definition,
+            description: description.to_string(),
+            language: UDFLanguage::Wasm,
+            server: None,
+            wasm_module: Some(wasm_module),
+            is_aggregate: false,
+        }
+    }
+
+    pub fn new_external(name: &str, server: UDFServer, description: &str) -> Self {
+        let definition = format!(
+            "({}) RETURNS {} LANGUAGE {} HANDLER = '{}' ADDRESS = '{}'",
+            server.arg_types.join(", "),
+            server.return_type,
+            server.language,
+            server.handler,
+            server.address
+        );
+        Self {
+            name: name.to_string(),
+            parameters: vec![],
+            definition,
+            description: description.to_string(),
+            language: UDFLanguage::External,
+            server: Some(server),
+            wasm_module: None,
+            is_aggregate: false,
         }
     }
 }
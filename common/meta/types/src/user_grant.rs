@@ -25,30 +25,37 @@ pub enum GrantObject {
     Global,
     Database(String),
     Table(String, String),
+    Column(String, String, String),
 }
 
 impl GrantObject {
-    /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Comparing the grant objects, the Database object contains all the Table objects inside it,
+    /// the Table object contains all its Column objects. Global object contains all the Database
+    /// objects.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
             (GrantObject::Database(_), GrantObject::Global) => false,
             (GrantObject::Database(lhs), GrantObject::Database(rhs)) => lhs == rhs,
             (GrantObject::Database(lhs), GrantObject::Table(rhs, _)) => lhs == rhs,
-            (GrantObject::Table(lhs_db, lhs_table), GrantObject::Table(rhs_db, rhs_table)) => {
+            (GrantObject::Database(lhs), GrantObject::Column(rhs, _, _)) => lhs == rhs,
+            (GrantObject::Table(lhs_db, lhs_table), GrantObject::Table(rhs_db, rhs_table))
+            | (GrantObject::Table(lhs_db, lhs_table), GrantObject::Column(rhs_db, rhs_table, _)) => {
                 (lhs_db == rhs_db) && (lhs_table == rhs_table)
             }
             (GrantObject::Table(_, _), _) => false,
+            (GrantObject::Column(..), GrantObject::Column(..)) => self == object,
+            (GrantObject::Column(..), _) => false,
         }
     }
 
-    /// Global, database and table has different available privileges
+    /// Global, database, table and column has different available privileges
     pub fn available_privileges(&self) -> UserPrivilegeSet {
         match self {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(..) => UserPrivilegeSet::available_privileges_on_column(),
         }
     }
 }
@@ -59,6 +66,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Global => write!(f, "*.*"),
             GrantObject::Database(ref db) => write!(f, "'{}'.*", db),
             GrantObject::Table(ref db, ref table) => write!(f, "'{}'.'{}'", db, table),
+            GrantObject::Column(ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'", db, table, column)
+            }
         }
     }
 }
@@ -74,6 +84,14 @@ impl GrantEntry {
         Self { object, privileges }
     }
 
+    pub fn object(&self) -> &GrantObject {
+        &self.object
+    }
+
+    pub fn privileges(&self) -> UserPrivilegeSet {
+        self.privileges.into()
+    }
+
     pub fn verify_privilege(&self, object: &GrantObject, privilege: UserPrivilegeType) -> bool {
         // the verified object should be smaller than the object inside my grant entry.
         if !self.object.contains(object) {
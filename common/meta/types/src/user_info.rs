@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core::fmt;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 
 use common_exception::ErrorCode;
@@ -102,6 +103,24 @@ impl TryFrom<Vec<u8>> for UserInfo {
 #[serde(default)]
 pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
+
+    // The current database of the new sessions of the user.
+    default_database: Option<String>,
+
+    // The only role of the new sessions of the user to check the privileges with.
+    default_role: Option<String>,
+
+    // The settings applied to the new sessions of the user.
+    default_settings: BTreeMap<String, String>,
+
+    // The constraints of the settings the user can change.
+    setting_constraints: BTreeMap<String, SettingConstraint>,
+
+    // The state of the password maintained by the server for the password policies.
+    password_state: PasswordState,
+
+    // The network policy checked when the user logins.
+    network_policy: Option<String>,
 }
 
 impl UserOption {
@@ -120,6 +139,81 @@ impl UserOption {
     pub fn has_option_flag(&self, flag: UserOptionFlag) -> bool {
         self.flags.contains(flag)
     }
+
+    pub fn default_database(&self) -> Option<&String> {
+        self.default_database.as_ref()
+    }
+
+    pub fn set_default_database(&mut self, database: Option<String>) {
+        self.default_database = database;
+    }
+
+    pub fn default_role(&self) -> Option<&String> {
+        self.default_role.as_ref()
+    }
+
+    pub fn set_default_role(&mut self, role: Option<String>) {
+        self.default_role = role;
+    }
+
+    pub fn default_settings(&self) -> &BTreeMap<String, String> {
+        &self.default_settings
+    }
+
+    pub fn set_default_settings(&mut self, settings: BTreeMap<String, String>) {
+        self.default_settings = settings;
+    }
+
+    pub fn setting_constraints(&self) -> &BTreeMap<String, SettingConstraint> {
+        &self.setting_constraints
+    }
+
+    /// Set the constraint of the setting, or remove it if None.
+    pub fn set_setting_constraint(&mut self, name: String, constraint: Option<SettingConstraint>) {
+        match constraint {
+            Some(constraint) => self.setting_constraints.insert(name, constraint),
+            None => self.setting_constraints.remove(&name),
+        };
+    }
+
+    pub fn password_state(&self) -> &PasswordState {
+        &self.password_state
+    }
+
+    pub fn password_state_mut(&mut self) -> &mut PasswordState {
+        &mut self.password_state
+    }
+
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
+    pub fn set_network_policy(&mut self, policy: Option<String>) {
+        self.network_policy = policy;
+    }
+}
+
+/// The state of the password of the user, the times are the seconds since the epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PasswordState {
+    /// The user must change the password before executing the other statements.
+    pub must_change: bool,
+    /// None for the users created before the password policies, their passwords never expire.
+    pub changed_at: Option<u64>,
+    /// The previous passwords, the latest first.
+    pub history: Vec<AuthInfo>,
+    pub failed_attempts: u64,
+    pub locked_until: Option<u64>,
+}
+
+/// The values the user can set a setting to, the min and max only apply to the numeric settings.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct SettingConstraint {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub readonly: bool,
 }
 
 #[bitflags]
@@ -111,8 +111,9 @@ impl UserPrivilegeSet {
     /// on databases and tables, and has some Global only privileges.
     pub fn available_privileges_on_global() -> Self {
         let database_privs = Self::available_privileges_on_database();
-        let privs =
-            make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | CreateRole | Grant });
+        let privs = make_bitflags!(UserPrivilegeType::{
+            Usage | Super | CreateUser | CreateRole | CreateStage | Grant
+        });
         (database_privs.privileges | privs).into()
     }
 
@@ -128,6 +129,11 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// The privileges which can be granted on the columns of a table
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select | Insert | Update }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -137,6 +143,10 @@ impl UserPrivilegeSet {
         self.privileges |= privilege;
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.privileges.is_empty()
+    }
+
     pub fn has_privilege(&self, privilege: UserPrivilegeType) -> bool {
         self.privileges.contains(privilege)
     }
@@ -69,12 +69,37 @@ pub enum StageFileCompression {
     RawDeflate,
     Lzo,
     Snappy,
+    Xz,
     None,
 }
 
+// The compression is detected by the file extension and the magic bytes by default.
 impl Default for StageFileCompression {
     fn default() -> Self {
-        Self::None
+        Self::Auto
+    }
+}
+
+impl FromStr for StageFileCompression {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "AUTO" => Ok(StageFileCompression::Auto),
+            "GZIP" => Ok(StageFileCompression::Gzip),
+            "BZ2" => Ok(StageFileCompression::Bz2),
+            "BROTLI" => Ok(StageFileCompression::Brotli),
+            "ZSTD" => Ok(StageFileCompression::Zstd),
+            "DEFLATE" => Ok(StageFileCompression::Deflate),
+            "RAW_DEFLATE" => Ok(StageFileCompression::RawDeflate),
+            "LZO" => Ok(StageFileCompression::Lzo),
+            "SNAPPY" => Ok(StageFileCompression::Snappy),
+            "XZ" => Ok(StageFileCompression::Xz),
+            "NONE" => Ok(StageFileCompression::None),
+            _ => Err(
+                "Unknown file compression type, must one of { AUTO | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAW_DEFLATE | LZO | SNAPPY | XZ | NONE }"
+                    .to_string(),
+            ),
+        }
     }
 }
 
@@ -99,13 +124,14 @@ impl FromStr for StageFileFormatType {
     fn from_str(s: &str) -> std::result::Result<Self, String> {
         match s.to_uppercase().as_str() {
             "CSV" => Ok(StageFileFormatType::Csv),
-            "JSON" => Ok(StageFileFormatType::Json),
+            // The JSON files are read line by line, each line is a JSON object.
+            "JSON" | "NDJSON" => Ok(StageFileFormatType::Json),
             "AVRO" => Ok(StageFileFormatType::Avro),
             "ORC" => Ok(StageFileFormatType::Orc),
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
             _ => Err(
-                "Unknown file format type, must one of { CSV | JSON | AVRO | ORC | PARQUET | XML }"
+                "Unknown file format type, must one of { CSV | JSON | NDJSON | AVRO | ORC | PARQUET | XML }"
                     .to_string(),
             ),
         }
@@ -120,6 +146,14 @@ pub struct FileFormatOptions {
     pub skip_header: u64,
     pub field_delimiter: String,
     pub record_delimiter: String,
+    // The quote character of the CSV fields.
+    pub quote: String,
+    // The escape character in the quoted CSV fields, the quote is escaped by doubling it if empty.
+    pub escape: String,
+    // The CSV field values loaded as NULL.
+    pub null_if: Vec<String>,
+    // Whether to trim the whitespaces around the CSV fields.
+    pub trim_space: bool,
     pub compression: StageFileCompression,
 }
 
@@ -130,6 +164,10 @@ impl Default for FileFormatOptions {
             record_delimiter: "\n".to_string(),
             field_delimiter: ",".to_string(),
             skip_header: 0,
+            quote: "\"".to_string(),
+            escape: "".to_string(),
+            null_if: vec![],
+            trim_space: false,
             compression: StageFileCompression::default(),
         }
     }
@@ -186,6 +224,7 @@ impl FromStr for OnErrorMode {
             "" => Ok(OnErrorMode::None),
             "CONTINUE" => Ok(OnErrorMode::Continue),
             "SKIP_FILE" => Ok(OnErrorMode::SkipFile),
+            "ABORT_STATEMENT" => Ok(OnErrorMode::AbortStatement),
             v => {
                 let num_str = v.replace("SKIP_FILE_", "");
                 let nums = num_str.parse::<u64>();
@@ -202,6 +241,18 @@ impl FromStr for OnErrorMode {
     }
 }
 
+impl OnErrorMode {
+    /// The number of the malformed records of a file that are rejected and skipped,
+    /// the load of the file fails on the next one.
+    pub fn max_record_errors(&self) -> u64 {
+        match self {
+            OnErrorMode::Continue => u64::MAX,
+            OnErrorMode::SkipFileNum(n) => n.saturating_sub(1),
+            OnErrorMode::None | OnErrorMode::SkipFile | OnErrorMode::AbortStatement => 0,
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
 #[serde(default)]
 pub struct CopyOptions {
@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::SeqV;
+
+/// The prefix of the keys of the database DDL changes.
+pub const WATCH_DATABASE_PREFIX: &str = "__fd_watch_databases/";
+
+/// The prefix of the keys of the table DDL changes.
+pub const WATCH_TABLE_PREFIX: &str = "__fd_watch_tables/";
+
+/// A change of a key sent by the watch API of the metasrv.
+///
+/// The changes of the databases and the tables are not changes of the general purpose kv,
+/// they are sent with the keys built by [`database_watch_key`] and [`table_watch_key`] and
+/// without the values, e.g. to invalidate the cached table metadata.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WatchEvent {
+    pub key: String,
+    pub prev: Option<SeqV>,
+    pub current: Option<SeqV>,
+}
+
+impl WatchEvent {
+    pub fn new(key: impl Into<String>, prev: Option<SeqV>, current: Option<SeqV>) -> Self {
+        WatchEvent {
+            key: key.into(),
+            prev,
+            current,
+        }
+    }
+}
+
+/// The key of the changes of a database: created or dropped.
+pub fn database_watch_key(tenant: &str, db_name: &str) -> String {
+    format!("{}{}/{}", WATCH_DATABASE_PREFIX, tenant, db_name)
+}
+
+/// The key of the changes of a table: created, dropped, renamed or the options are updated.
+pub fn table_watch_key(table_id: u64) -> String {
+    format!("{}{}", WATCH_TABLE_PREFIX, table_id)
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::NetworkPolicy;
+
+#[test]
+fn test_network_policy() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "policy1",
+        vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+        vec!["192.168.1.99".to_string()],
+    );
+    policy.validate()?;
+
+    let cases = vec![
+        ("192.168.1.1", true),
+        ("192.168.1.99", false),
+        ("192.168.2.1", false),
+        ("10.0.0.1", true),
+        ("10.0.0.2", false),
+        ("::ffff:192.168.1.1", true),
+        ("::1", false),
+    ];
+    for (ip, expect) in cases {
+        assert_eq!(policy.is_allowed(&ip.parse().unwrap()), expect, "{}", ip);
+    }
+
+    // An empty allowed list allows all the addresses but the blocked ones.
+    let policy = NetworkPolicy::new("policy2", vec![], vec!["fd00::/8".to_string()]);
+    assert!(policy.is_allowed(&"127.0.0.1".parse().unwrap()));
+    assert!(!policy.is_allowed(&"fd12::1".parse().unwrap()));
+
+    for entry in ["192.168.1.0/33", "192.168.1", "localhost"] {
+        let policy = NetworkPolicy::new("policy3", vec![entry.to_string()], vec![]);
+        assert!(policy.validate().is_err(), "{}", entry);
+    }
+
+    Ok(())
+}
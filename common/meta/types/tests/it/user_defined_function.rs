@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use common_exception::exception::Result;
+use common_meta_types::UDFLanguage;
+use common_meta_types::UDFServer;
+use common_meta_types::UDFWasmModule;
 use common_meta_types::UserDefinedFunction;
 
 #[test]
@@ -30,3 +33,71 @@ fn test_udf() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_wasm_udf() -> Result<()> {
+    let wasm_module = UDFWasmModule {
+        stage_path: "@my_stage/f.wasm".to_string(),
+        handler: "gcd".to_string(),
+        arg_types: vec!["Int64".to_string(), "Int64".to_string()],
+        return_type: "Int64".to_string(),
+    };
+    let udf = UserDefinedFunction::new_wasm("gcd", wasm_module.clone(), "");
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert_eq!(de.language, UDFLanguage::Wasm);
+    assert_eq!(de.wasm_module, Some(wasm_module));
+    assert_eq!(
+        de.definition,
+        "(Int64, Int64) RETURNS Int64 LANGUAGE WASM HANDLER = 'gcd' AS '@my_stage/f.wasm'"
+    );
+
+    // The UDFs stored without a language are SQL UDFs.
+    let ser =
+        r#"{"name":"isnotnull","parameters":["p"],"description":"","definition":"not(isnull(p))"}"#;
+    let de = UserDefinedFunction::try_from(ser.as_bytes().to_vec())?;
+    assert_eq!(de.language, UDFLanguage::Sql);
+    assert!(!de.is_aggregate);
+
+    Ok(())
+}
+
+#[test]
+fn test_external_udf() -> Result<()> {
+    let server = UDFServer {
+        address: "127.0.0.1:8815".to_string(),
+        handler: "gcd".to_string(),
+        language: "python".to_string(),
+        arg_types: vec!["Int64".to_string(), "Int64".to_string()],
+        return_type: "Int64".to_string(),
+    };
+    let udf = UserDefinedFunction::new_external("gcd", server.clone(), "");
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert_eq!(de.language, UDFLanguage::External);
+    assert_eq!(de.server, Some(server));
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_udf() -> Result<()> {
+    let udf = UserDefinedFunction::new_aggregate(
+        "my_avg",
+        vec!["x".to_string()],
+        "sum(x) / count(x)",
+        "",
+    );
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert!(de.is_aggregate);
+    assert_eq!(de.language, UDFLanguage::Sql);
+
+    Ok(())
+}
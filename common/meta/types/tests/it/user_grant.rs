@@ -77,6 +77,31 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Table("db1".into(), "c".into()),
+            rhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Database("db1".into()),
+            rhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Column("db1".into(), "c".into(), "b".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("db1".into(), "c".into(), "a".into()),
+            rhs: GrantObject::Table("db1".into(), "c".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
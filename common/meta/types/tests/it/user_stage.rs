@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use common_exception::exception::Result;
+use common_meta_types::OnErrorMode;
 use common_meta_types::UserStageInfo;
 
 #[test]
@@ -25,3 +28,22 @@ fn test_user_stage() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_on_error_max_record_errors() -> Result<()> {
+    let cases = vec![
+        ("", 0),
+        ("ABORT_STATEMENT", 0),
+        ("SKIP_FILE", 0),
+        ("SKIP_FILE_1", 0),
+        ("skip_file_3", 2),
+        ("CONTINUE", u64::MAX),
+    ];
+
+    for (on_error, expect) in cases {
+        let mode = OnErrorMode::from_str(on_error).unwrap();
+        assert_eq!(mode.max_record_errors(), expect, "on_error: {}", on_error);
+    }
+
+    Ok(())
+}
@@ -23,5 +23,8 @@ pub use dump::SummaryCount;
 pub use metrics_exporter_prometheus::PrometheusHandle;
 pub use recorder::init_default_metrics_recorder;
 pub use recorder::label_counter;
+pub use recorder::label_counter_with_tag;
 pub use recorder::label_counter_with_val;
 pub use recorder::try_handle;
+pub use recorder::LABEL_KEY_CLUSTER;
+pub use recorder::LABEL_KEY_TENANT;
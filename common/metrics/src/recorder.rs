@@ -27,6 +27,7 @@ static PROMETHEUS_HANDLE: Lazy<Arc<RwLock<Option<PrometheusHandle>>>> =
 
 pub const LABEL_KEY_TENANT: &str = "tenant";
 pub const LABEL_KEY_CLUSTER: &str = "cluster_name";
+pub const LABEL_KEY_QUERY_TAG: &str = "query_tag";
 
 #[inline]
 pub fn label_counter(name: &'static str, tenant_id: &str, cluster_id: &str) {
@@ -42,6 +43,23 @@ pub fn label_counter_with_val(name: &'static str, val: u64, tenant_id: &str, clu
     counter!(name, val, &labels);
 }
 
+/// The counter with the `query_tag` label, so the load can be attributed to the jobs.
+#[inline]
+pub fn label_counter_with_tag(
+    name: &'static str,
+    val: u64,
+    tenant_id: &str,
+    cluster_id: &str,
+    query_tag: &str,
+) {
+    let labels = [
+        (LABEL_KEY_TENANT, tenant_id.to_string()),
+        (LABEL_KEY_CLUSTER, cluster_id.to_string()),
+        (LABEL_KEY_QUERY_TAG, query_tag.to_string()),
+    ];
+    counter!(name, val, &labels);
+}
+
 pub fn init_default_metrics_recorder() {
     static START: Once = Once::new();
     START.call_once(init_prometheus_recorder)
@@ -28,6 +28,7 @@ mod plan_expression_chain;
 mod plan_expression_column;
 mod plan_expression_common;
 mod plan_expression_function;
+mod plan_expression_lambda;
 mod plan_expression_literal;
 mod plan_expression_monotonicity;
 mod plan_expression_rewriter;
@@ -41,6 +42,10 @@ mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
 mod plan_list;
+mod plan_masking_policy_create;
+mod plan_masking_policy_drop;
+mod plan_network_policy_create;
+mod plan_network_policy_drop;
 mod plan_node;
 mod plan_node_builder;
 mod plan_node_display;
@@ -61,6 +66,9 @@ mod plan_role_create;
 mod plan_role_drop;
 mod plan_role_grant;
 mod plan_role_revoke;
+mod plan_role_set;
+mod plan_row_access_policy_create;
+mod plan_row_access_policy_drop;
 mod plan_select;
 mod plan_setting;
 mod plan_show;
@@ -81,10 +89,14 @@ mod plan_subqueries_set;
 mod plan_table_create;
 mod plan_table_describe;
 mod plan_table_drop;
+mod plan_table_masking_policy;
 mod plan_table_optimize;
 mod plan_table_rename;
+mod plan_table_row_access_policy;
 mod plan_table_show_create;
 mod plan_table_truncate;
+mod plan_table_undrop;
+mod plan_table_vacuum_drop;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -98,11 +110,13 @@ mod plan_user_udf_drop;
 mod plan_view_alter;
 mod plan_view_create;
 mod plan_view_drop;
+mod plan_window_func;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_call::CallPlan;
+pub use plan_copy::CopyIntoLocationPlan;
 pub use plan_copy::CopyPlan;
 pub use plan_copy::ValidationMode;
 pub use plan_database_create::CreateDatabasePlan;
@@ -118,6 +132,7 @@ pub use plan_expression::Expressions;
 pub use plan_expression_action::*;
 pub use plan_expression_chain::ExpressionChain;
 pub use plan_expression_column::col;
+pub use plan_expression_common::collated_group_values;
 pub use plan_expression_common::expand_aggregate_arg_exprs;
 pub use plan_expression_common::expand_wildcard;
 pub use plan_expression_common::expr_as_column_expr;
@@ -125,6 +140,9 @@ pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_window_exprs;
+pub use plan_expression_common::find_window_exprs_in_expr;
+pub use plan_expression_common::get_scalar_function;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -156,6 +174,10 @@ pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
 pub use plan_list::ListPlan;
+pub use plan_masking_policy_create::CreateMaskingPolicyPlan;
+pub use plan_masking_policy_drop::DropMaskingPolicyPlan;
+pub use plan_network_policy_create::CreateNetworkPolicyPlan;
+pub use plan_network_policy_drop::DropNetworkPolicyPlan;
 pub use plan_node::PlanNode;
 pub use plan_node_builder::PlanBuilder;
 pub use plan_node_extras::Extras;
@@ -179,6 +201,9 @@ pub use plan_role_create::CreateRolePlan;
 pub use plan_role_drop::DropRolePlan;
 pub use plan_role_grant::GrantRolePlan;
 pub use plan_role_revoke::RevokeRolePlan;
+pub use plan_role_set::SetRolePlan;
+pub use plan_row_access_policy_create::CreateRowAccessPolicyPlan;
+pub use plan_row_access_policy_drop::DropRowAccessPolicyPlan;
 pub use plan_select::SelectPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
@@ -203,12 +228,17 @@ pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_describe::DescribeTablePlan;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_table_masking_policy::AlterTableMaskingPolicyPlan;
 pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
 pub use plan_table_rename::RenameTableEntity;
 pub use plan_table_rename::RenameTablePlan;
+pub use plan_table_row_access_policy::AlterTableRowAccessPolicyPlan;
+pub use plan_table_row_access_policy::RowAccessPolicyAction;
 pub use plan_table_show_create::ShowCreateTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
+pub use plan_table_undrop::UndropTablePlan;
+pub use plan_table_vacuum_drop::VacuumDropTablePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -222,3 +252,7 @@ pub use plan_user_udf_drop::DropUserUDFPlan;
 pub use plan_view_alter::AlterViewPlan;
 pub use plan_view_create::CreateViewPlan;
 pub use plan_view_drop::DropViewPlan;
+pub use plan_window_func::WindowFrame;
+pub use plan_window_func::WindowFrameBound;
+pub use plan_window_func::WindowFrameUnits;
+pub use plan_window_func::WindowFuncPlan;
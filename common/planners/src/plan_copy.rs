@@ -16,9 +16,11 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_meta_types::MetaId;
+use common_meta_types::UserStageInfo;
 
+use crate::PlanNode;
 use crate::ReadDataSourcePlan;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone, Debug)]
@@ -84,3 +86,42 @@ impl Debug for CopyPlan {
         write!(f, " ,validation_mode:{:?}", self.validation_mode)
     }
 }
+
+/// Unloads the result of the query into files of a stage or an external location:
+/// COPY INTO '@stage/path/' | 's3://bucket/path/' FROM table | (query)
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct CopyIntoLocationPlan {
+    pub stage: UserStageInfo,
+    pub path: String,
+    pub query: Box<PlanNode>,
+    // The files are split when they reach the size(in bytes), 0 is unlimited.
+    pub max_file_size: usize,
+    pub overwrite: bool,
+    pub single: bool,
+}
+
+impl CopyIntoLocationPlan {
+    // The written files, one row per file.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("rows_unloaded", u64::to_data_type()),
+            DataField::new("file_size", u64::to_data_type()),
+        ])
+    }
+}
+
+impl Debug for CopyIntoLocationPlan {
+    // Ignore the credentials of the stage.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Copy into {:}, path:{:?}",
+            self.stage.stage_name, self.path
+        )?;
+        write!(f, " ,format:{:?}", self.stage.file_format_options.format)?;
+        write!(f, " ,max_file_size:{:}", self.max_file_size)?;
+        write!(f, " ,overwrite:{:}", self.overwrite)?;
+        write!(f, " ,single:{:}", self.single)
+    }
+}
@@ -23,6 +23,7 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    PipelineGraph,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -26,6 +26,7 @@ use once_cell::sync::Lazy;
 use crate::plan_expression_common::ExpressionDataTypeVisitor;
 use crate::ExpressionVisitor;
 use crate::PlanNode;
+use crate::WindowFrame;
 
 static OP_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     ["database", "version", "current_user"]
@@ -94,6 +95,21 @@ pub enum Expression {
         args: Vec<Expression>,
     },
 
+    /// A window function over the partitions of the rows, e.g.
+    /// `sum(a) OVER (PARTITION BY b ORDER BY c ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)`.
+    /// The op is either an aggregate function or one of the window functions like `lag`.
+    WindowFunction {
+        op: String,
+        distinct: bool,
+        params: Vec<DataValue>,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        /// The `Expression::Sort` to order the rows of the partitions
+        order_by: Vec<Expression>,
+        /// None if the frame is not specified, see `WindowFrame::default`
+        window_frame: Option<WindowFrame>,
+    },
+
     /// A sort expression, that can be used to sort values.
     Sort {
         /// The expression to sort on
@@ -122,6 +138,13 @@ pub enum Expression {
         is_nullable: bool,
     },
 
+    /// A lambda as the argument of higher-order functions, e.g. `x -> x + 1` in
+    /// `array_map(x -> x + 1, arr)`. The body only references the parameters.
+    Lambda {
+        params: Vec<String>,
+        body: Box<Expression>,
+    },
+
     /// Scalar sub query. such as `SELECT (SELECT 1)`
     ScalarSubquery {
         name: String,
@@ -189,36 +212,100 @@ impl Expression {
                 distinct,
                 params,
                 args,
+            } => Self::aggregate_column_name(op, *distinct, params, args),
+            Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
             } => {
-                let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
-                let params_name = params
-                    .iter()
-                    .map(|v| DataValue::custom_display(v, true))
-                    .collect::<Vec<_>>();
-
-                let prefix = if params.is_empty() {
-                    op.to_string()
-                } else {
-                    format!("{}({})", op, params_name.join(", "))
-                };
-
-                match distinct {
-                    true => format!("{}(distinct {})", prefix, args_column_name.join(", ")),
-                    false => format!("{}({})", prefix, args_column_name.join(", ")),
+                let mut window = vec![];
+                if !partition_by.is_empty() {
+                    let partition_by = partition_by.iter().map(Expression::column_name);
+                    window.push(format!(
+                        "PARTITION BY {}",
+                        partition_by.collect::<Vec<_>>().join(", ")
+                    ));
+                }
+                if !order_by.is_empty() {
+                    let order_by = order_by.iter().map(|expr| match expr {
+                        Expression::Sort {
+                            expr,
+                            asc,
+                            nulls_first,
+                            ..
+                        } => {
+                            let asc_name = if *asc { "" } else { " DESC" };
+                            let nulls_name = match (*asc, *nulls_first) {
+                                (true, false) => " NULLS LAST",
+                                (false, true) => " NULLS FIRST",
+                                _ => "",
+                            };
+                            format!("{}{}{}", expr.column_name(), asc_name, nulls_name)
+                        }
+                        _ => expr.column_name(),
+                    });
+                    window.push(format!(
+                        "ORDER BY {}",
+                        order_by.collect::<Vec<_>>().join(", ")
+                    ));
                 }
+                if let Some(window_frame) = window_frame {
+                    window.push(window_frame.to_string());
+                }
+
+                format!(
+                    "{} OVER ({})",
+                    Self::aggregate_column_name(op, *distinct, params, args),
+                    window.join(" ")
+                )
             }
             Expression::Sort { expr, .. } => expr.column_name(),
             Expression::Cast {
-                expr, data_type, ..
+                expr,
+                data_type,
+                is_nullable,
             } => {
-                format!("cast({} as {:?})", expr.column_name(), data_type)
+                let name = if *is_nullable { "try_cast" } else { "cast" };
+                format!("{}({} as {:?})", name, expr.column_name(), data_type)
             }
+            Expression::Lambda { params, body } => match params.len() {
+                1 => format!("{} -> {}", params[0], body.column_name()),
+                _ => format!("({}) -> {}", params.join(", "), body.column_name()),
+            },
             Expression::Subquery { name, .. } => name.clone(),
             Expression::ScalarSubquery { name, .. } => name.clone(),
             _ => format!("{:?}", self),
         }
     }
 
+    fn aggregate_column_name(
+        op: &str,
+        distinct: bool,
+        params: &[DataValue],
+        args: &[Expression],
+    ) -> String {
+        let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
+        let params_name = params
+            .iter()
+            .map(|v| DataValue::custom_display(v, true))
+            .collect::<Vec<_>>();
+
+        let prefix = if params.is_empty() {
+            op.to_string()
+        } else {
+            format!("{}({})", op, params_name.join(", "))
+        };
+
+        match distinct {
+            true => format!("{}(distinct {})", prefix, args_column_name.join(", ")),
+            false => format!("{}({})", prefix, args_column_name.join(", ")),
+        }
+    }
+
     pub fn to_data_field(&self, input_schema: &DataSchemaRef) -> Result<DataField> {
         let name = self.column_name();
         self.to_data_type(input_schema)
@@ -290,6 +377,8 @@ impl Expression {
         visitor.visit(self)?.finalize()
     }
 
+    /// The aggregate function of the expression, the window function evaluating an aggregate
+    /// function over the window also gets its aggregate function.
     pub fn to_aggregate_function(&self, schema: &DataSchemaRef) -> Result<AggregateFunctionRef> {
         match self {
             Expression::AggregateFunction {
@@ -297,6 +386,13 @@ impl Expression {
                 distinct,
                 params,
                 args,
+            }
+            | Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                ..
             } => {
                 let mut func_name = op.clone();
                 if *distinct {
@@ -315,6 +411,28 @@ impl Expression {
         }
     }
 
+    /// The return type of the window function: the ranking functions return the rank of the row,
+    /// the navigation functions return the value of another row of the partition, which is null
+    /// if there is no such row, and the aggregate functions return the aggregate of the frame.
+    pub fn to_window_data_type(&self, schema: &DataSchemaRef) -> Result<DataTypePtr> {
+        match self {
+            Expression::WindowFunction { op, args, .. } => match op.to_lowercase().as_str() {
+                "row_number" | "rank" | "dense_rank" => Ok(u64::to_data_type()),
+                "lag" | "lead" | "first_value" | "last_value" => match args.first() {
+                    Some(arg) => Ok(wrap_nullable(&arg.to_data_type(schema)?)),
+                    None => Err(ErrorCode::BadArguments(format!(
+                        "Window function {} expects the value argument",
+                        op
+                    ))),
+                },
+                _ => self.to_aggregate_function(schema)?.return_type(),
+            },
+            _ => Err(ErrorCode::LogicalError(
+                "Expression must be window function",
+            )),
+        }
+    }
+
     pub fn to_aggregate_function_names(&self) -> Result<Vec<String>> {
         match self {
             Expression::AggregateFunction { args, .. } => {
@@ -404,13 +522,21 @@ impl fmt::Debug for Expression {
                 Ok(())
             }
 
+            Expression::WindowFunction { .. } => write!(f, "{}", self.column_name()),
             Expression::Sort { expr, .. } => write!(f, "{:?}", expr),
             Expression::Wildcard => write!(f, "*"),
             Expression::Cast {
-                expr, data_type, ..
+                expr,
+                data_type,
+                is_nullable,
             } => {
-                write!(f, "cast({:?} as {:?})", expr, data_type)
+                let name = if *is_nullable { "try_cast" } else { "cast" };
+                write!(f, "{}({:?} as {:?})", name, expr, data_type)
             }
+            Expression::Lambda { params, body } => match params.len() {
+                1 => write!(f, "{} -> {:?}", params[0], body),
+                _ => write!(f, "({}) -> {:?}", params.join(", "), body),
+            },
         }
     }
 }
@@ -19,6 +19,8 @@ use common_exception::Result;
 use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 
+use crate::get_scalar_function;
+use crate::plan_expression_common::is_lambda;
 use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
@@ -164,21 +166,27 @@ impl ExpressionChain {
             }
 
             Expression::ScalarFunction { op, args } => {
+                // The lambda is evaluated by the function taking it, it's not an input column.
                 let arg_types = args
                     .iter()
+                    .filter(|arg| !is_lambda(arg))
                     .map(|action| action.to_data_type(&self.schema))
                     .collect::<Result<Vec<_>>>()?;
 
                 let arg_types2: Vec<&DataTypePtr> = arg_types.iter().collect();
 
-                let func = FunctionFactory::instance().get(op, &arg_types2)?;
+                let func = get_scalar_function(op, args, &arg_types2)?;
                 let return_type = func.return_type(&arg_types2)?;
 
                 let function = ActionFunction {
                     name: expr.column_name(),
                     func_name: op.clone(),
                     func,
-                    arg_names: args.iter().map(|action| action.column_name()).collect(),
+                    arg_names: args
+                        .iter()
+                        .filter(|arg| !is_lambda(arg))
+                        .map(|action| action.column_name())
+                        .collect(),
                     arg_types,
                     return_type,
                 };
@@ -191,7 +199,12 @@ impl ExpressionChain {
                     "Action must be a non-aggregated function.",
                 ));
             }
-            Expression::Wildcard | Expression::Sort { .. } => {}
+            Expression::WindowFunction { .. } => {
+                return Err(ErrorCode::LogicalError(
+                    "Action must be a non-window function.",
+                ));
+            }
+            Expression::Wildcard | Expression::Sort { .. } | Expression::Lambda { .. } => {}
             Expression::Cast {
                 expr: sub_expr,
                 data_type,
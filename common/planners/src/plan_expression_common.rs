@@ -18,8 +18,15 @@ use std::collections::HashSet;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::create_lambda_function;
+use common_functions::scalars::Function;
+use common_functions::scalars::FunctionAdapter;
 use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::StructGetFunction;
+use common_functions::scalars::UDFServerFunction;
+use common_functions::scalars::WasmUDFFunction;
 
+use crate::plan_expression_lambda::LambdaFunction;
 use crate::Expression;
 use crate::ExpressionVisitor;
 use crate::Recursion;
@@ -51,6 +58,44 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::WindowFunction`. They are returned in order of
+/// occurrence (depth first), with duplicates omitted.
+pub fn find_window_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+pub fn find_window_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+/// The strings with a collation are grouped by their folded form, see
+/// `DataBlock::fold_collations`, the value of such a group is the `min` of its original strings.
+/// Returns the name of each collated group by column with the aggregate of its value.
+pub fn collated_group_values(
+    group_expr: &[Expression],
+    schema: &DataSchemaRef,
+) -> Result<Vec<(String, Expression)>> {
+    let mut values = vec![];
+    for expr in group_expr {
+        let field = expr.to_data_field(schema)?;
+        if !collation_of(field.data_type()).is_binary() {
+            let column_name = expr.column_name();
+            let value = Expression::AggregateFunction {
+                op: "min".to_string(),
+                distinct: false,
+                params: vec![],
+                args: vec![Expression::Column(column_name.clone())],
+            };
+            values.push((column_name, value));
+        }
+    }
+    Ok(values)
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -303,6 +348,32 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                     .collect::<Result<Vec<Expression>>>()?,
             }),
 
+            Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let clone_exprs = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|e| clone_with_replacement(e, replacement_fn))
+                        .collect::<Result<Vec<Expression>>>()
+                };
+                Ok(Expression::WindowFunction {
+                    op: op.clone(),
+                    distinct: *distinct,
+                    params: params.clone(),
+                    args: clone_exprs(args)?,
+                    partition_by: clone_exprs(partition_by)?,
+                    order_by: clone_exprs(order_by)?,
+                    window_frame: *window_frame,
+                })
+            }
+
             Expression::Sort {
                 expr: nested_expr,
                 asc,
@@ -324,9 +395,11 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                 is_nullable: *is_nullable,
             }),
 
+            // The body of lambda only references the parameters of the lambda.
             Expression::Column(_)
             | Expression::QualifiedColumn(_)
             | Expression::Literal { .. }
+            | Expression::Lambda { .. }
             | Expression::Subquery { .. }
             | Expression::ScalarSubquery { .. } => Ok(expr.clone()),
         },
@@ -374,6 +447,78 @@ pub fn unwrap_alias_exprs(expr: &Expression) -> Result<Expression> {
     })
 }
 
+/// Get the scalar function of the expression. Unlike the other functions, the return type of
+/// the field access of structs, e.g. `get(s, 'a')`, depends on the value of the field argument,
+/// so it's resolved by the literal argument here. So are the higher-order functions taking a
+/// lambda, the `arg_types` are the types of the arguments except the lambda. And the return
+/// type of `udf_server` and `wasm_udf` is given by its third argument.
+pub fn get_scalar_function(
+    op: &str,
+    args: &[Expression],
+    arg_types: &[&DataTypePtr],
+) -> Result<Box<dyn Function>> {
+    if args.iter().skip(1).any(is_lambda) {
+        return Err(ErrorCode::BadArguments(format!(
+            "The lambda must be the first argument of function '{}'",
+            op.to_uppercase()
+        )));
+    }
+    if let Some(Expression::Lambda { params, body }) = args.first() {
+        let lambda = LambdaFunction::try_create(params, body)?;
+        let func = create_lambda_function(op, lambda)?;
+        let features = FunctionFactory::instance().get_features(op)?;
+        return Ok(FunctionAdapter::create(func, features.passthrough_null));
+    }
+
+    if op.eq_ignore_ascii_case("get")
+        && args.len() == 2
+        && arg_types[0].data_type_id() == TypeID::Struct
+    {
+        return match &args[1] {
+            Expression::Literal { value, .. } => {
+                let func = StructGetFunction::try_create(op, arg_types[0], value)?;
+                Ok(FunctionAdapter::create(func, false))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The field argument of function '{}' on struct must be a constant",
+                op.to_uppercase()
+            ))),
+        };
+    }
+
+    let is_udf_server = op.eq_ignore_ascii_case("udf_server");
+    let is_wasm_udf = op.eq_ignore_ascii_case("wasm_udf");
+    if (is_udf_server || is_wasm_udf) && args.len() >= 3 {
+        let constants = args[..3]
+            .iter()
+            .map(|arg| match arg {
+                Expression::Literal { value, .. } => value
+                    .as_string()
+                    .ok()
+                    .map(|v| String::from_utf8_lossy(&v).to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(constants) = constants {
+            let func = match is_wasm_udf {
+                true => {
+                    WasmUDFFunction::try_create(op, &constants[0], &constants[1], &constants[2])?
+                }
+                false => {
+                    UDFServerFunction::try_create(op, &constants[0], &constants[1], &constants[2])?
+                }
+            };
+            return Ok(FunctionAdapter::create(func, false));
+        }
+    }
+
+    FunctionFactory::instance().get(op, arg_types)
+}
+
+pub(crate) fn is_lambda(expr: &Expression) -> bool {
+    matches!(expr, Expression::Lambda { .. })
+}
+
 pub struct ExpressionDataTypeVisitor {
     stack: Vec<DataTypePtr>,
     input_schema: DataSchemaRef,
@@ -396,7 +541,12 @@ impl ExpressionDataTypeVisitor {
         }
     }
 
-    fn visit_function(mut self, op: &str, args_size: usize) -> Result<ExpressionDataTypeVisitor> {
+    fn visit_function(
+        mut self,
+        op: &str,
+        args: &[Expression],
+        args_size: usize,
+    ) -> Result<ExpressionDataTypeVisitor> {
         let mut arguments = Vec::with_capacity(args_size);
         for index in 0..args_size {
             arguments.push(match self.stack.pop() {
@@ -410,7 +560,7 @@ impl ExpressionDataTypeVisitor {
 
         let arguments: Vec<&DataTypePtr> = arguments.iter().collect();
 
-        let function = FunctionFactory::instance().get(op, &arguments)?;
+        let function = get_scalar_function(op, args, &arguments)?;
         let return_type = function.return_type(&arguments)?;
         self.stack.push(return_type);
         Ok(self)
@@ -449,9 +599,12 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
                 self.stack.push(data_type);
                 Ok(self)
             }
-            Expression::BinaryExpression { op, .. } => self.visit_function(op, 2),
-            Expression::UnaryExpression { op, .. } => self.visit_function(op, 1),
-            Expression::ScalarFunction { op, args } => self.visit_function(op, args.len()),
+            Expression::BinaryExpression { op, .. } => self.visit_function(op, &[], 2),
+            Expression::UnaryExpression { op, .. } => self.visit_function(op, &[], 1),
+            Expression::ScalarFunction { op, args } => {
+                let args_size = args.iter().filter(|arg| !is_lambda(arg)).count();
+                self.visit_function(op, args, args_size)
+            }
             expr @ Expression::AggregateFunction { args, .. } => {
                 // Pop arguments.
                 for index in 0..args.len() {
@@ -470,6 +623,28 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
                 self.stack.push(return_type);
                 Ok(self)
             }
+            expr @ Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                // Pop the arguments and the expressions of the window, the sort expressions
+                // push nothing but their nested expressions.
+                let children = args.len() + partition_by.len() + order_by.len();
+                for index in 0..children {
+                    if self.stack.pop().is_none() {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "Expected {} arguments, actual {}.",
+                            children, index
+                        )));
+                    }
+                }
+
+                let return_type = expr.to_window_data_type(&self.input_schema)?;
+                self.stack.push(return_type);
+                Ok(self)
+            }
             Expression::Cast { data_type, .. } => {
                 let inner_type = match self.stack.pop() {
                     None => Err(ErrorCode::LogicalError(
@@ -481,7 +656,10 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
                 self.stack.push(inner_type.clone());
                 Ok(self)
             }
-            Expression::Alias(_, _) | Expression::Sort { .. } => Ok(self),
+            // The lambda is resolved with the function taking it.
+            Expression::Alias(_, _) | Expression::Sort { .. } | Expression::Lambda { .. } => {
+                Ok(self)
+            }
         }
     }
 }
@@ -0,0 +1,149 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::Function;
+
+use crate::plan_expression_common::find_column_exprs;
+use crate::Expression;
+use crate::ExpressionAction;
+use crate::ExpressionChain;
+
+/// The lambda argument of higher-order functions, e.g. `x -> x + 1` in
+/// `array_map(x -> x + 1, arr)`. It's evaluated as a function whose arguments are the parameters,
+/// the body is compiled into an expression chain on the types of the arguments.
+#[derive(Clone)]
+pub struct LambdaFunction {
+    display_name: String,
+    params: Vec<String>,
+    body: Expression,
+}
+
+impl LambdaFunction {
+    pub fn try_create(params: &[String], body: &Expression) -> Result<Box<dyn Function>> {
+        let display_name = Expression::Lambda {
+            params: params.to_vec(),
+            body: Box::new(body.clone()),
+        }
+        .column_name();
+
+        for column in find_column_exprs(&[body.clone()]) {
+            let name = column.column_name();
+            if !params.contains(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The lambda {} can only reference its parameters, but got column {}",
+                    display_name, name
+                )));
+            }
+        }
+
+        Ok(Box::new(LambdaFunction {
+            display_name,
+            params: params.to_vec(),
+            body: body.clone(),
+        }))
+    }
+
+    fn schema(&self, args: &[&DataTypePtr]) -> Result<DataSchemaRef> {
+        if args.len() != self.params.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "The lambda {} expects {} arguments, but got {}",
+                self.display_name,
+                self.params.len(),
+                args.len()
+            )));
+        }
+
+        let fields = self
+            .params
+            .iter()
+            .zip(args.iter())
+            .map(|(param, data_type)| DataField::new(param, (*data_type).clone()))
+            .collect();
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl Function for LambdaFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        self.body.to_data_type(&self.schema(args)?)
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let args = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        let chain = ExpressionChain::try_create(self.schema(&args)?, &[self.body.clone()])?;
+
+        let mut column_map: HashMap<&str, ColumnWithField> = HashMap::new();
+        for (param, column) in self.params.iter().zip(columns.iter()) {
+            column_map.insert(param, column.clone());
+        }
+
+        for action in chain.actions.iter() {
+            if column_map.contains_key(action.column_name()) {
+                continue;
+            }
+
+            match action {
+                ExpressionAction::Constant(constant) => {
+                    let column = constant
+                        .data_type
+                        .create_constant_column(&constant.value, input_rows)?;
+                    let field = DataField::new(&constant.name, constant.data_type.clone());
+                    column_map.insert(&constant.name, ColumnWithField::new(column, field));
+                }
+                ExpressionAction::Function(f) => {
+                    let arg_columns = f
+                        .arg_names
+                        .iter()
+                        .map(|name| {
+                            column_map.get(name.as_str()).cloned().ok_or_else(|| {
+                                ErrorCode::LogicalError(
+                                    "Arguments must be prepared before function transform",
+                                )
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    let column = f.func.eval(&arg_columns, input_rows)?;
+                    let field = DataField::new(&f.name, f.return_type.clone());
+                    column_map.insert(&f.name, ColumnWithField::new(column, field));
+                }
+                ExpressionAction::Input(_) | ExpressionAction::Alias(_) => {}
+            }
+        }
+
+        match column_map.get(self.body.column_name().as_str()) {
+            Some(column) => Ok(column.column().clone()),
+            None => Err(ErrorCode::LogicalError(format!(
+                "The result of lambda {} is not evaluated, there are bugs!",
+                self.display_name
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for LambdaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -22,6 +22,7 @@ use crate::Expression;
 use crate::ExpressionVisitor;
 use crate::PlanNode;
 use crate::Recursion;
+use crate::WindowFrame;
 
 /// Trait for potentially recursively rewriting an [`Expr`] expression
 /// tree. When passed to `Expr::rewrite`, `ExprVisitor::mutate` is
@@ -124,6 +125,29 @@ pub trait ExpressionRewriter: Sized {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn mutate_window_function(
+        &mut self,
+        name: &str,
+        distinct: bool,
+        params: &[DataValue],
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        order_by: Vec<Expression>,
+        window_frame: &Option<WindowFrame>,
+        _origin_expr: &Expression,
+    ) -> Result<Expression> {
+        Ok(Expression::WindowFunction {
+            op: name.to_string(),
+            distinct,
+            params: params.to_owned(),
+            args,
+            partition_by,
+            order_by,
+            window_frame: *window_frame,
+        })
+    }
+
     fn mutate_cast(
         &mut self,
         typ: &DataTypePtr,
@@ -288,6 +312,47 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                 self.stack.push(new_expr);
                 Ok(self)
             }
+            Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let mut pop_exprs = |len: usize| {
+                    let mut exprs = Vec::with_capacity(len);
+                    for index in 0..len {
+                        match self.stack.pop() {
+                            None => {
+                                return Err(ErrorCode::LogicalError(format!(
+                                    "Expected {} arguments, actual {}.",
+                                    len, index
+                                )));
+                            }
+                            Some(expr) => exprs.push(expr),
+                        };
+                    }
+                    Ok(exprs)
+                };
+
+                let args_expr = pop_exprs(args.len())?;
+                let partition_by_expr = pop_exprs(partition_by.len())?;
+                let order_by_expr = pop_exprs(order_by.len())?;
+                let new_expr = self.inner.mutate_window_function(
+                    op,
+                    *distinct,
+                    params,
+                    args_expr,
+                    partition_by_expr,
+                    order_by_expr,
+                    window_frame,
+                    expr,
+                )?;
+                self.stack.push(new_expr);
+                Ok(self)
+            }
             Expression::Cast {
                 data_type,
                 is_nullable,
@@ -368,6 +433,11 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                 self.stack.push(new_expr);
                 Ok(self)
             }
+            // The body of lambda is not visited, it only references the parameters.
+            Expression::Lambda { .. } => {
+                self.stack.push(expr.clone());
+                Ok(self)
+            }
         }
     }
 }
@@ -27,6 +27,7 @@ use common_meta_types::UserStageInfo;
 pub struct ListPlan {
     pub stage: UserStageInfo,
     pub path: String,
+    pub files: Vec<String>,
     pub pattern: String,
 }
 
@@ -42,6 +43,9 @@ impl Debug for ListPlan {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "List {:?}", self.stage)?;
+        if !self.files.is_empty() {
+            write!(f, " ,files:{:?}", self.files)?;
+        }
         if !self.pattern.is_empty() {
             write!(f, " ,pattern:{:?}", self.pattern)?;
         }
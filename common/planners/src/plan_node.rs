@@ -18,14 +18,20 @@ use common_datavalues::DataSchemaRef;
 
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTableMaskingPolicyPlan;
+use crate::AlterTableRowAccessPolicyPlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::BroadcastPlan;
 use crate::CallPlan;
+use crate::CopyIntoLocationPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateMaskingPolicyPlan;
+use crate::CreateNetworkPolicyPlan;
 use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
@@ -34,7 +40,10 @@ use crate::CreateViewPlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
+use crate::DropMaskingPolicyPlan;
+use crate::DropNetworkPolicyPlan;
 use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -60,6 +69,7 @@ use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -69,7 +79,10 @@ use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::WindowFuncPlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -88,6 +101,7 @@ pub enum PlanNode {
     Sort(SortPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
+    WindowFunc(WindowFuncPlan),
     ReadSource(ReadDataSourcePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Sink(SinkPlan),
@@ -103,6 +117,7 @@ pub enum PlanNode {
 
     // Copy.
     Copy(CopyPlan),
+    CopyIntoLocation(CopyIntoLocationPlan),
 
     // Call.
     Call(CallPlan),
@@ -121,6 +136,8 @@ pub enum PlanNode {
     // Table.
     CreateTable(CreateTablePlan),
     DropTable(DropTablePlan),
+    UndropTable(UndropTablePlan),
+    VacuumDropTable(VacuumDropTablePlan),
     RenameTable(RenameTablePlan),
     TruncateTable(TruncateTablePlan),
     OptimizeTable(OptimizeTablePlan),
@@ -148,6 +165,7 @@ pub enum PlanNode {
     // Role.
     CreateRole(CreateRolePlan),
     DropRole(DropRolePlan),
+    SetRole(SetRolePlan),
 
     // Stage.
     CreateUserStage(CreateUserStagePlan),
@@ -158,6 +176,14 @@ pub enum PlanNode {
     CreateUserUDF(CreateUserUDFPlan),
     DropUserUDF(DropUserUDFPlan),
     AlterUserUDF(AlterUserUDFPlan),
+    CreateNetworkPolicy(CreateNetworkPolicyPlan),
+    DropNetworkPolicy(DropNetworkPolicyPlan),
+    CreateMaskingPolicy(CreateMaskingPolicyPlan),
+    DropMaskingPolicy(DropMaskingPolicyPlan),
+    AlterTableMaskingPolicy(AlterTableMaskingPolicyPlan),
+    CreateRowAccessPolicy(CreateRowAccessPolicyPlan),
+    DropRowAccessPolicy(DropRowAccessPolicyPlan),
+    AlterTableRowAccessPolicy(AlterTableRowAccessPolicyPlan),
 
     // Use.
     UseDatabase(UseDatabasePlan),
@@ -186,6 +212,7 @@ impl PlanNode {
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
+            PlanNode::WindowFunc(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
@@ -202,6 +229,7 @@ impl PlanNode {
 
             // Copy.
             PlanNode::Copy(v) => v.schema(),
+            PlanNode::CopyIntoLocation(v) => v.schema(),
 
             // Call.
             PlanNode::Call(v) => v.schema(),
@@ -217,6 +245,8 @@ impl PlanNode {
             // Table.
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
+            PlanNode::UndropTable(v) => v.schema(),
+            PlanNode::VacuumDropTable(v) => v.schema(),
             PlanNode::RenameTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
             PlanNode::OptimizeTable(v) => v.schema(),
@@ -244,6 +274,7 @@ impl PlanNode {
             // Role.
             PlanNode::CreateRole(v) => v.schema(),
             PlanNode::DropRole(v) => v.schema(),
+            PlanNode::SetRole(v) => v.schema(),
 
             // Stage.
             PlanNode::CreateUserStage(v) => v.schema(),
@@ -257,6 +288,14 @@ impl PlanNode {
             PlanNode::CreateUserUDF(v) => v.schema(),
             PlanNode::DropUserUDF(v) => v.schema(),
             PlanNode::AlterUserUDF(v) => v.schema(),
+            PlanNode::CreateNetworkPolicy(v) => v.schema(),
+            PlanNode::DropNetworkPolicy(v) => v.schema(),
+            PlanNode::CreateMaskingPolicy(v) => v.schema(),
+            PlanNode::DropMaskingPolicy(v) => v.schema(),
+            PlanNode::AlterTableMaskingPolicy(v) => v.schema(),
+            PlanNode::CreateRowAccessPolicy(v) => v.schema(),
+            PlanNode::DropRowAccessPolicy(v) => v.schema(),
+            PlanNode::AlterTableRowAccessPolicy(v) => v.schema(),
 
             // Use.
             PlanNode::UseDatabase(v) => v.schema(),
@@ -284,6 +323,7 @@ impl PlanNode {
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
+            PlanNode::WindowFunc(_) => "WindowFuncPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
@@ -300,6 +340,7 @@ impl PlanNode {
 
             // Copy.
             PlanNode::Copy(_) => "CopyPlan",
+            PlanNode::CopyIntoLocation(_) => "CopyIntoLocationPlan",
 
             // Call.
             PlanNode::Call(_) => "CallPlan",
@@ -315,6 +356,8 @@ impl PlanNode {
             // Table.
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::UndropTable(_) => "UndropTablePlan",
+            PlanNode::VacuumDropTable(_) => "VacuumDropTablePlan",
             PlanNode::RenameTable(_) => "RenameTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
             PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
@@ -342,6 +385,7 @@ impl PlanNode {
             // Role.
             PlanNode::CreateRole(_) => "CreateRole",
             PlanNode::DropRole(_) => "DropRole",
+            PlanNode::SetRole(_) => "SetRole",
 
             // Stage.
             PlanNode::CreateUserStage(_) => "CreateUserStagePlan",
@@ -355,6 +399,14 @@ impl PlanNode {
             PlanNode::CreateUserUDF(_) => "CreateUserUDFPlan",
             PlanNode::DropUserUDF(_) => "DropUserUDFPlan",
             PlanNode::AlterUserUDF(_) => "AlterUserUDFPlan",
+            PlanNode::CreateNetworkPolicy(_) => "CreateNetworkPolicyPlan",
+            PlanNode::DropNetworkPolicy(_) => "DropNetworkPolicyPlan",
+            PlanNode::CreateMaskingPolicy(_) => "CreateMaskingPolicyPlan",
+            PlanNode::DropMaskingPolicy(_) => "DropMaskingPolicyPlan",
+            PlanNode::AlterTableMaskingPolicy(_) => "AlterTableMaskingPolicyPlan",
+            PlanNode::CreateRowAccessPolicy(_) => "CreateRowAccessPolicyPlan",
+            PlanNode::DropRowAccessPolicy(_) => "DropRowAccessPolicyPlan",
+            PlanNode::AlterTableRowAccessPolicy(_) => "AlterTableRowAccessPolicyPlan",
 
             // Use.
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
@@ -381,6 +433,7 @@ impl PlanNode {
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
+            PlanNode::WindowFunc(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
             PlanNode::Sink(v) => vec![v.input.clone()],
 
@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 
 use crate::col;
+use crate::collated_group_values;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
@@ -37,6 +38,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::WindowFuncPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -120,6 +122,16 @@ impl PlanBuilder {
         aggr_expr: &[Expression],
         group_expr: &[Expression],
     ) -> Result<Self> {
+        // The values of the collated group by columns are aggregated along, see
+        // `collated_group_values`.
+        let mut aggr_expr = aggr_expr.to_vec();
+        for (_, value) in collated_group_values(group_expr, &schema_before_groupby)? {
+            if !aggr_expr.contains(&value) {
+                aggr_expr.push(value);
+            }
+        }
+        let aggr_expr = aggr_expr.as_slice();
+
         Ok(match mode {
             AggregateMode::Partial => {
                 let fields = RewriteHelper::exprs_to_fields(aggr_expr, &schema_before_groupby)?;
@@ -222,6 +234,20 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a window function, its result is appended to the input.
+    pub fn window_func(&self, expr: Expression) -> Result<Self> {
+        validate_expression(&expr)?;
+        let input_schema = self.plan.schema();
+        let mut fields = input_schema.fields().clone();
+        fields.push(expr.to_data_field(&input_schema)?);
+
+        Ok(Self::from(&PlanNode::WindowFunc(WindowFuncPlan {
+            window_func: expr,
+            input: Arc::new(self.plan.clone()),
+            schema: DataSchemaRefExt::create(fields),
+        })))
+    }
+
     /// Apply a limit
     pub fn limit(&self, n: usize) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
@@ -19,6 +19,7 @@ use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
 use crate::BroadcastPlan;
 use crate::CallPlan;
+use crate::CopyIntoLocationPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -36,6 +37,9 @@ use crate::RenameTablePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::UndropTablePlan;
+use crate::VacuumDropTablePlan;
+use crate::WindowFuncPlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
@@ -70,16 +74,20 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
+            PlanNode::WindowFunc(plan) => Self::format_window_func(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
             PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+            PlanNode::UndropTable(plan) => Self::format_undrop_table(f, plan),
+            PlanNode::VacuumDropTable(plan) => Self::format_vacuum_drop_table(f, plan),
             PlanNode::RenameTable(plan) => Self::format_rename_table(f, plan),
             PlanNode::CreateRole(plan) => Self::format_create_role(f, plan),
             PlanNode::DropRole(plan) => Self::format_drop_role(f, plan),
             PlanNode::Copy(plan) => Self::format_copy(f, plan),
+            PlanNode::CopyIntoLocation(plan) => Self::format_copy_into_location(f, plan),
             PlanNode::Call(plan) => Self::format_call(f, plan),
             _ => {
                 let mut printed = true;
@@ -193,6 +201,16 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         fmt::Result::Ok(())
     }
 
+    fn format_window_func(f: &mut Formatter, plan: &WindowFuncPlan) -> fmt::Result {
+        let expr = &plan.window_func;
+        write!(
+            f,
+            "WindowFunc: {:?}:{:?}",
+            expr,
+            expr.to_data_type(&plan.input.schema()).unwrap()
+        )
+    }
+
     fn format_limit(f: &mut Formatter, plan: &LimitPlan) -> fmt::Result {
         match (plan.n, plan.offset) {
             (Some(n), 0) => write!(f, "Limit: {}", n),
@@ -305,6 +323,15 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, " if_exists:{:}", plan.if_exists)
     }
 
+    fn format_undrop_table(f: &mut Formatter, plan: &UndropTablePlan) -> fmt::Result {
+        write!(f, "Undrop table {:}.{:}", plan.db, plan.table)
+    }
+
+    fn format_vacuum_drop_table(f: &mut Formatter, plan: &VacuumDropTablePlan) -> fmt::Result {
+        write!(f, "Vacuum drop table {:}", plan.db)?;
+        write!(f, " retain_secs:{:}", plan.retain_secs)
+    }
+
     fn format_rename_table(f: &mut Formatter, plan: &RenameTablePlan) -> fmt::Result {
         write!(f, "Rename table,")?;
         write!(f, " [")?;
@@ -326,6 +353,10 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "{:?}", plan)
     }
 
+    fn format_copy_into_location(f: &mut Formatter, plan: &CopyIntoLocationPlan) -> fmt::Result {
+        write!(f, "{:?}", plan)
+    }
+
     fn format_call(f: &mut Formatter, plan: &CallPlan) -> fmt::Result {
         write!(f, "Call {:}", plan.name)?;
         write!(f, " args: {:?}", plan.args)
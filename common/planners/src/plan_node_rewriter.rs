@@ -25,13 +25,19 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTableMaskingPolicyPlan;
+use crate::AlterTableRowAccessPolicyPlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::CallPlan;
+use crate::CopyIntoLocationPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateMaskingPolicyPlan;
+use crate::CreateNetworkPolicyPlan;
 use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
@@ -40,7 +46,10 @@ use crate::CreateViewPlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
+use crate::DropMaskingPolicyPlan;
+use crate::DropNetworkPolicyPlan;
 use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -71,6 +80,7 @@ use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -79,7 +89,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::WindowFuncPlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -117,6 +130,7 @@ pub trait PlanRewriter: Sized {
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
+            PlanNode::WindowFunc(plan) => self.rewrite_window_func(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
@@ -132,6 +146,7 @@ pub trait PlanRewriter: Sized {
 
             // Copy.
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
+            PlanNode::CopyIntoLocation(plan) => self.rewrite_copy_into_location(plan),
 
             // Call.
             PlanNode::Call(plan) => self.rewrite_call(plan),
@@ -147,6 +162,8 @@ pub trait PlanRewriter: Sized {
             // Table.
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
+            PlanNode::UndropTable(plan) => self.rewrite_undrop_table(plan),
+            PlanNode::VacuumDropTable(plan) => self.rewrite_vacuum_drop_table(plan),
             PlanNode::RenameTable(plan) => self.rewrite_rename_table(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
@@ -174,6 +191,7 @@ pub trait PlanRewriter: Sized {
             // Role.
             PlanNode::CreateRole(plan) => self.rewrite_create_role(plan),
             PlanNode::DropRole(plan) => self.rewrite_drop_role(plan),
+            PlanNode::SetRole(plan) => self.rewrite_set_role(plan),
 
             // Stage.
             PlanNode::CreateUserStage(plan) => self.rewrite_create_user_stage(plan),
@@ -185,6 +203,18 @@ pub trait PlanRewriter: Sized {
             PlanNode::CreateUserUDF(plan) => self.rewrite_create_user_udf(plan),
             PlanNode::DropUserUDF(plan) => self.rewrite_drop_user_udf(plan),
             PlanNode::AlterUserUDF(plan) => self.rewrite_alter_user_udf(plan),
+            PlanNode::CreateNetworkPolicy(plan) => self.rewrite_create_network_policy(plan),
+            PlanNode::DropNetworkPolicy(plan) => self.rewrite_drop_network_policy(plan),
+            PlanNode::CreateMaskingPolicy(plan) => self.rewrite_create_masking_policy(plan),
+            PlanNode::DropMaskingPolicy(plan) => self.rewrite_drop_masking_policy(plan),
+            PlanNode::AlterTableMaskingPolicy(plan) => {
+                self.rewrite_alter_table_masking_policy(plan)
+            }
+            PlanNode::CreateRowAccessPolicy(plan) => self.rewrite_create_row_access_policy(plan),
+            PlanNode::DropRowAccessPolicy(plan) => self.rewrite_drop_row_access_policy(plan),
+            PlanNode::AlterTableRowAccessPolicy(plan) => {
+                self.rewrite_alter_table_row_access_policy(plan)
+            }
 
             // Use.
             PlanNode::UseDatabase(plan) => self.rewrite_use_database(plan),
@@ -331,6 +361,14 @@ pub trait PlanRewriter: Sized {
             .build()
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_window_func = self.rewrite_expr(&new_input.schema(), &plan.window_func)?;
+        PlanBuilder::from(&new_input)
+            .window_func(new_window_func)?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         Ok(PlanNode::ReadSource(plan.clone()))
     }
@@ -400,6 +438,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DropTable(plan.clone()))
     }
 
+    fn rewrite_undrop_table(&mut self, plan: &UndropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::UndropTable(plan.clone()))
+    }
+
+    fn rewrite_vacuum_drop_table(&mut self, plan: &VacuumDropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::VacuumDropTable(plan.clone()))
+    }
+
     fn rewrite_drop_database(&mut self, plan: &DropDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropDatabase(plan.clone()))
     }
@@ -412,6 +458,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Copy(plan.clone()))
     }
 
+    fn rewrite_copy_into_location(&mut self, plan: &CopyIntoLocationPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyIntoLocation(plan.clone()))
+    }
+
     fn rewrite_call(&mut self, plan: &CallPlan) -> Result<PlanNode> {
         Ok(PlanNode::Call(plan.clone()))
     }
@@ -448,6 +498,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DropRole(plan.clone()))
     }
 
+    fn rewrite_set_role(&mut self, plan: &SetRolePlan) -> Result<PlanNode> {
+        Ok(PlanNode::SetRole(plan.clone()))
+    }
+
     fn grant_privilege(&mut self, plan: &GrantPrivilegePlan) -> Result<PlanNode> {
         Ok(PlanNode::GrantPrivilege(plan.clone()))
     }
@@ -495,6 +549,56 @@ pub trait PlanRewriter: Sized {
     fn rewrite_alter_user_udf(&mut self, plan: &AlterUserUDFPlan) -> Result<PlanNode> {
         Ok(PlanNode::AlterUserUDF(plan.clone()))
     }
+
+    fn rewrite_create_network_policy(
+        &mut self,
+        plan: &CreateNetworkPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateNetworkPolicy(plan.clone()))
+    }
+
+    fn rewrite_drop_network_policy(&mut self, plan: &DropNetworkPolicyPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropNetworkPolicy(plan.clone()))
+    }
+
+    fn rewrite_create_masking_policy(
+        &mut self,
+        plan: &CreateMaskingPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateMaskingPolicy(plan.clone()))
+    }
+
+    fn rewrite_drop_masking_policy(&mut self, plan: &DropMaskingPolicyPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropMaskingPolicy(plan.clone()))
+    }
+
+    fn rewrite_alter_table_masking_policy(
+        &mut self,
+        plan: &AlterTableMaskingPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTableMaskingPolicy(plan.clone()))
+    }
+
+    fn rewrite_create_row_access_policy(
+        &mut self,
+        plan: &CreateRowAccessPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateRowAccessPolicy(plan.clone()))
+    }
+
+    fn rewrite_drop_row_access_policy(
+        &mut self,
+        plan: &DropRowAccessPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::DropRowAccessPolicy(plan.clone()))
+    }
+
+    fn rewrite_alter_table_row_access_policy(
+        &mut self,
+        plan: &AlterTableRowAccessPolicyPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTableRowAccessPolicy(plan.clone()))
+    }
 }
 
 pub struct RewriteHelper {}
@@ -639,6 +743,33 @@ impl RewriteHelper {
                 }
             }
 
+            Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+            } => {
+                let mut rewrite_exprs = |exprs: &[Expression]| {
+                    exprs
+                        .iter()
+                        .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
+                        .collect::<Result<Vec<Expression>>>()
+                };
+
+                Ok(Expression::WindowFunction {
+                    op: op.clone(),
+                    distinct: *distinct,
+                    params: params.clone(),
+                    args: rewrite_exprs(args)?,
+                    partition_by: rewrite_exprs(partition_by)?,
+                    order_by: order_by.clone(),
+                    window_frame: *window_frame,
+                })
+            }
+
             Expression::Alias(alias, plan) => {
                 if data.inside_aliases.contains(alias) {
                     return Result::Err(ErrorCode::SyntaxException(format!(
@@ -671,6 +802,7 @@ impl RewriteHelper {
             Expression::Wildcard
             | Expression::QualifiedColumn(_)
             | Expression::Literal { .. }
+            | Expression::Lambda { .. }
             | Expression::Subquery { .. }
             | Expression::ScalarSubquery { .. }
             | Expression::Sort { .. } => Ok(expr.clone()),
@@ -735,9 +867,21 @@ impl RewriteHelper {
             }
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => args
+                .iter()
+                .chain(partition_by)
+                .chain(order_by)
+                .cloned()
+                .collect(),
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
+            Expression::Lambda { .. } => vec![],
         })
     }
 
@@ -773,9 +917,23 @@ impl RewriteHelper {
                 }
                 v
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = vec![];
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    let mut col = Self::expression_plan_columns(arg)?;
+                    v.append(&mut col);
+                }
+                v
+            }
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
+            Expression::Lambda { .. } => vec![],
         })
     }
 
@@ -845,6 +1003,27 @@ impl RewriteHelper {
                 params: params.clone(),
                 args: expressions.to_vec(),
             },
+            Expression::WindowFunction {
+                op,
+                distinct,
+                params,
+                args,
+                partition_by,
+                window_frame,
+                ..
+            } => {
+                let (args, others) = expressions.split_at(args.len());
+                let (partition_by, order_by) = others.split_at(partition_by.len());
+                Expression::WindowFunction {
+                    op: op.clone(),
+                    distinct: *distinct,
+                    params: params.clone(),
+                    args: args.to_vec(),
+                    partition_by: partition_by.to_vec(),
+                    order_by: order_by.to_vec(),
+                    window_frame: *window_frame,
+                }
+            }
             other => other.clone(),
         }
     }
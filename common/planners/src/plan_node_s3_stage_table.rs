@@ -22,6 +22,8 @@ use common_meta_types::UserStageInfo;
 pub struct S3StageTableInfo {
     pub schema: DataSchemaRef,
     pub file_name: Option<String>,
+    // The files read one by one if no file_name is given, e.g. `SELECT * FROM @stage`.
+    pub files: Vec<String>,
     pub stage_info: UserStageInfo,
     pub path: String,
 }
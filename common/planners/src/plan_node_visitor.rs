@@ -18,13 +18,19 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTableMaskingPolicyPlan;
+use crate::AlterTableRowAccessPolicyPlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::CallPlan;
+use crate::CopyIntoLocationPlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateMaskingPolicyPlan;
+use crate::CreateNetworkPolicyPlan;
 use crate::CreateRolePlan;
+use crate::CreateRowAccessPolicyPlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
@@ -33,7 +39,10 @@ use crate::CreateViewPlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
+use crate::DropMaskingPolicyPlan;
+use crate::DropNetworkPolicyPlan;
 use crate::DropRolePlan;
+use crate::DropRowAccessPolicyPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -61,6 +70,7 @@ use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
 use crate::SelectPlan;
+use crate::SetRolePlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -69,7 +79,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
 use crate::UseDatabasePlan;
+use crate::VacuumDropTablePlan;
+use crate::WindowFuncPlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -129,6 +142,7 @@ pub trait PlanVisitor {
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
+            PlanNode::WindowFunc(plan) => self.visit_window_func(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.visit_append(plan),
@@ -144,6 +158,7 @@ pub trait PlanVisitor {
 
             // Copy.
             PlanNode::Copy(plan) => self.visit_copy(plan),
+            PlanNode::CopyIntoLocation(plan) => self.visit_copy_into_location(plan),
 
             // Call.
             PlanNode::Call(plan) => self.visit_call(plan),
@@ -159,6 +174,8 @@ pub trait PlanVisitor {
             // Table.
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
+            PlanNode::UndropTable(plan) => self.visit_undrop_table(plan),
+            PlanNode::VacuumDropTable(plan) => self.visit_vacuum_drop_table(plan),
             PlanNode::RenameTable(plan) => self.visit_rename_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
@@ -186,6 +203,7 @@ pub trait PlanVisitor {
             // Role.
             PlanNode::CreateRole(plan) => self.visit_create_role(plan),
             PlanNode::DropRole(plan) => self.visit_drop_role(plan),
+            PlanNode::SetRole(plan) => self.visit_set_role(plan),
 
             // Stage.
             PlanNode::CreateUserStage(plan) => self.visit_create_user_stage(plan),
@@ -197,6 +215,16 @@ pub trait PlanVisitor {
             PlanNode::CreateUserUDF(plan) => self.visit_create_user_udf(plan),
             PlanNode::DropUserUDF(plan) => self.visit_drop_user_udf(plan),
             PlanNode::AlterUserUDF(plan) => self.visit_alter_user_udf(plan),
+            PlanNode::CreateNetworkPolicy(plan) => self.visit_create_network_policy(plan),
+            PlanNode::DropNetworkPolicy(plan) => self.visit_drop_network_policy(plan),
+            PlanNode::CreateMaskingPolicy(plan) => self.visit_create_masking_policy(plan),
+            PlanNode::DropMaskingPolicy(plan) => self.visit_drop_masking_policy(plan),
+            PlanNode::AlterTableMaskingPolicy(plan) => self.visit_alter_table_masking_policy(plan),
+            PlanNode::CreateRowAccessPolicy(plan) => self.visit_create_row_access_policy(plan),
+            PlanNode::DropRowAccessPolicy(plan) => self.visit_drop_row_access_policy(plan),
+            PlanNode::AlterTableRowAccessPolicy(plan) => {
+                self.visit_alter_table_row_access_policy(plan)
+            }
 
             // Use.
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
@@ -301,6 +329,11 @@ pub trait PlanVisitor {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_window_func(&mut self, plan: &WindowFuncPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_expr(&plan.window_func)
+    }
+
     fn visit_read_data_source(&mut self, _: &ReadDataSourcePlan) -> Result<()> {
         Ok(())
     }
@@ -361,6 +394,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_set_role(&mut self, _: &SetRolePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_describe_table(&mut self, _: &DescribeTablePlan) -> Result<()> {
         Ok(())
     }
@@ -385,6 +422,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_undrop_table(&mut self, _: &UndropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_vacuum_drop_table(&mut self, _: &VacuumDropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
@@ -401,6 +446,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_copy_into_location(&mut self, _: &CopyIntoLocationPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_call(&mut self, _: &CallPlan) -> Result<()> {
         Ok(())
     }
@@ -459,4 +508,39 @@ pub trait PlanVisitor {
     fn visit_alter_user_udf(&mut self, _: &AlterUserUDFPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_create_network_policy(&mut self, _: &CreateNetworkPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_network_policy(&mut self, _: &DropNetworkPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_masking_policy(&mut self, _: &CreateMaskingPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_masking_policy(&mut self, _: &DropMaskingPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table_masking_policy(&mut self, _: &AlterTableMaskingPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_row_access_policy(&mut self, _: &CreateRowAccessPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_row_access_policy(&mut self, _: &DropRowAccessPolicyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table_row_access_policy(
+        &mut self,
+        _: &AlterTableRowAccessPolicyPlan,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
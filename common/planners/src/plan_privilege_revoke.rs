@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_datavalues::DataSchema;
@@ -25,6 +26,8 @@ pub struct RevokePrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
     pub on: GrantObject,
+    /// The privileges on the columns of the table, by the column name.
+    pub column_priv_types: BTreeMap<String, UserPrivilegeSet>,
 }
 
 impl RevokePrivilegePlan {
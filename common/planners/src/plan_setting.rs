@@ -21,6 +21,8 @@ use common_datavalues::DataSchemaRef;
 pub struct VarValue {
     pub variable: String,
     pub value: String,
+    // SET GLOBAL persists the value to the metasrv, it applies to the new sessions.
+    pub is_global: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -25,6 +25,8 @@ pub struct DropTablePlan {
     pub db: String,
     /// The table name
     pub table: String,
+    /// Remove the data of the table too, then it can't be undropped, see `DROP TABLE ... ALL`.
+    pub all: bool,
 }
 
 impl DropTablePlan {
@@ -40,6 +42,7 @@ impl From<DropTablePlan> for DropTableReq {
             tenant: p.tenant,
             db: p.db,
             table: p.table,
+            purge: p.all,
         }
     }
 }
@@ -123,3 +123,37 @@ fn test_expression_validate() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_expression_lambda() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "arr",
+        ArrayType::arc(Int64Type::arc()),
+    )]);
+
+    let array_map = |body: Expression| Expression::ScalarFunction {
+        op: "array_map".to_string(),
+        args: vec![
+            Expression::Lambda {
+                params: vec!["x".to_string()],
+                body: Box::new(body),
+            },
+            col("arr"),
+        ],
+    };
+
+    let expr = array_map(add(col("x"), lit(1i64)));
+    assert_eq!("array_map(x -> (x + 1), arr)", expr.column_name());
+    assert_eq!(
+        ArrayType::arc(Int64Type::arc()),
+        expr.to_data_type(&schema)?
+    );
+
+    let expr = array_map(add(col("x"), col("arr")));
+    let result = expr.to_data_type(&schema);
+    assert_eq!(
+        "The lambda x -> (x + arr) can only reference its parameters, but got column arr",
+        result.err().unwrap().message()
+    );
+    Ok(())
+}
@@ -532,6 +532,43 @@ fn test_dates_function() -> Result<()> {
                 right: None,
             },
         },
+        Test {
+            name: "f(z) = date_trunc('hour', z)",
+            expr: Expression::create_scalar_function("date_trunc", vec![
+                lit("hour".as_bytes()),
+                col("z"),
+            ]),
+            column: "z",
+            left: create_datetime(1638288000),
+            right: create_datetime(1638295199),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1638288000),
+                right: create_datetime(1638291600),
+            },
+        },
+        Test {
+            name: "f(z) = to_start_of_interval(z, INTERVAL 5 MINUTE)",
+            expr: Expression::create_scalar_function("to_start_of_interval", vec![
+                col("z"),
+                Expression::create_literal_with_type(
+                    DataValue::Int64(5),
+                    IntervalType::arc(IntervalKind::Minute),
+                ),
+            ]),
+            column: "z",
+            left: create_datetime(1638288000),
+            right: create_datetime(1638288599),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_datetime(1638288000),
+                right: create_datetime(1638288300),
+            },
+        },
     ];
 
     for t in test_suite.into_iter() {
@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::Compression;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+const BROTLI_QUALITY: u32 = 5;
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
+/// Compresses the data in memory, the reverse of `decompress`.
+pub fn compress(compression: &Compression, bytes: &[u8]) -> Result<Vec<u8>> {
+    let result = match compression {
+        Compression::None => return Ok(bytes.to_vec()),
+        Compression::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+            encoder.write_all(bytes).and_then(|_| encoder.finish())
+        }
+        Compression::Deflate => {
+            let mut encoder =
+                flate2::write::ZlibEncoder::new(vec![], flate2::Compression::default());
+            encoder.write_all(bytes).and_then(|_| encoder.finish())
+        }
+        Compression::RawDeflate => {
+            let mut encoder =
+                flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+            encoder.write_all(bytes).and_then(|_| encoder.finish())
+        }
+        Compression::Zstd => zstd::stream::encode_all(bytes, zstd::DEFAULT_COMPRESSION_LEVEL),
+        Compression::Brotli => {
+            let mut writer = brotli::CompressorWriter::new(
+                vec![],
+                BROTLI_BUFFER_SIZE,
+                BROTLI_QUALITY,
+                BROTLI_WINDOW_SIZE,
+            );
+            writer.write_all(bytes).map(|_| writer.into_inner())
+        }
+        other => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Compression {:?} is not supported yet",
+                other
+            )))
+        }
+    };
+
+    result.map_err(|e| ErrorCode::BadBytes(format!("Compress {:?} error: {}", compression, e)))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compression;
 mod sources;
 mod stream;
 mod stream_abort;
@@ -19,6 +20,7 @@ mod stream_cast;
 mod stream_correct_with_schema;
 mod stream_datablock;
 mod stream_limit_by;
+mod stream_memory_limit;
 mod stream_progress;
 mod stream_skip;
 mod stream_sort;
@@ -26,6 +28,7 @@ mod stream_source;
 mod stream_sub_queries;
 mod stream_take;
 
+pub use compression::compress;
 pub use sources::*;
 pub use stream::*;
 pub use stream_abort::AbortStream;
@@ -33,6 +36,7 @@ pub use stream_cast::CastStream;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
 pub use stream_limit_by::LimitByStream;
+pub use stream_memory_limit::MemoryLimitStream;
 pub use stream_progress::ProgressStream;
 pub use stream_skip::SkipStream;
 pub use stream_sort::SortStream;
@@ -0,0 +1,373 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+use super::schema::AvroField;
+use super::schema::AvroSchema;
+
+const MAGIC: &[u8] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+
+/// A value of a top level field, the nested values (record, array and map) are JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroValue {
+    Null,
+    Boolean(bool),
+    /// int and long.
+    Int(i64),
+    /// float and double.
+    Float(f64),
+    /// bytes, string, fixed and the enum symbols.
+    Bytes(Vec<u8>),
+    /// The days since UNIX epoch.
+    Date(i64),
+    /// The nanoseconds since UNIX epoch.
+    Timestamp(i64),
+    /// The unscaled value and the scale.
+    Decimal(i128, i64),
+    Json(Value),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Codec {
+    Null,
+    Deflate,
+    Snappy,
+    Zstandard,
+}
+
+/// A reader of the Avro object container files.
+pub struct AvroReader {
+    data: Vec<u8>,
+    fields: Vec<AvroField>,
+    codec: Codec,
+    sync: Vec<u8>,
+    // The position of the next block in the file.
+    pos: usize,
+    // The current block and the number of the records left in it.
+    block: Vec<u8>,
+    block_pos: usize,
+    block_records: usize,
+}
+
+fn unexpected_eof() -> ErrorCode {
+    ErrorCode::AvroError("Unexpected end of Avro data")
+}
+
+fn read_long(buf: &[u8], pos: &mut usize) -> Result<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *buf.get(*pos).ok_or_else(unexpected_eof)?;
+        *pos += 1;
+        if shift < 64 {
+            value |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(((value >> 1) as i64) ^ -((value & 1) as i64));
+        }
+        shift += 7;
+    }
+}
+
+fn read_fixed<'a>(buf: &'a [u8], pos: &mut usize, size: usize) -> Result<&'a [u8]> {
+    let end = pos.checked_add(size).ok_or_else(unexpected_eof)?;
+    let bytes = buf.get(*pos..end).ok_or_else(unexpected_eof)?;
+    *pos = end;
+    Ok(bytes)
+}
+
+fn read_bytes<'a>(buf: &'a [u8], pos: &mut usize) -> Result<&'a [u8]> {
+    let size = read_long(buf, pos)?;
+    if size < 0 {
+        return Err(ErrorCode::AvroError(format!(
+            "Invalid Avro bytes length {}",
+            size
+        )));
+    }
+    read_fixed(buf, pos, size as usize)
+}
+
+// The number of the items of the next array or map block, 0 at the end.
+fn read_block_count(buf: &[u8], pos: &mut usize) -> Result<usize> {
+    let count = read_long(buf, pos)?;
+    if count < 0 {
+        // A negative count is followed by the size of the block in bytes.
+        read_long(buf, pos)?;
+    }
+    Ok(count.unsigned_abs() as usize)
+}
+
+// The two's complement big endian unscaled value of the decimals.
+fn decimal_from_bytes(bytes: &[u8]) -> i128 {
+    match bytes.first() {
+        None => 0,
+        Some(first) => {
+            let init = if first & 0x80 != 0 { -1i128 } else { 0 };
+            bytes
+                .iter()
+                .fold(init, |value, byte| (value << 8) | *byte as i128)
+        }
+    }
+}
+
+impl AvroReader {
+    pub fn try_create(data: Vec<u8>) -> Result<Self> {
+        if !data.starts_with(MAGIC) {
+            return Err(ErrorCode::AvroError(
+                "Invalid Avro file, the magic 'Obj1' is missing",
+            ));
+        }
+
+        // The file metadata is a map of string to bytes.
+        let mut pos = MAGIC.len();
+        let mut schema = None;
+        let mut codec = Codec::Null;
+        loop {
+            let count = read_block_count(&data, &mut pos)?;
+            if count == 0 {
+                break;
+            }
+            for _ in 0..count {
+                let key = read_bytes(&data, &mut pos)?;
+                let value = read_bytes(&data, &mut pos)?;
+                match key {
+                    b"avro.schema" => {
+                        schema = Some(AvroSchema::parse(&String::from_utf8_lossy(value))?)
+                    }
+                    b"avro.codec" => {
+                        codec = match value {
+                            b"null" => Codec::Null,
+                            b"deflate" => Codec::Deflate,
+                            b"snappy" => Codec::Snappy,
+                            b"zstandard" => Codec::Zstandard,
+                            other => {
+                                return Err(ErrorCode::AvroError(format!(
+                                    "Avro codec '{}' is not supported",
+                                    String::from_utf8_lossy(other)
+                                )))
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let sync = read_fixed(&data, &mut pos, SYNC_SIZE)?.to_vec();
+
+        let fields = match schema {
+            Some(AvroSchema::Record(fields)) => fields,
+            Some(other) => {
+                return Err(ErrorCode::AvroError(format!(
+                    "The Avro file schema must be a record, but got {:?}",
+                    other
+                )))
+            }
+            None => return Err(ErrorCode::AvroError("The Avro file has no schema")),
+        };
+
+        Ok(AvroReader {
+            data,
+            fields,
+            codec,
+            sync,
+            pos,
+            block: vec![],
+            block_pos: 0,
+            block_records: 0,
+        })
+    }
+
+    pub fn fields(&self) -> &[AvroField] {
+        &self.fields
+    }
+
+    /// The values of the next record, in the order of the fields.
+    pub fn next_record(&mut self) -> Result<Option<Vec<AvroValue>>> {
+        while self.block_records == 0 {
+            if self.pos >= self.data.len() {
+                return Ok(None);
+            }
+            self.read_block()?;
+        }
+
+        let mut pos = self.block_pos;
+        let values = self
+            .fields
+            .iter()
+            .map(|field| decode_value(&self.block, &mut pos, &field.schema))
+            .collect::<Result<Vec<_>>>()?;
+        self.block_pos = pos;
+        self.block_records -= 1;
+        Ok(Some(values))
+    }
+
+    // A block is: [record count][byte size][records][sync marker].
+    fn read_block(&mut self) -> Result<()> {
+        let count = read_long(&self.data, &mut self.pos)?;
+        let size = read_long(&self.data, &mut self.pos)?;
+        if count < 0 || size < 0 {
+            return Err(ErrorCode::AvroError(format!(
+                "Invalid Avro block with {} records of {} bytes",
+                count, size
+            )));
+        }
+
+        let mut pos = self.pos;
+        let bytes = read_fixed(&self.data, &mut pos, size as usize)?;
+        let block = self.decompress(bytes)?;
+        if read_fixed(&self.data, &mut pos, SYNC_SIZE)? != self.sync.as_slice() {
+            return Err(ErrorCode::AvroError(
+                "Invalid Avro block, the sync marker does not match",
+            ));
+        }
+
+        self.pos = pos;
+        self.block = block;
+        self.block_pos = 0;
+        self.block_records = count as usize;
+        Ok(())
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        match self.codec {
+            Codec::Null => Ok(bytes.to_vec()),
+            Codec::Deflate => {
+                let mut block = vec![];
+                flate2::read::DeflateDecoder::new(bytes)
+                    .read_to_end(&mut block)
+                    .map_err(|e| ErrorCode::AvroError(format!("Avro deflate block: {}", e)))?;
+                Ok(block)
+            }
+            Codec::Snappy => {
+                // The compressed data is followed by the 4 bytes CRC32 of the uncompressed data.
+                let len = bytes.len().checked_sub(4).ok_or_else(unexpected_eof)?;
+                snap::raw::Decoder::new()
+                    .decompress_vec(&bytes[..len])
+                    .map_err(|e| ErrorCode::AvroError(format!("Avro snappy block: {}", e)))
+            }
+            Codec::Zstandard => zstd::stream::decode_all(bytes)
+                .map_err(|e| ErrorCode::AvroError(format!("Avro zstandard block: {}", e))),
+        }
+    }
+}
+
+fn decode_value(buf: &[u8], pos: &mut usize, schema: &AvroSchema) -> Result<AvroValue> {
+    let value = match schema {
+        AvroSchema::Null => AvroValue::Null,
+        AvroSchema::Boolean => AvroValue::Boolean(read_fixed(buf, pos, 1)?[0] != 0),
+        AvroSchema::Int | AvroSchema::Long => AvroValue::Int(read_long(buf, pos)?),
+        AvroSchema::Float => {
+            let bytes = read_fixed(buf, pos, 4)?;
+            AvroValue::Float(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64)
+        }
+        AvroSchema::Double => {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(read_fixed(buf, pos, 8)?);
+            AvroValue::Float(f64::from_le_bytes(value))
+        }
+        AvroSchema::Bytes | AvroSchema::String => AvroValue::Bytes(read_bytes(buf, pos)?.to_vec()),
+        AvroSchema::Fixed(size) => AvroValue::Bytes(read_fixed(buf, pos, *size)?.to_vec()),
+        AvroSchema::Enum(symbols) => {
+            let index = read_long(buf, pos)?;
+            let symbol = symbols.get(index as usize).ok_or_else(|| {
+                ErrorCode::AvroError(format!("Avro enum index {} is out of bounds", index))
+            })?;
+            AvroValue::Bytes(symbol.as_bytes().to_vec())
+        }
+        AvroSchema::Date => AvroValue::Date(read_long(buf, pos)?),
+        AvroSchema::TimestampMillis => AvroValue::Timestamp(read_long(buf, pos)? * 1_000_000),
+        AvroSchema::TimestampMicros => AvroValue::Timestamp(read_long(buf, pos)? * 1_000),
+        AvroSchema::Decimal { scale, fixed, .. } => {
+            let bytes = match fixed {
+                Some(size) => read_fixed(buf, pos, *size)?,
+                None => read_bytes(buf, pos)?,
+            };
+            AvroValue::Decimal(decimal_from_bytes(bytes), *scale as i64)
+        }
+        AvroSchema::Union(schemas) => {
+            let index = read_long(buf, pos)?;
+            let schema = schemas.get(index as usize).ok_or_else(|| {
+                ErrorCode::AvroError(format!("Avro union index {} is out of bounds", index))
+            })?;
+            decode_value(buf, pos, schema)?
+        }
+        AvroSchema::Record(_) | AvroSchema::Array(_) | AvroSchema::Map(_) => {
+            AvroValue::Json(decode_json(buf, pos, schema)?)
+        }
+    };
+    Ok(value)
+}
+
+fn decode_json(buf: &[u8], pos: &mut usize, schema: &AvroSchema) -> Result<Value> {
+    let value = match schema {
+        AvroSchema::Record(fields) => {
+            let mut object = Map::with_capacity(fields.len());
+            for field in fields {
+                object.insert(field.name.clone(), decode_json(buf, pos, &field.schema)?);
+            }
+            Value::Object(object)
+        }
+        AvroSchema::Array(items) => {
+            let mut array = vec![];
+            loop {
+                let count = read_block_count(buf, pos)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    array.push(decode_json(buf, pos, items)?);
+                }
+            }
+            Value::Array(array)
+        }
+        AvroSchema::Map(values) => {
+            let mut object = Map::new();
+            loop {
+                let count = read_block_count(buf, pos)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    let key = String::from_utf8_lossy(read_bytes(buf, pos)?).to_string();
+                    object.insert(key, decode_json(buf, pos, values)?);
+                }
+            }
+            Value::Object(object)
+        }
+        other => match decode_value(buf, pos, other)? {
+            AvroValue::Null => Value::Null,
+            AvroValue::Boolean(v) => Value::Bool(v),
+            AvroValue::Int(v) | AvroValue::Date(v) | AvroValue::Timestamp(v) => {
+                Value::Number(v.into())
+            }
+            AvroValue::Float(v) => Number::from_f64(v)
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            AvroValue::Bytes(v) => Value::String(String::from_utf8_lossy(&v).to_string()),
+            AvroValue::Decimal(v, scale) => Number::from_f64(v as f64 / 10_f64.powi(scale as i32))
+                .map(Value::Number)
+                .unwrap_or(Value::Null),
+            AvroValue::Json(v) => v,
+        },
+    };
+    Ok(value)
+}
@@ -0,0 +1,250 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+/// The Avro schema, see https://avro.apache.org/docs/current/spec.html#schemas.
+/// The logical types are resolved to their own variants, the unknown logical
+/// types are read as their underlying types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Record(Vec<AvroField>),
+    Enum(Vec<String>),
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Fixed(usize),
+    /// int, the days since UNIX epoch.
+    Date,
+    /// long, the milliseconds since UNIX epoch.
+    TimestampMillis,
+    /// long, the microseconds since UNIX epoch.
+    TimestampMicros,
+    /// bytes or fixed, the two's complement big endian unscaled value.
+    Decimal {
+        precision: usize,
+        scale: usize,
+        fixed: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroField {
+    pub name: String,
+    pub schema: AvroSchema,
+}
+
+impl AvroSchema {
+    pub fn parse(json: &str) -> Result<AvroSchema> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| ErrorCode::AvroError(format!("Invalid Avro schema: {}", e)))?;
+        SchemaParser::default().parse(&value, None)
+    }
+}
+
+// The named types (record, enum and fixed) can be referenced by their names.
+#[derive(Default)]
+struct SchemaParser {
+    names: HashMap<String, AvroSchema>,
+}
+
+impl SchemaParser {
+    fn parse(&mut self, value: &Value, namespace: Option<&str>) -> Result<AvroSchema> {
+        match value {
+            Value::String(name) => self.parse_name(name, namespace),
+            Value::Array(types) => {
+                let types = types
+                    .iter()
+                    .map(|v| self.parse(v, namespace))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(AvroSchema::Union(types))
+            }
+            Value::Object(object) => {
+                let typ = object.get("type").ok_or_else(|| {
+                    ErrorCode::AvroError(format!("Avro schema has no type: {}", value))
+                })?;
+
+                let typ = match typ {
+                    Value::String(typ) => typ.as_str(),
+                    // {"type": {"type": "array", ...}} or {"type": ["null", "string"]}.
+                    other => return self.parse(other, namespace),
+                };
+
+                let namespace = object
+                    .get("namespace")
+                    .and_then(|v| v.as_str())
+                    .or(namespace);
+
+                let schema = match typ {
+                    "record" | "error" => {
+                        let fields =
+                            object
+                                .get("fields")
+                                .and_then(|v| v.as_array())
+                                .ok_or_else(|| {
+                                    ErrorCode::AvroError(format!(
+                                        "Avro record has no fields: {}",
+                                        value
+                                    ))
+                                })?;
+                        let fields = fields
+                            .iter()
+                            .map(|field| {
+                                let name = field.get("name").and_then(|v| v.as_str()).ok_or_else(
+                                    || {
+                                        ErrorCode::AvroError(format!(
+                                            "Avro record field has no name: {}",
+                                            field
+                                        ))
+                                    },
+                                )?;
+                                let schema = field.get("type").ok_or_else(|| {
+                                    ErrorCode::AvroError(format!(
+                                        "Avro record field has no type: {}",
+                                        field
+                                    ))
+                                })?;
+                                Ok(AvroField {
+                                    name: name.to_string(),
+                                    schema: self.parse(schema, namespace)?,
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        AvroSchema::Record(fields)
+                    }
+                    "enum" => {
+                        let symbols = object
+                            .get("symbols")
+                            .and_then(|v| v.as_array())
+                            .map(|v| {
+                                v.iter()
+                                    .map(|s| s.as_str().unwrap_or_default().to_string())
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        AvroSchema::Enum(symbols)
+                    }
+                    "array" => {
+                        let items = object.get("items").ok_or_else(|| {
+                            ErrorCode::AvroError(format!("Avro array has no items: {}", value))
+                        })?;
+                        AvroSchema::Array(Box::new(self.parse(items, namespace)?))
+                    }
+                    "map" => {
+                        let values = object.get("values").ok_or_else(|| {
+                            ErrorCode::AvroError(format!("Avro map has no values: {}", value))
+                        })?;
+                        AvroSchema::Map(Box::new(self.parse(values, namespace)?))
+                    }
+                    "fixed" => {
+                        let size =
+                            object.get("size").and_then(|v| v.as_u64()).ok_or_else(|| {
+                                ErrorCode::AvroError(format!("Avro fixed has no size: {}", value))
+                            })?;
+                        AvroSchema::Fixed(size as usize)
+                    }
+                    primitive => self.parse_name(primitive, namespace)?,
+                };
+
+                let schema = Self::logical_type(object, schema);
+
+                if let (Some(name), "record" | "error" | "enum" | "fixed") =
+                    (object.get("name").and_then(|v| v.as_str()), typ)
+                {
+                    self.names.insert(name.to_string(), schema.clone());
+                    if let Some(namespace) = namespace {
+                        self.names
+                            .insert(format!("{}.{}", namespace, name), schema.clone());
+                    }
+                }
+                Ok(schema)
+            }
+            other => Err(ErrorCode::AvroError(format!(
+                "Invalid Avro schema: {}",
+                other
+            ))),
+        }
+    }
+
+    fn parse_name(&self, name: &str, namespace: Option<&str>) -> Result<AvroSchema> {
+        let schema = match name {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            name => {
+                let full_name = namespace.map(|namespace| format!("{}.{}", namespace, name));
+                match full_name
+                    .and_then(|v| self.names.get(&v))
+                    .or_else(|| self.names.get(name))
+                {
+                    Some(schema) => schema.clone(),
+                    None => {
+                        return Err(ErrorCode::AvroError(format!(
+                            "Unknown Avro type '{}', the recursive types are not supported",
+                            name
+                        )))
+                    }
+                }
+            }
+        };
+        Ok(schema)
+    }
+
+    fn logical_type(object: &serde_json::Map<String, Value>, schema: AvroSchema) -> AvroSchema {
+        let logical_type = object.get("logicalType").and_then(|v| v.as_str());
+        match (logical_type, &schema) {
+            (Some("date"), AvroSchema::Int) => AvroSchema::Date,
+            (Some("timestamp-millis" | "local-timestamp-millis"), AvroSchema::Long) => {
+                AvroSchema::TimestampMillis
+            }
+            (Some("timestamp-micros" | "local-timestamp-micros"), AvroSchema::Long) => {
+                AvroSchema::TimestampMicros
+            }
+            (Some("decimal"), AvroSchema::Bytes | AvroSchema::Fixed(_)) => {
+                let precision = object.get("precision").and_then(|v| v.as_u64());
+                let scale = object.get("scale").and_then(|v| v.as_u64()).unwrap_or(0);
+                match precision {
+                    Some(precision) => AvroSchema::Decimal {
+                        precision: precision as usize,
+                        scale: scale as usize,
+                        fixed: match schema {
+                            AvroSchema::Fixed(size) => Some(size),
+                            _ => None,
+                        },
+                    },
+                    // An invalid decimal is read as the underlying type.
+                    None => schema,
+                }
+            }
+            _ => schema,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::Compression;
+use futures::io::Cursor;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+
+// The bytes read ahead to detect the compression by the magic bytes.
+const MAGIC_BYTES_LEN: usize = 6;
+const BUFFER_SIZE: usize = 4096;
+
+fn from_extension(path: &str) -> Option<Compression> {
+    let extension = path.rsplit_once('.').map(|(_, v)| v.to_lowercase())?;
+    match extension.as_str() {
+        "gz" | "gzip" => Some(Compression::Gzip),
+        "bz2" => Some(Compression::Bz2),
+        "zst" | "zstd" => Some(Compression::Zstd),
+        "xz" => Some(Compression::Xz),
+        _ => None,
+    }
+}
+
+fn from_magic_bytes(bytes: &[u8]) -> Compression {
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        Compression::Gzip
+    } else if bytes.starts_with(b"BZh") {
+        Compression::Bz2
+    } else if bytes.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Compression::Zstd
+    } else if bytes.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+        Compression::Xz
+    } else {
+        Compression::None
+    }
+}
+
+/// Resolves AUTO by the file extension, or by the magic bytes at the head of the file if the
+/// extension is unknown. The other compressions are returned as they are.
+pub fn resolve_compression(compression: &Compression, path: &str, head: &[u8]) -> Compression {
+    match compression {
+        Compression::Auto => from_extension(path).unwrap_or_else(|| from_magic_bytes(head)),
+        other => other.clone(),
+    }
+}
+
+pub fn decompress(compression: &Compression, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    let mut output = vec![];
+    let result = match compression {
+        Compression::None => return Ok(bytes),
+        Compression::Auto => return decompress(&from_magic_bytes(&bytes), bytes),
+        // The multi-member decoders read the concatenated streams, e.g. `cat a.gz b.gz`.
+        Compression::Gzip => {
+            flate2::read::MultiGzDecoder::new(bytes.as_slice()).read_to_end(&mut output)
+        }
+        Compression::Bz2 => {
+            bzip2::read::MultiBzDecoder::new(bytes.as_slice()).read_to_end(&mut output)
+        }
+        Compression::Zstd => zstd::stream::read::Decoder::new(bytes.as_slice())
+            .and_then(|mut decoder| decoder.read_to_end(&mut output)),
+        Compression::Brotli => {
+            brotli::Decompressor::new(bytes.as_slice(), BUFFER_SIZE).read_to_end(&mut output)
+        }
+        Compression::Xz => {
+            xz2::read::XzDecoder::new_multi_decoder(bytes.as_slice()).read_to_end(&mut output)
+        }
+        Compression::Deflate => {
+            flate2::read::ZlibDecoder::new(bytes.as_slice()).read_to_end(&mut output)
+        }
+        Compression::RawDeflate => {
+            flate2::read::DeflateDecoder::new(bytes.as_slice()).read_to_end(&mut output)
+        }
+        Compression::Snappy => {
+            snap::read::FrameDecoder::new(bytes.as_slice()).read_to_end(&mut output)
+        }
+        other => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Compression {:?} is not supported yet",
+                other
+            )))
+        }
+    };
+
+    result
+        .map_err(|e| ErrorCode::BadBytes(format!("Decompress {:?} error: {}", compression, e)))?;
+    Ok(output)
+}
+
+/// Decompresses the whole file which is already read into memory.
+pub fn decompress_file(compression: &Compression, path: &str, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    let compression = resolve_compression(compression, path, &bytes);
+    decompress(&compression, bytes)
+}
+
+/// Wraps the reader of a file to read the decompressed data.
+/// The uncompressed files are still streamed, the head read ahead for the magic bytes is chained back.
+/// The compressed files are decompressed in memory.
+pub async fn decompress_reader<'a, R>(
+    compression: &Compression,
+    path: &str,
+    mut reader: R,
+) -> Result<Box<dyn AsyncRead + Unpin + Send + 'a>>
+where
+    R: AsyncRead + Unpin + Send + 'a,
+{
+    let mut head = Vec::with_capacity(MAGIC_BYTES_LEN);
+    if *compression == Compression::Auto && from_extension(path).is_none() {
+        (&mut reader)
+            .take(MAGIC_BYTES_LEN as u64)
+            .read_to_end(&mut head)
+            .await?;
+    }
+
+    match resolve_compression(compression, path, &head) {
+        Compression::None => Ok(Box::new(Cursor::new(head).chain(reader))),
+        compression => {
+            reader.read_to_end(&mut head).await?;
+            Ok(Box::new(Cursor::new(decompress(&compression, head)?)))
+        }
+    }
+}
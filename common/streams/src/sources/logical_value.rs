@@ -0,0 +1,104 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The conversions of the logical values (dates, timestamps and decimals) of the
+//! binary file formats to the data values of the target column types.
+
+use common_datavalues::prelude::*;
+
+pub fn integer_value(value: i64, data_type: &DataTypePtr) -> DataValue {
+    let data_type = remove_nullable(data_type);
+    match data_type.data_type_id() {
+        TypeID::Boolean => DataValue::Boolean(value != 0),
+        TypeID::Decimal => DataValue::Int128(rescale(value as i128, 0, decimal_scale(&data_type))),
+        _ => DataValue::Int64(value),
+    }
+}
+
+/// The days since UNIX epoch.
+pub fn date_value(days: i64, data_type: &DataTypePtr) -> DataValue {
+    let data_type = remove_nullable(data_type);
+    match data_type.data_type_id() {
+        TypeID::DateTime32 => DataValue::Int64(days * 24 * 3600),
+        TypeID::DateTime64 => DataValue::Int64(from_nano_seconds(
+            &data_type,
+            days * 24 * 3600 * 1_000_000_000,
+        )),
+        _ => DataValue::Int64(days),
+    }
+}
+
+/// The nanoseconds since UNIX epoch.
+pub fn timestamp_value(nanos: i64, data_type: &DataTypePtr) -> DataValue {
+    let data_type = remove_nullable(data_type);
+    match data_type.data_type_id() {
+        TypeID::Date16 | TypeID::Date32 => {
+            DataValue::Int64(nanos.div_euclid(24 * 3600 * 1_000_000_000))
+        }
+        TypeID::DateTime32 => DataValue::Int64(nanos.div_euclid(1_000_000_000)),
+        TypeID::DateTime64 => DataValue::Int64(from_nano_seconds(&data_type, nanos)),
+        _ => DataValue::Int64(nanos),
+    }
+}
+
+/// The unscaled value and the scale of a decimal.
+pub fn decimal_value(value: i128, scale: i64, data_type: &DataTypePtr) -> DataValue {
+    let data_type = remove_nullable(data_type);
+    match data_type.data_type_id() {
+        TypeID::Decimal => DataValue::Int128(rescale(value, scale, decimal_scale(&data_type))),
+        TypeID::Float32 | TypeID::Float64 => {
+            DataValue::Float64(value as f64 / 10_f64.powi(scale as i32))
+        }
+        TypeID::String => DataValue::String(format_decimal(value, scale).into_bytes()),
+        _ => DataValue::Int64(rescale(value, scale, 0) as i64),
+    }
+}
+
+fn decimal_scale(data_type: &DataTypePtr) -> i64 {
+    match data_type.as_any().downcast_ref::<DecimalType>() {
+        Some(decimal) => decimal.scale() as i64,
+        None => 0,
+    }
+}
+
+fn from_nano_seconds(data_type: &DataTypePtr, nanos: i64) -> i64 {
+    match data_type.as_any().downcast_ref::<DateTime64Type>() {
+        Some(datetime) => datetime.from_nano_seconds(nanos),
+        None => nanos,
+    }
+}
+
+fn rescale(value: i128, from_scale: i64, to_scale: i64) -> i128 {
+    match to_scale >= from_scale {
+        true => value * 10_i128.pow((to_scale - from_scale) as u32),
+        false => value / 10_i128.pow((from_scale - to_scale) as u32),
+    }
+}
+
+fn format_decimal(value: i128, scale: i64) -> String {
+    if scale <= 0 {
+        return rescale(value, scale, 0).to_string();
+    }
+
+    let factor = 10_u128.pow(scale as u32);
+    let sign = if value < 0 { "-" } else { "" };
+    let abs = value.unsigned_abs();
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        abs / factor,
+        abs % factor,
+        width = scale as usize
+    )
+}
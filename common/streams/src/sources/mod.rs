@@ -12,12 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
+mod decompression;
+mod logical_value;
+mod orc;
+mod rejected_records;
+mod schema_inference;
 mod source;
+mod source_avro;
 mod source_csv;
 mod source_ndjson;
+mod source_orc;
 mod source_parquet;
 
+pub use decompression::decompress;
+pub use decompression::decompress_file;
+pub use decompression::decompress_reader;
+pub use decompression::resolve_compression;
+pub use rejected_records::RejectedRecord;
+pub use rejected_records::RejectedRecords;
+pub use schema_inference::infer_json_type;
+pub use schema_inference::infer_text_type;
+pub use schema_inference::merge_inferred_types;
+pub use schema_inference::SchemaInference;
+pub use schema_inference::INFER_SCHEMA_MAX_RECORDS;
 pub use source::Source;
+pub use source_avro::AvroSource;
+pub use source_avro::AvroSourceBuilder;
+pub use source_csv::CsvChunk;
 pub use source_csv::CsvSourceBuilder;
 pub use source_ndjson::NDJsonSourceBuilder;
+pub use source_orc::OrcSource;
+pub use source_orc::OrcSourceBuilder;
 pub use source_parquet::ParquetSourceBuilder;
@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::proto::CompressionKind;
+
+// The compressed streams are a sequence of chunks, each chunk has a 3 bytes header:
+// the lowest bit tells if the chunk is stored uncompressed, the others are the chunk length.
+pub fn decompress(kind: CompressionKind, block_size: usize, buf: &[u8]) -> Result<Vec<u8>> {
+    if kind == CompressionKind::None {
+        return Ok(buf.to_vec());
+    }
+
+    let mut output = Vec::with_capacity(buf.len() * 2);
+    let mut pos = 0;
+    while pos < buf.len() {
+        if pos + 3 > buf.len() {
+            return Err(ErrorCode::OrcError(
+                "Unexpected end of ORC compressed chunk header",
+            ));
+        }
+        let header =
+            buf[pos] as usize | (buf[pos + 1] as usize) << 8 | (buf[pos + 2] as usize) << 16;
+        let original = header & 0x01 == 1;
+        let length = header >> 1;
+        pos += 3;
+
+        if pos + length > buf.len() {
+            return Err(ErrorCode::OrcError(
+                "Unexpected end of ORC compressed chunk",
+            ));
+        }
+        let chunk = &buf[pos..pos + length];
+        pos += length;
+
+        if original {
+            output.extend_from_slice(chunk);
+            continue;
+        }
+
+        match kind {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut output)
+                    .map_err(|e| ErrorCode::OrcError(format!("ORC zlib chunk: {}", e)))?;
+            }
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| ErrorCode::OrcError(format!("ORC snappy chunk: {}", e)))?;
+                output.extend_from_slice(&decompressed);
+            }
+            CompressionKind::Lz4 => {
+                let decompressed = lz4::block::decompress(chunk, Some(block_size as i32))
+                    .map_err(|e| ErrorCode::OrcError(format!("ORC lz4 chunk: {}", e)))?;
+                output.extend_from_slice(&decompressed);
+            }
+            CompressionKind::Zstd => {
+                let decompressed = zstd::stream::decode_all(chunk)
+                    .map_err(|e| ErrorCode::OrcError(format!("ORC zstd chunk: {}", e)))?;
+                output.extend_from_slice(&decompressed);
+            }
+            other => {
+                return Err(ErrorCode::OrcError(format!(
+                    "ORC compression {:?} is not supported",
+                    other
+                )))
+            }
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,446 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The protobuf messages of the ORC file tail and stripe footers.
+//! Only the fields needed to locate and decode the column streams are kept,
+//! see https://orc.apache.org/specification/ORCv1/ for the full definitions.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+struct ProtoReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    fn create(buf: &'a [u8]) -> Self {
+        ProtoReader { buf, pos: 0 }
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = *self
+                .buf
+                .get(self.pos)
+                .ok_or_else(|| ErrorCode::OrcError("Unexpected end of protobuf message"))?;
+            self.pos += 1;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    // The next field number and wire type, None at the end of the message.
+    fn next_field(&mut self) -> Result<Option<(u32, u8)>> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.read_varint()?;
+        Ok(Some(((key >> 3) as u32, (key & 0x07) as u8)))
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.read_varint()? as usize;
+        let end = self.pos.checked_add(len).unwrap_or(usize::MAX);
+        if end > self.buf.len() {
+            return Err(ErrorCode::OrcError("Unexpected end of protobuf message"));
+        }
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        Ok(String::from_utf8_lossy(self.read_bytes()?).to_string())
+    }
+
+    // Repeated integers may be packed (length delimited) or not.
+    fn read_repeated_u32(&mut self, wire_type: u8, values: &mut Vec<u32>) -> Result<()> {
+        match wire_type {
+            2 => {
+                let mut packed = ProtoReader::create(self.read_bytes()?);
+                while packed.pos < packed.buf.len() {
+                    values.push(packed.read_varint()? as u32);
+                }
+            }
+            _ => values.push(self.read_varint()? as u32),
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, wire_type: u8) -> Result<()> {
+        let len = match wire_type {
+            0 => {
+                self.read_varint()?;
+                0
+            }
+            1 => 8,
+            2 => self.read_varint()? as usize,
+            5 => 4,
+            other => {
+                return Err(ErrorCode::OrcError(format!(
+                    "Unsupported protobuf wire type {}",
+                    other
+                )))
+            }
+        };
+        self.pos = self.pos.checked_add(len).unwrap_or(usize::MAX);
+        if self.pos > self.buf.len() {
+            return Err(ErrorCode::OrcError("Unexpected end of protobuf message"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionKind {
+    fn from_value(value: u64) -> Result<Self> {
+        match value {
+            0 => Ok(CompressionKind::None),
+            1 => Ok(CompressionKind::Zlib),
+            2 => Ok(CompressionKind::Snappy),
+            3 => Ok(CompressionKind::Lzo),
+            4 => Ok(CompressionKind::Lz4),
+            5 => Ok(CompressionKind::Zstd),
+            other => Err(ErrorCode::OrcError(format!(
+                "Unknown ORC compression kind {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub magic: String,
+}
+
+impl PostScript {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut postscript = PostScript {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            compression_block_size: 256 * 1024,
+            magic: String::new(),
+        };
+
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => postscript.footer_length = reader.read_varint()?,
+                2 => postscript.compression = CompressionKind::from_value(reader.read_varint()?)?,
+                3 => postscript.compression_block_size = reader.read_varint()?,
+                8000 => postscript.magic = reader.read_string()?,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(postscript)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut stripe = StripeInformation::default();
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => stripe.offset = reader.read_varint()?,
+                2 => stripe.index_length = reader.read_varint()?,
+                3 => stripe.data_length = reader.read_varint()?,
+                4 => stripe.footer_length = reader.read_varint()?,
+                5 => stripe.number_of_rows = reader.read_varint()?,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(stripe)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn from_value(value: u64) -> Result<Self> {
+        let kind = match value {
+            0 => TypeKind::Boolean,
+            1 => TypeKind::Byte,
+            2 => TypeKind::Short,
+            3 => TypeKind::Int,
+            4 => TypeKind::Long,
+            5 => TypeKind::Float,
+            6 => TypeKind::Double,
+            7 => TypeKind::String,
+            8 => TypeKind::Binary,
+            9 => TypeKind::Timestamp,
+            10 => TypeKind::List,
+            11 => TypeKind::Map,
+            12 => TypeKind::Struct,
+            13 => TypeKind::Union,
+            14 => TypeKind::Decimal,
+            15 => TypeKind::Date,
+            16 => TypeKind::Varchar,
+            17 => TypeKind::Char,
+            18 => TypeKind::TimestampInstant,
+            other => {
+                return Err(ErrorCode::OrcError(format!(
+                    "Unknown ORC type kind {}",
+                    other
+                )))
+            }
+        };
+        Ok(kind)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl Type {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut typ = Type {
+            kind: TypeKind::Struct,
+            subtypes: vec![],
+            field_names: vec![],
+            precision: 0,
+            scale: 0,
+        };
+
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => typ.kind = TypeKind::from_value(reader.read_varint()?)?,
+                2 => reader.read_repeated_u32(wire_type, &mut typ.subtypes)?,
+                3 => typ.field_names.push(reader.read_string()?),
+                5 => typ.precision = reader.read_varint()? as u32,
+                6 => typ.scale = reader.read_varint()? as u32,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(typ)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+}
+
+impl Footer {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut footer = Footer::default();
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                3 => footer
+                    .stripes
+                    .push(StripeInformation::decode(reader.read_bytes()?)?),
+                4 => footer.types.push(Type::decode(reader.read_bytes()?)?),
+                6 => footer.number_of_rows = reader.read_varint()?,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(footer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    // Index, bloom filter and encrypted streams are not read.
+    Other,
+}
+
+impl StreamKind {
+    fn from_value(value: u64) -> Self {
+        match value {
+            0 => StreamKind::Present,
+            1 => StreamKind::Data,
+            2 => StreamKind::Length,
+            3 => StreamKind::DictionaryData,
+            5 => StreamKind::Secondary,
+            _ => StreamKind::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+impl Stream {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut stream = Stream {
+            kind: StreamKind::Other,
+            column: 0,
+            length: 0,
+        };
+
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => stream.kind = StreamKind::from_value(reader.read_varint()?),
+                2 => stream.column = reader.read_varint()? as u32,
+                3 => stream.length = reader.read_varint()?,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(stream)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnEncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+impl ColumnEncoding {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut encoding = ColumnEncoding {
+            kind: ColumnEncodingKind::Direct,
+            dictionary_size: 0,
+        };
+
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => {
+                    encoding.kind = match reader.read_varint()? {
+                        0 => ColumnEncodingKind::Direct,
+                        1 => ColumnEncodingKind::Dictionary,
+                        2 => ColumnEncodingKind::DirectV2,
+                        3 => ColumnEncodingKind::DictionaryV2,
+                        other => {
+                            return Err(ErrorCode::OrcError(format!(
+                                "Unknown ORC column encoding {}",
+                                other
+                            )))
+                        }
+                    }
+                }
+                2 => encoding.dictionary_size = reader.read_varint()? as u32,
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(encoding)
+    }
+
+    pub fn is_v2(&self) -> bool {
+        matches!(
+            self.kind,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2
+        )
+    }
+
+    pub fn is_dictionary(&self) -> bool {
+        matches!(
+            self.kind,
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut footer = StripeFooter::default();
+        let mut reader = ProtoReader::create(buf);
+        while let Some((field, wire_type)) = reader.next_field()? {
+            match field {
+                1 => footer.streams.push(Stream::decode(reader.read_bytes()?)?),
+                2 => footer
+                    .columns
+                    .push(ColumnEncoding::decode(reader.read_bytes()?)?),
+                _ => reader.skip(wire_type)?,
+            }
+        }
+        Ok(footer)
+    }
+}
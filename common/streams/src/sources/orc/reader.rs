@@ -0,0 +1,367 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::compression::decompress;
+use super::proto::CompressionKind;
+use super::proto::Footer;
+use super::proto::PostScript;
+use super::proto::StreamKind;
+use super::proto::StripeFooter;
+use super::proto::Type;
+use super::proto::TypeKind;
+use super::rle::decode_boolean_rle;
+use super::rle::decode_byte_rle;
+use super::rle::decode_int_rle;
+use super::rle::read_varint_i128;
+
+// The ORC timestamps are the seconds since 2015-01-01 00:00:00 UTC.
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// The values of a column in a stripe, the nulls are not included.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnValues {
+    Boolean(Vec<bool>),
+    /// BYTE, SHORT, INT and LONG.
+    Integer(Vec<i64>),
+    /// FLOAT and DOUBLE.
+    Float(Vec<f64>),
+    /// STRING, VARCHAR, CHAR and BINARY.
+    Binary(Vec<Vec<u8>>),
+    /// The days since UNIX epoch.
+    Date(Vec<i64>),
+    /// The nanoseconds since UNIX epoch.
+    Timestamp(Vec<i64>),
+    /// The unscaled values and the scale of each value.
+    Decimal(Vec<i128>, Vec<i64>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrcColumn {
+    /// None if all the rows are present.
+    pub validity: Option<Vec<bool>>,
+    pub values: ColumnValues,
+}
+
+pub struct Stripe {
+    rows: usize,
+    footer: StripeFooter,
+    // The (offset, length) of the streams in the file.
+    streams: HashMap<(u32, StreamKind), (usize, usize)>,
+}
+
+impl Stripe {
+    pub fn number_of_rows(&self) -> usize {
+        self.rows
+    }
+}
+
+pub struct OrcFile {
+    data: Vec<u8>,
+    compression: CompressionKind,
+    compression_block_size: usize,
+    footer: Footer,
+}
+
+impl OrcFile {
+    pub fn try_create(data: Vec<u8>) -> Result<Self> {
+        if data.len() < 4 || &data[..3] != b"ORC" {
+            return Err(ErrorCode::OrcError(
+                "Invalid ORC file, the magic 'ORC' is missing",
+            ));
+        }
+
+        // The tail of the file: [footer][postscript][postscript length: 1 byte].
+        let postscript_end = data.len() - 1;
+        let postscript_start = postscript_end
+            .checked_sub(data[postscript_end] as usize)
+            .ok_or_else(|| ErrorCode::OrcError("Invalid ORC postscript length"))?;
+        let postscript = PostScript::decode(&data[postscript_start..postscript_end])?;
+
+        let footer_start = postscript_start
+            .checked_sub(postscript.footer_length as usize)
+            .ok_or_else(|| ErrorCode::OrcError("Invalid ORC footer length"))?;
+        let compression_block_size = postscript.compression_block_size as usize;
+        let footer = Footer::decode(&decompress(
+            postscript.compression,
+            compression_block_size,
+            &data[footer_start..postscript_start],
+        )?)?;
+
+        match footer.types.first() {
+            Some(root) if root.kind == TypeKind::Struct => Ok(OrcFile {
+                data,
+                compression: postscript.compression,
+                compression_block_size,
+                footer,
+            }),
+            _ => Err(ErrorCode::OrcError(
+                "Invalid ORC file, the root type must be a struct",
+            )),
+        }
+    }
+
+    pub fn number_of_rows(&self) -> u64 {
+        self.footer.number_of_rows
+    }
+
+    pub fn number_of_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    /// The names and the column ids of the top level fields.
+    pub fn fields(&self) -> Vec<(String, u32)> {
+        let root = &self.footer.types[0];
+        root.field_names
+            .iter()
+            .cloned()
+            .zip(root.subtypes.iter().cloned())
+            .collect()
+    }
+
+    pub fn column_type(&self, column: u32) -> Result<&Type> {
+        self.footer.types.get(column as usize).ok_or_else(|| {
+            ErrorCode::OrcError(format!("ORC column {} has no type in the footer", column))
+        })
+    }
+
+    pub fn read_stripe(&self, index: usize) -> Result<Stripe> {
+        let info = &self.footer.stripes[index];
+        let offset = info.offset as usize;
+        let footer_start = offset + (info.index_length + info.data_length) as usize;
+        let footer_end = footer_start + info.footer_length as usize;
+        if footer_end > self.data.len() {
+            return Err(ErrorCode::OrcError(format!(
+                "ORC stripe {} is out of the file bounds",
+                index
+            )));
+        }
+
+        let footer = StripeFooter::decode(&self.decompress(&self.data[footer_start..footer_end])?)?;
+
+        // The streams are stored one after another from the start of the stripe.
+        let mut streams = HashMap::new();
+        let mut stream_offset = offset;
+        for stream in &footer.streams {
+            let length = stream.length as usize;
+            if stream.kind != StreamKind::Other {
+                streams.insert((stream.column, stream.kind), (stream_offset, length));
+            }
+            stream_offset += length;
+        }
+
+        Ok(Stripe {
+            rows: info.number_of_rows as usize,
+            footer,
+            streams,
+        })
+    }
+
+    fn decompress(&self, buf: &[u8]) -> Result<Vec<u8>> {
+        decompress(self.compression, self.compression_block_size, buf)
+    }
+
+    fn stream(&self, stripe: &Stripe, column: u32, kind: StreamKind) -> Result<Option<Vec<u8>>> {
+        match stripe.streams.get(&(column, kind)) {
+            None => Ok(None),
+            Some((offset, length)) => {
+                if offset + length > self.data.len() {
+                    return Err(ErrorCode::OrcError(format!(
+                        "ORC {:?} stream of column {} is out of the file bounds",
+                        kind, column
+                    )));
+                }
+                Ok(Some(self.decompress(&self.data[*offset..offset + length])?))
+            }
+        }
+    }
+
+    fn required_stream(&self, stripe: &Stripe, column: u32, kind: StreamKind) -> Result<Vec<u8>> {
+        self.stream(stripe, column, kind)?.ok_or_else(|| {
+            ErrorCode::OrcError(format!(
+                "ORC {:?} stream of column {} is missing",
+                kind, column
+            ))
+        })
+    }
+
+    pub fn read_column(&self, stripe: &Stripe, column: u32) -> Result<OrcColumn> {
+        let typ = self.column_type(column)?;
+        let encoding = stripe.footer.columns.get(column as usize).ok_or_else(|| {
+            ErrorCode::OrcError(format!(
+                "ORC column {} has no encoding in the stripe",
+                column
+            ))
+        })?;
+        let v2 = encoding.is_v2();
+
+        let validity = match self.stream(stripe, column, StreamKind::Present)? {
+            None => None,
+            Some(buf) => Some(decode_boolean_rle(&buf, stripe.rows)?),
+        };
+        let count = match &validity {
+            None => stripe.rows,
+            Some(validity) => validity.iter().filter(|v| **v).count(),
+        };
+
+        let values = match typ.kind {
+            TypeKind::Boolean => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                ColumnValues::Boolean(decode_boolean_rle(&data, count)?)
+            }
+            TypeKind::Byte => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                let values = decode_byte_rle(&data, count)?;
+                ColumnValues::Integer(values.into_iter().map(|v| v as i8 as i64).collect())
+            }
+            TypeKind::Short | TypeKind::Int | TypeKind::Long => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                ColumnValues::Integer(decode_int_rle(&data, count, true, v2)?)
+            }
+            TypeKind::Float => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                check_length(&data, count * 4, column)?;
+                let values = data
+                    .chunks_exact(4)
+                    .take(count)
+                    .map(|v| f32::from_le_bytes(v.try_into().unwrap()) as f64)
+                    .collect();
+                ColumnValues::Float(values)
+            }
+            TypeKind::Double => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                check_length(&data, count * 8, column)?;
+                let values = data
+                    .chunks_exact(8)
+                    .take(count)
+                    .map(|v| f64::from_le_bytes(v.try_into().unwrap()))
+                    .collect();
+                ColumnValues::Float(values)
+            }
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                let lengths = self.required_stream(stripe, column, StreamKind::Length)?;
+                match encoding.is_dictionary() {
+                    true => {
+                        let dictionary_data =
+                            self.stream(stripe, column, StreamKind::DictionaryData)?;
+                        let dictionary_size = encoding.dictionary_size as usize;
+                        let lengths = decode_int_rle(&lengths, dictionary_size, false, v2)?;
+                        let dictionary =
+                            split_binary(&dictionary_data.unwrap_or_default(), &lengths, column)?;
+
+                        let indexes = decode_int_rle(&data, count, false, v2)?;
+                        let values = indexes
+                            .into_iter()
+                            .map(|i| {
+                                dictionary.get(i as usize).cloned().ok_or_else(|| {
+                                    ErrorCode::OrcError(format!(
+                                        "ORC dictionary index {} of column {} is out of bounds",
+                                        i, column
+                                    ))
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        ColumnValues::Binary(values)
+                    }
+                    false => {
+                        let lengths = decode_int_rle(&lengths, count, false, v2)?;
+                        ColumnValues::Binary(split_binary(&data, &lengths, column)?)
+                    }
+                }
+            }
+            TypeKind::Date => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                ColumnValues::Date(decode_int_rle(&data, count, true, v2)?)
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                let secondary = self.required_stream(stripe, column, StreamKind::Secondary)?;
+                let seconds = decode_int_rle(&data, count, true, v2)?;
+                let nanos = decode_int_rle(&secondary, count, false, v2)?;
+
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos.into_iter())
+                    .map(|(seconds, nanos)| {
+                        // The low 3 bits are the number of trailing zeros removed from the nanoseconds.
+                        let zeros = nanos & 0x07;
+                        let mut nanos = nanos >> 3;
+                        if zeros != 0 {
+                            nanos *= 10_i64.pow(zeros as u32 + 1);
+                        }
+                        // The writers store the negative timestamps with the seconds rounded toward zero.
+                        let mut seconds = seconds + ORC_TIMESTAMP_BASE_SECONDS;
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds * 1_000_000_000 + nanos
+                    })
+                    .collect();
+                ColumnValues::Timestamp(values)
+            }
+            TypeKind::Decimal => {
+                let data = self.required_stream(stripe, column, StreamKind::Data)?;
+                let secondary = self.required_stream(stripe, column, StreamKind::Secondary)?;
+                let mut pos = 0;
+                let values = (0..count)
+                    .map(|_| read_varint_i128(&data, &mut pos))
+                    .collect::<Result<Vec<_>>>()?;
+                let scales = decode_int_rle(&secondary, count, true, v2)?;
+                ColumnValues::Decimal(values, scales)
+            }
+            other => {
+                return Err(ErrorCode::OrcError(format!(
+                    "ORC type {:?} of column {} is not supported yet",
+                    other, column
+                )))
+            }
+        };
+
+        Ok(OrcColumn { validity, values })
+    }
+}
+
+fn check_length(data: &[u8], expected: usize, column: u32) -> Result<()> {
+    match data.len() >= expected {
+        true => Ok(()),
+        false => Err(ErrorCode::OrcError(format!(
+            "ORC data stream of column {} is too short, expected {} bytes but got {}",
+            column,
+            expected,
+            data.len()
+        ))),
+    }
+}
+
+fn split_binary(data: &[u8], lengths: &[i64], column: u32) -> Result<Vec<Vec<u8>>> {
+    let total = lengths.iter().map(|v| *v as usize).sum();
+    check_length(data, total, column)?;
+
+    let mut pos = 0;
+    let values = lengths
+        .iter()
+        .map(|length| {
+            let value = data[pos..pos + *length as usize].to_vec();
+            pos += *length as usize;
+            value
+        })
+        .collect();
+    Ok(values)
+}
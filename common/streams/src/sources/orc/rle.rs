@@ -0,0 +1,315 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The run length encodings of the ORC streams.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+fn unexpected_eof() -> ErrorCode {
+    ErrorCode::OrcError("Unexpected end of ORC stream")
+}
+
+fn next_byte(buf: &[u8], pos: &mut usize) -> Result<u8> {
+    let byte = *buf.get(*pos).ok_or_else(unexpected_eof)?;
+    *pos += 1;
+    Ok(byte)
+}
+
+pub fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = next_byte(buf, pos)?;
+        if shift < 64 {
+            value |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+// The unbounded varint of the decimal values.
+pub fn read_varint_i128(buf: &[u8], pos: &mut usize) -> Result<i128> {
+    let mut value = 0u128;
+    let mut shift = 0;
+    loop {
+        let byte = next_byte(buf, pos)?;
+        if shift < 128 {
+            value |= ((byte & 0x7f) as u128) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(((value >> 1) as i128) ^ -((value & 1) as i128));
+        }
+        shift += 7;
+    }
+}
+
+#[inline]
+fn zigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn read_signed_varint(buf: &[u8], pos: &mut usize) -> Result<i64> {
+    Ok(zigzag(read_varint(buf, pos)?))
+}
+
+// Byte run length encoding, used by the byte columns and the boolean streams.
+pub fn decode_byte_rle(buf: &[u8], count: usize) -> Result<Vec<u8>> {
+    let mut values = Vec::with_capacity(count);
+    let mut pos = 0;
+    while values.len() < count {
+        let header = next_byte(buf, &mut pos)? as i8;
+        if header >= 0 {
+            let value = next_byte(buf, &mut pos)?;
+            let run = header as usize + 3;
+            values.extend(std::iter::repeat(value).take(run));
+        } else {
+            let run = -(header as i32) as usize;
+            let end = pos + run;
+            if end > buf.len() {
+                return Err(unexpected_eof());
+            }
+            values.extend_from_slice(&buf[pos..end]);
+            pos = end;
+        }
+    }
+    values.truncate(count);
+    Ok(values)
+}
+
+// The booleans are byte run length encoded bits, the most significant bit first.
+pub fn decode_boolean_rle(buf: &[u8], count: usize) -> Result<Vec<bool>> {
+    let bytes = decode_byte_rle(buf, (count + 7) / 8)?;
+    let values = (0..count)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect();
+    Ok(values)
+}
+
+pub fn decode_int_rle(buf: &[u8], count: usize, signed: bool, v2: bool) -> Result<Vec<i64>> {
+    let mut values = match v2 {
+        true => decode_int_rle_v2(buf, count, signed)?,
+        false => decode_int_rle_v1(buf, count, signed)?,
+    };
+    values.truncate(count);
+    Ok(values)
+}
+
+fn decode_int_rle_v1(buf: &[u8], count: usize, signed: bool) -> Result<Vec<i64>> {
+    let read = |pos: &mut usize| match signed {
+        true => read_signed_varint(buf, pos),
+        false => read_varint(buf, pos).map(|v| v as i64),
+    };
+
+    let mut values = Vec::with_capacity(count);
+    let mut pos = 0;
+    while values.len() < count {
+        let header = next_byte(buf, &mut pos)? as i8;
+        if header >= 0 {
+            let run = header as i64 + 3;
+            let delta = next_byte(buf, &mut pos)? as i8 as i64;
+            let base = read(&mut pos)?;
+            values.extend((0..run).map(|i| base.wrapping_add(i * delta)));
+        } else {
+            for _ in 0..-(header as i32) {
+                values.push(read(&mut pos)?);
+            }
+        }
+    }
+    Ok(values)
+}
+
+// The 5 bits width code of the RLE v2 runs.
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+// Read `count` big endian bit packed values, the next run starts at the next byte.
+fn read_bit_packed(buf: &[u8], pos: &mut usize, count: usize, width: usize) -> Result<Vec<u64>> {
+    let mut values = Vec::with_capacity(count);
+    let mut bit_pos = 0;
+    for _ in 0..count {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = *buf.get(*pos + bit_pos / 8).ok_or_else(unexpected_eof)?;
+            let available = 8 - bit_pos % 8;
+            let take = std::cmp::min(available, remaining);
+            let bits = (byte as u64 >> (available - take)) & ((1u64 << take) - 1);
+            value = (value << take) | bits;
+            remaining -= take;
+            bit_pos += take;
+        }
+        values.push(value);
+    }
+    *pos += (bit_pos + 7) / 8;
+    Ok(values)
+}
+
+fn read_big_endian(buf: &[u8], pos: &mut usize, bytes: usize) -> Result<u64> {
+    let mut value = 0u64;
+    for _ in 0..bytes {
+        value = (value << 8) | next_byte(buf, pos)? as u64;
+    }
+    Ok(value)
+}
+
+fn decode_int_rle_v2(buf: &[u8], count: usize, signed: bool) -> Result<Vec<i64>> {
+    let mut values = Vec::with_capacity(count);
+    let mut pos = 0;
+    while values.len() < count {
+        let header = next_byte(buf, &mut pos)?;
+        match header >> 6 {
+            // Short repeat.
+            0 => {
+                let width = ((header >> 3) & 0x07) as usize + 1;
+                let run = (header & 0x07) as usize + 3;
+                let value = read_big_endian(buf, &mut pos, width)?;
+                let value = match signed {
+                    true => zigzag(value),
+                    false => value as i64,
+                };
+                values.extend(std::iter::repeat(value).take(run));
+            }
+            // Direct.
+            1 => {
+                let width = decode_bit_width((header >> 1) & 0x1f);
+                let run =
+                    (((header & 0x01) as usize) << 8 | next_byte(buf, &mut pos)? as usize) + 1;
+                let packed = read_bit_packed(buf, &mut pos, run, width)?;
+                values.extend(packed.into_iter().map(|v| match signed {
+                    true => zigzag(v),
+                    false => v as i64,
+                }));
+            }
+            // Patched base.
+            2 => {
+                let width = decode_bit_width((header >> 1) & 0x1f);
+                let run =
+                    (((header & 0x01) as usize) << 8 | next_byte(buf, &mut pos)? as usize) + 1;
+
+                let third = next_byte(buf, &mut pos)?;
+                let base_width = ((third >> 5) & 0x07) as usize + 1;
+                let patch_width = decode_bit_width(third & 0x1f);
+
+                let fourth = next_byte(buf, &mut pos)?;
+                let gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+                let patch_count = (fourth & 0x1f) as usize;
+
+                // The most significant bit of the base is the sign.
+                let base = read_big_endian(buf, &mut pos, base_width)?;
+                let sign_mask = 1u64 << (base_width * 8 - 1);
+                let base = match base & sign_mask {
+                    0 => base as i64,
+                    _ => -((base & !sign_mask) as i64),
+                };
+
+                let mut packed = read_bit_packed(buf, &mut pos, run, width)?;
+                let patches = read_bit_packed(
+                    buf,
+                    &mut pos,
+                    patch_count,
+                    closest_fixed_bits(gap_width + patch_width),
+                )?;
+
+                // A gap of 255 with an empty patch only moves the position.
+                let patch_mask = match patch_width {
+                    64 => u64::MAX,
+                    _ => (1u64 << patch_width) - 1,
+                };
+                let mut index = 0;
+                for patch in patches {
+                    let gap = (patch >> patch_width) as usize;
+                    let patch = patch & patch_mask;
+                    index += gap;
+                    if gap == 255 && patch == 0 {
+                        continue;
+                    }
+                    let value = packed.get_mut(index).ok_or_else(|| {
+                        ErrorCode::OrcError("ORC patched base run is out of bounds")
+                    })?;
+                    *value |= patch << width;
+                }
+
+                values.extend(packed.into_iter().map(|v| base.wrapping_add(v as i64)));
+            }
+            // Delta.
+            _ => {
+                let code = (header >> 1) & 0x1f;
+                let width = match code {
+                    0 => 0,
+                    _ => decode_bit_width(code),
+                };
+                let run =
+                    (((header & 0x01) as usize) << 8 | next_byte(buf, &mut pos)? as usize) + 1;
+
+                let base = match signed {
+                    true => read_signed_varint(buf, &mut pos)?,
+                    false => read_varint(buf, &mut pos)? as i64,
+                };
+                let delta = read_signed_varint(buf, &mut pos)?;
+
+                values.push(base);
+                if width == 0 {
+                    // Fixed delta.
+                    let mut value = base;
+                    for _ in 1..run {
+                        value = value.wrapping_add(delta);
+                        values.push(value);
+                    }
+                } else if run > 1 {
+                    let mut value = base.wrapping_add(delta);
+                    values.push(value);
+                    let deltas = read_bit_packed(buf, &mut pos, run - 2, width)?;
+                    for d in deltas {
+                        value = match delta < 0 {
+                            true => value.wrapping_sub(d as i64),
+                            false => value.wrapping_add(d as i64),
+                        };
+                        values.push(value);
+                    }
+                }
+            }
+        }
+    }
+    Ok(values)
+}
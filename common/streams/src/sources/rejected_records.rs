@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::TypeDeserializer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+
+/// A record of a loaded file that can't be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedRecord {
+    pub file: String,
+    /// The number of the record in the file starting from 1, the skipped headers included.
+    pub line: usize,
+    pub record: String,
+    pub error: String,
+}
+
+/// Collects the rejected records of a load, the clones share the collected records.
+#[derive(Debug, Clone)]
+pub struct RejectedRecords {
+    records: Arc<Mutex<Vec<RejectedRecord>>>,
+}
+
+impl RejectedRecords {
+    pub fn create() -> Self {
+        RejectedRecords {
+            records: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    pub fn push(&self, record: RejectedRecord) {
+        self.records.lock().push(record);
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.lock().is_empty()
+    }
+
+    pub fn take(&self) -> Vec<RejectedRecord> {
+        std::mem::take(&mut *self.records.lock())
+    }
+}
+
+// The row-level error handling of the text sources: a malformed record is rejected and skipped,
+// until there are more than `max_errors` of them and the read fails.
+// The clones share the error count, so the chunks of a file parsed in parallel have one limit.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecordErrorHandler {
+    pub(crate) max_errors: u64,
+    pub(crate) file_name: String,
+    pub(crate) rejected: Option<RejectedRecords>,
+    errors: Arc<AtomicU64>,
+}
+
+impl RecordErrorHandler {
+    // A clone of the handler which counts the errors from zero, for a new source.
+    pub(crate) fn new_count(&self) -> Self {
+        RecordErrorHandler {
+            errors: Arc::new(AtomicU64::new(0)),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn reject(&mut self, line: usize, record: &str, cause: ErrorCode) -> Result<()> {
+        let errors = self.errors.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(rejected) = &self.rejected {
+            rejected.push(RejectedRecord {
+                file: self.file_name.clone(),
+                line,
+                record: record.to_string(),
+                error: cause.message(),
+            });
+        }
+
+        match errors > self.max_errors {
+            true => Err(cause),
+            false => Ok(()),
+        }
+    }
+}
+
+// Finish the rows deserialized into the packs, the values of a partially deserialized
+// record after the last row are dropped.
+pub(crate) fn finish_rows(
+    schema: &DataSchemaRef,
+    packs: &mut [Box<dyn TypeDeserializer>],
+    rows: usize,
+) -> DataBlock {
+    let columns = packs
+        .iter_mut()
+        .map(|deser| {
+            let column = deser.finish_to_column();
+            match column.len() > rows {
+                true => column.slice(0, rows),
+                false => column,
+            }
+        })
+        .collect::<Vec<_>>();
+    DataBlock::create(schema.clone(), columns)
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::merge_types;
+use serde_json::Value;
+
+// The maximum records sampled from a file to infer its schema.
+pub const INFER_SCHEMA_MAX_RECORDS: usize = 1000;
+
+/// Collects the types of the sampled values by column name and merges them into a schema.
+/// The types of a column are merged by `merge_types`, the ones that can't be merged fall back to String.
+/// The columns which are missing or NULL in some of the samples are nullable.
+#[derive(Debug, Default, Clone)]
+pub struct SchemaInference {
+    // The column name, the merged type and the number of samples it has a value in.
+    columns: Vec<(String, DataTypePtr, usize)>,
+    samples: usize,
+}
+
+impl SchemaInference {
+    pub fn create() -> Self {
+        Self::default()
+    }
+
+    pub fn add_value(&mut self, name: &str, data_type: DataTypePtr) {
+        match self.columns.iter_mut().find(|(v, _, _)| v == name) {
+            Some((_, merged, count)) => {
+                *merged = merge_inferred_types(merged, &data_type);
+                *count += 1;
+            }
+            None => self.columns.push((name.to_string(), data_type, 1)),
+        }
+    }
+
+    // Finish one sample, a record of a file or the schema of a file.
+    pub fn finish_sample(&mut self) {
+        self.samples += 1;
+    }
+
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    // The position of the column in the inferred schema.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|(v, _, _)| v == name)
+    }
+
+    pub fn finish(&self) -> DataSchemaRef {
+        let fields = self
+            .columns
+            .iter()
+            .map(|(name, data_type, count)| {
+                let data_type = match data_type.data_type_id() {
+                    // All the values are NULL.
+                    TypeID::Null => wrap_nullable(&StringType::arc()),
+                    _ if *count < self.samples => wrap_nullable(data_type),
+                    _ => data_type.clone(),
+                };
+                DataField::new(name, data_type)
+            })
+            .collect::<Vec<_>>();
+
+        DataSchemaRefExt::create(fields)
+    }
+}
+
+pub fn merge_inferred_types(lhs: &DataTypePtr, rhs: &DataTypePtr) -> DataTypePtr {
+    let nullable = lhs.is_nullable() || rhs.is_nullable();
+    let (lhs_id, rhs_id) = (
+        remove_nullable(lhs).data_type_id(),
+        remove_nullable(rhs).data_type_id(),
+    );
+
+    let merged = match (lhs_id, rhs_id) {
+        // A column of dates and timestamps is a timestamp column.
+        (TypeID::Date32, TypeID::DateTime64) => Ok(remove_nullable(rhs)),
+        (TypeID::DateTime64, TypeID::Date32) => Ok(remove_nullable(lhs)),
+        _ => merge_types(lhs, rhs),
+    };
+
+    let merged = merged.unwrap_or_else(|_| StringType::arc());
+    match nullable {
+        true => wrap_nullable(&merged),
+        false => merged,
+    }
+}
+
+// Infer the type of a text value by the deserializers of the load path, the first type
+// which can load the value is taken, String if none of them can.
+pub fn infer_text_type(value: &[u8]) -> DataTypePtr {
+    if value.is_empty() {
+        return NullType::arc();
+    }
+
+    let candidates = [
+        BooleanType::arc(),
+        Int64Type::arc(),
+        Float64Type::arc(),
+        Date32Type::arc(),
+        DateTime64Type::arc(6, None),
+    ];
+
+    for data_type in candidates {
+        let mut deserializer = data_type.create_deserializer(1);
+        if deserializer.de_whole_text(value).is_ok() {
+            return data_type;
+        }
+    }
+
+    StringType::arc()
+}
+
+pub fn infer_json_type(value: &Value) -> DataTypePtr {
+    match value {
+        Value::Null => NullType::arc(),
+        Value::Bool(_) => BooleanType::arc(),
+        Value::Number(v) if v.is_i64() => Int64Type::arc(),
+        Value::Number(v) if v.is_u64() => UInt64Type::arc(),
+        Value::Number(_) => Float64Type::arc(),
+        Value::String(_) => StringType::arc(),
+        Value::Array(_) | Value::Object(_) => VariantType::arc(),
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sources::avro::AvroReader;
+use crate::sources::avro::AvroValue;
+use crate::sources::logical_value::date_value;
+use crate::sources::logical_value::decimal_value;
+use crate::sources::logical_value::integer_value;
+use crate::sources::logical_value::timestamp_value;
+use crate::Source;
+
+#[derive(Debug, Clone)]
+pub struct AvroSourceBuilder {
+    schema: DataSchemaRef,
+    block_size: usize,
+    size_limit: usize,
+}
+
+impl AvroSourceBuilder {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        AvroSourceBuilder {
+            schema,
+            block_size: 10000,
+            size_limit: usize::MAX,
+        }
+    }
+
+    pub fn block_size(&mut self, block_size: usize) -> &mut Self {
+        self.block_size = block_size;
+        self
+    }
+
+    pub fn size_limit(&mut self, size_limit: usize) -> &mut Self {
+        self.size_limit = size_limit;
+        self
+    }
+
+    pub fn build(&self, data: Vec<u8>) -> Result<AvroSource> {
+        AvroSource::try_create(self.clone(), data)
+    }
+}
+
+pub struct AvroSource {
+    builder: AvroSourceBuilder,
+    reader: AvroReader,
+    // The Avro field index of each schema field, None if the field is missing in the file.
+    field_indexes: Vec<Option<usize>>,
+    rows: usize,
+}
+
+impl AvroSource {
+    fn try_create(builder: AvroSourceBuilder, data: Vec<u8>) -> Result<Self> {
+        let reader = AvroReader::try_create(data)?;
+
+        // The columns are matched by name, the missing columns are filled with
+        // NULL if nullable, otherwise the default value of the type.
+        let field_indexes = builder
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                reader
+                    .fields()
+                    .iter()
+                    .position(|v| v.name.eq_ignore_ascii_case(field.name()))
+            })
+            .collect();
+
+        Ok(AvroSource {
+            builder,
+            reader,
+            field_indexes,
+            rows: 0,
+        })
+    }
+}
+
+#[async_trait]
+impl Source for AvroSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        // Check size_limit.
+        if self.rows >= self.builder.size_limit {
+            return Ok(None);
+        }
+
+        let fields = self.builder.schema.fields();
+        let mut packs = fields
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.builder.block_size))
+            .collect::<Vec<_>>();
+
+        let mut rows = 0;
+        while let Some(mut record) = self.reader.next_record()? {
+            for ((field, index), deser) in fields
+                .iter()
+                .zip(self.field_indexes.iter())
+                .zip(packs.iter_mut())
+            {
+                let value = match index {
+                    Some(index) => std::mem::replace(&mut record[*index], AvroValue::Null),
+                    None => AvroValue::Null,
+                };
+
+                if value == AvroValue::Null {
+                    if !deser.de_null() {
+                        deser.de_default();
+                    }
+                    continue;
+                }
+
+                let data_type = field.data_type();
+                deser
+                    .append_data_value(avro_value(value, data_type))
+                    .map_err(|e| {
+                        ErrorCode::AvroError(format!(
+                            "Cannot load the Avro field '{}' as {} at row {}: {}",
+                            field.name(),
+                            data_type.name(),
+                            self.rows,
+                            e.message()
+                        ))
+                    })?;
+            }
+
+            rows += 1;
+            self.rows += 1;
+
+            // Check size_limit.
+            if self.rows >= self.builder.size_limit {
+                break;
+            }
+
+            // Check block_size.
+            if rows >= self.builder.block_size {
+                break;
+            }
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = packs
+            .iter_mut()
+            .map(|deser| deser.finish_to_column())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create(self.builder.schema.clone(), series)))
+    }
+}
+
+// Convert an Avro value to the data value of the target type.
+fn avro_value(value: AvroValue, data_type: &DataTypePtr) -> DataValue {
+    match value {
+        AvroValue::Null => DataValue::Null,
+        AvroValue::Boolean(v) => DataValue::Boolean(v),
+        AvroValue::Int(v) => integer_value(v, data_type),
+        AvroValue::Float(v) => DataValue::Float64(v),
+        AvroValue::Bytes(v) => DataValue::String(v),
+        AvroValue::Date(v) => date_value(v, data_type),
+        AvroValue::Timestamp(v) => timestamp_value(v, data_type),
+        AvroValue::Decimal(v, scale) => decimal_value(v, scale, data_type),
+        AvroValue::Json(v) => DataValue::Json(v),
+    }
+}
@@ -12,30 +12,63 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::NullType;
+use common_datavalues::TypeDeserializer;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_io::prelude::FormatSettings;
 use csv_async::AsyncReader;
 use csv_async::AsyncReaderBuilder;
+use csv_async::ByteRecord;
+use csv_async::ErrorKind;
 use csv_async::Terminator;
+use csv_async::Trim;
 use futures::stream::StreamExt;
 use futures::AsyncRead;
+use futures::AsyncReadExt;
 
+use crate::infer_text_type;
+use crate::sources::rejected_records::finish_rows;
+use crate::sources::rejected_records::RecordErrorHandler;
+use crate::RejectedRecords;
+use crate::SchemaInference;
 use crate::Source;
 
+// The default size in bytes of the chunks parsed in parallel.
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
 #[derive(Debug, Clone)]
 pub struct CsvSourceBuilder {
     schema: DataSchemaRef,
-    skip_header: bool,
+    // Number of records at the start of the file to skip.
+    skip_header: usize,
     empty_as_default: bool,
+    error_as_null: bool,
     block_size: usize,
     size_limit: usize,
     field_delimiter: u8,
     record_delimiter: Terminator,
+    quote: u8,
+    escape: Option<u8>,
+    // The field values loaded as NULL, or as the default value if the column is not nullable.
+    null_if: Vec<Vec<u8>>,
+    // Whether to trim the whitespaces around the fields.
+    trim: bool,
+    on_error: RecordErrorHandler,
+    // Number of the records before the data, for the line numbers of the rejected records.
+    first_line: usize,
+    // Number of the chunks parsed at the same time by the parallel source.
+    parallelism: usize,
+    // Size in bytes of the chunks of the parallel source, a chunk is cut at the end of a record.
+    chunk_size: usize,
 }
 
 impl CsvSourceBuilder {
@@ -56,7 +89,8 @@ impl CsvSourceBuilder {
         };
 
         let empty_as_default = format_settings.empty_as_default;
-        let skip_header = format_settings.skip_header;
+        let error_as_null = format_settings.error_as_null;
+        let skip_header = format_settings.skip_header as usize;
 
         CsvSourceBuilder {
             schema,
@@ -64,8 +98,17 @@ impl CsvSourceBuilder {
             field_delimiter,
             record_delimiter,
             empty_as_default,
+            error_as_null,
             block_size: 10000,
             size_limit: usize::MAX,
+            quote: b'"',
+            escape: None,
+            null_if: vec![],
+            trim: false,
+            on_error: RecordErrorHandler::default(),
+            first_line: 0,
+            parallelism: 1,
+            chunk_size: DEFAULT_CHUNK_SIZE,
         }
     }
 
@@ -79,8 +122,8 @@ impl CsvSourceBuilder {
         self
     }
 
-    // Whether to skip the header
-    pub fn skip_header(&mut self, skip_header: bool) -> &mut Self {
+    // Number of records at the start of the file to skip.
+    pub fn skip_header(&mut self, skip_header: usize) -> &mut Self {
         self.skip_header = skip_header;
         self
     }
@@ -113,9 +156,141 @@ impl CsvSourceBuilder {
         self
     }
 
+    // The quote character, default '"'.
+    pub fn quote(&mut self, quote_str: &str) -> &mut Self {
+        if !quote_str.is_empty() {
+            self.quote = quote_str.as_bytes()[0];
+        }
+        self
+    }
+
+    // The escape character of the quoted fields, the quote is escaped by doubling it if not set.
+    pub fn escape(&mut self, escape_str: &str) -> &mut Self {
+        if !escape_str.is_empty() {
+            self.escape = Some(escape_str.as_bytes()[0]);
+        }
+        self
+    }
+
+    pub fn null_if(&mut self, null_if: &[String]) -> &mut Self {
+        self.null_if = null_if.iter().map(|v| v.as_bytes().to_vec()).collect();
+        self
+    }
+
+    pub fn trim(&mut self, trim: bool) -> &mut Self {
+        self.trim = trim;
+        self
+    }
+
+    // The number of the malformed records that are rejected and skipped, the read fails on the next one.
+    pub fn max_errors(&mut self, max_errors: u64) -> &mut Self {
+        self.on_error.max_errors = max_errors;
+        self
+    }
+
+    // Collect the rejected records of the file into `rejected`.
+    pub fn rejected_records(&mut self, file_name: &str, rejected: RejectedRecords) -> &mut Self {
+        self.on_error.file_name = file_name.to_string();
+        self.on_error.rejected = Some(rejected);
+        self
+    }
+
+    // Number of the chunks parsed at the same time by the parallel source, default 1.
+    pub fn parallelism(&mut self, parallelism: usize) -> &mut Self {
+        self.parallelism = std::cmp::max(1, parallelism);
+        self
+    }
+
+    pub fn chunk_size(&mut self, chunk_size: usize) -> &mut Self {
+        self.chunk_size = std::cmp::max(1, chunk_size);
+        self
+    }
+
     pub fn build<R>(&self, reader: R) -> Result<CsvSource<R>>
     where R: AsyncRead + Unpin + Send {
-        CsvSource::try_create(self.clone(), reader)
+        CsvSource::try_create(self.clone(), reader, self.on_error.new_count())
+    }
+
+    // The data is split into chunks of whole records, which are parsed concurrently.
+    // The blocks are returned in the order of the data.
+    pub fn build_parallel<R>(&self, reader: R) -> Result<ParallelCsvSource<R>>
+    where R: AsyncRead + Unpin + Send {
+        Ok(ParallelCsvSource::create(self.clone(), reader))
+    }
+
+    // Infer the schema from the first records. The column names are taken from the first header
+    // record if the header is skipped, otherwise they are c1, c2, ...
+    pub async fn infer_schema<R>(&self, reader: R, max_records: usize) -> Result<DataSchemaRef>
+    where R: AsyncRead + Unpin + Send {
+        let mut reader = self.reader_builder().create_reader(reader);
+        let mut records = reader.byte_records();
+
+        let mut names: Vec<String> = vec![];
+        let mut skipped = 0;
+        let mut inference = SchemaInference::create();
+        while let Some(record) = records.next().await {
+            let record = record.map_err_to_code(ErrorCode::BadBytes, || {
+                format!("Parse csv error at line {}", skipped + inference.samples())
+            })?;
+
+            if skipped < self.skip_header {
+                if skipped == 0 {
+                    names = record
+                        .iter()
+                        .map(|v| String::from_utf8_lossy(v).trim().to_string())
+                        .collect();
+                }
+                skipped += 1;
+                continue;
+            }
+
+            for (col, value) in record.iter().enumerate() {
+                let name = match names.get(col) {
+                    Some(name) if !name.is_empty() => name.clone(),
+                    _ => format!("c{}", col + 1),
+                };
+                let data_type = match self.null_if.iter().any(|v| v == value) {
+                    true => NullType::arc(),
+                    false => infer_text_type(value),
+                };
+                inference.add_value(&name, data_type);
+            }
+            inference.finish_sample();
+
+            if inference.samples() >= max_records {
+                break;
+            }
+        }
+
+        Ok(inference.finish())
+    }
+
+    fn reader_builder(&self) -> AsyncReaderBuilder {
+        // The header records are skipped when reading, so any number of them can be skipped.
+        let mut builder = AsyncReaderBuilder::new();
+        builder
+            .has_headers(false)
+            .delimiter(self.field_delimiter)
+            .terminator(self.record_delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .trim(if self.trim { Trim::Fields } else { Trim::None });
+        builder
+    }
+
+    fn create_packs(&self) -> Vec<Box<dyn TypeDeserializer>> {
+        self.schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect()
+    }
+
+    // The record as it is in the file, for the rejected records.
+    fn record_text(&self, record: &ByteRecord) -> String {
+        let delimiter = [self.field_delimiter];
+        let fields = record.iter().collect::<Vec<_>>();
+        String::from_utf8_lossy(&fields.join(&delimiter[..])).to_string()
     }
 }
 
@@ -123,24 +298,59 @@ pub struct CsvSource<R> {
     builder: CsvSourceBuilder,
     reader: AsyncReader<R>,
     rows: usize,
+    skipped: usize,
+    // Number of the records read, the headers and the rejected records included.
+    lines: usize,
+    on_error: RecordErrorHandler,
 }
 
 impl<R> CsvSource<R>
 where R: AsyncRead + Unpin + Send
 {
-    fn try_create(builder: CsvSourceBuilder, reader: R) -> Result<Self> {
-        let reader = AsyncReaderBuilder::new()
-            .has_headers(builder.skip_header)
-            .delimiter(builder.field_delimiter)
-            .terminator(builder.record_delimiter)
-            .create_reader(reader);
+    fn try_create(
+        builder: CsvSourceBuilder,
+        reader: R,
+        on_error: RecordErrorHandler,
+    ) -> Result<Self> {
+        let reader = builder.reader_builder().create_reader(reader);
+        let lines = builder.first_line;
 
         Ok(Self {
             builder,
             reader,
             rows: 0,
+            skipped: 0,
+            lines,
+            on_error,
         })
     }
+
+    fn deserialize(
+        &self,
+        packs: &mut [Box<dyn TypeDeserializer>],
+        record: &ByteRecord,
+    ) -> Result<()> {
+        for (col, pack) in packs.iter_mut().enumerate() {
+            match record.get(col) {
+                Some(bytes) => {
+                    if self.builder.null_if.iter().any(|v| v == bytes) {
+                        pack.de_default();
+                    } else if bytes.is_empty() && self.builder.empty_as_default {
+                        pack.de_default();
+                    } else if let Err(cause) = pack.de_whole_text(bytes) {
+                        // The failed value is not appended, the nullable columns get a NULL
+                        // instead and the others get the default value.
+                        if !self.builder.error_as_null {
+                            return Err(cause);
+                        }
+                        pack.de_default();
+                    }
+                }
+                None => pack.de_default(),
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -153,37 +363,61 @@ where R: AsyncRead + Unpin + Send
             return Ok(None);
         }
 
-        let mut packs = self
-            .builder
-            .schema
-            .fields()
-            .iter()
-            .map(|f| f.data_type().create_deserializer(self.builder.block_size))
-            .collect::<Vec<_>>();
-
+        let mut packs = self.builder.create_packs();
+        let mut blocks = vec![];
+        let mut pack_rows = 0;
         let mut rows = 0;
-        let mut records = self.reader.byte_records();
 
-        while let Some(record) = records.next().await {
-            let record = record.map_err_to_code(ErrorCode::BadBytes, || {
-                format!("Parse csv error at line {}", self.rows)
-            })?;
+        loop {
+            let record = match self.reader.byte_records().next().await {
+                Some(record) => record,
+                None => break,
+            };
+            self.lines += 1;
+
+            let record = match record {
+                Ok(record) => record,
+                // The records with a wrong number of fields can be skipped, the reader is still
+                // at the start of the next record.
+                Err(cause) if matches!(cause.kind(), ErrorKind::UnequalLengths { .. }) => {
+                    let cause = ErrorCode::BadBytes(format!(
+                        "Parse csv error at line {}, cause: {}",
+                        self.rows, cause
+                    ));
+                    self.on_error.reject(self.lines, "", cause)?;
+                    continue;
+                }
+                Err(cause) => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Parse csv error at line {}, cause: {}",
+                        self.rows, cause
+                    )));
+                }
+            };
 
             if record.is_empty() {
                 break;
             }
-            for (col, pack) in packs.iter_mut().enumerate() {
-                match record.get(col) {
-                    Some(bytes) => {
-                        if bytes.is_empty() && self.builder.empty_as_default {
-                            pack.de_default();
-                        } else {
-                            pack.de_whole_text(bytes)?
-                        }
-                    }
-                    None => pack.de_default(),
-                }
+
+            // Skip the header records.
+            if self.skipped < self.builder.skip_header {
+                self.skipped += 1;
+                continue;
+            }
+
+            if let Err(cause) = self.deserialize(&mut packs, &record) {
+                let record_text = self.builder.record_text(&record);
+                self.on_error.reject(self.lines, &record_text, cause)?;
+
+                // Some values of the rejected record may have been appended, they are dropped
+                // and the next rows go to new packs.
+                blocks.push(finish_rows(&self.builder.schema, &mut packs, pack_rows));
+                packs = self.builder.create_packs();
+                pack_rows = 0;
+                continue;
             }
+
+            pack_rows += 1;
             rows += 1;
             self.rows += 1;
 
@@ -202,11 +436,285 @@ where R: AsyncRead + Unpin + Send
             return Ok(None);
         }
 
-        let series = packs
-            .iter_mut()
-            .map(|deser| deser.finish_to_column())
-            .collect::<Vec<_>>();
+        blocks.push(finish_rows(&self.builder.schema, &mut packs, pack_rows));
+        match blocks.len() {
+            1 => Ok(blocks.pop()),
+            _ => Ok(Some(DataBlock::concat_blocks(&blocks)?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SplitState {
+    FieldStart,
+    InField,
+    InQuoted,
+    InQuotedEscape,
+    QuoteInQuoted,
+}
+
+/// A range of whole records of the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvChunk {
+    pub range: Range<usize>,
+    /// Number of the non-empty records of the chunk.
+    pub records: usize,
+}
+
+impl CsvSourceBuilder {
+    fn is_terminator(&self, b: u8) -> bool {
+        match self.record_delimiter {
+            Terminator::CRLF => b == b'\n' || b == b'\r',
+            Terminator::Any(t) => b == t,
+            _ => false,
+        }
+    }
+
+    /// Split the data into chunks of whole records, of at least `chunk_size` bytes except the last.
+    /// The quotes are tracked like the csv reader does, so a record delimiter in a quoted field
+    /// never ends a chunk. The first chunk has at least `min_records` records, for the headers.
+    ///
+    /// If not `eof`, the data after the last record delimiter is not in any chunk, it's the start
+    /// of a record which ends in the next data.
+    pub fn split_chunks(
+        &self,
+        data: &[u8],
+        chunk_size: usize,
+        min_records: usize,
+        eof: bool,
+    ) -> Vec<CsvChunk> {
+        let mut chunks = vec![];
+        let mut state = SplitState::FieldStart;
+        let mut start = 0;
+        let mut records = 0;
+        let mut record_end = 0;
+        let mut has_data = false;
+
+        for (i, b) in data.iter().enumerate() {
+            let b = *b;
+            let end_of_record = match state {
+                SplitState::InQuoted => {
+                    if Some(b) == self.escape {
+                        state = SplitState::InQuotedEscape;
+                    } else if b == self.quote {
+                        state = SplitState::QuoteInQuoted;
+                    }
+                    false
+                }
+                SplitState::InQuotedEscape => {
+                    state = SplitState::InQuoted;
+                    false
+                }
+                SplitState::FieldStart | SplitState::InField | SplitState::QuoteInQuoted => {
+                    if self.is_terminator(b) {
+                        state = SplitState::FieldStart;
+                        true
+                    } else if b == self.field_delimiter {
+                        state = SplitState::FieldStart;
+                        false
+                    } else if b == self.quote && state != SplitState::InField {
+                        // A quote at the start of a field, or a doubled quote in a quoted field.
+                        state = SplitState::InQuoted;
+                        false
+                    } else {
+                        state = SplitState::InField;
+                        false
+                    }
+                }
+            };
+
+            if !end_of_record {
+                has_data = true;
+                continue;
+            }
+
+            // The empty lines are not records.
+            if has_data {
+                records += 1;
+                has_data = false;
+            }
+            record_end = i + 1;
+
+            let enough_records = !chunks.is_empty() || records >= min_records;
+            if record_end - start >= chunk_size && enough_records {
+                chunks.push(CsvChunk {
+                    range: start..record_end,
+                    records,
+                });
+                start = record_end;
+                records = 0;
+            }
+        }
+
+        if eof {
+            if start < data.len() {
+                chunks.push(CsvChunk {
+                    range: start..data.len(),
+                    records: records + has_data as usize,
+                });
+            }
+        } else if start < record_end {
+            chunks.push(CsvChunk {
+                range: start..record_end,
+                records,
+            });
+        }
+        chunks
+    }
+}
+
+// The bytes of a chunk, shared by the chunks of the data.
+struct ChunkData {
+    data: Arc<Vec<u8>>,
+    range: Range<usize>,
+}
+
+impl AsRef<[u8]> for ChunkData {
+    fn as_ref(&self) -> &[u8] {
+        &self.data[self.range.clone()]
+    }
+}
+
+/// Reads the data in windows of `parallelism` chunks, the chunks of a window are parsed
+/// concurrently by csv sources on the runtime.
+pub struct ParallelCsvSource<R> {
+    builder: CsvSourceBuilder,
+    reader: R,
+    // The data read but not parsed yet, starting at a record.
+    buffer: Vec<u8>,
+    eof: bool,
+    // Whether the first chunk, which skips the headers, is parsed.
+    started: bool,
+    // Number of the records before the buffer, the headers included.
+    lines: usize,
+    rows: usize,
+    blocks: VecDeque<DataBlock>,
+    on_error: RecordErrorHandler,
+}
 
-        Ok(Some(DataBlock::create(self.builder.schema.clone(), series)))
+impl<R> ParallelCsvSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    fn create(builder: CsvSourceBuilder, reader: R) -> Self {
+        let on_error = builder.on_error.new_count();
+        ParallelCsvSource {
+            builder,
+            reader,
+            buffer: vec![],
+            eof: false,
+            started: false,
+            lines: 0,
+            rows: 0,
+            blocks: VecDeque::new(),
+            on_error,
+        }
+    }
+
+    async fn fill_buffer(&mut self, size: usize) -> Result<()> {
+        let mut buf = vec![0; 64 * 1024];
+        while !self.eof && self.buffer.len() < size {
+            let n = self.reader.read(&mut buf).await?;
+            match n {
+                0 => self.eof = true,
+                _ => self.buffer.extend_from_slice(&buf[..n]),
+            }
+        }
+        Ok(())
+    }
+
+    async fn parse_window(&mut self) -> Result<()> {
+        let chunk_size = self.builder.chunk_size;
+        let window = chunk_size * self.builder.parallelism;
+        // The headers are skipped by the first chunk of the data.
+        let min_records = match self.started {
+            false => self.builder.skip_header,
+            true => 0,
+        };
+
+        // A record larger than the window is read as a whole.
+        let mut size = window;
+        let chunks = loop {
+            self.fill_buffer(size).await?;
+            let chunks = self
+                .builder
+                .split_chunks(&self.buffer, chunk_size, min_records, self.eof);
+            if !chunks.is_empty() || self.eof {
+                break chunks;
+            }
+            size += window;
+        };
+
+        let consumed = chunks
+            .last()
+            .map(|c| c.range.end)
+            .unwrap_or(self.buffer.len());
+        let data = Arc::new(self.buffer.drain(..consumed).collect::<Vec<_>>());
+
+        let mut handles = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let mut builder = self.builder.clone();
+            builder.first_line = self.lines;
+            if self.started {
+                builder.skip_header = 0;
+            }
+            self.started = true;
+            self.lines += chunk.records;
+
+            let on_error = self.on_error.clone();
+            let reader = futures::io::Cursor::new(ChunkData {
+                data: data.clone(),
+                range: chunk.range,
+            });
+            handles.push(common_base::tokio::spawn(async move {
+                let mut source = CsvSource::try_create(builder, reader, on_error)?;
+                let mut blocks = vec![];
+                while let Some(block) = source.read().await? {
+                    blocks.push(block);
+                }
+                Result::Ok(blocks)
+            }));
+        }
+
+        for handle in handles {
+            match handle.await {
+                Ok(Ok(blocks)) => self.blocks.extend(blocks),
+                Ok(Err(cause)) => return Err(cause),
+                Err(cause) => {
+                    return Err(ErrorCode::TokioError(format!(
+                        "Cannot join future {:?}",
+                        cause
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R> Source for ParallelCsvSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            // Check size_limit, the chunks are parsed up to the limit each.
+            let remaining = self.builder.size_limit.saturating_sub(self.rows);
+            if remaining == 0 {
+                return Ok(None);
+            }
+
+            if let Some(mut block) = self.blocks.pop_front() {
+                if block.num_rows() > remaining {
+                    block = block.slice(0, remaining);
+                }
+                self.rows += block.num_rows();
+                return Ok(Some(block));
+            }
+
+            if self.eof && self.buffer.is_empty() {
+                return Ok(None);
+            }
+            self.parse_window().await?;
+        }
     }
 }
@@ -18,11 +18,22 @@ use async_trait::async_trait;
 use common_base::tokio::io::AsyncBufRead;
 use common_base::tokio::io::AsyncBufReadExt;
 use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataTypePtr;
+use common_datavalues::TypeDeserializer;
+use common_datavalues::TypeID;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use common_io::prelude::FormatSettings;
+use serde_json::Value;
 
+use crate::infer_json_type;
+use crate::sources::rejected_records::finish_rows;
+use crate::sources::rejected_records::RecordErrorHandler;
+use crate::RejectedRecords;
+use crate::SchemaInference;
 use crate::Source;
 
 #[derive(Debug, Clone)]
@@ -30,14 +41,20 @@ pub struct NDJsonSourceBuilder {
     schema: DataSchemaRef,
     block_size: usize,
     size_limit: usize,
+    // Reject the values whose JSON types mismatch the column types if true,
+    // otherwise convert them to the column types.
+    strict: bool,
+    on_error: RecordErrorHandler,
 }
 
 impl NDJsonSourceBuilder {
-    pub fn create(schema: DataSchemaRef) -> Self {
+    pub fn create(schema: DataSchemaRef, format_settings: FormatSettings) -> Self {
         NDJsonSourceBuilder {
             schema,
             block_size: 10000,
             size_limit: usize::MAX,
+            strict: format_settings.json_strict,
+            on_error: RecordErrorHandler::default(),
         }
     }
 
@@ -51,30 +68,193 @@ impl NDJsonSourceBuilder {
         self
     }
 
+    // The number of the malformed lines that are rejected and skipped, the read fails on the next one.
+    pub fn max_errors(&mut self, max_errors: u64) -> &mut Self {
+        self.on_error.max_errors = max_errors;
+        self
+    }
+
+    // Collect the rejected lines of the file into `rejected`.
+    pub fn rejected_records(&mut self, file_name: &str, rejected: RejectedRecords) -> &mut Self {
+        self.on_error.file_name = file_name.to_string();
+        self.on_error.rejected = Some(rejected);
+        self
+    }
+
     pub fn build<R>(&self, reader: R) -> Result<NDJsonSource<R>>
     where R: AsyncBufRead + Unpin + Send {
         NDJsonSource::try_create(self.clone(), reader)
     }
+
+    // Infer the schema from the keys and the JSON types of the first lines.
+    pub async fn infer_schema<R>(
+        &self,
+        mut reader: R,
+        max_records: usize,
+    ) -> Result<DataSchemaRef>
+    where
+        R: AsyncBufRead + Unpin + Send,
+    {
+        let mut buffer = String::new();
+        let mut inference = SchemaInference::create();
+        while inference.samples() < max_records {
+            buffer.clear();
+            if reader
+                .read_line(&mut buffer)
+                .await
+                .map_err_to_code(ErrorCode::BadBytes, || {
+                    format!("Parse NDJson error at line {}", inference.samples())
+                })?
+                == 0
+            {
+                break;
+            }
+
+            if buffer.trim().is_empty() {
+                continue;
+            }
+
+            let json: Value = serde_json::from_reader(buffer.as_bytes())?;
+            let object = json.as_object().ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "error at row {}: the line must be a JSON object, value={}",
+                    inference.samples(),
+                    maybe_truncated(buffer.trim(), 1024),
+                ))
+            })?;
+
+            for (name, value) in object {
+                inference.add_value(name, infer_json_type(value));
+            }
+            inference.finish_sample();
+        }
+
+        Ok(inference.finish())
+    }
+
+    fn create_packs(&self) -> Vec<Box<dyn TypeDeserializer>> {
+        self.schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect()
+    }
 }
 
 pub struct NDJsonSource<R> {
     builder: NDJsonSourceBuilder,
     reader: R,
     rows: usize,
+    // Number of the lines read, the rejected lines included.
+    lines: usize,
     buffer: String,
+    on_error: RecordErrorHandler,
 }
 
 impl<R> NDJsonSource<R>
 where R: AsyncBufRead + Unpin + Send
 {
     fn try_create(builder: NDJsonSourceBuilder, reader: R) -> Result<Self> {
+        let on_error = builder.on_error.new_count();
         Ok(Self {
             builder,
             reader,
             rows: 0,
+            lines: 0,
             buffer: String::new(),
+            on_error,
         })
     }
+
+    fn deserialize(&self, packs: &mut [Box<dyn TypeDeserializer>], rows: usize) -> Result<()> {
+        let json: Value = serde_json::from_reader(self.buffer.as_bytes())?;
+        let object = json.as_object().ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "error at row {}: the line must be a JSON object, value={}",
+                rows,
+                maybe_truncated(self.buffer.trim(), 1024),
+            ))
+        })?;
+
+        for (field, deser) in self.builder.schema.fields().iter().zip(packs.iter_mut()) {
+            let (name, data_type) = (field.name(), field.data_type());
+            let value = match object.get(name) {
+                Some(Value::Null) if !self.builder.strict => None,
+                value => value,
+            };
+
+            // The missing fields are filled with NULL if nullable, otherwise the default value
+            // of the type, and so are the nulls of the not nullable columns in relaxed mode.
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    if !deser.de_null() {
+                        deser.de_default();
+                    }
+                    continue;
+                }
+            };
+
+            let value = match self.builder.strict {
+                true => Cow::Borrowed(value),
+                false => coerce_json(value, data_type),
+            };
+
+            deser.de_json(&value).map_err(|e| {
+                let value_str = format!("{:?}", value);
+                ErrorCode::BadBytes(format!(
+                    "error at row {} column {}: type={}, err={}, value={}",
+                    rows,
+                    name,
+                    data_type.name(),
+                    e.message(),
+                    maybe_truncated(&value_str, 1024),
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// Convert the scalar JSON value to the JSON type the column deserializer expects,
+// the values which can't be converted are returned as is and fail in the deserializer.
+fn coerce_json<'a>(value: &'a Value, data_type: &DataTypePtr) -> Cow<'a, Value> {
+    let type_id = remove_nullable(data_type).data_type_id();
+    let coerced = match value {
+        Value::String(v) if type_id.is_numeric() => {
+            let v = v.trim();
+            if let Ok(v) = v.parse::<i64>() {
+                Some(Value::from(v))
+            } else if let Ok(v) = v.parse::<u64>() {
+                Some(Value::from(v))
+            } else {
+                v.parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            }
+        }
+        Value::Bool(v) if type_id.is_numeric() => Some(Value::from(*v as u8)),
+        Value::String(v) if type_id == TypeID::Boolean => {
+            match v.trim().to_ascii_lowercase().as_str() {
+                "true" | "1" => Some(Value::Bool(true)),
+                "false" | "0" => Some(Value::Bool(false)),
+                _ => None,
+            }
+        }
+        Value::Number(v) if type_id == TypeID::Boolean => v.as_f64().map(|v| Value::Bool(v != 0.0)),
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_)
+            if type_id.is_string() =>
+        {
+            Some(Value::String(value.to_string()))
+        }
+        _ => None,
+    };
+
+    match coerced {
+        Some(v) => Cow::Owned(v),
+        None => Cow::Borrowed(value),
+    }
 }
 
 fn maybe_truncated(s: &str, limit: usize) -> Cow<'_, str> {
@@ -100,22 +280,9 @@ where R: AsyncBufRead + Unpin + Send
             return Ok(None);
         }
 
-        let mut packs = self
-            .builder
-            .schema
-            .fields()
-            .iter()
-            .map(|f| f.data_type().create_deserializer(self.builder.block_size))
-            .collect::<Vec<_>>();
-
-        let fields = self
-            .builder
-            .schema
-            .fields()
-            .iter()
-            .map(|f| (f.name(), f.data_type().name()))
-            .collect::<Vec<_>>();
-
+        let mut packs = self.builder.create_packs();
+        let mut blocks = vec![];
+        let mut pack_rows = 0;
         let mut rows = 0;
 
         loop {
@@ -132,28 +299,25 @@ where R: AsyncBufRead + Unpin + Send
             {
                 break;
             }
+            self.lines += 1;
 
             if self.buffer.trim().is_empty() {
                 continue;
             }
 
-            let json: serde_json::Value = serde_json::from_reader(self.buffer.as_bytes())?;
-
-            for ((name, type_name), deser) in fields.iter().zip(packs.iter_mut()) {
-                let value = &json[name];
-                deser.de_json(value).map_err(|e| {
-                    let value_str = format!("{:?}", value);
-                    ErrorCode::BadBytes(format!(
-                        "error at row {} column {}: type={}, err={}, value={}",
-                        rows,
-                        name,
-                        type_name,
-                        e.message(),
-                        maybe_truncated(&value_str, 1024),
-                    ))
-                })?;
+            if let Err(cause) = self.deserialize(&mut packs, rows) {
+                self.on_error
+                    .reject(self.lines, self.buffer.trim_end(), cause)?;
+
+                // Some values of the rejected line may have been appended, they are dropped
+                // and the next rows go to new packs.
+                blocks.push(finish_rows(&self.builder.schema, &mut packs, pack_rows));
+                packs = self.builder.create_packs();
+                pack_rows = 0;
+                continue;
             }
 
+            pack_rows += 1;
             rows += 1;
             self.rows += 1;
 
@@ -172,11 +336,10 @@ where R: AsyncBufRead + Unpin + Send
             return Ok(None);
         }
 
-        let series = packs
-            .iter_mut()
-            .map(|deser| deser.finish_to_column())
-            .collect::<Vec<_>>();
-
-        Ok(Some(DataBlock::create(self.builder.schema.clone(), series)))
+        blocks.push(finish_rows(&self.builder.schema, &mut packs, pack_rows));
+        match blocks.len() {
+            1 => Ok(blocks.pop()),
+            _ => Ok(Some(DataBlock::concat_blocks(&blocks)?)),
+        }
     }
 }
@@ -0,0 +1,204 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sources::logical_value::date_value;
+use crate::sources::logical_value::decimal_value;
+use crate::sources::logical_value::integer_value;
+use crate::sources::logical_value::timestamp_value;
+use crate::sources::orc::ColumnValues;
+use crate::sources::orc::OrcColumn;
+use crate::sources::orc::OrcFile;
+use crate::Source;
+
+#[derive(Debug, Clone)]
+pub struct OrcSourceBuilder {
+    schema: DataSchemaRef,
+    block_size: usize,
+    size_limit: usize,
+}
+
+impl OrcSourceBuilder {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        OrcSourceBuilder {
+            schema,
+            block_size: 10000,
+            size_limit: usize::MAX,
+        }
+    }
+
+    pub fn block_size(&mut self, block_size: usize) -> &mut Self {
+        self.block_size = block_size;
+        self
+    }
+
+    pub fn size_limit(&mut self, size_limit: usize) -> &mut Self {
+        self.size_limit = size_limit;
+        self
+    }
+
+    // The ORC metadata is at the end of the file, so the source works on the whole file.
+    pub fn build(&self, data: Vec<u8>) -> Result<OrcSource> {
+        OrcSource::try_create(self.clone(), data)
+    }
+}
+
+pub struct OrcSource {
+    builder: OrcSourceBuilder,
+    file: OrcFile,
+    // The ORC column id of each schema field.
+    column_ids: Vec<u32>,
+    next_stripe: usize,
+    // The columns of the current stripe and the next value index of each column.
+    columns: Vec<OrcColumn>,
+    value_indexes: Vec<usize>,
+    stripe_rows: usize,
+    stripe_row: usize,
+    rows: usize,
+}
+
+impl OrcSource {
+    fn try_create(builder: OrcSourceBuilder, data: Vec<u8>) -> Result<Self> {
+        let file = OrcFile::try_create(data)?;
+        let orc_fields = file.fields();
+
+        // The columns are matched by name, the files written by Hive without the
+        // column names (`_col0`, `_col1`, ...) are matched by position.
+        let column_ids = builder
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let by_name = orc_fields
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(field.name()));
+                let by_position = orc_fields
+                    .get(i)
+                    .filter(|(name, _)| *name == format!("_col{}", i));
+
+                match by_name.or(by_position) {
+                    Some((_, id)) => Ok(*id),
+                    None => Err(ErrorCode::OrcError(format!(
+                        "Column '{}' is not found in the ORC file",
+                        field.name()
+                    ))),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(OrcSource {
+            builder,
+            file,
+            column_ids,
+            next_stripe: 0,
+            columns: vec![],
+            value_indexes: vec![],
+            stripe_rows: 0,
+            stripe_row: 0,
+            rows: 0,
+        })
+    }
+
+    fn read_next_stripe(&mut self) -> Result<()> {
+        let stripe = self.file.read_stripe(self.next_stripe)?;
+        self.columns = self
+            .column_ids
+            .iter()
+            .map(|id| self.file.read_column(&stripe, *id))
+            .collect::<Result<Vec<_>>>()?;
+        self.value_indexes = vec![0; self.columns.len()];
+        self.stripe_rows = stripe.number_of_rows();
+        self.stripe_row = 0;
+        self.next_stripe += 1;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Source for OrcSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        // Check size_limit.
+        if self.rows >= self.builder.size_limit {
+            return Ok(None);
+        }
+
+        while self.stripe_row >= self.stripe_rows {
+            if self.next_stripe >= self.file.number_of_stripes() {
+                return Ok(None);
+            }
+            self.read_next_stripe()?;
+        }
+
+        let rows = (self.stripe_rows - self.stripe_row)
+            .min(self.builder.block_size)
+            .min(self.builder.size_limit - self.rows);
+
+        let fields = self.builder.schema.fields();
+        let mut series = Vec::with_capacity(fields.len());
+        for (i, field) in fields.iter().enumerate() {
+            let data_type = field.data_type();
+            let column = &self.columns[i];
+            let mut deser = data_type.create_deserializer(rows);
+
+            for row in self.stripe_row..self.stripe_row + rows {
+                let present = match &column.validity {
+                    Some(validity) => validity[row],
+                    None => true,
+                };
+
+                if !present {
+                    if !deser.de_null() {
+                        deser.de_default();
+                    }
+                    continue;
+                }
+
+                let value = orc_value(&column.values, self.value_indexes[i], data_type);
+                self.value_indexes[i] += 1;
+                deser.append_data_value(value).map_err(|e| {
+                    ErrorCode::OrcError(format!(
+                        "Cannot load the ORC column '{}' as {}: {}",
+                        field.name(),
+                        data_type.name(),
+                        e.message()
+                    ))
+                })?;
+            }
+            series.push(deser.finish_to_column());
+        }
+
+        self.stripe_row += rows;
+        self.rows += rows;
+        Ok(Some(DataBlock::create(self.builder.schema.clone(), series)))
+    }
+}
+
+// Convert an ORC value to the data value of the target type.
+fn orc_value(values: &ColumnValues, index: usize, data_type: &DataTypePtr) -> DataValue {
+    match values {
+        ColumnValues::Boolean(v) => DataValue::Boolean(v[index]),
+        ColumnValues::Integer(v) => integer_value(v[index], data_type),
+        ColumnValues::Float(v) => DataValue::Float64(v[index]),
+        ColumnValues::Binary(v) => DataValue::String(v[index].clone()),
+        ColumnValues::Date(v) => date_value(v[index], data_type),
+        ColumnValues::Timestamp(v) => timestamp_value(v[index], data_type),
+        ColumnValues::Decimal(v, scales) => decimal_value(v[index], scales[index], data_type),
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
 use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
@@ -39,6 +40,7 @@ pub struct ParquetSourceBuilder {
     projection: Vec<usize>,
     size_limit: usize,
     metadata: Option<FileMetaData>,
+    row_groups: Option<Vec<usize>>,
 }
 
 impl ParquetSourceBuilder {
@@ -49,6 +51,7 @@ impl ParquetSourceBuilder {
             projection: (0..size).collect(),
             size_limit: usize::MAX,
             metadata: None,
+            row_groups: None,
         }
     }
 
@@ -62,10 +65,33 @@ impl ParquetSourceBuilder {
         self
     }
 
+    // Only read the given row groups, default is all the row groups of the file.
+    pub fn row_groups(&mut self, row_groups: Vec<usize>) -> &mut Self {
+        self.row_groups = Some(row_groups);
+        self
+    }
+
     pub fn build<R>(&self, reader: R) -> Result<ParquetSource<R>>
     where R: AsyncRead + AsyncSeek + Unpin + Send {
         Ok(ParquetSource::create(self.clone(), reader))
     }
+
+    // Read the schema from the file metadata, no rows need to be sampled.
+    pub async fn infer_schema<R>(reader: &mut R) -> Result<DataSchemaRef>
+    where R: AsyncRead + AsyncSeek + Unpin + Send {
+        let metadata = read_metadata_async(reader)
+            .await
+            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        let arrow_schema =
+            infer_schema(&metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+
+        let fields = arrow_schema
+            .fields
+            .iter()
+            .map(DataField::from)
+            .collect::<Vec<_>>();
+        Ok(DataSchemaRefExt::create(fields))
+    }
 }
 
 pub struct ParquetSource<R> {
@@ -115,13 +141,21 @@ where R: AsyncRead + AsyncSeek + Unpin + Send
             }
         };
 
-        if self.current_row_group >= metadata.row_groups.len() {
+        let row_group_index = match &self.builder.row_groups {
+            None => self.current_row_group,
+            Some(row_groups) => match row_groups.get(self.current_row_group) {
+                None => return Ok(None),
+                Some(index) => *index,
+            },
+        };
+
+        if row_group_index >= metadata.row_groups.len() {
             return Ok(None);
         }
 
         let fields = &self.arrow_table_schema.fields;
 
-        let row_group = &metadata.row_groups[self.current_row_group];
+        let row_group = &metadata.row_groups[row_group_index];
         let fields_to_read: Vec<&Field> = self
             .builder
             .projection
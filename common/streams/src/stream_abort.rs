@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::RwLock;
 use futures::stream::AbortHandle;
 use futures::stream::Abortable;
 use futures::Stream;
@@ -28,15 +30,25 @@ use crate::SendableDataBlockStream;
 pin_project! {
     pub struct AbortStream {
         #[pin]
-        input: Abortable<SendableDataBlockStream>
+        input: Abortable<SendableDataBlockStream>,
+        reason: Arc<RwLock<Option<ErrorCode>>>,
     }
 }
 
 impl AbortStream {
     pub fn try_create(input: SendableDataBlockStream) -> Result<(AbortHandle, Self)> {
+        Self::try_create_with_reason(input, Arc::new(RwLock::new(None)))
+    }
+
+    /// The stream returns the error of the reason if it is set when aborted, e.g. the timeout.
+    pub fn try_create_with_reason(
+        input: SendableDataBlockStream,
+        reason: Arc<RwLock<Option<ErrorCode>>>,
+    ) -> Result<(AbortHandle, Self)> {
         let (handle, reg) = AbortHandle::new_pair();
         Ok((handle, Self {
             input: Abortable::new(input, reg),
+            reason,
         }))
     }
 }
@@ -54,9 +66,12 @@ impl Stream for AbortStream {
         match this.input.poll_next(ctx) {
             Poll::Ready(None) => match is_aborted {
                 false => Poll::Ready(None),
-                true => Poll::Ready(Some(Err(ErrorCode::AbortedQuery(
-                    "Aborted query, because the server is shutting down or the query was killed",
-                )))),
+                true => match &*this.reason.read() {
+                    Some(reason) => Poll::Ready(Some(Err(reason.clone()))),
+                    None => Poll::Ready(Some(Err(ErrorCode::AbortedQuery(
+                        "Aborted query, because the server is shutting down or the query was killed",
+                    )))),
+                },
             },
             other => other,
         }
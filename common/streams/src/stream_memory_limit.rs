@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_base::RuntimeTracker;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::SendableDataBlockStream;
+
+pin_project! {
+    /// Fail the stream once an allocation tracked by the runtime of the query exceeded the limit
+    /// of its memory tracker, see `MemoryTracker::set_limit`.
+    pub struct MemoryLimitStream {
+        #[pin]
+        input: SendableDataBlockStream,
+        tracker: Arc<RuntimeTracker>,
+    }
+}
+
+impl MemoryLimitStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        tracker: Arc<RuntimeTracker>,
+    ) -> Result<Self> {
+        Ok(Self { input, tracker })
+    }
+}
+
+impl Stream for MemoryLimitStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match this.input.poll_next(ctx) {
+            Poll::Ready(Some(Ok(block))) => match this.tracker.get_memory_tracker().check_limit() {
+                Ok(_) => Poll::Ready(Some(Ok(block))),
+                Err(cause) => Poll::Ready(Some(Err(cause))),
+            },
+            other => other,
+        }
+    }
+}
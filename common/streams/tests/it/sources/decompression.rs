@@ -0,0 +1,135 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_io::prelude::Compression;
+use common_streams::compress;
+use common_streams::decompress;
+use common_streams::decompress_file;
+use common_streams::decompress_reader;
+use futures::AsyncReadExt;
+
+const DATA: &[u8] = b"1,a\n2,b\n3,c\n";
+
+fn compressed(compression: &Compression) -> Vec<u8> {
+    match compression {
+        Compression::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+            encoder.write_all(DATA).unwrap();
+            encoder.finish().unwrap()
+        }
+        Compression::Bz2 => {
+            let mut encoder = bzip2::write::BzEncoder::new(vec![], bzip2::Compression::default());
+            encoder.write_all(DATA).unwrap();
+            encoder.finish().unwrap()
+        }
+        Compression::Zstd => zstd::stream::encode_all(DATA, 0).unwrap(),
+        Compression::Xz => {
+            let mut encoder = xz2::write::XzEncoder::new(vec![], 6);
+            encoder.write_all(DATA).unwrap();
+            encoder.finish().unwrap()
+        }
+        _ => DATA.to_vec(),
+    }
+}
+
+#[test]
+fn test_compress() -> Result<()> {
+    for compression in [
+        Compression::None,
+        Compression::Gzip,
+        Compression::Deflate,
+        Compression::RawDeflate,
+        Compression::Zstd,
+        Compression::Brotli,
+    ] {
+        let bytes = compress(&compression, DATA)?;
+        let output = decompress(&compression, bytes)?;
+        assert_eq!(output, DATA, "{:?}", compression);
+    }
+
+    assert!(compress(&Compression::Lzo, DATA).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decompress_file() -> Result<()> {
+    for compression in [
+        Compression::Gzip,
+        Compression::Bz2,
+        Compression::Zstd,
+        Compression::Xz,
+    ] {
+        let bytes = compressed(&compression);
+
+        // Detected by the magic bytes.
+        let output = decompress_file(&Compression::Auto, "data", bytes.clone())?;
+        assert_eq!(output, DATA, "{:?}", compression);
+
+        // Given explicitly.
+        let output = decompress_file(&compression, "data.csv", bytes.clone())?;
+        assert_eq!(output, DATA, "{:?}", compression);
+
+        // NONE overrides the detection.
+        let output = decompress_file(&Compression::None, "data.csv.gz", bytes.clone())?;
+        assert_eq!(output, bytes, "{:?}", compression);
+    }
+
+    // The concatenated gzip members are all read.
+    let mut bytes = compressed(&Compression::Gzip);
+    bytes.extend(compressed(&Compression::Gzip));
+    let output = decompress_file(&Compression::Auto, "data.csv.gz", bytes)?;
+    assert_eq!(output, [DATA, DATA].concat());
+
+    // The uncompressed data is returned as it is.
+    let output = decompress_file(&Compression::Auto, "data.csv", DATA.to_vec())?;
+    assert_eq!(output, DATA);
+
+    // The file extension is trusted.
+    let result = decompress_file(&Compression::Auto, "data.csv.gz", DATA.to_vec());
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_decompress_reader() -> Result<()> {
+    for (compression, path) in [
+        (Compression::Gzip, "data.csv.gz"),
+        (Compression::Zstd, "data"),
+        (Compression::None, "data.csv"),
+        // Shorter than the magic bytes.
+        (Compression::None, "x"),
+    ] {
+        let bytes = match path {
+            "x" => b"1".to_vec(),
+            _ => compressed(&compression),
+        };
+        let expect = match path {
+            "x" => b"1".to_vec(),
+            _ => DATA.to_vec(),
+        };
+
+        let reader = futures::io::Cursor::new(bytes);
+        let mut reader = decompress_reader(&Compression::Auto, path, reader).await?;
+        let mut output = vec![];
+        reader.read_to_end(&mut output).await?;
+        assert_eq!(output, expect, "{}", path);
+    }
+
+    Ok(())
+}
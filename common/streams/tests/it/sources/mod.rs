@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod decompression;
+mod schema_inference;
+mod source_avro;
 mod source_csv;
 mod source_ndjson;
+mod source_orc;
 mod source_parquet;
@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use common_streams::infer_json_type;
+use common_streams::infer_text_type;
+use common_streams::merge_inferred_types;
+use common_streams::CsvSourceBuilder;
+use common_streams::NDJsonSourceBuilder;
+use serde_json::json;
+
+fn schema_columns(schema: &DataSchemaRef) -> Vec<String> {
+    schema
+        .fields()
+        .iter()
+        .map(|f| format!("{}: {}", f.name(), f.data_type().name()))
+        .collect()
+}
+
+#[test]
+fn test_infer_value_types() -> Result<()> {
+    let tests: Vec<(&str, &str)> = vec![
+        ("true", "Boolean"),
+        ("-12", "Int64"),
+        ("1.5", "Float64"),
+        ("2022-01-01", "Date32"),
+        ("2022-01-01 10:00:00", "DateTime64(6)"),
+        ("abc", "String"),
+        ("", "Null"),
+    ];
+    for (value, expect) in tests {
+        assert_eq!(
+            infer_text_type(value.as_bytes()).name(),
+            expect,
+            "{}",
+            value
+        );
+    }
+
+    let tests = vec![
+        (json!(null), "Null"),
+        (json!(false), "Boolean"),
+        (json!(-1), "Int64"),
+        (json!(18446744073709551615u64), "UInt64"),
+        (json!(1.5), "Float64"),
+        (json!("a"), "String"),
+        (json!([1, 2]), "Variant"),
+        (json!({"a": 1}), "Variant"),
+    ];
+    for (value, expect) in tests {
+        assert_eq!(infer_json_type(&value).name(), expect, "{}", value);
+    }
+
+    let tests = vec![
+        (Int64Type::arc(), Float64Type::arc(), "Float64"),
+        (
+            Date32Type::arc(),
+            DateTime64Type::arc(6, None),
+            "DateTime64(6)",
+        ),
+        (Int64Type::arc(), StringType::arc(), "String"),
+        (NullType::arc(), Int64Type::arc(), "Nullable(Int64)"),
+        (
+            wrap_nullable(&Int64Type::arc()),
+            BooleanType::arc(),
+            "Nullable(String)",
+        ),
+    ];
+    for (lhs, rhs, expect) in tests {
+        assert_eq!(merge_inferred_types(&lhs, &rhs).name(), expect);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_csv_infer_schema() -> Result<()> {
+    let bytes =
+        "id,name,score,day\n1,a,1.5,2022-01-01\n2,,2,2022-01-02 10:00:00\n3,c,x,\n4,d,1,N/A";
+
+    // The header names the columns.
+    let schema = DataSchemaRefExt::create(vec![]);
+    let mut builder = CsvSourceBuilder::create(schema.clone(), FormatSettings::default());
+    builder.skip_header(1);
+    builder.null_if(&["N/A".to_string()]);
+
+    let reader = futures::io::Cursor::new(bytes.as_bytes());
+    let inferred = builder.infer_schema(reader, 1000).await?;
+    assert_eq!(schema_columns(&inferred), vec![
+        "id: Int64",
+        "name: Nullable(String)",
+        "score: String",
+        "day: Nullable(DateTime64(6))",
+    ]);
+
+    // Only the first records are sampled, and the header is read as a record without skip_header.
+    let builder = CsvSourceBuilder::create(schema, FormatSettings::default());
+    let reader = futures::io::Cursor::new(bytes.as_bytes());
+    let inferred = builder.infer_schema(reader, 2).await?;
+    assert_eq!(schema_columns(&inferred), vec![
+        "c1: String",
+        "c2: String",
+        "c3: String",
+        "c4: String",
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ndjson_infer_schema() -> Result<()> {
+    let bytes = r#"{"a": 1, "b": "x", "c": null}
+    {"a": 2.5, "c": null, "d": [1, 2]}
+
+    {"a": 3, "b": "y", "c": null, "d": {"k": true}}
+    "#;
+
+    let schema = DataSchemaRefExt::create(vec![]);
+    let builder = NDJsonSourceBuilder::create(schema, FormatSettings::default());
+    let reader = std::io::Cursor::new(bytes.as_bytes());
+    let inferred = builder.infer_schema(reader, 1000).await?;
+    assert_eq!(schema_columns(&inferred), vec![
+        "a: Float64",
+        "b: Nullable(String)",
+        "c: Nullable(String)",
+        "d: Nullable(Variant)",
+    ]);
+
+    let reader = std::io::Cursor::new("[1, 2]".as_bytes());
+    let result = builder.infer_schema(reader, 1000).await;
+    assert!(result.is_err());
+
+    Ok(())
+}
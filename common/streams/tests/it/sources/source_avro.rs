@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::AvroSourceBuilder;
+use common_streams::Source;
+
+// sample.avro is a deflate compressed container file of 3 rows with the record fields:
+// id int, name [null, string], score double, flag boolean, day date, ts timestamp-millis,
+// price decimal(10, 2), color enum and tags array<string>.
+fn sample_avro() -> Vec<u8> {
+    std::fs::read("../../tests/data/sample.avro").unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_avro() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i32::to_data_type()),
+        DataField::new_nullable("name", Vu8::to_data_type()),
+        DataField::new("score", f64::to_data_type()),
+        DataField::new("flag", bool::to_data_type()),
+        DataField::new("day", Date32Type::arc()),
+        DataField::new("ts", DateTime64Type::arc(3, None)),
+        DataField::new("price", Vu8::to_data_type()),
+        DataField::new("color", Vu8::to_data_type()),
+        DataField::new("tags", Vu8::to_data_type()),
+    ]);
+
+    let builder = AvroSourceBuilder::create(schema);
+    let mut avro_source = builder.build(sample_avro())?;
+
+    let mut blocks = vec![];
+    while let Some(block) = avro_source.read().await? {
+        blocks.push(block);
+    }
+
+    assert_blocks_eq(
+        vec![
+            "+----+-------+-------+-------+-------+---------------+--------+-------+-----------+",
+            "| id | name  | score | flag  | day   | ts            | price  | color | tags      |",
+            "+----+-------+-------+-------+-------+---------------+--------+-------+-----------+",
+            "| 1  | alice | 1.5   | true  | 19000 | 1641600000000 | 123.45 | RED   | [\"a\",\"b\"] |",
+            "| 2  | NULL  | -2.25 | false | -1    | -1500         | -1.00  | GREEN | []        |",
+            "| 3  | carol | 0     | true  | 0     | 0             | 0.05   | RED   | [\"c\"]     |",
+            "+----+-------+-------+-------+-------+---------------+--------+-------+-----------+",
+        ],
+        &blocks,
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_avro_missing_columns() -> Result<()> {
+    // The fields are matched by name, case insensitively, and the missing ones get
+    // NULL if nullable, otherwise the default value of the type.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("PRICE", f64::to_data_type()),
+        DataField::new("id", u64::to_data_type()),
+        DataField::new_nullable("comment", Vu8::to_data_type()),
+        DataField::new("level", i32::to_data_type()),
+    ]);
+
+    let mut builder = AvroSourceBuilder::create(schema);
+    builder.block_size(1).size_limit(2);
+    let mut avro_source = builder.build(sample_avro())?;
+
+    let mut blocks = vec![];
+    while let Some(block) = avro_source.read().await? {
+        blocks.push(block);
+    }
+    assert_eq!(blocks.len(), 2);
+
+    assert_blocks_eq(
+        vec![
+            "+--------+----+---------+-------+",
+            "| PRICE  | id | comment | level |",
+            "+--------+----+---------+-------+",
+            "| 123.45 | 1  | NULL    | 0     |",
+            "| -1     | 2  | NULL    | 0     |",
+            "+--------+----+---------+-------+",
+        ],
+        &blocks,
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_avro_errors() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("name", i32::to_data_type())]);
+    let builder = AvroSourceBuilder::create(schema);
+
+    let mut avro_source = builder.build(sample_avro())?;
+    let result = avro_source.read().await;
+    assert!(result.is_err());
+    assert!(result
+        .err()
+        .unwrap()
+        .message()
+        .starts_with("Cannot load the Avro field 'name' as Int32 at row 0"));
+
+    let result = builder.build(b"not an avro file".to_vec());
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Invalid Avro file, the magic 'Obj1' is missing"
+    );
+
+    Ok(())
+}
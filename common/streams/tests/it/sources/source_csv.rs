@@ -17,10 +17,13 @@ use std::io::Write;
 
 use common_base::tokio;
 use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
+use common_streams::CsvChunk;
 use common_streams::CsvSourceBuilder;
+use common_streams::RejectedRecords;
 use common_streams::Source;
 use opendal::services::fs;
 use opendal::Operator;
@@ -64,7 +67,7 @@ async fn test_parse_csv_delimiter() -> Result<()> {
             );
 
             let mut builder = CsvSourceBuilder::create(schema, FormatSettings::default());
-            builder.skip_header(false);
+            builder.skip_header(0);
             builder.field_delimiter(field_delimiter);
             builder.record_delimiter(record_delimiter);
             builder.block_size(10);
@@ -129,7 +132,7 @@ async fn test_parse_csv_text() -> Result<()> {
     );
 
     let mut builder = CsvSourceBuilder::create(schema, FormatSettings::default());
-    builder.skip_header(false);
+    builder.skip_header(0);
     builder.field_delimiter(",");
     builder.record_delimiter("\n");
     builder.block_size(10);
@@ -162,3 +165,292 @@ async fn test_parse_csv_text() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_error_as_null() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1,1.5\nx,y\n3,z").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("a", i8::to_data_type()),
+        DataField::new("b", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let reader = local.object(name).reader().await?;
+    let builder = CsvSourceBuilder::create(schema.clone(), FormatSettings::default());
+    let mut csv_source = builder.build(reader)?;
+    assert!(csv_source.read().await.is_err());
+
+    let settings = FormatSettings {
+        error_as_null: true,
+        ..Default::default()
+    };
+    let reader = local.object(name).reader().await?;
+    let builder = CsvSourceBuilder::create(schema, settings);
+    let mut csv_source = builder.build(reader)?;
+
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+------+-----+",
+            "| a    | b   |",
+            "+------+-----+",
+            "| 1    | 1.5 |",
+            "| NULL | 0   |",
+            "| 3    | 0   |",
+            "+------+-----+",
+        ],
+        &[block],
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_dialect() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(
+        file,
+        "id,name,score\nint,string,double\n 1 ,'x, y',NULL\n2,'it\\'s', \n3 , N/A ,3.5"
+    )
+    .unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+        DataField::new_nullable("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema, FormatSettings::default());
+    builder.skip_header(2);
+    builder.quote("'");
+    builder.escape("\\");
+    builder.null_if(&["NULL".to_string(), "N/A".to_string(), "".to_string()]);
+    builder.trim(true);
+
+    let reader = local.object(name).reader().await?;
+    let mut csv_source = builder.build(reader)?;
+
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+------+",
+            "| a | b    | c    |",
+            "+---+------+------+",
+            "| 1 | x, y | NULL |",
+            "| 2 | it's | NULL |",
+            "| 3 | NULL | 3.5  |",
+            "+---+------+------+",
+        ],
+        &[block],
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_rejected_records() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "a,b\n1,1.5\nx,2.5\n3,y\n4,4.5").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    // The malformed records are skipped, the values of "3,y" before the error are dropped.
+    let rejected = RejectedRecords::create();
+    let mut builder = CsvSourceBuilder::create(schema.clone(), FormatSettings::default());
+    builder.skip_header(1);
+    builder.max_errors(2);
+    builder.rejected_records(name, rejected.clone());
+
+    let reader = local.object(name).reader().await?;
+    let mut csv_source = builder.build(reader)?;
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+-----+",
+            "| a | b   |",
+            "+---+-----+",
+            "| 1 | 1.5 |",
+            "| 4 | 4.5 |",
+            "+---+-----+",
+        ],
+        &[block],
+    );
+
+    let records = rejected.take();
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        (
+            records[0].file.as_str(),
+            records[0].line,
+            records[0].record.as_str()
+        ),
+        (name, 3, "x,2.5")
+    );
+    assert_eq!(
+        (
+            records[1].file.as_str(),
+            records[1].line,
+            records[1].record.as_str()
+        ),
+        (name, 4, "3,y")
+    );
+
+    // The read fails on the record after the limit, the failed record is rejected too.
+    builder.max_errors(1);
+    let reader = local.object(name).reader().await?;
+    let mut csv_source = builder.build(reader)?;
+    assert!(csv_source.read().await.is_err());
+    assert_eq!(rejected.len(), 2);
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn test_split_csv_chunks() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![]);
+    let builder = CsvSourceBuilder::create(schema, FormatSettings::default());
+
+    // The record delimiters in the quoted fields don't end the chunks.
+    let data = b"1,\"a\nb\"\n2,\"c\"\"\nd\"\n\n3,e\"f\n4";
+    let chunks = builder.split_chunks(data, 1, 0, true);
+    assert_eq!(chunks, vec![
+        CsvChunk {
+            range: 0..8,
+            records: 1
+        },
+        CsvChunk {
+            range: 8..18,
+            records: 1
+        },
+        CsvChunk {
+            range: 18..19,
+            records: 0
+        },
+        CsvChunk {
+            range: 19..25,
+            records: 1
+        },
+        CsvChunk {
+            range: 25..26,
+            records: 1
+        },
+    ]);
+
+    // Without the end of the data, the last record is left for the next data.
+    let chunks = builder.split_chunks(&data[..12], 100, 0, false);
+    assert_eq!(chunks, vec![CsvChunk {
+        range: 0..8,
+        records: 1
+    }]);
+
+    // The first chunk has the headers.
+    let chunks = builder.split_chunks(b"h1\nh2\n1\n2\n", 1, 2, true);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0].records, 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_parse_csv_parallel() -> Result<()> {
+    let data = "a,b\n1,\"x\ny\"\n2,\"p,q\"\nz,3\n4,\"s\"\"t\"\n5,u\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let rejected = RejectedRecords::create();
+    let mut builder = CsvSourceBuilder::create(schema, FormatSettings::default());
+    builder.skip_header(1);
+    builder.max_errors(1);
+    builder.rejected_records("data.csv", rejected.clone());
+    builder.chunk_size(4);
+    builder.parallelism(3);
+
+    let reader = futures::io::Cursor::new(data.as_bytes().to_vec());
+    let mut csv_source = builder.build_parallel(reader)?;
+    let mut blocks = vec![];
+    while let Some(block) = csv_source.read().await? {
+        blocks.push(block);
+    }
+
+    // The blocks are in the order of the data.
+    let block = DataBlock::concat_blocks(&blocks)?;
+    assert_eq!(block.num_rows(), 4);
+    let values = (0..block.num_rows())
+        .map(|row| (block.column(0).get(row), block.column(1).get(row)))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![
+        (DataValue::Int64(1), DataValue::String(b"x\ny".to_vec())),
+        (DataValue::Int64(2), DataValue::String(b"p,q".to_vec())),
+        (DataValue::Int64(4), DataValue::String(b"s\"t".to_vec())),
+        (DataValue::Int64(5), DataValue::String(b"u".to_vec())),
+    ]);
+
+    // The line numbers of the rejected records count the records of the previous chunks.
+    let records = rejected.take();
+    assert_eq!(records.len(), 1);
+    assert_eq!((records[0].line, records[0].record.as_str()), (4, "z,3"));
+
+    // The size limit is applied to the blocks of all the chunks.
+    builder.size_limit(3);
+    let reader = futures::io::Cursor::new(data.as_bytes().to_vec());
+    let mut csv_source = builder.build_parallel(reader)?;
+    let mut rows = 0;
+    while let Some(block) = csv_source.read().await? {
+        rows += block.num_rows();
+    }
+    assert_eq!(rows, 3);
+
+    Ok(())
+}
@@ -15,7 +15,9 @@
 use common_base::tokio;
 use common_datablocks::assert_blocks_eq;
 use common_exception::Result;
+use common_io::prelude::FormatSettings;
 use common_streams::NDJsonSourceBuilder;
+use common_streams::RejectedRecords;
 use common_streams::Source;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -37,7 +39,7 @@ async fn test_source_ndjson() -> Result<()> {
 
     let reader = std::io::Cursor::new(bytes);
 
-    let builder = NDJsonSourceBuilder::create(schema);
+    let builder = NDJsonSourceBuilder::create(schema, FormatSettings::default());
     let mut json_source = builder.build(reader).unwrap();
     // expects `page_nums_expects` blocks, and
     while let Some(block) = json_source.read().await? {
@@ -58,3 +60,145 @@ async fn test_source_ndjson() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_ndjson_missing_fields() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i32::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+        DataField::new("c", Vu8::to_data_type()),
+    ]);
+
+    // The missing fields are NULL if nullable, otherwise the default value of the type.
+    let bytes = r#"{"a":1, "b":"x", "c":"y"}
+    {"b":"z"}
+    {}
+    "#
+    .as_bytes();
+
+    let builder = NDJsonSourceBuilder::create(schema, FormatSettings::default());
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    let block = json_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+---+",
+            "| a | b    | c |",
+            "+---+------+---+",
+            "| 1 | x    | y |",
+            "| 0 | z    |   |",
+            "| 0 | NULL |   |",
+            "+---+------+---+",
+        ],
+        &[block],
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_ndjson_strict_and_relaxed() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i32::to_data_type()),
+        DataField::new("b", bool::to_data_type()),
+        DataField::new("c", Vu8::to_data_type()),
+        DataField::new("d", f64::to_data_type()),
+    ]);
+
+    let bytes = r#"{"a":"1", "b":"true", "c":1.5, "d":"2.5"}
+    {"a":true, "b":0, "c":{"k":[1,2]}, "d":null}
+    "#
+    .as_bytes();
+
+    // The strict mode rejects the values whose JSON types mismatch the column types.
+    let builder = NDJsonSourceBuilder::create(schema.clone(), FormatSettings::default());
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    let result = json_source.read().await;
+    assert!(result.is_err());
+    assert!(result.err().unwrap().message().starts_with(
+        "error at row 0 column a: type=Int32, err=Incorrect json value, must be number"
+    ));
+
+    // The relaxed mode converts them, and loads the nulls of the not nullable columns as the default values.
+    let settings = FormatSettings {
+        json_strict: false,
+        ..Default::default()
+    };
+    let builder = NDJsonSourceBuilder::create(schema.clone(), settings.clone());
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    let block = json_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+-------+-------------+-----+",
+            "| a | b     | c           | d   |",
+            "+---+-------+-------------+-----+",
+            "| 1 | true  | 1.5         | 2.5 |",
+            "| 1 | false | {\"k\":[1,2]} | 0   |",
+            "+---+-------+-------------+-----+",
+        ],
+        &[block],
+    );
+
+    // The values which can't be converted still fail.
+    let bytes = r#"{"a":"x1"}"#.as_bytes();
+    let builder = NDJsonSourceBuilder::create(schema, settings);
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    assert!(json_source.read().await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_ndjson_rejected_records() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i32::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let bytes = r#"{"a":1, "b":"x"}
+{"a":2, "b":
+{"b":"z", "a":"three"}
+[4, "w"]
+{"a":5, "b":"v"}
+"#
+    .as_bytes();
+
+    // The malformed lines are skipped and collected.
+    let rejected = RejectedRecords::create();
+    let mut builder = NDJsonSourceBuilder::create(schema, FormatSettings::default());
+    builder.max_errors(u64::MAX);
+    builder.rejected_records("data.ndjson", rejected.clone());
+
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    let block = json_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 5 | v |",
+            "+---+---+",
+        ],
+        &[block],
+    );
+
+    let records = rejected.take();
+    let lines = records.iter().map(|r| r.line).collect::<Vec<_>>();
+    assert_eq!(lines, vec![2, 3, 4]);
+    assert_eq!(records[2].record, r#"[4, "w"]"#);
+    assert!(records[2].error.contains("the line must be a JSON object"));
+
+    // The first error fails the read by default.
+    builder.max_errors(0);
+    let mut json_source = builder.build(std::io::Cursor::new(bytes))?;
+    assert!(json_source.read().await.is_err());
+    assert_eq!(rejected.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::OrcSourceBuilder;
+use common_streams::Source;
+
+// sample.orc has one stripe of 6 rows with the columns:
+// id INT, name STRING (with a null), city STRING (dictionary encoded), score DOUBLE,
+// flag BOOLEAN, day DATE, ts TIMESTAMP, price DECIMAL(10, 2) and tiny TINYINT.
+fn sample_orc() -> Vec<u8> {
+    std::fs::read("../../tests/data/sample.orc").unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_orc() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i32::to_data_type()),
+        DataField::new_nullable("name", Vu8::to_data_type()),
+        DataField::new("city", Vu8::to_data_type()),
+        DataField::new("score", f64::to_data_type()),
+        DataField::new("flag", bool::to_data_type()),
+        DataField::new("day", Date32Type::arc()),
+        DataField::new("ts", DateTime64Type::arc(3, None)),
+        DataField::new("price", Vu8::to_data_type()),
+        DataField::new("tiny", i8::to_data_type()),
+    ]);
+
+    let builder = OrcSourceBuilder::create(schema);
+    let mut orc_source = builder.build(sample_orc())?;
+
+    let mut blocks = vec![];
+    while let Some(block) = orc_source.read().await? {
+        blocks.push(block);
+    }
+
+    assert_blocks_eq(
+        vec![
+            "+----+-------+----------+---------+-------+-------+---------------+--------+------+",
+            "| id | name  | city     | score   | flag  | day   | ts            | price  | tiny |",
+            "+----+-------+----------+---------+-------+-------+---------------+--------+------+",
+            "| 1  | alice | beijing  | 1.5     | true  | 19000 | 1641600000000 | 123.45 | 1    |",
+            "| 2  | bob   | shanghai | 2.25    | false | 19001 | 1641600001500 | -1.00  | -1   |",
+            "| 3  | NULL  | beijing  | -3      | true  | 19002 | 0             | 0.00   | 127  |",
+            "| 4  | dave  | beijing  | 4       | true  | 0     | 1420070400123 | 999.99 | -128 |",
+            "| 5  | eve   | shanghai | 0       | false | -1    | -1500         | 0.01   | 0    |",
+            "| 6  | frank | beijing  | 100.125 | false | 18262 | 1577836800000 | 0.5    | 5    |",
+            "+----+-------+----------+---------+-------+-------+---------------+--------+------+",
+        ],
+        &blocks,
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_orc_block_size_and_limit() -> Result<()> {
+    // The columns are matched by name, case insensitively.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("PRICE", f64::to_data_type()),
+        DataField::new("id", u64::to_data_type()),
+    ]);
+
+    let mut builder = OrcSourceBuilder::create(schema);
+    builder.block_size(2).size_limit(3);
+    let mut orc_source = builder.build(sample_orc())?;
+
+    let mut blocks = vec![];
+    while let Some(block) = orc_source.read().await? {
+        blocks.push(block);
+    }
+    assert_eq!(blocks.len(), 2);
+
+    assert_blocks_eq(
+        vec![
+            "+--------+----+",
+            "| PRICE  | id |",
+            "+--------+----+",
+            "| 123.45 | 1  |",
+            "| -1     | 2  |",
+            "| 0      | 3  |",
+            "+--------+----+",
+        ],
+        &blocks,
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_orc_errors() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("not_exists", i32::to_data_type())]);
+    let builder = OrcSourceBuilder::create(schema);
+
+    let result = builder.build(sample_orc());
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Column 'not_exists' is not found in the ORC file"
+    );
+
+    let result = builder.build(b"not an orc file".to_vec());
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Invalid ORC file, the magic 'ORC' is missing"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::*;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::*;
+use futures::TryStreamExt;
+
+#[tokio::test]
+async fn test_memory_limit_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![1i64, 2, 3])]);
+
+    let tracker = RuntimeTracker::create();
+    let memory_tracker = tracker.get_memory_tracker();
+    memory_tracker.set_limit(2048);
+    memory_tracker.alloc_memory(1024);
+
+    // Under the limit.
+    {
+        let input = DataBlockStream::create(schema.clone(), None, vec![block.clone()]);
+        let stream = MemoryLimitStream::try_create(Box::pin(input), tracker.clone())?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 1);
+    }
+
+    // Exceeded the limit, even if the memory is freed since.
+    memory_tracker.alloc_memory(2048);
+    memory_tracker.dealloc_memory(2048);
+    {
+        let input = DataBlockStream::create(schema.clone(), None, vec![block]);
+        let stream = MemoryLimitStream::try_create(Box::pin(input), tracker.clone())?;
+        let result = stream.try_collect::<Vec<_>>().await;
+        let error = result.unwrap_err();
+        assert_eq!(error.code(), ErrorCode::MemoryLimitExceeded("").code());
+        assert_eq!(
+            error.message(),
+            "Query memory usage 3072 bytes exceeds the max_memory_usage 2048 bytes"
+        );
+    }
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ mod tracing_to_jaeger;
 
 pub use logging::init_default_ut_tracing;
 pub use logging::init_global_tracing;
+pub use logging::init_global_tracing_with_otlp;
 pub use logging::init_meta_ut_tracing;
 pub use logging::init_query_logger;
 pub use panic_hook::set_panic_hook;
@@ -26,6 +27,7 @@ pub use tracing_appender;
 pub use tracing_futures;
 pub use tracing_subscriber;
 pub use tracing_to_jaeger::extract_remote_span_as_parent;
+pub use tracing_to_jaeger::extract_remote_span_from_headers;
 pub use tracing_to_jaeger::inject_span_to_tonic_request;
 
 #[macro_export]
@@ -20,6 +20,10 @@ use std::sync::Once;
 use once_cell::sync::Lazy;
 use opentelemetry::global;
 use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace;
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
 use tracing::Event;
 use tracing::Subscriber;
 use tracing_appender::non_blocking::WorkerGuard;
@@ -69,6 +73,18 @@ static GLOBAL_UT_LOG_GUARD: Lazy<Arc<Mutex<Option<Vec<WorkerGuard>>>>> =
 ///
 // TODO(xp): use DATABEND_JAEGER to assign jaeger server address.
 pub fn init_global_tracing(app_name: &str, dir: &str, level: &str) -> Vec<WorkerGuard> {
+    init_global_tracing_with_otlp(app_name, dir, level, "")
+}
+
+/// Init logging and tracing, the spans are exported to the OpenTelemetry collector at
+/// `otlp_endpoint` (e.g. `http://127.0.0.1:4317`) with the OTLP gRPC protocol.
+/// The spans are sent to the local jaeger agent if the endpoint is empty.
+pub fn init_global_tracing_with_otlp(
+    app_name: &str,
+    dir: &str,
+    level: &str,
+    otlp_endpoint: &str,
+) -> Vec<WorkerGuard> {
     let mut guards = vec![];
 
     // Enable log compatible layer to convert log record to tracing span.
@@ -85,13 +101,28 @@ pub fn init_global_tracing(app_name: &str, dir: &str, level: &str) -> Vec<Worker
     let file_logging_layer = BunyanFormattingLayer::new(app_name.to_string(), rolling_writer);
     guards.push(rolling_writer_guard);
 
-    // Jaeger layer.
+    // OpenTelemetry layer.
     global::set_text_map_propagator(TraceContextPropagator::new());
-    let tracer = opentelemetry_jaeger::new_pipeline()
-        .with_service_name(app_name)
-        .install_batch(opentelemetry::runtime::Tokio)
-        .expect("install");
-    let jaeger_layer = Some(tracing_opentelemetry::layer().with_tracer(tracer));
+    let tracer =
+        match otlp_endpoint.is_empty() {
+            true => opentelemetry_jaeger::new_pipeline()
+                .with_service_name(app_name)
+                .install_batch(opentelemetry::runtime::Tokio)
+                .expect("install"),
+            false => opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(otlp_endpoint),
+                )
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", app_name.to_string()),
+                ])))
+                .install_batch(opentelemetry::runtime::Tokio)
+                .expect("install"),
+        };
+    let opentelemetry_layer = Some(tracing_opentelemetry::layer().with_tracer(tracer));
 
     // Use env RUST_LOG to initialize log if present.
     // Otherwise use the specified level.
@@ -102,7 +133,7 @@ pub fn init_global_tracing(app_name: &str, dir: &str, level: &str) -> Vec<Worker
         .with(JsonStorageLayer)
         .with(stdout_logging_layer)
         .with(file_logging_layer)
-        .with(jaeger_layer);
+        .with(opentelemetry_layer);
 
     #[cfg(feature = "console")]
     let subscriber = subscriber.with(console_subscriber::spawn());
@@ -15,6 +15,7 @@
 use opentelemetry::global;
 use opentelemetry::propagation::Extractor;
 use opentelemetry::propagation::Injector;
+use tonic::codegen::http;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 /// Inject tracing info into tonic request meta.
@@ -87,3 +88,13 @@ pub fn extract_remote_span_as_parent<T>(request: &tonic::Request<T>) {
     let span = tracing::Span::current();
     span.set_parent(parent_cx);
 }
+
+/// Extract the W3C trace context (the `traceparent` header) from the http request headers
+/// and set it as the parent of `span`, to chain the span with the one of the client.
+pub fn extract_remote_span_from_headers(span: &tracing::Span, headers: &http::HeaderMap) {
+    let metadata = tonic::metadata::MetadataMap::from_headers(headers.clone());
+    let parent_cx =
+        global::get_text_map_propagator(|prop| prop.extract(&MetadataMapExtractor(&metadata)));
+
+    span.set_parent(parent_cx);
+}
@@ -16,6 +16,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Instant;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
 use common_grpc::GrpcClaim;
@@ -28,6 +29,8 @@ use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::protobuf::WatchResponse;
 use common_tracing::tracing;
 use futures::StreamExt;
 use prost::Message;
@@ -41,6 +44,7 @@ use tonic::Streaming;
 use crate::executor::ActionHandler;
 use crate::meta_service::meta_service_impl::GrpcStream;
 use crate::meta_service::MetaNode;
+use crate::metrics::meta_metrics::record_grpc_request;
 
 pub struct MetaServiceImpl {
     token: GrpcToken,
@@ -124,7 +128,11 @@ impl MetaService for MetaServiceImpl {
         let action: MetaGrpcWriteReq = request.try_into()?;
         tracing::info!("Receive write_action: {:?}", action);
 
+        let start = Instant::now();
+        let name = write_req_name(&action);
         let body = self.action_handler.execute_write(action).await;
+        record_grpc_request(name, start.elapsed(), body.error.is_empty());
+
         Ok(Response::new(body))
     }
 
@@ -135,7 +143,10 @@ impl MetaService for MetaServiceImpl {
         let action: MetaGrpcReadReq = request.try_into()?;
         tracing::info!("Receive read_action: {:?}", action);
 
+        let start = Instant::now();
+        let name = read_req_name(&action);
         let res = self.action_handler.execute_read(action).await;
+        record_grpc_request(name, start.elapsed(), res.error.is_empty());
 
         Ok(Response::new(res))
     }
@@ -161,6 +172,60 @@ impl MetaService for MetaServiceImpl {
 
         Ok(Response::new(Box::pin(s)))
     }
+
+    type WatchStream = GrpcStream<WatchResponse>;
+
+    // Watch the changes applied to the state machine of this node.
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        self.check_token(request.metadata())?;
+
+        let WatchRequest { key_prefix } = request.into_inner();
+        tracing::info!("Receive watch: key_prefix: {}", key_prefix);
+
+        let meta_node = &self.action_handler.meta_node;
+        let stream = meta_node.sto.watcher.watch(key_prefix).map(|event| {
+            let data = serde_json::to_string(&event?)
+                .map_err(|e| Status::internal(format!("invalid watch event: {}", e)))?;
+            Ok(WatchResponse { data })
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+// The `request` label of the metrics of a write request.
+fn write_req_name(req: &MetaGrpcWriteReq) -> &'static str {
+    match req {
+        MetaGrpcWriteReq::CreateDatabase(_) => "create_database",
+        MetaGrpcWriteReq::DropDatabase(_) => "drop_database",
+        MetaGrpcWriteReq::CreateTable(_) => "create_table",
+        MetaGrpcWriteReq::DropTable(_) => "drop_table",
+        MetaGrpcWriteReq::UndropTable(_) => "undrop_table",
+        MetaGrpcWriteReq::GcDroppedTable(_) => "gc_dropped_table",
+        MetaGrpcWriteReq::RenameTable(_) => "rename_table",
+        MetaGrpcWriteReq::CommitTable(_) => "commit_table",
+        MetaGrpcWriteReq::UpdateTableSchema(_) => "update_table_schema",
+        MetaGrpcWriteReq::UpsertKV(_) => "upsert_kv",
+        MetaGrpcWriteReq::Transaction(_) => "transaction",
+    }
+}
+
+// The `request` label of the metrics of a read request.
+fn read_req_name(req: &MetaGrpcReadReq) -> &'static str {
+    match req {
+        MetaGrpcReadReq::GetDatabase(_) => "get_database",
+        MetaGrpcReadReq::ListDatabases(_) => "list_databases",
+        MetaGrpcReadReq::GetTable(_) => "get_table",
+        MetaGrpcReadReq::GetTableExt(_) => "get_table_ext",
+        MetaGrpcReadReq::ListTables(_) => "list_tables",
+        MetaGrpcReadReq::ListDroppedTables(_) => "list_dropped_tables",
+        MetaGrpcReadReq::GetKV(_) => "get_kv",
+        MetaGrpcReadReq::MGetKV(_) => "mget_kv",
+        MetaGrpcReadReq::PrefixListKV(_) => "prefix_list_kv",
+    }
 }
 
 pub struct ExportStream {
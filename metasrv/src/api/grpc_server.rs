@@ -16,26 +16,30 @@ use std::sync::Arc;
 
 use anyerror::AnyError;
 use common_base::tokio;
+use common_base::tokio::net::TcpListener;
 use common_base::tokio::sync::oneshot;
 use common_base::tokio::sync::oneshot::Receiver;
 use common_base::tokio::sync::oneshot::Sender;
 use common_base::tokio::task::JoinHandle;
 use common_base::Stoppable;
+use common_grpc::TlsIncoming;
 use common_meta_types::protobuf::meta_service_server::MetaServiceServer;
 use common_meta_types::protobuf::FILE_DESCRIPTOR_SET;
+use common_meta_types::ConnectionError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaNetworkError;
 use common_meta_types::MetaResult;
 use common_tracing::tracing;
 use common_tracing::tracing::Instrument;
 use futures::future::Either;
-use tonic::transport::Identity;
+use futures::TryStreamExt;
+use tokio_stream::wrappers::TcpListenerStream;
 use tonic::transport::Server;
-use tonic::transport::ServerTlsConfig;
 
 use crate::api::grpc::grpc_service::MetaServiceImpl;
 use crate::configs::Config;
 use crate::meta_service::MetaNode;
+use crate::metrics::CountedConnection;
 
 pub struct GrpcServer {
     conf: Config,
@@ -75,21 +79,6 @@ impl GrpcServer {
             .build()
             .unwrap();
 
-        let builder = Server::builder();
-
-        let tls_conf = Self::tls_config(&self.conf)
-            .await
-            .map_err(|e| MetaNetworkError::TLSConfigError(AnyError::new(&e)))?;
-
-        let mut builder = if let Some(tls_conf) = tls_conf {
-            tracing::info!("gRPC TLS enabled");
-            builder
-                .tls_config(tls_conf)
-                .map_err(|e| MetaNetworkError::TLSConfigError(AnyError::new(&e)))?
-        } else {
-            builder
-        };
-
         let ret = conf.grpc_api_address.parse::<std::net::SocketAddr>();
         let addr = match ret {
             Ok(addr) => addr,
@@ -100,21 +89,49 @@ impl GrpcServer {
         };
         tracing::info!("gRPC addr: {}", addr);
 
+        let listener = TcpListener::bind(addr).await.map_err(|e| {
+            MetaNetworkError::ConnectionError(ConnectionError::new(e, "while binding gRPC addr"))
+        })?;
+        let incoming = if conf.tls_rpc_server_enabled() {
+            tracing::info!("gRPC TLS enabled");
+            let incoming = TlsIncoming::create(conf.tls_rpc_server_conf(), listener)
+                .map_err(|e| MetaNetworkError::TLSConfigError(AnyError::new(&e)))?;
+            Either::Left(incoming)
+        } else {
+            Either::Right(TcpListenerStream::new(listener))
+        };
+
         let grpc_impl = MetaServiceImpl::create(meta_node.clone());
         let grpc_srv = MetaServiceServer::new(grpc_impl);
 
         let j = tokio::spawn(
             async move {
-                let res = builder
+                let router = Server::builder()
                     .add_service(reflect_srv)
-                    .add_service(grpc_srv)
-                    .serve_with_shutdown(addr, async move {
-                        let _ = started_tx.send(());
-                        tracing::info!("metasrv starts to wait for stop signal: {}", addr);
-                        let _ = stop_rx.await;
-                        tracing::info!("metasrv receives stop signal: {}", addr);
-                    })
-                    .await;
+                    .add_service(grpc_srv);
+                let shutdown = async move {
+                    let _ = started_tx.send(());
+                    tracing::info!("metasrv starts to wait for stop signal: {}", addr);
+                    let _ = stop_rx.await;
+                    tracing::info!("metasrv receives stop signal: {}", addr);
+                };
+
+                // The TLS connections are accepted by the incoming to reload the rotated
+                // certificates. The connections are counted for the metrics.
+                let res = match incoming {
+                    Either::Left(incoming) => {
+                        let incoming = incoming.map_ok(CountedConnection::new);
+                        router
+                            .serve_with_incoming_shutdown(incoming, shutdown)
+                            .await
+                    }
+                    Either::Right(incoming) => {
+                        let incoming = incoming.map_ok(CountedConnection::new);
+                        router
+                            .serve_with_incoming_shutdown(incoming, shutdown)
+                            .await
+                    }
+                };
 
                 // gRPC server quit. Starting to shutdown meta node.
 
@@ -182,19 +199,6 @@ impl GrpcServer {
         }
         Ok(())
     }
-
-    async fn tls_config(conf: &Config) -> Result<Option<ServerTlsConfig>, std::io::Error> {
-        if conf.tls_rpc_server_enabled() {
-            let cert = tokio::fs::read(conf.grpc_tls_server_cert.as_str()).await?;
-            let key = tokio::fs::read(conf.grpc_tls_server_key.as_str()).await?;
-            let server_identity = Identity::from_pem(cert, key);
-
-            let tls = ServerTlsConfig::new().identity(server_identity);
-            Ok(Some(tls))
-        } else {
-            Ok(None)
-        }
-    }
 }
 
 #[tonic::async_trait]
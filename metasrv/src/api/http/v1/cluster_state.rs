@@ -14,14 +14,25 @@
 
 use std::sync::Arc;
 
+use common_meta_types::ChangeRoleRequest;
+use common_meta_types::ForwardRequest;
+use common_meta_types::ForwardRequestBody;
+use common_meta_types::NodeId;
 use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Query;
+use serde::Deserialize;
 use serde_json;
 
 use crate::meta_service::MetaNode;
 
+#[derive(Deserialize, Debug)]
+pub struct ChangeRoleQuery {
+    node_id: NodeId,
+}
+
 // GET /v1/cluster/nodes
 // list all nodes in current databend-metasrv cluster
 // request: None
@@ -66,3 +77,51 @@ pub async fn state_handler(
         "leader": leader_node,
     })))
 }
+
+// POST /v1/cluster/promote?node_id=<node_id>
+// promote a learner to a voter
+// request: the id of the learner
+// return: None
+#[poem::handler]
+pub async fn promote_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    Query(query): Query<ChangeRoleQuery>,
+) -> poem::Result<impl IntoResponse> {
+    change_role(&meta_node, query.node_id, false).await
+}
+
+// POST /v1/cluster/demote?node_id=<node_id>
+// demote a voter to a learner, which still replicates the logs
+// request: the id of the voter
+// return: None
+#[poem::handler]
+pub async fn demote_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+    Query(query): Query<ChangeRoleQuery>,
+) -> poem::Result<impl IntoResponse> {
+    change_role(&meta_node, query.node_id, true).await
+}
+
+async fn change_role(
+    meta_node: &MetaNode,
+    node_id: NodeId,
+    as_learner: bool,
+) -> poem::Result<StatusCode> {
+    let req = ForwardRequest {
+        forward_to_leader: 1,
+        body: ForwardRequestBody::ChangeRole(ChangeRoleRequest {
+            node_id,
+            as_learner,
+        }),
+    };
+    meta_node
+        .handle_forwardable_request(req)
+        .await
+        .map_err(|e| {
+            poem::Error::from_string(
+                format!("failed to change the role of node {}: {}", node_id, e),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(StatusCode::OK)
+}
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_tracing::tracing;
 use poem::get;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
@@ -55,6 +56,14 @@ impl HttpService {
                 "/v1/cluster/state",
                 get(super::http::v1::cluster_state::state_handler),
             )
+            .at(
+                "/v1/cluster/promote",
+                post(super::http::v1::cluster_state::promote_handler),
+            )
+            .at(
+                "/v1/cluster/demote",
+                post(super::http::v1::cluster_state::demote_handler),
+            )
             .at(
                 "/debug/home",
                 get(super::http::debug::home::debug_home_handler),
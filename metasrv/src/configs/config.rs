@@ -14,6 +14,7 @@
 
 use clap::Parser;
 use common_base::Format;
+use common_grpc::RpcServerTlsConfig;
 use common_meta_raft_store::config as raft_config;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_types::MetaError;
@@ -42,6 +43,7 @@ pub const ADMIN_TLS_SERVER_KEY: &str = "ADMIN_TLS_SERVER_KEY";
 pub const METASRV_GRPC_API_ADDRESS: &str = "METASRV_GRPC_API_ADDRESS";
 pub const GRPC_TLS_SERVER_CERT: &str = "GRPC_TLS_SERVER_CERT";
 pub const GRPC_TLS_SERVER_KEY: &str = "GRPC_TLS_SERVER_KEY";
+pub const GRPC_TLS_SERVER_ROOT_CA_CERT: &str = "GRPC_TLS_SERVER_ROOT_CA_CERT";
 
 /// METASRV Config file.
 const METASRV_CONFIG_FILE: &str = "METASRV_CONFIG_FILE";
@@ -81,6 +83,11 @@ pub struct Config {
     #[clap(long, env = GRPC_TLS_SERVER_KEY, default_value = "")]
     pub grpc_tls_server_key: String,
 
+    /// The CA to verify the certificates of the clients, the clients are not verified if it is
+    /// empty.
+    #[clap(long, env = GRPC_TLS_SERVER_ROOT_CA_CERT, default_value = "")]
+    pub grpc_tls_server_root_ca_cert: String,
+
     #[clap(flatten)]
     pub raft_config: RaftConfig,
 }
@@ -98,6 +105,7 @@ impl Default for Config {
             grpc_api_address: "127.0.0.1:9191".to_string(),
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
+            grpc_tls_server_root_ca_cert: "".to_string(),
             raft_config: Default::default(),
         }
     }
@@ -117,6 +125,14 @@ impl Config {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
 
+    pub fn tls_rpc_server_conf(&self) -> RpcServerTlsConfig {
+        RpcServerTlsConfig {
+            rpc_tls_server_cert: self.grpc_tls_server_cert.clone(),
+            rpc_tls_server_key: self.grpc_tls_server_key.clone(),
+            rpc_tls_server_root_ca_cert: self.grpc_tls_server_root_ca_cert.clone(),
+        }
+    }
+
     pub fn check(&self) -> MetaResult<()> {
         self.raft_config.check()?;
         Ok(())
@@ -162,6 +178,11 @@ impl Config {
         load_field_from_env!(cfg.grpc_api_address, String, METASRV_GRPC_API_ADDRESS);
         load_field_from_env!(cfg.grpc_tls_server_cert, String, GRPC_TLS_SERVER_CERT);
         load_field_from_env!(cfg.grpc_tls_server_key, String, GRPC_TLS_SERVER_KEY);
+        load_field_from_env!(
+            cfg.grpc_tls_server_root_ca_cert,
+            String,
+            GRPC_TLS_SERVER_ROOT_CA_CERT
+        );
         load_field_from_env!(
             cfg.raft_config.raft_listen_host,
             String,
@@ -199,6 +220,7 @@ impl Config {
             raft_config::KVSRV_INSTALL_SNAPSHOT_TIMEOUT
         );
         load_field_from_env!(cfg.raft_config.single, bool, raft_config::KVSRV_SINGLE);
+        load_field_from_env!(cfg.raft_config.learner, bool, raft_config::KVSRV_LEARNER);
         load_field_from_env!(cfg.raft_config.id, u64, raft_config::KVSRV_ID);
     }
 }
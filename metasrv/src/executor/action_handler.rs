@@ -48,6 +48,10 @@ impl ActionHandler {
                 let r = self.meta_node.upsert_kv(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::Transaction(a) => {
+                let r = self.meta_node.transaction(a).await;
+                RaftReply::from(r)
+            }
             // database
             MetaGrpcWriteReq::CreateDatabase(a) => {
                 let r = self.handle(a).await;
@@ -67,6 +71,14 @@ impl ActionHandler {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::UndropTable(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
+            MetaGrpcWriteReq::GcDroppedTable(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
             MetaGrpcWriteReq::RenameTable(a) => {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
@@ -75,6 +87,10 @@ impl ActionHandler {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::UpdateTableSchema(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
         }
     }
 
@@ -114,6 +130,10 @@ impl ActionHandler {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcReadReq::ListDroppedTables(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
             MetaGrpcReadReq::GetTableExt(a) => {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
@@ -23,7 +23,10 @@ use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::GcDroppedTable;
 use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::UndropTable;
+use common_meta_types::Cmd::UpdateTableSchema;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -36,9 +39,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaError;
@@ -50,9 +57,13 @@ use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_meta_types::TableVersionMismatched;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
 use common_meta_types::UnknownDatabase;
 use common_meta_types::UnknownTable;
 use common_meta_types::UnknownTableId;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -70,6 +81,7 @@ impl RequestHandler<CreateDatabaseReq> for ActionHandler {
 
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: CreateDatabase {
                 tenant,
                 name: db_name.clone(),
@@ -117,6 +129,7 @@ impl RequestHandler<DropDatabaseReq> for ActionHandler {
         let if_exists = req.if_exists;
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: DropDatabase {
                 tenant,
                 name: db_name.clone(),
@@ -157,6 +170,7 @@ impl RequestHandler<CreateTableReq> for ActionHandler {
 
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: CreateTable {
                 tenant,
                 db_name: db_name.clone(),
@@ -197,10 +211,12 @@ impl RequestHandler<DropTableReq> for ActionHandler {
 
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: DropTable {
                 tenant,
                 db_name: db_name.clone(),
                 table_name: table_name.clone(),
+                purge: req.purge,
             },
         };
 
@@ -221,6 +237,52 @@ impl RequestHandler<DropTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<UndropTableReq> for ActionHandler {
+    async fn handle(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError> {
+        let cr = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: UndropTable {
+                tenant: req.tenant,
+                db_name: req.db,
+                table_name: req.table,
+            },
+        };
+
+        let res = self.meta_node.write(cr).await?;
+
+        let mut ch: Change<TableMeta> = res
+            .try_into()
+            .map_err(|e: &str| MetaError::MetaServiceError(e.to_string()))?;
+        let table_id = ch.ident.take().unwrap();
+        Ok(UndropTableReply { table_id })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<GcDroppedTableReq> for ActionHandler {
+    async fn handle(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableReply, MetaError> {
+        let cr = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: GcDroppedTable {
+                tenant: req.tenant,
+                db_name: req.db,
+                table_name: req.table,
+                table_id: req.table_id,
+            },
+        };
+
+        let res = self.meta_node.write(cr).await?;
+
+        let _ch: Change<TableMeta> = res
+            .try_into()
+            .map_err(|e: &str| MetaError::MetaServiceError(e.to_string()))?;
+        Ok(GcDroppedTableReply {})
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<RenameTableReq> for ActionHandler {
     async fn handle(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
@@ -233,6 +295,7 @@ impl RequestHandler<RenameTableReq> for ActionHandler {
 
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: RenameTable {
                 tenant,
                 db_name: db_name.clone(),
@@ -309,11 +372,20 @@ impl RequestHandler<ListTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<ListDroppedTableReq> for ActionHandler {
+    async fn handle(&self, req: ListDroppedTableReq) -> Result<Vec<DroppedTableInfo>, MetaError> {
+        let res = self.meta_node.consistent_read(req).await?;
+        Ok(res)
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
     async fn handle(&self, req: UpsertTableOptionReq) -> Result<UpsertTableOptionReply, MetaError> {
         let cr = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: UpsertTableOptions(req.clone()),
         };
 
@@ -339,3 +411,36 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTableSchemaReq> for ActionHandler {
+    async fn handle(&self, req: UpdateTableSchemaReq) -> Result<UpdateTableSchemaReply, MetaError> {
+        let cr = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: UpdateTableSchema(req.clone()),
+        };
+
+        let res = self.meta_node.write(cr).await?;
+
+        let ch: Change<TableMeta> = res
+            .try_into()
+            .map_err(|e: &str| MetaError::MetaServiceError(e.to_string()))?;
+        // safe unwrap: the table is present, so `prev` and `result` are not None.
+        let (prev, result) = ch.unwrap();
+        if prev.seq == result.seq {
+            let ae = AppError::from(TableVersionMismatched::new(
+                req.table_id,
+                req.seq,
+                prev.seq,
+                "RequestHandler: update_table_schema",
+            ));
+
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(UpdateTableSchemaReply {
+            schema_version: result.data.schema_version,
+        })
+    }
+}
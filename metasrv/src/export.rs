@@ -59,6 +59,7 @@ pub fn serialize_kv_variant(kv: &KeySpaceKV) -> Result<(sled::IVec, sled::IVec),
         KeySpaceKV::TableLookup { key, value } => ser!(TableLookup, key, value),
         KeySpaceKV::DatabaseLookup { key, value } => ser!(DatabaseLookup, key, value),
         KeySpaceKV::LogMeta { key, value } => ser!(LogMeta, key, value),
+        KeySpaceKV::DroppedTableLookup { key, value } => ser!(DroppedTableLookup, key, value),
     }
 }
 
@@ -85,7 +86,8 @@ pub fn deserialize_to_kv_variant(d: &[Vec<u8>]) -> Result<KeySpaceKV, MetaStorag
         ClientLastResps,
         TableLookup,
         DatabaseLookup,
-        LogMeta
+        LogMeta,
+        DroppedTableLookup
     );
 
     unreachable!("unknown prefix: {}", prefix);
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_meta_api::KVApi;
 use common_meta_api::MetaApi;
@@ -20,6 +22,7 @@ use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::error::ClientWriteError;
 use common_meta_sled_store::openraft::raft::EntryPayload;
 use common_meta_types::AppliedState;
+use common_meta_types::ChangeRoleRequest;
 use common_meta_types::Cmd;
 use common_meta_types::ForwardRequest;
 use common_meta_types::ForwardResponse;
@@ -61,6 +64,10 @@ impl<'a> MetaLeader<'a> {
                 self.join(join_req).await?;
                 Ok(ForwardResponse::Join(()))
             }
+            ForwardRequestBody::ChangeRole(change_role_req) => {
+                self.change_role(change_role_req).await?;
+                Ok(ForwardResponse::ChangeRole(()))
+            }
             ForwardRequestBody::Write(entry) => {
                 let res = self.write(entry).await?;
                 Ok(ForwardResponse::AppliedState(res))
@@ -87,6 +94,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.get_table(req).await?;
                 Ok(ForwardResponse::TableInfo(res))
             }
+            ForwardRequestBody::ListDroppedTable(req) => {
+                let sm = self.meta_node.get_state_machine().await;
+                let res = sm.list_dropped_tables(req).await?;
+                Ok(ForwardResponse::ListDroppedTable(res))
+            }
             ForwardRequestBody::GetKV(req) => {
                 let sm = self.meta_node.get_state_machine().await;
                 let res = sm.get_kv(&req.key).await?;
@@ -108,9 +120,10 @@ impl<'a> MetaLeader<'a> {
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter, unless it joins as a learner.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
+    /// A learner that joins again as a voter is promoted to a voter.
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn join(&self, req: JoinRequest) -> Result<(), MetaError> {
         let node_id = req.node_id;
@@ -122,16 +135,20 @@ impl<'a> MetaLeader<'a> {
             return Ok(());
         }
 
+        if req.as_learner && self.meta_node.get_node(&node_id).await?.is_some() {
+            // Already joined as a learner.
+            return Ok(());
+        }
+
         // TODO(xp): deal with joint config
         assert!(membership.get_ith_config(1).is_none());
 
         // safe unwrap: if the first config is None, panic is the expected behavior here.
         let mut membership = membership.get_ith_config(0).unwrap().clone();
 
-        membership.insert(node_id);
-
         let ent = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: Cmd::AddNode {
                 node_id,
                 node: Node {
@@ -143,9 +160,86 @@ impl<'a> MetaLeader<'a> {
 
         self.write(ent.clone()).await?;
 
+        if req.as_learner {
+            return self.add_learner(node_id).await.map_err(|e| {
+                MetaRaftError::JoinClusterFail(format!("fail to add learner {}: {}", node_id, e))
+                    .into()
+            });
+        }
+
+        membership.insert(node_id);
+
         self.change_membership(membership).await
     }
 
+    /// Promote a learner to a voter, or demote a voter to a learner.
+    ///
+    /// A demoted voter keeps replicating the logs, thus a voter can be replaced without
+    /// downtime: join the new node as a learner, promote it when it catches up, then demote
+    /// the old one and shut it down.
+    ///
+    /// If the node already has the role, it still returns Ok.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn change_role(&self, req: ChangeRoleRequest) -> Result<(), MetaError> {
+        let node_id = req.node_id;
+
+        if self.meta_node.get_node(&node_id).await?.is_none() {
+            return Err(MetaRaftError::ChangeRoleFail(format!(
+                "node {} has not joined the cluster",
+                node_id
+            ))
+            .into());
+        }
+
+        let metrics = self.meta_node.raft.metrics().borrow().clone();
+        let membership = metrics.membership_config.membership.clone();
+
+        // TODO(xp): deal with joint config
+        assert!(membership.get_ith_config(1).is_none());
+
+        // safe unwrap: if the first config is None, panic is the expected behavior here.
+        let mut membership = membership.get_ith_config(0).unwrap().clone();
+
+        if !req.as_learner {
+            if !membership.insert(node_id) {
+                return Ok(());
+            }
+            return self.change_membership(membership).await;
+        }
+
+        if !membership.remove(&node_id) {
+            return Ok(());
+        }
+        if membership.is_empty() {
+            return Err(MetaRaftError::ChangeRoleFail(format!(
+                "can not demote the last voter {}",
+                node_id
+            ))
+            .into());
+        }
+
+        self.change_membership(membership).await?;
+
+        // The replication to a node removed from membership is stopped, restart it.
+        // If this node is no longer the leader, e.g. it demoted itself, the new leader restarts
+        // it when the leadership is established.
+        if let Err(e) = self.add_learner(node_id).await {
+            tracing::info!("fail to add demoted node {} as learner: {}", node_id, e);
+        }
+        Ok(())
+    }
+
+    /// Start replication to a node that is not in membership.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn add_learner(&self, node_id: NodeId) -> Result<(), MetaError> {
+        self.meta_node
+            .raft
+            .add_learner(node_id, false)
+            .await
+            .map_err(|e| MetaError::MetaServiceError(format!("{:?}", e)))?;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn change_membership(&self, membership: BTreeSet<NodeId>) -> Result<(), MetaError> {
         let res = self
@@ -174,13 +268,44 @@ impl<'a> MetaLeader<'a> {
         }
     }
 
+    /// Propose a log to remove the expired kv entries, returns the number of the expired keys.
+    ///
+    /// Nothing is written if there is no expired key.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn clean_expired(&self) -> Result<usize, MetaError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let keys = {
+            let sm = self.meta_node.sto.state_machine.read().await;
+            sm.expired_kv_keys(now.as_secs())?
+        };
+
+        if keys.is_empty() {
+            return Ok(0);
+        }
+
+        let n = keys.len();
+        self.write(LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::CleanExpired { keys },
+        })
+        .await?;
+        Ok(n)
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
     /// If the leadership is lost during writing the log, it returns an UnknownError.
     /// TODO(xp): elaborate the UnknownError, e.g. LeaderLostError
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn write(&self, entry: LogEntry) -> Result<AppliedState, MetaError> {
+    pub async fn write(&self, mut entry: LogEntry) -> Result<AppliedState, MetaError> {
+        // The expiration of the kv entries is checked against the time assigned by the leader.
+        if entry.time_ms.is_none() {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            entry.time_ms = Some(now.as_millis() as u64);
+        }
+
         let write_rst = self
             .meta_node
             .raft
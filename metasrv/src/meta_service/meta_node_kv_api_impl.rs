@@ -25,6 +25,8 @@ use common_meta_types::MGetKVReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaResultError;
 use common_meta_types::PrefixListReply;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_tracing::tracing;
@@ -41,6 +43,7 @@ impl KVApi for MetaNode {
     async fn upsert_kv(&self, act: UpsertKVAction) -> Result<UpsertKVActionReply, MetaError> {
         let ent = LogEntry {
             txid: None,
+            time_ms: None,
             cmd: Cmd::UpsertKV {
                 key: act.key,
                 seq: act.seq,
@@ -91,4 +94,21 @@ impl KVApi for MetaNode {
 
         Ok(res)
     }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::Transaction(txn),
+        };
+        let rst = self.write(ent).await?;
+
+        match rst {
+            AppliedState::TxnReply(x) => Ok(x),
+            _ => Err(MetaError::MetaResultError(MetaResultError::InvalidType {
+                expect: "AppliedState::TxnReply".to_string(),
+                got: "other".to_string(),
+            })),
+        }
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use common_meta_types::ChangeRoleRequest;
 pub use common_meta_types::ForwardRequest;
 pub use common_meta_types::ForwardRequestBody;
 pub use common_meta_types::JoinRequest;
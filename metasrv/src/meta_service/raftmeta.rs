@@ -16,6 +16,7 @@ use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::net::Ipv4Addr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::tokio;
 use common_base::tokio::sync::watch;
@@ -64,10 +65,14 @@ use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::ForwardRequestBody;
 use crate::meta_service::JoinRequest;
 use crate::meta_service::RaftServiceImpl;
+use crate::metrics::meta_metrics::record_raft_metrics;
 use crate::network::Network;
 use crate::store::MetaRaftStore;
 use crate::Opened;
 
+/// The interval the leader checks the expired kv entries and removes them.
+const CLEAN_EXPIRED_INTERVAL: Duration = Duration::from_secs(10);
+
 // MetaRaft is a impl of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<LogEntry, AppliedState, Network, MetaRaftStore>;
 
@@ -130,6 +135,8 @@ impl MetaNodeBuilder {
             MetaNode::subscribe_metrics(mn.clone(), metrics_rx).await;
         }
 
+        MetaNode::spawn_clean_expired(mn.clone()).await;
+
         let endpoint = if let Some(a) = self.endpoint.take() {
             a
         } else {
@@ -365,6 +372,7 @@ impl MetaNode {
         let h = tokio::task::spawn(
             {
                 async move {
+                    let mut prev_leader = None;
                     loop {
                         let changed = tokio::select! {
                             _ = running_rx.changed() => {
@@ -376,6 +384,15 @@ impl MetaNode {
                         };
                         if changed.is_ok() {
                             let mm = metrics_rx.borrow().clone();
+
+                            let last_purged = mn.sto.log.get_last_purged().ok().flatten();
+                            record_raft_metrics(
+                                &mm,
+                                prev_leader,
+                                last_purged.map(|x| x.index).unwrap_or_default(),
+                            );
+                            prev_leader = mm.current_leader;
+
                             if let Some(cur) = mm.current_leader {
                                 if cur == mn.sto.id {
                                     // TODO: check result
@@ -404,6 +421,40 @@ impl MetaNode {
         jh.push(h);
     }
 
+    // spawn a task to remove the expired kv entries periodically, only the leader proposes the
+    // logs to remove them, thus every node removes the same entries.
+    pub async fn spawn_clean_expired(mn: Arc<Self>) {
+        let mut running_rx = mn.running_rx.clone();
+        let mut jh = mn.join_handles.lock().await;
+
+        let mn = mn.clone();
+
+        let span = tracing::span!(tracing::Level::INFO, "clean-expired");
+
+        let h = tokio::task::spawn(
+            async move {
+                loop {
+                    tokio::select! {
+                        _ = running_rx.changed() => {
+                            return Ok::<(), MetaError>(());
+                        }
+                        _ = tokio::time::sleep(CLEAN_EXPIRED_INTERVAL) => {}
+                    }
+
+                    if let Ok(leader) = mn.as_leader().await {
+                        match leader.clean_expired().await {
+                            Ok(0) => {}
+                            Ok(n) => tracing::info!("cleaned {} expired kv entries", n),
+                            Err(e) => tracing::warn!("fail to clean expired kv entries: {}", e),
+                        }
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        jh.push(h);
+    }
+
     /// Start MetaNode in either `boot`, `single`, `join` or `open` mode,
     /// according to config.
     #[tracing::instrument(level = "debug", skip(config))]
@@ -448,6 +499,7 @@ impl MetaNode {
                 body: ForwardRequestBody::Join(JoinRequest {
                     node_id: conf.id,
                     endpoint: raft_api_advertise_host_endpoint.clone(),
+                    as_learner: conf.learner,
                 }),
             };
 
@@ -678,6 +730,7 @@ impl MetaNode {
         let resp = self
             .write(LogEntry {
                 txid: None,
+                time_ms: None,
                 cmd: Cmd::AddNode {
                     node_id,
                     node: Node {
@@ -0,0 +1,88 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_meta_sled_store::openraft::RaftMetrics;
+use common_meta_sled_store::openraft::State;
+use common_meta_types::NodeId;
+use metrics::decrement_gauge;
+use metrics::gauge;
+use metrics::histogram;
+use metrics::increment_counter;
+use metrics::increment_gauge;
+
+// The raft state of this node.
+pub static META_RAFT_IS_LEADER: &str = "meta.raft.is_leader";
+pub static META_RAFT_HAS_LEADER: &str = "meta.raft.has_leader";
+pub static META_RAFT_LEADER_CHANGES: &str = "meta.raft.leader_changes";
+pub static META_RAFT_CURRENT_TERM: &str = "meta.raft.current_term";
+pub static META_RAFT_LAST_LOG_INDEX: &str = "meta.raft.last_log_index";
+pub static META_RAFT_APPLIED_INDEX: &str = "meta.raft.applied_index";
+// The logs that are appended but not yet applied to the state machine.
+pub static META_RAFT_APPLY_LAG: &str = "meta.raft.apply_lag";
+// The logs that are not yet purged by snapshots.
+pub static META_RAFT_LOG_SIZE: &str = "meta.raft.log_size";
+
+// The requests of the gRPC clients, labeled by the `request` type.
+pub static META_GRPC_REQUEST_DURATION: &str = "meta.grpc.request_duration_seconds";
+pub static META_GRPC_REQUEST_FAILURES: &str = "meta.grpc.request_failures";
+pub static META_GRPC_CLIENT_CONNECTIONS: &str = "meta.grpc.client_connections";
+
+/// Record the raft state of this node from the latest `RaftMetrics`.
+///
+/// `prev_leader` is the leader seen by the last call, a leader change is counted if it differs.
+pub fn record_raft_metrics(
+    metrics: &RaftMetrics,
+    prev_leader: Option<NodeId>,
+    last_purged_index: u64,
+) {
+    if metrics.current_leader != prev_leader {
+        increment_counter!(META_RAFT_LEADER_CHANGES);
+    }
+
+    let is_leader = metrics.state == State::Leader;
+    gauge!(META_RAFT_IS_LEADER, if is_leader { 1.0 } else { 0.0 });
+    let has_leader = metrics.current_leader.is_some();
+    gauge!(META_RAFT_HAS_LEADER, if has_leader { 1.0 } else { 0.0 });
+    gauge!(META_RAFT_CURRENT_TERM, metrics.current_term as f64);
+
+    let last_log_index = metrics.last_log_index.unwrap_or_default();
+    let applied_index = metrics.last_applied.map(|x| x.index).unwrap_or_default();
+    gauge!(META_RAFT_LAST_LOG_INDEX, last_log_index as f64);
+    gauge!(META_RAFT_APPLIED_INDEX, applied_index as f64);
+    gauge!(
+        META_RAFT_APPLY_LAG,
+        last_log_index.saturating_sub(applied_index) as f64
+    );
+    gauge!(
+        META_RAFT_LOG_SIZE,
+        last_log_index.saturating_sub(last_purged_index) as f64
+    );
+}
+
+pub fn record_grpc_request(request: &'static str, duration: Duration, succeeded: bool) {
+    histogram!(META_GRPC_REQUEST_DURATION, duration, "request" => request);
+    if !succeeded {
+        increment_counter!(META_GRPC_REQUEST_FAILURES, "request" => request);
+    }
+}
+
+pub fn incr_client_connections() {
+    increment_gauge!(META_GRPC_CLIENT_CONNECTIONS, 1.0);
+}
+
+pub fn decr_client_connections() {
+    decrement_gauge!(META_GRPC_CLIENT_CONNECTIONS, 1.0);
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod counted_connection;
+pub mod meta_metrics;
 mod metric_service;
 
+pub use counted_connection::CountedConnection;
 pub use metric_service::MetricService;
@@ -27,6 +27,7 @@ use common_meta_raft_store::state::RaftState;
 use common_meta_raft_store::state_machine::SerializableSnapshot;
 use common_meta_raft_store::state_machine::Snapshot;
 use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::StateMachineSubscriber;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::storage::LogState;
@@ -54,6 +55,7 @@ use openraft::StorageError;
 
 use crate::export::vec_kv_to_json;
 use crate::store::ToStorageError;
+use crate::watcher::WatchDispatcher;
 use crate::Opened;
 
 /// An storage implementing the `async_raft::RaftStorage` trait.
@@ -98,6 +100,9 @@ pub struct MetaRaftStore {
     /// - Acquire a write lock before installing a snapshot, to prevent any write to the db.
     pub state_machine: RwLock<StateMachine>,
 
+    /// Dispatches the changes applied to the state machine to the watchers.
+    pub watcher: WatchDispatcher,
+
     /// The current snapshot.
     pub current_snapshot: RwLock<Option<Snapshot>>,
 }
@@ -139,7 +144,10 @@ impl MetaRaftStore {
             raft_state.write_state_machine_id(&(sm_id, sm_id)).await?;
         }
 
-        let sm = RwLock::new(StateMachine::open(config, sm_id).await?);
+        let watcher = WatchDispatcher::create();
+        let mut sm = StateMachine::open(config, sm_id).await?;
+        sm.set_subscriber(Box::new(watcher.clone()));
+        let sm = RwLock::new(sm);
         let current_snapshot = RwLock::new(None);
 
         Ok(Self {
@@ -150,6 +158,7 @@ impl MetaRaftStore {
             raft_state,
             log,
             state_machine: sm,
+            watcher,
             current_snapshot,
         })
     }
@@ -183,7 +192,8 @@ impl MetaRaftStore {
             .write_state_machine_id(&(sm_id, new_sm_id))
             .await?;
 
-        let new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        let mut new_sm = StateMachine::open(&self.config, new_sm_id).await?;
+        new_sm.set_subscriber(Box::new(self.watcher.clone()));
         tracing::info!(
             "insert all key-value into new state machine, n={}",
             snap.kvs.len()
@@ -226,6 +236,9 @@ impl MetaRaftStore {
         // TODO(xp): use checksum to check consistency?
 
         *sm = new_sm;
+
+        // The changes made by the snapshot are unknown, the watchers have to reload.
+        self.watcher.reset();
         Ok(())
     }
 
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_base::tokio::sync::broadcast;
+use common_base::tokio::sync::broadcast::error::RecvError;
+use common_base::tokio::sync::mpsc;
+use common_meta_raft_store::state_machine::StateMachineSubscriber;
+use common_meta_types::WatchEvent;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+
+/// The max number of changes a watcher can fall behind, it is closed if it is exceeded.
+const WATCH_CHANNEL_CAPACITY: usize = 1024;
+
+const WATCH_STREAM_BUFFER: usize = 64;
+
+/// Dispatches the changes applied to the state machine to the watchers.
+///
+/// `None` is dispatched if the changes are unknown, e.g. a snapshot is installed.
+#[derive(Debug, Clone)]
+pub struct WatchDispatcher {
+    tx: broadcast::Sender<Option<WatchEvent>>,
+}
+
+impl WatchDispatcher {
+    pub fn create() -> Self {
+        let (tx, _) = broadcast::channel(WATCH_CHANNEL_CAPACITY);
+        WatchDispatcher { tx }
+    }
+
+    /// Watch the changes of the keys starting with `key_prefix`.
+    ///
+    /// The stream ends with a `DataLoss` error if some changes are lost, the watcher should
+    /// reload the data then watch again.
+    pub fn watch(&self, key_prefix: String) -> ReceiverStream<Result<WatchEvent, Status>> {
+        let mut rx = self.tx.subscribe();
+        let (tx, stream_rx) = mpsc::channel(WATCH_STREAM_BUFFER);
+
+        tokio::spawn(async move {
+            loop {
+                // Stop once the watcher drops the stream.
+                let received = tokio::select! {
+                    received = rx.recv() => received,
+                    _ = tx.closed() => break,
+                };
+
+                let item = match received {
+                    Ok(Some(event)) if event.key.starts_with(&key_prefix) => Ok(event),
+                    Ok(Some(_)) => continue,
+                    Ok(None) => Err(Status::data_loss("the state machine is replaced")),
+                    Err(RecvError::Lagged(n)) => Err(Status::data_loss(format!(
+                        "the watcher falls behind {} changes",
+                        n
+                    ))),
+                    Err(RecvError::Closed) => break,
+                };
+
+                let lost = item.is_err();
+                if tx.send(item).await.is_err() || lost {
+                    break;
+                }
+            }
+        });
+
+        ReceiverStream::new(stream_rx)
+    }
+}
+
+impl StateMachineSubscriber for WatchDispatcher {
+    fn changed(&self, event: WatchEvent) {
+        // No watcher if it fails.
+        let _ = self.tx.send(Some(event));
+    }
+
+    fn reset(&self) {
+        let _ = self.tx.send(None);
+    }
+}
@@ -54,6 +54,7 @@ admin_tls_server_key = "admin tls key"
 grpc_api_address = "127.0.0.1:10000"
 grpc_tls_server_cert = "grpc server cert"
 grpc_tls_server_key = "grpc server key"
+grpc_tls_server_root_ca_cert = "grpc server root ca cert"
 
 [raft_config]
 config_id = "raft config id"
@@ -67,6 +68,7 @@ heartbeat_interval = 2000
 install_snapshot_timeout = 3000
 single = false
 join = ["j1", "j2"]
+learner = true
 id = 20
 sled_tree_prefix = "sled_foo"
              "#
@@ -82,6 +84,7 @@ sled_tree_prefix = "sled_foo"
     assert_eq!(cfg.grpc_api_address, "127.0.0.1:10000");
     assert_eq!(cfg.grpc_tls_server_cert, "grpc server cert");
     assert_eq!(cfg.grpc_tls_server_key, "grpc server key");
+    assert_eq!(cfg.grpc_tls_server_root_ca_cert, "grpc server root ca cert");
     assert_eq!(cfg.raft_config.config_id, "raft config id");
     assert_eq!(cfg.raft_config.raft_listen_host, "127.0.0.1");
     assert_eq!(cfg.raft_config.raft_api_port, 11000);
@@ -92,6 +95,7 @@ sled_tree_prefix = "sled_foo"
     assert_eq!(cfg.raft_config.install_snapshot_timeout, 3000);
     assert!(!cfg.raft_config.single);
     assert_eq!(cfg.raft_config.join, vec!["j1", "j2"]);
+    assert!(cfg.raft_config.learner);
     assert_eq!(cfg.raft_config.id, 20);
     assert_eq!(cfg.raft_config.sled_tree_prefix, "sled_foo");
 
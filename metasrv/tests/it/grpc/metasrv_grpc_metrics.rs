@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_meta_api::KVApi;
+use common_meta_grpc::MetaGrpcClient;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+use common_metrics::init_default_metrics_recorder;
+use common_tracing::tracing::Instrument;
+
+use crate::init_meta_ut;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_metrics() -> anyhow::Result<()> {
+    // - Start a metasrv server and send requests to it.
+    // - The raft state, the requests and the client connections are recorded.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+
+    async {
+        init_default_metrics_recorder();
+
+        let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+        let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+        client
+            .upsert_kv(UpsertKVAction::new(
+                "foo",
+                MatchSeq::Any,
+                Operation::Update(b"bar".to_vec()),
+                None,
+            ))
+            .await?;
+        client.get_kv("foo").await?;
+
+        let output = common_metrics::try_handle().unwrap().render();
+        for want in [
+            "meta_raft_is_leader",
+            "meta_raft_applied_index",
+            "meta_raft_apply_lag",
+            "meta_raft_log_size",
+            "meta_grpc_client_connections",
+            "meta_grpc_request_duration_seconds",
+            r#"request="upsert_kv""#,
+            r#"request="get_kv""#,
+        ] {
+            assert!(output.contains(want), "{} not found in: {}", want, output);
+        }
+
+        Ok(())
+    }
+    .instrument(ut_span)
+    .await
+}
@@ -0,0 +1,150 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_meta_api::KVApi;
+use common_meta_api::MetaApi;
+use common_meta_grpc::MetaGrpcClient;
+use common_meta_types::database_watch_key;
+use common_meta_types::table_watch_key;
+use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReq;
+use common_meta_types::DropTableReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+use common_meta_types::WATCH_DATABASE_PREFIX;
+use common_meta_types::WATCH_TABLE_PREFIX;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
+use tokio_stream::StreamExt;
+
+use crate::init_meta_ut;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_watch_kv() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Watch a prefix and upsert keys in and out of it.
+    // - Only the changes of the keys with the prefix are received.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+
+    async {
+        let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+        let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+
+        let mut events = client.watch("a/").await?;
+
+        tracing::info!("--- upsert kv");
+        for (k, op) in [
+            ("a/1", Operation::Update(b"x".to_vec())),
+            ("b/1", Operation::Update(b"y".to_vec())),
+            ("a/1", Operation::Delete),
+            ("a/2", Operation::Delete),
+        ] {
+            client
+                .upsert_kv(UpsertKVAction::new(k, MatchSeq::Any, op, None))
+                .await?;
+        }
+
+        tracing::info!("--- check the changes");
+        let event = events.next().await.unwrap()?;
+        assert_eq!("a/1", event.key);
+        assert!(event.prev.is_none());
+        assert_eq!(b"x".to_vec(), event.current.unwrap().data);
+
+        let event = events.next().await.unwrap()?;
+        assert_eq!("a/1", event.key);
+        assert_eq!(b"x".to_vec(), event.prev.unwrap().data);
+        assert!(event.current.is_none());
+
+        // Deleting the absent `a/2` is not a change, the next one is `a/3`.
+
+        client
+            .upsert_kv(UpsertKVAction::new(
+                "a/3",
+                MatchSeq::Any,
+                Operation::Update(b"z".to_vec()),
+                None,
+            ))
+            .await?;
+        let event = events.next().await.unwrap()?;
+        assert_eq!("a/3", event.key);
+
+        Ok(())
+    }
+    .instrument(ut_span)
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_watch_ddl() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Watch the database and table changes.
+    // - Create a database, create and drop a table.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+
+    async {
+        let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+        let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+
+        let mut db_events = client.watch(WATCH_DATABASE_PREFIX).await?;
+        let mut table_events = client.watch(WATCH_TABLE_PREFIX).await?;
+
+        tracing::info!("--- create database");
+        client
+            .create_database(CreateDatabaseReq {
+                if_not_exists: false,
+                tenant: "tenant1".to_string(),
+                db: "db1".to_string(),
+                meta: Default::default(),
+            })
+            .await?;
+
+        let event = db_events.next().await.unwrap()?;
+        assert_eq!(database_watch_key("tenant1", "db1"), event.key);
+
+        tracing::info!("--- create and drop table");
+        let reply = client
+            .create_table(CreateTableReq {
+                if_not_exists: false,
+                tenant: "tenant1".to_string(),
+                db: "db1".to_string(),
+                table: "tb1".to_string(),
+                table_meta: Default::default(),
+            })
+            .await?;
+        let event = table_events.next().await.unwrap()?;
+        assert_eq!(table_watch_key(reply.table_id), event.key);
+
+        client
+            .drop_table(DropTableReq {
+                if_exists: false,
+                tenant: "tenant1".to_string(),
+                db: "db1".to_string(),
+                table: "tb1".to_string(),
+                purge: false,
+            })
+            .await?;
+        let event = table_events.next().await.unwrap()?;
+        assert_eq!(table_watch_key(reply.table_id), event.key);
+
+        Ok(())
+    }
+    .instrument(ut_span)
+    .await
+}
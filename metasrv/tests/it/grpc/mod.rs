@@ -19,4 +19,6 @@ pub mod metasrv_grpc_kv_api_restart_cluster;
 pub mod metasrv_grpc_meta_api;
 pub mod metasrv_grpc_meta_api_follower_follower;
 pub mod metasrv_grpc_meta_api_leader_follower;
+mod metasrv_grpc_metrics;
 pub mod metasrv_grpc_tls;
+mod metasrv_grpc_watch;
@@ -14,6 +14,8 @@
 
 use std::collections::BTreeSet;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_base::tokio;
 use common_base::tokio::time::Duration;
@@ -28,6 +30,7 @@ use common_meta_types::Cmd;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::Endpoint;
 use common_meta_types::ForwardToLeader;
+use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaError;
@@ -39,6 +42,7 @@ use common_meta_types::SeqV;
 use common_tracing::tracing;
 use databend_meta::configs;
 use databend_meta::meta_service::meta_leader::MetaLeader;
+use databend_meta::meta_service::ChangeRoleRequest;
 use databend_meta::meta_service::ForwardRequest;
 use databend_meta::meta_service::ForwardRequestBody;
 use databend_meta::meta_service::JoinRequest;
@@ -82,7 +86,7 @@ async fn test_meta_node_graceful_shutdown() -> anyhow::Result<()> {
     let mut rx0 = mn0.raft.metrics();
 
     let joined = mn0.stop().await?;
-    assert_eq!(3, joined);
+    assert_eq!(4, joined);
 
     // tx closed:
     loop {
@@ -122,6 +126,64 @@ async fn test_meta_node_leader_and_non_voter() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_meta_node_clean_expired() -> anyhow::Result<()> {
+    // - Start a leader and a non-voter;
+    // - Write an expiring kv to leader, clean it after it expires.
+    // - Check it is removed on every node.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let mn0 = tc0.meta_node();
+
+    let (_nid1, tc1) = start_meta_node_non_voter(mn0.clone(), 1).await?;
+    let mn1 = tc1.meta_node();
+
+    let all = vec![mn0.clone(), mn1.clone()];
+    let key = "expiring_key".to_string();
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    mn0.as_leader()
+        .await?
+        .write(LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: Cmd::UpsertKV {
+                key: key.clone(),
+                seq: MatchSeq::Exact(0),
+                value: Operation::Update(b"v".to_vec()),
+                value_meta: Some(KVMeta {
+                    expire_at: Some(now + 1),
+                }),
+            },
+        })
+        .await?;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    tracing::info!("--- clean the expired kv on leader");
+    mn0.as_leader().await?.clean_expired().await?;
+
+    let last_applied = mn0.raft.metrics().borrow().last_applied;
+    assert_applied_index(all.clone(), last_applied.index().unwrap()).await?;
+
+    for mn in all.iter() {
+        let sm = mn.sto.state_machine.read().await;
+        assert!(
+            sm.kvs().get(&key)?.is_none(),
+            "removed on node {}",
+            mn.sto.id
+        );
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 5)]
 async fn test_meta_node_write_to_local_leader() -> anyhow::Result<()> {
     // - Start a leader, 2 followers and a non-voter;
@@ -148,6 +210,7 @@ async fn test_meta_node_write_to_local_leader() -> anyhow::Result<()> {
             let rst = maybe_leader
                 .write(LogEntry {
                     txid: None,
+                    time_ms: None,
                     cmd: Cmd::UpsertKV {
                         key: key.to_string(),
                         seq: MatchSeq::Any,
@@ -208,6 +271,7 @@ async fn test_meta_node_add_database() -> anyhow::Result<()> {
             let rst = mn
                 .write(LogEntry {
                     txid: None,
+                    time_ms: None,
                     cmd: Cmd::CreateDatabase {
                         tenant: tenant.to_string(),
                         name: name.to_string(),
@@ -278,6 +342,7 @@ async fn test_meta_node_snapshot_replication() -> anyhow::Result<()> {
         let key = format!("test_meta_node_snapshot_replication-key-{}", i);
         mn.write(LogEntry {
             txid: None,
+            time_ms: None,
             cmd: Cmd::UpsertKV {
                 key: key.clone(),
                 seq: MatchSeq::Any,
@@ -516,6 +581,86 @@ async fn test_meta_node_join_rejoin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 5)]
+async fn test_meta_node_join_as_learner() -> anyhow::Result<()> {
+    // - Bring up a cluster
+    // - Join a new node as a learner, it replicates logs but is not in membership.
+    // - Promote the learner to a voter.
+    // - Demote it to a learner again, it still replicates logs.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let span = tracing::span!(tracing::Level::INFO, "test_meta_node_join_as_learner");
+    let _ent = span.enter();
+
+    let (mut _nlog, tcs) = start_meta_node_cluster(btreeset![0], btreeset![]).await?;
+    let mut all = test_context_nodes(&tcs);
+    let leader = all[0].clone();
+
+    tracing::info!("--- bring up node 1 and join it as a learner");
+
+    let node_id = 1;
+    let tc1 = MetaSrvTestContext::new(node_id);
+    let mn1 = MetaNode::open_create_boot(&tc1.config.raft_config, None, Some(()), None).await?;
+
+    let req = ForwardRequest {
+        forward_to_leader: 1,
+        body: ForwardRequestBody::Join(JoinRequest {
+            node_id,
+            endpoint: tc1.config.raft_config.raft_api_addr().await?,
+            as_learner: true,
+        }),
+    };
+    leader.handle_forwardable_request(req.clone()).await?;
+    tracing::info!("--- join node-1 as a learner again");
+    leader.handle_forwardable_request(req).await?;
+
+    all.push(mn1.clone());
+
+    wait_for_state(&mn1, State::Learner).await?;
+    assert_upsert_kv_synced(all.clone(), "learner_key").await?;
+    assert_eq!(1, leader.get_voters().await?.len());
+    assert_eq!(1, leader.get_non_voters().await?.len());
+
+    tracing::info!("--- promote node-1 to a voter");
+
+    leader
+        .handle_forwardable_request(change_role_req(node_id, false))
+        .await?;
+    for mn in all.iter() {
+        mn.raft
+            .wait(timeout())
+            .members(
+                btreeset! {0,1},
+                format!("node-1 is promoted: {}", mn.sto.id),
+            )
+            .await?;
+    }
+
+    tracing::info!("--- demote node-1 to a learner by sending request to itself");
+
+    mn1.handle_forwardable_request(change_role_req(node_id, true))
+        .await?;
+    for mn in all.iter() {
+        mn.raft
+            .wait(timeout())
+            .members(btreeset! {0}, format!("node-1 is demoted: {}", mn.sto.id))
+            .await?;
+    }
+    wait_for_state(&mn1, State::Learner).await?;
+    assert_upsert_kv_synced(all.clone(), "demoted_key").await?;
+
+    tracing::info!("--- the last voter can not be demoted");
+
+    let res = leader
+        .handle_forwardable_request(change_role_req(0, true))
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 5)]
 async fn test_meta_node_restart() -> anyhow::Result<()> {
     // TODO check restarted follower.
@@ -614,6 +759,7 @@ async fn test_meta_node_restart_single_node() -> anyhow::Result<()> {
             .await?
             .write(LogEntry {
                 txid: None,
+                time_ms: None,
                 cmd: Cmd::UpsertKV {
                     key: "foo".to_string(),
                     seq: MatchSeq::Any,
@@ -815,7 +961,21 @@ async fn start_meta_node_non_voter(
 fn join_req(node_id: NodeId, endpoint: Endpoint, forward: u64) -> ForwardRequest {
     ForwardRequest {
         forward_to_leader: forward,
-        body: ForwardRequestBody::Join(JoinRequest { node_id, endpoint }),
+        body: ForwardRequestBody::Join(JoinRequest {
+            node_id,
+            endpoint,
+            as_learner: false,
+        }),
+    }
+}
+
+fn change_role_req(node_id: NodeId, as_learner: bool) -> ForwardRequest {
+    ForwardRequest {
+        forward_to_leader: 1,
+        body: ForwardRequestBody::ChangeRole(ChangeRoleRequest {
+            node_id,
+            as_learner,
+        }),
     }
 }
 
@@ -833,6 +993,7 @@ async fn assert_upsert_kv_synced(meta_nodes: Vec<Arc<MetaNode>>, key: &str) -> a
             .await?
             .write(LogEntry {
                 txid: None,
+                time_ms: None,
                 cmd: Cmd::UpsertKV {
                     key: key.to_string(),
                     seq: MatchSeq::Any,
@@ -938,6 +1099,7 @@ async fn test_meta_node_incr_seq() -> anyhow::Result<()> {
     for (name, txid, k, want) in cases.iter() {
         let req = LogEntry {
             txid: txid.clone(),
+            time_ms: None,
             cmd: Cmd::IncrSeq { key: k.to_string() },
         };
         let raft_reply = client.write(req).await?.into_inner();
@@ -12,6 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::env;
+use std::path::Path;
+
 fn main() {
     common_building::setup();
+    build_proto();
+}
+
+fn build_proto() {
+    let manifest_dir =
+        env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR env variable unset");
+
+    let proto_dir = Path::new(&manifest_dir).join("proto");
+    let protos = [&Path::new(&proto_dir).join(Path::new("flight_sql.proto"))];
+
+    for proto in protos.iter() {
+        println!("cargo:rerun-if-changed={}", proto.to_str().unwrap());
+    }
+
+    tonic_build::configure()
+        .compile(&protos, &[&proto_dir])
+        .unwrap();
 }
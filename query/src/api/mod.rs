@@ -23,6 +23,7 @@ pub use rpc::FlightAction;
 pub use rpc::FlightClient;
 pub use rpc::FlightTicket;
 pub use rpc::ShuffleAction;
+pub use rpc::StageProgressAction;
 pub use rpc::StreamTicket;
 pub use rpc_service::RpcService;
 
@@ -115,11 +115,40 @@ impl TryInto<Vec<u8>> for CancelAction {
     }
 }
 
+/// Fetch the progress of the stages of the distributed queries running on the node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct StageProgressAction {}
+
+impl TryInto<StageProgressAction> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<StageProgressAction, Self::Error> {
+        match std::str::from_utf8(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(utf8_body) => match serde_json::from_str::<StageProgressAction>(utf8_body) {
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+                Ok(action) => Ok(action),
+            },
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for StageProgressAction {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
+            "Logical error: cannot serialize StageProgressAction."
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     PrepareShuffleAction(ShuffleAction),
     BroadcastAction(BroadcastAction),
     CancelAction(CancelAction),
+    StageProgressAction(StageProgressAction),
 }
 
 impl FlightAction {
@@ -172,6 +201,7 @@ impl TryInto<FlightAction> for Action {
             "PrepareShuffleAction" => Ok(FlightAction::PrepareShuffleAction(self.body.try_into()?)),
             "BroadcastAction" => Ok(FlightAction::BroadcastAction(self.body.try_into()?)),
             "CancelAction" => Ok(FlightAction::CancelAction(self.body.try_into()?)),
+            "StageProgressAction" => Ok(FlightAction::StageProgressAction(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -197,6 +227,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("CancelAction"),
                 body: cancel_action.try_into()?,
             }),
+            FlightAction::StageProgressAction(stage_progress_action) => Ok(Action {
+                r#type: String::from("StageProgressAction"),
+                body: stage_progress_action.try_into()?,
+            }),
         }
     }
 }
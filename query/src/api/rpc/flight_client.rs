@@ -22,15 +22,20 @@ use common_base::tokio::time::Duration;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::ToErrorCode;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use tonic::transport::channel::Channel;
+use tonic::Code;
 use tonic::Request;
+use tonic::Status;
 use tonic::Streaming;
 
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::rpc::flight_actions::StageProgressAction;
 use crate::api::rpc::flight_client_stream::FlightDataStream;
 use crate::api::rpc::flight_tickets::FlightTicket;
+use crate::sessions::QueryProgress;
 
 pub struct FlightClient {
     inner: FlightServiceClient<Channel>,
@@ -58,6 +63,15 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Fetch the progress of the stages of the distributed queries running on the node.
+    pub async fn fetch_stage_progresses(&mut self, timeout: u64) -> Result<Vec<QueryProgress>> {
+        let action = FlightAction::StageProgressAction(StageProgressAction {});
+        let body = self.do_action(action, timeout).await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::BadBytes, || {
+            "Cannot deserialize the stage progresses"
+        })
+    }
+
     // Execute do_get.
     #[tracing::instrument(level = "debug", skip_all)]
     async fn do_get(&mut self, ticket: Ticket, timeout: u64) -> Result<Streaming<FlightData>> {
@@ -78,7 +92,11 @@ impl FlightClient {
         let mut request = common_tracing::inject_span_to_tonic_request(request);
         request.set_timeout(Duration::from_secs(timeout));
 
-        let response = self.inner.do_action(request).await?;
+        let response = self
+            .inner
+            .do_action(request)
+            .await
+            .map_err(Self::action_error)?;
 
         match response.into_inner().message().await? {
             Some(response) => Ok(response.body),
@@ -87,4 +105,13 @@ impl FlightClient {
             ))),
         }
     }
+
+    // The node cannot be connected (e.g. it is down), the scheduler excludes it from the
+    // cluster by the error code.
+    fn action_error(status: Status) -> ErrorCode {
+        match status.code() {
+            Code::Unavailable => ErrorCode::CannotConnectNode(status.to_string()),
+            _ => ErrorCode::from(status),
+        }
+    }
 }
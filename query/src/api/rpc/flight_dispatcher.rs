@@ -73,6 +73,31 @@ impl DatabendQueryFlightDispatcher {
         self.abort.load(Ordering::Relaxed)
     }
 
+    /// Drop the streams of the query, the stages waiting to start are woken up, and the running
+    /// ones stop as soon as they push the blocks to the dropped streams.
+    pub fn cancel_query(&self, query_id: &str) {
+        let prefix = format!("{}/", query_id);
+        let stages_notify = {
+            let mut stages_notify = self.stages_notify.write();
+            let stages = stages_notify
+                .keys()
+                .filter(|stage_name| stage_name.starts_with(&prefix))
+                .cloned()
+                .collect::<Vec<_>>();
+            stages
+                .iter()
+                .filter_map(|stage_name| stages_notify.remove(stage_name))
+                .collect::<Vec<_>>()
+        };
+
+        self.streams
+            .write()
+            .retain(|stream_name, _| !stream_name.starts_with(&prefix));
+        for notify in stages_notify {
+            notify.notify_waiters();
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn get_stream(
         &self,
@@ -129,6 +154,7 @@ impl DatabendQueryFlightDispatcher {
     #[tracing::instrument(level = "debug", skip_all, fields(session.id = session.get_id().as_str()))]
     async fn one_sink_action(&self, session: SessionRef, action: &FlightAction) -> Result<()> {
         let query_context = session.create_query_context().await?;
+        query_context.set_current_stage(&format!("Stage {}", action.get_stage_id()));
         let action_context = QueryContext::create_from(query_context.clone());
         let pipeline_builder = PipelineBuilder::create(action_context.clone());
 
@@ -185,6 +211,7 @@ impl DatabendQueryFlightDispatcher {
         T: FlightScatter + Send + 'static,
     {
         let query_context = session.create_query_context().await?;
+        query_context.set_current_stage(&format!("Stage {}", action.get_stage_id()));
         let action_context = QueryContext::create_from(query_context.clone());
         let pipeline_builder = PipelineBuilder::create(action_context.clone());
 
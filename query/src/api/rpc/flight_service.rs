@@ -152,12 +152,19 @@ impl FlightService for DatabendQueryFlightService {
                 // We only destroy when session is exist
                 let session_id = action.query_id.clone();
                 if let Some(session) = self.sessions.get_session_by_id(&session_id).await {
-                    // TODO: remove streams
                     session.force_kill_session();
                 }
+                self.dispatcher.cancel_query(&action.query_id);
 
                 FlightResult { body: vec![] }
             }
+            FlightAction::StageProgressAction(_) => {
+                let progresses = self.sessions.stage_progresses().await;
+                match serde_json::to_vec(&progresses) {
+                    Ok(body) => FlightResult { body },
+                    Err(cause) => return Err(Status::internal(cause.to_string())),
+                }
+            }
             FlightAction::BroadcastAction(action) => {
                 let session_id = action.query_id.clone();
                 let is_aborted = self.dispatcher.is_aborted();
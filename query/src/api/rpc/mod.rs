@@ -16,6 +16,7 @@ pub use flight_actions::BroadcastAction;
 pub use flight_actions::CancelAction;
 pub use flight_actions::FlightAction;
 pub use flight_actions::ShuffleAction;
+pub use flight_actions::StageProgressAction;
 pub use flight_client::FlightClient;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
 pub use flight_service::DatabendQueryFlightService;
@@ -17,16 +17,14 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use common_arrow::arrow_format::flight::service::flight_service_server::FlightServiceServer;
-use common_base::tokio;
 use common_base::tokio::net::TcpListener;
 use common_base::tokio::sync::Notify;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_grpc::TlsIncoming;
 use common_tracing::tracing;
 use tokio_stream::wrappers::TcpListenerStream;
-use tonic::transport::Identity;
 use tonic::transport::Server;
-use tonic::transport::ServerTlsConfig;
 
 use crate::api::rpc::DatabendQueryFlightDispatcher;
 use crate::api::rpc::DatabendQueryFlightService;
@@ -64,40 +62,29 @@ impl RpcService {
         }
     }
 
-    async fn server_tls_config(conf: &Config) -> Result<ServerTlsConfig> {
-        let cert = tokio::fs::read(conf.query.rpc_tls_server_cert.as_str()).await?;
-        let key = tokio::fs::read(conf.query.rpc_tls_server_key.as_str()).await?;
-        let server_identity = Identity::from_pem(cert, key);
-        let tls_conf = ServerTlsConfig::new().identity(server_identity);
-        Ok(tls_conf)
-    }
-
     pub async fn start_with_incoming(&mut self, listener_stream: TcpListenerStream) -> Result<()> {
         let sessions = self.sessions.clone();
         let flight_dispatcher = self.dispatcher.clone();
         let flight_api_service = DatabendQueryFlightService::create(flight_dispatcher, sessions);
         let conf = self.sessions.get_conf();
-        let builder = Server::builder();
-        let mut builder = if conf.tls_rpc_server_enabled() {
+        let router = Server::builder().add_service(FlightServiceServer::new(flight_api_service));
+
+        if conf.tls_rpc_server_enabled() {
             tracing::info!("databend query tls rpc enabled");
-            builder
-                .tls_config(Self::server_tls_config(&conf).await.map_err(|e| {
-                    ErrorCode::TLSConfigurationFailure(format!(
-                        "failed to load server tls config: {e}",
-                    ))
-                })?)
-                .map_err(|e| {
-                    ErrorCode::TLSConfigurationFailure(format!("failed to invoke tls_config: {e}",))
-                })?
+            let incoming =
+                TlsIncoming::create(conf.tls_rpc_server_conf(), listener_stream.into_inner())
+                    .map_err(|e| {
+                        ErrorCode::TLSConfigurationFailure(format!(
+                            "failed to load server tls config: {e}",
+                        ))
+                    })?;
+            let server = router.serve_with_incoming_shutdown(incoming, self.shutdown_notify());
+            common_base::tokio::spawn(server);
         } else {
-            builder
-        };
-
-        let server = builder
-            .add_service(FlightServiceServer::new(flight_api_service))
-            .serve_with_incoming_shutdown(listener_stream, self.shutdown_notify());
-
-        common_base::tokio::spawn(server);
+            let server =
+                router.serve_with_incoming_shutdown(listener_stream, self.shutdown_notify());
+            common_base::tokio::spawn(server);
+        }
         Ok(())
     }
 }
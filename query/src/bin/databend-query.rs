@@ -18,7 +18,7 @@ use common_base::RuntimeTracker;
 use common_macros::databend_main;
 use common_meta_embedded::MetaEmbedded;
 use common_metrics::init_default_metrics_recorder;
-use common_tracing::init_global_tracing;
+use common_tracing::init_global_tracing_with_otlp;
 use common_tracing::set_panic_hook;
 use common_tracing::tracing;
 use databend_query::api::HttpService;
@@ -26,8 +26,11 @@ use databend_query::api::RpcService;
 use databend_query::configs::Config;
 use databend_query::metrics::MetricService;
 use databend_query::servers::ClickHouseHandler;
+use databend_query::servers::ClickHouseHttpHandler;
+use databend_query::servers::FlightSQLHandler;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
@@ -59,10 +62,11 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         conf.query.cluster_id, conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
     //let _guards = init_tracing_with_file(
-    let _guards = init_global_tracing(
+    let _guards = init_global_tracing_with_otlp(
         app_name.as_str(),
         conf.log.log_dir.as_str(),
         conf.log.log_level.as_str(),
+        conf.log.log_tracing_otlp_endpoint.as_str(),
     );
 
     init_default_metrics_recorder();
@@ -109,6 +113,54 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
             listening.port(),
         );
     }
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+
+        let mut srv = PostgresHandler::create(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        tracing::info!(
+            "PostgreSQL handler listening on {}, Usage: psql -h{} -p{} -Uroot -ddefault",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+    // Flight SQL handler.
+    {
+        let hostname = conf.query.flight_sql_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.flight_sql_handler_port);
+
+        let mut srv = FlightSQLHandler::create(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        tracing::info!(
+            "Flight SQL handler listening on {}, Usage: grpc://{}:{}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+    // ClickHouse HTTP handler.
+    {
+        let hostname = conf.query.clickhouse_http_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.clickhouse_http_handler_port);
+
+        let mut srv = ClickHouseHttpHandler::create(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        tracing::info!(
+            "ClickHouse HTTP handler listening on {}, Usage: curl 'http://{}:{}/?query=SELECT%201'",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
     // HTTP handler.
     {
         let hostname = conf.query.http_handler_host.clone();
@@ -26,9 +26,13 @@ use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
@@ -37,6 +41,10 @@ use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -131,6 +139,11 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.undrop_table(req).await })
+            .await
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
         self.query_backend(move |cli| async move { cli.rename_table(req).await })
             .await
@@ -149,6 +162,22 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn list_dropped_tables(
+        &self,
+        req: ListDroppedTableReq,
+    ) -> std::result::Result<Vec<DroppedTableInfo>, MetaError> {
+        self.query_backend(move |cli| async move { cli.list_dropped_tables(req).await })
+            .await
+    }
+
+    async fn gc_dropped_table(
+        &self,
+        req: GcDroppedTableReq,
+    ) -> std::result::Result<GcDroppedTableReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.gc_dropped_table(req).await })
+            .await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -165,6 +194,14 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> std::result::Result<UpdateTableSchemaReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.update_table_schema(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
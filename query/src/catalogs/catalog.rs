@@ -22,12 +22,19 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use dyn_clone::DynClone;
@@ -92,6 +99,19 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    // Restore the table dropped last by the name.
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply>;
+
+    // Get the dropped tables of a database that are not vacuumed yet.
+    async fn list_dropped_tables(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<DroppedTableInfo>>;
+
+    // Remove a dropped table from the meta, after its data are purged.
+    async fn gc_dropped_table(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableReply>;
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
 
     // Check a db.table is exists or not.
@@ -113,6 +133,12 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    /// Replace the schema of a table with a new version, the previous one is kept in the meta.
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply>;
+
     ///
     /// Table function
     ///
@@ -23,12 +23,19 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -270,6 +277,68 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.drop_table(req).await
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        if req.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while undrop table)",
+            ));
+        }
+        tracing::info!("Undrop table from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+        {
+            return self.immutable_catalog.undrop_table(req).await;
+        }
+        self.mutable_catalog.undrop_table(req).await
+    }
+
+    async fn list_dropped_tables(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<DroppedTableInfo>> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while list dropped tables)",
+            ));
+        }
+
+        if self
+            .immutable_catalog
+            .exists_database(tenant, db_name)
+            .await?
+        {
+            return self
+                .immutable_catalog
+                .list_dropped_tables(tenant, db_name)
+                .await;
+        }
+        self.mutable_catalog
+            .list_dropped_tables(tenant, db_name)
+            .await
+    }
+
+    async fn gc_dropped_table(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableReply> {
+        if req.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while vacuum dropped table)",
+            ));
+        }
+        tracing::info!("Gc dropped table from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+        {
+            return self.immutable_catalog.gc_dropped_table(req).await;
+        }
+        self.mutable_catalog.gc_dropped_table(req).await
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
         if req.tenant.is_empty() {
             return Err(ErrorCode::TenantIsEmpty(
@@ -302,6 +371,13 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.upsert_table_option(req).await
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        self.mutable_catalog.update_table_schema(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -22,12 +22,19 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -55,14 +62,14 @@ pub struct ImmutableCatalog {
 }
 
 impl ImmutableCatalog {
-    pub async fn try_create_with_config(_conf: &Config) -> Result<Self> {
+    pub async fn try_create_with_config(conf: &Config) -> Result<Self> {
         // The global db meta.
         let mut sys_db_meta = InMemoryMetas::create(SYS_DB_ID_BEGIN, SYS_TBL_ID_BEGIN);
         sys_db_meta.init_db("system");
         sys_db_meta.init_db("information_schema");
         sys_db_meta.init_db("INFORMATION_SCHEMA");
 
-        let sys_db = SystemDatabase::create(&mut sys_db_meta);
+        let sys_db = SystemDatabase::create(&mut sys_db_meta, conf);
         let info_schema_db = InformationSchemaDatabase::<false>::create(&mut sys_db_meta);
         let info_schema_db_upper = InformationSchemaDatabase::<true>::create(&mut sys_db_meta);
 
@@ -151,6 +158,26 @@ impl Catalog for ImmutableCatalog {
         ))
     }
 
+    async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot undrop table in system database",
+        ))
+    }
+
+    async fn list_dropped_tables(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+    ) -> Result<Vec<DroppedTableInfo>> {
+        Ok(vec![])
+    }
+
+    async fn gc_dropped_table(&self, _req: GcDroppedTableReq) -> Result<GcDroppedTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot vacuum table in system database",
+        ))
+    }
+
     async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
         Err(ErrorCode::UnImplement(
             "Cannot rename table in system database",
@@ -166,4 +193,14 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "Update table schema not allowed for system database {:?}",
+            req
+        )))
+    }
 }
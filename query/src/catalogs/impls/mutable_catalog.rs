@@ -26,9 +26,13 @@ use common_meta_types::DatabaseMeta;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DroppedTableInfo;
+use common_meta_types::GcDroppedTableReply;
+use common_meta_types::GcDroppedTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListDroppedTableReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
@@ -36,6 +40,10 @@ use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableSchemaReply;
+use common_meta_types::UpdateTableSchemaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -238,6 +246,29 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        let res = self.ctx.meta.undrop_table(req).await?;
+        Ok(res)
+    }
+
+    async fn list_dropped_tables(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<DroppedTableInfo>> {
+        let res = self
+            .ctx
+            .meta
+            .list_dropped_tables(ListDroppedTableReq::new(tenant, db_name))
+            .await?;
+        Ok(res)
+    }
+
+    async fn gc_dropped_table(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableReply> {
+        let res = self.ctx.meta.gc_dropped_table(req).await?;
+        Ok(res)
+    }
+
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
         let res = self.ctx.meta.rename_table(req).await?;
         Ok(res)
@@ -251,6 +282,14 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn update_table_schema(
+        &self,
+        req: UpdateTableSchemaReq,
+    ) -> Result<UpdateTableSchemaReply> {
+        let res = self.ctx.meta.update_table_schema(req).await?;
+        Ok(res)
+    }
+
     fn get_table_engines(&self) -> Vec<StorageDescription> {
         self.ctx.storage_factory.get_storage_descriptors()
     }
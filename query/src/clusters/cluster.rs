@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::tokio;
@@ -47,10 +49,16 @@ use crate::api::FlightClient;
 use crate::common::MetaClientProvider;
 use crate::configs::Config;
 
+/// The nodes of the cluster are registered with leases in the meta service, a node renews its
+/// lease by the heartbeats and it is removed from the cluster once the lease expires.
 pub struct ClusterDiscovery {
     local_id: String,
+    lift_time: Duration,
     heartbeat: Mutex<ClusterHeartbeat>,
     api_provider: Arc<dyn ClusterApi>,
+    // The nodes failed to connect, they are excluded from the cluster until their leases are
+    // renewed after one lift time, or expired.
+    unreachable_nodes: common_infallible::Mutex<HashMap<String, Instant>>,
 }
 
 impl ClusterDiscovery {
@@ -69,8 +77,10 @@ impl ClusterDiscovery {
 
         Ok(Arc::new(ClusterDiscovery {
             local_id: local_id.clone(),
+            lift_time,
             api_provider: provider.clone(),
             heartbeat: Mutex::new(ClusterHeartbeat::create(lift_time, provider)),
+            unreachable_nodes: common_infallible::Mutex::new(HashMap::new()),
         }))
     }
 
@@ -81,12 +91,16 @@ impl ClusterDiscovery {
         // TODO: generate if tenant or cluster id is empty
         let tenant_id = &cfg.query.tenant_id;
         let cluster_id = &cfg.query.cluster_id;
-        let lift_time = Duration::from_secs(60);
+        let lift_time = Duration::from_secs(cfg.query.cluster_lease_secs);
         let cluster_manager = ClusterMgr::create(api, tenant_id, cluster_id, lift_time)?;
 
         Ok((lift_time, Arc::new(cluster_manager)))
     }
 
+    pub fn local_id(&self) -> String {
+        self.local_id.clone()
+    }
+
     pub async fn discover(&self) -> Result<Arc<Cluster>> {
         match self.api_provider.get_nodes().await {
             Err(cause) => Err(cause.add_message_back("(while cluster api get_nodes).")),
@@ -94,6 +108,9 @@ impl ClusterDiscovery {
                 let mut res = Vec::with_capacity(cluster_nodes.len());
 
                 for node in &cluster_nodes {
+                    if node.id != self.local_id && self.is_unreachable(&node.id) {
+                        continue;
+                    }
                     res.push(Arc::new(node.clone()))
                 }
 
@@ -102,6 +119,22 @@ impl ClusterDiscovery {
         }
     }
 
+    /// Exclude the node from the cluster view, e.g. it cannot be connected while scheduling a
+    /// query, the node is back after one lift time if its lease is still renewed.
+    pub fn report_unreachable(&self, node_id: &str) {
+        if node_id != self.local_id {
+            tracing::warn!("Cluster node {} is unreachable", node_id);
+            let mut unreachable_nodes = self.unreachable_nodes.lock();
+            unreachable_nodes.insert(node_id.to_string(), Instant::now());
+        }
+    }
+
+    fn is_unreachable(&self, node_id: &str) -> bool {
+        let mut unreachable_nodes = self.unreachable_nodes.lock();
+        unreachable_nodes.retain(|_, reported_at| reported_at.elapsed() < self.lift_time);
+        unreachable_nodes.contains_key(node_id)
+    }
+
     async fn drop_invalid_nodes(self: &Arc<Self>, node_info: &NodeInfo) -> Result<()> {
         let current_nodes_info = match self.api_provider.get_nodes().await {
             Ok(nodes) => nodes,
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use clap::Parser;
+use common_base::get_available_cpus;
 use common_base::Format;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::RpcClientTlsConfig;
+use common_grpc::RpcServerTlsConfig;
 use once_cell::sync::Lazy;
 use serde::Deserialize;
 use serde::Serialize;
@@ -88,7 +90,7 @@ impl Config {
     pub fn load_from_args() -> Self {
         let mut cfg = Config::parse();
         if cfg.query.num_cpus == 0 {
-            cfg.query.num_cpus = num_cpus::get() as u64;
+            cfg.query.num_cpus = get_available_cpus() as u64;
         }
         cfg
     }
@@ -101,7 +103,7 @@ impl Config {
         let format = Format::from_path(file)?;
         let mut cfg: Config = format.load_config(&txt)?;
         if cfg.query.num_cpus == 0 {
-            cfg.query.num_cpus = num_cpus::get() as u64;
+            cfg.query.num_cpus = get_available_cpus() as u64;
         }
         Ok(cfg)
     }
@@ -134,6 +136,16 @@ impl Config {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.query.rpc_tls_query_server_root_ca_cert.to_string(),
             domain_name: self.query.rpc_tls_query_service_domain_name.to_string(),
+            rpc_tls_client_cert: self.query.rpc_tls_query_client_cert.to_string(),
+            rpc_tls_client_key: self.query.rpc_tls_query_client_key.to_string(),
+        }
+    }
+
+    pub fn tls_rpc_server_conf(&self) -> RpcServerTlsConfig {
+        RpcServerTlsConfig {
+            rpc_tls_server_cert: self.query.rpc_tls_server_cert.to_string(),
+            rpc_tls_server_key: self.query.rpc_tls_server_key.to_string(),
+            rpc_tls_server_root_ca_cert: self.query.rpc_tls_server_client_ca_cert.to_string(),
         }
     }
 
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use clap::Args;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -22,6 +24,10 @@ use crate::configs::Config;
 pub const LOG_LEVEL: &str = "LOG_LEVEL";
 pub const LOG_DIR: &str = "LOG_DIR";
 pub const LOG_QUERY_ENABLED: &str = "LOG_QUERY_ENABLED";
+pub const LOG_QUERY_RETENTION_HOURS: &str = "LOG_QUERY_RETENTION_HOURS";
+pub const LOG_AUDIT_SINKS: &str = "LOG_AUDIT_SINKS";
+pub const LOG_AUDIT_MAX_ROWS: &str = "LOG_AUDIT_MAX_ROWS";
+pub const LOG_TRACING_OTLP_ENDPOINT: &str = "LOG_TRACING_OTLP_ENDPOINT";
 
 /// Log config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -39,6 +45,38 @@ pub struct LogConfig {
     /// Log file dir
     #[clap(long, env = LOG_QUERY_ENABLED)]
     pub log_query_enabled: bool,
+
+    /// The records older than the hours are removed from system.query_log, 0 to keep them
+    /// until the `max_query_log_size` is reached.
+    #[clap(long, env = LOG_QUERY_RETENTION_HOURS, default_value = "0")]
+    pub log_query_retention_hours: u64,
+
+    /// The sinks of the audit log of the statements, comma separated <table|file>. `table` is
+    /// system.audit_log, `file` is the `audit` files in the log dir. Empty to disable it.
+    #[clap(long, env = LOG_AUDIT_SINKS, default_value = "")]
+    pub log_audit_sinks: String,
+
+    /// The max number of the records kept in system.audit_log.
+    #[clap(long, env = LOG_AUDIT_MAX_ROWS, default_value = "100000")]
+    pub log_audit_max_rows: u64,
+
+    /// The OpenTelemetry collector endpoint the tracing spans are exported to with the OTLP
+    /// gRPC protocol, e.g. `http://127.0.0.1:4317`. Empty to send them to the local jaeger agent.
+    #[clap(long, env = LOG_TRACING_OTLP_ENDPOINT, default_value = "")]
+    pub log_tracing_otlp_endpoint: String,
+}
+
+/// Where the audit log is written to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AuditSinks {
+    pub table: bool,
+    pub file: bool,
+}
+
+impl AuditSinks {
+    pub fn enabled(&self) -> bool {
+        self.table || self.file
+    }
 }
 
 impl Default for LogConfig {
@@ -47,6 +85,10 @@ impl Default for LogConfig {
             log_level: "INFO".to_string(),
             log_dir: "./_logs".to_string(),
             log_query_enabled: false,
+            log_query_retention_hours: 0,
+            log_audit_sinks: "".to_string(),
+            log_audit_max_rows: 100000,
+            log_tracing_otlp_endpoint: "".to_string(),
         }
     }
 }
@@ -56,5 +98,39 @@ impl LogConfig {
         env_helper!(mut_config, log, log_level, String, LOG_LEVEL);
         env_helper!(mut_config, log, log_dir, String, LOG_DIR);
         env_helper!(mut_config, log, log_query_enabled, bool, LOG_QUERY_ENABLED);
+        env_helper!(
+            mut_config,
+            log,
+            log_query_retention_hours,
+            u64,
+            LOG_QUERY_RETENTION_HOURS
+        );
+        env_helper!(mut_config, log, log_audit_sinks, String, LOG_AUDIT_SINKS);
+        env_helper!(mut_config, log, log_audit_max_rows, u64, LOG_AUDIT_MAX_ROWS);
+        env_helper!(
+            mut_config,
+            log,
+            log_tracing_otlp_endpoint,
+            String,
+            LOG_TRACING_OTLP_ENDPOINT
+        );
+    }
+
+    pub fn audit_sinks(&self) -> Result<AuditSinks> {
+        let mut sinks = AuditSinks::default();
+        for sink in self.log_audit_sinks.split(',').map(str::trim) {
+            match sink.to_lowercase().as_str() {
+                "" => {}
+                "table" => sinks.table = true,
+                "file" => sinks.file = true,
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "Invalid log_audit_sinks '{}', expect table or file",
+                        sink
+                    )));
+                }
+            }
+        }
+        Ok(sinks)
     }
 }
@@ -30,6 +30,8 @@ pub const META_PASSWORD: &str = "META_PASSWORD";
 pub const META_EMBEDDED_DIR: &str = "META_EMBEDDED_DIR";
 pub const META_RPC_TLS_SERVER_ROOT_CA_CERT: &str = "META_RPC_TLS_SERVER_ROOT_CA_CERT";
 pub const META_RPC_TLS_SERVICE_DOMAIN_NAME: &str = "META_RPC_TLS_SERVICE_DOMAIN_NAME";
+pub const META_RPC_TLS_CLIENT_CERT: &str = "META_RPC_TLS_CLIENT_CERT";
+pub const META_RPC_TLS_CLIENT_KEY: &str = "META_RPC_TLS_CLIENT_KEY";
 
 /// Meta config group.
 #[derive(Clone, PartialEq, Serialize, Deserialize, Args)]
@@ -69,6 +71,17 @@ pub struct MetaConfig {
         default_value = "localhost"
     )]
     pub rpc_tls_meta_service_domain_name: String,
+
+    #[clap(
+        long,
+        env = "META_RPC_TLS_CLIENT_CERT",
+        default_value = "",
+        help = "Certificate for client to identify itself to meta rpc server"
+    )]
+    pub rpc_tls_meta_client_cert: String,
+
+    #[clap(long, env = "META_RPC_TLS_CLIENT_KEY", default_value = "")]
+    pub rpc_tls_meta_client_key: String,
 }
 
 impl Default for MetaConfig {
@@ -81,6 +94,8 @@ impl Default for MetaConfig {
             meta_client_timeout_in_second: 10,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
+            rpc_tls_meta_client_cert: "".to_string(),
+            rpc_tls_meta_client_key: "".to_string(),
         }
     }
 }
@@ -104,6 +119,20 @@ impl MetaConfig {
             String,
             META_RPC_TLS_SERVICE_DOMAIN_NAME
         );
+        env_helper!(
+            mut_config,
+            meta,
+            rpc_tls_meta_client_cert,
+            String,
+            META_RPC_TLS_CLIENT_CERT
+        );
+        env_helper!(
+            mut_config,
+            meta,
+            rpc_tls_meta_client_key,
+            String,
+            META_RPC_TLS_CLIENT_KEY
+        );
     }
 
     pub fn is_tls_enabled(&self) -> bool {
@@ -119,6 +148,8 @@ impl MetaConfig {
         Some(RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert.clone(),
             domain_name: self.rpc_tls_meta_service_domain_name.clone(),
+            rpc_tls_client_cert: self.rpc_tls_meta_client_cert.clone(),
+            rpc_tls_client_key: self.rpc_tls_meta_client_key.clone(),
         })
     }
 
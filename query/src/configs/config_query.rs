@@ -25,8 +25,18 @@ pub const QUERY_NUM_CPUS: &str = "QUERY_NUM_CPUS";
 pub const QUERY_MYSQL_HANDLER_HOST: &str = "QUERY_MYSQL_HANDLER_HOST";
 pub const QUERY_MYSQL_HANDLER_PORT: &str = "QUERY_MYSQL_HANDLER_PORT";
 pub const QUERY_MAX_ACTIVE_SESSIONS: &str = "QUERY_MAX_ACTIVE_SESSIONS";
+pub const QUERY_MAX_RUNNING_QUERIES: &str = "QUERY_MAX_RUNNING_QUERIES";
+pub const QUERY_MAX_RUNNING_QUERIES_PER_USER: &str = "QUERY_MAX_RUNNING_QUERIES_PER_USER";
+pub const QUERY_QUEUE_TIMEOUT_SECS: &str = "QUERY_QUEUE_TIMEOUT_SECS";
+pub const QUERY_SESSION_IDLE_TIMEOUT_SECS: &str = "QUERY_SESSION_IDLE_TIMEOUT_SECS";
 pub const QUERY_CLICKHOUSE_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HANDLER_HOST";
 pub const QUERY_CLICKHOUSE_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_PORT";
+pub const QUERY_POSTGRES_HANDLER_HOST: &str = "QUERY_POSTGRES_HANDLER_HOST";
+pub const QUERY_POSTGRES_HANDLER_PORT: &str = "QUERY_POSTGRES_HANDLER_PORT";
+pub const QUERY_FLIGHT_SQL_HANDLER_HOST: &str = "QUERY_FLIGHT_SQL_HANDLER_HOST";
+pub const QUERY_FLIGHT_SQL_HANDLER_PORT: &str = "QUERY_FLIGHT_SQL_HANDLER_PORT";
+pub const QUERY_CLICKHOUSE_HTTP_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HTTP_HANDLER_HOST";
+pub const QUERY_CLICKHOUSE_HTTP_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HTTP_HANDLER_PORT";
 pub const QUERY_HTTP_HANDLER_HOST: &str = "QUERY_HTTP_HANDLER_HOST";
 pub const QUERY_HTTP_HANDLER_PORT: &str = "QUERY_HTTP_HANDLER_PORT";
 pub const QUERY_HTTP_HANDLER_RESULT_TIMEOUT_MILLIS: &str =
@@ -36,6 +46,8 @@ pub const QUERY_ADMIN_API_ADDRESS: &str = "QUERY_ADMIN_API_ADDRESS";
 pub const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
 pub const QUERY_WAIT_TIMEOUT_MILLS: &str = "QUERY_WAIT_TIMEOUT_MILLS";
 pub const QUERY_MAX_QUERY_LOG_SIZE: &str = "QUERY_MAX_QUERY_LOG_SIZE";
+pub const QUERY_RESULT_CACHE_MAX_BYTES: &str = "QUERY_RESULT_CACHE_MAX_BYTES";
+pub const QUERY_UDF_SERVER_ALLOW_LIST: &str = "QUERY_UDF_SERVER_ALLOW_LIST";
 pub const QUERY_TABLE_CACHE_ENABLED: &str = "QUERY_TABLE_CACHE_ENABLED";
 pub const QUERY_TABLE_CACHE_SNAPSHOT_COUNT: &str = "QUERY_TABLE_CACHE_SNAPSHOT_COUNT";
 pub const QUERY_TABLE_CACHE_SEGMENT_COUNT: &str = "QUERY_TABLE_CACHE_SEGMENT_COUNT";
@@ -49,6 +61,11 @@ const QUERY_HTTP_HANDLER_TLS_SERVER_KEY: &str = "QUERY_HTTP_HANDLER_TLS_SERVER_K
 const QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT: &str =
     "QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT";
 
+const QUERY_MYSQL_HANDLER_TLS_SERVER_CERT: &str = "QUERY_MYSQL_HANDLER_TLS_SERVER_CERT";
+const QUERY_MYSQL_HANDLER_TLS_SERVER_KEY: &str = "QUERY_MYSQL_HANDLER_TLS_SERVER_KEY";
+const QUERY_MYSQL_HANDLER_TLS_SERVER_ROOT_CA_CERT: &str =
+    "QUERY_MYSQL_HANDLER_TLS_SERVER_ROOT_CA_CERT";
+
 const QUERY_API_TLS_SERVER_CERT: &str = "QUERY_API_TLS_SERVER_CERT";
 const QUERY_API_TLS_SERVER_KEY: &str = "QUERY_API_TLS_SERVER_KEY";
 const QUERY_API_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_API_TLS_SERVER_ROOT_CA_CERT";
@@ -57,6 +74,9 @@ const QUERY_RPC_TLS_SERVER_CERT: &str = "QUERY_RPC_TLS_SERVER_CERT";
 const QUERY_RPC_TLS_SERVER_KEY: &str = "QUERY_RPC_TLS_SERVER_KEY";
 const QUERY_RPC_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_RPC_TLS_SERVER_ROOT_CA_CERT";
 const QUERY_RPC_TLS_SERVICE_DOMAIN_NAME: &str = "QUERY_RPC_TLS_SERVICE_DOMAIN_NAME";
+const QUERY_RPC_TLS_SERVER_CLIENT_CA_CERT: &str = "QUERY_RPC_TLS_SERVER_CLIENT_CA_CERT";
+const QUERY_RPC_TLS_CLIENT_CERT: &str = "QUERY_RPC_TLS_CLIENT_CERT";
+const QUERY_RPC_TLS_CLIENT_KEY: &str = "QUERY_RPC_TLS_CLIENT_KEY";
 
 const QUERY_TABLE_ENGINE_CSV_ENABLED: &str = "QUERY_TABLE_ENGINE_CSV_ENABLED";
 const QUERY_TABLE_ENGINE_PARQUET_ENABLED: &str = "QUERY_TABLE_ENGINE_PARQUET_ENABLED";
@@ -65,6 +85,25 @@ const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB
 
 const QUERY_MANAGEMENT_MODE: &str = "QUERY_MANAGEMENT_MODE";
 const QUERY_JWT_KEY_FILE: &str = "QUERY_JWT_KEY_FILE";
+const QUERY_JWT_USER_CLAIM: &str = "QUERY_JWT_USER_CLAIM";
+const QUERY_JWT_ROLE_CLAIM: &str = "QUERY_JWT_ROLE_CLAIM";
+const QUERY_JWT_TENANT_CLAIM: &str = "QUERY_JWT_TENANT_CLAIM";
+const QUERY_LDAP_URL: &str = "QUERY_LDAP_URL";
+const QUERY_LDAP_BIND_DN_TEMPLATE: &str = "QUERY_LDAP_BIND_DN_TEMPLATE";
+const QUERY_LDAP_GROUP_SEARCH_BASE: &str = "QUERY_LDAP_GROUP_SEARCH_BASE";
+const QUERY_LDAP_GROUP_FILTER: &str = "QUERY_LDAP_GROUP_FILTER";
+const QUERY_LDAP_GROUP_ROLE_MAPPING: &str = "QUERY_LDAP_GROUP_ROLE_MAPPING";
+const QUERY_LDAP_POOL_SIZE: &str = "QUERY_LDAP_POOL_SIZE";
+const QUERY_PASSWORD_MIN_LENGTH: &str = "QUERY_PASSWORD_MIN_LENGTH";
+const QUERY_PASSWORD_REQUIRE_MIXED_CASE: &str = "QUERY_PASSWORD_REQUIRE_MIXED_CASE";
+const QUERY_PASSWORD_REQUIRE_DIGIT: &str = "QUERY_PASSWORD_REQUIRE_DIGIT";
+const QUERY_PASSWORD_REQUIRE_SPECIAL_CHAR: &str = "QUERY_PASSWORD_REQUIRE_SPECIAL_CHAR";
+const QUERY_PASSWORD_EXPIRE_DAYS: &str = "QUERY_PASSWORD_EXPIRE_DAYS";
+const QUERY_PASSWORD_HISTORY: &str = "QUERY_PASSWORD_HISTORY";
+const QUERY_PASSWORD_MAX_FAILED_ATTEMPTS: &str = "QUERY_PASSWORD_MAX_FAILED_ATTEMPTS";
+const QUERY_PASSWORD_LOCKOUT_MINUTES: &str = "QUERY_PASSWORD_LOCKOUT_MINUTES";
+const QUERY_NETWORK_POLICY: &str = "QUERY_NETWORK_POLICY";
+const QUERY_CLUSTER_LEASE_SECS: &str = "QUERY_CLUSTER_LEASE_SECS";
 
 /// Query config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -90,12 +129,48 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_MAX_ACTIVE_SESSIONS, default_value = "256")]
     pub max_active_sessions: u64,
 
+    /// The maximum number of the queries running at the same time, the others wait in the queue.
+    /// 0 means no limit.
+    #[clap(long, env = QUERY_MAX_RUNNING_QUERIES, default_value = "0")]
+    pub max_running_queries: u64,
+
+    /// The maximum number of the queries of a user running at the same time, 0 means no limit.
+    #[clap(long, env = QUERY_MAX_RUNNING_QUERIES_PER_USER, default_value = "0")]
+    pub max_running_queries_per_user: u64,
+
+    /// The maximum time in seconds a query waits in the queue before it fails.
+    #[clap(long, env = QUERY_QUEUE_TIMEOUT_SECS, default_value = "60")]
+    pub query_queue_timeout_secs: u64,
+
+    /// The sessions of the connections idle longer than the seconds are closed, to release the
+    /// resources held by the abandoned connections. 0 means never.
+    #[clap(long, env = QUERY_SESSION_IDLE_TIMEOUT_SECS, default_value = "28800")]
+    pub session_idle_timeout_secs: u64,
+
     #[clap(long, env = QUERY_CLICKHOUSE_HANDLER_HOST, default_value = "127.0.0.1")]
     pub clickhouse_handler_host: String,
 
     #[clap(long, env = QUERY_CLICKHOUSE_HANDLER_PORT, default_value = "9000")]
     pub clickhouse_handler_port: u16,
 
+    #[clap(long, env = QUERY_POSTGRES_HANDLER_HOST, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, env = QUERY_POSTGRES_HANDLER_PORT, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, env = QUERY_FLIGHT_SQL_HANDLER_HOST, default_value = "127.0.0.1")]
+    pub flight_sql_handler_host: String,
+
+    #[clap(long, env = QUERY_FLIGHT_SQL_HANDLER_PORT, default_value = "8900")]
+    pub flight_sql_handler_port: u16,
+
+    #[clap(long, env = QUERY_CLICKHOUSE_HTTP_HANDLER_HOST, default_value = "127.0.0.1")]
+    pub clickhouse_http_handler_host: String,
+
+    #[clap(long, env = QUERY_CLICKHOUSE_HTTP_HANDLER_PORT, default_value = "8124")]
+    pub clickhouse_http_handler_port: u16,
+
     #[clap(long, env = QUERY_HTTP_HANDLER_HOST, default_value = "127.0.0.1")]
     pub http_handler_host: String,
 
@@ -123,6 +198,16 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT, default_value = "")]
     pub http_handler_tls_server_root_ca_cert: String,
 
+    #[clap(long, env = QUERY_MYSQL_HANDLER_TLS_SERVER_CERT, default_value = "")]
+    pub mysql_handler_tls_server_cert: String,
+
+    #[clap(long, env = QUERY_MYSQL_HANDLER_TLS_SERVER_KEY, default_value = "")]
+    pub mysql_handler_tls_server_key: String,
+
+    /// The CA to verify the client certificates, the clients are not verified if it is empty.
+    #[clap(long, env = QUERY_MYSQL_HANDLER_TLS_SERVER_ROOT_CA_CERT, default_value = "")]
+    pub mysql_handler_tls_server_root_ca_cert: String,
+
     #[clap(long, env = QUERY_API_TLS_SERVER_CERT, default_value = "")]
     pub api_tls_server_cert: String,
 
@@ -140,6 +225,11 @@ pub struct QueryConfig {
     #[clap(long, env = "QUERY_RPC_TLS_SERVER_KEY", default_value = "")]
     pub rpc_tls_server_key: String,
 
+    /// The CA to verify the certificates of the other query nodes, the rpc server does not
+    /// verify the clients if it is empty.
+    #[clap(long, env = QUERY_RPC_TLS_SERVER_CLIENT_CA_CERT, default_value = "")]
+    pub rpc_tls_server_client_ca_cert: String,
+
     /// Certificate for client to identify query rpc server
     #[clap(long, env = "QUERY_RPC_TLS_SERVER_ROOT_CA_CERT", default_value = "")]
     pub rpc_tls_query_server_root_ca_cert: String,
@@ -151,6 +241,13 @@ pub struct QueryConfig {
     )]
     pub rpc_tls_query_service_domain_name: String,
 
+    /// Certificate and key for rpc client to identify itself to the other query nodes
+    #[clap(long, env = QUERY_RPC_TLS_CLIENT_CERT, default_value = "")]
+    pub rpc_tls_query_client_cert: String,
+
+    #[clap(long, env = QUERY_RPC_TLS_CLIENT_KEY, default_value = "")]
+    pub rpc_tls_query_client_key: String,
+
     /// Table engine csv enabled
     #[clap(long, env = QUERY_TABLE_ENGINE_CSV_ENABLED)]
     pub table_engine_csv_enabled: bool,
@@ -180,9 +277,20 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_WAIT_TIMEOUT_MILLS, default_value = "5000")]
     pub wait_timeout_mills: u64,
 
+    /// The max number of the records kept in system.query_log.
     #[clap(long, env = QUERY_MAX_QUERY_LOG_SIZE, default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// The maximum bytes of the query results cached on the node, the larger results are not
+    /// cached and the least recently used ones are evicted.
+    #[clap(long, env = QUERY_RESULT_CACHE_MAX_BYTES, default_value = "1073741824")]
+    pub query_result_cache_max_bytes: u64,
+
+    /// The addresses of the UDF servers the external UDFs may call, `host:port` separated by
+    /// commas, no UDF server may be called by default.
+    #[clap(long, env = QUERY_UDF_SERVER_ALLOW_LIST, default_value = "")]
+    pub udf_server_allow_list: String,
+
     /// Table Cached enabled
     #[clap(long, env = QUERY_TABLE_CACHE_ENABLED)]
     pub table_cache_enabled: bool,
@@ -217,6 +325,87 @@ pub struct QueryConfig {
 
     #[clap(long, env = QUERY_JWT_KEY_FILE, default_value = "")]
     pub jwt_key_file: String,
+
+    /// The claim of the JWT to get the user name from.
+    #[clap(long, env = QUERY_JWT_USER_CLAIM, default_value = "sub")]
+    pub jwt_user_claim: String,
+
+    /// The claim of the JWT to get the role granted to the session from, no role is granted
+    /// if it's empty.
+    #[clap(long, env = QUERY_JWT_ROLE_CLAIM, default_value = "")]
+    pub jwt_role_claim: String,
+
+    /// The claim of the JWT to get the tenant the token is issued for from, the token is only
+    /// accepted in the tenant of the config if it's empty or the claim is missing.
+    #[clap(long, env = QUERY_JWT_TENANT_CLAIM, default_value = "")]
+    pub jwt_tenant_claim: String,
+
+    /// The LDAP server to authenticate the users `IDENTIFIED WITH ldap`, e.g. ldap://127.0.0.1:389.
+    #[clap(long, env = QUERY_LDAP_URL, default_value = "")]
+    pub ldap_url: String,
+
+    /// The DN to bind the users with, `{user}` is replaced by the user name,
+    /// e.g. uid={user},ou=people,dc=example,dc=com.
+    #[clap(long, env = QUERY_LDAP_BIND_DN_TEMPLATE, default_value = "")]
+    pub ldap_bind_dn_template: String,
+
+    /// The base DN to search the groups of the users, the groups are not searched if it's empty.
+    #[clap(long, env = QUERY_LDAP_GROUP_SEARCH_BASE, default_value = "")]
+    pub ldap_group_search_base: String,
+
+    /// The filter of the groups of a user, `{dn}` and `{user}` are replaced by the DN and the
+    /// name of the user.
+    #[clap(long, env = QUERY_LDAP_GROUP_FILTER, default_value = "(member={dn})")]
+    pub ldap_group_filter: String,
+
+    /// The roles granted to the members of the groups, e.g. analysts:analyst,dbas:admin.
+    #[clap(long, env = QUERY_LDAP_GROUP_ROLE_MAPPING, default_value = "")]
+    pub ldap_group_role_mapping: String,
+
+    /// The max number of the pooled connections to the LDAP server.
+    #[clap(long, env = QUERY_LDAP_POOL_SIZE, default_value = "8")]
+    pub ldap_pool_size: u64,
+
+    /// The min length of the passwords of the users, 0 for no limit.
+    #[clap(long, env = QUERY_PASSWORD_MIN_LENGTH, default_value = "0")]
+    pub password_min_length: u64,
+
+    /// The passwords must have both the lowercase and the uppercase letters.
+    #[clap(long, env = QUERY_PASSWORD_REQUIRE_MIXED_CASE)]
+    pub password_require_mixed_case: bool,
+
+    /// The passwords must have a digit.
+    #[clap(long, env = QUERY_PASSWORD_REQUIRE_DIGIT)]
+    pub password_require_digit: bool,
+
+    /// The passwords must have a character other than the letters and the digits.
+    #[clap(long, env = QUERY_PASSWORD_REQUIRE_SPECIAL_CHAR)]
+    pub password_require_special_char: bool,
+
+    /// The days the passwords expire after they are set, 0 for never.
+    #[clap(long, env = QUERY_PASSWORD_EXPIRE_DAYS, default_value = "0")]
+    pub password_expire_days: u64,
+
+    /// The number of the previous passwords a user cannot reuse.
+    #[clap(long, env = QUERY_PASSWORD_HISTORY, default_value = "0")]
+    pub password_history: u64,
+
+    /// The failed logins in a row to lock the user, 0 for no lockout.
+    #[clap(long, env = QUERY_PASSWORD_MAX_FAILED_ATTEMPTS, default_value = "0")]
+    pub password_max_failed_attempts: u64,
+
+    /// The minutes the user is locked after too many failed logins.
+    #[clap(long, env = QUERY_PASSWORD_LOCKOUT_MINUTES, default_value = "10")]
+    pub password_lockout_minutes: u64,
+
+    /// The network policy checked for all the users on login, in addition to their own ones.
+    #[clap(long, env = QUERY_NETWORK_POLICY, default_value = "")]
+    pub network_policy: String,
+
+    /// The lease of the node in the cluster, it is renewed by the heartbeats. A node is removed
+    /// from the cluster if its lease is not renewed in time.
+    #[clap(long, env = QUERY_CLUSTER_LEASE_SECS, default_value = "60")]
+    pub cluster_lease_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -228,8 +417,18 @@ impl Default for QueryConfig {
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
             max_active_sessions: 256,
+            max_running_queries: 0,
+            max_running_queries_per_user: 0,
+            query_queue_timeout_secs: 60,
+            session_idle_timeout_secs: 28800,
             clickhouse_handler_host: "127.0.0.1".to_string(),
             clickhouse_handler_port: 9000,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
+            flight_sql_handler_host: "127.0.0.1".to_string(),
+            flight_sql_handler_port: 8900,
+            clickhouse_http_handler_host: "127.0.0.1".to_string(),
+            clickhouse_http_handler_port: 8124,
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_millis: 10000,
@@ -242,16 +441,24 @@ impl Default for QueryConfig {
             http_handler_tls_server_cert: "".to_string(),
             http_handler_tls_server_key: "".to_string(),
             http_handler_tls_server_root_ca_cert: "".to_string(),
+            mysql_handler_tls_server_cert: "".to_string(),
+            mysql_handler_tls_server_key: "".to_string(),
+            mysql_handler_tls_server_root_ca_cert: "".to_string(),
             rpc_tls_server_cert: "".to_string(),
             rpc_tls_server_key: "".to_string(),
+            rpc_tls_server_client_ca_cert: "".to_string(),
             rpc_tls_query_server_root_ca_cert: "".to_string(),
             rpc_tls_query_service_domain_name: "localhost".to_string(),
+            rpc_tls_query_client_cert: "".to_string(),
+            rpc_tls_query_client_key: "".to_string(),
             table_engine_csv_enabled: false,
             table_engine_parquet_enabled: false,
             table_engine_memory_enabled: true,
             database_engine_github_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
+            query_result_cache_max_bytes: 1073741824,
+            udf_server_allow_list: "".to_string(),
             table_cache_enabled: false,
             table_cache_snapshot_count: 256,
             table_cache_segment_count: 10240,
@@ -261,6 +468,25 @@ impl Default for QueryConfig {
             table_disk_cache_mb_size: 1024,
             management_mode: false,
             jwt_key_file: "".to_string(),
+            jwt_user_claim: "sub".to_string(),
+            jwt_role_claim: "".to_string(),
+            jwt_tenant_claim: "".to_string(),
+            ldap_url: "".to_string(),
+            ldap_bind_dn_template: "".to_string(),
+            ldap_group_search_base: "".to_string(),
+            ldap_group_filter: "(member={dn})".to_string(),
+            ldap_group_role_mapping: "".to_string(),
+            ldap_pool_size: 8,
+            password_min_length: 0,
+            password_require_mixed_case: false,
+            password_require_digit: false,
+            password_require_special_char: false,
+            password_expire_days: 0,
+            password_history: 0,
+            password_max_failed_attempts: 0,
+            password_lockout_minutes: 10,
+            network_policy: "".to_string(),
+            cluster_lease_secs: 60,
         }
     }
 }
@@ -291,6 +517,34 @@ impl QueryConfig {
             u64,
             QUERY_MAX_ACTIVE_SESSIONS
         );
+        env_helper!(
+            mut_config,
+            query,
+            max_running_queries,
+            u64,
+            QUERY_MAX_RUNNING_QUERIES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            max_running_queries_per_user,
+            u64,
+            QUERY_MAX_RUNNING_QUERIES_PER_USER
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_queue_timeout_secs,
+            u64,
+            QUERY_QUEUE_TIMEOUT_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            session_idle_timeout_secs,
+            u64,
+            QUERY_SESSION_IDLE_TIMEOUT_SECS
+        );
         env_helper!(
             mut_config,
             query,
@@ -305,6 +559,48 @@ impl QueryConfig {
             u16,
             QUERY_CLICKHOUSE_HANDLER_PORT
         );
+        env_helper!(
+            mut_config,
+            query,
+            postgres_handler_host,
+            String,
+            QUERY_POSTGRES_HANDLER_HOST
+        );
+        env_helper!(
+            mut_config,
+            query,
+            postgres_handler_port,
+            u16,
+            QUERY_POSTGRES_HANDLER_PORT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            flight_sql_handler_host,
+            String,
+            QUERY_FLIGHT_SQL_HANDLER_HOST
+        );
+        env_helper!(
+            mut_config,
+            query,
+            flight_sql_handler_port,
+            u16,
+            QUERY_FLIGHT_SQL_HANDLER_PORT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_http_handler_host,
+            String,
+            QUERY_CLICKHOUSE_HTTP_HANDLER_HOST
+        );
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_http_handler_port,
+            u16,
+            QUERY_CLICKHOUSE_HTTP_HANDLER_PORT
+        );
         env_helper!(
             mut_config,
             query,
@@ -369,6 +665,31 @@ impl QueryConfig {
             QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT
         );
 
+        // for mysql handler
+        env_helper!(
+            mut_config,
+            query,
+            mysql_handler_tls_server_cert,
+            String,
+            QUERY_MYSQL_HANDLER_TLS_SERVER_CERT
+        );
+
+        env_helper!(
+            mut_config,
+            query,
+            mysql_handler_tls_server_key,
+            String,
+            QUERY_MYSQL_HANDLER_TLS_SERVER_KEY
+        );
+
+        env_helper!(
+            mut_config,
+            query,
+            mysql_handler_tls_server_root_ca_cert,
+            String,
+            QUERY_MYSQL_HANDLER_TLS_SERVER_ROOT_CA_CERT
+        );
+
         env_helper!(
             mut_config,
             query,
@@ -394,6 +715,14 @@ impl QueryConfig {
             QUERY_RPC_TLS_SERVER_KEY
         );
 
+        env_helper!(
+            mut_config,
+            query,
+            rpc_tls_server_client_ca_cert,
+            String,
+            QUERY_RPC_TLS_SERVER_CLIENT_CA_CERT
+        );
+
         // for query rpc client
         env_helper!(
             mut_config,
@@ -409,6 +738,20 @@ impl QueryConfig {
             String,
             QUERY_RPC_TLS_SERVICE_DOMAIN_NAME
         );
+        env_helper!(
+            mut_config,
+            query,
+            rpc_tls_query_client_cert,
+            String,
+            QUERY_RPC_TLS_CLIENT_CERT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            rpc_tls_query_client_key,
+            String,
+            QUERY_RPC_TLS_CLIENT_KEY
+        );
         env_helper!(
             mut_config,
             query,
@@ -423,6 +766,20 @@ impl QueryConfig {
             usize,
             QUERY_MAX_QUERY_LOG_SIZE
         );
+        env_helper!(
+            mut_config,
+            query,
+            query_result_cache_max_bytes,
+            u64,
+            QUERY_RESULT_CACHE_MAX_BYTES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            udf_server_allow_list,
+            String,
+            QUERY_UDF_SERVER_ALLOW_LIST
+        );
         env_helper!(
             mut_config,
             query,
@@ -507,6 +864,127 @@ impl QueryConfig {
             bool,
             QUERY_MANAGEMENT_MODE
         );
-        env_helper!(mut_config, query, management_mode, bool, QUERY_JWT_KEY_FILE);
+        env_helper!(mut_config, query, jwt_key_file, String, QUERY_JWT_KEY_FILE);
+        env_helper!(
+            mut_config,
+            query,
+            jwt_user_claim,
+            String,
+            QUERY_JWT_USER_CLAIM
+        );
+        env_helper!(
+            mut_config,
+            query,
+            jwt_role_claim,
+            String,
+            QUERY_JWT_ROLE_CLAIM
+        );
+        env_helper!(
+            mut_config,
+            query,
+            jwt_tenant_claim,
+            String,
+            QUERY_JWT_TENANT_CLAIM
+        );
+        env_helper!(mut_config, query, ldap_url, String, QUERY_LDAP_URL);
+        env_helper!(
+            mut_config,
+            query,
+            ldap_bind_dn_template,
+            String,
+            QUERY_LDAP_BIND_DN_TEMPLATE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            ldap_group_search_base,
+            String,
+            QUERY_LDAP_GROUP_SEARCH_BASE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            ldap_group_filter,
+            String,
+            QUERY_LDAP_GROUP_FILTER
+        );
+        env_helper!(
+            mut_config,
+            query,
+            ldap_group_role_mapping,
+            String,
+            QUERY_LDAP_GROUP_ROLE_MAPPING
+        );
+        env_helper!(mut_config, query, ldap_pool_size, u64, QUERY_LDAP_POOL_SIZE);
+        env_helper!(
+            mut_config,
+            query,
+            password_min_length,
+            u64,
+            QUERY_PASSWORD_MIN_LENGTH
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_require_mixed_case,
+            bool,
+            QUERY_PASSWORD_REQUIRE_MIXED_CASE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_require_digit,
+            bool,
+            QUERY_PASSWORD_REQUIRE_DIGIT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_require_special_char,
+            bool,
+            QUERY_PASSWORD_REQUIRE_SPECIAL_CHAR
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_expire_days,
+            u64,
+            QUERY_PASSWORD_EXPIRE_DAYS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_history,
+            u64,
+            QUERY_PASSWORD_HISTORY
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_max_failed_attempts,
+            u64,
+            QUERY_PASSWORD_MAX_FAILED_ATTEMPTS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            password_lockout_minutes,
+            u64,
+            QUERY_PASSWORD_LOCKOUT_MINUTES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            network_policy,
+            String,
+            QUERY_NETWORK_POLICY
+        );
+        env_helper!(
+            mut_config,
+            query,
+            cluster_lease_secs,
+            u64,
+            QUERY_CLUSTER_LEASE_SECS
+        );
     }
 }
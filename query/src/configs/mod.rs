@@ -23,6 +23,7 @@ pub mod config_storage;
 
 pub use config::Config;
 pub use config::DATABEND_COMMIT_VERSION;
+pub use config_log::AuditSinks;
 pub use config_log::LogConfig;
 pub use config_meta::MetaConfig;
 pub use config_query::QueryConfig;
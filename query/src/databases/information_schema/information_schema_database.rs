@@ -19,6 +19,7 @@ use common_meta_types::DatabaseMeta;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
+use crate::storages::information_schema::ColumnPrivilegesTable;
 use crate::storages::information_schema::ColumnsTable;
 use crate::storages::information_schema::KeywordsTable;
 use crate::storages::information_schema::SchemataTable;
@@ -39,6 +40,7 @@ impl<const UPPER: bool> InformationSchemaDatabase<UPPER> {
             KeywordsTable::create(sys_db_meta.next_table_id()),
             ViewsTable::create(sys_db_meta.next_table_id()),
             SchemataTable::create(sys_db_meta.next_table_id()),
+            ColumnPrivilegesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = if UPPER {
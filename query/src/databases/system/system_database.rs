@@ -14,11 +14,13 @@
 //
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DatabaseMeta;
 
 use crate::catalogs::InMemoryMetas;
+use crate::configs::Config;
 use crate::databases::Database;
 use crate::storages::system;
 use crate::storages::Table;
@@ -29,7 +31,7 @@ pub struct SystemDatabase {
 }
 
 impl SystemDatabase {
-    pub fn create(sys_db_meta: &mut InMemoryMetas) -> Self {
+    pub fn create(sys_db_meta: &mut InMemoryMetas, conf: &Config) -> Self {
         let table_list: Vec<Arc<dyn Table>> = vec![
             system::OneTable::create(sys_db_meta.next_table_id()),
             system::FunctionsTable::create(sys_db_meta.next_table_id()),
@@ -46,9 +48,29 @@ impl SystemDatabase {
             system::ColumnsTable::create(sys_db_meta.next_table_id()),
             system::UsersTable::create(sys_db_meta.next_table_id()),
             system::WarehousesTable::create(sys_db_meta.next_table_id()),
-            Arc::new(system::QueryLogTable::create(sys_db_meta.next_table_id())),
+            Arc::new(system::QueryLogTable::create(
+                sys_db_meta.next_table_id(),
+                conf.query.max_query_log_size,
+                match conf.log.log_query_retention_hours {
+                    0 => None,
+                    hours => Some(Duration::from_secs(hours * 60 * 60)),
+                },
+            )),
             system::EnginesTable::create(sys_db_meta.next_table_id()),
             system::RolesTable::create(sys_db_meta.next_table_id()),
+            system::ColumnPrivilegesTable::create(sys_db_meta.next_table_id()),
+            Arc::new(system::LoadErrorsTable::create(sys_db_meta.next_table_id())),
+            Arc::new(system::AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                conf.log.log_audit_max_rows as usize,
+            )),
+            system::EventsTable::create(sys_db_meta.next_table_id()),
+            system::QueryProfileTable::create(sys_db_meta.next_table_id()),
+            Arc::new(system::SlowQueryLogTable::create(
+                sys_db_meta.next_table_id(),
+                conf.query.max_query_log_size,
+            )),
+            system::MemoryTable::create(sys_db_meta.next_table_id()),
         ];
 
         for tbl in table_list.into_iter() {
@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+use serde::Serialize;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::sql::redact_query_text;
+use crate::storages::system::AuditLogTable;
+
+#[derive(Clone, Serialize)]
+pub struct AuditEvent {
+    pub event_time: u64,
+    pub query_id: String,
+    pub tenant_id: String,
+    pub handler_type: String,
+    // Who.
+    pub sql_user: String,
+    pub roles: String,
+    pub client_address: String,
+    // What.
+    pub query_kind: String,
+    pub query_text: String,
+    pub objects: String,
+    // Result.
+    pub status: String,
+    pub exception_code: i32,
+    pub exception_text: String,
+    pub affected_rows: u64,
+}
+
+/// Write one audit record for each statement when it finished or failed, to the sinks of
+/// `log_audit_sinks`.
+pub struct InterpreterAuditLog {
+    ctx: Arc<QueryContext>,
+    query_kind: String,
+    logged: AtomicBool,
+}
+
+impl InterpreterAuditLog {
+    pub fn create(ctx: Arc<QueryContext>, plan: &PlanNode) -> Self {
+        InterpreterAuditLog {
+            ctx,
+            query_kind: plan.name().to_string(),
+            logged: AtomicBool::new(false),
+        }
+    }
+
+    /// Write the failure of the statement if the result stream returns an error.
+    pub fn audit_stream(
+        self: &Arc<Self>,
+        stream: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        let audit_log = self.clone();
+        Box::pin(stream.then(move |block| {
+            let audit_log = audit_log.clone();
+            async move {
+                if let Err(cause) = &block {
+                    audit_log.log_error(cause).await;
+                }
+                block
+            }
+        }))
+    }
+
+    pub async fn log_error(&self, cause: &ErrorCode) {
+        if let Err(e) = self.write_log(Some(cause)).await {
+            tracing::error!("Cannot write the audit log: {:?}", e);
+        }
+    }
+
+    pub async fn log_finish(&self) -> Result<()> {
+        // The killed query is finished too, with the reason as the error.
+        let kill_reason = self.ctx.get_kill_reason();
+        self.write_log(kill_reason.as_ref()).await
+    }
+
+    async fn write_log(&self, error: Option<&ErrorCode>) -> Result<()> {
+        let sinks = self.ctx.get_config().log.audit_sinks()?;
+        // Each statement is audited once, the first result wins.
+        if !sinks.enabled() || self.logged.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let event = self.create_event(error)?;
+        if sinks.table {
+            self.write_table(&event).await?;
+        }
+        if sinks.file {
+            if let Some(logger) = self.ctx.get_audit_logger() {
+                let event_str = serde_json::to_string(&event)?;
+                tracing::subscriber::with_default(logger, || {
+                    tracing::info!("{}", event_str);
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn create_event(&self, error: Option<&ErrorCode>) -> Result<AuditEvent> {
+        let session = self.ctx.get_current_session();
+        let user = self.ctx.get_current_user()?;
+
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64;
+        let client_address = match self.ctx.get_client_address() {
+            Some(addr) => format!("{:?}", addr),
+            None => "".to_string(),
+        };
+        let objects = self
+            .ctx
+            .get_referred_tables()
+            .iter()
+            .map(|(database, table)| format!("{}.{}", database, table))
+            .collect::<Vec<_>>()
+            .join(",");
+        let (status, exception_code, exception_text) = match error {
+            None => ("Success", 0, "".to_string()),
+            Some(cause) => ("Error", cause.code() as i32, cause.message()),
+        };
+
+        Ok(AuditEvent {
+            event_time,
+            query_id: self.ctx.get_id(),
+            tenant_id: self.ctx.get_tenant(),
+            handler_type: session.get_type().to_string(),
+            sql_user: user.identity().to_string(),
+            roles: session.get_active_roles()?.join(","),
+            client_address,
+            query_kind: self.query_kind.clone(),
+            // The passwords and the credentials are not kept in the audit trail.
+            query_text: redact_query_text(&self.ctx.get_query_str()),
+            objects,
+            status: status.to_string(),
+            exception_code,
+            exception_text,
+            affected_rows: self.ctx.get_write_progress_value().rows as u64,
+        })
+    }
+
+    async fn write_table(&self, event: &AuditEvent) -> Result<()> {
+        // Get the table by the catalog, it's not one of the objects referred by the query.
+        let table = self
+            .ctx
+            .get_catalog()
+            .get_table(&self.ctx.get_tenant(), "system", "audit_log")
+            .await?;
+        let audit_log = table
+            .as_any()
+            .downcast_ref::<AuditLogTable>()
+            .ok_or_else(|| ErrorCode::LogicalError("system.audit_log is not AuditLogTable"))?;
+        let schema = table.get_table_info().meta.schema.clone();
+
+        let block = DataBlock::create(schema, vec![
+            Series::from_data(vec![event.event_time as i64]),
+            Series::from_data(vec![event.query_id.as_str()]),
+            Series::from_data(vec![event.tenant_id.as_str()]),
+            Series::from_data(vec![event.handler_type.as_str()]),
+            Series::from_data(vec![event.sql_user.as_str()]),
+            Series::from_data(vec![event.roles.as_str()]),
+            Series::from_data(vec![event.client_address.as_str()]),
+            Series::from_data(vec![event.query_kind.as_str()]),
+            Series::from_data(vec![event.query_text.as_str()]),
+            Series::from_data(vec![event.objects.as_str()]),
+            Series::from_data(vec![event.status.as_str()]),
+            Series::from_data(vec![event.exception_code]),
+            Series::from_data(vec![event.exception_text.as_str()]),
+            Series::from_data(vec![event.affected_rows]),
+        ]);
+        audit_log.append(block);
+        Ok(())
+    }
+}
@@ -12,10 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
 use common_exception::Result;
 use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeSet;
+use common_streams::RejectedRecord;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -50,8 +58,89 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(database_name, table_name, column_name) => {
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            if !table.schema().has_field(column_name) {
+                return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                    "column {} of table {}.{} not exists",
+                    column_name, database_name, table_name,
+                )));
+            }
+        }
         GrantObject::Global => (),
     }
 
     Ok(())
 }
+
+/// The objects of a GRANT/REVOKE statement with their privileges: the object itself, and the
+/// columns of the table for the column privileges.
+pub fn grant_objects_with_privileges(
+    object: &GrantObject,
+    priv_types: UserPrivilegeSet,
+    column_priv_types: &BTreeMap<String, UserPrivilegeSet>,
+) -> Vec<(GrantObject, UserPrivilegeSet)> {
+    let mut objects = vec![];
+    if !priv_types.is_empty() || column_priv_types.is_empty() {
+        objects.push((object.clone(), priv_types));
+    }
+
+    if let GrantObject::Table(database_name, table_name) = object {
+        for (column_name, column_priv_types) in column_priv_types {
+            let column = GrantObject::Column(
+                database_name.clone(),
+                table_name.clone(),
+                column_name.clone(),
+            );
+            objects.push((column, *column_priv_types));
+        }
+    }
+    objects
+}
+
+// Write the records rejected by a load to system.load_errors.
+pub async fn write_rejected_records(
+    ctx: &Arc<QueryContext>,
+    records: &[RejectedRecord],
+) -> Result<()> {
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let load_errors = ctx.get_table("system", "load_errors").await?;
+    let schema = load_errors.get_table_info().meta.schema.clone();
+
+    let query_id = ctx.get_id();
+    let event_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as i64;
+
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![query_id.as_str(); records.len()]),
+        Series::from_data(vec![event_time; records.len()]),
+        Series::from_data(
+            records
+                .iter()
+                .map(|r| r.file.trim_start_matches('/'))
+                .collect::<Vec<_>>(),
+        ),
+        Series::from_data(records.iter().map(|r| r.line as u64).collect::<Vec<_>>()),
+        Series::from_data(
+            records
+                .iter()
+                .map(|r| r.record.as_str())
+                .collect::<Vec<_>>(),
+        ),
+        Series::from_data(records.iter().map(|r| r.error.as_str()).collect::<Vec<_>>()),
+    ]);
+    let blocks = vec![Ok(block)];
+    let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
+    let _ = load_errors
+        .append_data(ctx.clone(), Box::pin(input_stream))
+        .await?;
+
+    Ok(())
+}
@@ -12,23 +12,33 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::path::Path;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_io::prelude::S3File;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::GrantObject;
+use common_meta_types::OnErrorMode;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CopyPlan;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 use common_planners::SourceInfo;
 use common_streams::DataBlockStream;
+use common_streams::RejectedRecord;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 use futures::TryStreamExt;
-use regex::Regex;
 
+use crate::interpreters::interpreter_common::write_rejected_records;
 use crate::interpreters::stream::ProcessorExecutorStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -42,43 +52,95 @@ pub struct CopyInterpreter {
     plan: CopyPlan,
 }
 
+// The load result of one file.
+struct CopyFileResult {
+    file: String,
+    // The file to add to the copy history of the table if it is loaded.
+    copied_file: CopiedFileInfo,
+    rows_loaded: usize,
+    // The append operations, they are committed after all the files are loaded.
+    operations: Vec<DataBlock>,
+    error: Option<ErrorCode>,
+}
+
 impl CopyInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CopyPlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(CopyInterpreter { ctx, plan }))
     }
 
-    // List the files.
-    // There are two cases here:
-    // 1. If the plan.files is not empty, we already set the files sets to the COPY command with: `files=(<file1>, <file2>)` syntax, only need to add the prefix to the file.
-    // 2. If the plan.files is empty, there are also two case:
-    //     2.1 If the path is a file like /path/to/path/file, S3File::list() will return the same file path.
-    //     2.2 If the path is a folder, S3File::list() will return all the files in it.
+    // List the files to copy.
+    // The stage path is listed first:
+    // 1. If the path is a file like /path/to/path/file, S3File::list() will return the same file path.
+    // 2. If the path is a folder, S3File::list() will return all the files in it.
+    // Then `files=(<file1>, <file2>)` picks the listed files by name and `pattern='<regex>'` filters them.
     async fn list_files(&self) -> Result<Vec<String>> {
-        let files = match &self.plan.from.source_info {
+        match &self.plan.from.source_info {
             SourceInfo::S3StageSource(table_info) => {
-                let path = &table_info.path;
-                // Here we add the path to the file: /path/to/path/file1.
-                let files_with_path = if !self.plan.files.is_empty() {
-                    let mut files_with_path = vec![];
-                    for file in &self.plan.files {
-                        let new_path = Path::new(path).join(file);
-                        files_with_path.push(new_path.to_string_lossy().to_string());
-                    }
-                    files_with_path
-                } else {
-                    let op = StageSource::get_op(&self.ctx, &table_info.stage_info).await?;
-                    S3File::list(&op, path).await?
-                };
-
-                Ok(files_with_path)
+                StageSource::list_files(
+                    &self.ctx,
+                    &table_info.stage_info,
+                    &table_info.path,
+                    &self.plan.files,
+                    &self.plan.pattern,
+                )
+                .await
             }
             other => Err(ErrorCode::LogicalError(format!(
                 "Cannot list files for the source info: {:?}",
                 other
             ))),
+        }
+    }
+
+    // Skip the files already copied into the table, a file is copied again if its etag or size is changed.
+    // The files to copy are returned with their info for the copy history.
+    async fn filter_copied_files(
+        &self,
+        table_id: u64,
+        files: Vec<String>,
+    ) -> Result<Vec<(String, CopiedFileInfo)>> {
+        let op = match &self.plan.from.source_info {
+            SourceInfo::S3StageSource(table_info) => {
+                StageSource::get_op(&self.ctx, &table_info.stage_info).await?
+            }
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Cannot get the files info for the source info: {:?}",
+                    other
+                )))
+            }
         };
 
-        files
+        let copied_files = self
+            .ctx
+            .get_user_manager()
+            .get_copied_files(&self.ctx.get_tenant(), table_id)
+            .await?
+            .into_iter()
+            .map(|f| (f.file.clone(), f))
+            .collect::<HashMap<_, _>>();
+
+        let mut selected = Vec::with_capacity(files.len());
+        for file in files {
+            let meta = op.object(&file).metadata().await?;
+            let info = CopiedFileInfo {
+                file: file.trim_start_matches('/').to_string(),
+                etag: meta
+                    .content_md5()
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                size: meta.content_length(),
+                ..Default::default()
+            };
+
+            match copied_files.get(&info.file) {
+                Some(copied) if copied.is_same_file(&info) => {
+                    tracing::info!("skip the file already copied: {:?}", copied);
+                }
+                _ => selected.push((file, info)),
+            }
+        }
+        Ok(selected)
     }
 
     // Rewrite the ReadDataSourcePlan.S3StageSource.file_name to new file name.
@@ -101,8 +163,12 @@ impl CopyInterpreter {
     //  We parse the `s3://` to ReadSourcePlan instead of to a SELECT plan is that:
     //  COPY should deal with the file one by one and do some error handler on the OnError strategy.
 
-    #[tracing::instrument(level = "debug", name = "copy_one_file_to_table", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
-    async fn copy_one_file_to_table(&self, file_name: Option<String>) -> Result<Vec<DataBlock>> {
+    #[tracing::instrument(level = "debug", name = "copy_one_file_to_table", skip(self, rows_loaded), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn copy_one_file_to_table(
+        &self,
+        file_name: Option<String>,
+        rows_loaded: Arc<AtomicUsize>,
+    ) -> Result<Vec<DataBlock>> {
         let ctx = self.ctx.clone();
         let settings = self.ctx.get_settings();
 
@@ -121,19 +187,112 @@ impl CopyInterpreter {
 
         let async_runtime = ctx.get_storage_runtime();
         let executor = PipelinePullingExecutor::try_create(async_runtime, pipeline)?;
-        let source_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
+        let source_stream =
+            ProcessorExecutorStream::create(ctx.clone(), executor)?.inspect_ok(move |block| {
+                rows_loaded.fetch_add(block.num_rows(), Ordering::Relaxed);
+            });
 
         let table = ctx
             .get_table(&self.plan.db_name, &self.plan.tbl_name)
             .await?;
         let operations = table
-            .append_data(ctx.clone(), source_stream)
+            .append_data(ctx.clone(), Box::pin(source_stream))
             .await?
             .try_collect()
             .await?;
 
         Ok(operations)
     }
+
+    async fn copy_file(&self, file: String, copied_file: CopiedFileInfo) -> CopyFileResult {
+        let rows_loaded = Arc::new(AtomicUsize::new(0));
+        let result = self
+            .copy_one_file_to_table(Some(file.clone()), rows_loaded.clone())
+            .await;
+
+        match result {
+            Ok(operations) => CopyFileResult {
+                file,
+                copied_file,
+                rows_loaded: rows_loaded.load(Ordering::Relaxed),
+                operations,
+                error: None,
+            },
+            Err(error) => CopyFileResult {
+                file,
+                copied_file,
+                rows_loaded: 0,
+                operations: vec![],
+                error: Some(error),
+            },
+        }
+    }
+
+    fn on_error_mode(&self) -> OnErrorMode {
+        match &self.plan.from.source_info {
+            SourceInfo::S3StageSource(table_info) => {
+                table_info.stage_info.copy_options.on_error.clone()
+            }
+            _ => OnErrorMode::None,
+        }
+    }
+
+    fn results_schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("status", Vu8::to_data_type()),
+            DataField::new("rows_loaded", u64::to_data_type()),
+            DataField::new("errors_seen", u64::to_data_type()),
+            DataField::new("first_error", Vu8::to_data_type()),
+        ])
+    }
+
+    // The rejected records are counted in the errors of their files, a file loaded with some
+    // records rejected is PARTIALLY_LOADED.
+    fn results_block(results: &[CopyFileResult], rejected: &[RejectedRecord]) -> DataBlock {
+        let mut files = Vec::with_capacity(results.len());
+        let mut status = Vec::with_capacity(results.len());
+        let mut rows_loaded = Vec::with_capacity(results.len());
+        let mut errors_seen = Vec::with_capacity(results.len());
+        let mut first_errors = Vec::with_capacity(results.len());
+        for result in results {
+            let mut file_rejected = rejected.iter().filter(|r| r.file == result.file);
+            let first_rejected = file_rejected.next();
+            let rejected_num = first_rejected.map_or(0, |_| 1 + file_rejected.count());
+
+            files.push(result.file.trim_start_matches('/').to_string());
+            rows_loaded.push(result.rows_loaded as u64);
+            match (&result.error, first_rejected) {
+                (None, None) => {
+                    status.push("LOADED");
+                    errors_seen.push(0);
+                    first_errors.push("".to_string());
+                }
+                (None, Some(record)) => {
+                    status.push("PARTIALLY_LOADED");
+                    errors_seen.push(rejected_num as u64);
+                    first_errors.push(record.error.clone());
+                }
+                (Some(error), _) => {
+                    // The error of the file may be the last rejected record.
+                    status.push("LOAD_FAILED");
+                    errors_seen.push(std::cmp::max(rejected_num, 1) as u64);
+                    match first_rejected {
+                        Some(record) => first_errors.push(record.error.clone()),
+                        None => first_errors.push(error.message()),
+                    }
+                }
+            }
+        }
+
+        DataBlock::create(Self::results_schema(), vec![
+            Series::from_data(files),
+            Series::from_data(status),
+            Series::from_data(rows_loaded),
+            Series::from_data(errors_seen),
+            Series::from_data(first_errors),
+        ])
+    }
 }
 
 #[async_trait::async_trait]
@@ -142,53 +301,104 @@ impl Interpreter for CopyInterpreter {
         "CopyInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        Self::results_schema()
+    }
+
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute(
         &self,
         mut _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let mut files = self.list_files().await?;
-
-        // Pattern match check.
-        let pattern = &self.plan.pattern;
-        if !pattern.is_empty() {
-            let regex = Regex::new(pattern).map_err(|e| {
-                ErrorCode::SyntaxException(format!(
-                    "Pattern format invalid, got:{}, error:{:?}",
-                    pattern, e
-                ))
-            })?;
-
-            let matched_files = files
-                .iter()
-                .filter(|file| regex.is_match(file))
-                .cloned()
-                .collect();
-            files = matched_files;
-        }
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(self.plan.db_name.clone(), self.plan.tbl_name.clone()),
+                UserPrivilegeType::Insert,
+            )
+            .await?;
 
-        tracing::info!("copy file list:{:?}, pattern:{}", &files, pattern,);
+        let files = self.list_files().await?;
 
-        let mut write_results = vec![];
-        for file in files {
-            let result = self.copy_one_file_to_table(Some(file)).await?;
-            write_results.extend_from_slice(result.as_slice());
-        }
+        tracing::info!(
+            "copy file list:{:?}, pattern:{}",
+            &files,
+            &self.plan.pattern
+        );
 
         let table = self
             .ctx
             .get_table(&self.plan.db_name, &self.plan.tbl_name)
             .await?;
+        let files = self.filter_copied_files(table.get_id(), files).await?;
+
+        // The files are loaded in parallel, the results keep the order of the files.
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        let results = futures::stream::iter(files)
+            .map(|(file, copied_file)| self.copy_file(file, copied_file))
+            .buffered(std::cmp::max(max_threads, 1))
+            .collect::<Vec<_>>()
+            .await;
+
+        // The malformed records rejected by the files are kept in system.load_errors,
+        // the records of the failed files included.
+        let rejected = self.ctx.get_rejected_records().take();
+        write_rejected_records(&self.ctx, &rejected).await?;
+
+        // On error:
+        // 1. CONTINUE skips the malformed records, a file that fails for other reasons is skipped.
+        // 2. SKIP_FILE_<num> skips the malformed records of a file until there are <num> of them,
+        //    then the file fails and is skipped. SKIP_FILE skips the file on the first error.
+        // 3. ABORT_STATEMENT (the default) aborts the COPY on the first error and nothing is committed.
+        let skip_failed_files = matches!(
+            self.on_error_mode(),
+            OnErrorMode::Continue | OnErrorMode::SkipFile | OnErrorMode::SkipFileNum(_)
+        );
+        let results_block = Self::results_block(&results, &rejected);
+        let load_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as i64;
+        let mut write_results = vec![];
+        let mut copied_files = vec![];
+        for result in results {
+            match result.error {
+                Some(error) if !skip_failed_files => {
+                    return Err(error.add_message(format!("Failed to copy file {}", result.file)));
+                }
+                Some(_) => {}
+                None => {
+                    write_results.extend(result.operations);
+                    copied_files.push(CopiedFileInfo {
+                        rows_loaded: result.rows_loaded as u64,
+                        load_time,
+                        ..result.copied_file
+                    });
+                }
+            }
+        }
 
         // Commit.
         table
             .commit_insertion(self.ctx.clone(), write_results, false)
             .await?;
 
+        // The committed files are skipped by the next COPY of them until they are expired.
+        let expire_secs = self.ctx.get_settings().get_copy_history_expire_secs()?;
+        self.ctx
+            .get_user_manager()
+            .add_copied_files(
+                &self.ctx.get_tenant(),
+                table.get_id(),
+                copied_files,
+                expire_secs,
+            )
+            .await?;
+
         Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
+            Self::results_schema(),
             None,
-            vec![],
+            vec![results_block],
         )))
     }
 }
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CopyIntoLocationPlan;
+use common_planners::PlanNode;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::sessions::QueryContext;
+use crate::storages::StageSink;
+
+pub struct CopyIntoLocationInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CopyIntoLocationPlan,
+}
+
+impl CopyIntoLocationInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: CopyIntoLocationPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoLocationInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoLocationInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoLocationInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "copy_into_location_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let select = match self.plan.query.as_ref() {
+            PlanNode::Select(plan) => SelectInterpreter::try_create(self.ctx.clone(), plan.clone()),
+            other => Err(ErrorCode::LogicalError(format!(
+                "Copy into location must be from a select query, but got: {}",
+                other.name()
+            ))),
+        }?;
+
+        let mut sink = StageSink::try_create(
+            &self.ctx,
+            self.plan.stage.clone(),
+            self.plan.path.clone(),
+            self.plan.max_file_size,
+            self.plan.overwrite,
+            self.plan.single,
+        )
+        .await?;
+
+        let mut stream = select.execute(None).await?;
+        while let Some(block) = stream.try_next().await? {
+            sink.append(block).await?;
+        }
+        let files = sink.finish().await?;
+        tracing::info!("copy into location, files:{:?}", files);
+
+        let schema = self.plan.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(
+                files
+                    .iter()
+                    .map(|f| f.path.trim_start_matches('/'))
+                    .collect::<Vec<_>>(),
+            ),
+            Series::from_data(files.iter().map(|f| f.rows as u64).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.size as u64).collect::<Vec<_>>()),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -24,6 +24,7 @@ use common_streams::SendableDataBlockStream;
 use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::VacuumDropTableInterpreter;
 use crate::sessions::QueryContext;
 
 pub struct DropDatabaseInterpreter {
@@ -52,7 +53,15 @@ impl Interpreter for DropDatabaseInterpreter {
             .validate_privilege(&GrantObject::Global, UserPrivilegeType::Drop)
             .await?;
 
+        // The dropped tables can not be restored after the database is dropped, purge their data.
         let catalog = self.ctx.get_catalog();
+        if catalog
+            .exists_database(&self.plan.tenant, &self.plan.db)
+            .await?
+        {
+            VacuumDropTableInterpreter::vacuum_dropped_tables(&self.ctx, &self.plan.db, 0).await?;
+        }
+
         catalog.drop_database(self.plan.clone().into()).await?;
 
         Ok(Box::pin(DataBlockStream::create(
@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::SelectPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
@@ -26,6 +27,8 @@ use crate::interpreters::plan_schedulers;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
+use crate::pipelines::new::executor::PipelinePullingExecutor;
+use crate::pipelines::new::QueryPipelineBuilder;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::QueryContext;
 
@@ -50,6 +53,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::PipelineGraph => self.explain_pipeline_graph(),
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -110,4 +114,36 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create(schema, vec![formatted_pipeline]))
     }
+
+    /// Executes the query with the new processor framework, and dumps the processors and the
+    /// rows passed between them in the Graphviz DOT format.
+    fn explain_pipeline_graph(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let plan = plan_schedulers::apply_plan_rewrite(
+            Optimizers::create(self.ctx.clone()),
+            &self.explain.input,
+        )?;
+
+        let select_plan = SelectPlan {
+            input: Arc::new(plan),
+        };
+        let builder = QueryPipelineBuilder::create(self.ctx.clone());
+        let mut pipeline = builder.finalize(&select_plan)?;
+        let max_threads = self.ctx.get_settings().get_max_threads()?;
+        pipeline.set_max_threads(max_threads as usize);
+
+        let async_runtime = self.ctx.get_storage_runtime();
+        let mut executor = PipelinePullingExecutor::try_create(async_runtime, pipeline)?;
+        executor.start();
+        while executor.pull_data()?.is_some() {}
+
+        let formatted_graph = Series::from_data(
+            executor
+                .display_graphviz()
+                .lines()
+                .map(|s| s.as_bytes())
+                .collect::<Vec<_>>(),
+        );
+        Ok(DataBlock::create(schema, vec![formatted_graph]))
+    }
 }
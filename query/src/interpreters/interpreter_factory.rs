@@ -26,19 +26,28 @@ use super::CreateUserStageInterpreter;
 use super::ListInterpreter;
 use crate::interpreters::interpreter_show_engines::ShowEnginesInterpreter;
 use crate::interpreters::interpreter_table_rename::RenameTableInterpreter;
+use crate::interpreters::AlterTableMaskingPolicyInterpreter;
+use crate::interpreters::AlterTableRowAccessPolicyInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::AlterUserUDFInterpreter;
 use crate::interpreters::CallInterpreter;
 use crate::interpreters::CopyInterpreter;
+use crate::interpreters::CopyIntoLocationInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateMaskingPolicyInterpreter;
+use crate::interpreters::CreateNetworkPolicyInterpreter;
 use crate::interpreters::CreateRoleInterpreter;
+use crate::interpreters::CreateRowAccessPolicyInterpreter;
 use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
 use crate::interpreters::CreateUserUDFInterpreter;
 use crate::interpreters::CreateViewInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::DropMaskingPolicyInterpreter;
+use crate::interpreters::DropNetworkPolicyInterpreter;
 use crate::interpreters::DropRoleInterpreter;
+use crate::interpreters::DropRowAccessPolicyInterpreter;
 use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::DropUserUDFInterpreter;
@@ -54,6 +63,7 @@ use crate::interpreters::OptimizeTableInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
 use crate::interpreters::RevokeRoleInterpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateDatabaseInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
@@ -68,7 +78,9 @@ use crate::interpreters::ShowTabStatInterpreter;
 use crate::interpreters::ShowTablesInterpreter;
 use crate::interpreters::ShowUsersInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UndropTableInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
+use crate::interpreters::VacuumDropTableInterpreter;
 use crate::sessions::QueryContext;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -84,6 +96,7 @@ impl InterpreterFactory {
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx_clone, v),
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
             PlanNode::Copy(v) => CopyInterpreter::try_create(ctx_clone, v),
+            PlanNode::CopyIntoLocation(v) => CopyIntoLocationInterpreter::try_create(ctx_clone, v),
             PlanNode::Call(v) => CallInterpreter::try_create(ctx_clone, v),
             PlanNode::Show(ShowPlan::ShowDatabases(v)) => {
                 ShowDatabasesInterpreter::try_create(ctx_clone, v)
@@ -129,6 +142,8 @@ impl InterpreterFactory {
             // Table related transforms
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::UndropTable(v) => UndropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::VacuumDropTable(v) => VacuumDropTableInterpreter::try_create(ctx_clone, v),
             PlanNode::RenameTable(v) => RenameTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx_clone, v),
@@ -154,11 +169,36 @@ impl InterpreterFactory {
 
             PlanNode::CreateRole(v) => CreateRoleInterpreter::try_create(ctx_clone, v),
             PlanNode::DropRole(v) => DropRoleInterpreter::try_create(ctx_clone, v),
+            PlanNode::SetRole(v) => SetRoleInterpreter::try_create(ctx_clone, v),
 
             // UDF related transforms
             PlanNode::CreateUserUDF(v) => CreateUserUDFInterpreter::try_create(ctx_clone, v),
             PlanNode::DropUserUDF(v) => DropUserUDFInterpreter::try_create(ctx_clone, v),
             PlanNode::AlterUserUDF(v) => AlterUserUDFInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateNetworkPolicy(v) => {
+                CreateNetworkPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::DropNetworkPolicy(v) => {
+                DropNetworkPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::CreateMaskingPolicy(v) => {
+                CreateMaskingPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::DropMaskingPolicy(v) => {
+                DropMaskingPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::AlterTableMaskingPolicy(v) => {
+                AlterTableMaskingPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::CreateRowAccessPolicy(v) => {
+                CreateRowAccessPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::DropRowAccessPolicy(v) => {
+                DropRowAccessPolicyInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::AlterTableRowAccessPolicy(v) => {
+                AlterTableRowAccessPolicyInterpreter::try_create(ctx_clone, v)
+            }
 
             // Stage related transforms
             PlanNode::CreateUserStage(v) => CreateUserStageInterpreter::try_create(ctx_clone, v),
@@ -15,30 +15,101 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metrics::label_counter_with_tag;
 use common_planners::PlanNode;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterQueryResultCache;
 use crate::sessions::QueryContext;
+use crate::users::PasswordPolicy;
 
 pub struct InterceptorInterpreter {
     ctx: Arc<QueryContext>,
     inner: InterpreterPtr,
-    query_log: InterpreterQueryLog,
+    query_log: Arc<InterpreterQueryLog>,
+    audit_log: Arc<InterpreterAuditLog>,
+    query_result_cache: InterpreterQueryResultCache,
+    queued: bool,
+    alter_user: bool,
 }
 
 impl InterceptorInterpreter {
     pub fn create(ctx: Arc<QueryContext>, inner: InterpreterPtr, plan: PlanNode) -> Self {
+        // KILL never waits in the query queue, so the running queries can always be killed.
+        let queued = !matches!(plan, PlanNode::Kill(_));
+        let alter_user = matches!(plan, PlanNode::AlterUser(_));
         InterceptorInterpreter {
             ctx: ctx.clone(),
             inner,
-            query_log: InterpreterQueryLog::create(ctx, plan),
+            audit_log: Arc::new(InterpreterAuditLog::create(ctx.clone(), &plan)),
+            query_result_cache: InterpreterQueryResultCache::create(ctx.clone(), &plan),
+            query_log: Arc::new(InterpreterQueryLog::create(ctx, plan)),
+            queued,
+            alter_user,
         }
     }
+
+    async fn execute_inner(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        self.ctx
+            .execute_with_statement_timeout(self.execute_statement(input_stream))
+            .await
+    }
+
+    async fn execute_statement(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let session = self.ctx.get_current_session();
+        // The user with the expired password can only change the password by ALTER USER.
+        if !self.alter_user && session.get_type().is_user_session() {
+            if let Ok(user) = session.get_current_user() {
+                let auth_manager = self.ctx.get_auth_manager();
+                let policy = auth_manager.get_password_policy();
+                if policy.must_change_password(&user, PasswordPolicy::now()) {
+                    return Err(ErrorCode::PasswordExpired(format!(
+                        "The password of the user '{}'@'{}' must be changed by ALTER USER",
+                        user.name, user.hostname
+                    )));
+                }
+            }
+        }
+
+        if self.queued && session.get_type().is_user_session() {
+            self.ctx.acquire_query_permit().await?;
+        }
+
+        self.ctx.set_current_stage("Executing");
+        let result_stream = self
+            .query_result_cache
+            .execute(&self.inner, input_stream)
+            .await?;
+        let metric_stream =
+            ProgressStream::try_create(result_stream, self.ctx.get_result_progress())?;
+        Ok(Box::pin(metric_stream))
+    }
+
+    fn label_counter(&self, name: &'static str, val: u64) -> Result<()> {
+        let conf = self.ctx.get_config();
+        let query_tag = self.ctx.get_settings().get_query_tag()?;
+        label_counter_with_tag(
+            name,
+            val,
+            &self.ctx.get_tenant(),
+            &conf.query.cluster_id,
+            &query_tag,
+        );
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -51,10 +122,17 @@ impl Interpreter for InterceptorInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let result_stream = self.inner.execute(input_stream).await?;
-        let metric_stream =
-            ProgressStream::try_create(result_stream, self.ctx.get_result_progress())?;
-        Ok(Box::pin(metric_stream))
+        match self.execute_inner(input_stream).await {
+            Ok(stream) => {
+                let stream = self.query_log.log_stream(stream);
+                Ok(self.audit_log.audit_stream(stream))
+            }
+            Err(cause) => {
+                self.audit_log.log_error(&cause).await;
+                self.query_log.log_error(SystemTime::now(), &cause).await;
+                Err(cause)
+            }
+        }
     }
 
     async fn start(&self) -> Result<()> {
@@ -66,6 +144,7 @@ impl Interpreter for InterceptorInterpreter {
                 .status
                 .write()
                 .query_start(now);
+            self.label_counter(super::metrics::METRIC_QUERY_START_NUMBERS, 1)?;
         }
         self.query_log.log_start(now).await
     }
@@ -79,8 +158,13 @@ impl Interpreter for InterceptorInterpreter {
                 .get_session_manager()
                 .status
                 .write()
-                .query_finish(now)
+                .query_finish(now);
+            let scan_bytes = self.ctx.get_scan_progress_value().bytes as u64;
+            let result_bytes = self.ctx.get_result_progress_value().bytes as u64;
+            self.label_counter(super::metrics::METRIC_QUERY_SCAN_BYTES, scan_bytes)?;
+            self.label_counter(super::metrics::METRIC_QUERY_RESULT_BYTES, result_bytes)?;
         }
+        self.audit_log.log_finish().await?;
         self.query_log.log_finish(now).await
     }
 }
@@ -16,7 +16,6 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
 use common_planners::InsertInputSource;
 use common_planners::InsertPlan;
@@ -54,10 +53,22 @@ impl Interpreter for InsertInterpreter {
     ) -> Result<SendableDataBlockStream> {
         let plan = &self.plan;
 
+        // INSERT OVERWRITE replaces all the columns, so the privilege on the table is required.
+        let columns = match plan.overwrite {
+            true => vec![],
+            false => plan
+                .schema
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect::<Vec<_>>(),
+        };
         self.ctx
             .get_current_session()
-            .validate_privilege(
-                &GrantObject::Table(plan.database_name.clone(), plan.table_name.clone()),
+            .validate_columns_privilege(
+                &plan.database_name,
+                &plan.table_name,
+                &columns,
                 UserPrivilegeType::Insert,
             )
             .await?;
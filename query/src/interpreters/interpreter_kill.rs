@@ -53,8 +53,13 @@ impl Interpreter for KillInterpreter {
             .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
             .await?;
 
+        // The id is the id of the session, or the id of the query running in the session.
         let id = &self.plan.id;
-        match self.ctx.get_session_by_id(id).await {
+        let session = match self.ctx.get_session_by_id(id).await {
+            Some(session) => Some(session),
+            None => self.ctx.get_session_by_query_id(id).await,
+        };
+        match session {
             None => Err(ErrorCode::UnknownSession(format!(
                 "Not found session id {}",
                 id
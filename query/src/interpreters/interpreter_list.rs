@@ -17,15 +17,12 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_datavalues::Series;
 use common_datavalues::SeriesFrom;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::get_file_name;
-use common_io::prelude::S3File;
 use common_planners::ListPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
-use regex::Regex;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -43,28 +40,14 @@ impl ListInterpreter {
     }
 
     async fn list_files(&self) -> Result<Vec<String>> {
-        let op = StageSource::get_op(&self.ctx, &self.plan.stage).await?;
-        let pattern = &self.plan.pattern;
-        let path = &self.plan.path;
-        let mut files = S3File::list(&op, path).await?;
-
-        if !pattern.is_empty() {
-            let regex = Regex::new(pattern).map_err(|e| {
-                ErrorCode::SyntaxException(format!(
-                    "Pattern format invalid, got:{}, error:{:?}",
-                    pattern, e
-                ))
-            })?;
-
-            let matched_files = files
-                .iter()
-                .filter(|file| regex.is_match(file))
-                .cloned()
-                .collect();
-            files = matched_files;
-        }
-
-        Ok(files)
+        StageSource::list_files(
+            &self.ctx,
+            &self.plan.stage,
+            &self.plan.path,
+            &self.plan.files,
+            &self.plan.pattern,
+        )
+        .await
     }
 }
 
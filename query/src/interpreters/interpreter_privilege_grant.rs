@@ -18,11 +18,13 @@ use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::PrincipalIdentity;
 use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
 use common_planners::GrantPrivilegePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
+use crate::interpreters::interpreter_common::grant_objects_with_privileges;
 use crate::interpreters::interpreter_common::validate_grant_object_exists;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -52,25 +54,34 @@ impl Interpreter for GrantPrivilegeInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+        let objects =
+            grant_objects_with_privileges(&plan.on, plan.priv_types, &plan.column_priv_types);
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for (object, priv_types) in &objects {
+            validate_grant_privileges(object, *priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
-        // TODO: check privilege on granting on the grant object
+        self.ctx
+            .get_current_session()
+            .validate_privilege(&plan.on, UserPrivilegeType::Grant)
+            .await?;
 
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
-                user_mgr
-                    .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
-            }
-            PrincipalIdentity::Role(role) => {
-                user_mgr
-                    .grant_privileges_to_role(&tenant, role, plan.on, plan.priv_types)
-                    .await?;
+        for (object, priv_types) in objects {
+            match &plan.principal {
+                PrincipalIdentity::User(user) => {
+                    user_mgr
+                        .grant_privileges_to_user(&tenant, user.clone(), object, priv_types)
+                        .await?;
+                }
+                PrincipalIdentity::Role(role) => {
+                    user_mgr
+                        .grant_privileges_to_role(&tenant, role.clone(), object, priv_types)
+                        .await?;
+                }
             }
         }
 
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
@@ -19,13 +22,19 @@ use std::time::UNIX_EPOCH;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 use serde::Serialize;
 use serde_json;
 
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::sql::redact_query_text;
+use crate::storages::system::SlowQueryLogTable;
 
 #[derive(Clone, Copy, Serialize)]
 pub enum LogType {
@@ -52,8 +61,11 @@ pub struct LogEvent {
     pub query_id: String,
     pub query_kind: String,
     pub query_text: String,
+    pub query_tag: String,
     pub event_date: i32,
     pub event_time: u64,
+    pub query_start_time: u64,
+    pub query_duration_ms: u64,
 
     // Schema.
     pub current_database: String,
@@ -77,6 +89,7 @@ pub struct LogEvent {
     pub result_bytes: u64,
     pub cpu_usage: u32,
     pub memory_usage: u64,
+    pub peak_memory_usage: u64,
 
     // Client.
     pub client_info: String,
@@ -98,18 +111,83 @@ pub struct LogEvent {
     pub extra: String,
 }
 
+/// The record of the slow query log, with the plan and the pruning/scan statistics.
+#[derive(Clone, Serialize)]
+pub struct SlowLogEvent {
+    pub event_time: u64,
+    pub query_id: String,
+    pub tenant_id: String,
+    pub sql_user: String,
+    pub query_kind: String,
+    pub query_text: String,
+    pub query_duration_ms: u64,
+    pub plan: String,
+    pub scan_rows: u64,
+    pub scan_bytes: u64,
+    pub scan_io_bytes: u64,
+    pub scan_io_bytes_cost_ms: u64,
+    pub scan_partitions: u64,
+    pub total_partitions: u64,
+    pub result_rows: u64,
+    pub peak_memory_usage: u64,
+    pub exception_code: i32,
+}
+
+/// Write the start record of the query, and one finish or error record when it finished.
 pub struct InterpreterQueryLog {
     ctx: Arc<QueryContext>,
     plan: PlanNode,
+    // The event time of the start record in milliseconds.
+    start_time: AtomicU64,
+    finished: AtomicBool,
 }
 
 impl InterpreterQueryLog {
     pub fn create(ctx: Arc<QueryContext>, plan: PlanNode) -> Self {
-        InterpreterQueryLog { ctx, plan }
+        InterpreterQueryLog {
+            ctx,
+            plan,
+            start_time: AtomicU64::new(0),
+            finished: AtomicBool::new(false),
+        }
+    }
+
+    /// Write the error record if the result stream returns an error.
+    pub fn log_stream(
+        self: &Arc<Self>,
+        stream: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        let query_log = self.clone();
+        Box::pin(stream.then(move |block| {
+            let query_log = query_log.clone();
+            async move {
+                if let Err(cause) = &block {
+                    query_log.log_error(SystemTime::now(), cause).await;
+                }
+                block
+            }
+        }))
+    }
+
+    pub async fn log_error(&self, now: SystemTime, cause: &ErrorCode) {
+        if let Err(e) = self.log_end(now, Some(cause)).await {
+            tracing::error!("Cannot write the query log: {:?}", e);
+        }
+    }
+
+    pub async fn log_finish(&self, now: SystemTime) -> Result<()> {
+        // The killed query is failed with the reason.
+        let kill_reason = self.ctx.get_kill_reason();
+        self.log_end(now, kill_reason.as_ref()).await
     }
 
     async fn write_log(&self, event: &LogEvent) -> Result<()> {
-        let query_log = self.ctx.get_table("system", "query_log").await?;
+        // Not cached in the context, so it is not listed in the objects of the audit log.
+        let query_log = self
+            .ctx
+            .get_catalog()
+            .get_table(&self.ctx.get_tenant(), "system", "query_log")
+            .await?;
         let schema = query_log.get_table_info().meta.schema.clone();
 
         let block = DataBlock::create(schema.clone(), vec![
@@ -126,8 +204,11 @@ impl InterpreterQueryLog {
             Series::from_data(vec![event.query_id.as_str()]),
             Series::from_data(vec![event.query_kind.as_str()]),
             Series::from_data(vec![event.query_text.as_str()]),
+            Series::from_data(vec![event.query_tag.as_str()]),
             Series::from_data(vec![event.event_date as i32]),
             Series::from_data(vec![event.event_time as i64]),
+            Series::from_data(vec![event.query_start_time as i64]),
+            Series::from_data(vec![event.query_duration_ms]),
             // Schema.
             Series::from_data(vec![event.current_database.as_str()]),
             Series::from_data(vec![event.databases.as_str()]),
@@ -149,6 +230,7 @@ impl InterpreterQueryLog {
             Series::from_data(vec![event.result_bytes as u64]),
             Series::from_data(vec![event.cpu_usage]),
             Series::from_data(vec![event.memory_usage as u64]),
+            Series::from_data(vec![event.peak_memory_usage]),
             // Client.
             Series::from_data(vec![event.client_info.as_str()]),
             Series::from_data(vec![event.client_address.as_str()]),
@@ -182,6 +264,75 @@ impl InterpreterQueryLog {
         Ok(())
     }
 
+    /// Write the query to the slow query log if it takes longer than `long_query_time`.
+    async fn write_slow_log(&self, event: &LogEvent) -> Result<()> {
+        let long_query_time_ms = self.ctx.get_settings().get_long_query_time()? * 1000;
+        if event.query_start_time == 0 || event.query_duration_ms < long_query_time_ms {
+            return Ok(());
+        }
+
+        let slow_event = SlowLogEvent {
+            event_time: event.event_time,
+            query_id: event.query_id.clone(),
+            tenant_id: event.tenant_id.clone(),
+            sql_user: event.sql_user.clone(),
+            query_kind: event.query_kind.clone(),
+            query_text: event.query_text.clone(),
+            query_duration_ms: event.query_duration_ms,
+            plan: format!("{:?}", self.plan),
+            scan_rows: event.scan_rows,
+            scan_bytes: event.scan_bytes,
+            scan_io_bytes: event.scan_io_bytes,
+            scan_io_bytes_cost_ms: event.scan_io_bytes_cost_ms,
+            scan_partitions: event.scan_partitions,
+            total_partitions: event.total_partitions,
+            result_rows: event.result_rows,
+            peak_memory_usage: event.peak_memory_usage,
+            exception_code: event.exception_code,
+        };
+
+        let table = self
+            .ctx
+            .get_catalog()
+            .get_table(&self.ctx.get_tenant(), "system", "slow_query_log")
+            .await?;
+        let slow_query_log = table
+            .as_any()
+            .downcast_ref::<SlowQueryLogTable>()
+            .ok_or_else(|| {
+                ErrorCode::LogicalError("system.slow_query_log is not SlowQueryLogTable")
+            })?;
+        let schema = table.get_table_info().meta.schema.clone();
+
+        let block = DataBlock::create(schema, vec![
+            Series::from_data(vec![slow_event.event_time as i64]),
+            Series::from_data(vec![slow_event.query_id.as_str()]),
+            Series::from_data(vec![slow_event.tenant_id.as_str()]),
+            Series::from_data(vec![slow_event.sql_user.as_str()]),
+            Series::from_data(vec![slow_event.query_kind.as_str()]),
+            Series::from_data(vec![slow_event.query_text.as_str()]),
+            Series::from_data(vec![slow_event.query_duration_ms]),
+            Series::from_data(vec![slow_event.plan.as_str()]),
+            Series::from_data(vec![slow_event.scan_rows]),
+            Series::from_data(vec![slow_event.scan_bytes]),
+            Series::from_data(vec![slow_event.scan_io_bytes]),
+            Series::from_data(vec![slow_event.scan_io_bytes_cost_ms]),
+            Series::from_data(vec![slow_event.scan_partitions]),
+            Series::from_data(vec![slow_event.total_partitions]),
+            Series::from_data(vec![slow_event.result_rows]),
+            Series::from_data(vec![slow_event.peak_memory_usage]),
+            Series::from_data(vec![slow_event.exception_code]),
+        ]);
+        slow_query_log.append(block);
+
+        let event_str = serde_json::to_string(&slow_event)?;
+        tracing::subscriber::with_default(self.ctx.get_slow_query_logger(), || {
+            tracing::info!("{}", event_str);
+        });
+
+        Ok(())
+    }
+
     pub async fn log_start(&self, now: SystemTime) -> Result<()> {
         // User.
         let handler_type = self.ctx.get_current_session().get_type().to_string();
@@ -195,7 +346,9 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        // The secrets, like the value of `SET aes_encryption_key`, are not kept in the logs.
+        let query_text = redact_query_text(&self.ctx.get_query_str());
+        let query_tag = self.ctx.get_settings().get_query_tag()?;
         // Schema.
         let current_database = self.ctx.get_current_database();
 
@@ -205,6 +358,7 @@ impl InterpreterQueryLog {
             .expect("Time went backwards")
             .as_millis() as u64;
         let event_date = (event_time / (24 * 3600000)) as i32;
+        self.start_time.store(event_time, Ordering::Relaxed);
 
         let written_rows = 0u64;
         let written_bytes = 0u64;
@@ -220,6 +374,7 @@ impl InterpreterQueryLog {
         let result_bytes = 0u64;
         let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = self.ctx.get_current_session().get_memory_usage() as u64;
+        let peak_memory_usage = 0u64;
 
         // Client.
         let client_address = match self.ctx.get_client_address() {
@@ -250,8 +405,11 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
+            query_start_time: event_time,
+            query_duration_ms: 0,
             current_database,
             databases: "".to_string(),
             tables: "".to_string(),
@@ -271,6 +429,7 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            peak_memory_usage,
             client_info: "".to_string(),
             client_address,
 
@@ -285,10 +444,15 @@ impl InterpreterQueryLog {
         self.write_log(&log_event).await
     }
 
-    pub async fn log_finish(&self, now: SystemTime) -> Result<()> {
+    async fn log_end(&self, now: SystemTime, error: Option<&ErrorCode>) -> Result<()> {
+        // Each query has one finish or error record, the first one wins.
+        if self.finished.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
         // User.
         let handler_type = self.ctx.get_current_session().get_type().to_string();
-        let tenant_id = self.ctx.get_config().query.tenant_id;
+        let tenant_id = self.ctx.get_tenant();
         let cluster_id = self.ctx.get_config().query.cluster_id;
         let user = self.ctx.get_current_user()?;
         let sql_user = user.name;
@@ -298,7 +462,9 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        // The secrets, like the value of `SET aes_encryption_key`, are not kept in the logs.
+        let query_text = redact_query_text(&self.ctx.get_query_str());
+        let query_tag = self.ctx.get_settings().get_query_tag()?;
 
         // Stats.
         let event_time = now
@@ -306,6 +472,11 @@ impl InterpreterQueryLog {
             .expect("Time went backwards")
             .as_millis() as u64;
         let event_date = (event_time / (24 * 3600000)) as i32;
+        let query_start_time = self.start_time.load(Ordering::Relaxed);
+        let query_duration_ms = match query_start_time {
+            0 => 0,
+            start_time => event_time.saturating_sub(start_time),
+        };
         let dal_metrics = self.ctx.get_dal_metrics();
 
         let written_rows = self.ctx.get_write_progress_value().rows as u64;
@@ -322,6 +493,7 @@ impl InterpreterQueryLog {
         let total_partitions = dal_metrics.get_partitions_total();
         let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = self.ctx.get_current_session().get_memory_usage() as u64;
+        let peak_memory_usage = self.ctx.get_peak_memory_usage();
 
         // Result.
         let result_rows = self.ctx.get_result_progress_value().rows as u64;
@@ -348,8 +520,19 @@ impl InterpreterQueryLog {
         }
         session_settings.push_str("scope: SESSION");
 
+        // Exception.
+        let (log_type, exception_code, exception, stack_trace) = match error {
+            None => (LogType::Finish, 0, "".to_string(), "".to_string()),
+            Some(cause) => (
+                LogType::Error,
+                cause.code() as i32,
+                cause.message(),
+                cause.backtrace_str(),
+            ),
+        };
+
         let log_event = LogEvent {
-            log_type: LogType::Finish,
+            log_type,
             handler_type,
             tenant_id,
             cluster_id,
@@ -359,8 +542,11 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
+            query_start_time,
+            query_duration_ms,
             databases: "".to_string(),
             tables: "".to_string(),
             columns: "".to_string(),
@@ -379,18 +565,20 @@ impl InterpreterQueryLog {
             result_bytes,
             cpu_usage,
             memory_usage,
+            peak_memory_usage,
             client_info: "".to_string(),
             client_address,
             current_database,
 
-            exception_code: 0,
-            exception: "".to_string(),
-            stack_trace: "".to_string(),
+            exception_code,
+            exception,
+            stack_trace,
             server_version: "".to_string(),
             session_settings,
             extra: "".to_string(),
         };
 
-        self.write_log(&log_event).await
+        self.write_log(&log_event).await?;
+        self.write_slow_log(&log_event).await
     }
 }
@@ -0,0 +1,277 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Recursion;
+use common_planners::SelectPlan;
+use common_planners::SourceInfo;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::interpreters::stream::QueryResultCacheStream;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_SNAPSHOT_LOC;
+use crate::sql::OPT_KEY_SNAPSHOT_LOCATION;
+
+// The settings changing the results of the queries, besides `aes_encryption_key`.
+const RESULT_SETTINGS: [&str; 2] = ["timezone", "block_encryption_mode"];
+
+/// Serve the result of a SELECT from the query result cache of the node if it is cached, otherwise
+/// the result is cached once it is read to the end, see `enable_query_result_cache`.
+///
+/// The result is keyed by the normalized text of the query, the user, the current database and
+/// the settings changing the result, the bound plan, and the versions of the tables read by the
+/// query: the table gets a new snapshot once it is modified, so the results cached before are
+/// never read again. The plan has the predicates of the row access policies and the expressions
+/// of the masking policies, so the results aren't shared once a policy changes.
+/// Only the queries reading the fuse tables and the numbers table functions, and calling only
+/// the deterministic functions, e.g. not `now()`, are cached.
+pub struct InterpreterQueryResultCache {
+    ctx: Arc<QueryContext>,
+    select: Option<SelectPlan>,
+}
+
+impl InterpreterQueryResultCache {
+    pub fn create(ctx: Arc<QueryContext>, plan: &PlanNode) -> Self {
+        let select = match plan {
+            PlanNode::Select(select) => Some(select.clone()),
+            _ => None,
+        };
+        InterpreterQueryResultCache { ctx, select }
+    }
+
+    pub async fn execute(
+        &self,
+        interpreter: &InterpreterPtr,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let key = match self.key()? {
+            None => return interpreter.execute(input_stream).await,
+            Some(key) => key,
+        };
+
+        let settings = self.ctx.get_settings();
+        let cache = self.ctx.get_query_result_cache();
+        let ttl = Duration::from_secs(settings.get_query_result_cache_ttl_secs()?);
+        if let Some(result) = cache.get(&key, ttl) {
+            tracing::debug!(
+                "The result of the query {} is read from the query result cache, {} bytes",
+                self.ctx.get_id(),
+                result.bytes
+            );
+            let blocks = result.blocks.clone();
+            return Ok(Box::pin(DataBlockStream::create(
+                interpreter.schema(),
+                None,
+                blocks,
+            )));
+        }
+
+        let stream = interpreter.execute(input_stream).await?;
+        Ok(Box::pin(QueryResultCacheStream::create(stream, cache, key)))
+    }
+
+    /// The key of the result of the query, None if it is not cached.
+    fn key(&self) -> Result<Option<String>> {
+        let settings = self.ctx.get_settings();
+        let select = match &self.select {
+            Some(select) if settings.get_enable_query_result_cache()? != 0 => select,
+            _ => return Ok(None),
+        };
+
+        let mut visitor = QueryResultCacheKeyVisitor {
+            cacheable: true,
+            tables: vec![],
+        };
+        visitor.visit_plan_node(&select.input)?;
+        if !visitor.cacheable {
+            return Ok(None);
+        }
+
+        // The query text is not attached by all the handlers.
+        let query = normalize_query_text(&self.ctx.get_query_str());
+        if query.is_empty() {
+            return Ok(None);
+        }
+
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => format!("'{}'@'{}'", user.name, user.hostname),
+            Err(_) => return Ok(None),
+        };
+        let role = self.ctx.get_current_session().get_current_role();
+        let values = settings.get_setting_values_short();
+        let mut result_settings = RESULT_SETTINGS
+            .iter()
+            .map(|name| format!("{}={:?}", name, values.get(*name)))
+            .collect::<Vec<_>>();
+        // The secret is masked in the values, only its digest is kept in the key.
+        let aes_key_digest = Sha256::digest(settings.get_aes_encryption_key()?);
+        result_settings.push(format!(
+            "aes_encryption_key={}",
+            hex::encode(aes_key_digest)
+        ));
+        let plan_digest = Sha256::digest(format!("{:?}", select.input));
+
+        Ok(Some(format!(
+            "{}\n{}\n{:?}\n{}\n{}\n{}\n{}\n{}",
+            self.ctx.get_tenant(),
+            user,
+            role,
+            self.ctx.get_current_database(),
+            result_settings.join(","),
+            visitor.tables.join(","),
+            hex::encode(plan_digest),
+            query,
+        )))
+    }
+}
+
+struct QueryResultCacheKeyVisitor {
+    cacheable: bool,
+    // The ids, the versions and the snapshots of the tables read by the query.
+    tables: Vec<String>,
+}
+
+impl PlanVisitor for QueryResultCacheKeyVisitor {
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        let finder = expr.accept(NonDeterministicFinder::default())?;
+        if finder.found {
+            self.cacheable = false;
+        }
+        for query_plan in finder.subqueries {
+            self.visit_subquery_plan(&query_plan)?;
+        }
+        Ok(())
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        if let Some(args) = &plan.tbl_args {
+            self.visit_exprs(args)?;
+        }
+
+        let table_info = match &plan.source_info {
+            SourceInfo::TableSource(table_info) => table_info,
+            SourceInfo::S3StageSource(_) => {
+                self.cacheable = false;
+                return Ok(());
+            }
+        };
+        match table_info.engine() {
+            "FUSE" => {
+                let options = table_info.options();
+                let snapshot_loc = options
+                    .get(OPT_KEY_SNAPSHOT_LOCATION)
+                    .or_else(|| options.get(OPT_KEY_SNAPSHOT_LOC));
+                self.tables.push(format!(
+                    "{}.{}:{}",
+                    table_info.ident.table_id,
+                    table_info.ident.seq,
+                    snapshot_loc.map(String::as_str).unwrap_or_default()
+                ));
+            }
+            "SystemNumbers" | "SystemNumbersMt" | "SystemNumbersLocal" => {}
+            _ => self.cacheable = false,
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct NonDeterministicFinder {
+    found: bool,
+    subqueries: Vec<Arc<PlanNode>>,
+}
+
+impl ExpressionVisitor for NonDeterministicFinder {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            // The context functions get the values of the session which are in the key, e.g.
+            // `database()`. The unknown functions are taken as non-deterministic.
+            Expression::ScalarFunction { op, .. } => {
+                match FunctionFactory::instance().get_features(op) {
+                    Ok(features) if features.is_deterministic || features.is_context_func => {}
+                    _ => self.found = true,
+                }
+            }
+            Expression::Subquery { query_plan, .. }
+            | Expression::ScalarSubquery { query_plan, .. } => {
+                self.subqueries.push(query_plan.clone());
+            }
+            _ => {}
+        }
+        Ok(Recursion::Continue(self))
+    }
+}
+
+/// The text of the query without the leading and trailing spaces and semicolons, and with the
+/// other spaces out of the quotes and the comments collapsed to one, so the queries only
+/// formatted differently share their results.
+fn normalize_query_text(query: &str) -> String {
+    let query = query.trim_matches(|c: char| c == ';' || c.is_whitespace());
+
+    let mut normalized = String::with_capacity(query.len());
+    let mut chars = query.chars().peekable();
+    let mut space = false;
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            space = true;
+            continue;
+        }
+        if space && !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        space = false;
+        normalized.push(c);
+
+        match c {
+            // The quoted text is kept as it is.
+            '\'' | '"' | '`' => {
+                let mut escaped = false;
+                for q in chars.by_ref() {
+                    normalized.push(q);
+                    match escaped {
+                        true => escaped = false,
+                        false if q == '\\' => escaped = true,
+                        false if q == c => break,
+                        false => {}
+                    }
+                }
+            }
+            // The line comment is kept with its end of line.
+            '-' if chars.peek() == Some(&'-') => {
+                for q in chars.by_ref() {
+                    normalized.push(q);
+                    if q == '\n' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    normalized
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::SetRolePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct SetRoleInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetRolePlan,
+}
+
+impl SetRoleInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetRolePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(SetRoleInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetRoleInterpreter {
+    fn name(&self) -> &str {
+        "SetRoleInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        let session = self.ctx.get_current_session();
+
+        if let Some(role_name) = &plan.role_name {
+            let tenant = self.ctx.get_tenant();
+            let user_mgr = self.ctx.get_user_manager();
+            user_mgr.get_role(&tenant, role_name.clone()).await?;
+
+            // The grants of the session user may be stale, check them from the meta.
+            let current_user = session.get_current_user()?;
+            let user = user_mgr.get_user(&tenant, current_user.identity()).await?;
+            if !user.grants.roles().contains(role_name) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Role '{}' is not granted to user '{}'@'{}'",
+                    role_name, user.name, user.hostname
+                )));
+            }
+        }
+        session.set_current_role(plan.role_name);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::SettingPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -46,6 +48,13 @@ impl Interpreter for SettingInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.set.clone();
+        if plan.vars.iter().any(|var| var.is_global) {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                .await?;
+        }
+
         for var in plan.vars {
             match var.variable.to_lowercase().as_str() {
                 // To be compatible with some drivers
@@ -53,7 +62,7 @@ impl Interpreter for SettingInterpreter {
                 _ => {
                     self.ctx
                         .get_settings()
-                        .set_settings(var.variable, var.value, false)?;
+                        .set_settings(var.variable, var.value, var.is_global)?;
                 }
             }
         }
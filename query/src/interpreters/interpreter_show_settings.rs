@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
+use common_planners::PlanShowKind;
 use common_planners::ShowSettingsPlan;
 use common_streams::SendableDataBlockStream;
 
@@ -29,7 +30,6 @@ use crate::sql::PlanParser;
 
 pub struct ShowSettingsInterpreter {
     ctx: Arc<QueryContext>,
-    #[allow(dead_code)]
     plan: ShowSettingsPlan,
 }
 
@@ -39,7 +39,20 @@ impl ShowSettingsInterpreter {
     }
 
     fn build_query(&self) -> Result<String> {
-        Ok("SELECT name, value, default, level, description, type FROM system.settings ORDER BY name".to_string())
+        return match &self.plan.kind {
+            PlanShowKind::All => Ok(
+                "SELECT name, value, default, level, description, type FROM system.settings ORDER BY name"
+                    .to_string(),
+            ),
+            PlanShowKind::Like(expr) => Ok(format!(
+                "SELECT name, value, default, level, description, type FROM system.settings WHERE name LIKE {} ORDER BY name",
+                expr
+            )),
+            PlanShowKind::Where(v) => Ok(format!(
+                "SELECT name, value, default, level, description, type FROM system.settings WHERE {} ORDER BY name",
+                v
+            )),
+        };
     }
 }
 
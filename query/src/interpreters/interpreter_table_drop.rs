@@ -75,7 +75,8 @@ impl Interpreter for DropTableInterpreter {
 
         // `drop_table` throws several types of exceptions
         // thus `optimize` operation is executed after it.
-        if let Some(tbl) = tbl {
+        // The data are kept for `UNDROP TABLE` unless they are dropped with `ALL`.
+        if let (Some(tbl), true) = (tbl, self.plan.all) {
             let keep_last_snapshot = false;
             tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
         }
@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterTableMaskingPolicyPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+
+pub struct AlterTableMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableMaskingPolicyPlan,
+}
+
+impl AlterTableMaskingPolicyInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: AlterTableMaskingPolicyPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(AlterTableMaskingPolicyInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableMaskingPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(plan.database.clone(), plan.table.clone()),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let catalog = self.ctx.get_catalog();
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        if !table.schema().has_field(&plan.column) {
+            return Err(ErrorCode::UnknownColumn(format!(
+                "column {} of table {}.{} not exists",
+                plan.column, plan.database, plan.table
+            )));
+        }
+
+        let table_info = table.get_table_info();
+        let mut policies = match table_info.options().get(OPT_KEY_MASKING_POLICIES) {
+            None => BTreeMap::new(),
+            Some(policies) => serde_json::from_str::<BTreeMap<String, String>>(policies)?,
+        };
+
+        match &plan.policy_name {
+            Some(policy_name) => {
+                // Make sure the policy exists.
+                self.ctx
+                    .get_user_manager()
+                    .get_masking_policy(&plan.tenant, policy_name)
+                    .await?;
+                policies.insert(plan.column.clone(), policy_name.clone());
+            }
+            None => {
+                if policies.remove(&plan.column).is_none() {
+                    return Err(ErrorCode::UnknownMaskingPolicy(format!(
+                        "Column {} of table {}.{} has no masking policy",
+                        plan.column, plan.database, plan.table
+                    )));
+                }
+            }
+        }
+
+        let value = match policies.is_empty() {
+            true => None,
+            false => Some(serde_json::to_string(&policies)?),
+        };
+        let mut options = HashMap::new();
+        options.insert(OPT_KEY_MASKING_POLICIES.to_string(), value);
+        catalog
+            .upsert_table_option(UpsertTableOptionReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.version),
+                options,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
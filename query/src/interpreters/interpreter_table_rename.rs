@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::GrantObject;
 use common_meta_types::RenameTableReq;
+use common_meta_types::UserPrivilegeType;
 use common_planners::RenameTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -46,9 +48,25 @@ impl Interpreter for RenameTableInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        // TODO check privileges
         // You must have ALTER and DROP privileges for the original table,
-        // and CREATE and INSERT privileges for the new table.
+        // and CREATE privilege for the database of the new table.
+        let session = self.ctx.get_current_session();
+        for entity in &self.plan.entities {
+            let table = GrantObject::Table(entity.db.clone(), entity.table_name.clone());
+            session
+                .validate_privilege(&table, UserPrivilegeType::Alter)
+                .await?;
+            session
+                .validate_privilege(&table, UserPrivilegeType::Drop)
+                .await?;
+            session
+                .validate_privilege(
+                    &GrantObject::Database(entity.new_db.clone()),
+                    UserPrivilegeType::Create,
+                )
+                .await?;
+        }
+
         let catalog = self.ctx.get_catalog();
         for entity in &self.plan.entities {
             let tenant = self.plan.tenant.clone();
@@ -0,0 +1,150 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AlterTableRowAccessPolicyPlan;
+use common_planners::RowAccessPolicyAction;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+pub struct AlterTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableRowAccessPolicyPlan,
+}
+
+impl AlterTableRowAccessPolicyInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: AlterTableRowAccessPolicyPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(AlterTableRowAccessPolicyInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableRowAccessPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(plan.database.clone(), plan.table.clone()),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let catalog = self.ctx.get_catalog();
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        let table_info = table.get_table_info();
+        let attached = table_info.options().get(OPT_KEY_ROW_ACCESS_POLICY);
+
+        let mut options = HashMap::new();
+        match &plan.action {
+            RowAccessPolicyAction::Add {
+                policy_name,
+                columns,
+            } => {
+                if let Some(attached) = attached {
+                    return Err(ErrorCode::RowAccessPolicyAlreadyExists(format!(
+                        "Table {}.{} already has the row access policy {}",
+                        plan.database, plan.table, attached
+                    )));
+                }
+
+                let policy = self
+                    .ctx
+                    .get_user_manager()
+                    .get_row_access_policy(&plan.tenant, policy_name)
+                    .await?;
+                if policy.parameters.len() != columns.len() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Row access policy {} requires {} columns, but got: {}",
+                        policy_name,
+                        policy.parameters.len(),
+                        columns.len()
+                    )));
+                }
+                let schema = table.schema();
+                for column in columns {
+                    if !schema.has_field(column) {
+                        return Err(ErrorCode::UnknownColumn(format!(
+                            "column {} of table {}.{} not exists",
+                            column, plan.database, plan.table
+                        )));
+                    }
+                }
+
+                options.insert(
+                    OPT_KEY_ROW_ACCESS_POLICY.to_string(),
+                    Some(policy_name.clone()),
+                );
+                options.insert(
+                    OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_string(),
+                    Some(columns.join(",")),
+                );
+            }
+            RowAccessPolicyAction::Drop { policy_name } => {
+                if attached != Some(policy_name) {
+                    return Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                        "Row access policy {} is not attached to table {}.{}",
+                        policy_name, plan.database, plan.table
+                    )));
+                }
+
+                options.insert(OPT_KEY_ROW_ACCESS_POLICY.to_string(), None);
+                options.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_string(), None);
+            }
+        }
+
+        catalog
+            .upsert_table_option(UpsertTableOptionReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.version),
+                options,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
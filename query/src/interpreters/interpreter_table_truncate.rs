@@ -59,6 +59,12 @@ impl Interpreter for TruncateTableInterpreter {
 
         let tbl = self.ctx.get_table(db_name, tbl_name).await?;
         tbl.truncate(self.ctx.clone(), self.plan.clone()).await?;
+
+        // The files copied into the table can be copied again.
+        self.ctx
+            .get_user_manager()
+            .remove_copied_files(&self.ctx.get_tenant(), tbl.get_id())
+            .await?;
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
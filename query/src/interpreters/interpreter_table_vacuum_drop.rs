@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::Result;
+use common_meta_types::GcDroppedTableReq;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::VacuumDropTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct VacuumDropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VacuumDropTablePlan,
+}
+
+impl VacuumDropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumDropTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(VacuumDropTableInterpreter { ctx, plan }))
+    }
+
+    /// Purge the data of the tables of `db` dropped more than `retain_secs` seconds ago, and
+    /// remove them from the meta. They can not be restored by `UNDROP TABLE` any more.
+    pub async fn vacuum_dropped_tables(
+        ctx: &Arc<QueryContext>,
+        db: &str,
+        retain_secs: u64,
+    ) -> Result<()> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+
+        for dropped in catalog.list_dropped_tables(&tenant, db).await? {
+            if dropped.dropped_on + retain_secs > now {
+                continue;
+            }
+
+            let table_info = dropped.table_info.as_ref();
+            tracing::info!("Vacuum dropped table {}", table_info.desc);
+
+            // The meta is removed after the data, so that a failed vacuum can be retried.
+            let table = catalog.get_table_by_info(table_info)?;
+            let keep_last_snapshot = false;
+            table.optimize(ctx.clone(), keep_last_snapshot).await?;
+
+            catalog
+                .gc_dropped_table(GcDroppedTableReq {
+                    tenant: tenant.clone(),
+                    db: db.to_string(),
+                    table: table_info.name.clone(),
+                    table_id: table_info.ident.table_id,
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumDropTableInterpreter {
+    fn name(&self) -> &str {
+        "VacuumDropTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Database(self.plan.db.clone()),
+                UserPrivilegeType::Drop,
+            )
+            .await?;
+
+        Self::vacuum_dropped_tables(&self.ctx, &self.plan.db, self.plan.retain_secs).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
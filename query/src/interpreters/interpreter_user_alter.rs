@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::AlterUserPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -23,6 +25,7 @@ use common_tracing::tracing;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::users::PasswordPolicy;
 
 #[derive(Debug)]
 pub struct AlterUserInterpreter {
@@ -48,14 +51,57 @@ impl Interpreter for AlterUserInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+        // The users can change their own passwords, the others need the CREATE USER privilege.
+        let session = self.ctx.get_current_session();
+        let is_current_user = session.get_current_user()?.identity() == plan.user;
+        if !is_current_user || plan.user_option.is_some() {
+            session
+                .validate_privilege(&GrantObject::Global, UserPrivilegeType::CreateUser)
+                .await?;
+        }
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
-        if plan.auth_info.is_some() || plan.user_option.is_some() {
+        let mut user_option = plan.user_option;
+        if plan.auth_info.is_some() {
+            // Keep the old password in the history, and renew the expiration.
+            let user_info = user_mgr.get_user(&tenant, plan.user.clone()).await?;
+            let mut option = user_option.unwrap_or_else(|| user_info.option.clone());
+            let auth_manager = self.ctx.get_auth_manager();
+            auth_manager.get_password_policy().password_changed(
+                option.password_state_mut(),
+                user_info.auth_info,
+                PasswordPolicy::now(),
+            );
+            // The password reset by the others may still have to be changed by the user.
+            if is_current_user {
+                option.password_state_mut().must_change = false;
+            }
+            user_option = Some(option);
+        }
+
+        if plan.auth_info.is_some() || user_option.is_some() {
             user_mgr
-                .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
+                .update_user(
+                    &tenant,
+                    plan.user,
+                    plan.auth_info.clone(),
+                    user_option.clone(),
+                )
                 .await?;
         }
 
+        // The session of the user sees the new password at once, so it can execute the other
+        // statements if the password had to be changed.
+        if let (true, Some(auth_info), Some(option)) =
+            (is_current_user, plan.auth_info, user_option)
+        {
+            let mut current_user = session.get_current_user()?;
+            current_user.auth_info = auth_info;
+            current_user.option = option;
+            session.set_current_user(current_user);
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -90,6 +90,7 @@ impl AlterViewInterpreter {
             tenant: self.plan.tenant.clone(),
             db: self.plan.db.clone(),
             table: self.plan.viewname.clone(),
+            purge: true,
         };
         catalog.drop_table(plan).await?;
 
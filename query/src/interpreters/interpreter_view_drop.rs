@@ -81,6 +81,7 @@ impl Interpreter for DropViewInterpreter {
             tenant: self.plan.tenant.clone(),
             db: db_name,
             table: viewname,
+            purge: true,
         };
         catalog.drop_table(plan).await?;
 
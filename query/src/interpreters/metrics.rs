@@ -0,0 +1,18 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The metrics of the queries, labeled by the tenant, the cluster and the `query_tag`.
+pub static METRIC_QUERY_START_NUMBERS: &str = "query.start_numbers";
+pub static METRIC_QUERY_SCAN_BYTES: &str = "query.scan_bytes";
+pub static METRIC_QUERY_RESULT_BYTES: &str = "query.result_bytes";
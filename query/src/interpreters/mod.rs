@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod interpreter;
+mod interpreter_audit_log;
 mod interpreter_call;
 mod interpreter_common;
 mod interpreter_copy;
+mod interpreter_copy_into_location;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_show_create;
@@ -26,13 +28,21 @@ mod interpreter_insert;
 mod interpreter_insert_with_stream;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_masking_policy_create;
+mod interpreter_masking_policy_drop;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_drop;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_query_log;
+mod interpreter_query_result_cache;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
+mod interpreter_role_set;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_databases;
@@ -49,10 +59,14 @@ mod interpreter_show_users;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
+mod interpreter_table_masking_policy;
 mod interpreter_table_optimize;
 mod interpreter_table_rename;
+mod interpreter_table_row_access_policy;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
+mod interpreter_table_undrop;
+mod interpreter_table_vacuum_drop;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
@@ -66,13 +80,18 @@ mod interpreter_user_udf_drop;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
+mod metrics;
 mod plan_schedulers;
 mod stream;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_audit_log::AuditEvent;
+pub use interpreter_audit_log::InterpreterAuditLog;
 pub use interpreter_call::CallInterpreter;
+pub use interpreter_common::write_rejected_records;
 pub use interpreter_copy::CopyInterpreter;
+pub use interpreter_copy_into_location::CopyIntoLocationInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
@@ -82,15 +101,23 @@ pub use interpreter_factory_interceptor::InterceptorInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_masking_policy_create::CreateMaskingPolicyInterpreter;
+pub use interpreter_masking_policy_drop::DropMaskingPolicyInterpreter;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_query_log::LogEvent;
 pub use interpreter_query_log::LogType;
+pub use interpreter_query_result_cache::InterpreterQueryResultCache;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
+pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_databases::ShowDatabasesInterpreter;
@@ -106,10 +133,14 @@ pub use interpreter_show_users::ShowUsersInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_masking_policy::AlterTableMaskingPolicyInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_row_access_policy::AlterTableRowAccessPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
+pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_table_vacuum_drop::VacuumDropTableInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
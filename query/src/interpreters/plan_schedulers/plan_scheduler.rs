@@ -42,6 +42,7 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::WindowFuncPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -302,6 +303,7 @@ impl PlanScheduler {
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
+            PlanNode::WindowFunc(plan) => self.visit_window_func(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
             PlanNode::Sink(plan) => self.visit_sink(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
@@ -780,6 +782,33 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_window_func(&mut self, plan: &WindowFuncPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_window_func(plan),
+            RunningMode::Standalone => self.visit_local_window_func(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_window_func(&mut self, plan: &WindowFuncPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::WindowFunc(WindowFuncPlan {
+            window_func: plan.window_func.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_window_func(&mut self, plan: &WindowFuncPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::WindowFunc(WindowFuncPlan {
+                window_func: plan.window_func.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_data_source(&mut self, plan: &ReadDataSourcePlan, _: &mut Tasks) -> Result<()> {
         let table = self.query_context.build_table_from_source_plan(plan)?;
 
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_streams::SendableDataBlockStream;
@@ -44,7 +45,13 @@ pub async fn schedule_query(
         let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
         let executing_action = flight_client.execute_action(action.clone(), timeout);
 
-        executing_action.await?;
+        if let Err(cause) = executing_action.await {
+            // The dead node is excluded from the following queries before its lease expires.
+            if cause.code() == ErrorCode::CannotConnectNodeCode() {
+                ctx.get_cluster_discovery().report_unreachable(&node.id);
+            }
+            return Err(cause);
+        }
         scheduled.insert(node.id.clone(), node.clone());
     }
 
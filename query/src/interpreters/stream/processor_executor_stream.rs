@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 
@@ -21,15 +22,24 @@ use common_exception::Result;
 use futures::Stream;
 
 use crate::pipelines::new::executor::PipelinePullingExecutor;
+use crate::sessions::QueryContext;
 
 pub struct ProcessorExecutorStream {
+    ctx: Arc<QueryContext>,
     executor: PipelinePullingExecutor,
 }
 
 impl ProcessorExecutorStream {
-    pub fn create(mut executor: PipelinePullingExecutor) -> Result<Self> {
+    pub fn create(ctx: Arc<QueryContext>, mut executor: PipelinePullingExecutor) -> Result<Self> {
         executor.start();
-        Ok(Self { executor })
+        Ok(Self { ctx, executor })
+    }
+}
+
+impl Drop for ProcessorExecutorStream {
+    fn drop(&mut self) {
+        // The stream is dropped once the query is finished or aborted.
+        self.ctx.record_query_profiles(self.executor.get_profiles());
     }
 }
 
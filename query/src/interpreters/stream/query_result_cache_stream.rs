@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::sessions::QueryResultCache;
+
+/// Collect the blocks of the result, they are put into the query result cache once the result is
+/// read to the end. Nothing is cached if the query fails or the result exceeds the capacity of the
+/// cache.
+pub struct QueryResultCacheStream {
+    input: SendableDataBlockStream,
+    cache: Arc<QueryResultCache>,
+    // None once the result is not to be cached.
+    key: Option<String>,
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+impl QueryResultCacheStream {
+    pub fn create(
+        input: SendableDataBlockStream,
+        cache: Arc<QueryResultCache>,
+        key: String,
+    ) -> Self {
+        QueryResultCacheStream {
+            input,
+            cache,
+            key: Some(key),
+            blocks: vec![],
+            bytes: 0,
+        }
+    }
+
+    fn skip_caching(&mut self) {
+        self.key = None;
+        self.blocks.clear();
+    }
+}
+
+impl Stream for QueryResultCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let self_ = Pin::get_mut(self);
+        let next = self_.input.poll_next_unpin(ctx);
+        match &next {
+            Poll::Ready(Some(Ok(block))) if self_.key.is_some() => {
+                self_.bytes += block.memory_size();
+                match self_.bytes as u64 > self_.cache.max_bytes() {
+                    true => self_.skip_caching(),
+                    false => self_.blocks.push(block.clone()),
+                }
+            }
+            Poll::Ready(Some(Err(_))) => self_.skip_caching(),
+            Poll::Ready(None) => {
+                if let Some(key) = self_.key.take() {
+                    let blocks = std::mem::take(&mut self_.blocks);
+                    self_.cache.put(key, blocks);
+                }
+            }
+            _ => {}
+        }
+        next
+    }
+}
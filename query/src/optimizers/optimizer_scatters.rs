@@ -31,6 +31,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::WindowFuncPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -174,6 +175,31 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        // The window function is evaluated on all the rows, we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Cluster window function input is None.",
+            )),
+            Some(input) => Self::convergent_shuffle_stage_builder(input)
+                .window_func(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
+    fn standalone_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Standalone window function input is None.",
+            )),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .window_func(plan.window_func.clone())?
+                .build(),
+        }
+    }
+
     fn convergent_shuffle_stage_builder(input: Arc<PlanNode>) -> PlanBuilder {
         PlanBuilder::from(&PlanNode::Stage(StagePlan {
             kind: StageKind::Convergent,
@@ -278,6 +304,15 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_window_func(plan),
+            RunningMode::Standalone => self.standalone_window_func(plan),
+        }
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
 
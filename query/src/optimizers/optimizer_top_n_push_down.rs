@@ -75,6 +75,17 @@ impl PlanRewriter for TopNPushDownImpl {
         }
     }
 
+    fn rewrite_window_func(&mut self, plan: &WindowFuncPlan) -> Result<PlanNode> {
+        // The window functions are evaluated on all the rows, we clear the top n option.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+        let new_window_func = self.rewrite_expr(&new_input.schema(), &plan.window_func)?;
+        PlanBuilder::from(&new_input)
+            .window_func(new_window_func)?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let current_limit = self.limit;
         let current_order_by = self.order_by.clone();
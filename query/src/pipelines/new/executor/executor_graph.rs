@@ -31,6 +31,7 @@ use petgraph::Direction;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
+use crate::pipelines::new::executor::ProcessorProfile;
 use crate::pipelines::new::pipe::NewPipe;
 use crate::pipelines::new::pipeline::NewPipeline;
 use crate::pipelines::new::processors::connect;
@@ -54,9 +55,7 @@ struct Node {
     processor: ProcessorPtr,
 
     updated_list: Arc<UpdateList>,
-    #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
-    #[allow(dead_code)]
     outputs_port: Vec<Arc<OutputPort>>,
 }
 
@@ -82,10 +81,47 @@ impl Node {
     pub unsafe fn create_trigger(&self, index: EdgeIndex) -> *mut UpdateTrigger {
         self.updated_list.create_trigger(index)
     }
+
+    pub unsafe fn get_profile(&self) -> ProcessorProfile {
+        let mut profile = ProcessorProfile {
+            id: self.processor.id().index(),
+            name: self.processor.name().to_string(),
+            cpu_time: self.processor.get_time().get_cpu_time(),
+            wait_time: self.processor.get_time().get_wait_time(),
+            ..Default::default()
+        };
+
+        for input_port in &self.inputs_port {
+            let values = input_port.get_progress_values();
+            profile.input_rows += values.rows;
+            profile.input_bytes += values.bytes;
+        }
+
+        for output_port in &self.outputs_port {
+            let values = output_port.get_progress_values();
+            profile.output_rows += values.rows;
+            profile.output_bytes += values.bytes;
+        }
+
+        profile
+    }
+}
+
+// The edge from an output port to the input port of the next processor.
+struct Edge {
+    output_port: Arc<OutputPort>,
+}
+
+impl Edge {
+    pub fn create(output_port: &Arc<OutputPort>) -> Edge {
+        Edge {
+            output_port: output_port.clone(),
+        }
+    }
 }
 
 struct ExecutingGraph {
-    graph: StableGraph<Arc<Node>, ()>,
+    graph: StableGraph<Arc<Node>, Edge>,
 }
 
 type StateLockGuard<'a> = RwLockUpgradableReadGuard<'a, ExecutingGraph>;
@@ -112,7 +148,8 @@ impl ExecutingGraph {
 
                     for index in 0..node_stack.len() {
                         let source_index = node_stack[index];
-                        let edge_index = graph.add_edge(source_index, target_index, ());
+                        let edge = Edge::create(&edge_stack[index]);
+                        let edge_index = graph.add_edge(source_index, target_index, edge);
 
                         let input_trigger = resize_node.create_trigger(edge_index);
                         inputs_port[index].set_trigger(input_trigger);
@@ -159,7 +196,8 @@ impl ExecutingGraph {
 
                         if !node_stack.is_empty() {
                             let source_index = node_stack[index];
-                            let edge_index = graph.add_edge(source_index, target_index, ());
+                            let edge = Edge::create(&edge_stack[index]);
+                            let edge_index = graph.add_edge(source_index, target_index, edge);
 
                             inputs_port[index].set_trigger(target_node.create_trigger(edge_index));
                             edge_stack[index]
@@ -348,6 +386,24 @@ impl RunningGraph {
         ExecutingGraph::schedule_queue(&self.0.upgradable_read(), node_index, &mut schedule_queue)?;
         Ok(schedule_queue)
     }
+
+    /// The stats of the processors so far, ordered by the processor id.
+    pub fn get_profiles(&self) -> Vec<ProcessorProfile> {
+        let graph = self.0.read();
+        let mut profiles = graph
+            .graph
+            .node_indices()
+            .map(|index| unsafe { graph.graph[index].get_profile() })
+            .collect::<Vec<_>>();
+        profiles.sort_by_key(|profile| profile.id);
+        profiles
+    }
+
+    /// The graph in the Graphviz DOT format, the edges are labeled by the rows passed so far.
+    pub fn display_graphviz(&self) -> String {
+        let graph = self.0.read();
+        format!("{:?}", Dot::with_config(&graph.graph, &[]))
+    }
 }
 
 impl Debug for Node {
@@ -356,6 +412,13 @@ impl Debug for Node {
     }
 }
 
+impl Debug for Edge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let values = self.output_port.get_progress_values();
+        write!(f, "{} rows", values.rows)
+    }
+}
+
 impl Debug for ExecutingGraph {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -15,7 +15,9 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_base::ThreadTracker;
 use common_base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -71,14 +73,29 @@ impl ExecutorWorkerContext {
             ExecutorTask::Sync(processor) => self.execute_sync_task(processor),
             ExecutorTask::Async(processor) => self.execute_async_task(processor, exec),
             ExecutorTask::AsyncCompleted(task) => match task.res {
-                Ok(_) => Ok(Some(task.id)),
+                Ok(_) => {
+                    // The query fails once an allocation of its runtime exceeded the limit.
+                    ThreadTracker::check_memory_limit()?;
+                    Ok(Some(task.id))
+                }
                 Err(cause) => Err(cause),
             },
         }
     }
 
     unsafe fn execute_sync_task(&mut self, processor: ProcessorPtr) -> Result<Option<NodeIndex>> {
-        processor.process()?;
+        // The memory of the synchronous work is tracked by the kind of the processor as well.
+        let memory_scope = ThreadTracker::current_runtime_tracker().map(|runtime_tracker| {
+            let tracker = runtime_tracker.get_category_memory_tracker(processor.name());
+            ThreadTracker::track_scope(tracker)
+        });
+
+        let start = Instant::now();
+        let res = processor.process();
+        processor.get_time().add_cpu_time(start.elapsed());
+        drop(memory_scope);
+        res?;
+        ThreadTracker::check_memory_limit()?;
         Ok(Some(processor.id()))
     }
 
@@ -91,7 +108,9 @@ impl ExecutorWorkerContext {
         let workers_notify = self.get_workers_notify().clone();
         let tasks_queue = executor.global_tasks_queue.clone();
         executor.async_runtime.spawn(async move {
+            let start = Instant::now();
             let res = processor.async_process().await;
+            processor.get_time().add_wait_time(start.elapsed());
             let task = CompletedAsyncTask::create(processor, worker_id, res);
             tasks_queue.completed_async_task(task);
             workers_notify.wakeup(worker_id);
@@ -21,7 +21,9 @@ mod executor_worker_context;
 mod pipeline_complete_executor;
 mod pipeline_pulling_executor;
 mod pipeline_pushing_executor;
+mod processor_profile;
 
 pub use executor_graph::RunningGraph;
 pub use pipeline_executor::PipelineExecutor;
 pub use pipeline_pulling_executor::PipelinePullingExecutor;
+pub use processor_profile::ProcessorProfile;
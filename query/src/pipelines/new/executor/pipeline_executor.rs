@@ -26,6 +26,7 @@ use crate::pipelines::new::executor::executor_graph::RunningGraph;
 use crate::pipelines::new::executor::executor_notify::WorkersNotify;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
+use crate::pipelines::new::executor::ProcessorProfile;
 use crate::pipelines::new::pipeline::NewPipeline;
 
 pub struct PipelineExecutor {
@@ -68,6 +69,14 @@ impl PipelineExecutor {
         Ok(())
     }
 
+    pub fn get_profiles(&self) -> Vec<ProcessorProfile> {
+        self.graph.get_profiles()
+    }
+
+    pub fn display_graphviz(&self) -> String {
+        self.graph.display_graphviz()
+    }
+
     pub fn execute(self: &Arc<Self>) -> Result<()> {
         let mut threads = self.execute_threads(self.threads_num);
 
@@ -17,11 +17,13 @@ use std::sync::mpsc::SyncSender;
 use std::sync::Arc;
 
 use common_base::Runtime;
+use common_base::Thread;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::pipelines::new::executor::PipelineExecutor;
+use crate::pipelines::new::executor::ProcessorProfile;
 use crate::pipelines::new::processors::port::InputPort;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Sink;
@@ -88,7 +90,8 @@ impl PipelinePullingExecutor {
         let state = self.state.clone();
         let threads_executor = self.executor.clone();
         let thread_function = Self::thread_function(state, threads_executor);
-        std::thread::spawn(thread_function);
+        // The memory of the executor threads is tracked by the runtime of the query.
+        Thread::spawn(thread_function);
     }
 
     fn thread_function(state: Arc<State>, executor: Arc<PipelineExecutor>) -> impl Fn() {
@@ -111,6 +114,14 @@ impl PipelinePullingExecutor {
         self.executor.finish()
     }
 
+    pub fn get_profiles(&self) -> Vec<ProcessorProfile> {
+        self.executor.get_profiles()
+    }
+
+    pub fn display_graphviz(&self) -> String {
+        self.executor.display_graphviz()
+    }
+
     pub fn pull_data(&mut self) -> Result<Option<DataBlock>> {
         match self.receiver.recv() {
             Ok(data_block) => data_block,
@@ -0,0 +1,30 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+/// The execution stats of a processor in the pipeline.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProcessorProfile {
+    pub id: usize,
+    pub name: String,
+    pub input_rows: usize,
+    pub input_bytes: usize,
+    pub output_rows: usize,
+    pub output_bytes: usize,
+    /// The time spent in the synchronous work.
+    pub cpu_time: Duration,
+    /// The time spent in the asynchronous work, e.g. waiting for the IO.
+    pub wait_time: Duration,
+}
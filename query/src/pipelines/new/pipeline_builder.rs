@@ -30,6 +30,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SelectPlan;
 use common_planners::SortPlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::WindowFuncPlan;
 
 use crate::pipelines::new::pipeline::NewPipeline;
 use crate::pipelines::new::processors::AggregatorParams;
@@ -45,6 +46,7 @@ use crate::pipelines::new::processors::TransformLimit;
 use crate::pipelines::new::processors::TransformLimitBy;
 use crate::pipelines::new::processors::TransformSortMerge;
 use crate::pipelines::new::processors::TransformSortPartial;
+use crate::pipelines::new::processors::TransformWindowFunc;
 use crate::pipelines::transforms::get_sort_descriptions;
 use crate::sessions::QueryContext;
 /// Builder for query pipeline
@@ -90,6 +92,7 @@ impl PlanVisitor for QueryPipelineBuilder {
             PlanNode::Sort(n) => self.visit_sort(n),
             PlanNode::Limit(n) => self.visit_limit(n),
             PlanNode::LimitBy(n) => self.visit_limit_by(n),
+            PlanNode::WindowFunc(n) => self.visit_window_func(n),
             PlanNode::ReadSource(n) => self.visit_read_data_source(n),
             PlanNode::Select(n) => self.visit_select(n),
             PlanNode::SubQueryExpression(n) => self.visit_sub_queries_sets(n),
@@ -293,6 +296,22 @@ impl PlanVisitor for QueryPipelineBuilder {
             })
     }
 
+    fn visit_window_func(&mut self, plan: &WindowFuncPlan) -> Result<()> {
+        self.visit_plan_node(&plan.input)?;
+
+        // The window function is evaluated on all the rows of the input.
+        self.pipeline.resize(1)?;
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformWindowFunc::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    plan.input.schema(),
+                    plan.window_func.clone(),
+                )
+            })
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
@@ -53,3 +53,4 @@ pub use transforms::TransformLimit;
 pub use transforms::TransformLimitBy;
 pub use transforms::TransformSortMerge;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformWindowFunc;
@@ -16,6 +16,8 @@ use std::sync::atomic::AtomicPtr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_base::Progress;
+use common_base::ProgressValues;
 use common_datablocks::DataBlock;
 use common_exception::Result;
 
@@ -108,9 +110,22 @@ impl SharedStatus {
     }
 }
 
+#[inline(always)]
+fn block_progress(data: &Result<DataBlock>) -> ProgressValues {
+    match data {
+        Ok(block) => ProgressValues {
+            rows: block.num_rows(),
+            bytes: block.memory_size(),
+        },
+        Err(_) => ProgressValues { rows: 0, bytes: 0 },
+    }
+}
+
 pub struct InputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    // The rows and bytes pulled from the port.
+    progress: Progress,
 }
 
 impl InputPort {
@@ -118,9 +133,14 @@ impl InputPort {
         Arc::new(InputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            progress: Progress::create(),
         })
     }
 
+    pub fn get_progress_values(&self) -> ProgressValues {
+        self.progress.get_values()
+    }
+
     #[inline(always)]
     pub fn finish(&self) {
         unsafe {
@@ -168,7 +188,11 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    let data = (*Box::from_raw(address)).0;
+                    self.progress.incr(&block_progress(&data));
+                    Some(data)
+                }
             }
         }
     }
@@ -191,6 +215,8 @@ impl InputPort {
 pub struct OutputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    // The rows and bytes pushed into the port.
+    progress: Progress,
 }
 
 impl OutputPort {
@@ -198,13 +224,19 @@ impl OutputPort {
         Arc::new(OutputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            progress: Progress::create(),
         })
     }
 
+    pub fn get_progress_values(&self) -> ProgressValues {
+        self.progress.get_values()
+    }
+
     #[inline(always)]
     pub fn push_data(&self, data: Result<DataBlock>) {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
+            self.progress.incr(&block_progress(&data));
 
             let data = Box::into_raw(Box::new(SharedData(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use std::cell::UnsafeCell;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -48,10 +51,38 @@ pub trait Processor: Send {
     }
 }
 
+/// The time a processor spends in `process` (cpu time) and in `async_process` (wait time).
+#[derive(Default)]
+pub struct ProcessorTime {
+    cpu_nanos: AtomicU64,
+    wait_nanos: AtomicU64,
+}
+
+impl ProcessorTime {
+    pub fn add_cpu_time(&self, elapsed: Duration) {
+        self.cpu_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_wait_time(&self, elapsed: Duration) {
+        self.wait_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn get_cpu_time(&self) -> Duration {
+        Duration::from_nanos(self.cpu_nanos.load(Ordering::Relaxed))
+    }
+
+    pub fn get_wait_time(&self) -> Duration {
+        Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed))
+    }
+}
+
 #[derive(Clone)]
 pub struct ProcessorPtr {
     id: Arc<UnsafeCell<NodeIndex>>,
     inner: Arc<UnsafeCell<Box<dyn Processor>>>,
+    time: Arc<ProcessorTime>,
 }
 
 unsafe impl Send for ProcessorPtr {}
@@ -63,9 +94,14 @@ impl ProcessorPtr {
         ProcessorPtr {
             id: Arc::new(UnsafeCell::new(node_index(0))),
             inner: Arc::new(UnsafeCell::new(inner)),
+            time: Arc::new(ProcessorTime::default()),
         }
     }
 
+    pub fn get_time(&self) -> &Arc<ProcessorTime> {
+        &self.time
+    }
+
     /// # Safety
     pub unsafe fn id(&self) -> NodeIndex {
         *self.id.get()
@@ -15,6 +15,7 @@
 use std::borrow::BorrowMut;
 use std::sync::Arc;
 
+use common_base::ThreadTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodKeysU16;
@@ -168,6 +169,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
                 };
 
                 for group_entity in self.state.iter() {
+                    ThreadTracker::check_memory_limit()?;
                     let place: StateAddr = (*group_entity.get_state_value()).into();
 
                     for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
@@ -189,7 +191,10 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
                 }
 
                 columns.extend_from_slice(&group_columns_builder.finish()?);
-                Ok(Some(DataBlock::create(self.params.schema.clone(), columns)))
+                let block = DataBlock::create(self.params.schema.clone(), columns);
+                let block =
+                    DataBlock::unfold_collations(block, &self.params.group_values_column_name)?;
+                Ok(Some(block))
             }
         }
     }
@@ -221,6 +226,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
                     .method
                     .group_columns_builder(self.state.len(), &self.params);
                 for group_entity in self.state.iter() {
+                    ThreadTracker::check_memory_limit()?;
                     columns_builder.append_value(group_entity.get_state_key());
                 }
 
@@ -22,6 +22,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_planners::collated_group_values;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::Expression;
@@ -34,6 +35,8 @@ pub struct AggregatorParams {
     pub before_schema: DataSchemaRef,
     pub group_columns_name: Vec<String>,
     pub group_data_fields: Vec<DataField>,
+    // the collated group by columns with the aggregate of their values, see `collated_group_values`
+    pub group_values_column_name: Vec<(String, String)>,
 
     pub aggregate_functions: Vec<AggregateFunctionRef>,
     pub aggregate_functions_column_name: Vec<String>,
@@ -52,9 +55,20 @@ impl AggregatorParams {
             .collect::<Vec<_>>()
     }
 
+    fn extract_group_values(
+        group_exprs: &[Expression],
+        before_schema: &DataSchemaRef,
+    ) -> Result<Vec<(String, String)>> {
+        Ok(collated_group_values(group_exprs, before_schema)?
+            .into_iter()
+            .map(|(name, value)| (name, value.column_name()))
+            .collect::<Vec<_>>())
+    }
+
     pub fn try_create_final(plan: &AggregatorFinalPlan) -> Result<Arc<AggregatorParams>> {
         let before_schema = &plan.schema_before_group_by;
         let group_cols = Self::extract_group_columns(&plan.group_expr);
+        let group_values = Self::extract_group_values(&plan.group_expr, before_schema)?;
         let mut aggregate_functions = Vec::with_capacity(plan.aggr_expr.len());
         let mut aggregate_functions_column_name = Vec::with_capacity(plan.aggr_expr.len());
         let mut aggregate_functions_arguments_name = Vec::with_capacity(plan.aggr_expr.len());
@@ -75,6 +89,7 @@ impl AggregatorParams {
 
         Ok(Arc::new(AggregatorParams {
             group_data_fields,
+            group_values_column_name: group_values,
             aggregate_functions,
             aggregate_functions_column_name,
             aggregate_functions_arguments_name,
@@ -89,6 +104,7 @@ impl AggregatorParams {
     pub fn try_create_partial(plan: &AggregatorPartialPlan) -> Result<Arc<AggregatorParams>> {
         let before_schema = plan.input.schema();
         let group_cols = Self::extract_group_columns(&plan.group_expr);
+        let group_values = Self::extract_group_values(&plan.group_expr, &before_schema)?;
         let mut aggregate_functions = Vec::with_capacity(plan.aggr_expr.len());
         let mut aggregate_functions_column_name = Vec::with_capacity(plan.aggr_expr.len());
         let mut aggregate_functions_arguments_name = Vec::with_capacity(plan.aggr_expr.len());
@@ -110,6 +126,7 @@ impl AggregatorParams {
         Ok(Arc::new(AggregatorParams {
             before_schema,
             group_data_fields,
+            group_values_column_name: group_values,
             aggregate_functions,
             aggregate_functions_column_name,
             aggregate_functions_arguments_name,
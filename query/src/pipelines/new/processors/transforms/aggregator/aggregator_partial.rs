@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use bytes::BytesMut;
+use common_base::ThreadTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodKeysU16;
@@ -189,6 +190,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
 
         let mut bytes = BytesMut::new();
         for group_entity in self.state.iter() {
+            ThreadTracker::check_memory_limit()?;
             let place: StateAddr = (*group_entity.get_state_value()).into();
 
             for (idx, func) in funcs.iter().enumerate() {
@@ -218,8 +220,10 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        let folded = DataBlock::fold_collations(block.clone(), &self.params.group_columns_name)?;
+
         // 1.1 and 1.2.
-        let group_columns = Self::group_columns(&self.params.group_columns_name, &block)?;
+        let group_columns = Self::group_columns(&self.params.group_columns_name, &folded)?;
         let group_keys = self.method.build_keys(&group_columns, block.num_rows())?;
 
         let places = Self::lookup_state(&self.params, group_keys, &mut self.state);
@@ -237,6 +241,8 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        let block = DataBlock::fold_collations(block, &self.params.group_columns_name)?;
+
         // 1.1 and 1.2.
         let group_columns = Self::group_columns(&self.params.group_columns_name, &block)?;
         let group_keys = self.method.build_keys(&group_columns, block.num_rows())?;
@@ -251,6 +257,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
                 self.is_generated = true;
                 let mut keys_column_builder = self.method.keys_column_builder(self.state.len());
                 for group_entity in self.state.iter() {
+                    ThreadTracker::check_memory_limit()?;
                     keys_column_builder.append_value(group_entity.get_state_key());
                 }
 
@@ -23,6 +23,7 @@ mod transform_limit;
 mod transform_limit_by;
 mod transform_sort_merge;
 mod transform_sort_partial;
+mod transform_window_func;
 
 pub use aggregator::AggregatorParams;
 pub use aggregator::AggregatorTransformParams;
@@ -38,3 +39,4 @@ pub use transform_limit::TransformLimit;
 pub use transform_limit_by::TransformLimitBy;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_window_func::TransformWindowFunc;
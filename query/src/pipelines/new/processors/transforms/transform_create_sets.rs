@@ -103,7 +103,7 @@ impl SubQueriesPuller {
         let subquery_ctx = QueryContext::create_from(self.ctx.clone());
         let async_runtime = subquery_ctx.get_storage_runtime();
 
-        let interpreter = SelectInterpreter::try_create(subquery_ctx, plan)?;
+        let interpreter = SelectInterpreter::try_create(subquery_ctx.clone(), plan)?;
         let query_pipeline = interpreter.create_new_pipeline()?;
         let mut query_executor =
             PipelinePullingExecutor::try_create(async_runtime, query_pipeline)?;
@@ -123,6 +123,7 @@ impl SubQueriesPuller {
                 columns[column_index].append(&mut values)
             }
         }
+        subquery_ctx.record_query_profiles(query_executor.get_profiles());
 
         let mut struct_fields = Vec::with_capacity(columns.len());
 
@@ -141,14 +142,16 @@ impl SubQueriesPuller {
         let subquery_ctx = QueryContext::create_from(self.ctx.clone());
         let async_runtime = subquery_ctx.get_storage_runtime();
 
-        let interpreter = SelectInterpreter::try_create(subquery_ctx, plan)?;
+        let interpreter = SelectInterpreter::try_create(subquery_ctx.clone(), plan)?;
         let query_pipeline = interpreter.create_new_pipeline()?;
 
         let mut query_executor =
             PipelinePullingExecutor::try_create(async_runtime, query_pipeline)?;
 
         query_executor.start();
-        match query_executor.pull_data()? {
+        let data_block = query_executor.pull_data()?;
+        subquery_ctx.record_query_profiles(query_executor.get_profiles());
+        match data_block {
             None => Err(ErrorCode::ScalarSubqueryBadRows(
                 "Scalar subquery result set must be one row.",
             )),
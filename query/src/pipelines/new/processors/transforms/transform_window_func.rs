@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::Processor;
+use crate::pipelines::transforms::WindowFuncExecutor;
+
+enum WindowFuncState {
+    Consume,
+    Evaluating,
+    Evaluated(Option<DataBlock>),
+}
+
+/// Evaluate the window function once all the blocks of the input are consumed.
+pub struct TransformWindowFunc {
+    state: WindowFuncState,
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    input_data_blocks: Vec<DataBlock>,
+    executor: WindowFuncExecutor,
+}
+
+impl TransformWindowFunc {
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        input_schema: DataSchemaRef,
+        window_func: Expression,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(TransformWindowFunc {
+            state: WindowFuncState::Consume,
+            input_port,
+            output_port,
+            input_data_blocks: vec![],
+            executor: WindowFuncExecutor::try_create(window_func, input_schema)?,
+        })))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowFunc {
+    fn name(&self) -> &'static str {
+        "WindowFuncTransform"
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match &mut self.state {
+            WindowFuncState::Consume => {
+                if self.input_port.is_finished() {
+                    self.state = WindowFuncState::Evaluating;
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    let data_block = self.input_port.pull_data().unwrap()?;
+                    self.input_data_blocks.push(data_block);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            WindowFuncState::Evaluating => Err(ErrorCode::LogicalError("It's a bug.")),
+            WindowFuncState::Evaluated(data_block) => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                match data_block.take() {
+                    None => {
+                        self.output_port.finish();
+                        Ok(Event::Finished)
+                    }
+                    Some(data_block) => {
+                        self.output_port.push_data(Ok(data_block));
+                        Ok(Event::NeedConsume)
+                    }
+                }
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.state {
+            WindowFuncState::Evaluating => {
+                let data_blocks = std::mem::take(&mut self.input_data_blocks);
+                let data_block = self.executor.execute(&data_blocks)?;
+                self.state = WindowFuncState::Evaluated(data_block);
+                Ok(())
+            }
+            _ => Err(ErrorCode::LogicalError("State invalid. it's a bug.")),
+        }
+    }
+}
@@ -32,6 +32,7 @@ use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::WindowFuncPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
@@ -53,6 +54,7 @@ use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
 use crate::pipelines::transforms::WhereTransform;
+use crate::pipelines::transforms::WindowFuncTransform;
 use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
@@ -94,6 +96,7 @@ impl PipelineBuilder {
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
+            PlanNode::WindowFunc(node) => self.visit_window_func(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
@@ -308,6 +311,19 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_window_func(&mut self, node: &WindowFuncPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(WindowFuncTransform::try_create(
+                node.input.schema(),
+                node.schema(),
+                node.window_func.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<Pipeline> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
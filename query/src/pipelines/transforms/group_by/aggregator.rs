@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::ThreadTracker;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datavalues::prelude::*;
@@ -58,7 +59,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         match aggregator_params.aggregate_functions.is_empty() {
             true => {
                 while let Some(block) = stream.next().await {
-                    let block = block?;
+                    let block = DataBlock::fold_collations(block?, &group_cols)?;
 
                     // 1.1 and 1.2.
                     let group_columns = Self::group_columns(&group_cols, &block)?;
@@ -69,9 +70,10 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
             false => {
                 while let Some(block) = stream.next().await {
                     let block = block?;
+                    let folded = DataBlock::fold_collations(block.clone(), &group_cols)?;
 
                     // 1.1 and 1.2.
-                    let group_columns = Self::group_columns(&group_cols, &block)?;
+                    let group_columns = Self::group_columns(&group_cols, &folded)?;
                     let group_keys = hash_method.build_keys(&group_columns, block.num_rows())?;
 
                     let places = self.lookup_state(group_keys, &mut state);
@@ -201,6 +203,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
         let mut bytes = BytesMut::new();
         for group_entity in groups.iter() {
+            ThreadTracker::check_memory_limit()?;
             let place: StateAddr = (*group_entity.get_state_value()).into();
 
             for (idx, func) in funcs.iter().enumerate() {
@@ -27,6 +27,8 @@ mod transform_remote;
 mod transform_sort_merge;
 mod transform_sort_partial;
 mod transform_source;
+mod transform_window_func;
+mod transform_window_func_executor;
 
 pub mod group_by;
 mod streams;
@@ -52,3 +54,5 @@ pub use transform_sort_merge::SortMergeTransform;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::SortPartialTransform;
 pub use transform_source::SourceTransform;
+pub use transform_window_func::WindowFuncTransform;
+pub use transform_window_func_executor::WindowFuncExecutor;
@@ -27,6 +27,7 @@ use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::StateAddr;
 use common_infallible::RwLock;
+use common_planners::collated_group_values;
 use common_planners::Expression;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -100,6 +101,11 @@ impl Processor for GroupByFinalTransform {
             .map(|x| x.column_name())
             .collect::<Vec<_>>();
 
+        let group_values = collated_group_values(&self.group_exprs, &self.schema_before_group_by)?
+            .into_iter()
+            .map(|(name, value)| (name, value.column_name()))
+            .collect::<Vec<_>>();
+
         let group_fields = self
             .group_exprs
             .iter()
@@ -225,6 +231,7 @@ impl Processor for GroupByFinalTransform {
                 let mut blocks = vec![];
                 if !columns.is_empty() {
                     let block = DataBlock::create(self.schema.clone(), columns);
+                    let block = DataBlock::unfold_collations(block, &group_values)?;
                     blocks = DataBlock::split_block_by_size(&block, self.max_block_size)?;
                 }
 
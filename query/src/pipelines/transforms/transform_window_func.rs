@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::WindowFuncExecutor;
+
+pub struct WindowFuncTransform {
+    schema: DataSchemaRef,
+    executor: WindowFuncExecutor,
+    input: Arc<dyn Processor>,
+}
+
+impl WindowFuncTransform {
+    pub fn try_create(
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        window_func: Expression,
+    ) -> Result<Self> {
+        Ok(WindowFuncTransform {
+            schema: output_schema,
+            executor: WindowFuncExecutor::try_create(window_func, input_schema)?,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+}
+
+#[async_trait]
+impl Processor for WindowFuncTransform {
+    fn name(&self) -> &str {
+        "WindowFuncTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "window_func_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let mut blocks = vec![];
+        let mut stream = self.input.execute().await?;
+        while let Some(block) = stream.next().await {
+            blocks.push(block?);
+        }
+
+        let results = match self.executor.execute(&blocks)? {
+            None => vec![],
+            Some(block) => vec![block],
+        };
+
+        Ok(Box::pin(CorrectWithSchemaStream::new(
+            Box::pin(DataBlockStream::create(self.schema.clone(), None, results)),
+            self.schema.clone(),
+        )))
+    }
+}
@@ -0,0 +1,446 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::StateAddr;
+use common_functions::scalars::default_column_cast;
+use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
+use common_tracing::tracing;
+
+/// WindowFuncExecutor evaluates a window function over all the rows of its input: the rows are
+/// sorted by the partition and the order of the window, and the result of the window function is
+/// appended to them as the column named after the window function.
+#[derive(Debug, Clone)]
+pub struct WindowFuncExecutor {
+    window_func: Expression,
+    input_schema: DataSchemaRef,
+    output_field: DataField,
+}
+
+// The rows of a partition sorted by the order of the window, the peers are the rows with the
+// same values of the ORDER BY expressions.
+struct WindowPartition {
+    rows: Range<usize>,
+    // The peers of the rows.
+    peers: Vec<Range<usize>>,
+}
+
+impl WindowFuncExecutor {
+    pub fn try_create(window_func: Expression, input_schema: DataSchemaRef) -> Result<Self> {
+        let output_field = window_func.to_data_field(&input_schema)?;
+        Ok(WindowFuncExecutor {
+            window_func,
+            input_schema,
+            output_field,
+        })
+    }
+
+    #[tracing::instrument(level = "debug", name = "window_func_execute", skip(self, blocks))]
+    pub fn execute(&self, blocks: &[DataBlock]) -> Result<Option<DataBlock>> {
+        let (op, args, partition_by, order_by, window_frame) = match &self.window_func {
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+                window_frame,
+                ..
+            } => (op, args, partition_by, order_by, window_frame),
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "Expression must be window function",
+                ))
+            }
+        };
+
+        if blocks.is_empty() {
+            return Ok(None);
+        }
+
+        let mut sort_columns_descriptions = partition_by
+            .iter()
+            .map(|expr| SortColumnDescription {
+                column_name: expr.column_name(),
+                asc: true,
+                nulls_first: true,
+            })
+            .collect::<Vec<_>>();
+        let mut order_keys = Vec::with_capacity(order_by.len());
+        for expr in order_by {
+            match expr {
+                Expression::Sort {
+                    expr,
+                    asc,
+                    nulls_first,
+                    ..
+                } => {
+                    order_keys.push((expr.column_name(), *asc));
+                    sort_columns_descriptions.push(SortColumnDescription {
+                        column_name: expr.column_name(),
+                        asc: *asc,
+                        nulls_first: *nulls_first,
+                    });
+                }
+                _ => return Err(ErrorCode::LogicalError("Order by must be sort expression")),
+            }
+        }
+
+        let block = DataBlock::concat_blocks(blocks)?;
+        let block = match sort_columns_descriptions.is_empty() {
+            true => block,
+            false => DataBlock::sort_block(&block, &sort_columns_descriptions, None)?,
+        };
+
+        let partition_columns = Self::columns(&block, partition_by.iter())?;
+        let order_columns = order_keys
+            .iter()
+            .map(|(name, _)| Ok(block.try_column_by_name(name)?.convert_full_column()))
+            .collect::<Result<Vec<_>>>()?;
+        let partitions = Self::partitions(&block, &partition_columns, &order_columns);
+
+        let frame = window_frame.unwrap_or_default();
+        let column = match op.as_str() {
+            "row_number" | "rank" | "dense_rank" => Self::rank(op, &partitions)?,
+            "lag" | "lead" => self.navigation(op, &block, args, &partitions)?,
+            "first_value" | "last_value" => {
+                let value = &Self::columns(&block, args.iter())?[0];
+                let mut values = Vec::with_capacity(block.num_rows());
+                for partition in &partitions {
+                    let frames = Self::frames(&block, partition, &frame, &order_keys)?;
+                    for rows in frames {
+                        values.push(match (rows.is_empty(), op.as_str()) {
+                            (true, _) => DataValue::Null,
+                            (false, "first_value") => value.get(rows.start),
+                            (false, _) => value.get(rows.end - 1),
+                        });
+                    }
+                }
+                self.output_field.data_type().create_column(&values)?
+            }
+            _ => self.aggregate(&block, args, &partitions, &frame, &order_keys)?,
+        };
+
+        Ok(Some(block.add_column(column, self.output_field.clone())?))
+    }
+
+    fn columns<'a>(
+        block: &DataBlock,
+        exprs: impl Iterator<Item = &'a Expression>,
+    ) -> Result<Vec<ColumnRef>> {
+        exprs
+            .map(|expr| {
+                let column = block.try_column_by_name(&expr.column_name())?;
+                Ok(column.convert_full_column())
+            })
+            .collect()
+    }
+
+    fn partitions(
+        block: &DataBlock,
+        partition_columns: &[ColumnRef],
+        order_columns: &[ColumnRef],
+    ) -> Vec<WindowPartition> {
+        let equals = |columns: &[ColumnRef], a: usize, b: usize| {
+            columns.iter().all(|column| column.get(a) == column.get(b))
+        };
+
+        let mut partitions = vec![];
+        let num_rows = block.num_rows();
+        let mut start = 0;
+        while start < num_rows {
+            let mut end = start + 1;
+            while end < num_rows && equals(partition_columns, start, end) {
+                end += 1;
+            }
+
+            // All the rows of the partition are peers if the window has no ORDER BY.
+            let mut peers = Vec::with_capacity(end - start);
+            let mut peers_start = start;
+            while peers_start < end {
+                let mut peers_end = peers_start + 1;
+                while peers_end < end && equals(order_columns, peers_start, peers_end) {
+                    peers_end += 1;
+                }
+                peers.extend((peers_start..peers_end).map(|_| peers_start..peers_end));
+                peers_start = peers_end;
+            }
+
+            partitions.push(WindowPartition {
+                rows: start..end,
+                peers,
+            });
+            start = end;
+        }
+        partitions
+    }
+
+    // The frames of the rows of the partition.
+    fn frames(
+        block: &DataBlock,
+        partition: &WindowPartition,
+        frame: &WindowFrame,
+        order_keys: &[(String, bool)],
+    ) -> Result<Vec<Range<usize>>> {
+        let rows = &partition.rows;
+        let frames = match frame.units {
+            WindowFrameUnits::Rows => rows
+                .clone()
+                .map(|row| {
+                    let start = match frame.start_bound {
+                        WindowFrameBound::Preceding(None) => rows.start,
+                        WindowFrameBound::Preceding(Some(n)) => {
+                            row.saturating_sub(n as usize).max(rows.start)
+                        }
+                        WindowFrameBound::CurrentRow => row,
+                        WindowFrameBound::Following(Some(n)) => {
+                            row.saturating_add(n as usize).min(rows.end)
+                        }
+                        WindowFrameBound::Following(None) => rows.end,
+                    };
+                    let end = match frame.end_bound {
+                        WindowFrameBound::Preceding(None) => rows.start,
+                        WindowFrameBound::Preceding(Some(n)) => {
+                            (row + 1).saturating_sub(n as usize).max(rows.start)
+                        }
+                        WindowFrameBound::CurrentRow => row + 1,
+                        WindowFrameBound::Following(Some(n)) => {
+                            row.saturating_add(n as usize + 1).min(rows.end)
+                        }
+                        WindowFrameBound::Following(None) => rows.end,
+                    };
+                    start..end.max(start)
+                })
+                .collect(),
+            WindowFrameUnits::Range => Self::range_frames(block, partition, frame, order_keys)?,
+        };
+        Ok(frames)
+    }
+
+    // The frames of RANGE are bounded by the peers of the current row, or by the rows whose key
+    // is within the offset from the key of the current row.
+    fn range_frames(
+        block: &DataBlock,
+        partition: &WindowPartition,
+        frame: &WindowFrame,
+        order_keys: &[(String, bool)],
+    ) -> Result<Vec<Range<usize>>> {
+        let rows = &partition.rows;
+        let has_offset = |bound: &WindowFrameBound| {
+            matches!(
+                bound,
+                WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+            )
+        };
+
+        // The keys ascend within the partition, the keys of DESC are negated.
+        let mut keys = Vec::with_capacity(rows.len());
+        if has_offset(&frame.start_bound) || has_offset(&frame.end_bound) {
+            let (name, asc) =
+                match order_keys {
+                    [order_key] => order_key,
+                    _ => return Err(ErrorCode::LogicalError(
+                        "RANGE window frame with offset requires exactly one ORDER BY expression",
+                    )),
+                };
+            let column = block.try_column_by_name(name)?;
+            for row in rows.clone() {
+                keys.push(match column.get(row) {
+                    DataValue::Null => None,
+                    value => {
+                        let key = value.as_f64().map_err(|_| {
+                            ErrorCode::BadDataValueType(format!(
+                                "RANGE window frame with offset requires numeric ORDER BY expression, but got {:?}",
+                                value
+                            ))
+                        })?;
+                        Some(if *asc { key } else { -key })
+                    }
+                });
+            }
+        }
+
+        // The rows with the null keys are either before or after the others.
+        let non_null_start = keys.iter().take_while(|key| key.is_none()).count();
+        let non_null_end = non_null_start
+            + keys[non_null_start..]
+                .iter()
+                .take_while(|key| key.is_some())
+                .count();
+        let non_null_keys = keys[non_null_start..non_null_end]
+            .iter()
+            .map(|key| key.unwrap_or_default())
+            .collect::<Vec<_>>();
+        // The first row of the non null rows whose key is not less (or greater if `inclusive`)
+        // than the key.
+        let partition_point = |key: f64, inclusive: bool| {
+            rows.start
+                + non_null_start
+                + non_null_keys.partition_point(|k| match inclusive {
+                    true => *k <= key,
+                    false => *k < key,
+                })
+        };
+
+        let mut frames = Vec::with_capacity(rows.len());
+        for index in 0..rows.len() {
+            let peers = &partition.peers[index];
+            let key = keys.get(index).copied().flatten();
+            let start = match (frame.start_bound, key) {
+                (WindowFrameBound::Preceding(None), _) => rows.start,
+                (WindowFrameBound::Following(None), _) => rows.end,
+                (WindowFrameBound::CurrentRow, _) | (_, None) => peers.start,
+                (WindowFrameBound::Preceding(Some(n)), Some(key)) => {
+                    partition_point(key - n as f64, false)
+                }
+                (WindowFrameBound::Following(Some(n)), Some(key)) => {
+                    partition_point(key + n as f64, false)
+                }
+            };
+            let end = match (frame.end_bound, key) {
+                (WindowFrameBound::Preceding(None), _) => rows.start,
+                (WindowFrameBound::Following(None), _) => rows.end,
+                (WindowFrameBound::CurrentRow, _) | (_, None) => peers.end,
+                (WindowFrameBound::Preceding(Some(n)), Some(key)) => {
+                    partition_point(key - n as f64, true)
+                }
+                (WindowFrameBound::Following(Some(n)), Some(key)) => {
+                    partition_point(key + n as f64, true)
+                }
+            };
+            frames.push(start..end.max(start));
+        }
+        Ok(frames)
+    }
+
+    fn rank(op: &str, partitions: &[WindowPartition]) -> Result<ColumnRef> {
+        let mut values = vec![];
+        for partition in partitions {
+            let mut dense_rank = 0;
+            for (index, peers) in partition.peers.iter().enumerate() {
+                let row = partition.rows.start + index;
+                if peers.start == row {
+                    dense_rank += 1;
+                }
+                values.push(match op {
+                    "row_number" => (index + 1) as u64,
+                    "rank" => (peers.start - partition.rows.start + 1) as u64,
+                    _ => dense_rank,
+                });
+            }
+        }
+        Ok(Series::from_data(values))
+    }
+
+    // lag(value [, offset [, default]]) and lead(...) return the value of the row at the offset
+    // before or after the current row in the partition, or the default if there is no such row.
+    fn navigation(
+        &self,
+        op: &str,
+        block: &DataBlock,
+        args: &[Expression],
+        partitions: &[WindowPartition],
+    ) -> Result<ColumnRef> {
+        let data_type = self.output_field.data_type();
+        let mut columns = Self::columns(block, args.iter())?;
+        if let Some(default) = columns.get_mut(2) {
+            *default = default_column_cast(default, data_type)?;
+        }
+
+        let mut values = Vec::with_capacity(block.num_rows());
+        for partition in partitions {
+            for row in partition.rows.clone() {
+                let offset = match columns.get(1) {
+                    None => Some(1),
+                    Some(offset) => match offset.get(row) {
+                        DataValue::Null => None,
+                        value => Some(value.as_u64().map_err(|_| {
+                            ErrorCode::BadArguments(format!(
+                                "The offset of {} must be a non-negative integer, but got {:?}",
+                                op, value
+                            ))
+                        })? as usize),
+                    },
+                };
+                let target = offset.and_then(|offset| match op {
+                    "lag" => row.checked_sub(offset),
+                    _ => row.checked_add(offset),
+                });
+                values.push(match target {
+                    None => DataValue::Null,
+                    Some(target) if partition.rows.contains(&target) => columns[0].get(target),
+                    Some(_) => match columns.get(2) {
+                        Some(default) => default.get(row),
+                        _ => DataValue::Null,
+                    },
+                });
+            }
+        }
+        data_type.create_column(&values)
+    }
+
+    // The aggregate of the frame of each row. The states are accumulated row by row if all the
+    // frames of the partition start from its first row, otherwise each frame is aggregated.
+    fn aggregate(
+        &self,
+        block: &DataBlock,
+        args: &[Expression],
+        partitions: &[WindowPartition],
+        frame: &WindowFrame,
+        order_keys: &[(String, bool)],
+    ) -> Result<ColumnRef> {
+        let func = self.window_func.to_aggregate_function(&self.input_schema)?;
+        let columns = Self::columns(block, args.iter())?;
+        let mut builder = func.return_type()?.create_mutable(block.num_rows());
+
+        let arena = bumpalo::Bump::new();
+        let cumulative = frame.start_bound == WindowFrameBound::Preceding(None);
+        for partition in partitions {
+            let frames = Self::frames(block, partition, frame, order_keys)?;
+            match cumulative {
+                true => {
+                    let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                    func.init_state(place);
+                    let mut accumulated = partition.rows.start;
+                    for frame in frames {
+                        while accumulated < frame.end {
+                            func.accumulate_row(place, &columns, accumulated)?;
+                            accumulated += 1;
+                        }
+                        func.merge_result(place, builder.as_mut())?;
+                    }
+                }
+                false => {
+                    for frame in frames {
+                        let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                        func.init_state(place);
+                        for row in frame {
+                            func.accumulate_row(place, &columns, row)?;
+                        }
+                        func.merge_result(place, builder.as_mut())?;
+                    }
+                }
+            }
+        }
+        Ok(builder.to_column())
+    }
+}
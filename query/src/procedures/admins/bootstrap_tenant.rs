@@ -18,6 +18,8 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchema;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
+use common_meta_types::CreateDatabaseReq;
+use common_meta_types::DatabaseMeta;
 use common_meta_types::GrantObject;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserInfo;
@@ -25,6 +27,7 @@ use common_meta_types::UserOptionFlag;
 use common_meta_types::UserPrivilegeSet;
 use common_tracing::tracing;
 
+use crate::catalogs::Catalog;
 use crate::procedures::Procedure;
 use crate::procedures::ProcedureFeatures;
 use crate::sessions::QueryContext;
@@ -88,6 +91,18 @@ impl Procedure for BootstrapTenantProcedure {
         user_info.grants.grant_role(account_admin_role.identity());
         user_mgr.add_user(&tenant, user_info, true).await?;
 
+        // Create the default database, the sessions of the tenant use it by default.
+        let req = CreateDatabaseReq {
+            if_not_exists: true,
+            tenant,
+            db: "default".to_string(),
+            meta: DatabaseMeta {
+                engine: "".to_string(),
+                ..Default::default()
+            },
+        };
+        ctx.get_catalog().create_database(req).await?;
+
         Ok(DataBlock::empty())
     }
 
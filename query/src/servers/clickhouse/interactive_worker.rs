@@ -109,7 +109,17 @@ impl ClickHouseSession for InteractiveWorker {
         let ctx = self.session.create_query_context().await;
         match ctx {
             Ok(c) => {
-                let user_info_auth = c.get_auth_manager().auth(&credential).await;
+                let tenant = c.get_tenant();
+                let auth_manager = c.get_auth_manager();
+                let user_info_auth = match auth_manager.auth(&tenant, &credential).await {
+                    Ok(user_info) => {
+                        let checked = auth_manager
+                            .check_network_policy(&tenant, &user_info, Some(client_addr))
+                            .await;
+                        checked.map(|_| user_info)
+                    }
+                    Err(cause) => Err(cause),
+                };
                 match user_info_auth {
                     Ok(user_info) => {
                         self.session.set_current_user(user_info);
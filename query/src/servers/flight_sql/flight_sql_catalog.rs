@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::like_pattern_to_regex;
+use regex::Regex;
+
+use crate::catalogs::Catalog;
+use crate::servers::flight_sql::protobuf::CommandGetDbSchemas;
+use crate::servers::flight_sql::protobuf::CommandGetTables;
+use crate::sessions::QueryContext;
+use crate::storages::view::view_table::VIEW_ENGINE;
+
+// Databend has a single catalog, the databases are the schemas of Flight SQL.
+const CATALOG_NAME: &str = "default";
+
+const TABLE_TYPE_TABLE: &str = "TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+
+pub fn get_catalogs_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![DataField::new("catalog_name", Vu8::to_data_type())])
+}
+
+pub fn get_db_schemas_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("catalog_name", Vu8::to_data_type()),
+        DataField::new("db_schema_name", Vu8::to_data_type()),
+    ])
+}
+
+pub fn get_tables_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("catalog_name", Vu8::to_data_type()),
+        DataField::new("db_schema_name", Vu8::to_data_type()),
+        DataField::new("table_name", Vu8::to_data_type()),
+        DataField::new("table_type", Vu8::to_data_type()),
+    ])
+}
+
+pub fn get_table_types_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![DataField::new("table_type", Vu8::to_data_type())])
+}
+
+pub fn get_catalogs() -> DataBlock {
+    DataBlock::create(get_catalogs_schema(), vec![Series::from_data(vec![
+        CATALOG_NAME.as_bytes(),
+    ])])
+}
+
+pub fn get_table_types() -> DataBlock {
+    DataBlock::create(get_table_types_schema(), vec![Series::from_data(vec![
+        TABLE_TYPE_TABLE.as_bytes(),
+        TABLE_TYPE_VIEW.as_bytes(),
+    ])])
+}
+
+pub async fn get_db_schemas(
+    ctx: Arc<QueryContext>,
+    command: &CommandGetDbSchemas,
+) -> Result<DataBlock> {
+    let databases = match is_current_catalog(&command.catalog) {
+        false => vec![],
+        true => list_databases(&ctx, &command.db_schema_filter_pattern).await?,
+    };
+
+    let catalogs: Vec<&[u8]> = databases.iter().map(|_| CATALOG_NAME.as_bytes()).collect();
+    let databases: Vec<&[u8]> = databases.iter().map(|d| d.as_bytes()).collect();
+    Ok(DataBlock::create(get_db_schemas_schema(), vec![
+        Series::from_data(catalogs),
+        Series::from_data(databases),
+    ]))
+}
+
+pub async fn get_tables(ctx: Arc<QueryContext>, command: &CommandGetTables) -> Result<DataBlock> {
+    if command.include_schema {
+        return Err(ErrorCode::UnImplement(
+            "GetTables with include_schema is not supported",
+        ));
+    }
+
+    let mut database_tables = vec![];
+    if is_current_catalog(&command.catalog) {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+        let table_pattern = compile_pattern(&command.table_name_filter_pattern)?;
+        for database in list_databases(&ctx, &command.db_schema_filter_pattern).await? {
+            for table in catalog.list_tables(tenant.as_str(), &database).await? {
+                let table_type = match table.engine() == VIEW_ENGINE {
+                    true => TABLE_TYPE_VIEW,
+                    false => TABLE_TYPE_TABLE,
+                };
+                let type_matched = command.table_types.is_empty()
+                    || command.table_types.iter().any(|t| t == table_type);
+                if type_matched && matches(&table_pattern, table.name()) {
+                    database_tables.push((database.clone(), table.name().to_string(), table_type));
+                }
+            }
+        }
+    }
+
+    let catalogs: Vec<&[u8]> = database_tables
+        .iter()
+        .map(|_| CATALOG_NAME.as_bytes())
+        .collect();
+    let databases: Vec<&[u8]> = database_tables
+        .iter()
+        .map(|(d, _, _)| d.as_bytes())
+        .collect();
+    let names: Vec<&[u8]> = database_tables
+        .iter()
+        .map(|(_, t, _)| t.as_bytes())
+        .collect();
+    let table_types: Vec<&[u8]> = database_tables
+        .iter()
+        .map(|(_, _, t)| t.as_bytes())
+        .collect();
+    Ok(DataBlock::create(get_tables_schema(), vec![
+        Series::from_data(catalogs),
+        Series::from_data(databases),
+        Series::from_data(names),
+        Series::from_data(table_types),
+    ]))
+}
+
+// An empty catalog in the request means all the catalogs.
+fn is_current_catalog(catalog: &str) -> bool {
+    catalog.is_empty() || catalog == CATALOG_NAME
+}
+
+async fn list_databases(ctx: &Arc<QueryContext>, pattern: &str) -> Result<Vec<String>> {
+    let pattern = compile_pattern(pattern)?;
+    let tenant = ctx.get_tenant();
+    let databases = ctx.get_catalog().list_databases(tenant.as_str()).await?;
+    Ok(databases
+        .iter()
+        .map(|database| database.name().to_string())
+        .filter(|name| matches(&pattern, name))
+        .collect())
+}
+
+// The filter patterns use the syntax of LIKE, an empty pattern matches everything.
+fn compile_pattern(pattern: &str) -> Result<Option<Regex>> {
+    match pattern.is_empty() {
+        true => Ok(None),
+        false => Regex::new(&like_pattern_to_regex(pattern))
+            .map(Some)
+            .map_err(|e| ErrorCode::BadArguments(format!("Invalid filter pattern: {}", e))),
+    }
+}
+
+fn matches(pattern: &Option<Regex>, name: &str) -> bool {
+    pattern.as_ref().map(|p| p.is_match(name)).unwrap_or(true)
+}
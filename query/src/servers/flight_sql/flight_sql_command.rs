@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use prost::Message;
+use tonic::Status;
+
+use crate::servers::flight_sql::protobuf::ActionClosePreparedStatementRequest;
+use crate::servers::flight_sql::protobuf::ActionCreatePreparedStatementRequest;
+use crate::servers::flight_sql::protobuf::ActionCreatePreparedStatementResult;
+use crate::servers::flight_sql::protobuf::Any;
+use crate::servers::flight_sql::protobuf::CommandGetCatalogs;
+use crate::servers::flight_sql::protobuf::CommandGetDbSchemas;
+use crate::servers::flight_sql::protobuf::CommandGetTableTypes;
+use crate::servers::flight_sql::protobuf::CommandGetTables;
+use crate::servers::flight_sql::protobuf::CommandPreparedStatementQuery;
+use crate::servers::flight_sql::protobuf::CommandPreparedStatementUpdate;
+use crate::servers::flight_sql::protobuf::CommandStatementQuery;
+use crate::servers::flight_sql::protobuf::CommandStatementUpdate;
+use crate::servers::flight_sql::protobuf::DoPutUpdateResult;
+use crate::servers::flight_sql::protobuf::TicketStatementQuery;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+
+/// The Flight SQL messages, which are sent as `google.protobuf.Any` in the descriptor
+/// commands, the tickets and the action bodies.
+#[derive(Clone, Debug)]
+pub enum FlightSQLCommand {
+    GetCatalogs(CommandGetCatalogs),
+    GetDbSchemas(CommandGetDbSchemas),
+    GetTables(CommandGetTables),
+    GetTableTypes(CommandGetTableTypes),
+    StatementQuery(CommandStatementQuery),
+    TicketStatementQuery(TicketStatementQuery),
+    StatementUpdate(CommandStatementUpdate),
+    PreparedStatementQuery(CommandPreparedStatementQuery),
+    PreparedStatementUpdate(CommandPreparedStatementUpdate),
+    CreatePreparedStatementRequest(ActionCreatePreparedStatementRequest),
+    CreatePreparedStatementResult(ActionCreatePreparedStatementResult),
+    ClosePreparedStatementRequest(ActionClosePreparedStatementRequest),
+    DoPutUpdateResult(DoPutUpdateResult),
+}
+
+macro_rules! decode_command {
+    ($any: expr, $($variant: ident => $message: ident),*) => {
+        match $any.type_url.strip_prefix(TYPE_URL_PREFIX) {
+            $(Some(stringify!($message)) => $message::decode($any.value.as_slice())
+                .map(FlightSQLCommand::$variant)
+                .map_err(|e| Status::invalid_argument(format!("Cannot decode {}: {}", stringify!($message), e))),)*
+            _ => Err(Status::unimplemented(format!(
+                "Unsupported Flight SQL command: {}",
+                $any.type_url
+            ))),
+        }
+    };
+}
+
+impl FlightSQLCommand {
+    pub fn try_decode(buf: &[u8]) -> Result<FlightSQLCommand, Status> {
+        let any = Any::decode(buf)
+            .map_err(|e| Status::invalid_argument(format!("Cannot decode the command: {}", e)))?;
+
+        decode_command!(any,
+            GetCatalogs => CommandGetCatalogs,
+            GetDbSchemas => CommandGetDbSchemas,
+            GetTables => CommandGetTables,
+            GetTableTypes => CommandGetTableTypes,
+            StatementQuery => CommandStatementQuery,
+            TicketStatementQuery => TicketStatementQuery,
+            StatementUpdate => CommandStatementUpdate,
+            PreparedStatementQuery => CommandPreparedStatementQuery,
+            PreparedStatementUpdate => CommandPreparedStatementUpdate,
+            CreatePreparedStatementRequest => ActionCreatePreparedStatementRequest,
+            CreatePreparedStatementResult => ActionCreatePreparedStatementResult,
+            ClosePreparedStatementRequest => ActionClosePreparedStatementRequest,
+            DoPutUpdateResult => DoPutUpdateResult
+        )
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let (name, value) = match self {
+            FlightSQLCommand::GetCatalogs(v) => ("CommandGetCatalogs", v.encode_to_vec()),
+            FlightSQLCommand::GetDbSchemas(v) => ("CommandGetDbSchemas", v.encode_to_vec()),
+            FlightSQLCommand::GetTables(v) => ("CommandGetTables", v.encode_to_vec()),
+            FlightSQLCommand::GetTableTypes(v) => ("CommandGetTableTypes", v.encode_to_vec()),
+            FlightSQLCommand::StatementQuery(v) => ("CommandStatementQuery", v.encode_to_vec()),
+            FlightSQLCommand::TicketStatementQuery(v) => {
+                ("TicketStatementQuery", v.encode_to_vec())
+            }
+            FlightSQLCommand::StatementUpdate(v) => ("CommandStatementUpdate", v.encode_to_vec()),
+            FlightSQLCommand::PreparedStatementQuery(v) => {
+                ("CommandPreparedStatementQuery", v.encode_to_vec())
+            }
+            FlightSQLCommand::PreparedStatementUpdate(v) => {
+                ("CommandPreparedStatementUpdate", v.encode_to_vec())
+            }
+            FlightSQLCommand::CreatePreparedStatementRequest(v) => {
+                ("ActionCreatePreparedStatementRequest", v.encode_to_vec())
+            }
+            FlightSQLCommand::CreatePreparedStatementResult(v) => {
+                ("ActionCreatePreparedStatementResult", v.encode_to_vec())
+            }
+            FlightSQLCommand::ClosePreparedStatementRequest(v) => {
+                ("ActionClosePreparedStatementRequest", v.encode_to_vec())
+            }
+            FlightSQLCommand::DoPutUpdateResult(v) => ("DoPutUpdateResult", v.encode_to_vec()),
+        };
+
+        Any {
+            type_url: format!("{}{}", TYPE_URL_PREFIX, name),
+            value,
+        }
+        .encode_to_vec()
+    }
+}
@@ -0,0 +1,530 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_arrow::arrow_format::flight::data::Action;
+use common_arrow::arrow_format::flight::data::ActionType;
+use common_arrow::arrow_format::flight::data::Criteria;
+use common_arrow::arrow_format::flight::data::Empty;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::data::FlightEndpoint;
+use common_arrow::arrow_format::flight::data::FlightInfo;
+use common_arrow::arrow_format::flight::data::HandshakeRequest;
+use common_arrow::arrow_format::flight::data::HandshakeResponse;
+use common_arrow::arrow_format::flight::data::PutResult;
+use common_arrow::arrow_format::flight::data::Result as FlightResult;
+use common_arrow::arrow_format::flight::data::SchemaResult;
+use common_arrow::arrow_format::flight::data::Ticket;
+use common_arrow::arrow_format::flight::service::flight_service_server::FlightService;
+use common_base::tokio::sync::mpsc;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_infallible::RwLock;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
+use tokio_stream::Stream;
+use tokio_stream::StreamExt;
+use tonic::Request;
+use tonic::Response as RawResponse;
+use tonic::Status;
+use tonic::Streaming;
+
+use crate::interpreters::InterpreterFactory;
+use crate::servers::flight_sql::flight_sql_catalog;
+use crate::servers::flight_sql::flight_sql_command::FlightSQLCommand;
+use crate::servers::flight_sql::flight_sql_stream::FlightSQLDataStream;
+use crate::servers::flight_sql::protobuf::ActionCreatePreparedStatementResult;
+use crate::servers::flight_sql::protobuf::DoPutUpdateResult;
+use crate::servers::flight_sql::protobuf::TicketStatementQuery;
+use crate::servers::http::formats::arrow_output::ArrowStreamSerializer;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
+use crate::sessions::SessionType;
+use crate::sql::PlanParser;
+use crate::users::auth::auth_mgr::Credential;
+
+pub type FlightStream<T> =
+    Pin<Box<dyn Stream<Item = std::result::Result<T, Status>> + Send + Sync + 'static>>;
+
+type Response<T> = std::result::Result<RawResponse<T>, Status>;
+type StreamReq<T> = Request<Streaming<T>>;
+
+// The sessions which are not used for a while are released at the next handshake.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+// The metadata of the handshake to choose the tenant of the session.
+const TENANT_METADATA_KEY: &str = "x-databend-tenant";
+
+const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+struct FlightSQLSession {
+    session: SessionRef,
+    last_access: Instant,
+}
+
+pub struct FlightSQLService {
+    sessions: Arc<SessionManager>,
+    // The authenticated sessions by the bearer token returned from the handshake.
+    tokens: RwLock<HashMap<String, FlightSQLSession>>,
+    // The queries of the prepared statements by the handle.
+    prepared_statements: RwLock<HashMap<Vec<u8>, String>>,
+}
+
+impl FlightSQLService {
+    pub fn create(sessions: Arc<SessionManager>) -> Self {
+        FlightSQLService {
+            sessions,
+            tokens: RwLock::new(HashMap::new()),
+            prepared_statements: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn authenticate<T>(&self, request: &Request<T>) -> Result<SessionRef> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if let Some(token) = authorization.strip_prefix("Bearer ") {
+            let mut tokens = self.tokens.write();
+            return match tokens.get_mut(token) {
+                None => Err(ErrorCode::AuthenticateFailure("invalid bearer token")),
+                Some(session) => {
+                    session.last_access = Instant::now();
+                    Ok(session.session.clone())
+                }
+            };
+        }
+
+        let (name, password) = match authorization.strip_prefix("Basic ") {
+            None => return Err(ErrorCode::AuthenticateFailure("authorization required")),
+            Some(encoded) => decode_basic(encoded)?,
+        };
+
+        let credential = Credential::Password {
+            name,
+            password: (!password.is_empty()).then_some(password.into_bytes()),
+            hostname: request.remote_addr().map(|addr| addr.ip().to_string()),
+        };
+
+        let session = self.sessions.create_session(SessionType::FlightSQL).await?;
+        // The session is in the tenant of the config, unless the client chooses another one, the
+        // built-in users are only accepted in the tenant of the config, see `AuthMgr::auth`.
+        let tenant = request
+            .metadata()
+            .get(TENANT_METADATA_KEY)
+            .and_then(|value| value.to_str().ok())
+            .filter(|tenant| !tenant.is_empty());
+        if let Some(tenant) = tenant {
+            session.set_current_tenant(tenant.to_string()).await?;
+        }
+        let user_info = self
+            .sessions
+            .get_auth_manager()
+            .auth(&session.get_tenant(), &credential)
+            .await?;
+        session.set_current_user(user_info);
+        Ok(session)
+    }
+
+    fn get_prepared_query(&self, handle: &[u8]) -> Result<String> {
+        match self.prepared_statements.read().get(handle) {
+            Some(query) => Ok(query.clone()),
+            None => Err(ErrorCode::BadArguments("Unknown prepared statement handle")),
+        }
+    }
+
+    async fn describe_query(&self, session: &SessionRef, query: &str) -> Result<DataSchemaRef> {
+        let context = session.create_query_context().await?;
+        let plan = PlanParser::parse(context.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(context, plan)?;
+        Ok(interpreter.schema())
+    }
+
+    async fn describe_command(
+        &self,
+        session: &SessionRef,
+        command: &FlightSQLCommand,
+    ) -> Result<DataSchemaRef> {
+        match command {
+            FlightSQLCommand::GetCatalogs(_) => Ok(flight_sql_catalog::get_catalogs_schema()),
+            FlightSQLCommand::GetDbSchemas(_) => Ok(flight_sql_catalog::get_db_schemas_schema()),
+            FlightSQLCommand::GetTables(_) => Ok(flight_sql_catalog::get_tables_schema()),
+            FlightSQLCommand::GetTableTypes(_) => Ok(flight_sql_catalog::get_table_types_schema()),
+            FlightSQLCommand::StatementQuery(command) => {
+                self.describe_query(session, &command.query).await
+            }
+            FlightSQLCommand::PreparedStatementQuery(command) => {
+                let query = self.get_prepared_query(&command.prepared_statement_handle)?;
+                self.describe_query(session, &query).await
+            }
+            _ => Err(ErrorCode::BadArguments(
+                "The command of the descriptor has no result set",
+            )),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, session))]
+    async fn execute_query(
+        &self,
+        session: SessionRef,
+        query: &str,
+    ) -> Result<FlightStream<FlightData>> {
+        let context = session.create_query_context().await?;
+        context.attach_query_str(query);
+        let plan = PlanParser::parse(context.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(context.clone(), plan)?;
+        let schema = interpreter.schema();
+
+        let (tx, rx) = mpsc::channel(2);
+        context.try_spawn(
+            async move {
+                // Write start query log.
+                let _ = interpreter
+                    .start()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+                match interpreter.execute(None).await {
+                    Err(cause) => {
+                        let _ = tx.send(Err(cause)).await;
+                    }
+                    Ok(mut data_stream) => {
+                        while let Some(block) = data_stream.next().await {
+                            if tx.send(block).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                }
+                // Write finish query log.
+                let _ = interpreter
+                    .finish()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
+            }
+            .in_current_span(),
+        )?;
+
+        Ok(Box::pin(FlightSQLDataStream::create(session, schema, rx)))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, session))]
+    async fn execute_update(&self, session: SessionRef, query: &str) -> Result<i64> {
+        let context = session.create_query_context().await?;
+        context.attach_query_str(query);
+        let plan = PlanParser::parse(context.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(context.clone(), plan)?;
+
+        let query_result = context.try_spawn(
+            async move {
+                let _ = interpreter
+                    .start()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+                let data_stream = interpreter.execute(None).await?;
+                data_stream.collect::<Result<Vec<DataBlock>>>().await?;
+                let _ = interpreter
+                    .finish()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
+                Ok::<(), ErrorCode>(())
+            }
+            .in_current_span(),
+        )?;
+
+        query_result
+            .await
+            .map_err_to_code(ErrorCode::TokioError, || {
+                "Cannot join handle from context's runtime"
+            })??;
+        Ok(context.get_write_progress_value().rows as i64)
+    }
+
+    async fn catalog_stream(
+        &self,
+        session: SessionRef,
+        command: &FlightSQLCommand,
+    ) -> Result<FlightStream<FlightData>> {
+        let schema = self.describe_command(&session, command).await?;
+        let context = session.create_query_context().await?;
+        let block = match command {
+            FlightSQLCommand::GetCatalogs(_) => flight_sql_catalog::get_catalogs(),
+            FlightSQLCommand::GetTableTypes(_) => flight_sql_catalog::get_table_types(),
+            FlightSQLCommand::GetDbSchemas(command) => {
+                flight_sql_catalog::get_db_schemas(context, command).await?
+            }
+            FlightSQLCommand::GetTables(command) => {
+                flight_sql_catalog::get_tables(context, command).await?
+            }
+            _ => return Err(ErrorCode::LogicalError("Not a catalog command")),
+        };
+
+        let (tx, rx) = mpsc::channel(1);
+        let _ = tx.send(Ok(block)).await;
+        Ok(Box::pin(FlightSQLDataStream::create(session, schema, rx)))
+    }
+
+    fn evict_idle_sessions(&self) {
+        let now = Instant::now();
+        self.tokens
+            .write()
+            .retain(|_, session| now.duration_since(session.last_access) < SESSION_IDLE_TIMEOUT);
+    }
+}
+
+#[async_trait::async_trait]
+impl FlightService for FlightSQLService {
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+
+    async fn handshake(
+        &self,
+        request: StreamReq<HandshakeRequest>,
+    ) -> Response<Self::HandshakeStream> {
+        self.evict_idle_sessions();
+
+        let session = self.authenticate(&request).await?;
+        let token = session.get_id();
+        self.tokens.write().insert(token.clone(), FlightSQLSession {
+            session,
+            last_access: Instant::now(),
+        });
+
+        let output = HandshakeResponse {
+            protocol_version: 0,
+            payload: token.as_bytes().to_vec(),
+        };
+        let mut response = RawResponse::new(
+            Box::pin(tokio_stream::once(Ok(output))) as FlightStream<HandshakeResponse>
+        );
+        let bearer = format!("Bearer {}", token)
+            .parse()
+            .map_err(|e| Status::internal(format!("Invalid bearer token: {}", e)))?;
+        response.metadata_mut().insert("authorization", bearer);
+        Ok(response)
+    }
+
+    type ListFlightsStream = FlightStream<FlightInfo>;
+
+    async fn list_flights(&self, _: Request<Criteria>) -> Response<Self::ListFlightsStream> {
+        Err(Status::unimplemented(
+            "DatabendQuery does not implement list_flights.",
+        ))
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_flight_info(&self, request: Request<FlightDescriptor>) -> Response<FlightInfo> {
+        let session = self.authenticate(&request).await?;
+        let descriptor = request.into_inner();
+        let command = FlightSQLCommand::try_decode(&descriptor.cmd)?;
+        let schema = self.describe_command(&session, &command).await?;
+
+        // The statements are executed at DoGet, the ticket carries the query itself.
+        let ticket = match command {
+            FlightSQLCommand::StatementQuery(command) => {
+                FlightSQLCommand::TicketStatementQuery(TicketStatementQuery {
+                    statement_handle: command.query.into_bytes(),
+                })
+                .encode()
+            }
+            _ => descriptor.cmd.clone(),
+        };
+
+        Ok(RawResponse::new(FlightInfo {
+            schema: ArrowStreamSerializer::create(schema).serialize_schema(),
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket { ticket }),
+                location: vec![],
+            }],
+            total_records: -1,
+            total_bytes: -1,
+        }))
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_schema(&self, request: Request<FlightDescriptor>) -> Response<SchemaResult> {
+        let session = self.authenticate(&request).await?;
+        let command = FlightSQLCommand::try_decode(&request.into_inner().cmd)?;
+        let schema = self.describe_command(&session, &command).await?;
+
+        Ok(RawResponse::new(SchemaResult {
+            schema: ArrowStreamSerializer::create(schema).serialize_schema(),
+        }))
+    }
+
+    type DoGetStream = FlightStream<FlightData>;
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
+        let session = self.authenticate(&request).await?;
+        let command = FlightSQLCommand::try_decode(&request.into_inner().ticket)?;
+
+        let stream = match &command {
+            FlightSQLCommand::TicketStatementQuery(ticket) => {
+                let query = String::from_utf8(ticket.statement_handle.clone())
+                    .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {}", e)))?;
+                self.execute_query(session, &query).await?
+            }
+            FlightSQLCommand::PreparedStatementQuery(command) => {
+                let query = self.get_prepared_query(&command.prepared_statement_handle)?;
+                self.execute_query(session, &query).await?
+            }
+            FlightSQLCommand::GetCatalogs(_)
+            | FlightSQLCommand::GetDbSchemas(_)
+            | FlightSQLCommand::GetTables(_)
+            | FlightSQLCommand::GetTableTypes(_) => self.catalog_stream(session, &command).await?,
+            _ => return Err(Status::invalid_argument("Invalid ticket")),
+        };
+
+        Ok(RawResponse::new(stream))
+    }
+
+    type DoPutStream = FlightStream<PutResult>;
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_put(&self, request: StreamReq<FlightData>) -> Response<Self::DoPutStream> {
+        let session = self.authenticate(&request).await?;
+        let mut stream = request.into_inner();
+        let descriptor = match stream.message().await? {
+            Some(FlightData {
+                flight_descriptor: Some(descriptor),
+                ..
+            }) => descriptor,
+            _ => return Err(Status::invalid_argument("Missing the flight descriptor")),
+        };
+
+        let query = match FlightSQLCommand::try_decode(&descriptor.cmd)? {
+            FlightSQLCommand::StatementUpdate(command) => command.query,
+            FlightSQLCommand::PreparedStatementUpdate(command) => {
+                self.get_prepared_query(&command.prepared_statement_handle)?
+            }
+            _ => {
+                return Err(Status::unimplemented(
+                    "DatabendQuery only implements do_put for the updates.",
+                ))
+            }
+        };
+
+        let record_count = self.execute_update(session, &query).await?;
+        let result = PutResult {
+            app_metadata: FlightSQLCommand::DoPutUpdateResult(DoPutUpdateResult { record_count })
+                .encode(),
+        };
+        Ok(RawResponse::new(
+            Box::pin(tokio_stream::once(Ok(result))) as FlightStream<PutResult>
+        ))
+    }
+
+    type DoExchangeStream = FlightStream<FlightData>;
+
+    async fn do_exchange(&self, _: StreamReq<FlightData>) -> Response<Self::DoExchangeStream> {
+        Err(Status::unimplemented(
+            "DatabendQuery does not implement do_exchange.",
+        ))
+    }
+
+    type DoActionStream = FlightStream<FlightResult>;
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
+        let session = self.authenticate(&request).await?;
+        let action = request.into_inner();
+
+        let body = match (
+            action.r#type.as_str(),
+            FlightSQLCommand::try_decode(&action.body)?,
+        ) {
+            (
+                CREATE_PREPARED_STATEMENT,
+                FlightSQLCommand::CreatePreparedStatementRequest(request),
+            ) => {
+                let schema = self.describe_query(&session, &request.query).await?;
+                let handle = uuid::Uuid::new_v4().to_string().into_bytes();
+                self.prepared_statements
+                    .write()
+                    .insert(handle.clone(), request.query);
+
+                // The parameters are not supported, the parameter schema is left empty.
+                FlightSQLCommand::CreatePreparedStatementResult(
+                    ActionCreatePreparedStatementResult {
+                        prepared_statement_handle: handle,
+                        dataset_schema: ArrowStreamSerializer::create(schema).serialize_schema(),
+                        parameter_schema: vec![],
+                    },
+                )
+                .encode()
+            }
+            (
+                CLOSE_PREPARED_STATEMENT,
+                FlightSQLCommand::ClosePreparedStatementRequest(request),
+            ) => {
+                self.prepared_statements
+                    .write()
+                    .remove(&request.prepared_statement_handle);
+                vec![]
+            }
+            (action_type, _) => {
+                return Err(Status::invalid_argument(format!(
+                    "Invalid body of the action {}",
+                    action_type
+                )))
+            }
+        };
+
+        Ok(RawResponse::new(
+            Box::pin(tokio_stream::once(Ok(FlightResult { body }))) as FlightStream<FlightResult>,
+        ))
+    }
+
+    type ListActionsStream = FlightStream<ActionType>;
+
+    async fn list_actions(&self, _: Request<Empty>) -> Response<Self::ListActionsStream> {
+        Ok(RawResponse::new(Box::pin(tokio_stream::iter(vec![
+            Ok(ActionType {
+                r#type: CREATE_PREPARED_STATEMENT.to_string(),
+                description: "Creates a reusable prepared statement resource on the server."
+                    .to_string(),
+            }),
+            Ok(ActionType {
+                r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+                description: "Closes a reusable prepared statement resource on the server."
+                    .to_string(),
+            }),
+        ])) as FlightStream<ActionType>))
+    }
+}
+
+// Decode the `user:password` credentials of the basic authorization.
+fn decode_basic(encoded: &str) -> Result<(String, String)> {
+    let decoded = base64::decode(encoded.trim())
+        .map_err(|_| ErrorCode::AuthenticateFailure("bad Basic authorization"))?;
+    let decoded = String::from_utf8(decoded)
+        .map_err(|_| ErrorCode::AuthenticateFailure("bad Basic authorization"))?;
+    match decoded.split_once(':') {
+        Some((name, password)) => Ok((name.to_string(), password.to_string())),
+        None => Ok((decoded, String::new())),
+    }
+}
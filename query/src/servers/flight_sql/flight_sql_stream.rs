@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_base::tokio::macros::support::Pin;
+use common_base::tokio::macros::support::Poll;
+use common_base::tokio::sync::mpsc::Receiver;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use futures::task::Context;
+use tokio_stream::Stream;
+use tonic::Status;
+
+use crate::servers::http::formats::arrow_output::output_arrow_chunk;
+use crate::servers::http::formats::arrow_output::output_arrow_schema;
+use crate::sessions::SessionRef;
+
+/// The result of a DoGet: the schema message, then a record batch message per block.
+pub struct FlightSQLDataStream {
+    // Keep the session alive until the client has read all the results.
+    _session: SessionRef,
+    schema: Option<FlightData>,
+    input: Receiver<common_exception::Result<DataBlock>>,
+    ipc_fields: Vec<IpcField>,
+    options: WriteOptions,
+}
+
+impl FlightSQLDataStream {
+    pub fn create(
+        session: SessionRef,
+        schema: DataSchemaRef,
+        input: Receiver<common_exception::Result<DataBlock>>,
+    ) -> FlightSQLDataStream {
+        let arrow_schema = output_arrow_schema(&schema);
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let schema = serialize_schema(&arrow_schema, Some(&ipc_fields));
+
+        FlightSQLDataStream {
+            _session: session,
+            schema: Some(schema),
+            input,
+            ipc_fields,
+            options: WriteOptions { compression: None },
+        }
+    }
+}
+
+impl Stream for FlightSQLDataStream {
+    type Item = Result<FlightData, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(schema) = self.schema.take() {
+            return Poll::Ready(Some(Ok(schema)));
+        }
+
+        self.input.poll_recv(cx).map(|x| match x {
+            None => None,
+            Some(Err(error)) => Some(Err(Status::from(error))),
+            Some(Ok(block)) => match output_arrow_chunk(&block) {
+                Err(error) => Some(Err(Status::from(error))),
+                Ok(chunk) => {
+                    let (dicts, values) = serialize_batch(&chunk, &self.ipc_fields, &self.options);
+
+                    match dicts.is_empty() {
+                        true => Some(Ok(values)),
+                        false => Some(Err(Status::unimplemented(
+                            "DatabendQuery does not implement dicts.",
+                        ))),
+                    }
+                }
+            },
+        })
+    }
+}
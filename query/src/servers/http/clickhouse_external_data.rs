@@ -0,0 +1,197 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_streams::CsvSourceBuilder;
+use common_streams::Source;
+use futures::io::Cursor;
+use poem::web::Multipart;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+// https://clickhouse.com/docs/en/engines/table-engines/special/external-data/
+
+pub const EXTERNAL_DATA_ENGINE: &str = "ExternalData";
+
+// The ids of the external tables, far above the ids of the tables in the meta service.
+static NEXT_EXTERNAL_TABLE_ID: AtomicU64 = AtomicU64::new(1 << 62);
+
+/// A table sent along with the query, which only lives during the query.
+pub struct ExternalDataTable {
+    table_info: TableInfo,
+    blocks: Vec<DataBlock>,
+}
+
+impl SyncSystemTable for ExternalDataTable {
+    const NAME: &'static str = "external_data";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        match self.blocks.is_empty() {
+            true => Ok(DataBlock::empty_with_schema(self.table_info.schema())),
+            false => DataBlock::concat_blocks(&self.blocks),
+        }
+    }
+}
+
+impl ExternalDataTable {
+    pub fn create(name: &str, schema: DataSchemaRef, blocks: Vec<DataBlock>) -> Arc<dyn Table> {
+        let table_id = NEXT_EXTERNAL_TABLE_ID.fetch_add(1, Ordering::Relaxed);
+        let table_info = TableInfo {
+            desc: format!("'_external'.'{}'", name),
+            name: name.to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: EXTERNAL_DATA_ENGINE.to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(ExternalDataTable { table_info, blocks })
+    }
+}
+
+/// Parse the parts of the multipart request into the external tables of the query.
+///
+/// The part name is the table name, its columns are described by the query parameters
+/// `<name>_structure` (`id UInt32, name String`) or `<name>_types` (`UInt32,String`, the
+/// columns are named `_1`, `_2`, ...), and `<name>_format` is TabSeparated by default.
+pub async fn register_external_tables(
+    ctx: &Arc<QueryContext>,
+    params: &HashMap<String, String>,
+    mut multipart: Multipart,
+) -> Result<()> {
+    let format_settings = ctx.get_format_settings()?;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err_to_code(ErrorCode::BadBytes, || "Read external data part error")?
+    {
+        let name = match field.name() {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "The external data part must have a name",
+                ))
+            }
+        };
+
+        let schema = external_table_schema(&name, params)?;
+        let format = params
+            .get(&format!("{}_format", name))
+            .map(|f| f.as_str())
+            .unwrap_or("TabSeparated");
+
+        let mut builder = CsvSourceBuilder::create(schema.clone(), format_settings.clone());
+        match format {
+            "TSV" | "TabSeparated" => builder.field_delimiter("\t"),
+            "TSVWithNames" | "TabSeparatedWithNames" => {
+                builder.field_delimiter("\t").skip_header(1)
+            }
+            "CSV" => builder.field_delimiter(","),
+            "CSVWithNames" => builder.field_delimiter(",").skip_header(1),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The format {} of the external data {} is not supported",
+                    format, name
+                )))
+            }
+        };
+
+        let bytes = field
+            .bytes()
+            .await
+            .map_err_to_code(ErrorCode::BadBytes, || "Read external data part error")?;
+        let mut source = builder.build(Cursor::new(bytes))?;
+        let mut blocks = vec![];
+        while let Some(block) = source.read().await? {
+            blocks.push(block);
+        }
+
+        ctx.add_external_table(ExternalDataTable::create(&name, schema, blocks))?;
+    }
+    Ok(())
+}
+
+fn external_table_schema(name: &str, params: &HashMap<String, String>) -> Result<DataSchemaRef> {
+    let mut fields = vec![];
+    if let Some(structure) = params.get(&format!("{}_structure", name)) {
+        for column in split_top_level(structure) {
+            let (column_name, type_name) =
+                column.split_once(char::is_whitespace).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid column '{}' in the structure of the external data {}",
+                        column, name
+                    ))
+                })?;
+            let data_type = TypeFactory::instance().get(type_name.trim())?;
+            fields.push(DataField::new(column_name, data_type.clone()));
+        }
+    } else if let Some(types) = params.get(&format!("{}_types", name)) {
+        for (index, type_name) in split_top_level(types).into_iter().enumerate() {
+            let data_type = TypeFactory::instance().get(type_name)?;
+            fields.push(DataField::new(
+                &format!("_{}", index + 1),
+                data_type.clone(),
+            ));
+        }
+    }
+
+    if fields.is_empty() {
+        return Err(ErrorCode::BadArguments(format!(
+            "Neither {}_structure nor {}_types is set for the external data",
+            name, name
+        )));
+    }
+    Ok(DataSchemaRefExt::create(fields))
+}
+
+// Split by the commas outside of the parentheses, e.g. `a Nullable(String), b Array(UInt8)`.
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(value[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(value[start..].trim());
+    parts.into_iter().filter(|p| !p.is_empty()).collect()
+}
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_stream::stream;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use common_io::prelude::FormatSettings;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
 use common_streams::NDJsonSourceBuilder;
@@ -25,23 +27,35 @@ use common_streams::SendableDataBlockStream;
 use common_streams::SourceStream;
 use common_tracing::tracing;
 use futures::StreamExt;
+use once_cell::sync::Lazy;
 use poem::error::BadRequest;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
+use poem::get;
 use poem::post;
 use poem::web::Data;
+use poem::web::Multipart;
 use poem::web::Query;
 use poem::Body;
 use poem::Endpoint;
 use poem::EndpointExt;
+use poem::FromRequest;
+use poem::Request;
+use poem::RequestBody;
+use poem::Response;
 use poem::Route;
+use regex::Regex;
 use serde::Deserialize;
 
 use crate::interpreters::InterpreterFactory;
-use crate::servers::http::formats::tsv_output::block_to_tsv;
+use crate::servers::http::clickhouse_external_data::register_external_tables;
+use crate::servers::http::formats::output_serializer::OutputSerializer;
 use crate::servers::http::formats::Format;
+use crate::servers::http::formats::OutputFormat;
+use crate::servers::http::middleware::HttpTenant;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
 use crate::sessions::SessionType;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
@@ -50,21 +64,139 @@ use crate::sql::PlanParser;
 // https://clickhouse.com/docs/en/interfaces/http/
 
 const FORMAT_JSON_EACH_ROW: &str = "JSONEachRow";
+const FORMAT_JSON: &str = "JSON";
+const FORMAT_TSV: &str = "TSV";
+const FORMAT_TAB_SEPARATED: &str = "TabSeparated";
+const FORMAT_TSV_WITH_NAMES: &str = "TSVWithNames";
+const FORMAT_TAB_SEPARATED_WITH_NAMES: &str = "TabSeparatedWithNames";
+const FORMAT_TSV_WITH_NAMES_AND_TYPES: &str = "TSVWithNamesAndTypes";
+const FORMAT_TAB_SEPARATED_WITH_NAMES_AND_TYPES: &str = "TabSeparatedWithNamesAndTypes";
+const FORMAT_CSV: &str = "CSV";
+const FORMAT_CSV_WITH_NAMES: &str = "CSVWithNames";
+const FORMAT_ARROW_STREAM: &str = "ArrowStream";
+
+// The trailing `FORMAT <name>` clause of the queries other than INSERT.
+static FORMAT_CLAUSE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\s+FORMAT\s+([A-Za-z]+)\s*;?\s*$").unwrap());
 
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
+    // The query may also be sent in the body of POST.
+    #[serde(default)]
     query: String,
+    // The format of the query results, TSV by default.
+    default_format: Option<String>,
+    // The current database of the query.
+    database: Option<String>,
+    // The structures and formats of the external data, e.g. `<name>_structure`.
+    #[serde(flatten)]
+    external: HashMap<String, String>,
 }
 
 fn supported_formats() -> String {
     vec![FORMAT_JSON_EACH_ROW].join("|")
 }
 
+fn supported_output_formats() -> String {
+    vec![
+        FORMAT_TSV,
+        FORMAT_TAB_SEPARATED,
+        FORMAT_TSV_WITH_NAMES,
+        FORMAT_TAB_SEPARATED_WITH_NAMES,
+        FORMAT_TSV_WITH_NAMES_AND_TYPES,
+        FORMAT_TAB_SEPARATED_WITH_NAMES_AND_TYPES,
+        FORMAT_CSV,
+        FORMAT_CSV_WITH_NAMES,
+        FORMAT_JSON_EACH_ROW,
+        FORMAT_JSON,
+        FORMAT_ARROW_STREAM,
+    ]
+    .join("|")
+}
+
+fn try_parse_output_format(format: Option<&str>) -> Result<OutputFormat> {
+    match format {
+        None | Some("") | Some(FORMAT_TSV) | Some(FORMAT_TAB_SEPARATED) => Ok(OutputFormat::TSV),
+        Some(FORMAT_TSV_WITH_NAMES) | Some(FORMAT_TAB_SEPARATED_WITH_NAMES) => {
+            Ok(OutputFormat::TSVWithNames)
+        }
+        Some(FORMAT_TSV_WITH_NAMES_AND_TYPES) | Some(FORMAT_TAB_SEPARATED_WITH_NAMES_AND_TYPES) => {
+            Ok(OutputFormat::TSVWithNamesAndTypes)
+        }
+        Some(FORMAT_CSV) => Ok(OutputFormat::CSV),
+        Some(FORMAT_CSV_WITH_NAMES) => Ok(OutputFormat::CSVWithNames),
+        Some(FORMAT_JSON_EACH_ROW) => Ok(OutputFormat::JSONEachRow),
+        Some(FORMAT_JSON) => Ok(OutputFormat::JSON),
+        Some(FORMAT_ARROW_STREAM) => Ok(OutputFormat::ArrowStream),
+        Some(format) => Err(ErrorCode::SyntaxException(format!(
+            "output format {} not supported; only support: {}",
+            format,
+            supported_output_formats()
+        ))),
+    }
+}
+
+// Strip the `FORMAT <name>` clause from the query, it takes precedence over `default_format`.
+// The FORMAT clause of INSERT is the format of the inserted data, which is left to the parser.
+fn split_format_clause(sql: &str) -> (String, Option<String>) {
+    let is_insert = sql
+        .trim_start()
+        .get(..6)
+        .map(|s| s.eq_ignore_ascii_case("INSERT"))
+        .unwrap_or(false);
+    if !is_insert {
+        if let Some(captures) = FORMAT_CLAUSE.captures(sql) {
+            let clause = captures.get(0).unwrap();
+            return (
+                sql[..clause.start()].to_string(),
+                Some(captures[1].to_string()),
+            );
+        }
+    }
+    (sql.to_string(), None)
+}
+
+fn output_format(params: &StatementHandlerParams, clause: Option<String>) -> Result<OutputFormat> {
+    match clause {
+        Some(format) => try_parse_output_format(Some(&format)),
+        None => try_parse_output_format(params.default_format.as_deref()),
+    }
+}
+
+async fn create_query_context(
+    sessions: &Arc<SessionManager>,
+    tenant: &str,
+    user_info: &UserInfo,
+    params: &StatementHandlerParams,
+) -> PoemResult<(SessionRef, Arc<QueryContext>)> {
+    let session = sessions
+        .create_session(SessionType::ClickHouseHttpHandler)
+        .await
+        .map_err(InternalServerError)?;
+    session
+        .set_current_tenant(tenant.to_string())
+        .await
+        .map_err(InternalServerError)?;
+    session.set_current_user(user_info.clone());
+
+    let ctx = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+    if let Some(database) = &params.database {
+        ctx.set_current_database(database.clone())
+            .await
+            .map_err(BadRequest)?;
+    }
+    Ok((session, ctx))
+}
+
 async fn execute(
     ctx: Arc<QueryContext>,
     plan: PlanNode,
     input_stream: Option<SendableDataBlockStream>,
-) -> Result<Body> {
+    format: OutputFormat,
+) -> Result<Response> {
     let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
     let _ = interpreter
         .start()
@@ -74,44 +206,44 @@ async fn execute(
     let data_stream = interpreter.execute(input_stream).await?;
     let mut data_stream = ctx.try_create_abortable(data_stream)?;
 
+    let mut serializer = OutputSerializer::create(format, interpreter.schema());
+    let content_type = serializer.content_type();
+
     let stream = stream! {
+        yield(Ok(serializer.serialize_prefix()));
+
         while let Some(block) = data_stream.next().await {
             match block{
-                Ok(block) => {
-                    yield(block_to_tsv(&block))
-                },
+                Ok(block) => yield(serializer.serialize_block(&block)),
                 Err(err) => yield(Err(err)),
             };
         }
 
+        yield(Ok(serializer.serialize_suffix()));
+
         let _ = interpreter
             .finish()
             .await
             .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
     };
 
-    Ok(Body::from_bytes_stream(stream))
+    Ok(Response::builder()
+        .content_type(content_type)
+        .body(Body::from_bytes_stream(stream)))
 }
 
 #[poem::handler]
 pub async fn clickhouse_handler_get(
     sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
     user_info: Data<&UserInfo>,
     Query(params): Query<StatementHandlerParams>,
-) -> PoemResult<Body> {
-    let session_manager = sessions_extension.0;
-    let session = session_manager
-        .create_session(SessionType::ClickHouseHttpHandler)
-        .await
-        .map_err(InternalServerError)?;
-    session.set_current_user(user_info.0.clone());
+) -> PoemResult<Response> {
+    let (sql, clause) = split_format_clause(&params.query);
+    let format = output_format(&params, clause).map_err(BadRequest)?;
+    let (_session, context) =
+        create_query_context(sessions_extension.0, tenant, user_info.0, &params).await?;
 
-    let context = session
-        .create_query_context()
-        .await
-        .map_err(InternalServerError)?;
-
-    let mut sql = params.query;
     let plan = PlanParser::parse(context.clone(), &sql)
         .await
         .map_err(BadRequest)?;
@@ -122,7 +254,7 @@ pub async fn clickhouse_handler_get(
         )));
     }
     context.attach_query_str(&sql);
-    execute(context, plan, None)
+    execute(context, plan, None, format)
         .await
         .map_err(InternalServerError)
 }
@@ -158,41 +290,62 @@ fn try_parse_insert_formatted(sql: &str) -> Result<Option<(Format, Vec<DfStateme
 
 #[poem::handler]
 pub async fn clickhouse_handler_post(
+    req: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
     user_info: Data<&UserInfo>,
     body: Body,
     Query(params): Query<StatementHandlerParams>,
-) -> PoemResult<Body> {
-    let session_manager = sessions_extension.0;
-    let session = session_manager
-        .create_session(SessionType::ClickHouseHttpHandler)
-        .await
-        .map_err(InternalServerError)?;
-    session.set_current_user(user_info.0.clone());
+) -> PoemResult<Response> {
+    let (_session, ctx) =
+        create_query_context(sessions_extension.0, tenant, user_info.0, &params).await?;
 
-    let ctx = session
-        .create_query_context()
-        .await
-        .map_err(InternalServerError)?;
+    // The parts of a multipart request are the external data, the query is in the URL.
+    let is_multipart = req
+        .content_type()
+        .map(|t| t.starts_with("multipart/form-data"))
+        .unwrap_or(false);
+    if is_multipart {
+        let multipart = Multipart::from_request(req, &mut RequestBody::new(body)).await?;
+        register_external_tables(&ctx, &params.external, multipart)
+            .await
+            .map_err(BadRequest)?;
 
-    let mut sql = params.query;
+        let (sql, clause) = split_format_clause(&params.query);
+        let format = output_format(&params, clause).map_err(BadRequest)?;
+        let plan = PlanParser::parse(ctx.clone(), &sql)
+            .await
+            .map_err(BadRequest)?;
+        ctx.attach_query_str(&sql);
+        return execute(ctx, plan, None, format)
+            .await
+            .map_err(InternalServerError);
+    }
+
+    let sql = params.query.clone();
 
     // Insert into format sql
-    let (plan, input_stream) =
+    let (plan, input_stream, format) =
         if let Some((format, statements)) = try_parse_insert_formatted(&sql).map_err(BadRequest)? {
+            let result_format = output_format(&params, None).map_err(BadRequest)?;
             let plan = PlanParser::build_plan(statements, ctx.clone())
                 .await
                 .map_err(InternalServerError)?;
             ctx.attach_query_str(&sql);
 
+            let format_settings = ctx.get_format_settings().map_err(InternalServerError)?;
             let input_stream = match format {
-                Format::NDJson => build_ndjson_stream(&plan, body).await.map_err(BadRequest)?,
+                Format::NDJson => build_ndjson_stream(&plan, &format_settings, body)
+                    .await
+                    .map_err(BadRequest)?,
             };
-            (plan, Some(input_stream))
+            (plan, Some(input_stream), result_format)
         } else {
             // Other sql
             let body = body.into_string().await.map_err(BadRequest)?;
             let sql = format!("{}\n{}", sql, body);
+            let (sql, clause) = split_format_clause(&sql);
+            let result_format = output_format(&params, clause).map_err(BadRequest)?;
             let (statements, _) = DfParser::parse_sql(&sql).map_err(BadRequest)?;
 
             let plan = PlanParser::build_plan(statements, ctx.clone())
@@ -200,16 +353,20 @@ pub async fn clickhouse_handler_post(
                 .map_err(InternalServerError)?;
             ctx.attach_query_str(&sql);
 
-            (plan, None)
+            (plan, None, result_format)
         };
 
-    execute(ctx, plan, input_stream)
+    execute(ctx, plan, input_stream, format)
         .await
         .map_err(InternalServerError)
 }
 
-async fn build_ndjson_stream(plan: &PlanNode, body: Body) -> Result<SendableDataBlockStream> {
-    let builder = NDJsonSourceBuilder::create(plan.schema());
+async fn build_ndjson_stream(
+    plan: &PlanNode,
+    format_settings: &FormatSettings,
+    body: Body,
+) -> Result<SendableDataBlockStream> {
+    let builder = NDJsonSourceBuilder::create(plan.schema(), format_settings.clone());
     let cursor = std::io::Cursor::new(
         body.into_vec()
             .await
@@ -219,11 +376,17 @@ async fn build_ndjson_stream(plan: &PlanNode, body: Body) -> Result<SendableData
     SourceStream::new(Box::new(source)).execute().await
 }
 
+#[poem::handler]
+pub async fn clickhouse_ping_handler() -> String {
+    "Ok.\n".to_string()
+}
+
 pub fn clickhouse_router() -> impl Endpoint {
     Route::new()
         .at(
             "/",
             post(clickhouse_handler_post).get(clickhouse_handler_get),
         )
+        .at("/ping", get(clickhouse_ping_handler))
         .with(poem::middleware::Compression)
 }
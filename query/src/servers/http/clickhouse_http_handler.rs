@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_exception::Result;
+use poem::get;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Route;
+
+use crate::common::service::HttpShutdownHandler;
+use crate::servers::http::clickhouse_handler::clickhouse_handler_get;
+use crate::servers::http::clickhouse_handler::clickhouse_handler_post;
+use crate::servers::http::clickhouse_handler::clickhouse_ping_handler;
+use crate::servers::http::middleware::HTTPSessionMiddleware;
+use crate::servers::Server;
+use crate::sessions::SessionManager;
+
+/// The HTTP interface of ClickHouse on its own port, with the queries at the root path as
+/// `/?query=...`, so that the clients of ClickHouse work without changes.
+pub struct ClickHouseHttpHandler {
+    session_manager: Arc<SessionManager>,
+    shutdown_handler: HttpShutdownHandler,
+}
+
+impl ClickHouseHttpHandler {
+    pub fn create(session_manager: Arc<SessionManager>) -> Box<dyn Server> {
+        Box::new(ClickHouseHttpHandler {
+            session_manager,
+            shutdown_handler: HttpShutdownHandler::create("clickhouse http handler".to_string()),
+        })
+    }
+
+    fn build_router(&self) -> impl Endpoint {
+        Route::new()
+            .at(
+                "/",
+                post(clickhouse_handler_post)
+                    .get(clickhouse_handler_get)
+                    .with(HTTPSessionMiddleware {
+                        session_manager: self.session_manager.clone(),
+                    }),
+            )
+            // The health check of the load balancers, without authentication.
+            .at("/ping", get(clickhouse_ping_handler))
+            .with(poem::middleware::Compression)
+            .boxed()
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for ClickHouseHttpHandler {
+    async fn shutdown(&mut self, graceful: bool) {
+        self.shutdown_handler.shutdown(graceful).await;
+    }
+
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        self.shutdown_handler
+            .start_service(listening, None, self.build_router())
+            .await
+    }
+}
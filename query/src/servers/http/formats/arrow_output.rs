@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+// Serialize the blocks to the Arrow IPC streaming format: the schema message, a record
+// batch message per block, then the end-of-stream marker.
+pub struct ArrowStreamSerializer {
+    schema: ArrowSchema,
+    ipc_fields: Vec<IpcField>,
+    options: WriteOptions,
+}
+
+impl ArrowStreamSerializer {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        let schema = output_arrow_schema(&schema);
+        let ipc_fields = default_ipc_fields(&schema.fields);
+        ArrowStreamSerializer {
+            schema,
+            ipc_fields,
+            options: WriteOptions { compression: None },
+        }
+    }
+
+    pub fn serialize_schema(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        write_message(
+            &mut buf,
+            &serialize_schema(&self.schema, Some(&self.ipc_fields)),
+        );
+        buf
+    }
+
+    pub fn serialize_block(&self, block: &DataBlock) -> Result<Vec<u8>> {
+        if block.num_columns() != self.schema.fields.len() {
+            return Err(ErrorCode::UnexpectedError(format!(
+                "fail to serialize block to arrow stream, expects {} columns, but got {}",
+                self.schema.fields.len(),
+                block.num_columns()
+            )));
+        }
+
+        let chunk = output_arrow_chunk(block)?;
+        let (dicts, batch) = serialize_batch(&chunk, &self.ipc_fields, &self.options);
+
+        let mut buf = vec![];
+        for dict in dicts.iter() {
+            write_message(&mut buf, dict);
+        }
+        write_message(&mut buf, &batch);
+        Ok(buf)
+    }
+
+    pub fn finish(&self) -> Vec<u8> {
+        let mut buf = CONTINUATION_MARKER.to_vec();
+        buf.extend_from_slice(&0_i32.to_le_bytes());
+        buf
+    }
+}
+
+// An encapsulated message: the continuation marker, the length of the metadata, the
+// metadata padded to 8 bytes so that the body is aligned, then the body.
+fn write_message(buf: &mut Vec<u8>, data: &FlightData) {
+    let header_len = data.data_header.len();
+    let padded_len = (header_len + 7) / 8 * 8;
+    buf.extend_from_slice(&CONTINUATION_MARKER);
+    buf.extend_from_slice(&(padded_len as i32).to_le_bytes());
+    buf.extend_from_slice(&data.data_header);
+    buf.resize(buf.len() + padded_len - header_len, 0);
+    buf.extend_from_slice(&data.data_body);
+}
+
+/// The arrow schema of the results sent to the clients. The variants are sent as their JSON
+/// text, the clients don't know their extension types, the decimals are arrow decimals.
+pub fn output_arrow_schema(schema: &DataSchemaRef) -> ArrowSchema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(
+            |f| match remove_nullable(f.data_type()).data_type_id().is_variant() {
+                true => ArrowField::new(f.name(), ArrowType::LargeUtf8, f.is_nullable()),
+                false => f.to_arrow(),
+            },
+        )
+        .collect::<Vec<_>>();
+    ArrowSchema::from(fields).with_metadata(schema.meta().clone())
+}
+
+/// The arrow chunk of a block of the results, its arrays match `output_arrow_schema`.
+pub fn output_arrow_chunk(block: &DataBlock) -> Result<Chunk<ArrayRef>> {
+    let arrays = block
+        .columns()
+        .iter()
+        .zip(block.schema().fields().iter())
+        .map(|(c, f)| output_arrow_array(&c.convert_full_column(), f.data_type()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Chunk::try_new(arrays)?)
+}
+
+fn output_arrow_array(column: &ColumnRef, data_type: &DataTypePtr) -> Result<ArrayRef> {
+    let inner_type = remove_nullable(data_type);
+    if !inner_type.data_type_id().is_variant() {
+        return Ok(decimal_arrow_array(
+            column.as_arrow_array(),
+            &inner_type.arrow_type(),
+        ));
+    }
+
+    let (_, validity) = column.validity();
+    let validity = validity.cloned();
+    let values = inner_type
+        .create_serializer()
+        .serialize_column(&Series::remove_nullable(column))?;
+    let array = Utf8Array::<i64>::from_slice(values).with_validity(validity);
+    Ok(Arc::new(array))
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const FIELD_DELIMITER: u8 = b',';
+const ROW_DELIMITER: u8 = b'\n';
+const QUOTE: u8 = b'"';
+
+pub fn block_to_csv(block: &DataBlock) -> Result<Vec<u8>> {
+    let rows_size = block.num_rows();
+    let columns_size = block.num_columns();
+
+    let mut col_table = Vec::new();
+    let mut col_quoted = Vec::new();
+    for col_index in 0..columns_size {
+        let column = block.column(col_index);
+        let column = column.convert_full_column();
+        let field = block.schema().field(col_index);
+        let data_type = field.data_type();
+        let serializer = data_type.create_serializer();
+        col_table.push(serializer.serialize_column(&column).map_err(|e| {
+            ErrorCode::UnexpectedError(format!(
+                "fail to serialize filed {}, error = {}",
+                field.name(),
+                e
+            ))
+        })?);
+        // The numbers are written as is, the other values are quoted.
+        col_quoted.push(!remove_nullable(data_type).data_type_id().is_numeric());
+    }
+
+    let mut buf = vec![];
+    for row_index in 0..rows_size {
+        for col_index in 0..columns_size {
+            if col_index > 0 {
+                buf.push(FIELD_DELIMITER);
+            }
+            let value = &col_table[col_index][row_index];
+            match col_quoted[col_index] && !is_null(block, col_index, row_index) {
+                true => write_quoted(&mut buf, value),
+                false => buf.extend_from_slice(value.as_bytes()),
+            }
+        }
+        buf.push(ROW_DELIMITER);
+    }
+    Ok(buf)
+}
+
+// The header of CSVWithNames.
+pub fn csv_header(schema: &DataSchemaRef) -> Vec<u8> {
+    let mut buf = vec![];
+    for (index, field) in schema.fields().iter().enumerate() {
+        if index > 0 {
+            buf.push(FIELD_DELIMITER);
+        }
+        write_quoted(&mut buf, field.name());
+    }
+    buf.push(ROW_DELIMITER);
+    buf
+}
+
+fn is_null(block: &DataBlock, col_index: usize, row_index: usize) -> bool {
+    let column = block.column(col_index);
+    column.is_nullable() && column.null_at(row_index)
+}
+
+// The quotes inside the value are escaped by doubling them.
+fn write_quoted(buf: &mut Vec<u8>, value: &str) {
+    buf.push(QUOTE);
+    for c in value.bytes() {
+        if c == QUOTE {
+            buf.push(QUOTE);
+        }
+        buf.push(c);
+    }
+    buf.push(QUOTE);
+}
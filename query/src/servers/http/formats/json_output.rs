@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::servers::http::v1::JsonBlock;
+
+// The rows as JSON objects, with the fields in the order of the columns.
+pub fn block_to_json_rows(block: &DataBlock) -> Result<Vec<String>> {
+    let names = block
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| JsonValue::from(f.name().as_str()).to_string())
+        .collect::<Vec<_>>();
+
+    let json_block = JsonBlock::new(block)?;
+    Ok(json_block
+        .data()
+        .iter()
+        .map(|row| {
+            let fields = names
+                .iter()
+                .zip(row.iter())
+                .map(|(name, value)| format!("{}:{}", name, value))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(","))
+        })
+        .collect())
+}
+
+// Each row of JSONEachRow is an object on its own line.
+pub fn block_to_json_each_row(block: &DataBlock) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    for row in block_to_json_rows(block)? {
+        buf.extend_from_slice(row.as_bytes());
+        buf.push(b'\n');
+    }
+    Ok(buf)
+}
+
+// The start of the JSON format, the rows are written into the "data" array.
+pub fn json_prefix(schema: &DataSchemaRef) -> Vec<u8> {
+    let meta = schema
+        .fields()
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "name": f.name(),
+                "type": f.data_type().name(),
+            })
+        })
+        .collect::<Vec<_>>();
+    format!("{{\n\t\"meta\": {},\n\t\"data\": [", JsonValue::from(meta)).into_bytes()
+}
+
+pub fn json_suffix(rows: usize) -> Vec<u8> {
+    format!("\n\t],\n\t\"rows\": {}\n}}\n", rows).into_bytes()
+}
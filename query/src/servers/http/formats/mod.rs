@@ -12,8 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod arrow_output;
+pub mod csv_output;
+pub mod json_output;
+pub mod output_serializer;
 pub mod tsv_output;
 
 pub enum Format {
     NDJson,
 }
+
+// The formats of the query results.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    TSV,
+    TSVWithNames,
+    TSVWithNamesAndTypes,
+    CSV,
+    CSVWithNames,
+    JSONEachRow,
+    JSON,
+    ArrowStream,
+}
@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+
+use crate::servers::http::formats::arrow_output::ArrowStreamSerializer;
+use crate::servers::http::formats::arrow_output::ARROW_STREAM_CONTENT_TYPE;
+use crate::servers::http::formats::csv_output::block_to_csv;
+use crate::servers::http::formats::csv_output::csv_header;
+use crate::servers::http::formats::json_output::block_to_json_each_row;
+use crate::servers::http::formats::json_output::block_to_json_rows;
+use crate::servers::http::formats::json_output::json_prefix;
+use crate::servers::http::formats::json_output::json_suffix;
+use crate::servers::http::formats::tsv_output::block_to_tsv;
+use crate::servers::http::formats::tsv_output::tsv_header;
+use crate::servers::http::formats::OutputFormat;
+
+const TSV_CONTENT_TYPE: &str = "text/tab-separated-values; charset=UTF-8";
+const CSV_CONTENT_TYPE: &str = "text/csv; charset=UTF-8";
+const JSON_EACH_ROW_CONTENT_TYPE: &str = "application/x-ndjson; charset=UTF-8";
+const JSON_CONTENT_TYPE: &str = "application/json; charset=UTF-8";
+
+// Serialize the query results in an output format: the prefix, the blocks, then the suffix.
+pub struct OutputSerializer {
+    format: OutputFormat,
+    schema: DataSchemaRef,
+    arrow_serializer: Option<ArrowStreamSerializer>,
+    rows: usize,
+}
+
+impl OutputSerializer {
+    pub fn create(format: OutputFormat, schema: DataSchemaRef) -> Self {
+        let arrow_serializer = match format {
+            OutputFormat::ArrowStream => Some(ArrowStreamSerializer::create(schema.clone())),
+            _ => None,
+        };
+        OutputSerializer {
+            format,
+            schema,
+            arrow_serializer,
+            rows: 0,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self.format {
+            OutputFormat::TSV | OutputFormat::TSVWithNames | OutputFormat::TSVWithNamesAndTypes => {
+                TSV_CONTENT_TYPE
+            }
+            OutputFormat::CSV | OutputFormat::CSVWithNames => CSV_CONTENT_TYPE,
+            OutputFormat::JSONEachRow => JSON_EACH_ROW_CONTENT_TYPE,
+            OutputFormat::JSON => JSON_CONTENT_TYPE,
+            OutputFormat::ArrowStream => ARROW_STREAM_CONTENT_TYPE,
+        }
+    }
+
+    pub fn serialize_prefix(&self) -> Vec<u8> {
+        match self.format {
+            OutputFormat::TSVWithNames => tsv_header(&self.schema, false),
+            OutputFormat::TSVWithNamesAndTypes => tsv_header(&self.schema, true),
+            OutputFormat::CSVWithNames => csv_header(&self.schema),
+            OutputFormat::JSON => json_prefix(&self.schema),
+            OutputFormat::ArrowStream => match &self.arrow_serializer {
+                Some(serializer) => serializer.serialize_schema(),
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    pub fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        if block.num_rows() == 0 {
+            return Ok(vec![]);
+        }
+
+        match self.format {
+            OutputFormat::TSV | OutputFormat::TSVWithNames | OutputFormat::TSVWithNamesAndTypes => {
+                block_to_tsv(block)
+            }
+            OutputFormat::CSV | OutputFormat::CSVWithNames => block_to_csv(block),
+            OutputFormat::JSONEachRow => block_to_json_each_row(block),
+            OutputFormat::JSON => {
+                let mut buf = vec![];
+                for row in block_to_json_rows(block)? {
+                    // The rows are separated by commas inside the "data" array.
+                    let separator = match self.rows {
+                        0 => "\n\t\t",
+                        _ => ",\n\t\t",
+                    };
+                    buf.extend_from_slice(separator.as_bytes());
+                    buf.extend_from_slice(row.as_bytes());
+                    self.rows += 1;
+                }
+                Ok(buf)
+            }
+            OutputFormat::ArrowStream => match &self.arrow_serializer {
+                Some(serializer) => serializer.serialize_block(block),
+                None => Ok(vec![]),
+            },
+        }
+    }
+
+    pub fn serialize_suffix(&self) -> Vec<u8> {
+        match self.format {
+            OutputFormat::JSON => json_suffix(self.rows),
+            OutputFormat::ArrowStream => match &self.arrow_serializer {
+                Some(serializer) => serializer.finish(),
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+}
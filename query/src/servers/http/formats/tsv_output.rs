@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -50,3 +51,28 @@ pub fn block_to_tsv(block: &DataBlock) -> Result<Vec<u8>> {
     }
     Ok(buf)
 }
+
+// The header of TSVWithNames, and of TSVWithNamesAndTypes with the types row.
+pub fn tsv_header(schema: &DataSchemaRef, with_types: bool) -> Vec<u8> {
+    let mut rows = vec![schema
+        .fields()
+        .iter()
+        .map(|f| f.name().to_string())
+        .collect::<Vec<_>>()];
+    if with_types {
+        rows.push(
+            schema
+                .fields()
+                .iter()
+                .map(|f| f.data_type().name().to_string())
+                .collect(),
+        );
+    }
+
+    let mut buf = vec![];
+    for row in rows {
+        buf.extend_from_slice(row.join("\t").as_bytes());
+        buf.push(ROW_DELIMITER);
+    }
+    buf
+}
@@ -0,0 +1,140 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::Compression;
+use common_streams::compress;
+use common_streams::decompress;
+use poem::error::Error as PoemError;
+use poem::error::Result as PoemResult;
+use poem::http::header;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+
+// The content codings of the responses, the first is preferred if the client accepts several
+// with the same quality.
+const RESPONSE_CODINGS: [&str; 4] = ["zstd", "br", "gzip", "deflate"];
+
+fn coding_compression(coding: &str) -> Option<Compression> {
+    match coding.trim().to_ascii_lowercase().as_str() {
+        "zstd" => Some(Compression::Zstd),
+        "br" => Some(Compression::Brotli),
+        "gzip" | "x-gzip" => Some(Compression::Gzip),
+        "deflate" => Some(Compression::Deflate),
+        "identity" => Some(Compression::None),
+        _ => None,
+    }
+}
+
+/// Choose the compression of the response by the `Accept-Encoding` of the request,
+/// e.g. `gzip;q=0.8, zstd`.
+pub fn negotiate_compression(accept_encoding: &str) -> Option<(&'static str, Compression)> {
+    let mut best: Option<(f32, usize)> = None;
+    for item in accept_encoding.split(',') {
+        let mut parts = item.split(';');
+        let coding = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let quality = parts
+            .find_map(|p| p.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        let rank = match RESPONSE_CODINGS.iter().position(|c| *c == coding) {
+            Some(rank) if quality > 0.0 => rank,
+            _ => continue,
+        };
+        best = match best {
+            Some((q, r)) if q > quality || (q == quality && r < rank) => Some((q, r)),
+            _ => Some((quality, rank)),
+        };
+    }
+
+    best.and_then(|(_, rank)| {
+        let coding = RESPONSE_CODINGS[rank];
+        coding_compression(coding).map(|compression| (coding, compression))
+    })
+}
+
+/// The response of the body, compressed if it is enabled and the client accepts it.
+pub fn compressed_response(
+    req: &Request,
+    enabled: bool,
+    content_type: &str,
+    body: Vec<u8>,
+) -> Result<Response> {
+    let negotiated = match enabled {
+        true => req
+            .headers()
+            .get(header::ACCEPT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .and_then(negotiate_compression),
+        false => None,
+    };
+
+    let builder = Response::builder().content_type(content_type);
+    Ok(match negotiated {
+        None => builder.body(body),
+        Some((coding, compression)) => builder
+            .header(header::CONTENT_ENCODING, coding)
+            .header(header::VARY, "Accept-Encoding")
+            .body(compress(&compression, &body)?),
+    })
+}
+
+/// Decompress the body of the request by its `Content-Encoding`: gzip, deflate, br or zstd.
+pub struct HTTPDecompressionMiddleware;
+
+impl<E: Endpoint> Middleware<E> for HTTPDecompressionMiddleware {
+    type Output = HTTPDecompressionEndpoint<E>;
+    fn transform(&self, ep: E) -> Self::Output {
+        HTTPDecompressionEndpoint { ep }
+    }
+}
+
+pub struct HTTPDecompressionEndpoint<E> {
+    ep: E,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint> Endpoint for HTTPDecompressionEndpoint<E> {
+    type Output = E::Output;
+
+    async fn call(&self, mut req: Request) -> PoemResult<Self::Output> {
+        let coding = req
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+
+        if let Some(coding) = coding {
+            let compression = coding_compression(&coding).ok_or_else(|| {
+                PoemError::from_string(
+                    format!("Unsupported Content-Encoding: {}", coding),
+                    StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                )
+            })?;
+
+            let body = req.take_body().into_vec().await?;
+            let body = decompress(&compression, body)
+                .map_err(|e| PoemError::from_string(e.message(), StatusCode::BAD_REQUEST))?;
+            req.headers_mut().remove(header::CONTENT_ENCODING);
+            req.headers_mut().remove(header::CONTENT_LENGTH);
+            req.set_body(body);
+        }
+
+        self.ep.call(req).await
+    }
+}
@@ -25,9 +25,11 @@ use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
 
+use super::v1::download_from_stage;
 use super::v1::upload_to_stage;
 use crate::common::service::HttpShutdownHandler;
 use crate::configs::Config;
+use crate::servers::http::http_compression::HTTPDecompressionMiddleware;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::query_route;
@@ -68,8 +70,17 @@ echo '{}' | curl '{:?}/clickhouse/?query=INSERT%20INTO%20test%20FORMAT%20JSONEac
             .nest("/clickhouse", clickhouse_router())
             .nest("/v1/statement", statement_router())
             .nest("/v1/query", query_route())
-            .at("/v1/streaming_load", put(streaming_load))
-            .at("/v1/upload_to_stage", put(upload_to_stage))
+            .at(
+                "/v1/streaming_load",
+                put(streaming_load)
+                    .post(streaming_load)
+                    .with(HTTPDecompressionMiddleware),
+            )
+            .at(
+                "/v1/upload_to_stage",
+                put(upload_to_stage).post(upload_to_stage),
+            )
+            .at("/v1/download_from_stage", get(download_from_stage))
             .with(HTTPSessionMiddleware {
                 session_manager: self.session_manager.clone(),
             })
@@ -29,15 +29,75 @@ use poem::http::StatusCode;
 use poem::Endpoint;
 use poem::Middleware;
 use poem::Request;
+use serde::Deserialize;
 
 use crate::sessions::SessionManager;
 use crate::users::auth::auth_mgr::Credential;
 
+// The header to choose the tenant of the request, the tenant of the config by default.
+pub const HEADER_TENANT: &str = "X-DATABEND-TENANT";
+
 pub struct HTTPSessionMiddleware {
     pub session_manager: Arc<SessionManager>,
 }
 
-fn get_credential(headers: &HeaderMap) -> Result<Option<Credential>> {
+/// The tenant the user of the request is authenticated in, the sessions of the request must be
+/// bound to it.
+#[derive(Clone, Debug)]
+pub struct HttpTenant(pub String);
+
+fn get_tenant(req: &Request, default_tenant: String) -> String {
+    req.headers()
+        .get(HEADER_TENANT)
+        .and_then(|value| value.to_str().ok())
+        .filter(|tenant| !tenant.is_empty())
+        .map(str::to_string)
+        .unwrap_or(default_tenant)
+}
+
+// The credentials of the ClickHouse HTTP interface, in the URL parameters.
+#[derive(Deserialize)]
+struct ClickHouseCredentialParams {
+    user: Option<String>,
+    password: Option<String>,
+}
+
+fn get_credential(req: &Request) -> Result<Option<Credential>> {
+    match get_auth_credential(req.headers())? {
+        Some(credential) => Ok(Some(credential)),
+        None => Ok(get_clickhouse_credential(req)),
+    }
+}
+
+// The clients of ClickHouse send the user and password with the headers `X-ClickHouse-User` and
+// `X-ClickHouse-Key`, or with the URL parameters `user` and `password`.
+fn get_clickhouse_credential(req: &Request) -> Option<Credential> {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string())
+    };
+    let (name, password) = match header("X-ClickHouse-User") {
+        Some(name) => (name, header("X-ClickHouse-Key")),
+        None => match req.params::<ClickHouseCredentialParams>() {
+            Ok(ClickHouseCredentialParams {
+                user: Some(name),
+                password,
+            }) => (name, password),
+            _ => return None,
+        },
+    };
+
+    let password = password.filter(|p| !p.is_empty()).map(|p| p.into_bytes());
+    Some(Credential::Password {
+        name,
+        password,
+        hostname: None,
+    })
+}
+
+fn get_auth_credential(headers: &HeaderMap) -> Result<Option<Credential>> {
     let auth_headers: Vec<_> = headers.get_all(AUTHORIZATION).iter().collect();
     if auth_headers.len() > 1 {
         let msg = &format!("Multiple {} headers detected", AUTHORIZATION);
@@ -90,12 +150,23 @@ pub struct HTTPSessionEndpoint<E> {
 }
 
 impl<E> HTTPSessionEndpoint<E> {
-    async fn auth(&self, req: &Request) -> Result<UserInfo> {
-        let credential = get_credential(req.headers())?;
-        match credential {
-            Some(c) => self.manager.get_auth_manager().auth(&c).await,
-            None => self.manager.get_auth_manager().no_auth().await,
-        }
+    async fn auth(&self, req: &Request) -> Result<(HttpTenant, UserInfo)> {
+        let tenant = get_tenant(req, self.manager.get_conf().query.tenant_id);
+        let credential = get_credential(req)?;
+        let auth_manager = self.manager.get_auth_manager();
+        let user_info = match credential {
+            Some(c) => auth_manager.auth(&tenant, &c).await?,
+            None => auth_manager.no_auth(&tenant).await?,
+        };
+
+        let client_addr = req
+            .remote_addr()
+            .as_socket_addr()
+            .map(|addr| addr.to_string());
+        auth_manager
+            .check_network_policy(&tenant, &user_info, client_addr.as_deref())
+            .await?;
+        Ok((HttpTenant(tenant), user_info))
     }
 }
 
@@ -106,8 +177,9 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
     async fn call(&self, mut req: Request) -> PoemResult<Self::Output> {
         tracing::debug!("receive http request: {:?},", req);
         let res = match self.auth(&req).await {
-            Ok(user_info) => {
+            Ok((tenant, user_info)) => {
                 req.extensions_mut().insert(self.manager.clone());
+                req.extensions_mut().insert(tenant);
                 req.extensions_mut().insert(user_info);
                 self.ep.call(req).await
             }
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clickhouse_external_data;
 mod clickhouse_handler;
+mod clickhouse_http_handler;
 pub mod formats;
+pub mod http_compression;
 mod http_services;
 pub mod middleware;
 pub mod v1;
 
+pub use clickhouse_http_handler::ClickHouseHttpHandler;
 pub use http_services::HttpHandler;
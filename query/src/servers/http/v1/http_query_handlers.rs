@@ -19,7 +19,9 @@ use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_meta_types::UserInfo;
 use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
 use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::get;
 use poem::http::StatusCode;
@@ -29,17 +31,37 @@ use poem::web::Json;
 use poem::web::Path;
 use poem::web::Query;
 use poem::IntoResponse;
+use poem::Request;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 
 use super::query::ExecuteStateKind;
+use super::query::ExternalResult;
+use super::query::HttpQuery;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use super::query_websocket::query_websocket_handler;
+use crate::servers::http::http_compression::compressed_response;
+use crate::servers::http::middleware::HttpTenant;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::SessionManager;
 
+const JSON_CONTENT_TYPE: &str = "application/json; charset=utf-8";
+
+/// The header to tag the queries of the request, it overrides the `query_tag` setting.
+pub const HEADER_QUERY_TAG: &str = "X-DATABEND-QUERY-TAG";
+
+pub(crate) fn query_tag_of(request: &Request) -> Option<String> {
+    request
+        .headers()
+        .get(HEADER_QUERY_TAG)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
 }
@@ -61,7 +83,7 @@ pub struct QueryError {
 }
 
 impl QueryError {
-    fn from_error_code(e: &ErrorCode) -> Self {
+    pub(crate) fn from_error_code(e: &ErrorCode) -> Self {
         QueryError {
             code: e.code(),
             message: e.message(),
@@ -90,6 +112,8 @@ pub struct QueryResponse {
     // just call it after client not use it anymore, not care about the server-side behavior
     pub final_uri: Option<String>,
     pub next_uri: Option<String>,
+    // The presigned URLs of the result files if the result is spooled to the storage.
+    pub external_result: Option<ExternalResult>,
 }
 
 impl QueryResponse {
@@ -99,7 +123,15 @@ impl QueryResponse {
             Some(d) => (d.page.data, d.next_page_no.map(|n| make_page_uri(&id, n))),
             None => (JsonBlock::empty(), None),
         };
-        let schema = data.schema().clone();
+        let schema = match r
+            .state
+            .external_result
+            .as_ref()
+            .and_then(|v| v.schema.clone())
+        {
+            Some(schema) => schema,
+            None => data.schema().clone(),
+        };
         let session_id = r.initial_state.as_ref().map(|v| v.session_id.clone());
         let stats = QueryStats {
             scan_progress: state.scan_progress.clone(),
@@ -116,6 +148,7 @@ impl QueryResponse {
             stats_uri: Some(make_state_uri(&id)),
             final_uri: Some(make_final_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
+            external_result: r.state.external_result,
         }
     }
 
@@ -131,6 +164,7 @@ impl QueryResponse {
             stats_uri: None,
             final_uri: None,
             error: Some(QueryError::from_error_code(err)),
+            external_result: None,
         }
     }
 }
@@ -162,15 +196,22 @@ async fn query_cancel_handler(
 
 #[poem::handler]
 async fn query_state_handler(
+    req: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Path(query_id): Path<String>,
-) -> PoemResult<Json<QueryResponse>> {
+) -> PoemResult<Response> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     match http_query_manager.get_query(&query_id).await {
         Some(query) => {
             let response = query.get_response_state_only().await;
-            Ok(Json(QueryResponse::from_internal(query_id, response)))
+            // Polling the state keeps the query alive as well as fetching the pages.
+            query.update_expire_time().await;
+            query_response(
+                req,
+                &query,
+                QueryResponse::from_internal(query_id, response),
+            )
         }
         None => Err(query_id_not_found(query_id)),
     }
@@ -178,9 +219,10 @@ async fn query_state_handler(
 
 #[poem::handler]
 async fn query_page_handler(
+    req: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
     Path((query_id, page_no)): Path<(String, usize)>,
-) -> PoemResult<Json<QueryResponse>> {
+) -> PoemResult<Response> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     match http_query_manager.get_query(&query_id).await {
@@ -191,7 +233,7 @@ async fn query_page_handler(
                 .await
                 .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
             query.update_expire_time().await;
-            Ok(Json(QueryResponse::from_internal(query_id, resp)))
+            query_response(req, &query, QueryResponse::from_internal(query_id, resp))
         }
         None => Err(query_id_not_found(query_id)),
     }
@@ -199,16 +241,20 @@ async fn query_page_handler(
 
 #[poem::handler]
 pub(crate) async fn query_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
     user_info: Data<&UserInfo>,
-    Json(req): Json<HttpQueryRequest>,
-) -> PoemResult<Json<QueryResponse>> {
+    Json(mut req): Json<HttpQueryRequest>,
+) -> PoemResult<Response> {
     tracing::info!("receive http query: {:?}", req);
+    req.query_tag = query_tag_of(request);
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
     let query = http_query_manager
-        .try_create_query(&query_id, req, session_manager, &user_info)
+        .try_create_query(&query_id, req, session_manager, tenant, &user_info)
+        .instrument(query_span(request, &query_id))
         .await;
 
     match query {
@@ -218,19 +264,75 @@ pub(crate) async fn query_handler(
                 .await
                 .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
             query.update_expire_time().await;
-            Ok(Json(QueryResponse::from_internal(
-                query.id.to_string(),
-                resp,
-            )))
+            let response = QueryResponse::from_internal(query.id.to_string(), resp);
+            query_response(request, &query, response)
         }
-        Err(e) => Ok(Json(QueryResponse::fail_to_start_sql(query_id, &e))),
+        Err(e) => Ok(Json(QueryResponse::fail_to_start_sql(query_id, &e)).into_response()),
     }
 }
 
+/// Submit the query without waiting for any result, the response has the id of the query.
+///
+/// Poll the `stats_uri` until the query is not `Running`, then fetch the results from the
+/// `next_uri`. A submitted query never expires while running, and its results are kept for
+/// `http_handler_result_timeout_millis` after it finished or was polled.
+#[poem::handler]
+pub(crate) async fn query_submit_handler(
+    request: &Request,
+    sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
+    user_info: Data<&UserInfo>,
+    Json(mut req): Json<HttpQueryRequest>,
+) -> PoemResult<Response> {
+    tracing::info!("receive http query submission: {:?}", req);
+    req.pagination.wait_time_secs = 0;
+    req.submitted = true;
+    req.query_tag = query_tag_of(request);
+    let session_manager = sessions_extension.0;
+    let http_query_manager = session_manager.get_http_query_manager();
+    let query_id = http_query_manager.next_query_id();
+    let query = http_query_manager
+        .try_create_query(&query_id, req, session_manager, tenant, &user_info)
+        .instrument(query_span(request, &query_id))
+        .await;
+
+    match query {
+        Ok(query) => {
+            let mut resp = query.get_response_state_only().await;
+            resp.initial_state = Some(query.get_initial_state().await);
+            let mut response = QueryResponse::from_internal(query.id.to_string(), resp);
+            response.next_uri = Some(make_page_uri(&query.id, 0));
+            query_response(request, &query, response)
+        }
+        Err(e) => Ok(Json(QueryResponse::fail_to_start_sql(query_id, &e)).into_response()),
+    }
+}
+
+// The span of the execution of the query, it is a child of the span of the client if the
+// request has the W3C trace context headers.
+fn query_span(req: &Request, query_id: &str) -> tracing::Span {
+    let span = tracing::info_span!("http_query", query_id = query_id);
+    common_tracing::extract_remote_span_from_headers(&span, req.headers());
+    span
+}
+
+// The JSON of the response, compressed if the session of the query enables it.
+fn query_response(
+    req: &Request,
+    query: &HttpQuery,
+    response: QueryResponse,
+) -> PoemResult<Response> {
+    let body = serde_json::to_vec(&response).map_err(InternalServerError)?;
+    compressed_response(req, query.enable_compression, JSON_CONTENT_TYPE, body)
+        .map_err(InternalServerError)
+}
+
 pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     Route::new()
         .at("/", post(query_handler))
+        .at("/submit", post(query_submit_handler))
+        .at("/ws", get(query_websocket_handler))
         .at("/:id", get(query_state_handler))
         .at("/:id/page/:page_no", get(query_page_handler))
         .at(
@@ -12,21 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use async_compat::CompatExt;
 use async_stream::stream;
 use common_base::ProgressValues;
 use common_exception::ErrorCode;
+use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_io::prelude::parse_escape_string;
 use common_io::prelude::FormatSettings;
+use common_meta_types::OnErrorMode;
 use common_meta_types::UserInfo;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
+use common_streams::decompress_file;
+use common_streams::decompress_reader;
+use common_streams::AvroSourceBuilder;
 use common_streams::CsvSourceBuilder;
 use common_streams::NDJsonSourceBuilder;
+use common_streams::OrcSourceBuilder;
 use common_streams::ParquetSourceBuilder;
+use common_streams::RejectedRecords;
 use common_streams::SendableDataBlockStream;
 use common_streams::Source;
 use common_tracing::tracing;
@@ -42,7 +50,10 @@ use poem::Request;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::interpreters::write_rejected_records;
 use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::servers::http::middleware::HttpTenant;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 use crate::sql::PlanParser;
@@ -50,15 +61,22 @@ use crate::sql::PlanParser;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LoadResponse {
     pub id: String,
+    /// SUCCESS or FAILURE, the rows of a failed load are not loaded.
     pub state: String,
     pub stats: ProgressValues,
     pub error: Option<String>,
+    /// Number of the rows appended to the table.
+    pub rows_loaded: u64,
+    /// Number of the malformed records rejected, see `system.load_errors`.
+    pub errors_seen: u64,
+    pub first_error: Option<String>,
 }
 
 #[poem::handler]
 pub async fn streaming_load(
     req: &Request,
     mut multipart: Multipart,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
     user_info: Data<&UserInfo>,
     sessions_extension: Data<&Arc<SessionManager>>,
 ) -> PoemResult<Json<LoadResponse>> {
@@ -68,6 +86,10 @@ pub async fn streaming_load(
         .await
         .map_err(InternalServerError)?;
 
+    session
+        .set_current_tenant(tenant.clone())
+        .await
+        .map_err(InternalServerError)?;
     // TODO: list user's grant list and check client address
     session.set_current_user(user_info.0.clone());
 
@@ -105,20 +127,48 @@ pub async fn streaming_load(
         .get_max_block_size()
         .map_err(InternalServerError)? as usize;
 
+    // The chunks of the CSV files are parsed by the threads in parallel.
+    let max_threads = context
+        .get_settings()
+        .get_max_threads()
+        .map_err(InternalServerError)? as usize;
+
     let format_settings = context.get_format_settings().map_err(InternalServerError)?;
 
+    // On error, the malformed CSV and NDJSON records are rejected up to the limit of the mode.
+    let on_error = context
+        .get_settings()
+        .get_on_error()
+        .map_err(InternalServerError)?;
+    let on_error = OnErrorMode::from_str(&String::from_utf8_lossy(&on_error))
+        .map_err(|e| poem::Error::from_string(e, StatusCode::BAD_REQUEST))?;
+    let max_errors = on_error.max_record_errors();
+    let rejected = context.get_rejected_records();
+
     // validate plan
     let source_stream = match &plan {
         PlanNode::Insert(insert) => match &insert.source {
             InsertInputSource::StreamingWithFormat(format) => {
                 if format.to_lowercase().as_str() == "csv" {
-                    build_csv_stream(&plan, &format_settings, multipart, max_block_size)
+                    build_csv_stream(
+                        &plan,
+                        &format_settings,
+                        multipart,
+                        max_block_size,
+                        max_threads,
+                        max_errors,
+                        rejected,
+                    )
                 } else if format.to_lowercase().as_str() == "parquet" {
-                    build_parquet_stream(&plan, multipart)
+                    build_parquet_stream(&plan, &format_settings, multipart)
                 } else if format.to_lowercase().as_str() == "ndjson"
                     || format.to_lowercase().as_str() == "jsoneachrow"
                 {
-                    build_ndjson_stream(&plan, multipart)
+                    build_ndjson_stream(&plan, &format_settings, multipart, max_errors, rejected)
+                } else if format.to_lowercase().as_str() == "orc" {
+                    build_orc_stream(&plan, &format_settings, multipart, max_block_size)
+                } else if format.to_lowercase().as_str() == "avro" {
+                    build_avro_stream(&plan, &format_settings, multipart, max_block_size)
                 } else {
                     Err(poem::Error::from_string(
                         format!(
@@ -153,11 +203,13 @@ pub async fn streaming_load(
         .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
 
     // this runs inside the runtime of poem, load is not cpu densive so it's ok
-    let mut data_stream = interpreter
-        .execute(Some(source_stream))
+    let result = execute_load(&interpreter, source_stream).await;
+
+    // The rejected records are kept in system.load_errors, even if the load failed.
+    let rejected = context.get_rejected_records().take();
+    write_rejected_records(&context, &rejected)
         .await
         .map_err(InternalServerError)?;
-    while let Some(_block) = data_stream.next().await {}
 
     // Write Finish to query log table.
     let _ = interpreter
@@ -165,25 +217,52 @@ pub async fn streaming_load(
         .await
         .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
 
+    let (state, error, rows_loaded) = match result {
+        Ok(()) => (
+            "SUCCESS",
+            None,
+            context.get_write_progress_value().rows as u64,
+        ),
+        Err(cause) => ("FAILURE", Some(cause.message()), 0),
+    };
+
     // TODO generate id
     // TODO duplicate by insert_label
-    let mut id = uuid::Uuid::new_v4().to_string();
+    let id = uuid::Uuid::new_v4().to_string();
     Ok(Json(LoadResponse {
         id,
-        state: "SUCCESS".to_string(),
+        state: state.to_string(),
         stats: context.get_scan_progress_value(),
-        error: None,
+        error,
+        rows_loaded,
+        errors_seen: rejected.len() as u64,
+        first_error: rejected.first().map(|record| record.error.clone()),
     }))
 }
 
+async fn execute_load(
+    interpreter: &InterpreterPtr,
+    source_stream: SendableDataBlockStream,
+) -> Result<()> {
+    let mut data_stream = interpreter.execute(Some(source_stream)).await?;
+    while let Some(block) = data_stream.next().await {
+        block?;
+    }
+    Ok(())
+}
+
 fn build_parquet_stream(
     plan: &PlanNode,
+    format_settings: &FormatSettings,
     mut multipart: Multipart,
 ) -> PoemResult<SendableDataBlockStream> {
     let builder = ParquetSourceBuilder::create(plan.schema());
+    let compression = format_settings.compression.clone();
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
             let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+            let bytes = decompress_file(&compression, &file_name, bytes)?;
             let cursor = Cursor::new(bytes);
 
             let mut source = builder.build(cursor)?;
@@ -204,14 +283,83 @@ fn build_parquet_stream(
 
 fn build_ndjson_stream(
     plan: &PlanNode,
+    format_settings: &FormatSettings,
     mut multipart: Multipart,
+    max_errors: u64,
+    rejected: RejectedRecords,
 ) -> PoemResult<SendableDataBlockStream> {
-    let builder = NDJsonSourceBuilder::create(plan.schema());
+    let mut builder = NDJsonSourceBuilder::create(plan.schema(), format_settings.clone());
+    builder.max_errors(max_errors);
+    let compression = format_settings.compression.clone();
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
             let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+            let bytes = decompress_file(&compression, &file_name, bytes)?;
             let cursor = std::io::Cursor::new(bytes);
-            let mut source = builder.build(cursor)?;
+            let mut source = builder.clone().rejected_records(&file_name, rejected.clone()).build(cursor)?;
+
+            loop {
+                let block = source.read().await;
+                match block {
+                    Ok(None) => break,
+                    Ok(Some(b)) =>  yield(Ok(b)),
+                    Err(e) => yield(Err(e)),
+                }
+            }
+        }
+    };
+
+    Ok(Box::pin(stream))
+}
+
+fn build_orc_stream(
+    plan: &PlanNode,
+    format_settings: &FormatSettings,
+    mut multipart: Multipart,
+    block_size: usize,
+) -> PoemResult<SendableDataBlockStream> {
+    let mut builder = OrcSourceBuilder::create(plan.schema());
+    builder.block_size(block_size);
+
+    let compression = format_settings.compression.clone();
+    let stream = stream! {
+        while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
+            let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+            let bytes = decompress_file(&compression, &file_name, bytes)?;
+            let mut source = builder.build(bytes)?;
+
+            loop {
+                let block = source.read().await;
+                match block {
+                    Ok(None) => break,
+                    Ok(Some(b)) =>  yield(Ok(b)),
+                    Err(e) => yield(Err(e)),
+                }
+            }
+        }
+    };
+
+    Ok(Box::pin(stream))
+}
+
+fn build_avro_stream(
+    plan: &PlanNode,
+    format_settings: &FormatSettings,
+    mut multipart: Multipart,
+    block_size: usize,
+) -> PoemResult<SendableDataBlockStream> {
+    let mut builder = AvroSourceBuilder::create(plan.schema());
+    builder.block_size(block_size);
+
+    let compression = format_settings.compression.clone();
+    let stream = stream! {
+        while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
+            let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+            let bytes = decompress_file(&compression, &file_name, bytes)?;
+            let mut source = builder.build(bytes)?;
 
             loop {
                 let block = source.read().await;
@@ -232,14 +380,22 @@ fn build_csv_stream(
     format_settings: &FormatSettings,
     mut multipart: Multipart,
     block_size: usize,
+    parallelism: usize,
+    max_errors: u64,
+    rejected: RejectedRecords,
 ) -> PoemResult<SendableDataBlockStream> {
     let mut builder = CsvSourceBuilder::create(plan.schema(), format_settings.clone());
     builder.block_size(block_size);
+    builder.parallelism(parallelism);
+    builder.max_errors(max_errors);
 
+    let compression = format_settings.compression.clone();
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
             let reader = field.into_async_read();
-            let mut source = builder.build(reader.compat())?;
+            let reader = decompress_reader(&compression, &file_name, reader.compat()).await?;
+            let mut source = builder.clone().rejected_records(&file_name, rejected.clone()).build_parallel(reader)?;
 
             loop {
                 let block = source.read().await;
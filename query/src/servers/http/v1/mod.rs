@@ -16,6 +16,7 @@ mod http_query_handlers;
 pub mod json_block;
 mod load;
 mod query;
+mod query_websocket;
 mod stage;
 mod statement;
 
@@ -23,8 +24,10 @@ pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
+pub use http_query_handlers::HEADER_QUERY_TAG;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
@@ -33,9 +36,16 @@ pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
 pub use query::HttpSession;
 pub use query::HttpSessionConf;
+pub use query_websocket::query_websocket_handler;
+pub use query_websocket::QueryEvent;
+pub use stage::download_from_stage;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
+pub use statement::multi_statement_handler;
+pub use statement::split_statements;
 pub use statement::statement_handler;
 pub use statement::statement_router;
+pub use statement::MultiStatementResponse;
+pub use statement::StatementResult;
 
 pub use crate::servers::http::clickhouse_handler::clickhouse_router;
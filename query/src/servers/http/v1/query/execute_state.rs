@@ -25,11 +25,14 @@ use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
 use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use ExecuteState::*;
 
+use super::external_result::ExternalResult;
+use super::external_result::ExternalResultWriter;
 use super::http_query::HttpQueryRequest;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
@@ -78,6 +81,8 @@ pub(crate) struct ExecuteStopped {
 pub(crate) struct Executor {
     start_time: Instant,
     pub(crate) state: ExecuteState,
+    // The result files of the query if the result is spooled to the storage.
+    pub(crate) external_result: Option<ExternalResult>,
 }
 
 impl Executor {
@@ -144,6 +149,10 @@ impl ExecuteState {
         let plan = PlanParser::parse(ctx.clone(), sql).await?;
 
         let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let external_writer = match &request.external_result {
+            Some(conf) => Some(ExternalResultWriter::try_create(&ctx, conf).await?),
+            None => None,
+        };
         // Write Start to query log table.
         let _ = interpreter
             .start()
@@ -163,23 +172,38 @@ impl ExecuteState {
         let executor = Arc::new(RwLock::new(Executor {
             start_time,
             state: Running(running_state),
+            external_result: None,
         }));
 
         let executor_clone = executor.clone();
         let ctx_clone = ctx.clone();
-        ctx.try_spawn(async move {
+        // Executed in the span of the query, it's the one of the http request.
+        let execution = async move {
             // drop/close block_tx after calling Executor::stop
             // so handler task can get newest state before return
             // otherwise the handler task and this task may competing for the executor lock
             let block_tx_clone = block_tx.clone();
-            match execute(interpreter, ctx_clone, block_tx_clone, &mut abort_rx).await {
-                Ok(_) => Executor::stop(&executor_clone, Ok(()), false).await,
+            match execute(
+                interpreter,
+                ctx_clone,
+                block_tx_clone,
+                &mut abort_rx,
+                external_writer,
+            )
+            .await
+            {
+                Ok(external_result) => {
+                    executor_clone.write().await.external_result = external_result;
+                    Executor::stop(&executor_clone, Ok(()), false).await
+                }
                 Err(err) => {
-                    let kill = err.message().starts_with("aborted");
+                    let kill = err.message().starts_with("aborted")
+                        || err.code() == ErrorCode::StatementTimeout("").code();
                     Executor::stop(&executor_clone, Err(err), kill).await
                 }
             };
-        })?;
+        };
+        ctx.try_spawn(execution.in_current_span())?;
 
         Ok(executor)
     }
@@ -190,19 +214,40 @@ async fn execute(
     ctx: Arc<QueryContext>,
     block_tx: mpsc::Sender<DataBlock>,
     abort_rx: &mut mpsc::Receiver<()>,
-) -> Result<()> {
+    external_writer: Option<ExternalResultWriter>,
+) -> Result<Option<ExternalResult>> {
     let data_stream = interpreter.execute(None).await?;
     let mut data_stream = ctx.try_create_abortable(data_stream)?;
+
+    // The blocks are written into the result files instead of the pages.
+    if let Some(mut writer) = external_writer {
+        loop {
+            tokio::select! {
+                block_r = data_stream.next() => match block_r {
+                    Some(Ok(block)) => writer.append(block).await?,
+                    Some(Err(err)) => return Err(err),
+                    None => break,
+                },
+                _ = abort_rx.recv() => return Err(aborted_error(&ctx)),
+            }
+        }
+        return Ok(Some(writer.finish(interpreter.schema()).await?));
+    }
+
     while let Some(block_r) = data_stream.next().await {
         match block_r {
             Ok(block) => tokio::select! {
                 _ = block_tx.send(block) => { },
-                _ = abort_rx.recv() => {
-                    return Err(ErrorCode::AbortedQuery("aborted"))
-                },
+                _ = abort_rx.recv() => return Err(aborted_error(&ctx)),
             },
             Err(err) => return Err(err),
         };
     }
-    Ok(())
+    Ok(None)
+}
+
+// The error of the killed query, the reason is set if it is killed by the statement timeout.
+fn aborted_error(ctx: &Arc<QueryContext>) -> ErrorCode {
+    ctx.get_kill_reason()
+        .unwrap_or_else(|| ErrorCode::AbortedQuery("aborted"))
 }
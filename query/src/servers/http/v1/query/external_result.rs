@@ -0,0 +1,166 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::FileFormatOptions;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use opendal::Scheme as DalSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::sessions::QueryContext;
+use crate::storages::S3Presigner;
+use crate::storages::StageSink;
+
+// The prefix of the result files in the storage, the files of a query are in _result/<query_id>/.
+const RESULT_PREFIX: &str = "_result";
+
+/// Spools the result of a query into files of the storage, the response has the presigned URLs
+/// of the files instead of the data, so a huge result is not sent through the query node.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExternalResultConf {
+    // The format of the result files: parquet or csv.
+    pub format: String,
+    // The result is split into the files of about max_file_size bytes.
+    pub max_file_size: usize,
+    // The seconds the presigned URLs are valid.
+    pub expire_secs: u64,
+}
+
+impl Default for ExternalResultConf {
+    fn default() -> Self {
+        ExternalResultConf {
+            format: "parquet".to_string(),
+            max_file_size: 64 * 1024 * 1024,
+            expire_secs: 3600,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExternalResultFile {
+    pub url: String,
+    pub rows: usize,
+    pub size: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalResult {
+    pub total_rows: usize,
+    pub files: Vec<ExternalResultFile>,
+    // The schema of the result is the schema of the response.
+    #[serde(skip)]
+    pub schema: Option<DataSchemaRef>,
+}
+
+pub(crate) struct ExternalResultWriter {
+    sink: StageSink,
+    presigner: S3Presigner,
+    bucket: String,
+    root: String,
+    expire_secs: u64,
+}
+
+impl ExternalResultWriter {
+    pub(crate) async fn try_create(
+        ctx: &Arc<QueryContext>,
+        conf: &ExternalResultConf,
+    ) -> Result<ExternalResultWriter> {
+        let format = match StageFileFormatType::from_str(&conf.format) {
+            Ok(v @ (StageFileFormatType::Parquet | StageFileFormatType::Csv)) => v,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                "Unsupported format of the external result: {}, must be one of {{ PARQUET | CSV }}",
+                conf.format
+            )))
+            }
+        };
+
+        // Only the files of S3 can be presigned.
+        let storage = ctx.get_config().storage;
+        if !matches!(DalSchema::from_str(&storage.storage_type)?, DalSchema::S3) {
+            return Err(ErrorCode::BadArguments(format!(
+                "The external result needs the s3 storage, but got: {}",
+                storage.storage_type
+            )));
+        }
+
+        let stage = UserStageInfo {
+            stage_type: StageType::Internal,
+            file_format_options: FileFormatOptions {
+                format,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let path = format!("{}/{}/", RESULT_PREFIX, ctx.get_id());
+        let sink = StageSink::try_create(ctx, stage, path, conf.max_file_size, true, false).await?;
+
+        let s3 = &storage.s3;
+        Ok(ExternalResultWriter {
+            sink,
+            presigner: S3Presigner::create(
+                &s3.endpoint_url,
+                &s3.region,
+                &s3.access_key_id,
+                &s3.secret_access_key,
+            ),
+            bucket: s3.bucket.clone(),
+            root: s3.root.trim_matches('/').to_string(),
+            expire_secs: conf.expire_secs,
+        })
+    }
+
+    pub(crate) async fn append(&mut self, block: DataBlock) -> Result<()> {
+        self.sink.append(block).await
+    }
+
+    pub(crate) async fn finish(self, schema: DataSchemaRef) -> Result<ExternalResult> {
+        let files = self.sink.finish().await?;
+
+        let now = Utc::now();
+        let mut result_files = Vec::with_capacity(files.len());
+        for file in &files {
+            // The key of the file in the bucket is under the root of the storage.
+            let path = file.path.trim_start_matches('/');
+            let key = match self.root.is_empty() {
+                true => path.to_string(),
+                false => format!("{}/{}", self.root, path),
+            };
+            result_files.push(ExternalResultFile {
+                url: self
+                    .presigner
+                    .presign_object(&self.bucket, &key, self.expire_secs, now)?,
+                rows: file.rows,
+                size: file.size,
+            });
+        }
+
+        Ok(ExternalResult {
+            total_rows: files.iter().map(|f| f.rows).sum(),
+            files: result_files,
+            schema: Some(schema),
+        })
+    }
+}
@@ -32,12 +32,19 @@ use crate::servers::http::v1::query::http_query_manager::HttpQueryConfig;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
+use crate::servers::http::v1::query::ExternalResult;
+use crate::servers::http::v1::query::ExternalResultConf;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::ResultDataManager;
 use crate::servers::http::v1::query::Wait;
+use crate::servers::http::v1::query::DEFAULT_MAX_ROWS_PER_PAGE;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 
+// The blocks of a submitted query buffered in memory, spool the larger results by
+// `external_result` instead.
+const SUBMITTED_QUERY_MAX_BLOCKS: usize = 1 << 20;
+
 #[derive(Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct HttpQueryRequest {
@@ -46,16 +53,35 @@ pub struct HttpQueryRequest {
     pub sql: String,
     #[serde(default)]
     pub pagination: PaginationConf,
+    // Spool the result into the files of the storage and respond with their presigned URLs.
+    #[serde(default)]
+    pub external_result: Option<ExternalResultConf>,
+    // Set by `POST /v1/query/submit`, the results are buffered until they are fetched.
+    #[serde(skip)]
+    pub(crate) submitted: bool,
+    // Set by the `X-DATABEND-QUERY-TAG` header, it overrides the `query_tag` of the session.
+    #[serde(skip)]
+    pub(crate) query_tag: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct PaginationConf {
     pub(crate) wait_time_secs: i32,
+    // A page has at most the rows, the rest of a block is returned in the next page.
+    #[serde(default = "default_max_rows_per_page")]
+    pub(crate) max_rows_per_page: usize,
+}
+
+fn default_max_rows_per_page() -> usize {
+    DEFAULT_MAX_ROWS_PER_PAGE
 }
 
 impl Default for PaginationConf {
     fn default() -> Self {
-        PaginationConf { wait_time_secs: 1 }
+        PaginationConf {
+            wait_time_secs: 1,
+            max_rows_per_page: DEFAULT_MAX_ROWS_PER_PAGE,
+        }
     }
 }
 
@@ -101,6 +127,7 @@ pub struct ResponseState {
     pub scan_progress: Option<ProgressValues>,
     pub state: ExecuteStateKind,
     pub error: Option<ErrorCode>,
+    pub external_result: Option<ExternalResult>,
 }
 
 pub struct HttpQueryResponseInternal {
@@ -112,6 +139,8 @@ pub struct HttpQueryResponseInternal {
 pub struct HttpQuery {
     pub(crate) id: String,
     pub(crate) session_id: String,
+    // The responses are compressed by the setting enable_http_compression of the session.
+    pub(crate) enable_compression: bool,
     #[allow(dead_code)]
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
@@ -125,6 +154,7 @@ impl HttpQuery {
         id: &str,
         request: HttpQueryRequest,
         session_manager: &Arc<SessionManager>,
+        tenant: &str,
         user_info: &UserInfo,
         config: HttpQueryConfig,
     ) -> Result<Arc<HttpQuery>> {
@@ -134,6 +164,9 @@ impl HttpQuery {
                 let session = session_manager
                     .create_session(SessionType::HTTPQuery)
                     .await?;
+                session.set_current_tenant(tenant.to_string()).await?;
+                // The database of the request overrides the default database of the user.
+                session.set_current_user(user_info.clone());
                 if let Some(db) = &session_conf.database {
                     session.set_current_database(db.clone());
                 }
@@ -151,6 +184,10 @@ impl HttpQuery {
                     .get_session(id)
                     .await
                     .ok_or_else(|| ErrorCode::UnknownSession(id))?;
+                // The sessions of the other tenants are invisible.
+                if session.get_tenant() != tenant {
+                    return Err(ErrorCode::UnknownSession(id));
+                }
                 if session.expire_state() == ExpiringState::InUse {
                     return Err(ErrorCode::BadArguments(
                         "last query on the session not finished",
@@ -160,16 +197,34 @@ impl HttpQuery {
             }
         };
         session.set_current_user(user_info.clone());
+        if let Some(query_tag) = &request.query_tag {
+            session.get_settings().set_settings(
+                "query_tag".to_string(),
+                query_tag.clone(),
+                false,
+            )?;
+        }
         let session_id = session.get_id().clone();
+        let enable_compression = session.get_settings().get_enable_http_compression()? != 0;
 
         //TODO(youngsofun): support config/set channel size
-        let (block_tx, block_rx) = mpsc::channel(10);
+        // Nobody fetches the pages of a submitted query until it finishes, so the blocks can not
+        // be back pressured.
+        let channel_size = match request.submitted {
+            true => SUBMITTED_QUERY_MAX_BLOCKS,
+            false => 10,
+        };
+        let (block_tx, block_rx) = mpsc::channel(channel_size);
 
         let state = ExecuteState::try_create(&request, session, block_tx).await?;
-        let data = Arc::new(TokioMutex::new(ResultDataManager::new(block_rx)));
+        let data = Arc::new(TokioMutex::new(ResultDataManager::new(
+            block_rx,
+            request.pagination.max_rows_per_page,
+        )));
         let query = HttpQuery {
             id: id.to_string(),
             session_id,
+            enable_compression,
             request,
             state,
             data,
@@ -184,6 +239,15 @@ impl HttpQuery {
         self.request.pagination.wait_time_secs == 0
     }
 
+    pub fn is_submitted(&self) -> bool {
+        self.request.submitted
+    }
+
+    async fn is_running(&self) -> bool {
+        let state = self.state.read().await;
+        matches!(state.state, ExecuteState::Running(_))
+    }
+
     pub async fn get_response_page(
         &self,
         page_no: usize,
@@ -222,6 +286,7 @@ impl HttpQuery {
             scan_progress: state.get_progress(),
             state: exe_state,
             error: err,
+            external_result: state.external_result.clone(),
         }
     }
 
@@ -253,12 +318,22 @@ impl HttpQuery {
     }
 
     pub async fn update_expire_time(&self) {
+        // A running submitted query never expires, however long it is not polled.
+        if self.is_submitted() && self.is_running().await {
+            return self.clear_expire_time().await;
+        }
         let mut t = self.expire_at.lock().await;
         *t = Some(Instant::now() + Duration::from_millis(self.config.result_timeout_millis));
     }
 
     pub async fn check_expire(&self) -> Option<Duration> {
-        let expire_at = self.expire_at.lock().await;
+        let finished = self.is_submitted() && !self.is_running().await;
+        let mut expire_at = self.expire_at.lock().await;
+        // The results of a submitted query are kept for the result timeout after it finished.
+        if finished && expire_at.is_none() {
+            *expire_at =
+                Some(Instant::now() + Duration::from_millis(self.config.result_timeout_millis));
+        }
         if let Some(expire_at) = *expire_at {
             let now = Instant::now();
             if now >= expire_at {
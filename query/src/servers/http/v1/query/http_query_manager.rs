@@ -65,10 +65,12 @@ impl HttpQueryManager {
         id: &str,
         request: HttpQueryRequest,
         session_manager: &Arc<SessionManager>,
+        tenant: &str,
         user_info: &UserInfo,
     ) -> Result<Arc<HttpQuery>> {
         let query =
-            HttpQuery::try_create(id, request, session_manager, user_info, self.config).await?;
+            HttpQuery::try_create(id, request, session_manager, tenant, user_info, self.config)
+                .await?;
         self.add_query(id, query.clone()).await;
         Ok(query)
     }
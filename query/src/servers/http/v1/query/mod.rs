@@ -15,6 +15,7 @@
 mod execute_state;
 mod expirable;
 mod expiring_map;
+mod external_result;
 mod http_query;
 mod http_query_manager;
 mod result_data_manager;
@@ -23,6 +24,9 @@ pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateKind;
 pub(crate) use execute_state::Executor;
 pub use execute_state::HttpQueryHandle;
+pub use external_result::ExternalResult;
+pub use external_result::ExternalResultConf;
+pub use external_result::ExternalResultFile;
 pub use http_query::HttpQuery;
 pub use http_query::HttpQueryRequest;
 pub use http_query::HttpQueryResponseInternal;
@@ -36,3 +40,4 @@ pub use result_data_manager::Page;
 pub use result_data_manager::ResponseData;
 pub use result_data_manager::ResultDataManager;
 pub use result_data_manager::Wait;
+pub use result_data_manager::DEFAULT_MAX_ROWS_PER_PAGE;
@@ -24,7 +24,7 @@ use common_tracing::tracing;
 
 use crate::servers::http::v1::JsonBlock;
 
-const TARGET_ROWS_PER_PAGE: usize = 10000;
+pub const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Wait {
@@ -49,16 +49,21 @@ pub struct ResultDataManager {
     total_pages: usize,
     last_page: Option<Page>,
     pub(crate) block_rx: mpsc::Receiver<DataBlock>,
+    // The rows of a received block beyond the last page, they start the next page.
+    pending_block: Option<DataBlock>,
+    max_rows_per_page: usize,
     end: bool,
 }
 
 impl ResultDataManager {
-    pub fn new(block_rx: mpsc::Receiver<DataBlock>) -> ResultDataManager {
+    pub fn new(block_rx: mpsc::Receiver<DataBlock>, max_rows_per_page: usize) -> ResultDataManager {
         ResultDataManager {
             block_rx,
             total_rows: 0,
             last_page: None,
             total_pages: 0,
+            pending_block: None,
+            max_rows_per_page: std::cmp::max(1, max_rows_per_page),
             end: false,
         }
     }
@@ -123,16 +128,22 @@ impl ResultDataManager {
     pub async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
         let mut results: Vec<JsonBlock> = Vec::new();
         let mut rows = 0;
-        let block_rx = &mut self.block_rx;
+        let max_rows = self.max_rows_per_page;
+
+        // The rows left by the last page come first.
+        if let Some(block) = self.pending_block.take() {
+            rows += self.push_block(&mut results, block, max_rows)?;
+            if rows >= max_rows {
+                return Ok((JsonBlock::concat(results), false));
+            }
+        }
 
         let mut end = false;
         loop {
-            match ResultDataManager::receive(block_rx, tp).await {
+            match ResultDataManager::receive(&mut self.block_rx, tp).await {
                 Ok(block) => {
-                    rows += block.num_rows();
-                    results.push(JsonBlock::new(&block)?);
-                    // TODO(youngsofun):  set it in post if needed
-                    if rows >= TARGET_ROWS_PER_PAGE {
+                    rows += self.push_block(&mut results, block, max_rows - rows)?;
+                    if rows >= max_rows {
                         break;
                     }
                 }
@@ -146,4 +157,22 @@ impl ResultDataManager {
         }
         Ok((JsonBlock::concat(results), end))
     }
+
+    // Push at most `limit` rows of the block into the page, the rest is kept for the next page.
+    fn push_block(
+        &mut self,
+        results: &mut Vec<JsonBlock>,
+        block: DataBlock,
+        limit: usize,
+    ) -> Result<usize> {
+        let num_rows = block.num_rows();
+        if num_rows <= limit {
+            results.push(JsonBlock::new(&block)?);
+            return Ok(num_rows);
+        }
+
+        results.push(JsonBlock::new(&block.slice(0, limit))?);
+        self.pending_block = Some(block.slice(limit, num_rows - limit));
+        Ok(limit)
+    }
 }
@@ -0,0 +1,218 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserInfo;
+use common_tracing::tracing;
+use futures::Sink;
+use futures::SinkExt;
+use futures::StreamExt;
+use poem::web::websocket::Message;
+use poem::web::websocket::WebSocket;
+use poem::web::websocket::WebSocketStream;
+use poem::web::Data;
+use poem::IntoResponse;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use super::query::ExecuteStateKind;
+use super::query::HttpQueryRequest;
+use crate::servers::http::middleware::HttpTenant;
+use crate::servers::http::v1::QueryError;
+use crate::servers::http::v1::QueryStats;
+use crate::sessions::SessionManager;
+
+/// The events of the query streamed to the client, each one is a JSON text message.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QueryEvent {
+    /// The rows produced since the last event.
+    Data {
+        id: String,
+        schema: DataSchemaRef,
+        data: Vec<Vec<JsonValue>>,
+    },
+    Progress {
+        id: String,
+        stats: QueryStats,
+    },
+    /// The last event of the query, the socket is closed after it.
+    End {
+        id: String,
+        state: ExecuteStateKind,
+        error: Option<QueryError>,
+        stats: QueryStats,
+    },
+}
+
+/// Run a query and stream its results over the WebSocket.
+///
+/// The first message of the client is the request, the same as the body of `POST /v1/query`.
+/// The rows are sent as soon as they are produced, with a progress event at least every
+/// `pagination.wait_time_secs`. Closing the socket kills the query.
+#[poem::handler]
+pub async fn query_websocket_handler(
+    sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
+    user_info: Data<&UserInfo>,
+    websocket: WebSocket,
+) -> impl IntoResponse {
+    let session_manager = sessions_extension.0.clone();
+    let tenant = tenant.clone();
+    let user_info = user_info.0.clone();
+    websocket.on_upgrade(move |socket| async move {
+        if let Err(error) = stream_query(socket, session_manager, tenant, user_info).await {
+            tracing::warn!("WebSocket query closed: {}", error);
+        }
+    })
+}
+
+async fn stream_query(
+    socket: WebSocketStream,
+    session_manager: Arc<SessionManager>,
+    tenant: String,
+    user_info: UserInfo,
+) -> Result<()> {
+    let (mut sink, mut stream) = socket.split();
+    let mut req = match stream.next().await {
+        Some(Ok(Message::Text(text))) => match serde_json::from_str::<HttpQueryRequest>(&text) {
+            Ok(req) => req,
+            Err(error) => {
+                let error = ErrorCode::BadArguments(format!("Invalid query request: {}", error));
+                return send_failure(&mut sink, "", &error).await;
+            }
+        },
+        _ => return Ok(()),
+    };
+    tracing::info!("receive websocket query: {:?}", req);
+
+    // The events are pushed, the wait time only bounds the interval of the progress events.
+    if req.pagination.wait_time_secs <= 0 {
+        req.pagination.wait_time_secs = 1;
+    }
+
+    let http_query_manager = session_manager.get_http_query_manager();
+    let query_id = http_query_manager.next_query_id();
+    let query = match http_query_manager
+        .try_create_query(&query_id, req, &session_manager, &tenant, &user_info)
+        .await
+    {
+        Ok(query) => query,
+        Err(error) => return send_failure(&mut sink, &query_id, &error).await,
+    };
+    // The query lives as long as the socket.
+    query.clear_expire_time().await;
+
+    let watched_query = query.clone();
+    let watcher = tokio::spawn(async move {
+        while let Some(Ok(message)) = stream.next().await {
+            if let Message::Close(_) = message {
+                break;
+            }
+        }
+        watched_query.kill().await;
+    });
+
+    let mut page_no = 0;
+    let result = loop {
+        let response = match query.get_response_page(page_no, false).await {
+            Ok(response) => response,
+            Err(error) => break send_failure(&mut sink, &query_id, &error).await,
+        };
+
+        let stats = QueryStats {
+            scan_progress: response.state.scan_progress.clone(),
+            running_time_ms: response.state.running_time_ms,
+        };
+        let next_page_no = match response.data {
+            Some(data) => {
+                if !data.page.data.is_empty() {
+                    let event = QueryEvent::Data {
+                        id: query_id.clone(),
+                        schema: data.page.data.schema().clone(),
+                        data: data.page.data.into(),
+                    };
+                    if let Err(error) = send_event(&mut sink, &event).await {
+                        break Err(error);
+                    }
+                }
+                data.next_page_no
+            }
+            None => None,
+        };
+
+        let event = match next_page_no {
+            Some(next_page_no) => {
+                page_no = next_page_no;
+                QueryEvent::Progress {
+                    id: query_id.clone(),
+                    stats,
+                }
+            }
+            None => QueryEvent::End {
+                id: query_id.clone(),
+                state: response.state.state,
+                error: response
+                    .state
+                    .error
+                    .as_ref()
+                    .map(QueryError::from_error_code),
+                stats,
+            },
+        };
+        let is_end = matches!(event, QueryEvent::End { .. });
+        if let Err(error) = send_event(&mut sink, &event).await {
+            break Err(error);
+        }
+        if is_end {
+            break Ok(());
+        }
+    };
+
+    watcher.abort();
+    query.kill().await;
+    http_query_manager.remove_query(&query_id).await;
+    let _ = sink.close().await;
+    result
+}
+
+async fn send_event<S>(sink: &mut S, event: &QueryEvent) -> Result<()>
+where
+    S: Sink<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    let text = serde_json::to_string(event)?;
+    sink.send(Message::Text(text))
+        .await
+        .map_err(|error| ErrorCode::NetworkRequestError(error.to_string()))
+}
+
+async fn send_failure<S>(sink: &mut S, query_id: &str, error: &ErrorCode) -> Result<()>
+where
+    S: Sink<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    let event = QueryEvent::End {
+        id: query_id.to_string(),
+        state: ExecuteStateKind::Failed,
+        error: Some(QueryError::from_error_code(error)),
+        stats: QueryStats::default(),
+    };
+    send_event(sink, &event).await
+}
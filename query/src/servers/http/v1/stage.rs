@@ -18,6 +18,9 @@ use common_io::prelude::get_abs_path;
 use common_meta_types::StageStorage;
 use common_meta_types::StageType;
 use common_meta_types::UserInfo;
+use common_meta_types::UserStageInfo;
+use futures::AsyncReadExt;
+use opendal::Operator;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
@@ -28,8 +31,10 @@ use poem::Request;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::servers::http::middleware::HttpTenant;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::storages::StageSource;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UploadToStageResponse {
@@ -39,20 +44,36 @@ pub struct UploadToStageResponse {
     pub files: Vec<String>,
 }
 
-#[poem::handler]
-pub async fn upload_to_stage(
+// The paths are joined to the stage root, a `..` segment could reach the files out of the stage.
+fn check_stage_path(header: &str, path: &str) -> PoemResult<()> {
+    if path.split('/').any(|segment| segment == "..") {
+        return Err(poem::Error::from_string(
+            format!("Invalid {} {}, '..' is not allowed", header, path),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+    Ok(())
+}
+
+// Resolves the stage and the path of the request headers `stage_name` and `relative_path`,
+// the operator of the stage is created by the credentials in the stage.
+async fn stage_operator(
     req: &Request,
-    mut multipart: Multipart,
-    user_info: Data<&UserInfo>,
-    sessions_extension: Data<&Arc<SessionManager>>,
-) -> PoemResult<Json<UploadToStageResponse>> {
-    let session_manager = sessions_extension.0;
+    tenant: &str,
+    user_info: &UserInfo,
+    session_manager: &Arc<SessionManager>,
+    session_type: &str,
+) -> PoemResult<(UserStageInfo, String, Operator)> {
     let session = session_manager
-        .create_session(SessionType::HTTPAPI("UploadToStage".to_string()))
+        .create_session(SessionType::HTTPAPI(session_type.to_string()))
         .await
         .map_err(InternalServerError)?;
 
-    session.set_current_user(user_info.0.clone());
+    session
+        .set_current_tenant(tenant.to_string())
+        .await
+        .map_err(InternalServerError)?;
+    session.set_current_user(user_info.clone());
     let context = session
         .create_query_context()
         .await
@@ -60,11 +81,6 @@ pub async fn upload_to_stage(
 
     let user_mgr = context.get_user_manager();
 
-    let op = context
-        .get_storage_operator()
-        .map_err(InternalServerError)?;
-    let mut files = vec![];
-
     let stage_name = req
         .headers()
         .get("stage_name")
@@ -86,6 +102,7 @@ pub async fn upload_to_stage(
         .and_then(|v| v.to_str().ok())
         .unwrap_or("")
         .trim_matches(|c| c == '/');
+    check_stage_path("relative_path", relative_path)?;
 
     let final_related_path: String;
     match stage.stage_type {
@@ -103,11 +120,36 @@ pub async fn upload_to_stage(
         },
     }
 
+    let op = StageSource::get_op(&context, &stage)
+        .await
+        .map_err(InternalServerError)?;
+    Ok((stage, final_related_path, op))
+}
+
+#[poem::handler]
+pub async fn upload_to_stage(
+    req: &Request,
+    mut multipart: Multipart,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
+    user_info: Data<&UserInfo>,
+    sessions_extension: Data<&Arc<SessionManager>>,
+) -> PoemResult<Json<UploadToStageResponse>> {
+    let (stage, final_related_path, op) = stage_operator(
+        req,
+        tenant,
+        user_info.0,
+        sessions_extension.0,
+        "UploadToStage",
+    )
+    .await?;
+    let mut files = vec![];
+
     while let Ok(Some(field)) = multipart.next_field().await {
         let name = match field.file_name() {
             Some(name) => name.to_string(),
             None => uuid::Uuid::new_v4().to_string(),
         };
+        check_stage_path("file name", &name)?;
         let bytes = field.bytes().await.map_err(InternalServerError)?;
         let obj = format!("{}/{}", final_related_path, name);
         let _ = op
@@ -118,11 +160,55 @@ pub async fn upload_to_stage(
         files.push(name.clone());
     }
 
-    let mut id = uuid::Uuid::new_v4().to_string();
+    let id = uuid::Uuid::new_v4().to_string();
     Ok(Json(UploadToStageResponse {
         id,
-        stage_name: stage_name.to_string(),
+        stage_name: stage.stage_name,
         state: "SUCCESS".to_string(),
         files,
     }))
 }
+
+// Downloads the file `file_name` in the `relative_path` of the stage `stage_name`.
+#[poem::handler]
+pub async fn download_from_stage(
+    req: &Request,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
+    user_info: Data<&UserInfo>,
+    sessions_extension: Data<&Arc<SessionManager>>,
+) -> PoemResult<Vec<u8>> {
+    let (_, final_related_path, op) = stage_operator(
+        req,
+        tenant,
+        user_info.0,
+        sessions_extension.0,
+        "DownloadFromStage",
+    )
+    .await?;
+
+    let file_name = req
+        .headers()
+        .get("file_name")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            poem::Error::from_string(
+                "Parse file_name error, not found".to_string(),
+                StatusCode::BAD_REQUEST,
+            )
+        })?;
+    check_stage_path("file_name", file_name)?;
+
+    let obj = format!("{}/{}", final_related_path, file_name.trim_matches('/'));
+    let mut reader = op
+        .object(&obj)
+        .reader()
+        .await
+        .map_err(|e| poem::Error::new(e, StatusCode::NOT_FOUND))?;
+
+    let mut data = vec![];
+    reader
+        .read_to_end(&mut data)
+        .await
+        .map_err(InternalServerError)?;
+    Ok(data)
+}
@@ -13,33 +13,97 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_base::ProgressValues;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_meta_types::UserInfo;
+use common_tracing::tracing;
+use futures::StreamExt;
 use hyper::StatusCode;
+use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::post;
 use poem::web::Data;
 use poem::web::Json;
 use poem::web::Query;
 use poem::Endpoint;
+use poem::Request;
 use poem::Route;
 use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
 
+use super::http_query_handlers::query_tag_of;
+use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpSession;
 use super::query::HttpSessionConf;
 use super::query::PaginationConf;
+use super::JsonBlock;
+use super::QueryError;
 use super::QueryResponse;
+use super::QueryStats;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::middleware::HttpTenant;
 use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
+use crate::sessions::SessionType;
+use crate::sql::PlanParser;
 
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     db: Option<String>,
 }
 
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    Stop,
+    Continue,
+}
+
+impl Default for OnError {
+    fn default() -> Self {
+        OnError::Stop
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MultiStatementHandlerParams {
+    db: Option<String>,
+    // Whether to run the rest of the statements after a statement failed.
+    #[serde(default)]
+    on_error: OnError,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StatementResult {
+    pub sql: String,
+    pub schema: Option<DataSchemaRef>,
+    pub data: Vec<Vec<JsonValue>>,
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub stats: QueryStats,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MultiStatementResponse {
+    pub session_id: String,
+    // Failed if any of the statements failed.
+    pub state: ExecuteStateKind,
+    // The results of the executed statements, in order.
+    pub results: Vec<StatementResult>,
+}
+
 #[poem::handler]
 pub async fn statement_handler(
+    request: &Request,
     sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
     user_info: Data<&UserInfo>,
     sql: String,
     Query(params): Query<StatementHandlerParams>,
@@ -54,10 +118,16 @@ pub async fn statement_handler(
     let req = HttpQueryRequest {
         sql,
         session: HttpSession::New(session),
-        pagination: PaginationConf { wait_time_secs: -1 },
+        pagination: PaginationConf {
+            wait_time_secs: -1,
+            ..Default::default()
+        },
+        external_result: None,
+        submitted: false,
+        query_tag: query_tag_of(request),
     };
     let query = http_query_manager
-        .try_create_query(&query_id, req, session_manager, &user_info)
+        .try_create_query(&query_id, req, session_manager, tenant, &user_info)
         .await;
     match query {
         Ok(query) => {
@@ -72,6 +142,188 @@ pub async fn statement_handler(
     }
 }
 
+/// Run the semicolon separated statements one by one in a single session, so the statements
+/// like `USE` and `SET` affect the following ones.
+#[poem::handler]
+pub async fn multi_statement_handler(
+    request: &Request,
+    sessions_extension: Data<&Arc<SessionManager>>,
+    Data(HttpTenant(tenant)): Data<&HttpTenant>,
+    user_info: Data<&UserInfo>,
+    sql: String,
+    Query(params): Query<MultiStatementHandlerParams>,
+) -> PoemResult<Json<MultiStatementResponse>> {
+    let session = sessions_extension
+        .0
+        .create_session(SessionType::HTTPQuery)
+        .await
+        .map_err(InternalServerError)?;
+    session
+        .set_current_tenant(tenant.clone())
+        .await
+        .map_err(InternalServerError)?;
+    session.set_current_user(user_info.0.clone());
+    if let Some(db) = params.db.filter(|x| !x.is_empty()) {
+        session.set_current_database(db);
+    }
+    if let Some(query_tag) = query_tag_of(request) {
+        session
+            .get_settings()
+            .set_settings("query_tag".to_string(), query_tag, false)
+            .map_err(InternalServerError)?;
+    }
+
+    let mut state = ExecuteStateKind::Succeeded;
+    let mut results = vec![];
+    for statement in split_statements(&sql) {
+        let start_time = Instant::now();
+        let result = execute_statement(&session, statement).await;
+        let running_time_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+        let result = match result {
+            Ok((schema, blocks, scan_progress)) => {
+                let data = blocks
+                    .iter()
+                    .map(JsonBlock::new)
+                    .collect::<Result<Vec<_>>>()
+                    .map(|blocks| JsonBlock::concat(blocks).into());
+                match data {
+                    Ok(data) => StatementResult {
+                        sql: statement.to_string(),
+                        schema: Some(schema),
+                        data,
+                        state: ExecuteStateKind::Succeeded,
+                        error: None,
+                        stats: QueryStats {
+                            scan_progress,
+                            running_time_ms,
+                        },
+                    },
+                    Err(e) => failed_statement(statement, &e, running_time_ms),
+                }
+            }
+            Err(e) => failed_statement(statement, &e, running_time_ms),
+        };
+
+        let failed = result.state == ExecuteStateKind::Failed;
+        results.push(result);
+        if failed {
+            state = ExecuteStateKind::Failed;
+            if params.on_error == OnError::Stop {
+                break;
+            }
+        }
+    }
+
+    Ok(Json(MultiStatementResponse {
+        session_id: session.get_id(),
+        state,
+        results,
+    }))
+}
+
+async fn execute_statement(
+    session: &SessionRef,
+    sql: &str,
+) -> Result<(DataSchemaRef, Vec<DataBlock>, Option<ProgressValues>)> {
+    let ctx = session.create_query_context().await?;
+    ctx.attach_query_str(sql);
+    let plan = PlanParser::parse(ctx.clone(), sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    // Write Start to query log table.
+    let _ = interpreter
+        .start()
+        .await
+        .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+
+    let data_stream = interpreter.execute(None).await?;
+    let mut data_stream = ctx.try_create_abortable(data_stream)?;
+    let mut blocks = vec![];
+    while let Some(block) = data_stream.next().await {
+        blocks.push(block?);
+    }
+
+    // Write Finish to query log table.
+    let _ = interpreter
+        .finish()
+        .await
+        .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
+    Ok((
+        interpreter.schema(),
+        blocks,
+        Some(ctx.get_scan_progress_value()),
+    ))
+}
+
+fn failed_statement(sql: &str, e: &ErrorCode, running_time_ms: f64) -> StatementResult {
+    StatementResult {
+        sql: sql.to_string(),
+        schema: None,
+        data: vec![],
+        state: ExecuteStateKind::Failed,
+        error: Some(QueryError::from_error_code(e)),
+        stats: QueryStats {
+            scan_progress: None,
+            running_time_ms,
+        },
+    }
+}
+
+/// Split the SQL by the semicolons outside the quotes and the comments,
+/// the statements without anything but the comments are skipped.
+pub fn split_statements(sql: &str) -> Vec<&str> {
+    let bytes = sql.as_bytes();
+    let mut statements = vec![];
+    let mut start = 0;
+    let mut has_content = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                has_content = true;
+                i += 1;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b'\\' => i += 1,
+                        // A doubled quote is an escaped quote.
+                        c if c == quote && bytes.get(i + 1) == Some(&quote) => i += 1,
+                        c if c == quote => break,
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b';' => {
+                if has_content {
+                    statements.push(sql[start..i].trim());
+                }
+                start = i + 1;
+                has_content = false;
+            }
+            c if c.is_ascii_whitespace() => {}
+            _ => has_content = true,
+        }
+        i += 1;
+    }
+    if has_content {
+        statements.push(sql[start..].trim());
+    }
+    statements
+}
+
 pub fn statement_router() -> impl Endpoint {
-    Route::new().at("/", post(statement_handler))
+    Route::new()
+        .at("/", post(statement_handler))
+        .at("/multi", post(multi_statement_handler))
 }
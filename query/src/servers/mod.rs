@@ -15,14 +15,20 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 pub use clickhouse::ClickHouseHandler;
+pub use flight_sql::FlightSQLHandler;
 pub use server::Server;
 pub use server::ShutdownHandle;
 
+pub use self::http::ClickHouseHttpHandler;
 pub use self::http::HttpHandler;
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLHandler;
+pub use self::postgres::PostgresConnection;
+pub use self::postgres::PostgresHandler;
 
 mod clickhouse;
+mod flight_sql;
 pub mod http;
 mod mysql;
+mod postgres;
 pub(crate) mod server;
@@ -29,9 +29,11 @@ use futures::future::AbortRegistration;
 use futures::future::Abortable;
 use futures::StreamExt;
 use opensrv_mysql::*;
+use tokio_rustls::TlsAcceptor;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::servers::mysql::mysql_session::MySQLConnection;
+use crate::servers::mysql::mysql_tls::MySQLTls;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
@@ -66,27 +68,42 @@ impl MySQLHandler {
         Ok((TcpListenerStream::new(listener), listener_addr))
     }
 
-    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+    fn listen_loop(
+        &self,
+        stream: ListeningStream,
+        rt: Arc<Runtime>,
+        tls_acceptor: Option<TlsAcceptor>,
+    ) -> impl Future<Output = ()> {
         let sessions = self.sessions.clone();
         stream.for_each(move |accept_socket| {
             let executor = rt.clone();
             let sessions = sessions.clone();
+            let tls_acceptor = tls_acceptor.clone();
             async move {
                 match accept_socket {
                     Err(error) => tracing::error!("Broken session connection: {}", error),
-                    Ok(socket) => MySQLHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => {
+                        MySQLHandler::accept_socket(sessions, executor, socket, tls_acceptor)
+                    }
                 };
             }
         })
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls_acceptor: Option<TlsAcceptor>,
+    ) {
         executor.spawn(async move {
             match sessions.create_session(SessionType::MySQL).await {
                 Err(error) => Self::reject_session(socket, error).await,
                 Ok(session) => {
                     tracing::info!("MySQL connection coming: {:?}", socket.peer_addr());
-                    if let Err(error) = MySQLConnection::run_on_stream(session, socket) {
+                    if let Err(error) =
+                        MySQLConnection::run_on_stream(session, socket, tls_acceptor).await
+                    {
                         tracing::error!("Unexpected error occurred during query: {:?}", error);
                     };
                 }
@@ -132,13 +149,22 @@ impl Server for MySQLHandler {
         match self.abort_registration.take() {
             None => Err(ErrorCode::LogicalError("MySQLHandler already running.")),
             Some(registration) => {
+                let tls_acceptor = MySQLTls::create_acceptor(&self.sessions.get_conf())?;
+                if tls_acceptor.is_some() {
+                    tracing::info!("MySQL Handler TLS enabled");
+                }
+
                 let rejected_rt = Arc::new(Runtime::with_worker_threads(
                     1,
                     Some("mysql-handler".to_string()),
                 )?);
                 let (stream, listener) = Self::listener_tcp(listening).await?;
                 let stream = Abortable::new(stream, registration);
-                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt)));
+                self.join_handle = Some(tokio::spawn(self.listen_loop(
+                    stream,
+                    rejected_rt,
+                    tls_acceptor,
+                )));
                 Ok(listener)
             }
         }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
@@ -19,6 +20,7 @@ use std::time::Instant;
 use common_base::TrySpawn;
 use common_datablocks::DataBlock;
 use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::Series;
 use common_datavalues::SeriesFrom;
@@ -27,11 +29,15 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_io::prelude::*;
+use common_meta_types::AuthInfo;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 use common_tracing::tracing::Instrument;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -42,6 +48,8 @@ use regex::RegexSet;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::mysql_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::sessions::QueryContext;
@@ -51,6 +59,8 @@ use crate::users::CertifiedInfo;
 
 struct InteractiveWorkerBase<W: std::io::Write> {
     session: SessionRef,
+    statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
     generic_hold: PhantomData<W>,
 }
 
@@ -221,40 +231,105 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
 
         let ctx = self.session.create_query_context().await?;
+        let tenant = ctx.get_tenant();
         let user_manager = ctx.get_user_manager();
         let user_info = user_manager
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+            .get_user_with_client_ip(&tenant, user_name, client_ip)
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
-        if authed {
-            self.session.set_current_user(user_info);
-        }
-        Ok(authed)
+        let auth_manager = ctx.get_auth_manager();
+        let user_info = match &user_info.auth_info {
+            AuthInfo::Password { .. } => {
+                auth_manager
+                    .verify_password(&tenant, user_info, authed)
+                    .await?
+            }
+            _ if authed => user_info,
+            _ => return Ok(false),
+        };
+        auth_manager
+            .check_network_policy(&tenant, &user_info, Some(&info.user_client_address))
+            .await?;
+        self.session.set_current_user(user_info);
+        Ok(true)
     }
 
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Prepare is not support in Databend.".as_bytes(),
-        )?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let statement = PreparedStatement::create(query);
+        let columns = match self.describe_query(&statement).await {
+            Ok(columns) => columns,
+            Err(cause) => {
+                tracing::error!("OnPrepare Error: {:?}", cause);
+                writer.error(ErrorKind::ER_UNKNOWN_ERROR, format!("{}", cause).as_bytes())?;
+                return Ok(());
+            }
+        };
+
+        // The types of the parameters are unknown until the execution, report them as strings.
+        let params = (0..statement.num_params)
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+
+        let id = self.next_statement_id;
+        self.next_statement_id = self.next_statement_id.wrapping_add(1);
+        self.statements.insert(id, statement);
+        writer.reply(id, &params, &columns)?;
         Ok(())
     }
 
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Execute is not support in Databend.".as_bytes(),
-        )?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let query = match self.statements.get(&id) {
+            Some(statement) => statement.bind(params),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement handler ({}) given to mysqld_stmt_execute",
+                id
+            ))),
+        };
+
+        let instant = Instant::now();
+        let blocks = match query {
+            Ok(query) => self.do_query(&query).await,
+            Err(cause) => Err(cause),
+        };
+        let write_result = writer.write(blocks);
+
+        histogram!(
+            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+            instant.elapsed()
+        );
+
+        write_result
     }
 
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.statements.remove(&id);
+    }
+
+    async fn describe_query(&mut self, statement: &PreparedStatement) -> Result<Vec<Column>> {
+        let query = statement.query_without_params()?;
+        let schema: DataSchemaRef = match self.federated_server_setup_set_or_jdbc_command(&query) {
+            Some(data_block) => data_block.schema().clone(),
+            None => {
+                let context = self.session.create_query_context().await?;
+                let plan = PlanParser::parse(context.clone(), &query).await?;
+                InterpreterFactory::get(context, plan)?.schema()
+            }
+        };
+        convert_schema(&schema)
+    }
 
     fn variable_block(name: &str, value: &str) -> Option<DataBlock> {
         Some(DataBlock::create(
@@ -454,6 +529,8 @@ impl<W: std::io::Write> InteractiveWorker<W> {
             session: session.clone(),
             base: InteractiveWorkerBase::<W> {
                 session,
+                statements: HashMap::new(),
+                next_statement_id: 1,
                 generic_hold: PhantomData::default(),
             },
             salt: scramble,
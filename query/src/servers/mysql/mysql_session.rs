@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::net::Shutdown;
+use std::net::SocketAddr;
 
 use common_base::tokio::net::TcpStream;
 use common_base::Runtime;
@@ -23,24 +24,36 @@ use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_tracing::tracing;
 use opensrv_mysql::AsyncMysqlIntermediary;
+use tokio_rustls::TlsAcceptor;
 
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
+use crate::servers::mysql::mysql_tls::MySQLTls;
 use crate::sessions::SessionRef;
 
 pub struct MySQLConnection;
 
 impl MySQLConnection {
-    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+    pub async fn run_on_stream(
+        session: SessionRef,
+        stream: TcpStream,
+        tls_acceptor: Option<TlsAcceptor>,
+    ) -> Result<()> {
+        let client_addr = stream.peer_addr()?;
+        let stream = match tls_acceptor {
+            None => stream,
+            Some(acceptor) => MySQLTls::relay(acceptor, stream).await?,
+        };
+
         let blocking_stream = Self::convert_stream(stream)?;
-        MySQLConnection::attach_session(&session, &blocking_stream)?;
+        MySQLConnection::attach_session(&session, client_addr, &blocking_stream)?;
 
         let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
         let query_executor =
             Runtime::with_worker_threads(1, Some("mysql-query-executor".to_string()))?;
         Thread::spawn(move || {
             let join_handle = query_executor.spawn(async move {
-                let client_addr = non_blocking_stream.peer_addr().unwrap().to_string();
-                let interactive_worker = InteractiveWorker::create(session, client_addr);
+                let interactive_worker =
+                    InteractiveWorker::create(session, client_addr.to_string());
                 AsyncMysqlIntermediary::run_on(interactive_worker, non_blocking_stream).await
             });
             let _ = futures::executor::block_on(join_handle);
@@ -48,10 +61,14 @@ impl MySQLConnection {
         Ok(())
     }
 
-    fn attach_session(session: &SessionRef, blocking_stream: &std::net::TcpStream) -> Result<()> {
-        let host = blocking_stream.peer_addr().ok();
+    // The stream may be relayed from the TLS connection, so the host is the address of the client.
+    fn attach_session(
+        session: &SessionRef,
+        client_addr: SocketAddr,
+        blocking_stream: &std::net::TcpStream,
+    ) -> Result<()> {
         let blocking_stream_ref = blocking_stream.try_clone()?;
-        session.attach(host, move || {
+        session.attach(Some(client_addr), move || {
             if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
                 tracing::error!("Cannot shutdown MySQL session io {}", error);
             }
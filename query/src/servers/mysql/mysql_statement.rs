@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::Value;
+use opensrv_mysql::ValueInner;
+
+/// A statement prepared by COM_STMT_PREPARE, the `?` placeholders are bound at execution.
+pub struct PreparedStatement {
+    pub query: String,
+    pub num_params: usize,
+}
+
+impl PreparedStatement {
+    pub fn create(query: &str) -> PreparedStatement {
+        let mut num_params = 0;
+        visit_placeholders(query, |_| num_params += 1);
+        PreparedStatement {
+            query: query.to_string(),
+            num_params,
+        }
+    }
+
+    /// The query with NULL in place of the parameters, to plan the result columns.
+    pub fn query_without_params(&self) -> Result<String> {
+        bind_literals(&self.query, &vec!["NULL".to_string(); self.num_params])
+    }
+
+    /// The query with the parameters of COM_STMT_EXECUTE as SQL literals.
+    pub fn bind(&self, params: ParamParser<'_>) -> Result<String> {
+        let literals = params
+            .into_iter()
+            .map(|param| to_literal(param.value))
+            .collect::<Result<Vec<_>>>()?;
+        bind_literals(&self.query, &literals)
+    }
+}
+
+fn to_literal(value: Value<'_>) -> Result<String> {
+    match value.into_inner() {
+        ValueInner::NULL => Ok("NULL".to_string()),
+        ValueInner::Int(v) => Ok(v.to_string()),
+        ValueInner::UInt(v) => Ok(v.to_string()),
+        ValueInner::Double(v) => Ok(v.to_string()),
+        ValueInner::Bytes(v) => Ok(quote(&String::from_utf8_lossy(v))),
+        ValueInner::Date(_) => {
+            let date = NaiveDate::from(value);
+            Ok(quote(&date.format("%Y-%m-%d").to_string()))
+        }
+        ValueInner::Datetime(_) => {
+            let date_time = NaiveDateTime::from(value);
+            Ok(quote(&date_time.format("%Y-%m-%d %H:%M:%S%.6f").to_string()))
+        }
+        ValueInner::Time(_) => Err(ErrorCode::UnImplement(
+            "TIME parameters are not supported",
+        )),
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Iterate the `?` placeholders outside of the quoted strings and identifiers.
+fn visit_placeholders(query: &str, mut f: impl FnMut(usize)) {
+    let mut quote: Option<u8> = None;
+    for (index, c) in query.bytes().enumerate() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == b'\'' || c == b'"' || c == b'`' => quote = Some(c),
+            None if c == b'?' => f(index),
+            None => {}
+        }
+    }
+}
+
+fn bind_literals(query: &str, literals: &[String]) -> Result<String> {
+    let mut placeholders = vec![];
+    visit_placeholders(query, |index| placeholders.push(index));
+
+    if placeholders.len() != literals.len() {
+        return Err(ErrorCode::BadArguments(format!(
+            "Expected {} parameters, but got {}",
+            placeholders.len(),
+            literals.len()
+        )));
+    }
+
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for (index, literal) in placeholders.into_iter().zip(literals) {
+        bound.push_str(&query[last..index]);
+        bound.push_str(literal);
+        last = index + 1;
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
@@ -0,0 +1,236 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncReadExt;
+use common_base::tokio::io::AsyncWrite;
+use common_base::tokio::io::AsyncWriteExt;
+use common_base::tokio::net::TcpListener;
+use common_base::tokio::net::TcpStream;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_tracing::tracing;
+use rustls_pemfile::Item;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+use crate::configs::Config;
+
+// https://dev.mysql.com/doc/internals/en/ssl-handshake.html
+const CLIENT_SSL: u32 = 0x0000_0800;
+// The SSLRequest only has the capability flags, the max packet size, the charset and 23 zeros.
+const SSL_REQUEST_SIZE: usize = 32;
+
+const OK_HEADER: u8 = 0x00;
+const ERR_HEADER: u8 = 0xff;
+const AUTH_MORE_DATA_HEADER: u8 = 0x01;
+const FAST_AUTH_SUCCESS: u8 = 0x03;
+
+/// The TLS of the MySQL handler.
+///
+/// The MySQL server of opensrv only serves the plain TCP streams, so the connection of the
+/// client is relayed through a loopback connection: the relay advertises the SSL capability in
+/// the greeting, upgrades the connection to TLS if the client sends a SSLRequest, then forwards
+/// the decrypted packets to the server.
+pub struct MySQLTls;
+
+impl MySQLTls {
+    pub fn create_acceptor(config: &Config) -> Result<Option<TlsAcceptor>> {
+        let conf = &config.query;
+        if conf.mysql_handler_tls_server_cert.is_empty()
+            || conf.mysql_handler_tls_server_key.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let certs = Self::load_certs(&conf.mysql_handler_tls_server_cert)?;
+        let key = Self::load_private_key(&conf.mysql_handler_tls_server_key)?;
+
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = match conf.mysql_handler_tls_server_root_ca_cert.is_empty() {
+            true => builder.with_no_client_auth(),
+            false => {
+                let mut roots = RootCertStore::empty();
+                for cert in Self::load_certs(&conf.mysql_handler_tls_server_root_ca_cert)? {
+                    roots
+                        .add(&cert)
+                        .map_err_to_code(ErrorCode::TLSConfigurationFailure, || {
+                            "Invalid MySQL handler TLS root CA certificate"
+                        })?;
+                }
+                builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots))
+            }
+        };
+
+        let server_config = builder
+            .with_single_cert(certs, key)
+            .map_err_to_code(ErrorCode::TLSConfigurationFailure, || {
+                "Invalid MySQL handler TLS certificate or key"
+            })?;
+        Ok(Some(TlsAcceptor::from(Arc::new(server_config))))
+    }
+
+    /// Relay the connection of the client, the returned stream is served as a plain connection.
+    pub async fn relay(acceptor: TlsAcceptor, client: TcpStream) -> Result<TcpStream> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let (server, accepted) = tokio::try_join!(
+            TcpStream::connect(listener.local_addr()?),
+            listener.accept()
+        )?;
+
+        tokio::spawn(async move {
+            if let Err(error) = Self::relay_packets(acceptor, client, accepted.0).await {
+                tracing::warn!("MySQL TLS connection closed: {}", error);
+            }
+        });
+        Ok(server)
+    }
+
+    async fn relay_packets(
+        acceptor: TlsAcceptor,
+        mut client: TcpStream,
+        mut server: TcpStream,
+    ) -> Result<()> {
+        let (sequence, mut greeting) = read_packet(&mut server).await?;
+        advertise_ssl(&mut greeting)?;
+        write_packet(&mut client, sequence, &greeting).await?;
+
+        let (sequence, response) = read_packet(&mut client).await?;
+        if !is_ssl_request(&response) {
+            write_packet(&mut server, sequence, &response).await?;
+            tokio::io::copy_bidirectional(&mut client, &mut server).await?;
+            return Ok(());
+        }
+
+        let mut client = acceptor.accept(client).await?;
+
+        // The server never sees the SSLRequest, so the sequence ids of the packets are shifted
+        // until the authentication finishes.
+        let (sequence, mut response) = read_packet(&mut client).await?;
+        clear_ssl(&mut response);
+        write_packet(&mut server, sequence.wrapping_sub(1), &response).await?;
+        loop {
+            let (sequence, packet) = read_packet(&mut server).await?;
+            write_packet(&mut client, sequence.wrapping_add(1), &packet).await?;
+            match (packet.first(), packet.get(1)) {
+                (Some(&OK_HEADER), _) | (Some(&ERR_HEADER), _) => break,
+                // The OK packet follows the fast authentication without any response.
+                (Some(&AUTH_MORE_DATA_HEADER), Some(&FAST_AUTH_SUCCESS)) => continue,
+                _ => {
+                    let (sequence, packet) = read_packet(&mut client).await?;
+                    write_packet(&mut server, sequence.wrapping_sub(1), &packet).await?;
+                }
+            }
+        }
+
+        tokio::io::copy_bidirectional(&mut client, &mut server).await?;
+        Ok(())
+    }
+
+    fn load_certs(path: &str) -> Result<Vec<Certificate>> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let certs = rustls_pemfile::certs(&mut reader)?;
+        match certs.is_empty() {
+            true => Err(ErrorCode::TLSConfigurationFailure(format!(
+                "No certificate found in {}",
+                path
+            ))),
+            false => Ok(certs.into_iter().map(Certificate).collect()),
+        }
+    }
+
+    fn load_private_key(path: &str) -> Result<PrivateKey> {
+        let mut reader = BufReader::new(File::open(path)?);
+        while let Some(item) = rustls_pemfile::read_one(&mut reader)? {
+            if let Item::RSAKey(key) | Item::PKCS8Key(key) = item {
+                return Ok(PrivateKey(key));
+            }
+        }
+        Err(ErrorCode::TLSConfigurationFailure(format!(
+            "No private key found in {}",
+            path
+        )))
+    }
+}
+
+async fn read_packet<R: AsyncRead + Unpin>(reader: &mut R) -> Result<(u8, Vec<u8>)> {
+    let mut header = [0u8; 4];
+    reader.read_exact(&mut header).await?;
+    let length = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+    let mut payload = vec![0u8; length];
+    reader.read_exact(&mut payload).await?;
+    Ok((header[3], payload))
+}
+
+async fn write_packet<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    sequence: u8,
+    payload: &[u8],
+) -> Result<()> {
+    let length = (payload.len() as u32).to_le_bytes();
+    writer
+        .write_all(&[length[0], length[1], length[2], sequence])
+        .await?;
+    writer.write_all(payload).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+// The lower capability flags follow the protocol version, the null terminated server version,
+// the connection id, the first 8 bytes of the auth data and a filler.
+fn advertise_ssl(greeting: &mut [u8]) -> Result<()> {
+    let offset = greeting
+        .iter()
+        .skip(1)
+        .position(|b| *b == 0)
+        .map(|version_length| 1 + version_length + 1 + 4 + 8 + 1);
+
+    match offset {
+        Some(offset) if greeting.len() >= offset + 2 => {
+            let flags =
+                u16::from_le_bytes([greeting[offset], greeting[offset + 1]]) | CLIENT_SSL as u16;
+            greeting[offset..offset + 2].copy_from_slice(&flags.to_le_bytes());
+            Ok(())
+        }
+        _ => Err(ErrorCode::BadBytes("Invalid MySQL handshake packet")),
+    }
+}
+
+fn capability_flags(response: &[u8]) -> u32 {
+    match response.len() >= 4 {
+        true => u32::from_le_bytes([response[0], response[1], response[2], response[3]]),
+        false => 0,
+    }
+}
+
+fn is_ssl_request(response: &[u8]) -> bool {
+    response.len() == SSL_REQUEST_SIZE && capability_flags(response) & CLIENT_SSL != 0
+}
+
+fn clear_ssl(response: &mut [u8]) {
+    if response.len() >= 4 {
+        let flags = capability_flags(response) & !CLIENT_SSL;
+        response[0..4].copy_from_slice(&flags.to_le_bytes());
+    }
+}
@@ -31,17 +31,29 @@ use opensrv_mysql::*;
 
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
+    binary: bool,
 }
 
 impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
     pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: false,
+        }
+    }
+
+    /// The writer of the results of COM_STMT_EXECUTE, the rows are in the binary protocol.
+    pub fn create_binary(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: true,
+        }
     }
 
     pub fn write(&mut self, query_result: Result<(Vec<DataBlock>, String)>) -> Result<()> {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer)?,
+                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer, self.binary)?,
                 Err(error) => Self::err(&error, writer)?,
             }
         }
@@ -52,6 +64,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
         blocks: Vec<DataBlock>,
         extra_info: String,
         dataset_writer: QueryResultWriter<'a, W>,
+        binary: bool,
     ) -> Result<()> {
         // XXX: num_columns == 0 may is error?
         let default_response = OkResponse {
@@ -64,49 +77,6 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match remove_nullable(field.data_type()).data_type_id() {
-                TypeID::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                TypeID::Date16 | TypeID::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                TypeID::DateTime32 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                TypeID::DateTime64 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
-                TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                _ => Err(ErrorCode::UnImplement(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
-            }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
-        }
-
         let block = blocks[0].clone();
         let utc: Tz = "UTC".parse().unwrap();
         match convert_schema(block.schema()) {
@@ -151,21 +121,43 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz = data_type.tz();
                                     let tz = tz.cloned().unwrap_or_else(|| "UTC".to_string());
                                     let tz: Tz = tz.parse().unwrap();
+                                    let date_time =
+                                        v.to_date_time64(data_type.precision(), &tz).naive_local();
 
-                                    row_writer.write_col(
-                                        v.to_date_time64(data_type.precision(), &tz)
-                                            .naive_local()
-                                            .format(data_type.format_string().as_str())
-                                            .to_string(),
-                                    )?
+                                    match binary {
+                                        true => row_writer.write_col(date_time)?,
+                                        false => row_writer.write_col(
+                                            date_time
+                                                .format(data_type.format_string().as_str())
+                                                .to_string(),
+                                        )?,
+                                    }
+                                }
+                                (TypeID::Decimal, DataValue::Int128(_))
+                                | (TypeID::Decimal256, DataValue::String(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
                                 (TypeID::String, DataValue::String(v)) => {
                                     row_writer.write_col(v)?
                                 }
+                                (TypeID::Array, DataValue::Array(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (TypeID::Struct, DataValue::Struct(_)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
+                                (TypeID::Map, DataValue::Array(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
+                                (TypeID::Bitmap, DataValue::String(_))
+                                | (TypeID::Geometry, DataValue::String(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (TypeID::Variant, DataValue::Json(_)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
@@ -178,6 +170,9 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
+                                (TypeID::Float32, DataValue::Float64(v)) => {
+                                    row_writer.write_col(v as f32)?
+                                }
                                 (_, DataValue::Int64(v)) => row_writer.write_col(v)?,
 
                                 (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
@@ -215,3 +210,59 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
         Ok(())
     }
 }
+
+// The types must match the values written in the binary protocol, e.g. LONGLONG for 8 bytes.
+fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+    match remove_nullable(field.data_type()).data_type_id() {
+        TypeID::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+        TypeID::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        TypeID::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+        TypeID::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+        TypeID::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        TypeID::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+        TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+        TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+        TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+        TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        TypeID::Date16 | TypeID::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
+        TypeID::DateTime32 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        TypeID::DateTime64 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+        TypeID::Decimal | TypeID::Decimal256 => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
+        TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Map => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        _ => Err(ErrorCode::UnImplement(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+fn make_column_from_field(field: &DataField) -> Result<Column> {
+    let colflags = match remove_nullable(field.data_type()).data_type_id() {
+        TypeID::UInt8 | TypeID::UInt16 | TypeID::UInt32 | TypeID::UInt64 => {
+            ColumnFlags::UNSIGNED_FLAG
+        }
+        _ => ColumnFlags::empty(),
+    };
+
+    convert_field_type(field).map(|column_type| Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype: column_type,
+        colflags,
+    })
+}
+
+pub fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
+    schema.fields().iter().map(make_column_from_field).collect()
+}
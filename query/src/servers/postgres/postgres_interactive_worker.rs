@@ -0,0 +1,540 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncWrite;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_exception::ABORT_QUERY;
+use common_exception::ABORT_SESSION;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
+use rand::RngCore;
+use tokio_stream::StreamExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::servers::postgres::postgres_message::read_message;
+use crate::servers::postgres::postgres_message::read_startup;
+use crate::servers::postgres::postgres_message::FrontendMessage;
+use crate::servers::postgres::postgres_message::MessageWriter;
+use crate::servers::postgres::postgres_message::StartupMessage;
+use crate::servers::postgres::postgres_types::block_to_text_rows;
+use crate::servers::postgres::postgres_types::convert_schema;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionRef;
+use crate::sql::PlanParser;
+use crate::users::auth::auth_mgr::Credential;
+
+// The version reported to the clients, some of them refuse to work with the old servers.
+const SERVER_VERSION: &str = "14.0";
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    query: String,
+    // The schema is planned by Describe and reused by Execute.
+    schema: Option<DataSchemaRef>,
+}
+
+pub struct InteractiveWorker<S> {
+    session: SessionRef,
+    stream: S,
+    client_addr: String,
+    writer: MessageWriter,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin + Send> InteractiveWorker<S> {
+    pub fn create(session: SessionRef, stream: S, client_addr: String) -> InteractiveWorker<S> {
+        InteractiveWorker {
+            session,
+            stream,
+            client_addr,
+            writer: MessageWriter::create(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    pub async fn run(mut self) -> Result<()> {
+        let params = loop {
+            match read_startup(&mut self.stream).await? {
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    self.writer.encryption_not_supported();
+                    self.writer.flush(&mut self.stream).await?;
+                }
+                // Cancellation of the running queries is not supported.
+                StartupMessage::CancelRequest => return Ok(()),
+                StartupMessage::Startup { params } => break params,
+            }
+        };
+
+        let user = params.get("user").cloned().unwrap_or_default();
+        if let Err(cause) = self.authenticate(&user).await {
+            tracing::error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                user,
+                self.client_addr,
+                cause
+            );
+            let message = format!("password authentication failed for user \"{}\"", user);
+            self.writer.error_response("FATAL", "28P01", &message);
+            return self.writer.flush(&mut self.stream).await;
+        }
+
+        if let Some(database) = params.get("database") {
+            if let Err(cause) = self.do_init(database).await {
+                self.write_error("FATAL", &cause);
+                return self.writer.flush(&mut self.stream).await;
+            }
+        }
+
+        self.writer.authentication_ok();
+        self.writer
+            .parameter_status("server_version", SERVER_VERSION);
+        self.writer.parameter_status("server_encoding", "UTF8");
+        self.writer.parameter_status("client_encoding", "UTF8");
+        self.writer.parameter_status("DateStyle", "ISO, MDY");
+        self.writer.parameter_status("TimeZone", "UTC");
+        self.writer.parameter_status("integer_datetimes", "on");
+        self.writer
+            .parameter_status("standard_conforming_strings", "on");
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            ((rng.next_u32() >> 1) as i32, (rng.next_u32() >> 1) as i32)
+        };
+        self.writer.backend_key_data(process_id, secret_key);
+        self.writer.ready_for_query();
+        self.writer.flush(&mut self.stream).await?;
+
+        self.message_loop().await
+    }
+
+    async fn message_loop(&mut self) -> Result<()> {
+        // After an error in the extended query protocol, discard the messages until Sync.
+        let mut skip_until_sync = false;
+
+        while let Some(message) = read_message(&mut self.stream).await? {
+            if self.session.is_aborting() {
+                let cause = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.write_error("FATAL", &cause);
+                self.writer.flush(&mut self.stream).await?;
+                return Err(cause);
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    self.writer.ready_for_query();
+                    self.writer.flush(&mut self.stream).await?;
+                }
+                FrontendMessage::Flush => self.writer.flush(&mut self.stream).await?,
+                FrontendMessage::Query(query) => {
+                    if let Err(cause) = self.on_query(&query).await {
+                        self.write_error("ERROR", &cause);
+                    }
+                    self.writer.ready_for_query();
+                    self.writer.flush(&mut self.stream).await?;
+                }
+                FrontendMessage::Password(_) => {
+                    return Err(ErrorCode::BadBytes("Unexpected password message"));
+                }
+                _ if skip_until_sync => {}
+                message => {
+                    if let Err(cause) = self.on_extended_message(message).await {
+                        self.write_error("ERROR", &cause);
+                        skip_until_sync = true;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn authenticate(&mut self, user: &str) -> Result<()> {
+        self.writer.authentication_cleartext_password();
+        self.writer.flush(&mut self.stream).await?;
+
+        let password = match read_message(&mut self.stream).await? {
+            Some(FrontendMessage::Password(password)) => password,
+            _ => return Err(ErrorCode::AuthenticateFailure("password required")),
+        };
+
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0];
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password: Some(password),
+            hostname: Some(client_ip.to_string()),
+        };
+        let ctx = self.session.create_query_context().await?;
+        let user_info = ctx
+            .get_auth_manager()
+            .auth(&ctx.get_tenant(), &credential)
+            .await?;
+        self.session.set_current_user(user_info);
+        Ok(())
+    }
+
+    async fn do_init(&mut self, database: &str) -> Result<()> {
+        if database.is_empty() {
+            return Ok(());
+        }
+        let query = format!("USE `{}`", database);
+        self.do_query(&query).await.map(|_| ())
+    }
+
+    async fn on_query(&mut self, query: &str) -> Result<()> {
+        if is_empty_query(query) {
+            self.writer.empty_query_response();
+            return Ok(());
+        }
+
+        let (schema, blocks) = self.do_query(query).await?;
+        if !schema.fields().is_empty() {
+            self.writer.row_description(&convert_schema(&schema));
+        }
+        self.write_rows(query, &schema, blocks)
+    }
+
+    async fn on_extended_message(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                self.writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                ..
+            } => {
+                let statement = self.get_statement(&statement)?;
+                if param_formats.iter().any(|format| *format != 0) {
+                    return Err(ErrorCode::UnImplement(
+                        "Binary format parameters are not supported",
+                    ));
+                }
+
+                let params = params
+                    .into_iter()
+                    .map(|param| param.map(|v| String::from_utf8_lossy(&v).to_string()))
+                    .collect::<Vec<_>>();
+                let query = bind_parameters(&statement.query, &params)?;
+                self.portals.insert(portal, Portal {
+                    query,
+                    schema: None,
+                });
+                self.writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let num_params = count_parameters(&statement.query);
+                // The unspecified types are reported as text.
+                let mut param_types = statement.param_types.clone();
+                param_types.resize(num_params.max(param_types.len()), 0);
+                for param_type in param_types.iter_mut().filter(|t| **t == 0) {
+                    *param_type = TEXT_OID;
+                }
+                // Plan with NULL in place of the parameters to know the result columns.
+                let query = bind_parameters(&statement.query, &vec![None; num_params])?;
+
+                self.writer.parameter_description(&param_types);
+                let schema = self.describe_query(&query).await?;
+                self.write_row_description(&schema);
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let query = self.get_portal(&name)?.query.clone();
+                let schema = self.describe_query(&query).await?;
+                self.write_row_description(&schema);
+                if let Some(portal) = self.portals.get_mut(&name) {
+                    portal.schema = Some(schema);
+                }
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Invalid describe message subtype '{}'",
+                    kind as char
+                )));
+            }
+            FrontendMessage::Execute { portal, .. } => {
+                let portal = self.get_portal(&portal)?;
+                let query = portal.query.clone();
+                let described = portal.schema.clone();
+                if is_empty_query(&query) {
+                    self.writer.empty_query_response();
+                    return Ok(());
+                }
+
+                // All rows are returned at once, the row limit of the portal is ignored.
+                let (schema, blocks) = self.do_query(&query).await?;
+                if described.is_none() && !schema.fields().is_empty() {
+                    self.writer.row_description(&convert_schema(&schema));
+                }
+                self.write_rows(&query, &schema, blocks)?;
+            }
+            FrontendMessage::Close { kind, name } => {
+                // Closing a nonexistent statement or portal is not an error.
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                self.writer.close_complete();
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "Unexpected message in the extended query protocol",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn get_portal(&self, name: &str) -> Result<&Portal> {
+        self.portals
+            .get(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    fn write_row_description(&mut self, schema: &DataSchemaRef) {
+        match schema.fields().is_empty() {
+            true => self.writer.no_data(),
+            false => self.writer.row_description(&convert_schema(schema)),
+        }
+    }
+
+    fn write_rows(
+        &mut self,
+        query: &str,
+        schema: &DataSchemaRef,
+        blocks: Vec<DataBlock>,
+    ) -> Result<()> {
+        let mut rows_size = 0;
+        if !schema.fields().is_empty() {
+            for block in &blocks {
+                for row in block_to_text_rows(block)? {
+                    self.writer.data_row(&row);
+                    rows_size += 1;
+                }
+            }
+        }
+
+        self.writer
+            .command_complete(&command_tag(query, schema, rows_size));
+        Ok(())
+    }
+
+    fn write_error(&mut self, severity: &str, error: &ErrorCode) {
+        if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
+            tracing::error!("OnQuery Error: {:?}", error);
+        }
+        self.writer
+            .error_response(severity, error_sqlstate(error), &error.message());
+    }
+
+    async fn describe_query(&self, query: &str) -> Result<DataSchemaRef> {
+        let context = self.session.create_query_context().await?;
+        let plan = PlanParser::parse(context.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(context, plan)?;
+        Ok(interpreter.schema())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn do_query(&self, query: &str) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let context = self.session.create_query_context().await?;
+        context.attach_query_str(query);
+        let plan = PlanParser::parse(context.clone(), query).await;
+        Self::exec_query(plan, &context).await
+    }
+
+    async fn exec_query(
+        plan: Result<PlanNode>,
+        context: &Arc<QueryContext>,
+    ) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let interpreter = InterpreterFactory::get(context.clone(), plan?)?;
+        let schema = interpreter.schema();
+
+        let query_result = context.try_spawn(
+            async move {
+                // Write start query log.
+                let _ = interpreter
+                    .start()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+                let data_stream = interpreter.execute(None).await?;
+                let collector = data_stream.collect::<Result<Vec<DataBlock>>>();
+                let query_result = collector.await?;
+                // Write finish query log.
+                let _ = interpreter
+                    .finish()
+                    .await
+                    .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
+
+                Ok::<Vec<DataBlock>, ErrorCode>(query_result)
+            }
+            .in_current_span(),
+        )?;
+
+        let query_result = query_result
+            .await
+            .map_err_to_code(ErrorCode::TokioError, || {
+                "Cannot join handle from context's runtime"
+            })?;
+        query_result.map(|blocks| (schema, blocks))
+    }
+}
+
+fn is_empty_query(query: &str) -> bool {
+    query.trim().trim_matches(';').trim().is_empty()
+}
+
+/// The tag of CommandComplete, such as `SELECT 3` or `CREATE`.
+fn command_tag(query: &str, schema: &DataSchemaRef, rows_size: usize) -> String {
+    let command = query
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_matches(';')
+        .to_uppercase();
+
+    match command.as_str() {
+        _ if !schema.fields().is_empty() => format!("SELECT {}", rows_size),
+        "INSERT" => "INSERT 0 0".to_string(),
+        "COPY" => "COPY 0".to_string(),
+        _ => command,
+    }
+}
+
+fn error_sqlstate(error: &ErrorCode) -> &'static str {
+    match error.code() {
+        // SyntaxException
+        1005 => "42601",
+        // UnknownDatabase
+        1003 => "3D000",
+        // UnknownTable
+        1025 => "42P01",
+        // AuthenticateFailure
+        1051 => "28P01",
+        ABORT_QUERY => "57014",
+        ABORT_SESSION => "57P01",
+        _ => "XX000",
+    }
+}
+
+/// Iterate the `$n` placeholders outside of the quoted strings and identifiers,
+/// call `f` with the byte range and the parameter number of each one.
+fn visit_parameters(query: &str, mut f: impl FnMut(usize, usize, usize)) {
+    let bytes = query.as_bytes();
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == b'\'' || c == b'"' || c == b'`' => quote = Some(c),
+            None if c == b'$' => {
+                let start = i;
+                let mut end = i + 1;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+                if end > start + 1 {
+                    if let Ok(n) = query[start + 1..end].parse::<usize>() {
+                        f(start, end, n);
+                    }
+                    i = end;
+                    continue;
+                }
+            }
+            None => {}
+        }
+        i += 1;
+    }
+}
+
+fn count_parameters(query: &str) -> usize {
+    let mut count = 0;
+    visit_parameters(query, |_, _, n| count = count.max(n));
+    count
+}
+
+/// Replace the `$n` placeholders with the parameters as SQL literals.
+fn bind_parameters(query: &str, params: &[Option<String>]) -> Result<String> {
+    let mut placeholders = vec![];
+    visit_parameters(query, |start, end, n| placeholders.push((start, end, n)));
+
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for (start, end, n) in placeholders {
+        let param = match n {
+            0 => None,
+            n => params.get(n - 1),
+        };
+        let literal = match param {
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "there is no parameter ${}",
+                    n
+                )))
+            }
+            Some(None) => "NULL".to_string(),
+            Some(Some(value)) => format!("'{}'", value.replace('\'', "''")),
+        };
+        bound.push_str(&query[last..start]);
+        bound.push_str(&literal);
+        last = end;
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
@@ -0,0 +1,407 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use bytes::Buf;
+use bytes::BufMut;
+use bytes::BytesMut;
+use common_base::tokio::io::AsyncRead;
+use common_base::tokio::io::AsyncReadExt;
+use common_base::tokio::io::AsyncWrite;
+use common_base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Refuse the oversize packet, a sane client never sends messages this large.
+const MAX_MESSAGE_SIZE: usize = 256 * 1024 * 1024;
+
+/// The first packet sent by the client, which has no type byte.
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Startup { params: HashMap<String, String> },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Password(Vec<u8>),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+}
+
+pub async fn read_startup<S: AsyncRead + Unpin>(stream: &mut S) -> Result<StartupMessage> {
+    let len = stream.read_i32().await? as usize;
+    if !(8..=MAX_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid startup message length: {}",
+            len
+        )));
+    }
+
+    let mut buf = vec![0; len - 4];
+    stream.read_exact(&mut buf).await?;
+    let mut buf = BytesMut::from(&buf[..]);
+
+    match buf.get_i32() {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let key = read_cstring(&mut buf)?;
+                if key.is_empty() {
+                    break;
+                }
+                params.insert(key, read_cstring(&mut buf)?);
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        version => Err(ErrorCode::BadBytes(format!(
+            "Unsupported frontend protocol {}.{}",
+            version >> 16,
+            version & 0xFFFF
+        ))),
+    }
+}
+
+/// Read a typed message, return None if the client closed the connection.
+pub async fn read_message<S: AsyncRead + Unpin>(stream: &mut S) -> Result<Option<FrontendMessage>> {
+    let tag = match stream.read_u8().await {
+        Ok(tag) => tag,
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let len = stream.read_i32().await? as usize;
+    if !(4..=MAX_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid message length: {}",
+            len
+        )));
+    }
+
+    let mut buf = vec![0; len - 4];
+    stream.read_exact(&mut buf).await?;
+    let mut buf = BytesMut::from(&buf[..]);
+
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(read_cstring(&mut buf)?),
+        b'p' => {
+            // The password is null-terminated.
+            let mut password = buf.to_vec();
+            if password.last() == Some(&0) {
+                password.pop();
+            }
+            FrontendMessage::Password(password)
+        }
+        b'P' => {
+            let name = read_cstring(&mut buf)?;
+            let query = read_cstring(&mut buf)?;
+            let num_types = read_i16(&mut buf)?;
+            let mut param_types = Vec::with_capacity(num_types.max(0) as usize);
+            for _ in 0..num_types {
+                param_types.push(read_i32(&mut buf)? as u32);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = read_cstring(&mut buf)?;
+            let statement = read_cstring(&mut buf)?;
+
+            let num_formats = read_i16(&mut buf)?;
+            let mut param_formats = Vec::with_capacity(num_formats.max(0) as usize);
+            for _ in 0..num_formats {
+                param_formats.push(read_i16(&mut buf)?);
+            }
+
+            let num_params = read_i16(&mut buf)?;
+            let mut params = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                let len = read_i32(&mut buf)?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    params.push(Some(read_bytes(&mut buf, len as usize)?));
+                }
+            }
+
+            let num_formats = read_i16(&mut buf)?;
+            let mut result_formats = Vec::with_capacity(num_formats.max(0) as usize);
+            for _ in 0..num_formats {
+                result_formats.push(read_i16(&mut buf)?);
+            }
+
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: read_u8(&mut buf)?,
+            name: read_cstring(&mut buf)?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: read_cstring(&mut buf)?,
+            max_rows: read_i32(&mut buf)?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: read_u8(&mut buf)?,
+            name: read_cstring(&mut buf)?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        tag => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported frontend message type '{}'",
+                tag as char
+            )))
+        }
+    };
+
+    Ok(Some(message))
+}
+
+fn check_remaining(buf: &BytesMut, size: usize) -> Result<()> {
+    match buf.remaining() < size {
+        true => Err(ErrorCode::BadBytes("Unexpected end of message")),
+        false => Ok(()),
+    }
+}
+
+fn read_u8(buf: &mut BytesMut) -> Result<u8> {
+    check_remaining(buf, 1)?;
+    Ok(buf.get_u8())
+}
+
+fn read_i16(buf: &mut BytesMut) -> Result<i16> {
+    check_remaining(buf, 2)?;
+    Ok(buf.get_i16())
+}
+
+fn read_i32(buf: &mut BytesMut) -> Result<i32> {
+    check_remaining(buf, 4)?;
+    Ok(buf.get_i32())
+}
+
+fn read_bytes(buf: &mut BytesMut, len: usize) -> Result<Vec<u8>> {
+    check_remaining(buf, len)?;
+    Ok(buf.split_to(len).to_vec())
+}
+
+fn read_cstring(buf: &mut BytesMut) -> Result<String> {
+    match buf.iter().position(|b| *b == 0) {
+        None => Err(ErrorCode::BadBytes("Unterminated string in message")),
+        Some(pos) => {
+            let value = String::from_utf8_lossy(&buf[..pos]).to_string();
+            buf.advance(pos + 1);
+            Ok(value)
+        }
+    }
+}
+
+/// One column of the RowDescription message.
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+}
+
+/// Buffers the backend messages until `flush` is called.
+pub struct MessageWriter {
+    buf: BytesMut,
+}
+
+impl MessageWriter {
+    pub fn create() -> MessageWriter {
+        MessageWriter {
+            buf: BytesMut::with_capacity(8192),
+        }
+    }
+
+    pub async fn flush<S: AsyncWrite + Unpin>(&mut self, stream: &mut S) -> Result<()> {
+        if !self.buf.is_empty() {
+            stream.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        stream.flush().await?;
+        Ok(())
+    }
+
+    fn write_message(&mut self, tag: u8, body: impl FnOnce(&mut BytesMut)) {
+        self.buf.put_u8(tag);
+        let len_pos = self.buf.len();
+        self.buf.put_i32(0);
+        body(&mut self.buf);
+        let len = (self.buf.len() - len_pos) as i32;
+        self.buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    /// Answer to SSLRequest and GSSENCRequest, we don't support the encryption.
+    pub fn encryption_not_supported(&mut self) {
+        self.buf.put_u8(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.write_message(b'R', |buf| buf.put_i32(0));
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        self.write_message(b'R', |buf| buf.put_i32(3));
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.write_message(b'S', |buf| {
+            put_cstring(buf, name);
+            put_cstring(buf, value);
+        });
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.write_message(b'K', |buf| {
+            buf.put_i32(process_id);
+            buf.put_i32(secret_key);
+        });
+    }
+
+    pub fn ready_for_query(&mut self) {
+        // Always idle, there are no transactions.
+        self.write_message(b'Z', |buf| buf.put_u8(b'I'));
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        self.write_message(b'T', |buf| {
+            buf.put_i16(fields.len() as i16);
+            for field in fields {
+                put_cstring(buf, &field.name);
+                // Table oid and column attribute number.
+                buf.put_i32(0);
+                buf.put_i16(0);
+                buf.put_u32(field.type_oid);
+                buf.put_i16(field.type_size);
+                // Type modifier.
+                buf.put_i32(-1);
+                // Text format.
+                buf.put_i16(0);
+            }
+        });
+    }
+
+    pub fn data_row(&mut self, values: &[Option<String>]) {
+        self.write_message(b'D', |buf| {
+            buf.put_i16(values.len() as i16);
+            for value in values {
+                match value {
+                    None => buf.put_i32(-1),
+                    Some(value) => {
+                        buf.put_i32(value.len() as i32);
+                        buf.put_slice(value.as_bytes());
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.write_message(b'C', |buf| put_cstring(buf, tag));
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.write_message(b'I', |_| {});
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.write_message(b'1', |_| {});
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.write_message(b'2', |_| {});
+    }
+
+    pub fn close_complete(&mut self) {
+        self.write_message(b'3', |_| {});
+    }
+
+    pub fn no_data(&mut self) {
+        self.write_message(b'n', |_| {});
+    }
+
+    pub fn parameter_description(&mut self, param_types: &[u32]) {
+        self.write_message(b't', |buf| {
+            buf.put_i16(param_types.len() as i16);
+            for param_type in param_types {
+                buf.put_u32(*param_type);
+            }
+        });
+    }
+
+    pub fn error_response(&mut self, severity: &str, sqlstate: &str, message: &str) {
+        self.write_message(b'E', |buf| {
+            buf.put_u8(b'S');
+            put_cstring(buf, severity);
+            buf.put_u8(b'V');
+            put_cstring(buf, severity);
+            buf.put_u8(b'C');
+            put_cstring(buf, sqlstate);
+            buf.put_u8(b'M');
+            put_cstring(buf, message);
+            buf.put_u8(0);
+        });
+    }
+}
+
+fn put_cstring(buf: &mut BytesMut, value: &str) {
+    buf.put_slice(value.as_bytes());
+    buf.put_u8(0);
+}
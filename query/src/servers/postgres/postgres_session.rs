@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::Shutdown;
+
+use common_base::tokio::net::TcpStream;
+use common_base::Runtime;
+use common_base::Thread;
+use common_base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_tracing::tracing;
+
+use crate::servers::postgres::postgres_interactive_worker::InteractiveWorker;
+use crate::sessions::SessionRef;
+
+pub struct PostgresConnection;
+
+impl PostgresConnection {
+    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+        let std_stream = stream
+            .into_std()
+            .map_err_to_code(ErrorCode::TokioError, || {
+                "Cannot to convert Tokio TcpStream to Std TcpStream"
+            })?;
+        PostgresConnection::attach_session(&session, &std_stream)?;
+
+        let non_blocking_stream = TcpStream::from_std(std_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("postgres-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                let client_addr = non_blocking_stream.peer_addr().unwrap().to_string();
+                let interactive_worker =
+                    InteractiveWorker::create(session, non_blocking_stream, client_addr);
+                if let Err(error) = interactive_worker.run().await {
+                    tracing::error!("PostgreSQL session io error: {:?}", error);
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    fn attach_session(session: &SessionRef, std_stream: &std::net::TcpStream) -> Result<()> {
+        let host = std_stream.peer_addr().ok();
+        let std_stream_ref = std_stream.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = std_stream_ref.shutdown(Shutdown::Both) {
+                tracing::error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::TypeID;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::servers::postgres::postgres_message::FieldDescription;
+
+// Type oids from the PostgreSQL catalog pg_type.
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const NUMERIC_OID: u32 = 1700;
+
+/// Map the field type to the PostgreSQL type oid and type size, -1 is a variable-width type.
+pub fn convert_field_type(field: &DataField) -> (u32, i16) {
+    match remove_nullable(field.data_type()).data_type_id() {
+        TypeID::Boolean => (BOOL_OID, 1),
+        TypeID::Int8 | TypeID::Int16 | TypeID::UInt8 => (INT2_OID, 2),
+        TypeID::Int32 | TypeID::UInt16 => (INT4_OID, 4),
+        TypeID::Int64 | TypeID::UInt32 => (INT8_OID, 8),
+        // UInt64 may overflow the int8.
+        TypeID::UInt64 => (NUMERIC_OID, -1),
+        TypeID::Float32 => (FLOAT4_OID, 4),
+        TypeID::Float64 => (FLOAT8_OID, 8),
+        TypeID::Decimal | TypeID::Decimal256 => (NUMERIC_OID, -1),
+        TypeID::String => (VARCHAR_OID, -1),
+        TypeID::Date16 | TypeID::Date32 => (DATE_OID, 4),
+        TypeID::DateTime32 | TypeID::DateTime64 => (TIMESTAMP_OID, 8),
+        TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => (JSON_OID, -1),
+        _ => (TEXT_OID, -1),
+    }
+}
+
+pub fn convert_schema(schema: &DataSchemaRef) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let (type_oid, type_size) = convert_field_type(field);
+            FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_size,
+            }
+        })
+        .collect()
+}
+
+/// Serialize the block into rows of values in the text format, None is NULL.
+pub fn block_to_text_rows(block: &DataBlock) -> Result<Vec<Vec<Option<String>>>> {
+    let rows_size = block.num_rows();
+    let columns_size = block.num_columns();
+
+    let mut col_table = Vec::with_capacity(columns_size);
+    for col_index in 0..columns_size {
+        let column = block.column(col_index).convert_full_column();
+        let field = block.schema().field(col_index);
+        let data_type = field.data_type();
+        let serializer = data_type.create_serializer();
+        let values = serializer.serialize_column(&column).map_err(|e| {
+            ErrorCode::UnexpectedError(format!(
+                "fail to serialize field {}, error = {}",
+                field.name(),
+                e
+            ))
+        })?;
+
+        let is_boolean = remove_nullable(data_type).data_type_id() == TypeID::Boolean;
+        let values = values
+            .into_iter()
+            .enumerate()
+            .map(|(row, value)| match column.null_at(row) {
+                true => None,
+                false if is_boolean => Some(if value == "1" { "t" } else { "f" }.to_string()),
+                false => Some(value),
+            })
+            .collect::<Vec<_>>();
+        col_table.push(values);
+    }
+
+    let mut rows = Vec::with_capacity(rows_size);
+    for row_index in 0..rows_size {
+        rows.push(
+            col_table
+                .iter_mut()
+                .map(|col| col[row_index].take())
+                .collect(),
+        );
+    }
+    Ok(rows)
+}
@@ -0,0 +1,45 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio::net::TcpStream;
+use common_exception::Result;
+
+use crate::servers::postgres::postgres_message::read_startup;
+use crate::servers::postgres::postgres_message::MessageWriter;
+use crate::servers::postgres::postgres_message::StartupMessage;
+
+pub struct RejectConnection;
+
+impl RejectConnection {
+    pub async fn reject_postgres_connection(
+        mut stream: TcpStream,
+        sqlstate: &str,
+        error_message: impl Into<String>,
+    ) -> Result<()> {
+        let mut writer = MessageWriter::create();
+        loop {
+            match read_startup(&mut stream).await? {
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    writer.encryption_not_supported();
+                    writer.flush(&mut stream).await?;
+                }
+                StartupMessage::CancelRequest => return Ok(()),
+                StartupMessage::Startup { .. } => break,
+            }
+        }
+
+        writer.error_response("FATAL", sqlstate, &error_message.into());
+        writer.flush(&mut stream).await
+    }
+}
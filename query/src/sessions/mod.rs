@@ -15,6 +15,9 @@
 mod metrics;
 mod query_ctx;
 mod query_ctx_shared;
+mod query_profiles;
+mod query_queue;
+mod query_result_cache;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -28,12 +31,21 @@ mod session_type;
 
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_profiles::QueryProfile;
+pub use query_profiles::QueryProfiles;
+pub use query_queue::QueryPermit;
+pub use query_queue::QueryQueue;
+pub use query_result_cache::QueryResult;
+pub use query_result_cache::QueryResultCache;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
+pub use session_info::QueryMemoryUsage;
+pub use session_info::QueryProgress;
 pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_ref::SessionRef;
 pub use session_settings::Settings;
+pub use session_settings::SECRET_SETTINGS;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
@@ -18,7 +18,9 @@ use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::tokio;
 use common_base::tokio::task::JoinHandle;
 use common_base::Progress;
 use common_base::ProgressValues;
@@ -41,6 +43,8 @@ use common_planners::S3StageTableInfo;
 use common_planners::SourceInfo;
 use common_planners::Statistics;
 use common_streams::AbortStream;
+use common_streams::MemoryLimitStream;
+use common_streams::RejectedRecords;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use opendal::Operator;
@@ -48,10 +52,15 @@ use opendal::Operator;
 use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
+use crate::clusters::ClusterDiscovery;
 use crate::configs::Config;
+use crate::pipelines::new::executor::ProcessorProfile;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryMemoryUsage;
+use crate::sessions::QueryProfile;
+use crate::sessions::QueryResultCache;
 use crate::sessions::Session;
 use crate::sessions::SessionRef;
 use crate::sessions::Settings;
@@ -111,6 +120,10 @@ impl QueryContext {
         table_info: &TableInfo,
         table_args: Option<Vec<Expression>>,
     ) -> Result<Arc<dyn Table>> {
+        if let Some(table) = self.shared.get_external_table(table_info.ident.table_id) {
+            return Ok(table);
+        }
+
         let catalog = self.get_catalog();
 
         if table_args.is_none() {
@@ -169,6 +182,10 @@ impl QueryContext {
                 }
             }
         }
+        self.shared
+            .dal_ctx
+            .get_metrics()
+            .inc_partitions_read(partitions.len() as u64);
         Ok(partitions)
     }
 
@@ -205,10 +222,19 @@ impl QueryContext {
         self.shared.attach_query_plan(query_plan);
     }
 
+    /// Set the stage the query is running, it's listed in system.processes.
+    pub fn set_current_stage(&self, stage: &str) {
+        self.shared.set_current_stage(stage);
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.shared.get_cluster()
     }
 
+    pub fn get_cluster_discovery(&self) -> Arc<ClusterDiscovery> {
+        self.shared.session.session_mgr.get_cluster_discovery()
+    }
+
     pub fn get_catalog(&self) -> Arc<DatabaseCatalog> {
         self.shared.get_catalog()
     }
@@ -224,16 +250,101 @@ impl QueryContext {
         self.shared.get_table(database, table).await
     }
 
+    // Register a table sent along with the query, e.g. the external data of the ClickHouse handler.
+    pub fn add_external_table(&self, table: Arc<dyn Table>) -> Result<()> {
+        self.shared.add_external_table(table)
+    }
+
+    pub fn is_external_table(&self, table_id: u64) -> bool {
+        self.shared.get_external_table(table_id).is_some()
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
 
     pub fn try_create_abortable(&self, input: SendableDataBlockStream) -> Result<AbortStream> {
-        let (abort_handle, abort_stream) = AbortStream::try_create(input)?;
+        let input = self.try_limit_memory(input)?;
+        let reason = self.shared.kill_reason.clone();
+        let (abort_handle, abort_stream) = AbortStream::try_create_with_reason(input, reason)?;
         self.shared.add_source_abort_handle(abort_handle);
         Ok(abort_stream)
     }
 
+    // The allocations of the runtime of the query are checked against the max_memory_usage, see
+    // `QueryContextShared::try_get_runtime`, the stream fails once one of them exceeded it, so the
+    // query fails before it takes down the whole node.
+    fn try_limit_memory(&self, input: SendableDataBlockStream) -> Result<SendableDataBlockStream> {
+        if self.get_settings().get_max_memory_usage()? == 0 {
+            return Ok(input);
+        }
+
+        let tracker = self.shared.try_get_runtime()?.get_tracker();
+        Ok(Box::pin(MemoryLimitStream::try_create(input, tracker)?))
+    }
+
+    /// Execute the statement under the statement_timeout_ms, the timer starts with the execution
+    /// of the statement, so the time waiting in the query queue is counted. The execution fails
+    /// once the timer expires before it returns the stream, then all the streams of the query
+    /// are killed.
+    pub async fn execute_with_statement_timeout<F>(
+        &self,
+        execute: F,
+    ) -> Result<SendableDataBlockStream>
+    where
+        F: Future<Output = Result<SendableDataBlockStream>>,
+    {
+        let timeout_ms = self.get_settings().get_statement_timeout_ms()?;
+        if timeout_ms == 0
+            || self
+                .shared
+                .statement_timer_started
+                .swap(true, Ordering::SeqCst)
+        {
+            return execute.await;
+        }
+
+        let shared = Arc::downgrade(&self.shared);
+        self.try_spawn(async move {
+            tokio::time::sleep(Duration::from_millis(timeout_ms)).await;
+            if let Some(shared) = shared.upgrade() {
+                tracing::warn!("Statement timeout after {}ms, kill the query", timeout_ms);
+                shared.kill_with_reason(statement_timeout_error(timeout_ms));
+            }
+        })?;
+
+        match tokio::time::timeout(Duration::from_millis(timeout_ms), execute).await {
+            Ok(stream) => stream,
+            Err(_) => Err(statement_timeout_error(timeout_ms)),
+        }
+    }
+
+    /// Wait in the query queue until the query is allowed to run, the permit is held until the
+    /// query context is destroyed. The sub-queries share the permit of the query.
+    pub async fn acquire_query_permit(&self) -> Result<()> {
+        if self.shared.query_permit.lock().is_some() {
+            return Ok(());
+        }
+
+        let session = &self.shared.session;
+        let user = self.get_current_user()?.name;
+        let queue = session.get_session_manager().get_query_queue();
+        let permit = queue.acquire(&user, &session.get_id()).await?;
+        self.shared.attach_query_permit(permit);
+        Ok(())
+    }
+
+    /// Override the settings for this query only, e.g. by the hint
+    /// `/*+ SET_VAR(max_threads = 4) */`, the session settings are restored once it finishes.
+    pub fn set_query_settings(&self, settings: &[(String, String)]) -> Result<()> {
+        self.shared.set_query_settings(settings)
+    }
+
+    /// The reason of the killed query, None if it is killed by the user or the server.
+    pub fn get_kill_reason(&self) -> Option<ErrorCode> {
+        self.shared.get_kill_reason()
+    }
+
     pub fn get_current_database(&self) -> String {
         self.shared.get_current_database()
     }
@@ -273,6 +384,11 @@ impl QueryContext {
         self.shared.get_format_settings()
     }
 
+    /// Get the collector of the records rejected by the loads.
+    pub fn get_rejected_records(&self) -> RejectedRecords {
+        self.shared.rejected_records.clone()
+    }
+
     pub fn get_config(&self) -> Config {
         self.shared.get_config()
     }
@@ -313,6 +429,14 @@ impl QueryContext {
             .await
     }
 
+    pub async fn get_session_by_query_id(self: &Arc<Self>, query_id: &str) -> Option<SessionRef> {
+        self.shared
+            .session
+            .get_session_manager()
+            .get_session_by_query_id(query_id)
+            .await
+    }
+
     // Get all the processes list info.
     pub async fn get_processes_info(self: &Arc<Self>) -> Vec<ProcessInfo> {
         self.shared
@@ -322,11 +446,41 @@ impl QueryContext {
             .await
     }
 
+    pub async fn get_queries_memory_usage(self: &Arc<Self>) -> Vec<QueryMemoryUsage> {
+        self.shared
+            .session
+            .get_session_manager()
+            .queries_memory_usage()
+            .await
+    }
+
+    /// Record the processor profiles of the query, they are listed in system.query_profile.
+    pub fn record_query_profiles(&self, processors: Vec<ProcessorProfile>) {
+        self.shared.session.session_mgr.get_query_profiles().record(
+            &self.get_tenant(),
+            &self.get_id(),
+            processors,
+        )
+    }
+
+    /// Get the recorded query profiles of the current tenant.
+    pub fn get_query_profiles(&self) -> Vec<QueryProfile> {
+        self.shared
+            .session
+            .session_mgr
+            .get_query_profiles()
+            .get_profiles(&self.get_tenant())
+    }
+
     /// Get the data accessor metrics.
     pub fn get_dal_metrics(&self) -> DalMetrics {
         self.shared.dal_ctx.get_metrics().as_ref().clone()
     }
 
+    pub fn get_peak_memory_usage(&self) -> u64 {
+        self.shared.get_peak_memory_usage()
+    }
+
     /// Get the session running query.
     pub fn get_query_str(&self) -> String {
         self.shared.get_query_str()
@@ -337,6 +491,11 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// Get the query result cache of the node.
+    pub fn get_query_result_cache(&self) -> Arc<QueryResultCache> {
+        self.shared.session.session_mgr.get_query_result_cache()
+    }
+
     /// Get the storage cache manager
     pub fn get_storage_cache_manager(&self) -> Arc<CacheManager> {
         self.shared.session.session_mgr.get_storage_cache_manager()
@@ -364,6 +523,19 @@ impl QueryContext {
     pub fn get_query_logger(&self) -> Option<Arc<dyn tracing::Subscriber + Send + Sync>> {
         self.shared.session.session_mgr.get_query_logger()
     }
+
+    pub fn get_audit_logger(&self) -> Option<Arc<dyn tracing::Subscriber + Send + Sync>> {
+        self.shared.session.session_mgr.get_audit_logger()
+    }
+
+    pub fn get_slow_query_logger(&self) -> Arc<dyn tracing::Subscriber + Send + Sync> {
+        self.shared.session.session_mgr.get_slow_query_logger()
+    }
+
+    /// The tables referred by the query so far, as (database, table).
+    pub fn get_referred_tables(&self) -> Vec<(String, String)> {
+        self.shared.get_referred_tables()
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -395,6 +567,8 @@ impl QueryContextShared {
         if self.ref_count.fetch_sub(1, Ordering::Release) == 1 {
             std::sync::atomic::fence(Acquire);
             tracing::debug!("Destroy QueryContext");
+            self.release_query_permit();
+            self.restore_query_settings();
             self.session.destroy_context_shared();
         }
     }
@@ -403,3 +577,10 @@ impl QueryContextShared {
         self.ref_count.fetch_add(1, Ordering::Relaxed);
     }
 }
+
+fn statement_timeout_error(timeout_ms: u64) -> ErrorCode {
+    ErrorCode::StatementTimeout(format!(
+        "Query exceeded the statement timeout of {}ms",
+        timeout_ms
+    ))
+}
@@ -14,19 +14,24 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use common_base::Progress;
 use common_base::Runtime;
+use common_base::RuntimeTracker;
 use common_contexts::DalContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_infallible::RwLock;
+use common_io::prelude::Compression;
 use common_io::prelude::FormatSettings;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
+use common_streams::RejectedRecords;
 use futures::future::AbortHandle;
 use uuid::Uuid;
 
@@ -35,6 +40,8 @@ use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::Config;
 use crate::servers::http::v1::HttpQueryHandle;
+use crate::sessions::session_settings::SettingValue;
+use crate::sessions::QueryPermit;
 use crate::sessions::Session;
 use crate::sessions::Settings;
 use crate::storages::Table;
@@ -65,16 +72,28 @@ pub struct QueryContextShared {
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) sources_abort_handle: Arc<RwLock<Vec<AbortHandle>>>,
+    /// The error returned by the aborted streams, e.g. the statement timeout.
+    pub(in crate::sessions) kill_reason: Arc<RwLock<Option<ErrorCode>>>,
+    pub(in crate::sessions) statement_timer_started: AtomicBool,
+    /// The permit of the query queue, released when the query finishes.
+    pub(in crate::sessions) query_permit: Mutex<Option<QueryPermit>>,
+    /// The session settings overridden by the query, restored when the query finishes.
+    pub(in crate::sessions) query_settings_backup: Mutex<Vec<(String, SettingValue)>>,
     pub(in crate::sessions) ref_count: Arc<AtomicUsize>,
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
+    pub(in crate::sessions) current_stage: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
+    /// The tables sent along with the query, by table id.
+    pub(in crate::sessions) external_tables: Arc<RwLock<HashMap<u64, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
     pub(in crate::sessions) user_manager: Arc<UserApiProvider>,
     pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
     pub(in crate::sessions) role_cache_manager: Arc<RoleCacheMgr>,
+    /// The malformed records rejected by the loads of the query.
+    pub(in crate::sessions) rejected_records: RejectedRecords,
 }
 
 impl QueryContextShared {
@@ -93,16 +112,23 @@ impl QueryContextShared {
             write_progress: Arc::new(Progress::create()),
             runtime: Arc::new(RwLock::new(None)),
             sources_abort_handle: Arc::new(RwLock::new(Vec::new())),
+            kill_reason: Arc::new(RwLock::new(None)),
+            statement_timer_started: AtomicBool::new(false),
+            query_permit: Mutex::new(None),
+            query_settings_backup: Mutex::new(vec![]),
             ref_count: Arc::new(AtomicUsize::new(0)),
             subquery_index: Arc::new(AtomicUsize::new(1)),
             running_query: Arc::new(RwLock::new(None)),
+            current_stage: Arc::new(RwLock::new(None)),
             http_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
+            external_tables: Arc::new(RwLock::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
             user_manager: user_manager.clone(),
             auth_manager: Arc::new(AuthMgr::create(conf, user_manager.clone()).await?),
             role_cache_manager: Arc::new(RoleCacheMgr::new(user_manager)),
+            rejected_records: RejectedRecords::create(),
         }))
     }
 
@@ -121,6 +147,49 @@ impl QueryContextShared {
         // TODO: Wait for the query to be processed (write out the last error)
     }
 
+    /// Kill the query, the aborted streams return the reason instead of the aborted error.
+    pub fn kill_with_reason(&self, reason: ErrorCode) {
+        {
+            let mut kill_reason = self.kill_reason.write();
+            if kill_reason.is_none() {
+                *kill_reason = Some(reason);
+            }
+        }
+        self.kill();
+    }
+
+    pub fn get_kill_reason(&self) -> Option<ErrorCode> {
+        self.kill_reason.read().clone()
+    }
+
+    pub fn attach_query_permit(&self, permit: QueryPermit) {
+        *self.query_permit.lock() = Some(permit);
+    }
+
+    pub(in crate::sessions) fn release_query_permit(&self) {
+        self.query_permit.lock().take();
+    }
+
+    pub fn set_query_settings(&self, settings: &[(String, String)]) -> Result<()> {
+        let session_settings = self.get_settings();
+        for (name, value) in settings {
+            let backup = session_settings.backup_setting(name)?;
+            session_settings.set_settings(name.clone(), value.clone(), false)?;
+            self.query_settings_backup
+                .lock()
+                .push((name.clone(), backup));
+        }
+        Ok(())
+    }
+
+    pub(in crate::sessions) fn restore_query_settings(&self) {
+        let session_settings = self.get_settings();
+        let mut backup = self.query_settings_backup.lock();
+        while let Some((name, value)) = backup.pop() {
+            session_settings.restore_setting(&name, value);
+        }
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.cluster_cache.clone()
     }
@@ -177,6 +246,33 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_referred_tables(&self) -> Vec<(String, String)> {
+        let mut tables = self.tables_refs.lock().keys().cloned().collect::<Vec<_>>();
+        tables.sort();
+        tables
+    }
+
+    /// Register a table which only lives in the query, in the current database.
+    pub fn add_external_table(&self, table: Arc<dyn Table>) -> Result<()> {
+        let table_meta_key = (self.get_current_database(), table.name().to_string());
+        match self.tables_refs.lock().entry(table_meta_key) {
+            Entry::Occupied(_) => Err(ErrorCode::TableAlreadyExists(format!(
+                "External table {} already exists",
+                table.name()
+            ))),
+            Entry::Vacant(entry) => {
+                let table_id = table.get_id();
+                entry.insert(table.clone());
+                self.external_tables.write().insert(table_id, table);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get_external_table(&self, table_id: u64) -> Option<Arc<dyn Table>> {
+        self.external_tables.read().get(&table_id).cloned()
+    }
+
     async fn get_table_to_cache(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let catalog = self.get_catalog();
@@ -204,12 +300,35 @@ impl QueryContextShared {
                     max_threads,
                     Some("query-ctx".to_string()),
                 )?);
+                // The allocations of the query are checked against the max_memory_usage.
+                let max_memory_usage = settings.get_max_memory_usage()?;
+                let limit = i64::try_from(max_memory_usage).unwrap_or(i64::MAX);
+                runtime.get_tracker().get_memory_tracker().set_limit(limit);
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
         }
     }
 
+    /// The tracker of the runtime of the query, None if the runtime is not created.
+    pub fn get_runtime_tracker(&self) -> Option<Arc<RuntimeTracker>> {
+        self.runtime
+            .read()
+            .as_ref()
+            .map(|runtime| runtime.get_tracker())
+    }
+
+    /// The peak memory tracked by the runtime of the query, 0 if the runtime is not created.
+    pub fn get_peak_memory_usage(&self) -> u64 {
+        match &*self.runtime.read() {
+            Some(runtime) => {
+                let tracker = runtime.get_tracker();
+                tracker.get_memory_tracker().get_peak_memory_usage().max(0) as u64
+            }
+            None => 0,
+        }
+    }
+
     pub fn attach_http_query_handle(&self, handle: HttpQueryHandle) {
         let mut http_query = self.http_query.write();
         *http_query = Some(handle);
@@ -225,12 +344,27 @@ impl QueryContextShared {
         running_query.as_ref().unwrap_or(&"".to_string()).clone()
     }
 
+    pub fn set_current_stage(&self, stage: &str) {
+        let mut current_stage = self.current_stage.write();
+        *current_stage = Some(stage.to_string());
+    }
+
+    pub fn get_current_stage(&self) -> Option<String> {
+        self.current_stage.read().clone()
+    }
+
     pub fn attach_query_plan(&self, plan: &PlanNode) {
         let mut running_plan = self.running_plan.write();
         *running_plan = Some(plan.clone());
     }
 
     pub fn add_source_abort_handle(&self, handle: AbortHandle) {
+        // The streams created after the query is killed, e.g. by the statement timeout, are
+        // aborted at once.
+        if self.kill_reason.read().is_some() {
+            handle.abort();
+        }
+
         let mut sources_abort_handle = self.sources_abort_handle.write();
         sources_abort_handle.push(handle);
     }
@@ -246,7 +380,12 @@ impl QueryContextShared {
             format.record_delimiter = settings.get_record_delimiter()?;
             format.field_delimiter = settings.get_field_delimiter()?;
             format.empty_as_default = settings.get_empty_as_default()? > 0;
-            format.skip_header = settings.get_skip_header()? > 0;
+            format.error_as_null = settings.get_error_as_null()? > 0;
+            format.skip_header = settings.get_skip_header()?;
+            format.json_strict = settings.get_json_strict()? > 0;
+
+            let compression = String::from_utf8_lossy(&settings.get_compression()?).to_string();
+            format.compression = Compression::from_str(&compression)?;
         }
         Ok(format)
     }
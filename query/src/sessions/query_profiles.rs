@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_infallible::RwLock;
+
+use crate::configs::Config;
+use crate::pipelines::new::executor::ProcessorProfile;
+
+/// The processor profiles of a query.
+#[derive(Clone, Debug)]
+pub struct QueryProfile {
+    pub tenant: String,
+    pub query_id: String,
+    pub processors: Vec<ProcessorProfile>,
+}
+
+/// The profiles of the latest queries executed by the processors, they are kept like the query
+/// log: at most `max_query_log_size` queries within the `log_query_retention_hours`.
+pub struct QueryProfiles {
+    max_queries: usize,
+    retention: Option<Duration>,
+    // The profiles with the time they are recorded.
+    profiles: RwLock<VecDeque<(Instant, QueryProfile)>>,
+}
+
+impl QueryProfiles {
+    pub fn create(conf: &Config) -> Arc<QueryProfiles> {
+        Arc::new(QueryProfiles {
+            max_queries: conf.query.max_query_log_size,
+            retention: match conf.log.log_query_retention_hours {
+                0 => None,
+                hours => Some(Duration::from_secs(hours * 60 * 60)),
+            },
+            profiles: RwLock::new(VecDeque::new()),
+        })
+    }
+
+    /// Record the profiles of a query, the profiles of the query recorded before (e.g. by the
+    /// sub-queries) are extended.
+    pub fn record(&self, tenant: &str, query_id: &str, processors: Vec<ProcessorProfile>) {
+        if self.max_queries == 0 || processors.is_empty() {
+            return;
+        }
+
+        let mut profiles = self.profiles.write();
+        let recorded = profiles
+            .iter_mut()
+            .find(|(_, profile)| profile.query_id == query_id && profile.tenant == tenant);
+        match recorded {
+            Some((_, profile)) => profile.processors.extend(processors),
+            None => profiles.push_back((Instant::now(), QueryProfile {
+                tenant: tenant.to_string(),
+                query_id: query_id.to_string(),
+                processors,
+            })),
+        }
+
+        while profiles.len() > self.max_queries {
+            profiles.pop_front();
+        }
+    }
+
+    /// The profiles of the tenant, the ones out of the retention are removed.
+    pub fn get_profiles(&self, tenant: &str) -> Vec<QueryProfile> {
+        let mut profiles = self.profiles.write();
+        if let Some(retention) = self.retention {
+            while matches!(profiles.front(), Some((recorded, _)) if recorded.elapsed() > retention)
+            {
+                profiles.pop_front();
+            }
+        }
+
+        profiles
+            .iter()
+            .filter(|(_, profile)| profile.tenant == tenant)
+            .map(|(_, profile)| profile.clone())
+            .collect()
+    }
+}
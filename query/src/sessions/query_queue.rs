@@ -0,0 +1,190 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::oneshot;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+
+use crate::configs::Config;
+
+/// The admission control of the queries.
+///
+/// At most `max_running_queries` queries (and `max_running_queries_per_user` of a user) run at
+/// the same time, the others wait in a FIFO queue for `query_queue_timeout_secs`. A query
+/// blocked only by the limit of its user does not block the queries of the other users.
+pub struct QueryQueue {
+    max_running: usize,
+    max_running_per_user: usize,
+    timeout: Duration,
+    state: Mutex<QueueState>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    next_ticket: u64,
+    running: usize,
+    running_per_user: HashMap<String, usize>,
+    waiting: VecDeque<QueuedQuery>,
+}
+
+struct QueuedQuery {
+    ticket: u64,
+    user: String,
+    session_id: String,
+    notify: oneshot::Sender<()>,
+}
+
+/// The query holds the permit while it is running, the next query of the queue runs once it is
+/// dropped.
+pub struct QueryPermit {
+    queue: Arc<QueryQueue>,
+    user: String,
+}
+
+impl Drop for QueryPermit {
+    fn drop(&mut self) {
+        self.queue.release(&self.user);
+    }
+}
+
+impl QueryQueue {
+    pub fn create(conf: &Config) -> Arc<QueryQueue> {
+        Arc::new(QueryQueue {
+            max_running: conf.query.max_running_queries as usize,
+            max_running_per_user: conf.query.max_running_queries_per_user as usize,
+            timeout: Duration::from_secs(conf.query.query_queue_timeout_secs),
+            state: Mutex::new(QueueState::default()),
+        })
+    }
+
+    /// Wait until the query of the user is allowed to run.
+    pub async fn acquire(self: &Arc<Self>, user: &str, session_id: &str) -> Result<QueryPermit> {
+        let (ticket, mut notified) = {
+            let mut state = self.state.lock();
+            if self.can_run(&state, user) {
+                state.start(user);
+                return Ok(self.permit(user));
+            }
+
+            let (tx, rx) = oneshot::channel();
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiting.push_back(QueuedQuery {
+                ticket,
+                user: user.to_string(),
+                session_id: session_id.to_string(),
+                notify: tx,
+            });
+            (ticket, rx)
+        };
+
+        if let Ok(Ok(_)) = tokio::time::timeout(self.timeout, &mut notified).await {
+            return Ok(self.permit(user));
+        }
+
+        // The query may be started right after the timeout, the receiver is still alive so it is
+        // counted as running.
+        let mut state = self.state.lock();
+        match state
+            .waiting
+            .iter()
+            .position(|query| query.ticket == ticket)
+        {
+            None => Ok(self.permit(user)),
+            Some(position) => {
+                state.waiting.remove(position);
+                Err(ErrorCode::QueryQueueTimeout(format!(
+                    "Query waited in the queue for more than {} seconds, there are {} queries running",
+                    self.timeout.as_secs(),
+                    state.running
+                )))
+            }
+        }
+    }
+
+    pub fn is_queued(&self, session_id: &str) -> bool {
+        let state = self.state.lock();
+        state
+            .waiting
+            .iter()
+            .any(|query| query.session_id == session_id)
+    }
+
+    pub fn running_queries(&self) -> usize {
+        self.state.lock().running
+    }
+
+    pub fn queued_queries(&self) -> usize {
+        self.state.lock().waiting.len()
+    }
+
+    fn permit(self: &Arc<Self>, user: &str) -> QueryPermit {
+        QueryPermit {
+            queue: self.clone(),
+            user: user.to_string(),
+        }
+    }
+
+    fn can_run(&self, state: &QueueState, user: &str) -> bool {
+        let running_of_user = state.running_per_user.get(user).copied().unwrap_or(0);
+        (self.max_running == 0 || state.running < self.max_running)
+            && (self.max_running_per_user == 0 || running_of_user < self.max_running_per_user)
+    }
+
+    fn release(&self, user: &str) {
+        let mut state = self.state.lock();
+        state.finish(user);
+
+        // Start the queries in the order of the queue, skip the ones blocked by their users.
+        let mut position = 0;
+        while position < state.waiting.len() {
+            if !self.can_run(&state, &state.waiting[position].user) {
+                position += 1;
+                continue;
+            }
+
+            if let Some(query) = state.waiting.remove(position) {
+                state.start(&query.user);
+                // The waiter is gone, e.g. the connection is closed.
+                if query.notify.send(()).is_err() {
+                    state.finish(&query.user);
+                }
+            }
+        }
+    }
+}
+
+impl QueueState {
+    fn start(&mut self, user: &str) {
+        self.running += 1;
+        *self.running_per_user.entry(user.to_string()).or_insert(0) += 1;
+    }
+
+    fn finish(&mut self, user: &str) {
+        self.running = self.running.saturating_sub(1);
+        if let Some(running) = self.running_per_user.get_mut(user) {
+            *running -= 1;
+            if *running == 0 {
+                self.running_per_user.remove(user);
+            }
+        }
+    }
+}
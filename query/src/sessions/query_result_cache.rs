@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+use common_infallible::Mutex;
+
+use crate::configs::Config;
+
+/// The result set of a query in the query result cache.
+pub struct QueryResult {
+    pub blocks: Vec<DataBlock>,
+    pub bytes: usize,
+    cached_at: Instant,
+}
+
+struct QueryResultMeter;
+
+impl Meter<String, Arc<QueryResult>> for QueryResultMeter {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<QueryResult>) -> usize
+    where String: Borrow<Q> {
+        v.bytes
+    }
+}
+
+/// The result sets of the latest queries on the node, keyed by the query text and the snapshots
+/// of the tables read by the query, see `InterpreterQueryResultCache`.
+///
+/// The results are evicted by LRU once the results exceed the `query_result_cache_max_bytes` of
+/// the config, and are dropped once they are older than the ttl of the query reading them.
+pub struct QueryResultCache {
+    max_bytes: u64,
+    results: Mutex<LruCache<String, Arc<QueryResult>, DefaultHashBuilder, QueryResultMeter>>,
+}
+
+impl QueryResultCache {
+    pub fn create(conf: &Config) -> Arc<QueryResultCache> {
+        let max_bytes = conf.query.query_result_cache_max_bytes;
+        Arc::new(QueryResultCache {
+            max_bytes,
+            results: Mutex::new(LruCache::with_meter(max_bytes, QueryResultMeter)),
+        })
+    }
+
+    /// The capacity of the cache, the larger results are not cached.
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// The result of the key cached within the ttl.
+    pub fn get(&self, key: &str, ttl: Duration) -> Option<Arc<QueryResult>> {
+        let mut results = self.results.lock();
+        match results.get(key).cloned() {
+            Some(result) if result.cached_at.elapsed() <= ttl => Some(result),
+            Some(_) => {
+                results.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache the result of the key, nothing is cached if the result itself exceeds the capacity.
+    pub fn put(&self, key: String, blocks: Vec<DataBlock>) {
+        let bytes = blocks.iter().map(DataBlock::memory_size).sum::<usize>();
+        if bytes as u64 > self.max_bytes {
+            return;
+        }
+
+        let mut results = self.results.lock();
+        results.put(
+            key,
+            Arc::new(QueryResult {
+                blocks,
+                bytes,
+                cached_at: Instant::now(),
+            }),
+        );
+    }
+
+    /// The number of the results and their bytes.
+    pub fn get_usage(&self) -> (usize, u64) {
+        let results = self.results.lock();
+        (results.len(), results.size())
+    }
+}
@@ -24,6 +24,7 @@ use common_mem_allocator::malloc_size;
 use common_meta_types::GrantObject;
 use common_meta_types::UserInfo;
 use common_meta_types::UserPrivilegeType;
+use common_tracing::tracing;
 use futures::channel::*;
 use opendal::Operator;
 
@@ -62,6 +63,7 @@ impl Session {
         let session_ctx = Arc::new(SessionContext::try_create(conf.clone())?);
         let session_settings =
             Settings::try_create(&conf, session_ctx.clone(), session_mgr.get_user_manager())?;
+        session_settings.load_global_settings().await?;
         let ref_count = Arc::new(AtomicUsize::new(0));
         let status = Arc::new(Default::default());
 
@@ -116,6 +118,12 @@ impl Session {
         }
     }
 
+    pub fn get_running_query_id(&self) -> Option<String> {
+        self.session_ctx
+            .get_query_context_shared()
+            .map(|shared| shared.init_query_id.read().clone())
+    }
+
     /// Create a query context for query.
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
@@ -179,6 +187,16 @@ impl Session {
         self.session_ctx.get_tenant()
     }
 
+    /// Bind the session to a tenant before the user is authenticated, the users, databases and
+    /// the GLOBAL settings of the session are all scoped by the tenant.
+    pub async fn set_current_tenant(self: &Arc<Self>, tenant: String) -> Result<()> {
+        if self.session_ctx.get_tenant() != tenant {
+            self.session_ctx.set_current_tenant(tenant);
+            self.session_settings.load_global_settings().await?;
+        }
+        Ok(())
+    }
+
     pub fn get_current_user(self: &Arc<Self>) -> Result<UserInfo> {
         self.session_ctx
             .get_current_user()
@@ -186,18 +204,110 @@ impl Session {
     }
 
     pub fn set_current_user(self: &Arc<Self>, user: UserInfo) {
+        // The defaults of the user are applied once, the session may have changed them later.
+        if self.session_ctx.get_current_user().is_none() {
+            self.apply_user_defaults(&user);
+        }
         self.session_ctx.set_current_user(user)
     }
 
+    // Apply the default database and settings of the user to the new session, the invalid
+    // ones (e.g. the dropped database) are skipped so the user can still login.
+    fn apply_user_defaults(self: &Arc<Self>, user: &UserInfo) {
+        if let Some(database) = user.option.default_database() {
+            self.set_current_database(database.clone());
+        }
+
+        let settings = self.get_settings();
+        for (name, value) in user.option.default_settings() {
+            if let Err(cause) = settings.set_settings(name.clone(), value.clone(), false) {
+                tracing::warn!(
+                    "Cannot apply the default setting {} of the user {}: {}",
+                    name,
+                    user.name,
+                    cause
+                );
+            }
+        }
+    }
+
+    pub fn get_current_role(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx.get_current_role()
+    }
+
+    pub fn set_current_role(self: &Arc<Self>, role: Option<String>) {
+        self.session_ctx.set_current_role(role)
+    }
+
+    // The roles to check the privileges with: the role activated by SET ROLE, the default
+    // role if the user has it, otherwise all the granted roles.
+    fn active_roles(self: &Arc<Self>, user: &UserInfo) -> Vec<String> {
+        if let Some(role) = self.get_current_role() {
+            return vec![role];
+        }
+
+        let roles = user.grants.roles();
+        match user.option.default_role() {
+            Some(role) if roles.contains(role) => vec![role.clone()],
+            _ => roles,
+        }
+    }
+
+    pub fn get_active_roles(self: &Arc<Self>) -> Result<Vec<String>> {
+        let user = self.get_current_user()?;
+        Ok(self.active_roles(&user))
+    }
+
     pub async fn validate_privilege(
         self: &Arc<Self>,
         object: &GrantObject,
         privilege: UserPrivilegeType,
     ) -> Result<()> {
+        if self.has_privilege(object, privilege).await? {
+            return Ok(());
+        }
+
+        let current_user = self.get_current_user()?;
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, user '{}'@'{}' requires {} privilege on {}",
+            &current_user.name, &current_user.hostname, privilege, object
+        )))
+    }
+
+    /// Validate the privilege on the columns of a table, it is granted on the table or on each
+    /// of the columns.
+    pub async fn validate_columns_privilege(
+        self: &Arc<Self>,
+        database: &str,
+        table: &str,
+        columns: &[String],
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let table_object = GrantObject::Table(database.to_string(), table.to_string());
+        if self.has_privilege(&table_object, privilege).await? {
+            return Ok(());
+        }
+        if columns.is_empty() {
+            return self.validate_privilege(&table_object, privilege).await;
+        }
+
+        for column in columns {
+            let column_object =
+                GrantObject::Column(database.to_string(), table.to_string(), column.clone());
+            self.validate_privilege(&column_object, privilege).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn has_privilege(
+        self: &Arc<Self>,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Result<bool> {
         let current_user = self.get_current_user()?;
         let user_verified = current_user.grants.verify_privilege(object, privilege);
         if user_verified {
-            return Ok(());
+            return Ok(true);
         }
 
         let tenant = self.get_tenant();
@@ -206,16 +316,14 @@ impl Session {
             .await?
             .get_role_cache_manager();
         let role_verified = role_cache
-            .verify_privilege(&tenant, &current_user.grants.roles(), object, privilege)
+            .verify_privilege(
+                &tenant,
+                &self.active_roles(&current_user),
+                object,
+                privilege,
+            )
             .await?;
-        if role_verified {
-            return Ok(());
-        }
-
-        Err(ErrorCode::PermissionDenied(format!(
-            "Permission denied, user '{}'@'{}' requires {} privilege on {}",
-            &current_user.name, &current_user.hostname, privilege, object
-        )))
+        Ok(role_verified)
     }
 
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
@@ -32,8 +32,10 @@ pub struct SessionContext {
     conf: Config,
     abort: AtomicBool,
     current_database: RwLock<String>,
+    current_tenant: RwLock<String>,
     #[ignore_malloc_size_of = "insignificant"]
     current_user: RwLock<Option<UserInfo>>,
+    current_role: RwLock<Option<String>>,
     #[ignore_malloc_size_of = "insignificant"]
     client_host: RwLock<Option<SocketAddr>>,
     #[ignore_malloc_size_of = "insignificant"]
@@ -45,9 +47,11 @@ pub struct SessionContext {
 impl SessionContext {
     pub fn try_create(conf: Config) -> Result<Self> {
         Ok(SessionContext {
+            current_tenant: RwLock::new(conf.query.tenant_id.clone()),
             conf,
             abort: Default::default(),
             current_user: Default::default(),
+            current_role: Default::default(),
             client_host: Default::default(),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
@@ -77,8 +81,16 @@ impl SessionContext {
         *lock = db
     }
 
+    // Get the tenant of the session, the tenant of the config by default.
     pub fn get_tenant(&self) -> String {
-        self.conf.query.tenant_id.clone()
+        let lock = self.current_tenant.read();
+        lock.clone()
+    }
+
+    // Set the tenant the user is authenticated in.
+    pub fn set_current_tenant(&self, tenant: String) {
+        let mut lock = self.current_tenant.write();
+        *lock = tenant
     }
 
     // Get current user
@@ -93,6 +105,18 @@ impl SessionContext {
         *lock = Some(user);
     }
 
+    // Get the role activated by SET ROLE
+    pub fn get_current_role(&self) -> Option<String> {
+        let lock = self.current_role.read();
+        lock.clone()
+    }
+
+    // Set the role activated by SET ROLE, None is the default
+    pub fn set_current_role(&self, role: Option<String>) {
+        let mut lock = self.current_role.write();
+        *lock = role;
+    }
+
     pub fn get_client_host(&self) -> Option<SocketAddr> {
         let lock = self.client_host.read();
         *lock
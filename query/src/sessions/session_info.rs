@@ -18,6 +18,8 @@ use std::sync::Arc;
 use common_base::ProgressValues;
 use common_contexts::DalMetrics;
 use common_meta_types::UserInfo;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::sessions::Session;
 use crate::sessions::SessionContext;
@@ -26,6 +28,7 @@ use crate::sessions::Settings;
 
 pub struct ProcessInfo {
     pub id: String,
+    pub query_id: Option<String>,
     pub typ: String,
     pub state: String,
     pub database: String,
@@ -36,6 +39,62 @@ pub struct ProcessInfo {
     pub memory_usage: i64,
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    pub current_stage: Option<String>,
+    /// The id of the cluster node running the process.
+    pub node: String,
+}
+
+/// The progress of a stage of a distributed query on a node, it's added to the process of the
+/// query on the coordinator by system.processes.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryProgress {
+    pub query_id: String,
+    pub scan_rows: u64,
+    pub scan_bytes: u64,
+    pub partitions_read: u64,
+    pub memory_usage: i64,
+}
+
+impl QueryProgress {
+    pub fn merge(&mut self, other: &QueryProgress) {
+        self.scan_rows += other.scan_rows;
+        self.scan_bytes += other.scan_bytes;
+        self.partitions_read += other.partitions_read;
+        self.memory_usage += other.memory_usage;
+    }
+}
+
+/// The memory tracked for the query of a session, in total and by the kinds of the processors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryMemoryUsage {
+    pub query_id: String,
+    pub memory_usage: i64,
+    pub peak_memory_usage: i64,
+    /// The name of the processor, the memory usage and the peak memory usage.
+    pub processors: Vec<(String, i64, i64)>,
+}
+
+impl ProcessInfo {
+    /// The progress of the stages if it is the process of a distributed query on the node, whose
+    /// id is the query id of the coordinator.
+    pub fn stage_progress(&self) -> Option<QueryProgress> {
+        if self.typ != SessionType::FlightRPC.to_string() || self.query_id.is_none() {
+            return None;
+        }
+
+        let scan_progress = self.scan_progress_value.clone().unwrap_or_default();
+        Some(QueryProgress {
+            query_id: self.id.clone(),
+            scan_rows: scan_progress.rows as u64,
+            scan_bytes: scan_progress.bytes as u64,
+            partitions_read: self
+                .dal_metrics
+                .as_ref()
+                .map(DalMetrics::get_partitions_read)
+                .unwrap_or(0),
+            memory_usage: self.memory_usage,
+        })
+    }
 }
 
 impl Session {
@@ -44,6 +103,20 @@ impl Session {
         self.to_process_info(&session_ctx)
     }
 
+    /// The memory usage of the query running in the session, None if it has not started.
+    pub fn query_memory_usage(self: &Arc<Self>) -> Option<QueryMemoryUsage> {
+        let shared = self.session_ctx.get_query_context_shared()?;
+        let runtime_tracker = shared.get_runtime_tracker()?;
+        let memory_tracker = runtime_tracker.get_memory_tracker();
+        let query_id = shared.init_query_id.read().clone();
+        Some(QueryMemoryUsage {
+            query_id,
+            memory_usage: memory_tracker.get_memory_usage(),
+            peak_memory_usage: memory_tracker.get_peak_memory_usage(),
+            processors: runtime_tracker.get_category_memory_usages(),
+        })
+    }
+
     fn to_process_info(self: &Arc<Self>, status: &SessionContext) -> ProcessInfo {
         let mut memory_usage = 0;
 
@@ -57,6 +130,9 @@ impl Session {
 
         ProcessInfo {
             id: self.id.clone(),
+            query_id: status
+                .get_query_context_shared()
+                .map(|shared| shared.init_query_id.read().clone()),
             typ: self.typ.clone().to_string(),
             state: self.process_state(status),
             database: status.get_current_database(),
@@ -67,6 +143,10 @@ impl Session {
             memory_usage,
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
+            current_stage: status
+                .get_query_context_shared()
+                .and_then(|shared| shared.get_current_stage()),
+            node: self.session_mgr.get_cluster_discovery().local_id(),
         }
     }
 
@@ -74,6 +154,9 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => String::from("Aborting"),
             None => String::from("Idle"),
+            Some(_) if self.session_mgr.get_query_queue().is_queued(&self.id) => {
+                String::from("Queued")
+            }
             Some(_) => String::from("Query"),
         }
     }
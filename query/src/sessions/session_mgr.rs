@@ -19,12 +19,14 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::get_available_cpus;
 use common_base::tokio;
 use common_base::Runtime;
 use common_base::SignalStream;
 use common_contexts::DalRuntime;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::UDFServerFunction;
 use common_infallible::RwLock;
 use common_metrics::label_counter;
 use common_tracing::init_query_logger;
@@ -46,6 +48,11 @@ use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryMemoryUsage;
+use crate::sessions::QueryProfiles;
+use crate::sessions::QueryProgress;
+use crate::sessions::QueryQueue;
+use crate::sessions::QueryResultCache;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
 use crate::storages::cache::CacheManager;
@@ -59,12 +66,17 @@ pub struct SessionManager {
     pub(in crate::sessions) user_manager: RwLock<Arc<UserApiProvider>>,
     pub(in crate::sessions) auth_manager: RwLock<Arc<AuthMgr>>,
     pub(in crate::sessions) http_query_manager: Arc<HttpQueryManager>,
+    pub(in crate::sessions) query_queue: Arc<QueryQueue>,
+    pub(in crate::sessions) query_profiles: Arc<QueryProfiles>,
+    pub(in crate::sessions) query_result_cache: Arc<QueryResultCache>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     pub(in crate::sessions) storage_cache_manager: RwLock<Arc<CacheManager>>,
     pub(in crate::sessions) query_logger:
         RwLock<Option<Arc<dyn tracing::Subscriber + Send + Sync>>>,
+    pub(in crate::sessions) audit_logger: Option<Arc<dyn tracing::Subscriber + Send + Sync>>,
+    pub(in crate::sessions) slow_query_logger: Arc<dyn tracing::Subscriber + Send + Sync>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
     storage_operator: RwLock<Operator>,
     storage_runtime: Arc<Runtime>,
@@ -82,7 +94,7 @@ impl SessionManager {
         let storage_runtime = {
             let mut storage_num_cpus = conf.storage.storage_num_cpus as usize;
             if storage_num_cpus == 0 {
-                storage_num_cpus = std::cmp::max(1, num_cpus::get() / 2)
+                storage_num_cpus = std::cmp::max(1, get_available_cpus() / 2)
             }
             Runtime::with_worker_threads(storage_num_cpus, Some("IO-worker".to_owned()))?
         };
@@ -97,11 +109,15 @@ impl SessionManager {
         let user = UserApiProvider::create_global(conf.clone()).await?;
         let auth_manager = Arc::new(AuthMgr::create(conf.clone(), user.clone()).await?);
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
+        let query_queue = QueryQueue::create(&conf);
+        let query_profiles = QueryProfiles::create(&conf);
+        let query_result_cache = QueryResultCache::create(&conf);
+        UDFServerFunction::set_allowed_addresses(&conf.query.udf_server_allow_list);
         let max_sessions = conf.query.max_active_sessions as usize;
         let active_sessions = Arc::new(RwLock::new(HashMap::with_capacity(max_sessions)));
         let status = Arc::new(RwLock::new(Default::default()));
 
-        let (_guards, query_logger) = if conf.log.log_query_enabled {
+        let (mut _guards, query_logger) = if conf.log.log_query_enabled {
             let (_guards, query_logger) =
                 init_query_logger("query-detail", conf.log.log_dir.as_str());
             (_guards, Some(query_logger))
@@ -109,22 +125,44 @@ impl SessionManager {
             (Vec::new(), None)
         };
 
-        Ok(Arc::new(SessionManager {
+        // The audit log is written to its own files, it's kept independent of the query log.
+        let audit_logger = if conf.log.audit_sinks()?.file {
+            let (audit_guards, audit_logger) =
+                init_query_logger("audit", conf.log.log_dir.as_str());
+            _guards.extend(audit_guards);
+            Some(audit_logger)
+        } else {
+            None
+        };
+
+        let (slow_query_guards, slow_query_logger) =
+            init_query_logger("slow-query", conf.log.log_dir.as_str());
+        _guards.extend(slow_query_guards);
+
+        let session_manager = Arc::new(SessionManager {
             conf: RwLock::new(conf),
             catalog: RwLock::new(catalog),
             discovery: RwLock::new(discovery),
             user_manager: RwLock::new(user),
             http_query_manager,
+            query_queue,
+            query_profiles,
+            query_result_cache,
             max_sessions,
             active_sessions,
             auth_manager: RwLock::new(auth_manager),
             storage_cache_manager: RwLock::new(storage_cache_manager),
             query_logger: RwLock::new(query_logger),
+            audit_logger,
+            slow_query_logger,
             status,
             storage_operator: RwLock::new(storage_operator),
             storage_runtime: Arc::new(storage_runtime),
             _guards,
-        }))
+        });
+
+        session_manager.start_idle_sessions_reaper();
+        Ok(session_manager)
     }
 
     pub fn get_conf(&self) -> Config {
@@ -139,6 +177,18 @@ impl SessionManager {
         self.http_query_manager.clone()
     }
 
+    pub fn get_query_queue(self: &Arc<Self>) -> Arc<QueryQueue> {
+        self.query_queue.clone()
+    }
+
+    pub fn get_query_profiles(self: &Arc<Self>) -> Arc<QueryProfiles> {
+        self.query_profiles.clone()
+    }
+
+    pub fn get_query_result_cache(self: &Arc<Self>) -> Arc<QueryResultCache> {
+        self.query_result_cache.clone()
+    }
+
     pub fn get_auth_manager(self: &Arc<Self>) -> Arc<AuthMgr> {
         self.auth_manager.read().clone()
     }
@@ -252,6 +302,15 @@ impl SessionManager {
             .map(|session| SessionRef::create(session.clone()))
     }
 
+    // The session running the query, including the session of a query stage in cluster mode.
+    pub async fn get_session_by_query_id(self: &Arc<Self>, query_id: &str) -> Option<SessionRef> {
+        let sessions = self.active_sessions.read();
+        sessions
+            .values()
+            .find(|session| session.get_running_query_id().as_deref() == Some(query_id))
+            .map(|session| SessionRef::create(session.clone()))
+    }
+
     #[allow(clippy::ptr_arg)]
     pub fn destroy_session(self: &Arc<Self>, session_id: &String) {
         let config = self.get_config();
@@ -265,6 +324,60 @@ impl SessionManager {
         sessions.remove(session_id);
     }
 
+    // Check the idle sessions every second, until the session manager is dropped.
+    fn start_idle_sessions_reaper(self: &Arc<Self>) {
+        let session_manager = Arc::downgrade(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                match session_manager.upgrade() {
+                    None => break,
+                    Some(session_manager) => {
+                        session_manager.expire_idle_sessions();
+                    }
+                }
+            }
+        });
+    }
+
+    /// Kill the sessions of the connections without any running query for longer than the
+    /// `session_idle_timeout_secs`, their connections are closed and the resources are released.
+    /// The HTTP and FlightSQL sessions are not connections, they expire on their own.
+    /// Returns the number of the killed sessions.
+    pub fn expire_idle_sessions(self: &Arc<Self>) -> usize {
+        let timeout_secs = self.conf.read().query.session_idle_timeout_secs;
+        if timeout_secs == 0 {
+            return 0;
+        }
+
+        let timeout = Duration::from_secs(timeout_secs);
+        let idle_sessions = self
+            .active_sessions
+            .read()
+            .values()
+            .filter(|session| {
+                matches!(
+                    session.get_type(),
+                    SessionType::MySQL | SessionType::Clickhouse | SessionType::PostgreSQL
+                )
+            })
+            .filter(|session| !session.is_aborting() && session.query_context_shared_is_none())
+            .filter(|session| session.get_status().read().last_access().elapsed() >= timeout)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Kill them out of the lock, the closed connections destroy their sessions.
+        for session in &idle_sessions {
+            tracing::info!(
+                "Session {} is idle for more than {} secs, close it.",
+                session.get_id(),
+                timeout_secs
+            );
+            session.kill();
+        }
+        idle_sessions.len()
+    }
+
     pub fn graceful_shutdown(
         self: &Arc<Self>,
         mut signal: SignalStream,
@@ -306,6 +419,24 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// The memory usage of the queries running on the node.
+    pub async fn queries_memory_usage(self: &Arc<Self>) -> Vec<QueryMemoryUsage> {
+        let sessions = self.active_sessions.read();
+        sessions
+            .values()
+            .filter_map(Session::query_memory_usage)
+            .collect::<Vec<_>>()
+    }
+
+    /// The progress of the stages of the distributed queries running on the node.
+    pub async fn stage_progresses(self: &Arc<Self>) -> Vec<QueryProgress> {
+        self.processes_info()
+            .await
+            .iter()
+            .filter_map(ProcessInfo::stage_progress)
+            .collect()
+    }
+
     async fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Arc<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
@@ -436,4 +567,12 @@ impl SessionManager {
     pub fn get_query_logger(&self) -> Option<Arc<dyn tracing::Subscriber + Send + Sync>> {
         self.query_logger.write().to_owned()
     }
+
+    pub fn get_audit_logger(&self) -> Option<Arc<dyn tracing::Subscriber + Send + Sync>> {
+        self.audit_logger.clone()
+    }
+
+    pub fn get_slow_query_logger(&self) -> Arc<dyn tracing::Subscriber + Send + Sync> {
+        self.slow_query_logger.clone()
+    }
 }
@@ -18,10 +18,14 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use chrono_tz::Tz;
+use common_base::get_available_cpus;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::AesMode;
 use common_infallible::RwLock;
+use common_meta_types::SettingConstraint;
 use common_meta_types::UserSetting;
 use itertools::Itertools;
 
@@ -48,6 +52,13 @@ impl Debug for ScopeLevel {
     }
 }
 
+/// The settings whose values are secrets: they are masked in SHOW SETTINGS, system.settings and
+/// the logs, and they can't be set GLOBAL to be persisted to the metasrv.
+pub const SECRET_SETTINGS: [&str; 1] = ["aes_encryption_key"];
+
+// The value shown instead of the value of the secret settings.
+const MASKED_SETTING_VALUE: &str = "******";
+
 #[derive(Clone, Debug)]
 pub struct SettingValue {
     // Default value of this setting.
@@ -63,7 +74,6 @@ pub struct Settings {
     settings: Arc<RwLock<HashMap<String, SettingValue>>>,
     #[allow(dead_code)]
     user_api: Arc<UserApiProvider>,
-    #[allow(dead_code)]
     session_ctx: Arc<SessionContext>,
 }
 
@@ -90,6 +100,14 @@ impl Settings {
                 desc: "The maximum number of threads to execute the request. By default, it is determined automatically.",
             },
 
+            // max_memory_usage
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_memory_usage", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The maximum memory usage in bytes of a query, the query fails once it is exceeded. By default, it is 0 (no limit).",
+            },
+
             // flight_client_timeout
             SettingValue {
                 default_value: DataValue::UInt64(60),
@@ -159,11 +177,114 @@ impl Settings {
                 desc: "Format empty_as_default, default value: 1",
             },
 
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("error_as_null", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Load the values failed to parse as NULL, or as the default value if the column is not nullable, default value: 0",
+            },
+
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("json_strict", DataValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Reject the NDJSON values whose JSON types mismatch the column types, or convert them if 0, default value: 1",
+            },
+
             SettingValue {
                 default_value: DataValue::UInt64(0),
                 user_setting: UserSetting::create("skip_header", DataValue::UInt64(0)),
                 level: ScopeLevel::Session,
-                desc: "Whether to skip the input header, default value: 0",
+                desc: "Number of header lines to skip in the input, default value: 0",
+            },
+
+            // query_tag
+            SettingValue {
+                default_value: DataValue::String("".as_bytes().to_vec()),
+                user_setting: UserSetting::create("query_tag", DataValue::String("".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "The tag of the queries in the query log, the processes and the metrics, to attribute the load to the jobs. By default, it is empty.",
+            },
+
+            // statement_timeout_ms
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("statement_timeout_ms", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The timeout of a statement in millisecond, the query is killed once it is exceeded. By default, it is 0 (no timeout).",
+            },
+
+            // long_query_time
+            SettingValue {
+                default_value: DataValue::UInt64(10),
+                user_setting: UserSetting::create("long_query_time", DataValue::UInt64(10)),
+                level: ScopeLevel::Session,
+                desc: "The queries taking longer than the seconds are written to the slow query log. By default, it is 10 seconds, 0 to log all the queries.",
+            },
+
+            // enable_query_result_cache
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_query_result_cache", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Serve the SELECT queries from the query result cache and cache their results if value != 0, default value: 0",
+            },
+
+            // query_result_cache_ttl_secs
+            SettingValue {
+                default_value: DataValue::UInt64(300),
+                user_setting: UserSetting::create("query_result_cache_ttl_secs", DataValue::UInt64(300)),
+                level: ScopeLevel::Session,
+                desc: "The seconds the cached query results are served, they are skipped once the tables are modified. By default, it is 300 seconds.",
+            },
+
+            SettingValue {
+                default_value: DataValue::String("auto".as_bytes().to_vec()),
+                user_setting: UserSetting::create("compression", DataValue::String("auto".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "Format compression: auto, none, gzip, bz2, zstd, xz and so on, default value: auto",
+            },
+
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("enable_http_compression", DataValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Compress the HTTP query results if the client accepts zstd, br, gzip or deflate, default value: 1",
+            },
+
+            SettingValue {
+                default_value: DataValue::String("abort_statement".as_bytes().to_vec()),
+                user_setting: UserSetting::create("on_error", DataValue::String("abort_statement".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "Streaming load on error: continue, skip_file_<num> or abort_statement, default value: abort_statement",
+            },
+
+            SettingValue {
+                default_value: DataValue::UInt64(604800),
+                user_setting: UserSetting::create("copy_history_expire_secs", DataValue::UInt64(604800)),
+                level: ScopeLevel::Session,
+                desc: "The seconds the files loaded by COPY are kept in the history of the table to skip them when they are copied again, default value: 604800",
+            },
+
+            SettingValue {
+                default_value: DataValue::String("UTC".as_bytes().to_vec()),
+                user_setting: UserSetting::create("timezone", DataValue::String("UTC".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "Timezone, default value: UTC",
+            },
+
+            SettingValue {
+                default_value: DataValue::String(vec![]),
+                user_setting: UserSetting::create("aes_encryption_key", DataValue::String(vec![])),
+                level: ScopeLevel::Session,
+                desc: "The hex encoded key of aes_encrypt and aes_decrypt if they are called without a key, default value: empty",
+            },
+
+            SettingValue {
+                default_value: DataValue::String("aes-256-gcm".as_bytes().to_vec()),
+                user_setting: UserSetting::create("block_encryption_mode", DataValue::String("aes-256-gcm".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "The mode of aes_encrypt and aes_decrypt if they are called without a mode, default value: aes-256-gcm",
             },
         ];
 
@@ -188,7 +309,7 @@ impl Settings {
         {
             // Set max threads.
             let cpus = if conf.query.num_cpus == 0 {
-                num_cpus::get() as u64
+                get_available_cpus() as u64
             } else {
                 conf.query.num_cpus
             };
@@ -198,6 +319,12 @@ impl Settings {
         Ok(ret)
     }
 
+    // Get the max memory usage of a query in bytes, 0 means no limit.
+    pub fn get_max_memory_usage(&self) -> Result<u64> {
+        let key = "max_memory_usage";
+        self.try_get_u64(key)
+    }
+
     // Get max_block_size.
     pub fn get_max_block_size(&self) -> Result<u64> {
         let key = "max_block_size";
@@ -268,11 +395,90 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    pub fn get_error_as_null(&self) -> Result<u64> {
+        let key = "error_as_null";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_json_strict(&self) -> Result<u64> {
+        let key = "json_strict";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_compression(&self) -> Result<Vec<u8>> {
+        let key = "compression";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_enable_http_compression(&self) -> Result<u64> {
+        let key = "enable_http_compression";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_query_tag(&self) -> Result<String> {
+        let key = "query_tag";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+            .map(|v| String::from_utf8_lossy(&v).to_string())
+    }
+
+    pub fn get_on_error(&self) -> Result<Vec<u8>> {
+        let key = "on_error";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_copy_history_expire_secs(&self) -> Result<u64> {
+        let key = "copy_history_expire_secs";
+        self.try_get_u64(key)
+    }
+
     pub fn get_skip_header(&self) -> Result<u64> {
         let key = "skip_header";
         self.try_get_u64(key)
     }
 
+    // Get the statement timeout in ms, 0 means no timeout.
+    pub fn get_statement_timeout_ms(&self) -> Result<u64> {
+        let key = "statement_timeout_ms";
+        self.try_get_u64(key)
+    }
+
+    // Get the seconds beyond which the queries are written to the slow query log.
+    pub fn get_long_query_time(&self) -> Result<u64> {
+        let key = "long_query_time";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enable_query_result_cache(&self) -> Result<u64> {
+        let key = "enable_query_result_cache";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
+        let key = "query_result_cache_ttl_secs";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_timezone(&self) -> Result<Vec<u8>> {
+        let key = "timezone";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_aes_encryption_key(&self) -> Result<Vec<u8>> {
+        let key = "aes_encryption_key";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    pub fn get_block_encryption_mode(&self) -> Result<Vec<u8>> {
+        let key = "block_encryption_mode";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         let settings = self.settings.read();
         settings.get(key).is_some()
@@ -286,6 +492,15 @@ impl Settings {
         Ok(setting.clone())
     }
 
+    // The value of the setting, to restore it once the query overriding it finishes.
+    pub(in crate::sessions) fn backup_setting(&self, key: &str) -> Result<SettingValue> {
+        self.check_and_get_setting_value(key)
+    }
+
+    pub(in crate::sessions) fn restore_setting(&self, key: &str, value: SettingValue) {
+        self.settings.write().insert(key.to_string(), value);
+    }
+
     // Get u64 value, we don't get from the metasrv.
     fn try_get_u64(&self, key: &str) -> Result<u64> {
         let setting = self.check_and_get_setting_value(key)?;
@@ -302,6 +517,8 @@ impl Settings {
 
         if is_global {
             self.set_to_global(setting)?;
+        } else {
+            setting.level = ScopeLevel::Session;
         }
 
         Ok(())
@@ -316,6 +533,8 @@ impl Settings {
 
         if is_global {
             self.set_to_global(setting)?;
+        } else {
+            setting.level = ScopeLevel::Session;
         }
 
         Ok(())
@@ -332,6 +551,35 @@ impl Settings {
         Ok(())
     }
 
+    // Overwrite the settings by the GLOBAL ones in the metasrv, the GLOBAL ones loaded before
+    // (e.g. of another tenant) are reset to the defaults first.
+    pub async fn load_global_settings(&self) -> Result<()> {
+        let tenant = self.session_ctx.get_tenant();
+        let global_settings = self
+            .user_api
+            .get_setting_api_client(&tenant)?
+            .get_settings()
+            .await?;
+
+        let mut settings = self.settings.write();
+        for setting in settings.values_mut() {
+            if matches!(setting.level, ScopeLevel::Global) {
+                setting.user_setting.value = setting.default_value.clone();
+                setting.level = ScopeLevel::Session;
+            }
+        }
+        for global_setting in global_settings {
+            if SECRET_SETTINGS.contains(&global_setting.name.as_str()) {
+                continue;
+            }
+            if let Some(setting) = settings.get_mut(&global_setting.name) {
+                setting.user_setting.value = global_setting.value;
+                setting.level = ScopeLevel::Global;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_setting_values(&self) -> Vec<DataValue> {
         let settings = self.settings.read();
 
@@ -341,9 +589,9 @@ impl Settings {
                 // Name.
                 DataValue::String(k.as_bytes().to_vec()),
                 // Value.
-                v.user_setting.value.clone(),
+                Self::masked_value(k, &v.user_setting.value),
                 // Default Value.
-                v.default_value.clone(),
+                Self::masked_value(k, &v.default_value),
                 // Scope level.
                 DataValue::String(format!("{:?}", v.level).into_bytes()),
                 // Desc.
@@ -359,20 +607,62 @@ impl Settings {
 
         let mut result = BTreeMap::new();
         for (k, v) in settings.iter().sorted_by_key(|&(k, _)| k) {
-            result.insert(k.clone(), v.user_setting.value.clone());
+            result.insert(k.clone(), Self::masked_value(k, &v.user_setting.value));
         }
         result
     }
 
+    // The non-empty values of the secret settings are masked.
+    fn masked_value(key: &str, value: &DataValue) -> DataValue {
+        match value {
+            DataValue::String(v) if !v.is_empty() && SECRET_SETTINGS.contains(&key) => {
+                DataValue::String(MASKED_SETTING_VALUE.as_bytes().to_vec())
+            }
+            _ => value.clone(),
+        }
+    }
+
     pub fn set_settings(&self, key: String, val: String, is_global: bool) -> Result<()> {
         let setting = self.check_and_get_setting_value(&key)?;
+        if is_global && SECRET_SETTINGS.contains(&key.as_str()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Setting {:?} is a secret, it can't be set GLOBAL",
+                key
+            )));
+        }
+        let constraint = self.get_setting_constraint(&key);
+        if let Some(constraint) = &constraint {
+            if constraint.readonly {
+                return Err(ErrorCode::SettingConstraintViolation(format!(
+                    "Setting {:?} is readonly for the current user",
+                    key
+                )));
+            }
+        }
 
         match setting.user_setting.value.max_data_type().data_type_id() {
             TypeID::UInt64 => {
                 let u64_val = val.parse::<u64>()?;
+                if let Some(constraint) = &constraint {
+                    Self::check_setting_range(&key, u64_val, constraint)?;
+                }
                 self.try_set_u64(&key, u64_val, is_global)?;
             }
             TypeID::String => {
+                if key == "timezone" && val.parse::<Tz>().is_err() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid timezone: {:?}",
+                        val
+                    )));
+                }
+                if key == "aes_encryption_key" && hex::decode(&val).is_err() {
+                    return Err(ErrorCode::BadArguments(
+                        "Invalid aes_encryption_key, expected a hex encoded key",
+                    ));
+                }
+                if key == "block_encryption_mode" {
+                    AesMode::parse(val.as_bytes())?;
+                }
                 self.try_set_string(&key, val.into_bytes(), is_global)?;
             }
 
@@ -386,4 +676,48 @@ impl Settings {
 
         Ok(())
     }
+
+    // The constraint of the setting for the current user, set by the administrator.
+    fn get_setting_constraint(&self, key: &str) -> Option<SettingConstraint> {
+        self.session_ctx
+            .get_current_user()
+            .and_then(|user| user.option.setting_constraints().get(key).cloned())
+    }
+
+    fn check_setting_range(key: &str, val: u64, constraint: &SettingConstraint) -> Result<()> {
+        if let Some(min) = constraint.min {
+            if val < min {
+                return Err(ErrorCode::SettingConstraintViolation(format!(
+                    "Setting {:?} should not be less than {} for the current user, but got {}",
+                    key, min, val
+                )));
+            }
+        }
+        if let Some(max) = constraint.max {
+            if val > max {
+                return Err(ErrorCode::SettingConstraintViolation(format!(
+                    "Setting {:?} should not be greater than {} for the current user, but got {}",
+                    key, max, val
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the constraint can apply to the setting: the min and max need a numeric setting.
+    pub fn check_setting_constraint(
+        &self,
+        key: &str,
+        constraint: &SettingConstraint,
+    ) -> Result<()> {
+        let setting = self.check_and_get_setting_value(key)?;
+        let numeric = setting.user_setting.value.max_data_type().data_type_id() == TypeID::UInt64;
+        if !numeric && (constraint.min.is_some() || constraint.max.is_some()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Setting {:?} is not numeric, only the readonly constraint applies to it",
+                key
+            )));
+        }
+        Ok(())
+    }
 }
@@ -21,6 +21,7 @@ mod plan_parser;
 mod planner;
 mod sql_common;
 mod sql_parser;
+mod sql_redact;
 mod sql_statement;
 pub mod statements;
 mod table_option_keys;
@@ -30,5 +31,7 @@ pub use plan_parser::PlanParser;
 pub use planner::*;
 pub use sql_common::SQLCommon;
 pub use sql_parser::DfParser;
+pub use sql_redact::redact_query_text;
+pub use sql_redact::REDACTED_SECRET;
 pub use sql_statement::*;
 pub use table_option_keys::*;
@@ -18,8 +18,11 @@ mod parser_database;
 mod parser_explain;
 mod parser_insert;
 mod parser_kill;
+mod parser_masking_policy;
+mod parser_network_policy;
 mod parser_optimize;
 mod parser_query;
+mod parser_row_access_policy;
 mod parser_set;
 mod parser_show;
 mod parser_stage;
@@ -22,7 +22,9 @@ use sqlparser::parser::IsOptional;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
+use crate::sql::statements::CopyIntoLocationSource;
 use crate::sql::statements::DfCopy;
+use crate::sql::statements::DfCopyIntoLocation;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -30,6 +32,10 @@ impl<'a> DfParser<'a> {
     // copy into table from [?] ...
     pub(crate) fn parse_copy(&mut self) -> Result<DfStatement<'a>, ParserError> {
         self.parser.expect_keyword(Keyword::INTO)?;
+        if let Token::SingleQuotedString(_) = self.parser.peek_token() {
+            return self.parse_copy_into_location();
+        }
+
         let name = self.parser.parse_object_name()?;
         let columns = self
             .parser
@@ -120,4 +126,81 @@ impl<'a> DfParser<'a> {
             validation_mode,
         }))
     }
+
+    // copy into 's3://mybucket/data/files/' from { table | ( query ) } ...
+    fn parse_copy_into_location(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let location = self.parser.parse_literal_string()?;
+
+        // from mytable | from (select * from mytable)
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let source = if self.parser.consume_token(&Token::LParen) {
+            let query = self.parser.parse_query()?;
+            self.parser.expect_token(&Token::RParen)?;
+            CopyIntoLocationSource::Query(Box::new(query))
+        } else {
+            CopyIntoLocationSource::Table(self.parser.parse_object_name()?)
+        };
+
+        // credentials=(aws_key_id='$AWS_ACCESS_KEY_ID' aws_secret_key='$AWS_SECRET_ACCESS_KEY')
+        let mut credential_options = HashMap::default();
+        if self.consume_token("CREDENTIALS") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            credential_options = self.parse_options()?;
+            self.expect_token(")")?;
+        }
+
+        // encryption=(master_key = '$MASER_KEY')
+        let mut encryption_options = HashMap::default();
+        if self.consume_token("ENCRYPTION") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            encryption_options = self.parse_options()?;
+            self.expect_token(")")?;
+        }
+
+        // file_format = (type = parquet)
+        let mut file_format_options = HashMap::default();
+        if self.consume_token("FILE_FORMAT") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            file_format_options = self.parse_options()?;
+            self.expect_token(")")?;
+        }
+
+        /*
+         copyOptions ::=
+         MAX_FILE_SIZE = <num>
+         OVERWRITE = TRUE | FALSE
+         SINGLE = TRUE | FALSE
+        */
+        let mut max_file_size = "".to_string();
+        let mut overwrite = "".to_string();
+        let mut single = "".to_string();
+        loop {
+            if self.consume_token("MAX_FILE_SIZE") {
+                self.expect_token("=")?;
+                max_file_size = self.parse_value_or_ident()?;
+            } else if self.consume_token("OVERWRITE") {
+                self.expect_token("=")?;
+                overwrite = self.parse_value_or_ident()?;
+            } else if self.consume_token("SINGLE") {
+                self.expect_token("=")?;
+                single = self.parse_value_or_ident()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(DfStatement::CopyIntoLocation(DfCopyIntoLocation {
+            location,
+            source,
+            credential_options,
+            encryption_options,
+            file_format_options,
+            max_file_size,
+            overwrite,
+            single,
+        }))
+    }
 }
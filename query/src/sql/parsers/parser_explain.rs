@@ -32,7 +32,13 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => match w.value.to_uppercase().as_str() {
                 "PIPELINE" => {
                     self.parser.next_token();
-                    ExplainType::Pipeline
+                    match self.parser.peek_token() {
+                        Token::Word(w) if w.value.to_uppercase() == "GRAPH" => {
+                            self.parser.next_token();
+                            ExplainType::PipelineGraph
+                        }
+                        _ => ExplainType::Pipeline,
+                    }
                 }
                 "GRAPH" => {
                     self.parser.next_token();
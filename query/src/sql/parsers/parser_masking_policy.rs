@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfCreateMaskingPolicy;
+use crate::sql::statements::DfDropMaskingPolicy;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    pub(crate) fn expect_masking_policy(&mut self) -> Result<(), ParserError> {
+        self.expect_token("MASKING")?;
+        self.expect_token("POLICY")
+    }
+
+    // CREATE MASKING POLICY [IF NOT EXISTS] <name> AS (<parameter>) -> <expression>
+    // [EXEMPT ROLES = ('<role>', ...)] [DESC = '<description>']
+    pub(crate) fn parse_create_masking_policy(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_masking_policy()?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+
+        let policy_name = self.parser.parse_literal_string()?;
+        self.parser.expect_keyword(Keyword::AS)?;
+
+        let exempt_token = "EXEMPT";
+        let desc_token = "DESC";
+        let parameters = self.parse_udf_parameters()?;
+        let definition = self.parse_udf_definition_expr(vec![exempt_token, desc_token])?;
+
+        let mut exempt_roles = vec![];
+        if self.consume_token(exempt_token) {
+            self.expect_token("ROLES")?;
+            self.parser.expect_token(&Token::Eq)?;
+            self.parser.expect_token(&Token::LParen)?;
+            exempt_roles = self
+                .parser
+                .parse_comma_separated(Parser::parse_literal_string)?;
+            self.parser.expect_token(&Token::RParen)?;
+        }
+
+        let description = self.parse_udf_desc(desc_token)?;
+        Ok(DfStatement::CreateMaskingPolicy(DfCreateMaskingPolicy {
+            if_not_exists,
+            policy_name,
+            parameters,
+            definition,
+            exempt_roles,
+            description,
+        }))
+    }
+
+    // DROP MASKING POLICY [IF EXISTS] <name>
+    pub(crate) fn parse_drop_masking_policy(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_masking_policy()?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let policy_name = self.parser.parse_literal_string()?;
+
+        Ok(DfStatement::DropMaskingPolicy(DfDropMaskingPolicy {
+            if_exists,
+            policy_name,
+        }))
+    }
+}
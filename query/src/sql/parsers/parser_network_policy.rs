@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfCreateNetworkPolicy;
+use crate::sql::statements::DfDropNetworkPolicy;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    pub(crate) fn expect_network_policy(&mut self) -> Result<(), ParserError> {
+        self.expect_token("NETWORK")?;
+        self.expect_token("POLICY")
+    }
+
+    // CREATE NETWORK POLICY [IF NOT EXISTS] <name> [ALLOWED_IP_LIST = ('<ip>', ...)]
+    // [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<comment>']
+    pub(crate) fn parse_create_network_policy(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_network_policy()?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let policy_name = self.parser.parse_literal_string()?;
+
+        let mut allowed_ip_list = vec![];
+        let mut blocked_ip_list = vec![];
+        let mut comment = String::new();
+        loop {
+            if self.consume_token("ALLOWED_IP_LIST") {
+                allowed_ip_list = self.parse_ip_list()?;
+            } else if self.consume_token("BLOCKED_IP_LIST") {
+                blocked_ip_list = self.parse_ip_list()?;
+            } else if self.consume_token("COMMENT") {
+                self.parser.expect_token(&Token::Eq)?;
+                comment = self.parser.parse_literal_string()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(DfStatement::CreateNetworkPolicy(DfCreateNetworkPolicy {
+            if_not_exists,
+            policy_name,
+            allowed_ip_list,
+            blocked_ip_list,
+            comment,
+        }))
+    }
+
+    // = ('<ip>', ...), the list can be empty.
+    fn parse_ip_list(&mut self) -> Result<Vec<String>, ParserError> {
+        self.parser.expect_token(&Token::Eq)?;
+        self.parser.expect_token(&Token::LParen)?;
+        if self.parser.consume_token(&Token::RParen) {
+            return Ok(vec![]);
+        }
+        let ip_list = self
+            .parser
+            .parse_comma_separated(Parser::parse_literal_string)?;
+        self.parser.expect_token(&Token::RParen)?;
+        Ok(ip_list)
+    }
+
+    // DROP NETWORK POLICY [IF EXISTS] <name>
+    pub(crate) fn parse_drop_network_policy(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_network_policy()?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let policy_name = self.parser.parse_literal_string()?;
+
+        Ok(DfStatement::DropNetworkPolicy(DfDropNetworkPolicy {
+            if_exists,
+            policy_name,
+        }))
+    }
+}
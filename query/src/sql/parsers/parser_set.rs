@@ -19,6 +19,7 @@ use sqlparser::ast::Statement;
 use sqlparser::parser::ParserError;
 
 use crate::parser_err;
+use crate::sql::statements::DfSetRole;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
@@ -27,6 +28,12 @@ impl<'a> DfParser<'a> {
     // Set.
     pub(crate) fn parse_set(&mut self) -> Result<DfStatement<'a>, ParserError> {
         self.parser.next_token();
+        if self.consume_token("ROLE") {
+            return self.parse_set_role();
+        }
+
+        // SET SESSION is the same as SET, which is left to the parser.
+        let global = self.consume_token("GLOBAL");
         match self.parser.parse_set()? {
             Statement::SetVariable {
                 local,
@@ -34,6 +41,7 @@ impl<'a> DfParser<'a> {
                 variable,
                 value,
             } => Ok(DfStatement::SetVariable(DfSetVariable {
+                global,
                 local,
                 hivevar,
                 variable,
@@ -42,4 +50,13 @@ impl<'a> DfParser<'a> {
             _ => parser_err!("Expect set Variable statement"),
         }
     }
+
+    /// SET ROLE { 'name' | DEFAULT }
+    fn parse_set_role(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let role_name = match self.consume_token("DEFAULT") {
+            true => None,
+            false => Some(self.parser.parse_literal_string()?),
+        };
+        Ok(DfStatement::SetRole(DfSetRole { role_name }))
+    }
 }
@@ -22,6 +22,7 @@ use sqlparser::tokenizer::Token;
 use crate::sql::statements::DfShowDatabases;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowKind;
+use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTabStat;
 use crate::sql::statements::DfShowTables;
 use crate::sql::DfParser;
@@ -105,6 +106,26 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // parse show settings statement
+    pub(crate) fn parse_show_settings(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let tok = self.parser.next_token();
+        match &tok {
+            Token::EOF | Token::SemiColon => Ok(DfStatement::ShowSettings(DfShowSettings::create(
+                DfShowKind::All,
+            ))),
+            Token::Word(w) => match w.keyword {
+                Keyword::LIKE => Ok(DfStatement::ShowSettings(DfShowSettings::create(
+                    DfShowKind::Like(self.parser.parse_identifier()?),
+                ))),
+                Keyword::WHERE => Ok(DfStatement::ShowSettings(DfShowSettings::create(
+                    DfShowKind::Where(self.parser.parse_expr()?),
+                ))),
+                _ => self.expected("like or where", tok),
+            },
+            _ => self.expected("like or where", tok),
+        }
+    }
+
     // parse show functions statement
     pub(crate) fn parse_show_functions(&mut self) -> Result<DfStatement<'a>, ParserError> {
         let tok = self.parser.next_token();
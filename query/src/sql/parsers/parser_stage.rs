@@ -139,12 +139,25 @@ impl<'a> DfParser<'a> {
             unexpected => self.expected("@string_literal", unexpected),
         }?;
 
+        // FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] )
+        let mut files: Vec<String> = vec![];
+        if self.consume_token("FILES") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            files = self.parse_list(&Token::Comma)?;
+            self.expect_token(")")?;
+        }
+
         // PATTERN = '<regex_pattern>'
         let mut pattern = "".to_string();
         if self.consume_token("PATTERN") {
             self.expect_token("=")?;
             pattern = self.parse_value_or_ident()?;
         }
-        Ok(DfStatement::List(DfList { location, pattern }))
+        Ok(DfStatement::List(DfList {
+            location,
+            files,
+            pattern,
+        }))
     }
 }
@@ -21,6 +21,7 @@ use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
 use sqlparser::ast::TableConstraint;
 use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Word;
@@ -35,8 +36,14 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
+use crate::sql::statements::DfVacuumDropTable;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::storages::external::EXTERNAL_OPT_KEY_AWS_KEY_ID;
+use crate::storages::external::EXTERNAL_OPT_KEY_AWS_SECRET_KEY;
+use crate::storages::external::EXTERNAL_OPT_KEY_FORMAT;
+use crate::storages::external::EXTERNAL_OPT_KEY_LOCATION;
 
 impl<'a> DfParser<'a> {
     // Create table.
@@ -89,19 +96,117 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateTable(create))
     }
 
+    // Create external table, the files of the location are read in place.
+    // CREATE EXTERNAL TABLE [IF NOT EXISTS] t (c1 int, ...) LOCATION 's3://<bucket>[/<path>]'
+    // [CREDENTIALS = (aws_key_id='$AWS_ACCESS_KEY_ID' aws_secret_key='$AWS_SECRET_ACCESS_KEY')]
+    // FORMAT PARQUET
+    pub(crate) fn parse_create_external_table(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let table_name = self.parser.parse_object_name()?;
+
+        let (columns, _) = self.parse_columns()?;
+        if columns.is_empty() {
+            return parser_err!("external table must have column definitions.");
+        }
+
+        let mut options = HashMap::new();
+
+        self.expect_token("LOCATION")?;
+        self.consume_token("=");
+        let location = self.parser.parse_literal_string()?;
+        options.insert(EXTERNAL_OPT_KEY_LOCATION.to_string(), location);
+
+        if self.consume_token("CREDENTIALS") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            let credential_options = self.parse_options()?;
+            self.expect_token(")")?;
+
+            for key in [EXTERNAL_OPT_KEY_AWS_KEY_ID, EXTERNAL_OPT_KEY_AWS_SECRET_KEY] {
+                if let Some(value) = credential_options.get(key) {
+                    options.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+
+        self.expect_token("FORMAT")?;
+        self.consume_token("=");
+        let format = self.parse_value_or_ident()?;
+        options.insert(EXTERNAL_OPT_KEY_FORMAT.to_string(), format);
+
+        let create = DfCreateTable {
+            if_not_exists,
+            name: table_name,
+            columns,
+            engine: "EXTERNAL".to_string(),
+            options,
+            like: None,
+            query: None,
+        };
+
+        Ok(DfStatement::CreateTable(create))
+    }
+
     // Drop table.
+    // syntax: DROP TABLE [IF EXISTS] [db.]name [ALL]
     pub(crate) fn parse_drop_table(&mut self) -> Result<DfStatement<'a>, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let table_name = self.parser.parse_object_name()?;
+        let all = self.parser.parse_keyword(Keyword::ALL);
 
         let drop = DfDropTable {
             if_exists,
             name: table_name,
+            all,
         };
 
         Ok(DfStatement::DropTable(drop))
     }
 
+    // Undrop table.
+    // syntax: UNDROP TABLE [db.]name
+    pub(crate) fn parse_undrop_table(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_token("UNDROP")?;
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let table_name = self.parser.parse_object_name()?;
+
+        let undrop = DfUndropTable { name: table_name };
+
+        Ok(DfStatement::UndropTable(undrop))
+    }
+
+    // Vacuum the dropped tables.
+    // syntax: VACUUM DROP TABLE [FROM db] [RETAIN n HOURS]
+    pub(crate) fn parse_vacuum_drop_table(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_token("VACUUM")?;
+        self.parser.expect_keyword(Keyword::DROP)?;
+        self.parser.expect_keyword(Keyword::TABLE)?;
+
+        let database = if self.parser.parse_keyword(Keyword::FROM) {
+            Some(self.parser.parse_identifier()?.value)
+        } else {
+            None
+        };
+
+        let retain_hours = if self.consume_token("RETAIN") {
+            let hours = self.parser.parse_literal_uint()?;
+            self.expect_token("HOURS")?;
+            Some(hours)
+        } else {
+            None
+        };
+
+        let vacuum = DfVacuumDropTable {
+            database,
+            retain_hours,
+        };
+
+        Ok(DfStatement::VacuumDropTable(vacuum))
+    }
+
     // Alter table
     pub(crate) fn parse_alter_table(&mut self) -> Result<DfStatement<'a>, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
@@ -117,9 +222,60 @@ impl<'a> DfParser<'a> {
             };
 
             Ok(DfStatement::AlterTable(rename))
+        } else if self.parser.parse_keyword(Keyword::ADD) {
+            // ALTER TABLE <table> ADD ROW ACCESS POLICY <policy> ON (<column>, ...)
+            self.expect_row_access_policy()?;
+            let policy_name = self.parser.parse_literal_string()?;
+            self.parser.expect_keyword(Keyword::ON)?;
+            self.parser.expect_token(&Token::LParen)?;
+            let columns = self
+                .parser
+                .parse_comma_separated(Parser::parse_identifier)?;
+            self.parser.expect_token(&Token::RParen)?;
+
+            Ok(DfStatement::AlterTable(DfAlterTable {
+                if_exists,
+                table_name,
+                action: AlterTableAction::AddRowAccessPolicy {
+                    policy_name,
+                    columns: columns.into_iter().map(|c| c.value).collect(),
+                },
+            }))
+        } else if self.parser.parse_keyword(Keyword::DROP) {
+            // ALTER TABLE <table> DROP ROW ACCESS POLICY <policy>
+            self.expect_row_access_policy()?;
+            let policy_name = self.parser.parse_literal_string()?;
+
+            Ok(DfStatement::AlterTable(DfAlterTable {
+                if_exists,
+                table_name,
+                action: AlterTableAction::DropRowAccessPolicy(policy_name),
+            }))
+        } else if self.consume_token("MODIFY") {
+            // ALTER TABLE <table> MODIFY COLUMN <column> { SET MASKING POLICY <policy> | UNSET MASKING POLICY }
+            self.expect_token("COLUMN")?;
+            let column = self.parser.parse_identifier()?.value;
+            let action = if self.consume_token("SET") {
+                self.expect_masking_policy()?;
+                let policy_name = self.parser.parse_literal_string()?;
+                AlterTableAction::SetMaskingPolicy {
+                    column,
+                    policy_name,
+                }
+            } else {
+                self.expect_token("UNSET")?;
+                self.expect_masking_policy()?;
+                AlterTableAction::UnsetMaskingPolicy(column)
+            };
+
+            Ok(DfStatement::AlterTable(DfAlterTable {
+                if_exists,
+                table_name,
+                action,
+            }))
         } else {
             Err(ParserError::ParserError(String::from(
-                "Alter table only support rename for now!",
+                "Alter table only support rename, row access policy and masking policy for now!",
             )))
         }
     }
@@ -15,7 +15,9 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use common_meta_types::UDFLanguage;
 use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Word;
@@ -24,11 +26,12 @@ use crate::parser_err;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfDropUDF;
+use crate::sql::statements::DfUDFServer;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
-    fn parse_udf_parameters(&mut self) -> Result<Vec<String>, ParserError> {
+    pub(crate) fn parse_udf_parameters(&mut self) -> Result<Vec<String>, ParserError> {
         let mut params = vec![];
         let mut found_right_paren = false;
         let mut expect_next_param = false;
@@ -91,7 +94,10 @@ impl<'a> DfParser<'a> {
         Ok(params)
     }
 
-    fn parse_udf_definition_expr(&mut self, until_token: Vec<&str>) -> Result<String, ParserError> {
+    pub(crate) fn parse_udf_definition_expr(
+        &mut self,
+        until_token: Vec<&str>,
+    ) -> Result<String, ParserError> {
         // Match ->
         self.parser.expect_token(&Token::Minus)?;
         let next_token = self.parser.next_token_no_skip();
@@ -99,7 +105,24 @@ impl<'a> DfParser<'a> {
             return parser_err!(format!("Expected >, found: {:#?}", next_token));
         }
 
-        let definition = self.consume_token_until_or_end(until_token).join("");
+        // Keep the whitespaces, e.g. `age >= 18 and age < 65` must not become `age>=18andage<65`.
+        let mut definition = String::new();
+        loop {
+            let next_token = self.parser.peek_token();
+            if next_token == Token::EOF
+                || next_token == Token::SemiColon
+                || until_token.contains(&next_token.to_string().to_uppercase().as_str())
+            {
+                break;
+            }
+
+            match self.parser.next_token_no_skip() {
+                Some(token) => definition.push_str(&token.to_string()),
+                None => break,
+            }
+        }
+
+        let definition = definition.trim().to_string();
         if definition.is_empty() {
             return parser_err!("UDF definition can not be empty");
         }
@@ -107,12 +130,21 @@ impl<'a> DfParser<'a> {
         Ok(definition)
     }
 
-    pub(crate) fn parse_create_udf(&mut self) -> Result<DfStatement<'a>, ParserError> {
+    // CREATE [AGGREGATE] FUNCTION [IF NOT EXISTS] <name> AS (<parameter>, ...) -> <definition>
+    // [DESC = '<description>'], the definition of an aggregate function calls aggregate
+    // functions, e.g. `(x) -> sum(x) / count(x)`.
+    pub(crate) fn parse_create_udf(
+        &mut self,
+        is_aggregate: bool,
+    ) -> Result<DfStatement<'a>, ParserError> {
         let if_not_exists =
             self.parser
                 .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
 
         let udf_name = self.parser.parse_literal_string()?;
+        if !is_aggregate && self.parser.peek_token() == Token::LParen {
+            return self.parse_create_external_udf(if_not_exists, udf_name);
+        }
         self.parser.expect_keyword(Keyword::AS)?;
 
         let desc_token = "DESC";
@@ -126,12 +158,90 @@ impl<'a> DfParser<'a> {
             parameters,
             definition,
             description,
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate,
+        };
+
+        Ok(DfStatement::CreateUDF(create_udf))
+    }
+
+    // CREATE FUNCTION <name> (<arg type>, ...) RETURNS <return type> LANGUAGE <language>
+    // HANDLER = '<handler>' ADDRESS = '<address>' [DESC = '<description>']
+    // CREATE FUNCTION <name> (<arg type>, ...) RETURNS <return type> LANGUAGE WASM
+    // HANDLER = '<handler>' AS '@<stage>/<path>' [DESC = '<description>']
+    fn parse_create_external_udf(
+        &mut self,
+        if_not_exists: bool,
+        udf_name: String,
+    ) -> Result<DfStatement<'a>, ParserError> {
+        self.parser.expect_token(&Token::LParen)?;
+        let arg_types = match self.parser.consume_token(&Token::RParen) {
+            true => vec![],
+            false => {
+                let arg_types = self.parser.parse_comma_separated(Parser::parse_data_type)?;
+                self.parser.expect_token(&Token::RParen)?;
+                arg_types
+            }
+        };
+
+        self.expect_token("RETURNS")?;
+        let return_type = self.parser.parse_data_type()?;
+        self.expect_token("LANGUAGE")?;
+        let language = self.parser.parse_identifier()?.value;
+
+        self.expect_token("HANDLER")?;
+        self.parser.expect_token(&Token::Eq)?;
+        let handler = self.parser.parse_literal_string()?;
+
+        let udf_language = match language.eq_ignore_ascii_case("WASM") {
+            true => UDFLanguage::Wasm,
+            false => UDFLanguage::External,
+        };
+        let address = match udf_language {
+            UDFLanguage::Wasm => {
+                self.parser.expect_keyword(Keyword::AS)?;
+                let stage_path = self.parser.parse_literal_string()?;
+                if !stage_path.starts_with('@') {
+                    return parser_err!(format!(
+                        "WASM module must be a stage path like '@my_stage/f.wasm', got: {}",
+                        stage_path
+                    ));
+                }
+                stage_path
+            }
+            _ => {
+                self.expect_token("ADDRESS")?;
+                self.parser.expect_token(&Token::Eq)?;
+                self.parser.parse_literal_string()?
+            }
+        };
+
+        let description = self.parse_udf_desc("DESC")?;
+        let create_udf = DfCreateUDF {
+            if_not_exists,
+            udf_name,
+            parameters: vec![],
+            definition: handler.clone(),
+            description,
+            language: udf_language,
+            server: Some(DfUDFServer {
+                arg_types,
+                return_type,
+                language,
+                handler,
+                address,
+            }),
+            is_aggregate: false,
         };
 
         Ok(DfStatement::CreateUDF(create_udf))
     }
 
-    pub(crate) fn parse_alter_udf(&mut self) -> Result<DfStatement<'a>, ParserError> {
+    pub(crate) fn parse_alter_udf(
+        &mut self,
+        is_aggregate: bool,
+    ) -> Result<DfStatement<'a>, ParserError> {
         let udf_name = self.parser.parse_literal_string()?;
         let as_token = Token::make_keyword("AS");
         self.parser.expect_token(&as_token)?;
@@ -146,6 +256,7 @@ impl<'a> DfParser<'a> {
             parameters,
             definition,
             description,
+            is_aggregate,
         };
 
         Ok(DfStatement::AlterUDF(update_udf))
@@ -162,7 +273,7 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropUDF(drop_udf))
     }
 
-    fn parse_udf_desc(&mut self, desc_token: &str) -> Result<String, ParserError> {
+    pub(crate) fn parse_udf_desc(&mut self, desc_token: &str) -> Result<String, ParserError> {
         if self.consume_token(desc_token) {
             self.parser.expect_token(&Token::Eq)?;
             Ok(self.parser.parse_literal_string()?)
@@ -15,12 +15,17 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
 use common_meta_types::PrincipalIdentity;
+use common_meta_types::SettingConstraint;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
 use sqlparser::ast::Ident;
 use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
@@ -136,7 +141,7 @@ impl<'a> DfParser<'a> {
     /// GRANT privs TO [USER] 'name'@'host'
     /// GRANT privs TO ROLE 'name'
     pub(crate) fn parse_grant_privilege(&mut self) -> Result<DfStatement<'a>, ParserError> {
-        let privileges = self.parse_privileges()?;
+        let (privileges, column_privileges) = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
         }
@@ -149,6 +154,7 @@ impl<'a> DfParser<'a> {
             principal,
             on,
             priv_types: privileges,
+            column_priv_types: column_privileges,
         };
         Ok(DfStatement::GrantPrivilege(grant))
     }
@@ -176,7 +182,7 @@ impl<'a> DfParser<'a> {
     /// REVOKE privs ON * FROM [USER] 'name'@'host'
     /// REVOKE privs ON * FROM ROLE 'name'
     pub fn parse_revoke_privilege(&mut self) -> Result<DfStatement<'a>, ParserError> {
-        let privileges = self.parse_privileges()?;
+        let (privileges, column_privileges) = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
             return self.expected("keyword ON", self.parser.peek_token());
         }
@@ -189,6 +195,7 @@ impl<'a> DfParser<'a> {
             principal,
             on,
             priv_types: privileges,
+            column_priv_types: column_privileges,
         };
         Ok(DfStatement::RevokePrivilege(revoke))
     }
@@ -288,13 +295,41 @@ impl<'a> DfParser<'a> {
             return Ok(user_options);
         }
         loop {
-            match self.parser.peek_token().to_string().as_str().try_into() {
-                Ok(option) => user_options.push(option),
-                Err(_) => {
-                    return self.expected("user option", self.parser.peek_token());
+            if self.consume_token("DEFAULT_DATABASE") {
+                self.expect_token("=")?;
+                let database = self.parser.parse_literal_string()?;
+                user_options.push(DfUserWithOption::DefaultDatabase(database));
+            } else if self.consume_token("DEFAULT_ROLE") {
+                self.expect_token("=")?;
+                let role = self.parser.parse_literal_string()?;
+                user_options.push(DfUserWithOption::DefaultRole(role));
+            } else if self.consume_token("DEFAULT_SETTINGS") {
+                self.expect_token("=")?;
+                self.expect_token("(")?;
+                let settings = self.parse_options()?.into_iter().collect();
+                self.expect_token(")")?;
+                user_options.push(DfUserWithOption::DefaultSettings(settings));
+            } else if self.consume_token("NETWORK_POLICY") {
+                self.expect_token("=")?;
+                let policy = self.parser.parse_literal_string()?;
+                user_options.push(DfUserWithOption::NetworkPolicy(policy));
+            } else if self.consume_token("SETTING_CONSTRAINT") {
+                let name = self.parser.parse_identifier()?.value.to_lowercase();
+                self.expect_token("=")?;
+                self.expect_token("(")?;
+                let options = self.parse_options()?;
+                self.expect_token(")")?;
+                let constraint = Self::parse_setting_constraint(options)?;
+                user_options.push(DfUserWithOption::SettingConstraint(name, constraint));
+            } else {
+                match self.parser.peek_token().to_string().as_str().try_into() {
+                    Ok(option) => user_options.push(option),
+                    Err(_) => {
+                        return self.expected("user option", self.parser.peek_token());
+                    }
                 }
+                self.parser.next_token();
             }
-            self.parser.next_token();
             if !self.parser.consume_token(&Token::Comma) {
                 break;
             }
@@ -302,6 +337,27 @@ impl<'a> DfParser<'a> {
         Ok(user_options)
     }
 
+    // min = 1 max = 8 readonly = true, all of them are optional, no option means no constraint.
+    fn parse_setting_constraint(
+        options: HashMap<String, String>,
+    ) -> Result<Option<SettingConstraint>, ParserError> {
+        if options.is_empty() {
+            return Ok(None);
+        }
+
+        let mut constraint = SettingConstraint::default();
+        for (name, value) in options {
+            let invalid = || ParserError::ParserError(format!("Invalid {}: {}", name, value));
+            match name.as_str() {
+                "min" => constraint.min = Some(value.parse().map_err(|_| invalid())?),
+                "max" => constraint.max = Some(value.parse().map_err(|_| invalid())?),
+                "readonly" => constraint.readonly = value.parse().map_err(|_| invalid())?,
+                _ => return parser_err!(format!("Unknown setting constraint: {}", name)),
+            }
+        }
+        Ok(Some(constraint))
+    }
+
     fn parse_auth_option(&mut self) -> Result<DfAuthOption, ParserError> {
         let exist_not = self.parser.parse_keyword(Keyword::NOT);
         let exist_identified = self.consume_token("IDENTIFIED");
@@ -336,30 +392,37 @@ impl<'a> DfParser<'a> {
         }
     }
 
-    fn parse_privileges(&mut self) -> Result<UserPrivilegeSet, ParserError> {
+    /// The privileges on the object, and the privileges on the columns of the table,
+    /// e.g. `SELECT (a, b), INSERT (a)`.
+    fn parse_privileges(
+        &mut self,
+    ) -> Result<(UserPrivilegeSet, BTreeMap<String, UserPrivilegeSet>), ParserError> {
         let mut privileges = UserPrivilegeSet::empty();
+        let mut column_privileges: BTreeMap<String, UserPrivilegeSet> = BTreeMap::new();
         loop {
-            match self.parser.next_token() {
+            let privilege = match self.parser.next_token() {
                 Token::Word(w) => match w.keyword {
-                    // Keyword::USAGE => privileges.set_privilege(UserPrivilegeType::Usage),
+                    // Keyword::USAGE => UserPrivilegeType::Usage,
                     Keyword::CREATE => {
                         if self.consume_token("USER") {
-                            privileges.set_privilege(UserPrivilegeType::CreateUser)
+                            UserPrivilegeType::CreateUser
                         } else if self.consume_token("ROLE") {
-                            privileges.set_privilege(UserPrivilegeType::CreateRole)
+                            UserPrivilegeType::CreateRole
+                        } else if self.consume_token("STAGE") {
+                            UserPrivilegeType::CreateStage
                         } else {
-                            privileges.set_privilege(UserPrivilegeType::Create)
+                            UserPrivilegeType::Create
                         }
                     }
-                    Keyword::DROP => privileges.set_privilege(UserPrivilegeType::Drop),
-                    Keyword::ALTER => privileges.set_privilege(UserPrivilegeType::Alter),
-                    Keyword::SELECT => privileges.set_privilege(UserPrivilegeType::Select),
-                    Keyword::INSERT => privileges.set_privilege(UserPrivilegeType::Insert),
-                    Keyword::UPDATE => privileges.set_privilege(UserPrivilegeType::Update),
-                    Keyword::DELETE => privileges.set_privilege(UserPrivilegeType::Delete),
+                    Keyword::DROP => UserPrivilegeType::Drop,
+                    Keyword::ALTER => UserPrivilegeType::Alter,
+                    Keyword::SELECT => UserPrivilegeType::Select,
+                    Keyword::INSERT => UserPrivilegeType::Insert,
+                    Keyword::UPDATE => UserPrivilegeType::Update,
+                    Keyword::DELETE => UserPrivilegeType::Delete,
                     // TODO: uncomment this after sqlparser-rs accepts the SUPER keyword
-                    // Keyword::SUPER => privileges.set_privilege(UserPrivilegeType::Super)
-                    Keyword::GRANT => privileges.set_privilege(UserPrivilegeType::Grant),
+                    // Keyword::SUPER => UserPrivilegeType::Super,
+                    Keyword::GRANT => UserPrivilegeType::Grant,
                     Keyword::ALL => {
                         privileges.set_all_privileges();
                         // GRANT ALL [PRIVILEGES]
@@ -370,10 +433,27 @@ impl<'a> DfParser<'a> {
                 },
                 unexpected => return self.expected("privilege type", unexpected),
             };
+
+            match self.parser.consume_token(&Token::LParen) {
+                true => {
+                    let columns = self
+                        .parser
+                        .parse_comma_separated(Parser::parse_identifier)?;
+                    self.parser.expect_token(&Token::RParen)?;
+                    for column in columns {
+                        column_privileges
+                            .entry(column.value)
+                            .or_insert_with(UserPrivilegeSet::empty)
+                            .set_privilege(privilege);
+                    }
+                }
+                false => privileges.set_privilege(privilege),
+            }
+
             if !self.parser.consume_token(&Token::Comma) {
                 break;
             }
         }
-        Ok(privileges)
+        Ok((privileges, column_privileges))
     }
 }
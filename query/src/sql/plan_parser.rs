@@ -21,6 +21,7 @@ use common_planners::Expression;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_tracing::tracing;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -35,7 +36,8 @@ pub struct PlanParser;
 
 impl PlanParser {
     pub async fn parse(ctx: Arc<QueryContext>, query: &str) -> Result<PlanNode> {
-        let (statements, _) = DfParser::parse_sql(query)?;
+        let (statements, hints) = DfParser::parse_sql(query)?;
+        PlanParser::set_hint_settings(&ctx, &hints)?;
         PlanParser::build_plan(statements, ctx).await
     }
 
@@ -45,10 +47,26 @@ impl PlanParser {
     ) -> (Result<PlanNode>, Vec<DfHint>) {
         match DfParser::parse_sql(query) {
             Err(cause) => (Err(cause), vec![]),
-            Ok((statements, hints)) => (PlanParser::build_plan(statements, ctx).await, hints),
+            Ok((statements, hints)) => match PlanParser::set_hint_settings(&ctx, &hints) {
+                Err(cause) => (Err(cause), hints),
+                Ok(_) => (PlanParser::build_plan(statements, ctx).await, hints),
+            },
+        }
+    }
+
+    // The settings of the optimizer hints apply to the query only.
+    fn set_hint_settings(ctx: &Arc<QueryContext>, hints: &[DfHint]) -> Result<()> {
+        let settings = hints
+            .iter()
+            .flat_map(|hint| hint.settings.iter().cloned())
+            .collect::<Vec<_>>();
+        match settings.is_empty() {
+            true => Ok(()),
+            false => ctx.set_query_settings(&settings),
         }
     }
 
+    #[tracing::instrument(level = "debug", name = "plan_parser_build_plan", skip(statements, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub async fn build_plan(
         statements: Vec<DfStatement<'_>>,
         ctx: Arc<QueryContext>,
@@ -76,9 +94,21 @@ impl PlanParser {
         let from = Self::build_from_plan(data)?;
         let filter = Self::build_filter_plan(from, data)?;
         let group_by = Self::build_group_by_plan(filter, data)?;
-        let before_order = Self::build_before_order(group_by, data)?;
-        let having = Self::build_having_plan(before_order, data)?;
-        let order_by = Self::build_order_by_plan(having, data)?;
+        let order_by = match data.window_expressions.is_empty() {
+            true => {
+                let before_order = Self::build_before_order(group_by, data)?;
+                let having = Self::build_having_plan(before_order, data)?;
+                Self::build_order_by_plan(having, data)?
+            }
+            // The window functions are evaluated on the rows filtered by HAVING.
+            false => {
+                let before_window = Self::build_before_window(group_by, data)?;
+                let having = Self::build_having_plan(before_window, data)?;
+                let window = Self::build_window_plan(having, data)?;
+                let before_order = Self::build_before_order(window, data)?;
+                Self::build_order_by_plan(before_order, data)?
+            }
+        };
         let projection = Self::build_projection_plan(order_by, data)?;
         let limit = Self::build_limit_plan(projection, data)?;
 
@@ -144,6 +174,31 @@ impl PlanParser {
         }
     }
 
+    fn build_before_window(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        fn is_all_column(exprs: &[Expression]) -> bool {
+            exprs
+                .iter()
+                .all(|expr| matches!(expr, Expression::Column(_)))
+        }
+
+        match data.before_window_expressions.is_empty() {
+            true => Ok(plan),
+            // if all expression is column expression expression, we skip this expression
+            false if is_all_column(&data.before_window_expressions) => Ok(plan),
+            false => PlanBuilder::from(&plan)
+                .expression(&data.before_window_expressions, "Before Window")?
+                .build(),
+        }
+    }
+
+    fn build_window_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        let mut builder = PlanBuilder::from(&plan);
+        for window_expression in &data.window_expressions {
+            builder = builder.window_func(window_expression.clone())?;
+        }
+        builder.build()
+    }
+
     fn build_having_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         match &data.having {
             None => Ok(plan),
@@ -16,6 +16,7 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
+use sqlparser::ast::ObjectName;
 
 pub struct SQLCommon;
 
@@ -32,12 +33,16 @@ impl SQLCommon {
             | SQLDataType::String
             | SQLDataType::Text => Ok(Vu8::to_data_type()),
 
-            SQLDataType::Decimal(_, _) => Ok(f64::to_data_type()),
+            SQLDataType::Decimal(precision, scale) => DecimalType::try_arc(
+                precision.unwrap_or(DECIMAL_DEFAULT_PRECISION as u64) as usize,
+                scale.unwrap_or(DECIMAL_DEFAULT_SCALE as u64) as usize,
+            ),
             SQLDataType::Float(_) => Ok(f32::to_data_type()),
             SQLDataType::Real | SQLDataType::Double => Ok(f64::to_data_type()),
             SQLDataType::Boolean => Ok(bool::to_data_type()),
             SQLDataType::Date => Ok(Date16Type::arc()),
-            SQLDataType::Timestamp => Ok(DateTime32Type::arc(None)),
+            SQLDataType::Timestamp => Ok(DateTime64Type::arc(6, None)),
+            SQLDataType::Array(inner) => Ok(ArrayType::arc(Self::make_data_type(inner)?)),
 
             //custom types for databend
             // Custom(ObjectName([Ident { value: "uint8", quote_style: None }])
@@ -58,4 +63,32 @@ impl SQLCommon {
             ))),
         }
     }
+
+    /// Maps the SQL type of a column, which may have a `COLLATE` clause.
+    pub fn make_collated_data_type(
+        sql_type: &SQLDataType,
+        collation: &Option<ObjectName>,
+    ) -> Result<DataTypePtr> {
+        let data_type = Self::make_data_type(sql_type)?;
+        match collation {
+            None => Ok(data_type),
+            Some(collation) => Self::collate_data_type(&data_type, &collation.to_string()),
+        }
+    }
+
+    /// Only strings can have a collation.
+    pub fn collate_data_type(data_type: &DataTypePtr, collation: &str) -> Result<DataTypePtr> {
+        if remove_nullable(data_type).data_type_id() != TypeID::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "COLLATE can only be applied to strings, but got {:?}",
+                data_type
+            )));
+        }
+
+        let collated = StringType::arc_with_collation(Collation::from_name(collation)?);
+        match data_type.is_nullable() {
+            true => Ok(wrap_nullable(&collated)),
+            false => Ok(collated),
+        }
+    }
 }
@@ -35,7 +35,6 @@ use super::statements::DfShowRoles;
 use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowMetrics;
 use crate::sql::statements::DfShowProcessList;
-use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
@@ -64,7 +63,8 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &'a str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let (tokens, position_map) = tokenizer.tokenize()?;
+        let (mut tokens, position_map) = tokenizer.tokenize()?;
+        Self::rewrite_lambda_tokens(&mut tokens);
 
         Ok(DfParser {
             sql,
@@ -72,6 +72,68 @@ impl<'a> DfParser<'a> {
         })
     }
 
+    /// Rewrites the lambdas in function arguments to the named arguments the sqlparser accepts,
+    /// `x -> x + 1` is rewritten to `x => x + 1`, and `(acc, x) -> acc + x` to
+    /// `` `acc,x` => acc + x ``. The tokens are replaced in place to keep the positions of the
+    /// others, the parameters are split from the name of the argument in analyzing.
+    fn rewrite_lambda_tokens(tokens: &mut [Token]) {
+        let space = || Token::Whitespace(Whitespace::Space);
+        let prev_token = |tokens: &[Token], end: usize| {
+            (0..end)
+                .rev()
+                .find(|i| !matches!(tokens[*i], Token::Whitespace(_)))
+        };
+
+        for arrow in 0..tokens.len().saturating_sub(1) {
+            if tokens[arrow] != Token::Minus || tokens[arrow + 1] != Token::Gt {
+                continue;
+            }
+
+            let params_end = match prev_token(tokens, arrow) {
+                Some(end) => end,
+                None => continue,
+            };
+            let (params_start, params) = match &tokens[params_end] {
+                Token::Word(_) => (params_end, None),
+                Token::RParen => {
+                    let mut params = vec![];
+                    let mut start = None;
+                    for i in (0..params_end).rev() {
+                        match &tokens[i] {
+                            Token::Word(w) => params.insert(0, w.value.clone()),
+                            Token::Comma | Token::Whitespace(_) => {}
+                            Token::LParen => {
+                                start = Some(i);
+                                break;
+                            }
+                            _ => break,
+                        }
+                    }
+                    match start {
+                        Some(start) if !params.is_empty() => (start, Some(params)),
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+
+            // Only the arguments of functions, e.g. not the `AS (p) -> ...` of CREATE FUNCTION.
+            match prev_token(tokens, params_start) {
+                Some(i) if matches!(tokens[i], Token::LParen | Token::Comma) => {}
+                _ => continue,
+            }
+
+            if let Some(params) = params {
+                tokens[params_start] = Token::make_word(&params.join(","), Some('`'));
+                for token in tokens[params_start + 1..=params_end].iter_mut() {
+                    *token = space();
+                }
+            }
+            tokens[arrow] = Token::RArrow;
+            tokens[arrow + 1] = space();
+        }
+    }
+
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &'a str) -> Result<(Vec<DfStatement<'a>>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
@@ -111,13 +173,23 @@ impl<'a> DfParser<'a> {
         let mut hints = Vec::new();
 
         let mut parser = DfParser::new_with_dialect(sql, dialect)?;
+        // The comment hints are on the first line, the optimizer hints are anywhere.
+        let mut first_line = true;
         loop {
             let token = parser.parser.next_token_no_skip();
             match token {
-                Some(Token::Whitespace(Whitespace::SingleLineComment { comment, prefix })) => {
+                Some(Token::Whitespace(Whitespace::SingleLineComment { comment, prefix }))
+                    if first_line =>
+                {
                     hints.push(DfHint::create_from_comment(comment, prefix));
                 }
-                Some(Token::Whitespace(Whitespace::Newline)) | Some(Token::EOF) | None => break,
+                Some(Token::Whitespace(Whitespace::MultiLineComment(comment))) => {
+                    if let Some(comment) = comment.strip_prefix('+') {
+                        hints.push(DfHint::create_from_optimizer_hint(comment));
+                    }
+                }
+                Some(Token::Whitespace(Whitespace::Newline)) => first_line = false,
+                Some(Token::EOF) | None => break,
                 _ => continue,
             }
         }
@@ -196,6 +268,8 @@ impl<'a> DfParser<'a> {
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "OPTIMIZE" => self.parse_optimize(),
+                        "UNDROP" => self.parse_undrop_table(),
+                        "VACUUM" => self.parse_vacuum_drop_table(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -212,12 +286,29 @@ impl<'a> DfParser<'a> {
                 //TODO:make stage to sql parser keyword
                 match w.keyword {
                     Keyword::TABLE => self.parse_create_table(),
+                    Keyword::EXTERNAL => self.parse_create_external_table(),
                     Keyword::DATABASE => self.parse_create_database(),
                     Keyword::USER => self.parse_create_user(),
                     Keyword::ROLE => self.parse_create_role(),
-                    Keyword::FUNCTION => self.parse_create_udf(),
+                    Keyword::FUNCTION => self.parse_create_udf(false),
                     Keyword::STAGE => self.parse_create_stage(),
                     Keyword::VIEW => self.parse_create_view(),
+                    _ if w.value.eq_ignore_ascii_case("AGGREGATE") => {
+                        self.parser.expect_keyword(Keyword::FUNCTION)?;
+                        self.parse_create_udf(true)
+                    }
+                    _ if w.value.eq_ignore_ascii_case("ROW") => {
+                        self.parser.prev_token();
+                        self.parse_create_row_access_policy()
+                    }
+                    _ if w.value.eq_ignore_ascii_case("MASKING") => {
+                        self.parser.prev_token();
+                        self.parse_create_masking_policy()
+                    }
+                    _ if w.value.eq_ignore_ascii_case("NETWORK") => {
+                        self.parser.prev_token();
+                        self.parse_create_network_policy()
+                    }
                     _ => self.expected("create statement", Token::Word(w)),
                 }
             }
@@ -278,9 +369,13 @@ impl<'a> DfParser<'a> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::USER => self.parse_alter_user(),
-                Keyword::FUNCTION => self.parse_alter_udf(),
+                Keyword::FUNCTION => self.parse_alter_udf(false),
                 Keyword::TABLE => self.parse_alter_table(),
                 Keyword::VIEW => self.parse_alter_view(),
+                _ if w.value.eq_ignore_ascii_case("AGGREGATE") => {
+                    self.parser.expect_keyword(Keyword::FUNCTION)?;
+                    self.parse_alter_udf(true)
+                }
                 _ => self.expected("keyword USER or FUNCTION", Token::Word(w)),
             },
             unexpected => self.expected("alter statement", unexpected),
@@ -318,6 +413,22 @@ impl<'a> DfParser<'a> {
                 Keyword::FUNCTION => self.parse_drop_udf(),
                 Keyword::STAGE => self.parse_drop_stage(),
                 Keyword::VIEW => self.parse_drop_view(),
+                _ if w.value.eq_ignore_ascii_case("AGGREGATE") => {
+                    self.parser.expect_keyword(Keyword::FUNCTION)?;
+                    self.parse_drop_udf()
+                }
+                _ if w.value.eq_ignore_ascii_case("ROW") => {
+                    self.parser.prev_token();
+                    self.parse_drop_row_access_policy()
+                }
+                _ if w.value.eq_ignore_ascii_case("MASKING") => {
+                    self.parser.prev_token();
+                    self.parse_drop_masking_policy()
+                }
+                _ if w.value.eq_ignore_ascii_case("NETWORK") => {
+                    self.parser.prev_token();
+                    self.parse_drop_network_policy()
+                }
                 _ => self.expected("drop statement", Token::Word(w)),
             },
             unexpected => self.expected("drop statement", unexpected),
@@ -333,7 +444,7 @@ impl<'a> DfParser<'a> {
         } else if self.consume_token("DATABASES") {
             self.parse_show_databases()
         } else if self.consume_token("SETTINGS") {
-            Ok(DfStatement::ShowSettings(DfShowSettings))
+            self.parse_show_settings()
         } else if self.consume_token("CREATE") {
             self.parse_show_create()
         } else if self.consume_token("PROCESSLIST") {
@@ -391,7 +502,22 @@ impl<'a> DfParser<'a> {
                 self.parser.prev_token();
                 break;
             }
-            let value = self.parse_value_or_ident()?;
+            // A parenthesized list of values is kept as the SQL text of the quoted values,
+            // e.g. null_if = ('NULL', '').
+            let value = if self.consume_token("(") {
+                let mut list = vec![];
+                if !self.consume_token(")") {
+                    list = self.parse_list(&Token::Comma)?;
+                    self.expect_token(")")?;
+                }
+                let list = list
+                    .iter()
+                    .map(|v| format!("'{}'", v.replace('\'', "''")))
+                    .collect::<Vec<_>>();
+                format!("({})", list.join(", "))
+            } else {
+                self.parse_value_or_ident()?
+            };
 
             options.insert(name.to_string().to_lowercase(), value);
         }
@@ -420,25 +546,6 @@ impl<'a> DfParser<'a> {
         }
     }
 
-    pub(crate) fn consume_token_until_or_end(&mut self, until_tokens: Vec<&str>) -> Vec<String> {
-        let mut tokens = vec![];
-
-        loop {
-            let next_token = self.parser.peek_token();
-
-            if next_token == Token::EOF
-                || next_token == Token::SemiColon
-                || until_tokens.contains(&next_token.to_string().to_uppercase().as_str())
-            {
-                break;
-            }
-
-            tokens.push(self.parser.next_token().to_string());
-        }
-
-        tokens
-    }
-
     pub(crate) fn expect_token(&mut self, expected: &str) -> Result<(), ParserError> {
         if self.consume_token(expected) {
             Ok(())
@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use crate::sessions::SECRET_SETTINGS;
+
+/// The string literal replacing the secrets in the redacted query text.
+pub const REDACTED_SECRET: &str = "'******'";
+
+#[derive(PartialEq)]
+enum Lexeme {
+    Word,
+    String,
+    Punct(char),
+    Space,
+}
+
+/// The text of the query with the secrets replaced, to be kept in the logs:
+/// - the password of `CREATE/ALTER USER ... IDENTIFIED [WITH <method>] BY '<password>'`.
+/// - the string values in `CREDENTIALS = (...)` and `ENCRYPTION = (...)` of the stages, the
+///   external tables and `COPY`.
+/// - the value of the secret settings, like `SET aes_encryption_key = '<key>'`.
+pub fn redact_query_text(query: &str) -> String {
+    let lexemes = lex(query);
+    let words = lexemes
+        .iter()
+        .filter(|(lexeme, _)| *lexeme != Lexeme::Space)
+        .collect::<Vec<_>>();
+    let word_is = |i: usize, expected: &str| match words.get(i) {
+        Some((Lexeme::Word, range)) => query[range.clone()].eq_ignore_ascii_case(expected),
+        _ => false,
+    };
+
+    let mut secrets = vec![];
+    let mut i = 0;
+    while i < words.len() {
+        if word_is(i, "CREDENTIALS") || word_is(i, "ENCRYPTION") {
+            // All the string values in the parentheses are secrets.
+            let mut j = i + 1;
+            if matches!(words.get(j), Some((Lexeme::Punct('='), _))) {
+                j += 1;
+            }
+            if matches!(words.get(j), Some((Lexeme::Punct('('), _))) {
+                while j < words.len() && !matches!(words[j], (Lexeme::Punct(')'), _)) {
+                    if words[j].0 == Lexeme::String {
+                        secrets.push(words[j].1.clone());
+                    }
+                    j += 1;
+                }
+                i = j;
+            }
+        } else if word_is(i, "IDENTIFIED") {
+            // IDENTIFIED [WITH <method>] BY '<password>'
+            let by = match word_is(i + 1, "WITH") {
+                true => i + 3,
+                false => i + 1,
+            };
+            if word_is(by, "BY") {
+                if let Some((Lexeme::String, range)) = words.get(by + 1) {
+                    secrets.push(range.clone());
+                }
+            }
+        } else if SECRET_SETTINGS.iter().any(|setting| word_is(i, *setting)) {
+            // <setting> = '<value>'
+            if let (Some((Lexeme::Punct('='), _)), Some((Lexeme::String, range))) =
+                (words.get(i + 1), words.get(i + 2))
+            {
+                secrets.push(range.clone());
+            }
+        }
+        i += 1;
+    }
+
+    let mut redacted = String::with_capacity(query.len());
+    let mut end = 0;
+    for secret in secrets {
+        redacted.push_str(&query[end..secret.start]);
+        redacted.push_str(REDACTED_SECRET);
+        end = secret.end;
+    }
+    redacted.push_str(&query[end..]);
+    redacted
+}
+
+// Split the query into the words, the string literals, the punctuations and the spaces with the
+// comments, the quoted identifiers are words.
+fn lex(query: &str) -> Vec<(Lexeme, Range<usize>)> {
+    let mut lexemes = vec![];
+    let mut chars = query.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+        let lexeme = match c {
+            '\'' | '"' | '`' => {
+                // The quote is escaped by a backslash or by doubling it.
+                let mut escaped = false;
+                while let Some((i, q)) = chars.next() {
+                    end = i + q.len_utf8();
+                    match escaped {
+                        true => escaped = false,
+                        false if q == '\\' => escaped = true,
+                        false if q == c => match chars.peek() {
+                            Some((_, next)) if *next == c => escaped = true,
+                            _ => break,
+                        },
+                        false => {}
+                    }
+                }
+                match c {
+                    '\'' => Lexeme::String,
+                    _ => Lexeme::Word,
+                }
+            }
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                for (i, q) in chars.by_ref() {
+                    end = i + q.len_utf8();
+                    if q == '\n' {
+                        break;
+                    }
+                }
+                Lexeme::Space
+            }
+            // The hints like `/*+ SET_VAR(...) */` are lexed as the query.
+            '/' if query[start..].starts_with("/*") && !query[start..].starts_with("/*+") => {
+                let mut star = false;
+                chars.next();
+                for (i, q) in chars.by_ref() {
+                    end = i + q.len_utf8();
+                    if star && q == '/' {
+                        break;
+                    }
+                    star = q == '*';
+                }
+                Lexeme::Space
+            }
+            c if c.is_whitespace() => Lexeme::Space,
+            c if c.is_alphanumeric() || c == '_' || c == '$' => {
+                while let Some((i, q)) = chars.peek() {
+                    if !(q.is_alphanumeric() || *q == '_' || *q == '$') {
+                        break;
+                    }
+                    end = i + q.len_utf8();
+                    chars.next();
+                }
+                Lexeme::Word
+            }
+            c => Lexeme::Punct(c),
+        };
+        lexemes.push((lexeme, start..end));
+    }
+    lexemes
+}
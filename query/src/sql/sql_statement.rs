@@ -22,6 +22,7 @@ use nom::IResult;
 use super::statements::DfAlterView;
 use super::statements::DfCall;
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoLocation;
 use super::statements::DfCreateUserStage;
 use super::statements::DfDescribeUserStage;
 use super::statements::DfDropUserStage;
@@ -33,14 +34,20 @@ use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCreateDatabase;
+use crate::sql::statements::DfCreateMaskingPolicy;
+use crate::sql::statements::DfCreateNetworkPolicy;
 use crate::sql::statements::DfCreateRole;
+use crate::sql::statements::DfCreateRowAccessPolicy;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
 use crate::sql::statements::DfCreateView;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
+use crate::sql::statements::DfDropMaskingPolicy;
+use crate::sql::statements::DfDropNetworkPolicy;
 use crate::sql::statements::DfDropRole;
+use crate::sql::statements::DfDropRowAccessPolicy;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
@@ -52,6 +59,7 @@ use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokePrivilegeStatement;
+use crate::sql::statements::DfSetRole;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
 use crate::sql::statements::DfShowCreateTable;
@@ -67,7 +75,9 @@ use crate::sql::statements::DfShowTabStat;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
 use crate::sql::statements::DfUseDatabase;
+use crate::sql::statements::DfVacuumDropTable;
 
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
@@ -90,6 +100,8 @@ pub enum DfStatement<'a> {
     CreateTable(DfCreateTable),
     DescribeTable(DfDescribeTable),
     DropTable(DfDropTable),
+    UndropTable(DfUndropTable),
+    VacuumDropTable(DfVacuumDropTable),
     AlterTable(DfAlterTable),
     TruncateTable(DfTruncateTable),
     OptimizeTable(DfOptimizeTable),
@@ -131,10 +143,12 @@ pub enum DfStatement<'a> {
     // Role
     CreateRole(DfCreateRole),
     DropRole(DfDropRole),
+    SetRole(DfSetRole),
     ShowRoles(DfShowRoles),
 
     // Copy
     Copy(DfCopy),
+    CopyIntoLocation(DfCopyIntoLocation),
 
     // Stage
     CreateStage(DfCreateUserStage),
@@ -159,6 +173,18 @@ pub enum DfStatement<'a> {
     DropUDF(DfDropUDF),
     AlterUDF(DfAlterUDF),
 
+    // Row access policy
+    CreateRowAccessPolicy(DfCreateRowAccessPolicy),
+    DropRowAccessPolicy(DfDropRowAccessPolicy),
+
+    // Masking policy
+    CreateMaskingPolicy(DfCreateMaskingPolicy),
+    DropMaskingPolicy(DfDropMaskingPolicy),
+
+    // Network policy
+    CreateNetworkPolicy(DfCreateNetworkPolicy),
+    DropNetworkPolicy(DfDropNetworkPolicy),
+
     // Engine
     ShowEngines(DfShowEngines),
 }
@@ -166,11 +192,15 @@ pub enum DfStatement<'a> {
 /// Comment hints from SQL.
 /// It'll be enabled when using `--comment` in mysql client.
 /// Eg: `SELECT * FROM system.number LIMIT 1; -- { ErrorCode 25 }`
+///
+/// The optimizer hints override the settings for the query only.
+/// Eg: `SELECT /*+ SET_VAR(max_threads = 4) */ * FROM t`
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfHint {
     pub error_code: Option<u16>,
     pub comment: String,
     pub prefix: String,
+    pub settings: Vec<(String, String)>,
 }
 
 impl DfHint {
@@ -184,7 +214,43 @@ impl DfHint {
             error_code,
             comment: comment.to_owned(),
             prefix: prefix.to_owned(),
+            settings: vec![],
+        }
+    }
+
+    // The comment of `/*+ ... */` without the `+`.
+    pub fn create_from_optimizer_hint(comment: &str) -> Self {
+        Self {
+            error_code: None,
+            comment: comment.to_owned(),
+            prefix: "/*+".to_owned(),
+            settings: Self::parse_settings(comment),
+        }
+    }
+
+    //  SET_VAR(max_threads = 4) SET_VAR(timezone = 'UTC')
+    pub fn parse_settings(comment: &str) -> Vec<(String, String)> {
+        const SET_VAR: &str = "SET_VAR(";
+
+        let mut settings = vec![];
+        let mut rest = comment;
+        while let Some(start) = rest.to_ascii_uppercase().find(SET_VAR) {
+            let args = &rest[start + SET_VAR.len()..];
+            let end = match args.find(')') {
+                Some(end) => end,
+                None => break,
+            };
+            if let Some((name, value)) = args[..end].split_once('=') {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('\'')
+                    .and_then(|v| v.strip_suffix('\''))
+                    .unwrap_or(value);
+                settings.push((name.trim().to_lowercase(), value.to_string()));
+            }
+            rest = &args[end + 1..];
         }
+        settings
     }
 
     //  { ErrorCode 25 }
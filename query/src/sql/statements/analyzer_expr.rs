@@ -16,6 +16,7 @@ use std::convert::TryFrom;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono_tz::Tz;
 use common_ast::parser::expr::ExprTraverser;
 use common_ast::parser::expr::ExprVisitor;
 use common_ast::udfs::UDFDefinition;
@@ -27,23 +28,41 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::is_window_function;
+use common_functions::scalars::WasmUDFFunction;
+use common_meta_types::UDFLanguage;
+use common_meta_types::UDFWasmModule;
 use common_planners::Expression;
+use common_planners::WindowFrame;
+use common_planners::WindowFrameBound;
+use common_planners::WindowFrameUnits;
+use futures::AsyncReadExt;
+use sqlparser::ast::DataType as SQLDataType;
 use sqlparser::ast::DateTimeField;
 use sqlparser::ast::Expr;
+use sqlparser::ast::Function;
+use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
+use sqlparser::ast::WindowFrame as SQLWindowFrame;
+use sqlparser::ast::WindowFrameBound as SQLWindowFrameBound;
+use sqlparser::ast::WindowFrameUnits as SQLWindowFrameUnits;
+use sqlparser::ast::WindowSpec;
 
 use crate::procedures::ContextFunction;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_value_expr::ValueExprAnalyzer;
+use crate::sql::statements::location_to_stage_path;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::StageSource;
 
 #[derive(Clone)]
 pub struct ExpressionAnalyzer {
@@ -68,7 +87,8 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Wildcard => self.analyze_wildcard(&mut stack)?,
                 ExprRPNItem::Exists(v) => self.analyze_exists(v, &mut stack).await?,
                 ExprRPNItem::Subquery(v) => self.analyze_scalar_subquery(v, &mut stack).await?,
-                ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
+                ExprRPNItem::Cast(v) => self.analyze_cast(v, false, &mut stack)?,
+                ExprRPNItem::TryCast(v) => self.analyze_cast(v, true, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
                 ExprRPNItem::InList(v) => self.analyze_inlist(v, &mut stack)?,
             }
@@ -127,6 +147,18 @@ impl ExpressionAnalyzer {
     }
 
     fn analyze_function(&self, info: &FunctionExprInfo, args: &mut Vec<Expression>) -> Result<()> {
+        // The expressions of the window are on the top of the arguments.
+        let window_exprs = match &info.window {
+            None => vec![],
+            Some(window) => {
+                let window_exprs_count = window.partition_by_count + window.order_by.len();
+                match args.len() < window_exprs_count {
+                    true => return Err(ErrorCode::LogicalError("It's a bug.")),
+                    false => args.split_off(args.len() - window_exprs_count),
+                }
+            }
+        };
+
         let mut arguments = Vec::with_capacity(info.args_count);
         for _ in 0..info.args_count {
             match args.pop() {
@@ -139,6 +171,24 @@ impl ExpressionAnalyzer {
             }
         }
 
+        for (index, params) in &info.lambdas {
+            arguments[*index] = Expression::Lambda {
+                params: params.clone(),
+                body: Box::new(arguments[*index].clone()),
+            };
+        }
+
+        if let Some(window) = &info.window {
+            args.push(self.window_function(info, window, &arguments, window_exprs)?);
+            return Ok(());
+        }
+        if is_window_function(&info.name) {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Window function {} requires an OVER clause",
+                info.name
+            )));
+        }
+
         args.push(
             match AggregateFunctionFactory::instance().check(&info.name) {
                 true => self.aggr_function(info, &arguments),
@@ -178,6 +228,13 @@ impl ExpressionAnalyzer {
 
     /// Function to process when args's size is more than 2.
     fn other_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        if let Some(expr) = self.timezone_function(info, args)? {
+            return Ok(expr);
+        }
+        if let Some(expr) = self.encryption_function(info, args)? {
+            return Ok(expr);
+        }
+
         let query_context = self.context.clone();
         let context_args = ContextFunction::build_args_from_ctx(query_context, &info.name)?;
 
@@ -200,7 +257,122 @@ impl ExpressionAnalyzer {
         }
     }
 
+    /// now() and toDateTime(x) follow the session timezone, toTimeZone(x, 'tz') converts x to a
+    /// Timestamp in the given timezone. They are all rewritten to casts with the timezone in type.
+    fn timezone_function(
+        &self,
+        info: &FunctionExprInfo,
+        args: &[Expression],
+    ) -> Result<Option<Expression>> {
+        let name = info.name.to_lowercase();
+        let cast = |expr: Expression, data_type: DataTypePtr| Expression::Cast {
+            expr: Box::new(expr),
+            data_type,
+            is_nullable: false,
+        };
+
+        match (name.as_str(), args) {
+            ("totimezone", [expr, Expression::Literal { value, .. }]) => {
+                let tz = String::from_utf8(value.as_string()?)?;
+                Self::check_timezone(&tz)?;
+                let data_type = DateTime64Type::arc(6, Some(tz));
+                Ok(Some(cast(expr.clone(), data_type)))
+            }
+            ("totimezone", _) => Err(ErrorCode::BadArguments(
+                "toTimeZone expects a datetime and a constant timezone, e.g: toTimeZone(now(), 'Asia/Shanghai')",
+            )),
+            ("now", [])
+            | ("todatetime", [_])
+            | ("todatetime32", [_])
+            | ("todatetime64", [_])
+            | ("totimestamp", [_]) => {
+                let tz = String::from_utf8(self.context.get_settings().get_timezone()?)?;
+                if tz == "UTC" {
+                    return Ok(None);
+                }
+
+                let expr = match args {
+                    [arg] => {
+                        let data_type = TypeFactory::instance().get(&info.name[2..])?;
+                        cast(arg.clone(), Self::datetime_with_timezone(data_type, tz))
+                    }
+                    _ => cast(
+                        Expression::create_scalar_function(&info.name, vec![]),
+                        DateTime32Type::arc(Some(tz)),
+                    ),
+                };
+                Ok(Some(expr))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// aes_encrypt(x) and aes_decrypt(x) take the key from the aes_encryption_key setting, and the
+    /// mode from the block_encryption_mode setting if it is omitted.
+    fn encryption_function(
+        &self,
+        info: &FunctionExprInfo,
+        args: &[Expression],
+    ) -> Result<Option<Expression>> {
+        let name = info.name.to_lowercase();
+        match name.as_str() {
+            "aes_encrypt" | "aes_decrypt" | "try_aes_decrypt" if matches!(args.len(), 1 | 2) => {}
+            _ => return Ok(None),
+        }
+
+        let settings = self.context.get_settings();
+        let mut args = args.to_vec();
+        if args.len() == 1 {
+            let key = hex::decode(settings.get_aes_encryption_key()?).map_err(|_| {
+                ErrorCode::BadArguments("Invalid aes_encryption_key, expected a hex encoded key")
+            })?;
+            if key.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} expects a key, either as the argument or from the aes_encryption_key setting",
+                    info.name
+                )));
+            }
+            // Name the literal after the setting so the key never shows up in the column names.
+            args.push(Expression::Literal {
+                value: DataValue::String(key),
+                column_name: Some("aes_encryption_key".to_string()),
+                data_type: StringType::arc(),
+            });
+        }
+        let mode = settings.get_block_encryption_mode()?;
+        args.push(Expression::create_literal(DataValue::String(mode)));
+
+        Ok(Some(Expression::ScalarFunction {
+            op: info.name.clone(),
+            args,
+        }))
+    }
+
+    fn datetime_with_timezone(data_type: &DataTypePtr, tz: String) -> DataTypePtr {
+        match data_type.as_any().downcast_ref::<DateTime64Type>() {
+            Some(datetime) => DateTime64Type::arc(datetime.precision(), Some(tz)),
+            None => DateTime32Type::arc(Some(tz)),
+        }
+    }
+
+    fn check_timezone(tz: &str) -> Result<()> {
+        match tz.parse::<Tz>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ErrorCode::BadArguments(format!(
+                "Invalid timezone: {:?}",
+                tz
+            ))),
+        }
+    }
+
     fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
+        if !info.lambdas.is_empty() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Aggregate function {} doesn't accept a lambda argument",
+                info.name
+            )));
+        }
+
         let mut parameters = Vec::with_capacity(info.parameters.len());
 
         for parameter in &info.parameters {
@@ -217,6 +389,19 @@ impl ExpressionAnalyzer {
             };
         }
 
+        // group_concat(expr, 'separator', key, ...) takes the separator as a parameter.
+        let mut args = args.to_vec();
+        if info.name.eq_ignore_ascii_case("group_concat") && parameters.is_empty() {
+            if let Some(Expression::Literal {
+                value: value @ DataValue::String(_),
+                ..
+            }) = args.get(1)
+            {
+                parameters.push(value.clone());
+                args.remove(1);
+            }
+        }
+
         let optimize_remove_count_args = info.name.eq_ignore_ascii_case("count")
             && !info.distinct
             && (args.len() == 1 && matches!(args[0], Expression::Wildcard)
@@ -235,12 +420,85 @@ impl ExpressionAnalyzer {
             Ok(Expression::AggregateFunction {
                 op: info.name.clone(),
                 distinct: info.distinct,
-                args: args.to_owned(),
+                args,
                 params: parameters,
             })
         }
     }
 
+    /// The aggregate functions and the window functions like `lag` are evaluated over the window.
+    fn window_function(
+        &self,
+        info: &FunctionExprInfo,
+        window: &WindowExprInfo,
+        args: &[Expression],
+        mut window_exprs: Vec<Expression>,
+    ) -> Result<Expression> {
+        let order_by = window_exprs
+            .split_off(window.partition_by_count)
+            .into_iter()
+            .zip(window.order_by.iter())
+            .map(|(expr, (asc, nulls_first))| Expression::Sort {
+                expr: Box::new(expr.clone()),
+                asc: *asc,
+                nulls_first: *nulls_first,
+                origin_expr: Box::new(expr),
+            })
+            .collect();
+        let partition_by = window_exprs;
+
+        let (op, distinct, params, args) = if AggregateFunctionFactory::instance().check(&info.name)
+        {
+            match self.aggr_function(info, args)? {
+                Expression::AggregateFunction {
+                    op,
+                    distinct,
+                    params,
+                    args,
+                } => (op, distinct, params, args),
+                _ => return Err(ErrorCode::LogicalError("It's a bug.")),
+            }
+        } else if is_window_function(&info.name) {
+            let name = info.name.to_lowercase();
+            let args_range = match name.as_str() {
+                "lag" | "lead" => 1..=3,
+                "first_value" | "last_value" => 1..=1,
+                _ => 0..=0,
+            };
+            if info.distinct || !info.parameters.is_empty() || !info.lambdas.is_empty() {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Window function {} doesn't accept DISTINCT, parameters or lambdas",
+                    info.name
+                )));
+            }
+            if !args_range.contains(&args.len()) {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "Window function {} expects {} to {} arguments, but got {}",
+                    info.name,
+                    args_range.start(),
+                    args_range.end(),
+                    args.len()
+                )));
+            }
+            (name, false, vec![], args.to_vec())
+        } else {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Function {} is neither an aggregate function nor a window function, it can't be used with OVER",
+                info.name
+            )));
+        };
+
+        Ok(Expression::WindowFunction {
+            op,
+            distinct,
+            params,
+            args,
+            partition_by,
+            order_by,
+            window_frame: window.window_frame,
+        })
+    }
+
     fn analyze_identifier(&self, ident: &Ident, arguments: &mut Vec<Expression>) -> Result<()> {
         let column_name = ident.clone().value;
         arguments.push(Expression::Column(column_name));
@@ -320,16 +578,38 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
-    fn analyze_cast(&self, data_type: &DataTypePtr, args: &mut Vec<Expression>) -> Result<()> {
+    fn analyze_cast(
+        &self,
+        data_type: &DataTypePtr,
+        is_nullable: bool,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
         match args.pop() {
             None => Err(ErrorCode::LogicalError(
                 "Cast operator must be one children.",
             )),
             Some(inner_expr) => {
+                // Datetime literals and casts are in the session timezone.
+                let mut data_type = data_type.clone();
+                if matches!(
+                    data_type.data_type_id(),
+                    TypeID::DateTime32 | TypeID::DateTime64
+                ) {
+                    let tz = String::from_utf8(self.context.get_settings().get_timezone()?)?;
+                    if tz != "UTC" {
+                        data_type = Self::datetime_with_timezone(&data_type, tz);
+                    }
+                }
+
+                // TRY_CAST returns NULL for the values failed to cast.
+                if is_nullable && !data_type.is_nullable() && data_type.can_inside_nullable() {
+                    data_type = Arc::new(NullableType::create(data_type));
+                }
+
                 args.push(Expression::Cast {
                     expr: Box::new(inner_expr),
-                    data_type: data_type.clone(),
-                    is_nullable: false,
+                    data_type,
+                    is_nullable,
                 });
                 Ok(())
             }
@@ -377,6 +657,106 @@ struct FunctionExprInfo {
     args_count: usize,
     kind: OperatorKind,
     parameters: Vec<Value>,
+    // The indexes of the lambda arguments with their parameters.
+    lambdas: Vec<(usize, Vec<String>)>,
+    // The window of the function evaluated over a window.
+    window: Option<WindowExprInfo>,
+}
+
+// The PARTITION BY and then the ORDER BY expressions of the window follow the arguments of the
+// function in the RPN.
+struct WindowExprInfo {
+    partition_by_count: usize,
+    // The asc and the nulls_first of the ORDER BY expressions.
+    order_by: Vec<(bool, bool)>,
+    window_frame: Option<WindowFrame>,
+}
+
+impl WindowExprInfo {
+    fn try_create(window: &WindowSpec) -> Result<WindowExprInfo> {
+        let order_by = window
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let asc = order_by.asc.unwrap_or(true);
+                (asc, order_by.nulls_first.unwrap_or(asc))
+            })
+            .collect::<Vec<_>>();
+
+        let window_frame = match &window.window_frame {
+            None => None,
+            Some(window_frame) => Some(Self::window_frame(window_frame, order_by.len())?),
+        };
+
+        Ok(WindowExprInfo {
+            partition_by_count: window.partition_by.len(),
+            order_by,
+            window_frame,
+        })
+    }
+
+    fn window_frame(window_frame: &SQLWindowFrame, order_by_count: usize) -> Result<WindowFrame> {
+        let units = match window_frame.units {
+            SQLWindowFrameUnits::Rows => WindowFrameUnits::Rows,
+            SQLWindowFrameUnits::Range => WindowFrameUnits::Range,
+            SQLWindowFrameUnits::Groups => {
+                return Err(ErrorCode::UnImplement(
+                    "GROUPS window frames are not supported yet",
+                ))
+            }
+        };
+        let bound = |bound: &SQLWindowFrameBound| match bound {
+            SQLWindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+            SQLWindowFrameBound::Preceding(n) => WindowFrameBound::Preceding(*n),
+            SQLWindowFrameBound::Following(n) => WindowFrameBound::Following(*n),
+        };
+        let start_bound = bound(&window_frame.start_bound);
+        let end_bound = match &window_frame.end_bound {
+            None => WindowFrameBound::CurrentRow,
+            Some(end_bound) => bound(end_bound),
+        };
+
+        // The frame can't start after its end, e.g. `BETWEEN CURRENT ROW AND 1 PRECEDING`.
+        let bound_order = |bound: &WindowFrameBound| match bound {
+            WindowFrameBound::Preceding(None) => 0,
+            WindowFrameBound::Preceding(Some(_)) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(Some(_)) => 3,
+            WindowFrameBound::Following(None) => 4,
+        };
+        if matches!(start_bound, WindowFrameBound::Following(None))
+            || matches!(end_bound, WindowFrameBound::Preceding(None))
+            || bound_order(&start_bound) > bound_order(&end_bound)
+        {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Invalid window frame: {}",
+                window_frame
+            )));
+        }
+
+        // The offsets of RANGE are the distances from the value of the current row.
+        let has_offset = |bound: &WindowFrameBound| {
+            matches!(
+                bound,
+                WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+            )
+        };
+        if units == WindowFrameUnits::Range
+            && (has_offset(&start_bound) || has_offset(&end_bound))
+            && order_by_count != 1
+        {
+            return Err(ErrorCode::SyntaxException(format!(
+                "RANGE window frame with offset requires exactly one ORDER BY expression: {}",
+                window_frame
+            )));
+        }
+
+        Ok(WindowFrame {
+            units,
+            start_bound,
+            end_bound,
+        })
+    }
 }
 
 struct InListInfo {
@@ -393,6 +773,7 @@ enum ExprRPNItem {
     Exists(Box<Query>),
     Subquery(Box<Query>),
     Cast(DataTypePtr),
+    TryCast(DataTypePtr),
     Between(bool),
     InList(InListInfo),
 }
@@ -405,6 +786,8 @@ impl ExprRPNItem {
             args_count,
             kind: OperatorKind::Other,
             parameters: Vec::new(),
+            lambdas: Vec::new(),
+            window: None,
         })
     }
 
@@ -415,6 +798,8 @@ impl ExprRPNItem {
             args_count: 2,
             kind: OperatorKind::Binary,
             parameters: Vec::new(),
+            lambdas: Vec::new(),
+            window: None,
         })
     }
 
@@ -425,6 +810,8 @@ impl ExprRPNItem {
             args_count: 1,
             kind: OperatorKind::Unary,
             parameters: Vec::new(),
+            lambdas: Vec::new(),
+            window: None,
         })
     }
 }
@@ -444,6 +831,17 @@ impl ExprRPNBuilder {
         Ok(builder.rpn)
     }
 
+    /// Read the WASM module from its stage and check it, returns it encoded to be shipped in the
+    /// expression.
+    async fn load_wasm_module(&self, module: &UDFWasmModule) -> Result<String> {
+        let (stage, path) = location_to_stage_path(&module.stage_path, &self.context).await?;
+        let op = StageSource::get_op(&self.context, &stage).await?;
+        let mut reader = op.object(&path).reader().await?;
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        WasmUDFFunction::encode_module(&bytes)
+    }
+
     fn process_expr(&mut self, expr: &Expr) -> Result<()> {
         match expr {
             Expr::Value(value) => {
@@ -484,18 +882,48 @@ impl ExprRPNBuilder {
                 self.rpn.push(ExprRPNItem::Subquery(subquery.clone()));
             }
             Expr::Function(function) => {
+                let window = match &function.over {
+                    None => None,
+                    Some(window) => Some(WindowExprInfo::try_create(window)?),
+                };
+                // The lambdas are parsed as named arguments, e.g. `(acc, x) -> acc + x` is
+                // `` `acc,x` => acc + x ``.
+                let lambdas = function
+                    .args
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, arg)| match arg {
+                        FunctionArg::Named { name, .. } => {
+                            let params = name.value.split(',');
+                            Some((index, params.map(|p| p.trim().to_string()).collect()))
+                        }
+                        FunctionArg::Unnamed(_) => None,
+                    })
+                    .collect();
                 self.rpn.push(ExprRPNItem::Function(FunctionExprInfo {
                     name: function.name.to_string(),
                     distinct: function.distinct,
                     args_count: function.args.len(),
                     kind: OperatorKind::Other,
                     parameters: function.params.to_owned(),
+                    lambdas,
+                    window,
                 }));
             }
             Expr::Cast { data_type, .. } => {
                 self.rpn
                     .push(ExprRPNItem::Cast(SQLCommon::make_data_type(data_type)?));
             }
+            Expr::TryCast { data_type, .. } => {
+                self.rpn
+                    .push(ExprRPNItem::TryCast(SQLCommon::make_data_type(data_type)?));
+            }
+            Expr::Collate { collation, .. } => {
+                // `expr COLLATE name` is a cast to a string with the collation.
+                let collation = Collation::from_name(&collation.to_string())?;
+                self.rpn
+                    .push(ExprRPNItem::Cast(StringType::arc_with_collation(collation)));
+            }
             Expr::TypedString { data_type, value } => {
                 self.rpn.push(ExprRPNItem::Value(Value::SingleQuotedString(
                     value.to_string(),
@@ -564,6 +992,25 @@ impl ExprRPNBuilder {
                     .rpn
                     .push(ExprRPNItem::function(String::from("toSecond"), 1)),
             },
+            Expr::MapAccess { keys, .. } => {
+                // v:a.b[0] is get(get(get(v, 'a'), 'b'), 0)
+                for key in keys {
+                    let key = match key {
+                        Value::Number(_, _) => key.clone(),
+                        Value::SingleQuotedString(k)
+                        | Value::ColonString(k)
+                        | Value::PeriodString(k) => Value::SingleQuotedString(k.clone()),
+                        other => {
+                            return Err(ErrorCode::SyntaxException(format!(
+                                "Unsupported key in path access: {}",
+                                other
+                            )))
+                        }
+                    };
+                    self.rpn.push(ExprRPNItem::Value(key));
+                    self.rpn.push(ExprRPNItem::function(String::from("get"), 2));
+                }
+            }
             _ => (),
         }
 
@@ -580,6 +1027,26 @@ impl UDFFetcher for ExprRPNBuilder {
             .get_user_manager()
             .get_udf(&tenant, name)
             .await?;
+        if let (UDFLanguage::Wasm, Some(module)) = (udf.language, &udf.wasm_module) {
+            let encoded = self.load_wasm_module(module).await?;
+            return Ok(udf_call_definition(
+                "wasm_udf",
+                &encoded,
+                &module.handler,
+                &module.arg_types,
+                &module.return_type,
+            ));
+        }
+        if let (UDFLanguage::External, Some(server)) = (udf.language, &udf.server) {
+            return Ok(udf_call_definition(
+                "udf_server",
+                &server.address,
+                &server.handler,
+                &server.arg_types,
+                &server.return_type,
+            ));
+        }
+
         let mut udf_parser = UDFParser::default();
         let definition = udf_parser
             .parse(&udf.name, &udf.parameters, &udf.definition)
@@ -589,6 +1056,43 @@ impl UDFFetcher for ExprRPNBuilder {
     }
 }
 
+/// The external UDF `f(a, b)` is `udf_server('<address>', '<handler>', '<return type>',
+/// CAST(a AS <arg type>), CAST(b AS <arg type>))`, the server always gets the declared types.
+/// So is the WASM UDF by `wasm_udf('<base64 of the module>', ...)`.
+fn udf_call_definition(
+    function: &str,
+    target: &str,
+    handler: &str,
+    arg_types: &[String],
+    return_type: &str,
+) -> UDFDefinition {
+    let literal = |s: &str| {
+        FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+            Value::SingleQuotedString(s.to_string()),
+        )))
+    };
+    let parameters = (0..arg_types.len())
+        .map(|i| format!("arg{}", i))
+        .collect::<Vec<_>>();
+
+    let mut args = vec![literal(target), literal(handler), literal(return_type)];
+    for (param, arg_type) in parameters.iter().zip(arg_types.iter()) {
+        args.push(FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new(param))),
+            data_type: SQLDataType::Custom(ObjectName(vec![Ident::new(arg_type)])),
+        })));
+    }
+
+    let expr = Expr::Function(Function {
+        name: ObjectName(vec![Ident::new(function)]),
+        params: vec![],
+        args,
+        over: None,
+        distinct: false,
+    });
+    UDFDefinition::new(parameters, expr)
+}
+
 #[async_trait]
 impl ExprVisitor for ExprRPNBuilder {
     async fn pre_visit(&mut self, expr: &Expr) -> Result<Expr> {
@@ -54,6 +54,10 @@ pub struct QueryAnalyzeState {
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    // The arguments and the windows of the window functions, and the window functions
+    pub before_window_expressions: Vec<Expression>,
+    pub window_expressions: Vec<Expression>,
+
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 
@@ -73,6 +77,12 @@ impl QueryAnalyzeState {
             self.before_group_by_expressions.push(expr.clone());
         }
     }
+
+    pub fn add_before_window_expression(&mut self, expr: &Expression) {
+        if !self.before_window_expressions.contains(expr) {
+            self.before_window_expressions.push(expr.clone());
+        }
+    }
 }
 
 impl Default for QueryAnalyzeState {
@@ -86,6 +96,8 @@ impl Default for QueryAnalyzeState {
             group_by_expressions: vec![],
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            before_window_expressions: vec![],
+            window_expressions: vec![],
             limit: None,
             offset: None,
             relation: QueryRelation::None,
@@ -114,6 +126,14 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
 
+        if !self.before_window_expressions.is_empty() {
+            debug_struct.field("before_window", &self.before_window_expressions);
+        }
+
+        if !self.window_expressions.is_empty() {
+            debug_struct.field("window", &self.window_expressions);
+        }
+
         if !self.expressions.is_empty() {
             match self.order_by_expressions.is_empty() {
                 true => debug_struct.field("before_projection", &self.expressions),
@@ -155,6 +175,8 @@ impl<'a> AnalyzableStatement for DfStatement<'a> {
             DfStatement::CreateTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeTable(v) => v.analyze(ctx).await,
             DfStatement::DropTable(v) => v.analyze(ctx).await,
+            DfStatement::UndropTable(v) => v.analyze(ctx).await,
+            DfStatement::VacuumDropTable(v) => v.analyze(ctx).await,
             DfStatement::AlterTable(v) => v.analyze(ctx).await,
             DfStatement::RenameTable(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
@@ -179,13 +201,21 @@ impl<'a> AnalyzableStatement for DfStatement<'a> {
             DfStatement::RevokeRole(v) => v.analyze(ctx).await,
             DfStatement::DropUser(v) => v.analyze(ctx).await,
             DfStatement::Copy(v) => v.analyze(ctx).await,
+            DfStatement::CopyIntoLocation(v) => v.analyze(ctx).await,
             DfStatement::Call(v) => v.analyze(ctx).await,
             DfStatement::ShowFunctions(v) => v.analyze(ctx).await,
             DfStatement::CreateUDF(v) => v.analyze(ctx).await,
             DfStatement::DropUDF(v) => v.analyze(ctx).await,
             DfStatement::AlterUDF(v) => v.analyze(ctx).await,
+            DfStatement::CreateRowAccessPolicy(v) => v.analyze(ctx).await,
+            DfStatement::DropRowAccessPolicy(v) => v.analyze(ctx).await,
+            DfStatement::CreateMaskingPolicy(v) => v.analyze(ctx).await,
+            DfStatement::DropMaskingPolicy(v) => v.analyze(ctx).await,
+            DfStatement::CreateNetworkPolicy(v) => v.analyze(ctx).await,
+            DfStatement::DropNetworkPolicy(v) => v.analyze(ctx).await,
             DfStatement::CreateRole(v) => v.analyze(ctx).await,
             DfStatement::DropRole(v) => v.analyze(ctx).await,
+            DfStatement::SetRole(v) => v.analyze(ctx).await,
             DfStatement::ShowEngines(v) => v.analyze(ctx).await,
             DfStatement::CreateStage(v) => v.analyze(ctx).await,
             DfStatement::DropStage(v) => v.analyze(ctx).await,
@@ -72,7 +72,6 @@ impl ValueExprAnalyzer {
     }
 
     fn unsupported_interval(interval: &Value) -> Result<Expression> {
-        //TODO: support parsing literal interval like '1 hour'
         Err(ErrorCode::SyntaxException(format!(
             "Unsupported interval expression: {}.",
             interval
@@ -80,24 +79,40 @@ impl ValueExprAnalyzer {
     }
 
     fn analyze_interval(value: &str, unit: &Option<DateTimeField>) -> Result<Expression> {
-        // We only accept i32 for number in "interval [num] [year|month|day|hour|minute|second]"
-        let num = value.parse::<i32>()?;
-
-        //TODO: support default unit for interval
         match unit {
-            None => Err(ErrorCode::SyntaxException(
-                "Interval must have unit, e.g: '1 HOUR'",
-            )),
-            Some(DateTimeField::Year) => Self::interval_literal(num, IntervalKind::Year),
-            Some(DateTimeField::Month) => Self::interval_literal(num, IntervalKind::Month),
-            Some(DateTimeField::Day) => Self::interval_literal(num, IntervalKind::Day),
-            Some(DateTimeField::Hour) => Self::interval_literal(num, IntervalKind::Hour),
-            Some(DateTimeField::Minute) => Self::interval_literal(num, IntervalKind::Minute),
-            Some(DateTimeField::Second) => Self::interval_literal(num, IntervalKind::Second),
+            // "interval '[num] [year|quarter|month|week|day|hour|minute|second]'"
+            None => Self::analyze_interval_string(value),
+            // We only accept i32 for number in "interval [num] [year|month|day|hour|minute|second]"
+            Some(DateTimeField::Year) => Self::interval_literal(value, IntervalKind::Year),
+            Some(DateTimeField::Month) => Self::interval_literal(value, IntervalKind::Month),
+            Some(DateTimeField::Day) => Self::interval_literal(value, IntervalKind::Day),
+            Some(DateTimeField::Hour) => Self::interval_literal(value, IntervalKind::Hour),
+            Some(DateTimeField::Minute) => Self::interval_literal(value, IntervalKind::Minute),
+            Some(DateTimeField::Second) => Self::interval_literal(value, IntervalKind::Second),
         }
     }
 
-    fn interval_literal(num: i32, kind: IntervalKind) -> Result<Expression> {
+    fn analyze_interval_string(value: &str) -> Result<Expression> {
+        let parts = value.split_whitespace().collect::<Vec<_>>();
+        match parts.as_slice() {
+            [num, unit] => match IntervalKind::from_unit(unit) {
+                Some(kind) => Self::interval_literal(num, kind),
+                None => Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported interval unit: {}.",
+                    unit
+                ))),
+            },
+            _ => {
+                value.parse::<i32>()?;
+                Err(ErrorCode::SyntaxException(
+                    "Interval must have unit, e.g: '1 HOUR'",
+                ))
+            }
+        }
+    }
+
+    fn interval_literal(value: &str, kind: IntervalKind) -> Result<Expression> {
+        let num = value.parse::<i32>()?;
         Ok(Expression::Literal {
             value: DataValue::Int64(num as i64),
             column_name: Some(num.to_string()),
@@ -24,8 +24,12 @@ mod statement_alter_view;
 mod statement_call;
 mod statement_common;
 mod statement_copy;
+mod statement_copy_into_location;
 mod statement_create_database;
+mod statement_create_masking_policy;
+mod statement_create_network_policy;
 mod statement_create_role;
+mod statement_create_row_access_policy;
 mod statement_create_table;
 mod statement_create_udf;
 mod statement_create_user;
@@ -34,7 +38,10 @@ mod statement_create_view;
 mod statement_describe_table;
 mod statement_describe_user_stage;
 mod statement_drop_database;
+mod statement_drop_masking_policy;
+mod statement_drop_network_policy;
 mod statement_drop_role;
+mod statement_drop_row_access_policy;
 mod statement_drop_table;
 mod statement_drop_udf;
 mod statement_drop_user;
@@ -50,6 +57,7 @@ mod statement_rename_table;
 mod statement_revoke;
 mod statement_select;
 mod statement_select_convert;
+mod statement_set_role;
 mod statement_set_variable;
 mod statement_show_create_database;
 mod statement_show_create_table;
@@ -66,7 +74,9 @@ mod statement_show_tab_stat;
 mod statement_show_tables;
 mod statement_show_users;
 mod statement_truncate_table;
+mod statement_undrop_table;
 mod statement_use_database;
+mod statement_vacuum_drop_table;
 mod value_source;
 
 pub use analyzer_expr::ExpressionAnalyzer;
@@ -83,10 +93,16 @@ pub use statement_alter_view::DfAlterView;
 pub use statement_call::DfCall;
 pub use statement_common::*;
 pub use statement_copy::*;
+pub use statement_copy_into_location::CopyIntoLocationSource;
+pub use statement_copy_into_location::DfCopyIntoLocation;
 pub use statement_create_database::DfCreateDatabase;
+pub use statement_create_masking_policy::DfCreateMaskingPolicy;
+pub use statement_create_network_policy::DfCreateNetworkPolicy;
 pub use statement_create_role::DfCreateRole;
+pub use statement_create_row_access_policy::DfCreateRowAccessPolicy;
 pub use statement_create_table::DfCreateTable;
 pub use statement_create_udf::DfCreateUDF;
+pub use statement_create_udf::DfUDFServer;
 pub use statement_create_user::DfAuthOption;
 pub use statement_create_user::DfCreateUser;
 pub use statement_create_user::DfUserWithOption;
@@ -95,7 +111,10 @@ pub use statement_create_view::DfCreateView;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_describe_user_stage::DfDescribeUserStage;
 pub use statement_drop_database::DfDropDatabase;
+pub use statement_drop_masking_policy::DfDropMaskingPolicy;
+pub use statement_drop_network_policy::DfDropNetworkPolicy;
 pub use statement_drop_role::DfDropRole;
+pub use statement_drop_row_access_policy::DfDropRowAccessPolicy;
 pub use statement_drop_table::DfDropTable;
 pub use statement_drop_udf::DfDropUDF;
 pub use statement_drop_user::DfDropUser;
@@ -114,6 +133,7 @@ pub use statement_rename_table::DfRenameTable;
 pub use statement_revoke::DfRevokePrivilegeStatement;
 pub use statement_revoke::DfRevokeRoleStatement;
 pub use statement_select::DfQueryStatement;
+pub use statement_set_role::DfSetRole;
 pub use statement_set_variable::DfSetVariable;
 pub use statement_show_create_database::DfShowCreateDatabase;
 pub use statement_show_create_table::DfShowCreateTable;
@@ -130,5 +150,7 @@ pub use statement_show_tab_stat::DfShowTabStat;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
 pub use statement_truncate_table::DfTruncateTable;
+pub use statement_undrop_table::DfUndropTable;
 pub use statement_use_database::DfUseDatabase;
+pub use statement_vacuum_drop_table::DfVacuumDropTable;
 pub use value_source::ValueSource;
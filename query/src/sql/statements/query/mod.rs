@@ -14,16 +14,20 @@
 
 mod query_ast_ir;
 mod query_collect_push_downs;
+mod query_masking_policy;
 mod query_normalizer;
 mod query_qualified_rewriter;
+mod query_row_access_policy;
 mod query_schema_joined;
 mod query_schema_joined_analyzer;
 
 pub use query_ast_ir::QueryASTIR;
 pub use query_ast_ir::QueryASTIRVisitor;
 pub use query_collect_push_downs::QueryCollectPushDowns;
+pub use query_masking_policy::MaskingPolicyRewriter;
 pub use query_normalizer::QueryNormalizer;
 pub use query_qualified_rewriter::QualifiedRewriter;
+pub use query_row_access_policy::RowAccessPolicyRewriter;
 pub use query_schema_joined::JoinedColumnDesc;
 pub use query_schema_joined::JoinedSchema;
 pub use query_schema_joined::JoinedTableDesc;
@@ -73,6 +73,18 @@ pub trait QueryASTIRVisitor<Data> {
 
                 Ok(())
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                for arg in args.iter_mut().chain(partition_by).chain(order_by) {
+                    Self::visit_recursive_expr(arg, data)?;
+                }
+
+                Ok(())
+            }
             Expression::Sort {
                 expr, origin_expr, ..
             } => {
@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 
 use common_exception::Result;
+use common_planners::find_window_exprs;
 use common_planners::Expression;
 use common_planners::Extras;
 
@@ -74,6 +75,8 @@ impl QueryCollectPushDowns {
             if schema.get_tables_desc().len() == 1
                 && ir.group_by_expressions.is_empty()
                 && ir.aggregate_expressions.is_empty()
+                && find_window_exprs(&ir.projection_expressions).is_empty()
+                && find_window_exprs(&ir.order_by_expressions).is_empty()
             {
                 limit = ir.limit.map(|c| c + ir.offset.unwrap_or(0));
                 order_by = ir.order_by_expressions.clone();
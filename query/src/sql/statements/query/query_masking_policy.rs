@@ -0,0 +1,167 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::udfs::UDFParser;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ExpressionRewriter;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::JoinedTableDesc;
+use crate::sql::statements::QueryAnalyzeState;
+use crate::sql::OPT_KEY_MASKING_POLICIES;
+
+/// Replace the columns with the masking policies by the masked values wherever they are read.
+///
+/// The masks are keyed by the column names the qualified rewriter resolves the columns of each
+/// table to, e.g. `t1.ssn` if `ssn` is ambiguous, so a mask only applies to the column of its
+/// table. The filter and the push downs are rewritten, so the WHERE sees the masked values too,
+/// and so is the first stage of expressions over the source: the expressions before the group by
+/// if the query has aggregations, before the window functions if it has some, or before the
+/// projection. The next stages refer to the masked values by the names of the columns.
+pub struct MaskingPolicyRewriter;
+
+impl MaskingPolicyRewriter {
+    pub async fn rewrite(
+        ctx: Arc<QueryContext>,
+        joined_schema: &mut JoinedSchema,
+        state: &mut QueryAnalyzeState,
+    ) -> Result<()> {
+        let mut masks = HashMap::new();
+        for table_desc in joined_schema.get_tables_desc() {
+            if let JoinedTableDesc::Table { table, .. } = table_desc {
+                if let Some(policies) = table
+                    .get_table_info()
+                    .options()
+                    .get(OPT_KEY_MASKING_POLICIES)
+                {
+                    let policies = serde_json::from_str::<BTreeMap<String, String>>(policies)?;
+                    for (column, policy_name) in policies {
+                        let column_name = Self::column_name(table_desc, &column);
+                        if let Some(mask) = Self::mask(&ctx, &column_name, &policy_name).await? {
+                            masks.insert(column_name, mask);
+                        }
+                    }
+                }
+            }
+        }
+
+        if masks.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(filter) = &state.filter {
+            state.filter = Some(MaskedColumnRewriter(&masks).mutate(filter)?);
+        }
+        for table_pos in 0..joined_schema.get_tables_desc().len() {
+            if let JoinedTableDesc::Table {
+                push_downs: Some(push_downs),
+                ..
+            } = &joined_schema.get_tables_desc()[table_pos]
+            {
+                let mut push_downs = push_downs.clone();
+                for expression in push_downs
+                    .filters
+                    .iter_mut()
+                    .chain(push_downs.order_by.iter_mut())
+                {
+                    *expression = MaskedColumnRewriter(&masks).mutate(expression)?;
+                }
+                joined_schema.set_table_push_downs(table_pos, push_downs);
+            }
+        }
+
+        let has_aggregation =
+            !state.group_by_expressions.is_empty() || !state.aggregate_expressions.is_empty();
+        let expressions = match has_aggregation {
+            true => &mut state.before_group_by_expressions,
+            false if !state.before_window_expressions.is_empty() => {
+                &mut state.before_window_expressions
+            }
+            false => &mut state.expressions,
+        };
+        for expression in expressions.iter_mut() {
+            let masked = MaskedColumnRewriter(&masks).mutate(expression)?;
+            // Keep the column name, the next steps refer to the expression by it.
+            *expression = match masked.column_name() == expression.column_name() {
+                true => masked,
+                false => Expression::Alias(expression.column_name(), Box::new(masked)),
+            };
+        }
+        Ok(())
+    }
+
+    // The name the qualified rewriter resolves the column of the table to.
+    fn column_name(table_desc: &JoinedTableDesc, column: &str) -> String {
+        let is_ambiguity = table_desc
+            .get_columns_desc()
+            .iter()
+            .any(|column_desc| column_desc.short_name == column && column_desc.is_ambiguity);
+        match is_ambiguity {
+            true => format!("{}.{}", table_desc.get_name_parts().join("."), column),
+            false => column.to_string(),
+        }
+    }
+
+    async fn mask(
+        ctx: &Arc<QueryContext>,
+        column: &str,
+        policy_name: &str,
+    ) -> Result<Option<Expression>> {
+        let tenant = ctx.get_tenant();
+        let user_mgr = ctx.get_user_manager();
+        let policy = user_mgr.get_masking_policy(&tenant, policy_name).await?;
+
+        let active_roles = ctx.get_current_session().get_active_roles()?;
+        if policy.is_exempt(&active_roles) {
+            return Ok(None);
+        }
+
+        let mut udf_parser = UDFParser::default();
+        let definition = udf_parser
+            .parse(&policy.name, &policy.parameters, &policy.definition)
+            .await?;
+        let expression = ExpressionAnalyzer::create(ctx.clone())
+            .analyze(&definition)
+            .await?;
+
+        // Bind the parameter of the policy to the column.
+        let mut binding = HashMap::new();
+        for parameter in &policy.parameters {
+            binding.insert(parameter.clone(), Expression::Column(column.to_string()));
+        }
+        Ok(Some(MaskedColumnRewriter(&binding).mutate(&expression)?))
+    }
+}
+
+struct MaskedColumnRewriter<'a>(&'a HashMap<String, Expression>);
+
+impl<'a> ExpressionRewriter for MaskedColumnRewriter<'a> {
+    fn mutate_column(
+        &mut self,
+        column_name: &str,
+        _origin_expr: &Expression,
+    ) -> Result<Expression> {
+        match self.0.get(column_name) {
+            None => Ok(Expression::Column(column_name.to_string())),
+            Some(expression) => Ok(expression.clone()),
+        }
+    }
+}
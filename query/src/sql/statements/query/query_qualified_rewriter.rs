@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
@@ -106,23 +107,31 @@ impl QualifiedRewriter {
 
     fn rewrite_qualified_column(&self, ref_names: &[String]) -> Result<Expression> {
         match self.best_match_table(ref_names) {
-            None => Err(ErrorCode::UnknownColumn(format!(
+            // column.field_a.field_b
+            None if self.tables_schema.contains_column(&ref_names[0]) => {
+                let column = Expression::Column(ref_names[0].clone());
+                Ok(Self::struct_field_access(column, &ref_names[1..]))
+            }
+            Some((pos, table_ref)) if pos < ref_names.len() => {
+                let column_name = &ref_names[pos..];
+                let column = Self::find_column(&table_ref, &column_name[0])?;
+                Ok(Self::struct_field_access(column, &column_name[1..]))
+            }
+            _ => Err(ErrorCode::UnknownColumn(format!(
                 "Unknown column {}",
                 ref_names.join(".")
             ))),
-            Some((pos, table_ref)) => {
-                let column_name = &ref_names[pos..];
-                match column_name.len() {
-                    1 => Self::find_column(&table_ref, &column_name[0]),
-                    // TODO: column.field_a.field_b => GetField(field_b, GetField(field_a, column))
-                    _ => Err(ErrorCode::SyntaxException(
-                        "Unsupported complex type field access",
-                    )),
-                }
-            }
         }
     }
 
+    // column.field_a.field_b => get(get(column, 'field_a'), 'field_b')
+    fn struct_field_access(column: Expression, fields: &[String]) -> Expression {
+        fields.iter().fold(column, |expr, field| {
+            let field = Expression::create_literal(DataValue::String(field.as_bytes().to_vec()));
+            Expression::create_scalar_function("get", vec![expr, field])
+        })
+    }
+
     fn find_column(table_desc: &JoinedTableDesc, name: &str) -> Result<Expression> {
         let name_parts = table_desc.get_name_parts();
         for column_desc in table_desc.get_columns_desc() {
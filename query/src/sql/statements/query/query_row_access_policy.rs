@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_ast::udfs::UDFDefinition;
+use common_ast::udfs::UDFFetcher;
+use common_ast::udfs::UDFParser;
+use common_ast::udfs::UDFTransformer;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+use sqlparser::ast::BinaryOperator;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Function;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::FunctionArgExpr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::JoinedTableDesc;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+/// Add the predicate of the row access policy attached to the table into the WHERE of the
+/// query. It is done before the query is normalized, so the predicate is pushed down and
+/// prunes the partitions like the other filters.
+pub struct RowAccessPolicyRewriter {
+    policy: RowAccessPolicy,
+}
+
+impl RowAccessPolicyRewriter {
+    pub async fn rewrite(
+        ctx: Arc<QueryContext>,
+        joined_schema: &JoinedSchema,
+        query: &DfQueryStatement,
+    ) -> Result<Option<DfQueryStatement>> {
+        let mut predicates = vec![];
+        for table_desc in joined_schema.get_tables_desc() {
+            if let JoinedTableDesc::Table { table, .. } = table_desc {
+                let options = table.get_table_info().options();
+                if let Some(policy_name) = options.get(OPT_KEY_ROW_ACCESS_POLICY) {
+                    let columns = options
+                        .get(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS)
+                        .map(|columns| columns.split(',').map(str::to_string).collect())
+                        .unwrap_or_default();
+                    let name_parts = table_desc.get_name_parts();
+                    if let Some(predicate) =
+                        Self::predicate(&ctx, policy_name, name_parts, columns).await?
+                    {
+                        predicates.push(predicate);
+                    }
+                }
+            }
+        }
+
+        if predicates.is_empty() {
+            return Ok(None);
+        }
+
+        let mut query = query.clone();
+        for predicate in predicates {
+            query.selection = Some(match query.selection.take() {
+                None => predicate,
+                Some(selection) => Expr::BinaryOp {
+                    left: Box::new(Expr::Nested(Box::new(selection))),
+                    op: BinaryOperator::And,
+                    right: Box::new(Expr::Nested(Box::new(predicate))),
+                },
+            });
+        }
+        Ok(Some(query))
+    }
+
+    async fn predicate(
+        ctx: &Arc<QueryContext>,
+        policy_name: &str,
+        name_parts: &[String],
+        columns: Vec<String>,
+    ) -> Result<Option<Expr>> {
+        let tenant = ctx.get_tenant();
+        let user_mgr = ctx.get_user_manager();
+        let policy = user_mgr.get_row_access_policy(&tenant, policy_name).await?;
+
+        let active_roles = ctx.get_current_session().get_active_roles()?;
+        if policy.is_exempt(&active_roles) {
+            return Ok(None);
+        }
+
+        // Call the policy like a UDF with the columns it is attached on, qualified by the alias
+        // or the name of the table, so they aren't taken for the columns of the joined tables.
+        let args = columns
+            .iter()
+            .map(|column| {
+                let idents = name_parts
+                    .iter()
+                    .chain(std::iter::once(column))
+                    .map(Ident::new)
+                    .collect();
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::CompoundIdentifier(idents)))
+            })
+            .collect();
+        let function = Function {
+            name: ObjectName(vec![Ident::new(&policy.name)]),
+            params: vec![],
+            args,
+            over: None,
+            distinct: false,
+        };
+
+        let rewriter = RowAccessPolicyRewriter { policy };
+        let predicate = UDFTransformer::transform_function(&function, &rewriter).await?;
+        Ok(Some(predicate))
+    }
+}
+
+#[async_trait]
+impl UDFFetcher for RowAccessPolicyRewriter {
+    async fn get_udf_definition(&self, _name: &str) -> Result<UDFDefinition> {
+        let policy = &self.policy;
+        let mut udf_parser = UDFParser::default();
+        let definition = udf_parser
+            .parse(&policy.name, &policy.parameters, &policy.definition)
+            .await?;
+
+        Ok(UDFDefinition::new(policy.parameters.clone(), definition))
+    }
+}
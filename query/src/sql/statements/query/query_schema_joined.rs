@@ -93,6 +93,16 @@ impl JoinedSchema {
         }
     }
 
+    pub fn set_table_column_privileges(&mut self, table_pos: usize, database: &str, table: &str) {
+        let table_desc = &mut self.tables_long_name_columns[table_pos];
+        if let JoinedTableDesc::Table {
+            column_privileges, ..
+        } = table_desc
+        {
+            *column_privileges = Some((database.to_string(), table.to_string()));
+        }
+    }
+
     pub fn take_tables_desc(self) -> Vec<JoinedTableDesc> {
         self.tables_long_name_columns
     }
@@ -165,6 +175,9 @@ pub enum JoinedTableDesc {
         name_parts: Vec<String>,
         columns_desc: Vec<JoinedColumnDesc>,
         push_downs: Option<Extras>,
+        // The database and table name if the SELECT privilege is granted on the columns
+        // instead of the table, the columns read by the query are checked after the push downs.
+        column_privileges: Option<(String, String)>,
     },
     Subquery {
         state: Box<QueryAnalyzeState>,
@@ -187,6 +200,7 @@ impl JoinedTableDesc {
             columns_desc,
             name_parts: prefix,
             push_downs: None,
+            column_privileges: None,
         }
     }
 
@@ -14,8 +14,15 @@
 
 use std::sync::Arc;
 
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::TableInfo;
+use common_meta_types::UserPrivilegeType;
+use common_planners::Expression;
+use common_planners::S3StageTableInfo;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
 use sqlparser::ast::JoinOperator;
@@ -28,6 +35,7 @@ use sqlparser::ast::TableWithJoins;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::location_to_stage_path;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
@@ -36,6 +44,11 @@ use crate::sql::DfParser;
 use crate::sql::DfStatement;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
+use crate::storages::S3StageTable;
+use crate::storages::StageSource;
+
+// The system tables with the queries of all the users of the tenant.
+const SUPER_SYSTEM_TABLES: [&str; 3] = ["audit_log", "query_log", "slow_query_log"];
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -98,12 +111,30 @@ impl JoinedSchemaAnalyzer {
     }
 
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
+        if item.name.0.len() == 1 && item.name.0[0].value.starts_with('@') {
+            return self.stage(&item.name.0[0].value, &item.alias, &[]).await;
+        }
+
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
         let read_table = self.ctx.get_table(&database, &table).await?;
         let tbl_info = read_table.get_table_info();
+        let table_granted = self
+            .has_select_privilege(&database, &table, tbl_info)
+            .await?;
 
         if tbl_info.engine() == VIEW_ENGINE {
+            // The columns of a view are not granted separately.
+            if !table_granted {
+                self.ctx
+                    .get_current_session()
+                    .validate_privilege(
+                        &GrantObject::Table(database.clone(), table.clone()),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+            }
+
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let (statements, _) = DfParser::parse_sql(query.as_str())?;
                 if statements.len() == 1 {
@@ -121,17 +152,125 @@ impl JoinedSchemaAnalyzer {
                 "Logical error, subquery analyzed data must be SelectQuery, it's a bug.",
             ))
         } else {
-            match &item.alias {
-                None => {
-                    let name_prefix = vec![database, table];
-                    JoinedSchema::from_table(read_table, name_prefix)
+            let name_prefix = match &item.alias {
+                None => vec![database.clone(), table.clone()],
+                Some(table_alias) => vec![table_alias.name.value.clone()],
+            };
+            let mut schema = JoinedSchema::from_table(read_table, name_prefix)?;
+            if !table_granted {
+                schema.set_table_column_privileges(0, &database, &table);
+            }
+            Ok(schema)
+        }
+    }
+
+    // The tables of the system databases are readable by everyone, except the logs with the
+    // queries of all the users which require the SUPER privilege, and so are the external tables
+    // sent along with the query.
+    async fn has_select_privilege(
+        &self,
+        database: &str,
+        table: &str,
+        table_info: &TableInfo,
+    ) -> Result<bool> {
+        if database.eq_ignore_ascii_case("system")
+            && SUPER_SYSTEM_TABLES.contains(&table.to_lowercase().as_str())
+        {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                .await?;
+            return Ok(true);
+        }
+
+        let is_system_database = database.eq_ignore_ascii_case("system")
+            || database.eq_ignore_ascii_case("information_schema");
+        if is_system_database || self.ctx.is_external_table(table_info.ident.table_id) {
+            return Ok(true);
+        }
+
+        self.ctx
+            .get_current_session()
+            .has_privilege(
+                &GrantObject::Table(database.to_string(), table.to_string()),
+                UserPrivilegeType::Select,
+            )
+            .await
+    }
+
+    // Select from the files of a stage, e.g. `SELECT * FROM @my_stage`, or
+    // `SELECT * FROM @my_stage (FILE_FORMAT => 'parquet', PATTERN => '.*[.]parquet')`.
+    // The schema is inferred from the first records of the files by the file format of the stage.
+    async fn stage(
+        &self,
+        location: &str,
+        alias: &Option<TableAlias>,
+        args: &[Expression],
+    ) -> Result<JoinedSchema> {
+        let (mut stage_info, path) = location_to_stage_path(location, &self.ctx).await?;
+
+        let mut pattern = String::new();
+        for arg in args {
+            match arg {
+                Expression::Alias(name, value) => {
+                    let value = match value.as_ref() {
+                        Expression::Literal {
+                            value: DataValue::String(value),
+                            ..
+                        } => String::from_utf8_lossy(value).to_string(),
+                        other => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Expected a string literal for the stage argument {}, but got {:?}",
+                                name, other
+                            )))
+                        }
+                    };
+                    match name.to_lowercase().as_str() {
+                        "file_format" => {
+                            stage_info.file_format_options.format = value
+                                .parse::<StageFileFormatType>()
+                                .map_err(ErrorCode::BadArguments)?
+                        }
+                        "pattern" => pattern = value,
+                        other => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Unknown argument '{}' for stage {}, expected one of file_format, pattern",
+                                other, location
+                            )))
+                        }
+                    }
                 }
-                Some(table_alias) => {
-                    let name_prefix = vec![table_alias.name.value.clone()];
-                    JoinedSchema::from_table(read_table, name_prefix)
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The arguments of stage {} must be named, e.g. FILE_FORMAT => 'parquet'",
+                        location
+                    )))
                 }
             }
         }
+
+        let files = StageSource::list_files(&self.ctx, &stage_info, &path, &[], &pattern).await?;
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "There are no files in the stage location '{}'",
+                location
+            )));
+        }
+
+        let schema = StageSource::infer_schema(&self.ctx, &stage_info, &files).await?;
+        let table = S3StageTable::try_create(S3StageTableInfo {
+            schema,
+            file_name: None,
+            files,
+            stage_info,
+            path,
+        })?;
+
+        let name_prefix = match alias {
+            None => vec![location.to_string()],
+            Some(table_alias) => vec![table_alias.name.value.clone()],
+        };
+        JoinedSchema::from_table(table, name_prefix)
     }
 
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
@@ -147,11 +286,19 @@ impl JoinedSchemaAnalyzer {
 
         for table_arg in &item.args {
             table_args.push(match table_arg {
-                FunctionArg::Named { arg, .. } => analyzer.analyze_function_arg(arg).await?,
+                // The named arguments are passed as aliases, e.g. `infer_schema(stage => '@s')`.
+                FunctionArg::Named { name, arg } => Expression::Alias(
+                    name.value.clone(),
+                    Box::new(analyzer.analyze_function_arg(arg).await?),
+                ),
                 FunctionArg::Unnamed(arg) => analyzer.analyze_function_arg(arg).await?,
             });
         }
 
+        if table_name.starts_with('@') {
+            return self.stage(&table_name, &item.alias, &table_args).await;
+        }
+
         let catalog = self.ctx.get_catalog();
         let table_function = catalog.get_table_function(&table_name, Some(table_args))?;
         match &item.alias {
@@ -16,9 +16,12 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::AlterTableMaskingPolicyPlan;
+use common_planners::AlterTableRowAccessPolicyPlan;
 use common_planners::PlanNode;
 use common_planners::RenameTableEntity;
 use common_planners::RenameTablePlan;
+use common_planners::RowAccessPolicyAction;
 use common_tracing::tracing;
 use sqlparser::ast::ObjectName;
 
@@ -36,6 +39,16 @@ pub struct DfAlterTable {
 #[derive(Clone, Debug, PartialEq)]
 pub enum AlterTableAction {
     RenameTable(ObjectName),
+    AddRowAccessPolicy {
+        policy_name: String,
+        columns: Vec<String>,
+    },
+    DropRowAccessPolicy(String),
+    SetMaskingPolicy {
+        column: String,
+        policy_name: String,
+    },
+    UnsetMaskingPolicy(String),
     // TODO AddColumn etc.
 }
 
@@ -62,6 +75,55 @@ impl AnalyzableStatement for DfAlterTable {
                     PlanNode::RenameTable(RenameTablePlan { tenant, entities }),
                 )))
             }
+            AlterTableAction::AddRowAccessPolicy {
+                policy_name,
+                columns,
+            } => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                PlanNode::AlterTableRowAccessPolicy(AlterTableRowAccessPolicyPlan {
+                    tenant,
+                    database: db,
+                    table: table_name,
+                    action: RowAccessPolicyAction::Add {
+                        policy_name: policy_name.clone(),
+                        columns: columns.clone(),
+                    },
+                }),
+            ))),
+            AlterTableAction::DropRowAccessPolicy(policy_name) => {
+                Ok(AnalyzedResult::SimpleQuery(Box::new(
+                    PlanNode::AlterTableRowAccessPolicy(AlterTableRowAccessPolicyPlan {
+                        tenant,
+                        database: db,
+                        table: table_name,
+                        action: RowAccessPolicyAction::Drop {
+                            policy_name: policy_name.clone(),
+                        },
+                    }),
+                )))
+            }
+            AlterTableAction::SetMaskingPolicy {
+                column,
+                policy_name,
+            } => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                PlanNode::AlterTableMaskingPolicy(AlterTableMaskingPolicyPlan {
+                    tenant,
+                    database: db,
+                    table: table_name,
+                    column: column.clone(),
+                    policy_name: Some(policy_name.clone()),
+                }),
+            ))),
+            AlterTableAction::UnsetMaskingPolicy(column) => {
+                Ok(AnalyzedResult::SimpleQuery(Box::new(
+                    PlanNode::AlterTableMaskingPolicy(AlterTableMaskingPolicyPlan {
+                        tenant,
+                        database: db,
+                        table: table_name,
+                        column: column.clone(),
+                        policy_name: None,
+                    }),
+                )))
+            }
         }
     }
 }
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_ast::udfs::UDFParser;
 use common_exception::Result;
 use common_meta_types::UserDefinedFunction;
 use common_planners::AlterUserUDFPlan;
@@ -30,21 +31,42 @@ pub struct DfAlterUDF {
     pub parameters: Vec<String>,
     pub definition: String,
     pub description: String,
+    pub is_aggregate: bool,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfAlterUDF {
     #[tracing::instrument(level = "info", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
     async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        Ok(AnalyzedResult::SimpleQuery(Box::new(
-            PlanNode::AlterUserUDF(AlterUserUDFPlan {
-                udf: UserDefinedFunction::new(
+        // Verify the definition before it's stored, it would fail on every call otherwise.
+        let mut udf_parser = UDFParser::default();
+        let udf = match self.is_aggregate {
+            true => {
+                udf_parser
+                    .parse_aggregate(&self.udf_name, &self.parameters, &self.definition)
+                    .await?;
+                UserDefinedFunction::new_aggregate(
                     self.udf_name.as_str(),
                     self.parameters.clone(),
                     self.definition.as_str(),
                     self.description.as_str(),
-                ),
-            }),
+                )
+            }
+            false => {
+                udf_parser
+                    .parse(&self.udf_name, &self.parameters, &self.definition)
+                    .await?;
+                UserDefinedFunction::new(
+                    self.udf_name.as_str(),
+                    self.parameters.clone(),
+                    self.definition.as_str(),
+                    self.description.as_str(),
+                )
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::AlterUserUDF(AlterUserUDFPlan { udf }),
         )))
     }
 }
@@ -25,6 +25,7 @@ use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfUserWithOption;
+use crate::users::PasswordPolicy;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfAlterUser {
@@ -51,7 +52,14 @@ impl AnalyzableStatement for DfAlterUser {
             let auth_info = user_info
                 .auth_info
                 .alter(&auth_option.auth_type, &auth_option.by_value)?;
-            if user_info.auth_info == auth_info {
+            auth_option.check_password_policy(&ctx, &auth_info)?;
+            let auth_manager = ctx.get_auth_manager();
+            let policy = auth_manager.get_password_policy();
+            policy.check_reuse(&user_info, &auth_info)?;
+            // The same password still renews the password which must be changed.
+            if user_info.auth_info == auth_info
+                && !policy.must_change_password(&user_info, PasswordPolicy::now())
+            {
                 None
             } else {
                 Some(auth_info)
@@ -62,6 +70,7 @@ impl AnalyzableStatement for DfAlterUser {
 
         let mut user_option = user_info.option.clone();
         for option in &self.with_options {
+            option.validate(&ctx).await?;
             option.apply(&mut user_option);
         }
         let new_user_option = if user_option == user_info.option {
@@ -21,11 +21,15 @@ use common_exception::Result;
 use common_io::prelude::get_abs_path;
 use common_io::prelude::parse_escape_string;
 use common_meta_types::FileFormatOptions;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageS3Storage;
 use common_meta_types::StageStorage;
 use common_meta_types::StageType;
 use common_meta_types::UserStageInfo;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Tokenizer;
 
 use crate::sessions::QueryContext;
 
@@ -155,11 +159,82 @@ pub fn parse_copy_file_format_options(
             .as_bytes(),
     );
 
+    // Quote.
+    let quote = parse_escape_string(
+        file_format_options
+            .get("quote")
+            .unwrap_or(&"".to_string())
+            .as_bytes(),
+    );
+
+    // Escape.
+    let escape = parse_escape_string(
+        file_format_options
+            .get("escape")
+            .unwrap_or(&"".to_string())
+            .as_bytes(),
+    );
+
+    // Null if, a value or a list of values.
+    let null_if = match file_format_options.get("null_if") {
+        Some(v) => parse_list_option(v)?,
+        None => vec![],
+    };
+
+    // Trim space.
+    let trim_space = match file_format_options.get("trim_space") {
+        Some(v) => v.to_lowercase().parse::<bool>().map_err(|_| {
+            ErrorCode::SyntaxException(format!("trim_space must be true or false, got:{}", v))
+        })?,
+        None => false,
+    };
+
+    // Compression, detected by the file extension and the magic bytes if AUTO.
+    let compression = match file_format_options.get("compression") {
+        Some(v) => StageFileCompression::from_str(v).map_err(|e| {
+            ErrorCode::SyntaxException(format!("File compression type error:{:?}", e))
+        })?,
+        None => StageFileCompression::Auto,
+    };
+
+    for (name, value) in [("quote", &quote), ("escape", &escape)] {
+        if value.len() > 1 {
+            return Err(ErrorCode::SyntaxException(format!(
+                "{} must be a single character, got:{}",
+                name, value
+            )));
+        }
+    }
+
     Ok(FileFormatOptions {
         format: file_format,
         skip_header,
         field_delimiter,
         record_delimiter,
-        compression: Default::default(),
+        quote,
+        escape,
+        null_if,
+        trim_space,
+        compression,
     })
 }
+
+// The list options are kept by the parser as the SQL text of the quoted values, e.g. ('NULL', '').
+fn parse_list_option(value: &str) -> Result<Vec<String>> {
+    if !value.starts_with('(') {
+        return Ok(vec![value.to_string()]);
+    }
+
+    let dialect = GenericDialect {};
+    let (tokens, _) = Tokenizer::new(&dialect, value)
+        .tokenize()
+        .map_err(|e| ErrorCode::SyntaxException(format!("Invalid list {}, {:?}", value, e)))?;
+
+    Ok(tokens
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::SingleQuotedString(v) => Some(v),
+            _ => None,
+        })
+        .collect())
+}
@@ -120,6 +120,7 @@ impl AnalyzableStatement for DfCopy {
             source_info: SourceInfo::S3StageSource(S3StageTableInfo {
                 schema: schema.clone(),
                 file_name: None,
+                files: vec![],
                 stage_info,
                 path,
             }),
@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::get_abs_path;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::StageParams;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use common_planners::CopyIntoLocationPlan;
+use common_planners::PlanNode;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+
+use super::location_to_stage_path;
+use super::parse_copy_file_format_options;
+use super::parse_stage_storage;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopyIntoLocationSource {
+    Table(ObjectName),
+    Query(Box<Query>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopyIntoLocation {
+    pub location: String,
+    pub source: CopyIntoLocationSource,
+    pub credential_options: HashMap<String, String>,
+    pub encryption_options: HashMap<String, String>,
+    pub file_format_options: HashMap<String, String>,
+    pub max_file_size: String,
+    pub overwrite: String,
+    pub single: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCopyIntoLocation {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        // Stage info.
+        let (mut stage, path) = if self.location.starts_with('@') {
+            location_to_stage_path(self.location.as_str(), &ctx).await?
+        } else {
+            self.analyze_location()?
+        };
+
+        if !self.file_format_options.is_empty() {
+            stage.file_format_options = parse_copy_file_format_options(&self.file_format_options)?;
+        }
+
+        let extension = match stage.file_format_options.format {
+            StageFileFormatType::Csv => "csv",
+            StageFileFormatType::Parquet => "parquet",
+            ref other => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported file format to unload: {:?}, must one of {{ CSV | PARQUET }}",
+                    other
+                )))
+            }
+        };
+
+        // Copy options.
+        let max_file_size = match self.max_file_size.as_str() {
+            "" => 0,
+            v => v.parse::<usize>().map_err(|_e| {
+                ErrorCode::SyntaxException(format!("max_file_size must be number, got: {}", v))
+            })?,
+        };
+        let overwrite = Self::parse_bool_option("overwrite", &self.overwrite)?;
+        let single = Self::parse_bool_option("single", &self.single)?;
+
+        // The path is the file if SINGLE, the files are named data_<n> in the directory otherwise.
+        let path = if single && self.location.ends_with('/') {
+            get_abs_path(&path, &format!("data.{}", extension))
+        } else {
+            path
+        };
+
+        let query = self.analyze_query(ctx).await?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CopyIntoLocation(CopyIntoLocationPlan {
+                stage,
+                path,
+                query: Box::new(query),
+                max_file_size,
+                overwrite,
+                single,
+            }),
+        )))
+    }
+}
+
+impl DfCopyIntoLocation {
+    // External location(location starts without `@`):
+    // copy into 's3://mybucket/data/files/' from mytable
+    // credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+    // file_format = (type = parquet)
+    fn analyze_location(&self) -> Result<(UserStageInfo, String)> {
+        let (stage_storage, path) = parse_stage_storage(
+            &self.location,
+            &self.credential_options,
+            &self.encryption_options,
+        )?;
+        let stage = UserStageInfo {
+            stage_name: self.location.clone(),
+            stage_type: StageType::External,
+            stage_params: StageParams {
+                storage: stage_storage,
+            },
+            ..Default::default()
+        };
+        Ok((stage, path))
+    }
+
+    // The table is unloaded as `SELECT * FROM table`.
+    async fn analyze_query(&self, ctx: Arc<QueryContext>) -> Result<PlanNode> {
+        let statement = match &self.source {
+            CopyIntoLocationSource::Query(query) => DfQueryStatement::try_from(*query.clone())?,
+            CopyIntoLocationSource::Table(name) => {
+                let query = format!("SELECT * FROM {}", name);
+                let (mut statements, _) = DfParser::parse_sql(&query)?;
+                match statements.pop() {
+                    Some(DfStatement::Query(statement)) => *statement,
+                    _ => {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "Cannot build the query of the table {}",
+                            name
+                        )))
+                    }
+                }
+            }
+        };
+        PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await
+    }
+
+    fn parse_bool_option(name: &str, value: &str) -> Result<bool> {
+        match value.to_uppercase().as_str() {
+            "" | "FALSE" => Ok(false),
+            "TRUE" => Ok(true),
+            v => Err(ErrorCode::SyntaxException(format!(
+                "{} must be TRUE or FALSE, got: {}",
+                name, v
+            ))),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::udfs::UDFParser;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+use common_planners::CreateRowAccessPolicyPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateRowAccessPolicy {
+    pub if_not_exists: bool,
+    pub policy_name: String,
+    pub parameters: Vec<String>,
+    pub definition: String,
+    pub exempt_roles: Vec<String>,
+    pub description: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateRowAccessPolicy {
+    #[tracing::instrument(level = "info", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
+    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        // Verify the predicate before it's stored, every query of the tables would fail otherwise.
+        UDFParser::default()
+            .parse(&self.policy_name, &self.parameters, &self.definition)
+            .await?;
+
+        let policy = RowAccessPolicy {
+            name: self.policy_name.clone(),
+            parameters: self.parameters.clone(),
+            definition: self.definition.clone(),
+            exempt_roles: self.exempt_roles.clone(),
+            description: self.description.clone(),
+        };
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateRowAccessPolicy(CreateRowAccessPolicyPlan {
+                if_not_exists: self.if_not_exists,
+                policy,
+            }),
+        )))
+    }
+}
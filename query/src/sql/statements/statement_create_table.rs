@@ -39,6 +39,8 @@ use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::storages::external::ExternalTableOptions;
+use crate::storages::fuse::io::BlockWriteOptions;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -171,7 +173,9 @@ impl DfCreateTable {
                             _ => {}
                         }
                     }
-                    let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
+                    let data_type =
+                        SQLCommon::make_collated_data_type(&column.data_type, &column.collation);
+                    let field = data_type.map(|data_type| {
                         if nullable {
                             DataField::new_nullable(&column.name.value, data_type)
                                 .with_default_expr(default_expr)
@@ -226,11 +230,20 @@ impl DfCreateTable {
             })
             .collect::<Vec<_>>();
         if !reserved.is_empty() {
-            Err(ErrorCode::BadOption(format!("the following table options are reserved, please do not specify them in the CREATE TABLE statement: {}",
+            return Err(ErrorCode::BadOption(format!("the following table options are reserved, please do not specify them in the CREATE TABLE statement: {}",
                         reserved.join(",")
-                        )))
-        } else {
-            Ok(())
+                        )));
         }
+
+        // Check the Parquet writer options of the fuse table.
+        if self.engine.to_uppercase().as_str() == "FUSE" {
+            BlockWriteOptions::try_from_table_options(&self.options)?;
+        }
+
+        // Check the location and the format of the external table.
+        if self.engine.to_uppercase().as_str() == "EXTERNAL" {
+            ExternalTableOptions::try_from_table_options(&self.options)?;
+        }
+        Ok(())
     }
 }
@@ -14,15 +14,22 @@
 
 use std::sync::Arc;
 
+use common_ast::udfs::UDFParser;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UDFLanguage;
+use common_meta_types::UDFServer;
+use common_meta_types::UDFWasmModule;
 use common_meta_types::UserDefinedFunction;
 use common_planners::CreateUserUDFPlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
+use sqlparser::ast::DataType;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateUDF {
@@ -31,21 +38,113 @@ pub struct DfCreateUDF {
     pub parameters: Vec<String>,
     pub definition: String,
     pub description: String,
+    pub language: UDFLanguage,
+    pub server: Option<DfUDFServer>,
+    pub is_aggregate: bool,
+}
+
+/// The external UDF server of `CREATE FUNCTION ... LANGUAGE <language> HANDLER = ... ADDRESS = ...`,
+/// or the WASM module of `CREATE FUNCTION ... LANGUAGE WASM HANDLER = ... AS '@<stage>/<path>'`
+/// whose `address` is the stage path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUDFServer {
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+    pub language: String,
+    pub handler: String,
+    pub address: String,
+}
+
+impl DfUDFServer {
+    fn type_names(&self) -> Result<(Vec<String>, String)> {
+        let type_name = |sql_type: &DataType| -> Result<String> {
+            Ok(SQLCommon::make_data_type(sql_type)?.name().to_string())
+        };
+        let arg_types = self
+            .arg_types
+            .iter()
+            .map(type_name)
+            .collect::<Result<Vec<_>>>()?;
+        Ok((arg_types, type_name(&self.return_type)?))
+    }
+
+    fn to_udf_server(&self) -> Result<UDFServer> {
+        let (arg_types, return_type) = self.type_names()?;
+        Ok(UDFServer {
+            address: self.address.clone(),
+            handler: self.handler.clone(),
+            language: self.language.clone(),
+            arg_types,
+            return_type,
+        })
+    }
+
+    fn to_wasm_module(&self) -> Result<UDFWasmModule> {
+        let (arg_types, return_type) = self.type_names()?;
+        Ok(UDFWasmModule {
+            stage_path: self.address.clone(),
+            handler: self.handler.clone(),
+            arg_types,
+            return_type,
+        })
+    }
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCreateUDF {
     #[tracing::instrument(level = "info", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
     async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        Ok(AnalyzedResult::SimpleQuery(Box::new(
-            PlanNode::CreateUserUDF(CreateUserUDFPlan {
-                if_not_exists: self.if_not_exists,
-                udf: UserDefinedFunction::new(
+        let udf = match self.language {
+            UDFLanguage::Sql if self.is_aggregate => {
+                UDFParser::default()
+                    .parse_aggregate(&self.udf_name, &self.parameters, &self.definition)
+                    .await?;
+                UserDefinedFunction::new_aggregate(
                     self.udf_name.as_str(),
                     self.parameters.clone(),
                     self.definition.as_str(),
                     self.description.as_str(),
-                ),
+                )
+            }
+            UDFLanguage::Sql => {
+                // Verify the definition before it's stored, it would fail on every call otherwise.
+                UDFParser::default()
+                    .parse(&self.udf_name, &self.parameters, &self.definition)
+                    .await?;
+                UserDefinedFunction::new(
+                    self.udf_name.as_str(),
+                    self.parameters.clone(),
+                    self.definition.as_str(),
+                    self.description.as_str(),
+                )
+            }
+            UDFLanguage::Wasm => {
+                let module = self
+                    .server
+                    .as_ref()
+                    .ok_or_else(|| ErrorCode::LogicalError("WASM UDF must have a WASM module"))?;
+                UserDefinedFunction::new_wasm(
+                    self.udf_name.as_str(),
+                    module.to_wasm_module()?,
+                    self.description.as_str(),
+                )
+            }
+            UDFLanguage::External => {
+                let server = self.server.as_ref().ok_or_else(|| {
+                    ErrorCode::LogicalError("External UDF must have a UDF server")
+                })?;
+                UserDefinedFunction::new_external(
+                    self.udf_name.as_str(),
+                    server.to_udf_server()?,
+                    self.description.as_str(),
+                )
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateUserUDF(CreateUserUDFPlan {
+                if_not_exists: self.if_not_exists,
+                udf,
             }),
         )))
     }
@@ -12,21 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
+use common_meta_types::GrantObject;
+use common_meta_types::SettingConstraint;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserOption;
 use common_meta_types::UserOptionFlag;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CreateUserPlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::users::PasswordPolicy;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct DfAuthOption {
@@ -41,6 +48,21 @@ impl DfAuthOption {
             by_value: None,
         }
     }
+
+    /// Check the new password of the native user with the password policy.
+    pub fn check_password_policy(
+        &self,
+        ctx: &Arc<QueryContext>,
+        auth_info: &AuthInfo,
+    ) -> Result<()> {
+        if let (AuthInfo::Password { .. }, Some(password)) = (auth_info, &self.by_value) {
+            let auth_manager = ctx.get_auth_manager();
+            auth_manager
+                .get_password_policy()
+                .check_complexity(password)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +71,18 @@ pub enum DfUserWithOption {
     NoTenantSetting,
     ConfigReload,
     NoConfigReload,
+    // DEFAULT_DATABASE = 'db', an empty name unsets it.
+    DefaultDatabase(String),
+    // DEFAULT_ROLE = 'role', an empty name unsets it.
+    DefaultRole(String),
+    // DEFAULT_SETTINGS = (max_threads = 8 timezone = 'UTC'), replaces all the default settings.
+    DefaultSettings(BTreeMap<String, String>),
+    // SETTING_CONSTRAINT max_threads = (min = 1 max = 8 readonly = false), () removes it.
+    SettingConstraint(String, Option<SettingConstraint>),
+    // The user must change the password before executing the other statements.
+    MustChangePassword,
+    // NETWORK_POLICY = 'policy', an empty name unsets it.
+    NetworkPolicy(String),
 }
 
 impl TryFrom<&str> for DfUserWithOption {
@@ -60,6 +94,7 @@ impl TryFrom<&str> for DfUserWithOption {
             "NOTENANTSETTING" => Ok(DfUserWithOption::NoTenantSetting),
             "CONFIGRELOAD" => Ok(DfUserWithOption::ConfigReload),
             "NOCONFIGRELOAD" => Ok(DfUserWithOption::NoConfigReload),
+            "MUST_CHANGE_PASSWORD" => Ok(DfUserWithOption::MustChangePassword),
             _ => Err(format!("Unknown user option: {}", value)),
         }
     }
@@ -80,7 +115,76 @@ impl DfUserWithOption {
             Self::NoConfigReload => {
                 option.unset_option_flag(UserOptionFlag::ConfigReload);
             }
+            Self::DefaultDatabase(database) => {
+                option.set_default_database(Some(database.clone()).filter(|v| !v.is_empty()));
+            }
+            Self::DefaultRole(role) => {
+                option.set_default_role(Some(role.clone()).filter(|v| !v.is_empty()));
+            }
+            Self::DefaultSettings(settings) => {
+                option.set_default_settings(settings.clone());
+            }
+            Self::SettingConstraint(name, constraint) => {
+                option.set_setting_constraint(name.clone(), constraint.clone());
+            }
+            Self::MustChangePassword => {
+                option.password_state_mut().must_change = true;
+            }
+            Self::NetworkPolicy(policy) => {
+                option.set_network_policy(Some(policy.clone()).filter(|v| !v.is_empty()));
+            }
+        }
+    }
+
+    /// Check the default database, role, settings and network policy exist, and the current user
+    /// can change the setting constraints, which need the SUPER privilege.
+    pub async fn validate(&self, ctx: &Arc<QueryContext>) -> Result<()> {
+        match self {
+            Self::DefaultDatabase(database) if !database.is_empty() => {
+                let tenant = ctx.get_tenant();
+                ctx.get_catalog().get_database(&tenant, database).await?;
+            }
+            Self::DefaultRole(role) if !role.is_empty() => {
+                let tenant = ctx.get_tenant();
+                ctx.get_user_manager()
+                    .get_role(&tenant, role.clone())
+                    .await?;
+            }
+            Self::NetworkPolicy(policy) if !policy.is_empty() => {
+                let tenant = ctx.get_tenant();
+                ctx.get_user_manager()
+                    .get_network_policy(&tenant, policy)
+                    .await?;
+            }
+            Self::DefaultSettings(settings) => {
+                let session_settings = ctx.get_settings();
+                if let Some(name) = settings.keys().find(|k| !session_settings.has_setting(k)) {
+                    return Err(ErrorCode::UnknownVariable(format!(
+                        "Unknown variable: {:?}",
+                        name
+                    )));
+                }
+            }
+            Self::SettingConstraint(name, constraint) => {
+                ctx.get_current_session()
+                    .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+                match constraint {
+                    Some(constraint) => ctx
+                        .get_settings()
+                        .check_setting_constraint(name, constraint)?,
+                    None if !ctx.get_settings().has_setting(name) => {
+                        return Err(ErrorCode::UnknownVariable(format!(
+                            "Unknown variable: {:?}",
+                            name
+                        )));
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
         }
+        Ok(())
     }
 }
 
@@ -94,19 +198,20 @@ pub struct DfCreateUser {
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCreateUser {
-    #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let mut user_option = UserOption::default();
         for option in &self.with_options {
+            option.validate(&ctx).await?;
             option.apply(&mut user_option);
         }
+        let auth_info = AuthInfo::create(&self.auth_option.auth_type, &self.auth_option.by_value)?;
+        self.auth_option.check_password_policy(&ctx, &auth_info)?;
+        user_option.password_state_mut().changed_at = Some(PasswordPolicy::now());
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::CreateUser(
             CreateUserPlan {
                 user: self.user.clone(),
-                auth_info: AuthInfo::create(
-                    &self.auth_option.auth_type,
-                    &self.auth_option.by_value,
-                )?,
+                auth_info,
                 user_option,
             },
         ))))
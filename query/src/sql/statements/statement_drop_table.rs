@@ -29,6 +29,7 @@ use crate::sql::statements::AnalyzedResult;
 pub struct DfDropTable {
     pub if_exists: bool,
     pub name: ObjectName,
+    pub all: bool,
 }
 
 #[async_trait::async_trait]
@@ -45,6 +46,7 @@ impl AnalyzableStatement for DfDropTable {
                 tenant,
                 db,
                 table,
+                all: self.all,
             },
         ))))
     }
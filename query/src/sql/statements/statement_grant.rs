@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::PrincipalIdentity;
@@ -32,6 +34,7 @@ pub struct DfGrantPrivilegeStatement {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
     pub on: DfGrantObject,
+    pub column_priv_types: BTreeMap<String, UserPrivilegeSet>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -66,6 +69,11 @@ impl AnalyzableStatement for DfGrantPrivilegeStatement {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let grant_object = self.on.convert_to_grant_object(ctx);
+        if !self.column_priv_types.is_empty() && !matches!(grant_object, GrantObject::Table(..)) {
+            return Err(ErrorCode::IllegalGrant(
+                "Column privileges can only be granted on a table",
+            ));
+        }
 
         // ALL PRIVILEGES have different available privileges set on different grant objects
         let mut priv_types = self.priv_types;
@@ -78,6 +86,7 @@ impl AnalyzableStatement for DfGrantPrivilegeStatement {
                 principal: self.principal.clone(),
                 on: grant_object,
                 priv_types,
+                column_priv_types: self.column_priv_types.clone(),
             }),
         )))
     }
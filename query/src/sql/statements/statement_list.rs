@@ -27,6 +27,7 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfList {
     pub location: String,
+    pub files: Vec<String>,
     pub pattern: String,
 }
 
@@ -43,6 +44,7 @@ impl AnalyzableStatement for DfList {
         let plan_node = ListPlan {
             path,
             stage,
+            files: self.files.clone(),
             pattern: self.pattern.clone(),
         };
 
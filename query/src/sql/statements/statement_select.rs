@@ -18,9 +18,12 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserPrivilegeType;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_window_exprs;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::rebase_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
@@ -35,13 +38,16 @@ use crate::sql::statements::analyzer_statement::QueryAnalyzeState;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::query::JoinedTableDesc;
+use crate::sql::statements::query::MaskingPolicyRewriter;
 use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::query::QueryASTIR;
 use crate::sql::statements::query::QueryCollectPushDowns;
 use crate::sql::statements::query::QueryNormalizer;
+use crate::sql::statements::query::RowAccessPolicyRewriter;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::QueryRelation;
+use crate::storages::S3StageTable;
 use crate::storages::ToReadDataSourcePlan;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,13 +69,20 @@ impl AnalyzableStatement for DfQueryStatement {
         let analyzer = JoinedSchemaAnalyzer::create(ctx.clone());
         let mut joined_schema = analyzer.analyze(self).await?;
 
-        let mut ir = QueryNormalizer::normalize(ctx.clone(), self).await?;
+        // The predicate of the row access policy is added before the normalization, so it is
+        // also pushed down to the table.
+        let mut ir =
+            match RowAccessPolicyRewriter::rewrite(ctx.clone(), &joined_schema, self).await? {
+                None => QueryNormalizer::normalize(ctx.clone(), self).await?,
+                Some(query) => QueryNormalizer::normalize(ctx.clone(), &query).await?,
+            };
 
         let has_aggregation = !find_aggregate_exprs(&ir.projection_expressions).is_empty();
 
         QualifiedRewriter::rewrite(&joined_schema, ctx.clone(), &mut ir)?;
         QueryCollectPushDowns::collect_extras(&mut ir, &mut joined_schema, has_aggregation)?;
-        let analyze_state = self.analyze_query(ir).await?;
+        let mut analyze_state = self.analyze_query(ir).await?;
+        MaskingPolicyRewriter::rewrite(ctx.clone(), &mut joined_schema, &mut analyze_state).await?;
         self.check_and_finalize(joined_schema, analyze_state, ctx)
             .await
     }
@@ -87,6 +100,7 @@ impl DfQueryStatement {
 
         if let Some(predicate) = &ir.filter_predicate {
             Self::verify_no_aggregate(predicate, "filter")?;
+            Self::verify_no_window(predicate, "filter")?;
             analyze_state.filter = Some(predicate.clone());
         }
 
@@ -94,6 +108,7 @@ impl DfQueryStatement {
 
         // Allow `SELECT name FROM system.databases HAVING name = 'xxx'`
         if let Some(predicate) = &ir.having_predicate {
+            Self::verify_no_window(predicate, "having")?;
             analyze_state.having = Some(rebase_expr(predicate, &analyze_state.expressions)?);
         }
 
@@ -145,9 +160,58 @@ impl DfQueryStatement {
             Self::analyze_aggregate(&ir.aggregate_expressions, &mut analyze_state)?;
         }
 
+        Self::analyze_window(&mut analyze_state)?;
         Ok(analyze_state)
     }
 
+    // The window functions are evaluated after the aggregation and HAVING, so the expressions
+    // without window functions, the arguments and the windows are evaluated before them.
+    fn analyze_window(state: &mut QueryAnalyzeState) -> Result<()> {
+        let window_functions = find_window_exprs(&state.expressions);
+        if window_functions.is_empty() {
+            return Ok(());
+        }
+
+        for expression in &state.expressions.clone() {
+            if find_window_exprs_in_expr(expression).is_empty() {
+                state.add_before_window_expression(expression);
+            }
+        }
+
+        for window_function in &window_functions {
+            if let Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } = window_function
+            {
+                for expr in args.iter().chain(partition_by).chain(order_by) {
+                    Self::verify_no_window(expr, "window function")?;
+                    match expr {
+                        Expression::Sort { expr, .. } => state.add_before_window_expression(expr),
+                        _ => state.add_before_window_expression(expr),
+                    }
+                }
+            }
+        }
+
+        for window_function in &window_functions {
+            let base_exprs = &state.before_window_expressions;
+            state
+                .window_expressions
+                .push(rebase_expr(window_function, base_exprs)?);
+        }
+
+        let mut expressions = Vec::with_capacity(state.expressions.len());
+        for expression in &state.expressions {
+            let expression = rebase_expr(expression, &window_functions)?;
+            expressions.push(rebase_expr(&expression, &state.before_window_expressions)?);
+        }
+        state.expressions = expressions;
+        Ok(())
+    }
+
     fn analyze_aggregate(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
         let aggregate_functions = find_aggregate_exprs(exprs);
         let aggregate_functions_args = expand_aggregate_arg_exprs(&aggregate_functions);
@@ -180,6 +244,16 @@ impl DfQueryStatement {
         Ok(())
     }
 
+    fn verify_no_window(expr: &Expression, info: &str) -> Result<()> {
+        match find_window_exprs_in_expr(expr).is_empty() {
+            true => Ok(()),
+            false => Err(ErrorCode::SyntaxException(format!(
+                "{} cannot contain window functions",
+                info
+            ))),
+        }
+    }
+
     fn verify_no_aggregate(expr: &Expression, info: &str) -> Result<()> {
         match find_aggregate_exprs_in_expr(expr).is_empty() {
             true => Ok(()),
@@ -209,9 +283,35 @@ impl DfQueryStatement {
 
         match tables_desc.remove(0) {
             JoinedTableDesc::Table {
-                table, push_downs, ..
+                table,
+                push_downs,
+                column_privileges,
+                ..
             } => {
-                let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
+                if let Some((database, table_name)) = column_privileges {
+                    let schema = table.schema();
+                    let columns = match push_downs.as_ref().and_then(|e| e.projection.as_ref()) {
+                        Some(projection) => projection
+                            .iter()
+                            .map(|index| schema.field(*index).name().clone())
+                            .collect::<Vec<_>>(),
+                        None => schema.fields().iter().map(|f| f.name().clone()).collect(),
+                    };
+                    ctx.get_current_session()
+                        .validate_columns_privilege(
+                            &database,
+                            &table_name,
+                            &columns,
+                            UserPrivilegeType::Select,
+                        )
+                        .await?;
+                }
+
+                // The stage files have no table info, they are read by the stage source.
+                let source_plan = match table.as_any().downcast_ref::<S3StageTable>() {
+                    Some(stage_table) => stage_table.source_plan(push_downs),
+                    None => table.read_plan(ctx.clone(), push_downs).await?,
+                };
                 state.relation = QueryRelation::FromTable(Box::new(source_plan));
             }
             JoinedTableDesc::Subquery {
@@ -268,6 +368,30 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.window_expressions.is_empty() {
+            let mut fields = data_block.schema().fields().clone();
+            if !state.before_window_expressions.is_empty() {
+                match Self::dry_run_exprs(&state.before_window_expressions, &data_block) {
+                    Ok(res) => fields.extend(res.schema().fields().iter().cloned()),
+                    Err(cause) => {
+                        return Err(cause.add_message_back(" (while in select before window)"));
+                    }
+                }
+            }
+
+            let mut schema = DataSchemaRefExt::create(fields.clone());
+            for window_expression in &state.window_expressions {
+                match window_expression.to_data_field(&schema) {
+                    Ok(field) => fields.push(field),
+                    Err(cause) => {
+                        return Err(cause.add_message_back(" (while in select window)"));
+                    }
+                }
+                schema = DataSchemaRefExt::create(fields.clone());
+            }
+            data_block = DataBlock::empty_with_schema(schema);
+        }
+
         if !state.expressions.is_empty() {
             match Self::dry_run_exprs(&state.expressions, &data_block) {
                 Ok(res) => {
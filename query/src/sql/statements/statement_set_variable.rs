@@ -22,6 +22,7 @@ use common_planners::VarValue;
 use common_tracing::tracing;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SetVariableValue;
+use sqlparser::ast::Value;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -29,6 +30,7 @@ use crate::sql::statements::AnalyzedResult;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfSetVariable {
+    pub global: bool,
     pub local: bool,
     pub hivevar: bool,
     pub variable: Ident,
@@ -54,11 +56,15 @@ impl AnalyzableStatement for DfSetVariable {
 }
 
 impl DfSetVariable {
-    fn mapping_set_var(variable: String, value: &SetVariableValue) -> VarValue {
+    fn mapping_set_var(variable: String, value: &SetVariableValue, is_global: bool) -> VarValue {
         VarValue {
             variable,
+            is_global,
             value: match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
+                sqlparser::ast::SetVariableValue::Literal(Value::SingleQuotedString(v)) => {
+                    v.clone()
+                }
                 sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
             },
         }
@@ -68,7 +74,7 @@ impl DfSetVariable {
         let variable = self.variable.value.clone();
         self.value
             .iter()
-            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), value))
+            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), value, self.global))
             .collect()
     }
 }
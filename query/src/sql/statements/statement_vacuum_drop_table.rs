@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::VacuumDropTablePlan;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfVacuumDropTable {
+    pub database: Option<String>,
+    pub retain_hours: Option<u64>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfVacuumDropTable {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let tenant = ctx.get_tenant();
+        let db = self
+            .database
+            .clone()
+            .unwrap_or_else(|| ctx.get_current_database());
+        let retain_secs = self.retain_hours.unwrap_or(0) * 60 * 60;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::VacuumDropTable(VacuumDropTablePlan {
+                tenant,
+                db,
+                retain_secs,
+            }),
+        )))
+    }
+}
@@ -16,10 +16,21 @@ use std::collections::HashSet;
 
 use lazy_static::lazy_static;
 
+use crate::storages::external::EXTERNAL_OPT_KEY_AWS_SECRET_KEY;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 
+/// The row access policy attached to the table, and the columns bound to its parameters,
+/// separated by commas. They are set by `ALTER TABLE ... ADD ROW ACCESS POLICY`.
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+pub const OPT_KEY_ROW_ACCESS_POLICY_COLUMNS: &str = "row_access_policy_columns";
+
+/// The masking policies of the columns of the table, a JSON object from the column name to the
+/// policy name. It is set by `ALTER TABLE ... MODIFY COLUMN ... SET MASKING POLICY`.
+pub const OPT_KEY_MASKING_POLICIES: &str = "masking_policies";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -38,6 +49,9 @@ lazy_static! {
         let mut r = HashSet::new();
         r.insert(OPT_KEY_DATABASE_ID);
         r.insert(OPT_KEY_SNAPSHOT_LOC);
+        r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+        r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+        r.insert(OPT_KEY_MASKING_POLICIES);
         r
     };
 
@@ -47,6 +61,11 @@ lazy_static! {
         r.insert(OPT_KEY_SNAPSHOT_LOC);
         r.insert(OPT_KEY_SNAPSHOT_LOCATION);
         r.insert(OPT_KEY_DATABASE_ID);
+        r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+        r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+        r.insert(OPT_KEY_MASKING_POLICIES);
+        // The secret of the external table location.
+        r.insert(EXTERNAL_OPT_KEY_AWS_SECRET_KEY);
         r
     };
 }
@@ -0,0 +1,175 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::parquet::metadata::ColumnChunkMetaData;
+use common_arrow::parquet::metadata::RowGroupMetaData;
+use common_arrow::parquet::schema::types::PhysicalType;
+use common_arrow::parquet::statistics::BinaryStatistics;
+use common_arrow::parquet::statistics::BooleanStatistics;
+use common_arrow::parquet::statistics::PrimitiveStatistics;
+use common_arrow::parquet::statistics::Statistics as ParquetStatistics;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Extras;
+use common_planners::RequireColumnsVisitor;
+
+use crate::storages::index::BlockStatistics;
+use crate::storages::index::ColumnLeaves;
+use crate::storages::index::ColumnStatistics;
+use crate::storages::index::RangeFilter;
+
+/// Prunes the row groups of parquet files by the min/max statistics of their footers.
+pub struct RowGroupPruner {
+    schema: DataSchemaRef,
+    column_leaves: ColumnLeaves,
+    /// The range filter of the first filter of the push downs, and the leaf ids of its columns.
+    filter: Option<(RangeFilter, Vec<u32>)>,
+}
+
+impl RowGroupPruner {
+    pub fn try_create(schema: DataSchemaRef, push_downs: &Option<Extras>) -> Result<Self> {
+        let column_leaves = ColumnLeaves::new_from_schema(&schema);
+        let filter = match push_downs {
+            Some(extras) if !extras.filters.is_empty() => {
+                // for the time being, we only handle the first expr
+                let expr = &extras.filters[0];
+                let mut leaf_ids = vec![];
+                for name in RequireColumnsVisitor::collect_columns_from_expr(expr)? {
+                    if let Ok(index) = schema.index_of(&name) {
+                        let column_leaf = &column_leaves.column_leaves[index];
+                        if column_leaf.children.is_none() {
+                            leaf_ids.push(column_leaf.leaf_ids[0] as u32);
+                        }
+                    }
+                }
+                Some((RangeFilter::try_create(expr, schema.clone())?, leaf_ids))
+            }
+            _ => None,
+        };
+
+        Ok(RowGroupPruner {
+            schema,
+            column_leaves,
+            filter,
+        })
+    }
+
+    /// Returns false if none of the rows of the row group can match the filter.
+    pub fn should_keep(&self, row_group: &RowGroupMetaData) -> Result<bool> {
+        match &self.filter {
+            None => Ok(true),
+            Some((range_filter, leaf_ids)) => {
+                let stats = self.row_group_statistics(row_group);
+
+                // The row group is kept if any column of the filter has no statistics.
+                if leaf_ids.iter().any(|id| !stats.contains_key(id)) {
+                    return Ok(true);
+                }
+                range_filter.eval(&stats)
+            }
+        }
+    }
+
+    /// The statistics of the top level columns of the table, by their leaf ids.
+    /// Columns missing in the file, or with statistics of another type, are left out.
+    pub fn row_group_statistics(&self, row_group: &RowGroupMetaData) -> BlockStatistics {
+        let mut stats = BlockStatistics::new();
+        for (index, field) in self.schema.fields().iter().enumerate() {
+            let column_leaf = &self.column_leaves.column_leaves[index];
+            if column_leaf.children.is_some() {
+                continue;
+            }
+
+            let column = row_group
+                .columns()
+                .iter()
+                .find(|c| c.descriptor().path_in_schema() == [field.name().clone()]);
+            let type_id = remove_nullable(field.data_type()).data_type_id();
+            if let Some(column_stats) = column.and_then(|c| Self::column_statistics(type_id, c)) {
+                stats.insert(column_leaf.leaf_ids[0] as u32, column_stats);
+            }
+        }
+        stats
+    }
+
+    fn column_statistics(
+        type_id: TypeID,
+        column: &ColumnChunkMetaData,
+    ) -> Option<ColumnStatistics> {
+        let stats = column.statistics()?.ok()?;
+        let null_count = stats.null_count()? as u64;
+        let (min, max) = Self::min_max(type_id, stats.as_ref())?;
+        Some(ColumnStatistics {
+            min,
+            max,
+            null_count,
+            in_memory_size: column.uncompressed_size() as u64,
+        })
+    }
+
+    // The min/max values of the physical type, converted to the values of the column type.
+    fn min_max(type_id: TypeID, stats: &dyn ParquetStatistics) -> Option<(DataValue, DataValue)> {
+        let any = stats.as_any();
+        match stats.physical_type() {
+            PhysicalType::Boolean if type_id == TypeID::Boolean => {
+                let s = any.downcast_ref::<BooleanStatistics>()?;
+                Some((
+                    DataValue::Boolean(s.min_value?),
+                    DataValue::Boolean(s.max_value?),
+                ))
+            }
+            PhysicalType::Int32 if type_id.is_integer() => {
+                let s = any.downcast_ref::<PrimitiveStatistics<i32>>()?;
+                let (min, max) = (s.min_value?, s.max_value?);
+                match type_id.is_unsigned_integer() {
+                    true => Some((
+                        DataValue::UInt64(min as u32 as u64),
+                        DataValue::UInt64(max as u32 as u64),
+                    )),
+                    false => Some((DataValue::Int64(min as i64), DataValue::Int64(max as i64))),
+                }
+            }
+            PhysicalType::Int64 if type_id.is_integer() => {
+                let s = any.downcast_ref::<PrimitiveStatistics<i64>>()?;
+                let (min, max) = (s.min_value?, s.max_value?);
+                match type_id.is_unsigned_integer() {
+                    true => Some((DataValue::UInt64(min as u64), DataValue::UInt64(max as u64))),
+                    false => Some((DataValue::Int64(min), DataValue::Int64(max))),
+                }
+            }
+            PhysicalType::Float if type_id.is_floating() => {
+                let s = any.downcast_ref::<PrimitiveStatistics<f32>>()?;
+                Some((
+                    DataValue::Float64(s.min_value? as f64),
+                    DataValue::Float64(s.max_value? as f64),
+                ))
+            }
+            PhysicalType::Double if type_id.is_floating() => {
+                let s = any.downcast_ref::<PrimitiveStatistics<f64>>()?;
+                Some((
+                    DataValue::Float64(s.min_value?),
+                    DataValue::Float64(s.max_value?),
+                ))
+            }
+            PhysicalType::ByteArray if type_id.is_string() => {
+                let s = any.downcast_ref::<BinaryStatistics>()?;
+                Some((
+                    DataValue::String(s.min_value.clone()?),
+                    DataValue::String(s.max_value.clone()?),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::read_metadata_async;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::S3File;
+use common_meta_types::StageS3Storage;
+use common_meta_types::StageStorage;
+use common_meta_types::TableInfo;
+use common_planners::Extras;
+use common_planners::PartInfoPtr;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::ParquetSourceBuilder;
+use common_streams::SendableDataBlockStream;
+use common_streams::Source;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::AsyncSource;
+use crate::pipelines::new::processors::AsyncSourcer;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::new::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+use crate::sql::statements::parse_stage_storage;
+use crate::storages::external::ExternalPartInfo;
+use crate::storages::external::RowGroupPruner;
+use crate::storages::StorageContext;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+
+pub const EXTERNAL_OPT_KEY_LOCATION: &str = "location";
+pub const EXTERNAL_OPT_KEY_FORMAT: &str = "format";
+pub const EXTERNAL_OPT_KEY_AWS_KEY_ID: &str = "aws_key_id";
+pub const EXTERNAL_OPT_KEY_AWS_SECRET_KEY: &str = "aws_secret_key";
+
+/// The location of the external table, parsed from the table options.
+#[derive(Clone, Debug)]
+pub struct ExternalTableOptions {
+    pub s3: StageS3Storage,
+}
+
+impl ExternalTableOptions {
+    pub fn try_from_table_options(options: &HashMap<String, String>) -> Result<Self> {
+        let location = options.get(EXTERNAL_OPT_KEY_LOCATION).ok_or_else(|| {
+            ErrorCode::BadOption(format!(
+                "table option {} is required by the EXTERNAL engine",
+                EXTERNAL_OPT_KEY_LOCATION
+            ))
+        })?;
+
+        // Only the parquet files can be read in place for now.
+        if let Some(format) = options.get(EXTERNAL_OPT_KEY_FORMAT) {
+            if !format.eq_ignore_ascii_case("parquet") {
+                return Err(ErrorCode::BadOption(format!(
+                    "invalid value of table option {}: {}, expects parquet",
+                    EXTERNAL_OPT_KEY_FORMAT, format
+                )));
+            }
+        }
+
+        // The credentials are kept as the aws_key_id and aws_secret_key options.
+        let (storage, _) = parse_stage_storage(location, options, &HashMap::new())?;
+        match storage {
+            StageStorage::S3(s3) => Ok(ExternalTableOptions { s3 }),
+        }
+    }
+
+    // The location is a directory, all the files under it are the data of the table.
+    pub fn path(&self) -> String {
+        format!("{}/", self.s3.path.trim_end_matches('/'))
+    }
+
+    pub async fn operator(&self, ctx: &Arc<QueryContext>) -> Result<Operator> {
+        let endpoint = &ctx.get_config().storage.s3.endpoint_url;
+        let bucket = &self.s3.bucket;
+
+        let key_id = &self.s3.credentials_aws_key_id;
+        let secret_key = &self.s3.credentials_aws_secret_key;
+
+        S3File::open(endpoint, bucket, key_id, secret_key, "/").await
+    }
+}
+
+/// A table over the parquet files of a location, the files are read in place.
+pub struct ExternalTable {
+    table_info: TableInfo,
+    options: ExternalTableOptions,
+}
+
+impl ExternalTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = ExternalTableOptions::try_from_table_options(table_info.options())?;
+        Ok(Box::new(Self {
+            table_info,
+            options,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "EXTERNAL".to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+        }
+    }
+
+    // The data files under the location, hidden files like `_SUCCESS` or `.crc` files are skipped.
+    async fn list_files(&self, operator: &Operator) -> Result<Vec<String>> {
+        let mut files = S3File::list(operator, &self.options.path()).await?;
+        files.retain(|file| match Path::new(file).file_name() {
+            None => false,
+            Some(name) => {
+                let name = name.to_string_lossy();
+                !name.starts_with('_') && !name.starts_with('.')
+            }
+        });
+        files.sort();
+        Ok(files)
+    }
+
+    fn projected_schema(&self, push_downs: &Option<Extras>) -> DataSchemaRef {
+        let schema = self.table_info.schema();
+        match push_downs {
+            Some(Extras {
+                projection: Some(prj),
+                ..
+            }) => Arc::new(schema.project(prj.clone())),
+            _ => schema,
+        }
+    }
+
+    // Read the row group of the part, a row group is read as one block.
+    async fn read_part(
+        operator: &Operator,
+        schema: DataSchemaRef,
+        part: &PartInfoPtr,
+    ) -> Result<DataBlock> {
+        let part = ExternalPartInfo::from_part(part)?;
+        let reader = operator.object(&part.location).seekable_reader(..);
+        let mut source = ParquetSourceBuilder::create(schema)
+            .row_groups(vec![part.row_group])
+            .build(reader)?;
+
+        match source.read().await? {
+            Some(block) => Ok(block),
+            None => Err(ErrorCode::ParquetError(format!(
+                "Row group {} not found in file {}",
+                part.row_group, part.location
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    // Each row group of the files is a partition, the footers of the files are read
+    // to prune the row groups by their statistics.
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let operator = self.options.operator(&ctx).await?;
+        let files = self.list_files(&operator).await?;
+        if files.is_empty() {
+            return Ok((Statistics::default_exact(), vec![]));
+        }
+
+        let files_num = files.len();
+        let metas = futures::stream::iter(files)
+            .map(|file| {
+                let object = operator.object(&file);
+                async move {
+                    let mut reader = object.seekable_reader(..);
+                    let meta = read_metadata_async(&mut reader).await.map_err(|e| {
+                        ErrorCode::ParquetError(format!(
+                            "Cannot read the parquet footer of file {}: {}",
+                            file, e
+                        ))
+                    })?;
+                    Ok::<_, ErrorCode>((file, meta))
+                }
+            })
+            // configuration of the max size of buffered futures
+            .buffered(std::cmp::min(10, files_num))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let pruner = RowGroupPruner::try_create(self.table_info.schema(), &push_downs)?;
+        let limit = push_downs
+            .as_ref()
+            .and_then(|p| p.limit)
+            .unwrap_or(usize::MAX);
+
+        let mut statistics = Statistics::default_exact();
+        let mut partitions = Partitions::default();
+        for (file, meta) in &metas {
+            for (index, row_group) in meta.row_groups.iter().enumerate() {
+                statistics.partitions_total += 1;
+                if statistics.read_rows >= limit || !pruner.should_keep(row_group)? {
+                    continue;
+                }
+
+                let rows = row_group.num_rows() as usize;
+                partitions.push(ExternalPartInfo::create(file.clone(), index, rows));
+                statistics.read_rows += rows;
+                statistics.read_bytes += row_group.total_byte_size() as usize;
+            }
+        }
+
+        statistics.partitions_scanned = partitions.len();
+        statistics.is_exact = match &push_downs {
+            None => true,
+            Some(extras) => extras.filters.is_empty() && statistics.read_rows <= limit,
+        };
+
+        // Update context statistics.
+        ctx.get_dal_context()
+            .get_metrics()
+            .inc_partitions_total(statistics.partitions_total as u64);
+        ctx.get_dal_context()
+            .get_metrics()
+            .inc_partitions_scanned(statistics.partitions_scanned as u64);
+
+        Ok((statistics, partitions))
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let operator = self.options.operator(&ctx).await?;
+        let schema = self.projected_schema(&plan.push_downs);
+
+        let iter = std::iter::from_fn(move || match ctx.clone().try_get_partitions(1) {
+            Err(_) => None,
+            Ok(parts) if parts.is_empty() => None,
+            Ok(parts) => Some(parts),
+        })
+        .flatten();
+
+        let stream = futures::stream::iter(iter).then(move |part| {
+            let operator = operator.clone();
+            let schema = schema.clone();
+            async move { Self::read_part(&operator, schema, &part).await }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let schema = self.projected_schema(&plan.push_downs);
+
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let mut source_builder = SourcePipeBuilder::create();
+
+        for _index in 0..std::cmp::max(1, max_threads) {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                ExternalTableSource::create(
+                    ctx.clone(),
+                    output,
+                    self.options.clone(),
+                    schema.clone(),
+                )?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+}
+
+// Reads the partitions of the query one after another, the operator is created on the first read.
+struct ExternalTableSource {
+    ctx: Arc<QueryContext>,
+    options: ExternalTableOptions,
+    schema: DataSchemaRef,
+    operator: Option<Operator>,
+}
+
+impl ExternalTableSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        options: ExternalTableOptions,
+        schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, ExternalTableSource {
+            ctx,
+            options,
+            schema,
+            operator: None,
+        })
+    }
+}
+
+impl AsyncSource for ExternalTableSource {
+    const NAME: &'static str = "ExternalTableSource";
+
+    type BlockFuture<'a> = impl Future<Output = Result<Option<DataBlock>>> where Self: 'a;
+
+    fn generate(&mut self) -> Self::BlockFuture<'_> {
+        async move {
+            let mut parts = self.ctx.try_get_partitions(1)?;
+            if parts.is_empty() {
+                return Ok(None);
+            }
+
+            let operator = match &self.operator {
+                Some(operator) => operator.clone(),
+                None => {
+                    let operator = self.options.operator(&self.ctx).await?;
+                    self.operator = Some(operator.clone());
+                    operator
+                }
+            };
+
+            let part = parts.remove(0);
+            let block = ExternalTable::read_part(&operator, self.schema.clone(), &part).await?;
+            Ok(Some(block))
+        }
+    }
+}
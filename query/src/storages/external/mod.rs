@@ -0,0 +1,26 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod external_part;
+mod external_pruner;
+mod external_table;
+
+pub use external_part::ExternalPartInfo;
+pub use external_pruner::RowGroupPruner;
+pub use external_table::ExternalTable;
+pub use external_table::ExternalTableOptions;
+pub use external_table::EXTERNAL_OPT_KEY_AWS_KEY_ID;
+pub use external_table::EXTERNAL_OPT_KEY_AWS_SECRET_KEY;
+pub use external_table::EXTERNAL_OPT_KEY_FORMAT;
+pub use external_table::EXTERNAL_OPT_KEY_LOCATION;
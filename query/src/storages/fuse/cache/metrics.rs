@@ -13,13 +13,15 @@
 //  limitations under the License.
 //
 
-use common_metrics::label_counter;
-use common_metrics::label_counter_with_val;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
+use metrics::counter;
 
 const CACHE_READ_BYTES_FROM_REMOTE: &str = "cache_read_bytes_from_remote";
 const CACHE_READ_BYTES_FROM_LOCAL: &str = "cache_read_bytes_from_local";
 const CACHE_ACCESS_COUNT: &str = "cache_access_count";
 const CACHE_ACCESS_HIT_COUNT: &str = "cache_access_hit_count";
+const LABEL_KEY_CACHE: &str = "cache";
 
 pub struct TenantLabel {
     pub tenant_id: String,
@@ -28,32 +30,26 @@ pub struct TenantLabel {
 
 pub struct CacheDeferMetrics {
     pub tenant_label: TenantLabel,
+    pub cache_name: String,
     pub cache_hit: bool,
     pub read_bytes: u64,
 }
 
 impl Drop for CacheDeferMetrics {
     fn drop(&mut self) {
-        let label = &self.tenant_label;
-        let tenant_id = &label.tenant_id;
-        let cluster_id = &label.cluster_id;
+        // Labeled by the name of the cache as well, so the hit ratio of each cache is known.
+        let labels = [
+            (LABEL_KEY_TENANT, self.tenant_label.tenant_id.clone()),
+            (LABEL_KEY_CLUSTER, self.tenant_label.cluster_id.clone()),
+            (LABEL_KEY_CACHE, self.cache_name.clone()),
+        ];
 
-        label_counter(CACHE_ACCESS_COUNT, tenant_id, cluster_id);
+        counter!(CACHE_ACCESS_COUNT, 1, &labels);
         if self.cache_hit {
-            label_counter(CACHE_ACCESS_HIT_COUNT, tenant_id, cluster_id);
-            label_counter_with_val(
-                CACHE_READ_BYTES_FROM_LOCAL,
-                self.read_bytes,
-                tenant_id,
-                cluster_id,
-            );
+            counter!(CACHE_ACCESS_HIT_COUNT, 1, &labels);
+            counter!(CACHE_READ_BYTES_FROM_LOCAL, self.read_bytes, &labels);
         } else {
-            label_counter_with_val(
-                CACHE_READ_BYTES_FROM_REMOTE,
-                self.read_bytes,
-                tenant_id,
-                cluster_id,
-            );
+            counter!(CACHE_READ_BYTES_FROM_REMOTE, self.read_bytes, &labels);
         }
     }
 }
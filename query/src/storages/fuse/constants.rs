@@ -15,6 +15,10 @@
 pub const FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "block_size_threshold";
 pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
+pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
+pub const FUSE_OPT_KEY_DICTIONARY_ENCODING: &str = "dictionary_encoding";
+pub const FUSE_OPT_KEY_STATISTICS_LEVEL: &str = "statistics_level";
+pub const FUSE_OPT_KEY_WRITER_VERSION: &str = "writer_version";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
@@ -22,4 +26,5 @@ pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_BLOCK: usize = 1000 * 1000;
+pub const DEFAULT_ROW_PER_PAGE: usize = 0;
 pub const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
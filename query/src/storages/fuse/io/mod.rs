@@ -23,4 +23,5 @@ pub use read::SegmentInfoReader;
 pub use read::TableSnapshotReader;
 pub use write::BlockCompactor;
 pub use write::BlockStreamWriter;
+pub use write::BlockWriteOptions;
 pub use write::SegmentInfoStream;
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -42,14 +45,21 @@ use opendal::Operator;
 use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
 use crate::storages::fuse::meta::Compression;
+use crate::storages::fuse::FuseTableMetrics;
+use crate::storages::index::ColumnLeaves;
 
 #[derive(Clone)]
 pub struct BlockReader {
     operator: Operator,
     projection: Vec<usize>,
+    /// The ids of the leaf columns to read, struct columns are stored as their leaf columns.
+    leaf_projection: Vec<usize>,
+    column_leaves: ColumnLeaves,
     arrow_schema: Arc<Schema>,
     projected_schema: DataSchemaRef,
+    projected_leaf_schema: DataSchemaRef,
     parquet_schema_descriptor: SchemaDescriptor,
+    metrics: FuseTableMetrics,
 }
 
 impl BlockReader {
@@ -57,17 +67,28 @@ impl BlockReader {
         operator: Operator,
         schema: DataSchemaRef,
         projection: Vec<usize>,
+        metrics: FuseTableMetrics,
     ) -> Result<Arc<BlockReader>> {
         let projected_schema = DataSchemaRef::new(schema.project(projection.clone()));
 
-        let arrow_schema = schema.to_arrow();
+        let column_leaves = ColumnLeaves::new_from_schema(&schema);
+        let leaf_projection = column_leaves.leaf_ids(&projection);
+        let leaf_schema = column_leaves.leaf_schema();
+        let projected_leaf_schema =
+            DataSchemaRef::new(leaf_schema.project(leaf_projection.clone()));
+
+        let arrow_schema = leaf_schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
         Ok(Arc::new(BlockReader {
             operator,
             projection,
+            leaf_projection,
+            column_leaves,
             projected_schema,
+            projected_leaf_schema,
             parquet_schema_descriptor,
             arrow_schema: Arc::new(arrow_schema),
+            metrics,
         }))
     }
 
@@ -103,10 +124,10 @@ impl BlockReader {
 
         let rows = part.nums_rows;
         // TODO: add prefetch column data.
-        let num_cols = self.projection.len();
+        let num_cols = self.leaf_projection.len();
         let mut column_chunk_futs = Vec::with_capacity(num_cols);
         let mut col_idx = Vec::with_capacity(num_cols);
-        for index in &self.projection {
+        for index in &self.leaf_projection {
             let column_meta = &part.columns_meta[index];
             let column_reader = self.operator.object(&part.location);
             let fut = async move {
@@ -122,10 +143,13 @@ impl BlockReader {
             col_idx.push(index);
         }
 
+        let start = Instant::now();
         let chunks = futures::stream::iter(column_chunk_futs)
             .buffered(std::cmp::min(10, num_cols))
             .try_collect::<Vec<_>>()
             .await?;
+        let bytes = chunks.iter().map(|chunk| chunk.len() as u64).sum();
+        self.metrics.inc_block_read(bytes, start.elapsed());
 
         let mut columns_array_iter = Vec::with_capacity(num_cols);
         for (i, column_chunk) in chunks.into_iter().enumerate() {
@@ -147,18 +171,18 @@ impl BlockReader {
     }
 
     pub fn deserialize(&self, part: PartInfoPtr, chunks: Vec<Vec<u8>>) -> Result<DataBlock> {
-        if self.projection.len() != chunks.len() {
+        if self.leaf_projection.len() != chunks.len() {
             return Err(ErrorCode::LogicalError(
                 "Columns chunk len must be equals projections len.",
             ));
         }
 
         let part = FusePartInfo::from_part(&part)?;
-        let mut columns_array_iter = Vec::with_capacity(self.projection.len());
+        let mut columns_array_iter = Vec::with_capacity(self.leaf_projection.len());
 
         let num_rows = part.nums_rows;
         for (index, column_chunk) in chunks.into_iter().enumerate() {
-            let index = self.projection[index];
+            let index = self.leaf_projection[index];
             let field = self.arrow_schema.fields[index].clone();
             let column_descriptor = self.parquet_schema_descriptor.column(index);
             let column_meta = &part.columns_meta[&index];
@@ -177,15 +201,15 @@ impl BlockReader {
         match deserializer.next() {
             None => Err(ErrorCode::ParquetError("fail to get a chunk")),
             Some(Err(cause)) => Err(ErrorCode::from(cause)),
-            Some(Ok(chunk)) => DataBlock::from_chunk(&self.projected_schema, &chunk),
+            Some(Ok(chunk)) => self.build_block(&chunk),
         }
     }
 
     pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<Vec<u8>>> {
         let part = FusePartInfo::from_part(&part)?;
-        let mut join_handlers = Vec::with_capacity(self.projection.len());
+        let mut join_handlers = Vec::with_capacity(self.leaf_projection.len());
 
-        for index in &self.projection {
+        for index in &self.leaf_projection {
             let column_meta = &part.columns_meta[index];
 
             join_handlers.push(Self::read_column(
@@ -195,7 +219,11 @@ impl BlockReader {
             ));
         }
 
-        futures::future::try_join_all(join_handlers).await
+        let start = Instant::now();
+        let chunks = futures::future::try_join_all(join_handlers).await?;
+        let bytes = chunks.iter().map(|chunk| chunk.len() as u64).sum();
+        self.metrics.inc_block_read(bytes, start.elapsed());
+        Ok(chunks)
     }
 
     async fn read_column(o: Object, offset: u64, length: u64) -> Result<Vec<u8>> {
@@ -225,10 +253,18 @@ impl BlockReader {
         match deserializer.next() {
             None => Err(ErrorCode::ParquetError("fail to get a chunk")),
             Some(Err(cause)) => Err(ErrorCode::from(cause)),
-            Some(Ok(chunk)) => DataBlock::from_chunk(&self.projected_schema, &chunk),
+            Some(Ok(chunk)) => self.build_block(&chunk),
         }
     }
 
+    fn build_block(&self, chunk: &Chunk<ArrayRef>) -> Result<DataBlock> {
+        let leaf_block = DataBlock::from_chunk(&self.projected_leaf_schema, chunk)?;
+        let columns = self
+            .column_leaves
+            .rebuild_columns(&self.projection, leaf_block.columns().to_vec())?;
+        Ok(DataBlock::create(self.projected_schema.clone(), columns))
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> ParquetCompression {
         match meta_compression {
             Compression::Lz4 => ParquetCompression::Lz4,
@@ -65,11 +65,11 @@ where L: Loader<T> + HasTenantLabel
 
                 // in PR #3798, the cache is degenerated to metered by count of cached item,
                 // later, when the size of BlockMeta could be acquired (needs some enhancements of crate `parquet2`)
-                // 1) the `read_bytes` metric should be re-enabled
-                // 2) the metrics need to be labeled by the name of cache as well
+                // the `read_bytes` metric should be re-enabled
 
                 let mut metrics = CacheDeferMetrics {
                     tenant_label,
+                    cache_name: self.name.clone(),
                     cache_hit: false,
                     read_bytes: 0,
                 };
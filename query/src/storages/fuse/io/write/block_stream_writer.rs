@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_arrow::parquet::FileMetaData;
 use common_datablocks::DataBlock;
@@ -28,12 +29,15 @@ use futures::TryStreamExt;
 use opendal::Operator;
 
 use super::block_writer;
+use super::block_writer::BlockWriteOptions;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::ColumnMeta;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
+use crate::storages::fuse::FuseTableMetrics;
+use crate::storages::index::ColumnLeaves;
 
 pub type SegmentInfoStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = Result<SegmentInfo>> + Send>>;
@@ -45,6 +49,8 @@ pub struct BlockStreamWriter {
     number_of_blocks_accumulated: usize,
     statistics_accumulator: Option<StatisticsAccumulator>,
     meta_locations: TableMetaLocationGenerator,
+    write_options: BlockWriteOptions,
+    metrics: FuseTableMetrics,
 }
 
 impl BlockStreamWriter {
@@ -55,6 +61,8 @@ impl BlockStreamWriter {
         row_per_block: usize,
         block_per_segment: usize,
         meta_locations: TableMetaLocationGenerator,
+        write_options: BlockWriteOptions,
+        metrics: FuseTableMetrics,
     ) -> SegmentInfoStream {
         // filter out empty blocks
         let block_stream =
@@ -75,6 +83,8 @@ impl BlockStreamWriter {
             data_accessor,
             data_schema,
             meta_locations,
+            write_options,
+            metrics,
         );
         let segments = Self::transform(Box::pin(block_stream), block_writer);
 
@@ -86,6 +96,8 @@ impl BlockStreamWriter {
         data_accessor: Operator,
         data_schema: Arc<DataSchema>,
         meta_locations: TableMetaLocationGenerator,
+        write_options: BlockWriteOptions,
+        metrics: FuseTableMetrics,
     ) -> Self {
         Self {
             num_block_threshold,
@@ -94,6 +106,8 @@ impl BlockStreamWriter {
             number_of_blocks_accumulated: 0,
             statistics_accumulator: None,
             meta_locations,
+            write_options,
+            metrics,
         }
     }
 
@@ -126,13 +140,22 @@ impl BlockStreamWriter {
     }
 
     async fn write_block(&mut self, block: DataBlock) -> Result<Option<SegmentInfo>> {
+        // Struct columns are written and accumulated as their leaf columns.
+        let block = ColumnLeaves::new_from_schema(block.schema()).flatten_block(&block)?;
         let mut acc = self.statistics_accumulator.take().unwrap_or_default();
         let partial_acc = acc.begin(&block)?;
         let schema = block.schema().to_arrow();
         let location = self.meta_locations.gen_block_location();
-        let (file_size, file_meta_data) =
-            block_writer::write_block(&schema, block, self.data_accessor.clone(), &location)
-                .await?;
+        let start = Instant::now();
+        let (file_size, file_meta_data) = block_writer::write_block(
+            &schema,
+            block,
+            self.data_accessor.clone(),
+            &location,
+            &self.write_options,
+        )
+        .await?;
+        self.metrics.inc_block_written(file_size, start.elapsed());
         let col_metas = Self::column_metas(&file_meta_data)?;
         acc = partial_acc.end(file_size, location, col_metas);
         self.number_of_blocks_accumulated += 1;
@@ -13,8 +13,15 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::ArrayRef;
 use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntegerType;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::arrow::io::parquet::write::*;
@@ -25,40 +32,163 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use opendal::Operator;
 
+use crate::storages::fuse::DEFAULT_ROW_PER_PAGE;
+use crate::storages::fuse::FUSE_OPT_KEY_DICTIONARY_ENCODING;
+use crate::storages::fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::storages::fuse::FUSE_OPT_KEY_STATISTICS_LEVEL;
+use crate::storages::fuse::FUSE_OPT_KEY_WRITER_VERSION;
+
+/// The Parquet writer settings of the blocks, taken from the table options.
+///
+/// Each block is written as a single row group, whose size is set by the
+/// table option `row_per_block`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockWriteOptions {
+    /// Max number of rows per data page, 0 means one data page per column.
+    pub row_per_page: usize,
+    /// Dictionary encode the string columns.
+    pub dictionary_encoding: bool,
+    /// Write the min/max/null count statistics of the pages and column chunks.
+    pub write_statistics: bool,
+    pub version: Version,
+}
+
+impl Default for BlockWriteOptions {
+    fn default() -> Self {
+        Self {
+            row_per_page: DEFAULT_ROW_PER_PAGE,
+            dictionary_encoding: false,
+            write_statistics: false,
+            version: Version::V2,
+        }
+    }
+}
+
+impl BlockWriteOptions {
+    pub fn try_from_table_options(options: &HashMap<String, String>) -> Result<Self> {
+        let mut write_options = Self::default();
+        for (key, value) in options {
+            let value = value.trim().to_lowercase();
+            match key.as_str() {
+                FUSE_OPT_KEY_ROW_PER_PAGE => {
+                    write_options.row_per_page = value
+                        .parse::<usize>()
+                        .map_err(|_| bad_option(key, &value, "a non-negative integer"))?;
+                }
+                FUSE_OPT_KEY_DICTIONARY_ENCODING => {
+                    write_options.dictionary_encoding = match value.as_str() {
+                        "true" | "1" => true,
+                        "false" | "0" => false,
+                        _ => return Err(bad_option(key, &value, "true or false")),
+                    };
+                }
+                FUSE_OPT_KEY_STATISTICS_LEVEL => {
+                    write_options.write_statistics = match value.as_str() {
+                        "none" => false,
+                        "page" => true,
+                        _ => return Err(bad_option(key, &value, "none or page")),
+                    };
+                }
+                FUSE_OPT_KEY_WRITER_VERSION => {
+                    write_options.version = match value.as_str() {
+                        "1" => Version::V1,
+                        "2" => Version::V2,
+                        _ => return Err(bad_option(key, &value, "1 or 2")),
+                    };
+                }
+                _ => {}
+            }
+        }
+        Ok(write_options)
+    }
+}
+
+fn bad_option(key: &str, value: &str, expected: &str) -> ErrorCode {
+    ErrorCode::BadOption(format!(
+        "invalid value of table option {}: {}, expects {}",
+        key, value, expected
+    ))
+}
+
 pub async fn write_block(
     arrow_schema: &ArrowSchema,
     block: DataBlock,
     data_accessor: Operator,
     location: &str,
+    write_options: &BlockWriteOptions,
 ) -> Result<(u64, FileMetaData)> {
     let options = WriteOptions {
-        write_statistics: false,
+        write_statistics: write_options.write_statistics,
         compression: Compression::Lz4Raw,
-        version: Version::V2,
+        version: write_options.version,
     };
     let batch = Chunk::try_from(block)?;
+    let (arrow_schema, batch) = match write_options.dictionary_encoding {
+        true => dictionary_encoded(arrow_schema, batch)?,
+        false => (arrow_schema.clone(), batch),
+    };
     let encodings: Vec<_> = arrow_schema
         .fields
         .iter()
         .map(|f| col_encoding(&f.data_type))
         .collect();
 
-    let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
-
     // we need a configuration of block size threshold here
     let mut buf = Vec::with_capacity(100 * 1024 * 1024);
 
-    let result =
-        common_arrow::write_parquet_file(&mut buf, row_groups, arrow_schema.clone(), options)
-            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+    let result = common_arrow::write_parquet_chunk(
+        &mut buf,
+        batch,
+        arrow_schema,
+        options,
+        encodings,
+        write_options.row_per_page,
+    )
+    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
     data_accessor.object(location).write(buf).await?;
 
     Ok(result)
 }
 
-fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
+// Cast the string columns to dictionary arrays, which are written with a dictionary page
+// and decoded back to the string columns by the reader.
+fn dictionary_encoded(
+    arrow_schema: &ArrowSchema,
+    batch: Chunk<ArrayRef>,
+) -> Result<(ArrowSchema, Chunk<ArrayRef>)> {
+    let mut fields = Vec::with_capacity(arrow_schema.fields.len());
+    let mut arrays = Vec::with_capacity(arrow_schema.fields.len());
+    for (field, array) in arrow_schema.fields.iter().zip(batch.into_arrays()) {
+        match field.data_type {
+            ArrowDataType::Binary
+            | ArrowDataType::LargeBinary
+            | ArrowDataType::Utf8
+            | ArrowDataType::LargeUtf8 => {
+                let data_type = ArrowDataType::Dictionary(
+                    IntegerType::Int32,
+                    Box::new(field.data_type.clone()),
+                    false,
+                );
+                let array = cast::cast(array.as_ref(), &data_type, cast::CastOptions::default())?;
+                fields.push(ArrowField {
+                    data_type,
+                    ..field.clone()
+                });
+                arrays.push(Arc::from(array));
+            }
+            _ => {
+                fields.push(field.clone());
+                arrays.push(array);
+            }
+        }
+    }
+
+    let schema = ArrowSchema::from(fields).with_metadata(arrow_schema.metadata.clone());
+    Ok((schema, Chunk::try_new(arrays)?))
+}
+
+fn col_encoding(data_type: &ArrowDataType) -> Encoding {
     // Although encoding does work, parquet2 has not implemented decoding of DeltaLengthByteArray yet, we fallback to Plain
     // From parquet2: Decoding "DeltaLengthByteArray"-encoded required V2 pages is not yet implemented for Binary.
     //
@@ -69,5 +199,8 @@ fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
     //    | ArrowDataType::LargeUtf8 => Encoding::DeltaLengthByteArray,
     //    _ => Encoding::Plain,
     //}
-    Encoding::Plain
+    match data_type {
+        ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
+        _ => Encoding::Plain,
+    }
 }
@@ -19,3 +19,4 @@ mod block_writer;
 pub use block_stream_writer::BlockCompactor;
 pub use block_stream_writer::BlockStreamWriter;
 pub use block_stream_writer::SegmentInfoStream;
+pub use block_writer::BlockWriteOptions;
@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_meta_types::TableInfo;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
+use metrics::counter;
+use metrics::histogram;
+
+use crate::sessions::QueryContext;
+
+// The metrics of the fuse tables, labeled by the tenant, the cluster and the table.
+pub static METRIC_FUSE_BLOCKS_READ: &str = "fuse.blocks_read";
+pub static METRIC_FUSE_BLOCK_BYTES_READ: &str = "fuse.block_bytes_read";
+pub static METRIC_FUSE_BLOCKS_WRITTEN: &str = "fuse.blocks_written";
+pub static METRIC_FUSE_BLOCK_BYTES_WRITTEN: &str = "fuse.block_bytes_written";
+pub static METRIC_FUSE_PRUNING_BLOCKS_SCANNED: &str = "fuse.pruning_blocks_scanned";
+pub static METRIC_FUSE_PRUNING_BLOCKS_PRUNED: &str = "fuse.pruning_blocks_pruned";
+pub static METRIC_FUSE_PRUNING_BYTES_SCANNED: &str = "fuse.pruning_bytes_scanned";
+pub static METRIC_FUSE_PRUNING_BYTES_PRUNED: &str = "fuse.pruning_bytes_pruned";
+pub static METRIC_FUSE_COMMIT_RETRIES: &str = "fuse.commit_retries";
+pub static METRIC_FUSE_COMMIT_ABORTS: &str = "fuse.commit_aborts";
+pub static METRIC_FUSE_DAL_READ_DURATION: &str = "fuse.dal_read_duration";
+pub static METRIC_FUSE_DAL_WRITE_DURATION: &str = "fuse.dal_write_duration";
+
+const LABEL_KEY_TABLE: &str = "table";
+const LABEL_KEY_TABLE_ID: &str = "table_id";
+
+/// Records the metrics of the reads and the writes of a fuse table.
+///
+/// The default one has no labels, it is used where the table is unknown, e.g. in the tests.
+#[derive(Clone, Debug, Default)]
+pub struct FuseTableMetrics {
+    labels: Vec<(&'static str, String)>,
+}
+
+impl FuseTableMetrics {
+    pub fn create(ctx: &QueryContext, table_info: &TableInfo) -> Self {
+        let mgr = ctx.get_storage_cache_manager();
+        FuseTableMetrics {
+            labels: vec![
+                (LABEL_KEY_TENANT, mgr.get_tenant_id().to_string()),
+                (LABEL_KEY_CLUSTER, mgr.get_cluster_id().to_string()),
+                (LABEL_KEY_TABLE, table_info.name.clone()),
+                (LABEL_KEY_TABLE_ID, table_info.ident.table_id.to_string()),
+            ],
+        }
+    }
+
+    pub fn inc_block_read(&self, bytes: u64, duration: Duration) {
+        counter!(METRIC_FUSE_BLOCKS_READ, 1, &self.labels);
+        counter!(METRIC_FUSE_BLOCK_BYTES_READ, bytes, &self.labels);
+        histogram!(METRIC_FUSE_DAL_READ_DURATION, duration, &self.labels);
+    }
+
+    pub fn inc_block_written(&self, bytes: u64, duration: Duration) {
+        counter!(METRIC_FUSE_BLOCKS_WRITTEN, 1, &self.labels);
+        counter!(METRIC_FUSE_BLOCK_BYTES_WRITTEN, bytes, &self.labels);
+        histogram!(METRIC_FUSE_DAL_WRITE_DURATION, duration, &self.labels);
+    }
+
+    /// The blocks and the bytes left to scan after the pruning, of the given totals.
+    pub fn inc_pruning(
+        &self,
+        blocks_scanned: u64,
+        blocks_total: u64,
+        bytes_scanned: u64,
+        bytes_total: u64,
+    ) {
+        let blocks_pruned = blocks_total.saturating_sub(blocks_scanned);
+        let bytes_pruned = bytes_total.saturating_sub(bytes_scanned);
+        counter!(
+            METRIC_FUSE_PRUNING_BLOCKS_SCANNED,
+            blocks_scanned,
+            &self.labels
+        );
+        counter!(
+            METRIC_FUSE_PRUNING_BLOCKS_PRUNED,
+            blocks_pruned,
+            &self.labels
+        );
+        counter!(
+            METRIC_FUSE_PRUNING_BYTES_SCANNED,
+            bytes_scanned,
+            &self.labels
+        );
+        counter!(METRIC_FUSE_PRUNING_BYTES_PRUNED, bytes_pruned, &self.labels);
+    }
+
+    pub fn inc_commit_retries(&self) {
+        counter!(METRIC_FUSE_COMMIT_RETRIES, 1, &self.labels);
+    }
+
+    pub fn inc_commit_aborts(&self) {
+        counter!(METRIC_FUSE_COMMIT_ABORTS, 1, &self.labels);
+    }
+}
@@ -19,6 +19,7 @@ mod fuse_part;
 mod fuse_table;
 pub mod io;
 pub mod meta;
+mod metrics;
 pub mod operations;
 pub mod pruning;
 pub mod statistics;
@@ -27,5 +28,9 @@ mod table_functions;
 pub use constants::*;
 pub use fuse_history::FuseHistory;
 pub use fuse_table::FuseTable;
+pub use table_functions::parse_func_history_args;
+pub use table_functions::string_literal;
 pub use table_functions::FuseHistoryTable;
 pub use table_functions::FUSE_FUNC_HIST;
+
+pub use self::metrics::FuseTableMetrics;
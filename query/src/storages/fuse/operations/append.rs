@@ -24,8 +24,10 @@ use futures::StreamExt;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::io::BlockWriteOptions;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FuseTableMetrics;
 use crate::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
 use crate::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
@@ -46,7 +48,10 @@ impl FuseTable {
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
 
+        let write_options = BlockWriteOptions::try_from_table_options(self.table_info.options())?;
+
         let da = ctx.get_storage_operator()?;
+        let metrics = FuseTableMetrics::create(ctx.as_ref(), &self.table_info);
 
         let mut segment_stream = BlockStreamWriter::write_block_stream(
             da.clone(),
@@ -55,6 +60,8 @@ impl FuseTable {
             rows_per_block,
             block_per_seg,
             self.meta_location_generator().clone(),
+            write_options,
+            metrics,
         )
         .await;
 
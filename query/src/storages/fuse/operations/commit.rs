@@ -45,6 +45,7 @@ use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::operations::TableOperationLog;
 use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FuseTableMetrics;
 use crate::storages::Table;
 
 impl FuseTable {
@@ -60,6 +61,7 @@ impl FuseTable {
         let mut latest: Arc<dyn Table>;
 
         let mut retry_times = 0;
+        let metrics = FuseTableMetrics::create(ctx.as_ref(), &self.table_info);
 
         let settings = ctx.get_settings();
 
@@ -115,9 +117,11 @@ impl FuseTable {
                             latest = catalog.get_table_by_info(&table_info)?;
                             tbl = FuseTable::try_from_table(latest.as_ref())?;
                             retry_times += 1;
+                            metrics.inc_commit_retries();
                             continue;
                         }
                         None => {
+                            metrics.inc_commit_aborts();
                             break Err(ErrorCode::OCCRetryFailure(format!(
                                 "can not fulfill the tx after retries({} times, {} ms), aborted. table name {}, identity {}",
                                 retry_times,
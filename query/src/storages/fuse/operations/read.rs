@@ -37,6 +37,7 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::operations::read::State::Generated;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FuseTableMetrics;
 
 impl FuseTable {
     #[inline]
@@ -85,7 +86,8 @@ impl FuseTable {
 
         let operator = ctx.get_storage_operator()?;
         let table_schema = self.table_info.schema();
-        BlockReader::create(operator, table_schema, projection)
+        let metrics = FuseTableMetrics::create(ctx, &self.table_info);
+        BlockReader::create(operator, table_schema, projection, metrics)
     }
 
     #[inline]
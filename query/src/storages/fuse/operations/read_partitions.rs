@@ -29,6 +29,8 @@ use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::pruning::BlockPruner;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FuseTableMetrics;
+use crate::storages::index::ColumnLeaves;
 
 impl FuseTable {
     #[inline]
@@ -45,12 +47,26 @@ impl FuseTable {
                 }
                 let schema = self.table_info.schema();
                 let block_metas = BlockPruner::new(snapshot.clone())
-                    .apply(schema, &push_downs, ctx.as_ref())
+                    .apply(schema.clone(), &push_downs, ctx.as_ref())
                     .await?;
 
                 let partitions_scanned = block_metas.len();
                 let partitions_total = snapshot.summary.block_count as usize;
-
+                let bytes_scanned = block_metas.iter().map(|meta| meta.file_size).sum();
+                let bytes_total = snapshot.summary.compressed_byte_size;
+                FuseTableMetrics::create(ctx.as_ref(), &self.table_info).inc_pruning(
+                    partitions_scanned as u64,
+                    partitions_total as u64,
+                    bytes_scanned,
+                    bytes_total,
+                );
+
+                // Struct columns are stored as their leaf columns, so are the partitions.
+                let column_leaves = ColumnLeaves::new_from_schema(&schema);
+                let push_downs = push_downs.map(|mut extras| {
+                    extras.projection = extras.projection.map(|p| column_leaves.leaf_ids(&p));
+                    extras
+                });
                 let (mut statistics, parts) = Self::to_partitions(&block_metas, push_downs);
 
                 // Update planner statistics.
@@ -76,12 +76,7 @@ impl StatisticsAccumulator {
             let mut min = DataValue::Null;
             let mut max = DataValue::Null;
 
-            // TODO(b41sh): support max/min aggregate functions for variant
-            let nonull_data_type = remove_nullable(field.data_type());
-            if nonull_data_type.data_type_id() != TypeID::Variant
-                && nonull_data_type.data_type_id() != TypeID::VariantArray
-                && nonull_data_type.data_type_id() != TypeID::VariantObject
-            {
+            if supports_min_max(field.data_type()) {
                 let mins = eval_aggr("min", vec![], &[column_field.clone()], rows)?;
                 let maxs = eval_aggr("max", vec![], &[column_field], rows)?;
 
@@ -120,6 +115,23 @@ pub struct PartiallyAccumulated {
     block_column_statistics: HashMap<ColumnId, ColumnStatistics>,
 }
 
+/// Min/max are collected for the scalar columns only.
+pub fn supports_min_max(data_type: &DataTypePtr) -> bool {
+    // TODO(b41sh): support max/min aggregate functions for variant
+    let data_type = remove_nullable(data_type);
+    !matches!(
+        data_type.data_type_id(),
+        TypeID::Variant
+            | TypeID::VariantArray
+            | TypeID::VariantObject
+            | TypeID::Array
+            | TypeID::Struct
+            | TypeID::Map
+            | TypeID::Bitmap
+            | TypeID::Geometry
+    )
+}
+
 impl PartiallyAccumulated {
     pub fn end(
         mut self,
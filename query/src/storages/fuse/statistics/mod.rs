@@ -16,6 +16,7 @@ pub mod accumulator;
 pub mod reducers;
 
 pub use accumulator::PartiallyAccumulated;
+pub use accumulator::supports_min_max;
 pub use accumulator::StatisticsAccumulator;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_stats;
@@ -26,6 +26,8 @@ use common_functions::aggregates::eval_aggr;
 
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::statistics::supports_min_max;
+use crate::storages::index::ColumnLeaves;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
@@ -34,6 +36,8 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     schema: &DataSchema,
 ) -> Result<BlockStatistics> {
     let len = stats.len();
+    // The statistics are collected for the leaf columns of struct columns.
+    let schema = ColumnLeaves::new_from_schema(schema).leaf_schema();
 
     // transpose Vec<HashMap<_,(_,_)>> to HashMap<_, (_, Vec<_>)>
     let col_stat_list = stats.iter().fold(HashMap::new(), |acc, item| {
@@ -78,12 +82,7 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
             let mut min = DataValue::Null;
             let mut max = DataValue::Null;
 
-            // TODO(b41sh): support max/min aggregate functions for variant
-            let nonull_data_type = remove_nullable(data_type);
-            if nonull_data_type.data_type_id() != TypeID::Variant
-                && nonull_data_type.data_type_id() != TypeID::VariantArray
-                && nonull_data_type.data_type_id() != TypeID::VariantObject
-            {
+            if supports_min_max(data_type) {
                 let field = schema.field((*id) as usize);
                 // TODO
                 // for some data types, we shall balance the accuracy and the length
@@ -39,8 +39,8 @@ use crate::pipelines::new::processors::AsyncSourcer;
 use crate::pipelines::new::NewPipe;
 use crate::pipelines::new::NewPipeline;
 use crate::sessions::QueryContext;
-use crate::storages::fuse::table_functions::table_arg_util::parse_func_history_args;
-use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::table_functions::parse_func_history_args;
+use crate::storages::fuse::table_functions::string_literal;
 use crate::storages::fuse::FuseHistory;
 use crate::storages::fuse::FuseTable;
 use crate::storages::Table;
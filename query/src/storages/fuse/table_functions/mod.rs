@@ -18,3 +18,5 @@ mod table_arg_util;
 
 pub use fuse_history_table::FuseHistoryTable;
 pub use fuse_history_table::FUSE_FUNC_HIST;
+pub use table_arg_util::parse_func_history_args;
+pub use table_arg_util::string_literal;
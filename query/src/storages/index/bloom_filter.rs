@@ -443,6 +443,8 @@ impl BloomFilter {
                 | TypeID::DateTime32
                 | TypeID::DateTime64
                 | TypeID::Interval
+                | TypeID::Decimal
+                | TypeID::Decimal256
                 | TypeID::String
         )
     }
@@ -0,0 +1,229 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Struct columns are stored as one column for each of their leaf fields, so the
+/// readers and writers can handle them like any other column, and the statistics
+/// are collected for each leaf field.
+///
+/// Map columns are stored as two array columns, `m.keys` and `m.values`.
+///
+/// The leaf columns are identified by their position in the flattened schema,
+/// for schemas without struct and map columns the leaf id is the same as the field index.
+#[derive(Debug, Clone)]
+pub struct ColumnLeaf {
+    pub field: DataField,
+    /// The ids of all the leaf columns of the field.
+    pub leaf_ids: Vec<usize>,
+    /// The fields of a struct column, or the keys and the values of a map column.
+    pub children: Option<Vec<ColumnLeaf>>,
+}
+
+impl ColumnLeaf {
+    fn new(field: DataField, next_leaf_id: &mut usize) -> Self {
+        match Self::child_fields(field.data_type()) {
+            Some(child_fields) => {
+                let children = child_fields
+                    .into_iter()
+                    .map(|(name, ty)| {
+                        let name = format!("{}.{}", field.name(), name);
+                        ColumnLeaf::new(DataField::new(&name, ty), next_leaf_id)
+                    })
+                    .collect::<Vec<_>>();
+                let leaf_ids = children
+                    .iter()
+                    .flat_map(|c| c.leaf_ids.iter().cloned())
+                    .collect();
+                Self {
+                    field,
+                    leaf_ids,
+                    children: Some(children),
+                }
+            }
+            None => {
+                let leaf_id = *next_leaf_id;
+                *next_leaf_id += 1;
+                Self {
+                    field,
+                    leaf_ids: vec![leaf_id],
+                    children: None,
+                }
+            }
+        }
+    }
+
+    /// The fields of a struct, or the keys and the values of a map.
+    fn child_fields(data_type: &DataTypePtr) -> Option<Vec<(String, DataTypePtr)>> {
+        if let Some(struct_type) = data_type.as_any().downcast_ref::<StructType>() {
+            let names = struct_type.names().iter().cloned();
+            return Some(names.zip(struct_type.types().iter().cloned()).collect());
+        }
+        if let Some(map_type) = data_type.as_any().downcast_ref::<MapType>() {
+            return Some(vec![
+                ("keys".to_string(), ArrayType::arc(map_type.key_type().clone())),
+                ("values".to_string(), ArrayType::arc(map_type.value_type().clone())),
+            ]);
+        }
+        None
+    }
+
+    fn leaf_fields(&self, fields: &mut Vec<DataField>) {
+        match &self.children {
+            Some(children) => children.iter().for_each(|c| c.leaf_fields(fields)),
+            None => fields.push(self.field.clone()),
+        }
+    }
+
+    fn flatten_column(
+        &self,
+        column: &ColumnRef,
+        leaf_columns: &mut Vec<ColumnRef>,
+    ) -> Result<()> {
+        match &self.children {
+            Some(children) => {
+                let column = column.convert_full_column();
+                let values = match self.field.data_type().as_any().downcast_ref::<MapType>() {
+                    Some(map_type) => {
+                        let column: &ArrayColumn = Series::check_get(&column)?;
+                        let entries: &StructColumn = Series::check_get(column.values())?;
+                        let inner_types = [map_type.key_type(), map_type.value_type()];
+                        inner_types
+                            .iter()
+                            .zip(entries.values().iter())
+                            .map(|(ty, values)| {
+                                Arc::new(ArrayColumn::from_data(
+                                    ArrayType::arc((*ty).clone()),
+                                    column.offsets().to_vec().into(),
+                                    values.clone(),
+                                )) as ColumnRef
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    None => {
+                        let column: &StructColumn = Series::check_get(&column)?;
+                        column.values().to_vec()
+                    }
+                };
+                for (child, value) in children.iter().zip(values.iter()) {
+                    child.flatten_column(value, leaf_columns)?;
+                }
+                Ok(())
+            }
+            None => {
+                leaf_columns.push(column.clone());
+                Ok(())
+            }
+        }
+    }
+
+    fn rebuild_column(
+        &self,
+        leaf_columns: &mut impl Iterator<Item = ColumnRef>,
+    ) -> Result<ColumnRef> {
+        match &self.children {
+            Some(children) => {
+                let values = children
+                    .iter()
+                    .map(|c| c.rebuild_column(leaf_columns))
+                    .collect::<Result<Vec<_>>>()?;
+                let data_type = self.field.data_type().clone();
+                match data_type.as_any().downcast_ref::<MapType>() {
+                    Some(map_type) => {
+                        let keys: &ArrayColumn = Series::check_get(&values[0])?;
+                        let values: &ArrayColumn = Series::check_get(&values[1])?;
+                        let entries = StructColumn::from_data(
+                            vec![keys.values().clone(), values.values().clone()],
+                            map_type.entry_type(),
+                        );
+                        Ok(Arc::new(ArrayColumn::from_data(
+                            data_type.clone(),
+                            keys.offsets().to_vec().into(),
+                            Arc::new(entries),
+                        )))
+                    }
+                    None => Ok(Arc::new(StructColumn::from_data(values, data_type))),
+                }
+            }
+            None => leaf_columns.next().ok_or_else(|| {
+                ErrorCode::LogicalError(format!(
+                    "Missing leaf column of the field {}",
+                    self.field.name()
+                ))
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnLeaves {
+    pub column_leaves: Vec<ColumnLeaf>,
+}
+
+impl ColumnLeaves {
+    pub fn new_from_schema(schema: &DataSchema) -> Self {
+        let mut next_leaf_id = 0;
+        let column_leaves = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnLeaf::new(f.clone(), &mut next_leaf_id))
+            .collect();
+        Self { column_leaves }
+    }
+
+    /// The schema of the leaf columns, ordered by the leaf ids.
+    pub fn leaf_schema(&self) -> DataSchema {
+        let mut fields = Vec::new();
+        for leaf in &self.column_leaves {
+            leaf.leaf_fields(&mut fields);
+        }
+        DataSchema::new(fields)
+    }
+
+    /// The leaf ids of the fields in the projection.
+    pub fn leaf_ids(&self, projection: &[usize]) -> Vec<usize> {
+        projection
+            .iter()
+            .flat_map(|i| self.column_leaves[*i].leaf_ids.iter().cloned())
+            .collect()
+    }
+
+    /// Split the struct columns of the block into leaf columns.
+    pub fn flatten_block(&self, block: &DataBlock) -> Result<DataBlock> {
+        let mut leaf_columns = Vec::with_capacity(block.num_columns());
+        for (leaf, column) in self.column_leaves.iter().zip(block.columns()) {
+            leaf.flatten_column(column, &mut leaf_columns)?;
+        }
+        Ok(DataBlock::create(Arc::new(self.leaf_schema()), leaf_columns))
+    }
+
+    /// Build the columns of the projection from the leaf columns, which
+    /// are in the order of `leaf_ids(projection)`.
+    pub fn rebuild_columns(
+        &self,
+        projection: &[usize],
+        leaf_columns: Vec<ColumnRef>,
+    ) -> Result<Vec<ColumnRef>> {
+        let mut leaf_columns = leaf_columns.into_iter();
+        projection
+            .iter()
+            .map(|i| self.column_leaves[*i].rebuild_column(&mut leaf_columns))
+            .collect()
+    }
+}
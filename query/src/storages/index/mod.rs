@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod column_leaves;
 mod index_min_max;
 mod index_sparse;
 pub mod range_filter;
@@ -20,6 +21,8 @@ pub mod range_filter;
 pub use bloom_filter::BloomFilter;
 pub use bloom_filter::BloomFilterExprEvalResult;
 pub use bloom_filter::BloomFilterIndexer;
+pub use column_leaves::ColumnLeaf;
+pub use column_leaves::ColumnLeaves;
 pub use index_min_max::MinMaxIndex;
 pub use index_sparse::SparseIndex;
 pub use index_sparse::SparseIndexValue;
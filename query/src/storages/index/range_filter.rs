@@ -31,6 +31,7 @@ use common_planners::Expressions;
 use common_planners::RequireColumnsVisitor;
 
 use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::index::ColumnLeaves;
 
 pub type BlockStatistics = HashMap<u32, ColumnStatistics>;
 
@@ -349,6 +350,15 @@ impl<'a> VerifiableExprBuilder<'a> {
             ));
         }
 
+        // The min/max of strings are in the binary order, which can't prune by a collation.
+        for arg in &args {
+            if !collation_of(arg.to_data_field(schema)?.data_type()).is_binary() {
+                return Err(ErrorCode::UnknownException(
+                    "Strings with a collation are not currently supported",
+                ));
+            }
+        }
+
         let mut fields = Vec::with_capacity(cols.len());
 
         let left_cols = get_column_fields(schema, cols[0].clone())?;
@@ -631,12 +641,22 @@ fn check_maybe_monotonic(expr: &Expression) -> Result<bool> {
 }
 
 fn get_column_fields(schema: &DataSchemaRef, cols: HashSet<String>) -> Result<ColumnFields> {
+    let column_leaves = ColumnLeaves::new_from_schema(schema);
     let mut column_fields = HashMap::with_capacity(cols.len());
     for col in &cols {
         let (index, field) = schema
             .column_with_name(col.as_str())
             .ok_or_else(|| ErrorCode::UnknownException("Unable to find the column name"))?;
-        column_fields.insert(index as u32, field.clone());
+
+        // The statistics are collected by the leaf columns, struct and map columns have no
+        // statistics.
+        let column_leaf = &column_leaves.column_leaves[index];
+        if column_leaf.children.is_some() {
+            return Err(ErrorCode::UnknownException(
+                "Struct and map columns are not supported in range filter",
+            ));
+        }
+        column_fields.insert(column_leaf.leaf_ids[0] as u32, field.clone());
     }
     Ok(column_fields)
 }
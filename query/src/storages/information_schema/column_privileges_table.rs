@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::storages::view::view_table::QUERY;
+use crate::storages::view::ViewTable;
+use crate::storages::Table;
+
+pub struct ColumnPrivilegesTable {}
+
+impl ColumnPrivilegesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let query = "SELECT
+            grantee AS grantee,
+            'def' AS table_catalog,
+            table_schema AS table_schema,
+            table_name AS table_name,
+            column_name AS column_name,
+            privilege_type AS privilege_type,
+            is_grantable AS is_grantable,
+            grantee AS GRANTEE,
+            'def' AS TABLE_CATALOG,
+            table_schema AS TABLE_SCHEMA,
+            table_name AS TABLE_NAME,
+            column_name AS COLUMN_NAME,
+            privilege_type AS PRIVILEGE_TYPE,
+            is_grantable AS IS_GRANTABLE
+        FROM system.column_privileges;";
+
+        let mut options = HashMap::new();
+        options.insert(QUERY.to_string(), query.to_string());
+        let table_info = TableInfo {
+            desc: "'information_schema'.'COLUMN_PRIVILEGES'".to_string(),
+            name: "COLUMN_PRIVILEGES".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+        };
+
+        ViewTable::create(table_info)
+    }
+}
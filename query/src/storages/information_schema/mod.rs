@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_privileges_table;
 mod columns_table;
 mod keywords_table;
 mod schemata_table;
 mod tables_table;
 mod views_table;
 
+pub use column_privileges_table::ColumnPrivilegesTable;
 pub use columns_table::ColumnsTable;
 pub use keywords_table::KeywordsTable;
 pub use schemata_table::SchemataTable;
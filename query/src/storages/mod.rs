@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod cache;
+pub mod external;
 pub mod fuse;
 pub mod github;
 pub mod index;
@@ -28,8 +29,11 @@ mod storage_factory;
 mod storage_table;
 mod storage_table_read_plan;
 
+pub use s3::S3Presigner;
 pub use s3::S3StageTable;
+pub use s3::StageSink;
 pub use s3::StageSource;
+pub use s3::UnloadedFile;
 pub use storage_context::StorageContext;
 pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageDescription;
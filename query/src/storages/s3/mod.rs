@@ -12,8 +12,13 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod s3_presign;
+mod s3_stage_sink;
 mod s3_stage_source;
 mod s3_stage_table;
 
+pub use s3_presign::S3Presigner;
+pub use s3_stage_sink::StageSink;
+pub use s3_stage_sink::UnloadedFile;
 pub use s3_stage_source::StageSource;
 pub use s3_stage_table::S3StageTable;
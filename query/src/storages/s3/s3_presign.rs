@@ -0,0 +1,167 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use sha2::Digest;
+use sha2::Sha256;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+// The block size of SHA-256 for the HMAC.
+const BLOCK_SIZE: usize = 64;
+
+/// Presigns the GET requests of S3 objects by the AWS signature version 4 in the query string,
+/// the URL can be fetched without the credentials until it expires.
+pub struct S3Presigner {
+    endpoint_url: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+}
+
+impl S3Presigner {
+    pub fn create(
+        endpoint_url: &str,
+        region: &str,
+        access_key_id: &str,
+        secret_access_key: &str,
+    ) -> S3Presigner {
+        let region = match region {
+            "" => "us-east-1",
+            v => v,
+        };
+        S3Presigner {
+            endpoint_url: endpoint_url.trim_end_matches('/').to_string(),
+            region: region.to_string(),
+            access_key_id: access_key_id.to_string(),
+            secret_access_key: secret_access_key.to_string(),
+        }
+    }
+
+    // The URL of the object in the bucket, in the path style: <endpoint>/<bucket>/<key>.
+    pub fn presign_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        expire_secs: u64,
+        now: DateTime<Utc>,
+    ) -> Result<String> {
+        let path = format!("/{}/{}", bucket, key.trim_start_matches('/'));
+        self.presign(&path, expire_secs, now)
+    }
+
+    // The URL of the path on the endpoint, the path starts with '/'.
+    pub fn presign(&self, path: &str, expire_secs: u64, now: DateTime<Utc>) -> Result<String> {
+        let host = self.host()?;
+        let datetime = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+
+        // The query parameters are sorted by name.
+        let query = [
+            ("X-Amz-Algorithm", ALGORITHM.to_string()),
+            (
+                "X-Amz-Credential",
+                format!("{}/{}", self.access_key_id, scope),
+            ),
+            ("X-Amz-Date", datetime.clone()),
+            ("X-Amz-Expires", expire_secs.to_string()),
+            ("X-Amz-SignedHeaders", "host".to_string()),
+        ]
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, uri_encode(v, true)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+        let canonical_uri = uri_encode(path, false);
+        let canonical_request = format!(
+            "GET\n{}\n{}\nhost:{}\n\nhost\nUNSIGNED-PAYLOAD",
+            canonical_uri, query, host
+        );
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            ALGORITHM,
+            datetime,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = format!("AWS4{}", self.secret_access_key);
+        let key = hmac_sha256(key.as_bytes(), date.as_bytes());
+        let key = hmac_sha256(&key, self.region.as_bytes());
+        let key = hmac_sha256(&key, b"s3");
+        let key = hmac_sha256(&key, b"aws4_request");
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+        Ok(format!(
+            "{}{}?{}&X-Amz-Signature={}",
+            self.endpoint_url, canonical_uri, query, signature
+        ))
+    }
+
+    // The host and the port of the endpoint, e.g. 127.0.0.1:9900 of http://127.0.0.1:9900.
+    fn host(&self) -> Result<String> {
+        let url = url::Url::parse(&self.endpoint_url).map_err(|e| {
+            ErrorCode::BadArguments(format!(
+                "Invalid S3 endpoint url '{}', error: {}",
+                self.endpoint_url, e
+            ))
+        })?;
+        match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => Ok(format!("{}:{}", host, port)),
+            (Some(host), None) => Ok(host.to_string()),
+            (None, _) => Err(ErrorCode::BadArguments(format!(
+                "Invalid S3 endpoint url '{}', no host",
+                self.endpoint_url
+            ))),
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        let digest = Sha256::digest(key);
+        block[..digest.len()].copy_from_slice(&digest);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.iter().map(|b| b ^ 0x36).collect::<Vec<_>>());
+    inner.update(data);
+    let inner = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block.iter().map(|b| b ^ 0x5c).collect::<Vec<_>>());
+    outer.update(inner);
+    outer.finalize().to_vec()
+}
+
+// Encodes the bytes except the unreserved characters, '/' is kept unless encode_slash.
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
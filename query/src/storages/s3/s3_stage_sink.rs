@@ -0,0 +1,237 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::parquet::write::*;
+use common_arrow::parquet::encoding::Encoding;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::get_abs_path;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::UserStageInfo;
+use opendal::Operator;
+
+use crate::sessions::QueryContext;
+use crate::storages::StageSource;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UnloadedFile {
+    pub path: String,
+    pub rows: usize,
+    pub size: usize,
+}
+
+/// Writes the blocks into the files of a stage, it's the sink of `COPY INTO <location>`.
+/// The blocks are buffered until they reach `max_file_size`, then written as one file
+/// named `data_<n>.<format>`, and a `manifest.json` lists the files at the end.
+/// With `single`, all the blocks are written into the file of the path, without a manifest.
+pub struct StageSink {
+    op: Operator,
+    stage: UserStageInfo,
+    path: String,
+    max_file_size: usize,
+    overwrite: bool,
+    single: bool,
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    files: Vec<UnloadedFile>,
+}
+
+impl StageSink {
+    pub async fn try_create(
+        ctx: &Arc<QueryContext>,
+        stage: UserStageInfo,
+        path: String,
+        max_file_size: usize,
+        overwrite: bool,
+        single: bool,
+    ) -> Result<StageSink> {
+        let op = StageSource::get_op(ctx, &stage).await?;
+        Ok(StageSink {
+            op,
+            stage,
+            path,
+            max_file_size,
+            overwrite,
+            single,
+            blocks: vec![],
+            buffered_bytes: 0,
+            files: vec![],
+        })
+    }
+
+    pub async fn append(&mut self, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+
+        self.buffered_bytes += block.memory_size();
+        self.blocks.push(block);
+        if !self.single && self.max_file_size > 0 && self.buffered_bytes >= self.max_file_size {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    // Writes the rest of the blocks and the manifest, returns the written files.
+    pub async fn finish(mut self) -> Result<Vec<UnloadedFile>> {
+        self.flush().await?;
+
+        if !self.single {
+            let manifest = serde_json::to_vec_pretty(&self.files)?;
+            let location = get_abs_path(&self.path, MANIFEST_FILE);
+            self.write_file(&location, manifest).await?;
+        }
+        Ok(self.files)
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+
+        let block = DataBlock::concat_blocks(&self.blocks)?;
+        self.blocks.clear();
+        self.buffered_bytes = 0;
+
+        let format = &self.stage.file_format_options;
+        let (data, extension) = match format.format {
+            StageFileFormatType::Csv => {
+                let field_delimiter = match format.field_delimiter.as_str() {
+                    "" => ",",
+                    v => v,
+                };
+                let record_delimiter = match format.record_delimiter.as_str() {
+                    "" => "\n",
+                    v => v,
+                };
+                let data = serialize_csv(&block, field_delimiter, record_delimiter)?;
+                (data, "csv")
+            }
+            StageFileFormatType::Parquet => (serialize_parquet(block.clone())?, "parquet"),
+            ref other => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "Unsupported file format to unload: {:?}",
+                    other
+                )))
+            }
+        };
+
+        let location = if self.single {
+            self.path.clone()
+        } else {
+            let name = format!("data_{}.{}", self.files.len(), extension);
+            get_abs_path(&self.path, &name)
+        };
+
+        let size = data.len();
+        self.write_file(&location, data).await?;
+        self.files.push(UnloadedFile {
+            path: location,
+            rows: block.num_rows(),
+            size,
+        });
+        Ok(())
+    }
+
+    async fn write_file(&self, location: &str, data: Vec<u8>) -> Result<()> {
+        let object = self.op.object(location);
+        if !self.overwrite {
+            match object.metadata().await {
+                Ok(_) => {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "File {} already exists, use OVERWRITE = TRUE to replace it",
+                        location
+                    )))
+                }
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        object.write(data).await?;
+        Ok(())
+    }
+}
+
+fn serialize_parquet(block: DataBlock) -> Result<Vec<u8>> {
+    let arrow_schema = block.schema().to_arrow();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+    };
+    let encodings = arrow_schema
+        .fields
+        .iter()
+        .map(|_| Encoding::Plain)
+        .collect::<Vec<_>>();
+
+    let batch = Chunk::try_from(block)?;
+    let iter = vec![Ok(batch)];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &arrow_schema, options, encodings)?;
+
+    let mut buf = vec![];
+    common_arrow::write_parquet_file(&mut buf, row_groups, arrow_schema.clone(), options)
+        .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+    Ok(buf)
+}
+
+fn serialize_csv(
+    block: &DataBlock,
+    field_delimiter: &str,
+    record_delimiter: &str,
+) -> Result<Vec<u8>> {
+    let columns = block
+        .columns()
+        .iter()
+        .zip(block.schema().fields())
+        .map(|(column, field)| {
+            field
+                .data_type()
+                .create_serializer()
+                .serialize_column(column)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut buf = String::new();
+    for row in 0..block.num_rows() {
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                buf.push_str(field_delimiter);
+            }
+            let value = column[row].as_str();
+            // Quote the values having the delimiters or quotes, the quotes are doubled.
+            if value.contains(field_delimiter)
+                || value.contains(record_delimiter)
+                || value.contains('"')
+            {
+                buf.push('"');
+                buf.push_str(&value.replace('"', "\"\""));
+                buf.push('"');
+            } else {
+                buf.push_str(value);
+            }
+        }
+        buf.push_str(record_delimiter);
+    }
+    Ok(buf.into_bytes())
+}
@@ -12,25 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::future::Future;
+use std::path::Path;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::Compression;
 use common_io::prelude::S3File;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageStorage;
 use common_meta_types::StageType;
 use common_meta_types::UserStageInfo;
 use common_planners::S3StageTableInfo;
+use common_streams::decompress;
+use common_streams::decompress_reader;
+use common_streams::resolve_compression;
+use common_streams::AvroSourceBuilder;
 use common_streams::CsvSourceBuilder;
+use common_streams::NDJsonSourceBuilder;
+use common_streams::OrcSourceBuilder;
 use common_streams::ParquetSourceBuilder;
+use common_streams::SchemaInference;
 use common_streams::Source;
-use opendal::io_util::SeekableReader;
+use common_streams::INFER_SCHEMA_MAX_RECORDS;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use futures::AsyncSeek;
 use opendal::BytesReader;
 use opendal::Operator;
+use regex::Regex;
 
 use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
@@ -42,7 +58,7 @@ pub struct StageSource {
     ctx: Arc<QueryContext>,
     schema: DataSchemaRef,
     table_info: S3StageTableInfo,
-    initialized: bool,
+    files: VecDeque<String>,
     source: Option<Box<dyn Source>>,
 }
 
@@ -52,23 +68,57 @@ impl StageSource {
         output: Arc<OutputPort>,
         schema: DataSchemaRef,
         table_info: S3StageTableInfo,
+        files: Vec<String>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, StageSource {
             ctx,
             schema,
             table_info,
-            initialized: false,
+            files: VecDeque::from(files),
             source: None,
         })
     }
 
+    // The files to read, the single file of COPY, or the selected files of the stage,
+    // or the stage path itself.
+    pub fn files(table_info: &S3StageTableInfo) -> Vec<String> {
+        match &table_info.file_name {
+            Some(file_name) => vec![file_name.clone()],
+            None if !table_info.files.is_empty() => table_info.files.clone(),
+            None => vec!["".to_string()],
+        }
+    }
+
     // Get csv source stream.
     async fn csv_source(
         ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         stage_info: &UserStageInfo,
+        path: &str,
         reader: BytesReader,
     ) -> Result<Box<dyn Source>> {
+        let mut builder = Self::csv_builder(&ctx, schema, stage_info)?;
+
+        // On error, the malformed records are rejected and skipped up to the ON_ERROR limit.
+        {
+            builder.max_errors(stage_info.copy_options.on_error.max_record_errors());
+            builder.rejected_records(path, ctx.get_rejected_records());
+        }
+
+        // The chunks of the file are parsed by the threads in parallel.
+        {
+            let max_threads = ctx.get_settings().get_max_threads()?;
+            builder.parallelism(max_threads as usize);
+        }
+
+        Ok(Box::new(builder.build_parallel(reader)?))
+    }
+
+    fn csv_builder(
+        ctx: &Arc<QueryContext>,
+        schema: DataSchemaRef,
+        stage_info: &UserStageInfo,
+    ) -> Result<CsvSourceBuilder> {
         let settings = ctx.get_format_settings()?;
         let mut builder = CsvSourceBuilder::create(schema, settings);
         let size_limit = stage_info.copy_options.size_limit;
@@ -88,7 +138,7 @@ impl StageSource {
 
         // Skip header.
         {
-            builder.skip_header(stage_info.file_format_options.skip_header > 0);
+            builder.skip_header(stage_info.file_format_options.skip_header as usize);
         }
 
         // Field delimiter, default ','.
@@ -103,16 +153,139 @@ impl StageSource {
             builder.record_delimiter(record_delimiter);
         }
 
-        Ok(Box::new(builder.build(reader)?))
+        // Quote, default '"'.
+        {
+            let quote = &stage_info.file_format_options.quote;
+            builder.quote(quote);
+        }
+
+        // Escape, default none, the quotes are escaped by doubling them.
+        {
+            let escape = &stage_info.file_format_options.escape;
+            builder.escape(escape);
+        }
+
+        // Null if.
+        {
+            builder.null_if(&stage_info.file_format_options.null_if);
+        }
+
+        // Trim space.
+        {
+            builder.trim(stage_info.file_format_options.trim_space);
+        }
+
+        Ok(builder)
+    }
+
+    // Get ndjson source stream.
+    async fn ndjson_source(
+        ctx: Arc<QueryContext>,
+        schema: DataSchemaRef,
+        stage_info: &UserStageInfo,
+        path: &str,
+        mut reader: BytesReader,
+    ) -> Result<Box<dyn Source>> {
+        let settings = ctx.get_format_settings()?;
+        let mut builder = NDJsonSourceBuilder::create(schema, settings);
+        let size_limit = stage_info.copy_options.size_limit;
+
+        // Size limit.
+        {
+            if size_limit > 0 {
+                builder.size_limit(size_limit);
+            }
+        }
+
+        // Block size.
+        {
+            let max_block_size = ctx.get_settings().get_max_block_size()?;
+            builder.block_size(max_block_size as usize);
+        }
+
+        // On error, the malformed lines are rejected and skipped up to the ON_ERROR limit.
+        {
+            builder.max_errors(stage_info.copy_options.on_error.max_record_errors());
+            builder.rejected_records(path, ctx.get_rejected_records());
+        }
+
+        // The ndjson source reads lines from a buffered reader, so we load the whole file.
+        let mut buffer = vec![];
+        reader.read_to_end(&mut buffer).await?;
+
+        Ok(Box::new(builder.build(std::io::Cursor::new(buffer))?))
+    }
+
+    // Get avro source stream.
+    async fn avro_source(
+        ctx: Arc<QueryContext>,
+        schema: DataSchemaRef,
+        stage_info: &UserStageInfo,
+        mut reader: BytesReader,
+    ) -> Result<Box<dyn Source>> {
+        let mut builder = AvroSourceBuilder::create(schema);
+        let size_limit = stage_info.copy_options.size_limit;
+
+        // Size limit.
+        {
+            if size_limit > 0 {
+                builder.size_limit(size_limit);
+            }
+        }
+
+        // Block size.
+        {
+            let max_block_size = ctx.get_settings().get_max_block_size()?;
+            builder.block_size(max_block_size as usize);
+        }
+
+        // The avro blocks may be compressed as a whole, so we load the whole file.
+        let mut buffer = vec![];
+        reader.read_to_end(&mut buffer).await?;
+
+        Ok(Box::new(builder.build(buffer)?))
+    }
+
+    // Get orc source stream.
+    async fn orc_source(
+        ctx: Arc<QueryContext>,
+        schema: DataSchemaRef,
+        stage_info: &UserStageInfo,
+        mut reader: BytesReader,
+    ) -> Result<Box<dyn Source>> {
+        let mut builder = OrcSourceBuilder::create(schema);
+        let size_limit = stage_info.copy_options.size_limit;
+
+        // Size limit.
+        {
+            if size_limit > 0 {
+                builder.size_limit(size_limit);
+            }
+        }
+
+        // Block size.
+        {
+            let max_block_size = ctx.get_settings().get_max_block_size()?;
+            builder.block_size(max_block_size as usize);
+        }
+
+        // The orc metadata is at the end of the file, so we load the whole file.
+        let mut buffer = vec![];
+        reader.read_to_end(&mut buffer).await?;
+
+        Ok(Box::new(builder.build(buffer)?))
     }
 
     // Get parquet source stream.
-    async fn parquet_source(
+    async fn parquet_source<R>(
         _ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         _stage_info: &UserStageInfo,
-        reader: SeekableReader,
-    ) -> Result<Box<dyn Source>> {
+        reader: R,
+    ) -> Result<Box<dyn Source>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
         let mut builder = ParquetSourceBuilder::create(schema.clone());
 
         // Default is all the columns.
@@ -124,6 +297,28 @@ impl StageSource {
         Ok(Box::new(builder.build(reader)?))
     }
 
+    fn compression(stage: &UserStageInfo) -> Compression {
+        match stage.file_format_options.compression {
+            StageFileCompression::Auto => Compression::Auto,
+            StageFileCompression::Gzip => Compression::Gzip,
+            StageFileCompression::Bz2 => Compression::Bz2,
+            StageFileCompression::Brotli => Compression::Brotli,
+            StageFileCompression::Zstd => Compression::Zstd,
+            StageFileCompression::Deflate => Compression::Deflate,
+            StageFileCompression::RawDeflate => Compression::RawDeflate,
+            StageFileCompression::Lzo => Compression::Lzo,
+            StageFileCompression::Snappy => Compression::Snappy,
+            StageFileCompression::Xz => Compression::Xz,
+            StageFileCompression::None => Compression::None,
+        }
+    }
+
+    // Get the reader of the decompressed file.
+    async fn file_reader(op: &Operator, stage: &UserStageInfo, path: &str) -> Result<BytesReader> {
+        let reader = op.object(path).reader().await?;
+        decompress_reader(&Self::compression(stage), path, reader).await
+    }
+
     pub async fn get_op(ctx: &Arc<QueryContext>, stage: &UserStageInfo) -> Result<Operator> {
         if stage.stage_type == StageType::Internal {
             ctx.get_storage_operator()
@@ -143,41 +338,200 @@ impl StageSource {
         }
     }
 
-    async fn initialize(&mut self) -> Result<()> {
+    // Select the files under the stage path by the FILES list and the PATTERN regex.
+    // Both are evaluated against the stage listing, so nothing is read before the selection is known.
+    pub async fn list_files(
+        ctx: &Arc<QueryContext>,
+        stage: &UserStageInfo,
+        path: &str,
+        files: &[String],
+        pattern: &str,
+    ) -> Result<Vec<String>> {
+        let regex = match pattern.is_empty() {
+            true => None,
+            false => Some(Regex::new(pattern).map_err(|e| {
+                ErrorCode::SyntaxException(format!(
+                    "Pattern format invalid, got:{}, error:{:?}",
+                    pattern, e
+                ))
+            })?),
+        };
+
+        let op = Self::get_op(ctx, stage).await?;
+        let listed = S3File::list(&op, path).await?;
+
+        let mut selected = if files.is_empty() {
+            listed
+        } else {
+            let mut selected = Vec::with_capacity(files.len());
+            for file in files {
+                let file_path = Path::new(path).join(file);
+                let file_path = file_path.to_string_lossy();
+                let file_path = file_path.trim_start_matches('/');
+                match listed
+                    .iter()
+                    .find(|v| v.trim_start_matches('/') == file_path)
+                {
+                    Some(v) if !selected.contains(v) => selected.push(v.clone()),
+                    Some(_) => {}
+                    None => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "File '{}' does not exist in the stage location '{}'",
+                            file, path
+                        )))
+                    }
+                }
+            }
+            selected
+        };
+
+        if let Some(regex) = regex {
+            selected.retain(|file| regex.is_match(file));
+        }
+
+        Ok(selected)
+    }
+
+    // Infer the schema of the files from the first records of each file.
+    // The types are merged across the files, a column missing in some files is nullable.
+    pub async fn infer_schema(
+        ctx: &Arc<QueryContext>,
+        stage: &UserStageInfo,
+        files: &[String],
+    ) -> Result<DataSchemaRef> {
+        let mut inference = SchemaInference::create();
+        for file in files {
+            let schema = Self::infer_file_schema(ctx, stage, file).await?;
+            for field in schema.fields() {
+                inference.add_value(field.name(), field.data_type().clone());
+            }
+            inference.finish_sample();
+        }
+
+        Ok(inference.finish())
+    }
+
+    // Infer the schema of one file by the file format of the stage.
+    pub async fn infer_file_schema(
+        ctx: &Arc<QueryContext>,
+        stage: &UserStageInfo,
+        file: &str,
+    ) -> Result<DataSchemaRef> {
+        let op = Self::get_op(ctx, stage).await?;
+        let object = op.object(file);
+        let empty_schema = DataSchemaRefExt::create(vec![]);
+
+        match &stage.file_format_options.format {
+            StageFileFormatType::Csv => {
+                let builder = Self::csv_builder(ctx, empty_schema, stage)?;
+                let reader = Self::file_reader(&op, stage, file).await?;
+                builder.infer_schema(reader, INFER_SCHEMA_MAX_RECORDS).await
+            }
+            StageFileFormatType::Json => {
+                let settings = ctx.get_format_settings()?;
+                let builder = NDJsonSourceBuilder::create(empty_schema, settings);
+
+                let mut buffer = vec![];
+                let mut reader = Self::file_reader(&op, stage, file).await?;
+                reader.read_to_end(&mut buffer).await?;
+                builder
+                    .infer_schema(std::io::Cursor::new(buffer), INFER_SCHEMA_MAX_RECORDS)
+                    .await
+            }
+            StageFileFormatType::Parquet => {
+                match resolve_compression(&Self::compression(stage), file, &[]) {
+                    Compression::None => {
+                        ParquetSourceBuilder::infer_schema(&mut object.seekable_reader(..)).await
+                    }
+                    compression => {
+                        let mut buffer = vec![];
+                        object.reader().await?.read_to_end(&mut buffer).await?;
+                        let buffer = decompress(&compression, buffer)?;
+                        ParquetSourceBuilder::infer_schema(&mut futures::io::Cursor::new(buffer))
+                            .await
+                    }
+                }
+            }
+            format => Err(ErrorCode::BadArguments(format!(
+                "Schema inference only supports CSV, NDJSON and Parquet files, but got: {:?}",
+                format
+            ))),
+        }
+    }
+
+    async fn open(&self, path: &str) -> Result<Box<dyn Source>> {
         let ctx = self.ctx.clone();
-        let file_name = self.table_info.file_name.clone();
         let stage = &self.table_info.stage_info;
         let file_format = stage.file_format_options.format.clone();
 
         let op = Self::get_op(&self.ctx, &self.table_info.stage_info).await?;
-        let path = file_name.unwrap_or_else(|| "".to_string());
-        let object = op.object(&path);
+        let object = op.object(path);
 
-        // Get the format(CSV, Parquet) source stream.
+        // Get the format(CSV, NDJSON, Parquet) source stream.
         let source = match &file_format {
             StageFileFormatType::Csv => Ok(Self::csv_source(
                 ctx.clone(),
                 self.schema.clone(),
                 stage,
-                Box::new(object.reader().await?),
+                path,
+                Self::file_reader(&op, stage, path).await?,
+            )
+            .await?),
+            StageFileFormatType::Json => Ok(Self::ndjson_source(
+                ctx.clone(),
+                self.schema.clone(),
+                stage,
+                path,
+                Self::file_reader(&op, stage, path).await?,
+            )
+            .await?),
+            StageFileFormatType::Avro => Ok(Self::avro_source(
+                ctx.clone(),
+                self.schema.clone(),
+                stage,
+                Self::file_reader(&op, stage, path).await?,
             )
             .await?),
-            StageFileFormatType::Parquet => Ok(Self::parquet_source(
+            StageFileFormatType::Orc => Ok(Self::orc_source(
                 ctx.clone(),
                 self.schema.clone(),
                 stage,
-                object.seekable_reader(..),
+                Self::file_reader(&op, stage, path).await?,
             )
             .await?),
+            // The parquet file is read by seeking, so it's only decompressed if the compression
+            // is given or known by the file extension.
+            StageFileFormatType::Parquet => {
+                match resolve_compression(&Self::compression(stage), path, &[]) {
+                    Compression::None => Ok(Self::parquet_source(
+                        ctx.clone(),
+                        self.schema.clone(),
+                        stage,
+                        object.seekable_reader(..),
+                    )
+                    .await?),
+                    compression => {
+                        let mut buffer = vec![];
+                        object.reader().await?.read_to_end(&mut buffer).await?;
+                        let buffer = decompress(&compression, buffer)?;
+                        Ok(Self::parquet_source(
+                            ctx.clone(),
+                            self.schema.clone(),
+                            stage,
+                            futures::io::Cursor::new(buffer),
+                        )
+                        .await?)
+                    }
+                }
+            }
             // Unsupported.
             format => Err(ErrorCode::LogicalError(format!(
                 "Unsupported file format: {:?}",
                 format
             ))),
         }?;
-        self.source = Some(source);
 
-        Ok(())
+        Ok(source)
     }
 }
 
@@ -188,17 +542,21 @@ impl AsyncSource for StageSource {
 
     fn generate(&mut self) -> Self::BlockFuture<'_> {
         async move {
-            if !self.initialized {
-                self.initialized = true;
-                self.initialize().await?;
-            }
+            // The files of the source are read one after another.
+            loop {
+                if self.source.is_none() {
+                    match self.files.pop_front() {
+                        None => return Ok(None),
+                        Some(file) => self.source = Some(self.open(&file).await?),
+                    }
+                }
 
-            match &mut self.source {
-                None => Err(ErrorCode::LogicalError("Please init source first!")),
-                Some(source) => match source.read().await? {
-                    None => Ok(None),
-                    Some(data) => Ok(Some(data)),
-                },
+                if let Some(source) = &mut self.source {
+                    match source.read().await? {
+                        Some(data) => return Ok(Some(data)),
+                        None => self.source = None,
+                    }
+                }
             }
         }
     }
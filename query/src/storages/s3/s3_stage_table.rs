@@ -18,10 +18,12 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::S3StageTableInfo;
+use common_planners::SourceInfo;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
@@ -38,17 +40,40 @@ pub struct S3StageTable {
     // This is no used but a placeholder.
     // But the Table trait need it:
     // fn get_table_info(&self) -> &TableInfo).
+    // It only carries the schema for `SELECT * FROM @stage`.
     table_info_placeholder: TableInfo,
 }
 
 impl S3StageTable {
     pub fn try_create(table_info: S3StageTableInfo) -> Result<Arc<dyn Table>> {
-        let table_info_placeholder = TableInfo::default();
+        let table_info_placeholder = TableInfo {
+            name: table_info.stage_info.stage_name.clone(),
+            meta: TableMeta {
+                schema: table_info.schema.clone(),
+                engine: "S3Stage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
         Ok(Arc::new(Self {
             table_info,
             table_info_placeholder,
         }))
     }
+
+    // The read plan of the stage files, the same as the COPY source plan.
+    pub fn source_plan(&self, push_downs: Option<Extras>) -> ReadDataSourcePlan {
+        ReadDataSourcePlan {
+            source_info: SourceInfo::S3StageSource(self.table_info.clone()),
+            scan_fields: None,
+            parts: vec![],
+            statistics: Default::default(),
+            description: format!("(Read from stage: {})", self.table_info.desc()),
+            tbl_args: None,
+            push_downs,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -91,17 +116,34 @@ impl Table for S3StageTable {
         let table_info = &self.table_info;
         let schema = table_info.schema.clone();
 
+        // The files are distributed to the sources, which read them in parallel.
+        let files = StageSource::files(table_info);
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let sources_num = std::cmp::max(1, std::cmp::min(max_threads, files.len()));
+        let mut source_files = vec![vec![]; sources_num];
+        for (i, file) in files.into_iter().enumerate() {
+            source_files[i % sources_num].push(file);
+        }
+
         // Add StageSource Pipe to the pipeline.
-        let output = OutputPort::create();
+        let mut outputs_port = Vec::with_capacity(sources_num);
+        let mut processors = Vec::with_capacity(sources_num);
+        for files in source_files {
+            let output = OutputPort::create();
+            processors.push(StageSource::try_create(
+                ctx.clone(),
+                output.clone(),
+                schema.clone(),
+                table_info.clone(),
+                files,
+            )?);
+            outputs_port.push(output);
+        }
+
         pipeline.add_pipe(NewPipe::SimplePipe {
             inputs_port: vec![],
-            outputs_port: vec![output.clone()],
-            processors: vec![StageSource::try_create(
-                ctx,
-                output,
-                schema,
-                table_info.clone(),
-            )?],
+            outputs_port,
+            processors,
         });
 
         Ok(())
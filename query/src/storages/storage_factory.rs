@@ -21,6 +21,7 @@ use common_infallible::RwLock;
 use common_meta_types::TableInfo;
 
 use crate::configs::Config;
+use crate::storages::external::ExternalTable;
 use crate::storages::fuse::FuseTable;
 use crate::storages::github::GithubTable;
 use crate::storages::memory::MemoryTable;
@@ -105,6 +106,12 @@ impl StorageFactory {
             descriptor: Arc::new(FuseTable::description),
         });
 
+        // Register EXTERNAL table engine.
+        creators.insert("EXTERNAL".to_string(), Storage {
+            creator: Arc::new(ExternalTable::try_create),
+            descriptor: Arc::new(ExternalTable::description),
+        });
+
         // Register View table engine
         creators.insert("VIEW".to_string(), Storage {
             creator: Arc::new(ViewTable::try_create),
@@ -0,0 +1,191 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use common_meta_types::GrantObject;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_meta_types::UserPrivilegeType;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::new::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The audit trail of the executed statements. It's append-only: the records are only added
+/// by the audit log of the interpreters, they can't be inserted or truncated by the users, and
+/// it's only readable by the users with the SUPER privilege.
+pub struct AuditLogTable {
+    table_info: TableInfo,
+    max_rows: usize,
+    data: Arc<RwLock<VecDeque<DataBlock>>>,
+}
+
+impl AuditLogTable {
+    pub fn create(table_id: u64, max_rows: usize) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("event_time", DateTime64Type::arc(3, None)),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("tenant_id", Vu8::to_data_type()),
+            DataField::new("handler_type", Vu8::to_data_type()),
+            DataField::new("sql_user", Vu8::to_data_type()),
+            DataField::new("roles", Vu8::to_data_type()),
+            DataField::new("client_address", Vu8::to_data_type()),
+            DataField::new("query_kind", Vu8::to_data_type()),
+            DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("objects", Vu8::to_data_type()),
+            DataField::new("status", Vu8::to_data_type()),
+            DataField::new("exception_code", i32::to_data_type()),
+            DataField::new("exception_text", Vu8::to_data_type()),
+            DataField::new("affected_rows", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'audit_log'".to_string(),
+            name: "audit_log".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemAuditLog".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AuditLogTable {
+            table_info,
+            max_rows,
+            data: Arc::new(RwLock::new(VecDeque::new())),
+        }
+    }
+
+    /// Append the records, the oldest ones are dropped if there are more than max_rows.
+    pub fn append(&self, block: DataBlock) {
+        let mut data = self.data.write();
+        data.push_back(block);
+
+        let mut rows = data.iter().map(DataBlock::num_rows).sum::<usize>();
+        while rows > self.max_rows && data.len() > 1 {
+            rows -= data.pop_front().map(|block| block.num_rows()).unwrap_or(0);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for AuditLogTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        // The partitions are read by the planning of both pipelines, before the table is read.
+        ctx.get_current_session()
+            .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+            .await?;
+        Ok((Statistics::default(), vec![]))
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let data = self.data.read().clone();
+        let mut blocks = Vec::with_capacity(data.len());
+        for block in data {
+            blocks.push(block);
+        }
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+
+        source_builder.add_source(
+            output.clone(),
+            AuditLogSource::create(ctx, output, &self.data.read())?,
+        );
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn append_data(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        Err(ErrorCode::UnImplement(
+            "system.audit_log is append-only for the audit log, it can't be inserted",
+        ))
+    }
+}
+
+struct AuditLogSource {
+    data: VecDeque<DataBlock>,
+}
+
+impl AuditLogSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        data: &VecDeque<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, AuditLogSource { data: data.clone() })
+    }
+}
+
+impl SyncSource for AuditLogSource {
+    const NAME: &'static str = "system.audit_log";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.data.pop_front())
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::Vu8;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_meta_types::UserGrantSet;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The privileges granted on the columns of the tables, one row for each privilege of a
+/// column granted to a user or a role.
+pub struct ColumnPrivilegesTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct ColumnPrivilegeRows {
+    grantees: Vec<String>,
+    table_schemas: Vec<String>,
+    table_names: Vec<String>,
+    column_names: Vec<String>,
+    privilege_types: Vec<String>,
+}
+
+impl ColumnPrivilegeRows {
+    fn append(&mut self, grantee: String, grants: &UserGrantSet) {
+        for entry in grants.entries() {
+            if let GrantObject::Column(database, table, column) = entry.object() {
+                for privilege in entry.privileges().iter() {
+                    self.grantees.push(grantee.clone());
+                    self.table_schemas.push(database.clone());
+                    self.table_names.push(table.clone());
+                    self.column_names.push(column.clone());
+                    self.privilege_types.push(privilege.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ColumnPrivilegesTable {
+    const NAME: &'static str = "system.column_privileges";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_mgr = ctx.get_user_manager();
+
+        let mut rows = ColumnPrivilegeRows::default();
+        for user in user_mgr.get_users(&tenant).await? {
+            rows.append(user.identity().to_string(), &user.grants);
+        }
+        for role in user_mgr.get_roles(&tenant).await? {
+            rows.append(format!("'{}'", role.identity()), &role.grants);
+        }
+
+        // The privileges on the columns can not be granted to the others.
+        let is_grantables = vec!["NO"; rows.grantees.len()];
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(rows.grantees),
+            Series::from_data(rows.table_schemas),
+            Series::from_data(rows.table_names),
+            Series::from_data(rows.column_names),
+            Series::from_data(rows.privilege_types),
+            Series::from_data(is_grantables),
+        ]))
+    }
+}
+
+impl ColumnPrivilegesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("grantee", Vu8::to_data_type()),
+            DataField::new("table_schema", Vu8::to_data_type()),
+            DataField::new("table_name", Vu8::to_data_type()),
+            DataField::new("column_name", Vu8::to_data_type()),
+            DataField::new("privilege_type", Vu8::to_data_type()),
+            DataField::new("is_grantable", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_privileges'".to_string(),
+            name: "column_privileges".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnPrivileges".to_string(),
+                ..Default::default()
+            },
+        };
+        AsyncOneBlockSystemTable::create(ColumnPrivilegesTable { table_info })
+    }
+}
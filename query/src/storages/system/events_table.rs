@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_metrics::MetricValue;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+/// The cumulative events since the server started, they are the counters of the metrics
+/// registry. The current values of all the metrics are in system.metrics.
+pub struct EventsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for EventsTable {
+    const NAME: &'static str = "system.events";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _: Arc<QueryContext>) -> Result<DataBlock> {
+        let prometheus_handle = common_metrics::try_handle().ok_or_else(|| {
+            ErrorCode::InitPrometheusFailure("Prometheus recorder is not initialized yet.")
+        })?;
+
+        let samples = common_metrics::dump_metric_samples(prometheus_handle)?;
+        let mut events: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
+        let mut labels: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
+        let mut values: Vec<u64> = Vec::with_capacity(samples.len());
+        for sample in samples.into_iter() {
+            if let MetricValue::Counter(value) = sample.value {
+                // Sorted, so the same labels are always displayed the same.
+                let sample_labels = sample.labels.into_iter().collect::<BTreeMap<_, _>>();
+                let sample_labels = serde_json::to_string(&sample_labels)?;
+                events.push(sample.name.into_bytes());
+                labels.push(sample_labels.into_bytes());
+                values.push(value as u64);
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(events),
+            Series::from_data(labels),
+            Series::from_data(values),
+        ]))
+    }
+}
+
+impl EventsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("event", Vu8::to_data_type()),
+            DataField::new("labels", Vu8::to_data_type()),
+            DataField::new("value", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'events'".to_string(),
+            name: "events".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemEvents".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(EventsTable { table_info })
+    }
+}
@@ -75,7 +75,12 @@ impl AsyncSystemTable for FunctionsTable {
             .collect::<Vec<bool>>();
 
         let is_aggregate = (0..names.len())
-            .map(|i| i >= func_names.len() && i < builtin_func_len)
+            .map(|i| match i < builtin_func_len {
+                true => i >= func_names.len(),
+                false => udfs
+                    .get(i - builtin_func_len)
+                    .map_or(false, |udf| udf.is_aggregate),
+            })
             .collect::<Vec<bool>>();
 
         let definitions = (0..names.len())
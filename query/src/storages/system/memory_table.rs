@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::MemoryTracker;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The memory of the node tracked by the allocator: in total, by the running queries and by the
+/// kinds of the processors of the queries, and the stats of the allocator itself.
+pub struct MemoryTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct MemoryRows {
+    scopes: Vec<&'static str>,
+    query_ids: Vec<String>,
+    names: Vec<String>,
+    memory_usages: Vec<i64>,
+    peak_memory_usages: Vec<i64>,
+}
+
+impl MemoryRows {
+    fn push(&mut self, scope: &'static str, query_id: &str, name: &str, usage: i64, peak: i64) {
+        self.scopes.push(scope);
+        self.query_ids.push(query_id.to_string());
+        self.names.push(name.to_string());
+        self.memory_usages.push(usage);
+        self.peak_memory_usages.push(peak);
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for MemoryTable {
+    const NAME: &'static str = "system.memory";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let mut rows = MemoryRows::default();
+
+        // The runtimes of the queries are created in the global runtime, so the root tracker
+        // tracks all the memory of the node.
+        let mut global_tracker = MemoryTracker::current();
+        while let Some(parent) = global_tracker
+            .as_ref()
+            .and_then(|tracker| tracker.get_parent_memory_tracker())
+        {
+            global_tracker = Some(parent);
+        }
+        if let Some(tracker) = global_tracker {
+            let usage = tracker.get_memory_usage();
+            rows.push(
+                "global",
+                "",
+                "tracked",
+                usage,
+                tracker.get_peak_memory_usage(),
+            );
+        }
+
+        for (name, value) in common_mem_allocator::allocator_stats() {
+            rows.push("allocator", "", name, value as i64, 0);
+        }
+
+        for query in ctx.get_queries_memory_usage().await {
+            let query_id = &query.query_id;
+            let (usage, peak) = (query.memory_usage, query.peak_memory_usage);
+            rows.push("query", query_id, "total", usage, peak);
+            for (processor, usage, peak) in &query.processors {
+                rows.push("processor", query_id, processor, *usage, *peak);
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(rows.scopes),
+            Series::from_data(rows.query_ids),
+            Series::from_data(rows.names),
+            Series::from_data(rows.memory_usages),
+            Series::from_data(rows.peak_memory_usages),
+        ]))
+    }
+}
+
+impl MemoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("scope", Vu8::to_data_type()),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("memory_usage", i64::to_data_type()),
+            DataField::new("peak_memory_usage", i64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'memory'".to_string(),
+            name: "memory".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMemory".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(MemoryTable { table_info })
+    }
+}
@@ -12,20 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_log_table;
 mod clusters_table;
+mod column_privileges_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod events_table;
 mod functions_table;
+mod load_errors_table;
+mod memory_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
 mod query_log_table;
+mod query_profile_table;
 mod roles_table;
 mod settings_table;
+mod slow_query_log_table;
 mod table;
 mod tables_table;
 mod tracing_table;
@@ -33,20 +40,27 @@ mod tracing_table_stream;
 mod users_table;
 mod warehouses_table;
 
+pub use audit_log_table::AuditLogTable;
 pub use clusters_table::ClustersTable;
+pub use column_privileges_table::ColumnPrivilegesTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
+pub use events_table::EventsTable;
 pub use functions_table::FunctionsTable;
+pub use load_errors_table::LoadErrorsTable;
+pub use memory_table::MemoryTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use query_log_table::QueryLogTable;
+pub use query_profile_table::QueryProfileTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use slow_query_log_table::SlowQueryLogTable;
 pub use tables_table::TablesTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
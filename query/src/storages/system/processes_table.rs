@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
@@ -24,8 +25,11 @@ use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_meta_types::UserInfo;
+use common_tracing::tracing;
 
+use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryProgress;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
 use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
@@ -44,6 +48,7 @@ impl AsyncSystemTable for ProcessesTable {
 
     async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
         let processes_info = ctx.get_processes_info().await;
+        let stage_progresses = ProcessesTable::stage_progresses(&ctx, &processes_info).await?;
 
         let mut processes_id = Vec::with_capacity(processes_info.len());
         let mut processes_type = Vec::with_capacity(processes_info.len());
@@ -57,8 +62,20 @@ impl AsyncSystemTable for ProcessesTable {
         let mut processes_dal_metrics_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_query_tag = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_percent = Vec::with_capacity(processes_info.len());
+        let mut processes_current_stage = Vec::with_capacity(processes_info.len());
+        let mut processes_node = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
+            // The progress of the stages on the cluster nodes is added to the distributed query.
+            let stage_progress = match (process_info.stage_progress(), &process_info.query_id) {
+                (None, Some(query_id)) => stage_progresses.get(query_id).cloned(),
+                _ => None,
+            }
+            .unwrap_or_default();
+
             processes_id.push(process_info.id.clone().into_bytes());
             processes_type.push(process_info.typ.clone().into_bytes());
             processes_state.push(process_info.state.clone().into_bytes());
@@ -68,15 +85,26 @@ impl AsyncSystemTable for ProcessesTable {
             processes_extra_info.push(ProcessesTable::process_extra_info(
                 &process_info.session_extra_info,
             ));
-            processes_memory_usage.push(process_info.memory_usage);
+            processes_memory_usage.push(process_info.memory_usage + stage_progress.memory_usage);
             let (dal_metrics_read_bytes, dal_metrics_write_bytes) =
                 ProcessesTable::process_dal_metrics(&process_info.dal_metrics);
             processes_dal_metrics_read_bytes.push(dal_metrics_read_bytes);
             processes_dal_metrics_write_bytes.push(dal_metrics_write_bytes);
             let (scan_progress_read_rows, scan_progress_read_bytes) =
                 ProcessesTable::process_scan_progress_values(&process_info.scan_progress_value);
-            processes_scan_progress_read_rows.push(scan_progress_read_rows);
-            processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
+            processes_scan_progress_read_rows
+                .push(scan_progress_read_rows.map(|rows| rows + stage_progress.scan_rows));
+            processes_scan_progress_read_bytes
+                .push(scan_progress_read_bytes.map(|bytes| bytes + stage_progress.scan_bytes));
+            processes_query_id.push(process_info.query_id.clone().map(|s| s.into_bytes()));
+            processes_query_tag.push(ProcessesTable::process_query_tag(process_info));
+            processes_scan_progress_percent.push(ProcessesTable::process_scan_progress_percent(
+                &process_info.dal_metrics,
+                &stage_progress,
+            ));
+            processes_current_stage
+                .push(process_info.current_stage.clone().map(|s| s.into_bytes()));
+            processes_node.push(process_info.node.clone().into_bytes());
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -92,6 +120,11 @@ impl AsyncSystemTable for ProcessesTable {
             Series::from_data(processes_dal_metrics_write_bytes),
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
+            Series::from_data(processes_query_id),
+            Series::from_data(processes_query_tag),
+            Series::from_data(processes_scan_progress_percent),
+            Series::from_data(processes_current_stage),
+            Series::from_data(processes_node),
         ]))
     }
 }
@@ -111,6 +144,11 @@ impl ProcessesTable {
             DataField::new_nullable("dal_metrics_write_bytes", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
+            DataField::new_nullable("query_id", Vu8::to_data_type()),
+            DataField::new_nullable("query_tag", Vu8::to_data_type()),
+            DataField::new_nullable("scan_progress_percent", u8::to_data_type()),
+            DataField::new_nullable("current_stage", Vu8::to_data_type()),
+            DataField::new("node", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -128,6 +166,52 @@ impl ProcessesTable {
         AsyncOneBlockSystemTable::create(ProcessesTable { table_info })
     }
 
+    /// The progress of the stages of the distributed queries on all the cluster nodes, by the
+    /// query id. The nodes failed to respond are skipped.
+    async fn stage_progresses(
+        ctx: &Arc<QueryContext>,
+        processes_info: &[ProcessInfo],
+    ) -> Result<HashMap<String, QueryProgress>> {
+        let mut progresses = processes_info
+            .iter()
+            .filter_map(ProcessInfo::stage_progress)
+            .collect::<Vec<_>>();
+
+        let cluster = ctx.get_cluster();
+        let config = ctx.get_config();
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        for node in cluster.get_nodes() {
+            if cluster.is_local(&node) {
+                continue;
+            }
+
+            let fetched = match cluster.create_node_conn(&node.id, &config).await {
+                Ok(mut flight_client) => flight_client.fetch_stage_progresses(timeout).await,
+                Err(cause) => Err(cause),
+            };
+            match fetched {
+                Ok(node_progresses) => progresses.extend(node_progresses),
+                Err(cause) => tracing::warn!(
+                    "Cannot fetch the stage progresses of {}, cause: {}",
+                    node.id,
+                    cause
+                ),
+            }
+        }
+
+        let mut stage_progresses = HashMap::<String, QueryProgress>::new();
+        for progress in &progresses {
+            stage_progresses
+                .entry(progress.query_id.clone())
+                .or_insert_with(|| QueryProgress {
+                    query_id: progress.query_id.clone(),
+                    ..Default::default()
+                })
+                .merge(progress);
+        }
+        Ok(stage_progresses)
+    }
+
     fn process_host(client_address: &Option<SocketAddr>) -> Option<Vec<u8>> {
         client_address.as_ref().map(|s| s.to_string().into_bytes())
     }
@@ -140,6 +224,13 @@ impl ProcessesTable {
         session_extra_info.clone().map(|s| s.into_bytes())
     }
 
+    fn process_query_tag(process_info: &ProcessInfo) -> Option<Vec<u8>> {
+        match process_info.settings.get_query_tag() {
+            Ok(query_tag) if !query_tag.is_empty() => Some(query_tag.into_bytes()),
+            _ => None,
+        }
+    }
+
     fn process_dal_metrics(dal_metrics_opt: &Option<DalMetrics>) -> (Option<u64>, Option<u64>) {
         if dal_metrics_opt.is_some() {
             let dal_metrics = dal_metrics_opt.as_ref().unwrap();
@@ -152,6 +243,21 @@ impl ProcessesTable {
         }
     }
 
+    // The percentage of the planned partitions (after pruning) taken by the readers.
+    fn process_scan_progress_percent(
+        dal_metrics_opt: &Option<DalMetrics>,
+        stage_progress: &QueryProgress,
+    ) -> Option<u8> {
+        let dal_metrics = dal_metrics_opt.as_ref()?;
+        let planned = dal_metrics.get_partitions_scanned();
+        if planned == 0 {
+            return None;
+        }
+
+        let read = dal_metrics.get_partitions_read() + stage_progress.partitions_read;
+        Some((read.min(planned) * 100 / planned) as u8)
+    }
+
     fn process_scan_progress_values(
         scan_progress_opt: &Option<ProgressValues>,
     ) -> (Option<u64>, Option<u64>) {
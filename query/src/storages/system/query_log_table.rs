@@ -15,6 +15,8 @@
 use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -41,14 +43,18 @@ use crate::pipelines::new::SourcePipeBuilder;
 use crate::sessions::QueryContext;
 use crate::storages::Table;
 
+/// The in-memory log of the queries, it keeps the latest `max_rows` records which are written
+/// within the retention.
 pub struct QueryLogTable {
     table_info: TableInfo,
-    max_rows: i32,
-    data: Arc<RwLock<VecDeque<DataBlock>>>,
+    max_rows: usize,
+    retention: Option<Duration>,
+    // The records with the time they are written.
+    data: Arc<RwLock<VecDeque<(Instant, DataBlock)>>>,
 }
 
 impl QueryLogTable {
-    pub fn create(table_id: u64) -> Self {
+    pub fn create(table_id: u64, max_rows: usize, retention: Option<Duration>) -> Self {
         let schema = DataSchemaRefExt::create(vec![
             // Type.
             DataField::new("log_type", i8::to_data_type()),
@@ -63,8 +69,11 @@ impl QueryLogTable {
             DataField::new("query_id", Vu8::to_data_type()),
             DataField::new("query_kind", Vu8::to_data_type()),
             DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("query_tag", Vu8::to_data_type()),
             DataField::new("event_date", Date32Type::arc()),
             DataField::new("event_time", DateTime64Type::arc(3, None)),
+            DataField::new("query_start_time", DateTime64Type::arc(3, None)),
+            DataField::new("query_duration_ms", u64::to_data_type()),
             // Schema.
             DataField::new("current_database", Vu8::to_data_type()),
             DataField::new("databases", Vu8::to_data_type()),
@@ -86,6 +95,7 @@ impl QueryLogTable {
             DataField::new("result_bytes", u64::to_data_type()),
             DataField::new("cpu_usage", u32::to_data_type()),
             DataField::new("memory_usage", u64::to_data_type()),
+            DataField::new("peak_memory_usage", u64::to_data_type()),
             // Client.
             DataField::new("client_info", Vu8::to_data_type()),
             DataField::new("client_address", Vu8::to_data_type()),
@@ -114,14 +124,21 @@ impl QueryLogTable {
 
         QueryLogTable {
             table_info,
-            max_rows: 200000,
+            max_rows,
+            retention,
             data: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
-    #[allow(dead_code)]
-    pub fn set_max_rows(&mut self, max: i32) {
-        self.max_rows = max;
+    // The records out of the retention are removed before they are read.
+    fn blocks(&self) -> VecDeque<DataBlock> {
+        let mut data = self.data.write();
+        if let Some(retention) = self.retention {
+            while matches!(data.front(), Some((written, _)) if written.elapsed() > retention) {
+                data.pop_front();
+            }
+        }
+        data.iter().map(|(_, block)| block.clone()).collect()
     }
 }
 
@@ -148,11 +165,7 @@ impl Table for QueryLogTable {
         _ctx: Arc<QueryContext>,
         _plan: &ReadDataSourcePlan,
     ) -> Result<SendableDataBlockStream> {
-        let data = self.data.read().clone();
-        let mut blocks = Vec::with_capacity(data.len());
-        for block in data {
-            blocks.push(block);
-        }
+        let blocks = self.blocks().into_iter().collect();
         Ok(Box::pin(DataBlockStream::create(
             self.table_info.schema(),
             None,
@@ -172,7 +185,7 @@ impl Table for QueryLogTable {
 
         source_builder.add_source(
             output.clone(),
-            QueryLogSource::create(ctx, output, &self.data.read())?,
+            QueryLogSource::create(ctx, output, self.blocks())?,
         );
 
         pipeline.add_pipe(source_builder.finalize());
@@ -186,14 +199,21 @@ impl Table for QueryLogTable {
     ) -> Result<SendableDataBlockStream> {
         while let Some(block) = stream.next().await {
             let block = block?;
-            self.data.write().push_back(block);
+            self.data.write().push_back((Instant::now(), block));
         }
 
-        // Check overflow.
-        let over = self.data.read().len() as i32 - self.max_rows;
-        if over > 0 {
-            for _x in 0..over {
-                self.data.write().pop_front();
+        // Check overflow, the oldest records are dropped.
+        {
+            let mut data = self.data.write();
+            let mut rows = data
+                .iter()
+                .map(|(_, block)| block.num_rows())
+                .sum::<usize>();
+            while rows > self.max_rows && data.len() > 1 {
+                rows -= data
+                    .pop_front()
+                    .map(|(_, block)| block.num_rows())
+                    .unwrap_or(0);
             }
         }
 
@@ -223,9 +243,9 @@ impl QueryLogSource {
     pub fn create(
         ctx: Arc<QueryContext>,
         output: Arc<OutputPort>,
-        data: &VecDeque<DataBlock>,
+        data: VecDeque<DataBlock>,
     ) -> Result<ProcessorPtr> {
-        SyncSourcer::create(ctx, output, QueryLogSource { data: data.clone() })
+        SyncSourcer::create(ctx, output, QueryLogSource { data })
     }
 }
 
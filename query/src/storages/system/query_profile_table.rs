@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+/// The stats of the processors of the latest queries, a row per processor.
+pub struct QueryProfileTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryProfileTable {
+    const NAME: &'static str = "system.query_profile";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let profiles = ctx.get_query_profiles();
+        let rows = profiles.iter().map(|p| p.processors.len()).sum();
+
+        let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(rows);
+        let mut processor_ids: Vec<u64> = Vec::with_capacity(rows);
+        let mut processor_names: Vec<Vec<u8>> = Vec::with_capacity(rows);
+        let mut input_rows: Vec<u64> = Vec::with_capacity(rows);
+        let mut input_bytes: Vec<u64> = Vec::with_capacity(rows);
+        let mut output_rows: Vec<u64> = Vec::with_capacity(rows);
+        let mut output_bytes: Vec<u64> = Vec::with_capacity(rows);
+        let mut cpu_time: Vec<u64> = Vec::with_capacity(rows);
+        let mut wait_time: Vec<u64> = Vec::with_capacity(rows);
+        for profile in profiles {
+            for processor in profile.processors {
+                query_ids.push(profile.query_id.clone().into_bytes());
+                processor_ids.push(processor.id as u64);
+                processor_names.push(processor.name.into_bytes());
+                input_rows.push(processor.input_rows as u64);
+                input_bytes.push(processor.input_bytes as u64);
+                output_rows.push(processor.output_rows as u64);
+                output_bytes.push(processor.output_bytes as u64);
+                cpu_time.push(processor.cpu_time.as_micros() as u64);
+                wait_time.push(processor.wait_time.as_micros() as u64);
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(query_ids),
+            Series::from_data(processor_ids),
+            Series::from_data(processor_names),
+            Series::from_data(input_rows),
+            Series::from_data(input_bytes),
+            Series::from_data(output_rows),
+            Series::from_data(output_bytes),
+            Series::from_data(cpu_time),
+            Series::from_data(wait_time),
+        ]))
+    }
+}
+
+impl QueryProfileTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("processor_id", u64::to_data_type()),
+            DataField::new("processor_name", Vu8::to_data_type()),
+            DataField::new("input_rows", u64::to_data_type()),
+            DataField::new("input_bytes", u64::to_data_type()),
+            DataField::new("output_rows", u64::to_data_type()),
+            DataField::new("output_bytes", u64::to_data_type()),
+            DataField::new("cpu_time_us", u64::to_data_type()),
+            DataField::new("wait_time_us", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_profile'".to_string(),
+            name: "query_profile".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryProfile".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(QueryProfileTable { table_info })
+    }
+}
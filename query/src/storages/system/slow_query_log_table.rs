@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::RwLock;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::new::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+/// The queries taking longer than the `long_query_time` setting, with the plans and the scan
+/// statistics to tune them offline. It's append-only like system.audit_log.
+pub struct SlowQueryLogTable {
+    table_info: TableInfo,
+    max_rows: usize,
+    data: Arc<RwLock<VecDeque<DataBlock>>>,
+}
+
+impl SlowQueryLogTable {
+    pub fn create(table_id: u64, max_rows: usize) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("event_time", DateTime64Type::arc(3, None)),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("tenant_id", Vu8::to_data_type()),
+            DataField::new("sql_user", Vu8::to_data_type()),
+            DataField::new("query_kind", Vu8::to_data_type()),
+            DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("query_duration_ms", u64::to_data_type()),
+            DataField::new("plan", Vu8::to_data_type()),
+            DataField::new("scan_rows", u64::to_data_type()),
+            DataField::new("scan_bytes", u64::to_data_type()),
+            DataField::new("scan_io_bytes", u64::to_data_type()),
+            DataField::new("scan_io_bytes_cost_ms", u64::to_data_type()),
+            DataField::new("scan_partitions", u64::to_data_type()),
+            DataField::new("total_partitions", u64::to_data_type()),
+            DataField::new("result_rows", u64::to_data_type()),
+            DataField::new("peak_memory_usage", u64::to_data_type()),
+            DataField::new("exception_code", i32::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'slow_query_log'".to_string(),
+            name: "slow_query_log".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSlowQueryLog".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SlowQueryLogTable {
+            table_info,
+            max_rows,
+            data: Arc::new(RwLock::new(VecDeque::new())),
+        }
+    }
+
+    /// Append the records, the oldest ones are dropped if there are more than max_rows.
+    pub fn append(&self, block: DataBlock) {
+        let mut data = self.data.write();
+        data.push_back(block);
+
+        let mut rows = data.iter().map(DataBlock::num_rows).sum::<usize>();
+        while rows > self.max_rows && data.len() > 1 {
+            rows -= data.pop_front().map(|block| block.num_rows()).unwrap_or(0);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for SlowQueryLogTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let data = self.data.read().clone();
+        let mut blocks = Vec::with_capacity(data.len());
+        for block in data {
+            blocks.push(block);
+        }
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+
+        source_builder.add_source(
+            output.clone(),
+            SlowQueryLogSource::create(ctx, output, &self.data.read())?,
+        );
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn append_data(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        Err(ErrorCode::UnImplement(
+            "system.slow_query_log is append-only for the slow queries, it can't be inserted",
+        ))
+    }
+}
+
+struct SlowQueryLogSource {
+    data: VecDeque<DataBlock>,
+}
+
+impl SlowQueryLogSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        data: &VecDeque<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, SlowQueryLogSource { data: data.clone() })
+    }
+}
+
+impl SyncSource for SlowQueryLogSource {
+    const NAME: &'static str = "system.slow_query_log";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.data.pop_front())
+    }
+}
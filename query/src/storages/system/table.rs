@@ -261,7 +261,9 @@ where Self: AsyncSource
 
 impl<TTable: 'static + AsyncSystemTable> AsyncSource for SystemTableAsyncSource<TTable> {
     const NAME: &'static str = TTable::NAME;
-    type BlockFuture<'a> = impl Future<Output = Result<Option<DataBlock>>> where Self: 'a;
+    type BlockFuture<'a>
+        = impl Future<Output = Result<Option<DataBlock>>>
+    where Self: 'a;
 
     fn generate(&mut self) -> Self::BlockFuture<'_> {
         async move {
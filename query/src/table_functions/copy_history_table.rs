@@ -0,0 +1,228 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::future::Future;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::AsyncSource;
+use crate::pipelines::new::processors::AsyncSourcer;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::parse_func_history_args;
+use crate::storages::fuse::string_literal;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// Lists the files copied into a table and not expired, e.g. `select * from copy_history('db', 't')`.
+/// The files are skipped by COPY until they are changed or expired.
+pub struct CopyHistoryTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl CopyHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: "CopyHistory".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(CopyHistoryTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("etag", Vu8::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+            DataField::new("rows_loaded", u64::to_data_type()),
+            DataField::new("load_time", DateTime64Type::arc(3, None)),
+        ])
+    }
+
+    async fn get_history(
+        ctx: Arc<QueryContext>,
+        database_name: &str,
+        table_name: &str,
+    ) -> Result<DataBlock> {
+        let table = ctx.get_table(database_name, table_name).await?;
+        let mut files = ctx
+            .get_user_manager()
+            .get_copied_files(&ctx.get_tenant(), table.get_id())
+            .await?;
+        files.sort_by(|a, b| (a.load_time, &a.file).cmp(&(b.load_time, &b.file)));
+
+        Ok(DataBlock::create(Self::schema(), vec![
+            Series::from_data(files.iter().map(|f| f.file.as_str()).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.etag.as_str()).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.size).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.rows_loaded).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.load_time).collect::<Vec<_>>()),
+        ]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for CopyHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let block = Self::get_history(ctx, &self.arg_database_name, &self.arg_table_name).await?;
+        Ok(Box::pin(DataBlockStream::create(
+            Self::schema(),
+            None,
+            vec![block],
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![CopyHistorySource::create(
+                ctx,
+                output,
+                self.arg_database_name.to_owned(),
+                self.arg_table_name.to_owned(),
+            )?],
+        });
+
+        Ok(())
+    }
+}
+
+struct CopyHistorySource {
+    finish: bool,
+    ctx: Arc<QueryContext>,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl CopyHistorySource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, CopyHistorySource {
+            ctx,
+            finish: false,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+}
+
+impl AsyncSource for CopyHistorySource {
+    const NAME: &'static str = "copy_history";
+
+    type BlockFuture<'a> = impl Future<Output = Result<Option<DataBlock>>> where Self: 'a;
+
+    fn generate(&mut self) -> Self::BlockFuture<'_> {
+        async {
+            if self.finish {
+                return Ok(None);
+            }
+
+            self.finish = true;
+            let block = CopyHistoryTable::get_history(
+                self.ctx.clone(),
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+            Ok(Some(block))
+        }
+    }
+}
+
+impl TableFunction for CopyHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
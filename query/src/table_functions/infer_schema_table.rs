@@ -0,0 +1,329 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::future::Future;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::get_file_name;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SchemaInference;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::AsyncSource;
+use crate::pipelines::new::processors::AsyncSourcer;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::sessions::QueryContext;
+use crate::sql::statements::location_to_stage_path;
+use crate::storages::StageSource;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// Proposes the columns of the files in a stage, e.g.
+/// `select * from infer_schema(stage => '@my_stage/path', pattern => '.*[.]csv')`.
+/// The first records of every file are sampled by the file format of the stage, or by `file_format`.
+pub struct InferSchemaTable {
+    table_info: TableInfo,
+    args: InferSchemaArgs,
+}
+
+#[derive(Clone, Debug, Default)]
+struct InferSchemaArgs {
+    location: String,
+    pattern: String,
+    file_format: Option<String>,
+}
+
+impl InferSchemaTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = Self::parse_args(table_func_name, table_args)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: "InferSchema".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(InferSchemaTable { table_info, args }))
+    }
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("column_name", Vu8::to_data_type()),
+            DataField::new("type", Vu8::to_data_type()),
+            DataField::new("nullable", bool::to_data_type()),
+            DataField::new("filenames", Vu8::to_data_type()),
+            DataField::new("order_id", u64::to_data_type()),
+        ])
+    }
+
+    // The stage location is the first argument or the `stage` argument,
+    // `pattern` and `file_format` can only be given by name.
+    fn parse_args(table_func_name: &str, table_args: TableArgs) -> Result<InferSchemaArgs> {
+        let mut args = InferSchemaArgs::default();
+        for (i, arg) in table_args.unwrap_or_default().iter().enumerate() {
+            match arg {
+                Expression::Alias(name, expr) => {
+                    let value = Self::string_value(table_func_name, expr)?;
+                    match name.to_lowercase().as_str() {
+                        "stage" | "location" => args.location = value,
+                        "pattern" => args.pattern = value,
+                        "file_format" => args.file_format = Some(value),
+                        other => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Unknown argument '{}' for table function {}, expected one of stage, pattern, file_format",
+                                other, table_func_name
+                            )))
+                        }
+                    }
+                }
+                expr if i == 0 => args.location = Self::string_value(table_func_name, expr)?,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Only the stage location can be a positional argument of table function {}",
+                        table_func_name
+                    )))
+                }
+            }
+        }
+
+        if !args.location.starts_with('@') {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expected a stage location like '@my_stage/path' for table function {}, but got '{}'",
+                table_func_name, args.location
+            )));
+        }
+
+        if let Some(file_format) = &args.file_format {
+            file_format
+                .parse::<StageFileFormatType>()
+                .map_err(ErrorCode::BadArguments)?;
+        }
+
+        Ok(args)
+    }
+
+    fn string_value(table_func_name: &str, expr: &Expression) -> Result<String> {
+        match expr {
+            Expression::Literal {
+                value: DataValue::String(value),
+                ..
+            } => Ok(String::from_utf8_lossy(value).to_string()),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Expected a string literal argument for table function {}, but got {:?}",
+                table_func_name, other
+            ))),
+        }
+    }
+
+    async fn infer_schema(ctx: Arc<QueryContext>, args: &InferSchemaArgs) -> Result<DataBlock> {
+        let (mut stage, path) = location_to_stage_path(&args.location, &ctx).await?;
+        if let Some(file_format) = &args.file_format {
+            stage.file_format_options.format = file_format
+                .parse::<StageFileFormatType>()
+                .map_err(ErrorCode::BadArguments)?;
+        }
+
+        let files = StageSource::list_files(&ctx, &stage, &path, &[], &args.pattern).await?;
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "There are no files in the stage location '{}'",
+                args.location
+            )));
+        }
+
+        // The columns are in the order they are first seen, the same as the inferred schema.
+        let mut inference = SchemaInference::create();
+        let mut column_files: Vec<Vec<String>> = vec![];
+        for file in &files {
+            let schema = StageSource::infer_file_schema(&ctx, &stage, file).await?;
+            for field in schema.fields() {
+                let pos = inference.position(field.name());
+                inference.add_value(field.name(), field.data_type().clone());
+                match pos {
+                    Some(pos) => column_files[pos].push(get_file_name(file)),
+                    None => column_files.push(vec![get_file_name(file)]),
+                }
+            }
+            inference.finish_sample();
+        }
+
+        let schema = inference.finish();
+        let fields = schema.fields();
+        let mut names = Vec::with_capacity(fields.len());
+        let mut types = Vec::with_capacity(fields.len());
+        let mut nullables = Vec::with_capacity(fields.len());
+        let mut filenames = Vec::with_capacity(fields.len());
+        let mut order_ids = Vec::with_capacity(fields.len());
+        for (i, (field, files)) in fields.iter().zip(column_files.iter()).enumerate() {
+            names.push(field.name().clone());
+            types.push(remove_nullable(field.data_type()).name().to_string());
+            nullables.push(field.is_nullable());
+            filenames.push(files.join(", "));
+            order_ids.push(i as u64);
+        }
+
+        Ok(DataBlock::create(Self::schema(), vec![
+            Series::from_data(names),
+            Series::from_data(types),
+            Series::from_data(nullables),
+            Series::from_data(filenames),
+            Series::from_data(order_ids),
+        ]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for InferSchemaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        let string_arg = |name: &str, value: &str| {
+            Expression::Alias(
+                name.to_string(),
+                Box::new(Expression::create_literal(DataValue::String(
+                    value.as_bytes().to_vec(),
+                ))),
+            )
+        };
+
+        let mut args = vec![string_arg("stage", &self.args.location)];
+        if !self.args.pattern.is_empty() {
+            args.push(string_arg("pattern", &self.args.pattern));
+        }
+        if let Some(file_format) = &self.args.file_format {
+            args.push(string_arg("file_format", file_format));
+        }
+        Some(args)
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let block = Self::infer_schema(ctx, &self.args).await?;
+        Ok(Box::pin(DataBlockStream::create(
+            Self::schema(),
+            None,
+            vec![block],
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![InferSchemaSource::create(ctx, output, self.args.clone())?],
+        });
+
+        Ok(())
+    }
+}
+
+struct InferSchemaSource {
+    finish: bool,
+    ctx: Arc<QueryContext>,
+    args: InferSchemaArgs,
+}
+
+impl InferSchemaSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        args: InferSchemaArgs,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, InferSchemaSource {
+            ctx,
+            finish: false,
+            args,
+        })
+    }
+}
+
+impl AsyncSource for InferSchemaSource {
+    const NAME: &'static str = "infer_schema";
+
+    type BlockFuture<'a> = impl Future<Output = Result<Option<DataBlock>>> where Self: 'a;
+
+    fn generate(&mut self) -> Self::BlockFuture<'_> {
+        async {
+            if self.finish {
+                return Ok(None);
+            }
+
+            self.finish = true;
+            let block = InferSchemaTable::infer_schema(self.ctx.clone(), &self.args).await?;
+            Ok(Some(block))
+        }
+    }
+}
+
+impl TableFunction for InferSchemaTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
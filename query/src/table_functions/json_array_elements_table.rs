@@ -0,0 +1,203 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use serde_json::Value as JsonValue;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::unnest_table::UnnestSource;
+use crate::table_functions::TableFunction;
+
+/// Expand a JSON array to a table with one variant row per element, e.g.
+/// `select value from json_array_elements('[1, "a", {"k": 2}]')`. The argument may be a string
+/// of JSON text or a variant, NULL expands to no rows.
+pub struct JsonArrayElementsTable {
+    table_info: TableInfo,
+    json: JsonValue,
+}
+
+impl JsonArrayElementsTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let json = match &table_args {
+            Some(args) if args.len() == 1 => Self::eval_json(table_func_name, &args[0])?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Must have exactly one JSON argument for table function.{}",
+                    table_func_name
+                )))
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: DataSchemaRefExt::create(vec![DataField::new("value", VariantType::arc())]),
+                engine: "JsonArrayElements".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(JsonArrayElementsTable { table_info, json }))
+    }
+
+    /// The argument must be a constant expression of a JSON array.
+    fn eval_json(table_func_name: &str, expr: &Expression) -> Result<JsonValue> {
+        let input_schema =
+            DataSchemaRefExt::create(vec![DataField::new("_dummy", u8::to_data_type())]);
+        let data_type = expr.to_data_type(&input_schema)?;
+        let type_id = remove_nullable(&data_type).data_type_id();
+        if !type_id.is_string() && !type_id.is_variant_or_array() && type_id != TypeID::Null {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expected a JSON argument for table function {}, but got {:?}",
+                table_func_name, data_type
+            )));
+        }
+
+        let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&input_schema)?]);
+        let executor = ExpressionExecutor::try_create(
+            "Json array elements table function argument.",
+            input_schema.clone(),
+            output_schema,
+            vec![expr.clone()],
+            false,
+        )?;
+        let dummy_columns = vec![ConstColumn::new(Series::from_data(vec![1u8]), 1).arc()];
+        let block = executor.execute(&DataBlock::create(input_schema, dummy_columns))?;
+
+        let json = match block.column(0).get_checked(0)? {
+            DataValue::Null => JsonValue::Null,
+            DataValue::Json(json) => json,
+            DataValue::String(text) => serde_json::from_slice(&text)
+                .map_err(|e| ErrorCode::BadDataValueType(format!("Error parsing JSON: {}", e)))?,
+            other => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Expected a JSON argument for table function {}, but got {:?}",
+                    table_func_name, other
+                )))
+            }
+        };
+
+        match json {
+            JsonValue::Null | JsonValue::Array(_) => Ok(json),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Expected a JSON array for table function {}, but got {}",
+                table_func_name, other
+            ))),
+        }
+    }
+
+    fn generate_block(&self) -> Result<DataBlock> {
+        let values = match &self.json {
+            JsonValue::Array(values) => values.clone(),
+            _ => vec![],
+        };
+        let column = Series::from_data(values);
+        Ok(DataBlock::create(self.schema(), vec![column]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for JsonArrayElementsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![Expression::Literal {
+            value: DataValue::Json(self.json.clone()),
+            column_name: None,
+            data_type: VariantType::arc(),
+        }])
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema(),
+            None,
+            vec![self.generate_block()?],
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![UnnestSource::create(ctx, output, self.generate_block()?)?],
+        });
+
+        Ok(())
+    }
+}
+
+impl TableFunction for JsonArrayElementsTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
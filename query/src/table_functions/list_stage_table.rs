@@ -0,0 +1,242 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::future::Future;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::get_file_name;
+use common_io::prelude::S3File;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::AsyncSource;
+use crate::pipelines::new::processors::AsyncSourcer;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::sessions::QueryContext;
+use crate::sql::statements::location_to_stage_path;
+use crate::storages::StageSource;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// Lists the files of a named stage with their sizes, e.g.
+/// `select name, size from list_stage('@my_stage/path') where name like '%.csv'`.
+/// The stage is resolved when the table is read, the files are listed by the credentials of the stage.
+pub struct ListStageTable {
+    table_info: TableInfo,
+    location: String,
+}
+
+impl ListStageTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let location = match &table_args {
+            Some(args) if args.len() == 1 => Self::location_value(table_func_name, &args[0])?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Must have exactly one stage location argument for table function.{}",
+                    table_func_name
+                )))
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: "ListStage".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(ListStageTable {
+            table_info,
+            location,
+        }))
+    }
+
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+        ])
+    }
+
+    fn location_value(table_func_name: &str, expr: &Expression) -> Result<String> {
+        let location = match expr {
+            Expression::Literal {
+                value: DataValue::String(value),
+                ..
+            } => String::from_utf8_lossy(value).to_string(),
+            other => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Expected a string literal argument for table function {}, but got {:?}",
+                    table_func_name, other
+                )))
+            }
+        };
+
+        match location.starts_with('@') {
+            true => Ok(location),
+            false => Err(ErrorCode::BadArguments(format!(
+                "Expected a stage location like '@my_stage/path' for table function {}, but got '{}'",
+                table_func_name, location
+            ))),
+        }
+    }
+
+    async fn list_files(ctx: Arc<QueryContext>, location: &str) -> Result<DataBlock> {
+        let (stage, path) = location_to_stage_path(location, &ctx).await?;
+        let op = StageSource::get_op(&ctx, &stage).await?;
+
+        let files = S3File::list(&op, &path).await?;
+        let mut names = Vec::with_capacity(files.len());
+        let mut sizes = Vec::with_capacity(files.len());
+        for file in files {
+            let meta = op.object(&file).metadata().await?;
+            names.push(get_file_name(&file));
+            sizes.push(meta.content_length());
+        }
+
+        Ok(DataBlock::create(Self::schema(), vec![
+            Series::from_data(names),
+            Series::from_data(sizes),
+        ]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ListStageTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![Expression::create_literal(DataValue::String(
+            self.location.as_bytes().to_vec(),
+        ))])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let block = Self::list_files(ctx, &self.location).await?;
+        Ok(Box::pin(DataBlockStream::create(
+            Self::schema(),
+            None,
+            vec![block],
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![ListStageSource::create(ctx, output, self.location.clone())?],
+        });
+
+        Ok(())
+    }
+}
+
+struct ListStageSource {
+    finish: bool,
+    ctx: Arc<QueryContext>,
+    location: String,
+}
+
+impl ListStageSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        location: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, ListStageSource {
+            ctx,
+            finish: false,
+            location,
+        })
+    }
+}
+
+impl AsyncSource for ListStageSource {
+    const NAME: &'static str = "list_stage";
+
+    type BlockFuture<'a> = impl Future<Output = Result<Option<DataBlock>>> where Self: 'a;
+
+    fn generate(&mut self) -> Self::BlockFuture<'_> {
+        async {
+            if self.finish {
+                return Ok(None);
+            }
+
+            self.finish = true;
+            let block = ListStageTable::list_files(self.ctx.clone(), &self.location).await?;
+            Ok(Some(block))
+        }
+    }
+}
+
+impl TableFunction for ListStageTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -13,16 +13,26 @@
 //  limitations under the License.
 //
 
+mod copy_history_table;
+mod infer_schema_table;
+mod json_array_elements_table;
+mod list_stage_table;
 mod memory_block_part;
 mod numbers_part;
 mod numbers_stream;
 mod numbers_table;
 mod table_function;
 mod table_function_factory;
+mod unnest_table;
 
+pub use copy_history_table::CopyHistoryTable;
+pub use infer_schema_table::InferSchemaTable;
+pub use json_array_elements_table::JsonArrayElementsTable;
+pub use list_stage_table::ListStageTable;
 pub use memory_block_part::generate_numbers_parts;
 pub use numbers_part::NumbersPartInfo;
 pub use numbers_table::NumbersTable;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableArgs;
 pub use table_function_factory::TableFunctionFactory;
+pub use unnest_table::UnnestTable;
@@ -26,8 +26,13 @@ use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::FuseHistoryTable;
 use crate::storages::fuse::FUSE_FUNC_HIST;
+use crate::table_functions::CopyHistoryTable;
+use crate::table_functions::InferSchemaTable;
+use crate::table_functions::JsonArrayElementsTable;
+use crate::table_functions::ListStageTable;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
+use crate::table_functions::UnnestTable;
 
 pub type TableArgs = Option<Vec<Expression>>;
 type TableFunctionCreators = RwLock<HashMap<String, (MetaId, Arc<dyn TableFunctionCreator>)>>;
@@ -100,6 +105,37 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseHistoryTable::create)),
         );
 
+        let unnest_table_func_creator: Arc<dyn TableFunctionCreator> =
+            Arc::new(UnnestTable::create);
+        creators.insert(
+            "unnest".to_string(),
+            (next_id(), unnest_table_func_creator.clone()),
+        );
+        creators.insert(
+            "flatten".to_string(),
+            (next_id(), unnest_table_func_creator),
+        );
+
+        creators.insert(
+            "json_array_elements".to_string(),
+            (next_id(), Arc::new(JsonArrayElementsTable::create)),
+        );
+
+        creators.insert(
+            "list_stage".to_string(),
+            (next_id(), Arc::new(ListStageTable::create)),
+        );
+
+        creators.insert(
+            "infer_schema".to_string(),
+            (next_id(), Arc::new(InferSchemaTable::create)),
+        );
+
+        creators.insert(
+            "copy_history".to_string(),
+            (next_id(), Arc::new(CopyHistoryTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -0,0 +1,220 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// Expand an array to a table with one row per element, e.g.
+/// `select value from unnest(array(1, 2, 3))`. `flatten` is an alias.
+pub struct UnnestTable {
+    table_info: TableInfo,
+    array_type: DataTypePtr,
+    values: Vec<DataValue>,
+}
+
+impl UnnestTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (array_type, values) = match &table_args {
+            Some(args) if args.len() == 1 => Self::eval_array(&args[0])?,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Must have exactly one array argument for table function.{}",
+                    table_func_name
+                )))
+            }
+        };
+
+        let array: &ArrayType = array_type.as_any().downcast_ref().unwrap();
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: DataSchemaRefExt::create(vec![DataField::new(
+                    "value",
+                    array.inner_type().clone(),
+                )]),
+                engine: "Unnest".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(UnnestTable {
+            table_info,
+            array_type,
+            values,
+        }))
+    }
+
+    /// The argument must be a constant expression of array type.
+    fn eval_array(expr: &Expression) -> Result<(DataTypePtr, Vec<DataValue>)> {
+        let input_schema =
+            DataSchemaRefExt::create(vec![DataField::new("_dummy", u8::to_data_type())]);
+        let array_type = expr.to_data_type(&input_schema)?;
+        if array_type.data_type_id() != TypeID::Array {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expected an array argument for table function unnest, but got {:?}",
+                array_type
+            )));
+        }
+
+        let output_schema = DataSchemaRefExt::create(vec![expr.to_data_field(&input_schema)?]);
+        let executor = ExpressionExecutor::try_create(
+            "Unnest table function argument.",
+            input_schema.clone(),
+            output_schema,
+            vec![expr.clone()],
+            false,
+        )?;
+        let dummy_columns = vec![ConstColumn::new(Series::from_data(vec![1u8]), 1).arc()];
+        let block = executor.execute(&DataBlock::create(input_schema, dummy_columns))?;
+
+        match block.column(0).get_checked(0)? {
+            DataValue::Array(values) => Ok((array_type, values)),
+            other => Err(ErrorCode::BadArguments(format!(
+                "Expected an array argument for table function unnest, but got {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn generate_block(&self) -> Result<DataBlock> {
+        let column = self
+            .schema()
+            .field(0)
+            .data_type()
+            .create_column(&self.values)?;
+        Ok(DataBlock::create(self.schema(), vec![column]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for UnnestTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![Expression::Literal {
+            value: DataValue::Array(self.values.clone()),
+            column_name: None,
+            data_type: self.array_type.clone(),
+        }])
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema(),
+            None,
+            vec![self.generate_block()?],
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![UnnestSource::create(ctx, output, self.generate_block()?)?],
+        });
+
+        Ok(())
+    }
+}
+
+pub(crate) struct UnnestSource {
+    block: Option<DataBlock>,
+}
+
+impl UnnestSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        block: DataBlock,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, UnnestSource { block: Some(block) })
+    }
+}
+
+impl SyncSource for UnnestSource {
+    const NAME: &'static str = "unnest";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.block.take())
+    }
+}
+
+impl TableFunction for UnnestTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
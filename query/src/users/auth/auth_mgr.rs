@@ -12,24 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
+use common_meta_types::SeqV;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 
 pub use crate::configs::Config;
 use crate::users::auth::jwt::JwtAuthenticator;
+use crate::users::auth::ldap::LdapAuthenticator;
+use crate::users::auth::password_policy::PasswordPolicy;
 use crate::users::UserApiProvider;
 
+/// The users are authenticated in the tenant of the session, see `Session::get_tenant`.
+///
+/// The tenant is chosen by the client, so the credentials not issued by a tenant, the requests
+/// without credential and the built-in users, are only accepted in the tenant of the config.
 pub struct AuthMgr {
     tenant: String,
+    network_policy: Option<String>,
     users: Arc<UserApiProvider>,
     jwt: Option<JwtAuthenticator>,
+    ldap: Option<LdapAuthenticator>,
+    password_policy: PasswordPolicy,
 }
 
+// The users mocked in all the tenants without password, see `UserApiProvider::get_user`.
+const BUILTIN_USERS: [&str; 3] = ["default", "", "root"];
+
 pub enum Credential {
     Jwt {
         token: String,
@@ -46,40 +61,155 @@ impl AuthMgr {
         Ok(AuthMgr {
             users,
             tenant: cfg.query.tenant_id.clone(),
+            network_policy: Some(cfg.query.network_policy.clone()).filter(|v| !v.is_empty()),
+            password_policy: PasswordPolicy::create(&cfg),
+            ldap: LdapAuthenticator::try_create(cfg.clone())?,
             jwt: JwtAuthenticator::try_create(cfg).await?,
         })
     }
 
-    pub async fn no_auth(&self) -> Result<UserInfo> {
+    pub fn get_password_policy(&self) -> &PasswordPolicy {
+        &self.password_policy
+    }
+
+    /// Apply the lockout of the password policy to the result of the password verification of
+    /// the native user, it is shared by all the handlers.
+    ///
+    /// The password state is updated only if the user is not changed since it is read, and read
+    /// again otherwise, so the concurrent failed logins are all counted. The user read last is
+    /// returned, it has the updated password state.
+    pub async fn verify_password(
+        &self,
+        tenant: &str,
+        user: UserInfo,
+        verified: bool,
+    ) -> Result<UserInfo> {
+        let now = PasswordPolicy::now();
+        let policy = &self.password_policy;
+        let identity = user.identity();
+
+        loop {
+            let SeqV {
+                seq,
+                data: mut current,
+                ..
+            } = self
+                .users
+                .get_user_with_seq(tenant, identity.clone())
+                .await?;
+            let state = current.option.password_state_mut();
+            policy.check_locked(state, now)?;
+
+            let changed = match verified {
+                true => policy.login_succeeded(state),
+                false => policy.login_failed(state, now),
+            };
+            if changed {
+                let update = self.users.update_user_option_with_seq(
+                    tenant,
+                    identity.clone(),
+                    current.option.clone(),
+                    seq,
+                );
+                match update.await {
+                    Ok(_) => {}
+                    // Changed concurrently, e.g. by another login, apply it to the new state.
+                    Err(e) if e.code() == ErrorCode::user_seq_mismatch_code() => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+
+            return match verified {
+                true => Ok(current),
+                false => Err(ErrorCode::AuthenticateFailure("wrong password")),
+            };
+        }
+    }
+
+    /// Check the client address with the global network policy and the one of the user, the
+    /// unknown policies and addresses are denied.
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        client_addr: Option<&str>,
+    ) -> Result<()> {
+        let policies = self
+            .network_policy
+            .iter()
+            .chain(user.option.network_policy());
+        for name in policies {
+            let client_ip = client_addr.and_then(|addr| {
+                addr.parse::<SocketAddr>()
+                    .map(|addr| addr.ip())
+                    .or_else(|_| addr.parse::<IpAddr>())
+                    .ok()
+            });
+            let policy = self.users.get_network_policy(tenant, name).await?;
+            match client_ip {
+                Some(ip) if policy.is_allowed(&ip) => {}
+                _ => {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "client address {} is not allowed by the network policy {}",
+                        client_addr.unwrap_or("unknown"),
+                        name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn no_auth(&self, tenant: &str) -> Result<UserInfo> {
+        self.check_default_tenant(tenant, "the request without credential")?;
         self.users
-            .get_user(&self.tenant, UserIdentity::new("root", "127.0.0.1"))
+            .get_user(tenant, UserIdentity::new("root", "127.0.0.1"))
             .await
     }
 
-    pub async fn auth(&self, credential: &Credential) -> Result<UserInfo> {
+    pub async fn auth(&self, tenant: &str, credential: &Credential) -> Result<UserInfo> {
         match credential {
             Credential::Jwt { token: t } => {
-                let user_name = match &self.jwt {
-                    Some(j) => j.get_user(t.as_str())?,
+                let identity = match &self.jwt {
+                    Some(j) => j.get_identity(t.as_str()).await?,
                     None => return Err(ErrorCode::AuthenticateFailure("jwt auth not configured.")),
                 };
-                self.users
-                    .get_user(&self.tenant, UserIdentity::new(&user_name, "%"))
-                    .await
+                // The token is only valid in the tenant it is issued for, see `jwt_tenant_claim`.
+                match &identity.tenant {
+                    Some(claimed) if claimed == tenant => {}
+                    Some(claimed) => {
+                        return Err(ErrorCode::AuthenticateFailure(format!(
+                            "the jwt is issued for the tenant {}, not {}",
+                            claimed, tenant
+                        )));
+                    }
+                    None => self.check_default_tenant(tenant, "the jwt without tenant")?,
+                }
+                let mut user = self
+                    .users
+                    .get_user(tenant, UserIdentity::new(&identity.user, "%"))
+                    .await?;
+                if user.auth_info != AuthInfo::JWT {
+                    return Err(ErrorCode::AuthenticateFailure("wrong auth type"));
+                }
+                // The role of the token is only granted to the session, it is not persisted.
+                if let Some(role) = identity.role {
+                    user.grants.grant_role(role);
+                }
+                Ok(user)
             }
             Credential::Password {
                 name: n,
                 password: p,
                 hostname: h,
             } => {
-                let user = self
+                let mut user = self
                     .users
-                    .get_user_with_client_ip(
-                        &self.tenant,
-                        n,
-                        h.as_ref().unwrap_or(&"%".to_string()),
-                    )
+                    .get_user_with_client_ip(tenant, n, h.as_ref().unwrap_or(&"%".to_string()))
                     .await?;
+                if BUILTIN_USERS.contains(&n.as_str()) {
+                    self.check_default_tenant(tenant, &format!("the built-in user {}", n))?;
+                }
                 match &user.auth_info {
                     AuthInfo::None => Ok(user),
                     AuthInfo::Password {
@@ -88,11 +218,22 @@ impl AuthMgr {
                     } => match p {
                         None => Err(ErrorCode::AuthenticateFailure("password required")),
                         Some(p) => {
-                            if *h == t.hash(p) {
-                                Ok(user)
-                            } else {
-                                Err(ErrorCode::AuthenticateFailure("wrong password"))
+                            let verified = *h == t.hash(p);
+                            self.verify_password(tenant, user, verified).await
+                        }
+                    },
+                    AuthInfo::Ldap => match (&self.ldap, p) {
+                        (None, _) => {
+                            Err(ErrorCode::AuthenticateFailure("ldap auth not configured."))
+                        }
+                        (_, None) => Err(ErrorCode::AuthenticateFailure("password required")),
+                        (Some(ldap), Some(p)) => {
+                            // The roles mapped from the LDAP groups are only granted to the
+                            // session, they are not persisted.
+                            for role in ldap.authenticate(n, p).await? {
+                                user.grants.grant_role(role);
                             }
+                            Ok(user)
                         }
                     },
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
@@ -100,4 +241,14 @@ impl AuthMgr {
             }
         }
     }
+
+    fn check_default_tenant(&self, tenant: &str, what: &str) -> Result<()> {
+        match tenant == self.tenant {
+            true => Ok(()),
+            false => Err(ErrorCode::AuthenticateFailure(format!(
+                "{} is only allowed in the tenant {}, not {}",
+                what, self.tenant, tenant
+            ))),
+        }
+    }
 }
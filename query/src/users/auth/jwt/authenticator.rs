@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use jwt_simple::algorithms::RS256PublicKey;
 use jwt_simple::algorithms::RSAPublicKeyLike;
+use jwt_simple::token::Token;
 
 use crate::configs::Config;
 use crate::users::auth::jwt::jwk;
@@ -30,6 +31,16 @@ pub enum PubKey {
 pub struct JwtAuthenticator {
     //Todo(youngsofun): verify settings, like issuer
     key_store: jwk::JwkKeyStore,
+    user_claim: String,
+    role_claim: String,
+    tenant_claim: String,
+}
+
+/// The user and the role the JWT is issued to, and the tenant it is issued for.
+pub struct JwtIdentity {
+    pub user: String,
+    pub role: Option<String>,
+    pub tenant: Option<String>,
 }
 
 // to use user specified (in config) fields
@@ -41,21 +52,49 @@ impl JwtAuthenticator {
             return Ok(None);
         }
         let key_store = jwk::JwkKeyStore::new(cfg.query.jwt_key_file).await?;
-        Ok(Some(JwtAuthenticator { key_store }))
+        Ok(Some(JwtAuthenticator {
+            key_store,
+            user_claim: cfg.query.jwt_user_claim,
+            role_claim: cfg.query.jwt_role_claim,
+            tenant_claim: cfg.query.jwt_tenant_claim,
+        }))
     }
 
-    pub fn get_user(&self, token: &str) -> Result<String> {
-        let pub_key = self.key_store.get_key(None)?;
-        match &pub_key {
-            PubKey::RSA256(pk) => match pk.verify_token::<CustomClaims>(token, None) {
-                Ok(c) => match c.subject {
-                    None => Err(ErrorCode::AuthenticateFailure(
-                        "missing  field `subject` in jwt",
-                    )),
-                    Some(subject) => Ok(subject),
-                },
-                Err(err) => Err(ErrorCode::AuthenticateFailure(err.to_string())),
-            },
-        }
+    pub async fn get_identity(&self, token: &str) -> Result<JwtIdentity> {
+        let metadata = Token::decode_metadata(token)
+            .map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
+        let pub_key = self
+            .key_store
+            .get_key(metadata.key_id().map(str::to_string))
+            .await?;
+        let claims = match &pub_key {
+            PubKey::RSA256(pk) => pk
+                .verify_token::<CustomClaims>(token, None)
+                .map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?,
+        };
+
+        let user = match self.user_claim.as_str() {
+            "sub" => claims.subject.clone(),
+            claim => Self::string_claim(&claims.custom, claim),
+        };
+        let user = user.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(format!("missing field `{}` in jwt", self.user_claim))
+        })?;
+        let role = match self.role_claim.is_empty() {
+            true => None,
+            false => Self::string_claim(&claims.custom, &self.role_claim),
+        };
+        let tenant = match self.tenant_claim.is_empty() {
+            true => None,
+            false => Self::string_claim(&claims.custom, &self.tenant_claim),
+        };
+        Ok(JwtIdentity { user, role, tenant })
+    }
+
+    fn string_claim(claims: &CustomClaims, claim: &str) -> Option<String> {
+        claims
+            .get(claim)
+            .and_then(|value| value.as_str())
+            .map(str::to_string)
     }
 }
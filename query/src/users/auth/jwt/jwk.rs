@@ -15,12 +15,14 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use base64::decode_config;
 use base64::URL_SAFE_NO_PAD;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_tracing::tracing;
 use jwt_simple::prelude::RS256PublicKey;
 use serde::Deserialize;
 use serde::Serialize;
@@ -28,6 +30,8 @@ use serde::Serialize;
 use crate::users::auth::jwt::PubKey;
 
 const JWK_REFRESH_INTERVAL: u64 = 15;
+// The keys are reloaded for an unknown key id at most once in this interval (in seconds).
+const JWK_MISS_REFRESH_INTERVAL: u64 = 10;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JwkKey {
@@ -69,17 +73,19 @@ pub struct JwkKeys {
 pub struct JwkKeyStore {
     url: String,
     keys: Arc<RwLock<HashMap<String, PubKey>>>,
-    _refresh_interval: Duration,
+    refresh_interval: Duration,
+    last_refreshed: RwLock<Instant>,
 }
 
 impl JwkKeyStore {
     pub async fn new(url: String) -> Result<Self> {
-        let _refresh_interval = Duration::from_secs(JWK_REFRESH_INTERVAL * 60);
+        let refresh_interval = Duration::from_secs(JWK_REFRESH_INTERVAL * 60);
         let keys = Arc::new(RwLock::new(HashMap::new()));
-        let mut s = JwkKeyStore {
+        let s = JwkKeyStore {
             url,
             keys,
-            _refresh_interval,
+            refresh_interval,
+            last_refreshed: RwLock::new(Instant::now()),
         };
         s.load_keys().await?;
         Ok(s)
@@ -87,11 +93,13 @@ impl JwkKeyStore {
 }
 
 impl JwkKeyStore {
-    pub async fn load_keys(&mut self) -> Result<()> {
+    pub async fn load_keys(&self) -> Result<()> {
         let response = reqwest::get(&self.url).await.map_err(|e| {
             ErrorCode::NetworkRequestError(format!("Could not download JWKS: {}", e))
         })?;
-        let body = response.text().await.unwrap();
+        let body = response.text().await.map_err(|e| {
+            ErrorCode::NetworkRequestError(format!("Could not download JWKS: {}", e))
+        })?;
         let jwk_keys = serde_json::from_str::<JwkKeys>(&body)
             .map_err(|e| ErrorCode::InvalidConfig(format!("Failed to parse keys: {}", e)))?;
         let mut new_keys: HashMap<String, PubKey> = HashMap::new();
@@ -100,10 +108,30 @@ impl JwkKeyStore {
         }
         let mut keys = self.keys.write();
         *keys = new_keys;
+        *self.last_refreshed.write() = Instant::now();
         Ok(())
     }
 
-    pub(super) fn get_key(&self, key_id: Option<String>) -> Result<PubKey> {
+    // The keys are reloaded periodically, and when the token is signed by an unknown key, so
+    // the keys rotated by the identity provider are picked up without restarting.
+    async fn maybe_refresh(&self, key_id: &Option<String>) {
+        let elapsed = self.last_refreshed.read().elapsed();
+        let unknown_key = match key_id {
+            Some(kid) => !self.keys.read().contains_key(kid),
+            None => false,
+        };
+        if elapsed > self.refresh_interval
+            || (unknown_key && elapsed > Duration::from_secs(JWK_MISS_REFRESH_INTERVAL))
+        {
+            if let Err(cause) = self.load_keys().await {
+                tracing::warn!("Cannot refresh the JWKS from {}: {}", self.url, cause);
+            }
+        }
+    }
+
+    pub(super) async fn get_key(&self, key_id: Option<String>) -> Result<PubKey> {
+        self.maybe_refresh(&key_id).await;
+
         let keys = self.keys.read();
         match key_id {
             Some(kid) => match keys.get(&kid) {
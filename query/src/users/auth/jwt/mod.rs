@@ -16,4 +16,5 @@ mod authenticator;
 mod jwk;
 
 pub use authenticator::JwtAuthenticator;
+pub use authenticator::JwtIdentity;
 pub use authenticator::PubKey;
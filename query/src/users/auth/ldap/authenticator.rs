@@ -0,0 +1,181 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_tracing::tracing;
+use ldap3::dn_escape;
+use ldap3::ldap_escape;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+
+use crate::configs::Config;
+
+/// Authenticate the users `IDENTIFIED WITH ldap` by binding to the LDAP server with their
+/// passwords, and map the LDAP groups of the users to the roles.
+pub struct LdapAuthenticator {
+    url: String,
+    bind_dn_template: String,
+    group_search_base: String,
+    group_filter: String,
+    // The LDAP group name (cn) -> the role granted to its members.
+    group_roles: HashMap<String, String>,
+    pool_size: usize,
+    pool: Mutex<Vec<Ldap>>,
+}
+
+impl LdapAuthenticator {
+    pub fn try_create(cfg: Config) -> Result<Option<Self>> {
+        if cfg.query.ldap_url.is_empty() {
+            return Ok(None);
+        }
+        if !cfg.query.ldap_bind_dn_template.contains("{user}") {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "ldap_bind_dn_template must contain {{user}}, but got '{}'",
+                cfg.query.ldap_bind_dn_template
+            )));
+        }
+
+        Ok(Some(LdapAuthenticator {
+            url: cfg.query.ldap_url,
+            bind_dn_template: cfg.query.ldap_bind_dn_template,
+            group_search_base: cfg.query.ldap_group_search_base,
+            group_filter: cfg.query.ldap_group_filter,
+            group_roles: Self::parse_group_roles(&cfg.query.ldap_group_role_mapping)?,
+            pool_size: cfg.query.ldap_pool_size as usize,
+            pool: Mutex::new(vec![]),
+        }))
+    }
+
+    // Parse the mapping like `analysts:analyst,dbas:admin`.
+    fn parse_group_roles(mapping: &str) -> Result<HashMap<String, String>> {
+        let mut group_roles = HashMap::new();
+        for item in mapping.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match item.split_once(':') {
+                Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                    group_roles.insert(group.trim().to_string(), role.trim().to_string());
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "Invalid ldap_group_role_mapping '{}', expect <group>:<role>",
+                        item
+                    )));
+                }
+            }
+        }
+        Ok(group_roles)
+    }
+
+    /// Check the password of the user, returns the roles mapped from the groups of the user.
+    pub async fn authenticate(&self, user: &str, password: &[u8]) -> Result<Vec<String>> {
+        // An empty password is an unauthenticated bind for most of the LDAP servers, it
+        // always succeeds.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure("password required"));
+        }
+        let password = std::str::from_utf8(password)
+            .map_err(|_| ErrorCode::AuthenticateFailure("wrong password"))?;
+        let dn = self.bind_dn_template.replace("{user}", &dn_escape(user));
+
+        let pooled_ldap = self.pool.lock().pop();
+        let (mut ldap, pooled) = match pooled_ldap {
+            Some(ldap) => (ldap, true),
+            None => (self.connect().await?, false),
+        };
+
+        let mut roles = self.bind_and_search(&mut ldap, user, &dn, password).await;
+        if pooled {
+            // The pooled connection may be closed by the server, retry with a new one.
+            if let Err(cause) = &roles {
+                if !matches!(cause, LdapError::LdapResult { .. }) {
+                    tracing::debug!("Reconnect to the LDAP server, cause: {}", cause);
+                    ldap = self.connect().await?;
+                    roles = self.bind_and_search(&mut ldap, user, &dn, password).await;
+                }
+            }
+        }
+
+        match roles {
+            Ok(roles) => {
+                let mut pool = self.pool.lock();
+                if pool.len() < self.pool_size {
+                    pool.push(ldap);
+                }
+                Ok(roles)
+            }
+            Err(LdapError::LdapResult { result }) => {
+                tracing::debug!("LDAP authentication of {} failed: {}", user, result);
+                Err(ErrorCode::AuthenticateFailure("wrong password"))
+            }
+            Err(cause) => Err(ErrorCode::AuthenticateFailure(format!(
+                "Cannot authenticate with the LDAP server: {}",
+                cause
+            ))),
+        }
+    }
+
+    async fn connect(&self) -> Result<Ldap> {
+        let (conn, ldap) = LdapConnAsync::new(&self.url).await.map_err(|cause| {
+            ErrorCode::AuthenticateFailure(format!(
+                "Cannot connect to the LDAP server {}: {}",
+                self.url, cause
+            ))
+        })?;
+        common_base::tokio::spawn(async move {
+            if let Err(cause) = conn.drive().await {
+                tracing::warn!("LDAP connection error: {}", cause);
+            }
+        });
+        Ok(ldap)
+    }
+
+    async fn bind_and_search(
+        &self,
+        ldap: &mut Ldap,
+        user: &str,
+        dn: &str,
+        password: &str,
+    ) -> std::result::Result<Vec<String>, LdapError> {
+        ldap.simple_bind(dn, password).await?.success()?;
+        if self.group_search_base.is_empty() || self.group_roles.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let filter = self
+            .group_filter
+            .replace("{dn}", &ldap_escape(dn))
+            .replace("{user}", &ldap_escape(user));
+        let (entries, _) = ldap
+            .search(&self.group_search_base, Scope::Subtree, &filter, vec!["cn"])
+            .await?
+            .success()?;
+
+        let mut roles = vec![];
+        for entry in entries {
+            let entry = SearchEntry::construct(entry);
+            for group in entry.attrs.get("cn").into_iter().flatten() {
+                if let Some(role) = self.group_roles.get(group) {
+                    roles.push(role.clone());
+                }
+            }
+        }
+        Ok(roles)
+    }
+}
@@ -14,3 +14,5 @@
 
 pub(crate) mod auth_mgr;
 mod jwt;
+mod ldap;
+pub mod password_policy;
@@ -0,0 +1,162 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordState;
+use common_meta_types::UserInfo;
+
+use crate::configs::Config;
+
+/// The password policies of the native users: the complexity and the reuse of the new passwords,
+/// the expiration of the passwords and the lockout after too many failed logins.
+#[derive(Clone, Debug)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    require_mixed_case: bool,
+    require_digit: bool,
+    require_special_char: bool,
+    expire_secs: u64,
+    history: usize,
+    max_failed_attempts: u64,
+    lockout_secs: u64,
+}
+
+impl PasswordPolicy {
+    pub fn create(cfg: &Config) -> Self {
+        PasswordPolicy {
+            min_length: cfg.query.password_min_length as usize,
+            require_mixed_case: cfg.query.password_require_mixed_case,
+            require_digit: cfg.query.password_require_digit,
+            require_special_char: cfg.query.password_require_special_char,
+            expire_secs: cfg.query.password_expire_days * 24 * 60 * 60,
+            history: cfg.query.password_history as usize,
+            max_failed_attempts: cfg.query.password_max_failed_attempts,
+            lockout_secs: cfg.query.password_lockout_minutes * 60,
+        }
+    }
+
+    pub fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+    }
+
+    pub fn check_complexity(&self, password: &str) -> Result<()> {
+        let violation = |requirement: &str| {
+            Err(ErrorCode::PasswordPolicyViolation(format!(
+                "The password must {}",
+                requirement
+            )))
+        };
+
+        if password.chars().count() < self.min_length {
+            return violation(&format!("have at least {} characters", self.min_length));
+        }
+        if self.require_mixed_case
+            && !(password.chars().any(char::is_lowercase)
+                && password.chars().any(char::is_uppercase))
+        {
+            return violation("have both the lowercase and the uppercase letters");
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            return violation("have a digit");
+        }
+        if self.require_special_char && password.chars().all(char::is_alphanumeric) {
+            return violation("have a special character");
+        }
+        Ok(())
+    }
+
+    /// Check the new password is not the current one or one of the recent ones of the user.
+    pub fn check_reuse(&self, user: &UserInfo, auth_info: &AuthInfo) -> Result<()> {
+        if self.history == 0 {
+            return Ok(());
+        }
+
+        let history = &user.option.password_state().history;
+        let recent = std::iter::once(&user.auth_info).chain(history.iter().take(self.history - 1));
+        for previous in recent {
+            if previous == auth_info {
+                return Err(ErrorCode::PasswordPolicyViolation(format!(
+                    "The password cannot be one of the last {} passwords",
+                    self.history
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the change of the password, the old one is kept in the history.
+    pub fn password_changed(&self, state: &mut PasswordState, old_auth_info: AuthInfo, now: u64) {
+        if self.history > 1 {
+            state.history.insert(0, old_auth_info);
+        }
+        state.history.truncate(self.history.saturating_sub(1));
+        state.changed_at = Some(now);
+    }
+
+    /// The user must change the password before executing the other statements, if the password
+    /// is expired or it is required by `ALTER USER ... WITH MUST_CHANGE_PASSWORD`.
+    pub fn must_change_password(&self, user: &UserInfo, now: u64) -> bool {
+        let state = user.option.password_state();
+        if state.must_change {
+            return true;
+        }
+        match state.changed_at {
+            Some(changed_at) if self.expire_secs > 0 => now >= changed_at + self.expire_secs,
+            _ => false,
+        }
+    }
+
+    pub fn check_locked(&self, state: &PasswordState, now: u64) -> Result<()> {
+        match state.locked_until {
+            Some(locked_until) if now < locked_until => Err(ErrorCode::AuthenticateFailure(
+                format!("user is locked for {} seconds", locked_until - now),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Count the failed login and lock the user if the max attempts is reached, returns whether
+    /// the state is changed.
+    pub fn login_failed(&self, state: &mut PasswordState, now: u64) -> bool {
+        if self.max_failed_attempts == 0 {
+            return false;
+        }
+
+        state.failed_attempts += 1;
+        if state.failed_attempts >= self.max_failed_attempts {
+            state.failed_attempts = 0;
+            state.locked_until = Some(now + self.lockout_secs);
+        }
+        true
+    }
+
+    /// Reset the failed logins, returns whether the state is changed.
+    pub fn login_succeeded(&self, state: &mut PasswordState) -> bool {
+        if state.failed_attempts == 0 && state.locked_until.is_none() {
+            return false;
+        }
+
+        state.failed_attempts = 0;
+        state.locked_until = None;
+        true
+    }
+}
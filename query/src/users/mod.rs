@@ -15,7 +15,11 @@
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_copy_history;
+mod user_masking_policy;
 mod user_mgr;
+mod user_network_policy;
+mod user_row_access_policy;
 mod user_stage;
 mod user_udf;
 
@@ -24,6 +28,7 @@ pub mod role_cache_mgr;
 mod user_setting;
 mod user_warehouse;
 
+pub use auth::password_policy::PasswordPolicy;
 pub use role_cache_mgr::RoleCacheMgr;
 pub use user::CertifiedInfo;
 pub use user::User;
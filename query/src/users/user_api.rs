@@ -15,8 +15,16 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_management::CopyHistoryApi;
+use common_management::CopyHistoryMgr;
+use common_management::MaskingPolicyApi;
+use common_management::MaskingPolicyMgr;
+use common_management::NetworkPolicyApi;
+use common_management::NetworkPolicyMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
@@ -61,6 +69,30 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_masking_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn MaskingPolicyApi>> {
+        Ok(Arc::new(MaskingPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_network_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn NetworkPolicyApi>> {
+        Ok(Arc::new(NetworkPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_setting_api_client(&self, tenant: &str) -> Result<Arc<dyn SettingApi>> {
         Ok(Arc::new(SettingMgr::create(self.client.clone(), tenant)?))
     }
@@ -68,4 +100,11 @@ impl UserApiProvider {
     pub fn get_warehouse_api_client(&self, tenant: &str) -> Result<Arc<dyn WarehouseApi>> {
         Ok(Arc::new(WarehouseMgr::create(self.client.clone(), tenant)?))
     }
+
+    pub fn get_copy_history_api_client(&self, tenant: &str) -> Result<Arc<dyn CopyHistoryApi>> {
+        Ok(Arc::new(CopyHistoryMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
 }
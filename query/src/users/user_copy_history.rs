@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+
+use crate::users::UserApiProvider;
+
+/// copy history operations.
+impl UserApiProvider {
+    // Add the files loaded into a table, they are expired after expire_secs.
+    pub async fn add_copied_files(
+        &self,
+        tenant: &str,
+        table_id: u64,
+        files: Vec<CopiedFileInfo>,
+        expire_secs: u64,
+    ) -> Result<()> {
+        if files.is_empty() {
+            return Ok(());
+        }
+
+        let copy_history_api_provider = self.get_copy_history_api_client(tenant)?;
+        let add_copied_files =
+            copy_history_api_provider.add_copied_files(table_id, files, expire_secs);
+        match add_copied_files.await {
+            Err(e) => Err(e.add_message_back("(while add copied files).")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    // Get the files loaded into a table.
+    pub async fn get_copied_files(
+        &self,
+        tenant: &str,
+        table_id: u64,
+    ) -> Result<Vec<CopiedFileInfo>> {
+        let copy_history_api_provider = self.get_copy_history_api_client(tenant)?;
+        let get_copied_files = copy_history_api_provider.get_copied_files(table_id);
+        match get_copied_files.await {
+            Err(e) => Err(e.add_message_back("(while get copied files).")),
+            Ok(files) => Ok(files),
+        }
+    }
+
+    // Remove the files loaded into a table, they can be loaded again.
+    pub async fn remove_copied_files(&self, tenant: &str, table_id: u64) -> Result<()> {
+        let copy_history_api_provider = self.get_copy_history_api_client(tenant)?;
+        let remove_copied_files = copy_history_api_provider.remove_copied_files(table_id);
+        match remove_copied_files.await {
+            Err(e) => Err(e.add_message_back("(while remove copied files).")),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
 use common_meta_types::GrantObject;
+use common_meta_types::SeqV;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use common_meta_types::UserOption;
@@ -48,6 +49,16 @@ impl UserApiProvider {
         }
     }
 
+    // Get one user with its seq, to update it only if it is not changed since.
+    pub async fn get_user_with_seq(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+    ) -> Result<SeqV<UserInfo>> {
+        let client = self.get_user_api_client(tenant)?;
+        client.get_user(user, None).await
+    }
+
     /// find the matched user with the client ip address, like 'u1'@'127.0.0.1', if the specific
     /// user@host is not found, try 'u1'@'%'.
     pub async fn get_user_with_client_ip(
@@ -200,4 +211,21 @@ impl UserApiProvider {
             Err(e) => Err(e.add_message_back("(while alter user).")),
         }
     }
+
+    // Update the option of a user if its seq is still the given one, it fails with
+    // `UserSeqMismatch` if the user is changed since.
+    pub async fn update_user_option_with_seq(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        user_option: UserOption,
+        seq: u64,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client(tenant)?;
+        let update_user = client.update_user(user, None, Some(user_option), Some(seq));
+        match update_user.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update user option).")),
+        }
+    }
 }
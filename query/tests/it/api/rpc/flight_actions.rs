@@ -43,6 +43,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
     match from_action {
         FlightAction::CancelAction(_) => panic!(),
         FlightAction::BroadcastAction(_) => panic!(),
+        FlightAction::StageProgressAction(_) => panic!(),
         FlightAction::PrepareShuffleAction(action) => {
             assert_eq!(action.query_id, "query_id");
             assert_eq!(action.stage_id, "stage_id");
@@ -26,6 +26,7 @@ use common_exception::Result;
 use common_grpc::ConnectionFactory;
 use common_grpc::GrpcConnectionError;
 use common_grpc::RpcClientTlsConfig;
+use common_grpc::TLS_RELOAD_INTERVAL;
 use databend_query::api::DatabendQueryFlightDispatcher;
 use databend_query::api::RpcService;
 use databend_query::servers::Server;
@@ -35,6 +36,11 @@ use crate::tests::tls_constants::TEST_CA_CERT;
 use crate::tests::tls_constants::TEST_CN_NAME;
 use crate::tests::tls_constants::TEST_SERVER_CERT;
 use crate::tests::tls_constants::TEST_SERVER_KEY;
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_KEY;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -54,6 +60,7 @@ async fn test_tls_rpc_server() -> Result<()> {
     let tls_conf = Some(RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: TEST_CA_CERT.to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     });
 
     // normal case
@@ -72,6 +79,94 @@ async fn test_tls_rpc_server() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tls_rpc_server_mutual_tls() -> Result<()> {
+    let mut rpc_service = RpcService {
+        abort_notify: Arc::new(Notify::new()),
+        dispatcher: Arc::new(DatabendQueryFlightDispatcher::create()),
+        sessions: SessionManagerBuilder::create()
+            .rpc_tls_server_key(TEST_TLS_SERVER_KEY)
+            .rpc_tls_server_cert(TEST_TLS_SERVER_CERT)
+            .rpc_tls_server_client_ca_cert(TEST_TLS_CA_CERT)
+            .build()?,
+    };
+
+    let mut listener_address = SocketAddr::from_str("127.0.0.1:0")?;
+    listener_address = rpc_service.start(listener_address).await?;
+
+    // client with the certificate signed by the CA
+    let tls_conf = RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        rpc_tls_client_cert: TEST_TLS_CLIENT_CERT.to_string(),
+        rpc_tls_client_key: TEST_TLS_CLIENT_KEY.to_string(),
+    };
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, Some(tls_conf))?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_ok());
+
+    // client without certificate
+    let tls_conf = RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
+    };
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, Some(tls_conf))?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tls_rpc_server_reload_certs() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let cert_path = dir.path().join("server.pem");
+    let key_path = dir.path().join("server.key");
+    std::fs::copy(TEST_SERVER_CERT, &cert_path)?;
+    std::fs::copy(TEST_SERVER_KEY, &key_path)?;
+
+    let mut rpc_service = RpcService {
+        abort_notify: Arc::new(Notify::new()),
+        dispatcher: Arc::new(DatabendQueryFlightDispatcher::create()),
+        sessions: SessionManagerBuilder::create()
+            .rpc_tls_server_key(key_path.to_str().unwrap())
+            .rpc_tls_server_cert(cert_path.to_str().unwrap())
+            .build()?,
+    };
+
+    let mut listener_address = SocketAddr::from_str("127.0.0.1:0")?;
+    listener_address = rpc_service.start(listener_address).await?;
+
+    let list_actions = |ca_cert: &str| {
+        let tls_conf = RpcClientTlsConfig {
+            rpc_tls_server_root_ca_cert: ca_cert.to_string(),
+            domain_name: TEST_CN_NAME.to_string(),
+            ..Default::default()
+        };
+        let conn =
+            ConnectionFactory::create_rpc_channel(listener_address, None, Some(tls_conf)).unwrap();
+        async move {
+            FlightServiceClient::new(conn)
+                .list_actions(Empty {})
+                .await
+                .is_ok()
+        }
+    };
+    assert!(list_actions(TEST_CA_CERT).await);
+
+    // rotate the certificate of the server to the one signed by another CA
+    std::fs::copy(TEST_TLS_SERVER_CERT, &cert_path)?;
+    std::fs::copy(TEST_TLS_SERVER_KEY, &key_path)?;
+    tokio::time::sleep(TLS_RELOAD_INTERVAL * 2).await;
+    assert!(!list_actions(TEST_CA_CERT).await);
+    assert!(list_actions(TEST_TLS_CA_CERT).await);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tls_rpc_server_invalid_server_config() -> Result<()> {
     // setup, invalid cert locations
@@ -98,6 +193,7 @@ async fn test_tls_rpc_server_invalid_client_config() -> Result<()> {
     let client_conf = RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: "../tests/data/certs/nowhere.pem".to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     };
 
     let r = ConnectionFactory::create_rpc_channel("fake:1234", None, Some(client_conf));
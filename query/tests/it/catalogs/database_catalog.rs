@@ -170,6 +170,7 @@ async fn test_catalogs_table() -> Result<()> {
             tenant: tenant.to_string(),
             db: "default".to_string(),
             table: "test_table".to_string(),
+            purge: false,
         };
         let res = catalog.drop_table(req.clone()).await;
         assert!(res.is_ok());
@@ -31,6 +31,24 @@ async fn test_single_cluster_discovery() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_report_unreachable_node() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let cluster_discovery = ClusterDiscovery::create_global(conf.clone()).await?;
+    cluster_discovery.register_to_metastore(&conf).await?;
+
+    // The local node is never excluded from the cluster.
+    let local_id = cluster_discovery.discover().await?.local_id();
+    cluster_discovery.report_unreachable(&local_id);
+    cluster_discovery.report_unreachable("unknown_node");
+
+    let discover_cluster = cluster_discovery.discover().await?;
+    let discover_cluster_nodes = discover_cluster.get_nodes();
+    assert_eq!(discover_cluster_nodes.len(), 1);
+    assert!(discover_cluster.is_local(&discover_cluster_nodes[0]));
+    Ok(())
+}
+
 // TODO:(Winter) need KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
@@ -42,8 +42,18 @@ num_cpus = 0
 mysql_handler_host = \"127.0.0.1\"
 mysql_handler_port = 3307
 max_active_sessions = 256
+max_running_queries = 0
+max_running_queries_per_user = 0
+query_queue_timeout_secs = 60
+session_idle_timeout_secs = 28800
 clickhouse_handler_host = \"127.0.0.1\"
 clickhouse_handler_port = 9000
+postgres_handler_host = \"127.0.0.1\"
+postgres_handler_port = 5433
+flight_sql_handler_host = \"127.0.0.1\"
+flight_sql_handler_port = 8900
+clickhouse_http_handler_host = \"127.0.0.1\"
+clickhouse_http_handler_port = 8124
 http_handler_host = \"127.0.0.1\"
 http_handler_port = 8000
 http_handler_result_timeout_millis = 10000
@@ -53,19 +63,27 @@ metric_api_address = \"127.0.0.1:7070\"
 http_handler_tls_server_cert = \"\"
 http_handler_tls_server_key = \"\"
 http_handler_tls_server_root_ca_cert = \"\"
+mysql_handler_tls_server_cert = \"\"
+mysql_handler_tls_server_key = \"\"
+mysql_handler_tls_server_root_ca_cert = \"\"
 api_tls_server_cert = \"\"
 api_tls_server_key = \"\"
 api_tls_server_root_ca_cert = \"\"
 rpc_tls_server_cert = \"\"
 rpc_tls_server_key = \"\"
+rpc_tls_server_client_ca_cert = \"\"
 rpc_tls_query_server_root_ca_cert = \"\"
 rpc_tls_query_service_domain_name = \"localhost\"
+rpc_tls_query_client_cert = \"\"
+rpc_tls_query_client_key = \"\"
 table_engine_csv_enabled = false
 table_engine_parquet_enabled = false
 table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+query_result_cache_max_bytes = 1073741824
+udf_server_allow_list = \"\"
 table_cache_enabled = false
 table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
@@ -75,11 +93,34 @@ table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
 management_mode = false
 jwt_key_file = \"\"
+jwt_user_claim = \"sub\"
+jwt_role_claim = \"\"
+jwt_tenant_claim = \"\"
+ldap_url = \"\"
+ldap_bind_dn_template = \"\"
+ldap_group_search_base = \"\"
+ldap_group_filter = \"(member={dn})\"
+ldap_group_role_mapping = \"\"
+ldap_pool_size = 8
+password_min_length = 0
+password_require_mixed_case = false
+password_require_digit = false
+password_require_special_char = false
+password_expire_days = 0
+password_history = 0
+password_max_failed_attempts = 0
+password_lockout_minutes = 10
+network_policy = \"\"
+cluster_lease_secs = 60
 
 [log]
 log_level = \"INFO\"
 log_dir = \"./_logs\"
 log_query_enabled = false
+log_query_retention_hours = 0
+log_audit_sinks = \"\"
+log_audit_max_rows = 100000
+log_tracing_otlp_endpoint = \"\"
 
 [meta]
 meta_embedded_dir = \"./_meta_embedded\"
@@ -89,6 +130,8 @@ meta_password = \"\"
 meta_client_timeout_in_second = 10
 rpc_tls_meta_server_root_ca_cert = \"\"
 rpc_tls_meta_service_domain_name = \"localhost\"
+rpc_tls_meta_client_cert = \"\"
+rpc_tls_meta_client_key = \"\"
 
 [storage]
 storage_type = \"fs\"
@@ -128,6 +171,12 @@ fn test_env_config() -> Result<()> {
     std::env::set_var("QUERY_MAX_ACTIVE_SESSIONS", "255");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_HOST", "1.2.3.4");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_PORT", "9000");
+    std::env::set_var("QUERY_POSTGRES_HANDLER_HOST", "1.2.3.4");
+    std::env::set_var("QUERY_POSTGRES_HANDLER_PORT", "5432");
+    std::env::set_var("QUERY_FLIGHT_SQL_HANDLER_HOST", "1.2.3.4");
+    std::env::set_var("QUERY_FLIGHT_SQL_HANDLER_PORT", "8901");
+    std::env::set_var("QUERY_CLICKHOUSE_HTTP_HANDLER_HOST", "1.2.3.4");
+    std::env::set_var("QUERY_CLICKHOUSE_HTTP_HANDLER_PORT", "8125");
     std::env::set_var("QUERY_FLIGHT_API_ADDRESS", "1.2.3.4:9091");
     std::env::set_var("QUERY_ADMIN_API_ADDRESS", "1.2.3.4:8081");
     std::env::set_var("QUERY_METRIC_API_ADDRESS", "1.2.3.4:7071");
@@ -160,6 +209,12 @@ fn test_env_config() -> Result<()> {
     assert_eq!(255, configured.query.max_active_sessions);
     assert_eq!("1.2.3.4", configured.query.clickhouse_handler_host);
     assert_eq!(9000, configured.query.clickhouse_handler_port);
+    assert_eq!("1.2.3.4", configured.query.postgres_handler_host);
+    assert_eq!(5432, configured.query.postgres_handler_port);
+    assert_eq!("1.2.3.4", configured.query.flight_sql_handler_host);
+    assert_eq!(8901, configured.query.flight_sql_handler_port);
+    assert_eq!("1.2.3.4", configured.query.clickhouse_http_handler_host);
+    assert_eq!(8125, configured.query.clickhouse_http_handler_port);
 
     assert_eq!("1.2.3.4:9091", configured.query.flight_api_address);
     assert_eq!("1.2.3.4:8081", configured.query.admin_api_address);
@@ -196,6 +251,12 @@ fn test_env_config() -> Result<()> {
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_HOST");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_PORT");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_THREAD_NUM");
+    std::env::remove_var("QUERY_POSTGRES_HANDLER_HOST");
+    std::env::remove_var("QUERY_POSTGRES_HANDLER_PORT");
+    std::env::remove_var("QUERY_FLIGHT_SQL_HANDLER_HOST");
+    std::env::remove_var("QUERY_FLIGHT_SQL_HANDLER_PORT");
+    std::env::remove_var("QUERY_CLICKHOUSE_HTTP_HANDLER_HOST");
+    std::env::remove_var("QUERY_CLICKHOUSE_HTTP_HANDLER_PORT");
     std::env::remove_var("QUERY_FLIGHT_API_ADDRESS");
     std::env::remove_var("QUERY_ADMIN_API_ADDRESS");
     std::env::remove_var("QUERY_METRIC_API_ADDRESS");
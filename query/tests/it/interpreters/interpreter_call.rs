@@ -19,6 +19,7 @@ use common_meta_types::GrantObject;
 use common_meta_types::UserGrantSet;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserOptionFlag;
+use databend_query::catalogs::Catalog;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
 use pretty_assertions::assert_eq;
@@ -183,6 +184,10 @@ async fn test_call_bootstrap_tenant_interpreter() -> Result<()> {
             GrantObject::Global.available_privileges(),
         );
         assert_eq!(role_info.grants, grants);
+
+        // The default database of the tenant.
+        let catalog = ctx.get_catalog();
+        catalog.get_database("tenant1", "default").await?;
     }
 
     // Call again
@@ -52,3 +52,24 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_pipeline_graph_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let query = "EXPLAIN PIPELINE GRAPH SELECT number FROM numbers_mt(10) WHERE number > 6";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let output = common_datablocks::pretty_format_blocks(result.as_slice())?;
+
+    // The query is executed, the edges are labeled by the rows passed.
+    assert!(output.contains("digraph {"));
+    assert!(output.contains("label = \"10 rows\""));
+    assert!(output.contains("label = \"3 rows\""));
+
+    Ok(())
+}
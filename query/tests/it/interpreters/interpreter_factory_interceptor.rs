@@ -114,3 +114,175 @@ async fn test_interpreter_interceptor_for_insert() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_with_query_tag() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings()
+        .set_settings("query_tag".to_string(), "dashboard".to_string(), false)?;
+    {
+        let query = "select 1";
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        let stream = interpreter.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        interpreter.finish().await?;
+    }
+
+    // Check.
+    {
+        let query = "select log_type, query_text, query_tag from system.query_log";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+------------+-----------+",
+            "| log_type | query_text | query_tag |",
+            "+----------+------------+-----------+",
+            "| 1        | select 1   | dashboard |",
+            "| 2        | select 1   | dashboard |",
+            "+----------+------------+-----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_with_error() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    {
+        let query = "drop table t_not_exists";
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        assert!(interpreter.execute(None).await.is_err());
+        // The query has only one end record.
+        interpreter.finish().await?;
+    }
+
+    // Check.
+    {
+        let query = "select log_type, query_text from system.query_log where exception_code > 0";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+-------------------------+",
+            "| log_type | query_text              |",
+            "+----------+-------------------------+",
+            "| 3        | drop table t_not_exists |",
+            "+----------+-------------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_audit_log() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.log.log_audit_sinks = "table".to_string();
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    let queries = vec![
+        "select number from numbers_mt(10) where number > 7",
+        "insert into system.audit_log(query_id) values('forged')",
+        "create user 'audit_user'@'%' identified by 'password'",
+    ];
+    for query in queries {
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        if let Ok(stream) = interpreter.execute(None).await {
+            stream.try_collect::<Vec<_>>().await?;
+            interpreter.finish().await?;
+        }
+    }
+
+    // Check.
+    {
+        let query = "select sql_user, query_kind, query_text, objects, status, exception_code, affected_rows from system.audit_log";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+--------------------+------------+---------------------------------------------------------+------------------+---------+----------------+---------------+",
+            "| sql_user           | query_kind | query_text                                              | objects          | status  | exception_code | affected_rows |",
+            "+--------------------+------------+---------------------------------------------------------+------------------+---------+----------------+---------------+",
+            "| 'root'@'127.0.0.1' | CreateUser | create user 'audit_user'@'%' identified by '******'     |                  | Success | 0              | 0             |",
+            "| 'root'@'127.0.0.1' | InsertPlan | insert into system.audit_log(query_id) values('forged') | system.audit_log | Error   | 1002           | 0             |",
+            "| 'root'@'127.0.0.1' | SelectPlan | select number from numbers_mt(10) where number > 7      |                  | Success | 0              | 0             |",
+            "+--------------------+------------+---------------------------------------------------------+------------------+---------+----------------+---------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_slow_query_log() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+
+    // The first query is faster than the default long_query_time, 0 logs all the queries.
+    let queries = vec![
+        ("10", "select number from numbers_mt(10) where number > 8"),
+        ("0", "select number from numbers_mt(10) where number > 7"),
+    ];
+    for (long_query_time, query) in queries {
+        ctx.get_settings().set_settings(
+            "long_query_time".to_string(),
+            long_query_time.to_string(),
+            false,
+        )?;
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        let stream = interpreter.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        interpreter.finish().await?;
+    }
+
+    // Check.
+    {
+        let query =
+            "select sql_user, query_kind, query_text, exception_code from system.slow_query_log";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+------------+----------------------------------------------------+----------------+",
+            "| sql_user | query_kind | query_text                                         | exception_code |",
+            "+----------+------------+----------------------------------------------------+----------------+",
+            "| root     | SelectPlan | select number from numbers_mt(10) where number > 7 | 0              |",
+            "+----------+------------+----------------------------------------------------+----------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
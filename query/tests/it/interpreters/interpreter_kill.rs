@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_kill_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    // kill by the id of the query running in the session.
+    {
+        let running_ctx = ctx.get_current_session().create_query_context().await?;
+        let query = format!("kill query '{}'", running_ctx.get_id());
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "KillInterpreter");
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert!(result.is_empty());
+    }
+
+    // unknown id.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "kill query 'unknown'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let result = executor.execute(None).await;
+        assert!(result.is_err());
+        let expect = "Code: 1053, displayText = Not found session id unknown.";
+        assert_eq!(expect, format!("{}", result.err().unwrap()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_masking_policy_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+
+    execute(
+        &ctx,
+        "CREATE TABLE default.t(id int, email varchar) Engine = Memory",
+    )
+    .await?;
+    execute(
+        &ctx,
+        "INSERT INTO default.t VALUES (1, 'alice@x.com'), (2, 'bob@y.com'), (3, 'anna@z.com')",
+    )
+    .await?;
+
+    // Create the policy.
+    {
+        let query = "CREATE MASKING POLICY email_mask AS (val) -> concat(left(val, 1), '***') EXEMPT ROLES = ('admin')";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "CreateMaskingPolicyInterpreter");
+        executor.execute(None).await?;
+
+        let res = execute(&ctx, "CREATE MASKING POLICY email_mask AS (val) -> '***'").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::MaskingPolicyAlreadyExists("").code()
+        );
+        execute(
+            &ctx,
+            "CREATE MASKING POLICY IF NOT EXISTS email_mask AS (val) -> '***'",
+        )
+        .await?;
+    }
+
+    // Set the policy on the column.
+    {
+        let res = execute(
+            &ctx,
+            "ALTER TABLE default.t MODIFY COLUMN email SET MASKING POLICY unknown",
+        )
+        .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownMaskingPolicy("").code()
+        );
+
+        let res = execute(
+            &ctx,
+            "ALTER TABLE default.t MODIFY COLUMN unknown SET MASKING POLICY email_mask",
+        )
+        .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownColumn("").code());
+
+        let query = "ALTER TABLE default.t MODIFY COLUMN email SET MASKING POLICY email_mask";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "AlterTableMaskingPolicyInterpreter");
+        executor.execute(None).await?;
+    }
+
+    // The values are masked wherever they are read, the filter included.
+    {
+        let result = execute(&ctx, "SELECT id, email FROM default.t").await?;
+        let expected = vec![
+            "+----+-------+",
+            "| id | email |",
+            "+----+-------+",
+            "| 1  | a***  |",
+            "| 2  | b***  |",
+            "| 3  | a***  |",
+            "+----+-------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let result = execute(&ctx, "SELECT upper(email) AS e FROM default.t WHERE id = 1").await?;
+        let expected = vec!["+------+", "| e    |", "+------+", "| A*** |", "+------+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let result = execute(
+            &ctx,
+            "SELECT email FROM default.t WHERE email = 'bob@y.com'",
+        )
+        .await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+        let result = execute(&ctx, "SELECT id FROM default.t AS x WHERE x.email = 'b***'").await?;
+        let expected = vec!["+----+", "| id |", "+----+", "| 2  |", "+----+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let result = execute(
+            &ctx,
+            "SELECT email, count(*) AS c FROM default.t GROUP BY email",
+        )
+        .await?;
+        let expected = vec![
+            "+-------+---+",
+            "| email | c |",
+            "+-------+---+",
+            "| a***  | 2 |",
+            "| b***  | 1 |",
+            "+-------+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The exempt roles see the original values.
+    {
+        user_mgr
+            .add_role(&tenant, RoleInfo::new("admin".to_string()), false)
+            .await?;
+        execute(&ctx, "GRANT SELECT ON default.t TO ROLE 'admin'").await?;
+
+        let user_info = UserInfo::new_no_auth("test_user".to_string(), "%".to_string());
+        user_mgr.add_user(&tenant, user_info.clone(), false).await?;
+        user_mgr
+            .grant_role_to_user(&tenant, user_info.identity(), "admin".to_string())
+            .await?;
+        let user_info = user_mgr.get_user(&tenant, user_info.identity()).await?;
+        let root_info = ctx.get_current_user()?;
+        ctx.get_current_session().set_current_user(user_info);
+
+        let result = execute(&ctx, "SELECT email FROM default.t WHERE id = 1").await?;
+        let expected = vec![
+            "+-------------+",
+            "| email       |",
+            "+-------------+",
+            "| alice@x.com |",
+            "+-------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        ctx.get_current_session().set_current_user(root_info);
+    }
+
+    // Unset and drop the policy.
+    {
+        execute(
+            &ctx,
+            "ALTER TABLE default.t MODIFY COLUMN email UNSET MASKING POLICY",
+        )
+        .await?;
+        let result = execute(&ctx, "SELECT email FROM default.t WHERE id = 2").await?;
+        let expected = vec![
+            "+-----------+",
+            "| email     |",
+            "+-----------+",
+            "| bob@y.com |",
+            "+-----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let res = execute(
+            &ctx,
+            "ALTER TABLE default.t MODIFY COLUMN email UNSET MASKING POLICY",
+        )
+        .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownMaskingPolicy("").code()
+        );
+
+        let query = "DROP MASKING POLICY email_mask";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DropMaskingPolicyInterpreter");
+        executor.execute(None).await?;
+        execute(&ctx, "DROP MASKING POLICY IF EXISTS email_mask").await?;
+    }
+
+    Ok(())
+}
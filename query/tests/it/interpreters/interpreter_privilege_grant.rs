@@ -54,6 +54,10 @@ async fn test_grant_privilege_interpreter() -> Result<()> {
         .add_role(&tenant, RoleInfo::new("role1".to_string()), false)
         .await?;
 
+    let plan = PlanParser::parse(ctx.clone(), "CREATE TABLE default.t(a Int32, b Int32)").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+    let _ = executor.execute(None).await?;
+
     #[allow(dead_code)]
     struct Test {
         name: &'static str,
@@ -92,6 +96,38 @@ async fn test_grant_privilege_interpreter() -> Result<()> {
             }),
             expected_err: None,
         },
+        Test {
+            name: "grant select on columns for role",
+            query: "GRANT SELECT (a) ON default.t TO ROLE 'role1'".to_string(),
+            principal_identity: Some(PrincipalIdentity::role("role1".to_string())),
+            expected_grants: Some({
+                let mut grants = UserGrantSet::empty();
+                grants.grant_privileges(
+                    &GrantObject::Global,
+                    vec![UserPrivilegeType::CreateUser].into(),
+                );
+                grants.grant_privileges(
+                    &GrantObject::Column("default".to_string(), "t".to_string(), "a".to_string()),
+                    vec![UserPrivilegeType::Select].into(),
+                );
+                grants
+            }),
+            expected_err: None,
+        },
+        Test {
+            name: "grant select on unknown column and expect err",
+            query: "GRANT SELECT (c) ON default.t TO ROLE 'role1'".to_string(),
+            principal_identity: None,
+            expected_grants: None,
+            expected_err: Some("Code: 1058, displayText = column c of table default.t not exists."),
+        },
+        Test {
+            name: "grant delete on columns and expect err",
+            query: "GRANT DELETE (a) ON default.t TO ROLE 'role1'".to_string(),
+            principal_identity: None,
+            expected_grants: None,
+            expected_err: Some("Code: 1061, displayText = Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used."),
+        },
         Test {
             name: "grant create user to current database and expect err",
             query: format!("GRANT CREATE USER ON * TO '{}'@'{}'", name, hostname),
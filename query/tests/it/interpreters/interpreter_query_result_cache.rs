@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+
+// Each query runs in a new query context of the session like in the handlers, so it reads the
+// latest versions of the tables.
+async fn execute_query(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    ctx.attach_query_str(query);
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_result_cache() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    let cache = ctx.get_query_result_cache();
+
+    execute_query(&ctx, "create table t(a Int64)").await?;
+    execute_query(&ctx, "insert into t values(1),(2)").await?;
+
+    // Disabled by default.
+    execute_query(&ctx, "select sum(a) from t").await?;
+    assert_eq!(cache.get_usage().0, 0);
+
+    execute_query(&ctx, "set enable_query_result_cache = 1").await?;
+    let expected = vec![
+        "+--------+",
+        "| sum(a) |",
+        "+--------+",
+        "| 3      |",
+        "+--------+",
+    ];
+    let result = execute_query(&ctx, "select sum(a) from t").await?;
+    common_datablocks::assert_blocks_eq(expected.clone(), result.as_slice());
+    assert_eq!(cache.get_usage().0, 1);
+
+    // The same query formatted differently reads the cached result.
+    let result = execute_query(&ctx, "select  sum(a)\n  from t;").await?;
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    assert_eq!(cache.get_usage().0, 1);
+
+    // The table is modified, the query reads the new snapshot.
+    execute_query(&ctx, "insert into t values(3)").await?;
+    let expected = vec![
+        "+--------+",
+        "| sum(a) |",
+        "+--------+",
+        "| 6      |",
+        "+--------+",
+    ];
+    let result = execute_query(&ctx, "select sum(a) from t").await?;
+    common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    assert_eq!(cache.get_usage().0, 2);
+
+    // Not cached: the non-deterministic functions, the tables of the other engines and the
+    // queries opting out.
+    execute_query(&ctx, "select today(), sum(a) from t").await?;
+    execute_query(&ctx, "select generateUUIDv4(), sum(a) from t").await?;
+    execute_query(&ctx, "select count(*) from system.tables").await?;
+    let query = "select /*+ SET_VAR(enable_query_result_cache = 0) */ count(*) from t";
+    execute_query(&ctx, query).await?;
+    assert_eq!(cache.get_usage().0, 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_result_cache_max_bytes() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.query_result_cache_max_bytes = 1000;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    let cache = ctx.get_query_result_cache();
+    assert_eq!(cache.max_bytes(), 1000);
+
+    execute_query(&ctx, "set enable_query_result_cache = 1").await?;
+
+    // 8 bytes per row.
+    execute_query(&ctx, "select number from numbers_mt(100)").await?;
+    assert_eq!(cache.get_usage(), (1, 800));
+    execute_query(&ctx, "select number from numbers_mt(200)").await?;
+    assert_eq!(cache.get_usage(), (1, 800));
+
+    // The least recently used result is evicted.
+    execute_query(&ctx, "select number from numbers_mt(50)").await?;
+    execute_query(&ctx, "select number from numbers_mt(60)").await?;
+    assert_eq!(cache.get_usage(), (2, 880));
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_role_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+
+    execute(&ctx, "CREATE TABLE default.t(a int) Engine = Null").await?;
+    for role in ["reader", "writer", "other"] {
+        user_mgr
+            .add_role(&tenant, RoleInfo::new(role.to_string()), false)
+            .await?;
+    }
+    execute(&ctx, "GRANT SELECT ON default.t TO ROLE 'reader'").await?;
+
+    let user_info = UserInfo::new_no_auth("test_user".to_string(), "%".to_string());
+    user_mgr.add_user(&tenant, user_info.clone(), false).await?;
+    for role in ["reader", "writer"] {
+        user_mgr
+            .grant_role_to_user(&tenant, user_info.identity(), role.to_string())
+            .await?;
+    }
+    let user_info = user_mgr.get_user(&tenant, user_info.identity()).await?;
+    ctx.get_current_session().set_current_user(user_info);
+
+    // Set an unknown role.
+    {
+        let res = execute(&ctx, "SET ROLE 'unknown'").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownRole("").code());
+    }
+
+    // Set a role not granted to the user.
+    {
+        let res = execute(&ctx, "SET ROLE 'other'").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+    }
+
+    // Only the privileges of the activated role are checked.
+    {
+        execute(&ctx, "SET ROLE 'writer'").await?;
+        assert_eq!(
+            ctx.get_current_session().get_current_role(),
+            Some("writer".to_string())
+        );
+        let res = execute(&ctx, "SELECT * FROM default.t").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+
+        execute(&ctx, "SET ROLE 'reader'").await?;
+        execute(&ctx, "SELECT * FROM default.t").await?;
+    }
+
+    // All the granted roles are active by default.
+    {
+        execute(&ctx, "SET ROLE 'writer'").await?;
+        execute(&ctx, "SET ROLE DEFAULT").await?;
+        assert_eq!(ctx.get_current_session().get_current_role(), None);
+        execute(&ctx, "SELECT * FROM default.t").await?;
+    }
+
+    // The other statements are checked too.
+    {
+        let res = execute(&ctx, "CREATE USER 'u1' IDENTIFIED BY 'password'").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+
+        let res = execute(&ctx, "CREATE ROLE 'r1'").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+
+        let res = execute(&ctx, "INSERT INTO default.t VALUES (1)").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PermissionDenied("").code()
+        );
+    }
+
+    // The logs with the queries of all the users require SUPER.
+    {
+        for table in ["audit_log", "query_log", "slow_query_log"] {
+            let res = execute(&ctx, &format!("SELECT * FROM system.{}", table)).await;
+            assert_eq!(
+                res.unwrap_err().code(),
+                ErrorCode::PermissionDenied("").code()
+            );
+        }
+        execute(&ctx, "SELECT * FROM system.settings").await?;
+    }
+
+    Ok(())
+}
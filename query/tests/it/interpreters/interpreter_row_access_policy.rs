@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RoleInfo;
+use common_meta_types::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_row_access_policy_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+
+    execute(
+        &ctx,
+        "CREATE TABLE default.t(a int, region varchar) Engine = Memory",
+    )
+    .await?;
+    execute(
+        &ctx,
+        "INSERT INTO default.t VALUES (1, 'east'), (2, 'west'), (3, 'east')",
+    )
+    .await?;
+
+    // Create the policy.
+    {
+        let query =
+            "CREATE ROW ACCESS POLICY east_only AS (r) -> r = 'east' EXEMPT ROLES = ('admin')";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "CreateRowAccessPolicyInterpreter");
+        executor.execute(None).await?;
+
+        let policy = user_mgr.get_row_access_policy(&tenant, "east_only").await?;
+        assert_eq!(policy.parameters, vec!["r".to_string()]);
+        assert_eq!(policy.definition, "r = 'east'");
+        assert_eq!(policy.exempt_roles, vec!["admin".to_string()]);
+
+        let res = execute(
+            &ctx,
+            "CREATE ROW ACCESS POLICY east_only AS (r) -> r = 'west'",
+        )
+        .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::RowAccessPolicyAlreadyExists("").code()
+        );
+    }
+
+    // Attach the policy.
+    {
+        let res = execute(
+            &ctx,
+            "ALTER TABLE default.t ADD ROW ACCESS POLICY unknown ON (region)",
+        )
+        .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownRowAccessPolicy("").code()
+        );
+
+        let res = execute(
+            &ctx,
+            "ALTER TABLE default.t ADD ROW ACCESS POLICY east_only ON (a, region)",
+        )
+        .await;
+        assert!(res.is_err());
+
+        let query = "ALTER TABLE default.t ADD ROW ACCESS POLICY east_only ON (region)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "AlterTableRowAccessPolicyInterpreter");
+        executor.execute(None).await?;
+    }
+
+    // Only the rows matching the policy are read.
+    {
+        let result = execute(&ctx, "SELECT a FROM default.t WHERE a > 1").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 3 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let result = execute(&ctx, "SELECT count(*) FROM default.t").await?;
+        let expected = vec![
+            "+----------+",
+            "| count(*) |",
+            "+----------+",
+            "| 2        |",
+            "+----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        // The columns of the policy are qualified by the alias of the table.
+        let result = execute(&ctx, "SELECT x.a FROM default.t AS x WHERE x.a > 1").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 3 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The exempt roles read all the rows.
+    {
+        user_mgr
+            .add_role(&tenant, RoleInfo::new("admin".to_string()), false)
+            .await?;
+        execute(&ctx, "GRANT SELECT ON default.t TO ROLE 'admin'").await?;
+
+        let user_info = UserInfo::new_no_auth("test_user".to_string(), "%".to_string());
+        user_mgr.add_user(&tenant, user_info.clone(), false).await?;
+        user_mgr
+            .grant_role_to_user(&tenant, user_info.identity(), "admin".to_string())
+            .await?;
+        let user_info = user_mgr.get_user(&tenant, user_info.identity()).await?;
+        let root_info = ctx.get_current_user()?;
+        ctx.get_current_session().set_current_user(user_info);
+
+        let result = execute(&ctx, "SELECT count(*) FROM default.t").await?;
+        let expected = vec![
+            "+----------+",
+            "| count(*) |",
+            "+----------+",
+            "| 3        |",
+            "+----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        ctx.get_current_session().set_current_user(root_info);
+    }
+
+    // Detach and drop the policy.
+    {
+        execute(
+            &ctx,
+            "ALTER TABLE default.t DROP ROW ACCESS POLICY east_only",
+        )
+        .await?;
+        let result = execute(&ctx, "SELECT count(*) FROM default.t").await?;
+        let expected = vec![
+            "+----------+",
+            "| count(*) |",
+            "+----------+",
+            "| 3        |",
+            "+----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let query = "DROP ROW ACCESS POLICY east_only";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DropRowAccessPolicyInterpreter");
+        executor.execute(None).await?;
+
+        let res = execute(&ctx, "DROP ROW ACCESS POLICY east_only").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownRowAccessPolicy("").code()
+        );
+        execute(&ctx, "DROP ROW ACCESS POLICY IF EXISTS east_only").await?;
+    }
+
+    Ok(())
+}
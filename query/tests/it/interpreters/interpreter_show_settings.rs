@@ -49,5 +49,24 @@ async fn test_show_settings_interpreter() -> Result<()> {
         assert!(result[0].num_rows() > 5);
     }
 
+    // show settings like.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show settings like 'max_%'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------------------+-------+---------+---------+--------------------------------------------------------------------------------------------------------------------+--------+",
+            "| name             | value | default | level   | description                                                                                                        | type   |",
+            "+------------------+-------+---------+---------+--------------------------------------------------------------------------------------------------------------------+--------+",
+            "| max_block_size   | 10000 | 10000   | SESSION | Maximum block size for reading                                                                                     | UInt64 |",
+            "| max_memory_usage | 0     | 0       | SESSION | The maximum memory usage in bytes of a query, the query fails once it is exceeded. By default, it is 0 (no limit). | UInt64 |",
+            "| max_threads      | 8     | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                  | UInt64 |",
+            "+------------------+-------+---------+---------+--------------------------------------------------------------------------------------------------------------------+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
     Ok(())
 }
@@ -19,10 +19,15 @@ mod interpreter_database_show_create;
 mod interpreter_explain;
 mod interpreter_factory_interceptor;
 mod interpreter_insert;
+mod interpreter_kill;
+mod interpreter_masking_policy;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
+mod interpreter_query_result_cache;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
+mod interpreter_role_set;
+mod interpreter_row_access_policy;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_databases;
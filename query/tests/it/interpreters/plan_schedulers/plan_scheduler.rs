@@ -70,6 +70,7 @@ async fn test_scheduler_plan_with_one_convergent_stage() -> Result<()> {
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => panic!(),
+            FlightAction::StageProgressAction(_) => panic!(),
             FlightAction::BroadcastAction(_) => panic!(),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
@@ -148,6 +149,7 @@ async fn test_scheduler_plan_with_convergent_and_expansive_stage() -> Result<()>
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => panic!(),
+            FlightAction::StageProgressAction(_) => panic!(),
             FlightAction::BroadcastAction(_) => panic!(),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
@@ -248,6 +250,7 @@ async fn test_scheduler_plan_with_convergent_and_normal_stage() -> Result<()> {
     for (node, remote_action) in scheduled_tasks.get_tasks()? {
         match remote_action {
             FlightAction::CancelAction(_) => panic!(),
+            FlightAction::StageProgressAction(_) => panic!(),
             FlightAction::BroadcastAction(_) => panic!(),
             FlightAction::PrepareShuffleAction(action) => remote_actions.push((node, action)),
         }
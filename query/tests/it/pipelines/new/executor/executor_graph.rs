@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::tokio;
 use common_base::tokio::sync::mpsc::channel;
@@ -199,6 +200,47 @@ async fn test_resize_pipeline_init_queue() -> Result<()> {
 //     unimplemented!("")
 // }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_simple_pipeline_profiles() -> Result<()> {
+    let ctx = create_query_context().await?;
+    let profiles = create_simple_pipeline(ctx)?.get_profiles();
+
+    let names = profiles.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec![
+        "SyncReceiverSource",
+        "DummyTransform",
+        "SyncSenderSink"
+    ]);
+
+    // Nothing is executed yet.
+    for (id, profile) in profiles.iter().enumerate() {
+        assert_eq!(profile.id, id);
+        assert_eq!(profile.input_rows, 0);
+        assert_eq!(profile.output_rows, 0);
+        assert_eq!(profile.cpu_time, Duration::ZERO);
+        assert_eq!(profile.wait_time, Duration::ZERO);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_simple_pipeline_graphviz() -> Result<()> {
+    let ctx = create_query_context().await?;
+    assert_eq!(
+        create_simple_pipeline(ctx)?.display_graphviz(),
+        "digraph {\
+            \n    0 [ label = \"SyncReceiverSource\" ]\
+            \n    1 [ label = \"DummyTransform\" ]\
+            \n    2 [ label = \"SyncSenderSink\" ]\
+            \n    0 -> 1 [ label = \"0 rows\" ]\
+            \n    1 -> 2 [ label = \"0 rows\" ]\
+        \n}\n"
+    );
+
+    Ok(())
+}
+
 fn create_simple_pipeline(ctx: Arc<QueryContext>) -> Result<RunningGraph> {
     let (_rx, sink_pipe) = create_sink_pipe(1)?;
     let (_tx, source_pipe) = create_source_pipe(ctx, 1)?;
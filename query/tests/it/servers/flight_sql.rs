@@ -0,0 +1,164 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::deserialize_schemas;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::data::HandshakeRequest;
+use common_arrow::arrow_format::flight::data::Ticket;
+use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_base::tokio;
+use common_exception::Result;
+use common_grpc::ConnectionFactory;
+use databend_query::servers::FlightSQLHandler;
+use prost::Message;
+use tokio_stream::StreamExt;
+use tonic::transport::Channel;
+use tonic::Request;
+
+use crate::tests::SessionManagerBuilder;
+
+// The `google.protobuf.Any` which wraps the Flight SQL commands.
+#[derive(Clone, PartialEq, prost::Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CommandGetTableTypes {}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_sql_query() -> Result<()> {
+    let mut handler =
+        FlightSQLHandler::create(SessionManagerBuilder::create().max_sessions(2).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = FlightServiceClient::new(ConnectionFactory::create_rpc_channel(
+        listening, None, None,
+    )?);
+
+    // Without the authorization.
+    let descriptor = command_descriptor("CommandStatementQuery", CommandStatementQuery {
+        query: "SELECT 1".to_string(),
+    });
+    assert!(client.get_flight_info(descriptor.clone()).await.is_err());
+
+    let token = handshake(&mut client).await;
+    assert!(token.starts_with("Bearer "));
+
+    let mut request = Request::new(descriptor);
+    request
+        .metadata_mut()
+        .insert("authorization", token.parse().unwrap());
+    let info = client.get_flight_info(request).await.unwrap().into_inner();
+    assert!(!info.schema.is_empty());
+    assert_eq!(info.endpoint.len(), 1);
+
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let data = do_get(&mut client, &token, ticket).await;
+    assert_eq!(data.len(), 2);
+
+    let (schema, ipc_schema) = deserialize_schemas(&data[0].data_header)?;
+    assert_eq!(schema.fields.len(), 1);
+    assert_eq!(schema.fields[0].name, "1");
+    let chunk = deserialize_batch(&data[1], &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(chunk.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_sql_table_types() -> Result<()> {
+    let mut handler =
+        FlightSQLHandler::create(SessionManagerBuilder::create().max_sessions(2).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut client = FlightServiceClient::new(ConnectionFactory::create_rpc_channel(
+        listening, None, None,
+    )?);
+
+    let token = handshake(&mut client).await;
+    let descriptor = command_descriptor("CommandGetTableTypes", CommandGetTableTypes {});
+    let ticket = Ticket {
+        ticket: descriptor.cmd,
+    };
+    let data = do_get(&mut client, &token, ticket).await;
+    assert_eq!(data.len(), 2);
+
+    let (schema, ipc_schema) = deserialize_schemas(&data[0].data_header)?;
+    assert_eq!(schema.fields[0].name, "table_type");
+    let chunk = deserialize_batch(&data[1], &schema.fields, &ipc_schema, &Default::default())?;
+    assert_eq!(chunk.len(), 2);
+
+    Ok(())
+}
+
+fn command_descriptor(name: &str, command: impl Message) -> FlightDescriptor {
+    let any = Any {
+        type_url: format!("type.googleapis.com/arrow.flight.protocol.sql.{}", name),
+        value: command.encode_to_vec(),
+    };
+
+    FlightDescriptor {
+        r#type: 2,
+        cmd: any.encode_to_vec(),
+        path: vec![],
+    }
+}
+
+async fn handshake(client: &mut FlightServiceClient<Channel>) -> String {
+    let mut request = Request::new(tokio_stream::once(HandshakeRequest {
+        protocol_version: 0,
+        payload: vec![],
+    }));
+    // root with the empty password.
+    request
+        .metadata_mut()
+        .insert("authorization", "Basic cm9vdDo=".parse().unwrap());
+
+    let response = client.handshake(request).await.unwrap();
+    let token = response.metadata().get("authorization").unwrap();
+    token.to_str().unwrap().to_string()
+}
+
+async fn do_get(
+    client: &mut FlightServiceClient<Channel>,
+    token: &str,
+    ticket: Ticket,
+) -> Vec<FlightData> {
+    let mut request = Request::new(ticket);
+    request
+        .metadata_mut()
+        .insert("authorization", token.parse().unwrap());
+
+    let stream = client.do_get(request).await.unwrap().into_inner();
+    stream
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .await
+        .unwrap()
+}
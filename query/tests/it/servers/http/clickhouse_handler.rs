@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
 use common_base::tokio;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
@@ -98,6 +101,181 @@ async fn test_select() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_select_output_format() -> PoemResult<()> {
+    let server = Server::new();
+    let sql = "select number, 'a' as s from numbers(2) order by number";
+
+    {
+        let req = QueryBuilder::new(sql).format("TabSeparated").build();
+        let (status, content_type, body) = server.get_bytes(req).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "text/tab-separated-values; charset=UTF-8");
+        assert_eq!(body, b"0\ta\n1\ta\n");
+    }
+
+    {
+        let req = QueryBuilder::new(sql).format("ArrowStream").build();
+        let (status, content_type, body) = server.get_bytes(req).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "application/vnd.apache.arrow.stream");
+
+        let mut reader = std::io::Cursor::new(body);
+        let metadata = read_stream_metadata(&mut reader).unwrap();
+        let names = metadata
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["number", "s"]);
+
+        let mut rows = 0;
+        for state in StreamReader::new(reader, metadata) {
+            match state.unwrap() {
+                StreamState::Some(chunk) => rows += chunk.len(),
+                StreamState::Waiting => unreachable!("the stream is complete"),
+            }
+        }
+        assert_eq!(rows, 2);
+    }
+
+    {
+        let req = QueryBuilder::new(sql).format("Parquet").build();
+        let (status, body) = server.get_response(req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_error!(body, "output format Parquet not supported");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_select_format_clause() -> PoemResult<()> {
+    let server = Server::new();
+    let sql = "select number, 'a' as s from numbers(2) order by number";
+
+    {
+        let req = QueryBuilder::new(&format!("{} FORMAT CSVWithNames", sql)).build();
+        let (status, content_type, body) = server.get_bytes(req).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "text/csv; charset=UTF-8");
+        assert_eq!(body, b"\"number\",\"s\"\n0,\"a\"\n1,\"a\"\n");
+    }
+
+    {
+        // The FORMAT clause takes precedence over default_format.
+        let req = QueryBuilder::new(&format!("{} format TSVWithNamesAndTypes;", sql))
+            .format("ArrowStream")
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "number\ts\nUInt64\tString\n0\ta\n1\ta\n");
+    }
+
+    {
+        let req = QueryBuilder::new(sql).format("JSONEachRow").build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+        assert_eq!(
+            &body,
+            "{\"number\":0,\"s\":\"a\"}\n{\"number\":1,\"s\":\"a\"}\n"
+        );
+    }
+
+    {
+        let (status, body) = server.post(&format!("{} FORMAT JSON", sql), "").await;
+        assert_ok!(status, body);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["meta"][0]["name"], "number");
+        assert_eq!(json["meta"][1]["type"], "String");
+        assert_eq!(json["data"][1]["number"], 1);
+        assert_eq!(json["rows"], 2);
+    }
+
+    {
+        let (status, body) = server.get(&format!("{} FORMAT Parquet", sql)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_error!(body, "output format Parquet not supported");
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_external_data() -> PoemResult<()> {
+    let server = Server::new();
+    let boundary = "databend-external-data";
+    let body = format!(
+        "--{b}\r\nContent-Disposition: form-data; name=\"ids\"; filename=\"ids.tsv\"\r\n\r\n1\tabc\n2\tdef\n\r\n--{b}--\r\n",
+        b = boundary
+    );
+
+    let uri = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("query", "select name from ids where id > 1")
+        .append_pair("ids_structure", "id UInt32, name String")
+        .finish();
+    let req = Request::builder()
+        .uri(format!("/?{}", uri).parse::<Uri>().unwrap())
+        .method(Method::POST)
+        .header(
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .body(body.clone());
+    let (status, body) = server.get_response(req).await;
+    assert_ok!(status, body);
+    assert_eq!(&body, "def\n");
+
+    // Without the structure.
+    let uri = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("query", "select * from ids")
+        .finish();
+    let req = Request::builder()
+        .uri(format!("/?{}", uri).parse::<Uri>().unwrap())
+        .method(Method::POST)
+        .header(
+            "Content-Type",
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .body(format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"ids\"\r\n\r\n1\n\r\n--{b}--\r\n",
+            b = boundary
+        ));
+    let (status, body) = server.get_response(req).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_error!(body, "ids_structure");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clickhouse_credential() -> PoemResult<()> {
+    let server = Server::new();
+    let sql = "select current_user()";
+
+    {
+        let req = QueryBuilder::new(sql).build();
+        let req = Request::builder()
+            .uri(req.uri().clone())
+            .header("X-ClickHouse-User", "root")
+            .finish();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "'root'@'%'\n");
+    }
+
+    {
+        let uri = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("query", sql)
+            .append_pair("user", "unknown_user")
+            .finish();
+        let req = Request::builder()
+            .uri(format!("/?{}", uri).parse::<Uri>().unwrap())
+            .finish();
+        let (status, _) = server.get_response(req).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_insert_values() -> PoemResult<()> {
     let server = Server::new();
@@ -204,6 +382,7 @@ async fn test_insert_format_ndjson() -> PoemResult<()> {
 
 struct QueryBuilder {
     sql: String,
+    format: Option<String>,
     body: Option<Body>,
 }
 
@@ -211,10 +390,18 @@ impl QueryBuilder {
     pub fn new(sql: &str) -> Self {
         QueryBuilder {
             sql: sql.to_string(),
+            format: None,
             body: None,
         }
     }
 
+    pub fn format(self, format: &str) -> Self {
+        Self {
+            format: Some(format.to_string()),
+            ..self
+        }
+    }
+
     pub fn body(self, body: impl Into<Body>) -> Self {
         Self {
             body: Some(body.into()),
@@ -223,9 +410,12 @@ impl QueryBuilder {
     }
 
     pub fn build(self) -> Request {
-        let uri = url::form_urlencoded::Serializer::new(String::new())
-            .append_pair("query", &self.sql)
-            .finish();
+        let mut uri = url::form_urlencoded::Serializer::new(String::new());
+        uri.append_pair("query", &self.sql);
+        if let Some(format) = &self.format {
+            uri.append_pair("default_format", format);
+        }
+        let uri = uri.finish();
         let uri = "/?".to_string() + &uri;
         let uri = uri.parse::<Uri>().unwrap();
         let (method, body) = match self.body {
@@ -257,6 +447,14 @@ impl Server {
         (status, body)
     }
 
+    pub async fn get_bytes(&self, req: Request) -> (StatusCode, String, Vec<u8>) {
+        let response = self.endpoint.get_response(req).await;
+        let status = response.status();
+        let content_type = response.content_type().unwrap_or_default().to_string();
+        let body = response.into_body().into_vec().await.unwrap();
+        (status, content_type, body)
+    }
+
     pub async fn get(&self, sql: &str) -> (StatusCode, String) {
         self.get_response(QueryBuilder::new(sql).build()).await
     }
@@ -0,0 +1,153 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::Int32Array;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::servers::http::formats::arrow_output::ArrowStreamSerializer;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_arrow_stream_serializer() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c1", i32::to_data_type()),
+        DataField::new_nullable("c2", Vu8::to_data_type()),
+        DataField::new("c3", bool::to_data_type()),
+    ]);
+    let serializer = ArrowStreamSerializer::create(schema.clone());
+
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![1, 2, 3]),
+            Series::from_data(vec![Some("a"), None, Some("c")]),
+            Series::from_data(vec![true, true, false]),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            ConstColumn::new(Series::from_data(vec![4]), 2).arc(),
+            Series::from_data(vec![Some("d"), Some("e")]),
+            Series::from_data(vec![false, true]),
+        ]),
+    ];
+
+    let mut buf = serializer.serialize_schema();
+    for block in blocks.iter() {
+        buf.extend(serializer.serialize_block(block)?);
+    }
+    buf.extend(serializer.finish());
+
+    let mut reader = Cursor::new(buf);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema.to_arrow());
+
+    let mut chunks = vec![];
+    for state in StreamReader::new(reader, metadata) {
+        match state? {
+            StreamState::Some(chunk) => chunks.push(chunk),
+            StreamState::Waiting => unreachable!("the stream is complete"),
+        }
+    }
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].len(), 3);
+    assert_eq!(chunks[1].len(), 2);
+
+    let c1 = chunks[1].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(c1.values().as_slice(), &[4, 4]);
+    let c2 = chunks[0].arrays()[1].as_ref();
+    assert_eq!(c2.null_count(), 1);
+    let c3 = chunks[0].arrays()[2]
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap();
+    assert_eq!(c3.values().iter().collect::<Vec<_>>(), vec![
+        true, true, false
+    ]);
+
+    // A stream without blocks still carries the schema.
+    let mut buf = serializer.serialize_schema();
+    buf.extend(serializer.finish());
+    let mut reader = Cursor::new(buf);
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(StreamReader::new(reader, metadata).count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_arrow_stream_serializer_variant_and_decimal() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new_nullable("v", VariantType::arc()),
+        DataField::new("d", DecimalType::arc(10, 2)),
+    ]);
+    let serializer = ArrowStreamSerializer::create(schema.clone());
+
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![
+            Some(serde_json::json!({"a": 1})),
+            None,
+            Some(serde_json::json!([1, "b"])),
+        ]),
+        Series::from_data(vec![12345i128, -5, 0]),
+    ]);
+
+    let mut buf = serializer.serialize_schema();
+    buf.extend(serializer.serialize_block(&block)?);
+    buf.extend(serializer.finish());
+
+    let mut reader = Cursor::new(buf);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let fields = &metadata.schema.fields;
+    assert_eq!(fields[0].data_type(), &ArrowType::LargeUtf8);
+    assert!(fields[0].is_nullable);
+    assert_eq!(fields[1].data_type(), &ArrowType::Decimal(10, 2));
+
+    let mut chunks = vec![];
+    for state in StreamReader::new(reader, metadata) {
+        match state? {
+            StreamState::Some(chunk) => chunks.push(chunk),
+            StreamState::Waiting => unreachable!("the stream is complete"),
+        }
+    }
+    assert_eq!(chunks.len(), 1);
+
+    let v = chunks[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<Utf8Array<i64>>()
+        .unwrap();
+    assert_eq!(v.iter().collect::<Vec<_>>(), vec![
+        Some(r#"{"a":1}"#),
+        None,
+        Some(r#"[1,"b"]"#)
+    ]);
+    let d = chunks[0].arrays()[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap();
+    assert_eq!(d.data_type(), &ArrowType::Decimal(10, 2));
+    assert_eq!(d.values().as_slice(), &[12345, -5, 0]);
+
+    Ok(())
+}
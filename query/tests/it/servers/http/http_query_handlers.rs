@@ -14,6 +14,7 @@
 
 use std::fs::File;
 use std::io::Read;
+use std::sync::Arc;
 use std::time::Duration;
 
 use base64::encode_config;
@@ -22,10 +23,15 @@ use common_base::get_free_tcp_port;
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::Compression;
 use common_meta_types::AuthInfo;
+use common_meta_types::NetworkPolicy;
 use common_meta_types::UserInfo;
+use common_meta_types::UserOption;
+use common_streams::decompress;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::middleware::HEADER_TENANT;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
@@ -34,6 +40,7 @@ use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSession;
 use databend_query::servers::http::v1::QueryResponse;
 use databend_query::servers::HttpHandler;
+use databend_query::sessions::SessionManager;
 use headers::Header;
 use hyper::header;
 use jwt_simple::algorithms::RS256KeyPair;
@@ -107,6 +114,30 @@ async fn test_bad_sql() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_external_result_bad_conf() -> Result<()> {
+    // The result files can't be presigned without the s3 storage.
+    let json = serde_json::json!({"sql": "select 1", "external_result": {"format": "csv"}});
+    let (status, result) = post_json(&json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.state, ExecuteStateKind::Failed);
+    assert_eq!(
+        result.error.as_ref().map(|e| e.code),
+        Some(1006),
+        "{:?}",
+        result
+    );
+    assert!(result.external_result.is_none());
+
+    let json = serde_json::json!({"sql": "select 1", "external_result": {"format": "xml"}});
+    let (status, result) = post_json(&json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.state, ExecuteStateKind::Failed);
+    let message = result.error.map(|e| e.message).unwrap_or_default();
+    assert!(message.contains("Unsupported format"), "{}", message);
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_async() -> Result<()> {
     let ep = create_endpoint();
@@ -167,6 +198,55 @@ async fn test_async() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_submit() -> Result<()> {
+    let ep = create_endpoint();
+    let json = serde_json::json!({"sql": "select * from numbers(10)"});
+    let uri = "/v1/query/submit";
+    let body = serde_json::to_vec(&json)?;
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(uri.parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body),
+        )
+        .await
+        .map_err(|e| ErrorCode::UnexpectedError(e.to_string()))?;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data.len(), 0);
+    assert!(result.session_id.is_some());
+    let query_id = &result.id;
+    assert_eq!(result.next_uri, Some(make_page_uri(query_id, 0)));
+
+    // poll the state until the query finished
+    let state_uri = make_state_uri(query_id);
+    let mut state = result.state;
+    for _ in 0..100 {
+        if state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(Duration::from_millis(50)).await;
+        let (status, result) = get_uri_checked(&ep, &state_uri).await?;
+        assert_eq!(status, StatusCode::OK);
+        state = result.state;
+    }
+    assert_eq!(state, ExecuteStateKind::Succeeded);
+
+    // the results are kept after the query finished
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(query_id, 0)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data.len(), 10, "{:?}", result);
+    assert!(result.next_uri.is_none());
+
+    let status = delete_query(&ep, query_id).await;
+    assert_eq!(status, StatusCode::OK);
+    Ok(())
+}
+
 #[test]
 fn test_http_session_serde() {
     {
@@ -329,6 +409,69 @@ async fn test_multi_page() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_max_rows_per_page() -> Result<()> {
+    let ep = create_endpoint();
+
+    let sql = "select * from numbers(25)";
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 3, "max_rows_per_page": 10}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data.len(), 10);
+    let query_id = result.id;
+    assert_eq!(result.next_uri, Some(make_page_uri(&query_id, 1)));
+
+    // The rest of a block is returned by the following pages.
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(&query_id, 1)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data.len(), 10);
+    assert_eq!(result.next_uri, Some(make_page_uri(&query_id, 2)));
+
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(&query_id, 2)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data.len(), 5);
+    assert_eq!(result.next_uri, None);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_compressed_result() -> Result<()> {
+    let ep = create_endpoint();
+
+    let json = serde_json::json!({"sql": "select * from numbers(10)", "pagination": {"wait_time_secs": 3}});
+    for (accept_encoding, content_encoding, compression) in [
+        ("gzip", "gzip", Compression::Gzip),
+        ("gzip;q=0.5, zstd", "zstd", Compression::Zstd),
+        ("deflate, br", "br", Compression::Brotli),
+    ] {
+        let response = ep
+            .call(
+                Request::builder()
+                    .uri("/v1/query".parse().unwrap())
+                    .method(Method::POST)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(header::ACCEPT_ENCODING, accept_encoding)
+                    .body(serde_json::to_vec(&json)?),
+            )
+            .await
+            .map_err(|e| ErrorCode::UnexpectedError(e.to_string()))?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_ENCODING)
+                .and_then(|v| v.to_str().ok()),
+            Some(content_encoding)
+        );
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let result = serde_json::from_slice::<QueryResponse>(&decompress(&compression, body)?)?;
+        assert_eq!(result.data.len(), 10);
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_insert() -> Result<()> {
     let route = create_endpoint();
@@ -448,19 +591,15 @@ async fn test_auth_basic() -> Result<()> {
     Ok(())
 }
 
-#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-async fn test_auth_jwt() -> Result<()> {
-    let user_name = "user1";
-
-    let kid = "test_kid";
-    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+// Serve the public key of the key pair as a JWKS, returns the url of the JWKS.
+async fn mock_jwks(server: &MockServer, key_pair: &RS256KeyPair) -> String {
+    let kid = key_pair.key_id().clone().unwrap();
     let rsa_components = key_pair.public_key().to_components();
     let e = encode_config(rsa_components.e, URL_SAFE_NO_PAD);
     let n = encode_config(rsa_components.n, URL_SAFE_NO_PAD);
     let j =
         serde_json::json!({"keys": [ {"kty": "RSA", "kid": kid, "e": e, "n": n, } ] }).to_string();
 
-    let server = MockServer::start().await;
     let json_path = "/jwks.json";
     // Create a mock on the server.
     let template = ResponseTemplate::new(200).set_body_raw(j, "application/json");
@@ -469,15 +608,27 @@ async fn test_auth_jwt() -> Result<()> {
         .respond_with(template)
         .expect(1..)
         // Mounting the mock on the mock server - it's now effective!
-        .mount(&server)
+        .mount(server)
         .await;
-    let jwks_url = format!("http://{}{}", server.address(), json_path);
+    format!("http://{}{}", server.address(), json_path)
+}
 
-    let session_manager = SessionManagerBuilder::create()
-        .jwt_key_file(jwks_url)
-        .build()
-        .unwrap();
+fn jwt_claims<C>(subject: Option<String>, custom: C) -> JWTClaims<C> {
+    let now = Some(Clock::now_since_epoch());
+    JWTClaims {
+        issued_at: now,
+        expires_at: Some(now.unwrap() + jwt_simple::prelude::Duration::from_secs(10)),
+        invalid_before: now,
+        audiences: None,
+        issuer: None,
+        jwt_id: None,
+        subject,
+        nonce: None,
+        custom,
+    }
+}
 
+async fn add_jwt_user(session_manager: &Arc<SessionManager>, user_name: &str) -> Result<()> {
     let user_info = UserInfo {
         name: user_name.to_string(),
         hostname: "%".to_string(),
@@ -491,28 +642,343 @@ async fn test_auth_jwt() -> Result<()> {
     session_manager
         .get_user_manager()
         .add_user(tenant, user_info, false)
-        .await?;
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_jwt() -> Result<()> {
+    let user_name = "user1";
+
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+    let server = MockServer::start().await;
+    let jwks_url = mock_jwks(&server, &key_pair).await;
+
+    let session_manager = SessionManagerBuilder::create()
+        .jwt_key_file(jwks_url)
+        .build()
+        .unwrap();
+    add_jwt_user(&session_manager, user_name).await?;
 
     let ep = Route::new()
         .nest("/v1/query", query_route())
         .with(HTTPSessionMiddleware { session_manager });
 
-    let now = Some(Clock::now_since_epoch());
-    let claims = JWTClaims {
-        issued_at: now,
-        expires_at: Some(now.unwrap() + jwt_simple::prelude::Duration::from_secs(10)),
-        invalid_before: now,
-        audiences: None,
-        issuer: None,
-        jwt_id: None,
-        subject: Some(user_name.to_string()),
-        nonce: None,
-        custom: NoCustomClaims {},
-    };
+    let claims = jwt_claims(Some(user_name.to_string()), NoCustomClaims {});
+    let token = key_pair.sign(claims)?;
+    let bear = headers::Authorization::bearer(&token).unwrap();
+    test_auth_post(&ep, user_name, bear).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_jwt_claims() -> Result<()> {
+    let user_name = "service1";
+
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+    let server = MockServer::start().await;
+    let jwks_url = mock_jwks(&server, &key_pair).await;
+
+    let session_manager = SessionManagerBuilder::create()
+        .jwt_key_file(jwks_url)
+        .jwt_user_claim("client_id")
+        .jwt_role_claim("role")
+        .build()
+        .unwrap();
+    add_jwt_user(&session_manager, user_name).await?;
+
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware { session_manager });
 
+    // The user is taken from the configured claim instead of the subject.
+    let custom = serde_json::json!({"client_id": user_name, "role": "analyst"});
+    let claims = jwt_claims(Some("unknown".to_string()), custom);
     let token = key_pair.sign(claims)?;
     let bear = headers::Authorization::bearer(&token).unwrap();
     test_auth_post(&ep, user_name, bear).await?;
+
+    // The token without the user claim is rejected.
+    let claims = jwt_claims(Some(user_name.to_string()), NoCustomClaims {});
+    let token = key_pair.sign(claims)?;
+    let bear = headers::Authorization::bearer(&token).unwrap();
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(bear)
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_ldap() -> Result<()> {
+    let user_name = "ldap_user";
+    let user_info = UserInfo {
+        name: user_name.to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::Ldap,
+        grants: Default::default(),
+        quota: Default::default(),
+        option: Default::default(),
+    };
+
+    // Nothing listens on the port of the LDAP server.
+    let ldap_url = format!("ldap://127.0.0.1:{}", get_free_tcp_port());
+    for session_manager in [
+        SessionManagerBuilder::create().build()?,
+        SessionManagerBuilder::create().ldap_url(ldap_url).build()?,
+    ] {
+        session_manager
+            .get_user_manager()
+            .add_user("test", user_info.clone(), false)
+            .await?;
+
+        let ep = Route::new()
+            .nest("/v1/query", query_route())
+            .with(HTTPSessionMiddleware { session_manager });
+        let basic = headers::Authorization::basic(user_name, "password");
+        let json = serde_json::json!({"sql": "select current_user()"});
+        let response = ep
+            .call(
+                Request::builder()
+                    .uri("/v1/query".parse().unwrap())
+                    .method(Method::POST)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .typed_header(basic)
+                    .body(serde_json::to_vec(&json)?),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_network_policy() -> Result<()> {
+    let policy = NetworkPolicy::new("office_only", vec!["10.0.0.0/8".to_string()], vec![]);
+    let mut option = UserOption::default();
+    option.set_network_policy(Some(policy.name.clone()));
+    let restricted_user = UserInfo {
+        name: "restricted_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::None,
+        grants: Default::default(),
+        quota: Default::default(),
+        option,
+    };
+    let free_user = UserInfo {
+        name: "free_user".to_string(),
+        option: Default::default(),
+        ..restricted_user.clone()
+    };
+
+    let session_manager = SessionManagerBuilder::create().build()?;
+    let user_manager = session_manager.get_user_manager();
+    user_manager
+        .add_network_policy("test", policy.clone(), false)
+        .await?;
+    user_manager
+        .add_user("test", restricted_user.clone(), false)
+        .await?;
+    user_manager
+        .add_user("test", free_user.clone(), false)
+        .await?;
+
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware { session_manager });
+    test_auth_post(
+        &ep,
+        "free_user",
+        headers::Authorization::basic("free_user", ""),
+    )
+    .await?;
+
+    // The address of the test request is unknown, it's not in the allowed list.
+    let basic = headers::Authorization::basic("restricted_user", "");
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(basic)
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The global policy applies to all the users.
+    let session_manager = SessionManagerBuilder::create()
+        .network_policy("office_only")
+        .build()?;
+    let user_manager = session_manager.get_user_manager();
+    user_manager
+        .add_network_policy("test", policy, false)
+        .await?;
+    user_manager.add_user("test", free_user, false).await?;
+
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware { session_manager });
+    let basic = headers::Authorization::basic("free_user", "");
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .typed_header(basic)
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_tenant() -> Result<()> {
+    let user_info = UserInfo {
+        name: "tenant_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::None,
+        grants: Default::default(),
+        quota: Default::default(),
+        option: Default::default(),
+    };
+
+    let session_manager = SessionManagerBuilder::create().build()?;
+    session_manager
+        .get_user_manager()
+        .add_user("tenant1", user_info, false)
+        .await?;
+
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware { session_manager });
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let request = |tenant: Option<&str>| {
+        let mut builder = Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .typed_header(headers::Authorization::basic("tenant_user", ""));
+        if let Some(tenant) = tenant {
+            builder = builder.header(HEADER_TENANT, tenant);
+        }
+        builder.body(serde_json::to_vec(&json).unwrap())
+    };
+
+    // The user is not in the tenant of the config.
+    let response = ep.call(request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = ep.call(request(Some("tenant1"))).await.unwrap();
+    let (_, resp) = check_response(response).await?;
+    assert!(resp.error.is_none(), "{:?}", resp.error);
+    assert_eq!(
+        resp.data[0][0],
+        serde_json::Value::String("'tenant_user'@'%'".to_string())
+    );
+
+    let response = ep.call(request(Some("tenant2"))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The request without credential and the built-in root are only in the tenant of the config.
+    for auth in [None, Some(headers::Authorization::basic("root", ""))] {
+        let mut builder = Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(HEADER_TENANT, "tenant1");
+        if let Some(auth) = auth {
+            builder = builder.typed_header(auth);
+        }
+        let response = ep
+            .call(builder.body(serde_json::to_vec(&json)?))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_jwt_tenant() -> Result<()> {
+    let user_name = "service1";
+
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+    let server = MockServer::start().await;
+    let jwks_url = mock_jwks(&server, &key_pair).await;
+
+    let session_manager = SessionManagerBuilder::create()
+        .jwt_key_file(jwks_url)
+        .jwt_tenant_claim("tenant")
+        .build()
+        .unwrap();
+    add_jwt_user(&session_manager, user_name).await?;
+    let user_info = UserInfo {
+        name: user_name.to_string(),
+        hostname: "%".to_string(),
+        auth_info: AuthInfo::JWT,
+        grants: Default::default(),
+        quota: Default::default(),
+        option: Default::default(),
+    };
+    session_manager
+        .get_user_manager()
+        .add_user("tenant1", user_info, false)
+        .await?;
+
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .with(HTTPSessionMiddleware { session_manager });
+    let json = serde_json::json!({"sql": "select current_user()"});
+    let status = |token: String, tenant: Option<&str>| {
+        let mut builder = Request::builder()
+            .uri("/v1/query".parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .typed_header(headers::Authorization::bearer(&token).unwrap());
+        if let Some(tenant) = tenant {
+            builder = builder.header(HEADER_TENANT, tenant);
+        }
+        let request = builder.body(serde_json::to_vec(&json).unwrap());
+        let ep = &ep;
+        async move { ep.call(request).await.unwrap().status() }
+    };
+
+    let custom = serde_json::json!({"tenant": "tenant1"});
+    let token = key_pair.sign(jwt_claims(Some(user_name.to_string()), custom))?;
+    assert_eq!(status(token.clone(), Some("tenant1")).await, StatusCode::OK);
+    // The token is only accepted in the tenant it is issued for.
+    assert_eq!(status(token.clone(), None).await, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        status(token, Some("tenant2")).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    // The token without tenant is only accepted in the tenant of the config.
+    let token = key_pair.sign(jwt_claims(Some(user_name.to_string()), NoCustomClaims {}))?;
+    assert_eq!(status(token.clone(), None).await, StatusCode::OK);
+    assert_eq!(
+        status(token, Some("tenant1")).await,
+        StatusCode::UNAUTHORIZED
+    );
     Ok(())
 }
 
@@ -15,7 +15,11 @@
 use common_base::tokio;
 use common_exception::Result;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::multi_statement_handler;
+use databend_query::servers::http::v1::split_statements;
 use databend_query::servers::http::v1::statement_handler;
+use databend_query::servers::http::v1::ExecuteStateKind;
+use databend_query::servers::http::v1::MultiStatementResponse;
 use databend_query::servers::http::v1::QueryResponse;
 use poem::http::Method;
 use poem::http::StatusCode;
@@ -91,3 +95,65 @@ async fn test_sql(
     let result = serde_json::from_slice::<QueryResponse>(&body)?;
     Ok((status, result))
 }
+
+#[tokio::test]
+async fn test_split_statements() -> Result<()> {
+    let sql = "select 1; select ';' -- a comment; \n;; /* ; */ select \"a;b\";\n-- the end";
+    assert_eq!(split_statements(sql), vec![
+        "select 1",
+        "select ';' -- a comment;",
+        "/* ; */ select \"a;b\"",
+    ]);
+    assert_eq!(split_statements("select 'it''s; ok'"), vec![
+        "select 'it''s; ok'"
+    ]);
+    assert!(split_statements(" ; -- nothing").is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multi_statement() -> Result<()> {
+    let sql = "create database db1; use db1; create table t1(a int); insert into t1 values (1), (2); select * from t1";
+    let (status, result) = test_multi_sql(sql, "").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded);
+    assert_eq!(result.results.len(), 5);
+    assert_eq!(result.results[4].data.len(), 2);
+
+    // The statements after the failed one are skipped by default.
+    let sql = "select 1; bad sql; select 2";
+    let (status, result) = test_multi_sql(sql, "").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.state, ExecuteStateKind::Failed);
+    assert_eq!(result.results.len(), 2);
+    assert!(result.results[1].error.is_some());
+
+    let (_, result) = test_multi_sql(sql, "?on_error=continue").await?;
+    assert_eq!(result.state, ExecuteStateKind::Failed);
+    assert_eq!(result.results.len(), 3);
+    assert_eq!(result.results[2].state, ExecuteStateKind::Succeeded);
+    Ok(())
+}
+
+async fn test_multi_sql(sql: &str, query: &str) -> Result<(StatusCode, MultiStatementResponse)> {
+    let path = "/v1/statement/multi";
+    let session_manager = SessionManagerBuilder::create().build()?;
+    let cluster_router = Route::new()
+        .at(path, post(multi_statement_handler))
+        .with(HTTPSessionMiddleware { session_manager });
+    let uri = format!("{}{}", path, query);
+    let response = cluster_router
+        .call(
+            Request::builder()
+                .uri(uri.parse().unwrap())
+                .method(Method::POST)
+                .body(sql.to_string()),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().into_vec().await.unwrap();
+    let result = serde_json::from_slice::<MultiStatementResponse>(&body)?;
+    Ok((status, result))
+}
@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod clickhouse;
+mod flight_sql;
 mod http;
 mod mysql;
+mod postgres;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -24,10 +25,15 @@ use databend_query::servers::MySQLHandler;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
+use mysql_async::OptsBuilder;
 use mysql_async::Row;
+use mysql_async::SslOpts;
 use tokio::sync::Barrier;
 use tokio::task::JoinHandle;
 
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -45,6 +51,74 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_prepared_statement() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?, '?'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+
+    let rows: Vec<(i64, String, String)> = connection
+        .exec(&statement, (41, "a'b"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(rows, vec![(42, "a'b".to_string(), "?".to_string())]);
+
+    // Wrong number of the parameters.
+    let result: std::result::Result<Vec<EmptyRow>, _> = connection.exec("SELECT ?", (1, 2)).await;
+    assert!(result.is_err());
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tls_connection() -> Result<()> {
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(2)
+            .mysql_handler_tls_server_cert(TEST_TLS_SERVER_CERT)
+            .mysql_handler_tls_server_key(TEST_TLS_SERVER_KEY)
+            .build()?,
+    );
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    let ssl_opts = SslOpts::default()
+        .with_root_cert_path(Some(Path::new(TEST_TLS_CA_CERT)))
+        .with_danger_skip_domain_validation(true);
+    let uri = &format!("mysql://127.0.0.1:{}", runnable_server.port());
+    let opts = OptsBuilder::from_opts(mysql_async::Opts::from_url(uri).unwrap()).ssl_opts(ssl_opts);
+    let mut connection = mysql_async::Conn::new(opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "TLS connection failed")?;
+    let rows: Vec<(u8, u8)> = connection
+        .query("SELECT 1, 2")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(rows, vec![(1, 2)]);
+
+    // The plaintext connections are still accepted.
+    let mut connection = create_connection(runnable_server.port()).await?;
+    let result = connection.query_iter("SELECT 1").await;
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
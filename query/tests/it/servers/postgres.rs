@@ -0,0 +1,284 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::tokio;
+use common_base::tokio::io::AsyncReadExt;
+use common_base::tokio::io::AsyncWriteExt;
+use common_base::tokio::net::TcpStream;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_simple_query() -> Result<()> {
+    let mut handler =
+        PostgresHandler::create(SessionManagerBuilder::create().max_sessions(2).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = connect(listening.port()).await?;
+
+    send_message(&mut stream, b'Q', &cstring("SELECT 1 AS a, 'x' AS b")).await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(tags(&messages), "TDCZ");
+    assert_eq!(row_description(&messages[0].1), vec![
+        ("a".to_string(), 21),
+        ("b".to_string(), 1043)
+    ]);
+    assert_eq!(data_row(&messages[1].1), vec![
+        Some("1".to_string()),
+        Some("x".to_string())
+    ]);
+    assert_eq!(messages[2].1, cstring("SELECT 1"));
+
+    // Error.
+    send_message(
+        &mut stream,
+        b'Q',
+        &cstring("SELECT * FROM system.not_exists"),
+    )
+    .await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(tags(&messages), "EZ");
+    assert!(error_fields(&messages[0].1).contains(&(b'C', "42P01".to_string())));
+
+    // Empty query.
+    send_message(&mut stream, b'Q', &cstring(";")).await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(tags(&messages), "IZ");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_extended_query() -> Result<()> {
+    let mut handler =
+        PostgresHandler::create(SessionManagerBuilder::create().max_sessions(2).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = connect(listening.port()).await?;
+
+    // Parse.
+    let mut body = cstring("s1");
+    body.extend(cstring("SELECT $1 AS a, $2 AS b"));
+    body.extend(0_i16.to_be_bytes());
+    send_message(&mut stream, b'P', &body).await?;
+
+    // Describe the statement.
+    let mut body = vec![b'S'];
+    body.extend(cstring("s1"));
+    send_message(&mut stream, b'D', &body).await?;
+
+    // Bind, one parameter is NULL.
+    let mut body = cstring("");
+    body.extend(cstring("s1"));
+    body.extend(0_i16.to_be_bytes());
+    body.extend(2_i16.to_be_bytes());
+    body.extend(5_i32.to_be_bytes());
+    body.extend(b"it's");
+    body.extend((-1_i32).to_be_bytes());
+    body.extend(0_i16.to_be_bytes());
+    send_message(&mut stream, b'B', &body).await?;
+
+    // Describe the portal and execute.
+    let mut body = vec![b'P'];
+    body.extend(cstring(""));
+    send_message(&mut stream, b'D', &body).await?;
+    let mut body = cstring("");
+    body.extend(0_i32.to_be_bytes());
+    send_message(&mut stream, b'E', &body).await?;
+    send_message(&mut stream, b'S', &[]).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(tags(&messages), "1tT2TDCZ");
+    assert_eq!(messages[1].1, vec![0, 2, 0, 0, 0, 25, 0, 0, 0, 25]);
+    assert_eq!(data_row(&messages[5].1), vec![
+        Some("it's".to_string()),
+        None
+    ]);
+    assert_eq!(messages[6].1, cstring("SELECT 1"));
+
+    // Errors skip the messages until Sync.
+    let mut body = cstring("");
+    body.extend(cstring("not_exists"));
+    body.extend(0_i16.to_be_bytes());
+    body.extend(0_i16.to_be_bytes());
+    body.extend(0_i16.to_be_bytes());
+    send_message(&mut stream, b'B', &body).await?;
+    let mut body = cstring("");
+    body.extend(0_i32.to_be_bytes());
+    send_message(&mut stream, b'E', &body).await?;
+    send_message(&mut stream, b'S', &[]).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(tags(&messages), "EZ");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rejected_session() -> Result<()> {
+    let mut handler =
+        PostgresHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // Accepted connection
+    let _conn = connect(listening.port()).await?;
+
+    // Rejected connection
+    let mut stream = TcpStream::connect(("127.0.0.1", listening.port())).await?;
+    send_startup(&mut stream).await?;
+    let (tag, body) = read_message(&mut stream).await?;
+    assert_eq!(tag, b'E');
+    assert!(error_fields(&body).contains(&(b'C', "53300".to_string())));
+
+    Ok(())
+}
+
+async fn connect(port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+
+    // The SSL request is refused.
+    stream.write_all(&8_i32.to_be_bytes()).await?;
+    stream.write_all(&80877103_i32.to_be_bytes()).await?;
+    assert_eq!(stream.read_u8().await?, b'N');
+
+    send_startup(&mut stream).await?;
+    let (tag, body) = read_message(&mut stream).await?;
+    assert_eq!(tag, b'R');
+    assert_eq!(body, 3_i32.to_be_bytes());
+
+    send_message(&mut stream, b'p', &cstring("")).await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(messages[0], (b'R', 0_i32.to_be_bytes().to_vec()));
+    assert!(tags(&messages).ends_with("KZ"));
+    Ok(stream)
+}
+
+async fn send_startup(stream: &mut TcpStream) -> Result<()> {
+    let mut body = 196608_i32.to_be_bytes().to_vec();
+    body.extend(cstring("user"));
+    body.extend(cstring("root"));
+    body.extend(cstring("database"));
+    body.extend(cstring("default"));
+    body.push(0);
+
+    stream
+        .write_all(&(body.len() as i32 + 4).to_be_bytes())
+        .await?;
+    stream.write_all(&body).await?;
+    Ok(())
+}
+
+async fn send_message(stream: &mut TcpStream, tag: u8, body: &[u8]) -> Result<()> {
+    stream.write_u8(tag).await?;
+    stream
+        .write_all(&(body.len() as i32 + 4).to_be_bytes())
+        .await?;
+    stream.write_all(body).await?;
+    Ok(())
+}
+
+async fn read_message(stream: &mut TcpStream) -> Result<(u8, Vec<u8>)> {
+    let tag = stream.read_u8().await?;
+    let len = stream.read_i32().await?;
+    let mut body = vec![0; len as usize - 4];
+    stream.read_exact(&mut body).await?;
+    Ok((tag, body))
+}
+
+async fn read_until_ready(stream: &mut TcpStream) -> Result<Vec<(u8, Vec<u8>)>> {
+    let mut messages = vec![];
+    loop {
+        let message = read_message(stream).await?;
+        let ready = message.0 == b'Z';
+        messages.push(message);
+        if ready {
+            return Ok(messages);
+        }
+    }
+}
+
+fn tags(messages: &[(u8, Vec<u8>)]) -> String {
+    messages.iter().map(|(tag, _)| *tag as char).collect()
+}
+
+fn cstring(value: &str) -> Vec<u8> {
+    let mut bytes = value.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+fn read_cstring(body: &[u8], pos: &mut usize) -> String {
+    let end = *pos + body[*pos..].iter().position(|b| *b == 0).unwrap();
+    let value = String::from_utf8_lossy(&body[*pos..end]).to_string();
+    *pos = end + 1;
+    value
+}
+
+fn read_i16(body: &[u8], pos: &mut usize) -> i16 {
+    *pos += 2;
+    i16::from_be_bytes([body[*pos - 2], body[*pos - 1]])
+}
+
+fn read_i32(body: &[u8], pos: &mut usize) -> i32 {
+    *pos += 4;
+    i32::from_be_bytes(body[*pos - 4..*pos].try_into().unwrap())
+}
+
+fn row_description(body: &[u8]) -> Vec<(String, i32)> {
+    let mut pos = 0;
+    let num_fields = read_i16(body, &mut pos);
+    (0..num_fields)
+        .map(|_| {
+            let name = read_cstring(body, &mut pos);
+            pos += 6;
+            let type_oid = read_i32(body, &mut pos);
+            pos += 8;
+            (name, type_oid)
+        })
+        .collect()
+}
+
+fn data_row(body: &[u8]) -> Vec<Option<String>> {
+    let mut pos = 0;
+    let num_values = read_i16(body, &mut pos);
+    (0..num_values)
+        .map(|_| match read_i32(body, &mut pos) {
+            -1 => None,
+            len => {
+                let value = String::from_utf8_lossy(&body[pos..pos + len as usize]).to_string();
+                pos += len as usize;
+                Some(value)
+            }
+        })
+        .collect()
+}
+
+fn error_fields(body: &[u8]) -> Vec<(u8, String)> {
+    let mut pos = 0;
+    let mut fields = vec![];
+    while body[pos] != 0 {
+        let code = body[pos];
+        pos += 1;
+        fields.push((code, read_cstring(body, &mut pos)));
+    }
+    fields
+}
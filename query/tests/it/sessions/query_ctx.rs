@@ -13,9 +13,16 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
 use databend_query::configs::FsStorageConfig;
 use databend_query::configs::S3StorageConfig;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sql::PlanParser;
+use futures::StreamExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // This test need network
@@ -55,3 +62,73 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_statement_timeout() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_settings(
+        "statement_timeout_ms".to_string(),
+        "100".to_string(),
+        false,
+    )?;
+
+    // The stream never ends, it is aborted by the timeout.
+    let mut stream = ctx
+        .execute_with_statement_timeout(async {
+            let input: SendableDataBlockStream = Box::pin(futures::stream::pending());
+            let stream: SendableDataBlockStream = Box::pin(ctx.try_create_abortable(input)?);
+            Ok(stream)
+        })
+        .await?;
+    let error = stream.next().await.unwrap().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::StatementTimeout("").code());
+    assert_eq!(
+        error.message(),
+        "Query exceeded the statement timeout of 100ms"
+    );
+
+    // The statement never returns its stream, e.g. waiting in the query queue.
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_settings(
+        "statement_timeout_ms".to_string(),
+        "100".to_string(),
+        false,
+    )?;
+    let result = ctx
+        .execute_with_statement_timeout(futures::future::pending())
+        .await;
+    assert_eq!(
+        result.err().map(|e| e.code()),
+        Some(ErrorCode::StatementTimeout("").code())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_settings() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let session_manager = SessionManager::from_conf(conf).await?;
+    let session = session_manager.create_session(SessionType::Test).await?;
+    session.get_settings().set_max_threads(8)?;
+
+    let ctx = session.create_query_context().await?;
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "SELECT /*+ SET_VAR(max_threads = 2) SET_VAR(timezone = 'Asia/Shanghai') */ 1",
+    )
+    .await?;
+    assert!(matches!(plan, PlanNode::Select(_)));
+    assert_eq!(ctx.get_settings().get_max_threads()?, 2);
+    assert_eq!(
+        ctx.get_settings().get_timezone()?,
+        b"Asia/Shanghai".to_vec()
+    );
+
+    // The session settings are restored once the query finishes.
+    drop(ctx);
+    assert_eq!(session.get_settings().get_max_threads()?, 8);
+    assert_eq!(session.get_settings().get_timezone()?, b"UTC".to_vec());
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::QueryQueue;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.max_running_queries = 1;
+    conf.query.query_queue_timeout_secs = 10;
+    let queue = QueryQueue::create(&conf);
+
+    let permit = queue.acquire("a", "session-1").await?;
+    assert_eq!(queue.running_queries(), 1);
+
+    // The second query waits until the first one finishes.
+    let waiting_queue = queue.clone();
+    let waiting = tokio::spawn(async move { waiting_queue.acquire("b", "session-2").await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(queue.is_queued("session-2"));
+    assert_eq!(queue.queued_queries(), 1);
+
+    drop(permit);
+    let permit = waiting.await.unwrap()?;
+    assert!(!queue.is_queued("session-2"));
+    assert_eq!(queue.running_queries(), 1);
+
+    drop(permit);
+    assert_eq!(queue.running_queries(), 0);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue_per_user() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.max_running_queries = 2;
+    conf.query.max_running_queries_per_user = 1;
+    conf.query.query_queue_timeout_secs = 1;
+    let queue = QueryQueue::create(&conf);
+
+    let _permit = queue.acquire("a", "session-1").await?;
+
+    // The other users are not blocked by the queued query of the user.
+    let waiting_queue = queue.clone();
+    let waiting = tokio::spawn(async move { waiting_queue.acquire("a", "session-2").await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(queue.is_queued("session-2"));
+    let _other = queue.acquire("b", "session-3").await?;
+    assert_eq!(queue.running_queries(), 2);
+
+    // Timeout.
+    let result = waiting.await.unwrap();
+    let error = result.err().unwrap();
+    assert_eq!(error.code(), ErrorCode::QueryQueueTimeout("").code());
+    assert!(!queue.is_queued("session-2"));
+    assert_eq!(queue.running_queries(), 2);
+    Ok(())
+}
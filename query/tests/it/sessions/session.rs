@@ -15,6 +15,7 @@
 use common_base::tokio;
 use common_exception::Result;
 use common_mem_allocator::malloc_size;
+use common_meta_types::UserInfo;
 use databend_query::sessions::Session;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
@@ -50,3 +51,52 @@ async fn test_session() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_expire_idle_sessions() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.session_idle_timeout_secs = 1;
+
+    let session_manager = SessionManager::from_conf(conf).await.unwrap();
+    let mysql_session = session_manager.create_session(SessionType::MySQL).await?;
+    let test_session = session_manager.create_session(SessionType::Test).await?;
+    assert_eq!(session_manager.expire_idle_sessions(), 0);
+    assert!(!mysql_session.is_aborting());
+
+    // Only the sessions of the connections expire.
+    tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+    assert!(mysql_session.is_aborting());
+    assert!(!test_session.is_aborting());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_user_defaults() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let session_manager = SessionManager::from_conf(conf).await.unwrap();
+    let session = session_manager.create_session(SessionType::MySQL).await?;
+
+    let mut user_info = UserInfo::new_no_auth("test".to_string(), "%".to_string());
+    user_info
+        .option
+        .set_default_database(Some("system".to_string()));
+    user_info.option.set_default_settings(
+        [
+            ("max_threads".to_string(), "3".to_string()),
+            ("unknown_setting".to_string(), "1".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    session.set_current_user(user_info.clone());
+    assert_eq!(session.get_current_database(), "system");
+    assert_eq!(session.get_settings().get_max_threads()?, 3);
+
+    // The defaults are only applied to the new session.
+    session.set_current_database("default".to_string());
+    session.set_current_user(user_info);
+    assert_eq!(session.get_current_database(), "default");
+
+    Ok(())
+}
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::SettingConstraint;
+use common_meta_types::UserInfo;
 use databend_query::sessions::Session;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
@@ -28,7 +32,7 @@ async fn test_session_setting() -> Result<()> {
         conf.clone(),
         String::from("test-001"),
         SessionType::Test,
-        session_manager,
+        session_manager.clone(),
     )
     .await?;
 
@@ -41,5 +45,101 @@ async fn test_session_setting() -> Result<()> {
         assert_eq!(actual, expect);
     }
 
+    // The global settings apply to the new sessions.
+    {
+        let session = Session::try_create(
+            conf.clone(),
+            String::from("test-002"),
+            SessionType::Test,
+            session_manager.clone(),
+        )
+        .await?;
+        let settings = session.get_settings();
+        assert_eq!(settings.get_max_threads()?, 3);
+
+        settings.set_settings("max_threads".to_string(), "5".to_string(), false)?;
+        assert_eq!(settings.get_max_threads()?, 5);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_constraints() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let session_manager = SessionManager::from_conf(conf).await.unwrap();
+    let session = session_manager.create_session(SessionType::MySQL).await?;
+
+    let mut user_info = UserInfo::new_no_auth("test".to_string(), "%".to_string());
+    user_info.option.set_setting_constraint(
+        "max_memory_usage".to_string(),
+        Some(SettingConstraint {
+            min: Some(1024),
+            max: Some(4096),
+            readonly: false,
+        }),
+    );
+    user_info.option.set_setting_constraint(
+        "timezone".to_string(),
+        Some(SettingConstraint {
+            readonly: true,
+            ..Default::default()
+        }),
+    );
+    session.set_current_user(user_info);
+
+    let settings = session.get_settings();
+    settings.set_settings("max_memory_usage".to_string(), "2048".to_string(), false)?;
+    assert_eq!(settings.get_max_memory_usage()?, 2048);
+
+    for (name, value) in [
+        ("max_memory_usage", "0"),
+        ("max_memory_usage", "8192"),
+        ("timezone", "Asia/Shanghai"),
+    ] {
+        let result = settings.set_settings(name.to_string(), value.to_string(), false);
+        assert_eq!(
+            result.unwrap_err().code(),
+            ErrorCode::SettingConstraintViolation("").code()
+        );
+    }
+    assert_eq!(settings.get_max_memory_usage()?, 2048);
+
+    // The settings without constraints are not limited.
+    settings.set_settings("max_threads".to_string(), "3".to_string(), false)?;
+    assert_eq!(settings.get_max_threads()?, 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_secret() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let session_manager = SessionManager::from_conf(conf).await.unwrap();
+    let session = session_manager.create_session(SessionType::MySQL).await?;
+
+    let settings = session.get_settings();
+    let key = "aes_encryption_key".to_string();
+    let result = settings.set_settings(key.clone(), "0a1b".to_string(), true);
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::BadArguments("").code()
+    );
+
+    settings.set_settings(key.clone(), "0a1b".to_string(), false)?;
+    assert_eq!(settings.get_aes_encryption_key()?, b"0a1b".to_vec());
+
+    // The value is masked out of the settings.
+    let masked = DataValue::String(b"******".to_vec());
+    assert_eq!(settings.get_setting_values_short().get(&key), Some(&masked));
+    let values = settings.get_setting_values();
+    let value = values.iter().find_map(|value| match value {
+        DataValue::Struct(fields) if fields[0] == DataValue::String(key.clone().into_bytes()) => {
+            Some(fields[1].clone())
+        }
+        _ => None,
+    });
+    assert_eq!(value, Some(masked));
+
     Ok(())
 }
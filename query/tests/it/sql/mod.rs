@@ -16,4 +16,5 @@ mod optimizer;
 mod parsers;
 mod plan_parser;
 mod sql_parser;
+mod sql_redact;
 mod statements;
@@ -15,7 +15,10 @@
 mod parser_call;
 mod parser_copy;
 mod parser_database;
+mod parser_masking_policy;
+mod parser_network_policy;
 mod parser_optimize;
+mod parser_row_access_policy;
 mod parser_show;
 mod parser_stage;
 mod parser_table;
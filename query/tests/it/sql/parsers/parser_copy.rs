@@ -13,12 +13,18 @@
 // limitations under the License.
 
 use common_exception::Result;
+use databend_query::sql::statements::CopyIntoLocationSource;
 use databend_query::sql::statements::DfCopy;
+use databend_query::sql::statements::DfCopyIntoLocation;
 use databend_query::sql::DfStatement;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
+use sqlparser::ast::Statement;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
 
 use crate::sql::sql_parser::expect_parse_err;
+use crate::sql::sql_parser::expect_parse_err_contains;
 use crate::sql::sql_parser::expect_parse_ok;
 
 #[test]
@@ -29,45 +35,135 @@ fn copy_from_external_test() -> Result<()> {
         expect: Option<DfCopy>,
     }
 
-    let tests = vec![Test {
-        query: "copy into mytable
+    let tests = vec![
+        Test {
+            query: "copy into mytable
         from 's3://mybucket/data/files'
         credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1);",
-        err: "",
-        expect: Some(DfCopy {
-            name: ObjectName(vec![Ident::new("mytable")]),
-            columns: vec![],
-            location: "s3://mybucket/data/files".to_string(),
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: maplit::hashmap! {
+                       "aws_key_id".into() => "my_key_id".into(),
+                       "aws_secret_key".into() => "my_secret_key".into(),
+                },
+                encryption_options: maplit::hashmap! {
+                       "master_key".into() => "my_master_key".into(),
+                },
+
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                       "field_delimiter".into() => "|".into(),
+                       "skip_header".into() => "1".into(),
+                },
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+        // The list options are kept as the SQL text of the quoted values.
+        Test {
+            query: "copy into mytable
+        from 's3://mybucket/data/files'
+        file_format = (type = csv quote = '''' null_if = ('NULL', 'it''s', '') trim_space = true);",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                       "quote".into() => "'".into(),
+                       "null_if".into() => "('NULL', 'it''s', '')".into(),
+                       "trim_space".into() => "true".into(),
+                },
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+    ];
+
+    for test in tests {
+        if test.err.is_empty() {
+            expect_parse_ok(test.query, DfStatement::Copy(test.expect.unwrap()))?;
+        } else {
+            expect_parse_err(test.query, test.err.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn copy_into_location_test() -> Result<()> {
+    expect_parse_ok(
+        "copy into 's3://mybucket/data/files/'
+        from db1.mytable
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = parquet)
+        max_file_size = 1024 overwrite = true",
+        DfStatement::CopyIntoLocation(DfCopyIntoLocation {
+            location: "s3://mybucket/data/files/".to_string(),
+            source: CopyIntoLocationSource::Table(ObjectName(vec![
+                Ident::new("db1"),
+                Ident::new("mytable"),
+            ])),
             credential_options: maplit::hashmap! {
                    "aws_key_id".into() => "my_key_id".into(),
                    "aws_secret_key".into() => "my_secret_key".into(),
             },
-            encryption_options: maplit::hashmap! {
-                   "master_key".into() => "my_master_key".into(),
+            encryption_options: Default::default(),
+            file_format_options: maplit::hashmap! {
+                   "type".into() => "parquet".into(),
             },
+            max_file_size: "1024".to_string(),
+            overwrite: "true".to_string(),
+            single: "".to_string(),
+        }),
+    )?;
 
+    let query = match Parser::parse_sql(&GenericDialect {}, "select a, b from mytable where a > 1")
+        .unwrap()
+        .remove(0)
+    {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+    expect_parse_ok(
+        "copy into '@my_stage/result.csv'
+        from (select a, b from mytable where a > 1)
+        file_format = (type = csv field_delimiter = '|')
+        single = true",
+        DfStatement::CopyIntoLocation(DfCopyIntoLocation {
+            location: "@my_stage/result.csv".to_string(),
+            source: CopyIntoLocationSource::Query(query),
+            credential_options: Default::default(),
+            encryption_options: Default::default(),
             file_format_options: maplit::hashmap! {
                    "type".into() => "csv".into(),
                    "field_delimiter".into() => "|".into(),
-                   "skip_header".into() => "1".into(),
             },
-            files: vec![],
-            pattern: "".to_string(),
-            on_error: "".to_string(),
-            size_limit: "".to_string(),
-            validation_mode: "".to_string(),
+            max_file_size: "".to_string(),
+            overwrite: "".to_string(),
+            single: "true".to_string(),
         }),
-    }];
+    )?;
 
-    for test in tests {
-        if test.err.is_empty() {
-            expect_parse_ok(test.query, DfStatement::Copy(test.expect.unwrap()))?;
-        } else {
-            expect_parse_err(test.query, test.err.to_string())?;
-        }
-    }
+    expect_parse_err_contains(
+        "copy into 's3://mybucket/data/files/' mytable",
+        "Expected FROM, found: mytable".to_string(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfCreateMaskingPolicy;
+use databend_query::sql::statements::DfDropMaskingPolicy;
+use databend_query::sql::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn test_create_masking_policy() -> Result<()> {
+    expect_parse_ok(
+        "CREATE MASKING POLICY email_mask AS (val) -> concat(left(val, 1), '***')",
+        DfStatement::CreateMaskingPolicy(DfCreateMaskingPolicy {
+            if_not_exists: false,
+            policy_name: "email_mask".to_string(),
+            parameters: vec!["val".to_string()],
+            definition: "concat(left(val, 1), '***')".to_string(),
+            exempt_roles: vec![],
+            description: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE MASKING POLICY IF NOT EXISTS email_mask AS (val) -> '***' EXEMPT ROLES = ('admin') DESC = 'hide the emails'",
+        DfStatement::CreateMaskingPolicy(DfCreateMaskingPolicy {
+            if_not_exists: true,
+            policy_name: "email_mask".to_string(),
+            parameters: vec!["val".to_string()],
+            definition: "'***'".to_string(),
+            exempt_roles: vec!["admin".to_string()],
+            description: "hide the emails".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE MASKING POLICY email_mask AS val -> '***'",
+        "Expected (, found: val".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_drop_masking_policy() -> Result<()> {
+    expect_parse_ok(
+        "DROP MASKING POLICY email_mask",
+        DfStatement::DropMaskingPolicy(DfDropMaskingPolicy {
+            if_exists: false,
+            policy_name: "email_mask".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP MASKING POLICY IF EXISTS email_mask",
+        DfStatement::DropMaskingPolicy(DfDropMaskingPolicy {
+            if_exists: true,
+            policy_name: "email_mask".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfCreateNetworkPolicy;
+use databend_query::sql::statements::DfDropNetworkPolicy;
+use databend_query::sql::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn test_create_network_policy() -> Result<()> {
+    expect_parse_ok(
+        "CREATE NETWORK POLICY office_only ALLOWED_IP_LIST = ('192.168.1.0/24', '10.0.0.1')",
+        DfStatement::CreateNetworkPolicy(DfCreateNetworkPolicy {
+            if_not_exists: false,
+            policy_name: "office_only".to_string(),
+            allowed_ip_list: vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+            blocked_ip_list: vec![],
+            comment: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE NETWORK POLICY IF NOT EXISTS office_only ALLOWED_IP_LIST = () BLOCKED_IP_LIST = ('192.168.1.99') COMMENT = 'no guests'",
+        DfStatement::CreateNetworkPolicy(DfCreateNetworkPolicy {
+            if_not_exists: true,
+            policy_name: "office_only".to_string(),
+            allowed_ip_list: vec![],
+            blocked_ip_list: vec!["192.168.1.99".to_string()],
+            comment: "no guests".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE NETWORK POLICY office_only ALLOWED_IP_LIST = '10.0.0.1'",
+        "Expected (, found: '10.0.0.1'".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_drop_network_policy() -> Result<()> {
+    expect_parse_ok(
+        "DROP NETWORK POLICY office_only",
+        DfStatement::DropNetworkPolicy(DfDropNetworkPolicy {
+            if_exists: false,
+            policy_name: "office_only".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP NETWORK POLICY IF EXISTS office_only",
+        DfStatement::DropNetworkPolicy(DfDropNetworkPolicy {
+            if_exists: true,
+            policy_name: "office_only".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfCreateRowAccessPolicy;
+use databend_query::sql::statements::DfDropRowAccessPolicy;
+use databend_query::sql::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn test_create_row_access_policy() -> Result<()> {
+    expect_parse_ok(
+        "CREATE ROW ACCESS POLICY p1 AS (region) -> region = 'east'",
+        DfStatement::CreateRowAccessPolicy(DfCreateRowAccessPolicy {
+            if_not_exists: false,
+            policy_name: "p1".to_string(),
+            parameters: vec!["region".to_string()],
+            definition: "region = 'east'".to_string(),
+            exempt_roles: vec![],
+            description: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE ROW ACCESS POLICY IF NOT EXISTS p1 AS (a, b) -> a > b EXEMPT ROLES = ('admin', 'auditor') DESC = 'a greater than b'",
+        DfStatement::CreateRowAccessPolicy(DfCreateRowAccessPolicy {
+            if_not_exists: true,
+            policy_name: "p1".to_string(),
+            parameters: vec!["a".to_string(), "b".to_string()],
+            definition: "a > b".to_string(),
+            exempt_roles: vec!["admin".to_string(), "auditor".to_string()],
+            description: "a greater than b".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE ROW ACCESS POLICY p1 AS region -> region = 'east'",
+        "Expected (, found: region".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE ROW ACCESS POLICY p1 AS (region) -> region = 'east' EXEMPT ROLES 'admin'",
+        "Expected =, found: 'admin'".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_drop_row_access_policy() -> Result<()> {
+    expect_parse_ok(
+        "DROP ROW ACCESS POLICY p1",
+        DfStatement::DropRowAccessPolicy(DfDropRowAccessPolicy {
+            if_exists: false,
+            policy_name: "p1".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP ROW ACCESS POLICY IF EXISTS p1",
+        DfStatement::DropRowAccessPolicy(DfDropRowAccessPolicy {
+            if_exists: true,
+            policy_name: "p1".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
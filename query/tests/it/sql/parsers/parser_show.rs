@@ -44,7 +44,16 @@ fn show_queries() -> Result<()> {
         "SHOW FULL TABLES;",
         DfStatement::ShowTables(DfShowTables::create(DfShowKind::All, true, None)),
     )?;
-    expect_parse_ok("SHOW SETTINGS", DfStatement::ShowSettings(DfShowSettings))?;
+    expect_parse_ok(
+        "SHOW SETTINGS",
+        DfStatement::ShowSettings(DfShowSettings::create(DfShowKind::All)),
+    )?;
+    expect_parse_ok(
+        "SHOW SETTINGS LIKE 'max%'",
+        DfStatement::ShowSettings(DfShowSettings::create(DfShowKind::Like(Ident::with_quote(
+            '\'', "max%",
+        )))),
+    )?;
     expect_parse_ok(
         "SHOW TABLES LIKE 'aaa'",
         DfStatement::ShowTables(DfShowTables::create(
@@ -72,8 +72,18 @@ fn create_stage_test() -> Result<()> {
         "list @abc pattern = '*.csv'",
         DfStatement::List(DfList {
             location: "@abc".to_string(),
+            files: vec![],
             pattern: "*.csv".to_string(),
         }),
     )?;
+
+    expect_parse_ok(
+        "list @abc files = ('a.csv', 'b.csv') pattern = '.*[.]csv'",
+        DfStatement::List(DfList {
+            location: "@abc".to_string(),
+            files: vec!["a.csv".to_string(), "b.csv".to_string()],
+            pattern: ".*[.]csv".to_string(),
+        }),
+    )?;
     Ok(())
 }
@@ -24,6 +24,8 @@ use databend_query::sql::statements::DfQueryStatement;
 use databend_query::sql::statements::DfRenameTable;
 use databend_query::sql::statements::DfShowCreateTable;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUndropTable;
+use databend_query::sql::statements::DfVacuumDropTable;
 use databend_query::sql::*;
 use sqlparser::ast::*;
 
@@ -170,6 +172,56 @@ fn create_table_select() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_external_table() -> Result<()> {
+    expect_parse_ok(
+        "CREATE EXTERNAL TABLE t(c1 int) LOCATION 's3://bucket/prefix/' FORMAT PARQUET",
+        DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "EXTERNAL".to_string(),
+            options: maplit::hashmap! {
+                "location".into() => "s3://bucket/prefix/".into(),
+                "format".into() => "PARQUET".into(),
+            },
+            like: None,
+            query: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE EXTERNAL TABLE IF NOT EXISTS t(c1 int) LOCATION = 's3://bucket/prefix/' \
+        CREDENTIALS = (aws_key_id='key' aws_secret_key='secret') FORMAT = 'parquet'",
+        DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "EXTERNAL".to_string(),
+            options: maplit::hashmap! {
+                "location".into() => "s3://bucket/prefix/".into(),
+                "aws_key_id".into() => "key".into(),
+                "aws_secret_key".into() => "secret".into(),
+                "format".into() => "parquet".into(),
+            },
+            like: None,
+            query: None,
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE EXTERNAL TABLE t LOCATION 's3://bucket/prefix/' FORMAT PARQUET",
+        "external table must have column definitions".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE EXTERNAL TABLE t(c1 int) FORMAT PARQUET",
+        "Expected LOCATION".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn drop_table() -> Result<()> {
     {
@@ -177,6 +229,7 @@ fn drop_table() -> Result<()> {
         let expected = DfStatement::DropTable(DfDropTable {
             if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
+            all: false,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -186,10 +239,70 @@ fn drop_table() -> Result<()> {
         let expected = DfStatement::DropTable(DfDropTable {
             if_exists: true,
             name: ObjectName(vec![Ident::new("t1")]),
+            all: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP TABLE db1.t1 ALL";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            all: true,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn undrop_table() -> Result<()> {
+    {
+        let sql = "UNDROP TABLE t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("t1")]),
         });
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "UNDROP TABLE db1.t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains("UNDROP t1", "Expected TABLE".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn vacuum_drop_table() -> Result<()> {
+    {
+        let sql = "VACUUM DROP TABLE";
+        let expected = DfStatement::VacuumDropTable(DfVacuumDropTable {
+            database: None,
+            retain_hours: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "VACUUM DROP TABLE FROM db1 RETAIN 24 HOURS";
+        let expected = DfStatement::VacuumDropTable(DfVacuumDropTable {
+            database: Some("db1".to_string()),
+            retain_hours: Some(24),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains("VACUUM t1", "Expected DROP".to_string())?;
+    expect_parse_err_contains("VACUUM DROP TABLE RETAIN 24", "Expected HOURS".to_string())?;
+
     Ok(())
 }
 
@@ -208,6 +321,56 @@ fn alter_table() -> Result<()> {
         expect_parse_ok(sql, expected)?;
     }
 
+    // alter table add row access policy
+    {
+        let sql = "ALTER TABLE t1 ADD ROW ACCESS POLICY p1 ON (a, b)";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::AddRowAccessPolicy {
+                policy_name: "p1".to_string(),
+                columns: vec!["a".to_string(), "b".to_string()],
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    // alter table drop row access policy
+    {
+        let sql = "ALTER TABLE t1 DROP ROW ACCESS POLICY p1";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::DropRowAccessPolicy("p1".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    // alter table set masking policy
+    {
+        let sql = "ALTER TABLE t1 MODIFY COLUMN email SET MASKING POLICY email_mask";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::SetMaskingPolicy {
+                column: "email".to_string(),
+                policy_name: "email_mask".to_string(),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    // alter table unset masking policy
+    {
+        let sql = "ALTER TABLE t1 MODIFY COLUMN email UNSET MASKING POLICY";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::UnsetMaskingPolicy("email".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     Ok(())
 }
 
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_meta_types::UDFLanguage;
 use databend_query::sql::statements::DfAlterUDF;
 use databend_query::sql::statements::DfCreateUDF;
 use databend_query::sql::statements::DfDropUDF;
+use databend_query::sql::statements::DfUDFServer;
 use databend_query::sql::*;
+use sqlparser::ast::DataType;
 
 use crate::sql::sql_parser::*;
 
@@ -65,6 +68,9 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
             description: "".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: false,
         }),
     )?;
 
@@ -74,8 +80,25 @@ fn test_create_udf() -> Result<()> {
             if_not_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
-            definition: "not(isnotnull(p,d))".to_string(),
+            definition: "not(isnotnull(p, d))".to_string(),
             description: "".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: false,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE FUNCTION is_adult AS (age) ->  age >= 18 and age < 65  DESC = 'adult'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "is_adult".to_string(),
+            parameters: vec!["age".to_string()],
+            definition: "age >= 18 and age < 65".to_string(),
+            description: "adult".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: false,
         }),
     )?;
 
@@ -95,8 +118,11 @@ fn test_create_udf() -> Result<()> {
             if_not_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
-            definition: "not(isnotnull(p,d))".to_string(),
+            definition: "not(isnotnull(p, d))".to_string(),
             description: "this is a description".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: false,
         }),
     )?;
 
@@ -106,14 +132,145 @@ fn test_create_udf() -> Result<()> {
             if_not_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
-            definition: "not(isnotnull(p,d))".to_string(),
+            definition: "not(isnotnull(p, d))".to_string(),
             description: "this is a description".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: false,
         }),
     )?;
 
     Ok(())
 }
 
+#[test]
+fn test_create_aggregate_udf() -> Result<()> {
+    expect_parse_ok(
+        "CREATE AGGREGATE FUNCTION IF NOT EXISTS my_avg AS (x) -> sum(x) / count(x) DESC = 'avg'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: true,
+            udf_name: "my_avg".to_string(),
+            parameters: vec!["x".to_string()],
+            definition: "sum(x) / count(x)".to_string(),
+            description: "avg".to_string(),
+            language: UDFLanguage::Sql,
+            server: None,
+            is_aggregate: true,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "ALTER AGGREGATE FUNCTION my_avg AS (x) -> avg(x)",
+        DfStatement::AlterUDF(DfAlterUDF {
+            udf_name: "my_avg".to_string(),
+            parameters: vec!["x".to_string()],
+            definition: "avg(x)".to_string(),
+            description: "".to_string(),
+            is_aggregate: true,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP AGGREGATE FUNCTION IF EXISTS my_avg",
+        DfStatement::DropUDF(DfDropUDF {
+            if_exists: true,
+            udf_name: "my_avg".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE AGGREGATE my_avg AS (x) -> sum(x)",
+        "Expected FUNCTION".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_create_wasm_udf() -> Result<()> {
+    expect_parse_ok(
+        "CREATE FUNCTION gcd (INT, INT) RETURNS INT LANGUAGE WASM HANDLER = 'gcd' AS '@my_stage/f.wasm' DESC = 'from wasm'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "gcd".to_string(),
+            parameters: vec![],
+            definition: "gcd".to_string(),
+            description: "from wasm".to_string(),
+            language: UDFLanguage::Wasm,
+            server: Some(DfUDFServer {
+                arg_types: vec![DataType::Int(None), DataType::Int(None)],
+                return_type: DataType::Int(None),
+                language: "WASM".to_string(),
+                handler: "gcd".to_string(),
+                address: "@my_stage/f.wasm".to_string(),
+            }),
+            is_aggregate: false,
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION gcd (INT, INT) RETURNS INT LANGUAGE WASM HANDLER = 'gcd' AS '/tmp/f.wasm'",
+        "WASM module must be a stage path like '@my_stage/f.wasm', got: /tmp/f.wasm".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION gcd (INT, INT) RETURNS INT LANGUAGE WASM HANDLER = 'gcd' ADDRESS = '127.0.0.1:8815'",
+        "Expected AS".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn test_create_external_udf() -> Result<()> {
+    expect_parse_ok(
+        "CREATE FUNCTION gcd (INT, INT) RETURNS INT LANGUAGE python HANDLER = 'gcd' ADDRESS = '127.0.0.1:8815'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "gcd".to_string(),
+            parameters: vec![],
+            definition: "gcd".to_string(),
+            description: "".to_string(),
+            language: UDFLanguage::External,
+            server: Some(DfUDFServer {
+                arg_types: vec![DataType::Int(None), DataType::Int(None)],
+                return_type: DataType::Int(None),
+                language: "python".to_string(),
+                handler: "gcd".to_string(),
+                address: "127.0.0.1:8815".to_string(),
+            }),
+            is_aggregate: false,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE FUNCTION IF NOT EXISTS ping () RETURNS VARCHAR LANGUAGE python HANDLER = 'ping' ADDRESS = 'localhost:8815' DESC = 'ping the server'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: true,
+            udf_name: "ping".to_string(),
+            parameters: vec![],
+            definition: "ping".to_string(),
+            description: "ping the server".to_string(),
+            language: UDFLanguage::External,
+            server: Some(DfUDFServer {
+                arg_types: vec![],
+                return_type: DataType::Varchar(None),
+                language: "python".to_string(),
+                handler: "ping".to_string(),
+                address: "localhost:8815".to_string(),
+            }),
+            is_aggregate: false,
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION gcd (INT, INT) RETURNS INT LANGUAGE python ADDRESS = '127.0.0.1:8815'",
+        "Expected HANDLER".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn test_drop_udf() -> Result<()> {
     expect_parse_ok(
@@ -179,6 +336,7 @@ fn test_alter_udf() -> Result<()> {
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
             description: "".to_string(),
+            is_aggregate: false,
         }),
     )?;
 
@@ -187,8 +345,9 @@ fn test_alter_udf() -> Result<()> {
         DfStatement::AlterUDF(DfAlterUDF {
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
-            definition: "not(isnotnull(p,d))".to_string(),
+            definition: "not(isnotnull(p, d))".to_string(),
             description: "".to_string(),
+            is_aggregate: false,
         }),
     )?;
 
@@ -207,8 +366,9 @@ fn test_alter_udf() -> Result<()> {
         DfStatement::AlterUDF(DfAlterUDF {
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
-            definition: "not(isnotnull(p,d))".to_string(),
+            definition: "not(isnotnull(p, d))".to_string(),
             description: "this is a description".to_string(),
+            is_aggregate: false,
         }),
     )?;
 
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_exception::Result;
 use common_meta_types::PrincipalIdentity;
+use common_meta_types::SettingConstraint;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
@@ -27,6 +30,7 @@ use databend_query::sql::statements::DfGrantObject;
 use databend_query::sql::statements::DfGrantPrivilegeStatement;
 use databend_query::sql::statements::DfGrantRoleStatement;
 use databend_query::sql::statements::DfRevokePrivilegeStatement;
+use databend_query::sql::statements::DfSetRole;
 use databend_query::sql::statements::DfShowGrants;
 use databend_query::sql::statements::DfUserWithOption;
 use databend_query::sql::*;
@@ -148,6 +152,28 @@ fn create_user_test() -> Result<()> {
         }),
     )?;
 
+    let with_options = vec![
+        DfUserWithOption::DefaultDatabase("db1".to_string()),
+        DfUserWithOption::DefaultRole("role1".to_string()),
+        DfUserWithOption::DefaultSettings(
+            [
+                ("max_threads".to_string(), "8".to_string()),
+                ("timezone".to_string(), "Asia/Shanghai".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+    ];
+    expect_parse_ok(
+        "CREATE USER 'operator' WITH DEFAULT_DATABASE = 'db1', DEFAULT_ROLE = 'role1', DEFAULT_SETTINGS = (max_threads = 8 timezone = 'Asia/Shanghai') NOT IDENTIFIED",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: false,
+            user: UserIdentity::new("operator", "%"),
+            auth_option: DfAuthOption::no_password(),
+            with_options,
+        }),
+    )?;
+
     // create user with option
     expect_parse_err(
         "CREATE USER 'operator' NOT IDENTIFIED WITH TENANTSETTINGS",
@@ -271,6 +297,45 @@ fn alter_user_test() -> Result<()> {
         String::from("sql parser error: Expected user option, found: TEST"),
     )?;
 
+    let with_options = vec![
+        DfUserWithOption::SettingConstraint(
+            "max_memory_usage".to_string(),
+            Some(SettingConstraint {
+                min: None,
+                max: Some(1073741824),
+                readonly: false,
+            }),
+        ),
+        DfUserWithOption::SettingConstraint("max_threads".to_string(), None),
+    ];
+    expect_parse_ok(
+        "ALTER USER 'test'@'%' WITH SETTING_CONSTRAINT max_memory_usage = (max = 1073741824), SETTING_CONSTRAINT max_threads = ()",
+        DfStatement::AlterUser(DfAlterUser {
+            if_current_user: false,
+            user: UserIdentity::new("test", "%"),
+            auth_option: None,
+            with_options,
+        }),
+    )?;
+
+    expect_parse_err(
+        "ALTER USER 'test'@'%' WITH SETTING_CONSTRAINT max_threads = (maximum = 8)",
+        String::from("sql parser error: Unknown setting constraint: maximum"),
+    )?;
+
+    expect_parse_ok(
+        "ALTER USER 'test'@'%' WITH MUST_CHANGE_PASSWORD IDENTIFIED BY 'password'",
+        DfStatement::AlterUser(DfAlterUser {
+            if_current_user: false,
+            user: UserIdentity::new("test", "%"),
+            auth_option: Some(DfAuthOption {
+                auth_type: None,
+                by_value: Some("password".to_string()),
+            }),
+            with_options: vec![DfUserWithOption::MustChangePassword],
+        }),
+    )?;
+
     Ok(())
 }
 
@@ -361,6 +426,7 @@ fn grant_privilege_test() -> Result<()> {
             principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -370,6 +436,7 @@ fn grant_privilege_test() -> Result<()> {
             principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -383,6 +450,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Insert);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -396,6 +464,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Insert);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -409,6 +478,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Insert);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -423,6 +493,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Create);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -437,6 +508,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Create);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -453,6 +525,21 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::Select);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT CREATE STAGE ON *.* TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantPrivilegeStatement {
+            principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
+            on: DfGrantObject::Global,
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::CreateStage);
+                privileges
+            },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -467,6 +554,7 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::CreateRole);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
         }),
     )?;
 
@@ -480,6 +568,28 @@ fn grant_privilege_test() -> Result<()> {
                 privileges.set_privilege(UserPrivilegeType::CreateUser);
                 privileges
             },
+            column_priv_types: BTreeMap::new(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT SELECT (a, b), INSERT (a), UPDATE ON db1.tb1 TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantPrivilegeStatement {
+            principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
+            on: DfGrantObject::Table(Some("db1".into()), "tb1".into()),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Update);
+                privileges
+            },
+            column_priv_types: {
+                let mut a = UserPrivilegeSet::empty();
+                a.set_privilege(UserPrivilegeType::Select);
+                a.set_privilege(UserPrivilegeType::Insert);
+                let mut b = UserPrivilegeSet::empty();
+                b.set_privilege(UserPrivilegeType::Select);
+                BTreeMap::from([("a".to_string(), a), ("b".to_string(), b)])
+            },
         }),
     )?;
 
@@ -519,6 +629,21 @@ fn revoke_privilege_test() -> Result<()> {
             principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
             on: DfGrantObject::Database(None),
             priv_types: UserPrivilegeSet::all_privileges(),
+            column_priv_types: BTreeMap::new(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "REVOKE SELECT (a) ON db1.tb1 FROM 'test'@'localhost'",
+        DfStatement::RevokePrivilege(DfRevokePrivilegeStatement {
+            principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
+            on: DfGrantObject::Table(Some("db1".into()), "tb1".into()),
+            priv_types: UserPrivilegeSet::empty(),
+            column_priv_types: BTreeMap::from([("a".to_string(), {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Select);
+                privileges
+            })]),
         }),
     )?;
 
@@ -572,6 +697,23 @@ fn drop_role_test() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn set_role_test() -> Result<()> {
+    expect_parse_ok(
+        "SET ROLE 'test'",
+        DfStatement::SetRole(DfSetRole {
+            role_name: Some(String::from("test")),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SET ROLE DEFAULT",
+        DfStatement::SetRole(DfSetRole { role_name: None }),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn grant_role_test() -> Result<()> {
     // grant role to user without hostname
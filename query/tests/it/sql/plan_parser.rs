@@ -141,6 +141,18 @@ async fn test_plan_parser() -> Result<()> {
             expect: "Projection: cast('1' as Int32):Int32\n  Expression: cast(1 as Int32):Int32 (Before Projection)\n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
             error: "",
         },
+        Test {
+            name: "try-cast-passed",
+            sql: "select try_cast('1' as int)",
+            expect: "Projection: try_cast('1' as Nullable(Int32)):Nullable(Int32)\n  Expression: try_cast(1 as Nullable(Int32)):Nullable(Int32) (Before Projection)\n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "lambda-passed",
+            sql: "select array_map(x -> x + 1, array(1, 2))",
+            expect: "Projection: array_map(x -> (x + 1), array(1, 2)):Array(UInt16)\n  Expression: array_map(x -> (x + 1), array(1, 2)):Array(UInt16) (Before Projection)\n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
         Test {
             name: "database-passed",
             sql: "select database()",
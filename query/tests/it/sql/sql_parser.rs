@@ -123,5 +123,25 @@ fn hint_test() -> Result<()> {
         assert_eq!(expected.error_code, None);
     }
 
+    {
+        let comment = " SET_VAR(max_threads = 4) set_var(TIMEZONE='UTC') SET_VAR(unclosed ";
+        let expected = DfHint::create_from_optimizer_hint(comment);
+        assert_eq!(expected.settings, vec![
+            ("max_threads".to_string(), "4".to_string()),
+            ("timezone".to_string(), "UTC".to_string()),
+        ]);
+    }
+
+    {
+        let (_, hints) = DfParser::parse_sql(
+            "SELECT /*+ SET_VAR(max_threads = 2) */ 1 /* SET_VAR(max_threads = 3) */",
+        )?;
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].settings, vec![(
+            "max_threads".to_string(),
+            "2".to_string()
+        )]);
+    }
+
     Ok(())
 }
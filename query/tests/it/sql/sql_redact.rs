@@ -0,0 +1,46 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_query::sql::redact_query_text;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_redact_query_text() {
+    let tests = vec![
+        (
+            "CREATE USER 'u1'@'%' IDENTIFIED BY 'pass''word'",
+            "CREATE USER 'u1'@'%' IDENTIFIED BY '******'",
+        ),
+        (
+            "alter user u1 identified with sha256_password by 'password' with default_role=r1",
+            "alter user u1 identified with sha256_password by '******' with default_role=r1",
+        ),
+        (
+            "create stage s1 url='s3://load/files/' credentials=(aws_key_id='1a2b' aws_secret_key='4x5y') encryption=(master_key = 'key')",
+            "create stage s1 url='s3://load/files/' credentials=(aws_key_id='******' aws_secret_key='******') encryption=(master_key = '******')",
+        ),
+        (
+            "SET aes_encryption_key = 'key'",
+            "SET aes_encryption_key = '******'",
+        ),
+        (
+            "select /*+ SET_VAR(aes_encryption_key='key') */ 'identified by', `credentials` -- by 'x'",
+            "select /*+ SET_VAR(aes_encryption_key='******') */ 'identified by', `credentials` -- by 'x'",
+        ),
+        ("select 'password'", "select 'password'"),
+    ];
+    for (query, expected) in tests {
+        assert_eq!(redact_query_text(query), expected, "{}", query);
+    }
+}
@@ -13,8 +13,10 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_arrow::arrow::io::parquet::write::Version;
 use common_base::tokio;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -22,9 +24,11 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::storages::fuse::io::BlockCompactor;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::io::BlockWriteOptions;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::meta::Versioned;
+use databend_query::storages::fuse::FuseTableMetrics;
 use databend_query::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -61,6 +65,8 @@ async fn test_fuse_table_block_appender() {
         DEFAULT_BLOCK_PER_SEGMENT,
         0,
         locs.clone(),
+        BlockWriteOptions::default(),
+        FuseTableMetrics::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -88,6 +94,8 @@ async fn test_fuse_table_block_appender() {
         max_rows_per_block,
         max_blocks_per_segment,
         locs.clone(),
+        BlockWriteOptions::default(),
+        FuseTableMetrics::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -108,6 +116,8 @@ async fn test_fuse_table_block_appender() {
         DEFAULT_BLOCK_PER_SEGMENT,
         0,
         locs,
+        BlockWriteOptions::default(),
+        FuseTableMetrics::default(),
     )
     .await
     .collect::<Vec<_>>()
@@ -278,6 +288,8 @@ async fn test_block_stream_writer() -> common_exception::Result<()> {
             max_rows_per_block,
             max_blocks_per_segment,
             locs,
+            BlockWriteOptions::default(),
+            FuseTableMetrics::default(),
         )
         .await;
         let segs = stream.try_collect::<Vec<_>>().await?;
@@ -329,6 +341,48 @@ async fn test_block_stream_writer() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_block_write_options() -> Result<()> {
+    let options = |kvs: &[(&str, &str)]| {
+        kvs.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let default = BlockWriteOptions::try_from_table_options(&options(&[]))?;
+    assert_eq!(default, BlockWriteOptions::default());
+    assert_eq!(default.row_per_page, 0);
+    assert!(!default.dictionary_encoding);
+    assert!(!default.write_statistics);
+    assert_eq!(default.version, Version::V2);
+
+    let write_options = BlockWriteOptions::try_from_table_options(&options(&[
+        ("row_per_page", "1024"),
+        ("dictionary_encoding", "TRUE"),
+        ("statistics_level", "page"),
+        ("writer_version", "1"),
+        ("row_per_block", "10"),
+    ]))?;
+    assert_eq!(write_options, BlockWriteOptions {
+        row_per_page: 1024,
+        dictionary_encoding: true,
+        write_statistics: true,
+        version: Version::V1,
+    });
+
+    for (key, value) in [
+        ("row_per_page", "-1"),
+        ("dictionary_encoding", "yes"),
+        ("statistics_level", "row"),
+        ("writer_version", "3"),
+    ] {
+        let err = BlockWriteOptions::try_from_table_options(&options(&[(key, value)])).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BadOption("").code());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_meta_locations() -> Result<()> {
     let test_prefix = "test_pref";
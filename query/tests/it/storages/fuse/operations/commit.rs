@@ -15,6 +15,8 @@
 use common_base::tokio;
 use common_datablocks::DataBlock;
 use common_exception::Result;
+use common_metrics::init_default_metrics_recorder;
+use common_metrics::try_handle;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::execute_query;
@@ -22,6 +24,7 @@ use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test]
 async fn test_fuse_occ_retry() -> Result<()> {
+    init_default_metrics_recorder();
     let fixture = TestFixture::new().await;
     let db = fixture.default_db_name();
     let tbl = fixture.default_table_name();
@@ -75,5 +78,17 @@ async fn test_fuse_occ_retry() -> Result<()> {
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
 
+    // the retry, the writes and the reads are exported with the label of the table
+    let output = try_handle().unwrap().render();
+    for metric in [
+        "fuse_commit_retries",
+        "fuse_blocks_written",
+        "fuse_blocks_read",
+        "fuse_pruning_blocks_scanned",
+    ] {
+        assert!(output.contains(metric), "{} is not exported", metric);
+    }
+    assert!(output.contains(&format!("table=\"{}\"", tbl)));
+
     Ok(())
 }
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::storages::index::ColumnLeaves;
+
+#[test]
+fn test_column_leaves() -> Result<()> {
+    let inner_type: DataTypePtr = Arc::new(StructType::create(
+        vec!["c".to_string(), "d".to_string()],
+        vec![Int64Type::arc(), StringType::arc()],
+    ));
+    let struct_type: DataTypePtr = Arc::new(StructType::create(
+        vec!["b".to_string(), "e".to_string()],
+        vec![inner_type.clone(), Int64Type::arc()],
+    ));
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", Int64Type::arc()),
+        DataField::new("s", struct_type.clone()),
+        DataField::new("f", StringType::arc()),
+    ]);
+
+    let leaves = ColumnLeaves::new_from_schema(&schema);
+    let leaf_names = leaves
+        .leaf_schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(leaf_names, vec!["a", "s.b.c", "s.b.d", "s.e", "f"]);
+    assert_eq!(leaves.leaf_ids(&[2, 1]), vec![4, 1, 2, 3]);
+
+    let inner: ColumnRef = Arc::new(StructColumn::from_data(
+        vec![Series::from_data([1i64, 2]), Series::from_data(["x", "y"])],
+        inner_type,
+    ));
+    let s: ColumnRef = Arc::new(StructColumn::from_data(
+        vec![inner, Series::from_data([3i64, 4])],
+        struct_type,
+    ));
+    let columns = vec![
+        Series::from_data([5i64, 6]),
+        s.clone(),
+        Series::from_data(["z", "w"]),
+    ];
+    let block = DataBlock::create(schema, columns);
+
+    let leaf_block = leaves.flatten_block(&block)?;
+    assert_eq!(leaf_block.num_columns(), 5);
+    assert_eq!(leaf_block.column(3), &Series::from_data([3i64, 4]));
+
+    let leaf_columns = leaves
+        .leaf_ids(&[1])
+        .iter()
+        .map(|i| leaf_block.column(*i).clone())
+        .collect();
+    let columns = leaves.rebuild_columns(&[1], leaf_columns)?;
+    assert_eq!(columns, vec![s]);
+    Ok(())
+}
+
+#[test]
+fn test_column_leaves_map() -> Result<()> {
+    let map_type = MapType::arc(StringType::arc(), Int64Type::arc());
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("m", map_type.clone()),
+        DataField::new("a", Int64Type::arc()),
+    ]);
+
+    let leaves = ColumnLeaves::new_from_schema(&schema);
+    let leaf_schema = leaves.leaf_schema();
+    assert_eq!(leaf_schema.field(0).name(), "m.keys");
+    assert_eq!(
+        leaf_schema.field(1).data_type(),
+        &ArrayType::arc(Int64Type::arc())
+    );
+    assert_eq!(leaves.leaf_ids(&[1]), vec![2]);
+
+    let entry = DataValue::Struct(vec![b"k".as_slice().into(), DataValue::Int64(1)]);
+    let m = map_type.create_column(&[DataValue::Array(vec![entry]), DataValue::Array(vec![])])?;
+    let block = DataBlock::create(schema, vec![m.clone(), Series::from_data([1i64, 2])]);
+
+    let leaf_block = leaves.flatten_block(&block)?;
+    assert_eq!(leaf_block.num_columns(), 3);
+
+    let columns = leaves.rebuild_columns(&[0], leaf_block.columns()[0..2].to_vec())?;
+    assert_eq!(columns, vec![m]);
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bloom_filter;
+mod column_leaves;
 mod index_min_max;
 mod index_sparse;
 mod range_filter;
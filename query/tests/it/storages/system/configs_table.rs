@@ -34,72 +34,115 @@ async fn test_configs_table() -> Result<()> {
     assert_eq!(block.num_columns(), 4);
 
     let expected = vec![
-        "+--------------------------------------+--------------------------+---------+-------------+",
-        "| name                                 | value                    | group   | description |",
-        "+--------------------------------------+--------------------------+---------+-------------+",
-        "| admin_api_address                    | 127.0.0.1:8080           | query   |             |",
-        "| api_tls_server_cert                  |                          | query   |             |",
-        "| api_tls_server_key                   |                          | query   |             |",
-        "| api_tls_server_root_ca_cert          |                          | query   |             |",
-        "| azure_storage_blob.account           |                          | storage |             |",
-        "| azure_storage_blob.container         |                          | storage |             |",
-        "| azure_storage_blob.master_key        |                          | storage |             |",
-        "| clickhouse_handler_host              | 127.0.0.1                | query   |             |",
-        "| clickhouse_handler_port              | 9000                     | query   |             |",
-        "| cluster_id                           |                          | query   |             |",
-        "| database_engine_github_enabled       | true                     | query   |             |",
-        "| fs.data_path                         | _data                    | storage |             |",
-        "| flight_api_address                   | 127.0.0.1:9090           | query   |             |",
-        "| http_handler_host                    | 127.0.0.1                | query   |             |",
-        "| http_handler_port                    | 8000                     | query   |             |",
-        "| http_handler_result_timeout_millis   | 10000                    | query   |             |",
-        "| http_handler_tls_server_cert         |                          | query   |             |",
-        "| http_handler_tls_server_key          |                          | query   |             |",
-        "| http_handler_tls_server_root_ca_cert |                          | query   |             |",
-        "| jwt_key_file                         |                          | query   |             |",
-        "| log_dir                              | ./_logs                  | log     |             |",
-        "| log_level                            | INFO                     | log     |             |",
-        "| log_query_enabled                    | false                    | log     |             |",
-        "| management_mode                      | false                    | query   |             |",
-        "| max_active_sessions                  | 256                      | query   |             |",
-        "| max_query_log_size                   | 10000                    | query   |             |",
-        "| meta_address                         |                          | meta    |             |",
-        "| meta_client_timeout_in_second        | 10                       | meta    |             |",
-        "| meta_embedded_dir                    | ./_meta_embedded         | meta    |             |",
-        "| meta_password                        |                          | meta    |             |",
-        "| meta_username                        | root                     | meta    |             |",
-        "| metric_api_address                   | 127.0.0.1:7070           | query   |             |",
-        "| mysql_handler_host                   | 127.0.0.1                | query   |             |",
-        "| mysql_handler_port                   | 3307                     | query   |             |",
-        "| num_cpus                             | 0                        | query   |             |",
-        "| rpc_tls_meta_server_root_ca_cert     |                          | meta    |             |",
-        "| rpc_tls_meta_service_domain_name     | localhost                | meta    |             |",
-        "| rpc_tls_query_server_root_ca_cert    |                          | query   |             |",
-        "| rpc_tls_query_service_domain_name    | localhost                | query   |             |",
-        "| rpc_tls_server_cert                  |                          | query   |             |",
-        "| rpc_tls_server_key                   |                          | query   |             |",
-        "| s3.access_key_id                     |                          | storage |             |",
-        "| s3.bucket                            |                          | storage |             |",
-        "| s3.enable_pod_iam_policy             | false                    | storage |             |",
-        "| s3.endpoint_url                      | https://s3.amazonaws.com | storage |             |",
-        "| s3.region                            |                          | storage |             |",
-        "| s3.root                              |                          | storage |             |",
-        "| s3.secret_access_key                 |                          | storage |             |",
-        "| storage_num_cpus                     | 0                        | storage |             |",
-        "| storage_type                         | fs                       | storage |             |",
-        "| table_cache_block_meta_count         | 102400                   | query   |             |",
-        "| table_cache_enabled                  | false                    | query   |             |",
-        "| table_cache_segment_count            | 10240                    | query   |             |",
-        "| table_cache_snapshot_count           | 256                      | query   |             |",
-        "| table_disk_cache_mb_size             | 1024                     | query   |             |",
-        "| table_disk_cache_root                | _cache                   | query   |             |",
-        "| table_engine_csv_enabled             | false                    | query   |             |",
-        "| table_engine_memory_enabled          | true                     | query   |             |",
-        "| table_engine_parquet_enabled         | false                    | query   |             |",
-        "| table_memory_cache_mb_size           | 256                      | query   |             |",
-        "| tenant_id                            | test                     | query   |             |",
-        "| wait_timeout_mills                   | 5000                     | query   |             |",
-        "+--------------------------------------+--------------------------+---------+-------------+",
+        "+---------------------------------------+--------------------------+---------+-------------+",
+        "| name                                  | value                    | group   | description |",
+        "+---------------------------------------+--------------------------+---------+-------------+",
+        "| admin_api_address                     | 127.0.0.1:8080           | query   |             |",
+        "| api_tls_server_cert                   |                          | query   |             |",
+        "| api_tls_server_key                    |                          | query   |             |",
+        "| api_tls_server_root_ca_cert           |                          | query   |             |",
+        "| azure_storage_blob.account            |                          | storage |             |",
+        "| azure_storage_blob.container          |                          | storage |             |",
+        "| azure_storage_blob.master_key         |                          | storage |             |",
+        "| clickhouse_handler_host               | 127.0.0.1                | query   |             |",
+        "| clickhouse_handler_port               | 9000                     | query   |             |",
+        "| clickhouse_http_handler_host          | 127.0.0.1                | query   |             |",
+        "| clickhouse_http_handler_port          | 8124                     | query   |             |",
+        "| cluster_id                            |                          | query   |             |",
+        "| cluster_lease_secs                    | 60                       | query   |             |",
+        "| database_engine_github_enabled        | true                     | query   |             |",
+        "| fs.data_path                          | _data                    | storage |             |",
+        "| flight_api_address                    | 127.0.0.1:9090           | query   |             |",
+        "| flight_sql_handler_host               | 127.0.0.1                | query   |             |",
+        "| flight_sql_handler_port               | 8900                     | query   |             |",
+        "| http_handler_host                     | 127.0.0.1                | query   |             |",
+        "| http_handler_port                     | 8000                     | query   |             |",
+        "| http_handler_result_timeout_millis    | 10000                    | query   |             |",
+        "| http_handler_tls_server_cert          |                          | query   |             |",
+        "| http_handler_tls_server_key           |                          | query   |             |",
+        "| http_handler_tls_server_root_ca_cert  |                          | query   |             |",
+        "| jwt_key_file                          |                          | query   |             |",
+        "| jwt_role_claim                        |                          | query   |             |",
+        "| jwt_tenant_claim                      |                          | query   |             |",
+        "| jwt_user_claim                        | sub                      | query   |             |",
+        "| ldap_bind_dn_template                 |                          | query   |             |",
+        "| ldap_group_filter                     | (member={dn})            | query   |             |",
+        "| ldap_group_role_mapping               |                          | query   |             |",
+        "| ldap_group_search_base                |                          | query   |             |",
+        "| ldap_pool_size                        | 8                        | query   |             |",
+        "| ldap_url                              |                          | query   |             |",
+        "| log_audit_max_rows                    | 100000                   | log     |             |",
+        "| log_audit_sinks                       |                          | log     |             |",
+        "| log_dir                               | ./_logs                  | log     |             |",
+        "| log_level                             | INFO                     | log     |             |",
+        "| log_query_enabled                     | false                    | log     |             |",
+        "| log_query_retention_hours             | 0                        | log     |             |",
+        "| log_tracing_otlp_endpoint             |                          | log     |             |",
+        "| management_mode                       | false                    | query   |             |",
+        "| max_active_sessions                   | 256                      | query   |             |",
+        "| max_query_log_size                    | 10000                    | query   |             |",
+        "| max_running_queries                   | 0                        | query   |             |",
+        "| max_running_queries_per_user          | 0                        | query   |             |",
+        "| meta_address                          |                          | meta    |             |",
+        "| meta_client_timeout_in_second         | 10                       | meta    |             |",
+        "| meta_embedded_dir                     | ./_meta_embedded         | meta    |             |",
+        "| meta_password                         |                          | meta    |             |",
+        "| meta_username                         | root                     | meta    |             |",
+        "| metric_api_address                    | 127.0.0.1:7070           | query   |             |",
+        "| mysql_handler_host                    | 127.0.0.1                | query   |             |",
+        "| mysql_handler_port                    | 3307                     | query   |             |",
+        "| mysql_handler_tls_server_cert         |                          | query   |             |",
+        "| mysql_handler_tls_server_key          |                          | query   |             |",
+        "| mysql_handler_tls_server_root_ca_cert |                          | query   |             |",
+        "| network_policy                        |                          | query   |             |",
+        "| num_cpus                              | 0                        | query   |             |",
+        "| password_expire_days                  | 0                        | query   |             |",
+        "| password_history                      | 0                        | query   |             |",
+        "| password_lockout_minutes              | 10                       | query   |             |",
+        "| password_max_failed_attempts          | 0                        | query   |             |",
+        "| password_min_length                   | 0                        | query   |             |",
+        "| password_require_digit                | false                    | query   |             |",
+        "| password_require_mixed_case           | false                    | query   |             |",
+        "| password_require_special_char         | false                    | query   |             |",
+        "| postgres_handler_host                 | 127.0.0.1                | query   |             |",
+        "| postgres_handler_port                 | 5433                     | query   |             |",
+        "| query_queue_timeout_secs              | 60                       | query   |             |",
+        "| query_result_cache_max_bytes          | 1073741824               | query   |             |",
+        "| rpc_tls_meta_client_cert              |                          | meta    |             |",
+        "| rpc_tls_meta_client_key               |                          | meta    |             |",
+        "| rpc_tls_meta_server_root_ca_cert      |                          | meta    |             |",
+        "| rpc_tls_meta_service_domain_name      | localhost                | meta    |             |",
+        "| rpc_tls_query_client_cert             |                          | query   |             |",
+        "| rpc_tls_query_client_key              |                          | query   |             |",
+        "| rpc_tls_query_server_root_ca_cert     |                          | query   |             |",
+        "| rpc_tls_query_service_domain_name     | localhost                | query   |             |",
+        "| rpc_tls_server_cert                   |                          | query   |             |",
+        "| rpc_tls_server_client_ca_cert         |                          | query   |             |",
+        "| rpc_tls_server_key                    |                          | query   |             |",
+        "| s3.access_key_id                      |                          | storage |             |",
+        "| s3.bucket                             |                          | storage |             |",
+        "| s3.enable_pod_iam_policy              | false                    | storage |             |",
+        "| s3.endpoint_url                       | https://s3.amazonaws.com | storage |             |",
+        "| s3.region                             |                          | storage |             |",
+        "| s3.root                               |                          | storage |             |",
+        "| s3.secret_access_key                  |                          | storage |             |",
+        "| session_idle_timeout_secs             | 28800                    | query   |             |",
+        "| storage_num_cpus                      | 0                        | storage |             |",
+        "| storage_type                          | fs                       | storage |             |",
+        "| table_cache_block_meta_count          | 102400                   | query   |             |",
+        "| table_cache_enabled                   | false                    | query   |             |",
+        "| table_cache_segment_count             | 10240                    | query   |             |",
+        "| table_cache_snapshot_count            | 256                      | query   |             |",
+        "| table_disk_cache_mb_size              | 1024                     | query   |             |",
+        "| table_disk_cache_root                 | _cache                   | query   |             |",
+        "| table_engine_csv_enabled              | false                    | query   |             |",
+        "| table_engine_memory_enabled           | true                     | query   |             |",
+        "| table_engine_parquet_enabled          | false                    | query   |             |",
+        "| table_memory_cache_mb_size            | 256                      | query   |             |",
+        "| tenant_id                             | test                     | query   |             |",
+        "| udf_server_allow_list                 |                          | query   |             |",
+        "| wait_timeout_mills                    | 5000                     | query   |             |",
+        "+---------------------------------------+--------------------------+---------+-------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
@@ -122,72 +165,115 @@ async fn test_configs_table_redact() -> Result<()> {
     assert_eq!(block.num_columns(), 4);
 
     let expected = vec![
-        "+--------------------------------------+--------------------------+---------+-------------+",
-        "| name                                 | value                    | group   | description |",
-        "+--------------------------------------+--------------------------+---------+-------------+",
-        "| admin_api_address                    | 127.0.0.1:8080           | query   |             |",
-        "| api_tls_server_cert                  |                          | query   |             |",
-        "| api_tls_server_key                   |                          | query   |             |",
-        "| api_tls_server_root_ca_cert          |                          | query   |             |",
-        "| azure_storage_blob.account           |                          | storage |             |",
-        "| azure_storage_blob.container         |                          | storage |             |",
-        "| azure_storage_blob.master_key        |                          | storage |             |",
-        "| clickhouse_handler_host              | 127.0.0.1                | query   |             |",
-        "| clickhouse_handler_port              | 9000                     | query   |             |",
-        "| cluster_id                           |                          | query   |             |",
-        "| database_engine_github_enabled       | true                     | query   |             |",
-        "| fs.data_path                         | _data                    | storage |             |",
-        "| flight_api_address                   | 127.0.0.1:9090           | query   |             |",
-        "| http_handler_host                    | 127.0.0.1                | query   |             |",
-        "| http_handler_port                    | 8000                     | query   |             |",
-        "| http_handler_result_timeout_millis   | 10000                    | query   |             |",
-        "| http_handler_tls_server_cert         |                          | query   |             |",
-        "| http_handler_tls_server_key          |                          | query   |             |",
-        "| http_handler_tls_server_root_ca_cert |                          | query   |             |",
-        "| jwt_key_file                         |                          | query   |             |",
-        "| log_dir                              | ./_logs                  | log     |             |",
-        "| log_level                            | INFO                     | log     |             |",
-        "| log_query_enabled                    | false                    | log     |             |",
-        "| management_mode                      | false                    | query   |             |",
-        "| max_active_sessions                  | 256                      | query   |             |",
-        "| max_query_log_size                   | 10000                    | query   |             |",
-        "| meta_address                         |                          | meta    |             |",
-        "| meta_client_timeout_in_second        | 10                       | meta    |             |",
-        "| meta_embedded_dir                    | ./_meta_embedded         | meta    |             |",
-        "| meta_password                        |                          | meta    |             |",
-        "| meta_username                        | root                     | meta    |             |",
-        "| metric_api_address                   | 127.0.0.1:7070           | query   |             |",
-        "| mysql_handler_host                   | 127.0.0.1                | query   |             |",
-        "| mysql_handler_port                   | 3307                     | query   |             |",
-        "| num_cpus                             | 0                        | query   |             |",
-        "| rpc_tls_meta_server_root_ca_cert     |                          | meta    |             |",
-        "| rpc_tls_meta_service_domain_name     | localhost                | meta    |             |",
-        "| rpc_tls_query_server_root_ca_cert    |                          | query   |             |",
-        "| rpc_tls_query_service_domain_name    | localhost                | query   |             |",
-        "| rpc_tls_server_cert                  |                          | query   |             |",
-        "| rpc_tls_server_key                   |                          | query   |             |",
-        "| s3.access_key_id                     | ******_id                | storage |             |",
-        "| s3.bucket                            |                          | storage |             |",
-        "| s3.enable_pod_iam_policy             | false                    | storage |             |",
-        "| s3.endpoint_url                      | https://s3.amazonaws.com | storage |             |",
-        "| s3.region                            |                          | storage |             |",
-        "| s3.root                              |                          | storage |             |",
-        "| s3.secret_access_key                 | ******key                | storage |             |",
-        "| storage_num_cpus                     | 0                        | storage |             |",
-        "| storage_type                         | fs                       | storage |             |",
-        "| table_cache_block_meta_count         | 102400                   | query   |             |",
-        "| table_cache_enabled                  | false                    | query   |             |",
-        "| table_cache_segment_count            | 10240                    | query   |             |",
-        "| table_cache_snapshot_count           | 256                      | query   |             |",
-        "| table_disk_cache_mb_size             | 1024                     | query   |             |",
-        "| table_disk_cache_root                | _cache                   | query   |             |",
-        "| table_engine_csv_enabled             | false                    | query   |             |",
-        "| table_engine_memory_enabled          | true                     | query   |             |",
-        "| table_engine_parquet_enabled         | false                    | query   |             |",
-        "| table_memory_cache_mb_size           | 256                      | query   |             |",
-        "| tenant_id                            | test                     | query   |             |",
-        "| wait_timeout_mills                   | 5000                     | query   |             |",
-        "+--------------------------------------+--------------------------+---------+-------------+",
+        "+---------------------------------------+--------------------------+---------+-------------+",
+        "| name                                  | value                    | group   | description |",
+        "+---------------------------------------+--------------------------+---------+-------------+",
+        "| admin_api_address                     | 127.0.0.1:8080           | query   |             |",
+        "| api_tls_server_cert                   |                          | query   |             |",
+        "| api_tls_server_key                    |                          | query   |             |",
+        "| api_tls_server_root_ca_cert           |                          | query   |             |",
+        "| azure_storage_blob.account            |                          | storage |             |",
+        "| azure_storage_blob.container          |                          | storage |             |",
+        "| azure_storage_blob.master_key         |                          | storage |             |",
+        "| clickhouse_handler_host               | 127.0.0.1                | query   |             |",
+        "| clickhouse_handler_port               | 9000                     | query   |             |",
+        "| clickhouse_http_handler_host          | 127.0.0.1                | query   |             |",
+        "| clickhouse_http_handler_port          | 8124                     | query   |             |",
+        "| cluster_id                            |                          | query   |             |",
+        "| cluster_lease_secs                    | 60                       | query   |             |",
+        "| database_engine_github_enabled        | true                     | query   |             |",
+        "| fs.data_path                          | _data                    | storage |             |",
+        "| flight_api_address                    | 127.0.0.1:9090           | query   |             |",
+        "| flight_sql_handler_host               | 127.0.0.1                | query   |             |",
+        "| flight_sql_handler_port               | 8900                     | query   |             |",
+        "| http_handler_host                     | 127.0.0.1                | query   |             |",
+        "| http_handler_port                     | 8000                     | query   |             |",
+        "| http_handler_result_timeout_millis    | 10000                    | query   |             |",
+        "| http_handler_tls_server_cert          |                          | query   |             |",
+        "| http_handler_tls_server_key           |                          | query   |             |",
+        "| http_handler_tls_server_root_ca_cert  |                          | query   |             |",
+        "| jwt_key_file                          |                          | query   |             |",
+        "| jwt_role_claim                        |                          | query   |             |",
+        "| jwt_tenant_claim                      |                          | query   |             |",
+        "| jwt_user_claim                        | sub                      | query   |             |",
+        "| ldap_bind_dn_template                 |                          | query   |             |",
+        "| ldap_group_filter                     | (member={dn})            | query   |             |",
+        "| ldap_group_role_mapping               |                          | query   |             |",
+        "| ldap_group_search_base                |                          | query   |             |",
+        "| ldap_pool_size                        | 8                        | query   |             |",
+        "| ldap_url                              |                          | query   |             |",
+        "| log_audit_max_rows                    | 100000                   | log     |             |",
+        "| log_audit_sinks                       |                          | log     |             |",
+        "| log_dir                               | ./_logs                  | log     |             |",
+        "| log_level                             | INFO                     | log     |             |",
+        "| log_query_enabled                     | false                    | log     |             |",
+        "| log_query_retention_hours             | 0                        | log     |             |",
+        "| log_tracing_otlp_endpoint             |                          | log     |             |",
+        "| management_mode                       | false                    | query   |             |",
+        "| max_active_sessions                   | 256                      | query   |             |",
+        "| max_query_log_size                    | 10000                    | query   |             |",
+        "| max_running_queries                   | 0                        | query   |             |",
+        "| max_running_queries_per_user          | 0                        | query   |             |",
+        "| meta_address                          |                          | meta    |             |",
+        "| meta_client_timeout_in_second         | 10                       | meta    |             |",
+        "| meta_embedded_dir                     | ./_meta_embedded         | meta    |             |",
+        "| meta_password                         |                          | meta    |             |",
+        "| meta_username                         | root                     | meta    |             |",
+        "| metric_api_address                    | 127.0.0.1:7070           | query   |             |",
+        "| mysql_handler_host                    | 127.0.0.1                | query   |             |",
+        "| mysql_handler_port                    | 3307                     | query   |             |",
+        "| mysql_handler_tls_server_cert         |                          | query   |             |",
+        "| mysql_handler_tls_server_key          |                          | query   |             |",
+        "| mysql_handler_tls_server_root_ca_cert |                          | query   |             |",
+        "| network_policy                        |                          | query   |             |",
+        "| num_cpus                              | 0                        | query   |             |",
+        "| password_expire_days                  | 0                        | query   |             |",
+        "| password_history                      | 0                        | query   |             |",
+        "| password_lockout_minutes              | 10                       | query   |             |",
+        "| password_max_failed_attempts          | 0                        | query   |             |",
+        "| password_min_length                   | 0                        | query   |             |",
+        "| password_require_digit                | false                    | query   |             |",
+        "| password_require_mixed_case           | false                    | query   |             |",
+        "| password_require_special_char         | false                    | query   |             |",
+        "| postgres_handler_host                 | 127.0.0.1                | query   |             |",
+        "| postgres_handler_port                 | 5433                     | query   |             |",
+        "| query_queue_timeout_secs              | 60                       | query   |             |",
+        "| query_result_cache_max_bytes          | 1073741824               | query   |             |",
+        "| rpc_tls_meta_client_cert              |                          | meta    |             |",
+        "| rpc_tls_meta_client_key               |                          | meta    |             |",
+        "| rpc_tls_meta_server_root_ca_cert      |                          | meta    |             |",
+        "| rpc_tls_meta_service_domain_name      | localhost                | meta    |             |",
+        "| rpc_tls_query_client_cert             |                          | query   |             |",
+        "| rpc_tls_query_client_key              |                          | query   |             |",
+        "| rpc_tls_query_server_root_ca_cert     |                          | query   |             |",
+        "| rpc_tls_query_service_domain_name     | localhost                | query   |             |",
+        "| rpc_tls_server_cert                   |                          | query   |             |",
+        "| rpc_tls_server_client_ca_cert         |                          | query   |             |",
+        "| rpc_tls_server_key                    |                          | query   |             |",
+        "| s3.access_key_id                      | ******_id                | storage |             |",
+        "| s3.bucket                             |                          | storage |             |",
+        "| s3.enable_pod_iam_policy              | false                    | storage |             |",
+        "| s3.endpoint_url                       | https://s3.amazonaws.com | storage |             |",
+        "| s3.region                             |                          | storage |             |",
+        "| s3.root                               |                          | storage |             |",
+        "| s3.secret_access_key                  | ******key                | storage |             |",
+        "| session_idle_timeout_secs             | 28800                    | query   |             |",
+        "| storage_num_cpus                      | 0                        | storage |             |",
+        "| storage_type                          | fs                       | storage |             |",
+        "| table_cache_block_meta_count          | 102400                   | query   |             |",
+        "| table_cache_enabled                   | false                    | query   |             |",
+        "| table_cache_segment_count             | 10240                    | query   |             |",
+        "| table_cache_snapshot_count            | 256                      | query   |             |",
+        "| table_disk_cache_mb_size              | 1024                     | query   |             |",
+        "| table_disk_cache_root                 | _cache                   | query   |             |",
+        "| table_engine_csv_enabled              | false                    | query   |             |",
+        "| table_engine_memory_enabled           | true                     | query   |             |",
+        "| table_engine_parquet_enabled          | false                    | query   |             |",
+        "| table_memory_cache_mb_size            | 256                      | query   |             |",
+        "| tenant_id                             | test                     | query   |             |",
+        "| udf_server_allow_list                 |                          | query   |             |",
+        "| wait_timeout_mills                    | 5000                     | query   |             |",
+        "+---------------------------------------+--------------------------+---------+-------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
@@ -29,15 +29,16 @@ async fn test_engines_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
 
     let expected = vec![
-        "+--------+-----------------------------+",
-        "| Engine | Comment                     |",
-        "+--------+-----------------------------+",
-        "| FUSE   | FUSE Storage Engine         |",
-        "| GITHUB | GITHUB Storage Engine       |",
-        "| MEMORY | MEMORY Storage Engine       |",
-        "| NULL   | NULL Storage Engine         |",
-        "| VIEW   | VIEW STORAGE (LOGICAL VIEW) |",
-        "+--------+-----------------------------+",
+        "+----------+-----------------------------+",
+        "| Engine   | Comment                     |",
+        "+----------+-----------------------------+",
+        "| EXTERNAL | EXTERNAL Storage Engine     |",
+        "| FUSE     | FUSE Storage Engine         |",
+        "| GITHUB   | GITHUB Storage Engine       |",
+        "| MEMORY   | MEMORY Storage Engine       |",
+        "| NULL     | NULL Storage Engine         |",
+        "| VIEW     | VIEW STORAGE (LOGICAL VIEW) |",
+        "+----------+-----------------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected.clone(), result.as_slice());
 
@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_streams::RejectedRecord;
+use databend_query::interpreters::write_rejected_records;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_load_errors_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let records = vec![
+        RejectedRecord {
+            file: "/data/a.csv".to_string(),
+            line: 3,
+            record: "x,2.5".to_string(),
+            error: "Cannot parse value:x to Int8".to_string(),
+        },
+        RejectedRecord {
+            file: "/data/b.csv".to_string(),
+            line: 7,
+            record: "".to_string(),
+            error: "Parse csv error".to_string(),
+        },
+    ];
+    write_rejected_records(&ctx, &records).await?;
+
+    let table = ctx.get_table("system", "load_errors").await?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        r"\+--------------------------------------\+-------------------------\+------------\+------\+--------\+------------------------------\+",
+        r"\| query_id                             \| event_time              \| file       \| line \| record \| error                        \|",
+        r"\+--------------------------------------\+-------------------------\+------------\+------\+--------\+------------------------------\+",
+        r"\| [0-9a-f-]{36} \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} \| data/a\.csv \| 3    \| x,2\.5  \| Cannot parse value:x to Int8 \|",
+        r"\| [0-9a-f-]{36} \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} \| data/b\.csv \| 7    \|        \| Parse csv error              \|",
+        r"\+--------------------------------------\+-------------------------\+------------\+------\+--------\+------------------------------\+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq_with_regex(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_base::TrySpawn;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use databend_query::storages::system::MemoryTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memory_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = MemoryTable::create(1);
+
+    // Read it in the runtime of the query, whose memory is tracked.
+    let result = ctx
+        .try_spawn({
+            let ctx = ctx.clone();
+            async move {
+                let source_plan = table.read_plan(ctx.clone(), None).await?;
+                let stream = table.read(ctx, &source_plan).await?;
+                stream.try_collect::<Vec<_>>().await
+            }
+        })?
+        .await
+        .unwrap()?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 5);
+
+    // The global row is the first one, then the stats of the allocator and the queries.
+    let scope = block.try_column_by_name("scope")?;
+    assert_eq!(scope.get(0), DataValue::String(b"global".to_vec()));
+    let name = block.try_column_by_name("name")?;
+    assert_eq!(name.get(0), DataValue::String(b"tracked".to_vec()));
+
+    let query_id = block.try_column_by_name("query_id")?;
+    let current_query_id = ctx.get_id().into_bytes();
+    let has_query = (0..block.num_rows()).any(|row| {
+        scope.get(row) == DataValue::String(b"query".to_vec())
+            && query_id.get(row) == DataValue::String(current_query_id.clone())
+    });
+    assert!(has_query);
+
+    Ok(())
+}
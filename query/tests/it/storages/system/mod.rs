@@ -19,9 +19,14 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod events_table;
 mod functions_table;
+mod load_errors_table;
+mod memory_table;
 mod metrics_table;
+mod processes_table;
 mod query_log_table;
+mod query_profile_table;
 mod roles_table;
 mod settings_table;
 mod tables_table;
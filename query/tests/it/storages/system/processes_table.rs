@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use databend_query::storages::system::ProcessesTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let running_ctx = ctx.get_current_session().create_query_context().await?;
+    running_ctx.set_current_stage("Executing");
+    // 1 of the 4 planned partitions is read.
+    let dal_metrics = running_ctx.get_dal_metrics();
+    dal_metrics.inc_partitions_scanned(4);
+    dal_metrics.inc_partitions_read(1);
+
+    let table = ProcessesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 17);
+    assert_eq!(block.num_rows(), 1);
+
+    let percent = block.try_column_by_name("scan_progress_percent")?;
+    assert_eq!(percent.get(0), DataValue::UInt64(25));
+    let stage = block.try_column_by_name("current_stage")?;
+    assert_eq!(stage.get(0), DataValue::String(b"Executing".to_vec()));
+    let node = block.try_column_by_name("node")?;
+    let local_id = ctx.get_cluster_discovery().local_id();
+    assert_eq!(node.get(0), DataValue::String(local_id.into_bytes()));
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::tokio;
 use common_datablocks::assert_blocks_sorted_eq;
@@ -30,8 +31,7 @@ async fn test_query_log_table() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
     ctx.get_settings().set_max_threads(2)?;
 
-    let mut query_log = QueryLogTable::create(0);
-    query_log.set_max_rows(2);
+    let query_log = QueryLogTable::create(0, 2, None);
     let schema = query_log.schema();
     let table: Arc<dyn Table> = Arc::new(query_log);
 
@@ -54,12 +54,12 @@ async fn test_query_log_table() -> Result<()> {
         let result = stream.try_collect::<Vec<_>>().await?;
         assert_blocks_sorted_eq(
             vec![
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
-                "| log_type | handler_type | tenant_id | cluster_id | sql_user | sql_user_quota | sql_user_privileges | query_id | query_kind | query_text | event_date | event_time | current_database | databases | tables | columns | projections | written_rows | written_bytes | written_io_bytes | written_io_bytes_cost_ms | scan_rows | scan_bytes | scan_io_bytes | scan_io_bytes_cost_ms | scan_partitions | total_partitions | result_rows | result_bytes | cpu_usage | memory_usage | client_info | client_address | exception_code | exception_text | stack_trace | server_version | session_settings | extra |",
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
-                "| 2        |              |           |            |          |                |                     |          |            |            |            |            |                  |           |        |         |             |              |               |                  |                          |           |            |               |                       |                 |                  |             |              |           |              |             |                |                |                |             |                |                  |       |",
-                "| 3        |              |           |            |          |                |                     |          |            |            |            |            |                  |           |        |         |             |              |               |                  |                          |           |            |               |                       |                 |                  |             |              |           |              |             |                |                |                |             |                |                  |       |",
-                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+------------+------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+-----------+------------+------------+------------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
+                "| log_type | handler_type | tenant_id | cluster_id | sql_user | sql_user_quota | sql_user_privileges | query_id | query_kind | query_text | query_tag | event_date | event_time | query_start_time | query_duration_ms | current_database | databases | tables | columns | projections | written_rows | written_bytes | written_io_bytes | written_io_bytes_cost_ms | scan_rows | scan_bytes | scan_io_bytes | scan_io_bytes_cost_ms | scan_partitions | total_partitions | result_rows | result_bytes | cpu_usage | memory_usage | peak_memory_usage | client_info | client_address | exception_code | exception_text | stack_trace | server_version | session_settings | extra |",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+-----------+------------+------------+------------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
+                "| 2        |              |           |            |          |                |                     |          |            |            |           |            |            |                  |                   |                  |           |        |         |             |              |               |                  |                          |           |            |               |                       |                 |                  |             |              |           |              |                   |             |                |                |                |             |                |                  |       |",
+                "| 3        |              |           |            |          |                |                     |          |            |            |           |            |            |                  |                   |                  |           |        |         |             |              |               |                  |                          |           |            |               |                       |                 |                  |             |              |           |              |                   |             |                |                |                |             |                |                  |       |",
+                "+----------+--------------+-----------+------------+----------+----------------+---------------------+----------+------------+------------+-----------+------------+------------+------------------+-------------------+------------------+-----------+--------+---------+-------------+--------------+---------------+------------------+--------------------------+-----------+------------+---------------+-----------------------+-----------------+------------------+-------------+--------------+-----------+--------------+-------------------+-------------+----------------+----------------+----------------+-------------+----------------+------------------+-------+",
             ],
             &result,
         );
@@ -67,3 +67,29 @@ async fn test_query_log_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_retention() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let query_log = QueryLogTable::create(0, 10, Some(Duration::from_millis(100)));
+    let schema = query_log.schema();
+    let table: Arc<dyn Table> = Arc::new(query_log);
+
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![1u32])]);
+    let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(vec![Ok(block)]);
+    table
+        .append_data(ctx.clone(), Box::pin(input_stream))
+        .await?;
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    assert_eq!(stream.try_collect::<Vec<_>>().await?.len(), 1);
+
+    // The records out of the retention are removed.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    assert_eq!(stream.try_collect::<Vec<_>>().await?.len(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datablocks::pretty_format_blocks;
+use common_exception::Result;
+use databend_query::pipelines::new::executor::ProcessorProfile;
+use databend_query::storages::system::QueryProfileTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_profile_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.record_query_profiles(vec![ProcessorProfile {
+        id: 0,
+        name: "test_profile_source".to_string(),
+        output_rows: 10,
+        output_bytes: 80,
+        cpu_time: Duration::from_micros(15),
+        ..Default::default()
+    }]);
+    ctx.record_query_profiles(vec![ProcessorProfile {
+        id: 1,
+        name: "test_profile_sink".to_string(),
+        input_rows: 10,
+        input_bytes: 80,
+        wait_time: Duration::from_micros(20),
+        ..Default::default()
+    }]);
+
+    let table = QueryProfileTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 9);
+    assert_eq!(block.num_rows(), 2);
+
+    let output = pretty_format_blocks(result.as_slice())?;
+    let query_id = ctx.get_id();
+    assert!(output.contains(&format!(
+        "| {} | 0            | test_profile_source | 0          | 0           | 10          | 80           | 15          | 0            |",
+        query_id
+    )));
+    assert!(output.contains(&format!(
+        "| {} | 1            | test_profile_sink   | 10         | 80          | 0           | 0            | 0           | 20           |",
+        query_id
+    )));
+
+    Ok(())
+}
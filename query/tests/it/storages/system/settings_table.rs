@@ -30,23 +30,38 @@ async fn test_settings_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
 
     let expected = vec![
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
-        "| name                               | value   | default | level   | description                                                                                                                                | type   |",
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
-        "|                                    |         |         |         |                                                                                                                                            |        |",
-        "| empty_as_default                   | 1       | 1       | SESSION | Format empty_as_default, default value: 1                                                                                                  | UInt64 |",
-        "| enable_new_processor_framework     | 1       | 1       | SESSION | Enable new processor framework if value != 0, default value: 1                                                                             | UInt64 |",
-        "| field_delimiter                    | ,       | ,       | SESSION | Format field delimiter, default value: ,                                                                                                   | String |",
-        "| flight_client_timeout              | 60      | 60      | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
-        "| max_block_size                     | 10000   | 10000   | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
-        "| max_threads                        | 2       | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
-        "| record_delimiter                   |         |         | SESSION | Format record_delimiter, default value:                                                                                                    | String |",
-        "| skip_header                        | 0       | 0       | SESSION | Whether to skip the input header, default value: 0                                                                                         | UInt64 |",
-        "| storage_occ_backoff_init_delay_ms  | 5       | 5       | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
-        "| storage_occ_backoff_max_delay_ms   | 20000   | 20000   | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
-        "| storage_occ_backoff_max_elapsed_ms | 120000  | 120000  | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
-        "| storage_read_buffer_size           | 1048576 | 1048576 | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
-        "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "+------------------------------------+-----------------+-----------------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "| name                               | value           | default         | level   | description                                                                                                                                | type   |",
+        "+------------------------------------+-----------------+-----------------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "|                                    |                 |                 |         |                                                                                                                                            |        |",
+        "| aes_encryption_key                 |                 |                 | SESSION | The hex encoded key of aes_encrypt and aes_decrypt if they are called without a key, default value: empty                                  | String |",
+        "| block_encryption_mode              | aes-256-gcm     | aes-256-gcm     | SESSION | The mode of aes_encrypt and aes_decrypt if they are called without a mode, default value: aes-256-gcm                                      | String |",
+        "| compression                        | auto            | auto            | SESSION | Format compression: auto, none, gzip, bz2, zstd, xz and so on, default value: auto                                                         | String |",
+        "| copy_history_expire_secs           | 604800          | 604800          | SESSION | The seconds the files loaded by COPY are kept in the history of the table to skip them when they are copied again, default value: 604800   | UInt64 |",
+        "| empty_as_default                   | 1               | 1               | SESSION | Format empty_as_default, default value: 1                                                                                                  | UInt64 |",
+        "| enable_http_compression            | 1               | 1               | SESSION | Compress the HTTP query results if the client accepts zstd, br, gzip or deflate, default value: 1                                          | UInt64 |",
+        "| enable_new_processor_framework     | 1               | 1               | SESSION | Enable new processor framework if value != 0, default value: 1                                                                             | UInt64 |",
+        "| enable_query_result_cache          | 0               | 0               | SESSION | Serve the SELECT queries from the query result cache and cache their results if value != 0, default value: 0                               | UInt64 |",
+        "| error_as_null                      | 0               | 0               | SESSION | Load the values failed to parse as NULL, or as the default value if the column is not nullable, default value: 0                           | UInt64 |",
+        "| field_delimiter                    | ,               | ,               | SESSION | Format field delimiter, default value: ,                                                                                                   | String |",
+        "| flight_client_timeout              | 60              | 60              | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
+        "| json_strict                        | 1               | 1               | SESSION | Reject the NDJSON values whose JSON types mismatch the column types, or convert them if 0, default value: 1                                | UInt64 |",
+        "| long_query_time                    | 10              | 10              | SESSION | The queries taking longer than the seconds are written to the slow query log. By default, it is 10 seconds, 0 to log all the queries.      | UInt64 |",
+        "| max_block_size                     | 10000           | 10000           | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
+        "| max_memory_usage                   | 0               | 0               | SESSION | The maximum memory usage in bytes of a query, the query fails once it is exceeded. By default, it is 0 (no limit).                         | UInt64 |",
+        "| max_threads                        | 2               | 16              | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
+        "| on_error                           | abort_statement | abort_statement | SESSION | Streaming load on error: continue, skip_file_<num> or abort_statement, default value: abort_statement                                      | String |",
+        "| query_result_cache_ttl_secs        | 300             | 300             | SESSION | The seconds the cached query results are served, they are skipped once the tables are modified. By default, it is 300 seconds.             | UInt64 |",
+        "| query_tag                          |                 |                 | SESSION | The tag of the queries in the query log, the processes and the metrics, to attribute the load to the jobs. By default, it is empty.        | String |",
+        "| record_delimiter                   |                 |                 | SESSION | Format record_delimiter, default value:                                                                                                    | String |",
+        "| skip_header                        | 0               | 0               | SESSION | Number of header lines to skip in the input, default value: 0                                                                              | UInt64 |",
+        "| statement_timeout_ms               | 0               | 0               | SESSION | The timeout of a statement in millisecond, the query is killed once it is exceeded. By default, it is 0 (no timeout).                      | UInt64 |",
+        "| storage_occ_backoff_init_delay_ms  | 5               | 5               | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
+        "| storage_occ_backoff_max_delay_ms   | 20000           | 20000           | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
+        "| storage_occ_backoff_max_elapsed_ms | 120000          | 120000          | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
+        "| storage_read_buffer_size           | 1048576         | 1048576         | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
+        "| timezone                           | UTC             | UTC             | SESSION | Timezone, default value: UTC                                                                                                               | String |",
+        "+------------------------------------+-----------------+-----------------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
@@ -66,6 +66,9 @@ async fn test_users_table() -> Result<()> {
         .await?;
     let auth_data = AuthInfo::new(AuthType::Sha256Password, &Some("123456789".to_string()));
     assert!(auth_data.is_ok());
+    let mut option = UserOption::default();
+    option.set_default_database(Some("default".to_string()));
+    option.set_default_role(Some("role1".to_string()));
     ctx.get_user_manager()
         .add_user(
             &tenant,
@@ -75,7 +78,7 @@ async fn test_users_table() -> Result<()> {
                 hostname: "%".to_string(),
                 grants: UserGrantSet::empty(),
                 quota: UserQuota::no_limit(),
-                option: UserOption::default(),
+                option,
             },
             false,
         )
@@ -87,16 +90,16 @@ async fn test_users_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 4);
+    assert_eq!(block.num_columns(), 6);
 
     let expected = vec![
-        "+-------+-----------+--------------------+------------------------------------------------------------------+",
-        "| name  | hostname  | auth_type          | auth_string                                                      |",
-        "+-------+-----------+--------------------+------------------------------------------------------------------+",
-        "| test  | localhost | no_password        |                                                                  |",
-        "| test1 | %         | plaintext_password | 123456789                                                        |",
-        "| test2 | %         | sha256_password    | 15e2b0d3c33891ebb0f1ef609ec419420c20e320ce94c65fbc8c3312448eb225 |",
-        "+-------+-----------+--------------------+------------------------------------------------------------------+",
+        "+-------+-----------+--------------------+------------------------------------------------------------------+------------------+--------------+",
+        "| name  | hostname  | auth_type          | auth_string                                                      | default_database | default_role |",
+        "+-------+-----------+--------------------+------------------------------------------------------------------+------------------+--------------+",
+        "| test  | localhost | no_password        |                                                                  |                  |              |",
+        "| test1 | %         | plaintext_password | 123456789                                                        |                  |              |",
+        "| test2 | %         | sha256_password    | 15e2b0d3c33891ebb0f1ef609ec419420c20e320ce94c65fbc8c3312448eb225 | default          | role1        |",
+        "+-------+-----------+--------------------+------------------------------------------------------------------+------------------+--------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
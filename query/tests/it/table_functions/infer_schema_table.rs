@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::table_functions::InferSchemaTable;
+use databend_query::table_functions::TableFunction;
+
+fn string_arg(name: Option<&str>, value: &str) -> Expression {
+    let value = Expression::create_literal(DataValue::String(value.as_bytes().to_vec()));
+    match name {
+        None => value,
+        Some(name) => Expression::Alias(name.to_string(), Box::new(value)),
+    }
+}
+
+#[test]
+fn test_infer_schema_table_args() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        args: Vec<Expression>,
+        err: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "missing-stage",
+            args: vec![],
+            err: "Expected a stage location like '@my_stage/path' for table function infer_schema, but got ''",
+        },
+        Test {
+            name: "not-a-stage",
+            args: vec![string_arg(Some("stage"), "no_at")],
+            err: "Expected a stage location like '@my_stage/path' for table function infer_schema, but got 'no_at'",
+        },
+        Test {
+            name: "unknown-argument",
+            args: vec![
+                string_arg(Some("stage"), "@my_stage"),
+                string_arg(Some("size"), "1"),
+            ],
+            err: "Unknown argument 'size' for table function infer_schema, expected one of stage, pattern, file_format",
+        },
+        Test {
+            name: "positional-pattern",
+            args: vec![string_arg(None, "@my_stage"), string_arg(None, ".*")],
+            err: "Only the stage location can be a positional argument of table function infer_schema",
+        },
+        Test {
+            name: "unknown-file-format",
+            args: vec![
+                string_arg(Some("stage"), "@my_stage"),
+                string_arg(Some("file_format"), "txt"),
+            ],
+            err: "Unknown file format type, must one of { CSV | JSON | NDJSON | AVRO | ORC | PARQUET | XML }",
+        },
+        Test {
+            name: "positional-stage-ok",
+            args: vec![string_arg(None, "@my_stage/path")],
+            err: "",
+        },
+        Test {
+            name: "named-ok",
+            args: vec![
+                string_arg(Some("stage"), "@my_stage/path"),
+                string_arg(Some("pattern"), ".*[.]csv"),
+                string_arg(Some("file_format"), "csv"),
+            ],
+            err: "",
+        },
+    ];
+
+    for test in tests {
+        let result = InferSchemaTable::create("system", "infer_schema", 1, Some(test.args));
+        match result {
+            Ok(table) => {
+                assert_eq!(test.err, "", "{}", test.name);
+                assert_eq!(table.function_name(), "infer_schema", "{}", test.name);
+            }
+            Err(e) => assert_eq!(e.message(), test.err, "{}", test.name),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::table_functions::ListStageTable;
+use databend_query::table_functions::TableFunction;
+
+#[test]
+fn test_list_stage_table_args() -> Result<()> {
+    // Missing argument.
+    {
+        let result = ListStageTable::create("system", "list_stage", 1, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().message(),
+            "Must have exactly one stage location argument for table function.list_stage"
+        );
+    }
+
+    // Not a stage location.
+    {
+        let tbl_args = Some(vec![Expression::create_literal(DataValue::String(
+            b"no_at".to_vec(),
+        ))]);
+        let result = ListStageTable::create("system", "list_stage", 1, tbl_args);
+        assert!(result.is_err());
+        assert_eq!(
+            result.err().unwrap().message(),
+            "Expected a stage location like '@my_stage/path' for table function list_stage, but got 'no_at'"
+        );
+    }
+
+    // Ok.
+    {
+        let tbl_args = Some(vec![Expression::create_literal(DataValue::String(
+            b"@my_stage/path".to_vec(),
+        ))]);
+        let table = ListStageTable::create("system", "list_stage", 1, tbl_args)?;
+        assert_eq!(table.function_name(), "list_stage");
+    }
+
+    Ok(())
+}
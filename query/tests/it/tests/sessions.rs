@@ -69,12 +69,49 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::create_with_conf(new_config)
     }
 
+    pub fn rpc_tls_server_client_ca_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.rpc_tls_server_client_ca_cert = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
     pub fn jwt_key_file(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.jwt_key_file = value.into();
         SessionManagerBuilder::create_with_conf(new_config)
     }
 
+    pub fn jwt_user_claim(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.jwt_user_claim = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn jwt_role_claim(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.jwt_role_claim = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn jwt_tenant_claim(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.jwt_tenant_claim = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn ldap_url(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.ldap_url = value.into();
+        new_config.query.ldap_bind_dn_template = "uid={user},dc=example,dc=com".to_string();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn network_policy(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.network_policy = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
     pub fn http_handler_result_time_out(self, value: impl Into<u64>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.http_handler_result_timeout_millis = value.into();
@@ -102,6 +139,18 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::create_with_conf(new_config)
     }
 
+    pub fn mysql_handler_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.mysql_handler_tls_server_key = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn mysql_handler_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.mysql_handler_tls_server_cert = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
     pub fn api_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.api_tls_server_key = value.into();
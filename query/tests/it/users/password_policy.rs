@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::AuthType;
+use common_meta_types::PasswordState;
+use common_meta_types::UserInfo;
+use databend_query::users::PasswordPolicy;
+
+fn password(value: &str) -> Result<AuthInfo> {
+    AuthInfo::new(AuthType::Sha256Password, &Some(value.to_string()))
+}
+
+#[test]
+fn test_password_complexity() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.password_min_length = 8;
+    conf.query.password_require_mixed_case = true;
+    conf.query.password_require_digit = true;
+    conf.query.password_require_special_char = true;
+    let policy = PasswordPolicy::create(&conf);
+
+    let cases = vec![
+        ("Ab1!", "The password must have at least 8 characters"),
+        (
+            "abcdefg1!",
+            "The password must have both the lowercase and the uppercase letters",
+        ),
+        ("Abcdefgh!", "The password must have a digit"),
+        ("Abcdefgh1", "The password must have a special character"),
+    ];
+    for (value, expect) in cases {
+        let cause = policy.check_complexity(value).unwrap_err();
+        assert_eq!(cause.code(), 2206);
+        assert_eq!(cause.message(), expect);
+    }
+    policy.check_complexity("Abcdefg1!")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_password_history() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.password_history = 3;
+    let policy = PasswordPolicy::create(&conf);
+
+    let mut user = UserInfo::new("test".to_string(), "%".to_string(), password("p1")?);
+    for value in ["p2", "p3", "p4"] {
+        let new_password = password(value)?;
+        policy.check_reuse(&user, &new_password)?;
+        let old_password = std::mem::replace(&mut user.auth_info, new_password);
+        policy.password_changed(user.option.password_state_mut(), old_password, 100);
+    }
+
+    // The current and the last 2 passwords cannot be reused.
+    assert_eq!(user.option.password_state().history.len(), 2);
+    for value in ["p2", "p3", "p4"] {
+        assert!(policy.check_reuse(&user, &password(value)?).is_err());
+    }
+    policy.check_reuse(&user, &password("p1")?)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_password_expiration() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.password_expire_days = 1;
+    let policy = PasswordPolicy::create(&conf);
+
+    // The users created before the password policies never expire.
+    let mut user = UserInfo::new("test".to_string(), "%".to_string(), password("p1")?);
+    assert!(!policy.must_change_password(&user, 100));
+
+    user.option.password_state_mut().changed_at = Some(100);
+    assert!(!policy.must_change_password(&user, 100 + 24 * 60 * 60 - 1));
+    assert!(policy.must_change_password(&user, 100 + 24 * 60 * 60));
+
+    let mut user = UserInfo::new("test".to_string(), "%".to_string(), password("p1")?);
+    user.option.password_state_mut().must_change = true;
+    assert!(policy.must_change_password(&user, 100));
+
+    Ok(())
+}
+
+#[test]
+fn test_password_lockout() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.password_max_failed_attempts = 2;
+    conf.query.password_lockout_minutes = 1;
+    let policy = PasswordPolicy::create(&conf);
+
+    let mut state = PasswordState::default();
+    assert!(policy.login_failed(&mut state, 100));
+    policy.check_locked(&state, 100)?;
+    assert!(policy.login_failed(&mut state, 100));
+
+    let cause = policy.check_locked(&state, 159).unwrap_err();
+    assert_eq!(cause.message(), "user is locked for 1 seconds");
+    policy.check_locked(&state, 160)?;
+
+    assert!(policy.login_succeeded(&mut state));
+    assert_eq!(state, PasswordState::default());
+    assert!(!policy.login_succeeded(&mut state));
+
+    // No lockout by default.
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let policy = PasswordPolicy::create(&conf);
+    assert!(!policy.login_failed(&mut state, 100));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_password_lockout_concurrent_logins() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.password_max_failed_attempts = 8;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+    let user = UserInfo::new("test".to_string(), "%".to_string(), password("p1")?);
+    user_mgr.add_user(&tenant, user.clone(), false).await?;
+
+    // The successful login returns the user read again, with the failed attempts cleared.
+    let auth_mgr = ctx.get_auth_manager();
+    let _ = auth_mgr.verify_password(&tenant, user.clone(), false).await;
+    let verified = auth_mgr
+        .verify_password(&tenant, user.clone(), true)
+        .await?;
+    assert_eq!(verified.option.password_state().failed_attempts, 0);
+    let stored = user_mgr.get_user(&tenant, user.identity()).await?;
+    assert_eq!(verified.option, stored.option);
+
+    // All the concurrent failed logins are counted, the user is locked after the last one.
+    let logins = (0..8).map(|_| auth_mgr.verify_password(&tenant, user.clone(), false));
+    for result in futures::future::join_all(logins).await {
+        assert_eq!(result.unwrap_err().message(), "wrong password");
+    }
+
+    let user = user_mgr.get_user(&tenant, user.identity()).await?;
+    assert!(user.option.password_state().locked_until.is_some());
+    let cause = auth_mgr
+        .verify_password(&tenant, user, true)
+        .await
+        .unwrap_err();
+    assert!(cause.message().starts_with("user is locked"));
+
+    Ok(())
+}
@@ -18,6 +18,27 @@ cat $meta_json \
 
 diff $meta_json $exported
 
+# test backup and restore
+backup_dir="$SCRIPT_PATH/_backup/"
+mkdir -p "$backup_dir"
+
+./target/debug/databend-metactl --backup "$backup_dir" --raft-dir "$meta_dir"
+./target/debug/databend-metactl --restore "$backup_dir" --raft-dir "$meta_dir"
+./target/debug/databend-metactl --export --raft-dir "$meta_dir" > $exported
+
+diff $meta_json $exported
+
+# restore the data only as a new cluster
+./target/debug/databend-metactl --restore "$backup_dir" --restore-as-new-cluster --raft-dir "$meta_dir"
+./target/debug/databend-metactl --export --raft-dir "$meta_dir" > $exported
+
+if grep -q '"Logs"\|"RaftStateKV"\|"Nodes"\|"StateMachineMeta"' $exported; then
+    echo "raft state is restored as a new cluster"
+    exit 1
+fi
+test "$(grep -c '"GenericKV"' $exported)" = "$(grep -c '"GenericKV"' $meta_json)"
+test "$(grep -c '"Tables"' $exported)" = "$(grep -c '"Tables"' $meta_json)"
+
 # test export from grpc
 chmod +x ./target/debug/databend-meta
 ./target/debug/databend-meta --single &
@@ -32,3 +32,4 @@ SELECT toDate(toDateTime('2021-03-05 01:00:00')) = toDate('2021-03-05');
 SELECT toString(toDateTime64(1640019661000)) = '2021-12-20 17:01:01.000';
 SELECT toDate(toDateTime64(1640019661000)) = toDate('2021-12-20');
 SELECT toDateTime(toDateTime64(1640019661000)) = toDateTime('2021-12-20 17:01:01');
+SELECT toString(CAST(1640019661 AS TIMESTAMP)) = '2021-12-20 17:01:01.000000';
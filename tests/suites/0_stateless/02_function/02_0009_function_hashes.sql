@@ -37,3 +37,9 @@ SELECT XXHASH64('1.2');
 SELECT City64WithSeed('1234567890', 12);
 SELECT City64WithSeed('1234567890', 12.12);
 SELECT City64WithSeed(CAST(100000 as DateTime32), 1234);
+
+--- cityHash64
+SELECT cityHash64(1);
+SELECT cityHash64(number) FROM numbers(3);
+SELECT cityHash64('Alice') != cityHash64('Bob');
+SELECT cityHash64(NULL);
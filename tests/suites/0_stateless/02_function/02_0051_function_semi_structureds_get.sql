@@ -47,4 +47,10 @@ select get_path(obj, '["a"]') from t2;
 select get_path(obj, 'b.c') from t2;
 select get_path(obj, '["b"]["c"]') from t2;
 
+select '==path access from table==';
+select arr[3][0] from t1;
+select obj:a from t2;
+select obj:b.c from t2;
+select obj['b']['c'] from t2;
+
 DROP DATABASE db1;
@@ -0,0 +1,15 @@
+SELECT array(1, 2, 3);
+SELECT array_length(array(1, 2, 3)), array_length(array('a'));
+SELECT get(array(1, 2, 3), 1), get(array(1, 2, 3), 5);
+SELECT array_contains(array(1, 2, 3), 2), array_contains(array(1, 2, 3), 4);
+
+DROP TABLE IF EXISTS t_arr;
+CREATE TABLE t_arr(id Int32, a Array(Int64));
+INSERT INTO t_arr SELECT 1, array(3, 4);
+INSERT INTO t_arr SELECT 2, array(1, 2, 3);
+INSERT INTO t_arr SELECT 3, array(3, 4);
+SELECT a FROM t_arr ORDER BY a;
+SELECT a, count() FROM t_arr GROUP BY a ORDER BY a;
+DROP TABLE t_arr;
+
+SELECT * FROM unnest(array(1, 2, 3));
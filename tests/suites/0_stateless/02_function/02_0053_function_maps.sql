@@ -0,0 +1,12 @@
+SELECT map('a', 1, 'b', 2);
+SELECT map_keys(map('a', 1, 'b', 2)), map_values(map('a', 1, 'b', 2));
+SELECT map_get(map('a', 1, 'b', 2), 'b'), map_get(map('a', 1, 'b', 2), 'c');
+SELECT map_get(map_from_arrays(array(1, 2), array('x', 'y')), 2);
+SELECT map('a', 1, 'b'); -- {ErrorCode 1028}
+
+DROP TABLE IF EXISTS t_map;
+CREATE TABLE t_map AS SELECT number AS id, map('k', toString(number), 'id', toString(number * 10)) AS m FROM numbers(3);
+SELECT * FROM t_map ORDER BY id;
+SELECT m['k'], m['id'], m['none'] FROM t_map WHERE m['k'] = '1';
+SELECT map_keys(m) FROM t_map WHERE id = 2;
+DROP TABLE t_map;
@@ -0,0 +1,9 @@
+select date_add(toDate(18321), interval '1' day);
+select date_sub(toDateTime(1582970400), interval '2' hour);
+select toDate(18321) + interval '1 quarter';
+select toDate(18321) - interval '2 weeks';
+select toDateTime(1582970400) + interval '3 MONTHS';
+select dateDiff('year', toDate(18321), toDate(18687));
+select dateDiff('month', toDate(18321), toDate(18687));
+select dateDiff('day', toDate(18321), toDate(18687));
+select dateDiff('second', toDateTime(1582970400), toDateTime(1582970461));
@@ -0,0 +1,14 @@
+select toTypeName(cast('2022-04-01 06:50:20' as timestamp));
+select cast('2022-04-01 06:50:20.123456' as timestamp);
+select toDateTime(0);
+
+set timezone = 'Asia/Shanghai';
+select toDateTime(0);
+select toDateTime('1970-01-01 08:00:00') = toDateTime(0);
+select toTimeZone(cast('2022-04-01 06:50:20.123456' as timestamp), 'UTC');
+select toTimeZone(toDateTime(0), 'America/New_York');
+select toTimeZone(toDateTime(0), 'Mars/Olympus'); -- {ErrorCode 1006}
+
+set timezone = 'Mars/Olympus'; -- {ErrorCode 1006}
+set timezone = 'UTC';
+select toDateTime(0);
@@ -0,0 +1,13 @@
+SELECT to_bitmap('1,2,3'), bitmap_count(to_bitmap('1,2,3,3')), bitmap_count(to_bitmap(10));
+SELECT bitmap_and(to_bitmap('1,2,3'), to_bitmap('2,3,4')), bitmap_or(to_bitmap('1,2'), to_bitmap('5'));
+SELECT bitmap_contains(to_bitmap('1,2,3'), 2), bitmap_contains(to_bitmap('1,2,3'), 4);
+SELECT to_bitmap('1,a'); -- {ErrorCode 1010}
+
+DROP TABLE IF EXISTS t_bitmap;
+CREATE TABLE t_bitmap(dt String, users Bitmap);
+INSERT INTO t_bitmap VALUES ('2022-01-01', '1,2,3'), ('2022-01-01', '4'), ('2022-01-02', '2,3,5'), ('2022-01-03', '3,6');
+SELECT dt, users, bitmap_count(users) FROM t_bitmap ORDER BY dt, bitmap_count(users);
+SELECT intersect_count('2022-01-01', '2022-01-02')(users, dt) FROM t_bitmap;
+SELECT intersect_count('2022-01-01', '2022-01-02', '2022-01-03')(users, dt) FROM t_bitmap;
+SELECT intersect_count('2022-01-04')(users, dt) FROM t_bitmap;
+DROP TABLE t_bitmap;
@@ -0,0 +1,22 @@
+SELECT st_astext(st_point(1, 2)), st_astext(st_geomfromtext('polygon((0 0, 2 0, 2 2, 0 2, 0 0))'));
+SELECT st_distance(st_point(0, 0), st_point(3, 4)), st_contains(st_geomfromtext('POLYGON((0 0, 2 0, 2 2, 0 2, 0 0))'), st_point(1, 1));
+SELECT haversine(40.7128, -74.0060, 51.5074, -0.1278) BETWEEN 5570.22 AND 5570.23;
+SELECT geohash_encode(-5.60302734375, 42.593994140625), geohash_encode(-5.60302734375, 42.593994140625, 5), geohash_decode('ezs42');
+SELECT great_circle_distance(40.7128, -74.0060, 51.5074, -0.1278) = haversine(40.7128, -74.0060, 51.5074, -0.1278);
+SELECT point_in_polygon(tuple(1, 1), array(tuple(0, 0), tuple(4, 0), tuple(4, 4), tuple(2, 1), tuple(0, 4))), point_in_polygon(tuple(2, 3), array(tuple(0, 0), tuple(4, 0), tuple(4, 4), tuple(2, 1), tuple(0, 4)));
+SELECT point_in_polygon(tuple(0, 0), array(tuple(0, 0), tuple(1, 1))); -- {ErrorCode 1006}
+SELECT st_geomfromtext('POINT(1)'); -- {ErrorCode 1046}
+
+DROP TABLE IF EXISTS t_geo;
+CREATE TABLE t_geo(id Int32, g Geometry);
+INSERT INTO t_geo VALUES (1, 'POINT(1 1)'), (2, 'POINT(5 5)'), (3, 'LINESTRING(0 0, 3 3)');
+SELECT id, g FROM t_geo WHERE st_contains(st_geomfromtext('POLYGON((0 0, 4 0, 4 4, 0 4, 0 0))'), g) ORDER BY id;
+SELECT id, st_distance(g, st_point(5, 1)) < 4.5 FROM t_geo ORDER BY id;
+
+DROP TABLE IF EXISTS t_shops;
+CREATE TABLE t_shops(id Int32, lon Float64, lat Float64);
+INSERT INTO t_shops VALUES (1, 116.3974, 39.9093), (2, 121.4737, 31.2304), (3, 116.4551, 39.9289);
+SELECT id FROM t_shops WHERE point_in_polygon(tuple(lon, lat), array(tuple(116.3, 39.8), tuple(116.5, 39.8), tuple(116.5, 40.0), tuple(116.3, 40.0))) ORDER BY id;
+SELECT id, geohash_encode(lon, lat, 4) FROM t_shops WHERE great_circle_distance(lat, lon, 39.9093, 116.3974) < 10 ORDER BY id;
+DROP TABLE t_shops;
+DROP TABLE t_geo;
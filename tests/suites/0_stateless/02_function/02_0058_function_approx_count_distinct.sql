@@ -0,0 +1,5 @@
+SELECT approx_count_distinct(number % 100) BETWEEN 95 AND 105 FROM numbers(10000);
+SELECT approx_count_distinct(number) BETWEEN 95000 AND 105000 FROM numbers_mt(100000);
+SELECT approx_count_distinct(number % 3, number % 5) BETWEEN 14 AND 16 FROM numbers(1000);
+SELECT approx_count_distinct(number) FROM numbers(10) WHERE 1 = 2;
+SELECT number % 2 AS k, approx_count_distinct(number) BETWEEN 45 AND 55 FROM numbers(100) GROUP BY k ORDER BY k;
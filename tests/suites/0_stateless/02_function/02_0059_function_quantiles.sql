@@ -0,0 +1,10 @@
+SELECT median(number) FROM numbers(10);
+SELECT quantile(number) FROM numbers(10);
+SELECT quantile(0.25)(number) FROM numbers(5);
+SELECT array_length(quantiles(0.1, 0.5, 0.9)(number)) FROM numbers(100);
+SELECT get(quantiles(0.1, 0.5, 0.9)(number), 2) = 9 FROM numbers(11);
+SELECT approx_percentile(0.5)(number) BETWEEN 49000 AND 51000 FROM numbers_mt(100000);
+SELECT approx_percentile(0.99)(number) BETWEEN 98500 AND 99500 FROM numbers_mt(100000);
+SELECT array_length(approx_percentiles(0.5, 0.9, 0.99)(number)) FROM numbers(1000);
+SELECT number % 2 AS k, median(number) FROM numbers(10) GROUP BY k ORDER BY k;
+SELECT quantile(1.5)(number) FROM numbers(10); -- {ErrorCode 1006}
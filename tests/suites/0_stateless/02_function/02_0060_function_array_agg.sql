@@ -0,0 +1,14 @@
+SELECT array_agg(number, number) FROM numbers(5);
+SELECT array_agg(number, 0 - number) FROM numbers(5);
+SELECT array_length(array_agg(number)) FROM numbers_mt(10000);
+SELECT group_concat(number, number) FROM numbers(5);
+SELECT group_concat(number, ' | ', number) FROM numbers(5);
+SELECT number % 2 AS k, group_concat(number, '-', number) FROM numbers(10) GROUP BY k ORDER BY k;
+
+DROP TABLE IF EXISTS t_array_agg;
+CREATE TABLE t_array_agg(id Int32, name String NULL);
+INSERT INTO t_array_agg VALUES (3, 'c'), (1, 'a'), (2, NULL), (4, 'd');
+SELECT array_agg(name, id) FROM t_array_agg;
+SELECT group_concat(name, ';', id) FROM t_array_agg;
+SELECT group_concat(name, ';', id) FROM t_array_agg WHERE name IS NULL;
+DROP TABLE t_array_agg;
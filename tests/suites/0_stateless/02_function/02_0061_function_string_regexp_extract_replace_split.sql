@@ -0,0 +1,23 @@
+SELECT REGEXP_EXTRACT('abc123def456', '[0-9]+');
+SELECT REGEXP_EXTRACT('user=alice id=1', 'user=(\\w+) id=(\\d+)', 2);
+SELECT REGEXP_EXTRACT('no digits', '[0-9]+') = '';
+SELECT REGEXP_EXTRACT('abc', NULL);
+SELECT REGEXP_EXTRACT('abc', '(b)', 2); -- {ErrorCode 1006}
+--
+SELECT REGEXP_REPLACE('a b c', 'b', 'X');
+SELECT REGEXP_REPLACE('abc def ghi', '[a-z]+', 'X', 1, 3);
+SELECT REGEXP_REPLACE('abc def ghi', '[a-z]+', 'X', 5);
+SELECT REGEXP_REPLACE('ABC', 'b', 'x', 1, 0, 'c');
+SELECT REGEXP_REPLACE('2022-04-15', '(\\d+)-(\\d+)-(\\d+)', '$3/$2/$1');
+SELECT REGEXP_REPLACE('abc', 'b', NULL);
+--
+SELECT REGEXP_SPLIT_TO_ARRAY('a1b22c', '[0-9]+');
+SELECT REGEXP_SPLIT_TO_ARRAY('aXbxc', 'x', 'c');
+SELECT REGEXP_SPLIT_TO_ARRAY(NULL, ',');
+--
+DROP TABLE IF EXISTS t_regexp;
+CREATE TABLE t_regexp(line String, pat String);
+INSERT INTO t_regexp VALUES ('GET /index.html 200', '^(\\w+) (\\S+) (\\d+)$'), ('POST /login 302', '^(\\w+) (\\S+) (\\d+)$'), ('k1=v1;k2=v2', '(\\w+)=(\\w+)');
+SELECT REGEXP_EXTRACT(line, pat, 2), REGEXP_REPLACE(line, pat, '$1') FROM t_regexp ORDER BY line;
+SELECT REGEXP_EXTRACT(line, '^(\\w+) ', 1) FROM t_regexp ORDER BY line;
+DROP TABLE t_regexp;
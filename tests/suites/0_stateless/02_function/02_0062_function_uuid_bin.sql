@@ -0,0 +1,18 @@
+SELECT LENGTH(gen_random_uuid()) = 36;
+SELECT is_uuid(gen_random_uuid());
+SELECT COUNT(DISTINCT gen_random_uuid()) FROM numbers(100);
+--
+SELECT hex(uuid_to_bin('6ccd780c-baba-1026-9564-5b8c656024db'));
+SELECT hex(uuid_to_bin('6ccd780c-baba-1026-9564-5b8c656024db', 1));
+SELECT bin_to_uuid(unhex('6ccd780cbaba102695645b8c656024db'));
+SELECT bin_to_uuid(unhex('1026baba6ccd780c95645b8c656024db'), 1);
+SELECT bin_to_uuid(uuid_to_bin('6ccd780c-baba-1026-9564-5b8c656024db', 1), 1);
+SELECT uuid_to_bin(NULL);
+SELECT bin_to_uuid(NULL);
+SELECT uuid_to_bin('not a uuid'); -- {ErrorCode 1006}
+SELECT bin_to_uuid('abc'); -- {ErrorCode 1006}
+--
+SELECT is_uuid('6ccd780c-baba-1026-9564-5b8c656024db');
+SELECT is_uuid('6ccd780cbaba102695645b8c656024db');
+SELECT is_uuid('6ccd780c-baba-1026-9564');
+SELECT is_uuid(zeroUUID());
@@ -0,0 +1,27 @@
+SELECT '=== IPV4 ===';
+SELECT IPV4_STRING_TO_NUM('10.0.5.9');
+SELECT IPV4_NUM_TO_STRING(167773449);
+SELECT TRY_IPV4_STRING_TO_NUM('hello');
+SELECT '=== INET6_ATON ===';
+SELECT HEX(INET6_ATON('10.0.5.9'));
+SELECT HEX(INET6_ATON('fdfe::5a55:caff:fefa:9089'));
+SELECT INET6_ATON(NULL);
+SELECT INET6_ATON('hello'); -- {ErrorCode 1060}
+SELECT TRY_INET6_ATON('hello');
+SELECT '=== INET6_NTOA ===';
+SELECT INET6_NTOA(UNHEX('0A000509'));
+SELECT INET6_NTOA(UNHEX('FDFE0000000000005A55CAFFFEFA9089'));
+SELECT INET6_NTOA(INET6_ATON('::ffff:10.0.5.9'));
+SELECT INET6_NTOA(NULL);
+SELECT INET6_NTOA('abc'); -- {ErrorCode 1060}
+SELECT TRY_INET6_NTOA('abc');
+SELECT '=== IP_IN_RANGE ===';
+SELECT IP_IN_RANGE('10.1.2.3', '10.0.0.0/8');
+SELECT IP_IN_RANGE('192.168.1.1', '10.0.0.0/8');
+SELECT IP_IN_RANGE(INET_ATON('10.1.2.3'), '10.0.0.0/8');
+SELECT IP_IN_RANGE('2001:db8::1', '2001:db8::/32');
+SELECT IP_IN_RANGE('10.0.0.1', '::ffff:0:0/96');
+SELECT IP_IN_RANGE('hello', '10.0.0.0/8');
+SELECT IP_IN_RANGE(NULL, '10.0.0.0/8');
+SELECT IP_IN_RANGE('10.1.2.3', '10.0.0.0/33'); -- {ErrorCode 1006}
+SELECT COUNT() FROM numbers(1024) WHERE IP_IN_RANGE(number + 167772160, '10.0.2.0/23');
@@ -0,0 +1,24 @@
+SELECT '=== EXPLICIT KEY ===';
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm'), '0123456789abcdef', 'aes-128-gcm');
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef0123456789abcdef', 'aes-256-gcm'), '0123456789abcdef0123456789abcdef', 'aes-256-gcm');
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-cbc'), '0123456789abcdef', 'aes-128-cbc');
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef01234567', 'aes-192-cbc'), '0123456789abcdef01234567', 'aes-192-cbc');
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef0123456789abcdef', 'aes-256-cbc'), '0123456789abcdef0123456789abcdef', 'aes-256-cbc');
+SELECT LENGTH(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm'));
+SELECT LENGTH(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-cbc'));
+SELECT AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm') = AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm');
+SELECT AES_ENCRYPT(NULL, '0123456789abcdef', 'aes-128-gcm');
+SELECT AES_ENCRYPT('databend', 'short', 'aes-128-gcm'); -- {ErrorCode 1006}
+SELECT AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-ecb'); -- {ErrorCode 1006}
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm'), 'fedcba9876543210', 'aes-128-gcm'); -- {ErrorCode 1006}
+SELECT TRY_AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef', 'aes-128-gcm'), 'fedcba9876543210', 'aes-128-gcm');
+SELECT '=== KEY FROM SETTINGS ===';
+SELECT AES_ENCRYPT('databend'); -- {ErrorCode 1006}
+SET aes_encryption_key = 'not hex'; -- {ErrorCode 1006}
+SET block_encryption_mode = 'aes-128-ecb'; -- {ErrorCode 1006}
+SET aes_encryption_key = '30313233343536373839616263646566';
+SET block_encryption_mode = 'aes-128-cbc';
+SELECT AES_DECRYPT(AES_ENCRYPT('databend'));
+SELECT AES_DECRYPT(AES_ENCRYPT('databend', '0123456789abcdef'), '0123456789abcdef');
+SELECT AES_DECRYPT(AES_ENCRYPT('databend'), '0123456789abcdef', 'aes-128-cbc');
+SELECT LENGTH(AES_ENCRYPT('databend'));
@@ -0,0 +1,20 @@
+SELECT '=== DATE_TRUNC ===';
+SELECT DATE_TRUNC('second', toDateTime(1630812366));
+SELECT DATE_TRUNC('minute', toDateTime(1630812366));
+SELECT DATE_TRUNC('hour', toDateTime(1630812366));
+SELECT DATE_TRUNC('day', toDateTime(1630812366));
+SELECT DATE_TRUNC('week', toDateTime(1630812366));
+SELECT DATE_TRUNC('month', toDateTime(1630812366));
+SELECT DATE_TRUNC('quarter', toDateTime(1630812366));
+SELECT DATE_TRUNC('year', toDateTime(1630812366));
+SELECT DATE_TRUNC('hour', toDate('2021-09-05'));
+SELECT DATE_TRUNC('quarter', toDate('2021-09-05'));
+SELECT DATE_TRUNC('fortnight', toDateTime(1630812366)); -- {ErrorCode 1006}
+SELECT '=== TO_START_OF_INTERVAL ===';
+SELECT TO_START_OF_INTERVAL(toDateTime(1630812366), INTERVAL 5 MINUTE);
+SELECT TO_START_OF_INTERVAL(toDateTime(1630812366), INTERVAL 3 HOUR);
+SELECT TO_START_OF_INTERVAL(toDate('2021-09-05'), INTERVAL '2 week');
+SELECT TO_START_OF_INTERVAL(toDate('2021-09-05'), INTERVAL '1 quarter');
+SELECT toStartOfInterval(toDate('2021-09-05'), INTERVAL 2 YEAR);
+SELECT TO_START_OF_INTERVAL(toDateTime(1630812366), INTERVAL 0 MINUTE); -- {ErrorCode 1006}
+SELECT COUNT(*) FROM numbers(3600) GROUP BY TO_START_OF_INTERVAL(toDateTime(number), INTERVAL 15 MINUTE) ORDER BY 1 LIMIT 1;
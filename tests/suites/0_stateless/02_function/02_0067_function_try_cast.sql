@@ -0,0 +1,11 @@
+SELECT TRY_CAST('33' AS INT), TRY_CAST('33aa' AS INT), TRY_CAST('aa' AS Float64);
+SELECT TRY_CAST('2021-03-05' AS DATE), TRY_CAST('2021-13-05' AS DATE);
+SELECT TRY_CAST(NULL AS INT);
+SELECT CAST('33aa' AS INT); -- {ErrorCode 1010}
+
+DROP TABLE IF EXISTS t_try_cast;
+CREATE TABLE t_try_cast(s String);
+INSERT INTO t_try_cast VALUES ('1'), ('x'), ('3');
+SELECT TRY_CAST(s AS UInt8) FROM t_try_cast ORDER BY s;
+SELECT count(TRY_CAST(s AS UInt8)), sum(TRY_CAST(s AS UInt8)) FROM t_try_cast;
+DROP TABLE t_try_cast;
@@ -0,0 +1,18 @@
+SELECT array_map(x -> x * 2, array(1, 2, 3));
+SELECT array_map(x -> concat(x, '!'), array('a', 'b'));
+SELECT array_filter(x -> x % 2 = 1, array(1, 2, 3, 4, 5));
+SELECT array_reduce((acc, x) -> acc + x, array(1, 2, 3), 0);
+SELECT array_reduce((acc, x) -> concat(acc, x), array('b', 'c'), 'a');
+SELECT array_sort(array(3, 1, 2)), array_sort(x -> -x, array(3, 1, 2));
+SELECT array_map(x -> x + y, array(1, 2)); -- {ErrorCode 1006}
+SELECT array_map(1, array(1, 2)); -- {ErrorCode 1006}
+
+DROP TABLE IF EXISTS t_arr_lambda;
+CREATE TABLE t_arr_lambda(id Int32, a Array(Int64));
+INSERT INTO t_arr_lambda SELECT 1, array(3, 4);
+INSERT INTO t_arr_lambda SELECT 2, array(1, 2, 3);
+INSERT INTO t_arr_lambda SELECT 3, array(5);
+SELECT id, array_map(x -> x + id, a) FROM t_arr_lambda ORDER BY id; -- {ErrorCode 1006}
+SELECT id, array_filter(x -> x > 2, a), array_reduce((acc, x) -> acc * x, a, 1) FROM t_arr_lambda ORDER BY id;
+SELECT id, array_sort(x -> -x, array_map(x -> x * 10, a)) FROM t_arr_lambda ORDER BY id;
+DROP TABLE t_arr_lambda;
@@ -0,0 +1,27 @@
+select '==get_path==';
+select get_path('{"k1":[0,1,2], "k2":{"k3":3,"k4":4}}', 'k1[2]');
+select get_path('{"k1":[0,1,2], "k2":{"k3":3,"k4":4}}', 'k2:k5');
+select get_path('{"k1"', 'k1'); -- {ErrorCode 1010}
+
+select '==json_extract_path_text==';
+select json_extract_path_text('{"k1":[0,1,"a"], "k2":{"k3":"b"}}', 'k2.k3');
+select json_extract_path_text('{"k1":[0,1,"a"], "k2":{"k3":"b"}}', 'k1');
+select json_extract_path_text(parse_json('{"k1":[0,1,"a"], "k2":{"k3":"b"}}'), 'k1[2]');
+select json_extract_path_text('{"k1":null}', 'k1');
+select json_extract_path_text('{"k1":[0,1,"a"]}', 'k2');
+select json_extract_path_text(null, 'k1');
+
+select '==object_keys==';
+select object_keys(parse_json('{"a":1, "b":[2], "c":{"d":3}}'));
+select object_keys('{"a":1}');
+select object_keys('[1, 2]');
+select object_keys(null);
+
+select '==check_json==';
+select check_json(parse_json('"[1,"'));
+
+select '==json_array_elements==';
+select value from json_array_elements('[1, "a", {"k":[2]}]');
+select get_path(value, 'k[0]') as k from json_array_elements(parse_json('[1, "a", {"k":[2]}]'));
+select count(*) from json_array_elements(null);
+select value from json_array_elements('{"k":1}'); -- {ErrorCode 1006}
@@ -0,0 +1,23 @@
+select '==to_char==';
+select to_char(toDateTime(1630812366), 'YYYY-MM-DD HH24:MI:SS');
+select to_char(toDate('2021-09-05'), 'Dy, DD Mon YYYY');
+select to_char(toDateTime(1630812366), '"Q"YY HH12:MI AM');
+select to_char(to_timestamp('2021-09-05 03:26:06.123456', 'YYYY-MM-DD HH24:MI:SS.US'), 'SS.MS US');
+select to_char(null, 'YYYY');
+select to_char(toDateTime(1630812366), '"YYYY'); -- {ErrorCode 1006}
+
+select '==to_timestamp==';
+select to_timestamp('2022-04-01 10:20', 'YYYY-MM-DD HH24:MI');
+select to_timestamp('01 Apr 2022', 'DD Mon YYYY');
+select to_timestamp(null, 'YYYY-MM-DD');
+select to_timestamp('2022-13-01', 'YYYY-MM-DD'); -- {ErrorCode 1060}
+select try_to_timestamp('2022-13-01', 'YYYY-MM-DD');
+select try_to_timestamp('2022-12-01', 'YYYY-MM-DD');
+
+select '==format_number==';
+select format_number(1234567.891, '#,##0.00');
+select format_number(3.14159, '$0.0#');
+select format_number(-42, '0000');
+select format_number(0.5, '#.##');
+select format_number(1234.5, 2);
+select format_number(1, 'abc'); -- {ErrorCode 1006}
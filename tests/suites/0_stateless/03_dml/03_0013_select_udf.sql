@@ -3,3 +3,7 @@ CREATE FUNCTION notnull AS (p) -> not(isnull(p));
 SELECT notnull(null);
 SELECT notnull('null');
 SELECT cal(1, 2, 3, 4, 6);
+CREATE FUNCTION is_adult AS (age) -> age >= 18 and age < 200 DESC = 'Checks the age of full age';
+SELECT number * 10 AS age, is_adult(number * 10) FROM numbers(3);
+SHOW FUNCTIONS LIKE 'is_adult';
+DROP FUNCTION is_adult;
@@ -0,0 +1,20 @@
+DROP TABLE IF EXISTS t_collation;
+CREATE TABLE t_collation(id Int32, name Varchar COLLATE utf8_general_ci, city Varchar);
+INSERT INTO t_collation VALUES (1, 'alice', 'Zürich'), (2, 'Bob', 'zurich'), (3, 'ALICE', 'Berlin'), (4, 'bob', 'Åre'), (5, 'Carol', 'berlin');
+
+SELECT '==column collation==';
+SELECT id FROM t_collation WHERE name = 'Alice' ORDER BY id;
+SELECT id, name FROM t_collation WHERE name > 'ALICE' ORDER BY name, id;
+SELECT name, count() FROM t_collation GROUP BY name ORDER BY name;
+SELECT name, max(name), min(id) FROM t_collation GROUP BY name ORDER BY name;
+
+SELECT '==expression collation==';
+SELECT id FROM t_collation WHERE city = 'berlin' ORDER BY id;
+SELECT id FROM t_collation WHERE city COLLATE utf8_unicode_ci = 'ZURICH' ORDER BY id;
+SELECT id, city FROM t_collation ORDER BY city COLLATE sv_SE, id;
+
+SELECT 'a' COLLATE klingon; -- {ErrorCode 1006}
+SELECT name = (city COLLATE de_DE) FROM t_collation; -- {ErrorCode 1007}
+CREATE TABLE t_collation_error(id Int32 COLLATE utf8_general_ci); -- {ErrorCode 1007}
+
+DROP TABLE t_collation;
@@ -0,0 +1,24 @@
+SELECT '==ranking==';
+SELECT number, row_number() OVER (PARTITION BY number % 2 ORDER BY number), rank() OVER (ORDER BY number % 3), dense_rank() OVER (ORDER BY number % 3) FROM numbers(6) ORDER BY number;
+
+SELECT '==aggregate==';
+SELECT number, sum(number) OVER (PARTITION BY number % 2) FROM numbers(4) ORDER BY number;
+SELECT number, sum(number) OVER (PARTITION BY number % 2 ORDER BY number) FROM numbers(6) ORDER BY number;
+SELECT number, sum(number) OVER (ORDER BY number ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM numbers(5) ORDER BY number;
+SELECT number, count() OVER (ORDER BY number % 3 RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM numbers(6) ORDER BY number;
+
+SELECT '==navigation==';
+SELECT number, lag(number) OVER (ORDER BY number), lead(number, 2, 100) OVER (ORDER BY number) FROM numbers(5) ORDER BY number;
+SELECT number, first_value(number) OVER (PARTITION BY number % 2 ORDER BY number), last_value(number) OVER (PARTITION BY number % 2 ORDER BY number ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) FROM numbers(6) ORDER BY number;
+
+SELECT '==group by==';
+SELECT number % 3 AS k, sum(number), rank() OVER (ORDER BY sum(number) DESC) FROM numbers(6) GROUP BY k ORDER BY k;
+
+SELECT '==errors==';
+SELECT row_number() FROM numbers(3); -- {ErrorCode 1005}
+SELECT abs(number) OVER () FROM numbers(3); -- {ErrorCode 1005}
+SELECT number FROM numbers(3) WHERE row_number() OVER () > 1; -- {ErrorCode 1005}
+SELECT lag(number, 1, 0, 1) OVER (ORDER BY number) FROM numbers(3); -- {ErrorCode 1028}
+SELECT sum(number) OVER (ORDER BY number GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM numbers(3); -- {ErrorCode 1002}
+SELECT sum(number) OVER (ORDER BY number ROWS BETWEEN CURRENT ROW AND 1 PRECEDING) FROM numbers(3); -- {ErrorCode 1005}
+SELECT sum(number) OVER (RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM numbers(3); -- {ErrorCode 1005}
@@ -0,0 +1,16 @@
+DROP DATABASE IF EXISTS db_03_0021;
+CREATE DATABASE db_03_0021;
+USE db_03_0021;
+
+create table t(a uint64);
+
+-- no files are copied into the table
+select count(*) from copy_history('db_03_0021', 't');
+
+-- unknown objects
+select * from copy_history('db_03_0021', 'not_exist'); -- {ErrorCode 1025}
+select * from copy_history('not_exist', 'not_exist'); -- {ErrorCode 1003}
+select * from copy_history('db_03_0021'); -- {ErrorCode 1006}
+
+DROP TABLE t;
+DROP DATABASE db_03_0021;
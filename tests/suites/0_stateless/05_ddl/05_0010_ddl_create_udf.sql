@@ -4,3 +4,25 @@ CREATE FUNCTION isnotempty AS (p) -> not(isnull(p)); -- {ErrorCode 2603}
 CREATE FUNCTION isnotempty_with_desc AS (p) -> not(isnull(p)) DESC = 'This is a description';
 CREATE FUNCTION IF NOT EXISTS isnotempty_with_desc AS (p) -> not(isnull(p)) DESC = 'This is a description';
 CREATE FUNCTION isnotempty_with_desc AS (p) -> not(isnull(p)) DESC = 'This is a description'; -- {ErrorCode 2603}
+CREATE FUNCTION udf_unused_param AS (a, b) -> a + 1; -- {ErrorCode 1005}
+CREATE FUNCTION udf_undeclared_param AS (a) -> a + b; -- {ErrorCode 1005}
+CREATE FUNCTION udf_recursive AS (a) -> udf_recursive(a); -- {ErrorCode 1005}
+ALTER FUNCTION isnotempty AS (a) -> a + b; -- {ErrorCode 1005}
+CREATE FUNCTION wasm_udf (INT) RETURNS INT LANGUAGE WASM HANDLER = 'f' AS '@udf_unknown_stage/f.wasm' DESC = 'This is a WASM UDF';
+CREATE FUNCTION wasm_udf_not_staged (INT) RETURNS INT LANGUAGE WASM HANDLER = 'f' AS '/tmp/f.wasm'; -- {ErrorCode 1005}
+SELECT definition FROM system.functions WHERE name = 'wasm_udf';
+SELECT wasm_udf(1); -- {ErrorCode 2501}
+DROP FUNCTION wasm_udf;
+CREATE FUNCTION external_gcd (INT, INT) RETURNS INT LANGUAGE python HANDLER = 'gcd' ADDRESS = '127.0.0.1:1';
+CREATE FUNCTION external_unknown_type (FOO) RETURNS INT LANGUAGE python HANDLER = 'f' ADDRESS = '127.0.0.1:1'; -- {ErrorCode 1007}
+SELECT definition FROM system.functions WHERE name = 'external_gcd';
+SELECT external_gcd(1); -- {ErrorCode 1005}
+SELECT external_gcd(1, 2); -- {ErrorCode 1063}
+DROP FUNCTION external_gcd;
+CREATE AGGREGATE FUNCTION my_avg AS (x) -> sum(x) / count(x) DESC = 'This is an aggregate UDF';
+CREATE AGGREGATE FUNCTION udaf_without_aggregate AS (x) -> x + 1; -- {ErrorCode 1005}
+CREATE AGGREGATE FUNCTION udaf_bare_param AS (x) -> x + sum(x); -- {ErrorCode 1005}
+SELECT is_aggregate, definition FROM system.functions WHERE name = 'my_avg';
+SELECT my_avg(number) FROM numbers(5);
+SELECT number % 2 AS k, my_avg(number) FROM numbers(6) GROUP BY k ORDER BY k;
+DROP AGGREGATE FUNCTION my_avg;
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS ext;
+
+CREATE EXTERNAL TABLE ext(a Int32, b String) LOCATION 's3://bucket/prefix/' CREDENTIALS=(aws_key_id='key' aws_secret_key='secret') FORMAT PARQUET;
+SHOW CREATE TABLE ext;
+DROP TABLE ext;
+
+CREATE TABLE ext(a Int32) ENGINE = EXTERNAL; -- {ErrorCode 1022}
+CREATE EXTERNAL TABLE ext(a Int32) LOCATION 's3://bucket/prefix/' FORMAT CSV; -- {ErrorCode 1022}
+CREATE EXTERNAL TABLE ext(a Int32) LOCATION 'bucket/prefix/' FORMAT PARQUET; -- {ErrorCode 1005}
@@ -0,0 +1,42 @@
+DROP DATABASE IF EXISTS db_undrop;
+CREATE DATABASE db_undrop;
+USE db_undrop;
+
+CREATE TABLE t(c1 int);
+INSERT INTO t VALUES (1), (2);
+UNDROP TABLE t; -- {ErrorCode 2302}
+
+DROP TABLE t;
+SELECT * FROM t; -- {ErrorCode 1025}
+UNDROP TABLE t;
+SELECT sum(c1) FROM t;
+UNDROP TABLE t; -- {ErrorCode 2302}
+
+-- Every table dropped by the name is kept, the one dropped last is restored first.
+DROP TABLE t;
+CREATE TABLE t(c1 int);
+INSERT INTO t VALUES (5);
+DROP TABLE t;
+UNDROP TABLE t;
+SELECT sum(c1) FROM t;
+DROP TABLE t ALL;
+UNDROP TABLE t;
+SELECT sum(c1) FROM t;
+
+-- The tables dropped within the retention are kept by vacuum.
+DROP TABLE t;
+VACUUM DROP TABLE RETAIN 1 HOURS;
+UNDROP TABLE t;
+SELECT sum(c1) FROM t;
+
+-- The vacuumed tables can not be restored.
+DROP TABLE t;
+VACUUM DROP TABLE FROM db_undrop;
+UNDROP TABLE t; -- {ErrorCode 1025}
+UNDROP TABLE t_unknown; -- {ErrorCode 1025}
+
+-- The dropped tables are vacuumed with the database.
+CREATE TABLE t(c1 int);
+INSERT INTO t VALUES (7);
+DROP TABLE t;
+DROP DATABASE db_undrop;
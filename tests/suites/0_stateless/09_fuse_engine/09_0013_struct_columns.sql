@@ -0,0 +1,11 @@
+DROP TABLE IF EXISTS t09_0013;
+
+CREATE TABLE t09_0013 AS SELECT number AS id, tuple(number, toString(number * 10)) AS s FROM numbers(3);
+
+SELECT * FROM t09_0013 ORDER BY id;
+SELECT s.item_0, s:item_1 FROM t09_0013 ORDER BY id;
+SELECT t09_0013.s.item_1 FROM t09_0013 WHERE s.item_0 > 0 ORDER BY id;
+SELECT get(s, 0) FROM t09_0013 ORDER BY s DESC;
+SELECT s.item_2 FROM t09_0013; -- {ErrorCode 1006}
+
+DROP TABLE t09_0013;
@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS t09_0014;
+
+CREATE TABLE t09_0014(id INT, name VARCHAR NULL) ENGINE=FUSE ROW_PER_PAGE=2 DICTIONARY_ENCODING=true STATISTICS_LEVEL=page WRITER_VERSION=1;
+
+INSERT INTO t09_0014 VALUES(1, 'a'), (2, 'b'), (3, 'a'), (4, NULL), (5, 'b');
+INSERT INTO t09_0014 VALUES(6, 'c');
+
+SELECT * FROM t09_0014 ORDER BY id;
+SELECT name, count(*) FROM t09_0014 WHERE name IS NOT NULL GROUP BY name ORDER BY name;
+
+CREATE TABLE t09_0014_bad(id INT) ENGINE=FUSE WRITER_VERSION=3; -- {ErrorCode 1022}
+CREATE TABLE t09_0014_bad(id INT) ENGINE=FUSE STATISTICS_LEVEL=row; -- {ErrorCode 1022}
+CREATE TABLE t09_0014_bad(id INT) ENGINE=FUSE ROW_PER_PAGE='x'; -- {ErrorCode 1022}
+
+DROP TABLE t09_0014;
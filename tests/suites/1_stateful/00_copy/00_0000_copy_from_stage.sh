@@ -14,15 +14,20 @@ aws --endpoint-url http://127.0.0.1:9900/ s3 cp s3://testbucket/admin/data/ontim
 
 echo "CREATE STAGE s1;" | $MYSQL_CLIENT_CONNECT
 echo "list @s1 PATTERN = 'ontime.*'" | $MYSQL_CLIENT_CONNECT
-echo "copy into ontime200 from '@s1' PATTERN = 'ontime.*parquet' FILE_FORMAT = (type = 'PARQUET');" | $MYSQL_CLIENT_CONNECT
+echo "copy into ontime200 from '@s1' PATTERN = 'ontime.*parquet' FILE_FORMAT = (type = 'PARQUET');" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
 echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
 echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
 
+## FILES and PATTERN are checked against the stage listing before any file is read.
+echo "list @s1 FILES = ('ontime_200.csv', 'ontime_200.parquet') PATTERN = '.*[.]csv'" | $MYSQL_CLIENT_CONNECT
+echo "copy into ontime200 from '@s1' FILES = ('ontime_200.parquet', 'not_exists.parquet') FILE_FORMAT = (type = 'PARQUET')" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "does not exist"
+echo "select count(1) from ontime200" | $MYSQL_CLIENT_CONNECT
+
 
 ## Copy from named external stage
 echo "CREATE STAGE named_external_stage url = 's3://testbucket/admin/data/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin');" | $MYSQL_CLIENT_CONNECT
 echo "list @named_external_stage PATTERN = 'ontime.*parquet'" | $MYSQL_CLIENT_CONNECT
-echo "copy into ontime200 from '@named_external_stage'  PATTERN = 'ontime.*parquet' FILE_FORMAT = (type = 'PARQUET')" | $MYSQL_CLIENT_CONNECT
+echo "copy into ontime200 from '@named_external_stage'  PATTERN = 'ontime.*parquet' FILE_FORMAT = (type = 'PARQUET')" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
 echo "select count(1), avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
 echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
 
@@ -9,5 +9,9 @@ echo "list @s2" | $MYSQL_CLIENT_CONNECT
 curl  -H "stage_name:s2" -F "upload=@${CURDIR}/00_0001_upload_to_stage.sh" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
 
 echo "list @s2" | $MYSQL_CLIENT_CONNECT
+echo "select name from list_stage('@s2')" | $MYSQL_CLIENT_CONNECT
+
+curl -s -H "stage_name:s2" -H "file_name:00_0001_upload_to_stage.sh" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/download_from_stage" | cmp -s - "${CURDIR}/00_0001_upload_to_stage.sh" && echo "download ok"
+
 echo "drop stage s2;" | $MYSQL_CLIENT_CONNECT
 
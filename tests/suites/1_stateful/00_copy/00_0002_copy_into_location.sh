@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_unload;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_reload;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_unload(a int, b varchar);" | $MYSQL_CLIENT_CONNECT
+echo "create table test_reload(a int, b varchar);" | $MYSQL_CLIENT_CONNECT
+echo "insert into test_unload values(1, 'a'), (2, 'b,c'), (3, 'd');" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE if not exists unload_stage;" | $MYSQL_CLIENT_CONNECT
+
+## Unload the table as csv, the files are data_<n>.csv and manifest.json.
+echo "copy into '@unload_stage/' from test_unload FILE_FORMAT = (type = 'CSV')" | $MYSQL_CLIENT_CONNECT | cut -f 2
+echo "list @unload_stage" | $MYSQL_CLIENT_CONNECT
+
+## The files exist.
+echo "copy into '@unload_stage/' from test_unload FILE_FORMAT = (type = 'CSV')" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "already exists"
+echo "copy into '@unload_stage/' from test_unload FILE_FORMAT = (type = 'CSV') OVERWRITE = true" | $MYSQL_CLIENT_CONNECT | cut -f 2
+
+## Load it back.
+echo "copy into test_reload from '@unload_stage' FILES = ('data_0.csv') FILE_FORMAT = (type = 'CSV')" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select * from test_reload order by a" | $MYSQL_CLIENT_CONNECT
+echo "truncate table test_reload" | $MYSQL_CLIENT_CONNECT
+
+## Unload a query into a single parquet file.
+echo "copy into '@unload_stage/single.parquet' from (select a, b from test_unload where a > 1) FILE_FORMAT = (type = 'PARQUET') SINGLE = true" | $MYSQL_CLIENT_CONNECT | cut -f 2
+echo "copy into test_reload from '@unload_stage' FILES = ('single.parquet') FILE_FORMAT = (type = 'PARQUET')" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select * from test_reload order by a" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_unload" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_reload" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists unload_stage" | $MYSQL_CLIENT_CONNECT
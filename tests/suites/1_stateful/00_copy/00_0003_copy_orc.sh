@@ -0,0 +1,13 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_orc;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_orc(id Int32, name Varchar null, city Varchar, tiny Int8);" | $MYSQL_CLIENT_CONNECT
+
+## Copy from the ORC file, the columns are matched by name.
+echo "copy into test_orc from 's3://testbucket/admin/data/sample.orc' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILE_FORMAT = (type = 'ORC')" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_orc" | $MYSQL_CLIENT_CONNECT
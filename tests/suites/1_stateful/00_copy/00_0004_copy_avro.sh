@@ -0,0 +1,13 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_avro;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_avro(id Int32, name Varchar null, color Varchar, note Varchar null);" | $MYSQL_CLIENT_CONNECT
+
+## Copy from the Avro file, the fields are matched by name and the missing ones get the defaults.
+echo "copy into test_avro from 's3://testbucket/admin/data/sample.avro' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILE_FORMAT = (type = 'AVRO')" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select * from test_avro order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_avro" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_upload_copy;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s5;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_upload_copy(id Int32, name Varchar);" | $MYSQL_CLIENT_CONNECT
+echo "create stage s5;" | $MYSQL_CLIENT_CONNECT
+
+printf '1,alice\n2,bob\n' > /tmp/upload_copy_1.csv
+printf '3,carol\n' > /tmp/upload_copy_2.csv
+
+## Upload the local files to the internal stage, then copy them into the table.
+curl -s -H "stage_name:s5" -H "relative_path:books" -F "upload=@/tmp/upload_copy_1.csv" -F "upload=@/tmp/upload_copy_2.csv" -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" | grep -o '"files":\[[^]]*\]'
+echo "list @s5/books" | $MYSQL_CLIENT_CONNECT | cut -f 1 | sed "s|.*/||" | sort
+
+echo "copy into test_upload_copy from '@s5/books'" | $MYSQL_CLIENT_CONNECT | cut -f 2,3 | sort
+echo "select * from test_upload_copy order by id" | $MYSQL_CLIENT_CONNECT
+
+## Download the staged file back.
+curl -s -H "stage_name:s5" -H "relative_path:books" -H "file_name:upload_copy_2.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/download_from_stage" | cmp -s - /tmp/upload_copy_2.csv && echo "download ok"
+
+## The paths out of the stage are rejected.
+curl -s -o /dev/null -w "%{http_code}\n" -H "stage_name:s5" -H "relative_path:../other" -H "file_name:upload_copy_2.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/download_from_stage"
+
+echo "drop table test_upload_copy;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s5;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/upload_copy_1.csv /tmp/upload_copy_2.csv
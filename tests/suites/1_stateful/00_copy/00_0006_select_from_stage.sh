@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop stage if exists s6;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s6;" | $MYSQL_CLIENT_CONNECT
+
+printf '1,alice\n2,bob\n' > /tmp/select_stage_1.csv
+printf '3,carol\n' > /tmp/select_stage_2.csv
+printf '4,dave\n5,eve\n' > /tmp/select_stage_3.csv
+printf '{"id": 6, "name": "frank"}\n{"id": 7, "name": "grace"}\n' > /tmp/select_stage_4.ndjson
+
+curl -s -H "stage_name:s6" -F "upload=@/tmp/select_stage_1.csv" -F "upload=@/tmp/select_stage_2.csv" -F "upload=@/tmp/select_stage_3.csv" -F "upload=@/tmp/select_stage_4.ndjson" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
+
+## The CSV files are selected by the pattern and read in parallel.
+echo "select c1, c2 from @s6 (pattern => '.*[.]csv') order by c1" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from @s6 (pattern => '.*_[12][.]csv')" | $MYSQL_CLIENT_CONNECT
+
+## The file format of the stage is overridden.
+echo "select id, name from @s6 (file_format => 'ndjson', pattern => '.*[.]ndjson') order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "select * from @s6 (pattern => 'nothing')" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "There are no files"
+echo "select * from @s6 (size => '1')" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "Unknown argument 'size'"
+
+echo "drop stage s6;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/select_stage_1.csv /tmp/select_stage_2.csv /tmp/select_stage_3.csv /tmp/select_stage_4.ndjson
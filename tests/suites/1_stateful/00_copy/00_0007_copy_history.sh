@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists ontime_history;" | $MYSQL_CLIENT_CONNECT
+## Create table
+cat $CURDIR/../ontime/create_table.sql | sed 's/ontime/ontime_history/g' | $MYSQL_CLIENT_CONNECT
+
+copy_sql="copy into ontime_history from 's3://testbucket/admin/data/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILES = ('ontime_200.csv') FILE_FORMAT = (type = 'CSV' field_delimiter = ','  record_delimiter = '\n' skip_header = 1)"
+
+## The file is loaded once, the second COPY skips it.
+echo "$copy_sql" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "$copy_sql" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select count(1) from ontime_history" | $MYSQL_CLIENT_CONNECT
+echo "select file like '%ontime_200.csv', size > 0, rows_loaded from copy_history('default', 'ontime_history')" | $MYSQL_CLIENT_CONNECT
+
+## The history is cleared by truncate, the file is loaded again.
+echo "truncate table ontime_history" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from copy_history('default', 'ontime_history')" | $MYSQL_CLIENT_CONNECT
+echo "$copy_sql" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select count(1) from ontime_history" | $MYSQL_CLIENT_CONNECT
+
+## The history is kept for copy_history_expire_secs.
+echo "set copy_history_expire_secs = 1; truncate table ontime_history; $copy_sql" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+sleep 2
+echo "$copy_sql" | $MYSQL_CLIENT_CONNECT | cut -f 2,3
+echo "select count(1) from ontime_history" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table ontime_history;" | $MYSQL_CLIENT_CONNECT
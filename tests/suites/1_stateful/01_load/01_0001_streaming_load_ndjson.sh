@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_ndjson;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_ndjson(a Int32, b Varchar null, c Varchar);" | $MYSQL_CLIENT_CONNECT
+
+printf '{"a":1,"b":"x","c":"y"}\n{"a":2}\n' > /tmp/test_ndjson_missing.ndjson
+printf '{"a":"3","c":4.5}\n{"a":true,"b":null,"c":{"k":1}}\n' > /tmp/test_ndjson_mismatch.ndjson
+
+## The missing keys are loaded as NULL or the type default.
+curl -s -H "insert_sql:insert into test_ndjson format NdJson" -F "upload=@/tmp/test_ndjson_missing.ndjson" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from test_ndjson order by a" | $MYSQL_CLIENT_CONNECT
+
+## The mismatched JSON types are rejected in strict mode, and converted if json_strict is 0.
+curl -s -H "insert_sql:insert into test_ndjson format NdJson" -F "upload=@/tmp/test_ndjson_mismatch.ndjson" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(*) from test_ndjson" | $MYSQL_CLIENT_CONNECT
+curl -s -H "insert_sql:insert into test_ndjson format NdJson" -H "json_strict:0" -F "upload=@/tmp/test_ndjson_mismatch.ndjson" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from test_ndjson order by a, c" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_ndjson;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/test_ndjson_missing.ndjson /tmp/test_ndjson_mismatch.ndjson
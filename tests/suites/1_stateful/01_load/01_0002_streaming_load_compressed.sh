@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_compressed;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_compressed(a Int32, b Varchar);" | $MYSQL_CLIENT_CONNECT
+
+printf '1,x\n2,y\n' | gzip > /tmp/test_compressed.csv.gz
+printf '3,z\n' | gzip > /tmp/test_compressed_gzip
+
+## Detected by the file extension and by the magic bytes.
+curl -s -H "insert_sql:insert into test_compressed format Csv" -F "upload=@/tmp/test_compressed.csv.gz" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+curl -s -H "insert_sql:insert into test_compressed format Csv" -F "upload=@/tmp/test_compressed_gzip" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from test_compressed order by a" | $MYSQL_CLIENT_CONNECT
+
+## The detection is disabled by compression none, the compressed bytes fail to load.
+curl -s -H "insert_sql:insert into test_compressed format Csv" -H "compression:none" -F "upload=@/tmp/test_compressed.csv.gz" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(*) from test_compressed" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_compressed;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/test_compressed.csv.gz /tmp/test_compressed_gzip
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_on_error;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_on_error(a Int32, b Varchar);" | $MYSQL_CLIENT_CONNECT
+
+printf '1,x\nbad,y\n3,z\n' > /tmp/test_on_error.csv
+
+## The malformed record aborts the load by default.
+curl -s -H "insert_sql:insert into test_on_error format Csv" -F "upload=@/tmp/test_on_error.csv" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(*) from test_on_error" | $MYSQL_CLIENT_CONNECT
+
+## The malformed record is skipped with on_error continue.
+curl -s -H "insert_sql:insert into test_on_error format Csv" -H "on_error:continue" -F "upload=@/tmp/test_on_error.csv" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from test_on_error order by a" | $MYSQL_CLIENT_CONNECT
+
+## Both loads rejected the record.
+echo "select line, record from system.load_errors where file = 'test_on_error.csv'" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_on_error;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/test_on_error.csv
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_load_response;" | $MYSQL_CLIENT_CONNECT
+echo "create table test_load_response(a Int32, b Varchar);" | $MYSQL_CLIENT_CONNECT
+
+printf '1,x\nbad,y\n3,z\n' > /tmp/test_load_response.csv
+
+## The rows loaded and the rejected records are returned.
+curl -s -H "insert_sql:insert into test_load_response format Csv" -H "on_error:continue" -F "upload=@/tmp/test_load_response.csv" -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -o '"state":"[A-Z]*"\|"rows_loaded":[0-9]*,"errors_seen":[0-9]*'
+
+## A failed load loads nothing.
+curl -s -H "insert_sql:insert into test_load_response format Csv" -F "upload=@/tmp/test_load_response.csv" -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -o '"state":"[A-Z]*"\|"rows_loaded":[0-9]*,"errors_seen":[0-9]*'
+
+echo "select count(*) from test_load_response" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_load_response;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/test_load_response.csv
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists ontime_ext;" | $MYSQL_CLIENT_CONNECT
+
+## Create the external table over the parquet directory, the files are not copied.
+echo "create external table ontime_ext (Year UInt16, DayOfWeek UInt8, Origin String) location 's3://testbucket/admin/data/ontime_parquet/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') format parquet" | $MYSQL_CLIENT_CONNECT
+
+## Read all the row groups.
+echo "select count(1), avg(Year), sum(DayOfWeek) from ontime_ext" | $MYSQL_CLIENT_CONNECT
+echo "select count(1) from ontime_ext where Origin = 'DTW'" | $MYSQL_CLIENT_CONNECT
+
+## The row groups are pruned by the statistics of the footers.
+echo "explain select count(1) from ontime_ext where Year = 2020" | $MYSQL_CLIENT_CONNECT | grep -o "partitions_scanned: [0-9]*, partitions_total: [0-9]*"
+echo "explain select count(1) from ontime_ext where Year > 2020" | $MYSQL_CLIENT_CONNECT | grep -o "partitions_scanned: [0-9]*, partitions_total: [0-9]*"
+echo "select count(1) from ontime_ext where Year > 2020" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table if exists ontime_ext;" | $MYSQL_CLIENT_CONNECT
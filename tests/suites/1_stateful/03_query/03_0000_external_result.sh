@@ -0,0 +1,41 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## The result is spooled into the csv files of the storage, the files are fetched by the presigned URLs.
+curl -s -H "Content-Type: application/json" -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+	-d '{"sql": "select number, number * 2 as double from numbers(100000)", "pagination": {"wait_time_secs": -1}, "external_result": {"format": "csv", "max_file_size": 1000000}}' |
+	python3 -c '
+import json
+import sys
+import urllib.request
+
+resp = json.load(sys.stdin)
+result = resp["external_result"]
+print(resp["state"], len(resp["data"]), [f["name"] for f in resp["schema"]["fields"]])
+print(result["total_rows"], len(result["files"]) > 1)
+
+rows = 0
+total = 0
+for f in result["files"]:
+    lines = urllib.request.urlopen(f["url"]).read().decode().splitlines()
+    assert len(lines) == f["rows"]
+    rows += len(lines)
+    total += sum(int(line.split(",")[0]) for line in lines)
+print(rows, total)
+'
+
+## The parquet files.
+curl -s -H "Content-Type: application/json" -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+	-d '{"sql": "select number from numbers(10)", "pagination": {"wait_time_secs": -1}, "external_result": {}}' |
+	python3 -c '
+import json
+import sys
+import urllib.request
+
+resp = json.load(sys.stdin)
+result = resp["external_result"]
+data = urllib.request.urlopen(result["files"][0]["url"]).read()
+print(result["total_rows"], len(result["files"]), data[:4] == b"PAR1")
+'
@@ -0,0 +1,191 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use clap::Args;
+use futures::AsyncReadExt;
+use futures::StreamExt;
+use opendal::services::fs;
+use opendal::services::s3;
+use opendal::ObjectMode;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+const S3_STORAGE_REGION: &str = "S3_STORAGE_REGION";
+const S3_STORAGE_ENDPOINT_URL: &str = "S3_STORAGE_ENDPOINT_URL";
+const S3_STORAGE_ACCESS_KEY_ID: &str = "S3_STORAGE_ACCESS_KEY_ID";
+const S3_STORAGE_SECRET_ACCESS_KEY: &str = "S3_STORAGE_SECRET_ACCESS_KEY";
+
+const BACKUP_FILE_PREFIX: &str = "databend-meta-backup-";
+const BACKUP_FILE_SUFFIX: &str = ".json";
+const BACKUP_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// The credential of the backups on s3.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Args)]
+pub struct BackupS3Config {
+    #[clap(long, env = S3_STORAGE_REGION, default_value = "")]
+    pub s3_region: String,
+
+    #[clap(long, env = S3_STORAGE_ENDPOINT_URL, default_value = "https://s3.amazonaws.com")]
+    pub s3_endpoint_url: String,
+
+    #[clap(long, env = S3_STORAGE_ACCESS_KEY_ID, default_value = "")]
+    pub s3_access_key_id: String,
+
+    #[clap(long, env = S3_STORAGE_SECRET_ACCESS_KEY, default_value = "")]
+    pub s3_secret_access_key: String,
+}
+
+/// The location of the backups, a local path or `s3://<bucket>/<path>`.
+///
+/// If the location ends with `/`, it is a dir of the backups named by the time they are
+/// created: a new backup file is created in it, and the latest one is restored.
+pub struct BackupStorage {
+    operator: Operator,
+    dir: String,
+    file_name: String,
+}
+
+impl BackupStorage {
+    pub async fn create(location: &str, conf: &BackupS3Config) -> anyhow::Result<BackupStorage> {
+        let (bucket, path) = match location.strip_prefix("s3://") {
+            None => (None, location),
+            Some(bucket_path) => match bucket_path.split_once('/') {
+                Some((bucket, path)) if !bucket.is_empty() => (Some(bucket), path),
+                _ => return Err(anyhow!("Invalid s3 backup location: {}", location)),
+            },
+        };
+
+        let (dir, file_name) = match path.rfind('/') {
+            Some(pos) => (&path[..=pos], &path[pos + 1..]),
+            None => ("", path),
+        };
+
+        let accessor = match bucket {
+            Some(bucket) => {
+                let mut builder = s3::Backend::build();
+                builder.endpoint(&conf.s3_endpoint_url);
+                builder.region(&conf.s3_region);
+                builder.access_key_id(&conf.s3_access_key_id);
+                builder.secret_access_key(&conf.s3_secret_access_key);
+                builder.bucket(bucket);
+                if !dir.is_empty() {
+                    builder.root(&format!("/{}", dir.trim_start_matches('/')));
+                }
+                builder.finish().await?
+            }
+            None => {
+                let root = std::env::current_dir()?.join(dir);
+                fs::Backend::build()
+                    .root(&root.display().to_string())
+                    .finish()
+                    .await?
+            }
+        };
+
+        let dir_location = &location[..location.len() - file_name.len()];
+        Ok(BackupStorage {
+            operator: Operator::new(accessor),
+            dir: dir_location.to_string(),
+            file_name: file_name.to_string(),
+        })
+    }
+
+    /// Write the exported lines into the backup, returns the location of the backup file.
+    pub async fn write(&self, lines: &[String]) -> anyhow::Result<String> {
+        let file_name = match self.file_name.is_empty() {
+            true => backup_file_name(&Utc::now()),
+            false => self.file_name.clone(),
+        };
+
+        let mut data = lines.join("\n");
+        data.push('\n');
+        self.operator
+            .object(&file_name)
+            .write(data.into_bytes())
+            .await?;
+        Ok(format!("{}{}", self.dir, file_name))
+    }
+
+    /// Read the lines of the backup, the latest one created no later than `before` is read if
+    /// the location is a dir. Returns the location of the backup file and the lines.
+    pub async fn read(
+        &self,
+        before: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<(String, Vec<String>)> {
+        let file_name = match self.file_name.is_empty() {
+            true => self.latest_backup(before).await?,
+            false => self.file_name.clone(),
+        };
+
+        let mut reader = self.operator.object(&file_name).reader().await?;
+        let mut data = String::new();
+        reader.read_to_string(&mut data).await?;
+
+        let lines = data
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok((format!("{}{}", self.dir, file_name), lines))
+    }
+
+    async fn latest_backup(&self, before: Option<DateTime<Utc>>) -> anyhow::Result<String> {
+        let mut latest: Option<(DateTime<Utc>, String)> = None;
+
+        let mut objects = self.operator.object("/").list().await?;
+        while let Some(object) = objects.next().await {
+            let mut object = object?;
+            let meta = object.metadata_cached().await?;
+            if meta.mode() != ObjectMode::FILE {
+                continue;
+            }
+
+            let file_name = meta.path().rsplit('/').next().unwrap_or_default();
+            if let Some(created_on) = parse_backup_file_name(file_name) {
+                let in_time = before.map_or(true, |before| created_on <= before);
+                let is_later = latest.as_ref().map_or(true, |(t, _)| created_on > *t);
+                if in_time && is_later {
+                    latest = Some((created_on, file_name.to_string()));
+                }
+            }
+        }
+
+        match latest {
+            Some((_, file_name)) => Ok(file_name),
+            None => Err(anyhow!("No backup found in {}", self.dir)),
+        }
+    }
+}
+
+pub fn backup_file_name(created_on: &DateTime<Utc>) -> String {
+    format!(
+        "{}{}{}",
+        BACKUP_FILE_PREFIX,
+        created_on.format(BACKUP_TIME_FORMAT),
+        BACKUP_FILE_SUFFIX
+    )
+}
+
+pub fn parse_backup_file_name(file_name: &str) -> Option<DateTime<Utc>> {
+    let time = file_name
+        .strip_prefix(BACKUP_FILE_PREFIX)?
+        .strip_suffix(BACKUP_FILE_SUFFIX)?;
+    let time = NaiveDateTime::parse_from_str(time, BACKUP_TIME_FORMAT).ok()?;
+    Some(DateTime::from_utc(time, Utc))
+}
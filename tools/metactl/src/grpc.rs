@@ -16,7 +16,7 @@ use common_meta_grpc::MetaGrpcClient;
 use common_meta_types::protobuf::Empty;
 use tokio_stream::StreamExt;
 
-pub async fn export_meta(addr: &str) -> anyhow::Result<()> {
+pub async fn export_meta(addr: &str) -> anyhow::Result<Vec<String>> {
     let client = MetaGrpcClient::try_create(addr, "root", "xxx", None, None).await?;
 
     let mut grpc_client = client.make_client().await?;
@@ -25,13 +25,11 @@ pub async fn export_meta(addr: &str) -> anyhow::Result<()> {
 
     let mut stream = exported.into_inner();
 
+    let mut lines = vec![];
     while let Some(chunk_res) = stream.next().await {
         let chunk = chunk_res?;
-
-        for line in &chunk.data {
-            println!("{}", line);
-        }
+        lines.extend(chunk.data);
     }
 
-    Ok(())
+    Ok(lines)
 }
@@ -14,6 +14,7 @@
 
 #![feature(stdin_forwarders)]
 
+mod backup;
 mod grpc;
 
 use std::collections::BTreeMap;
@@ -21,10 +22,17 @@ use std::io;
 use std::net::SocketAddr;
 
 use anyhow::anyhow;
+use backup::BackupS3Config;
+use backup::BackupStorage;
+use chrono::DateTime;
+use chrono::Utc;
 use clap::Parser;
 use common_base::tokio;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::sled_key_spaces::KeySpaceKV;
+use common_meta_raft_store::state::RaftStateKey;
+use common_meta_raft_store::state::RaftStateValue;
+use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::init_sled_db;
 use common_tracing::init_global_tracing;
@@ -47,6 +55,28 @@ struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// Export the meta into a backup file at a local path or `s3://<bucket>/<path>`, a backup
+    /// named by the current time is created if the location ends with `/`.
+    #[clap(long, default_value = "")]
+    pub backup: String,
+
+    /// Clear the raft dir and import the meta from a backup file at a local path or
+    /// `s3://<bucket>/<path>`, the latest backup is restored if the location ends with `/`.
+    #[clap(long, default_value = "")]
+    pub restore: String,
+
+    /// Restore the latest backup created no later than the time, e.g. `2022-04-20T08:00:00Z`.
+    #[clap(long, default_value = "")]
+    pub restore_before: String,
+
+    /// Restore the data without the raft logs and the membership of the backup, the restored
+    /// raft dir boots as a new cluster, e.g. to clone the meta into another environment.
+    #[clap(long)]
+    pub restore_as_new_cluster: bool,
+
+    #[clap(flatten)]
+    pub s3: BackupS3Config,
+
     #[clap(long, env = METASRV_GRPC_API_ADDRESS, default_value = "127.0.0.1:9191")]
     pub grpc_api_address: String,
 
@@ -82,38 +112,81 @@ async fn main() -> anyhow::Result<()> {
     eprintln!("raft_config: {}", pretty(raft_config)?);
 
     // export from grpc api if metasrv is running
-    if config.export && !config.grpc_api_address.is_empty() {
-        let grpc_api_addr: SocketAddr = match config.grpc_api_address.parse() {
-            Ok(addr) => addr,
-            Err(e) => {
-                eprintln!(
-                    "ERROR: grpc api address is invalid: {}",
-                    &config.grpc_api_address
-                );
-                return Err(anyhow!(e));
-            }
-        };
-        if service_is_running(grpc_api_addr).await? {
-            eprintln!("export meta from: {}", &config.grpc_api_address);
-            grpc::export_meta(&config.grpc_api_address).await?;
+    if config.export || !config.backup.is_empty() {
+        if let Some(lines) = export_from_running_meta(&config).await? {
+            output_exported(&config, lines).await?;
             return Ok(());
         }
     }
 
     init_sled_db(raft_config.raft_dir.clone());
 
-    if config.export {
+    if config.export || !config.backup.is_empty() {
         eprintln!("export meta dir from: {}", raft_config.raft_dir);
-        print_meta()?;
+        let lines = export_from_dir()?;
+        output_exported(&config, lines).await?;
     } else if config.import {
         eprintln!("import meta dir into: {}", raft_config.raft_dir);
+        let lines = io::stdin().lines().collect::<Result<Vec<_>, _>>()?;
+        clear()?;
+        import_lines(lines, raft_config, false)?;
+    } else if !config.restore.is_empty() {
+        let storage = BackupStorage::create(&config.restore, &config.s3).await?;
+        let before = match config.restore_before.is_empty() {
+            true => None,
+            false => Some(config.restore_before.parse::<DateTime<Utc>>()?),
+        };
+        let (location, lines) = storage.read(before).await?;
+        eprintln!(
+            "restore meta dir {} from: {}",
+            raft_config.raft_dir, location
+        );
         clear()?;
-        import_from_stdin()?;
+        import_lines(lines, raft_config, config.restore_as_new_cluster)?;
     }
 
     Ok(())
 }
 
+async fn export_from_running_meta(config: &Config) -> anyhow::Result<Option<Vec<String>>> {
+    if config.grpc_api_address.is_empty() {
+        return Ok(None);
+    }
+
+    let grpc_api_addr: SocketAddr = match config.grpc_api_address.parse() {
+        Ok(addr) => addr,
+        Err(e) => {
+            eprintln!(
+                "ERROR: grpc api address is invalid: {}",
+                &config.grpc_api_address
+            );
+            return Err(anyhow!(e));
+        }
+    };
+
+    if !service_is_running(grpc_api_addr).await? {
+        return Ok(None);
+    }
+
+    eprintln!("export meta from: {}", &config.grpc_api_address);
+    Ok(Some(grpc::export_meta(&config.grpc_api_address).await?))
+}
+
+/// Print the exported lines, or write them into the backup.
+async fn output_exported(config: &Config, lines: Vec<String>) -> anyhow::Result<()> {
+    if config.backup.is_empty() {
+        for line in lines {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    let storage = BackupStorage::create(&config.backup, &config.s3).await?;
+    let location = storage.write(&lines).await?;
+    eprintln!("Backup {} records to {}", lines.len(), location);
+    Ok(())
+}
+
 fn pretty<T>(v: &T) -> Result<String, serde_json::Error>
 where T: Serialize {
     serde_json::to_string_pretty(v)
@@ -133,19 +206,30 @@ fn clear() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Read every line from stdin, deserialize it into tree_name, key and value. Insert them into sled db and flush.
-fn import_from_stdin() -> anyhow::Result<()> {
+/// Deserialize every line into tree_name, key and value. Insert them into sled db and flush.
+///
+/// To restore as a new cluster, only the data in the active state machine is imported, the raft
+/// state, the logs, the nodes and the applied state are dropped.
+fn import_lines(
+    lines: Vec<String>,
+    raft_config: &RaftConfig,
+    as_new_cluster: bool,
+) -> anyhow::Result<()> {
     let db = get_sled_db();
 
-    let mut trees = BTreeMap::new();
+    let mut records = Vec::with_capacity(lines.len());
+    for line in &lines {
+        let (tree_name, kv_variant): (String, KeySpaceKV) = serde_json::from_str(line)?;
+        records.push((tree_name, kv_variant));
+    }
 
-    let lines = io::stdin().lines();
-    let mut n = 0;
-    for line in lines {
-        let l = line?;
-        let (tree_name, kv_variant): (String, KeySpaceKV) = serde_json::from_str(&l)?;
-        // eprintln!("line: {}", l);
+    if as_new_cluster {
+        records = new_cluster_records(records, raft_config);
+    }
 
+    let mut trees = BTreeMap::new();
+    let mut n = 0;
+    for (tree_name, kv_variant) in records {
         if !trees.contains_key(&tree_name) {
             let tree = db.open_tree(&tree_name)?;
             trees.insert(tree_name.clone(), tree);
@@ -168,15 +252,53 @@ fn import_from_stdin() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Print the entire sled db.
+fn new_cluster_records(
+    records: Vec<(String, KeySpaceKV)>,
+    raft_config: &RaftConfig,
+) -> Vec<(String, KeySpaceKV)> {
+    // The state machine in use, the others are left by the unfinished snapshot installing.
+    let mut sm_id = 0;
+    for (_, kv_variant) in &records {
+        if let KeySpaceKV::RaftStateKV {
+            key: RaftStateKey::StateMachineId,
+            value: RaftStateValue::StateMachineId((id, _)),
+        } = kv_variant
+        {
+            sm_id = *id;
+        }
+    }
+
+    let sm_tree_suffix = format!("state_machine/{}", sm_id);
+    let new_sm_tree = StateMachine::tree_name(raft_config, 0);
+    records
+        .into_iter()
+        .filter(|(tree_name, kv_variant)| {
+            tree_name.ends_with(&sm_tree_suffix)
+                && matches!(
+                    kv_variant,
+                    KeySpaceKV::GenericKV { .. }
+                        | KeySpaceKV::Sequences { .. }
+                        | KeySpaceKV::Databases { .. }
+                        | KeySpaceKV::Tables { .. }
+                        | KeySpaceKV::TableLookup { .. }
+                        | KeySpaceKV::DatabaseLookup { .. }
+                        | KeySpaceKV::DroppedTableLookup { .. }
+                )
+        })
+        .map(|(_, kv_variant)| (new_sm_tree.clone(), kv_variant))
+        .collect()
+}
+
+/// Export the entire sled db.
 ///
 /// The output encodes every key-value into one line:
 /// `[sled_tree_name, {key_space: {key, value}}]`
 /// E.g.:
 /// `["test-29000-state_machine/0",{"GenericKV":{"key":"wow","value":{"seq":3,"meta":null,"data":[119,111,119]}}}`
-fn print_meta() -> anyhow::Result<()> {
+fn export_from_dir() -> anyhow::Result<Vec<String>> {
     let db = get_sled_db();
 
+    let mut lines = vec![];
     let tree_names = db.tree_names();
     for n in tree_names.iter() {
         let name = String::from_utf8(n.to_vec())?;
@@ -191,11 +313,11 @@ fn print_meta() -> anyhow::Result<()> {
 
             let line = serde_json::to_string(&tree_kv)?;
 
-            println!("{}", line);
+            lines.push(line);
         }
     }
 
-    Ok(())
+    Ok(lines)
 }
 
 // if port is open, service is running